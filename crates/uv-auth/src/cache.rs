@@ -11,8 +11,9 @@ use url::Url;
 use uv_once_map::OnceMap;
 use uv_redacted::DisplaySafeUrl;
 
+use crate::cloud::ProviderTokens;
 use crate::credentials::{Authentication, CredentialsFromUrlError, Username};
-use crate::{Credentials, Realm};
+use crate::{CredentialLookup, Credentials, Realm};
 
 type FxOnceMap<K, V> = OnceMap<K, V, BuildHasherDefault<FxHasher>>;
 
@@ -41,6 +42,10 @@ pub struct CredentialsCache {
     pub(crate) fetches: FxOnceMap<(FetchUrl, Username), Option<Arc<Authentication>>>,
     /// A cache per URL, uses a trie for efficient prefix queries.
     urls: RwLock<UrlTrie<Arc<Authentication>>>,
+    /// The external credential sources consulted per realm, to explain authentication failures.
+    lookups: RwLock<FxHashMap<Realm, Vec<CredentialLookup>>>,
+    /// Credentials minted by the built-in auth providers, shared across indexes.
+    pub(crate) provider_tokens: ProviderTokens,
 }
 
 impl Default for CredentialsCache {
@@ -56,6 +61,39 @@ impl CredentialsCache {
             fetches: FxOnceMap::default(),
             realms: RwLock::new(FxHashMap::default()),
             urls: RwLock::new(UrlTrie::new()),
+            lookups: RwLock::new(FxHashMap::default()),
+            provider_tokens: ProviderTokens::default(),
+        }
+    }
+
+    /// Return the external credential sources consulted for the realm of a URL, in order.
+    pub fn lookups(&self, url: &Url) -> Vec<CredentialLookup> {
+        self.lookups
+            .read()
+            .unwrap()
+            .get(&Realm::from(url))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Record the external credential sources consulted for the realm of a URL.
+    ///
+    /// A later lookup against the same source replaces the earlier outcome.
+    pub(crate) fn record_lookups(&self, url: &DisplaySafeUrl, lookups: Vec<CredentialLookup>) {
+        if lookups.is_empty() {
+            return;
+        }
+        let mut realms = self.lookups.write().unwrap();
+        let existing = realms.entry(Realm::from(url)).or_default();
+        for lookup in lookups {
+            if let Some(entry) = existing
+                .iter_mut()
+                .find(|entry| entry.source == lookup.source)
+            {
+                *entry = lookup;
+            } else {
+                existing.push(lookup);
+            }
         }
     }
 
@@ -0,0 +1,774 @@
+//! Built-in credential providers for indexes hosted by cloud platforms.
+//!
+//! Unlike the keyring, these providers mint short-lived access tokens on demand: Azure Artifacts
+//! accepts a Microsoft Entra token for a managed identity, and Google Artifact Registry accepts an
+//! OAuth 2.0 access token for a user or service account.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use http::StatusCode;
+use jiff::{SignedDuration, Timestamp};
+use reqwest_middleware::{ClientWithMiddleware, RequestBuilder};
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+use tracing::{debug, trace};
+use url::Url;
+
+use uv_fs::Simplified;
+use uv_redacted::DisplaySafeUrl;
+use uv_static::EnvVars;
+
+use crate::credentials::Authentication;
+use crate::{AuthProvider, Credentials};
+
+/// The Microsoft Entra resource ID of Azure DevOps, which includes Azure Artifacts.
+const AZURE_DEVOPS_RESOURCE: &str = "499b84ac-1321-427f-aa17-267ca6975798";
+
+/// The default base URL of the Azure Instance Metadata Service.
+const AZURE_IMDS_URL: &str = "http://169.254.169.254";
+
+/// The default host of the Google Cloud metadata server.
+const GCE_METADATA_HOST: &str = "metadata.google.internal";
+
+/// The default Google OAuth 2.0 token endpoint.
+const GOOGLE_TOKEN_URI: &str = "https://oauth2.googleapis.com/token";
+
+/// The OAuth 2.0 scope requested for Google Cloud access tokens.
+const GOOGLE_CLOUD_PLATFORM_SCOPE: &str = "https://www.googleapis.com/auth/cloud-platform";
+
+/// The lifetime requested for service account assertions, in seconds.
+const GOOGLE_ASSERTION_LIFETIME_SECS: i64 = 3600;
+
+/// Treat tokens as expired this many seconds ahead of their expiry.
+const EXPIRY_TOLERANCE_SECS: i64 = 60;
+
+/// The environment consulted by the built-in credential providers.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ProviderEnvironment {
+    /// A personal access token for Azure Artifacts.
+    pub(crate) azure_devops_token: Option<String>,
+    /// The client ID of a user-assigned managed identity.
+    pub(crate) azure_client_id: Option<String>,
+    /// The base URL of the Azure Instance Metadata Service, if overridden.
+    pub(crate) azure_imds_url: Option<String>,
+    /// An explicit path to Google Cloud credentials.
+    pub(crate) google_application_credentials: Option<PathBuf>,
+    /// The Google Cloud CLI configuration directory.
+    pub(crate) gcloud_config_dir: Option<PathBuf>,
+    /// The host of the Google Cloud metadata server, if overridden.
+    pub(crate) gce_metadata_host: Option<String>,
+}
+
+impl ProviderEnvironment {
+    /// Read the provider configuration from the environment.
+    pub(crate) fn from_env() -> Self {
+        fn var(name: &str) -> Option<String> {
+            std::env::var(name).ok().filter(|value| !value.is_empty())
+        }
+
+        Self {
+            azure_devops_token: var(EnvVars::AZURE_DEVOPS_TOKEN),
+            azure_client_id: var(EnvVars::AZURE_CLIENT_ID),
+            azure_imds_url: var(EnvVars::AZURE_POD_IDENTITY_AUTHORITY_HOST),
+            google_application_credentials: var(EnvVars::GOOGLE_APPLICATION_CREDENTIALS)
+                .map(PathBuf::from),
+            gcloud_config_dir: var(EnvVars::CLOUDSDK_CONFIG)
+                .map(PathBuf::from)
+                .or_else(default_gcloud_config_dir),
+            gce_metadata_host: var(EnvVars::GCE_METADATA_HOST),
+        }
+    }
+}
+
+/// Return the default configuration directory of the Google Cloud CLI.
+fn default_gcloud_config_dir() -> Option<PathBuf> {
+    if cfg!(windows) {
+        std::env::var_os(EnvVars::APPDATA).map(|appdata| PathBuf::from(appdata).join("gcloud"))
+    } else {
+        etcetera::home_dir()
+            .ok()
+            .map(|home| home.join(".config").join("gcloud"))
+    }
+}
+
+/// Credentials minted by a built-in provider, along with their expiry.
+#[derive(Debug)]
+struct ProviderToken {
+    credentials: Arc<Authentication>,
+    /// When the credentials expire, or `None` if they don't (e.g., a personal access token).
+    expires_at: Option<Timestamp>,
+}
+
+impl ProviderToken {
+    fn is_fresh(&self) -> bool {
+        self.expires_at.is_none_or(|expires_at| {
+            expires_at.duration_since(Timestamp::now())
+                > SignedDuration::from_secs(EXPIRY_TOLERANCE_SECS)
+        })
+    }
+}
+
+/// A process-wide cache of the credentials minted by each built-in provider.
+///
+/// The tokens aren't specific to an index, so a single token per provider is shared across all
+/// indexes that use it.
+#[derive(Debug, Default)]
+pub(crate) struct ProviderTokens(Mutex<FxHashMap<AuthProvider, ProviderToken>>);
+
+impl ProviderTokens {
+    /// Return the cached credentials for a provider, minting new credentials if none are cached or
+    /// the cached credentials have expired.
+    pub(crate) async fn get_or_fetch(
+        &self,
+        provider: AuthProvider,
+        index: &DisplaySafeUrl,
+        environment: &ProviderEnvironment,
+        client: &ClientWithMiddleware,
+    ) -> Result<Arc<Authentication>, AuthProviderError> {
+        // Hold the lock while minting, such that concurrent requests share a single token.
+        let mut tokens = self.0.lock().await;
+        if let Some(token) = tokens.get(&provider).filter(|token| token.is_fresh()) {
+            trace!("Using cached credentials from the `{provider}` auth provider");
+            return Ok(token.credentials.clone());
+        }
+
+        debug!("Fetching credentials for {index} from the `{provider}` auth provider");
+        let token = match provider {
+            AuthProvider::Azure => fetch_azure(environment, client).await,
+            AuthProvider::Gcp => fetch_gcp(environment, client).await,
+        }
+        .map_err(|kind| AuthProviderError {
+            index: index.clone(),
+            provider,
+            kind,
+        })?;
+
+        let credentials = token.credentials.clone();
+        tokens.insert(provider, token);
+        Ok(credentials)
+    }
+
+    /// Discard the cached credentials for a provider, if they're the given credentials (e.g.,
+    /// after they were rejected by the index).
+    pub(crate) async fn invalidate(
+        &self,
+        provider: AuthProvider,
+        credentials: &Arc<Authentication>,
+    ) {
+        let mut tokens = self.0.lock().await;
+        if tokens
+            .get(&provider)
+            .is_some_and(|token| Arc::ptr_eq(&token.credentials, credentials))
+        {
+            tokens.remove(&provider);
+        }
+    }
+}
+
+/// Mint credentials for Azure Artifacts.
+async fn fetch_azure(
+    environment: &ProviderEnvironment,
+    client: &ClientWithMiddleware,
+) -> Result<ProviderToken, AuthProviderErrorKind> {
+    // Azure Artifacts accepts a personal access token as the password, with any username.
+    if let Some(token) = &environment.azure_devops_token {
+        debug!(
+            "Using Azure DevOps personal access token from `{}`",
+            EnvVars::AZURE_DEVOPS_TOKEN
+        );
+        return Ok(ProviderToken {
+            credentials: Arc::new(Authentication::from(Credentials::basic(
+                Some("azure".to_string()),
+                Some(token.clone()),
+            ))),
+            expires_at: None,
+        });
+    }
+
+    // Otherwise, request a token for the managed identity.
+    // See: https://learn.microsoft.com/en-us/entra/identity/managed-identities-azure-resources/how-to-use-vm-token
+    let base = environment
+        .azure_imds_url
+        .as_deref()
+        .unwrap_or(AZURE_IMDS_URL)
+        .trim_end_matches('/');
+    let mut url = Url::parse(&format!("{base}/metadata/identity/oauth2/token")).map_err(|err| {
+        AuthProviderErrorKind::InvalidUrl {
+            variable: EnvVars::AZURE_POD_IDENTITY_AUTHORITY_HOST,
+            url: base.to_string(),
+            err,
+        }
+    })?;
+    url.query_pairs_mut()
+        .append_pair("api-version", "2018-02-01")
+        .append_pair("resource", AZURE_DEVOPS_RESOURCE);
+    if let Some(client_id) = &environment.azure_client_id {
+        url.query_pairs_mut().append_pair("client_id", client_id);
+    }
+
+    let request = client.get(url.clone()).header("Metadata", "true");
+    let response = request_token(request, TokenService::AzureInstanceMetadata, url).await?;
+    Ok(ProviderToken {
+        expires_at: response.expires_at(),
+        credentials: Arc::new(Authentication::from(Credentials::bearer(
+            response.access_token.into_bytes(),
+        ))),
+    })
+}
+
+/// Google Application Default Credentials, as written by `gcloud auth application-default login`
+/// or downloaded for a service account.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ApplicationDefaultCredentials {
+    AuthorizedUser {
+        client_id: String,
+        client_secret: String,
+        refresh_token: String,
+        token_uri: Option<String>,
+    },
+    ServiceAccount {
+        client_email: String,
+        private_key: String,
+        private_key_id: Option<String>,
+        token_uri: Option<String>,
+    },
+    #[serde(other)]
+    Unsupported,
+}
+
+/// Mint credentials for Google Artifact Registry.
+async fn fetch_gcp(
+    environment: &ProviderEnvironment,
+    client: &ClientWithMiddleware,
+) -> Result<ProviderToken, AuthProviderErrorKind> {
+    let response = match read_application_default_credentials(environment).await? {
+        Some((
+            path,
+            ApplicationDefaultCredentials::AuthorizedUser {
+                client_id,
+                client_secret,
+                refresh_token,
+                token_uri,
+            },
+        )) => {
+            debug!(
+                "Using Google Cloud user credentials from `{}`",
+                path.user_display()
+            );
+            let body = url::form_urlencoded::Serializer::new(String::new())
+                .append_pair("grant_type", "refresh_token")
+                .append_pair("client_id", &client_id)
+                .append_pair("client_secret", &client_secret)
+                .append_pair("refresh_token", &refresh_token)
+                .finish();
+            request_google_token(client, token_uri.as_deref(), body).await?
+        }
+        Some((
+            path,
+            ApplicationDefaultCredentials::ServiceAccount {
+                client_email,
+                private_key,
+                private_key_id,
+                token_uri,
+            },
+        )) => {
+            debug!(
+                "Using Google Cloud service account `{client_email}` from `{}`",
+                path.user_display()
+            );
+            let token_uri = token_uri.as_deref().unwrap_or(GOOGLE_TOKEN_URI);
+            let assertion = sign_assertion(
+                &client_email,
+                &private_key,
+                private_key_id.as_deref(),
+                token_uri,
+            )?;
+            let body = url::form_urlencoded::Serializer::new(String::new())
+                .append_pair("grant_type", "urn:ietf:params:oauth:grant-type:jwt-bearer")
+                .append_pair("assertion", &assertion)
+                .finish();
+            request_google_token(client, Some(token_uri), body).await?
+        }
+        Some((path, ApplicationDefaultCredentials::Unsupported)) => {
+            return Err(AuthProviderErrorKind::UnsupportedCredentials { path });
+        }
+        None => {
+            // Otherwise, request a token for the attached service account.
+            // See: https://cloud.google.com/compute/docs/access/authenticate-workloads#applications
+            let host = environment
+                .gce_metadata_host
+                .as_deref()
+                .unwrap_or(GCE_METADATA_HOST);
+            let url = Url::parse(&format!(
+                "http://{host}/computeMetadata/v1/instance/service-accounts/default/token"
+            ))
+            .map_err(|err| AuthProviderErrorKind::InvalidUrl {
+                variable: EnvVars::GCE_METADATA_HOST,
+                url: host.to_string(),
+                err,
+            })?;
+            let request = client.get(url.clone()).header("Metadata-Flavor", "Google");
+            request_token(request, TokenService::GoogleMetadata, url).await?
+        }
+    };
+
+    // Artifact Registry accepts an access token as the password for `oauth2accesstoken`.
+    Ok(ProviderToken {
+        expires_at: response.expires_at(),
+        credentials: Arc::new(Authentication::from(Credentials::basic(
+            Some("oauth2accesstoken".to_string()),
+            Some(response.access_token),
+        ))),
+    })
+}
+
+/// Read the Application Default Credentials, if any.
+///
+/// An explicit `GOOGLE_APPLICATION_CREDENTIALS` must exist; the well-known file in the Google
+/// Cloud CLI configuration directory is optional.
+async fn read_application_default_credentials(
+    environment: &ProviderEnvironment,
+) -> Result<Option<(PathBuf, ApplicationDefaultCredentials)>, AuthProviderErrorKind> {
+    let (path, required) = if let Some(path) = &environment.google_application_credentials {
+        (path.clone(), true)
+    } else if let Some(dir) = &environment.gcloud_config_dir {
+        (dir.join("application_default_credentials.json"), false)
+    } else {
+        return Ok(None);
+    };
+
+    let contents = match fs_err::tokio::read(&path).await {
+        Ok(contents) => contents,
+        Err(err) if !required && err.kind() == std::io::ErrorKind::NotFound => {
+            trace!(
+                "No Application Default Credentials found at `{}`",
+                path.user_display()
+            );
+            return Ok(None);
+        }
+        Err(err) => return Err(AuthProviderErrorKind::ReadCredentials { path, err }),
+    };
+
+    match serde_json::from_slice(&contents) {
+        Ok(credentials) => Ok(Some((path, credentials))),
+        Err(err) => Err(AuthProviderErrorKind::ParseCredentials { path, err }),
+    }
+}
+
+/// Sign a JWT assertion for a service account, to exchange for an access token.
+///
+/// See: <https://developers.google.com/identity/protocols/oauth2/service-account#httprest>
+fn sign_assertion(
+    client_email: &str,
+    private_key: &str,
+    private_key_id: Option<&str>,
+    token_uri: &str,
+) -> Result<String, AuthProviderErrorKind> {
+    #[derive(Serialize)]
+    struct Header<'a> {
+        alg: &'static str,
+        typ: &'static str,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        kid: Option<&'a str>,
+    }
+
+    #[derive(Serialize)]
+    struct Claims<'a> {
+        iss: &'a str,
+        scope: &'static str,
+        aud: &'a str,
+        iat: i64,
+        exp: i64,
+    }
+
+    let iat = Timestamp::now().as_second();
+    let header = Header {
+        alg: "RS256",
+        typ: "JWT",
+        kid: private_key_id,
+    };
+    let claims = Claims {
+        iss: client_email,
+        scope: GOOGLE_CLOUD_PLATFORM_SCOPE,
+        aud: token_uri,
+        iat,
+        exp: iat + GOOGLE_ASSERTION_LIFETIME_SECS,
+    };
+    reqsign::jwt::encode_rs256_pem(&header, &claims, private_key.as_bytes()).map_err(|err| {
+        AuthProviderErrorKind::SignAssertion {
+            client_email: client_email.to_string(),
+            err,
+        }
+    })
+}
+
+/// Exchange a grant for an access token at a Google OAuth 2.0 token endpoint.
+async fn request_google_token(
+    client: &ClientWithMiddleware,
+    token_uri: Option<&str>,
+    body: String,
+) -> Result<TokenResponse, AuthProviderErrorKind> {
+    let token_uri = token_uri.unwrap_or(GOOGLE_TOKEN_URI);
+    let url = Url::parse(token_uri).map_err(|err| AuthProviderErrorKind::InvalidUrl {
+        variable: "token_uri",
+        url: token_uri.to_string(),
+        err,
+    })?;
+    let request = client
+        .post(url.clone())
+        .header(
+            reqwest::header::CONTENT_TYPE,
+            "application/x-www-form-urlencoded",
+        )
+        .body(body);
+    request_token(request, TokenService::GoogleOAuth, url).await
+}
+
+/// The lifetime of a token, which the Azure Instance Metadata Service reports as a string.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ExpiresIn {
+    Seconds(i64),
+    String(String),
+}
+
+/// An OAuth 2.0 access token response.
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: Option<ExpiresIn>,
+}
+
+impl TokenResponse {
+    fn expires_at(&self) -> Option<Timestamp> {
+        let seconds = match self.expires_in.as_ref()? {
+            ExpiresIn::Seconds(seconds) => *seconds,
+            ExpiresIn::String(seconds) => seconds.parse().ok()?,
+        };
+        Timestamp::now()
+            .checked_add(SignedDuration::from_secs(seconds))
+            .ok()
+    }
+}
+
+/// Send a token request and parse the response.
+async fn request_token(
+    request: RequestBuilder,
+    service: TokenService,
+    url: Url,
+) -> Result<TokenResponse, AuthProviderErrorKind> {
+    let url = DisplaySafeUrl::from_url(url);
+    let response = match request.send().await {
+        Ok(response) => response,
+        Err(err) => return Err(AuthProviderErrorKind::TokenRequest { service, url, err }),
+    };
+    let status = response.status();
+    if !status.is_success() {
+        return Err(AuthProviderErrorKind::TokenStatus {
+            service,
+            url,
+            status,
+        });
+    }
+    match response.json::<TokenResponse>().await {
+        Ok(response) => Ok(response),
+        Err(err) => Err(AuthProviderErrorKind::TokenResponse { service, url, err }),
+    }
+}
+
+/// A service that issues access tokens.
+#[derive(Debug, Copy, Clone)]
+pub(crate) enum TokenService {
+    AzureInstanceMetadata,
+    GoogleOAuth,
+    GoogleMetadata,
+}
+
+impl std::fmt::Display for TokenService {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AzureInstanceMetadata => write!(f, "Azure Instance Metadata Service"),
+            Self::GoogleOAuth => write!(f, "Google OAuth 2.0 token endpoint"),
+            Self::GoogleMetadata => write!(f, "Google Cloud metadata server"),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Failed to fetch credentials for index `{index}` with the `{provider}` auth provider")]
+pub(crate) struct AuthProviderError {
+    index: DisplaySafeUrl,
+    provider: AuthProvider,
+    #[source]
+    kind: AuthProviderErrorKind,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum AuthProviderErrorKind {
+    #[error("Invalid `{variable}` URL: `{url}`")]
+    InvalidUrl {
+        variable: &'static str,
+        url: String,
+        #[source]
+        err: url::ParseError,
+    },
+    #[error("Failed to read Google Cloud credentials from `{}`", path.user_display())]
+    ReadCredentials {
+        path: PathBuf,
+        #[source]
+        err: std::io::Error,
+    },
+    #[error("Failed to parse Google Cloud credentials from `{}`", path.user_display())]
+    ParseCredentials {
+        path: PathBuf,
+        #[source]
+        err: serde_json::Error,
+    },
+    #[error(
+        "Unsupported Google Cloud credentials in `{}`; expected `authorized_user` or `service_account` credentials",
+        path.user_display()
+    )]
+    UnsupportedCredentials { path: PathBuf },
+    #[error("Failed to sign an access token request for service account `{client_email}`")]
+    SignAssertion {
+        client_email: String,
+        #[source]
+        err: reqsign::Error,
+    },
+    #[error("Failed to request an access token from the {service} at `{url}`")]
+    TokenRequest {
+        service: TokenService,
+        url: DisplaySafeUrl,
+        #[source]
+        err: reqwest_middleware::Error,
+    },
+    #[error("The {service} at `{url}` returned {status} when requesting an access token")]
+    TokenStatus {
+        service: TokenService,
+        url: DisplaySafeUrl,
+        status: StatusCode,
+    },
+    #[error("Failed to parse the access token response from the {service} at `{url}`")]
+    TokenResponse {
+        service: TokenService,
+        url: DisplaySafeUrl,
+        #[source]
+        err: reqwest::Error,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use wiremock::matchers::{body_string_contains, header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    fn client() -> ClientWithMiddleware {
+        ClientWithMiddleware::from(reqwest::Client::new())
+    }
+
+    fn index() -> DisplaySafeUrl {
+        DisplaySafeUrl::parse("https://pkgs.example.com/simple").unwrap()
+    }
+
+    /// Render an error and its sources, one per line.
+    fn error_chain(err: &dyn Error) -> String {
+        std::iter::successors(Some(err), |&err| err.source())
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[tokio::test]
+    async fn azure_personal_access_token() {
+        let environment = ProviderEnvironment {
+            azure_devops_token: Some("pat".to_string()),
+            ..ProviderEnvironment::default()
+        };
+        let credentials = ProviderTokens::default()
+            .get_or_fetch(AuthProvider::Azure, &index(), &environment, &client())
+            .await
+            .unwrap();
+        assert_eq!(
+            *credentials,
+            Authentication::from(Credentials::basic(
+                Some("azure".to_string()),
+                Some("pat".to_string())
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn azure_managed_identity() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/metadata/identity/oauth2/token"))
+            .and(header("Metadata", "true"))
+            .and(query_param("resource", AZURE_DEVOPS_RESOURCE))
+            .and(query_param("client_id", "identity"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "access_token": "token",
+                "expires_in": "3599",
+                "token_type": "Bearer",
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let environment = ProviderEnvironment {
+            azure_client_id: Some("identity".to_string()),
+            azure_imds_url: Some(server.uri()),
+            ..ProviderEnvironment::default()
+        };
+        let tokens = ProviderTokens::default();
+        let credentials = tokens
+            .get_or_fetch(AuthProvider::Azure, &index(), &environment, &client())
+            .await
+            .unwrap();
+        assert_eq!(
+            *credentials,
+            Authentication::from(Credentials::bearer(b"token".to_vec()))
+        );
+
+        // The token is cached for its lifetime.
+        let cached = tokens
+            .get_or_fetch(AuthProvider::Azure, &index(), &environment, &client())
+            .await
+            .unwrap();
+        assert!(Arc::ptr_eq(&credentials, &cached));
+    }
+
+    #[tokio::test]
+    async fn gcp_authorized_user() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/token"))
+            .and(body_string_contains("grant_type=refresh_token"))
+            .and(body_string_contains("refresh_token=refresh"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "access_token": "token",
+                "expires_in": 3599,
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("application_default_credentials.json");
+        fs_err::write(
+            &path,
+            serde_json::json!({
+                "type": "authorized_user",
+                "client_id": "client",
+                "client_secret": "secret",
+                "refresh_token": "refresh",
+                "token_uri": format!("{}/token", server.uri()),
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        let environment = ProviderEnvironment {
+            gcloud_config_dir: Some(dir.path().to_path_buf()),
+            ..ProviderEnvironment::default()
+        };
+        let credentials = ProviderTokens::default()
+            .get_or_fetch(AuthProvider::Gcp, &index(), &environment, &client())
+            .await
+            .unwrap();
+        assert_eq!(
+            *credentials,
+            Authentication::from(Credentials::basic(
+                Some("oauth2accesstoken".to_string()),
+                Some("token".to_string())
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn gcp_metadata_server() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(
+                "/computeMetadata/v1/instance/service-accounts/default/token",
+            ))
+            .and(header("Metadata-Flavor", "Google"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "access_token": "token",
+                "expires_in": 3599,
+                "token_type": "Bearer",
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        // Without Application Default Credentials, fall back to the metadata server.
+        let dir = tempfile::tempdir().unwrap();
+        let environment = ProviderEnvironment {
+            gcloud_config_dir: Some(dir.path().to_path_buf()),
+            gce_metadata_host: Some(server.address().to_string()),
+            ..ProviderEnvironment::default()
+        };
+        let credentials = ProviderTokens::default()
+            .get_or_fetch(AuthProvider::Gcp, &index(), &environment, &client())
+            .await
+            .unwrap();
+        assert_eq!(
+            *credentials,
+            Authentication::from(Credentials::basic(
+                Some("oauth2accesstoken".to_string()),
+                Some("token".to_string())
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn error_names_index_and_step() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/metadata/identity/oauth2/token"))
+            .respond_with(ResponseTemplate::new(400))
+            .mount(&server)
+            .await;
+
+        let environment = ProviderEnvironment {
+            azure_imds_url: Some(server.uri()),
+            ..ProviderEnvironment::default()
+        };
+        let err = ProviderTokens::default()
+            .get_or_fetch(AuthProvider::Azure, &index(), &environment, &client())
+            .await
+            .unwrap_err();
+        assert_eq!(
+            error_chain(&err),
+            format!(
+                "Failed to fetch credentials for index `https://pkgs.example.com/simple` with the `azure` auth provider\n\
+                 The Azure Instance Metadata Service at `{}/metadata/identity/oauth2/token?api-version=2018-02-01&resource={AZURE_DEVOPS_RESOURCE}` returned 400 Bad Request when requesting an access token",
+                server.uri()
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn gcp_missing_explicit_credentials() {
+        let dir = tempfile::tempdir().unwrap();
+        let environment = ProviderEnvironment {
+            google_application_credentials: Some(dir.path().join("missing.json")),
+            ..ProviderEnvironment::default()
+        };
+        let err = ProviderTokens::default()
+            .get_or_fetch(AuthProvider::Gcp, &index(), &environment, &client())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.kind,
+            AuthProviderErrorKind::ReadCredentials { .. }
+        ));
+    }
+}
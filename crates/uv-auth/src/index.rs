@@ -35,7 +35,9 @@ pub enum AuthPolicy {
     Always,
     /// Never authenticate.
     ///
-    /// If credentials are provided, uv will error. uv will not search for credentials.
+    /// uv will not search for credentials (e.g., in a netrc file, the credentials store, or the
+    /// keyring). Credentials provided explicitly for the index, either in its URL or via
+    /// environment variables, are still used.
     Never,
 }
 
@@ -49,6 +51,35 @@ impl Display for AuthPolicy {
     }
 }
 
+/// A built-in provider of credentials for an index hosted by a cloud platform.
+#[derive(
+    Copy, Clone, Debug, Hash, Eq, PartialEq, Ord, PartialOrd, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum AuthProvider {
+    /// Azure Artifacts.
+    ///
+    /// Uses the personal access token in `AZURE_DEVOPS_TOKEN`, if set. Otherwise, requests a
+    /// Microsoft Entra token for the managed identity from the Azure Instance Metadata Service.
+    Azure,
+    /// Google Artifact Registry.
+    ///
+    /// Uses the Application Default Credentials (i.e., `GOOGLE_APPLICATION_CREDENTIALS`, or the
+    /// credentials created by `gcloud auth application-default login`), if present. Otherwise,
+    /// requests an access token for the attached service account from the metadata server.
+    Gcp,
+}
+
+impl Display for AuthProvider {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Azure => write!(f, "azure"),
+            Self::Gcp => write!(f, "gcp"),
+        }
+    }
+}
+
 // TODO(john): We are not using `uv_distribution_types::Index` directly
 // here because it would cause circular crate dependencies. However, this
 // could potentially make sense for a future refactor.
@@ -59,6 +90,8 @@ pub struct Index {
     /// For PEP 503 endpoints, this excludes `/simple`.
     pub root_url: DisplaySafeUrl,
     pub auth_policy: AuthPolicy,
+    /// The built-in provider to fetch credentials from, if any.
+    pub auth_provider: Option<AuthProvider>,
 }
 
 impl Index {
@@ -138,6 +171,7 @@ mod tests {
             url: root_url.clone(),
             root_url,
             auth_policy,
+            auth_provider: None,
         }
     }
 
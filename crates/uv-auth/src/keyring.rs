@@ -1,14 +1,55 @@
+use std::hash::BuildHasherDefault;
+use std::sync::LazyLock;
+use std::time::{Duration, Instant};
 use std::{io::Write, process::Stdio};
+
+use rustc_hash::FxHasher;
+use tokio::io::{AsyncRead, AsyncReadExt};
 use tokio::process::Command;
 use tracing::{debug, instrument, trace, warn};
+use uv_once_map::OnceMap;
 use uv_redacted::DisplaySafeUrl;
-use uv_warnings::warn_user_once;
+use uv_static::EnvVars;
+use uv_warnings::{warn_user, warn_user_once};
 
 use crate::credentials::Credentials;
 
 /// Service name prefix for storing credentials in a keyring.
 static UV_SERVICE_PREFIX: &str = "uv:";
 
+/// The default timeout for a single invocation of the `keyring` command.
+const DEFAULT_SUBPROCESS_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The maximum number of bytes accepted on each of the `keyring` command's output streams.
+const MAX_SUBPROCESS_OUTPUT: u64 = 64 * 1024;
+
+/// The timeout for a single invocation of the `keyring` command, as set by `UV_KEYRING_TIMEOUT`.
+static SUBPROCESS_TIMEOUT: LazyLock<Duration> = LazyLock::new(|| {
+    let Ok(value) = std::env::var(EnvVars::UV_KEYRING_TIMEOUT) else {
+        return DEFAULT_SUBPROCESS_TIMEOUT;
+    };
+    if let Ok(seconds) = value.parse::<u64>() {
+        Duration::from_secs(seconds)
+    } else {
+        warn_user_once!(
+            "Ignoring invalid value for `{}`: `{value}` (expected a number of seconds)",
+            EnvVars::UV_KEYRING_TIMEOUT
+        );
+        DEFAULT_SUBPROCESS_TIMEOUT
+    }
+});
+
+/// The results of `keyring` command invocations, keyed by service name and username.
+///
+/// The `keyring` command can be slow, and the same service is often queried repeatedly (e.g.,
+/// once per URL on a given host), so lookups are memoized for the lifetime of the process.
+/// Concurrent lookups for the same key wait on a single invocation.
+static SUBPROCESS_LOOKUPS: LazyLock<SubprocessLookups> = LazyLock::new(OnceMap::default);
+
+/// A map from a service name and username to the username and password returned by `keyring`.
+type SubprocessLookups =
+    OnceMap<(String, Option<String>), Option<(String, String)>, BuildHasherDefault<FxHasher>>;
+
 /// A backend for retrieving credentials from a keyring.
 ///
 /// See pip's implementation for reference
@@ -267,6 +308,51 @@ impl KeyringProvider {
         &self,
         service_name: &str,
         username: Option<&str>,
+    ) -> Option<(String, String)> {
+        let key = (service_name.to_string(), username.map(ToString::to_string));
+        if let Some(credentials) = SUBPROCESS_LOOKUPS.register_or_wait(&key).await {
+            trace!("Using cached `keyring` result for `{service_name}`");
+            return credentials;
+        }
+
+        let start = Instant::now();
+        let timeout = *SUBPROCESS_TIMEOUT;
+        let credentials = if let Ok(credentials) =
+            tokio::time::timeout(timeout, Self::run_subprocess(service_name, username)).await
+        {
+            credentials
+        } else {
+            warn_user!(
+                "Timed out after {}s waiting for the `keyring` command to return credentials for `{service_name}`; continuing without credentials (set `{}` to increase the timeout)",
+                timeout.as_secs(),
+                EnvVars::UV_KEYRING_TIMEOUT
+            );
+            None
+        };
+
+        debug!(
+            "Keyring lookup for `{}` {} in {:.2?}",
+            if let Some(username) = username {
+                format!("{username}@{service_name}")
+            } else {
+                service_name.to_string()
+            },
+            if credentials.is_some() {
+                "returned credentials"
+            } else {
+                "returned no credentials"
+            },
+            start.elapsed()
+        );
+
+        SUBPROCESS_LOOKUPS.done(key, credentials.clone());
+        credentials
+    }
+
+    /// Invoke the `keyring` command to fetch credentials for the given service.
+    async fn run_subprocess(
+        service_name: &str,
+        username: Option<&str>,
     ) -> Option<(String, String)> {
         // https://github.com/pypa/pip/blob/24.0/src/pip/_internal/network/auth.py#L136-L141
         let mut command = Command::new("keyring");
@@ -278,7 +364,7 @@ impl KeyringProvider {
             command.arg("--mode").arg("creds");
         }
 
-        let child = command
+        let mut child = command
             .stdin(Stdio::null())
             .stdout(Stdio::piped())
             // If we're using `--mode creds`, we need to capture the output in order to avoid
@@ -289,26 +375,50 @@ impl KeyringProvider {
             } else {
                 Stdio::piped()
             })
+            // Ensure that the process is terminated if the lookup times out.
+            .kill_on_drop(true)
             .spawn()
             .inspect_err(|err| warn!("Failure running `keyring` command: {err}"))
             .ok()?;
 
-        let output = child
-            .wait_with_output()
+        // Read the output streams concurrently, up to a fixed limit.
+        let stdout = child.stdout.take()?;
+        let stderr = child.stderr.take();
+        let (stdout, stderr) = tokio::try_join!(read_limited(stdout), async {
+            match stderr {
+                Some(stderr) => read_limited(stderr).await,
+                None => Ok(Vec::new()),
+            }
+        })
+        .inspect_err(|err| warn!("Failed to read `keyring` output: {err}"))
+        .ok()?;
+
+        if stdout.len() as u64 > MAX_SUBPROCESS_OUTPUT
+            || stderr.len() as u64 > MAX_SUBPROCESS_OUTPUT
+        {
+            warn_user_once!(
+                "Ignoring the response from the `keyring` command for `{service_name}`, which exceeded {MAX_SUBPROCESS_OUTPUT} bytes"
+            );
+            child.start_kill().ok();
+            return None;
+        }
+
+        let status = child
+            .wait()
             .await
             .inspect_err(|err| warn!("Failed to wait for `keyring` output: {err}"))
             .ok()?;
 
-        if output.status.success() {
+        if status.success() {
             // If we captured stderr, display it in case it's helpful to the user
             // TODO(zanieb): This was done when we added `--mode creds` support for parity with the
             // existing behavior, but it might be a better UX to hide this on success? It also
             // might be problematic that we're not streaming it. We could change this given some
             // user feedback.
-            std::io::stderr().write_all(&output.stderr).ok();
+            std::io::stderr().write_all(&stderr).ok();
 
             // On success, parse the newline terminated credentials
-            let output = String::from_utf8(output.stdout)
+            let output = String::from_utf8(stdout)
                 .inspect_err(|err| warn!("Failed to parse response from `keyring` command: {err}"))
                 .ok()?;
 
@@ -339,7 +449,7 @@ impl KeyringProvider {
             Some((username.to_string(), password.to_string()))
         } else {
             // On failure, no password was available
-            let stderr = std::str::from_utf8(&output.stderr).ok()?;
+            let stderr = std::str::from_utf8(&stderr).ok()?;
             if stderr.contains("unrecognized arguments: --mode") {
                 // N.B. We do not show the `service_name` here because we'll show the warning twice
                 //      otherwise, once for the URL and once for the realm.
@@ -348,7 +458,7 @@ impl KeyringProvider {
                 );
             } else if username.is_none() {
                 // If we captured stderr, display it in case it's helpful to the user
-                std::io::stderr().write_all(&output.stderr).ok();
+                std::io::stderr().write_all(stderr.as_bytes()).ok();
             }
             None
         }
@@ -420,6 +530,17 @@ impl KeyringProvider {
     }
 }
 
+/// Read from the given stream until EOF, or until more than [`MAX_SUBPROCESS_OUTPUT`] bytes have
+/// been read.
+async fn read_limited(reader: impl AsyncRead + Unpin) -> std::io::Result<Vec<u8>> {
+    let mut buffer = Vec::new();
+    reader
+        .take(MAX_SUBPROCESS_OUTPUT + 1)
+        .read_to_end(&mut buffer)
+        .await?;
+    Ok(buffer)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,8 +1,9 @@
 pub use access_token::AccessToken;
 pub use cache::CredentialsCache;
 pub use credentials::{Credentials, CredentialsFromUrlError, Username};
-pub use index::{AuthPolicy, Index, Indexes};
+pub use index::{AuthPolicy, AuthProvider, Index, Indexes};
 pub use keyring::KeyringProvider;
+pub use lookup::{CredentialLookup, CredentialOutcome, CredentialSource};
 pub use middleware::AuthMiddleware;
 pub use pyx::{
     DEFAULT_TOLERANCE_SECS, PyxJwt, PyxOAuthTokens, PyxTokenStore, PyxTokens, TokenStoreError,
@@ -14,9 +15,11 @@ pub use store::{AuthBackend, TextCredentialStore, TomlCredentialError};
 
 mod access_token;
 mod cache;
+mod cloud;
 mod credentials;
 mod index;
 mod keyring;
+mod lookup;
 mod middleware;
 mod providers;
 mod pyx;
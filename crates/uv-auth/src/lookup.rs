@@ -0,0 +1,70 @@
+use std::fmt::{Display, Formatter};
+
+/// An external source of credentials consulted by the [`AuthMiddleware`](crate::AuthMiddleware).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CredentialSource {
+    /// The netrc file.
+    Netrc,
+    /// The plaintext credentials file, as populated by `uv auth login`.
+    TextStore,
+    /// The native system credential store.
+    NativeStore,
+    /// The keyring provider, as configured via `--keyring-provider`.
+    Keyring,
+}
+
+impl Display for CredentialSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Netrc => f.write_str("netrc file"),
+            Self::TextStore => f.write_str("uv credentials file"),
+            Self::NativeStore => f.write_str("native credential store"),
+            Self::Keyring => f.write_str("keyring"),
+        }
+    }
+}
+
+/// The outcome of consulting a [`CredentialSource`] for a request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CredentialOutcome {
+    /// The source provided credentials.
+    Found,
+    /// The source did not contain credentials matching the request.
+    NotFound,
+    /// The source was skipped, since the request did not include a username.
+    MissingUsername,
+}
+
+/// A record of a single [`CredentialSource`] lookup, used to explain authentication failures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CredentialLookup {
+    pub source: CredentialSource,
+    pub outcome: CredentialOutcome,
+}
+
+impl CredentialLookup {
+    pub(crate) fn new(source: CredentialSource, found: bool) -> Self {
+        Self {
+            source,
+            outcome: if found {
+                CredentialOutcome::Found
+            } else {
+                CredentialOutcome::NotFound
+            },
+        }
+    }
+}
+
+impl Display for CredentialLookup {
+    /// Display the lookup as seen from a failed request, i.e., any credentials that were found
+    /// were rejected.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.outcome {
+            CredentialOutcome::Found => write!(f, "{} (rejected)", self.source),
+            CredentialOutcome::NotFound => write!(f, "{} (no matching credentials)", self.source),
+            CredentialOutcome::MissingUsername => {
+                write!(f, "{} (skipped without a username)", self.source)
+            }
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use std::path::Path;
 use std::sync::{Arc, LazyLock};
 
 use anyhow::{anyhow, format_err};
@@ -13,12 +14,14 @@ use uv_redacted::DisplaySafeUrl;
 use uv_static::EnvVars;
 use uv_warnings::owo_colors::OwoColorize;
 
+use crate::cloud::ProviderEnvironment;
 use crate::providers::{
     AzureEndpointProvider, GcsEndpointProvider, HuggingFaceProvider, S3EndpointProvider,
 };
 use crate::pyx::{DEFAULT_TOLERANCE_SECS, PyxTokenStore};
 use crate::{
-    AccessToken, CredentialsCache, KeyringProvider,
+    AccessToken, AuthProvider, CredentialLookup, CredentialOutcome, CredentialSource,
+    CredentialsCache, KeyringProvider,
     cache::FetchUrl,
     credentials::{
         Authentication, AuthenticationError, Credentials, CredentialsFromUrlError, Username,
@@ -55,15 +58,24 @@ enum NetrcMode {
 
 impl Default for NetrcMode {
     fn default() -> Self {
-        Self::Automatic(LazyLock::new(|| match Netrc::new() {
-            Ok(netrc) => Some(netrc),
-            Err(uv_netrc::Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {
-                debug!("No netrc file found");
-                None
-            }
-            Err(err) => {
-                warn!("Error reading netrc file: {err}");
-                None
+        Self::Automatic(LazyLock::new(|| {
+            // Prefer `UV_NETRC`, falling back to `NETRC` and the standard location.
+            let netrc = if let Some(path) = std::env::var_os(EnvVars::UV_NETRC) {
+                debug!("Reading netrc file from `{}`", path.to_string_lossy());
+                Netrc::from_file(Path::new(&path))
+            } else {
+                Netrc::new()
+            };
+            match netrc {
+                Ok(netrc) => Some(netrc),
+                Err(uv_netrc::Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {
+                    debug!("No netrc file found");
+                    None
+                }
+                Err(err) => {
+                    warn!("Error reading netrc file: {err}");
+                    None
+                }
             }
         }))
     }
@@ -206,6 +218,8 @@ pub struct AuthMiddleware {
     gcs_credential_state: Mutex<GcsCredentialState>,
     /// Cached Azure credentials to avoid running the credential helper multiple times.
     azure_credential_state: Mutex<AzureCredentialState>,
+    /// The environment consulted by the built-in auth providers.
+    auth_provider_environment: ProviderEnvironment,
     preview: Preview,
 }
 
@@ -231,6 +245,7 @@ impl AuthMiddleware {
             s3_credential_state: Mutex::new(S3CredentialState::Uninitialized),
             gcs_credential_state: Mutex::new(GcsCredentialState::Uninitialized),
             azure_credential_state: Mutex::new(AzureCredentialState::Uninitialized),
+            auth_provider_environment: ProviderEnvironment::from_env(),
             preview: Preview::default(),
         }
     }
@@ -263,6 +278,14 @@ impl AuthMiddleware {
         self
     }
 
+    /// Configure the environment consulted by the built-in auth providers.
+    #[must_use]
+    #[cfg(test)]
+    fn with_auth_provider_environment(mut self, environment: ProviderEnvironment) -> Self {
+        self.auth_provider_environment = environment;
+        self
+    }
+
     /// Configure the [`KeyringProvider`] to use.
     #[must_use]
     pub fn with_keyring(mut self, keyring: Option<KeyringProvider>) -> Self {
@@ -382,6 +405,22 @@ impl Middleware for AuthMiddleware {
         trace!("Handling request for {url} with authentication policy {auth_policy}");
 
         let credentials: Option<Arc<Authentication>> = if matches!(auth_policy, AuthPolicy::Never) {
+            // Credentials on the request itself are sent as-is. Beyond those, only use the
+            // credentials configured explicitly for the index (i.e., embedded in its URL or
+            // provided via `UV_INDEX_{name}_USERNAME`), never any from the netrc file, the
+            // credential store, or the keyring.
+            if request_credentials.is_none()
+                && let Some(credentials) = index
+                    .and_then(|index| self.cache().get_url(&index.url, &Username::none()))
+                    .filter(|credentials| credentials.is_authenticated())
+            {
+                trace!("Request for {url} is authenticated with the credentials for its index");
+                request = credentials.authenticate(request).await?;
+                return self
+                    .complete_request(None, request, extensions, next, auth_policy)
+                    .await;
+            }
+            debug!("Skipping credential lookup for {url} due to authentication policy `never`");
             None
         } else {
             if let Some(request_credentials) = request_credentials {
@@ -398,6 +437,18 @@ impl Middleware for AuthMiddleware {
                     .await;
             }
 
+            // If the index is configured with a built-in auth provider, use it instead of
+            // searching for credentials.
+            if let Some(index) = index
+                && let Some(provider) = index.auth_provider
+            {
+                return self
+                    .complete_request_with_auth_provider(
+                        provider, request, extensions, next, &url, index,
+                    )
+                    .await;
+            }
+
             // We have no credentials
             trace!("Request for {url} is unauthenticated, checking cache");
 
@@ -693,6 +744,69 @@ impl AuthMiddleware {
             .await
     }
 
+    /// Use credentials from a built-in auth provider to complete the request.
+    ///
+    /// If the index rejects the credentials, they're discarded and the request is retried once
+    /// with freshly minted credentials.
+    async fn complete_request_with_auth_provider(
+        &self,
+        provider: AuthProvider,
+        request: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+        url: &DisplaySafeUrl,
+        index: &Index,
+    ) -> reqwest_middleware::Result<Response> {
+        let client = self
+            .base_client
+            .clone()
+            .unwrap_or_else(|| ClientWithMiddleware::from(reqwest::Client::new()));
+        let retry_request = request.try_clone();
+
+        let credentials = self
+            .cache()
+            .provider_tokens
+            .get_or_fetch(
+                provider,
+                &index.url,
+                &self.auth_provider_environment,
+                &client,
+            )
+            .await
+            .map_err(Error::middleware)?;
+        let request = credentials.authenticate(request).await?;
+        trace!("Attempting request for {url} with credentials from the `{provider}` auth provider");
+        let response = next.clone().run(request, extensions).await?;
+        if response.status() != StatusCode::UNAUTHORIZED {
+            return Ok(response);
+        }
+        let Some(retry_request) = retry_request else {
+            return Ok(response);
+        };
+
+        debug!(
+            "Request for {url} failed with {}, refreshing credentials from the `{provider}` auth provider",
+            response.status()
+        );
+        self.cache()
+            .provider_tokens
+            .invalidate(provider, &credentials)
+            .await;
+        let credentials = self
+            .cache()
+            .provider_tokens
+            .get_or_fetch(
+                provider,
+                &index.url,
+                &self.auth_provider_environment,
+                &client,
+            )
+            .await
+            .map_err(Error::middleware)?;
+        let retry_request = credentials.authenticate(retry_request).await?;
+        next.run(retry_request, extensions).await
+    }
+
     /// Fetch credentials for a URL.
     ///
     /// Supports netrc file and keyring lookups.
@@ -809,6 +923,9 @@ impl AuthMiddleware {
             }
         }
 
+        // Track the sources consulted for the URL, to explain any authentication failure.
+        let mut lookups = Vec::new();
+
         // If this is a known URL, authenticate it via the token store.
         let credentials = if let Some(credentials) = async {
             let base_client = self.base_client.as_ref()?;
@@ -849,13 +966,18 @@ impl AuthMiddleware {
         // Netrc support based on: <https://github.com/gribouille/netrc>.
         } else if let Some(credentials) = self.netrc.get().and_then(|netrc| {
             debug!("Checking netrc for credentials for {url}");
-            Credentials::from_netrc(
+            let credentials = Credentials::from_netrc(
                 netrc,
                 url,
                 credentials
                     .as_ref()
                     .and_then(|credentials| credentials.username()),
-            )
+            );
+            lookups.push(CredentialLookup::new(
+                CredentialSource::Netrc,
+                credentials.is_some(),
+            ));
+            credentials
         }) {
             debug!("Found credentials in netrc file for {url}");
             Some(credentials)
@@ -869,7 +991,13 @@ impl AuthMiddleware {
                     .as_ref()
                     .and_then(|credentials| credentials.username()),
             ) {
-                Ok(credentials) => credentials.cloned(),
+                Ok(credentials) => {
+                    lookups.push(CredentialLookup::new(
+                        CredentialSource::TextStore,
+                        credentials.is_some(),
+                    ));
+                    credentials.cloned()
+                }
                 Err(err) => {
                     debug!("Failed to get credentials from text store: {err}");
                     None
@@ -887,7 +1015,7 @@ impl AuthMiddleware {
                 } else {
                     String::new()
                 };
-                if let Some(index) = index {
+                let credentials = if let Some(index) = index {
                     // N.B. The native store performs an exact look up right now, so we use the root
                     // URL of the index instead of relying on prefix-matching.
                     debug!(
@@ -901,8 +1029,13 @@ impl AuthMiddleware {
                         display_username, url
                     );
                     native_store.fetch(url, username).await
-                }
+                };
                 // TODO(zanieb): We should have a realm fallback here too
+                lookups.push(CredentialLookup::new(
+                    CredentialSource::NativeStore,
+                    credentials.is_some(),
+                ));
+                credentials
             } else {
                 None
             }
@@ -919,7 +1052,7 @@ impl AuthMiddleware {
                 // URLs; instead, we fetch if there's a username or if the user has requested to
                 // always authenticate.
                 if let Some(username) = credentials.and_then(|credentials| credentials.username()) {
-                    if let Some(index) = index {
+                    let credentials = if let Some(index) = index {
                         debug!(
                             "Checking keyring for credentials for index URL {}@{}",
                             username, index.url
@@ -933,16 +1066,26 @@ impl AuthMiddleware {
                             username, url
                         );
                         keyring.fetch(url, Some(username)).await
-                    }
+                    };
+                    lookups.push(CredentialLookup::new(
+                        CredentialSource::Keyring,
+                        credentials.is_some(),
+                    ));
+                    credentials
                 } else if matches!(auth_policy, AuthPolicy::Always) {
                     if let Some(index) = index {
                         debug!(
                             "Checking keyring for credentials for index URL {} without username due to `authenticate = always`",
                             index.url
                         );
-                        keyring
+                        let credentials = keyring
                             .fetch(DisplaySafeUrl::ref_cast(&index.url), None)
-                            .await
+                            .await;
+                        lookups.push(CredentialLookup::new(
+                            CredentialSource::Keyring,
+                            credentials.is_some(),
+                        ));
+                        credentials
                     } else {
                         None
                     }
@@ -950,6 +1093,10 @@ impl AuthMiddleware {
                     debug!(
                         "Skipping keyring fetch for {url} without username; use `authenticate = always` to force"
                     );
+                    lookups.push(CredentialLookup {
+                        source: CredentialSource::Keyring,
+                        outcome: CredentialOutcome::MissingUsername,
+                    });
                     None
                 }
             }
@@ -964,6 +1111,7 @@ impl AuthMiddleware {
         let credentials = credentials.map(Authentication::from).map(Arc::new);
 
         // Register the fetch for this key
+        self.cache().record_lookups(url, lookups);
         self.cache().fetches.done(key, credentials.clone());
 
         Ok(credentials)
@@ -993,7 +1141,7 @@ mod tests {
     use test_log::test;
 
     use url::Url;
-    use wiremock::matchers::{basic_auth, method, path_regex};
+    use wiremock::matchers::{basic_auth, header, method, path, path_regex};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     use crate::Index;
@@ -2163,11 +2311,13 @@ mod tests {
                 url: DisplaySafeUrl::from_url(base_url_1.clone()),
                 root_url: DisplaySafeUrl::from_url(base_url_1.clone()),
                 auth_policy: AuthPolicy::Auto,
+                auth_provider: None,
             },
             Index {
                 url: DisplaySafeUrl::from_url(base_url_2.clone()),
                 root_url: DisplaySafeUrl::from_url(base_url_2.clone()),
                 auth_policy: AuthPolicy::Auto,
+                auth_provider: None,
             },
         ]);
 
@@ -2271,6 +2421,7 @@ mod tests {
             url: DisplaySafeUrl::from_url(index_url.clone()),
             root_url: DisplaySafeUrl::from_url(index_url.clone()),
             auth_policy: AuthPolicy::Auto,
+            auth_provider: None,
         }]);
 
         let client = test_client_builder()
@@ -2330,6 +2481,7 @@ mod tests {
             url: url.clone(),
             root_url: url.clone(),
             auth_policy: policy,
+            auth_provider: None,
         }])
     }
 
@@ -2477,6 +2629,76 @@ mod tests {
         Ok(())
     }
 
+    /// With the "never" auth policy, credentials from the netrc file should be ignored, while
+    /// credentials provided explicitly for the index should be used.
+    #[test(tokio::test)]
+    async fn test_auth_policy_never_with_index_credentials() -> Result<(), Error> {
+        let username = "user";
+        let password = "password";
+        let server = start_test_server(username, password).await;
+        let base_url = Url::parse(&server.uri())?;
+
+        let mut netrc_file = NamedTempFile::new()?;
+        writeln!(
+            netrc_file,
+            "machine {} login {username} password {password}",
+            base_url.host_str().unwrap()
+        )?;
+        let indexes = indexes_for(&base_url, AuthPolicy::Never);
+        let client = test_client_builder()
+            .with(
+                AuthMiddleware::new()
+                    .with_cache(CredentialsCache::new())
+                    .with_netrc(Some(
+                        Netrc::from_file(netrc_file.path()).expect("Test has valid netrc file"),
+                    ))
+                    .with_indexes(indexes.clone()),
+            )
+            .build();
+
+        assert_eq!(
+            client
+                .get(format!("{}/foo", server.uri()))
+                .send()
+                .await?
+                .status(),
+            401,
+            "Credentials should not be pulled from the netrc file"
+        );
+
+        let cache = CredentialsCache::new();
+        cache.insert(
+            DisplaySafeUrl::ref_cast(&base_url),
+            Arc::new(Authentication::from(Credentials::basic(
+                Some(username.to_string()),
+                Some(password.to_string()),
+            ))),
+        );
+
+        let client = test_client_builder()
+            .with(
+                AuthMiddleware::new()
+                    .with_cache(cache)
+                    .with_netrc(Some(
+                        Netrc::from_file(netrc_file.path()).expect("Test has valid netrc file"),
+                    ))
+                    .with_indexes(indexes),
+            )
+            .build();
+
+        assert_eq!(
+            client
+                .get(format!("{}/foo", server.uri()))
+                .send()
+                .await?
+                .status(),
+            200,
+            "Credentials configured for the index should be used"
+        );
+
+        Ok(())
+    }
+
     /// With the "never" auth policy, requests should succeed if
     /// unauthenticated requests succeed.
     #[test(tokio::test)]
@@ -2731,4 +2953,115 @@ mod tests {
 
         Ok(())
     }
+
+    #[test(tokio::test)]
+    async fn test_credential_lookups() -> Result<(), Error> {
+        let username = "user";
+        let password = "password";
+        let server = start_test_server(username, password).await;
+        let base_url = Url::parse(&server.uri())?;
+
+        let cache = Arc::new(CredentialsCache::new());
+        let client = test_client_builder()
+            .with(
+                AuthMiddleware::new()
+                    .with_cache_arc(cache.clone())
+                    .with_netrc(None)
+                    .with_text_store(Some(TextCredentialStore::default()))
+                    .with_keyring(Some(KeyringProvider::dummy([(
+                        format!(
+                            "{}:{}",
+                            base_url.host_str().unwrap(),
+                            base_url.port().unwrap()
+                        ),
+                        username,
+                        password,
+                    )]))),
+            )
+            .build();
+
+        let mut url = base_url.clone();
+        url.set_username("other_user").unwrap();
+        assert_eq!(client.get(url).send().await?.status(), 401);
+
+        // The disabled netrc file is omitted, while the text store and keyring are recorded.
+        assert_eq!(
+            cache.lookups(&base_url),
+            vec![
+                CredentialLookup::new(CredentialSource::TextStore, false),
+                CredentialLookup::new(CredentialSource::Keyring, false),
+            ]
+        );
+
+        Ok(())
+    }
+
+    /// Credentials from a built-in auth provider are refreshed when the index rejects them.
+    #[test(tokio::test)]
+    async fn test_auth_provider_refresh_on_unauthorized() -> Result<(), Error> {
+        let server = MockServer::start().await;
+
+        // The metadata service issues a new token on each request.
+        Mock::given(method("GET"))
+            .and(path("/metadata/identity/oauth2/token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "access_token": "stale",
+                "expires_in": "3599",
+            })))
+            .up_to_n_times(1)
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/metadata/identity/oauth2/token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "access_token": "fresh",
+                "expires_in": "3599",
+            })))
+            .mount(&server)
+            .await;
+
+        // The index only accepts the second token.
+        Mock::given(method("GET"))
+            .and(path("/simple/anyio/"))
+            .and(header("Authorization", "Bearer fresh"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/simple/anyio/"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&server)
+            .await;
+
+        let index_url = DisplaySafeUrl::parse(&format!("{}/simple", server.uri()))?;
+        let indexes = Indexes::from_indexes(vec![Index {
+            url: index_url.clone(),
+            root_url: index_url.clone(),
+            auth_policy: AuthPolicy::Auto,
+            auth_provider: Some(AuthProvider::Azure),
+        }]);
+        let client = test_client_builder()
+            .with(
+                AuthMiddleware::new()
+                    .with_cache(CredentialsCache::new())
+                    .with_netrc(None)
+                    .with_text_store(None)
+                    .with_indexes(indexes)
+                    .with_auth_provider_environment(ProviderEnvironment {
+                        azure_imds_url: Some(server.uri()),
+                        ..ProviderEnvironment::default()
+                    }),
+            )
+            .build();
+
+        let url = format!("{}/simple/anyio/", server.uri());
+        assert_eq!(client.get(&url).send().await?.status(), 200);
+
+        // The refreshed token is reused for subsequent requests.
+        assert_eq!(client.get(&url).send().await?.status(), 200);
+
+        Ok(())
+    }
 }
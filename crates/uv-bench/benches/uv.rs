@@ -15,7 +15,7 @@ use flate2::write::GzEncoder;
 use futures::executor::block_on;
 use futures::io::AllowStdIo;
 use tokio_util::compat::{FuturesAsyncReadCompatExt, FuturesAsyncWriteCompatExt};
-use uv_cache::Cache;
+use uv_cache::{Cache, CacheBucket};
 use uv_client::{BaseClientBuilder, Connectivity, RegistryClientBuilder};
 use uv_distribution_filename::{SourceDistExtension, WheelFilename};
 use uv_distribution_types::Requirement;
@@ -285,7 +285,7 @@ fn resolve_warm_jupyter(c: &mut Criterion<WallTime>) {
     let manifest = Manifest::simple(vec![Requirement::from(
         uv_pep508::Requirement::from_str("jupyter==1.0.0").unwrap(),
     )]);
-    let run = setup(manifest, false);
+    let run = setup(manifest, false, false);
     c.bench_function("resolve_warm_jupyter", |b| b.iter(&run));
 }
 
@@ -293,7 +293,7 @@ fn resolve_warm_jupyter_universal(c: &mut Criterion<WallTime>) {
     let manifest = Manifest::simple(vec![Requirement::from(
         uv_pep508::Requirement::from_str("jupyter==1.0.0").unwrap(),
     )]);
-    let run = setup(manifest, true);
+    let run = setup(manifest, true, false);
     c.bench_function("resolve_warm_jupyter_universal", |b| b.iter(&run));
 }
 
@@ -304,10 +304,21 @@ fn resolve_warm_airflow(c: &mut Criterion<WallTime>) {
             uv_pep508::Requirement::from_str("apache-airflow-providers-apache-beam>3.0.0").unwrap(),
         ),
     ]);
-    let run = setup(manifest, false);
+    let run = setup(manifest, false, false);
     c.bench_function("resolve_warm_airflow", |b| b.iter(&run));
 }
 
+fn resolve_warm_airflow_dependency_cache(c: &mut Criterion<WallTime>) {
+    let manifest = Manifest::simple(vec![
+        Requirement::from(uv_pep508::Requirement::from_str("apache-airflow[all]==2.9.3").unwrap()),
+        Requirement::from(
+            uv_pep508::Requirement::from_str("apache-airflow-providers-apache-beam>3.0.0").unwrap(),
+        ),
+    ]);
+    let run = setup(manifest, false, true);
+    c.bench_function("resolve_warm_airflow_dependency_cache", |b| b.iter(&run));
+}
+
 // This takes >5m to run in CodSpeed.
 // fn resolve_warm_airflow_universal(c: &mut Criterion<WallTime>) {
 //     let manifest = Manifest::simple(vec![
@@ -316,7 +327,7 @@ fn resolve_warm_airflow(c: &mut Criterion<WallTime>) {
 //             uv_pep508::Requirement::from_str("apache-airflow-providers-apache-beam>3.0.0").unwrap(),
 //         ),
 //     ]);
-//     let run = setup(manifest, true);
+//     let run = setup(manifest, true, false);
 //     c.bench_function("resolve_warm_airflow_universal", |b| b.iter(&run));
 // }
 
@@ -338,11 +349,12 @@ criterion_group! {
         install_wheel_many_files,
         resolve_warm_jupyter,
         resolve_warm_jupyter_universal,
-        resolve_warm_airflow
+        resolve_warm_airflow,
+        resolve_warm_airflow_dependency_cache
 }
 criterion_main!(uv);
 
-fn setup(manifest: Manifest, universal: bool) -> impl Fn() {
+fn setup(manifest: Manifest, universal: bool, dependency_cache: bool) -> impl Fn() {
     let runtime = tokio::runtime::Builder::new_current_thread()
         // CodSpeed limits the total number of threads to 500
         .max_blocking_threads(256)
@@ -360,6 +372,11 @@ fn setup(manifest: Manifest, universal: bool) -> impl Fn() {
     let client = RegistryClientBuilder::new(BaseClientBuilder::default(), cache.clone())
         .build()
         .expect("failed to build registry client");
+    let dependency_cache = dependency_cache.then(|| {
+        let bucket = cache.bucket(CacheBucket::Dependencies);
+        fs_err::create_dir_all(&bucket).unwrap();
+        bucket.join("bench.msgpack")
+    });
 
     // Prime the cache: First run for performance the network operation, the second run primes
     // reading from the cache from the first run. If they are already primed, we only lose ~1s for
@@ -372,6 +389,7 @@ fn setup(manifest: Manifest, universal: bool) -> impl Fn() {
                 black_box(&client),
                 &interpreter,
                 universal,
+                dependency_cache.clone(),
             ))
             .unwrap();
     }
@@ -392,12 +410,14 @@ fn setup(manifest: Manifest, universal: bool) -> impl Fn() {
                 black_box(&client),
                 &interpreter,
                 universal,
+                dependency_cache.clone(),
             ))
             .unwrap();
     }
 }
 
 mod resolver {
+    use std::path::PathBuf;
     use std::sync::LazyLock;
 
     use anyhow::Result;
@@ -468,6 +488,7 @@ mod resolver {
         client: &RegistryClient,
         interpreter: &Interpreter,
         universal: bool,
+        dependency_cache: Option<PathBuf>,
     ) -> Result<ResolverOutput> {
         let build_isolation = BuildIsolation::default();
         let extra_build_requires = ExtraBuildRequires::default();
@@ -492,6 +513,7 @@ mod resolver {
         let installed_packages = EmptyInstalledPackages;
         let options = OptionsBuilder::new()
             .exclude_newer(exclude_newer.clone())
+            .dependency_cache(dependency_cache)
             .build();
         let sources = NoSources::default();
         let dependency_metadata = DependencyMetadata::default();
@@ -9,11 +9,11 @@ use std::hint::black_box;
 use std::path::{Path, PathBuf};
 
 use clap::Parser;
-use criterion::{Criterion, criterion_group, criterion_main, measurement::WallTime};
+use criterion::{BatchSize, Criterion, criterion_group, criterion_main, measurement::WallTime};
 
 use uv::GlobalInitialization;
 use uv::commands::ExitStatus;
-use uv_cache::Cache;
+use uv_cache::{Cache, CacheBucket};
 use uv_cli::Cli;
 use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
 
@@ -325,6 +325,28 @@ fn run_python_version_synthetic_workspace(c: &mut Criterion<WallTime>) {
             );
         });
     });
+
+    // Measure the same run without the persisted workspace members, i.e., with a full discovery.
+    let workspaces_bucket = Cache::from_path(&cache_dir).bucket(CacheBucket::Workspaces);
+    c.bench_function("run_python_version_synthetic_workspace_uncached", |b| {
+        b.iter_batched(
+            || {
+                fs_err::remove_dir_all(&workspaces_bucket)
+                    .expect("Failed to remove cached workspace members");
+            },
+            |()| {
+                let cli =
+                    run_python_version_cli(black_box(&workspace_dir), black_box(&cache_dir), true);
+                run_cli(
+                    &runtime,
+                    cli,
+                    GlobalInitialization::Reuse,
+                    "Failed to run uncached synthetic workspace benchmark",
+                );
+            },
+            BatchSize::PerIteration,
+        );
+    });
 }
 
 fn run_python_version_cli(workspace_dir: &str, cache_dir: &str, offline: bool) -> Cli {
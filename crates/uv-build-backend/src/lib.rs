@@ -37,6 +37,8 @@ pub enum Error {
     Persist(PathBuf, #[source] io::Error),
     #[error("Invalid metadata format in: {}", _0.user_display())]
     Toml(PathBuf, #[source] toml::de::Error),
+    #[error("Expected `pyproject.toml` to be in a directory, found: {}", _0.user_display())]
+    MissingParent(PathBuf),
     #[error("Failed to serialize pyproject.toml")]
     TomlSerialize(#[source] toml::ser::Error),
     #[error("Invalid project metadata")]
@@ -16,7 +16,7 @@ use walkdir::WalkDir;
 use uv_fs::Simplified;
 use uv_globfilter::{GlobDirFilter, PortableGlobParser};
 use uv_normalize::{ExtraName, PackageName};
-use uv_pep440::{Version, VersionSpecifiers};
+use uv_pep440::{Version, VersionParseError, VersionSpecifiers};
 use uv_pep508::{
     ExtraOperator, MarkerExpression, MarkerTree, MarkerValueExtra, Requirement, VersionOrUrl,
 };
@@ -68,6 +68,18 @@ pub enum ValidationError {
     DescriptionNewlines,
     #[error("Dynamic metadata is not supported")]
     Dynamic,
+    #[error(
+        "`project.version` is missing; set it, or read the version from a file with `tool.uv.build-backend.version`"
+    )]
+    MissingVersion,
+    #[error("`project.version` must not be set when `tool.uv.build-backend.version` is used")]
+    StaticAndDynamicVersion,
+    #[error(
+        "`project.dynamic` must contain `version` when `tool.uv.build-backend.version` is used"
+    )]
+    UndeclaredDynamicVersion,
+    #[error("Version file `{}` does not contain a valid version", _0.user_display())]
+    InvalidVersionFile(PathBuf, #[source] VersionParseError),
     #[error(
         "When `project.license-files` is defined, `project.license` must be an SPDX expression string"
     )]
@@ -373,19 +385,68 @@ impl PyProjectToml {
         &self.project.name.normalized
     }
 
-    pub(crate) fn version(&self) -> &Version {
-        &self.project.version
+    /// The version of the project, either from `project.version` or read from the version file.
+    pub(crate) fn version(&self) -> Result<&Version, ValidationError> {
+        self.project
+            .version
+            .as_ref()
+            .ok_or(ValidationError::MissingVersion)
     }
 
     pub(crate) fn parse(path: &Path) -> Result<Self, Error> {
         let contents = fs_err::read_to_string(path)?;
-        let pyproject_toml =
+        let mut pyproject_toml: Self =
             tracing::info_span!("toml::from_str uv build backend", path = %path.display())
                 .in_scope(|| toml::from_str(&contents))
                 .map_err(|err| Error::Toml(path.to_path_buf(), err))?;
+        let Some(root) = path.parent() else {
+            return Err(Error::MissingParent(path.to_path_buf()));
+        };
+        pyproject_toml.resolve_version(root)?;
         Ok(pyproject_toml)
     }
 
+    /// The file to read the project version from, if the version is dynamic.
+    pub(crate) fn version_file(&self) -> Option<&Path> {
+        Some(self.settings()?.version.as_ref()?.file.as_path())
+    }
+
+    /// Whether `version` is declared in `project.dynamic`.
+    fn has_dynamic_version(&self) -> bool {
+        self.project
+            .dynamic
+            .iter()
+            .flatten()
+            .any(|field| field == "version")
+    }
+
+    /// Fill in `project.version` from `tool.uv.build-backend.version`, if configured.
+    fn resolve_version(&mut self, root: &Path) -> Result<(), Error> {
+        let Some(version_file) = self.version_file() else {
+            if self.project.version.is_none() {
+                return Err(ValidationError::MissingVersion.into());
+            }
+            return Ok(());
+        };
+        if self.project.version.is_some() {
+            return Err(ValidationError::StaticAndDynamicVersion.into());
+        }
+        if !self.has_dynamic_version() {
+            return Err(ValidationError::UndeclaredDynamicVersion.into());
+        }
+
+        // Tolerate surrounding whitespace, such as a trailing newline.
+        let contents = fs_err::read_to_string(root.join(version_file))?;
+        let version = Version::from_str(contents.trim())
+            .map_err(|err| ValidationError::InvalidVersionFile(version_file.to_path_buf(), err))?;
+        debug!(
+            "Read version {version} from `{}`",
+            version_file.user_display()
+        );
+        self.project.version = Some(version);
+        Ok(())
+    }
+
     pub(crate) fn readme(&self) -> Option<&Readme> {
         self.project.readme.as_ref()
     }
@@ -491,11 +552,13 @@ impl PyProjectToml {
             None => (None, None),
         };
 
+        // The only dynamic field we support is a version read from a file.
         if self
             .project
             .dynamic
-            .as_ref()
-            .is_some_and(|dynamic| !dynamic.is_empty())
+            .iter()
+            .flatten()
+            .any(|field| field != "version" || self.version_file().is_none())
         {
             return Err(ValidationError::Dynamic.into());
         }
@@ -651,7 +714,7 @@ impl PyProjectToml {
         Ok(Metadata23 {
             metadata_version: metadata_version.to_string(),
             name: self.project.name.given.clone(),
-            version: self.project.version.to_string(),
+            version: self.version()?.to_string(),
             // Not supported.
             platforms: vec![],
             // Not supported.
@@ -950,7 +1013,9 @@ struct Project {
     /// The name of the project.
     name: VerbatimPackageName,
     /// The version of the project.
-    version: Version,
+    ///
+    /// Only optional if the version is read from a file, see `tool.uv.build-backend.version`.
+    version: Option<Version>,
     /// The summary description of the project in one line.
     description: Option<String>,
     /// The full description of the project (i.e. the README).
@@ -1008,7 +1073,8 @@ struct Project {
     /// Specifies which fields listed by PEP 621 were intentionally unspecified so another tool
     /// can/will provide such metadata dynamically.
     ///
-    /// Not supported, an error if anything but the default empty list.
+    /// Not supported, an error if anything but the default empty list, or `["version"]` with
+    /// `tool.uv.build-backend.version`.
     dynamic: Option<Vec<String>>,
 }
 
@@ -2001,6 +2067,65 @@ mod tests {
         ");
     }
 
+    #[test]
+    fn version_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let pyproject_toml = indoc! {r#"
+            [project]
+            name = "hello-world"
+            dynamic = ["version"]
+
+            [build-system]
+            requires = ["uv_build>=0.4.15,<0.5.0"]
+            build-backend = "uv_build"
+
+            [tool.uv.build-backend]
+            version = { file = "VERSION" }
+        "#
+        };
+        fs_err::write(temp_dir.path().join("pyproject.toml"), pyproject_toml).unwrap();
+        fs_err::write(temp_dir.path().join("VERSION"), " 1.2.3\n\n").unwrap();
+
+        let pyproject_toml = PyProjectToml::parse(&temp_dir.path().join("pyproject.toml")).unwrap();
+        assert_eq!(pyproject_toml.version().unwrap().to_string(), "1.2.3");
+        let metadata = pyproject_toml.to_metadata(temp_dir.path()).unwrap();
+        assert_eq!(metadata.version, "1.2.3");
+
+        // The version file must contain a valid version.
+        fs_err::write(temp_dir.path().join("VERSION"), "one point two\n").unwrap();
+        let err = PyProjectToml::parse(&temp_dir.path().join("pyproject.toml")).unwrap_err();
+        assert_snapshot!(format_err(err), @"
+        Invalid project metadata
+          Caused by: Version file `VERSION` does not contain a valid version
+          Caused by: expected version to start with a number, but no leading ASCII digits were found
+        ");
+    }
+
+    #[test]
+    fn version_file_static_version() {
+        let temp_dir = TempDir::new().unwrap();
+        let pyproject_toml = indoc! {r#"
+            [project]
+            name = "hello-world"
+            version = "1.0.0"
+
+            [build-system]
+            requires = ["uv_build>=0.4.15,<0.5.0"]
+            build-backend = "uv_build"
+
+            [tool.uv.build-backend]
+            version = { file = "VERSION" }
+        "#
+        };
+        fs_err::write(temp_dir.path().join("pyproject.toml"), pyproject_toml).unwrap();
+
+        let err = PyProjectToml::parse(&temp_dir.path().join("pyproject.toml")).unwrap_err();
+        assert_snapshot!(format_err(err), @"
+        Invalid project metadata
+          Caused by: `project.version` must not be set when `tool.uv.build-backend.version` is used
+        ");
+    }
+
     fn script_error(contents: &str) -> String {
         let err = toml::from_str::<PyProjectToml>(contents)
             .unwrap()
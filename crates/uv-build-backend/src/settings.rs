@@ -171,6 +171,20 @@ pub struct BuildBackendSettings {
         example = r#"data = { headers = "include/headers", scripts = "bin" }"#
     )]
     pub(crate) data: WheelDataIncludes,
+
+    /// Read the project version from a file, instead of from `project.version`.
+    ///
+    /// The file must contain a single PEP 440 version; surrounding whitespace is ignored. When
+    /// this option is set, `project.version` must be omitted and `project.dynamic` must contain
+    /// `"version"`.
+    ///
+    /// `uv version` reads and updates the file when this option is set.
+    #[option(
+        default = r#"None"#,
+        value_type = "dict[str, str]",
+        example = r#"version = { file = "VERSION" }"#
+    )]
+    pub(crate) version: Option<VersionSource>,
 }
 
 impl Default for BuildBackendSettings {
@@ -184,6 +198,7 @@ impl Default for BuildBackendSettings {
             wheel_exclude: Vec::new(),
             namespace: false,
             data: WheelDataIncludes::default(),
+            version: None,
         }
     }
 }
@@ -199,6 +214,17 @@ pub(crate) enum ModuleName {
     Names(Vec<String>),
 }
 
+/// Where to read a dynamic project version from.
+///
+/// See `BuildBackendSettings::version`.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub(crate) struct VersionSource {
+    /// The file containing the version, relative to the project root.
+    pub(crate) file: PathBuf,
+}
+
 /// Data includes for wheels.
 ///
 /// See `BuildBackendSettings::data`.
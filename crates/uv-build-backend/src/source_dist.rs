@@ -36,7 +36,7 @@ pub fn build_source_dist(
     let pyproject_toml = PyProjectToml::parse(&source_tree.join("pyproject.toml"))?;
     let filename = SourceDistFilename {
         name: pyproject_toml.name().clone(),
-        version: pyproject_toml.version().clone(),
+        version: pyproject_toml.version()?.clone(),
         extension: SourceDistExtension::TarGz,
     };
     let source_dist_path = source_dist_directory.join(filename.to_string());
@@ -64,7 +64,7 @@ pub fn list_source_dist(
     let pyproject_toml = PyProjectToml::parse(&source_tree.join("pyproject.toml"))?;
     let filename = SourceDistFilename {
         name: pyproject_toml.name().clone(),
-        version: pyproject_toml.version().clone(),
+        version: pyproject_toml.version()?.clone(),
         extension: SourceDistExtension::TarGz,
     };
     let mut files = FileList::new();
@@ -126,6 +126,16 @@ fn source_dist_matcher(
         include_globs.push(glob);
     }
 
+    // Include the version file
+    if let Some(version_file) = pyproject_toml.version_file() {
+        let version_file = normalize_path(version_file);
+        trace!("Including version file at: {}", version_file.user_display());
+        let version_file = version_file.portable_display().to_string();
+        let glob =
+            Glob::new(&globset::escape(&version_file)).expect("escaped globset is parseable");
+        include_globs.push(glob);
+    }
+
     // Include the license files
     for license_files in pyproject_toml.license_files_source_dist() {
         trace!("Including license files at: {license_files}`");
@@ -211,14 +221,14 @@ fn write_source_dist(
 
     let filename = SourceDistFilename {
         name: pyproject_toml.name().clone(),
-        version: pyproject_toml.version().clone(),
+        version: pyproject_toml.version()?.clone(),
         extension: SourceDistExtension::TarGz,
     };
 
     let top_level = format!(
         "{}-{}",
         pyproject_toml.name().as_dist_info_name(),
-        pyproject_toml.version()
+        pyproject_toml.version()?
     );
 
     let metadata = pyproject_toml.to_metadata(source_tree)?;
@@ -55,7 +55,7 @@ pub fn build_wheel(
 
     let filename = WheelFilename::new(
         pyproject_toml.name().clone(),
-        pyproject_toml.version().clone(),
+        pyproject_toml.version()?.clone(),
         LanguageTag::Python {
             major: 3,
             minor: None,
@@ -103,7 +103,7 @@ pub fn list_wheel(
 
     let filename = WheelFilename::new(
         pyproject_toml.name().clone(),
-        pyproject_toml.version().clone(),
+        pyproject_toml.version()?.clone(),
         LanguageTag::Python {
             major: 3,
             minor: None,
@@ -210,7 +210,7 @@ fn write_wheel(
         let license_dir = format!(
             "{}-{}.dist-info/licenses/",
             pyproject_toml.name().as_dist_info_name(),
-            pyproject_toml.version()
+            pyproject_toml.version()?
         );
 
         wheel_subdir_from_globs(
@@ -266,7 +266,7 @@ pub fn build_editable(
 
     let filename = WheelFilename::new(
         pyproject_toml.name().clone(),
-        pyproject_toml.version().clone(),
+        pyproject_toml.version()?.clone(),
         LanguageTag::Python {
             major: 3,
             minor: None,
@@ -365,7 +365,7 @@ fn write_data_files<'data>(
         let data_dir = format!(
             "{}-{}.data/{}/",
             pyproject_toml.name().as_dist_info_name(),
-            pyproject_toml.version(),
+            pyproject_toml.version()?,
             name
         );
 
@@ -414,7 +414,7 @@ pub fn metadata(
 
     let filename = WheelFilename::new(
         pyproject_toml.name().clone(),
-        pyproject_toml.version().clone(),
+        pyproject_toml.version()?.clone(),
         LanguageTag::Python {
             major: 3,
             minor: None,
@@ -659,7 +659,7 @@ fn write_dist_info(
     let dist_info_dir = format!(
         "{}-{}.dist-info",
         pyproject_toml.name().as_dist_info_name(),
-        pyproject_toml.version()
+        pyproject_toml.version()?
     );
 
     writer.write_directory(&dist_info_dir)?;
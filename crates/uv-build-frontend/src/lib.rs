@@ -4,6 +4,7 @@
 
 mod error;
 mod pipreqs;
+mod running;
 
 use std::borrow::Cow;
 use std::ffi::OsString;
@@ -33,7 +34,8 @@ use uv_cache_key::cache_digest;
 use uv_configuration::{BuildKind, BuildOutput, NoSources};
 use uv_distribution::BuildRequires;
 use uv_distribution_types::{
-    ConfigSettings, ExtraBuildRequirement, ExtraBuildRequires, IndexLocations, Requirement,
+    BuildEnvPassthrough, ConfigSettings, ExtraBuildRequirement, ExtraBuildRequires, IndexLocations,
+    Requirement,
 };
 use uv_fs::{LockedFile, LockedFileMode};
 use uv_fs::{PythonExt, Simplified};
@@ -49,6 +51,8 @@ use uv_warnings::warn_user_once;
 use uv_workspace::WorkspaceCache;
 
 pub use crate::error::{Error, MissingHeaderCause};
+use crate::running::RunningBuild;
+pub use crate::running::abort_running_builds;
 
 /// The default backend to use when PEP 517 is used without a `build-system` section.
 static DEFAULT_BACKEND: LazyLock<Pep517Backend> = LazyLock::new(|| Pep517Backend {
@@ -305,6 +309,7 @@ impl SourceBuild {
         build_stack: &BuildStack,
         build_kind: BuildKind,
         mut environment_variables: FxHashMap<OsString, OsString>,
+        build_env_passthrough: &BuildEnvPassthrough,
         level: BuildOutput,
         credentials_cache: &CredentialsCache,
     ) -> Result<Self, Error> {
@@ -442,9 +447,30 @@ impl SourceBuild {
             OsString::from(venv.scripts())
         };
 
+        // If a pass-through policy applies, only forward the allowed variables from the parent
+        // environment.
+        let inherited_environment = build_env_passthrough
+            .for_package(package_name.as_ref())
+            .map(|passthrough| {
+                let inherited_environment = passthrough_environment(passthrough);
+                debug!(
+                    "Forwarding environment variables to the build backend: {}",
+                    inherited_environment
+                        .keys()
+                        .map(|key| key.to_string_lossy())
+                        .sorted()
+                        .join(", ")
+                );
+                inherited_environment
+            });
+
         // Create the PEP 517 build environment. If build isolation is disabled, we assume the build
         // environment is already set up.
-        let runner = PythonRunner::new(source_build_context.concurrent_build_slots.clone(), level);
+        let runner = PythonRunner::new(
+            source_build_context.concurrent_build_slots.clone(),
+            level,
+            inherited_environment,
+        );
         if build_isolation.is_isolated(package_name.as_ref()) {
             debug!("Creating PEP 517 build environment");
 
@@ -847,6 +873,7 @@ impl SourceBuild {
                 &self.source_tree,
                 &self.environment_variables,
                 &self.modified_path,
+                self.version_id.as_deref(),
             )
             .instrument(span)
             .await?;
@@ -967,6 +994,7 @@ impl SourceBuild {
                 &self.source_tree,
                 &self.environment_variables,
                 &self.modified_path,
+                self.version_id.as_deref(),
             )
             .instrument(span)
             .await?;
@@ -1077,6 +1105,7 @@ async fn create_pep517_build_environment(
             source_tree,
             environment_variables,
             modified_path,
+            version_id,
         )
         .instrument(span)
         .await?;
@@ -1175,12 +1204,61 @@ async fn create_pep517_build_environment(
     Ok(())
 }
 
+/// Environment variables that are always forwarded to build backends when a pass-through policy
+/// is configured, as common tooling fails to function without them.
+const BUILD_ENV_BASELINE: &[&str] = &[
+    "HOME",
+    "USER",
+    "LOGNAME",
+    "LANG",
+    "LC_ALL",
+    "LC_CTYPE",
+    "TMPDIR",
+    "TEMP",
+    "TMP",
+    "SYSTEMROOT",
+    "SYSTEMDRIVE",
+    "WINDIR",
+    "COMSPEC",
+    "PATHEXT",
+    "USERPROFILE",
+    "APPDATA",
+    "LOCALAPPDATA",
+    "PROGRAMDATA",
+];
+
+/// Collect the variables to forward from the parent environment to a build backend, given the
+/// user-provided pass-through list.
+fn passthrough_environment(passthrough: &[String]) -> FxHashMap<OsString, OsString> {
+    env::vars_os()
+        .filter(|(key, _)| {
+            let Some(key) = key.to_str() else {
+                return false;
+            };
+            BUILD_ENV_BASELINE
+                .iter()
+                .copied()
+                .chain(passthrough.iter().map(String::as_str))
+                .any(|allowed| {
+                    // Environment variables are case-insensitive on Windows.
+                    if cfg!(windows) {
+                        allowed.eq_ignore_ascii_case(key)
+                    } else {
+                        allowed == key
+                    }
+                })
+        })
+        .collect()
+}
+
 /// A runner that manages the execution of external python processes with a
 /// concurrency limit.
 #[derive(Debug)]
 struct PythonRunner {
     concurrent_build_slots: Arc<Semaphore>,
     level: BuildOutput,
+    /// The variables to inherit from the parent environment, or `None` to inherit all of them.
+    inherited_environment: Option<FxHashMap<OsString, OsString>>,
 }
 
 #[derive(Debug)]
@@ -1192,10 +1270,15 @@ struct PythonRunnerOutput {
 
 impl PythonRunner {
     /// Create a `PythonRunner` with the provided shared concurrency semaphore and output level.
-    fn new(concurrent_build_slots: Arc<Semaphore>, level: BuildOutput) -> Self {
+    fn new(
+        concurrent_build_slots: Arc<Semaphore>,
+        level: BuildOutput,
+        inherited_environment: Option<FxHashMap<OsString, OsString>>,
+    ) -> Self {
         Self {
             concurrent_build_slots,
             level,
+            inherited_environment,
         }
     }
 
@@ -1212,6 +1295,7 @@ impl PythonRunner {
         source_tree: &Path,
         environment_variables: &FxHashMap<OsString, OsString>,
         modified_path: &OsString,
+        version_id: Option<&str>,
     ) -> Result<PythonRunnerOutput, Error> {
         /// Read lines from a reader and store them in a buffer.
         async fn read_from(
@@ -1223,8 +1307,9 @@ impl PythonRunner {
                 match reader.next_segment().await? {
                     Some(line_buf) => {
                         let line_buf = line_buf.strip_suffix(b"\r").unwrap_or(&line_buf);
-                        let line = String::from_utf8_lossy(line_buf).into();
+                        let line: String = String::from_utf8_lossy(line_buf).into();
                         let _ = write!(printer, "{line}");
+                        uv_types::report_build_output(&line);
                         buffer.push(line);
                     }
                     None => return Ok(()),
@@ -1234,7 +1319,11 @@ impl PythonRunner {
 
         let _permit = self.concurrent_build_slots.acquire().await.unwrap();
 
-        let mut child = Command::new(venv.python_executable())
+        let mut command = Command::new(venv.python_executable());
+        if let Some(inherited_environment) = &self.inherited_environment {
+            command.env_clear().envs(inherited_environment);
+        }
+        command
             .args(["-c", script])
             .current_dir(source_tree.simplified())
             .envs(environment_variables)
@@ -1251,10 +1340,27 @@ impl PythonRunner {
             .env_remove(EnvVars::UV_API_KEY)
             .env_remove(EnvVars::PYX_AUTH_TOKEN)
             .env_remove(EnvVars::UV_AUTH_TOKEN)
+            .stdin(std::process::Stdio::null())
             .stdout(std::process::Stdio::piped())
-            .stderr(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped());
+
+        // Run the build backend in its own process group, such that it can be aborted along with
+        // any processes it spawns (e.g., compilers) if uv is interrupted.
+        #[cfg(unix)]
+        command.process_group(0);
+
+        let mut child = command
             .spawn()
             .map_err(|err| Error::CommandFailed(venv.python_executable().to_path_buf(), err))?;
+        let _running = child.id().map(|pid| {
+            RunningBuild::register(
+                pid,
+                version_id.map_or_else(
+                    || source_tree.user_display().to_string(),
+                    ToString::to_string,
+                ),
+            )
+        });
 
         // Create buffers to capture `stdout` and `stderr`.
         let mut stdout_buf = Vec::with_capacity(1024);
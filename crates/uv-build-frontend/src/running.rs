@@ -0,0 +1,56 @@
+//! Tracking of running build backend processes, such that they can be aborted on interrupt.
+
+use std::sync::{LazyLock, Mutex};
+
+use rustc_hash::FxHashMap;
+
+/// The build backend processes that are currently running, by process ID, along with the name of
+/// the distribution being built.
+static RUNNING_BUILDS: LazyLock<Mutex<FxHashMap<u32, String>>> = LazyLock::new(Mutex::default);
+
+/// A running build backend process, which is deregistered on drop.
+#[derive(Debug)]
+pub(crate) struct RunningBuild {
+    pid: u32,
+}
+
+impl RunningBuild {
+    /// Register a running build backend process with the given process ID.
+    pub(crate) fn register(pid: u32, name: String) -> Self {
+        RUNNING_BUILDS.lock().unwrap().insert(pid, name);
+        Self { pid }
+    }
+}
+
+impl Drop for RunningBuild {
+    fn drop(&mut self) {
+        RUNNING_BUILDS.lock().unwrap().remove(&self.pid);
+    }
+}
+
+/// Abort all running build backend processes, returning the names of the aborted builds.
+///
+/// On Unix, build backends are spawned in their own process group, so the entire group is killed,
+/// including any processes spawned by the build backend (e.g., compilers). On Windows, the console
+/// delivers `Ctrl-C` to all attached processes, so the build backends are not killed explicitly.
+pub fn abort_running_builds() -> Vec<String> {
+    let running = std::mem::take(&mut *RUNNING_BUILDS.lock().unwrap());
+
+    #[cfg(unix)]
+    for pid in running.keys() {
+        use nix::sys::signal::{Signal, killpg};
+        use nix::unistd::Pid;
+
+        let Ok(pid) = i32::try_from(*pid) else {
+            continue;
+        };
+        if let Err(err) = killpg(Pid::from_raw(pid), Signal::SIGKILL) {
+            tracing::debug!("Failed to abort build backend process group {pid}: {err}");
+        }
+    }
+
+    let mut names: Vec<_> = running.into_values().collect();
+    names.sort_unstable();
+    names.dedup();
+    names
+}
@@ -70,23 +70,30 @@ impl CacheInfo {
 
         // Read the cache keys.
         let pyproject_path = directory.join("pyproject.toml");
-        let cache_keys = if let Ok(contents) = fs_err::read_to_string(&pyproject_path) {
+        let tool_uv = if let Ok(contents) = fs_err::read_to_string(&pyproject_path) {
             let result = info_span!("toml::from_str cache keys", path = %pyproject_path.display())
                 .in_scope(|| toml::from_str::<PyProjectToml>(&contents));
             if let Ok(pyproject_toml) = result {
-                pyproject_toml
-                    .tool
-                    .and_then(|tool| tool.uv)
-                    .and_then(|tool_uv| tool_uv.cache_keys)
+                pyproject_toml.tool.and_then(|tool| tool.uv)
             } else {
                 None
             }
         } else {
             None
         };
+        let (cache_keys, version_file) = match tool_uv {
+            Some(tool_uv) => (
+                tool_uv.cache_keys,
+                tool_uv
+                    .build_backend
+                    .and_then(|build_backend| build_backend.version)
+                    .map(|version| version.file),
+            ),
+            None => (None, None),
+        };
 
         // If no cache keys were defined, use the defaults.
-        let cache_keys = cache_keys.unwrap_or_else(|| {
+        let mut cache_keys = cache_keys.unwrap_or_else(|| {
             vec![
                 CacheKey::Path(Cow::Borrowed("pyproject.toml")),
                 CacheKey::Path(Cow::Borrowed("setup.py")),
@@ -97,6 +104,12 @@ impl CacheInfo {
             ]
         });
 
+        // Always include the file the uv build backend reads the version from, as it determines
+        // the version in the built metadata.
+        if let Some(version_file) = version_file {
+            cache_keys.push(CacheKey::Path(Cow::Owned(version_file)));
+        }
+
         // Incorporate timestamps from any direct filepaths.
         let mut globs = vec![];
         for cache_key in cache_keys {
@@ -335,6 +348,21 @@ struct Tool {
 #[serde(rename_all = "kebab-case")]
 struct ToolUv {
     cache_keys: Option<Vec<CacheKey>>,
+    build_backend: Option<BuildBackend>,
+}
+
+/// The `[tool.uv.build-backend]` settings that affect the built metadata.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct BuildBackend {
+    version: Option<VersionSource>,
+}
+
+/// The source of a dynamic version, i.e., `tool.uv.build-backend.version`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct VersionSource {
+    file: String,
 }
 
 #[derive(Debug, Clone, serde::Deserialize)]
@@ -443,4 +471,29 @@ mod tests_unix {
 
         Ok(())
     }
+
+    #[test]
+    fn test_cache_info_version_file() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let dir = dir.path();
+
+        fs_err::write(
+            dir.join("pyproject.toml"),
+            r#"
+            [tool.uv]
+            cache-keys = ["missing"]
+
+            [tool.uv.build-backend]
+            version = { file = "VERSION" }
+            "#,
+        )?;
+        assert_eq!(CacheInfo::from_directory(dir)?.timestamp, None);
+
+        // The version file is part of the cache key, even with custom cache keys.
+        fs_err::write(dir.join("VERSION"), "1.0.0\n")?;
+        let version = Timestamp::from_metadata(&dir.join("VERSION").metadata()?);
+        assert_eq!(CacheInfo::from_directory(dir)?.timestamp, Some(version));
+
+        Ok(())
+    }
 }
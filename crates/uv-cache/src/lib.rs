@@ -5,20 +5,26 @@ use std::ops::Deref;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::{Duration, SystemTime};
 
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::{debug, trace, warn};
 
 use uv_cache_info::Timestamp;
-use uv_fs::{LockedFile, LockedFileError, LockedFileMode, Simplified, cachedir, directories};
+use uv_distribution_filename::WheelFilename;
+use uv_distribution_types::IndexUrl;
+use uv_fs::{
+    LockedFile, LockedFileError, LockedFileMode, Simplified, cachedir, directories, entries,
+};
 use uv_normalize::PackageName;
+use uv_pep440::Version;
 use uv_pypi_types::ResolutionMetadata;
 
 pub use crate::by_timestamp::CachedByTimestamp;
 #[cfg(feature = "clap")]
 pub use crate::cli::CacheArgs;
 use crate::removal::Remover;
-pub use crate::removal::{Removal, rm_rf};
+pub use crate::removal::{Removal, measure, rm_rf};
 pub use crate::wheel::WheelCache;
 use crate::wheel::WheelCacheKind;
 pub use archive::ArchiveId;
@@ -92,6 +98,19 @@ impl CacheEntry {
         Self(self.dir().join(file))
     }
 
+    /// Record that the [`CacheEntry`] was used, by updating the modification time of a marker
+    /// file alongside it.
+    ///
+    /// Used by [`Cache::prune`] to retain recently used environments.
+    pub fn touch(&self) -> io::Result<()> {
+        let file = fs_err::OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(false)
+            .open(last_used_path(self.path()))?;
+        file.file().set_modified(SystemTime::now())
+    }
+
     /// Acquire the [`CacheEntry`] as an exclusive lock.
     pub async fn lock(&self) -> Result<LockedFile, Error> {
         fs_err::create_dir_all(self.dir())?;
@@ -102,6 +121,19 @@ impl CacheEntry {
         )
         .await?)
     }
+
+    /// Acquire the [`CacheEntry`] as an exclusive lock, telling the user what the lock holder is
+    /// doing (e.g., ``unpacking `numpy==2.0.0` ``) if another uv process holds it.
+    pub async fn lock_for(&self, activity: impl Display) -> Result<LockedFile, Error> {
+        fs_err::create_dir_all(self.dir())?;
+        Ok(LockedFile::acquire_with_activity(
+            self.path(),
+            LockedFileMode::Exclusive,
+            self.path().display(),
+            activity,
+        )
+        .await?)
+    }
 }
 
 impl AsRef<Path> for CacheEntry {
@@ -110,6 +142,16 @@ impl AsRef<Path> for CacheEntry {
     }
 }
 
+/// The suffix of the marker file that records when a [`CacheEntry`] was last used.
+const LAST_USED_SUFFIX: &str = ".last-used";
+
+/// Return the path to the marker file that records when the given cache entry was last used.
+fn last_used_path(path: &Path) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(LAST_USED_SUFFIX);
+    PathBuf::from(path)
+}
+
 /// A subdirectory within the cache.
 #[derive(Debug, Clone)]
 pub struct CacheShard(PathBuf);
@@ -137,6 +179,19 @@ impl CacheShard {
         .await?)
     }
 
+    /// Acquire the cache entry as an exclusive lock, telling the user what the lock holder is
+    /// doing (e.g., ``building `numpy==2.0.0` ``) if another uv process holds it.
+    pub async fn lock_for(&self, activity: impl Display) -> Result<LockedFile, Error> {
+        fs_err::create_dir_all(self.as_ref())?;
+        Ok(LockedFile::acquire_with_activity(
+            self.join(".lock"),
+            LockedFileMode::Exclusive,
+            self.display(),
+            activity,
+        )
+        .await?)
+    }
+
     /// Return the [`CacheShard`] as a [`PathBuf`].
     pub fn into_path_buf(self) -> PathBuf {
         self.0
@@ -157,6 +212,101 @@ impl Deref for CacheShard {
     }
 }
 
+/// Options for [`Cache::prune`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PruneOptions {
+    /// Remove all pre-built wheels, retaining only the wheels that were built from source.
+    pub ci: bool,
+    /// Retain cached environments that were used within the given duration, rather than removing
+    /// all cached environments.
+    pub older_than: Option<Duration>,
+    /// Determine what would be removed, without removing anything.
+    pub dry_run: bool,
+}
+
+/// The result of [`Cache::prune`].
+#[derive(Debug, Default)]
+pub struct PruneSummary {
+    /// The entries removed from the cache (or that would be removed, in a dry run).
+    pub removal: Removal,
+    /// The cached environments found in the cache.
+    pub environments: EnvironmentsSummary,
+}
+
+/// The cached environments found by [`Cache::prune`].
+///
+/// Like [`Removal`], byte counts will over-count files that are hard linked into multiple
+/// environments.
+#[derive(Debug, Default)]
+pub struct EnvironmentsSummary {
+    /// The number of cached environments.
+    pub num_environments: u64,
+    /// The total size of the cached environments, in bytes.
+    pub total_bytes: u64,
+    /// The number of cached environments removed (or that would be removed, in a dry run).
+    pub num_removed: u64,
+    /// The size of the cached environments removed (or that would be removed, in a dry run), in
+    /// bytes.
+    pub removed_bytes: u64,
+}
+
+/// The artifacts to retain in [`Cache::prune_unreferenced`], e.g., those referenced by a set of
+/// lockfiles.
+#[derive(Debug, Default)]
+pub struct ReferencedArtifacts {
+    /// The names of the referenced packages.
+    packages: FxHashSet<String>,
+    /// The referenced registry source distributions, as a package name and version.
+    source_distributions: FxHashSet<(String, String)>,
+    /// The cache keys of the referenced wheels.
+    wheels: FxHashSet<String>,
+}
+
+impl ReferencedArtifacts {
+    /// Mark a package as referenced, retaining its index metadata.
+    ///
+    /// Source distributions from direct URLs, local paths, and Git repositories are retained by
+    /// name alone, since they aren't versioned in the cache.
+    pub fn insert_package(&mut self, name: &PackageName) {
+        self.packages.insert(name.to_string());
+    }
+
+    /// Mark a registry source distribution, and any wheels built from it, as referenced.
+    pub fn insert_source_dist(&mut self, name: &PackageName, version: &Version) {
+        self.packages.insert(name.to_string());
+        self.source_distributions
+            .insert((name.to_string(), version.to_string()));
+    }
+
+    /// Mark a wheel as referenced.
+    pub fn insert_wheel(&mut self, filename: &WheelFilename) {
+        self.packages.insert(filename.name.to_string());
+        // Wheel entries are keyed by the wheel's cache key, while some (e.g., locks and unzipped
+        // wheels) are keyed by the wheel's stem.
+        self.wheels.insert(filename.cache_key());
+        self.wheels.insert(filename.stem());
+    }
+
+    /// Returns `true` if no artifacts are referenced.
+    pub fn is_empty(&self) -> bool {
+        self.packages.is_empty()
+    }
+
+    /// Returns `true` if the cache entry, named by a wheel's cache key or stem, is referenced.
+    fn contains_wheel(&self, path: &Path) -> bool {
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            return true;
+        };
+        // Strip the extension of any files stored alongside the wheel (e.g., `.msgpack`, `.http`,
+        // and `.lock`), but not the wheel's own extension.
+        let key = [".msgpack", ".http", ".lock", ".whl"]
+            .iter()
+            .find_map(|suffix| name.strip_suffix(suffix))
+            .unwrap_or(name);
+        self.wheels.contains(key)
+    }
+}
+
 /// The main cache abstraction.
 ///
 /// While the cache is active, it holds a read (shared) lock that prevents cache cleaning
@@ -204,6 +354,11 @@ impl Cache {
         Self { refresh, ..self }
     }
 
+    /// Return the [`Refresh`] policy for the cache.
+    pub fn refresh(&self) -> &Refresh {
+        &self.refresh
+    }
+
     /// Acquire a lock that allows removing entries from the cache.
     pub async fn with_exclusive_lock(self) -> Result<Self, LockedFileError> {
         let Self {
@@ -298,6 +453,9 @@ impl Cache {
     }
 
     /// Create a temporary directory to be used as a Python virtual environment.
+    ///
+    /// The directory is created within the cache, such that it can be moved into the archive via
+    /// [`Cache::persist`] with a rename, preserving any hard links to other cache entries.
     pub fn venv_dir(&self) -> io::Result<tempfile::TempDir> {
         fs_err::create_dir_all(self.bucket(CacheBucket::Builds))?;
         tempfile::tempdir_in(self.bucket(CacheBucket::Builds))
@@ -315,6 +473,9 @@ impl Cache {
             Refresh::None(_) => false,
             Refresh::All(_) => true,
             Refresh::Packages(packages, _, _) => packages.contains(package),
+            Refresh::Scoped(scopes, _) => {
+                scopes.packages.contains(package) || scopes.artifacts.contains(package)
+            }
         }
     }
 
@@ -326,6 +487,10 @@ impl Cache {
             Refresh::Packages(_, paths, _) => paths
                 .iter()
                 .any(|target| same_file::is_same_file(path, target).unwrap_or(false)),
+            Refresh::Scoped(scopes, _) => scopes
+                .paths
+                .iter()
+                .any(|target| same_file::is_same_file(path, target).unwrap_or(false)),
         }
     }
 
@@ -356,8 +521,78 @@ impl Cache {
                     return Ok(Freshness::Fresh);
                 }
             }
+            Refresh::Scoped(scopes, timestamp) => {
+                let has_packages = !scopes.packages.is_empty() || !scopes.artifacts.is_empty();
+                if (has_packages && package.is_none())
+                    || package.is_some_and(|package| {
+                        scopes.packages.contains(package) || scopes.artifacts.contains(package)
+                    })
+                    || path.is_some_and(|path| {
+                        scopes
+                            .paths
+                            .iter()
+                            .any(|target| same_file::is_same_file(path, target).unwrap_or(false))
+                    })
+                {
+                    timestamp
+                } else {
+                    return Ok(Freshness::Fresh);
+                }
+            }
         };
 
+        Self::freshness_since(entry, timestamp)
+    }
+
+    /// Returns the [`Freshness`] for a cache entry containing index metadata (e.g., a Simple API
+    /// response), validating it against the [`Refresh`] policy.
+    ///
+    /// Unlike [`Cache::freshness`], index metadata is revalidated by `--refresh-metadata` and
+    /// `--refresh-index`, but not by `--refresh-artifacts`.
+    pub fn index_freshness(
+        &self,
+        entry: &CacheEntry,
+        index: Option<&IndexUrl>,
+        package: Option<&PackageName>,
+    ) -> io::Result<Freshness> {
+        // Grab the cutoff timestamp, if it's relevant.
+        let timestamp = match &self.refresh {
+            Refresh::None(_) => return Ok(Freshness::Fresh),
+            Refresh::All(timestamp) => timestamp,
+            Refresh::Packages(packages, _, timestamp) => {
+                if package.is_none_or(|package| packages.contains(package)) {
+                    timestamp
+                } else {
+                    return Ok(Freshness::Fresh);
+                }
+            }
+            Refresh::Scoped(scopes, timestamp) => {
+                if scopes.metadata
+                    || index.is_some_and(|index| {
+                        // Compare indexes by their location in the cache, which is insensitive to
+                        // (e.g.) trailing slashes.
+                        let root = WheelCache::Index(index).root();
+                        scopes
+                            .indexes
+                            .iter()
+                            .any(|target| WheelCache::Index(target).root() == root)
+                    })
+                    || (!scopes.packages.is_empty()
+                        && package.is_none_or(|package| scopes.packages.contains(package)))
+                {
+                    timestamp
+                } else {
+                    return Ok(Freshness::Fresh);
+                }
+            }
+        };
+
+        Self::freshness_since(entry, timestamp)
+    }
+
+    /// Returns the [`Freshness`] for a cache entry, given the cutoff timestamp of the [`Refresh`]
+    /// policy.
+    fn freshness_since(entry: &CacheEntry, timestamp: &Timestamp) -> io::Result<Freshness> {
         match fs_err::metadata(entry.path()) {
             Ok(metadata) => {
                 if Timestamp::from_metadata(&metadata) >= *timestamp {
@@ -582,7 +817,14 @@ impl Cache {
     }
 
     /// Prune dangling cache entries and cached environments.
-    pub fn prune(&self, ci: bool) -> Result<Removal, io::Error> {
+    pub fn prune(&self, options: PruneOptions) -> Result<PruneSummary, io::Error> {
+        let PruneOptions {
+            ci,
+            older_than,
+            dry_run,
+        } = options;
+        let remove = |path: &Path| if dry_run { measure(path) } else { rm_rf(path) };
+
         let mut summary = Removal::default();
 
         // First, remove any top-level directories that are unused. These typically represent
@@ -604,25 +846,56 @@ impl Cache {
                 if CacheBucket::iter().all(|bucket| entry.file_name() != bucket.to_str()) {
                     let path = entry.path();
                     debug!("Removing dangling cache bucket: {}", path.display());
-                    summary += rm_rf(path)?;
+                    summary += remove(&path)?;
                 }
             } else {
                 // If the file is not a marker file, remove it.
                 let path = entry.path();
                 debug!("Removing dangling cache bucket: {}", path.display());
-                summary += rm_rf(path)?;
+                summary += remove(&path)?;
             }
         }
 
-        // Second, remove all cached environments. Centralized project environments can be
-        // referenced by `.venv` links, but are recreated when next needed.
+        // Second, remove cached environments. Centralized project environments can be referenced by
+        // `.venv` links, but are recreated when next needed.
+        let mut environments = EnvironmentsSummary::default();
+        let mut retained = FxHashSet::default();
+        let now = SystemTime::now();
         match fs_err::read_dir(self.bucket(CacheBucket::Environments)) {
             Ok(entries) => {
                 for entry in entries {
                     let entry = entry?;
                     let path = entry.path();
+                    let file_type = entry.file_type()?;
+
+                    // Environments cached by their resolution are stored as links to the archive
+                    // bucket, sharded by interpreter, and can be retained if recently used.
+                    if let Some(older_than) = older_than
+                        && file_type.is_dir()
+                        && !path.join("pyvenv.cfg").exists()
+                    {
+                        summary += self.prune_environment_shard(
+                            &path,
+                            older_than,
+                            now,
+                            dry_run,
+                            &mut environments,
+                            &mut retained,
+                        )?;
+                        continue;
+                    }
+
+                    // Otherwise, remove the entry, including any environments it links to.
+                    if file_type.is_dir() {
+                        for bytes in self.environment_sizes(&path)? {
+                            environments.num_environments += 1;
+                            environments.total_bytes += bytes;
+                            environments.num_removed += 1;
+                            environments.removed_bytes += bytes;
+                        }
+                    }
                     debug!("Removing cached environment: {}", path.display());
-                    summary += rm_rf(path)?;
+                    summary += remove(&path)?;
                 }
             }
             Err(err) if err.kind() == io::ErrorKind::NotFound => (),
@@ -639,7 +912,7 @@ impl Cache {
                         let path = entry.path();
                         if path.is_dir() {
                             debug!("Removing unzipped wheel entry: {}", path.display());
-                            summary += rm_rf(path)?;
+                            summary += remove(&path)?;
                         }
                     }
                 }
@@ -683,7 +956,7 @@ impl Cache {
                         }
 
                         debug!("Removing unzipped built wheel entry: {}", path.display());
-                        summary += rm_rf(path)?;
+                        summary += remove(&path)?;
                     }
                 }
             }
@@ -698,9 +971,13 @@ impl Cache {
                     let entry = entry?;
                     let path = entry.path();
                     let target = fs_err::canonicalize(&path)?;
+                    // Never remove the target of a retained environment.
+                    if retained.contains(&target) {
+                        continue;
+                    }
                     if !references.contains_key(&target) {
                         debug!("Removing dangling cache archive: {}", path.display());
-                        summary += rm_rf(path)?;
+                        summary += remove(&path)?;
                     }
                 }
             }
@@ -708,9 +985,139 @@ impl Cache {
             Err(err) => return Err(err),
         }
 
+        Ok(PruneSummary {
+            removal: summary,
+            environments,
+        })
+    }
+
+    /// Prune a shard of environments cached by their resolution, removing any environments that
+    /// were not used within `older_than`, along with any environments whose link is dangling.
+    ///
+    /// The archive targets of retained environments are added to `retained`.
+    fn prune_environment_shard(
+        &self,
+        shard: &Path,
+        older_than: Duration,
+        now: SystemTime,
+        dry_run: bool,
+        environments: &mut EnvironmentsSummary,
+        retained: &mut FxHashSet<PathBuf>,
+    ) -> io::Result<Removal> {
+        let remove = |path: &Path| if dry_run { measure(path) } else { rm_rf(path) };
+
+        let mut summary = Removal::default();
+        let mut empty = true;
+        for entry in fs_err::read_dir(shard)? {
+            let path = entry?.path();
+
+            // Skip the lock and marker files, which are removed alongside their environment.
+            let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+            #[expect(clippy::case_sensitive_file_extension_comparisons)]
+            if name.ends_with(".lock") || name.ends_with(LAST_USED_SUFFIX) {
+                continue;
+            }
+
+            // Remove any cached resolutions, which are short-lived and cheap to recreate.
+            #[expect(clippy::case_sensitive_file_extension_comparisons)]
+            if name.ends_with(".spec") {
+                summary += remove(&path)?;
+                continue;
+            }
+
+            let last_used = last_used_path(&path);
+            let lock = path.with_file_name(format!("{name}.lock"));
+            match self.resolve_environment_link(&path)? {
+                Some(target) => {
+                    let bytes = measure(&target)?.total_bytes;
+                    environments.num_environments += 1;
+                    environments.total_bytes += bytes;
+
+                    // Fall back to the time at which the link was created for environments that
+                    // were last used before use was tracked.
+                    let used = fs_err::metadata(&last_used)
+                        .or_else(|_| fs_err::symlink_metadata(&path))
+                        .and_then(|metadata| metadata.modified())
+                        .unwrap_or(now);
+                    if now.duration_since(used).unwrap_or_default() < older_than {
+                        trace!("Retaining cached environment: {}", path.display());
+                        retained.insert(target);
+                        empty = false;
+                        continue;
+                    }
+
+                    debug!("Removing unused cached environment: {}", path.display());
+                    environments.num_removed += 1;
+                    environments.removed_bytes += bytes;
+                }
+                None => {
+                    debug!("Removing dangling cached environment: {}", path.display());
+                }
+            }
+            summary += remove(&path)?;
+            summary += remove(&last_used)?;
+            summary += remove(&lock)?;
+        }
+
+        // Remove the shard itself once all of its environments have been removed.
+        if empty {
+            summary += remove(shard)?;
+        }
+
         Ok(summary)
     }
 
+    /// Remove any wheels, source distributions, and index metadata that aren't included in the
+    /// [`ReferencedArtifacts`].
+    ///
+    /// Unlike [`Cache::prune`], this removes valid cache entries. Any unzipped wheels that are
+    /// no longer referenced are left in the archive bucket, to be removed by [`Cache::prune`].
+    pub fn prune_unreferenced(
+        &self,
+        referenced: &ReferencedArtifacts,
+        dry_run: bool,
+    ) -> io::Result<Removal> {
+        let mut summary = Removal::default();
+        for bucket in [
+            CacheBucket::Wheels,
+            CacheBucket::SourceDistributions,
+            CacheBucket::Simple,
+        ] {
+            summary += bucket.retain(self, referenced, dry_run)?;
+        }
+        Ok(summary)
+    }
+
+    /// Return the size of each environment in the given entry of the environments bucket, in bytes.
+    ///
+    /// The entry is either an environment itself, or a shard of links to environments in the
+    /// archive bucket.
+    fn environment_sizes(&self, path: &Path) -> io::Result<Vec<u64>> {
+        if path.join("pyvenv.cfg").exists() {
+            return Ok(vec![measure(path)?.total_bytes]);
+        }
+        let mut sizes = Vec::new();
+        for entry in fs_err::read_dir(path)? {
+            if let Some(target) = self.resolve_environment_link(&entry?.path())? {
+                sizes.push(measure(target)?.total_bytes);
+            }
+        }
+        Ok(sizes)
+    }
+
+    /// Resolve a link to a cached environment, returning `None` if the link is dangling or doesn't
+    /// point into the archive bucket.
+    fn resolve_environment_link(&self, path: &Path) -> io::Result<Option<PathBuf>> {
+        // Cache entries may contain unexpected links to paths outside the cache.
+        let archive_root = fs_err::canonicalize(&self.root)?.join(CacheBucket::Archive.to_str());
+        Ok(self
+            .resolve_link(path)
+            .ok()
+            .filter(|target| target.starts_with(&archive_root)))
+    }
+
     /// Find all references to entries in the archive bucket.
     ///
     /// Archive entries are often referenced by symlinks in other cache buckets. This method
@@ -1188,6 +1595,37 @@ pub enum CacheBucket {
     /// Cache structure:
     ///  * `osv-v0/vulnerability/<vuln_id>.msgpack` — cached full vulnerability records
     Osv,
+    /// Remote requirements files (e.g., constraints and overrides provided via URL).
+    ///
+    /// Cache structure:
+    ///  * `requirements-v0/<digest(url)>.txt` — the most recently fetched contents of the file
+    ///
+    /// The cached contents are only used when operating offline.
+    Requirements,
+    /// Checkpoints of interrupted `uv lock` resolutions.
+    ///
+    /// Cache structure:
+    ///  * `checkpoints-v0/<digest(workspace root)>.txt` — the versions selected by each completed
+    ///    fork, used as preferences when the resolution is retried
+    ///
+    /// A checkpoint is removed once the resolution succeeds.
+    Checkpoints,
+    /// The members discovered for each workspace.
+    ///
+    /// Cache structure:
+    ///  * `workspaces-v0/<digest(workspace root)>.msgpack` — the members of the workspace, along
+    ///    with the modification times of their manifests and of the directories searched for them
+    ///
+    /// An entry is only reused if none of the recorded modification times changed.
+    Workspaces,
+    /// The dependencies of the registry wheels used by `uv lock` resolutions.
+    ///
+    /// Cache structure:
+    ///  * `dependencies-v0/<digest(workspace root)>.msgpack` — the metadata of each registry wheel
+    ///    used by the last resolution, keyed by the package name, version, and wheel hash
+    ///
+    /// An entry is discarded if the resolver settings it was written with changed.
+    Dependencies,
 }
 
 impl CacheBucket {
@@ -1203,7 +1641,7 @@ impl CacheBucket {
             Self::Interpreter => "interpreter-v4",
             // Note that when bumping this, you'll also need to bump it
             // in `crates/uv/tests/build/cache_clean.rs`.
-            Self::Simple => "simple-v24",
+            Self::Simple => "simple-v26",
             // Note that when bumping this, you'll also need to bump it
             // in `crates/uv/tests/build/cache_prune.rs`.
             Self::Wheels => "wheels-v6",
@@ -1215,6 +1653,10 @@ impl CacheBucket {
             Self::Python => "python-v0",
             Self::Binaries => "binaries-v0",
             Self::Osv => "osv-v0",
+            Self::Requirements => "requirements-v0",
+            Self::Checkpoints => "checkpoints-v0",
+            Self::Workspaces => "workspaces-v0",
+            Self::Dependencies => "dependencies-v0",
         }
     }
 
@@ -1323,7 +1765,164 @@ impl CacheBucket {
             | Self::Environments
             | Self::Python
             | Self::Binaries
-            | Self::Osv => {
+            | Self::Osv
+            | Self::Requirements
+            | Self::Checkpoints
+            | Self::Workspaces
+            | Self::Dependencies => {
+                // Nothing to do.
+            }
+        }
+        Ok(summary)
+    }
+
+    /// Remove any entries from the cache bucket that aren't included in the
+    /// [`ReferencedArtifacts`].
+    ///
+    /// Returns the number of entries removed from the cache.
+    fn retain(
+        self,
+        cache: &Cache,
+        referenced: &ReferencedArtifacts,
+        dry_run: bool,
+    ) -> Result<Removal, io::Error> {
+        /// Returns `true` if the [`Path`] represents a built wheel for a referenced package.
+        fn is_referenced(path: &Path, referenced: &ReferencedArtifacts) -> bool {
+            let Ok(metadata) = fs_err::read(path.join("metadata.msgpack")) else {
+                return false;
+            };
+            let Ok(metadata) = rmp_serde::from_slice::<ResolutionMetadata>(&metadata) else {
+                return false;
+            };
+            referenced.packages.contains(metadata.name.as_str())
+        }
+
+        /// Returns the file name of the [`Path`], if it's valid UTF-8.
+        fn file_name(path: &Path) -> Option<&str> {
+            path.file_name().and_then(|name| name.to_str())
+        }
+
+        let remove = |path: &Path| {
+            debug!("Removing unreferenced cache entry: {}", path.display());
+            if dry_run { measure(path) } else { rm_rf(path) }
+        };
+
+        // For `pypi`, we expect a directory per package (indexed by name). For alternate indices
+        // (under an `index` subdirectory), we expect a directory for every index, followed by a
+        // directory per package.
+        let registries = |bucket: Self| -> Result<Vec<PathBuf>, io::Error> {
+            let mut registries = vec![cache.bucket(bucket).join(WheelCacheKind::Pypi)];
+            registries.extend(directories(
+                cache.bucket(bucket).join(WheelCacheKind::Index),
+            )?);
+            Ok(registries)
+        };
+
+        let mut summary = Removal::default();
+        match self {
+            Self::Wheels => {
+                // For direct URLs, we expect a directory for every URL, followed by a directory
+                // per package (indexed by name).
+                let mut roots = registries(self)?;
+                roots.extend(directories(cache.bucket(self).join(WheelCacheKind::Url))?);
+
+                for root in roots {
+                    for package in directories(root)? {
+                        if !file_name(&package)
+                            .is_some_and(|name| referenced.packages.contains(name))
+                        {
+                            summary += remove(&package)?;
+                            continue;
+                        }
+
+                        // Each wheel is stored alongside its metadata, cache policy, and lock.
+                        for entry in entries(&package)? {
+                            if !referenced.contains_wheel(&entry) {
+                                summary += remove(&entry)?;
+                            }
+                        }
+                    }
+                }
+            }
+            Self::SourceDistributions => {
+                // For registries, each package directory contains a directory per version.
+                for root in registries(self)? {
+                    for package in directories(root)? {
+                        let Some(name) = file_name(&package) else {
+                            continue;
+                        };
+                        if !referenced.packages.contains(name) {
+                            summary += remove(&package)?;
+                            continue;
+                        }
+                        for version in directories(&package)? {
+                            let Some(version_str) = file_name(&version) else {
+                                continue;
+                            };
+                            if !referenced
+                                .source_distributions
+                                .contains(&(name.to_string(), version_str.to_string()))
+                            {
+                                summary += remove(&version)?;
+                            }
+                        }
+                    }
+                }
+
+                // For direct URLs and local dependencies, we expect a directory for every URL or
+                // path, followed by a directory per revision. To determine whether the URL is
+                // referenced, we need to search for a wheel matching a referenced package.
+                for kind in [WheelCacheKind::Url, WheelCacheKind::Path] {
+                    for url in directories(cache.bucket(self).join(kind))? {
+                        if !directories(&url)?.any(|revision| is_referenced(&revision, referenced))
+                        {
+                            summary += remove(&url)?;
+                        }
+                    }
+                }
+
+                // For Git dependencies, we expect a directory for every repository, followed by a
+                // directory for every SHA.
+                for repository in directories(cache.bucket(self).join(WheelCacheKind::Git))? {
+                    for sha in directories(&repository)? {
+                        if !is_referenced(&sha, referenced) {
+                            summary += remove(&sha)?;
+                        }
+                    }
+                }
+            }
+            Self::Simple => {
+                // We expect a rkyv file per package, indexed by name, alongside its lock.
+                for root in registries(self)? {
+                    for entry in entries(root)? {
+                        let Some(name) = file_name(&entry) else {
+                            continue;
+                        };
+                        let Some(package) = name
+                            .strip_suffix(".rkyv")
+                            .or_else(|| name.strip_suffix(".lock"))
+                        else {
+                            continue;
+                        };
+                        if !referenced.packages.contains(package) {
+                            summary += remove(&entry)?;
+                        }
+                    }
+                }
+            }
+            Self::FlatIndex
+            | Self::Git
+            | Self::Interpreter
+            | Self::Archive
+            | Self::Builds
+            | Self::Environments
+            | Self::Python
+            | Self::Binaries
+            | Self::Osv
+            | Self::Requirements
+            | Self::Checkpoints
+            | Self::Workspaces
+            | Self::Dependencies => {
                 // Nothing to do.
             }
         }
@@ -1345,6 +1944,10 @@ impl CacheBucket {
             Self::Python,
             Self::Binaries,
             Self::Osv,
+            Self::Requirements,
+            Self::Checkpoints,
+            Self::Workspaces,
+            Self::Dependencies,
         ]
         .iter()
         .copied()
@@ -1380,6 +1983,8 @@ pub enum Refresh {
     None(Timestamp),
     /// Refresh entries linked to the given packages, if created before the given timestamp.
     Packages(Vec<PackageName>, Vec<Box<Path>>, Timestamp),
+    /// Refresh entries within the given scopes, if created before the given timestamp.
+    Scoped(Box<RefreshScopes>, Timestamp),
     /// Refresh all entries created before the given timestamp.
     All(Timestamp),
 }
@@ -1401,6 +2006,16 @@ impl Refresh {
         }
     }
 
+    /// Determine the refresh strategy to use for the given [`RefreshScopes`].
+    pub fn from_scopes(scopes: RefreshScopes) -> Self {
+        let timestamp = Timestamp::now();
+        if scopes.is_empty() {
+            Self::None(timestamp)
+        } else {
+            Self::Scoped(Box::new(scopes), timestamp)
+        }
+    }
+
     /// Combine two [`Refresh`] policies, taking the "max" of the two policies.
     #[must_use]
     pub fn combine(self, other: Self) -> Self {
@@ -1412,11 +2027,13 @@ impl Refresh {
             (Self::None(t1), Self::Packages(packages, paths, t2)) => {
                 Self::Packages(packages, paths, t1.max(t2))
             }
+            (Self::None(t1), Self::Scoped(scopes, t2)) => Self::Scoped(scopes, t1.max(t2)),
 
             // If the policy is `All`, refresh all packages.
-            (Self::All(t1), Self::None(t2) | Self::All(t2) | Self::Packages(.., t2)) => {
-                Self::All(t1.max(t2))
-            }
+            (
+                Self::All(t1),
+                Self::None(t2) | Self::All(t2) | Self::Packages(.., t2) | Self::Scoped(.., t2),
+            ) => Self::All(t1.max(t2)),
 
             // If the policy is `Packages`, take the "max" of the two policies.
             (Self::Packages(packages, paths, t1), Self::None(t2)) => {
@@ -1430,6 +2047,67 @@ impl Refresh {
                     t1.max(t2),
                 )
             }
+            (Self::Packages(packages, paths, t1), Self::Scoped(scopes, t2))
+            | (Self::Scoped(scopes, t1), Self::Packages(packages, paths, t2)) => Self::Scoped(
+                Box::new(scopes.combine(RefreshScopes {
+                    packages,
+                    paths,
+                    ..RefreshScopes::default()
+                })),
+                t1.max(t2),
+            ),
+
+            // If the policy is `Scoped`, take the union of the two scopes.
+            (Self::Scoped(scopes, t1), Self::None(t2)) => Self::Scoped(scopes, t1.max(t2)),
+            (Self::Scoped(.., t1), Self::All(t2)) => Self::All(t1.max(t2)),
+            (Self::Scoped(scopes1, t1), Self::Scoped(scopes2, t2)) => {
+                Self::Scoped(Box::new(scopes1.combine(*scopes2)), t1.max(t2))
+            }
+        }
+    }
+}
+
+/// The cache entries covered by a [`Refresh::Scoped`] policy.
+#[derive(Debug, Clone, Default)]
+pub struct RefreshScopes {
+    /// Refresh all entries linked to the given packages.
+    pub packages: Vec<PackageName>,
+    /// Refresh all entries linked to the given source trees.
+    pub paths: Vec<Box<Path>>,
+    /// Refresh index metadata (i.e., Simple API and `--find-links` responses) for all indexes.
+    pub metadata: bool,
+    /// Refresh index metadata for the given indexes.
+    pub indexes: Vec<IndexUrl>,
+    /// Refresh cached artifacts (i.e., wheels, source distributions, and builds) for the given
+    /// packages, while retaining cached index metadata.
+    pub artifacts: Vec<PackageName>,
+}
+
+impl RefreshScopes {
+    /// Returns `true` if no entries are covered by the scopes.
+    fn is_empty(&self) -> bool {
+        self.packages.is_empty()
+            && self.paths.is_empty()
+            && !self.metadata
+            && self.indexes.is_empty()
+            && self.artifacts.is_empty()
+    }
+
+    /// Returns `true` if any index metadata is covered by the scopes, such that the set of
+    /// available versions may have changed.
+    pub fn includes_metadata(&self) -> bool {
+        self.metadata || !self.indexes.is_empty() || !self.packages.is_empty()
+    }
+
+    /// Combine two sets of scopes, taking their union.
+    #[must_use]
+    fn combine(self, other: Self) -> Self {
+        Self {
+            packages: self.packages.into_iter().chain(other.packages).collect(),
+            paths: self.paths.into_iter().chain(other.paths).collect(),
+            metadata: self.metadata || other.metadata,
+            indexes: self.indexes.into_iter().chain(other.indexes).collect(),
+            artifacts: self.artifacts.into_iter().chain(other.artifacts).collect(),
         }
     }
 }
@@ -1463,7 +2141,7 @@ mod tests {
     #[test]
     #[cfg(unix)]
     fn prune_does_not_follow_environment_symlinks() {
-        use super::{Cache, CacheBucket};
+        use super::{Cache, CacheBucket, PruneOptions};
 
         let cache_root = tempfile::tempdir().unwrap();
         let victim_root = tempfile::tempdir().unwrap();
@@ -1475,7 +2153,10 @@ mod tests {
         fs_err::write(victim_dir.join("payload.txt"), "payload").unwrap();
         fs_err::os::unix::fs::symlink(&victim_dir, environments.join("escape")).unwrap();
 
-        let summary = Cache::from_path(cache_root.path()).prune(false).unwrap();
+        let summary = Cache::from_path(cache_root.path())
+            .prune(PruneOptions::default())
+            .unwrap()
+            .removal;
 
         assert_eq!(summary.num_files, 1);
         assert_eq!(summary.num_dirs, 0);
@@ -1487,7 +2168,7 @@ mod tests {
     #[test]
     #[cfg(unix)]
     fn prune_ci_does_not_follow_wheel_symlinks() {
-        use super::{Cache, CacheBucket};
+        use super::{Cache, CacheBucket, PruneOptions};
 
         let cache_root = tempfile::tempdir().unwrap();
         let victim_root = tempfile::tempdir().unwrap();
@@ -1504,7 +2185,13 @@ mod tests {
         fs_err::write(victim_dir.join("payload.txt"), "payload").unwrap();
         fs_err::os::unix::fs::symlink(&victim_dir, &symlink).unwrap();
 
-        let summary = Cache::from_path(cache_root.path()).prune(true).unwrap();
+        let summary = Cache::from_path(cache_root.path())
+            .prune(PruneOptions {
+                ci: true,
+                ..PruneOptions::default()
+            })
+            .unwrap()
+            .removal;
 
         assert_eq!(summary.num_files, 1);
         assert_eq!(summary.num_dirs, 0);
@@ -1516,7 +2203,7 @@ mod tests {
     #[test]
     #[cfg(unix)]
     fn prune_does_not_follow_archive_symlinks() {
-        use super::{Cache, CacheBucket};
+        use super::{Cache, CacheBucket, PruneOptions};
 
         let cache_root = tempfile::tempdir().unwrap();
         let victim_root = tempfile::tempdir().unwrap();
@@ -1529,7 +2216,10 @@ mod tests {
         fs_err::write(victim_dir.join("payload.txt"), "payload").unwrap();
         fs_err::os::unix::fs::symlink(&victim_dir, &symlink).unwrap();
 
-        let summary = Cache::from_path(cache_root.path()).prune(false).unwrap();
+        let summary = Cache::from_path(cache_root.path())
+            .prune(PruneOptions::default())
+            .unwrap()
+            .removal;
 
         assert_eq!(summary.num_files, 1);
         assert_eq!(summary.num_dirs, 0);
@@ -1537,4 +2227,159 @@ mod tests {
         assert!(victim_dir.join("payload.txt").is_file());
         assert!(fs_err::symlink_metadata(symlink).is_err());
     }
+
+    #[test]
+    #[cfg(unix)]
+    fn prune_retains_recently_used_environments() {
+        use std::time::Duration;
+
+        use super::{ArchiveId, Cache, CacheBucket, PruneOptions};
+
+        let cache_root = tempfile::tempdir().unwrap();
+        let cache = Cache::from_path(cache_root.path());
+
+        // Create a cached environment, and a link whose target no longer exists.
+        let id = ArchiveId::new();
+        fs_err::create_dir_all(cache.archive(&id)).unwrap();
+        fs_err::write(cache.archive(&id).join("pyvenv.cfg"), "").unwrap();
+        let entry = cache.entry(CacheBucket::Environments, "interpreter", "environment");
+        fs_err::create_dir_all(entry.dir()).unwrap();
+        cache.create_link(&id, entry.path()).unwrap();
+        entry.touch().unwrap();
+        let dangling = entry.with_file("dangling");
+        cache
+            .create_link(&ArchiveId::new(), dangling.path())
+            .unwrap();
+
+        // A recently used environment should be retained, along with its archive.
+        let summary = cache
+            .prune(PruneOptions {
+                older_than: Some(Duration::from_hours(1)),
+                ..PruneOptions::default()
+            })
+            .unwrap();
+        assert_eq!(summary.environments.num_environments, 1);
+        assert_eq!(summary.environments.num_removed, 0);
+        assert!(cache.resolve_link(entry.path()).is_ok());
+        assert!(fs_err::symlink_metadata(dangling.path()).is_err());
+
+        // A dry run should report the environment, without removing it.
+        let summary = cache
+            .prune(PruneOptions {
+                older_than: Some(Duration::ZERO),
+                dry_run: true,
+                ..PruneOptions::default()
+            })
+            .unwrap();
+        assert_eq!(summary.environments.num_removed, 1);
+        assert!(summary.removal.num_files > 0);
+        assert!(cache.resolve_link(entry.path()).is_ok());
+
+        // Otherwise, an environment that wasn't used within the window should be removed.
+        let summary = cache
+            .prune(PruneOptions {
+                older_than: Some(Duration::ZERO),
+                ..PruneOptions::default()
+            })
+            .unwrap();
+        assert_eq!(summary.environments.num_removed, 1);
+        assert!(fs_err::symlink_metadata(entry.path()).is_err());
+        assert!(!cache.archive(&id).exists());
+        assert!(!entry.dir().exists());
+    }
+
+    #[test]
+    fn scoped_refresh_freshness() {
+        use std::str::FromStr;
+        use std::time::{Duration, SystemTime};
+
+        use uv_cache_info::Timestamp;
+        use uv_distribution_types::IndexUrl;
+        use uv_normalize::PackageName;
+
+        use super::{Cache, CacheBucket, Freshness, Refresh, RefreshScopes};
+
+        let cache_root = tempfile::tempdir().unwrap();
+        let cache = Cache::from_path(cache_root.path());
+        let entry = cache.entry(CacheBucket::Simple, "index", "foo.rkyv");
+        fs_err::create_dir_all(entry.dir()).unwrap();
+        fs_err::write(entry.path(), "").unwrap();
+
+        // Use a cutoff in the future, such that any existing entry is considered stale.
+        let timestamp = Timestamp::from(SystemTime::now() + Duration::from_mins(1));
+        let foo = PackageName::from_str("foo").unwrap();
+        let pypi = IndexUrl::from_str("https://pypi.org/simple").unwrap();
+        let other = IndexUrl::from_str("https://example.com/simple/").unwrap();
+
+        // Refreshing metadata should revalidate index responses, but not artifacts.
+        let cache = cache.with_refresh(Refresh::Scoped(
+            Box::new(RefreshScopes {
+                metadata: true,
+                ..RefreshScopes::default()
+            }),
+            timestamp,
+        ));
+        assert_eq!(
+            cache
+                .index_freshness(&entry, Some(&pypi), Some(&foo))
+                .unwrap(),
+            Freshness::Stale
+        );
+        assert_eq!(
+            cache.freshness(&entry, Some(&foo), None).unwrap(),
+            Freshness::Fresh
+        );
+        assert!(!cache.must_revalidate_package(&foo));
+
+        // Refreshing a single index should only revalidate responses from that index.
+        let cache = cache.with_refresh(Refresh::Scoped(
+            Box::new(RefreshScopes {
+                indexes: vec![IndexUrl::from_str("https://example.com/simple").unwrap()],
+                ..RefreshScopes::default()
+            }),
+            timestamp,
+        ));
+        assert_eq!(
+            cache.index_freshness(&entry, Some(&other), None).unwrap(),
+            Freshness::Stale
+        );
+        assert_eq!(
+            cache.index_freshness(&entry, Some(&pypi), None).unwrap(),
+            Freshness::Fresh
+        );
+
+        // Refreshing artifacts should revalidate artifacts, but not index responses.
+        let cache = cache.with_refresh(Refresh::Scoped(
+            Box::new(RefreshScopes {
+                artifacts: vec![foo.clone()],
+                ..RefreshScopes::default()
+            }),
+            timestamp,
+        ));
+        assert_eq!(
+            cache
+                .index_freshness(&entry, Some(&pypi), Some(&foo))
+                .unwrap(),
+            Freshness::Fresh
+        );
+        assert_eq!(
+            cache.freshness(&entry, Some(&foo), None).unwrap(),
+            Freshness::Stale
+        );
+        assert!(cache.must_revalidate_package(&foo));
+
+        // Combining with a full package refresh should cover both.
+        let refresh = Refresh::from_args(None, vec![foo.clone()]).combine(Refresh::Scoped(
+            Box::new(RefreshScopes {
+                metadata: true,
+                ..RefreshScopes::default()
+            }),
+            timestamp,
+        ));
+        let cache = cache.with_refresh(refresh);
+        assert_eq!(
+            cache.freshness(&entry, Some(&foo), None).unwrap(),
+            Freshness::Stale
+        );
+    }
 }
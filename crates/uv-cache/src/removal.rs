@@ -13,6 +13,40 @@ pub fn rm_rf(path: impl AsRef<Path>) -> io::Result<Removal> {
     Remover::default().rm_rf(path, false)
 }
 
+/// Compute the [`Removal`] that [`rm_rf`] would perform for a file or directory, without
+/// removing anything.
+///
+/// Symbolic links are counted as files, rather than followed.
+pub fn measure(path: impl AsRef<Path>) -> io::Result<Removal> {
+    let mut removal = Removal::default();
+    let path = uv_fs::verbatim_path(path.as_ref());
+
+    match fs_err::symlink_metadata(&path) {
+        Ok(metadata) if !metadata.is_dir() => {
+            removal.num_files += 1;
+            removal.total_bytes += metadata.len();
+            return Ok(removal);
+        }
+        Ok(_) => {}
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(removal),
+        Err(err) => return Err(err),
+    }
+
+    for entry in walkdir::WalkDir::new(&path) {
+        let entry = entry?;
+        if entry.file_type().is_dir() {
+            removal.num_dirs += 1;
+        } else {
+            removal.num_files += 1;
+            if let Ok(metadata) = entry.metadata() {
+                removal.total_bytes += metadata.len();
+            }
+        }
+    }
+
+    Ok(removal)
+}
+
 /// A builder for a [`Remover`] that can remove files and directories.
 #[derive(Default)]
 pub(crate) struct Remover {
@@ -1,7 +1,8 @@
 use std::str::FromStr;
 
-/// A comma-separated string of requirements, e.g., `"flask,anyio"`, that takes extras into account
-/// (i.e., treats `"psycopg[binary,pool]"` as a single requirement).
+/// A comma-separated string of requirements, e.g., `"flask,anyio"`, that takes extras and markers
+/// into account (i.e., treats `"psycopg[binary,pool]"` as a single requirement, and ignores commas
+/// within quoted marker values).
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct CommaSeparatedRequirements(Vec<String>);
 
@@ -18,12 +19,22 @@ impl FromStr for CommaSeparatedRequirements {
     type Err = String;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        // Split on commas _outside_ of brackets.
+        // Split on commas _outside_ of brackets and quotes.
         let mut requirements = Vec::new();
         let mut depth = 0usize;
         let mut start = 0usize;
+        let mut quote = None;
         for (i, c) in input.char_indices() {
+            if let Some(open) = quote {
+                if c == open {
+                    quote = None;
+                }
+                continue;
+            }
             match c {
+                '\'' | '"' => {
+                    quote = Some(c);
+                }
                 '[' => {
                     depth = depth.saturating_add(1);
                 }
@@ -122,4 +133,32 @@ mod tests {
             CommaSeparatedRequirements(vec!["requests>=2.1,<3".to_string(), "flask".to_string()])
         );
     }
+
+    #[test]
+    fn markers() {
+        assert_eq!(
+            CommaSeparatedRequirements::from_str(
+                "pywin32; sys_platform == 'win32', colorama ; os_name == \"nt\""
+            )
+            .unwrap(),
+            CommaSeparatedRequirements(vec![
+                "pywin32; sys_platform == 'win32'".to_string(),
+                "colorama ; os_name == \"nt\"".to_string()
+            ])
+        );
+    }
+
+    #[test]
+    fn quoted_commas() {
+        assert_eq!(
+            CommaSeparatedRequirements::from_str(
+                "tomli; python_version in '3.9,3.10', flask[async]>=2,<4"
+            )
+            .unwrap(),
+            CommaSeparatedRequirements(vec![
+                "tomli; python_version in '3.9,3.10'".to_string(),
+                "flask[async]>=2,<4".to_string()
+            ])
+        );
+    }
 }
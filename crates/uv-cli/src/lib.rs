@@ -3,6 +3,7 @@ use std::fmt::{self, Display, Formatter};
 use std::ops::{Deref, DerefMut};
 use std::path::PathBuf;
 use std::str::FromStr;
+use std::time::Duration;
 
 use anyhow::{Result, anyhow};
 use clap::builder::styling::{AnsiColor, Effects, Style};
@@ -15,8 +16,9 @@ use uv_audit::VulnerabilityServiceFormat;
 use uv_auth::Service;
 use uv_cache::CacheArgs;
 use uv_configuration::{
-    ExportFormat, IndexStrategy, KeyringProviderType, PackageNameSpecifier, PipCompileFormat,
-    ProjectBuildBackend, TargetTriple, TrustedHost, TrustedPublishing, VersionControlSystem,
+    CiProvider, ExportFormat, IndexStrategy, KeyringProviderType, PackageNameSpecifier,
+    PipCompileFormat, ProjectBuildBackend, TargetTriple, TrustedHost, TrustedPublishing,
+    VersionControlSystem,
 };
 use uv_distribution_types::{
     ConfigSettingEntry, ConfigSettingPackageEntry, Index, IndexUrl, Origin, PipExtraIndex,
@@ -68,6 +70,24 @@ pub enum SyncFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum ConfigLintFormat {
+    /// Display the diagnostics in a human-readable format.
+    #[default]
+    Text,
+    /// Display the diagnostics in JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum DryRunFormat {
+    /// Display the planned changes in a human-readable format.
+    #[default]
+    Text,
+    /// Display the planned changes in JSON format.
+    Json,
+}
+
 #[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
 pub enum AuditOutputFormat {
     /// Display the result in a human-readable format.
@@ -79,6 +99,15 @@ pub enum AuditOutputFormat {
     Sarif,
 }
 
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum ShowFormat {
+    /// Display the package information in a `pip show`-like format.
+    #[default]
+    Text,
+    /// Display the package information in a machine-readable JSON format.
+    Json,
+}
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
 pub enum TreeFormat {
     /// Display the dependency graph as a human-readable tree.
@@ -313,6 +342,21 @@ pub struct GlobalArgs {
     )]
     pub allow_insecure_host: Option<Vec<Maybe<TrustedHost>>>,
 
+    /// Error when a package is available on multiple indexes that don't declare each other as
+    /// alternate locations.
+    ///
+    /// When combining distributions across indexes (i.e., with `--index-strategy
+    /// unsafe-best-match`), uv verifies that each pair of indexes serving a package is related per
+    /// PEP 708: either index tracks the other, or both declare each other as alternate locations.
+    /// By default, the verification only applies to
+    /// packages for which at least one index publishes PEP 708 metadata, and uv warns when it
+    /// fails, as it may indicate a dependency confusion attack.
+    ///
+    /// With `--strict-index-tracking`, the verification applies to all packages, and failures
+    /// are treated as errors.
+    #[arg(global = true, long)]
+    pub strict_index_tracking: bool,
+
     /// Whether to enable all experimental preview features [env: UV_PREVIEW=]
     ///
     /// Preview features may change without warning.
@@ -361,6 +405,21 @@ pub struct GlobalArgs {
     #[arg(global = true, long, value_parser = clap::builder::BoolishValueParser::new())]
     pub no_progress: bool,
 
+    /// Print a breakdown of the time spent in each phase of the command on completion.
+    ///
+    /// Use `--timings=json` to print the breakdown as JSON, e.g., to aggregate timings across
+    /// runs. Timings are only reported locally.
+    #[arg(
+        global = true,
+        long,
+        value_enum,
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "text",
+        value_name = "FORMAT"
+    )]
+    pub timings: Option<TimingsFormat>,
+
     /// Skip writing `uv` installer metadata files (e.g., `INSTALLER`, `REQUESTED`, and
     /// `direct_url.json`) to site-packages `.dist-info` directories [env: UV_NO_INSTALLER_METADATA=]
     #[arg(global = true, long, hide = true, value_parser = clap::builder::BoolishValueParser::new())]
@@ -390,6 +449,14 @@ pub struct GlobalArgs {
     pub project: Option<PathBuf>,
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq, clap::ValueEnum)]
+pub enum TimingsFormat {
+    /// Display the timings as a table.
+    Text,
+    /// Display the timings as JSON.
+    Json,
+}
+
 #[derive(Debug, Copy, Clone, clap::ValueEnum)]
 pub enum ColorChoice {
     /// Enables colored output only when the output is going to a terminal or TTY with support.
@@ -554,6 +621,12 @@ pub enum Commands {
         after_long_help = ""
     )]
     Workspace(WorkspaceNamespace),
+    /// Inspect uv's configuration.
+    #[command(
+        after_help = "Use `uv help config` for more details.",
+        after_long_help = ""
+    )]
+    Config(ConfigNamespace),
     /// The implementation of the build backend.
     ///
     /// These commands are not directly exposed to the user, instead users invoke their build
@@ -627,6 +700,17 @@ pub struct VersionArgs {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Display a unified diff of the `pyproject.toml` (or version file) that will be modified
+    #[arg(long)]
+    pub show_diff: bool,
+
+    /// Display a unified diff of the `pyproject.toml` (or version file) that would be modified,
+    /// without writing any changes
+    ///
+    /// Unlike `--dry-run`, the diff shows the exact content that would be written.
+    #[arg(long, conflicts_with_all = ["dry_run", "show_diff"])]
+    pub diff_only: bool,
+
     /// Only show the version
     ///
     /// By default, uv will show the project name before the version.
@@ -851,6 +935,39 @@ impl TypedValueParser for VersionBumpSpecValueParser {
     }
 }
 
+/// A lockfile to install for `uv run --with-lock`, given as `<path>[:<name>]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WithLock {
+    /// The path to the lockfile.
+    pub path: PathBuf,
+    /// The dependency group or extra to include, if any.
+    pub name: Option<GroupName>,
+}
+
+impl FromStr for WithLock {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        // `:` isn't valid in a group or extra name, but it can appear in a path (e.g., a Windows
+        // drive letter). Therefore we split on the last `:`, unless the input is itself a lockfile.
+        #[expect(clippy::case_sensitive_file_extension_comparisons)]
+        if !input.ends_with(".lock")
+            && let Some((path, name)) = input.rsplit_once(':')
+            && !path.is_empty()
+        {
+            let name = GroupName::from_str(name).map_err(|err| err.to_string())?;
+            return Ok(Self {
+                path: PathBuf::from(path),
+                name: Some(name),
+            });
+        }
+        Ok(Self {
+            path: PathBuf::from(input),
+            name: None,
+        })
+    }
+}
+
 #[derive(Args)]
 pub struct SelfNamespace {
     #[command(subcommand)]
@@ -887,6 +1004,39 @@ pub struct SelfUpdateArgs {
     pub dry_run: bool,
 }
 
+#[derive(Args)]
+pub struct ConfigNamespace {
+    #[command(subcommand)]
+    pub command: ConfigCommand,
+}
+
+#[derive(Subcommand)]
+pub enum ConfigCommand {
+    /// Lint the configuration that applies to the current directory.
+    ///
+    /// Reads every configuration source that would apply to a command run in the current
+    /// directory (i.e., the nearest `uv.toml` or `pyproject.toml` with a `[tool.uv]` table, the
+    /// user-level and system-level `uv.toml` files, and uv's environment variables), or the file
+    /// provided via `--config-file`, and reports:
+    ///
+    /// - Unknown settings, along with the closest known setting.
+    /// - Deprecated settings and environment variables, along with their replacements.
+    /// - Settings that aren't respected in the file in which they're defined.
+    /// - Values that are overridden by a source with higher precedence.
+    /// - Values that are accepted, but likely mistakes (e.g., a relative `cache-dir`, or an index
+    ///   without a `name`).
+    ///
+    /// Exits with a non-zero status if any issues are found.
+    Lint(ConfigLintArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct ConfigLintArgs {
+    /// Select the output format.
+    #[arg(long, value_enum, default_value_t = ConfigLintFormat::default())]
+    pub output_format: ConfigLintFormat,
+}
+
 #[derive(Args)]
 pub struct CacheNamespace {
     #[command(subcommand)]
@@ -960,6 +1110,45 @@ pub struct PruneArgs {
     /// `--force` is used, `uv cache prune` will proceed without taking a lock.
     #[arg(long)]
     pub force: bool,
+
+    /// Retain cached environments that were used within the given duration (e.g., `30d`).
+    ///
+    /// By default, `uv cache prune` removes all cached environments, which are recreated when
+    /// next needed. When `--older-than` is provided, the temporary environments cached by
+    /// `uv run --with` and `uvx` are only removed if they haven't been used within the given
+    /// duration, or if they're no longer valid. Other cached environments, like those for scripts,
+    /// are removed regardless.
+    ///
+    /// Accepts a number followed by a unit of `s` (seconds), `m` (minutes), `h` (hours), `d`
+    /// (days), or `w` (weeks).
+    #[arg(long, value_parser = parse_duration)]
+    pub older_than: Option<Duration>,
+
+    /// Retain only the artifacts referenced by the given lockfile.
+    ///
+    /// By default, `uv cache prune` retains all cached wheels, source distributions, and index
+    /// metadata that are still valid. When `--keep-lockfile` is provided, any such entries for
+    /// packages and versions that aren't referenced by the lockfile are removed, e.g., to trim
+    /// the cache to what a project needs before persisting it in a continuous integration
+    /// environment.
+    ///
+    /// May be provided multiple times, in which case the artifacts referenced by any of the
+    /// lockfiles are retained.
+    #[arg(long, value_name = "LOCKFILE", value_hint = ValueHint::FilePath)]
+    pub keep_lockfile: Vec<PathBuf>,
+
+    /// The platforms for which to retain wheels, when used with `--keep-lockfile`.
+    ///
+    /// Represented as a "target triple", like `x86_64-unknown-linux-gnu` or
+    /// `aarch64-apple-darwin`. May be provided multiple times.
+    ///
+    /// By default, wheels referenced by the lockfile are retained for all platforms.
+    #[arg(long, requires = "keep_lockfile")]
+    pub python_platform: Vec<TargetTriple>,
+
+    /// Report what would be removed, without removing anything.
+    #[arg(long)]
+    pub dry_run: bool,
 }
 
 #[derive(Args, Debug)]
@@ -967,6 +1156,14 @@ pub struct SizeArgs {
     /// Display the cache size in human-readable format (e.g., `1.2 GiB` instead of raw bytes).
     #[arg(long = "human", short = 'H', alias = "human-readable")]
     pub human: bool,
+
+    /// Display how much space is saved by deduplicating files across the cache.
+    ///
+    /// Files that are hard linked into multiple cache entries (e.g., distributions shared by
+    /// cached environments) are only stored once. Reports the combined size of all files, the size
+    /// they occupy once deduplicated, and the resulting ratio.
+    #[arg(long)]
+    pub dedup: bool,
 }
 
 #[derive(Args)]
@@ -1397,6 +1594,35 @@ fn parse_maybe_string(input: &str) -> Result<Maybe<String>, String> {
     }
 }
 
+/// Parse a duration consisting of a number and a unit (e.g., `30d`), where the unit is one of `s`
+/// (seconds), `m` (minutes), `h` (hours), `d` (days), or `w` (weeks).
+fn parse_duration(input: &str) -> Result<Duration, String> {
+    let input = input.trim();
+    let split = input
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(input.len());
+    let (value, unit) = input.split_at(split);
+    let value = value
+        .parse::<u64>()
+        .map_err(|_| format!("expected a duration like `30d`, found `{input}`"))?;
+    let seconds = match unit.trim() {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        "w" => 7 * 24 * 60 * 60,
+        _ => {
+            return Err(format!(
+                "expected a duration with a unit of `s`, `m`, `h`, `d`, or `w` (e.g., `30d`), found `{input}`"
+            ));
+        }
+    };
+    value
+        .checked_mul(seconds)
+        .map(Duration::from_secs)
+        .ok_or_else(|| format!("duration is too large: `{input}`"))
+}
+
 #[derive(Args)]
 #[command(group = clap::ArgGroup::new("sources").required(true).multiple(true))]
 pub struct PipCompileArgs {
@@ -1587,6 +1813,43 @@ pub struct PipCompileArgs {
     #[arg(long, env = EnvVars::UV_CUSTOM_COMPILE_COMMAND, value_hint = ValueHint::Other)]
     pub custom_compile_command: Option<String>,
 
+    /// Format the comment header at the top of the output file like pip-compile's header.
+    ///
+    /// The header lists the equivalent pip-compile command, e.g., `pip-compile requirements.in
+    /// --output-file=requirements.txt`, unless `--custom-compile-command` is provided.
+    ///
+    /// Intended for tooling that parses or diffs the header of files generated by pip-compile.
+    #[arg(
+        long,
+        overrides_with("no_pip_tools_header"),
+        conflicts_with("no_header")
+    )]
+    pub pip_tools_header: bool,
+
+    #[arg(long, overrides_with("pip_tools_header"), hide = true)]
+    pub no_pip_tools_header: bool,
+
+    /// Include a digest of the resolution inputs in the header of the output file.
+    ///
+    /// The digest covers the contents of the requirement, constraint, override, and `pyproject.toml`
+    /// files, the target Python version and platform, and the resolver settings, and is written as
+    /// a `# uv: inputs-sha256=...` comment. Use `--check-fresh` to determine whether an existing
+    /// output file is up-to-date without resolving.
+    #[arg(long, overrides_with("no_emit_inputs_hash"))]
+    pub emit_inputs_hash: bool,
+
+    #[arg(long, overrides_with("emit_inputs_hash"), hide = true)]
+    pub no_emit_inputs_hash: bool,
+
+    /// Check whether the given output file was compiled from the current inputs, without
+    /// resolving.
+    ///
+    /// Compares the `# uv: inputs-sha256=...` digest recorded by `--emit-inputs-hash` against a
+    /// digest of the current inputs. Exits with status 0 if the digests match, and 1 if they
+    /// differ or if the file doesn't contain a digest.
+    #[arg(long, value_hint = ValueHint::FilePath)]
+    pub check_fresh: Option<PathBuf>,
+
     /// The Python interpreter to use during resolution.
     ///
     /// A Python interpreter is required for building source distributions to determine package
@@ -1731,10 +1994,33 @@ pub struct PipCompileArgs {
     pub no_universal: bool,
 
     /// Specify a package to omit from the output resolution. Its dependencies will still be
-    /// included in the resolution. Equivalent to pip-compile's `--unsafe-package` option.
-    #[arg(long, alias = "unsafe-package", value_delimiter = ',', value_hint = ValueHint::Other)]
+    /// included in the resolution.
+    #[arg(long, value_delimiter = ',', value_hint = ValueHint::Other)]
     pub no_emit_package: Option<Vec<PackageName>>,
 
+    /// Specify a package to treat as unsafe, as in pip-compile's `--unsafe-package` option.
+    ///
+    /// Unsafe packages are omitted from the output resolution and listed as comments at the end of
+    /// the output file, unless `--allow-unsafe` is provided, in which case they're pinned at the end
+    /// of the output file. Their dependencies will still be included in the resolution.
+    ///
+    /// If `--allow-unsafe` or `--no-allow-unsafe` is provided without `--unsafe-package`,
+    /// pip-compile's default unsafe packages (`distribute`, `pip`, and `setuptools`) are used.
+    #[arg(long, value_delimiter = ',', value_hint = ValueHint::Other)]
+    pub unsafe_package: Option<Vec<PackageName>>,
+
+    /// Pin unsafe packages at the end of the output file, rather than listing them as comments.
+    ///
+    /// Equivalent to pip-compile's `--allow-unsafe` option.
+    #[arg(long, overrides_with("no_allow_unsafe"))]
+    pub allow_unsafe: bool,
+
+    /// List unsafe packages as comments at the end of the output file, rather than pinning them.
+    ///
+    /// Equivalent to pip-compile's `--no-allow-unsafe` option.
+    #[arg(long, overrides_with("allow_unsafe"))]
+    pub no_allow_unsafe: bool,
+
     /// Include `--index-url` and `--extra-index-url` entries in the generated output file.
     #[arg(long, overrides_with("no_emit_index_url"))]
     pub emit_index_url: bool,
@@ -1844,6 +2130,20 @@ pub struct PipSyncArgs {
     )]
     pub build_constraints: Vec<Maybe<PathBuf>>,
 
+    /// Omit the given package(s) from the installation, even if they're required by other packages.
+    ///
+    /// Excluded packages are still resolved, such that the remaining packages satisfy their
+    /// constraints, but are neither installed nor removed: they're assumed to be provided by the
+    /// environment. uv will error if the environment doesn't already contain a version that
+    /// satisfies the requirements of its dependents, unless `--exclude-unchecked` is provided.
+    #[arg(long, value_hint = ValueHint::Other)]
+    pub exclude_package: Vec<PackageName>,
+
+    /// Skip verifying that the environment provides a compatible version of each package omitted
+    /// via `--exclude-package`.
+    #[arg(long, requires = "exclude_package")]
+    pub exclude_unchecked: bool,
+
     /// Include optional dependencies from the specified extra name; may be provided more than once.
     ///
     /// Only applies to `pylock.toml`, `pyproject.toml`, `setup.py`, and `setup.cfg` sources.
@@ -2092,6 +2392,13 @@ pub struct PipSyncArgs {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// The format in which to display the plan for a dry run.
+    ///
+    /// With `json`, the planned installs, upgrades, downgrades, and removals are written to stdout,
+    /// along with the number of packages that would be left unchanged.
+    #[arg(long, value_enum, default_value_t = DryRunFormat::default(), requires = "dry_run")]
+    pub format: DryRunFormat,
+
     /// The backend to use when fetching packages in the PyTorch ecosystem (e.g., `cpu`, `cu126`, or `auto`).
     ///
     /// When set, uv will ignore the configured index URLs for packages in the PyTorch ecosystem,
@@ -2205,6 +2512,23 @@ pub struct PipInstallArgs {
     )]
     pub excludes: Vec<Maybe<PathBuf>>,
 
+    /// Omit the given package(s) from the installation, even if they're required by other packages.
+    ///
+    /// Excluded packages are still resolved, such that the remaining packages satisfy their
+    /// constraints, but are neither installed nor removed: they're assumed to be provided by the
+    /// environment. uv will error if the environment doesn't already contain a version that
+    /// satisfies the requirements of its dependents, unless `--exclude-unchecked` is provided.
+    ///
+    /// Unlike `--excludes`, which omits packages (and their dependencies) from the resolution,
+    /// `--exclude-package` only filters the set of packages to install.
+    #[arg(long, value_hint = ValueHint::Other)]
+    pub exclude_package: Vec<PackageName>,
+
+    /// Skip verifying that the environment provides a compatible version of each package omitted
+    /// via `--exclude-package`.
+    #[arg(long, requires = "exclude_package")]
+    pub exclude_unchecked: bool,
+
     /// Constrain build dependencies using the given requirements files when building source
     /// distributions.
     ///
@@ -2483,6 +2807,24 @@ pub struct PipInstallArgs {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// The format in which to display the plan for a dry run.
+    ///
+    /// With `json`, the planned installs, upgrades, downgrades, and removals are written to stdout,
+    /// along with the number of packages that would be left unchanged.
+    #[arg(long, value_enum, default_value_t = DryRunFormat::default(), requires = "dry_run")]
+    pub format: DryRunFormat,
+
+    /// Explain which artifact (wheel or source distribution) is selected for a package.
+    ///
+    /// For each named package, uv lists the candidate artifacts for the resolved version along
+    /// with the platform and interpreter tags of the target environment, and reports which
+    /// artifact was selected and why each of the others was rejected (e.g., an incompatible
+    /// platform tag, the hash-checking policy, `--no-binary`, a yank, or `requires-python`).
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_name = "PACKAGE", value_hint = ValueHint::Other)]
+    pub explain: Vec<PackageName>,
+
     /// The backend to use when fetching packages in the PyTorch ecosystem (e.g., `cpu`, `cu126`, or `auto`)
     ///
     /// When set, uv will ignore the configured index URLs for packages in the PyTorch ecosystem,
@@ -2827,6 +3169,10 @@ pub struct PipShowArgs {
     #[arg(short, long)]
     pub files: bool,
 
+    /// Select the output format.
+    #[arg(long, value_enum, default_value_t = ShowFormat::default())]
+    pub format: ShowFormat,
+
     /// The Python interpreter to find the package in.
     ///
     /// By default, uv looks for packages in a virtual environment but will look for packages in a
@@ -3017,6 +3363,17 @@ pub struct BuildArgs {
     #[arg(long)]
     pub clear: bool,
 
+    /// Warn about dependencies on other workspace members that lack a version specifier.
+    ///
+    /// Within a workspace, dependencies on other members (with `workspace = true` in
+    /// `tool.uv.sources`) are satisfied by the local member, but the built distributions only
+    /// contain the requirement as declared in `project.dependencies`. Without a version
+    /// specifier, the published package will accept any version of the sibling member.
+    ///
+    /// Dependencies that are pinned via `tool.uv.workspace-pin-policy` are not reported.
+    #[arg(long)]
+    pub check_bounds: bool,
+
     #[arg(long, overrides_with("no_create_gitignore"), hide = true)]
     pub create_gitignore: bool,
 
@@ -3234,9 +3591,48 @@ pub struct VenvArgs {
     /// pip install`. The `--system-site-packages` flag will provide the virtual environment with
     /// access to the system site packages directory at runtime, but will not affect the behavior of
     /// uv commands.
-    #[arg(long)]
+    #[arg(long, overrides_with("no_system_site_packages"))]
     pub system_site_packages: bool,
 
+    /// Remove the virtual environment's access to the system site packages directory.
+    ///
+    /// Only applicable to `--existing`.
+    #[arg(long, overrides_with("system_site_packages"), requires = "existing")]
+    pub no_system_site_packages: bool,
+
+    /// Update the configuration of an existing virtual environment, rather than creating one.
+    ///
+    /// Use with `--system-site-packages` or `--no-system-site-packages` to toggle access to the
+    /// system site packages directory, without recreating the environment or reinstalling its
+    /// packages.
+    ///
+    /// Defaults to the project environment, or `.venv` in the working directory.
+    #[arg(
+        long,
+        conflicts_with_all = ["seed", "clear", "allow_existing", "prompt", "relocatable", "sync", "from_lock"]
+    )]
+    pub existing: bool,
+
+    /// Move an existing virtual environment to the given path, rather than creating one.
+    ///
+    /// The environment is renamed if possible, or otherwise copied to the new location before the
+    /// original is removed. References to the previous location (e.g., in `pyvenv.cfg`, script
+    /// shebangs and launchers, activation scripts, and `.pth` files) are rewritten.
+    ///
+    /// When moving the project environment, the new location is recorded in the
+    /// `tool.uv.project-environment` setting of the workspace `pyproject.toml`, such that
+    /// subsequent project commands use it.
+    ///
+    /// The environment to move defaults to the project environment, or `.venv` in the working
+    /// directory.
+    #[arg(
+        long = "move",
+        value_name = "NEW_PATH",
+        value_hint = ValueHint::DirPath,
+        conflicts_with_all = ["seed", "clear", "allow_existing", "prompt", "relocatable", "sync", "from_lock", "existing", "system_site_packages", "no_system_site_packages"]
+    )]
+    pub move_to: Option<PathBuf>,
+
     /// Make the virtual environment relocatable [env: UV_VENV_RELOCATABLE=]
     ///
     /// A relocatable virtual environment can be moved around and redistributed without invalidating
@@ -3261,6 +3657,25 @@ pub struct VenvArgs {
     #[arg(long, overrides_with("relocatable"), hide = true)]
     pub no_relocatable: bool,
 
+    /// Sync the project's locked dependencies into the virtual environment after creating it.
+    ///
+    /// Equivalent to running `uv venv` followed by `uv sync`, but the environment is removed if
+    /// installation fails, rather than being left behind without the project's dependencies. An
+    /// existing environment reused via `--allow-existing` is left in place.
+    ///
+    /// Requires a project, and creates the environment at the project's default location.
+    #[arg(long, conflicts_with_all = ["no_project", "path", "from_lock"])]
+    pub sync: bool,
+
+    /// Install the packages from a `pylock.toml` file into the virtual environment after creating
+    /// it.
+    ///
+    /// Equivalent to running `uv venv` followed by `uv pip sync pylock.toml`, but the environment
+    /// is removed if installation fails, rather than being left behind half-populated. An existing
+    /// environment reused via `--allow-existing` is left in place.
+    #[arg(long, value_hint = ValueHint::FilePath)]
+    pub from_lock: Option<PathBuf>,
+
     #[command(flatten)]
     pub index_args: IndexArgs,
 
@@ -3320,7 +3735,8 @@ pub struct VenvArgs {
 
     /// The method to use when installing packages from the global cache.
     ///
-    /// This option is only used for installing seed packages.
+    /// This option is only used for installing seed packages, and packages requested via `--sync`
+    /// or `--from-lock`.
     ///
     /// Defaults to `clone` (also known as Copy-on-Write) on macOS and Linux, and `hardlink` on
     /// Windows.
@@ -3485,6 +3901,15 @@ pub struct InitArgs {
     #[arg(long, value_enum, conflicts_with = "script")]
     pub vcs: Option<VersionControlSystem>,
 
+    /// Generate a continuous integration workflow for the project.
+    ///
+    /// The workflow installs uv, verifies that the lockfile is up-to-date, and runs the project's
+    /// tests across the oldest and newest Python versions supported by the project.
+    ///
+    /// Existing workflow files are never overwritten. Defaults to `none`.
+    #[arg(long, value_enum, conflicts_with = "script")]
+    pub ci: Option<CiProvider>,
+
     /// Initialize a build-backend of choice for the project.
     ///
     /// Implicitly sets `--package`.
@@ -3719,6 +4144,23 @@ pub struct RunArgs {
     #[arg(long, value_delimiter = ',', value_parser = parse_maybe_file_path, value_hint = ValueHint::FilePath)]
     pub with_requirements: Vec<Maybe<PathBuf>>,
 
+    /// Run with the packages locked in the given `uv.lock` file.
+    ///
+    /// Accepts a path to a lockfile, optionally followed by `:<name>` to include a dependency group
+    /// or extra defined by the locked project (e.g., `../tools/uv.lock:lint`). Without a name, only
+    /// the base dependencies of the locked project are installed. The locked workspace members
+    /// themselves are not installed.
+    ///
+    /// The locked packages are selected for the current interpreter, and installed into a cached
+    /// environment that's layered on top of the project environment, as with `--with`.
+    #[arg(
+        long,
+        value_parser = WithLock::from_str,
+        value_hint = ValueHint::FilePath,
+        conflicts_with_all = ["with", "with_editable", "with_requirements"]
+    )]
+    pub with_lock: Option<WithLock>,
+
     /// Run the command in an isolated virtual environment [env: UV_ISOLATED=]
     ///
     /// Usually, the project environment is reused for performance. This option forces a fresh
@@ -3745,6 +4187,14 @@ pub struct RunArgs {
     #[arg(long, overrides_with = "active", hide = true)]
     pub no_active: bool,
 
+    /// Allow `--active` to target an active conda environment.
+    ///
+    /// Conda environments are treated like system Python environments: unless this flag is
+    /// provided, uv will not install packages into an active conda environment (as identified by
+    /// `CONDA_PREFIX`) for project commands, even with `--active`.
+    #[arg(long, requires = "active")]
+    pub allow_conda: bool,
+
     /// Avoid syncing the virtual environment [env: UV_NO_SYNC=]
     ///
     /// Implies `--frozen`, as the project dependencies will be ignored (i.e., the lockfile will not
@@ -3782,6 +4232,31 @@ pub struct RunArgs {
     #[arg(long, conflicts_with_all = ["script", "module"])]
     pub gui_script: bool,
 
+    /// Ignore the scripts declared in `tool.uv.scripts`.
+    ///
+    /// By default, if the command matches the name of a script declared in `tool.uv.scripts`, the
+    /// script is run in lieu of any executable with the same name.
+    #[arg(long)]
+    pub no_project_scripts: bool,
+
+    /// Do not run the commands declared in `tool.uv.post-sync` after syncing the environment
+    /// [env: UV_NO_POST_SYNC=]
+    #[arg(long, value_parser = clap::builder::BoolishValueParser::new())]
+    pub no_post_sync: bool,
+
+    /// Show the environment that the command would run in, instead of running it.
+    ///
+    /// The environment is prepared as usual, i.e., the project is locked and synced and any
+    /// `--with` requirements are installed, then a JSON document describing the environment is
+    /// written to stdout. The document includes the Python executable, the `sys.prefix` of the
+    /// environment, the environments it extends, the `site-packages` directories in import order,
+    /// and the scripts directory.
+    ///
+    /// When a command is provided, it is not executed, but is used to determine the environment,
+    /// e.g., to read the inline metadata of a script.
+    #[arg(long)]
+    pub show_environment: bool,
+
     #[command(flatten)]
     pub installer: ResolverInstallerArgs,
 
@@ -4022,6 +4497,14 @@ pub struct SyncArgs {
     #[arg(long, overrides_with = "active", hide = true)]
     pub no_active: bool,
 
+    /// Allow `--active` to target an active conda environment.
+    ///
+    /// Conda environments are treated like system Python environments: unless this flag is
+    /// provided, uv will not install packages into an active conda environment (as identified by
+    /// `CONDA_PREFIX`) for project commands, even with `--active`.
+    #[arg(long, requires = "active")]
+    pub allow_conda: bool,
+
     /// Do not install the current project [env: UV_NO_INSTALL_PROJECT=]
     ///
     /// By default, the current project is installed into the environment with all of its
@@ -4086,6 +4569,21 @@ pub struct SyncArgs {
     #[arg(long, conflicts_with = "no_install_package", hide = true, value_hint = ValueHint::Other)]
     pub only_install_package: Vec<PackageName>,
 
+    /// Omit the given package(s) from the installation, assuming they're provided by the
+    /// environment.
+    ///
+    /// Unlike `--no-install-package`, the excluded packages are neither installed nor removed,
+    /// and uv will error if the environment doesn't already contain a version that satisfies the
+    /// requirements of its dependents, unless `--exclude-unchecked` is provided. The lockfile is
+    /// unaffected.
+    #[arg(long, value_hint = ValueHint::Other)]
+    pub exclude_package: Vec<PackageName>,
+
+    /// Skip verifying that the environment provides a compatible version of each package omitted
+    /// via `--exclude-package`.
+    #[arg(long, requires = "exclude_package")]
+    pub exclude_unchecked: bool,
+
     /// Assert that the `uv.lock` will remain unchanged [env: UV_LOCKED=]
     ///
     /// Requires that the lockfile is up-to-date. If the lockfile is missing or needs to be updated,
@@ -4102,6 +4600,18 @@ pub struct SyncArgs {
     #[arg(long, conflicts_with_all = ["locked", "upgrade", "no_sources"])]
     pub frozen: bool,
 
+    /// Install exclusively from a bundle created with `uv export --bundle`.
+    ///
+    /// The bundle's lockfile is used as the source of truth in lieu of `uv.lock`, and every
+    /// package is installed from the artifacts contained in the bundle, with hash verification
+    /// and without network access. The project environment is created if it doesn't exist.
+    #[arg(
+        long,
+        value_hint = ValueHint::FilePath,
+        conflicts_with_all = ["locked", "frozen", "upgrade", "script", "dry_run"],
+    )]
+    pub from_bundle: Option<PathBuf>,
+
     /// Perform a dry run, without writing the lockfile or modifying the project environment.
     ///
     /// In dry-run mode, uv will resolve the project's dependencies and report on the resulting
@@ -4109,6 +4619,17 @@ pub struct SyncArgs {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Explain which artifact (wheel or source distribution) is selected for a package.
+    ///
+    /// For each named package, uv lists the candidate artifacts in the lockfile along with the
+    /// platform and interpreter tags of the project environment, and reports which artifact was
+    /// selected and why each of the others was rejected (e.g., an incompatible platform tag,
+    /// `--no-binary`, or `--no-build`).
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_name = "PACKAGE", value_hint = ValueHint::Other)]
+    pub explain: Vec<PackageName>,
+
     #[command(flatten)]
     pub installer: ResolverInstallerArgs,
 
@@ -4215,6 +4736,43 @@ pub struct SyncArgs {
 
     #[arg(long, overrides_with("check"), hide = true)]
     pub no_check: bool,
+
+    /// Check the markers of `dependency-groups` entries for likely mistakes before syncing.
+    ///
+    /// Flags entries that compare a marker to an unknown value (e.g., `sys_platform == "linx"`),
+    /// entries whose markers can never be satisfied given the `requires-python` and
+    /// `tool.uv.environments` of the workspace, and entries that request an undeclared extra of
+    /// the project itself. If any are found, uv will exit with an error.
+    #[arg(long, conflicts_with = "script")]
+    pub check_group_markers: bool,
+
+    /// Do not run the commands declared in `tool.uv.post-sync` after syncing [env:
+    /// UV_NO_POST_SYNC=]
+    #[arg(long, value_parser = clap::builder::BoolishValueParser::new())]
+    pub no_post_sync: bool,
+
+    /// Repair the project environment if it was moved from its original location, then sync.
+    ///
+    /// After the project directory is renamed or moved, the environment continues to reference
+    /// its previous location. uv detects the previous location from the environment's activation
+    /// scripts and console scripts, then rewrites the script shebangs, console-script launchers,
+    /// activation scripts, and `pyvenv.cfg` entries that point into it. Editable installs of
+    /// workspace members that point into the previous location of the workspace are reinstalled
+    /// by the sync.
+    ///
+    /// Only paths within the previous location of the environment are rewritten.
+    #[arg(long, conflicts_with = "script", conflicts_with = "check")]
+    pub repair: bool,
+
+    /// Also reinstall every locked package that depends on a package passed to
+    /// `--reinstall-package`.
+    ///
+    /// The dependents are computed transitively from the lockfile, and are reinstalled (and, for
+    /// packages built from source, rebuilt) along with the named packages. This is useful when a
+    /// package links against, or generates files from, another package at build time, such as an
+    /// editable native extension.
+    #[arg(long, requires = "reinstall_package", conflicts_with = "script")]
+    pub cascade: bool,
 }
 
 #[derive(Args)]
@@ -4255,12 +4813,162 @@ pub struct LockArgs {
     )]
     pub dry_run: bool,
 
+    /// Record a digest of the index metadata for each registry package in the lockfile.
+    ///
+    /// When the index metadata is pinned, subsequent `--locked` operations verify that the index
+    /// still serves the locked distributions with identical hashes, and fail if any have been
+    /// removed or altered. Verification fetches the index metadata, so it requires network access;
+    /// with `--offline`, the cached index metadata is used instead, and verification fails for any
+    /// package whose metadata is not cached.
+    ///
+    /// Once pinned, packages added to the lockfile are pinned automatically. Passing this flag
+    /// again re-pins the index metadata for all packages.
+    #[arg(
+        long,
+        conflicts_with = "check_exists",
+        conflicts_with = "check",
+        conflicts_with = "locked"
+    )]
+    pub pin_index_metadata: bool,
+
+    /// Prefer the package versions installed in an existing environment.
+    ///
+    /// By default, packages that aren't already in the lockfile are locked to their latest
+    /// compatible versions. When `--prefer-installed` is provided, the versions installed in the
+    /// project environment (or in the environment at the given path) are used as preferences
+    /// instead, such that the lockfile matches the environment wherever the project's
+    /// requirements allow.
+    ///
+    /// Versions in an existing lockfile take precedence over installed versions. Any installed
+    /// versions that could not be preserved are reported.
+    #[arg(
+        long,
+        num_args = 0..=1,
+        value_name = "PATH",
+        value_hint = ValueHint::DirPath,
+        conflicts_with = "check_exists",
+        conflicts_with = "check",
+        conflicts_with = "locked"
+    )]
+    pub prefer_installed: Option<Option<PathBuf>>,
+
+    /// Regenerate a lockfile that contains merge conflicts.
+    ///
+    /// When the lockfile contains Git merge conflict markers (e.g., after a merge or rebase), the
+    /// conflicted lockfile is discarded and regenerated from the project requirements, preferring
+    /// the versions locked on either side of the conflict, such that the new lockfile changes as
+    /// little as possible relative to both branches.
+    ///
+    /// Fails if the `pyproject.toml` contains merge conflict markers, which must be resolved first.
+    #[arg(
+        long,
+        conflicts_with = "check_exists",
+        conflicts_with = "check",
+        conflicts_with = "locked"
+    )]
+    pub resolve_conflicts: bool,
+
+    /// Report the core metadata versions declared by the locked packages.
+    ///
+    /// Prints the number of packages declaring each core metadata version (`Metadata-Version`),
+    /// along with the packages below the configured `require-metadata` floor, if any. Useful for
+    /// choosing a floor before enforcing it.
+    ///
+    /// Forces a resolution, even if the lockfile is up-to-date, in order to read the metadata of
+    /// each package.
+    #[arg(
+        long,
+        conflicts_with = "check_exists",
+        conflicts_with = "check",
+        conflicts_with = "locked"
+    )]
+    pub report_metadata_versions: bool,
+
+    /// Check the project configuration for inconsistencies, without locking.
+    ///
+    /// Reports extras and dependency groups that share a name but differ in their contents,
+    /// dependency groups that shadow a conventional extra name, and `default-groups` entries that
+    /// refer to an extra rather than a group. These checks also run during `uv lock`, where they
+    /// are reported as warnings (or errors, if `tool.uv.strict-config` is enabled).
+    ///
+    /// Exits with a non-zero status if any issues are found.
+    #[arg(
+        long,
+        conflicts_with = "check_exists",
+        conflicts_with = "check",
+        conflicts_with = "locked",
+        conflicts_with = "script"
+    )]
+    pub check_config: bool,
+
+    /// Display the provenance recorded in the lockfile for the given package, without locking.
+    ///
+    /// Prints the index that served each locked version of the package, the time at which it was
+    /// locked, and the upload time of each artifact, as recorded when `tool.uv.lock-provenance`
+    /// is enabled.
+    #[arg(
+        long,
+        value_name = "PACKAGE",
+        conflicts_with_all = ["check", "locked", "check_exists", "dry_run", "pin_index_metadata", "script"],
+        value_hint = ValueHint::Other
+    )]
+    pub show_provenance: Option<PackageName>,
+
+    /// Suggest changes to the project requirements if the resolution fails.
+    ///
+    /// When no solution can be found, uv analyzes the conflict and proposes up to three
+    /// remediations: a `constraint-dependencies` entry that satisfies every requirement on a
+    /// package, an `override-dependencies` entry that replaces an overly tight transitive
+    /// requirement, or a relaxed version specifier for one of the project's own requirements.
+    ///
+    /// Each suggestion is validated by re-running the resolution with the change applied; only
+    /// suggestions that resolve successfully are shown. The project files are never modified.
+    #[arg(
+        long,
+        conflicts_with_all = ["check", "locked", "check_exists", "show_provenance", "check_config"]
+    )]
+    pub suggest: bool,
+
     /// Lock the specified Python script, rather than the current project.
     ///
     /// If provided, uv will lock the script (based on its inline metadata table, in adherence with
     /// PEP 723) to a `.lock` file adjacent to the script itself.
-    #[arg(long, value_hint = ValueHint::FilePath)]
-    pub script: Option<PathBuf>,
+    ///
+    /// Multiple scripts may be provided alongside `--shared-lock`, in which case they're locked
+    /// together into a single lockfile.
+    #[arg(long, num_args = 1.., value_hint = ValueHint::FilePath)]
+    pub script: Vec<PathBuf>,
+
+    /// Lock the scripts provided via `--script` into a single, shared lockfile at the given path.
+    ///
+    /// The scripts must be in the same directory as the lockfile. The requirements of each script
+    /// are locked as a dependency group named after the script, such that `uv run` installs only
+    /// the packages required by the script being run.
+    ///
+    /// The lockfile must be named `uv.lock` for `uv run` to discover it.
+    #[arg(long, requires = "script", value_hint = ValueHint::FilePath)]
+    pub shared_lock: Option<PathBuf>,
+
+    /// Print a JSON Schema describing the lockfile format, rather than locking the project.
+    #[arg(
+        long,
+        conflicts_with_all = ["check", "locked", "check_exists", "dry_run", "pin_index_metadata", "script", "validate"]
+    )]
+    pub schema: bool,
+
+    /// Validate the given lockfile, rather than locking the project.
+    ///
+    /// Reports whether the lockfile version is supported by this version of uv, along with any
+    /// fields that are not part of the lockfile schema, dependencies on packages that are missing
+    /// from the lockfile, and artifacts that lack a hash when other artifacts include one.
+    ///
+    /// Exits with a non-zero status if any problems are found.
+    #[arg(
+        long,
+        conflicts_with_all = ["check", "locked", "check_exists", "dry_run", "pin_index_metadata", "script"],
+        value_hint = ValueHint::FilePath
+    )]
+    pub validate: Option<PathBuf>,
 
     #[command(flatten)]
     pub resolver: ResolverArgs,
@@ -4418,6 +5126,19 @@ pub struct AddArgs {
     #[arg(long, value_enum)]
     pub bounds: Option<AddBoundsKind>,
 
+    /// Display a unified diff of each file that will be modified.
+    ///
+    /// The diff reflects the exact content written to disk, including any changes to the
+    /// workspace root or to a script's inline metadata.
+    #[arg(long)]
+    pub show_diff: bool,
+
+    /// Display a unified diff of each file that would be modified, without writing any changes.
+    ///
+    /// The project will not be locked or synced.
+    #[arg(long, conflicts_with = "show_diff")]
+    pub diff_only: bool,
+
     /// Commit to use when adding a dependency from Git.
     #[arg(long, group = "git-ref", action = clap::ArgAction::Set, value_hint = ValueHint::Other)]
     pub rev: Option<String>,
@@ -4680,6 +5401,19 @@ pub struct RemoveArgs {
     )]
     pub group: Option<GroupName>,
 
+    /// Display a unified diff of each file that will be modified.
+    ///
+    /// The diff reflects the exact content written to disk, including any changes to the
+    /// workspace root or to a script's inline metadata.
+    #[arg(long)]
+    pub show_diff: bool,
+
+    /// Display a unified diff of each file that would be modified, without writing any changes.
+    ///
+    /// The project will not be locked or synced.
+    #[arg(long, conflicts_with = "show_diff")]
+    pub diff_only: bool,
+
     /// Avoid syncing the virtual environment after re-locking the project [env: UV_NO_SYNC=]
     #[arg(long)]
     pub no_sync: bool,
@@ -4760,6 +5494,16 @@ pub struct TreeArgs {
     #[arg(long, value_enum, default_value_t = TreeFormat::default())]
     pub format: TreeFormat,
 
+    /// Display only the packages that are locked at more than one version.
+    ///
+    /// A universal resolution may select different versions of a package for different
+    /// environments. For each locked version, shows the markers of the forks that selected it and
+    /// the shortest dependency path that requires it.
+    ///
+    /// Implies `--universal`.
+    #[arg(long, conflicts_with_all = ["invert", "python_version", "python_platform"])]
+    pub duplicates: bool,
+
     #[command(flatten)]
     pub tree: DisplayTreeArgs,
 
@@ -4836,6 +5580,24 @@ pub struct TreeArgs {
     #[arg(long, conflicts_with_all = ["locked", "upgrade", "no_sources"])]
     pub frozen: bool,
 
+    /// Read the lockfile from the given path, rather than the `uv.lock` in the project.
+    ///
+    /// Use `-` to read the lockfile from stdin. The lockfile is used as-is, as with `--frozen`:
+    /// the project is only used to determine its workspace members.
+    #[arg(
+        long,
+        value_hint = ValueHint::FilePath,
+        conflicts_with_all = ["locked", "upgrade", "script"]
+    )]
+    pub lockfile: Option<PathBuf>,
+
+    /// Skip validating that the workspace members in the lockfile match the current project.
+    ///
+    /// Only applies with `--lockfile`, e.g., to display a lockfile from a previous release in
+    /// which the workspace members differed.
+    #[arg(long, requires = "lockfile")]
+    pub no_validate: bool,
+
     #[command(flatten)]
     pub build: BuildOptionsArgs,
 
@@ -4919,10 +5681,24 @@ pub struct ExportArgs {
     /// Prune the given package from the dependency tree.
     ///
     /// Pruned packages will be excluded from the exported requirements file, as will any
-    /// dependencies that are no longer required after the pruned package is removed.
+    /// dependencies that are no longer required after the pruned package is removed. Dependencies
+    /// that remain reachable from other packages are retained.
+    ///
+    /// To exclude a package while retaining its dependencies, use `--omit` instead.
+    ///
+    /// When the `export-prune-root-check` preview feature is enabled, pruning a direct dependency of
+    /// the project requires `--allow-prune-root`.
     #[arg(long, conflicts_with = "all_packages", value_name = "PACKAGE")]
     pub prune: Vec<PackageName>,
 
+    /// Allow `--prune` to remove direct dependencies of the project.
+    ///
+    /// When the `export-prune-root-check` preview feature is enabled, uv will exit with an error if
+    /// a pruned package is a direct dependency of the project (or of a selected extra or dependency
+    /// group), as the exported requirements would otherwise silently omit a declared requirement.
+    #[arg(long, requires = "prune")]
+    pub allow_prune_root: bool,
+
     /// Include optional dependencies from the specified extra name.
     ///
     /// May be provided more than once.
@@ -5053,6 +5829,58 @@ pub struct ExportArgs {
     #[arg(long, short, value_hint = ValueHint::FilePath)]
     pub output_file: Option<PathBuf>,
 
+    /// The directory against which local path requirements should be made relative.
+    ///
+    /// By default, local paths are written relative to the directory containing the output file
+    /// (as provided via `--output-file` or `--output-dir`), such that the requirements file can be
+    /// installed from any working directory. If the output is written to stdout, paths are written
+    /// relative to the workspace root.
+    ///
+    /// Only applies to the `requirements.txt` format.
+    #[arg(long, value_hint = ValueHint::DirPath, conflicts_with = "bundle")]
+    pub relative_to: Option<PathBuf>,
+
+    /// Write a self-contained bundle for installation without network access.
+    ///
+    /// The bundle is a zstd-compressed tarball (e.g., `out.tar.zst`) containing the exported
+    /// lockfile, in `pylock.toml` format, alongside every wheel and archive required to install
+    /// it. Artifacts are downloaded as needed when the bundle is created.
+    ///
+    /// The resulting bundle can be installed with `uv sync --from-bundle`.
+    #[arg(
+        long,
+        value_hint = ValueHint::FilePath,
+        conflicts_with_all = ["format", "output_file"],
+    )]
+    pub bundle: Option<PathBuf>,
+
+    /// The platforms for which wheels should be included in the bundle.
+    ///
+    /// Represented as a "target triple", like `x86_64-unknown-linux-gnu` or
+    /// `aarch64-apple-darwin`. May be provided multiple times.
+    ///
+    /// Defaults to the platform of the Python interpreter used for export. If any package lacks a
+    /// compatible wheel for one of the requested platforms, bundle creation fails.
+    #[arg(long, requires = "bundle")]
+    pub python_platform: Vec<TargetTriple>,
+
+    /// Write a separate `requirements.txt` file for each selected dependency group.
+    ///
+    /// The project's dependencies are written to `requirements.txt` in the `--output-dir`, and
+    /// each selected dependency group is written to `requirements-<group>.txt`. Each group file
+    /// references the base file via `-r requirements.txt` and contains only the packages that are
+    /// not already included in the base file.
+    #[arg(
+        long,
+        requires = "output_dir",
+        conflicts_with_all = ["output_file", "bundle", "only_group", "only_dev"],
+    )]
+    pub split_per_group: bool,
+
+    /// The directory to which the split requirements files should be written.
+    #[arg(long, requires = "split_per_group", value_hint = ValueHint::DirPath)]
+    pub output_dir: Option<PathBuf>,
+
     /// Do not emit the current project.
     ///
     /// By default, the current project is included in the exported requirements file with all of
@@ -5126,11 +5954,15 @@ pub struct ExportArgs {
     /// By default, all project's dependencies are included in the exported requirements
     /// file. The `--no-emit-package` option allows exclusion of specific packages.
     ///
+    /// Unlike `--prune`, the dependencies of an excluded package are retained. This option is
+    /// also available as `--omit`.
+    ///
     /// The inverse `--only-emit-package` can be used to emit _only_ the specified packages,
     /// excluding all others.
     #[arg(
         long,
         alias = "no-install-package",
+        visible_alias = "omit",
         conflicts_with = "only_emit_package",
         value_delimiter = ',',
         value_hint = ValueHint::Other,
@@ -5161,6 +5993,24 @@ pub struct ExportArgs {
     #[arg(long, conflicts_with_all = ["locked", "upgrade", "no_sources"])]
     pub frozen: bool,
 
+    /// Read the lockfile from the given path, rather than the `uv.lock` in the project.
+    ///
+    /// Use `-` to read the lockfile from stdin. The lockfile is used as-is, as with `--frozen`:
+    /// the project is only used to determine its workspace members.
+    #[arg(
+        long,
+        value_hint = ValueHint::FilePath,
+        conflicts_with_all = ["locked", "upgrade", "script"]
+    )]
+    pub lockfile: Option<PathBuf>,
+
+    /// Skip validating that the workspace members in the lockfile match the current project.
+    ///
+    /// Only applies with `--lockfile`, e.g., to export a lockfile from a previous release in
+    /// which the workspace members differed.
+    #[arg(long, requires = "lockfile")]
+    pub no_validate: bool,
+
     #[command(flatten)]
     pub resolver: ResolverArgs,
 
@@ -5756,6 +6606,17 @@ pub struct ToolRunArgs {
     #[arg(long, value_hint = ValueHint::Other)]
     pub from: Option<String>,
 
+    /// The entry point to run from the package provided as the command.
+    ///
+    /// The entry point may be a console script, or an executable installed into the scripts
+    /// directory from the wheel's `.data` directory. For example, `uvx --entrypoint foo bar` runs
+    /// the `foo` executable provided by the `bar` package.
+    ///
+    /// When the command is a local wheel and no entry point is provided, the wheel must declare
+    /// exactly one entry point, which is run.
+    #[arg(long, conflicts_with = "from", value_hint = ValueHint::Other)]
+    pub entrypoint: Option<String>,
+
     /// Run with the given packages installed.
     #[arg(short = 'w', long, value_hint = ValueHint::Other)]
     pub with: Vec<comma::CommaSeparatedRequirements>,
@@ -5780,6 +6641,18 @@ pub struct ToolRunArgs {
     )]
     pub with_requirements: Vec<Maybe<PathBuf>>,
 
+    /// Expose the given host executable to the command.
+    ///
+    /// The executable is linked into a directory that is placed on the `PATH` directly after the
+    /// tool environment's scripts directory and before the inherited `PATH`, such that the command
+    /// (and any subprocesses) resolve it regardless of the host `PATH` ordering. On Windows, a
+    /// trampoline that invokes the executable is used in place of a symbolic link.
+    ///
+    /// May be provided multiple times. Fails if an executable shares a name with an executable in
+    /// the tool environment.
+    #[arg(long, value_hint = ValueHint::ExecutablePath)]
+    pub with_executable: Vec<PathBuf>,
+
     /// Constrain versions using the given requirements files.
     ///
     /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
@@ -6059,6 +6932,31 @@ pub struct ToolInstallArgs {
     #[arg(long)]
     pub force: bool,
 
+    /// Reinstall the tool from its existing receipt.
+    ///
+    /// Reuses the requirements, constraints, Python request, and index and resolver options that
+    /// were recorded when the tool was installed. The recorded options take precedence over those
+    /// provided on the command line or in configuration files.
+    ///
+    /// Requires `--reinstall`.
+    #[arg(
+        long,
+        requires = "reinstall",
+        conflicts_with_all = [
+            "from",
+            "with",
+            "with_requirements",
+            "with_editable",
+            "with_executables_from",
+            "editable",
+            "constraints",
+            "overrides",
+            "excludes",
+            "build_constraints",
+        ],
+    )]
+    pub keep_options: bool,
+
     /// Whether to use Git LFS when adding a dependency from Git.
     #[arg(long)]
     pub lfs: bool,
@@ -6606,6 +7504,9 @@ pub enum PythonCommand {
     /// Uninstall Python versions.
     Uninstall(PythonUninstallArgs),
 
+    /// Inspect the target platforms accepted by `--python-platform`.
+    Platform(PythonPlatformNamespace),
+
     /// Ensure that the Python executable directory is on the `PATH`.
     ///
     /// If the Python executable directory is not present on the `PATH`, uv will attempt to add it to
@@ -6663,6 +7564,26 @@ pub struct PythonListArgs {
     #[arg(long)]
     pub show_urls: bool,
 
+    /// Verify that managed Python installations are functional.
+    ///
+    /// Runs a smoke test that imports `ssl`, `sqlite3`, and `zlib` with each managed Python
+    /// installation and re-validates the installed files against the hashes recorded at install
+    /// time, then reports whether both checks passed. If the archive an installation was extracted
+    /// from is retained in the `UV_PYTHON_CACHE_DIR`, its checksum is re-validated too.
+    #[arg(long)]
+    pub verify: bool,
+
+    /// Show the disk usage and last use of managed Python installations.
+    ///
+    /// The last use is recorded when a managed Python installation is selected by a uv command,
+    /// at most once per day. Installations that haven't been used since uv began tracking usage
+    /// are shown as `unknown`.
+    ///
+    /// When `--output-format json` is used, the disk usage (in bytes) and the time of last use are
+    /// included as `disk_usage` and `last_used`.
+    #[arg(long)]
+    pub show_usage: bool,
+
     /// Select the output format.
     #[arg(long, value_enum, default_value_t = PythonListFormat::default())]
     pub output_format: PythonListFormat,
@@ -6672,6 +7593,36 @@ pub struct PythonListArgs {
     pub python_downloads_json_url: Option<String>,
 }
 
+#[derive(Args)]
+pub struct PythonPlatformNamespace {
+    #[command(subcommand)]
+    pub command: PythonPlatformCommand,
+}
+
+#[derive(Subcommand)]
+pub enum PythonPlatformCommand {
+    /// List the platforms accepted by `--python-platform`.
+    ///
+    /// Each platform is shown with its aliases and the wheel tags it accepts for the given Python
+    /// version, from highest to lowest priority. These are the same tags used when resolving and
+    /// installing with `--python-platform`, in both `uv pip` and project commands.
+    List(PythonPlatformListArgs),
+}
+
+#[derive(Args)]
+pub struct PythonPlatformListArgs {
+    /// A platform to show, e.g., `linux-arm64`.
+    ///
+    /// By default, all supported platforms are shown.
+    pub python_platform: Option<TargetTriple>,
+
+    /// The Python version for which to show the wheel tags, e.g., `3.12`.
+    ///
+    /// Tags are shown for a CPython interpreter of the given version.
+    #[arg(long)]
+    pub python_version: PythonVersion,
+}
+
 #[derive(Args)]
 pub struct PythonDirArgs {
     /// Show the directory into which `uv python` will install Python executables.
@@ -6837,6 +7788,36 @@ pub struct PythonInstallArgs {
     #[arg(long, conflicts_with("no_bin"))]
     pub default: bool,
 
+    /// Verify that already-installed Python versions are functional.
+    ///
+    /// Newly installed Python versions are always checked by running a smoke test that imports
+    /// `ssl`, `sqlite3`, and `zlib`; installations that fail the check are removed. When
+    /// `--verify` is provided, the smoke test is also run against requested Python versions that
+    /// are already installed, their files are re-validated against the hashes recorded at install
+    /// time, and the result is reported for each installation. If the archive an installation was
+    /// extracted from is retained in the `UV_PYTHON_CACHE_DIR`, its checksum is re-validated too.
+    #[arg(long)]
+    pub verify: bool,
+
+    /// Build the requested Python versions from source, rather than downloading pre-built
+    /// distributions.
+    ///
+    /// The CPython source distribution is downloaded from python.org, verified against its
+    /// published checksum, and built with `configure` and `make`. This is useful on platforms on
+    /// which the pre-built distributions can't be executed, e.g., due to an older glibc.
+    ///
+    /// A C compiler, `make`, and the OpenSSL, SQLite, and zlib development headers are required.
+    ///
+    /// Only CPython is supported, on Unix.
+    #[arg(long)]
+    pub build_from_source: bool,
+
+    /// Enable profile-guided and link-time optimizations when building from source.
+    ///
+    /// Optimized builds are faster, but take considerably longer to build.
+    #[arg(long, requires = "build_from_source")]
+    pub enable_optimizations: bool,
+
     #[command(flatten)]
     pub compile_bytecode: PythonInstallCompileBytecodeArgs,
 }
@@ -7048,6 +8029,20 @@ pub struct PythonPinArgs {
     #[arg(long, conflicts_with = "request", conflicts_with = "resolved")]
     pub rm: bool,
 
+    /// Show every Python version file that applies to the current directory, in order of
+    /// precedence, and which one is effective.
+    ///
+    /// Version files are listed from the working directory upwards, followed by the global
+    /// Python version pin. A `--python` request or the `UV_PYTHON` environment variable takes
+    /// precedence over all version files.
+    #[arg(
+        long,
+        conflicts_with = "request",
+        conflicts_with = "resolved",
+        conflicts_with = "rm"
+    )]
+    pub show_resolution: bool,
+
     /// URL pointing to JSON of custom Python installations.
     #[arg(long, value_hint = ValueHint::Other)]
     pub python_downloads_json_url: Option<String>,
@@ -7319,6 +8314,25 @@ pub struct RefreshArgs {
     /// Refresh cached data for a specific package.
     #[arg(long, help_heading = "Cache options", value_hint = ValueHint::Other)]
     refresh_package: Vec<PackageName>,
+
+    /// Revalidate cached index metadata, while retaining cached artifacts.
+    ///
+    /// Responses from package indexes (e.g., Simple API and `--find-links` pages) are revalidated
+    /// to discover newly published versions, but cached wheels, source distributions, and builds
+    /// are reused.
+    #[arg(long, help_heading = "Cache options")]
+    refresh_metadata: bool,
+
+    /// Revalidate cached index metadata for a specific index URL, while retaining cached artifacts.
+    #[arg(long, help_heading = "Cache options", value_hint = ValueHint::Url)]
+    refresh_index: Vec<IndexUrl>,
+
+    /// Refresh cached artifacts for a specific package, while retaining cached index metadata.
+    ///
+    /// Cached wheels, source distributions, and builds for the package are discarded, but the
+    /// cached responses from package indexes are reused.
+    #[arg(long, help_heading = "Cache options", value_hint = ValueHint::Other)]
+    refresh_artifacts: Vec<PackageName>,
 }
 
 #[derive(Args)]
@@ -8178,6 +9192,23 @@ pub struct PublishArgs {
     #[arg(default_value = "dist/*", value_hint = ValueHint::FilePath)]
     pub files: Vec<String>,
 
+    /// Upload exactly the distributions produced by the most recent `uv build` into the given
+    /// output directory.
+    ///
+    /// `uv build` records the distributions it produced in the output directory. Other files in
+    /// the directory, such as distributions left over from previous builds, are never uploaded.
+    ///
+    /// Defaults to the `dist` directory.
+    #[arg(
+        long,
+        num_args = 0..=1,
+        default_missing_value = "dist",
+        value_name = "OUT_DIR",
+        conflicts_with = "files",
+        value_hint = ValueHint::DirPath,
+    )]
+    pub from_build: Option<PathBuf>,
+
     /// The name of an index in the configuration to use for publishing.
     ///
     /// The index must have a `publish-url` setting, for example:
@@ -8296,6 +9327,17 @@ pub struct PublishArgs {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Check the metadata of the distributions before uploading any files.
+    ///
+    /// Similar to `twine check`, uv verifies that the name and version in the metadata match the
+    /// filename, that the `Description-Content-Type` is supported by PyPI, and that a
+    /// reStructuredText description has well-formed section titles. A missing description or
+    /// content type is reported as a warning.
+    ///
+    /// Combine with `--dry-run` to check the distributions without uploading them.
+    #[arg(long)]
+    pub check_metadata: bool,
+
     /// Do not upload attestations for the published files.
     ///
     /// By default, uv attempts to upload matching PEP 740 attestations with each distribution
@@ -8334,6 +9376,24 @@ pub enum WorkspaceCommand {
     ///
     /// Displays newline separated names of workspace members.
     List(WorkspaceListArgs),
+    /// Add a new member to the workspace.
+    ///
+    /// Creates a project at the given path, using the same templates as `uv init`, and adds it to
+    /// the `tool.uv.workspace.members` of the workspace root, unless an existing entry already
+    /// includes it.
+    ///
+    /// Use `--for` to add the new member as a dependency of an existing member.
+    Add(WorkspaceAddArgs),
+    /// Remove a member from the workspace.
+    ///
+    /// Removes the member from `tool.uv.workspace.members` (or excludes it, if it is included by a
+    /// glob) and removes any `tool.uv.sources` entries that refer to it as a workspace source.
+    ///
+    /// Members that depend on the removed member are not modified, and a warning is displayed
+    /// instead.
+    ///
+    /// The member's files are left in place unless `--delete` is provided.
+    Remove(WorkspaceRemoveArgs),
 }
 #[derive(Args)]
 pub struct MetadataArgs {
@@ -8430,6 +9490,87 @@ pub struct WorkspaceListArgs {
     pub scripts: bool,
 }
 
+#[derive(Args)]
+pub struct WorkspaceAddArgs {
+    /// The path to the new workspace member.
+    #[arg(value_hint = ValueHint::DirPath)]
+    pub path: PathBuf,
+
+    /// The name of the new member.
+    ///
+    /// Defaults to the name of the directory.
+    #[arg(long, value_hint = ValueHint::Other)]
+    pub name: Option<PackageName>,
+
+    /// Set up the member to be built as a Python package.
+    ///
+    /// Defines a `[build-system]` for the member.
+    #[arg(long, overrides_with = "no_package")]
+    pub r#package: bool,
+
+    /// Do not set up the member to be built as a Python package.
+    #[arg(long, overrides_with = "package", conflicts_with_all = ["lib", "build_backend"])]
+    pub r#no_package: bool,
+
+    /// Create an application member.
+    ///
+    /// This is the default behavior if `--lib` is not requested.
+    #[arg(long, alias = "application", conflicts_with = "lib")]
+    pub r#app: bool,
+
+    /// Create a library member.
+    #[arg(long, alias = "library", conflicts_with = "app")]
+    pub r#lib: bool,
+
+    /// Set the member description.
+    #[arg(long, value_hint = ValueHint::Other)]
+    pub description: Option<String>,
+
+    /// Initialize a build-backend of choice for the member.
+    ///
+    /// Implicitly sets `--package`.
+    #[arg(long, value_enum, conflicts_with = "no_package", env = EnvVars::UV_INIT_BUILD_BACKEND)]
+    pub build_backend: Option<ProjectBuildBackend>,
+
+    /// Do not create a `README.md` file.
+    #[arg(long)]
+    pub no_readme: bool,
+
+    /// Add the new member as a dependency of an existing workspace member.
+    ///
+    /// The dependency is added to the `project.dependencies` of the given member, with a
+    /// `workspace = true` entry in its `tool.uv.sources`.
+    #[arg(long = "for", value_name = "MEMBER", value_hint = ValueHint::Other)]
+    pub r#for: Option<PackageName>,
+
+    /// The Python interpreter to use to determine the minimum supported Python version.
+    ///
+    /// See `uv help python` to view supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+        value_hint = ValueHint::Other,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
+#[derive(Args, Debug)]
+pub struct WorkspaceRemoveArgs {
+    /// The name of the workspace member to remove.
+    #[arg(value_hint = ValueHint::Other)]
+    pub package: PackageName,
+
+    /// Delete the member's directory.
+    ///
+    /// By default, the member's files are left in place.
+    #[arg(long)]
+    pub delete: bool,
+}
+
 /// See [PEP 517](https://peps.python.org/pep-0517/) and
 /// [PEP 660](https://peps.python.org/pep-0660/) for specifications of the parameters.
 #[derive(Subcommand)]
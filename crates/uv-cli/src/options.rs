@@ -3,7 +3,7 @@ use std::fmt;
 
 use anyhow::bail;
 
-use uv_cache::Refresh;
+use uv_cache::{Refresh, RefreshScopes};
 use uv_configuration::{BuildIsolation, Reinstall, Upgrade};
 use uv_distribution_types::{ConfigSettings, Index, PackageConfigSettings, Requirement};
 use uv_resolver::{ExcludeNewerPackage, PrereleaseMode};
@@ -221,12 +221,25 @@ impl TryFrom<RefreshArgs> for Refresh {
             refresh,
             no_refresh,
             refresh_package,
+            refresh_metadata,
+            refresh_index,
+            refresh_artifacts,
         } = value;
 
-        Ok(Self::from_args(
-            flag(refresh, no_refresh, "no-refresh")?,
-            refresh_package,
-        ))
+        let refresh = flag(refresh, no_refresh, "no-refresh")?;
+        let policy = Self::from_args(refresh, refresh_package);
+
+        // `--no-refresh` disables all refresh scopes.
+        if refresh == Some(false) {
+            return Ok(policy);
+        }
+
+        Ok(policy.combine(Self::from_scopes(RefreshScopes {
+            metadata: refresh_metadata,
+            indexes: refresh_index,
+            artifacts: refresh_artifacts,
+            ..RefreshScopes::default()
+        })))
     }
 }
 
@@ -576,6 +589,8 @@ pub fn resolver_options(
             prerelease
         },
         fork_strategy,
+        prerelease_packages: None,
+        require_metadata: None,
         dependency_metadata: None,
         config_settings: config_setting
             .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
@@ -590,6 +605,8 @@ pub fn resolver_options(
         ),
         extra_build_dependencies: None,
         extra_build_variables: None,
+        build_env_passthrough: None,
+        build_env_passthrough_package: None,
         exclude_newer,
         exclude_newer_package: exclude_newer_package.map(ExcludeNewerPackage::from_iter),
         link_mode,
@@ -709,6 +726,8 @@ pub fn resolver_installer_options_with_indexes(
             prerelease
         },
         fork_strategy,
+        prerelease_packages: None,
+        require_metadata: None,
         dependency_metadata: None,
         config_settings: config_setting
             .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
@@ -723,6 +742,8 @@ pub fn resolver_installer_options_with_indexes(
         ),
         extra_build_dependencies: None,
         extra_build_variables: None,
+        build_env_passthrough: None,
+        build_env_passthrough_package: None,
         exclude_newer,
         exclude_newer_package: exclude_newer_package.map(ExcludeNewerPackage::from_iter),
         link_mode,
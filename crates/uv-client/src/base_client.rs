@@ -44,6 +44,9 @@ use crate::{Connectivity, RetriableError, RetryState, UvRetryableStrategy};
 
 pub const DEFAULT_RETRIES: u32 = 3;
 
+/// The default maximum size of a Simple API response, in bytes.
+pub const DEFAULT_MAX_SIMPLE_RESPONSE_SIZE: u64 = 256 * 1024 * 1024;
+
 /// Maximum number of redirects to follow before giving up.
 ///
 /// This is the default used by [`reqwest`].
@@ -123,6 +126,11 @@ pub struct BaseClientBuilder<'a> {
     no_retry_delay: bool,
     /// A shared, dedicated blocking pool for short-lived cache reads.
     cache_read_runtime: Arc<CacheReadRuntime>,
+    /// Whether to error when a package is served by multiple indexes that don't declare each
+    /// other as alternate locations.
+    strict_index_tracking: bool,
+    /// The maximum size of a Simple API response, in bytes.
+    max_simple_response_size: u64,
 }
 
 #[derive(Debug)]
@@ -224,6 +232,8 @@ impl Default for BaseClientBuilder<'_> {
             client_name: None,
             no_retry_delay: env::var_os(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY).is_some(),
             cache_read_runtime: Arc::new(CacheReadRuntime::new(Concurrency::DEFAULT_CACHE_READS)),
+            strict_index_tracking: false,
+            max_simple_response_size: DEFAULT_MAX_SIMPLE_RESPONSE_SIZE,
         }
     }
 }
@@ -370,6 +380,33 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    /// Error, rather than warn, when a package is served by multiple indexes that don't declare
+    /// each other as alternate locations, per PEP 708.
+    #[must_use]
+    pub fn strict_index_tracking(mut self, strict_index_tracking: bool) -> Self {
+        self.strict_index_tracking = strict_index_tracking;
+        self
+    }
+
+    /// Whether strict PEP 708 index tracking is enabled.
+    pub fn is_strict_index_tracking(&self) -> bool {
+        self.strict_index_tracking
+    }
+
+    /// Set the maximum size of a Simple API response, in bytes.
+    ///
+    /// Larger responses are rejected, rather than parsed and written to the cache.
+    #[must_use]
+    pub fn max_simple_response_size(mut self, max_simple_response_size: u64) -> Self {
+        self.max_simple_response_size = max_simple_response_size;
+        self
+    }
+
+    /// The maximum size of a Simple API response, in bytes.
+    pub fn simple_response_size_limit(&self) -> u64 {
+        self.max_simple_response_size
+    }
+
     #[must_use]
     pub fn redirect(mut self, policy: RedirectPolicy) -> Self {
         self.redirect_policy = policy;
@@ -436,6 +436,17 @@ pub enum ErrorKind {
     #[error("Package `{0}` was not found in the registry")]
     RemotePackageNotFound(PackageName),
 
+    /// The package was found on multiple indexes that don't declare each other as alternate
+    /// locations, per PEP 708.
+    #[error(
+        "Package `{package_name}` is available on both `{first}` and `{second}`, but the indexes don't declare each other as alternate locations"
+    )]
+    UntrackedIndexes {
+        package_name: PackageName,
+        first: Box<IndexUrl>,
+        second: Box<IndexUrl>,
+    },
+
     /// The package was not found in the local (file-based) index.
     #[error("Package `{0}` was not found in the local index")]
     LocalPackageNotFound(PackageName),
@@ -513,6 +524,9 @@ pub enum ErrorKind {
     #[error("Unsupported `Content-Type` \"{1}\" for {0}. Expected JSON or HTML.")]
     UnsupportedMediaType(DisplaySafeUrl, String),
 
+    #[error("The Simple API response from {0} exceeds the maximum supported size of {1} bytes")]
+    SimpleResponseTooLarge(DisplaySafeUrl, u64),
+
     #[error("Reading from cache archive failed: {0}")]
     ArchiveRead(String),
 
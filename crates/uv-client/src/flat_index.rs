@@ -190,7 +190,7 @@ impl<'a> FlatIndexClient<'a> {
         let cache_control = match self.connectivity {
             Connectivity::Online => CacheControl::from(
                 self.cache
-                    .freshness(&cache_entry, None, None)
+                    .index_freshness(&cache_entry, Some(flat_index), None)
                     .map_err(ErrorKind::Io)?,
             ),
             Connectivity::Offline => CacheControl::AllowStale,
@@ -218,6 +218,7 @@ impl<'a> FlatIndexClient<'a> {
                 })?;
                 let SimpleDetailHTML {
                     project_status: _,
+                    tracking: _,
                     base,
                     files,
                 } = SimpleDetailHTML::parse(&text, &url)
@@ -7,7 +7,9 @@ use tracing::{debug, instrument, warn};
 
 use uv_normalize::PackageName;
 use uv_pep440::{VersionSpecifiers, VersionSpecifiersParseError};
-use uv_pypi_types::{BaseUrl, CoreMetadata, Hashes, ProjectStatus, PypiFile, Status, Yanked};
+use uv_pypi_types::{
+    BaseUrl, CoreMetadata, Hashes, ProjectStatus, ProjectTracking, PypiFile, Status, Yanked,
+};
 use uv_pypi_types::{HashError, LenientVersionSpecifiers};
 use uv_redacted::{DisplaySafeUrl, DisplaySafeUrlError};
 use uv_small_str::SmallString;
@@ -70,6 +72,8 @@ pub(crate) struct SimpleDetailHTML {
     /// The PEP 792 project status information.
     #[allow(dead_code)]
     pub(crate) project_status: ProjectStatus,
+    /// The PEP 708 tracking information.
+    pub(crate) tracking: ProjectTracking,
     /// The [`BaseUrl`] to which all relative URLs should be resolved.
     pub(crate) base: BaseUrl,
     /// The list of [`PypiFile`]s available for download sorted by filename.
@@ -86,13 +90,21 @@ impl SimpleDetailHTML {
         // Specifically, it appears as `name="pypi:project-status"`
         // and `name="pypi:project-status-reason"` with corresponding
         // `content` attributes.
-        let project_status = dom
+        let head = dom
             .nodes()
             .iter()
-            .find(|node| node.as_tag().is_some_and(|tag| is_tag(tag, b"head")))
+            .find(|node| node.as_tag().is_some_and(|tag| is_tag(tag, b"head")));
+        let project_status = head
             .and_then(|head| Self::parse_project_status(dom.parser(), head))
             .unwrap_or_default();
 
+        // Tracking information also appears in the `<meta>` tags in the `<head>`, as
+        // `name="pypi:tracks"` and `name="pypi:alternate-locations"`, each of which may be
+        // repeated.
+        let tracking = head
+            .and_then(|head| Self::parse_tracking(dom.parser(), head))
+            .unwrap_or_default();
+
         // Parse the first `<base>` tag, if any, to determine the base URL to which all
         // relative URLs should be resolved. The HTML spec requires that the `<base>` tag
         // appear before other tags with attribute values of URLs.
@@ -134,6 +146,7 @@ impl SimpleDetailHTML {
 
         Ok(Self {
             project_status,
+            tracking,
             base,
             files,
         })
@@ -190,6 +203,37 @@ impl SimpleDetailHTML {
         }
     }
 
+    /// Parse a [`ProjectTracking`] from the `<meta>` tags in the given `<head>`.
+    ///
+    /// Precondition: `head` is a `<head>` tag.
+    fn parse_tracking(parser: &Parser, head: &Node) -> Option<ProjectTracking> {
+        let children = head.children()?;
+
+        let mut tracking = ProjectTracking::default();
+        for node in children.all(parser) {
+            let tag = match node.as_tag() {
+                Some(tag) if is_tag(tag, b"meta") => tag,
+                _ => continue,
+            };
+
+            let Some(name) = attribute(tag, "name") else {
+                continue;
+            };
+            let locations = match name.as_ref() {
+                "pypi:tracks" => &mut tracking.tracks,
+                "pypi:alternate-locations" => &mut tracking.alternate_locations,
+                _ => continue,
+            };
+            let Some(content) = attribute(tag, "content") else {
+                warn!("Invalid `{name}` metadata (missing content)");
+                continue;
+            };
+            locations.push(SmallString::from(content.as_ref()));
+        }
+
+        Some(tracking)
+    }
+
     /// Parse the `href` from a `<base>` tag.
     fn parse_base(base: &HTMLTag) -> Result<Option<DisplaySafeUrl>, Error> {
         let Some(href) = attribute(base, "href") else {
@@ -442,6 +486,10 @@ mod tests {
                 status: Active,
                 reason: None,
             },
+            tracking: ProjectTracking {
+                tracks: [],
+                alternate_locations: [],
+            },
             base: BaseUrl(
                 DisplaySafeUrl {
                     scheme: "https",
@@ -503,6 +551,10 @@ mod tests {
                 status: Active,
                 reason: None,
             },
+            tracking: ProjectTracking {
+                tracks: [],
+                alternate_locations: [],
+            },
             base: BaseUrl(
                 DisplaySafeUrl {
                     scheme: "https",
@@ -567,6 +619,10 @@ mod tests {
                 status: Active,
                 reason: None,
             },
+            tracking: ProjectTracking {
+                tracks: [],
+                alternate_locations: [],
+            },
             base: BaseUrl(
                 DisplaySafeUrl {
                     scheme: "https",
@@ -628,6 +684,10 @@ mod tests {
                 status: Active,
                 reason: None,
             },
+            tracking: ProjectTracking {
+                tracks: [],
+                alternate_locations: [],
+            },
             base: BaseUrl(
                 DisplaySafeUrl {
                     scheme: "https",
@@ -689,6 +749,10 @@ mod tests {
                 status: Active,
                 reason: None,
             },
+            tracking: ProjectTracking {
+                tracks: [],
+                alternate_locations: [],
+            },
             base: BaseUrl(
                 DisplaySafeUrl {
                     scheme: "https",
@@ -750,6 +814,10 @@ mod tests {
                 status: Active,
                 reason: None,
             },
+            tracking: ProjectTracking {
+                tracks: [],
+                alternate_locations: [],
+            },
             base: BaseUrl(
                 DisplaySafeUrl {
                     scheme: "https",
@@ -809,6 +877,10 @@ mod tests {
                 status: Active,
                 reason: None,
             },
+            tracking: ProjectTracking {
+                tracks: [],
+                alternate_locations: [],
+            },
             base: BaseUrl(
                 DisplaySafeUrl {
                     scheme: "https",
@@ -868,6 +940,10 @@ mod tests {
                 status: Active,
                 reason: None,
             },
+            tracking: ProjectTracking {
+                tracks: [],
+                alternate_locations: [],
+            },
             base: BaseUrl(
                 DisplaySafeUrl {
                     scheme: "https",
@@ -910,6 +986,10 @@ mod tests {
                 status: Active,
                 reason: None,
             },
+            tracking: ProjectTracking {
+                tracks: [],
+                alternate_locations: [],
+            },
             base: BaseUrl(
                 DisplaySafeUrl {
                     scheme: "https",
@@ -952,6 +1032,10 @@ mod tests {
                 status: Active,
                 reason: None,
             },
+            tracking: ProjectTracking {
+                tracks: [],
+                alternate_locations: [],
+            },
             base: BaseUrl(
                 DisplaySafeUrl {
                     scheme: "https",
@@ -1011,6 +1095,10 @@ mod tests {
                 status: Active,
                 reason: None,
             },
+            tracking: ProjectTracking {
+                tracks: [],
+                alternate_locations: [],
+            },
             base: BaseUrl(
                 DisplaySafeUrl {
                     scheme: "https",
@@ -1071,6 +1159,10 @@ mod tests {
                     status: Active,
                     reason: None,
                 },
+                tracking: ProjectTracking {
+                    tracks: [],
+                    alternate_locations: [],
+                },
                 base: BaseUrl(
                     DisplaySafeUrl {
                         scheme: "https",
@@ -1132,6 +1224,10 @@ mod tests {
                     status: Active,
                     reason: None,
                 },
+                tracking: ProjectTracking {
+                    tracks: [],
+                    alternate_locations: [],
+                },
                 base: BaseUrl(
                     DisplaySafeUrl {
                         scheme: "https",
@@ -1212,6 +1308,10 @@ mod tests {
                 status: Active,
                 reason: None,
             },
+            tracking: ProjectTracking {
+                tracks: [],
+                alternate_locations: [],
+            },
             base: BaseUrl(
                 DisplaySafeUrl {
                     scheme: "https",
@@ -1298,6 +1398,10 @@ mod tests {
                 status: Active,
                 reason: None,
             },
+            tracking: ProjectTracking {
+                tracks: [],
+                alternate_locations: [],
+            },
             base: BaseUrl(
                 DisplaySafeUrl {
                     scheme: "https",
@@ -1405,6 +1509,10 @@ mod tests {
                 status: Active,
                 reason: None,
             },
+            tracking: ProjectTracking {
+                tracks: [],
+                alternate_locations: [],
+            },
             base: BaseUrl(
                 DisplaySafeUrl {
                     scheme: "https",
@@ -1482,6 +1590,10 @@ mod tests {
                 status: Active,
                 reason: None,
             },
+            tracking: ProjectTracking {
+                tracks: [],
+                alternate_locations: [],
+            },
             base: BaseUrl(
                 DisplaySafeUrl {
                     scheme: "https",
@@ -1911,6 +2023,44 @@ mod tests {
         ");
     }
 
+    /// Test parsing PEP 708 tracking metadata.
+    #[test]
+    fn parse_simple_detail_with_tracking() {
+        let text = r#"
+<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta name="pypi:repository-version" content="1.2">
+    <meta name="pypi:tracks" content="https://pypi.org/simple/holygrail/">
+    <meta name="pypi:alternate-locations" content="https://pypi.org/simple/holygrail/">
+    <meta name="pypi:alternate-locations" content="https://example.com/simple/holygrail/">
+    <title>Links for holygrail</title>
+</head>
+<body>
+    <h1>Links for holygrail</h1>
+    <a href="https://example.com/holygrail">holygrail-1.0.tar.gz</a>
+    <br/>
+</body>
+</html>
+        "#;
+
+        let result = SimpleDetailHTML::parse(
+            text,
+            &DisplaySafeUrl::parse("https://example.com/simple/holygrail/").unwrap(),
+        );
+        insta::assert_debug_snapshot!(result.unwrap().tracking, @r#"
+        ProjectTracking {
+            tracks: [
+                "https://pypi.org/simple/holygrail/",
+            ],
+            alternate_locations: [
+                "https://pypi.org/simple/holygrail/",
+                "https://example.com/simple/holygrail/",
+            ],
+        }
+        "#);
+    }
+
     /// Test parsing Simple API index (root) HTML.
     #[test]
     fn parse_simple_index() {
@@ -1,8 +1,8 @@
 pub use base_client::{
     AuthIntegration, BaseClient, BaseClientBuilder, ClientBuildError, DEFAULT_CONNECT_TIMEOUT,
-    DEFAULT_MAX_REDIRECTS, DEFAULT_READ_TIMEOUT, DEFAULT_READ_TIMEOUT_UPLOAD, DEFAULT_RETRIES,
-    ExtraMiddleware, RedirectClientWithMiddleware, RedirectPolicy, RequestBuilder,
-    RetryParsingError, fetch_with_url_fallback,
+    DEFAULT_MAX_REDIRECTS, DEFAULT_MAX_SIMPLE_RESPONSE_SIZE, DEFAULT_READ_TIMEOUT,
+    DEFAULT_READ_TIMEOUT_UPLOAD, DEFAULT_RETRIES, ExtraMiddleware, RedirectClientWithMiddleware,
+    RedirectPolicy, RequestBuilder, RetryParsingError, fetch_with_url_fallback,
 };
 pub use cached_client::{CacheControl, CachedClient, CachedClientError, DataWithCachePolicy};
 pub use error::{Error, ErrorKind, ProblemDetails, WrappedReqwestError};
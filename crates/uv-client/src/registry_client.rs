@@ -5,6 +5,7 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use async_http_range_reader::AsyncHttpRangeReader;
+use encoding_rs::{Encoding, UTF_8};
 use futures::{FutureExt, StreamExt, TryStreamExt};
 use http::{HeaderMap, StatusCode};
 use itertools::Either;
@@ -30,13 +31,16 @@ use uv_normalize::PackageName;
 use uv_pep440::{Version, VersionSpecifiers};
 use uv_pep508::MarkerEnvironment;
 use uv_platform_tags::Platform;
-use uv_pypi_types::{HashAlgorithm, HashDigest, HashDigests, ProjectStatus, Yanked};
+use uv_pypi_types::{
+    HashAlgorithm, HashDigest, HashDigests, ProjectStatus, ProjectTracking, Yanked,
+};
 use uv_pypi_types::{
     PypiSimpleDetail, PypiSimpleIndex, PyxSimpleDetail, PyxSimpleIndex, ResolutionMetadata,
 };
 use uv_redacted::DisplaySafeUrl;
 use uv_small_str::SmallString;
 use uv_torch::TorchStrategy;
+use uv_warnings::warn_user_once;
 
 use crate::base_client::{BaseClientBuilder, ClientBuildError, ExtraMiddleware, RedirectPolicy};
 use crate::cached_client::CacheControl;
@@ -179,6 +183,9 @@ impl<'a> RegistryClientBuilder<'a> {
     ) -> Result<RegistryClient, ClientBuildError> {
         self.cache_index_credentials()?;
 
+        let strict_index_tracking = self.base_client_builder.is_strict_index_tracking();
+        let max_simple_response_size = self.base_client_builder.simple_response_size_limit();
+
         // Wrap in any relevant middleware and handle connectivity.
         let builder = self
             .base_client_builder
@@ -198,7 +205,9 @@ impl<'a> RegistryClientBuilder<'a> {
         Ok(RegistryClient {
             indexes: self.index_locations,
             index_strategy: self.index_strategy,
+            strict_index_tracking,
             torch_backend: self.torch_backend,
+            max_simple_response_size,
             cache: self.cache,
             connectivity,
             client,
@@ -216,8 +225,13 @@ pub struct RegistryClient {
     indexes: IndexLocations,
     /// The strategy to use when fetching across multiple indexes.
     index_strategy: IndexStrategy,
+    /// Whether to error, rather than warn, when a package is served by multiple indexes that
+    /// don't declare each other as alternate locations.
+    strict_index_tracking: bool,
     /// The strategy to use when selecting a PyTorch backend, if any.
     torch_backend: Option<TorchStrategy>,
+    /// The maximum size of a Simple API response, in bytes.
+    max_simple_response_size: u64,
     /// The underlying HTTP client.
     client: CachedClient,
     /// Used for the remote wheel METADATA cache.
@@ -332,7 +346,8 @@ impl RegistryClient {
 
         let mut results = Vec::new();
 
-        match self.index_strategy_for(package_name) {
+        let index_strategy = self.index_strategy_for(package_name);
+        match index_strategy {
             // If we're searching for the first index that contains the package, fetch serially.
             IndexStrategy::FirstIndex => {
                 for index in indexes {
@@ -428,9 +443,74 @@ impl RegistryClient {
             };
         }
 
+        // When combining distributions across indexes, verify that the indexes agree that they
+        // serve the same project.
+        if index_strategy == IndexStrategy::UnsafeBestMatch {
+            self.verify_index_tracking(package_name, &results)?;
+        }
+
         Ok(results)
     }
 
+    /// Verify that each pair of indexes serving the given package is related by a `tracks`
+    /// declaration on either index, or by `alternate-locations` declarations on both, per [PEP 708].
+    ///
+    /// Merging a project across indexes that don't vouch for each other is what enables
+    /// dependency confusion attacks, so we warn when it happens (or error, if
+    /// `--strict-index-tracking` is enabled). Without `--strict-index-tracking`, the check only
+    /// applies if at least one of the indexes publishes tracking information for the project.
+    ///
+    /// [PEP 708]: https://peps.python.org/pep-0708/
+    fn verify_index_tracking(
+        &self,
+        package_name: &PackageName,
+        results: &[(&IndexUrl, MetadataFormat)],
+    ) -> Result<(), Error> {
+        let projects = results
+            .iter()
+            .filter_map(|(index, metadata)| match metadata {
+                MetadataFormat::Simple(metadata) => Some((index, metadata.tracking())),
+                MetadataFormat::Flat(_) => None,
+            })
+            .map(|(index, tracking)| Ok((*index, project_url(index, package_name)?, tracking)))
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        // Unless strict verification was requested, only verify projects for which at least one
+        // index has opted in by publishing tracking information.
+        if !self.strict_index_tracking
+            && projects.iter().all(|(_, _, tracking)| tracking.is_empty())
+        {
+            return Ok(());
+        }
+
+        for (offset, (first, first_url, first_tracking)) in projects.iter().enumerate() {
+            for (second, second_url, second_tracking) in &projects[offset + 1..] {
+                // A project may unilaterally track another, but alternate locations only apply if
+                // both projects declare each other.
+                if first_tracking.is_tracking(second_url.as_str())
+                    || second_tracking.is_tracking(first_url.as_str())
+                    || (first_tracking.is_alternate_location(second_url.as_str())
+                        && second_tracking.is_alternate_location(first_url.as_str()))
+                {
+                    continue;
+                }
+                if self.strict_index_tracking {
+                    return Err(ErrorKind::UntrackedIndexes {
+                        package_name: package_name.clone(),
+                        first: Box::new((*first).clone()),
+                        second: Box::new((*second).clone()),
+                    }
+                    .into());
+                }
+                warn_user_once!(
+                    "`{package_name}` is available on both `{first}` and `{second}`, but the indexes don't declare each other as alternate locations; if this is unexpected, it may indicate a dependency confusion attack (use `--strict-index-tracking` to make this an error)"
+                );
+            }
+        }
+
+        Ok(())
+    }
+
     /// Fetch and combine entries for a package from the configured legacy `--find-links` locations.
     #[instrument(skip_all, fields(package = % package_name))]
     pub async fn find_links_entries(
@@ -509,15 +589,7 @@ impl RegistryClient {
         capabilities: &IndexCapabilities,
         status_code_strategy: &IndexStatusCodeStrategy,
     ) -> Result<SimpleMetadataSearchOutcome, Error> {
-        // Format the URL for PyPI.
-        let mut url = index.url().clone();
-        url.path_segments_mut()
-            .map_err(|()| ErrorKind::CannotBeABase(index.url().clone()))?
-            .pop_if_empty()
-            .push(package_name.as_ref())
-            // The URL *must* end in a trailing slash for proper relative path behavior
-            // ref https://github.com/servo/rust-url/issues/333
-            .push("");
+        let url = project_url(index, package_name)?;
 
         trace!("Fetching metadata for {package_name} from {url}");
 
@@ -534,7 +606,7 @@ impl RegistryClient {
             }
             Connectivity::Online => CacheControl::from(
                 self.cache
-                    .freshness(&cache_entry, Some(package_name), None)
+                    .index_freshness(&cache_entry, Some(index), Some(package_name))
                     .map_err(ErrorKind::Io)?,
             ),
             Connectivity::Offline => CacheControl::AllowStale,
@@ -572,6 +644,14 @@ impl RegistryClient {
                             return Err(err);
                         }
                     }
+                    if status_code == StatusCode::UNAUTHORIZED {
+                        // Retain the credential sources that were consulted, to explain the
+                        // failure to the user.
+                        capabilities.set_credential_lookups(
+                            index.clone(),
+                            self.credentials_cache().lookups(&url),
+                        );
+                    }
                     Ok(SimpleMetadataSearchOutcome::from(decision))
                 }
 
@@ -639,13 +719,7 @@ impl RegistryClient {
 
                 let unarchived = match media_type {
                     MediaType::PyxV1Msgpack => {
-                        let bytes = response.bytes().await.map_err(|err| {
-                            ErrorKind::from_reqwest(
-                                url.clone(),
-                                err,
-                                self.client.certificate_source(),
-                            )
-                        })?;
+                        let bytes = self.read_simple_response(response, &url).await?;
                         let data: PyxSimpleDetail = rmp_serde::from_slice(bytes.as_ref())
                             .map_err(|err| Error::from_msgpack_err(err, url.clone()))?;
 
@@ -656,15 +730,13 @@ impl RegistryClient {
                             data.project_status,
                             &url,
                         )
+                        .with_tracking(ProjectTracking {
+                            tracks: data.tracks,
+                            alternate_locations: data.alternate_locations,
+                        })
                     }
                     MediaType::PyxV1Json => {
-                        let bytes = response.bytes().await.map_err(|err| {
-                            ErrorKind::from_reqwest(
-                                url.clone(),
-                                err,
-                                self.client.certificate_source(),
-                            )
-                        })?;
+                        let bytes = self.read_simple_response(response, &url).await?;
                         let data: PyxSimpleDetail = serde_json::from_slice(bytes.as_ref())
                             .map_err(|err| Error::from_json_err(err, url.clone()))?;
 
@@ -675,15 +747,13 @@ impl RegistryClient {
                             data.project_status,
                             &url,
                         )
+                        .with_tracking(ProjectTracking {
+                            tracks: data.tracks,
+                            alternate_locations: data.alternate_locations,
+                        })
                     }
                     MediaType::PypiV1Json => {
-                        let bytes = response.bytes().await.map_err(|err| {
-                            ErrorKind::from_reqwest(
-                                url.clone(),
-                                err,
-                                self.client.certificate_source(),
-                            )
-                        })?;
+                        let bytes = self.read_simple_response(response, &url).await?;
 
                         let data: PypiSimpleDetail = serde_json::from_slice(bytes.as_ref())
                             .map_err(|err| Error::from_json_err(err, url.clone()))?;
@@ -694,15 +764,13 @@ impl RegistryClient {
                             data.project_status,
                             &url,
                         )
+                        .with_tracking(ProjectTracking {
+                            tracks: data.tracks,
+                            alternate_locations: data.alternate_locations,
+                        })
                     }
                     MediaType::PypiV1Html | MediaType::TextHtml => {
-                        let text = response.text().await.map_err(|err| {
-                            ErrorKind::from_reqwest(
-                                url.clone(),
-                                err,
-                                self.client.certificate_source(),
-                            )
-                        })?;
+                        let text = self.read_simple_html_response(response, &url).await?;
                         SimpleDetailMetadata::from_html(&text, package_name, &url)?
                     }
                 };
@@ -723,6 +791,60 @@ impl RegistryClient {
         Ok(simple)
     }
 
+    /// Read the body of a Simple API response, enforcing the maximum response size.
+    ///
+    /// The limit applies to the decoded body, such that compressed responses can't exceed it.
+    async fn read_simple_response(
+        &self,
+        mut response: Response,
+        url: &DisplaySafeUrl,
+    ) -> Result<Vec<u8>, Error> {
+        let too_large = || {
+            Error::from(ErrorKind::SimpleResponseTooLarge(
+                url.clone(),
+                self.max_simple_response_size,
+            ))
+        };
+        if response
+            .content_length()
+            .is_some_and(|content_length| content_length > self.max_simple_response_size)
+        {
+            return Err(too_large());
+        }
+
+        let mut body = Vec::new();
+        while let Some(chunk) = response.chunk().await.map_err(|err| {
+            ErrorKind::from_reqwest(url.clone(), err, self.client.certificate_source())
+        })? {
+            if (body.len() + chunk.len()) as u64 > self.max_simple_response_size {
+                return Err(too_large());
+            }
+            body.extend_from_slice(&chunk);
+        }
+        Ok(body)
+    }
+
+    /// Read the body of an HTML Simple API response as text, enforcing the maximum response size.
+    ///
+    /// The body is decoded using the `charset` of the `Content-Type` header (or a byte order
+    /// mark), defaulting to UTF-8, with malformed sequences replaced.
+    async fn read_simple_html_response(
+        &self,
+        response: Response,
+        url: &DisplaySafeUrl,
+    ) -> Result<String, Error> {
+        let encoding = response
+            .headers()
+            .get(http::header::CONTENT_TYPE)
+            .and_then(|content_type| content_type.to_str().ok())
+            .and_then(charset)
+            .and_then(|charset| Encoding::for_label(charset.as_bytes()))
+            .unwrap_or(UTF_8);
+        let bytes = self.read_simple_response(response, url).await?;
+        let (text, ..) = encoding.decode(&bytes);
+        Ok(text.into_owned())
+    }
+
     /// Fetch the [`SimpleDetailMetadata`] from a local file, using a PEP 503-compatible directory
     /// structure.
     async fn fetch_local_simple_detail(
@@ -807,7 +929,7 @@ impl RegistryClient {
             }
             Connectivity::Online => CacheControl::from(
                 self.cache
-                    .freshness(&cache_entry, None, None)
+                    .index_freshness(&cache_entry, Some(index), None)
                     .map_err(ErrorKind::Io)?,
             ),
             Connectivity::Offline => CacheControl::AllowStale,
@@ -836,49 +958,25 @@ impl RegistryClient {
 
                 let metadata = match media_type {
                     MediaType::PyxV1Msgpack => {
-                        let bytes = response.bytes().await.map_err(|err| {
-                            ErrorKind::from_reqwest(
-                                url.clone(),
-                                err,
-                                self.client.certificate_source(),
-                            )
-                        })?;
+                        let bytes = self.read_simple_response(response, &url).await?;
                         let data: PyxSimpleIndex = rmp_serde::from_slice(bytes.as_ref())
                             .map_err(|err| Error::from_msgpack_err(err, url.clone()))?;
                         SimpleIndexMetadata::from_pyx_index(data)
                     }
                     MediaType::PyxV1Json => {
-                        let bytes = response.bytes().await.map_err(|err| {
-                            ErrorKind::from_reqwest(
-                                url.clone(),
-                                err,
-                                self.client.certificate_source(),
-                            )
-                        })?;
+                        let bytes = self.read_simple_response(response, &url).await?;
                         let data: PyxSimpleIndex = serde_json::from_slice(bytes.as_ref())
                             .map_err(|err| Error::from_json_err(err, url.clone()))?;
                         SimpleIndexMetadata::from_pyx_index(data)
                     }
                     MediaType::PypiV1Json => {
-                        let bytes = response.bytes().await.map_err(|err| {
-                            ErrorKind::from_reqwest(
-                                url.clone(),
-                                err,
-                                self.client.certificate_source(),
-                            )
-                        })?;
+                        let bytes = self.read_simple_response(response, &url).await?;
                         let data: PypiSimpleIndex = serde_json::from_slice(bytes.as_ref())
                             .map_err(|err| Error::from_json_err(err, url.clone()))?;
                         SimpleIndexMetadata::from_pypi_index(data)
                     }
                     MediaType::PypiV1Html | MediaType::TextHtml => {
-                        let text = response.text().await.map_err(|err| {
-                            ErrorKind::from_reqwest(
-                                url.clone(),
-                                err,
-                                self.client.certificate_source(),
-                            )
-                        })?;
+                        let text = self.read_simple_html_response(response, &url).await?;
                         SimpleIndexMetadata::from_html(&text, &url)?
                     }
                 };
@@ -959,6 +1057,7 @@ impl RegistryClient {
                 let wheel = wheels.best_wheel();
 
                 let url = wheel.file.url.to_url().map_err(ErrorKind::InvalidUrl)?;
+                let url = self.indexes.rewrite_file_url_for(&wheel.index, url);
                 let location = if url.scheme() == "file" {
                     let path = url
                         .to_file_path()
@@ -1576,6 +1675,19 @@ impl From<&CachedHashDigests> for HashDigests {
     }
 }
 
+/// Return the Simple API URL for the given package on the given index.
+fn project_url(index: &IndexUrl, package_name: &PackageName) -> Result<DisplaySafeUrl, Error> {
+    let mut url = index.url().clone();
+    url.path_segments_mut()
+        .map_err(|()| ErrorKind::CannotBeABase(index.url().clone()))?
+        .pop_if_empty()
+        .push(package_name.as_ref())
+        // The URL *must* end in a trailing slash for proper relative path behavior
+        // ref https://github.com/servo/rust-url/issues/333
+        .push("");
+    Ok(url)
+}
+
 /// Decodes a lowercase hexadecimal digest of exactly `N` bytes.
 ///
 /// Rejecting non-canonical spellings lets [`CachedHashDigests::Other`] preserve their original
@@ -1658,6 +1770,7 @@ impl SimpleIndexMetadata {
 #[rkyv(derive(Debug))]
 pub struct SimpleDetailMetadata {
     project_status: ProjectStatus,
+    tracking: ProjectTracking,
     versions: Vec<SimpleDetailMetadatum>,
 }
 
@@ -1739,6 +1852,7 @@ impl SimpleDetailMetadata {
                 })
                 .collect(),
             project_status,
+            tracking: ProjectTracking::default(),
         }
     }
 
@@ -1799,6 +1913,7 @@ impl SimpleDetailMetadata {
                             requires_python: metadata.requires_python,
                             provides_extra: metadata.provides_extra,
                             dynamic: false,
+                            metadata_version: None,
                         })
                     });
                     SimpleDetailMetadatum {
@@ -1809,28 +1924,35 @@ impl SimpleDetailMetadata {
                 })
                 .collect(),
             project_status,
+            tracking: ProjectTracking::default(),
         }
     }
 
+    /// Attach the PEP 708 [`ProjectTracking`] information for the project.
+    #[must_use]
+    fn with_tracking(mut self, tracking: ProjectTracking) -> Self {
+        self.tracking = tracking;
+        self
+    }
+
     /// Read the [`SimpleDetailMetadata`] from an HTML index.
-    fn from_html(
+    pub fn from_html(
         text: &str,
         package_name: &PackageName,
         url: &DisplaySafeUrl,
     ) -> Result<Self, Error> {
         let SimpleDetailHTML {
             project_status,
+            tracking,
             base,
             files,
         } = SimpleDetailHTML::parse(text, url)
             .map_err(|err| Error::from_html_err(err, url.clone()))?;
 
-        Ok(Self::from_pypi_files(
-            files,
-            package_name,
-            project_status,
-            base.as_url(),
-        ))
+        Ok(
+            Self::from_pypi_files(files, package_name, project_status, base.as_url())
+                .with_tracking(tracking),
+        )
     }
 }
 
@@ -1858,6 +1980,13 @@ impl ArchivedSimpleDetailMetadata {
     pub fn project_status(&self) -> &rkyv::Archived<ProjectStatus> {
         &self.project_status
     }
+
+    /// Return the project-level [PEP 708] tracking information for this package.
+    ///
+    /// [PEP 708]: https://peps.python.org/pep-0708/
+    pub fn tracking(&self) -> &rkyv::Archived<ProjectTracking> {
+        &self.tracking
+    }
 }
 
 #[derive(Debug)]
@@ -1869,6 +1998,16 @@ enum MediaType {
     TextHtml,
 }
 
+/// Extract the `charset` parameter from a `Content-Type` header value, if any.
+fn charset(content_type: &str) -> Option<&str> {
+    content_type.split(';').skip(1).find_map(|parameter| {
+        let (name, value) = parameter.split_once('=')?;
+        name.trim()
+            .eq_ignore_ascii_case("charset")
+            .then(|| value.trim().trim_matches('"'))
+    })
+}
+
 impl MediaType {
     /// Parse a media type from a string, returning `None` if the media type is not supported.
     fn from_str(s: &str) -> Option<Self> {
@@ -1940,8 +2079,9 @@ mod tests {
     use uv_redacted::DisplaySafeUrl;
     use uv_torch::{TorchBackend, TorchSource, TorchStrategy};
 
+    use crate::registry_client::charset;
     use crate::{
-        BaseClientBuilder, Connectivity, RegistryClient, RegistryClientBuilder,
+        BaseClientBuilder, Connectivity, MetadataFormat, RegistryClient, RegistryClientBuilder,
         SimpleDetailMetadata, SimpleDetailMetadatum, html::SimpleDetailHTML,
     };
     use uv_cache::Cache;
@@ -2084,6 +2224,130 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn simple_detail_rejects_oversized_response() -> Result<(), Error> {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path_regex("/simple/validation/?"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_raw(
+                    format!(
+                        r#"{{"meta": {{"api-version": "1.0"}}, "name": "validation", "files": [], "padding": "{}"}}"#,
+                        "x".repeat(2048)
+                    ),
+                    "application/vnd.pypi.simple.v1+json",
+                ),
+            )
+            .mount(&server)
+            .await;
+
+        let cache = Cache::temp()?;
+        let index = IndexUrl::from_str(&format!("{}/simple", server.uri()))?;
+        let registry_client = RegistryClientBuilder::new(
+            BaseClientBuilder::default().max_simple_response_size(1024),
+            cache,
+        )
+        .build()?;
+
+        let error = registry_client
+            .simple_detail(
+                &PackageName::from_str("validation")?,
+                Some(IndexMetadataRef {
+                    url: &index,
+                    format: IndexFormat::Simple,
+                }),
+                &IndexCapabilities::default(),
+                &Semaphore::new(1),
+            )
+            .await
+            .expect_err("oversized response should be rejected");
+        assert!(
+            matches!(
+                error.kind(),
+                crate::ErrorKind::SimpleResponseTooLarge(_, 1024)
+            ),
+            "unexpected error: {error}"
+        );
+
+        // The same response is accepted within the default limit.
+        let registry_client =
+            RegistryClientBuilder::new(BaseClientBuilder::default(), Cache::temp()?).build()?;
+        let results = registry_client
+            .simple_detail(
+                &PackageName::from_str("validation")?,
+                Some(IndexMetadataRef {
+                    url: &index,
+                    format: IndexFormat::Simple,
+                }),
+                &IndexCapabilities::default(),
+                &Semaphore::new(1),
+            )
+            .await?;
+        assert_eq!(results.len(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn simple_detail_decodes_html_charset() -> Result<(), Error> {
+        let html = r#"<!DOCTYPE html>
+<html>
+  <body>
+    <h1>Links for validation</h1>
+    <a href="validation-2.0.0-py3-none-any.whl">validation-2.0.0-py3-none-any.whl</a>
+  </body>
+</html>"#;
+        // Serve the page as UTF-16, which is garbled if decoded as UTF-8.
+        let body = html
+            .encode_utf16()
+            .flat_map(u16::to_le_bytes)
+            .collect::<Vec<_>>();
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path_regex("/simple/validation/?"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_raw(body, "text/html; charset=utf-16le"),
+            )
+            .mount(&server)
+            .await;
+
+        let index = IndexUrl::from_str(&format!("{}/simple", server.uri()))?;
+        let registry_client =
+            RegistryClientBuilder::new(BaseClientBuilder::default(), Cache::temp()?).build()?;
+        let results = registry_client
+            .simple_detail(
+                &PackageName::from_str("validation")?,
+                Some(IndexMetadataRef {
+                    url: &index,
+                    format: IndexFormat::Simple,
+                }),
+                &IndexCapabilities::default(),
+                &Semaphore::new(1),
+            )
+            .await?;
+        let [(_, MetadataFormat::Simple(metadata))] = results.as_slice() else {
+            panic!("expected a single Simple API response");
+        };
+        assert_eq!(metadata.versions.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn content_type_charset() {
+        assert_eq!(charset("text/html"), None);
+        assert_eq!(charset("text/html; charset=utf-8"), Some("utf-8"));
+        assert_eq!(
+            charset("text/html;Charset=\"ISO-8859-1\""),
+            Some("ISO-8859-1")
+        );
+        assert_eq!(
+            charset("text/html; q=0.01; charset = latin1"),
+            Some("latin1")
+        );
+    }
+
     #[tokio::test]
     async fn test_redirect_to_server_with_credentials() -> Result<(), Error> {
         let username = "user";
@@ -2386,6 +2650,10 @@ mod tests {
                 status: Archived,
                 reason: None,
             },
+            tracking: ProjectTracking {
+                tracks: [],
+                alternate_locations: [],
+            },
             versions: [
                 SimpleDetailMetadatum {
                     version: "2.1.1",
@@ -2458,6 +2726,10 @@ mod tests {
                 status: Archived,
                 reason: None,
             },
+            tracking: ProjectTracking {
+                tracks: [],
+                alternate_locations: [],
+            },
             versions: [
                 SimpleDetailMetadatum {
                     version: "2.1.1",
@@ -2529,6 +2801,7 @@ mod tests {
             .unwrap();
         let SimpleDetailHTML {
             project_status: _,
+            tracking: _,
             base,
             files,
         } = SimpleDetailHTML::parse(text, &base).unwrap();
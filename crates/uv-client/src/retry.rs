@@ -11,7 +11,6 @@ use reqwest_retry::{
 };
 use rustls::{AlertDescription, Error as RustlsError};
 use tracing::{debug, trace};
-use url::Url;
 
 use uv_redacted::DisplaySafeUrl;
 
@@ -32,7 +31,10 @@ impl RetryableStrategy for UvRetryableStrategy {
         if retryable == Some(Retryable::Transient) {
             match res {
                 Ok(response) => {
-                    debug!("Transient request failure for: {}", response.url());
+                    debug!(
+                        "Transient request failure for: {}",
+                        DisplaySafeUrl::ref_cast(response.url())
+                    );
                 }
                 Err(err) => {
                     let context = iter::successors(err.source(), |&err| err.source())
@@ -40,7 +42,10 @@ impl RetryableStrategy for UvRetryableStrategy {
                         .join("\n");
                     debug!(
                         "Transient request failure for {}, retrying: {err}\n{context}",
-                        err.url().map(Url::as_str).unwrap_or("unknown URL")
+                        err.url().map_or_else(
+                            || "unknown URL".to_string(),
+                            |url| DisplaySafeUrl::ref_cast(url).to_string()
+                        )
                     );
                 }
             }
@@ -0,0 +1,39 @@
+use std::path::Path;
+
+use serde::Deserialize;
+
+/// The continuous integration provider to generate a workflow for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum CiProvider {
+    /// Do not generate a workflow.
+    #[default]
+    None,
+    /// Generate a GitHub Actions workflow.
+    Github,
+    /// Generate a GitLab CI/CD pipeline.
+    Gitlab,
+}
+
+impl CiProvider {
+    /// The path of the workflow file, relative to the project root.
+    pub fn workflow_path(self) -> Option<&'static Path> {
+        match self {
+            Self::None => None,
+            Self::Github => Some(Path::new(".github/workflows/ci.yml")),
+            Self::Gitlab => Some(Path::new(".gitlab-ci.yml")),
+        }
+    }
+}
+
+impl std::fmt::Display for CiProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => write!(f, "none"),
+            Self::Github => write!(f, "github"),
+            Self::Gitlab => write!(f, "gitlab"),
+        }
+    }
+}
@@ -0,0 +1,34 @@
+/// Whether to preview edits to `pyproject.toml` files and scripts as a unified diff.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ShowDiff {
+    /// Display a diff of each file before it's written.
+    Enabled,
+    /// Display a diff of each file that would change, without writing anything.
+    Only,
+    /// Write edits without displaying a diff.
+    #[default]
+    Disabled,
+}
+
+impl ShowDiff {
+    /// Determine the [`ShowDiff`] setting based on the command-line arguments.
+    pub fn from_args(show_diff: bool, diff_only: bool) -> Self {
+        if diff_only {
+            Self::Only
+        } else if show_diff {
+            Self::Enabled
+        } else {
+            Self::Disabled
+        }
+    }
+
+    /// Returns `true` if a diff should be displayed for each edited file.
+    pub const fn enabled(self) -> bool {
+        matches!(self, Self::Enabled | Self::Only)
+    }
+
+    /// Returns `true` if edits should be displayed, but not written.
+    pub const fn is_only(self) -> bool {
+        matches!(self, Self::Only)
+    }
+}
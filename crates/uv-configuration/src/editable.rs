@@ -31,6 +31,32 @@ impl EditableMode {
         }
     }
 
+    /// Extend the editable installation strategy with the packages that are configured to be
+    /// installed as non-editable (e.g., via `tool.uv.no-editable-package`).
+    ///
+    /// An explicit `--editable` or `--no-editable` takes precedence over the configured packages.
+    #[must_use]
+    pub fn with_no_editable_packages(
+        editable: Option<Self>,
+        no_editable_package: &[PackageName],
+    ) -> Option<Self> {
+        if no_editable_package.is_empty() {
+            return editable;
+        }
+        match editable {
+            None => Some(Self::NonEditablePackages(no_editable_package.to_vec())),
+            Some(Self::NonEditablePackages(mut packages)) => {
+                for package in no_editable_package {
+                    if !packages.contains(package) {
+                        packages.push(package.clone());
+                    }
+                }
+                Some(Self::NonEditablePackages(packages))
+            }
+            Some(editable) => Some(editable),
+        }
+    }
+
     /// Return the editable override for a specific package, if any.
     pub fn for_package(&self, package_name: &PackageName) -> Option<bool> {
         match self {
@@ -31,6 +31,11 @@ pub struct InstallOptions {
     no_install_package: Vec<PackageName>,
     /// Include only the specified packages in the resolution.
     only_install_package: Vec<PackageName>,
+    /// Omit the specified packages from the installation, assuming they're provided by the
+    /// environment.
+    exclude_package: Vec<PackageName>,
+    /// Skip verifying that the environment provides the excluded packages.
+    exclude_unchecked: bool,
 }
 
 impl InstallOptions {
@@ -54,9 +59,34 @@ impl InstallOptions {
             only_install_local,
             no_install_package,
             only_install_package,
+            exclude_package: Vec::new(),
+            exclude_unchecked: false,
         }
     }
 
+    /// Omit the given packages from the installation, on the assumption that the environment
+    /// already provides them.
+    ///
+    /// Unlike `--no-install-package`, excluded packages aren't considered by
+    /// [`InstallOptions::include_package`], since they're omitted after the environment has been
+    /// verified to provide them.
+    #[must_use]
+    pub fn with_exclude_package(mut self, packages: Vec<PackageName>, unchecked: bool) -> Self {
+        self.exclude_package = packages;
+        self.exclude_unchecked = unchecked;
+        self
+    }
+
+    /// Returns the packages to omit from the installation via `--exclude-package`.
+    pub fn exclude_package(&self) -> &[PackageName] {
+        &self.exclude_package
+    }
+
+    /// Returns `true` if the excluded packages should not be verified against the environment.
+    pub fn exclude_unchecked(&self) -> bool {
+        self.exclude_unchecked
+    }
+
     /// Returns `true` if a package passes the install filters.
     pub fn include_package(
         &self,
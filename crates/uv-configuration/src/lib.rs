@@ -1,8 +1,10 @@
 pub use authentication::*;
 pub use build_options::*;
+pub use ci_provider::*;
 pub use concurrency::*;
 pub use constraints::*;
 pub use dependency_groups::*;
+pub use diff::*;
 pub use dry_run::*;
 pub use editable::*;
 pub use env_file::*;
@@ -27,9 +29,11 @@ pub use vcs::*;
 
 mod authentication;
 mod build_options;
+mod ci_provider;
 mod concurrency;
 mod constraints;
 mod dependency_groups;
+mod diff;
 mod dry_run;
 mod editable;
 mod env_file;
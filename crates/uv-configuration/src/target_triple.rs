@@ -23,15 +23,23 @@ pub enum TargetTriple {
     Macos,
 
     /// A 64-bit x86 Windows target.
-    #[cfg_attr(feature = "clap", value(name = "x86_64-pc-windows-msvc"))]
+    #[cfg_attr(
+        feature = "clap",
+        value(name = "x86_64-pc-windows-msvc", alias = "windows-x86_64")
+    )]
     #[serde(rename = "x86_64-pc-windows-msvc")]
     #[serde(alias = "x8664-pc-windows-msvc")]
+    #[serde(alias = "windows-x86_64")]
     X8664PcWindowsMsvc,
 
     /// An ARM64 Windows target.
-    #[cfg_attr(feature = "clap", value(name = "aarch64-pc-windows-msvc"))]
+    #[cfg_attr(
+        feature = "clap",
+        value(name = "aarch64-pc-windows-msvc", alias = "windows-arm64")
+    )]
     #[serde(rename = "aarch64-pc-windows-msvc")]
     #[serde(alias = "arm64-pc-windows-msvc")]
+    #[serde(alias = "windows-arm64")]
     Aarch64PcWindowsMsvc,
 
     /// A 32-bit x86 Windows target.
@@ -40,42 +48,66 @@ pub enum TargetTriple {
     I686PcWindowsMsvc,
 
     /// An x86 Linux target. Equivalent to `x86_64-manylinux_2_28`.
-    #[cfg_attr(feature = "clap", value(name = "x86_64-unknown-linux-gnu"))]
+    #[cfg_attr(
+        feature = "clap",
+        value(name = "x86_64-unknown-linux-gnu", alias = "linux-x86_64")
+    )]
     #[serde(rename = "x86_64-unknown-linux-gnu")]
     #[serde(alias = "x8664-unknown-linux-gnu")]
+    #[serde(alias = "linux-x86_64")]
     X8664UnknownLinuxGnu,
 
     /// An ARM-based macOS target, as seen on Apple Silicon devices
     ///
     /// By default, assumes the least-recent, non-EOL macOS version (13.0), but respects
     /// the `MACOSX_DEPLOYMENT_TARGET` environment variable if set.
-    #[cfg_attr(feature = "clap", value(name = "aarch64-apple-darwin"))]
+    #[cfg_attr(
+        feature = "clap",
+        value(name = "aarch64-apple-darwin", alias = "macos-arm64")
+    )]
     #[serde(rename = "aarch64-apple-darwin")]
+    #[serde(alias = "macos-arm64")]
     Aarch64AppleDarwin,
 
     /// An x86 macOS target.
     ///
     /// By default, assumes the least-recent, non-EOL macOS version (13.0), but respects
     /// the `MACOSX_DEPLOYMENT_TARGET` environment variable if set.
-    #[cfg_attr(feature = "clap", value(name = "x86_64-apple-darwin"))]
+    #[cfg_attr(
+        feature = "clap",
+        value(name = "x86_64-apple-darwin", alias = "macos-x86_64")
+    )]
     #[serde(rename = "x86_64-apple-darwin")]
     #[serde(alias = "x8664-apple-darwin")]
+    #[serde(alias = "macos-x86_64")]
     X8664AppleDarwin,
 
     /// An ARM64 Linux target. Equivalent to `aarch64-manylinux_2_28`.
-    #[cfg_attr(feature = "clap", value(name = "aarch64-unknown-linux-gnu"))]
+    #[cfg_attr(
+        feature = "clap",
+        value(name = "aarch64-unknown-linux-gnu", alias = "linux-arm64")
+    )]
     #[serde(rename = "aarch64-unknown-linux-gnu")]
+    #[serde(alias = "linux-arm64")]
     Aarch64UnknownLinuxGnu,
 
     /// An ARM64 Linux target.
-    #[cfg_attr(feature = "clap", value(name = "aarch64-unknown-linux-musl"))]
+    #[cfg_attr(
+        feature = "clap",
+        value(name = "aarch64-unknown-linux-musl", alias = "alpine-arm64")
+    )]
     #[serde(rename = "aarch64-unknown-linux-musl")]
+    #[serde(alias = "alpine-arm64")]
     Aarch64UnknownLinuxMusl,
 
     /// An `x86_64` Linux target.
-    #[cfg_attr(feature = "clap", value(name = "x86_64-unknown-linux-musl"))]
+    #[cfg_attr(
+        feature = "clap",
+        value(name = "x86_64-unknown-linux-musl", alias = "alpine-x86_64")
+    )]
     #[serde(rename = "x86_64-unknown-linux-musl")]
     #[serde(alias = "x8664-unknown-linux-musl")]
+    #[serde(alias = "alpine-x86_64")]
     X8664UnknownLinuxMusl,
 
     /// A RISCV64 Linux target.
@@ -1097,3 +1129,64 @@ fn android_api_level() -> Option<u16> {
 
     Some(api_level)
 }
+
+#[cfg(test)]
+mod tests {
+    use uv_platform_tags::{Tags, TagsOptions};
+
+    use super::*;
+
+    /// Return the highest-priority wheel tag for a CPython interpreter on the given target.
+    fn best_tag(target: TargetTriple, python_version: (u8, u8)) -> String {
+        let tags = Tags::from_env(
+            target.platform(),
+            python_version,
+            "cpython",
+            python_version,
+            TagsOptions {
+                manylinux_compatible: target.manylinux_compatible(),
+                is_cross: true,
+                ..TagsOptions::default()
+            },
+        )
+        .unwrap();
+        tags.to_string().lines().next().unwrap().to_string()
+    }
+
+    #[test]
+    fn container_aliases() {
+        for (alias, expected) in [
+            ("linux-x86_64", TargetTriple::X8664UnknownLinuxGnu),
+            ("linux-arm64", TargetTriple::Aarch64UnknownLinuxGnu),
+            ("alpine-x86_64", TargetTriple::X8664UnknownLinuxMusl),
+            ("alpine-arm64", TargetTriple::Aarch64UnknownLinuxMusl),
+            ("windows-x86_64", TargetTriple::X8664PcWindowsMsvc),
+            ("windows-arm64", TargetTriple::Aarch64PcWindowsMsvc),
+            ("macos-x86_64", TargetTriple::X8664AppleDarwin),
+            ("macos-arm64", TargetTriple::Aarch64AppleDarwin),
+        ] {
+            let target: TargetTriple = serde_json::from_value(alias.into()).unwrap();
+            assert_eq!(target, expected, "unexpected target for `{alias}`");
+        }
+    }
+
+    #[test]
+    fn container_alias_tags() {
+        assert_eq!(
+            best_tag(TargetTriple::Aarch64UnknownLinuxGnu, (3, 12)),
+            "cp312-cp312-manylinux_2_28_aarch64"
+        );
+        assert_eq!(
+            best_tag(TargetTriple::X8664UnknownLinuxMusl, (3, 12)),
+            "cp312-cp312-musllinux_1_2_x86_64"
+        );
+        assert_eq!(
+            best_tag(TargetTriple::Aarch64UnknownLinuxMusl, (3, 13)),
+            "cp313-cp313-musllinux_1_2_aarch64"
+        );
+        assert_eq!(
+            best_tag(TargetTriple::Aarch64PcWindowsMsvc, (3, 12)),
+            "cp312-cp312-win_arm64"
+        );
+    }
+}
@@ -23,8 +23,8 @@ use uv_configuration::{BuildOutput, Concurrency, Excludes};
 use uv_distribution::DistributionDatabase;
 use uv_distribution_filename::DistFilename;
 use uv_distribution_types::{
-    CachedDist, ConfigSettings, DependencyMetadata, ExtraBuildRequires, ExtraBuildVariables,
-    Identifier, IndexCapabilities, IndexLocations, IsBuildBackendError, Name,
+    BuildEnvPassthrough, CachedDist, ConfigSettings, DependencyMetadata, ExtraBuildRequires,
+    ExtraBuildVariables, Identifier, IndexCapabilities, IndexLocations, IsBuildBackendError, Name,
     PackageConfigSettings, Requirement, Resolution, SourceDist, VersionOrUrlRef,
 };
 use uv_git::GitResolver;
@@ -127,6 +127,7 @@ pub struct BuildDispatch<'a> {
     exclude_newer: ExcludeNewer,
     source_build_context: SourceBuildContext,
     build_extra_env_vars: FxHashMap<OsString, OsString>,
+    build_env_passthrough: BuildEnvPassthrough,
     sources: NoSources,
     source_tree_editable_policy: SourceTreeEditablePolicy,
     workspace_cache: WorkspaceCache,
@@ -181,6 +182,7 @@ impl<'a> BuildDispatch<'a> {
             exclude_newer,
             source_build_context: SourceBuildContext::new(concurrency.builds_semaphore.clone()),
             build_extra_env_vars: FxHashMap::default(),
+            build_env_passthrough: BuildEnvPassthrough::default(),
             sources,
             source_tree_editable_policy,
             workspace_cache,
@@ -203,6 +205,17 @@ impl<'a> BuildDispatch<'a> {
             .collect();
         self
     }
+
+    /// Set the environment variables to pass through from the parent environment when building a
+    /// source distribution.
+    #[must_use]
+    pub fn with_build_env_passthrough(
+        mut self,
+        build_env_passthrough: BuildEnvPassthrough,
+    ) -> Self {
+        self.build_env_passthrough = build_env_passthrough;
+        self
+    }
 }
 
 #[allow(refining_impl_trait)]
@@ -573,6 +586,7 @@ impl BuildContext for BuildDispatch<'_> {
             &build_stack,
             build_kind,
             environment_variables,
+            &self.build_env_passthrough,
             build_output,
             self.client.credentials_cache(),
         )
@@ -684,6 +698,11 @@ impl SharedState {
         &self.git
     }
 
+    /// Return the [`IndexCapabilities`] used by the [`SharedState`].
+    pub fn capabilities(&self) -> &IndexCapabilities {
+        &self.capabilities
+    }
+
     /// Return the [`InMemoryIndex`] used by the [`SharedState`].
     pub fn index(&self) -> &InMemoryIndex {
         &self.index
@@ -185,3 +185,33 @@ impl CacheKey for ExtraBuildVariables {
         }
     }
 }
+
+/// The environment variables to pass through from the parent environment to build backends.
+///
+/// If neither a global nor a package-specific list is provided, build backends inherit the entire
+/// parent environment.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BuildEnvPassthrough {
+    /// The variables to pass through for all packages.
+    global: Option<Vec<String>>,
+    /// The variables to pass through for specific packages, overriding the global list.
+    package: BTreeMap<PackageName, Vec<String>>,
+}
+
+impl BuildEnvPassthrough {
+    /// Determine the pass-through policy from the user-provided settings.
+    pub fn from_args(
+        global: Option<Vec<String>>,
+        package: BTreeMap<PackageName, Vec<String>>,
+    ) -> Self {
+        Self { global, package }
+    }
+
+    /// Returns the variables to pass through when building the given package, or `None` if the
+    /// build backend should inherit the entire parent environment.
+    pub fn for_package(&self, name: Option<&PackageName>) -> Option<&[String]> {
+        name.and_then(|name| self.package.get(name))
+            .or(self.global.as_ref())
+            .map(Vec::as_slice)
+    }
+}
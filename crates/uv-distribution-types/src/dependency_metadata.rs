@@ -51,6 +51,7 @@ impl DependencyMetadata {
                 requires_python: metadata.requires_python.clone(),
                 provides_extra: metadata.provides_extra.clone(),
                 dynamic: false,
+                metadata_version: None,
             })
         } else {
             // If no version was requested (i.e., it's a direct URL dependency), allow a single
@@ -72,6 +73,7 @@ impl DependencyMetadata {
                 requires_python: metadata.requires_python.clone(),
                 provides_extra: metadata.provides_extra.clone(),
                 dynamic: false,
+                metadata_version: None,
             })
         }
     }
@@ -1,5 +1,8 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+use uv_fs::Simplified;
 use uv_normalize::PackageName;
+use uv_pep508::VerbatimUrl;
 use uv_redacted::DisplaySafeUrl;
 
 #[derive(thiserror::Error, Debug)]
@@ -22,6 +25,27 @@ pub enum Error {
     #[error("Distribution not found at: {0}")]
     NotFound(DisplaySafeUrl),
 
+    #[error("Distribution not found at: `{0}` (is the network share reachable?)")]
+    NetworkPathNotFound(String),
+
     #[error("Requested package name `{0}` does not match `{1}` in the distribution filename: {2}")]
     PackageNameMismatch(PackageName, PackageName, String),
 }
+
+impl Error {
+    /// Create an error for a local distribution that doesn't exist at `install_path`.
+    ///
+    /// Distributions on a network share (e.g., `\\server\share\...`) are reported using the path
+    /// as provided by the user, rather than the `file://server/share/...` URL.
+    pub fn not_found(url: &VerbatimUrl, install_path: &Path) -> Self {
+        if url.host_str().is_some_and(|host| !host.is_empty()) {
+            Self::NetworkPathNotFound(
+                url.given()
+                    .map(ToString::to_string)
+                    .unwrap_or_else(|| install_path.simplified_display().to_string()),
+            )
+        } else {
+            Self::NotFound(url.to_url())
+        }
+    }
+}
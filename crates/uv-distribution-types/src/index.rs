@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize, Serializer};
 use thiserror::Error;
 use url::Url;
 
-use uv_auth::{AuthPolicy, Credentials, CredentialsFromUrlError};
+use uv_auth::{AuthPolicy, AuthProvider, Credentials, CredentialsFromUrlError};
 use uv_pypi_types::HashAlgorithm;
 use uv_redacted::DisplaySafeUrl;
 use uv_small_str::SmallString;
@@ -207,6 +207,24 @@ pub struct Index {
     /// ```
     #[serde(default)]
     pub authenticate: AuthPolicy,
+    /// A built-in credential provider to use for requests to the index.
+    ///
+    /// When set, uv fetches short-lived credentials for the index from the given cloud provider,
+    /// rather than reading them from the URL, a `.netrc` file, or the keyring.
+    ///
+    /// - `azure`: Use the `AZURE_DEVOPS_TOKEN` personal access token if set, and otherwise request
+    ///   a token for Azure Artifacts from the Azure managed identity endpoint.
+    /// - `gcp`: Use Google application default credentials if available, and otherwise request a
+    ///   token from the Google Cloud metadata server.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "my-index"
+    /// url = "https://us-python.pkg.dev/my-project/my-repo/simple"
+    /// auth-provider = "gcp"
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth_provider: Option<AuthProvider>,
     /// Status codes that uv should ignore when deciding whether to continue resolution after a
     /// request to this index fails.
     ///
@@ -269,6 +287,21 @@ pub struct Index {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[cfg_attr(feature = "schemars", schemars(with = "ExcludeNewerOverride"))]
     pub exclude_newer: Option<ExcludeNewerOverride>,
+    /// Rewrite the URLs of files downloaded from this index, e.g., to fetch artifacts from a
+    /// mirror.
+    ///
+    /// Any file URL that starts with `from` will be downloaded from the same path under `to`
+    /// instead. The rewrite is only applied when fetching files; the lockfile retains the URLs
+    /// served by the index, and downloads are still validated against the locked hashes.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "pypi"
+    /// url = "https://pypi.org/simple"
+    /// rewrite-files = { from = "https://files.pythonhosted.org/", to = "https://mirror.internal/pypi-files/" }
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rewrite_files: Option<IndexFileRewrite>,
 }
 
 #[derive(Debug, Error)]
@@ -290,10 +323,12 @@ impl PartialEq for Index {
             format,
             publish_url,
             authenticate,
+            auth_provider,
             ignore_error_codes,
             cache_control,
             hash_algorithm,
             exclude_newer,
+            rewrite_files,
         } = self;
         *url == other.url
             && *name == other.name
@@ -302,10 +337,12 @@ impl PartialEq for Index {
             && *format == other.format
             && *publish_url == other.publish_url
             && *authenticate == other.authenticate
+            && *auth_provider == other.auth_provider
             && *ignore_error_codes == other.ignore_error_codes
             && *cache_control == other.cache_control
             && *hash_algorithm == other.hash_algorithm
             && *exclude_newer == other.exclude_newer
+            && *rewrite_files == other.rewrite_files
     }
 }
 
@@ -328,10 +365,12 @@ impl Ord for Index {
             format,
             publish_url,
             authenticate,
+            auth_provider,
             ignore_error_codes,
             cache_control,
             hash_algorithm,
             exclude_newer,
+            rewrite_files,
         } = self;
         url.cmp(&other.url)
             .then_with(|| name.cmp(&other.name))
@@ -340,10 +379,12 @@ impl Ord for Index {
             .then_with(|| format.cmp(&other.format))
             .then_with(|| publish_url.cmp(&other.publish_url))
             .then_with(|| authenticate.cmp(&other.authenticate))
+            .then_with(|| auth_provider.cmp(&other.auth_provider))
             .then_with(|| ignore_error_codes.cmp(&other.ignore_error_codes))
             .then_with(|| cache_control.cmp(&other.cache_control))
             .then_with(|| hash_algorithm.cmp(&other.hash_algorithm))
             .then_with(|| exclude_newer.cmp(&other.exclude_newer))
+            .then_with(|| rewrite_files.cmp(&other.rewrite_files))
     }
 }
 
@@ -358,10 +399,12 @@ impl std::hash::Hash for Index {
             format,
             publish_url,
             authenticate,
+            auth_provider,
             ignore_error_codes,
             cache_control,
             hash_algorithm,
             exclude_newer,
+            rewrite_files,
         } = self;
         url.hash(state);
         name.hash(state);
@@ -370,10 +413,12 @@ impl std::hash::Hash for Index {
         format.hash(state);
         publish_url.hash(state);
         authenticate.hash(state);
+        auth_provider.hash(state);
         ignore_error_codes.hash(state);
         cache_control.hash(state);
         hash_algorithm.hash(state);
         exclude_newer.hash(state);
+        rewrite_files.hash(state);
     }
 }
 
@@ -426,6 +471,43 @@ impl From<IndexHashAlgorithm> for HashAlgorithm {
     }
 }
 
+/// A rule to rewrite the URLs of files downloaded from an index.
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct IndexFileRewrite {
+    /// The URL prefix to replace.
+    pub from: DisplaySafeUrl,
+    /// The URL prefix to download files from instead.
+    pub to: DisplaySafeUrl,
+}
+
+impl IndexFileRewrite {
+    /// Apply the rewrite to the given URL, returning `None` if the URL doesn't match `from`.
+    pub fn apply(&self, url: &DisplaySafeUrl) -> Option<DisplaySafeUrl> {
+        let suffix = url.as_str().strip_prefix(self.from.as_str())?;
+        // Only match on path segment boundaries, e.g., `https://example.com/a` should not match
+        // `https://example.com/ab`.
+        if !self.from.as_str().ends_with('/')
+            && !suffix.is_empty()
+            && !suffix.starts_with(['/', '?', '#'])
+        {
+            return None;
+        }
+        let to = self.to.as_str();
+        let rewritten = if let Some(suffix) = suffix.strip_prefix('/')
+            && to.ends_with('/')
+        {
+            format!("{to}{suffix}")
+        } else if !to.ends_with('/') && self.from.as_str().ends_with('/') {
+            format!("{to}/{suffix}")
+        } else {
+            format!("{to}{suffix}")
+        };
+        DisplaySafeUrl::parse(&rewritten).ok()
+    }
+}
+
 impl Index {
     /// Initialize an [`Index`] from a pip-style `--index-url`.
     pub fn from_index_url(url: IndexUrl) -> Self {
@@ -438,10 +520,12 @@ impl Index {
             format: IndexFormat::Simple,
             publish_url: None,
             authenticate: AuthPolicy::default(),
+            auth_provider: None,
             ignore_error_codes: None,
             cache_control: None,
             hash_algorithm: None,
             exclude_newer: None,
+            rewrite_files: None,
         }
     }
 
@@ -456,10 +540,12 @@ impl Index {
             format: IndexFormat::Simple,
             publish_url: None,
             authenticate: AuthPolicy::default(),
+            auth_provider: None,
             ignore_error_codes: None,
             cache_control: None,
             hash_algorithm: None,
             exclude_newer: None,
+            rewrite_files: None,
         }
     }
 
@@ -474,10 +560,12 @@ impl Index {
             format: IndexFormat::Flat,
             publish_url: None,
             authenticate: AuthPolicy::default(),
+            auth_provider: None,
             ignore_error_codes: None,
             cache_control: None,
             hash_algorithm: None,
             exclude_newer: None,
+            rewrite_files: None,
         }
     }
 
@@ -600,6 +688,15 @@ impl Index {
     pub(crate) fn exclude_newer(&self) -> Option<&ExcludeNewerOverride> {
         self.exclude_newer.as_ref()
     }
+
+    /// Rewrite the URL of a file served by this index, if a matching `rewrite-files` rule is
+    /// configured.
+    pub(crate) fn rewrite_file_url(&self, url: DisplaySafeUrl) -> DisplaySafeUrl {
+        self.rewrite_files
+            .as_ref()
+            .and_then(|rewrite| rewrite.apply(&url))
+            .unwrap_or(url)
+    }
 }
 
 impl From<IndexUrl> for Index {
@@ -613,10 +710,12 @@ impl From<IndexUrl> for Index {
             format: IndexFormat::Simple,
             publish_url: None,
             authenticate: AuthPolicy::default(),
+            auth_provider: None,
             ignore_error_codes: None,
             cache_control: None,
             hash_algorithm: None,
             exclude_newer: None,
+            rewrite_files: None,
         }
     }
 }
@@ -640,10 +739,12 @@ impl FromStr for Index {
                 format: IndexFormat::Simple,
                 publish_url: None,
                 authenticate: AuthPolicy::default(),
+                auth_provider: None,
                 ignore_error_codes: None,
                 cache_control: None,
                 hash_algorithm: None,
                 exclude_newer: None,
+                rewrite_files: None,
             });
         }
 
@@ -658,10 +759,12 @@ impl FromStr for Index {
             format: IndexFormat::Simple,
             publish_url: None,
             authenticate: AuthPolicy::default(),
+            auth_provider: None,
             ignore_error_codes: None,
             cache_control: None,
             hash_algorithm: None,
             exclude_newer: None,
+            rewrite_files: None,
         })
     }
 }
@@ -750,6 +853,8 @@ struct IndexWire {
     #[serde(default)]
     authenticate: AuthPolicy,
     #[serde(default)]
+    auth_provider: Option<AuthProvider>,
+    #[serde(default)]
     ignore_error_codes: Option<Vec<SerializableStatusCode>>,
     #[serde(default)]
     cache_control: Option<IndexCacheControl>,
@@ -757,6 +862,8 @@ struct IndexWire {
     hash_algorithm: Option<IndexHashAlgorithm>,
     #[serde(default)]
     exclude_newer: Option<ExcludeNewerOverride>,
+    #[serde(default)]
+    rewrite_files: Option<IndexFileRewrite>,
 }
 
 impl<'de> Deserialize<'de> for Index {
@@ -782,10 +889,12 @@ impl<'de> Deserialize<'de> for Index {
             format: wire.format,
             publish_url: wire.publish_url,
             authenticate: wire.authenticate,
+            auth_provider: wire.auth_provider,
             ignore_error_codes: wire.ignore_error_codes,
             cache_control: wire.cache_control,
             hash_algorithm: wire.hash_algorithm,
             exclude_newer: wire.exclude_newer,
+            rewrite_files: wire.rewrite_files,
         })
     }
 }
@@ -10,7 +10,7 @@ use itertools::Either;
 use rustc_hash::{FxHashMap, FxHashSet};
 use thiserror::Error;
 use url::{ParseError, Url};
-use uv_auth::RealmRef;
+use uv_auth::{CredentialLookup, RealmRef};
 use uv_cache_key::CanonicalUrl;
 use uv_pep508::{Scheme, VerbatimUrl, VerbatimUrlError, split_scheme};
 use uv_pypi_types::HashAlgorithm;
@@ -536,6 +536,15 @@ impl<'a> IndexLocations {
     pub fn exclude_newer_for(&self, url: &IndexUrl) -> Option<&ExcludeNewerOverride> {
         self.index_for_url(url).and_then(Index::exclude_newer)
     }
+
+    /// Rewrite the URL of a file served by an [`IndexUrl`], if the index configures a
+    /// `rewrite-files` rule that matches it.
+    pub fn rewrite_file_url_for(&self, index: &IndexUrl, url: DisplaySafeUrl) -> DisplaySafeUrl {
+        match self.index_for_url(index) {
+            Some(index) => index.rewrite_file_url(url),
+            None => url,
+        }
+    }
 }
 
 impl From<&IndexLocations> for uv_auth::Indexes {
@@ -551,6 +560,7 @@ impl From<&IndexLocations> for uv_auth::Indexes {
                 url,
                 root_url,
                 auth_policy: index.authenticate,
+                auth_provider: index.auth_provider,
             }
         }))
     }
@@ -574,13 +584,17 @@ bitflags::bitflags! {
 /// authorized). The benefit is that the map is almost always empty, so validating capabilities is
 /// extremely cheap.
 #[derive(Debug, Default, Clone)]
-pub struct IndexCapabilities(Arc<RwLock<FxHashMap<IndexUrl, Flags>>>);
+pub struct IndexCapabilities {
+    flags: Arc<RwLock<FxHashMap<IndexUrl, Flags>>>,
+    /// The credential sources consulted for indexes that returned a `401 Unauthorized`.
+    credential_lookups: Arc<RwLock<FxHashMap<IndexUrl, Vec<CredentialLookup>>>>,
+}
 
 impl IndexCapabilities {
     /// Returns `true` if the given [`IndexUrl`] supports range requests.
     pub fn supports_range_requests(&self, index_url: &IndexUrl) -> bool {
         !self
-            .0
+            .flags
             .read()
             .unwrap()
             .get(index_url)
@@ -589,7 +603,7 @@ impl IndexCapabilities {
 
     /// Mark an [`IndexUrl`] as not supporting range requests.
     pub fn set_no_range_requests(&self, index_url: IndexUrl) {
-        self.0
+        self.flags
             .write()
             .unwrap()
             .entry(index_url)
@@ -599,7 +613,7 @@ impl IndexCapabilities {
 
     /// Returns `true` if the given [`IndexUrl`] returns a `401 Unauthorized` status code.
     pub fn unauthorized(&self, index_url: &IndexUrl) -> bool {
-        self.0
+        self.flags
             .read()
             .unwrap()
             .get(index_url)
@@ -608,7 +622,7 @@ impl IndexCapabilities {
 
     /// Mark an [`IndexUrl`] as returning a `401 Unauthorized` status code.
     pub(crate) fn set_unauthorized(&self, index_url: IndexUrl) {
-        self.0
+        self.flags
             .write()
             .unwrap()
             .entry(index_url)
@@ -616,9 +630,29 @@ impl IndexCapabilities {
             .insert(Flags::UNAUTHORIZED);
     }
 
+    /// Returns the credential sources consulted for an [`IndexUrl`] that returned a
+    /// `401 Unauthorized` status code.
+    pub fn credential_lookups(&self, index_url: &IndexUrl) -> Vec<CredentialLookup> {
+        self.credential_lookups
+            .read()
+            .unwrap()
+            .get(index_url)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Record the credential sources consulted for an [`IndexUrl`] that returned a
+    /// `401 Unauthorized` status code.
+    pub fn set_credential_lookups(&self, index_url: IndexUrl, lookups: Vec<CredentialLookup>) {
+        self.credential_lookups
+            .write()
+            .unwrap()
+            .insert(index_url, lookups);
+    }
+
     /// Returns `true` if the given [`IndexUrl`] returns a `403 Forbidden` status code.
     pub fn forbidden(&self, index_url: &IndexUrl) -> bool {
-        self.0
+        self.flags
             .read()
             .unwrap()
             .get(index_url)
@@ -627,7 +661,7 @@ impl IndexCapabilities {
 
     /// Mark an [`IndexUrl`] as returning a `403 Forbidden` status code.
     pub(crate) fn set_forbidden(&self, index_url: IndexUrl) {
-        self.0
+        self.flags
             .write()
             .unwrap()
             .entry(index_url)
@@ -710,9 +744,11 @@ mod tests {
                 format: IndexFormat::Simple,
                 publish_url: None,
                 authenticate: uv_auth::AuthPolicy::default(),
+                auth_provider: None,
                 ignore_error_codes: None,
                 hash_algorithm: None,
                 exclude_newer: None,
+                rewrite_files: None,
             },
             Index {
                 name: Some(IndexName::from_str("index2").unwrap()),
@@ -724,9 +760,11 @@ mod tests {
                 format: IndexFormat::Simple,
                 publish_url: None,
                 authenticate: uv_auth::AuthPolicy::default(),
+                auth_provider: None,
                 ignore_error_codes: None,
                 hash_algorithm: None,
                 exclude_newer: None,
+                rewrite_files: None,
             },
         ];
 
@@ -764,9 +802,11 @@ mod tests {
             format: IndexFormat::Simple,
             publish_url: None,
             authenticate: uv_auth::AuthPolicy::default(),
+            auth_provider: None,
             ignore_error_codes: None,
             hash_algorithm: None,
             exclude_newer: None,
+            rewrite_files: None,
         }];
 
         let index_locations = IndexLocations::new(indexes, Vec::new(), false);
@@ -801,9 +841,11 @@ mod tests {
             format: IndexFormat::Simple,
             publish_url: None,
             authenticate: uv_auth::AuthPolicy::default(),
+            auth_provider: None,
             ignore_error_codes: None,
             hash_algorithm: None,
             exclude_newer: None,
+            rewrite_files: None,
         }];
 
         let index_locations = IndexLocations::new(indexes, Vec::new(), false);
@@ -833,9 +875,11 @@ mod tests {
             format: IndexFormat::Simple,
             publish_url: None,
             authenticate: uv_auth::AuthPolicy::default(),
+            auth_provider: None,
             ignore_error_codes: None,
             hash_algorithm: None,
             exclude_newer: None,
+            rewrite_files: None,
         }];
 
         let index_locations = IndexLocations::new(indexes, Vec::new(), false);
@@ -853,4 +897,46 @@ mod tests {
             ))
         );
     }
+
+    #[test]
+    fn test_rewrite_file_url() {
+        use std::str::FromStr;
+
+        use crate::IndexFileRewrite;
+
+        let mut index = Index::from(IndexUrl::from_str("https://pypi.org/simple").unwrap());
+        index.rewrite_files = Some(IndexFileRewrite {
+            from: DisplaySafeUrl::parse("https://files.pythonhosted.org/").unwrap(),
+            to: DisplaySafeUrl::parse("https://mirror.internal/pypi-files/").unwrap(),
+        });
+        let index_locations = IndexLocations::new(vec![index], Vec::new(), false);
+
+        let pypi = IndexUrl::from_str("https://pypi.org/simple").unwrap();
+        let other = IndexUrl::from_str("https://other.example.com/simple").unwrap();
+        let file = DisplaySafeUrl::parse(
+            "https://files.pythonhosted.org/packages/ab/cd/anyio-4.3.0-py3-none-any.whl",
+        )
+        .unwrap();
+
+        assert_eq!(
+            index_locations
+                .rewrite_file_url_for(&pypi, file.clone())
+                .as_str(),
+            "https://mirror.internal/pypi-files/packages/ab/cd/anyio-4.3.0-py3-none-any.whl"
+        );
+
+        // Files from other indexes are not rewritten.
+        assert_eq!(
+            index_locations.rewrite_file_url_for(&other, file.clone()),
+            file
+        );
+
+        // Files that don't match the prefix are not rewritten.
+        let unmatched =
+            DisplaySafeUrl::parse("https://example.com/anyio-4.3.0-py3-none-any.whl").unwrap();
+        assert_eq!(
+            index_locations.rewrite_file_url_for(&pypi, unmatched.clone()),
+            unmatched
+        );
+    }
 }
@@ -443,7 +443,7 @@ impl Dist {
 
         // Validate that the path exists.
         if !install_path.exists() {
-            return Err(Error::NotFound(url.to_url()));
+            return Err(Error::not_found(&url, &install_path));
         }
 
         // Determine whether the path represents a built or source distribution.
@@ -505,7 +505,7 @@ impl Dist {
 
         // Validate that the path exists.
         if !install_path.exists() {
-            return Err(Error::NotFound(url.to_url()));
+            return Err(Error::not_found(&url, &install_path));
         }
 
         // Determine whether the path represents an archive or a directory.
@@ -339,6 +339,112 @@ pub enum HashComparison {
     Matched,
 }
 
+/// An artifact that was considered when selecting a distribution, along with the outcome.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArtifactCandidate {
+    /// The filename (or, for non-registry sources, the location) of the artifact.
+    pub artifact: String,
+    /// Whether the artifact was selected and, if not, why.
+    pub verdict: ArtifactVerdict,
+}
+
+/// The outcome of considering an [`ArtifactCandidate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArtifactVerdict {
+    /// The artifact was selected for installation.
+    Selected,
+    /// The artifact is usable, but a higher-priority artifact was selected instead.
+    Superseded,
+    /// The artifact can't be used.
+    Rejected(ArtifactRejection),
+}
+
+/// The reason an artifact was rejected during selection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArtifactRejection {
+    /// The wheel is incompatible with the target environment or settings.
+    Wheel(IncompatibleWheel),
+    /// The source distribution is incompatible with the target environment or settings.
+    Source(IncompatibleSource),
+    /// The artifact doesn't satisfy the hash-checking policy.
+    Hash(HashComparison),
+}
+
+impl Display for ArtifactRejection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Wheel(incompatibility) => match incompatibility {
+                IncompatibleWheel::NoBinary => {
+                    f.write_str("binary distributions are disabled (`--no-binary`)")
+                }
+                IncompatibleWheel::Tag(tag) => match tag {
+                    IncompatibleTag::Invalid => f.write_str("invalid wheel tags"),
+                    IncompatibleTag::Python => {
+                        f.write_str("incompatible Python implementation tag")
+                    }
+                    IncompatibleTag::Abi => f.write_str("incompatible ABI tag"),
+                    IncompatibleTag::FreethreadedAbi => {
+                        f.write_str("ABI tag is not free-threading compatible")
+                    }
+                    IncompatibleTag::AbiPythonVersion => {
+                        f.write_str("incompatible Python version tag")
+                    }
+                    IncompatibleTag::Platform => f.write_str("incompatible platform tag"),
+                },
+                IncompatibleWheel::Yanked(yanked) => fmt_yanked(yanked, f),
+                IncompatibleWheel::ExcludeNewer(ts) => fmt_exclude_newer(*ts, f),
+                IncompatibleWheel::RequiresPython(python, _) => {
+                    write!(f, "requires Python {python}")
+                }
+                IncompatibleWheel::MissingPlatform(marker) => {
+                    if let Some(platform) = KnownPlatform::from_marker(*marker) {
+                        write!(f, "not compatible with {platform}")
+                    } else if let Some(marker) = marker.try_to_string() {
+                        write!(f, "not compatible with `{marker}`")
+                    } else {
+                        f.write_str("not compatible with the required platforms")
+                    }
+                }
+            },
+            Self::Source(incompatibility) => match incompatibility {
+                IncompatibleSource::NoBuild => {
+                    f.write_str("building from source is disabled (`--no-build`)")
+                }
+                IncompatibleSource::Yanked(yanked) => fmt_yanked(yanked, f),
+                IncompatibleSource::ExcludeNewer(ts) => fmt_exclude_newer(*ts, f),
+                IncompatibleSource::RequiresPython(python, _) => {
+                    write!(f, "requires Python {python}")
+                }
+            },
+            Self::Hash(comparison) => match comparison {
+                HashComparison::Mismatched => f.write_str("hash doesn't match the required hashes"),
+                HashComparison::Missing => f.write_str("missing a required hash"),
+                HashComparison::Matched => {
+                    f.write_str("hash matches, but another artifact was preferred")
+                }
+            },
+        }
+    }
+}
+
+fn fmt_yanked(yanked: &Yanked, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match yanked {
+        Yanked::Bool(_) => f.write_str("yanked"),
+        Yanked::Reason(reason) => write!(
+            f,
+            "yanked (reason: {})",
+            reason.trim().trim_end_matches('.')
+        ),
+    }
+}
+
+fn fmt_exclude_newer(timestamp: Option<i64>, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match timestamp {
+        Some(_) => f.write_str("published after the `exclude-newer` cutoff"),
+        None => f.write_str("no publish time (required by `exclude-newer`)"),
+    }
+}
+
 impl PrioritizedDist {
     /// Create a new [`PrioritizedDist`] from the given wheel distribution.
     pub fn from_built(
@@ -481,6 +587,70 @@ impl PrioritizedDist {
         }
     }
 
+    /// Explain the selection made by [`PrioritizedDist::get`], returning every candidate
+    /// artifact along with whether it was selected, superseded, or rejected (and why).
+    ///
+    /// Only the highest-priority source distribution is retained, so at most one source
+    /// distribution is reported.
+    pub fn explain(&self) -> Vec<ArtifactCandidate> {
+        let (selected_wheel, selected_sdist) = match self.get() {
+            Some(CompatibleDist::CompatibleWheel { wheel, .. }) => (Some(wheel), None),
+            Some(
+                CompatibleDist::SourceDist { sdist, .. }
+                | CompatibleDist::IncompatibleWheel { sdist, .. },
+            ) => (None, Some(sdist)),
+            Some(CompatibleDist::InstalledDist(_)) | None => (None, None),
+        };
+
+        let wheels = self.0.wheels.iter().map(|(wheel, compatibility)| {
+            let verdict = if selected_wheel.is_some_and(|selected| std::ptr::eq(selected, wheel)) {
+                ArtifactVerdict::Selected
+            } else {
+                match compatibility {
+                    WheelCompatibility::Incompatible(incompatibility) => {
+                        ArtifactVerdict::Rejected(ArtifactRejection::Wheel(incompatibility.clone()))
+                    }
+                    WheelCompatibility::Compatible(HashComparison::Matched, ..) => {
+                        ArtifactVerdict::Superseded
+                    }
+                    WheelCompatibility::Compatible(hash, ..) => {
+                        ArtifactVerdict::Rejected(ArtifactRejection::Hash(*hash))
+                    }
+                }
+            };
+            ArtifactCandidate {
+                artifact: wheel.filename.to_string(),
+                verdict,
+            }
+        });
+
+        let source = self.0.source.iter().map(|(sdist, compatibility)| {
+            let verdict = if selected_sdist.is_some_and(|selected| std::ptr::eq(selected, sdist)) {
+                ArtifactVerdict::Selected
+            } else {
+                match compatibility {
+                    SourceDistCompatibility::Incompatible(incompatibility) => {
+                        ArtifactVerdict::Rejected(ArtifactRejection::Source(
+                            incompatibility.clone(),
+                        ))
+                    }
+                    SourceDistCompatibility::Compatible(HashComparison::Matched) => {
+                        ArtifactVerdict::Superseded
+                    }
+                    SourceDistCompatibility::Compatible(hash) => {
+                        ArtifactVerdict::Rejected(ArtifactRejection::Hash(*hash))
+                    }
+                }
+            };
+            ArtifactCandidate {
+                artifact: sdist.file.filename.to_string(),
+                verdict,
+            }
+        });
+
+        wheels.chain(source).collect()
+    }
+
     /// Return the incompatibility for the best source distribution, if any.
     pub fn incompatible_source(&self) -> Option<&IncompatibleSource> {
         self.0
@@ -191,6 +191,12 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     size,
                 } = WheelTarget::try_from(&*wheel.file)?;
 
+                // Fetch the wheel from a mirror, if the index configures one.
+                let url = self
+                    .build_context
+                    .locations()
+                    .rewrite_file_url_for(&wheel.index, url);
+
                 // Create a cache entry for the wheel.
                 let wheel_entry = self.build_context.cache().entry(
                     CacheBucket::Wheels,
@@ -506,6 +512,24 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
             };
         }
 
+        // If the wheel was unzipped previously, respect it. Source distributions are
+        // cached under a unique revision ID, so unzipped directories are never stale, and
+        // fully materialized archives can be read without acquiring the advisory lock.
+        match self.build_context.cache().resolve_link(&built_wheel.target) {
+            Ok(archive) => {
+                return Ok(LocalWheel {
+                    dist: Dist::Source(dist.clone()),
+                    archive: archive.into_boxed_path(),
+                    filename: built_wheel.filename,
+                    hashes: built_wheel.hashes,
+                    cache: built_wheel.cache_info,
+                    build: Some(built_wheel.build_info),
+                });
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => return Err(Error::CacheRead(err)),
+        }
+
         // Acquire the advisory lock.
         #[cfg(windows)]
         let _lock = {
@@ -516,11 +540,14 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     built_wheel.target.file_name().unwrap().to_str().unwrap()
                 ),
             );
-            lock_entry.lock().await.map_err(Error::CacheLock)?
+            lock_entry
+                .lock_for(format!("unpacking `{dist}`"))
+                .await
+                .map_err(Error::CacheLock)?
         };
 
-        // If the wheel was unzipped previously, respect it. Source distributions are
-        // cached under a unique revision ID, so unzipped directories are never stale.
+        // Another process may have unzipped the wheel while we were waiting on the lock.
+        #[cfg(windows)]
         match self.build_context.cache().resolve_link(&built_wheel.target) {
             Ok(archive) => {
                 return Ok(LocalWheel {
@@ -708,7 +735,10 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         #[cfg(windows)]
         let _lock = {
             let lock_entry = wheel_entry.with_file(format!("{}.lock", filename.stem()));
-            lock_entry.lock().await.map_err(Error::CacheLock)?
+            lock_entry
+                .lock_for(format!("unpacking `{filename}`"))
+                .await
+                .map_err(Error::CacheLock)?
         };
 
         // Create an entry for the HTTP cache.
@@ -882,7 +912,10 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         #[cfg(windows)]
         let _lock = {
             let lock_entry = wheel_entry.with_file(format!("{}.lock", filename.stem()));
-            lock_entry.lock().await.map_err(Error::CacheLock)?
+            lock_entry
+                .lock_for(format!("unpacking `{filename}`"))
+                .await
+                .map_err(Error::CacheLock)?
         };
 
         // Create an entry for the HTTP cache.
@@ -1054,7 +1087,10 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         #[cfg(windows)]
         let _lock = {
             let lock_entry = wheel_entry.with_file(format!("{}.lock", filename.stem()));
-            lock_entry.lock().await.map_err(Error::CacheLock)?
+            lock_entry
+                .lock_for(format!("unpacking `{filename}`"))
+                .await
+                .map_err(Error::CacheLock)?
         };
 
         // Determine the last-modified time of the wheel.
@@ -9,7 +9,7 @@ use uv_configuration::NoSources;
 use uv_distribution_types::{GitDirectorySourceUrl, IndexLocations, Requirement};
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::{Version, VersionSpecifiers};
-use uv_pypi_types::{HashDigests, ResolutionMetadata};
+use uv_pypi_types::{HashDigests, MetadataVersion, ResolutionMetadata};
 use uv_workspace::dependency_groups::DependencyGroupError;
 use uv_workspace::{WorkspaceCache, WorkspaceError};
 
@@ -74,6 +74,8 @@ pub struct Metadata {
     pub provides_extra: Box<[ExtraName]>,
     pub dependency_groups: BTreeMap<GroupName, Box<[Requirement]>>,
     pub dynamic: bool,
+    /// The core metadata version declared by the distribution, if known.
+    pub metadata_version: Option<MetadataVersion>,
 }
 
 impl Metadata {
@@ -90,6 +92,7 @@ impl Metadata {
             provides_extra: metadata.provides_extra,
             dependency_groups: BTreeMap::default(),
             dynamic: metadata.dynamic,
+            metadata_version: metadata.metadata_version,
         }
     }
 
@@ -141,6 +144,7 @@ impl Metadata {
             provides_extra,
             dependency_groups,
             dynamic,
+            metadata_version: metadata.metadata_version,
         })
     }
 }
@@ -1,4 +1,5 @@
 use std::sync::Arc;
+use std::time::Duration;
 
 use uv_distribution_types::BuildableSource;
 use uv_normalize::PackageName;
@@ -11,6 +12,12 @@ pub trait Reporter: Send + Sync {
     /// Callback to invoke when a source distribution build is complete.
     fn on_build_complete(&self, source: &BuildableSource, id: usize);
 
+    /// Callback to invoke when a source distribution build emits a line of output.
+    fn on_build_output(&self, id: usize, line: &str);
+
+    /// Callback to invoke periodically while a source distribution build is running.
+    fn on_build_progress(&self, id: usize, elapsed: Duration);
+
     /// Callback to invoke when a repository checkout begins.
     fn on_checkout_start(&self, url: &DisplaySafeUrl, rev: &str) -> usize;
 
@@ -9,10 +9,12 @@
 #![expect(clippy::redundant_closure_for_method_calls)]
 
 use std::borrow::Cow;
+use std::collections::BTreeSet;
 use std::ops::Bound;
 use std::path::Path;
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::Duration;
 
 use fs_err::tokio as fs;
 use futures::{FutureExt, TryStreamExt};
@@ -43,9 +45,13 @@ use uv_metadata::read_archive_metadata;
 use uv_normalize::PackageName;
 use uv_pep440::{Version, release_specifiers_to_ranges};
 use uv_platform_tags::Tags;
-use uv_pypi_types::{HashAlgorithm, HashDigest, HashDigests, PyProjectToml, ResolutionMetadata};
+use uv_pypi_types::{
+    HashAlgorithm, HashDigest, HashDigests, LenientRequirement, PyProjectToml, ResolutionMetadata,
+    VerbatimParsedUrl,
+};
 use uv_redacted::DisplaySafeUrl;
 use uv_types::{BuildContext, BuildKey, BuildStack, SourceBuildTrait};
+use uv_warnings::warn_user_once;
 use uv_workspace::pyproject::ToolUvSources;
 
 use crate::distribution_database::ManagedClient;
@@ -228,6 +234,9 @@ const METADATA: &str = "metadata.msgpack";
 /// The directory within each entry under which to store the unpacked source distribution.
 const SOURCE: &str = "src";
 
+/// The interval at which to report the elapsed time of a long-running build.
+const BUILD_PROGRESS_INTERVAL: Duration = Duration::from_secs(30);
+
 impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
     /// Initialize a [`SourceDistributionBuilder`] from a [`BuildContext`].
     pub(crate) fn new(build_context: &'a T) -> Self {
@@ -275,7 +284,10 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                         .join(dist.version.to_string()),
                 );
 
-                let url = dist.file.url.to_url()?;
+                let url = self
+                    .build_context
+                    .locations()
+                    .rewrite_file_url_for(&dist.index, dist.file.url.to_url()?);
 
                 // If the URL is a file URL, use the local path directly.
                 if url.scheme() == "file" {
@@ -438,7 +450,10 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                         .join(dist.version.to_string()),
                 );
 
-                let url = dist.file.url.to_url()?;
+                let url = self
+                    .build_context
+                    .locations()
+                    .rewrite_file_url_for(&dist.index, dist.file.url.to_url()?);
 
                 // If the URL is a file URL, use the local path directly.
                 if url.scheme() == "file" {
@@ -634,7 +649,10 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         hashes: HashPolicy<'_>,
         client: &ManagedClient<'_>,
     ) -> Result<BuiltWheelMetadata, Error> {
-        let _lock = cache_shard.lock().await.map_err(Error::CacheLock)?;
+        let _lock = cache_shard
+            .lock_for(format!("building `{source}`"))
+            .await
+            .map_err(Error::CacheLock)?;
 
         // Fetch the revision for the source distribution.
         let revision = self
@@ -721,12 +739,15 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
 
         // Build the source distribution.
         let (disk_filename, wheel_filename, metadata) = self
-            .build_distribution(
-                source,
-                source_dist_entry.path(),
-                subdirectory,
-                &cache_shard,
-                NoSources::None,
+            .report_build(
+                task,
+                self.build_distribution(
+                    source,
+                    source_dist_entry.path(),
+                    subdirectory,
+                    &cache_shard,
+                    NoSources::None,
+                ),
             )
             .await?;
 
@@ -767,7 +788,10 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         hashes: HashPolicy<'_>,
         client: &ManagedClient<'_>,
     ) -> Result<ArchiveMetadata, Error> {
-        let _lock = cache_shard.lock().await.map_err(Error::CacheLock)?;
+        let _lock = cache_shard
+            .lock_for(format!("building `{source}`"))
+            .await
+            .map_err(Error::CacheLock)?;
 
         // Fetch the revision for the source distribution.
         let revision = self
@@ -904,12 +928,15 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
 
         // Build the source distribution.
         let (_disk_filename, _wheel_filename, metadata) = self
-            .build_distribution(
-                source,
-                source_dist_entry.path(),
-                subdirectory,
-                &cache_shard,
-                NoSources::None,
+            .report_build(
+                task,
+                self.build_distribution(
+                    source,
+                    source_dist_entry.path(),
+                    subdirectory,
+                    &cache_shard,
+                    NoSources::None,
+                ),
             )
             .await?;
 
@@ -1043,7 +1070,10 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         tags: &Tags,
         hashes: HashPolicy<'_>,
     ) -> Result<BuiltWheelMetadata, Error> {
-        let _lock = cache_shard.lock().await.map_err(Error::CacheLock)?;
+        let _lock = cache_shard
+            .lock_for(format!("building `{source}`"))
+            .await
+            .map_err(Error::CacheLock)?;
 
         // Fetch the revision for the source distribution.
         let LocalRevisionPointer {
@@ -1109,12 +1139,15 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .map(|reporter| reporter.on_build_start(source));
 
         let (disk_filename, filename, metadata) = self
-            .build_distribution(
-                source,
-                source_entry.path(),
-                None,
-                &cache_shard,
-                NoSources::None,
+            .report_build(
+                task,
+                self.build_distribution(
+                    source,
+                    source_entry.path(),
+                    None,
+                    &cache_shard,
+                    NoSources::None,
+                ),
             )
             .await?;
 
@@ -1151,7 +1184,10 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         cache_shard: &CacheShard,
         hashes: HashPolicy<'_>,
     ) -> Result<ArchiveMetadata, Error> {
-        let _lock = cache_shard.lock().await.map_err(Error::CacheLock)?;
+        let _lock = cache_shard
+            .lock_for(format!("building `{source}`"))
+            .await
+            .map_err(Error::CacheLock)?;
 
         // Fetch the revision for the source distribution.
         let LocalRevisionPointer { revision, .. } = self
@@ -1262,12 +1298,15 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .map(|reporter| reporter.on_build_start(source));
 
         let (_disk_filename, _filename, metadata) = self
-            .build_distribution(
-                source,
-                source_entry.path(),
-                None,
-                &cache_shard,
-                NoSources::None,
+            .report_build(
+                task,
+                self.build_distribution(
+                    source,
+                    source_entry.path(),
+                    None,
+                    &cache_shard,
+                    NoSources::None,
+                ),
             )
             .await?;
 
@@ -1375,7 +1414,10 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         );
 
         // Acquire the advisory lock.
-        let _lock = cache_shard.lock().await.map_err(Error::CacheLock)?;
+        let _lock = cache_shard
+            .lock_for(format!("building `{source}`"))
+            .await
+            .map_err(Error::CacheLock)?;
 
         // Fetch the revision for the source distribution.
         let LocalRevisionPointer {
@@ -1424,12 +1466,15 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .map(|reporter| reporter.on_build_start(source));
 
         let (disk_filename, filename, metadata) = self
-            .build_distribution(
-                source,
-                resource.install_path,
-                None,
-                &cache_shard,
-                self.build_context.sources().clone(),
+            .report_build(
+                task,
+                self.build_distribution(
+                    source,
+                    resource.install_path,
+                    None,
+                    &cache_shard,
+                    self.build_context.sources().clone(),
+                ),
             )
             .await?;
 
@@ -1439,6 +1484,10 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             }
         }
 
+        // If the dependencies were read from `tool.uv.static-dependencies`, ensure that they match
+        // the built metadata.
+        check_static_dependencies(resource.install_path, &metadata).await?;
+
         // Store the metadata.
         let metadata_entry = cache_shard.entry(METADATA);
         write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
@@ -1512,7 +1561,10 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         );
 
         // Acquire the advisory lock.
-        let _lock = cache_shard.lock().await.map_err(Error::CacheLock)?;
+        let _lock = cache_shard
+            .lock_for(format!("building `{source}`"))
+            .await
+            .map_err(Error::CacheLock)?;
 
         // Fetch the revision for the source distribution.
         let LocalRevisionPointer { revision, .. } = self
@@ -1628,12 +1680,15 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .map(|reporter| reporter.on_build_start(source));
 
         let (_disk_filename, _filename, metadata) = self
-            .build_distribution(
-                source,
-                resource.install_path,
-                None,
-                &cache_shard,
-                self.build_context.sources().clone(),
+            .report_build(
+                task,
+                self.build_distribution(
+                    source,
+                    resource.install_path,
+                    None,
+                    &cache_shard,
+                    self.build_context.sources().clone(),
+                ),
             )
             .await?;
 
@@ -1733,8 +1788,10 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         pyproject_toml: &PyProjectToml,
         credentials_cache: &CredentialsCache,
     ) -> Result<Option<RequiresDist>, Error> {
-        // Attempt to read static metadata from the `pyproject.toml`.
-        match uv_pypi_types::RequiresDist::from_pyproject_toml(pyproject_toml.clone()) {
+        // Attempt to read static metadata from the `pyproject.toml`, respecting
+        // `tool.uv.static-dependencies`.
+        let pyproject_toml = with_static_dependencies(pyproject_toml.clone(), path).await?;
+        match uv_pypi_types::RequiresDist::from_pyproject_toml(pyproject_toml) {
             Ok(requires_dist) => {
                 debug!("Found static `requires-dist` for: {}", path.display());
                 let requires_dist = RequiresDist::from_project_maybe_workspace(
@@ -1904,12 +1961,15 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .map(|reporter| reporter.on_build_start(source));
 
         let (disk_filename, filename, metadata) = self
-            .build_distribution(
-                source,
-                source_entry.path(),
-                None,
-                &cache_shard,
-                NoSources::None,
+            .report_build(
+                task,
+                self.build_distribution(
+                    source,
+                    source_entry.path(),
+                    None,
+                    &cache_shard,
+                    NoSources::None,
+                ),
             )
             .await?;
 
@@ -2061,12 +2121,15 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .map(|reporter| reporter.on_build_start(source));
 
         let (_disk_filename, _filename, metadata) = self
-            .build_distribution(
-                source,
-                source_entry.path(),
-                None,
-                &cache_shard,
-                NoSources::None,
+            .report_build(
+                task,
+                self.build_distribution(
+                    source,
+                    source_entry.path(),
+                    None,
+                    &cache_shard,
+                    NoSources::None,
+                ),
             )
             .await?;
 
@@ -2132,7 +2195,10 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let metadata_entry = cache_shard.entry(METADATA);
 
         // Acquire the advisory lock.
-        let _lock = cache_shard.lock().await.map_err(Error::CacheLock)?;
+        let _lock = cache_shard
+            .lock_for(format!("building `{source}`"))
+            .await
+            .map_err(Error::CacheLock)?;
 
         // We don't track any cache information for Git-based source distributions; they're assumed
         // to be immutable.
@@ -2173,12 +2239,15 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .map(|reporter| reporter.on_build_start(source));
 
         let (disk_filename, filename, metadata) = self
-            .build_distribution(
-                source,
-                fetch.path(),
-                resource.subdirectory,
-                &cache_shard,
-                self.build_context.sources().clone(),
+            .report_build(
+                task,
+                self.build_distribution(
+                    source,
+                    fetch.path(),
+                    resource.subdirectory,
+                    &cache_shard,
+                    self.build_context.sources().clone(),
+                ),
             )
             .await?;
 
@@ -2324,7 +2393,10 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let metadata_entry = cache_shard.entry(METADATA);
 
         // Acquire the advisory lock.
-        let _lock = cache_shard.lock().await.map_err(Error::CacheLock)?;
+        let _lock = cache_shard
+            .lock_for(format!("building `{source}`"))
+            .await
+            .map_err(Error::CacheLock)?;
 
         let path = if let Some(subdirectory) = resource.subdirectory {
             Cow::Owned(fetch.path().join(subdirectory))
@@ -2475,12 +2547,15 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .map(|reporter| reporter.on_build_start(source));
 
         let (_disk_filename, _filename, metadata) = self
-            .build_distribution(
-                source,
-                fetch.path(),
-                resource.subdirectory,
-                &cache_shard,
-                self.build_context.sources().clone(),
+            .report_build(
+                task,
+                self.build_distribution(
+                    source,
+                    fetch.path(),
+                    resource.subdirectory,
+                    &cache_shard,
+                    self.build_context.sources().clone(),
+                ),
             )
             .await?;
 
@@ -2952,6 +3027,33 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         }
     }
 
+    /// Run a build for the given reporter task, forwarding the output of the build backend to the
+    /// reporter and reporting the elapsed time periodically while the build is running.
+    async fn report_build<R>(&self, task: Option<usize>, build: impl Future<Output = R>) -> R {
+        let (Some(reporter), Some(task)) = (self.reporter.clone(), task) else {
+            return build.await;
+        };
+
+        let build = uv_types::with_build_output_listener(
+            {
+                let reporter = reporter.clone();
+                move |line: &str| reporter.on_build_output(task, line)
+            },
+            build,
+        );
+        tokio::pin!(build);
+
+        let start = tokio::time::Instant::now();
+        let mut interval =
+            tokio::time::interval_at(start + BUILD_PROGRESS_INTERVAL, BUILD_PROGRESS_INTERVAL);
+        loop {
+            tokio::select! {
+                result = &mut build => return result,
+                _ = interval.tick() => reporter.on_build_progress(task, start.elapsed()),
+            }
+        }
+    }
+
     /// Build a source distribution, storing the built wheel in the cache.
     ///
     /// Returns the un-normalized disk filename, the parsed, normalized filename and the metadata
@@ -3270,7 +3372,17 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
 }
 
 /// Prune any unused source distributions from the cache.
-pub fn prune(cache: &Cache) -> Result<Removal, Error> {
+///
+/// If `dry_run` is set, determine what would be removed, without removing anything.
+pub fn prune(cache: &Cache, dry_run: bool) -> Result<Removal, Error> {
+    let remove = |path: &Path| {
+        if dry_run {
+            uv_cache::measure(path)
+        } else {
+            uv_cache::rm_rf(path)
+        }
+    };
+
     let mut removal = Removal::default();
 
     let bucket = cache.bucket(CacheBucket::SourceDistributions);
@@ -3297,8 +3409,7 @@ pub fn prune(cache: &Cache) -> Result<Removal, Error> {
                                     "Removing dangling source revision: {}",
                                     sibling.path().display()
                                 );
-                                removal +=
-                                    uv_cache::rm_rf(sibling.path()).map_err(Error::CacheWrite)?;
+                                removal += remove(&sibling.path()).map_err(Error::CacheWrite)?;
                             }
                         }
                     }
@@ -3320,8 +3431,7 @@ pub fn prune(cache: &Cache) -> Result<Removal, Error> {
                                     "Removing dangling source revision: {}",
                                     sibling.path().display()
                                 );
-                                removal +=
-                                    uv_cache::rm_rf(sibling.path()).map_err(Error::CacheWrite)?;
+                                removal += remove(&sibling.path()).map_err(Error::CacheWrite)?;
                             }
                         }
                     }
@@ -3361,6 +3471,14 @@ impl StaticMetadata {
             Err(err) => return Err(err),
         };
 
+        // For source trees, respect `tool.uv.static-dependencies`.
+        let pyproject_toml = match pyproject_toml {
+            Some(pyproject_toml) if source.is_source_tree() => {
+                Some(with_static_dependencies(pyproject_toml, source_root).await?)
+            }
+            pyproject_toml => pyproject_toml,
+        };
+
         // Determine whether the version is static or dynamic.
         let dynamic = pyproject_toml.as_ref().is_some_and(|pyproject_toml| {
             pyproject_toml.project.as_ref().is_some_and(|project| {
@@ -3687,6 +3805,141 @@ async fn read_pyproject_toml(
     Ok(pyproject_toml)
 }
 
+/// Apply `tool.uv.static-dependencies` to the `pyproject.toml` of a source tree, replacing the
+/// project's dynamic `dependencies` with those read from the declared requirements file.
+///
+/// If the hint is absent or can't be used, the `pyproject.toml` is returned unchanged.
+async fn with_static_dependencies(
+    pyproject_toml: PyProjectToml,
+    source_tree: &Path,
+) -> Result<PyProjectToml, Error> {
+    let Some(dependencies) = read_static_dependencies(source_tree, &pyproject_toml).await? else {
+        return Ok(pyproject_toml);
+    };
+    Ok(pyproject_toml.with_static_dependencies(dependencies))
+}
+
+/// Read the requirements file declared via `tool.uv.static-dependencies`, if any.
+async fn read_static_dependencies(
+    source_tree: &Path,
+    pyproject_toml: &PyProjectToml,
+) -> Result<Option<Vec<String>>, Error> {
+    let Some(path) = pyproject_toml.static_dependencies() else {
+        return Ok(None);
+    };
+    let path = source_tree.join(path);
+    let content = match fs::read_to_string(&path).await {
+        Ok(content) => content,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            warn_user_once!(
+                "Ignoring `tool.uv.static-dependencies` for `{}`: `{}` does not exist",
+                source_tree.simplified_display(),
+                path.simplified_display()
+            );
+            return Ok(None);
+        }
+        Err(err) => return Err(Error::CacheRead(err)),
+    };
+    let Some(dependencies) = parse_static_dependencies(&content) else {
+        debug!(
+            "Ignoring `tool.uv.static-dependencies` for `{}`: `{}` contains pip options",
+            source_tree.simplified_display(),
+            path.simplified_display()
+        );
+        return Ok(None);
+    };
+    debug!(
+        "Reading dependencies for `{}` from: `{}`",
+        source_tree.simplified_display(),
+        path.simplified_display()
+    );
+    Ok(Some(dependencies))
+}
+
+/// Parse the requirements from a static dependencies file.
+///
+/// Supports one requirement per line, along with comments and line continuations. Returns `None`
+/// if the file contains any pip options (e.g., `-r` or `--index-url`), which can't be expressed
+/// as project dependencies.
+fn parse_static_dependencies(content: &str) -> Option<Vec<String>> {
+    let mut dependencies = Vec::new();
+    let mut current = String::new();
+    for line in content.lines().chain(std::iter::once("")) {
+        // Strip comments, which must either start the line or be preceded by whitespace.
+        let line = line
+            .char_indices()
+            .find(|&(index, char)| {
+                char == '#' && (index == 0 || line[..index].ends_with(char::is_whitespace))
+            })
+            .map_or(line, |(index, _)| &line[..index]);
+
+        // Join continued lines.
+        if let Some(line) = line.trim_end().strip_suffix('\\') {
+            current.push_str(line);
+            continue;
+        }
+        current.push_str(line);
+
+        let dependency = current.trim();
+        if dependency.starts_with('-') {
+            return None;
+        }
+        if !dependency.is_empty() {
+            dependencies.push(dependency.to_string());
+        }
+        current.clear();
+    }
+    Some(dependencies)
+}
+
+/// Warn if the dependencies declared via `tool.uv.static-dependencies` diverge from those in the
+/// metadata produced by the build backend.
+async fn check_static_dependencies(
+    source_tree: &Path,
+    metadata: &ResolutionMetadata,
+) -> Result<(), Error> {
+    let pyproject_toml = match read_pyproject_toml(source_tree, None).await {
+        Ok(pyproject_toml) => pyproject_toml,
+        Err(Error::MissingPyprojectToml) => return Ok(()),
+        Err(err) => return Err(err),
+    };
+    let Some(path) = pyproject_toml.static_dependencies() else {
+        return Ok(());
+    };
+    let Some(dependencies) = read_static_dependencies(source_tree, &pyproject_toml).await? else {
+        return Ok(());
+    };
+
+    // Compare the declared requirements against the built requirements, omitting those that are
+    // only required by extras.
+    let Ok(declared) = dependencies
+        .iter()
+        .map(|dependency| {
+            LenientRequirement::<VerbatimParsedUrl>::from_str(dependency)
+                .map(|requirement| uv_pep508::Requirement::from(requirement).to_string())
+        })
+        .collect::<Result<BTreeSet<_>, _>>()
+    else {
+        return Ok(());
+    };
+    let built = metadata
+        .requires_dist
+        .iter()
+        .filter(|requirement| requirement.marker.top_level_extra().is_none())
+        .map(ToString::to_string)
+        .collect::<BTreeSet<_>>();
+
+    if declared != built {
+        warn_user_once!(
+            "The dependencies of `{}` in `{}` (declared via `tool.uv.static-dependencies`) differ from those reported by its build backend; the resolution may be out-of-date",
+            metadata.name,
+            source_tree.join(path).simplified_display()
+        );
+    }
+
+    Ok(())
+}
+
 /// Wheel metadata stored in the source distribution cache.
 #[derive(Debug, Clone)]
 struct CachedMetadata(ResolutionMetadata);
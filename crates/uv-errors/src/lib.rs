@@ -6,6 +6,7 @@ use std::fmt;
 use std::iter;
 
 use owo_colors::{AnsiColors, DynColor, OwoColorize};
+use uv_redacted::redact_urls;
 
 use line_wrap::{get_wrap_width, wrap_text};
 
@@ -244,7 +245,9 @@ pub fn write_error_chain_with_options<C: DynColor + Copy, W: fmt::Write>(
     } = options;
     let width = get_wrap_width(width_override);
 
+    // Errors from third-party crates (e.g., `reqwest`) may embed credentialed URLs verbatim.
     let main_msg = err.to_string();
+    let main_msg = redact_urls(&main_msg);
     let main_padding = " ".repeat(level.len() + 2);
     let wrapped_main = wrap_text(&main_msg, width, &main_padding, &main_padding, "");
     writeln!(
@@ -257,6 +260,7 @@ pub fn write_error_chain_with_options<C: DynColor + Copy, W: fmt::Write>(
 
     for source in iter::successors(err.source(), |&err| err.source()) {
         let msg = source.to_string();
+        let msg = redact_urls(&msg);
         let padding = "  ";
         let cause = "Caused by";
         let child_padding = " ".repeat(padding.len() + cause.len() + 2);
@@ -7,6 +7,8 @@ bitflags::bitflags! {
     pub struct EnvironmentFlags: u32 {
         const SKIP_WHEEL_FILENAME_CHECK = 1 << 0;
         const HIDE_BUILD_OUTPUT = 1 << 1;
+        const SKIP_EDITABLE_METADATA_CHECK = 1 << 2;
+        const NO_SCRIPTS_PATH_NOTICE = 1 << 3;
     }
 }
 
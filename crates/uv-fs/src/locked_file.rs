@@ -9,6 +9,7 @@ use thiserror::Error;
 use tracing::{debug, error, info, trace, warn};
 
 use uv_static::EnvVars;
+#[cfg(feature = "tokio")]
 #[cfg(windows)]
 use windows::Win32::Foundation::ERROR_LOCK_VIOLATION;
 
@@ -36,6 +37,10 @@ static LOCK_TIMEOUT: LazyLock<Duration> = LazyLock::new(|| {
     }
 });
 
+/// How long to wait on a contended lock before telling the user what the lock holder is doing.
+#[cfg(feature = "tokio")]
+const LOCK_NOTICE_DELAY: Duration = Duration::from_secs(1);
+
 #[derive(Debug, Error)]
 pub enum LockedFileError {
     #[error(
@@ -196,6 +201,7 @@ impl LockedFile {
         file: fs_err::File,
         mode: LockedFileMode,
         resource: &str,
+        activity: Option<&str>,
     ) -> Result<Self, LockedFileError> {
         trace!(
             "Checking lock for `{resource}` at `{}`",
@@ -223,14 +229,27 @@ impl LockedFile {
             file.path().user_display(),
         );
         let path = file.path().to_path_buf();
-        let lock_exclusive = tokio::task::spawn_blocking(move || (mode.lock(&file), file));
-        let (result, file) = tokio::time::timeout(*LOCK_TIMEOUT, lock_exclusive)
-            .await
-            .map_err(|_| LockedFileError::Timeout {
-                timeout: *LOCK_TIMEOUT,
-                resource: resource.to_string(),
-                path: path.clone(),
-            })??;
+        let deadline = tokio::time::Instant::now() + *LOCK_TIMEOUT;
+        let mut lock_exclusive = tokio::task::spawn_blocking(move || (mode.lock(&file), file));
+        let result = match activity {
+            // If we know what the lock holder is doing, report it once the wait becomes
+            // noticeable, rather than appearing to hang until the timeout.
+            Some(activity) => {
+                let notice = (tokio::time::Instant::now() + LOCK_NOTICE_DELAY).min(deadline);
+                if let Ok(result) = tokio::time::timeout_at(notice, &mut lock_exclusive).await {
+                    Ok(result)
+                } else {
+                    info!("Waiting for another uv process to finish {activity}...");
+                    tokio::time::timeout_at(deadline, lock_exclusive).await
+                }
+            }
+            None => tokio::time::timeout_at(deadline, lock_exclusive).await,
+        };
+        let (result, file) = result.map_err(|_| LockedFileError::Timeout {
+            timeout: *LOCK_TIMEOUT,
+            resource: resource.to_string(),
+            path: path.clone(),
+        })??;
         // Not an fs_err method, we need to build our own path context
         result.map_err(|err| LockedFileError::Lock {
             resource: resource.to_string(),
@@ -272,7 +291,23 @@ impl LockedFile {
     ) -> Result<Self, LockedFileError> {
         let file = Self::create(&path)?;
         let resource = resource.to_string();
-        Self::lock_file(file, mode, &resource).await
+        Self::lock_file(file, mode, &resource, None).await
+    }
+
+    /// Acquire a cross-process lock for a resource using a file at the provided path, telling the
+    /// user what they're waiting on if another process holds the lock for more than a moment.
+    ///
+    /// The `activity` describes what the lock holder is doing, e.g., ``building `numpy==2.0.0` ``.
+    pub async fn acquire_with_activity(
+        path: impl AsRef<Path>,
+        mode: LockedFileMode,
+        resource: impl Display,
+        activity: impl Display,
+    ) -> Result<Self, LockedFileError> {
+        let file = Self::create(&path)?;
+        let resource = resource.to_string();
+        let activity = activity.to_string();
+        Self::lock_file(file, mode, &resource, Some(&activity)).await
     }
 
     /// Acquire a cross-process lock for a resource using a file at the provided path
@@ -445,3 +480,67 @@ impl Drop for LockedFile {
         }
     }
 }
+
+#[cfg(all(test, feature = "tokio"))]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    use super::{LockedFile, LockedFileMode};
+
+    /// Many tasks contending on the same exclusive lock should hold it one at a time, and all
+    /// should eventually acquire it.
+    #[tokio::test]
+    async fn exclusive_lock_contention() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join(".lock");
+
+        let holders = Arc::new(AtomicUsize::new(0));
+        let acquired = Arc::new(AtomicUsize::new(0));
+        let tasks = (0..32)
+            .map(|_| {
+                let path = path.clone();
+                let holders = holders.clone();
+                let acquired = acquired.clone();
+                tokio::spawn(async move {
+                    let _lock = LockedFile::acquire_with_activity(
+                        &path,
+                        LockedFileMode::Exclusive,
+                        "test",
+                        "testing",
+                    )
+                    .await
+                    .unwrap();
+                    assert_eq!(holders.fetch_add(1, Ordering::SeqCst), 0);
+                    tokio::time::sleep(Duration::from_millis(5)).await;
+                    holders.fetch_sub(1, Ordering::SeqCst);
+                    acquired.fetch_add(1, Ordering::SeqCst);
+                })
+            })
+            .collect::<Vec<_>>();
+        for task in tasks {
+            task.await.unwrap();
+        }
+
+        assert_eq!(acquired.load(Ordering::SeqCst), 32);
+    }
+
+    /// Shared locks may be held concurrently, but exclude an exclusive lock.
+    #[tokio::test]
+    async fn shared_lock_excludes_exclusive() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join(".lock");
+
+        let first = LockedFile::acquire(&path, LockedFileMode::Shared, "test")
+            .await
+            .unwrap();
+        let second = LockedFile::acquire_no_wait(&path, LockedFileMode::Shared, "test");
+        assert!(second.is_some());
+        assert!(LockedFile::acquire_no_wait(&path, LockedFileMode::Exclusive, "test").is_none());
+
+        drop(first);
+        drop(second);
+        assert!(LockedFile::acquire_no_wait(&path, LockedFileMode::Exclusive, "test").is_some());
+    }
+}
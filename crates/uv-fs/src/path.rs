@@ -176,8 +176,9 @@ fn escape_path_for_python<P: AsRef<Path>>(path: P) -> String {
 
 /// Normalize the `path` component of a URL for use as a file path.
 ///
-/// For example, on Windows, transforms `C:\Users\ferris\wheel-0.42.0.tar.gz` to
-/// `/C:/Users/ferris/wheel-0.42.0.tar.gz`.
+/// For example, on Windows, transforms `/C:/Users/ferris/wheel-0.42.0.tar.gz` to
+/// `C:\Users\ferris\wheel-0.42.0.tar.gz`, and `//server/share/wheel-0.42.0.tar.gz` to
+/// `\\server\share\wheel-0.42.0.tar.gz`.
 ///
 /// On other platforms, this is a no-op.
 pub fn normalize_url_path(path: &str) -> Cow<'_, str> {
@@ -188,16 +189,52 @@ pub fn normalize_url_path(path: &str) -> Cow<'_, str> {
 
     // Return the path.
     if cfg!(windows) {
-        Cow::Owned(
-            path.strip_prefix('/')
-                .unwrap_or(&path)
-                .replace('/', std::path::MAIN_SEPARATOR_STR),
-        )
+        // Retain both leading slashes for UNC paths (e.g., `//server/share`).
+        let path: &str = if is_unc_url_path(&path) {
+            &path
+        } else {
+            path.strip_prefix('/').unwrap_or(&path)
+        };
+        Cow::Owned(path.replace('/', std::path::MAIN_SEPARATOR_STR))
     } else {
         path
     }
 }
 
+/// Returns `true` if the `path` component of a URL refers to a UNC path, as in
+/// `//server/share/...`.
+fn is_unc_url_path(path: &str) -> bool {
+    path.strip_prefix("//")
+        .is_some_and(|rest| !rest.is_empty() && !rest.starts_with('/'))
+}
+
+/// Resolve a `path` against `base_dir`, returning an absolute path.
+///
+/// Unlike [`Path::join`], this resolves Windows drive-relative paths (e.g., `C:foo`) against
+/// `base_dir` when it's on the same drive, and against the current directory of that drive
+/// otherwise, rather than returning the drive-relative path as-is.
+pub fn absolute_from<'a>(path: &'a Path, base_dir: &Path) -> Cow<'a, Path> {
+    if path.is_absolute() {
+        return Cow::Borrowed(path);
+    }
+
+    let mut components = path.components();
+    if let Some(Component::Prefix(prefix)) = components.next() {
+        // Ex) `C:foo`
+        return if base_dir
+            .components()
+            .next()
+            .is_some_and(|base| base == Component::Prefix(prefix))
+        {
+            Cow::Owned(base_dir.join(components.as_path()))
+        } else {
+            Cow::Owned(std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf()))
+        };
+    }
+
+    Cow::Owned(base_dir.join(path))
+}
+
 /// Normalize a path, removing things like `.` and `..`.
 ///
 /// Source: <https://github.com/rust-lang/cargo/blob/b48c41aedbd69ee3990d62a0e2006edbb506a480/crates/cargo-util/src/paths.rs#L76C1-L109C2>
@@ -569,7 +606,9 @@ where
 
 impl std::fmt::Display for PortablePath<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let path = self.0.to_slash_lossy();
+        // Strip any verbatim prefix (e.g., `\\?\UNC\server\share` to `\\server\share`), such
+        // that UNC paths are written as `//server/share`.
+        let path = dunce::simplified(self.0).to_slash_lossy();
         if path.is_empty() {
             write!(f, ".")
         } else {
@@ -580,7 +619,7 @@ impl std::fmt::Display for PortablePath<'_> {
 
 impl std::fmt::Display for PortablePathBuf {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let path = self.0.to_slash_lossy();
+        let path = dunce::simplified(&self.0).to_slash_lossy();
         if path.is_empty() {
             write!(f, ".")
         } else {
@@ -721,6 +760,76 @@ mod tests {
                 "./wheel cache/wheel-0.42.0.tar.gz"
             );
         }
+
+        if cfg!(windows) {
+            assert_eq!(
+                normalize_url_path("//fileserver/py%20wheels/caf%C3%A9-0.1.0.tar.gz"),
+                "\\\\fileserver\\py wheels\\café-0.1.0.tar.gz"
+            );
+        } else {
+            assert_eq!(
+                normalize_url_path("//fileserver/py%20wheels/caf%C3%A9-0.1.0.tar.gz"),
+                "//fileserver/py wheels/café-0.1.0.tar.gz"
+            );
+        }
+    }
+
+    #[test]
+    fn test_absolute_from() {
+        let base_dir = if cfg!(windows) {
+            Path::new(r"C:\Users\ferris\project")
+        } else {
+            Path::new("/home/ferris/project")
+        };
+        assert_eq!(
+            absolute_from(Path::new("wheels/foo"), base_dir),
+            base_dir.join("wheels/foo")
+        );
+        assert_eq!(
+            absolute_from(base_dir.join("wheels").as_path(), base_dir),
+            base_dir.join("wheels")
+        );
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn test_absolute_from_windows() {
+        let base_dir = Path::new(r"C:\Users\ferris\project");
+        let cases = [
+            (r"C:wheels\foo", r"C:\Users\ferris\project\wheels\foo"),
+            (r"\wheels\foo", r"C:\wheels\foo"),
+            (r"\\fileserver\pywheels\foo", r"\\fileserver\pywheels\foo"),
+            (r"//fileserver/pywheels/foo", r"\\fileserver\pywheels\foo"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(
+                absolute_from(Path::new(input), base_dir),
+                Path::new(expected)
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn test_portable_path_unc() {
+        let cases = [
+            (
+                r"\\fileserver\py wheels\café",
+                "//fileserver/py wheels/café",
+            ),
+            (
+                r"\\?\UNC\fileserver\pywheels\foo",
+                "//fileserver/pywheels/foo",
+            ),
+            (r"\\?\C:\Users\ferris\foo", "C:/Users/ferris/foo"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(PortablePath::from(Path::new(input)).to_string(), expected);
+
+            // The portable form round-trips to an equivalent path.
+            let portable = PortablePathBuf::from(expected);
+            assert_eq!(portable.as_ref(), dunce::simplified(Path::new(input)));
+        }
     }
 
     #[test]
@@ -198,10 +198,11 @@ impl GitResolver {
         let lock_dir = cache.join("locks");
         fs::create_dir_all(&lock_dir).await?;
         let repository_url = url.repository().clone();
-        let _lock = LockedFile::acquire(
+        let _lock = LockedFile::acquire_with_activity(
             lock_dir.join(cache_digest(&repository_url)),
             LockedFileMode::Exclusive,
             &repository_url,
+            format!("fetching `{repository_url}`"),
         )
         .await?;
 
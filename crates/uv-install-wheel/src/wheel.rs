@@ -104,6 +104,12 @@ fn copy_and_hash(reader: &mut impl Read, writer: &mut impl Write) -> io::Result<
     ))
 }
 
+/// The maximum length of a shebang line, including the trailing newline.
+///
+/// Linux kernels prior to 5.1 truncate the interpreter line beyond 127 bytes. Later kernels and
+/// macOS allow longer lines, but the conservative limit is used on all POSIX platforms.
+const MAX_SHEBANG_LENGTH: usize = 127;
+
 /// Format the shebang for a given Python executable.
 ///
 /// Like pip, if a shebang is non-simple (too long or contains spaces), we use `/bin/sh` as the
@@ -123,7 +129,13 @@ fn format_shebang(executable: impl AsRef<Path>, os_name: &str, relocatable: bool
         // If the shebang is too long, or contains spaces, wrap it in `/bin/sh`.
         // Same applies for relocatable scripts (executable is relative to script dir, hence `dirname` trick)
         // (note: the Windows trampoline binaries natively support relative paths to executable)
-        if shebang_length > 127 || executable.contains(' ') || relocatable {
+        let too_long = shebang_length > MAX_SHEBANG_LENGTH;
+        if too_long {
+            debug!(
+                "Shebang for `{executable}` exceeds {MAX_SHEBANG_LENGTH} characters; using a `/bin/sh` trampoline"
+            );
+        }
+        if too_long || executable.contains(' ') || relocatable {
             let prefix = if relocatable {
                 r#""$(dirname -- "$(realpath -- "$0")")"/"#
             } else {
@@ -471,7 +471,7 @@ impl<'a> Planner<'a> {
                 Dist::Built(BuiltDist::Path(wheel)) => {
                     // Validate that the path exists.
                     if !wheel.install_path.exists() {
-                        return Err(Error::NotFound(wheel.url.to_url()).into());
+                        return Err(Error::not_found(&wheel.url, &wheel.install_path).into());
                     }
 
                     if !wheel.filename.is_compatible(tags) {
@@ -673,7 +673,7 @@ impl<'a> Planner<'a> {
                 Dist::Source(SourceDist::Path(sdist)) => {
                     // Validate that the path exists.
                     if !sdist.install_path.exists() {
-                        return Err(Error::NotFound(sdist.url.to_url()).into());
+                        return Err(Error::not_found(&sdist.url, &sdist.install_path).into());
                     }
 
                     // Find the most-compatible wheel from the cache, since we don't know
@@ -707,7 +707,7 @@ impl<'a> Planner<'a> {
                 Dist::Source(SourceDist::Directory(sdist)) => {
                     // Validate that the path exists.
                     if !sdist.install_path.exists() {
-                        return Err(Error::NotFound(sdist.url.to_url()).into());
+                        return Err(Error::not_found(&sdist.url, &sdist.install_path).into());
                     }
 
                     // Find the most-compatible wheel from the cache, since we don't know
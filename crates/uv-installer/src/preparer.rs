@@ -1,5 +1,6 @@
 use std::cmp::Reverse;
 use std::sync::Arc;
+use std::time::Duration;
 
 use futures::{FutureExt, Stream, TryFutureExt, TryStreamExt, stream::FuturesUnordered};
 use tracing::{debug, instrument};
@@ -261,6 +262,12 @@ pub trait Reporter: Send + Sync {
     /// Callback to invoke when a source distribution build is complete.
     fn on_build_complete(&self, source: &BuildableSource, id: usize);
 
+    /// Callback to invoke when a source distribution build emits a line of output.
+    fn on_build_output(&self, id: usize, line: &str);
+
+    /// Callback to invoke periodically while a source distribution build is running.
+    fn on_build_progress(&self, id: usize, elapsed: Duration);
+
     /// Callback to invoke when a repository checkout begins.
     fn on_checkout_start(&self, url: &DisplaySafeUrl, rev: &str) -> usize;
 
@@ -291,6 +298,14 @@ impl uv_distribution::Reporter for Facade {
         self.reporter.on_build_complete(source, id);
     }
 
+    fn on_build_output(&self, id: usize, line: &str) {
+        self.reporter.on_build_output(id, line);
+    }
+
+    fn on_build_progress(&self, id: usize, elapsed: Duration) {
+        self.reporter.on_build_progress(id, elapsed);
+    }
+
     fn on_checkout_start(&self, url: &DisplaySafeUrl, rev: &str) -> usize {
         self.reporter.on_checkout_start(url, rev)
     }
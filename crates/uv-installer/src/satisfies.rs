@@ -1,5 +1,8 @@
 use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::fmt::Debug;
+use std::path::Path;
+use std::str::FromStr;
 
 use same_file::is_same_file;
 use tracing::{debug, trace};
@@ -413,6 +416,15 @@ impl RequirementSatisfaction {
                         return Self::CacheInvalid;
                     }
                 }
+
+                // If the editable's static version or entry points changed, it is out of date,
+                // even if none of its cache keys were modified.
+                if *requested_editable == Some(true)
+                    && !uv_flags::contains(uv_flags::EnvironmentFlags::SKIP_EDITABLE_METADATA_CHECK)
+                    && editable_metadata_changed(requested_path, distribution)
+                {
+                    return Self::OutOfDate;
+                }
             }
         }
 
@@ -448,6 +460,124 @@ impl RequirementSatisfaction {
     }
 }
 
+/// The subset of a `pyproject.toml` that determines an editable's version and entry points.
+#[derive(Debug, serde::Deserialize)]
+struct PyProjectToml {
+    project: Option<Project>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct Project {
+    version: Option<String>,
+    #[serde(default)]
+    scripts: BTreeMap<String, String>,
+    #[serde(default)]
+    gui_scripts: BTreeMap<String, String>,
+    #[serde(default)]
+    entry_points: BTreeMap<String, BTreeMap<String, String>>,
+    #[serde(default)]
+    dynamic: Vec<String>,
+}
+
+/// Returns `true` if the static version or entry points declared in the `pyproject.toml` of an
+/// editable differ from those of the installed distribution.
+fn editable_metadata_changed(path: &Path, distribution: &InstalledDist) -> bool {
+    let Ok(contents) = fs_err::read_to_string(path.join("pyproject.toml")) else {
+        return false;
+    };
+    let Ok(PyProjectToml {
+        project: Some(project),
+    }) = toml::from_str::<PyProjectToml>(&contents)
+    else {
+        return false;
+    };
+    let is_dynamic = |field: &str| project.dynamic.iter().any(|dynamic| dynamic == field);
+
+    if !is_dynamic("version")
+        && let Some(version) = project
+            .version
+            .as_deref()
+            .and_then(|version| Version::from_str(version).ok())
+        && version != *distribution.version()
+    {
+        debug!("Editable version mismatch for: {distribution} (declared: {version})");
+        return true;
+    }
+
+    if is_dynamic("scripts") || is_dynamic("gui-scripts") || is_dynamic("entry-points") {
+        return false;
+    }
+
+    let mut declared = project.entry_points;
+    declared.insert("console_scripts".to_string(), project.scripts);
+    declared.insert("gui_scripts".to_string(), project.gui_scripts);
+    let declared = normalize_entry_points(declared);
+
+    let installed =
+        match fs_err::read_to_string(distribution.install_path().join("entry_points.txt")) {
+            Ok(contents) => parse_entry_points(&contents),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => BTreeMap::default(),
+            Err(err) => {
+                debug!("Failed to read entry points for {distribution}: {err}");
+                return false;
+            }
+        };
+
+    if declared != installed {
+        debug!("Editable entry points mismatch for: {distribution}");
+        return true;
+    }
+
+    false
+}
+
+/// Parse an `entry_points.txt` file into a map from group to entry point name to object reference.
+fn parse_entry_points(contents: &str) -> BTreeMap<String, BTreeMap<String, String>> {
+    let mut entry_points = BTreeMap::<String, BTreeMap<String, String>>::new();
+    let mut group = None;
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+        if let Some(name) = line
+            .strip_prefix('[')
+            .and_then(|line| line.strip_suffix(']'))
+        {
+            group = Some(name.trim().to_string());
+            continue;
+        }
+        if let (Some(group), Some((name, value))) = (group.as_ref(), line.split_once('=')) {
+            entry_points
+                .entry(group.clone())
+                .or_default()
+                .insert(name.trim().to_string(), value.trim().to_string());
+        }
+    }
+    normalize_entry_points(entry_points)
+}
+
+/// Remove empty groups and insignificant whitespace from a map of entry points.
+fn normalize_entry_points(
+    entry_points: BTreeMap<String, BTreeMap<String, String>>,
+) -> BTreeMap<String, BTreeMap<String, String>> {
+    entry_points
+        .into_iter()
+        .filter(|(_, entries)| !entries.is_empty())
+        .map(|(group, entries)| {
+            let entries = entries
+                .into_iter()
+                .map(|(name, value)| {
+                    let value = value.chars().filter(|c| !c.is_whitespace()).collect();
+                    (name, value)
+                })
+                .collect();
+            (group, entries)
+        })
+        .collect()
+}
+
 /// Determine the [`ConfigSettings`] for the given package name.
 fn config_settings_for<'settings>(
     name: &PackageName,
@@ -13,7 +13,7 @@ use url::Url;
 use uv_cache_key::{CacheKey, CacheKeyHasher};
 
 #[cfg_attr(not(feature = "non-pep508-extensions"), allow(unused_imports))]
-use uv_fs::{normalize_absolute_path, normalize_url_path};
+use uv_fs::{absolute_from, normalize_absolute_path, normalize_url_path};
 use uv_redacted::{DisplaySafeUrl, DisplaySafeUrlError};
 
 use crate::Pep508Url;
@@ -152,11 +152,7 @@ impl VerbatimUrl {
         let path = path.as_ref();
 
         // Convert the path to an absolute path, if necessary.
-        let path = if path.is_absolute() {
-            Cow::Borrowed(path)
-        } else {
-            Cow::Owned(base_dir.as_ref().join(path))
-        };
+        let path = absolute_from(path, base_dir.as_ref());
 
         let path = normalize_absolute_path(&path)
             .map_err(|err| VerbatimUrlError::Normalization(path.to_path_buf(), err))?;
@@ -591,14 +587,31 @@ pub(crate) fn strip_host(path: &str) -> &str {
         return path;
     }
 
-    // Ex) `file:///...`.
-    if let Some(path) = path.strip_prefix("//") {
-        return path;
+    if let Some(stripped) = path.strip_prefix("//") {
+        // Ex) `file://server/share/...`, which refers to the UNC path `\\server\share\...`.
+        //
+        // On Windows, retain the host, such that the path is resolved on the network share.
+        if cfg!(windows) && is_unc_host(stripped) {
+            return path;
+        }
+
+        // Ex) `file:///...`.
+        return stripped;
     }
 
     path
 }
 
+/// Returns `true` if the authority of a `file://` URL (i.e., the path following the leading `//`)
+/// names a server, as in `file://server/share/...`, rather than a local path, as in `file:///...`
+/// or `file://C:/...`.
+#[cfg(feature = "non-pep508-extensions")]
+fn is_unc_host(path: &str) -> bool {
+    let host = path.split(['/', '\\']).next().unwrap_or_default();
+    let is_drive = matches!(host.as_bytes(), [letter, b':' | b'|'] if letter.is_ascii_alphabetic());
+    !host.is_empty() && !is_drive
+}
+
 /// Returns `true` if a URL looks like a reference to a Git repository (e.g., `https://github.com/user/repo.git`).
 pub fn looks_like_git_repository(url: &Url) -> bool {
     matches!(
@@ -803,6 +816,62 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "non-pep508-extensions")]
+    fn host() {
+        assert_eq!(strip_host("///home/ferris/project"), "/home/ferris/project");
+        assert_eq!(
+            strip_host("//localhost/home/ferris/project"),
+            "/home/ferris/project"
+        );
+        assert_eq!(strip_host("//C:/Users/ferris"), "C:/Users/ferris");
+        assert_eq!(
+            strip_host("////fileserver/pywheels/pkg"),
+            "//fileserver/pywheels/pkg"
+        );
+        if cfg!(windows) {
+            assert_eq!(
+                strip_host("//fileserver/pywheels/pkg"),
+                "//fileserver/pywheels/pkg"
+            );
+        } else {
+            assert_eq!(
+                strip_host("//fileserver/pywheels/pkg"),
+                "fileserver/pywheels/pkg"
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(all(windows, feature = "non-pep508-extensions"))]
+    fn unc_path() {
+        let base_dir = Path::new(r"C:\Users\ferris\project");
+        for given in [
+            r"\\fileserver\py wheels\café",
+            "file://fileserver/py%20wheels/caf%C3%A9",
+            "file:////fileserver/py%20wheels/caf%C3%A9",
+        ] {
+            let url = <VerbatimUrl as Pep508Url>::parse_url(given, Some(base_dir)).unwrap();
+            assert_eq!(url.as_str(), "file://fileserver/py%20wheels/caf%C3%A9");
+            assert_eq!(
+                url.to_file_path().unwrap(),
+                Path::new(r"\\fileserver\py wheels\café")
+            );
+            assert_eq!(url.given(), Some(given));
+        }
+    }
+
+    #[test]
+    #[cfg(all(windows, feature = "non-pep508-extensions"))]
+    fn drive_relative_path() {
+        let base_dir = Path::new(r"C:\Users\ferris\project");
+        let url = VerbatimUrl::from_path(r"C:wheels\foo", base_dir).unwrap();
+        assert_eq!(
+            url.to_file_path().unwrap(),
+            Path::new(r"C:\Users\ferris\project\wheels\foo")
+        );
+    }
+
     #[test]
     fn hash_in_path() {
         let assert_path = |url: VerbatimUrl, path: &Path| {
@@ -264,6 +264,9 @@ pub enum PreviewFeature {
     NoDistutilsPatch = 1 << 38,
     IndexHashAlgorithm = 1 << 39,
     LockfileFormatCheck = 1 << 40,
+    WorkspaceMembers = 1 << 41,
+    LockDependencyCache = 1 << 42,
+    ExportPruneRootCheck = 1 << 43,
 }
 
 impl PreviewFeature {
@@ -310,6 +313,9 @@ impl PreviewFeature {
             Self::NoDistutilsPatch => "no-distutils-patch",
             Self::IndexHashAlgorithm => "index-hash-algorithm",
             Self::LockfileFormatCheck => "lockfile-format-check",
+            Self::WorkspaceMembers => "workspace-members",
+            Self::LockDependencyCache => "lock-dependency-cache",
+            Self::ExportPruneRootCheck => "export-prune-root-check",
         }
     }
 }
@@ -369,6 +375,9 @@ impl FromStr for PreviewFeature {
             "no-distutils-patch" => Self::NoDistutilsPatch,
             "index-hash-algorithm" => Self::IndexHashAlgorithm,
             "lockfile-format-check" => Self::LockfileFormatCheck,
+            "workspace-members" => Self::WorkspaceMembers,
+            "lock-dependency-cache" => Self::LockDependencyCache,
+            "export-prune-root-check" => Self::ExportPruneRootCheck,
             _ => return Err(PreviewFeatureParseError),
         })
     }
@@ -464,6 +473,14 @@ impl Preview {
         }
     }
 
+    /// Return a copy of this [`Preview`] with the given feature enabled.
+    #[must_use]
+    pub fn with(self, flag: PreviewFeature) -> Self {
+        Self {
+            flags: self.flags | flag,
+        }
+    }
+
     /// Check if a single feature is enabled.
     pub fn is_enabled(&self, flag: PreviewFeature) -> bool {
         self.flags.contains(flag)
@@ -687,6 +704,18 @@ mod tests {
             PreviewFeature::LockfileFormatCheck.as_str(),
             "lockfile-format-check"
         );
+        assert_eq!(
+            PreviewFeature::WorkspaceMembers.as_str(),
+            "workspace-members"
+        );
+        assert_eq!(
+            PreviewFeature::LockDependencyCache.as_str(),
+            "lock-dependency-cache"
+        );
+        assert_eq!(
+            PreviewFeature::ExportPruneRootCheck.as_str(),
+            "export-prune-root-check"
+        );
     }
 
     #[test]
@@ -0,0 +1,200 @@
+//! Check the metadata of a distribution before uploading it, similar to `twine check`.
+
+use std::path::Path;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use uv_distribution_filename::DistFilename;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_pypi_types::Metadata23;
+
+use crate::{PublishError, PublishPrepareError, metadata};
+
+/// A problem with the metadata of a distribution that would cause the index to reject it, or fail
+/// to render its description.
+#[derive(Error, Debug)]
+pub enum MetadataCheckError {
+    #[error("The name in the metadata (`{metadata}`) does not match the filename (`{filename}`)")]
+    NameMismatch {
+        metadata: String,
+        filename: PackageName,
+    },
+    #[error(
+        "The version in the metadata (`{metadata}`) does not match the filename (`{filename}`)"
+    )]
+    VersionMismatch { metadata: String, filename: Version },
+    #[error(
+        "Unsupported `Description-Content-Type`: `{0}` (expected `text/plain`, `text/x-rst`, or `text/markdown`)"
+    )]
+    UnsupportedContentType(String),
+    #[error("Unsupported Markdown variant: `{0}` (expected `GFM` or `CommonMark`)")]
+    UnsupportedMarkdownVariant(String),
+    #[error(
+        "The reStructuredText description would not render: title underline too short on line {0}"
+    )]
+    RstTitleUnderline(usize),
+}
+
+/// Check the metadata of a distribution.
+///
+/// Returns warnings for problems that don't prevent the distribution from being uploaded, and an
+/// error for problems that would cause the index to reject it or fail to render its description.
+pub async fn check_metadata(
+    file: &Path,
+    filename: &DistFilename,
+) -> Result<Vec<String>, PublishError> {
+    let metadata = metadata(file, filename)
+        .await
+        .map_err(|err| PublishError::PublishPrepare(file.to_path_buf(), Box::new(err)))?;
+    check(&metadata, filename).map_err(|err| {
+        PublishError::PublishPrepare(
+            file.to_path_buf(),
+            Box::new(PublishPrepareError::MetadataCheck(err)),
+        )
+    })
+}
+
+fn check(
+    metadata: &Metadata23,
+    filename: &DistFilename,
+) -> Result<Vec<String>, MetadataCheckError> {
+    if PackageName::from_str(&metadata.name).ok().as_ref() != Some(filename.name()) {
+        return Err(MetadataCheckError::NameMismatch {
+            metadata: metadata.name.clone(),
+            filename: filename.name().clone(),
+        });
+    }
+    if Version::from_str(&metadata.version).ok().as_ref() != Some(filename.version()) {
+        return Err(MetadataCheckError::VersionMismatch {
+            metadata: metadata.version.clone(),
+            filename: filename.version().clone(),
+        });
+    }
+
+    let mut warnings = Vec::new();
+
+    let content_type = if let Some(content_type) = &metadata.description_content_type {
+        ContentType::parse(content_type)?
+    } else {
+        warnings
+            .push("`Description-Content-Type` is missing, defaulting to `text/x-rst`".to_string());
+        ContentType::Rst
+    };
+
+    match metadata
+        .description
+        .as_deref()
+        .filter(|description| !description.trim().is_empty())
+    {
+        None => warnings.push("`Description` is missing".to_string()),
+        Some(description) => {
+            if content_type == ContentType::Rst {
+                check_rst(description)?;
+            }
+        }
+    }
+
+    Ok(warnings)
+}
+
+/// The description content types supported by PyPI.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum ContentType {
+    Plain,
+    Rst,
+    Markdown,
+}
+
+impl ContentType {
+    /// Parse a `Description-Content-Type`, e.g., `text/markdown; charset=UTF-8; variant=GFM`.
+    fn parse(content_type: &str) -> Result<Self, MetadataCheckError> {
+        let mut parts = content_type.split(';');
+        let mime = parts.next().unwrap_or_default().trim();
+        let parsed = match mime.to_ascii_lowercase().as_str() {
+            "text/plain" => Self::Plain,
+            "text/x-rst" => Self::Rst,
+            "text/markdown" => Self::Markdown,
+            _ => {
+                return Err(MetadataCheckError::UnsupportedContentType(
+                    content_type.to_string(),
+                ));
+            }
+        };
+
+        if parsed == Self::Markdown {
+            for parameter in parts {
+                if let Some((key, value)) = parameter.split_once('=')
+                    && key.trim().eq_ignore_ascii_case("variant")
+                {
+                    let variant = value.trim().trim_matches('"');
+                    if !variant.eq_ignore_ascii_case("gfm")
+                        && !variant.eq_ignore_ascii_case("commonmark")
+                    {
+                        return Err(MetadataCheckError::UnsupportedMarkdownVariant(
+                            variant.to_string(),
+                        ));
+                    }
+                }
+            }
+        }
+
+        Ok(parsed)
+    }
+}
+
+/// Check that the section titles in a reStructuredText document are well-formed.
+///
+/// PyPI refuses to render descriptions for which docutils emits a warning. The most common cause is
+/// a section title whose underline is shorter than the title itself; docutils treats underlines of
+/// fewer than four characters as ordinary text instead.
+fn check_rst(description: &str) -> Result<(), MetadataCheckError> {
+    let lines = description.lines().collect::<Vec<_>>();
+    for (index, line) in lines.iter().enumerate().skip(1) {
+        if !is_adornment(line) {
+            continue;
+        }
+        let title = lines[index - 1];
+        if title.trim().is_empty() || title.starts_with(char::is_whitespace) || is_adornment(title)
+        {
+            continue;
+        }
+        // A title must start a new block.
+        if index >= 2 && !lines[index - 2].trim().is_empty() && !is_adornment(lines[index - 2]) {
+            continue;
+        }
+        let underline = line.trim_end().chars().count();
+        if underline >= 4 && underline < title.trim_end().chars().count() {
+            return Err(MetadataCheckError::RstTitleUnderline(index + 1));
+        }
+    }
+    Ok(())
+}
+
+/// Returns `true` if the line is a reStructuredText section adornment, i.e., a single punctuation
+/// character repeated.
+fn is_adornment(line: &str) -> bool {
+    let line = line.trim_end();
+    let mut chars = line.chars();
+    let Some(first) = chars.next() else {
+        return false;
+    };
+    first.is_ascii_punctuation() && line.chars().count() >= 2 && chars.all(|c| c == first)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::check_rst;
+
+    #[test]
+    fn rst_title_underline() {
+        assert!(check_rst("Title\n=====\n\nText\n").is_ok());
+        assert!(check_rst("=======\n Title\n=======\n").is_ok());
+        assert!(check_rst("A long title\n====\n").is_err());
+        // Too short to be an underline.
+        assert!(check_rst("A long title\n==\n").is_ok());
+        // Indented text is not a title.
+        assert!(check_rst("::\n\n    A long title\n    ====\n").is_ok());
+    }
+}
@@ -1,3 +1,4 @@
+mod check;
 mod trusted_publishing;
 
 use std::collections::BTreeSet;
@@ -27,7 +28,8 @@ use uv_auth::{Credentials, PyxTokenStore, Realm};
 use uv_cache::{Cache, Refresh};
 use uv_client::{
     BaseClient, ClientBuildError, DEFAULT_MAX_REDIRECTS, MetadataFormat, OwnedArchive,
-    RegistryClientBuilder, RequestBuilder, RetryParsingError, RetryState,
+    RegistryClient, RegistryClientBuilder, RequestBuilder, RetryParsingError, RetryState,
+    SimpleDetailMetadata,
 };
 use uv_configuration::{KeyringProviderType, TrustedPublishing};
 use uv_distribution_filename::{DistFilename, SourceDistExtension, SourceDistFilename};
@@ -35,10 +37,12 @@ use uv_distribution_types::{IndexCapabilities, IndexUrl};
 use uv_extract::hash::{HashReader, Hasher};
 use uv_fs::{ProgressReader, Simplified};
 use uv_metadata::read_metadata_async_seek;
+use uv_normalize::PackageName;
 use uv_pypi_types::{HashAlgorithm, HashDigest, Metadata23, MetadataError};
 use uv_redacted::{DisplaySafeUrl, DisplaySafeUrlError};
 use uv_warnings::warn_user;
 
+pub use crate::check::{MetadataCheckError, check_metadata};
 use crate::trusted_publishing::pypi::PyPIPublishingService;
 use crate::trusted_publishing::pyx::PyxPublishingService;
 use crate::trusted_publishing::{
@@ -54,6 +58,12 @@ pub enum PublishError {
     Glob(#[from] GlobError),
     #[error("Path patterns didn't match any wheels or source distributions")]
     NoFiles,
+    #[error("No build found in `{}`, run `uv build` before publishing with `--from-build`", _0.user_display())]
+    MissingBuildManifest(PathBuf),
+    #[error("Failed to read the build output in `{}`", _0.user_display())]
+    ReadBuildOutput(PathBuf, #[source] io::Error),
+    #[error("Distribution from the most recent build is missing: `{}`", _0.user_display())]
+    MissingBuildArtifact(PathBuf),
     #[error(transparent)]
     Fmt(#[from] fmt::Error),
     #[error("File is neither a wheel nor a source distribution: `{}`", _0.user_display())]
@@ -78,6 +88,16 @@ pub enum PublishError {
     MixedCredentials(String),
     #[error("Failed to query check URL")]
     CheckUrlIndex(#[source] uv_client::Error),
+    #[error("Failed to query check URL: {0}")]
+    CheckUrlRequest(Box<DisplaySafeUrl>, #[source] reqwest_middleware::Error),
+    #[error(
+        "Failed to query check URL: {0} rejected the credentials (status code {1}); check that they grant read access to the index"
+    )]
+    CheckUrlAuthentication(Box<DisplaySafeUrl>, StatusCode),
+    #[error("Failed to query check URL: {0} returned status code {1}")]
+    CheckUrlStatus(Box<DisplaySafeUrl>, StatusCode),
+    #[error("Check URL {0} does not implement the Simple API: {1}")]
+    CheckUrlMalformed(Box<DisplaySafeUrl>, String),
     #[error(transparent)]
     ClientBuild(#[from] ClientBuildError),
     #[error(
@@ -121,6 +141,8 @@ pub enum PublishPrepareError {
     Read(String, #[source] io::Error),
     #[error("Invalid PEP 740 attestation (not JSON): `{0}`")]
     InvalidAttestation(PathBuf, #[source] serde_json::Error),
+    #[error(transparent)]
+    MetadataCheck(#[from] MetadataCheckError),
 }
 
 /// Failure in or after (HTTP) transport for a specific file.
@@ -176,6 +198,166 @@ pub struct CheckUrlClient<'a> {
     pub cache: &'a Cache,
 }
 
+/// The Simple API support detected on the check URL for a project.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckUrlCapability {
+    /// The index serves the project page as JSON (PEP 691).
+    Json,
+    /// The index serves the project page as HTML (PEP 503).
+    Html,
+    /// The index labels the project page as JSON, but serves HTML.
+    MislabeledHtml,
+    /// The project doesn't exist on the index yet (e.g., for a first release), so no files
+    /// have been uploaded.
+    ProjectNotFound,
+}
+
+impl CheckUrlClient<'_> {
+    /// Probe the check URL for a project before uploading any files.
+    ///
+    /// Distinguishes a project that doesn't exist yet from authentication failures and from
+    /// responses that aren't Simple API pages, such that we can fail before uploading.
+    pub async fn probe(
+        &self,
+        package_name: &PackageName,
+    ) -> Result<CheckUrlCapability, PublishError> {
+        let url = check_url_project_url(&self.index_url, package_name)?;
+        let registry_client = self
+            .registry_client_builder
+            .clone()
+            .wrap_existing(self.client)?;
+
+        debug!("Probing check URL for {package_name}: {url}");
+        let response = registry_client
+            .uncached_client(&url)
+            .get(Url::from(url.clone()))
+            .header(reqwest::header::ACCEPT, SIMPLE_API_ACCEPT)
+            .send()
+            .await
+            .map_err(|err| PublishError::CheckUrlRequest(Box::new(url.clone()), err))?;
+
+        let status_code = response.status();
+        debug!("Response code for {url}: {status_code}");
+        match status_code {
+            StatusCode::NOT_FOUND | StatusCode::GONE => {
+                return Ok(CheckUrlCapability::ProjectNotFound);
+            }
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+                return Err(PublishError::CheckUrlAuthentication(
+                    Box::new(url),
+                    status_code,
+                ));
+            }
+            status_code if !status_code.is_success() => {
+                return Err(PublishError::CheckUrlStatus(Box::new(url), status_code));
+            }
+            _ => {}
+        }
+
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|content_type| content_type.to_str().ok())
+            .map(|content_type| {
+                content_type
+                    .split(';')
+                    .next()
+                    .unwrap_or(content_type)
+                    .trim()
+                    .to_ascii_lowercase()
+            });
+        let body = response
+            .text()
+            .await
+            .map_err(|err| PublishError::CheckUrlRequest(Box::new(url.clone()), err.into()))?;
+        trace!("Response content for {url}: {body}");
+
+        classify_simple_response(content_type.as_deref(), &body)
+            .map_err(|reason| PublishError::CheckUrlMalformed(Box::new(url), reason))
+    }
+}
+
+/// The `Accept` header for Simple API requests, preferring JSON over HTML.
+const SIMPLE_API_ACCEPT: &str = "application/vnd.pypi.simple.v1+json, application/vnd.pypi.simple.v1+html;q=0.2, text/html;q=0.01";
+
+/// Determine the Simple API format of a project page from its content type and body.
+fn classify_simple_response(
+    content_type: Option<&str>,
+    body: &str,
+) -> Result<CheckUrlCapability, String> {
+    let is_markup = body.trim_start().starts_with('<');
+    match content_type {
+        Some("application/vnd.pypi.simple.v1+json") => {
+            if serde_json::from_str::<serde_json::Value>(body).is_ok() {
+                Ok(CheckUrlCapability::Json)
+            } else if is_markup {
+                Ok(CheckUrlCapability::MislabeledHtml)
+            } else {
+                Err("the response is labeled as JSON, but is neither JSON nor HTML".to_string())
+            }
+        }
+        Some("application/vnd.pypi.simple.v1+html" | "text/html") => {
+            if is_markup {
+                Ok(CheckUrlCapability::Html)
+            } else {
+                Err("the response is labeled as HTML, but doesn't contain any markup".to_string())
+            }
+        }
+        Some(content_type) => Err(format!("unsupported content type `{content_type}`")),
+        None => Err("the response is missing a content type".to_string()),
+    }
+}
+
+/// Return the Simple API URL for a project on the check index.
+fn check_url_project_url(
+    index_url: &IndexUrl,
+    package_name: &PackageName,
+) -> Result<DisplaySafeUrl, PublishError> {
+    let mut url = index_url.url().clone();
+    url.path_segments_mut()
+        .map_err(|()| {
+            PublishError::CheckUrlIndex(
+                uv_client::ErrorKind::CannotBeABase(index_url.url().clone()).into(),
+            )
+        })?
+        .pop_if_empty()
+        .push(package_name.as_ref())
+        // The URL must end in a trailing slash for relative links to resolve correctly.
+        .push("");
+    Ok(url)
+}
+
+/// Fetch a project page from the check URL as HTML.
+///
+/// Used for indexes that label HTML responses as JSON, which the registry client rejects.
+async fn fetch_simple_html(
+    registry_client: &RegistryClient,
+    index_url: &IndexUrl,
+    package_name: &PackageName,
+) -> Result<SimpleDetailMetadata, PublishError> {
+    let url = check_url_project_url(index_url, package_name)?;
+    let response = registry_client
+        .uncached_client(&url)
+        .get(Url::from(url.clone()))
+        .header(reqwest::header::ACCEPT, "text/html")
+        .send()
+        .await
+        .map_err(|err| PublishError::CheckUrlRequest(Box::new(url.clone()), err))?;
+
+    let status_code = response.status();
+    if !status_code.is_success() {
+        return Err(PublishError::CheckUrlStatus(Box::new(url), status_code));
+    }
+
+    // Use the response URL as the base for relative links, in case of redirects.
+    let base = DisplaySafeUrl::from_url(response.url().clone());
+    let body = response
+        .text()
+        .await
+        .map_err(|err| PublishError::CheckUrlRequest(Box::new(url), err.into()))?;
+    SimpleDetailMetadata::from_html(&body, package_name, &base).map_err(PublishError::CheckUrlIndex)
+}
+
 impl PublishSendError {
     /// Extract `code` from the PyPI json error response, if any.
     ///
@@ -398,6 +580,86 @@ pub fn group_files_for_publishing(
     Ok(group_files(unroll_paths(paths)?, no_attestations))
 }
 
+/// The name of the file in which `uv build` records the distributions it produced.
+pub const BUILD_MANIFEST: &str = ".uv-build-manifest";
+
+/// Record the distributions produced by a build in the output directory, replacing the record of
+/// any previous build.
+pub fn write_build_manifest<'a>(
+    output_dir: &Path,
+    filenames: impl IntoIterator<Item = &'a str>,
+) -> io::Result<()> {
+    let mut contents = String::new();
+    for filename in filenames.into_iter().collect::<BTreeSet<_>>() {
+        contents.push_str(filename);
+        contents.push('\n');
+    }
+    fs_err::write(output_dir.join(BUILD_MANIFEST), contents)
+}
+
+/// Remove the record of a previous build from the output directory, if it exists.
+pub fn remove_build_manifest(output_dir: &Path) -> io::Result<()> {
+    match fs_err::remove_file(output_dir.join(BUILD_MANIFEST)) {
+        Ok(()) => Ok(()),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+        Err(err) => Err(err),
+    }
+}
+
+/// Collect the source distributions and wheels produced by the most recent `uv build` into the
+/// given output directory, along with their attestations.
+///
+/// Unlike [`group_files_for_publishing`], files in the output directory that weren't produced by
+/// the most recent build are ignored.
+pub fn group_build_files_for_publishing(
+    output_dir: &Path,
+    no_attestations: bool,
+) -> Result<Vec<UploadDistribution>, PublishError> {
+    let manifest = output_dir.join(BUILD_MANIFEST);
+    let contents = match fs_err::read_to_string(&manifest) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            return Err(PublishError::MissingBuildManifest(output_dir.to_path_buf()));
+        }
+        Err(err) => return Err(PublishError::ReadBuildOutput(output_dir.to_path_buf(), err)),
+    };
+
+    let filenames = contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<BTreeSet<_>>();
+
+    let mut files = Vec::new();
+    for filename in &filenames {
+        let file = output_dir.join(filename);
+        if !file.is_file() {
+            return Err(PublishError::MissingBuildArtifact(file));
+        }
+        files.push(file);
+    }
+
+    // Include any attestations that were generated for the distributions after the build, e.g.,
+    // `foo-1.2.3.tar.gz.publish.attestation`.
+    let read_error = |err| PublishError::ReadBuildOutput(output_dir.to_path_buf(), err);
+    for entry in fs_err::read_dir(output_dir).map_err(read_error)? {
+        let path = entry.map_err(read_error)?.path();
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        if let Some(dist_name) = name
+            .strip_suffix(".attestation")
+            .and_then(|name| name.rsplit_once('.'))
+            .map(|(dist_name, _)| dist_name)
+            && filenames.contains(dist_name)
+        {
+            files.push(path);
+        }
+    }
+
+    Ok(group_files(files, no_attestations))
+}
+
 pub enum TrustedPublishResult {
     /// We didn't check for trusted publishing.
     Skipped,
@@ -967,7 +1229,7 @@ pub async fn check_url(
         .wrap_existing(client)?;
 
     debug!("Checking for {filename} in the registry");
-    let response = match registry_client
+    let simple_metadata = match registry_client
         .simple_detail(
             filename.name(),
             Some(index_url.into()),
@@ -976,24 +1238,26 @@ pub async fn check_url(
         )
         .await
     {
-        Ok(response) => response,
-        Err(err) => {
-            return match err.kind() {
-                uv_client::ErrorKind::RemotePackageNotFound(_) => {
-                    // The package doesn't exist, so we can't have uploaded it.
-                    warn!(
-                        "Package not found in the registry; skipping upload check for {filename}"
-                    );
-                    Ok(false)
-                }
-                _ => Err(PublishError::CheckUrlIndex(err)),
+        Ok(response) => {
+            let [(_, MetadataFormat::Simple(simple_metadata))] = response.as_slice() else {
+                unreachable!("We queried a single index, we must get a single response");
             };
+            OwnedArchive::deserialize(simple_metadata)
         }
+        Err(err) => match err.kind() {
+            uv_client::ErrorKind::RemotePackageNotFound(_) => {
+                // The package doesn't exist, so we can't have uploaded it.
+                warn!("Package not found in the registry; skipping upload check for {filename}");
+                return Ok(false);
+            }
+            uv_client::ErrorKind::BadJson { .. } => {
+                // Some indexes serve HTML while labeling it as JSON.
+                debug!("Check URL returned invalid JSON for {filename}, retrying as HTML");
+                fetch_simple_html(&registry_client, index_url, filename.name()).await?
+            }
+            _ => return Err(PublishError::CheckUrlIndex(err)),
+        },
     };
-    let [(_, MetadataFormat::Simple(simple_metadata))] = response.as_slice() else {
-        unreachable!("We queried a single index, we must get a single response");
-    };
-    let simple_metadata = OwnedArchive::deserialize(simple_metadata);
     let Some(metadatum) = simple_metadata
         .iter()
         .find(|metadatum| &metadatum.version == filename.version())
@@ -1521,19 +1785,22 @@ async fn handle_response(
 #[cfg(test)]
 mod tests {
     use std::path::PathBuf;
+    use std::str::FromStr;
     use std::sync::Arc;
 
     use insta::{allow_duplicates, assert_debug_snapshot, assert_snapshot};
     use itertools::Itertools;
     use uv_auth::Credentials;
-    use uv_client::{AuthIntegration, BaseClientBuilder, RedirectPolicy};
+    use uv_cache::Cache;
+    use uv_client::{AuthIntegration, BaseClientBuilder, RedirectPolicy, RegistryClientBuilder};
     use uv_distribution_filename::DistFilename;
+    use uv_distribution_types::{IndexCapabilities, IndexUrl};
     use uv_pypi_types::{HashDigest, Metadata23};
     use uv_redacted::DisplaySafeUrl;
 
     use crate::{
-        FormMetadata, PublishError, Reporter, UploadDistribution, build_upload_request,
-        group_files, upload,
+        CheckUrlCapability, CheckUrlClient, FormMetadata, PublishError, Reporter,
+        UploadDistribution, build_upload_request, check_url, group_files, upload,
     };
     use tokio::sync::Semaphore;
     use uv_errors::{ErrorOptions, Hints, write_error_chain_with_options};
@@ -2374,4 +2641,194 @@ mod tests {
         "
         );
     }
+
+    const TQDM_WHEEL: &str =
+        "tqdm-4.66.1-py3-none-manylinux_2_12_x86_64.manylinux2010_x86_64.musllinux_1_1_x86_64.whl";
+    const TQDM_WHEEL_SHA256: &str =
+        "0d88ca657bc6b64995ca416e0c59c71af85cc10015d940fa446c42a8b485ee1c";
+
+    /// A PEP 691 project page, as served by PyPI.
+    fn pypi_json_fixture() -> String {
+        format!(
+            r#"{{"meta": {{"api-version": "1.1", "_last-serial": 19342612}}, "name": "tqdm", "versions": ["4.66.1"], "files": [{{"filename": "{TQDM_WHEEL}", "url": "https://files.pythonhosted.org/packages/00/e5/{TQDM_WHEEL}", "hashes": {{"sha256": "{TQDM_WHEEL_SHA256}"}}, "requires-python": ">=3.7", "size": 78258, "upload-time": "2023-08-08T12:00:00.000000Z", "yanked": false}}]}}"#
+        )
+    }
+
+    /// A PEP 503 project page, as served by `TestPyPI`.
+    fn testpypi_html_fixture() -> String {
+        format!(
+            r#"<!DOCTYPE html>
+<html>
+  <head>
+    <meta name="pypi:repository-version" content="1.1">
+    <title>Links for tqdm</title>
+  </head>
+  <body>
+    <h1>Links for tqdm</h1>
+    <a href="https://test-files.pythonhosted.org/packages/00/e5/{TQDM_WHEEL}#sha256={TQDM_WHEEL_SHA256}" data-requires-python="&gt;=3.7">{TQDM_WHEEL}</a><br />
+  </body>
+</html>"#
+        )
+    }
+
+    /// A PEP 503 project page with relative links, as served by Artifactory.
+    fn artifactory_html_fixture() -> String {
+        format!(
+            r#"<html>
+<head><meta name="pypi:repository-version" content="1.0"><title>Links for tqdm</title></head>
+<body>
+<h1>Links for tqdm</h1>
+<a href="../../packages/packages/00/e5/{TQDM_WHEEL}#sha256={TQDM_WHEEL_SHA256}" rel="internal">{TQDM_WHEEL}</a><br/>
+</body>
+</html>"#
+        )
+    }
+
+    /// Run the check URL preflight and the upload check for the tqdm wheel against a mock index.
+    async fn mock_server_check_url(
+        mock_server: &MockServer,
+    ) -> (
+        Result<CheckUrlCapability, PublishError>,
+        Result<bool, PublishError>,
+    ) {
+        let file = PathBuf::from("../../test/links/").join(TQDM_WHEEL);
+        let filename = DistFilename::try_from_normalized_filename(TQDM_WHEEL).unwrap();
+
+        let cache = Cache::temp().unwrap();
+        let client_builder = BaseClientBuilder::default().retries(0);
+        let client = client_builder
+            .clone()
+            .build()
+            .expect("failed to build base client");
+        let check_url_client = CheckUrlClient {
+            index_url: IndexUrl::from_str(&format!("{}/simple/", mock_server.uri())).unwrap(),
+            registry_client_builder: RegistryClientBuilder::new(client_builder, cache.clone()),
+            client: &client,
+            index_capabilities: IndexCapabilities::default(),
+            cache: &cache,
+        };
+
+        let probe = check_url_client.probe(filename.name()).await;
+        let exists = check_url(
+            &check_url_client,
+            &file,
+            &filename,
+            &Semaphore::new(1),
+            Arc::new(DummyReporter),
+        )
+        .await;
+        (probe, exists)
+    }
+
+    fn format_error(err: &PublishError, mock_server: &MockServer) -> String {
+        let mut capture = String::new();
+        write_error_chain_with_options(
+            err,
+            Hints::none(),
+            ErrorOptions::default().with_stream(&mut capture),
+        )
+        .unwrap();
+        let capture = capture.replace(&mock_server.uri(), "[SERVER]");
+        anstream::adapter::strip_str(&capture).to_string()
+    }
+
+    #[tokio::test]
+    async fn check_url_pypi_json() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/simple/tqdm/"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_raw(pypi_json_fixture(), "application/vnd.pypi.simple.v1+json"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let (probe, exists) = mock_server_check_url(&mock_server).await;
+        assert_eq!(probe.unwrap(), CheckUrlCapability::Json);
+        assert!(exists.unwrap());
+    }
+
+    #[tokio::test]
+    async fn check_url_testpypi_html() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/simple/tqdm/"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_raw(testpypi_html_fixture(), "text/html"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let (probe, exists) = mock_server_check_url(&mock_server).await;
+        assert_eq!(probe.unwrap(), CheckUrlCapability::Html);
+        assert!(exists.unwrap());
+    }
+
+    /// Artifactory can label HTML project pages as JSON; we fall back to parsing them as HTML.
+    #[tokio::test]
+    async fn check_url_artifactory_mislabeled_html() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/simple/tqdm/"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(
+                artifactory_html_fixture(),
+                "application/vnd.pypi.simple.v1+json",
+            ))
+            .mount(&mock_server)
+            .await;
+
+        let (probe, exists) = mock_server_check_url(&mock_server).await;
+        assert_eq!(probe.unwrap(), CheckUrlCapability::MislabeledHtml);
+        assert!(exists.unwrap());
+    }
+
+    /// PyPI and `TestPyPI` return a 404 for projects that haven't been uploaded yet.
+    #[tokio::test]
+    async fn check_url_first_release() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/simple/tqdm/"))
+            .respond_with(ResponseTemplate::new(404).set_body_raw("Not Found", "text/plain"))
+            .mount(&mock_server)
+            .await;
+
+        let (probe, exists) = mock_server_check_url(&mock_server).await;
+        assert_eq!(probe.unwrap(), CheckUrlCapability::ProjectNotFound);
+        assert!(!exists.unwrap());
+    }
+
+    #[tokio::test]
+    async fn check_url_authentication() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/simple/tqdm/"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&mock_server)
+            .await;
+
+        let (probe, _) = mock_server_check_url(&mock_server).await;
+        assert_snapshot!(
+            format_error(&probe.unwrap_err(), &mock_server),
+            @"error: Failed to query check URL: [SERVER]/simple/tqdm/ rejected the credentials (status code 401 Unauthorized); check that they grant read access to the index"
+        );
+    }
+
+    #[tokio::test]
+    async fn check_url_malformed() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/simple/tqdm/"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_raw("tqdm-4.66.1.tar.gz", "text/plain"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let (probe, _) = mock_server_check_url(&mock_server).await;
+        assert_snapshot!(
+            format_error(&probe.unwrap_err(), &mock_server),
+            @"error: Check URL [SERVER]/simple/tqdm/ does not implement the Simple API: unsupported content type `text/plain`"
+        );
+    }
 }
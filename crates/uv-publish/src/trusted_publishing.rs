@@ -10,6 +10,7 @@ use std::fmt::Display;
 use thiserror::Error;
 use uv_redacted::{DisplaySafeUrl, DisplaySafeUrlError};
 use uv_static::EnvVars;
+use uv_warnings::warn_user;
 
 pub(crate) mod pypi;
 pub(crate) mod pyx;
@@ -29,6 +30,11 @@ pub enum TrustedPublishingError {
     /// publishing outside of something like GitHub Actions or GitLab CI.
     #[error("No OIDC token discovered: are you in a supported trusted publishing environment?")]
     NoToken,
+    /// Running in GitLab CI, but the job doesn't request an ID token for the index's audience.
+    #[error(
+        "Running in GitLab CI, but no OIDC token was found in `{0}`: add `{0}` to the job's `id_tokens` with `aud: {1}`"
+    )]
+    GitLabIdTokenMissing(String, String),
     #[error("Failed to fetch: `{0}`")]
     Reqwest(DisplaySafeUrl, #[source] reqwest::Error),
     #[error("Failed to fetch: `{0}`")]
@@ -173,6 +179,20 @@ async fn get_oidc_token(
     let detector = ambient_id::Detector::new_with_client(client.clone());
 
     match detector.detect(audience).await {
+        // GitLab CI only provides ID tokens that are requested explicitly by the job, so a missing
+        // token is a configuration error rather than an unsupported environment.
+        Ok(None) if env::var(EnvVars::GITLAB_CI).is_ok_and(|value| value == "true") => {
+            if env::var_os(EnvVars::CI_JOB_JWT_V2).is_some() {
+                warn_user!(
+                    "`{}` is set, but can't be used for trusted publishing, since it isn't issued for the `{audience}` audience",
+                    EnvVars::CI_JOB_JWT_V2
+                );
+            }
+            Err(TrustedPublishingError::GitLabIdTokenMissing(
+                gitlab_id_token_variable(audience),
+                audience.to_string(),
+            ))
+        }
         Ok(token) => Ok(token),
         // Specialize the error case insufficient permissions error case,
         // since we can offer the user a hint about fixing their permissions.
@@ -185,6 +205,22 @@ async fn get_oidc_token(
     }
 }
 
+/// The environment variable in which GitLab CI provides the ID token for an audience, e.g.,
+/// `PYPI_ID_TOKEN` for `pypi`.
+fn gitlab_id_token_variable(audience: &str) -> String {
+    let audience = audience
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>();
+    format!("{audience}_ID_TOKEN")
+}
+
 /// Parse the JSON Web Token that the OIDC token is.
 ///
 /// See: <https://github.com/pypa/gh-action-pypi-publish/blob/db8f07d3871a0a180efa06b95d467625c19d5d5f/oidc-exchange.py#L165-L184>
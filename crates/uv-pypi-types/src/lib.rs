@@ -9,6 +9,7 @@ pub use metadata::*;
 pub use module_name::*;
 pub use parsed_url::*;
 pub use project_status::*;
+pub use project_tracking::*;
 pub use scheme::*;
 pub use simple_json::*;
 pub use supported_environments::*;
@@ -24,6 +25,7 @@ mod metadata;
 mod module_name;
 mod parsed_url;
 mod project_status;
+mod project_tracking;
 mod scheme;
 mod simple_json;
 mod supported_environments;
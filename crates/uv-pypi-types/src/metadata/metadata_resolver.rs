@@ -13,7 +13,9 @@ use uv_pep508::Requirement;
 use crate::lenient_requirement::LenientRequirement;
 use crate::metadata::Headers;
 use crate::metadata::pyproject_toml::PyProjectToml;
-use crate::{LenientVersionSpecifiers, MetadataError, VerbatimParsedUrl, metadata};
+use crate::{
+    LenientVersionSpecifiers, MetadataError, MetadataVersion, VerbatimParsedUrl, metadata,
+};
 
 /// A subset of the full core metadata specification, including only the
 /// fields that are relevant to dependency resolution.
@@ -36,6 +38,9 @@ pub struct ResolutionMetadata {
     /// Whether the version field is dynamic.
     #[serde(default)]
     pub dynamic: bool,
+    /// The core metadata version declared by the distribution, if known.
+    #[serde(default)]
+    pub metadata_version: Option<MetadataVersion>,
 }
 
 /// From <https://github.com/PyO3/python-pkginfo-rs/blob/d719988323a0cfea86d4737116d7917f30e819e2/src/metadata.rs#LL78C2-L91C26>
@@ -80,6 +85,18 @@ impl ResolutionMetadata {
         let dynamic = headers
             .get_all_values("Dynamic")
             .any(|field| field == "Version");
+        let metadata_version =
+            headers
+                .get_first_value("Metadata-Version")
+                .and_then(
+                    |metadata_version| match MetadataVersion::from_str(&metadata_version) {
+                        Ok(metadata_version) => Some(metadata_version),
+                        Err(err) => {
+                            warn!("Ignoring invalid metadata version: {err}");
+                            None
+                        }
+                    },
+                );
 
         Ok(Self {
             name,
@@ -88,6 +105,7 @@ impl ResolutionMetadata {
             requires_python,
             provides_extra,
             dynamic,
+            metadata_version,
         })
     }
 
@@ -165,6 +183,7 @@ impl ResolutionMetadata {
             requires_python,
             provides_extra,
             dynamic,
+            metadata_version: Some(MetadataVersion::new(major, minor)),
         })
     }
 
@@ -266,6 +285,7 @@ impl ResolutionMetadata {
             requires_python,
             provides_extra,
             dynamic,
+            metadata_version: None,
         })
     }
 }
@@ -294,6 +314,7 @@ mod tests {
         let meta = ResolutionMetadata::parse_metadata(s.as_bytes()).unwrap();
         assert_eq!(meta.name, PackageName::from_str("asdf").unwrap());
         assert_eq!(meta.version, Version::new([1, 0]));
+        assert_eq!(meta.metadata_version, Some(MetadataVersion::new(1, 0)));
 
         let s = "Metadata-Version: 1.0\nName: asdf\nVersion: 1.0\nAuthor: 中文\n\n一个 Python 包";
         let meta = ResolutionMetadata::parse_metadata(s.as_bytes()).unwrap();
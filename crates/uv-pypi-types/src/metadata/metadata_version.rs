@@ -0,0 +1,115 @@
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::MetadataError;
+use crate::metadata::parse_version;
+
+/// A core metadata version, as declared by the `Metadata-Version` field (e.g., `2.1`).
+///
+/// See: <https://packaging.python.org/en/latest/specifications/core-metadata/#metadata-version>
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    rkyv::Archive,
+    rkyv::Deserialize,
+    rkyv::Serialize,
+)]
+#[rkyv(derive(Debug))]
+pub struct MetadataVersion {
+    major: u8,
+    minor: u8,
+}
+
+impl MetadataVersion {
+    /// Create a [`MetadataVersion`] from its major and minor components.
+    pub const fn new(major: u8, minor: u8) -> Self {
+        Self { major, minor }
+    }
+
+    /// Return the major component of the version.
+    pub const fn major(self) -> u8 {
+        self.major
+    }
+
+    /// Return the minor component of the version.
+    pub const fn minor(self) -> u8 {
+        self.minor
+    }
+}
+
+impl FromStr for MetadataVersion {
+    type Err = MetadataError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (major, minor) = parse_version(s.trim())?;
+        Ok(Self { major, minor })
+    }
+}
+
+impl Display for MetadataVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+impl Serialize for MetadataVersion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for MetadataVersion {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = <Cow<'_, str>>::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for MetadataVersion {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("MetadataVersion")
+    }
+
+    fn json_schema(_generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "pattern": r"^\d+\.\d+$",
+            "description": "A core metadata version, like `2.2`."
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::MetadataVersion;
+
+    #[test]
+    fn ordering() {
+        let v1_2 = MetadataVersion::from_str("1.2").unwrap();
+        let v2_1 = MetadataVersion::from_str("2.1").unwrap();
+        let v2_10 = MetadataVersion::from_str("2.10").unwrap();
+        assert!(v1_2 < v2_1);
+        assert!(v2_1 < v2_10);
+        assert_eq!(v2_10.to_string(), "2.10");
+        assert!(MetadataVersion::from_str("2").is_err());
+        assert!(MetadataVersion::from_str("two.one").is_err());
+    }
+}
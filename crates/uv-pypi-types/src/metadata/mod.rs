@@ -2,6 +2,7 @@ mod build_requires;
 mod metadata10;
 mod metadata23;
 mod metadata_resolver;
+mod metadata_version;
 mod pyproject_toml;
 mod requires_dist;
 
@@ -18,6 +19,7 @@ use crate::VerbatimParsedUrl;
 
 pub use build_requires::BuildRequires;
 pub use metadata_resolver::ResolutionMetadata;
+pub use metadata_version::MetadataVersion;
 pub use metadata10::Metadata10;
 pub use metadata23::{Keywords, Metadata23, ProjectUrls};
 pub use pyproject_toml::PyProjectToml;
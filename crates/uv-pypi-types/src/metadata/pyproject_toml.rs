@@ -1,4 +1,5 @@
 use std::fmt::{Debug, Display};
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 use indexmap::IndexMap;
@@ -29,6 +30,37 @@ impl PyProjectToml {
         Ok(pyproject_toml)
     }
 
+    /// Return the requirements file declared via `tool.uv.static-dependencies`, if the project
+    /// declares its `dependencies` as dynamic.
+    pub fn static_dependencies(&self) -> Option<&Path> {
+        let project = self.project.as_ref()?;
+        if !project
+            .dynamic
+            .as_ref()
+            .is_some_and(|dynamic| dynamic.iter().any(|field| field == "dependencies"))
+        {
+            return None;
+        }
+        self.tool
+            .as_ref()?
+            .uv
+            .as_ref()?
+            .static_dependencies
+            .as_deref()
+    }
+
+    /// Replace the dynamic `dependencies` of a PEP 621 project with the given requirements.
+    #[must_use]
+    pub fn with_static_dependencies(mut self, dependencies: Vec<String>) -> Self {
+        if let Some(project) = self.project.as_mut() {
+            if let Some(dynamic) = project.dynamic.as_mut() {
+                dynamic.retain(|field| field != "dependencies");
+            }
+            project.dependencies = Some(dependencies);
+        }
+        self
+    }
+
     /// Extract static `requires-python` metadata from a PEP 621 project.
     ///
     /// Unlike [`crate::ResolutionMetadata::parse_pyproject_toml`], this does not require static
@@ -112,6 +144,7 @@ impl TryFrom<PyprojectTomlWire> for Project {
 #[serde(rename_all = "kebab-case")]
 pub struct Tool {
     pub poetry: Option<ToolPoetry>,
+    pub uv: Option<ToolUv>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -120,6 +153,12 @@ pub struct ToolPoetry {
     pub name: Option<PackageName>,
 }
 
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct ToolUv {
+    pub static_dependencies: Option<PathBuf>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::PyProjectToml;
@@ -0,0 +1,88 @@
+//! PEP 708 project tracking metadata.
+//!
+//! See: <https://peps.python.org/pep-0708/>
+
+use uv_small_str::SmallString;
+
+/// The metadata relating a project on one index to the same project on other indexes.
+#[derive(
+    Clone, Debug, Default, Eq, PartialEq, rkyv::Archive, rkyv::Deserialize, rkyv::Serialize,
+)]
+#[rkyv(derive(Debug))]
+pub struct ProjectTracking {
+    /// The URLs of the projects on other indexes that this project tracks.
+    pub tracks: Vec<SmallString>,
+    /// The URLs of all the indexes' projects that should be considered the same project.
+    pub alternate_locations: Vec<SmallString>,
+}
+
+impl ProjectTracking {
+    /// Returns `true` if the project doesn't declare any tracking information.
+    pub fn is_empty(&self) -> bool {
+        self.tracks.is_empty() && self.alternate_locations.is_empty()
+    }
+
+    /// Returns `true` if the project tracks the project at the given URL.
+    pub fn is_tracking(&self, url: &str) -> bool {
+        self.tracks
+            .iter()
+            .any(|location| is_same_project_url(location, url))
+    }
+
+    /// Returns `true` if the project declares the project at the given URL as an alternate
+    /// location.
+    pub fn is_alternate_location(&self, url: &str) -> bool {
+        self.alternate_locations
+            .iter()
+            .any(|location| is_same_project_url(location, url))
+    }
+}
+
+impl ArchivedProjectTracking {
+    /// Returns `true` if the project doesn't declare any tracking information.
+    pub fn is_empty(&self) -> bool {
+        self.tracks.is_empty() && self.alternate_locations.is_empty()
+    }
+
+    /// Returns `true` if the project tracks the project at the given URL.
+    pub fn is_tracking(&self, url: &str) -> bool {
+        self.tracks
+            .iter()
+            .any(|location| is_same_project_url(location.as_str(), url))
+    }
+
+    /// Returns `true` if the project declares the project at the given URL as an alternate
+    /// location.
+    pub fn is_alternate_location(&self, url: &str) -> bool {
+        self.alternate_locations
+            .iter()
+            .any(|location| is_same_project_url(location.as_str(), url))
+    }
+}
+
+/// Compare two project URLs, ignoring any trailing slash.
+fn is_same_project_url(a: &str, b: &str) -> bool {
+    a.trim_end_matches('/') == b.trim_end_matches('/')
+}
+
+#[cfg(test)]
+mod tests {
+    use uv_small_str::SmallString;
+
+    use crate::ProjectTracking;
+
+    #[test]
+    fn declares() {
+        let tracking = ProjectTracking {
+            tracks: vec![SmallString::from("https://pypi.org/simple/holygrail/")],
+            alternate_locations: vec![SmallString::from("https://example.com/simple/holygrail")],
+        };
+        assert!(tracking.is_tracking("https://pypi.org/simple/holygrail/"));
+        assert!(tracking.is_tracking("https://pypi.org/simple/holygrail"));
+        assert!(!tracking.is_tracking("https://example.com/simple/holygrail/"));
+        assert!(tracking.is_alternate_location("https://example.com/simple/holygrail/"));
+        assert!(!tracking.is_alternate_location("https://pypi.org/simple/holygrail/"));
+        assert!(!tracking.is_alternate_location("https://evil.example.com/simple/holygrail/"));
+        assert!(!ProjectTracking::default().is_tracking("https://pypi.org/simple/holygrail/"));
+    }
+}
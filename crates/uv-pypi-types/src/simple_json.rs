@@ -22,6 +22,12 @@ pub struct PypiSimpleDetail {
     /// PEP 792 project status information.
     #[serde(default)]
     pub project_status: ProjectStatus,
+    /// PEP 708 URLs of the projects on other indexes that this project tracks.
+    #[serde(default)]
+    pub tracks: Vec<SmallString>,
+    /// PEP 708 URLs of the projects on other indexes that serve the same project.
+    #[serde(default)]
+    pub alternate_locations: Vec<SmallString>,
     /// The list of [`PypiFile`]s available for download.
     #[serde(deserialize_with = "deserialize_pypi_files")]
     pub files: Vec<PypiFile>,
@@ -206,6 +212,12 @@ pub struct PyxSimpleDetail {
     /// PEP 792 project status information.
     #[serde(default)]
     pub project_status: ProjectStatus,
+    /// PEP 708 URLs of the projects on other indexes that this project tracks.
+    #[serde(default)]
+    pub tracks: Vec<SmallString>,
+    /// PEP 708 URLs of the projects on other indexes that serve the same project.
+    #[serde(default)]
+    pub alternate_locations: Vec<SmallString>,
     /// The list of [`PyxFile`]s available for download sorted by filename.
     #[serde(deserialize_with = "deserialize_pyx_files")]
     pub files: Vec<PyxFile>,
@@ -40,7 +40,7 @@ use crate::virtualenv::{
 };
 #[cfg(windows)]
 use crate::windows_registry::{WindowsPython, registry_pythons};
-use crate::{BrokenLink, Interpreter, PythonVersion};
+use crate::{BrokenLink, Interpreter, MissingPythonHint, PythonVersion};
 
 /// A request to find a Python installation.
 ///
@@ -159,6 +159,24 @@ impl From<bool> for PythonDownloads {
     }
 }
 
+/// Whether to fall back to an x64 Python interpreter on Windows ARM64, for packages that don't
+/// provide `win_arm64` wheels but can run under x64 emulation.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum WindowsArm64Fallback {
+    /// Always use the native ARM64 interpreter.
+    #[default]
+    None,
+    /// Use the native ARM64 interpreter, but warn if an installed x64 interpreter could be used
+    /// for packages that lack `win_arm64` wheels.
+    Warn,
+    /// Prefer the native ARM64 interpreter, but fall back to an installed x64 interpreter for
+    /// packages that lack `win_arm64` wheels.
+    PreferNative,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum EnvironmentPreference {
     /// Only use virtual environments, never allow a system environment.
@@ -1426,6 +1444,10 @@ pub(crate) fn find_python_installation(
 /// without comparing the patch version number. If that cannot be found, we fall back to
 /// the first available version.
 ///
+/// If a specific implementation is requested (e.g., `pypy@3.10`), we never fall back to
+/// an interpreter of a different implementation. Instead, the fallback is the first
+/// available interpreter of the requested implementation.
+///
 /// At all points, if the specified version cannot be found, we will attempt to
 /// download it if downloads are enabled.
 ///
@@ -1463,11 +1485,21 @@ pub(crate) async fn find_best_python_installation(
         _ => None,
     };
 
-    for (attempt, request) in iter::once(original_request)
+    // Implementation-qualified requests must be satisfied by the requested implementation, so
+    // the final fallback only relaxes the version.
+    let implementation = original_request.implementation();
+    let fallback_request = match implementation {
+        Some(implementation) => PythonRequest::Implementation(implementation),
+        None => PythonRequest::Default,
+    };
+
+    let attempts = iter::once(original_request)
         .chain(request_without_patch.iter())
-        .chain(iter::once(&PythonRequest::Default))
-        .enumerate()
-    {
+        .chain(iter::once(&fallback_request))
+        .dedup()
+        .collect::<Vec<_>>();
+
+    for (attempt, request) in attempts.iter().copied().enumerate() {
         debug!(
             "Looking for {request}{}",
             if request != original_request {
@@ -1559,6 +1591,33 @@ pub(crate) async fn find_best_python_installation(
             }
         }
 
+        // If this was a request for a specific implementation and we're on the last iteration,
+        // no interpreter of that implementation is available. List the implementations that
+        // _are_ available to help the user adjust their request.
+        if let Some(implementation) = implementation
+            && attempt + 1 == attempts.len()
+        {
+            return Err(match error {
+                crate::Error::MissingPython(err, _) => {
+                    let not_found = crate::Error::from(PythonNotFound {
+                        request: original_request.clone(),
+                        python_preference: err.python_preference,
+                        environment_preference: err.environment_preference,
+                    });
+                    let installed = installed_implementations(environments, preference, cache);
+                    if installed.is_empty() {
+                        not_found
+                    } else {
+                        not_found.with_hint(MissingPythonHint::OtherImplementations(
+                            implementation,
+                            installed,
+                        ))
+                    }
+                }
+                other => other,
+            });
+        }
+
         // If this was a request for the Default or Any version, this means that
         // either that's what we were called with, or we're on the last
         // iteration.
@@ -1578,7 +1637,35 @@ pub(crate) async fn find_best_python_installation(
         }
     }
 
-    unreachable!("The loop should have terminated when it reached PythonRequest::Default");
+    unreachable!("The loop should have terminated on the final fallback request");
+}
+
+/// Return the distinct implementations of the discoverable Python interpreters, formatted as
+/// requests (e.g., `cpython@3.12`), for use in error messages.
+pub(crate) fn installed_implementations(
+    environments: EnvironmentPreference,
+    preference: PythonPreference,
+    cache: &Cache,
+) -> Vec<String> {
+    let Ok(installations) =
+        find_all_python_installations(&PythonRequest::Any, environments, preference, cache)
+    else {
+        return Vec::new();
+    };
+    installations
+        .iter()
+        .map(|installation| {
+            let implementation = installation.implementation();
+            format!(
+                "{}@{}.{}",
+                <&str>::from(&implementation),
+                installation.interpreter().python_major(),
+                installation.interpreter().python_minor(),
+            )
+        })
+        .sorted()
+        .dedup()
+        .collect()
 }
 
 /// Display a warning if the Python version of the [`Interpreter`] is unsupported by uv.
@@ -1794,7 +1881,7 @@ impl PythonVariant {
         }
     }
 
-    fn is_freethreaded(self) -> bool {
+    pub(crate) fn is_freethreaded(self) -> bool {
         match self {
             Self::Default | Self::Debug | Self::Gil | Self::GilDebug => false,
             Self::Freethreaded | Self::FreethreadedDebug => true,
@@ -2027,6 +2114,21 @@ impl PythonRequest {
         Ok(rest.parse().ok())
     }
 
+    /// Return the Python implementation this request is restricted to, if any.
+    pub fn implementation(&self) -> Option<ImplementationName> {
+        match self {
+            Self::Implementation(implementation)
+            | Self::ImplementationVersion(implementation, _) => Some(*implementation),
+            Self::Key(request) => request.implementation().copied(),
+            Self::Default
+            | Self::Any
+            | Self::Version(_)
+            | Self::Directory(_)
+            | Self::File(_)
+            | Self::ExecutableName(_) => None,
+        }
+    }
+
     /// Check if this request includes a specific patch version.
     pub fn includes_patch(&self) -> bool {
         match self {
@@ -3,6 +3,7 @@ use std::collections::HashMap;
 use std::fmt::Display;
 use std::path::{Path, PathBuf};
 use std::pin::Pin;
+use std::process::ExitStatus;
 use std::str::FromStr;
 use std::task::{Context, Poll};
 use std::time::{Duration, Instant, SystemTimeError};
@@ -44,10 +45,14 @@ use crate::implementation::{
     Error as ImplementationError, ImplementationName, LenientImplementationName,
 };
 use crate::installation::PythonInstallationKey;
-use crate::managed::ManagedPythonInstallation;
+use crate::managed::{ManagedPythonInstallation, remove_installation_dir};
 use crate::python_version::{BuildVersionError, python_build_version_from_env};
 use crate::{Interpreter, PythonRequest, PythonVersion, VersionRequest};
 
+pub use crate::downloads::source::SourceBuildOptions;
+
+mod source;
+
 #[derive(Error, Debug)]
 pub enum Error {
     #[error(transparent)]
@@ -137,6 +142,24 @@ pub enum Error {
     NoPythonDownloadUrlFound,
     #[error(transparent)]
     SystemTime(#[from] SystemTimeError),
+    #[error(
+        "Building from source is only supported for CPython on the current Unix platform, but `{0}` was requested"
+    )]
+    SourceBuildUnsupported(Box<PythonInstallationKey>),
+    #[error("Missing system dependencies required to build Python from source:{}", _0.iter().map(|dependency| format!("\n- {dependency}")).join(""))]
+    MissingBuildDependencies(Vec<String>),
+    #[error("No checksum is published for {0}")]
+    MissingSourceChecksum(DisplaySafeUrl),
+    #[error("Unable to parse the JSON response from {0}")]
+    InvalidPythonOrgJSON(String, #[source] serde_json::Error),
+    #[error("Failed to run `{0}`")]
+    SourceBuildSpawn(String, #[source] io::Error),
+    #[error("Failed to build Python from source: `{command}` failed with {status}\n\n{output}")]
+    SourceBuildFailed {
+        command: String,
+        status: ExitStatus,
+        output: String,
+    },
 }
 
 impl RetriableError for Error {
@@ -1169,6 +1192,47 @@ async fn fetch_downloads_from_url(
         })
 }
 
+/// Return the directory in which downloaded Python archives are retained, if any.
+fn python_builds_dir() -> Option<PathBuf> {
+    env::var_os(EnvVars::UV_PYTHON_CACHE_DIR)
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Return the prefix of the filename of a retained Python archive with the given SHA256.
+fn cached_archive_prefix(sha256: Option<&str>) -> &str {
+    match sha256 {
+        // Shorten the hash to avoid too-long-filename errors
+        Some(sha) => sha.get(..9).unwrap_or(sha),
+        None => "none",
+    }
+}
+
+/// Find the retained Python archive with the given SHA256, if any.
+pub(crate) fn find_cached_archive(sha256: &str) -> io::Result<Option<PathBuf>> {
+    let Some(python_builds_dir) = python_builds_dir() else {
+        return Ok(None);
+    };
+    let prefix = format!("{}-", cached_archive_prefix(Some(sha256)));
+    let entries = match fs_err::read_dir(&python_builds_dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err),
+    };
+    for entry in entries {
+        let entry = entry?;
+        if entry
+            .file_name()
+            .to_str()
+            .is_some_and(|name| name.starts_with(&prefix))
+            && entry.file_type()?.is_file()
+        {
+            return Ok(Some(entry.path()));
+        }
+    }
+    Ok(None)
+}
+
 impl ManagedPythonDownload {
     pub(crate) fn url(&self) -> &Cow<'static, str> {
         &self.url
@@ -1238,7 +1302,12 @@ impl ManagedPythonDownload {
 
         // If it is not a reinstall and the dir already exists, return it.
         if !reinstall && path.is_dir() {
-            return Ok(DownloadResult::AlreadyAvailable(path));
+            if ManagedPythonInstallation::new(path.clone(), self).is_complete() {
+                return Ok(DownloadResult::AlreadyAvailable(path));
+            }
+            // Remove any partial installation left behind by an interrupted operation.
+            debug!("Removing incomplete installation: {}", path.user_display());
+            remove_installation_dir(&path, scratch_dir).await?;
         }
 
         // We improve filesystem compatibility by using neither the URL-encoded `%2B` nor the `+` it
@@ -1260,19 +1329,12 @@ impl ManagedPythonDownload {
 
         let temp_dir = tempfile::tempdir_in(scratch_dir).map_err(Error::DownloadDirError)?;
 
-        if let Some(python_builds_dir) =
-            env::var_os(EnvVars::UV_PYTHON_CACHE_DIR).filter(|s| !s.is_empty())
-        {
-            let python_builds_dir = PathBuf::from(python_builds_dir);
+        if let Some(python_builds_dir) = python_builds_dir() {
             fs_err::create_dir_all(&python_builds_dir)?;
-            let hash_prefix = match self.sha256.as_deref() {
-                Some(sha) => {
-                    // Shorten the hash to avoid too-long-filename errors
-                    &sha[..9]
-                }
-                None => "none",
-            };
-            let target_cache_file = python_builds_dir.join(format!("{hash_prefix}-{filename}"));
+            let target_cache_file = python_builds_dir.join(format!(
+                "{}-{filename}",
+                cached_archive_prefix(self.sha256.as_deref())
+            ));
 
             // Download the archive to the cache, or return a reader if we have it in cache.
             // TODO(konsti): We should "tee" the write so we can do the download-to-cache and unpacking
@@ -1401,7 +1463,7 @@ impl ManagedPythonDownload {
         // Remove the target if it already exists.
         if path.is_dir() {
             debug!("Removing existing directory: {}", path.user_display());
-            fs_err::tokio::remove_dir_all(&path).await?;
+            remove_installation_dir(&path, scratch_dir).await?;
         }
 
         // Persist it to the target.
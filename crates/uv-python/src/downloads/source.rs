@@ -0,0 +1,500 @@
+//! Build CPython from its source distribution, for platforms on which the managed Python
+//! distributions can't be executed (e.g., due to an older glibc).
+
+use std::ffi::OsString;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+
+use serde::Deserialize;
+use tokio::process::Command;
+use tracing::{debug, instrument};
+use url::Url;
+
+use uv_client::{BaseClient, WrappedReqwestError};
+use uv_distribution_filename::SourceDistExtension;
+use uv_extract::hash::{HashReader, Hasher};
+use uv_fs::{Simplified, rename_with_retry};
+use uv_platform::Platform;
+use uv_pypi_types::{HashAlgorithm, HashDigest};
+use uv_redacted::DisplaySafeUrl;
+use uv_static::EnvVars;
+
+use super::{
+    Direction, DownloadResult, Error, ManagedPythonDownload, ProgressReader, Reporter, read_url,
+};
+use crate::implementation::{ImplementationName, LenientImplementationName};
+use crate::managed::{ManagedPythonInstallation, remove_installation_dir};
+
+/// The base URL for CPython source distributions.
+const CPYTHON_SOURCE_URL_PREFIX: &str = "https://www.python.org/ftp/python/";
+
+/// The base URL for the python.org release API, which publishes the checksums of the source
+/// distributions.
+const PYTHON_ORG_API_URL: &str = "https://www.python.org/api/v2/downloads/";
+
+/// The file in which the origin of a source build is recorded.
+const SOURCE_FILE: &str = "SOURCE";
+
+/// The number of trailing output lines to include when a build step fails.
+const OUTPUT_TAIL_LINES: usize = 40;
+
+/// The headers that are required to build the `ssl`, `sqlite3`, and `zlib` modules, which are
+/// imported when verifying an installation.
+const REQUIRED_HEADERS: &[(&str, &str)] = &[
+    ("openssl/ssl.h", "OpenSSL"),
+    ("sqlite3.h", "SQLite"),
+    ("zlib.h", "zlib"),
+];
+
+/// Options for building CPython from source.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SourceBuildOptions {
+    /// Build with profile-guided and link-time optimizations (`--enable-optimizations`).
+    pub enable_optimizations: bool,
+}
+
+impl SourceBuildOptions {
+    /// The arguments to pass to `configure` for the given download, installed into `prefix`.
+    fn configure_args(self, download: &ManagedPythonDownload, prefix: &Path) -> Vec<OsString> {
+        let mut prefix_arg = OsString::from("--prefix=");
+        prefix_arg.push(prefix);
+        let mut args = vec![prefix_arg];
+        if self.enable_optimizations {
+            args.push("--enable-optimizations".into());
+        }
+        if download.key.variant.is_freethreaded() {
+            args.push("--disable-gil".into());
+        }
+        if download.key.variant.is_debug() {
+            args.push("--with-pydebug".into());
+        }
+        args
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct PythonOrgRelease {
+    resource_uri: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct PythonOrgReleaseFile {
+    url: String,
+    sha256_sum: Option<String>,
+}
+
+impl ManagedPythonDownload {
+    /// The URL of the CPython source distribution for this download.
+    pub fn source_url(&self) -> String {
+        format!(
+            "{CPYTHON_SOURCE_URL_PREFIX}{major}.{minor}.{patch}/Python-{version}.tgz",
+            major = self.key.major,
+            minor = self.key.minor,
+            patch = self.key.patch,
+            version = self.key.version(),
+        )
+    }
+
+    /// Download, build, and install CPython from its source distribution, in place of the
+    /// pre-built distribution.
+    #[instrument(skip_all, fields(download = % self.key()))]
+    pub async fn build_from_source(
+        &self,
+        client: &BaseClient,
+        installation_dir: &Path,
+        scratch_dir: &Path,
+        reinstall: bool,
+        options: SourceBuildOptions,
+        reporter: Option<&dyn Reporter>,
+    ) -> Result<DownloadResult, Error> {
+        if !cfg!(unix)
+            || !matches!(
+                self.key.implementation,
+                LenientImplementationName::Known(ImplementationName::CPython)
+            )
+            || !Platform::from_env()?.supports(self.key.platform())
+        {
+            return Err(Error::SourceBuildUnsupported(Box::new(self.key.clone())));
+        }
+
+        let path = std::path::absolute(installation_dir.join(self.key().to_string()))?;
+
+        // If it is not a reinstall and the dir already exists, return it.
+        if !reinstall && path.is_dir() {
+            if ManagedPythonInstallation::new(path.clone(), self).is_complete() {
+                return Ok(DownloadResult::AlreadyAvailable(path));
+            }
+            // Remove any partial installation left behind by an interrupted operation.
+            debug!("Removing incomplete installation: {}", path.user_display());
+            remove_installation_dir(&path, scratch_dir).await?;
+        }
+
+        // Check for the build tools before downloading anything.
+        let missing = missing_build_dependencies();
+        if !missing.is_empty() {
+            return Err(Error::MissingBuildDependencies(missing));
+        }
+
+        let url = DisplaySafeUrl::parse(&self.source_url())?;
+        let sha256 = fetch_source_sha256(&url, &self.key.version().to_string(), client).await?;
+
+        let temp_dir = tempfile::tempdir_in(scratch_dir).map_err(Error::DownloadDirError)?;
+        let source_dir = temp_dir.path().join("source");
+        let filename = format!("Python-{}.tgz", self.key.version());
+
+        debug!("Downloading {url}");
+        let (reader, size) = read_url(&url, client).await?;
+        let mut hashers = vec![Hasher::from(HashAlgorithm::Sha256)];
+        let mut hasher = HashReader::new(reader, &mut hashers);
+        if let Some(reporter) = reporter {
+            let progress_key = reporter.on_request_start(Direction::Download, &self.key, size);
+            let mut reader = ProgressReader::new(&mut hasher, progress_key, reporter);
+            uv_extract::stream::archive(
+                &filename,
+                &mut reader,
+                SourceDistExtension::Tgz,
+                &source_dir,
+            )
+            .await
+            .map_err(|err| Error::ExtractError(filename.clone(), err))?;
+            reporter.on_request_complete(Direction::Download, progress_key);
+        } else {
+            uv_extract::stream::archive(
+                &filename,
+                &mut hasher,
+                SourceDistExtension::Tgz,
+                &source_dir,
+            )
+            .await
+            .map_err(|err| Error::ExtractError(filename.clone(), err))?;
+        }
+        hasher.finish().await.map_err(Error::HashExhaustion)?;
+
+        let actual = HashDigest::from(hashers.pop().unwrap()).digest;
+        if !actual.eq_ignore_ascii_case(&sha256) {
+            return Err(Error::HashMismatch {
+                installation: url.to_string(),
+                expected: sha256,
+                actual: actual.to_string(),
+            });
+        }
+
+        let source_dir = uv_extract::strip_component(&source_dir)
+            .map_err(|err| Error::ExtractError(filename, err))?;
+
+        // Configure with the final installation path as the prefix, such that the paths embedded
+        // in the installation are correct, but stage the installation in the scratch directory.
+        let staging_dir = temp_dir.path().join("staging");
+        let mut configure = Command::new(source_dir.join("configure"));
+        configure.args(options.configure_args(self, &path));
+        run_build_step(configure, &source_dir).await?;
+
+        let jobs = std::thread::available_parallelism().map_or(1, std::num::NonZero::get);
+        let mut make = Command::new("make");
+        make.arg(format!("-j{jobs}"));
+        run_build_step(make, &source_dir).await?;
+
+        // Use `altinstall` to avoid overwriting the unversioned executables, which are linked
+        // separately below.
+        let mut install = Command::new("make");
+        let mut destdir = OsString::from("DESTDIR=");
+        destdir.push(&staging_dir);
+        install.arg("altinstall").arg(destdir);
+        run_build_step(install, &source_dir).await?;
+
+        let staged = staging_dir.join(path.strip_prefix("/").unwrap_or(&path));
+
+        // Add the `python3` and `python` links, as in the pre-built distributions.
+        #[cfg(unix)]
+        {
+            let executable = format!(
+                "python{}.{}{}",
+                self.key.major,
+                self.key.minor,
+                self.key.variant.executable_suffix()
+            );
+            for name in [format!("python{}", self.key.major), "python".to_string()] {
+                match fs_err::os::unix::fs::symlink(&executable, staged.join("bin").join(name)) {
+                    Ok(()) => {}
+                    Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => {}
+                    Err(err) => return Err(err.into()),
+                }
+            }
+        }
+
+        // Record where the installation was built from.
+        fs_err::write(
+            staged.join(SOURCE_FILE),
+            format!("{url}\nsha256:{sha256}\n"),
+        )?;
+
+        // Remove the target if it already exists.
+        if path.is_dir() {
+            debug!("Removing existing directory: {}", path.user_display());
+            remove_installation_dir(&path, scratch_dir).await?;
+        }
+
+        // Persist it to the target.
+        debug!("Moving {} to {}", staged.display(), path.user_display());
+        rename_with_retry(&staged, &path)
+            .await
+            .map_err(|err| Error::CopyError {
+                to: path.clone(),
+                err,
+            })?;
+
+        Ok(DownloadResult::Fetched(path))
+    }
+}
+
+/// Return the system dependencies that are required to build CPython, but missing.
+fn missing_build_dependencies() -> Vec<String> {
+    let mut missing = Vec::new();
+    if which::which("make").is_err() {
+        missing.push("`make`".to_string());
+    }
+
+    let Some(compiler) = find_c_compiler() else {
+        missing.push("a C compiler (`cc` or `gcc`, or set `CC`)".to_string());
+        return missing;
+    };
+
+    for (header, library) in REQUIRED_HEADERS {
+        if !has_header(&compiler, header) {
+            missing.push(format!("the {library} development headers (`{header}`)"));
+        }
+    }
+    missing
+}
+
+/// Find the C compiler used by `configure`, i.e., `$CC`, or `cc` or `gcc` on the `PATH`.
+fn find_c_compiler() -> Option<Vec<String>> {
+    if let Some(cc) = std::env::var(EnvVars::CC)
+        .ok()
+        .filter(|cc| !cc.trim().is_empty())
+    {
+        let command = cc
+            .split_whitespace()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        return which::which(&command[0]).is_ok().then_some(command);
+    }
+    ["cc", "gcc"]
+        .into_iter()
+        .find(|name| which::which(name).is_ok())
+        .map(|name| vec![name.to_string()])
+}
+
+/// Returns `true` if the given header can be included with the C compiler.
+fn has_header(compiler: &[String], header: &str) -> bool {
+    let child = std::process::Command::new(&compiler[0])
+        .args(&compiler[1..])
+        .args(["-E", "-x", "c", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn();
+    let mut child = match child {
+        Ok(child) => child,
+        Err(err) => {
+            debug!("Failed to run C compiler `{}`: {err}", compiler[0]);
+            return false;
+        }
+    };
+    if let Some(mut stdin) = child.stdin.take() {
+        use std::io::Write;
+        if writeln!(stdin, "#include <{header}>").is_err() {
+            return false;
+        }
+    }
+    let found = child.wait().is_ok_and(|status| status.success());
+    if !found {
+        debug!("Header `{header}` was not found by `{}`", compiler[0]);
+    }
+    found
+}
+
+/// Fetch the SHA256 checksum of a CPython source distribution from the python.org release API.
+async fn fetch_source_sha256(
+    url: &DisplaySafeUrl,
+    version: &str,
+    client: &BaseClient,
+) -> Result<String, Error> {
+    let mut releases_url = DisplaySafeUrl::parse(&format!("{PYTHON_ORG_API_URL}release/"))?;
+    releases_url
+        .query_pairs_mut()
+        .append_pair("name", &format!("Python {version}"));
+    let releases: Vec<PythonOrgRelease> = fetch_json(&releases_url, client).await?;
+
+    let Some(release_id) = releases.first().and_then(|release| {
+        release
+            .resource_uri
+            .trim_end_matches('/')
+            .rsplit('/')
+            .next()
+            .map(ToString::to_string)
+    }) else {
+        return Err(Error::MissingSourceChecksum(url.clone()));
+    };
+
+    let mut files_url = DisplaySafeUrl::parse(&format!("{PYTHON_ORG_API_URL}release_file/"))?;
+    files_url
+        .query_pairs_mut()
+        .append_pair("release", &release_id);
+    let files: Vec<PythonOrgReleaseFile> = fetch_json(&files_url, client).await?;
+
+    files
+        .into_iter()
+        .find(|file| file.url == url.as_str())
+        .and_then(|file| file.sha256_sum)
+        .filter(|sha256| !sha256.is_empty())
+        .ok_or_else(|| Error::MissingSourceChecksum(url.clone()))
+}
+
+async fn fetch_json<T: serde::de::DeserializeOwned>(
+    url: &DisplaySafeUrl,
+    client: &BaseClient,
+) -> Result<T, Error> {
+    debug!("Fetching {url}");
+    let response = client
+        .for_host(url)
+        .get(Url::from(url.clone()))
+        .send()
+        .await
+        .map_err(|err| Error::from_reqwest_middleware(url.clone(), err))?
+        .error_for_status()
+        .map_err(|err| Error::NetworkError(url.clone(), WrappedReqwestError::from(err)))?;
+    let bytes = response
+        .bytes()
+        .await
+        .map_err(|err| Error::NetworkError(url.clone(), WrappedReqwestError::from(err)))?;
+    serde_json::from_slice(&bytes).map_err(|err| Error::InvalidPythonOrgJSON(url.to_string(), err))
+}
+
+/// Run a step of the source build, capturing its output.
+async fn run_build_step(mut command: Command, cwd: &Path) -> Result<(), Error> {
+    let command_line = display_command(&command);
+    debug!("Running `{command_line}`");
+    let output = command
+        .current_dir(cwd)
+        .stdin(Stdio::null())
+        .output()
+        .await
+        .map_err(|err| Error::SourceBuildSpawn(command_line.clone(), err))?;
+    if output.status.success() {
+        return Ok(());
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let lines = stdout.lines().chain(stderr.lines()).collect::<Vec<_>>();
+    let tail = lines[lines.len().saturating_sub(OUTPUT_TAIL_LINES)..].join("\n");
+    Err(Error::SourceBuildFailed {
+        command: command_line,
+        status: output.status,
+        output: tail,
+    })
+}
+
+fn display_command(command: &Command) -> String {
+    let command = command.as_std();
+    let program = PathBuf::from(command.get_program());
+    let mut display = program.file_name().map_or_else(
+        || program.user_display().to_string(),
+        |name| name.to_string_lossy().to_string(),
+    );
+    for arg in command.get_args() {
+        let _ = write!(display, " {}", arg.to_string_lossy());
+    }
+    display
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::str::FromStr;
+
+    use uv_pep440::{Prerelease, PrereleaseKind};
+    use uv_platform::{Arch, Libc, Os, Platform};
+
+    use super::SourceBuildOptions;
+    use crate::PythonVariant;
+    use crate::downloads::ManagedPythonDownload;
+    use crate::implementation::{ImplementationName, LenientImplementationName};
+    use crate::installation::PythonInstallationKey;
+
+    fn cpython_download(
+        patch: u8,
+        prerelease: Option<Prerelease>,
+        variant: PythonVariant,
+    ) -> ManagedPythonDownload {
+        let key = PythonInstallationKey::new(
+            LenientImplementationName::Known(ImplementationName::CPython),
+            3,
+            14,
+            patch,
+            prerelease,
+            Platform::new(
+                Os::from_str("linux").unwrap(),
+                Arch::from_str("x86_64").unwrap(),
+                Libc::from_str("gnu").unwrap(),
+            ),
+            variant,
+        );
+
+        ManagedPythonDownload {
+            key,
+            url: "https://example.com/cpython.tar.gz".into(),
+            sha256: None,
+            build: Some("20251014"),
+        }
+    }
+
+    #[test]
+    fn test_source_url() {
+        let download = cpython_download(2, None, PythonVariant::Default);
+        assert_eq!(
+            download.source_url(),
+            "https://www.python.org/ftp/python/3.14.2/Python-3.14.2.tgz"
+        );
+
+        let download = cpython_download(
+            0,
+            Some(Prerelease {
+                kind: PrereleaseKind::Rc,
+                number: 1,
+            }),
+            PythonVariant::Default,
+        );
+        assert_eq!(
+            download.source_url(),
+            "https://www.python.org/ftp/python/3.14.0/Python-3.14.0rc1.tgz"
+        );
+    }
+
+    #[test]
+    fn test_configure_args() {
+        let prefix = Path::new("/python/cpython-3.14.2-linux-x86_64-gnu");
+
+        let download = cpython_download(2, None, PythonVariant::Default);
+        assert_eq!(
+            SourceBuildOptions::default().configure_args(&download, prefix),
+            ["--prefix=/python/cpython-3.14.2-linux-x86_64-gnu"]
+        );
+
+        let options = SourceBuildOptions {
+            enable_optimizations: true,
+        };
+        let download = cpython_download(2, None, PythonVariant::FreethreadedDebug);
+        assert_eq!(
+            options.configure_args(&download, prefix),
+            [
+                "--prefix=/python/cpython-3.14.2-linux-x86_64-gnu",
+                "--enable-optimizations",
+                "--disable-gil",
+                "--with-pydebug",
+            ]
+        );
+    }
+}
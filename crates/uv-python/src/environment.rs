@@ -12,6 +12,7 @@ use uv_pep440::Version;
 
 use crate::discovery::find_python_installation;
 use crate::installation::PythonInstallation;
+use crate::managed::ManagedPythonInstallation;
 use crate::virtualenv::{PyVenvConfiguration, virtualenv_python_executable};
 use crate::{
     EnvironmentPreference, Error, Interpreter, Prefix, PythonNotFound, PythonPreference,
@@ -159,6 +160,7 @@ impl PythonEnvironment {
                 Ok(installation) => installation,
                 Err(err) => return Err(EnvironmentNotFound::from(err).into()),
             };
+        ManagedPythonInstallation::mark_interpreter_used(installation.interpreter());
         Ok(Self::from_installation(installation))
     }
 
@@ -216,6 +218,7 @@ impl PythonEnvironment {
         }
 
         let interpreter = Interpreter::query(executable, cache)?;
+        ManagedPythonInstallation::mark_interpreter_used(&interpreter);
 
         Ok(Self(Arc::new(PythonEnvironmentShared {
             root: interpreter.sys_prefix().to_path_buf(),
@@ -18,7 +18,7 @@ use uv_platform::{Arch, Libc, Os, Platform};
 
 use crate::discovery::{
     EnvironmentPreference, PythonRequest, VersionRequest, find_best_python_installation,
-    find_python_installation,
+    find_python_installation, installed_implementations,
 };
 use crate::downloads::{
     DownloadResult, ManagedPythonDownload, ManagedPythonDownloadList, PythonDownloadRequest,
@@ -123,12 +123,9 @@ impl PythonInstallation {
         preference: PythonPreference,
         cache: &Cache,
     ) -> Result<Self, Error> {
-        Ok(find_python_installation(
-            request,
-            environments,
-            preference,
-            cache,
-        )??)
+        let installation = find_python_installation(request, environments, preference, cache)??;
+        ManagedPythonInstallation::mark_interpreter_used(&installation.interpreter);
+        Ok(installation)
     }
 
     /// Find or download a [`PythonInstallation`] that satisfies a requested version, if the request
@@ -161,6 +158,7 @@ impl PythonInstallation {
             python_downloads_json_url,
         )
         .await?;
+        ManagedPythonInstallation::mark_interpreter_used(&installation.interpreter);
         installation
             .download_and_warn_if_outdated_prerelease(
                 request,
@@ -260,7 +258,13 @@ impl PythonInstallation {
             // N.B. We should only be in this case when downloads are disabled; when downloads are
             // enabled, we should fail eagerly when something goes wrong with the download.
             debug_assert!(!downloads_enabled);
-            return Err(err);
+            return Err(with_implementations_hint(
+                err,
+                request,
+                environments,
+                preference,
+                cache,
+            ));
         };
 
         // If the download is available, but not usable, we attach a hint to the original error.
@@ -290,7 +294,13 @@ impl PythonInstallation {
                 return Err(err.with_hint(MissingPythonHint::Offline(request.clone())));
             }
 
-            return Err(err);
+            return Err(with_implementations_hint(
+                err,
+                request,
+                environments,
+                preference,
+                cache,
+            ));
         }
 
         // Python downloads are performing their own retries to catch stream errors, disable the
@@ -328,6 +338,7 @@ impl PythonInstallation {
         let installations_dir = installations.root();
         let scratch_dir = installations.scratch();
         let _lock = installations.lock().await?;
+        installations.clean_scratch()?;
 
         info!("Fetching requested Python...");
         let result = download
@@ -353,6 +364,8 @@ impl PythonInstallation {
         installed.ensure_sysconfig_patched()?;
         installed.ensure_canonical_executables()?;
         installed.ensure_build_file()?;
+        installed.ensure_archive_hash_file()?;
+        installed.record_file_hashes().await?;
 
         let minor_version = installed.minor_version_key();
         let highest_patch = installations
@@ -371,6 +384,7 @@ impl PythonInstallation {
         if let Err(e) = installed.ensure_dylib_patched() {
             e.warn_user(&installed);
         }
+        installed.mark_used();
 
         Ok(Self {
             source: PythonSource::Managed,
@@ -547,6 +561,31 @@ impl PythonInstallation {
     }
 }
 
+/// If an implementation-qualified request could not be satisfied, attach a hint listing the
+/// implementations of the interpreters that are available instead.
+fn with_implementations_hint(
+    err: Error,
+    request: &PythonRequest,
+    environments: EnvironmentPreference,
+    preference: PythonPreference,
+    cache: &Cache,
+) -> Error {
+    if !matches!(err, Error::MissingPython(..)) {
+        return err;
+    }
+    let Some(implementation) = request.implementation() else {
+        return err;
+    };
+    let installed = installed_implementations(environments, preference, cache);
+    if installed.is_empty() {
+        return err;
+    }
+    err.with_hint(MissingPythonHint::OtherImplementations(
+        implementation,
+        installed,
+    ))
+}
+
 #[derive(Error, Debug)]
 pub enum PythonInstallationKeyError {
     #[error("Failed to parse Python installation key `{0}`: {1}")]
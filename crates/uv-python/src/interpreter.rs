@@ -13,7 +13,7 @@ use owo_colors::OwoColorize;
 use same_file::is_same_file;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
-use tracing::{debug, trace, warn};
+use tracing::{debug, instrument, trace, warn};
 
 use uv_cache::{Cache, CacheBucket, CachedByTimestamp, Freshness};
 use uv_cache_info::Timestamp;
@@ -1120,7 +1120,8 @@ impl InterpreterInfo {
     ///
     /// Running a Python script is (relatively) expensive, and the markers won't change
     /// unless the Python executable changes, so we use the executable's last modified
-    /// time as a cache key.
+    /// time (or that of the virtual environment's `pyvenv.cfg`, if later) as a cache key.
+    #[instrument(name = "query_interpreter", skip_all, fields(executable = %executable.display()))]
     fn query_cached(executable: &Path, cache: &Cache) -> Result<Self, Error> {
         let absolute = std::path::absolute(executable)?;
 
@@ -1177,6 +1178,14 @@ impl InterpreterInfo {
         // interpreter has been modified.
         let modified = Timestamp::from_path(canonical).map_err(handle_io_error)?;
 
+        // The runtime `site-packages` directories of a virtual environment also depend on its
+        // `pyvenv.cfg` (e.g., `include-system-site-packages`), which can be modified in-place.
+        let modified = absolute
+            .parent()
+            .and_then(Path::parent)
+            .and_then(|root| Timestamp::from_path(root.join("pyvenv.cfg")).ok())
+            .map_or(modified, |cfg_modified| modified.max(cfg_modified));
+
         // Read from the cache.
         if cache
             .freshness(&cache_entry, None, None)
@@ -9,7 +9,7 @@ use crate::discovery::find_python_installations;
 pub use crate::discovery::{
     EnvironmentPreference, Error as DiscoveryError, PythonDownloads, PythonNotFound,
     PythonPreference, PythonRequest, PythonSource, PythonVariant, VersionRequest,
-    find_all_python_installations,
+    WindowsArm64Fallback, find_all_python_installations,
 };
 pub use crate::environment::{InvalidEnvironmentKind, PythonEnvironment};
 pub use crate::implementation::{ImplementationName, LenientImplementationName};
@@ -28,7 +28,10 @@ pub use crate::version_files::{
     FilePreference as VersionFilePreference, PYTHON_VERSION_FILENAME, PYTHON_VERSIONS_FILENAME,
     PythonVersionFile,
 };
-pub use crate::virtualenv::{Error as VirtualEnvError, PyVenvConfiguration, VirtualEnvironment};
+pub use crate::virtualenv::{
+    CondaEnvironment, Error as VirtualEnvError, PyVenvConfiguration, VirtualEnvironment,
+    is_conda_environment,
+};
 
 mod discovery;
 pub mod downloads;
@@ -121,6 +124,9 @@ pub enum MissingPythonHint {
     PreferenceOnlySystem(PythonRequest),
     /// uv is in offline mode.
     Offline(PythonRequest),
+    /// No interpreter of the requested implementation was found, but interpreters of other
+    /// implementations are available.
+    OtherImplementations(ImplementationName, Vec<String>),
 }
 
 impl MissingPythonHint {
@@ -170,6 +176,18 @@ impl std::fmt::Display for MissingPythonHint {
                     Self::for_request(request),
                 )
             }
+            Self::OtherImplementations(implementation, installed) => {
+                write!(
+                    f,
+                    "No {} interpreters were found, but the following are available: {}",
+                    implementation.pretty(),
+                    installed
+                        .iter()
+                        .map(|request| format!("`{request}`"))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                )
+            }
         }
     }
 }
@@ -1538,6 +1556,63 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn find_best_python_implementation_version_fallback() -> Result<()> {
+        let mut context = TestContext::new()?;
+        context.add_python_interpreters(&[
+            (true, ImplementationName::CPython, "python", "3.10.0"),
+            (true, ImplementationName::PyPy, "pypy", "3.9.1"),
+        ])?;
+
+        let python = context.run(|| {
+            find_best_python_installation_no_download(
+                &PythonRequest::parse("pypy@3.10"),
+                EnvironmentPreference::Any,
+                PythonPreference::OnlySystem,
+                &context.cache,
+            )
+        })?;
+        assert_eq!(
+            python.interpreter().implementation_name(),
+            "pypy",
+            "We should not fall back to a different implementation"
+        );
+        assert_eq!(
+            python.interpreter().python_full_version().to_string(),
+            "3.9.1",
+            "We should fall back to any version of the requested implementation"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn find_best_python_implementation_missing() -> Result<()> {
+        let mut context = TestContext::new()?;
+        context.add_python_interpreters(&[
+            (true, ImplementationName::CPython, "python", "3.10.0"),
+            (true, ImplementationName::CPython, "python", "3.12.1"),
+        ])?;
+
+        let result = context.run(|| {
+            find_best_python_installation_no_download(
+                &PythonRequest::parse("graalpy"),
+                EnvironmentPreference::Any,
+                PythonPreference::OnlySystem,
+                &context.cache,
+            )
+        });
+        let Err(crate::Error::MissingPython(_, Some(hint))) = result else {
+            panic!("We should not find a GraalPy interpreter; got {result:?}");
+        };
+        assert_eq!(
+            hint.to_string(),
+            "No GraalPy interpreters were found, but the following are available: `cpython@3.10`, `cpython@3.12`"
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn find_python_from_active_python() -> Result<()> {
         let context = TestContext::new()?;
@@ -6,27 +6,33 @@ use std::io::{self, Write};
 #[cfg(windows)]
 use std::os::windows::fs::MetadataExt;
 use std::path::{Path, PathBuf};
+use std::process::ExitStatus;
 use std::str::FromStr;
+use std::time::{Duration, SystemTime};
 
 use fs_err as fs;
 use itertools::Itertools;
 use thiserror::Error;
+use tokio::process::Command;
 use tracing::{debug, warn};
+use walkdir::WalkDir;
 #[cfg(windows)]
 use windows::Win32::Storage::FileSystem::FILE_ATTRIBUTE_REPARSE_POINT;
 
+use uv_extract::hash::{HashReader, Hasher};
 use uv_fs::{
     LockedFile, LockedFileError, LockedFileMode, Simplified, normalize_absolute_path,
-    replace_symlink, symlink_or_copy_file, verbatim_path,
+    rename_with_retry, replace_symlink, symlink_or_copy_file, verbatim_path,
 };
 use uv_platform::{Error as PlatformError, Os};
 use uv_platform::{LibcDetectionError, Platform};
+use uv_pypi_types::{HashAlgorithm, HashDigest};
 use uv_state::{StateBucket, StateStore};
 use uv_static::EnvVars;
 use uv_trampoline_builder::{Launcher, LauncherKind};
 
 use crate::discovery::VersionRequest;
-use crate::downloads::{Error as DownloadError, ManagedPythonDownload};
+use crate::downloads::{Error as DownloadError, ManagedPythonDownload, find_cached_archive};
 use crate::implementation::{
     Error as ImplementationError, ImplementationName, LenientImplementationName,
 };
@@ -81,8 +87,70 @@ pub enum Error {
     LibcDetection(#[from] LibcDetectionError),
     #[error(transparent)]
     MacOsDylib(#[from] macos_dylib::Error),
+    #[error("Failed to run Python executable at {}", _0.user_display())]
+    VerifySpawn(PathBuf, #[source] io::Error),
+    #[error(
+        "Python executable at {} failed the installation smoke test ({status}){}",
+        path.user_display(),
+        if stderr.is_empty() { String::new() } else { format!(":\n{stderr}") }
+    )]
+    VerifyFailed {
+        path: PathBuf,
+        status: ExitStatus,
+        stderr: String,
+    },
+    #[error(
+        "Hash mismatch for the archive of `{installation}` at {}\n\nExpected:\n{expected}\n\nComputed:\n{actual}",
+        archive.user_display()
+    )]
+    ArchiveHashMismatch {
+        installation: String,
+        archive: PathBuf,
+        expected: String,
+        actual: String,
+    },
+    #[error(
+        "Hash mismatch for `{}` in `{installation}`\n\nExpected:\n{expected}\n\nComputed:\n{actual}",
+        path.user_display()
+    )]
+    FileHashMismatch {
+        installation: String,
+        path: PathBuf,
+        expected: String,
+        actual: String,
+    },
+    #[error("File `{}` is missing from `{installation}`", path.user_display())]
+    FileMissing { installation: String, path: PathBuf },
+    #[error("Invalid entry in the file hashes at `{}`: `{entry}`", path.user_display())]
+    InvalidFileHashes { path: PathBuf, entry: String },
 }
 
+/// The outcome of verifying a managed Python installation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verification {
+    /// The installation passed the check.
+    Passed,
+    /// The check could not be performed, e.g., because the installation cannot be executed on the
+    /// current platform.
+    Skipped,
+}
+
+/// The name of the file, within an installation directory, that records the SHA256 of the archive
+/// the installation was extracted from.
+const ARCHIVE_SHA256_FILENAME: &str = "SHA256";
+
+/// The name of the file, within an installation directory, that records the SHA256 of each file
+/// in the installation, in the format used by `sha256sum`.
+const FILES_SHA256_FILENAME: &str = "SHA256SUMS";
+
+/// The name of the file, within an installation directory, whose modification time records when
+/// the installation was last used.
+const LAST_USED_FILENAME: &str = ".last-used";
+
+/// The minimum interval between updates to the last-used time of an installation, to avoid writing
+/// to the installation on every invocation.
+const LAST_USED_INTERVAL: Duration = Duration::from_hours(24);
+
 /// Compare two build version strings.
 ///
 /// Build versions are typically YYYYMMDD date strings. Comparison is done numerically
@@ -151,6 +219,34 @@ impl ManagedPythonInstallations {
         self.root.join(".temp")
     }
 
+    /// Remove any entries left in the scratch directory by interrupted installs.
+    ///
+    /// Must be called while holding the lock returned by [`ManagedPythonInstallations::lock`].
+    pub fn clean_scratch(&self) -> Result<(), Error> {
+        let scratch = self.scratch();
+        let entries = match fs::read_dir(&scratch) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+            Err(err) => return Err(Error::ReadError(err)),
+        };
+        for entry in entries {
+            let path = entry.map_err(Error::ReadError)?.path();
+            debug!(
+                "Removing orphaned temporary directory: {}",
+                path.user_display()
+            );
+            let result = if path.is_dir() {
+                fs::remove_dir_all(&path)
+            } else {
+                fs::remove_file(&path)
+            };
+            if let Err(err) = result {
+                warn!("Failed to remove `{}`: {err}", path.user_display());
+            }
+        }
+        Ok(())
+    }
+
     /// Initialize the Python installation directory.
     ///
     /// Ensures the directory is created.
@@ -238,6 +334,16 @@ impl ManagedPythonInstallations {
                     })
                     .ok()
             })
+            .filter(|installation| {
+                let complete = installation.is_complete();
+                if !complete {
+                    warn!(
+                        "Ignoring incomplete managed Python installation at: {}",
+                        installation.path().user_display()
+                    );
+                }
+                complete
+            })
             .sorted_unstable_by_key(|installation| Reverse(installation.key().clone())))
     }
 
@@ -289,6 +395,29 @@ impl ManagedPythonInstallations {
     }
 }
 
+/// Remove a directory from the managed Python installation directory.
+///
+/// The directory is first moved into the scratch directory, so that an interrupted removal doesn't
+/// leave a partial installation behind. Anything left in the scratch directory is removed by
+/// [`ManagedPythonInstallations::clean_scratch`].
+pub async fn remove_installation_dir(path: &Path, scratch: &Path) -> Result<(), io::Error> {
+    fs::create_dir_all(scratch)?;
+    let temp_dir = tempfile::tempdir_in(scratch)?;
+    let target = temp_dir
+        .path()
+        .join(path.file_name().unwrap_or(OsStr::new("python")));
+    match rename_with_retry(path, &target).await {
+        Ok(()) => temp_dir.close(),
+        Err(err) => {
+            debug!(
+                "Failed to move `{}` to the scratch directory, removing in place: {err}",
+                path.user_display()
+            );
+            fs::tokio::remove_dir_all(path).await
+        }
+    }
+}
+
 static EXTERNALLY_MANAGED: &str = "[externally-managed]
 Error=This Python installation is managed by uv and should not be modified.
 ";
@@ -325,6 +454,19 @@ impl ManagedPythonInstallation {
         }
     }
 
+    /// Create a [`ManagedPythonInstallation`] built from the source distribution of a download.
+    ///
+    /// Source builds don't have a build version, as they're not built by python-build-standalone.
+    pub fn from_source_build(path: PathBuf, download: &ManagedPythonDownload) -> Self {
+        Self {
+            path,
+            key: download.key().clone(),
+            url: Some(Cow::Owned(download.source_url())),
+            sha256: None,
+            build: None,
+        }
+    }
+
     fn from_path(path: impl AsRef<Path>) -> Result<Self, Error> {
         let path = path.as_ref();
 
@@ -345,11 +487,18 @@ impl ManagedPythonInstallation {
             Err(err) => return Err(err.into()),
         };
 
+        // Try to read the archive hash if it was recorded
+        let sha256 = match fs::read_to_string(path.join(ARCHIVE_SHA256_FILENAME)) {
+            Ok(content) => Some(Cow::Owned(content.trim().to_string())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => None,
+            Err(err) => return Err(err.into()),
+        };
+
         Ok(Self {
             path,
             key,
             url: None,
-            sha256: None,
+            sha256,
             build,
         })
     }
@@ -452,6 +601,18 @@ impl ManagedPythonInstallation {
         executable
     }
 
+    /// Returns `true` if the installation contains its Python executable.
+    ///
+    /// An interrupted install or removal may leave an incomplete installation behind. The
+    /// executable layout of installations for another operating system family is not known, so
+    /// those are assumed to be complete.
+    pub fn is_complete(&self) -> bool {
+        if self.key.os().is_windows() != cfg!(windows) {
+            return true;
+        }
+        self.executable(false).exists()
+    }
+
     fn python_dir(&self) -> PathBuf {
         let install = self.path.join("install");
         if install.is_dir() {
@@ -625,6 +786,234 @@ impl ManagedPythonInstallation {
         Ok(())
     }
 
+    /// Ensure the SHA256 of the archive is recorded in the installation directory, such that the
+    /// archive can be re-validated with [`ManagedPythonInstallation::verify_archive`].
+    pub fn ensure_archive_hash_file(&self) -> Result<(), Error> {
+        if let Some(ref sha256) = self.sha256 {
+            let hash_file = self.path.join(ARCHIVE_SHA256_FILENAME);
+            fs::write(&hash_file, sha256.as_ref())?;
+        }
+        Ok(())
+    }
+
+    /// Returns the time at which the installation was last selected by a uv command, if known.
+    pub fn last_used(&self) -> Option<SystemTime> {
+        fs::metadata(self.path.join(LAST_USED_FILENAME))
+            .and_then(|metadata| metadata.modified())
+            .ok()
+    }
+
+    /// Record that the installation was selected by a uv command.
+    ///
+    /// The last-used time is updated at most once per [`LAST_USED_INTERVAL`]. Failures are
+    /// ignored, e.g., if the installation directory is read-only.
+    pub fn mark_used(&self) {
+        if self.last_used().is_some_and(|last_used| {
+            last_used
+                .elapsed()
+                .is_ok_and(|elapsed| elapsed < LAST_USED_INTERVAL)
+        }) {
+            return;
+        }
+        if let Err(err) = fs::write(self.path.join(LAST_USED_FILENAME), "") {
+            debug!("Failed to record last use of `{}`: {err}", self.key);
+        }
+    }
+
+    /// Record that the managed installation backing the given interpreter, if any, was selected by
+    /// a uv command.
+    pub(crate) fn mark_interpreter_used(interpreter: &Interpreter) {
+        if let Some(installation) = Self::try_from_interpreter(interpreter) {
+            installation.mark_used();
+        }
+    }
+
+    /// Run a smoke test against the installation's Python executable.
+    ///
+    /// The test imports `ssl`, `sqlite3`, and `zlib`, which depend on shared libraries that are
+    /// missing or broken in a faulty distribution. Installations that cannot be executed on the
+    /// current platform, e.g., Pyodide or foreign architectures, are skipped.
+    pub async fn verify(&self) -> Result<Verification, Error> {
+        if self.key.os().is_emscripten() {
+            debug!("Skipping smoke test for `{}`", self.key);
+            return Ok(Verification::Skipped);
+        }
+        if !Platform::from_env()?.supports(self.key.platform()) {
+            debug!(
+                "Skipping smoke test for `{}`: not executable on the current platform",
+                self.key
+            );
+            return Ok(Verification::Skipped);
+        }
+
+        let executable = self.executable(false);
+        debug!("Running smoke test for `{}`", self.key);
+        let output = Command::new(&executable)
+            .arg("-I")
+            .arg("-c")
+            .arg("import ssl, sqlite3, sys, zlib; print(sys.version)")
+            .output()
+            .await
+            .map_err(|err| Error::VerifySpawn(executable.clone(), err))?;
+
+        if !output.status.success() {
+            return Err(Error::VerifyFailed {
+                path: executable,
+                status: output.status,
+                stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            });
+        }
+
+        debug!(
+            "Smoke test passed for `{}`: {}",
+            self.key,
+            String::from_utf8_lossy(&output.stdout).trim()
+        );
+        Ok(Verification::Passed)
+    }
+
+    /// Re-validate the archive the installation was extracted from against the SHA256 recorded at
+    /// install time.
+    ///
+    /// Archives are only retained in the `UV_PYTHON_CACHE_DIR`. If no hash was recorded, or the
+    /// archive was not retained, the check is skipped.
+    pub async fn verify_archive(&self) -> Result<Verification, Error> {
+        let Some(expected) = self.sha256.as_deref() else {
+            debug!(
+                "Skipping checksum validation for `{}`: no archive hash recorded",
+                self.key
+            );
+            return Ok(Verification::Skipped);
+        };
+        let Some(archive) = find_cached_archive(expected)? else {
+            debug!(
+                "Skipping checksum validation for `{}`: archive not found in the Python cache",
+                self.key
+            );
+            return Ok(Verification::Skipped);
+        };
+
+        debug!(
+            "Validating checksum of `{}` for `{}`",
+            archive.user_display(),
+            self.key
+        );
+        let actual = sha256(&archive).await?;
+        if !actual.eq_ignore_ascii_case(expected) {
+            return Err(Error::ArchiveHashMismatch {
+                installation: self.key.to_string(),
+                archive,
+                expected: expected.to_string(),
+                actual,
+            });
+        }
+
+        Ok(Verification::Passed)
+    }
+
+    /// Record the SHA256 of each file in the installation, such that the installed files can be
+    /// re-validated with [`ManagedPythonInstallation::verify_files`].
+    ///
+    /// Files that are expected to change after installation, such as bytecode and the contents of
+    /// `site-packages`, are not recorded.
+    pub async fn record_file_hashes(&self) -> Result<(), Error> {
+        let mut entries = Vec::new();
+        for relative in self.recorded_files()? {
+            let digest = sha256(&self.path.join(&relative)).await?;
+            entries.push(format!("{digest}  {relative}\n"));
+        }
+        fs::write(self.path.join(FILES_SHA256_FILENAME), entries.concat())?;
+        Ok(())
+    }
+
+    /// Re-validate the files in the installation against the SHA256 recorded at install time.
+    ///
+    /// If no hashes were recorded, e.g., for installations created by older versions of uv, the
+    /// check is skipped.
+    pub async fn verify_files(&self) -> Result<Verification, Error> {
+        let hash_file = self.path.join(FILES_SHA256_FILENAME);
+        let contents = match fs::read_to_string(&hash_file) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                debug!(
+                    "Skipping file validation for `{}`: no file hashes recorded",
+                    self.key
+                );
+                return Ok(Verification::Skipped);
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        debug!("Validating installed files for `{}`", self.key);
+        for entry in contents.lines() {
+            let Some((expected, relative)) = entry.split_once("  ") else {
+                return Err(Error::InvalidFileHashes {
+                    path: hash_file,
+                    entry: entry.to_string(),
+                });
+            };
+            let path = self.path.join(relative);
+            let actual = match sha256(&path).await {
+                Ok(actual) => actual,
+                Err(Error::Io(err)) if err.kind() == io::ErrorKind::NotFound => {
+                    return Err(Error::FileMissing {
+                        installation: self.key.to_string(),
+                        path,
+                    });
+                }
+                Err(err) => return Err(err),
+            };
+            if !actual.eq_ignore_ascii_case(expected) {
+                return Err(Error::FileHashMismatch {
+                    installation: self.key.to_string(),
+                    path,
+                    expected: expected.to_string(),
+                    actual,
+                });
+            }
+        }
+
+        Ok(Verification::Passed)
+    }
+
+    /// Return the relative paths of the files whose hashes are recorded for the installation,
+    /// using `/` as the separator.
+    fn recorded_files(&self) -> Result<Vec<String>, Error> {
+        let mut files = Vec::new();
+        let walker = WalkDir::new(&self.path)
+            .sort_by_file_name()
+            .into_iter()
+            .filter_entry(|entry| {
+                let name = entry.file_name();
+                !(entry.file_type().is_dir() && (name == "__pycache__" || name == "site-packages"))
+                    && name != FILES_SHA256_FILENAME
+                    && name != LAST_USED_FILENAME
+            });
+        for entry in walker {
+            let entry = entry.map_err(io::Error::from)?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let Ok(relative) = entry.path().strip_prefix(&self.path) else {
+                continue;
+            };
+            let Some(relative) = relative
+                .components()
+                .map(|component| component.as_os_str().to_str())
+                .collect::<Option<Vec<_>>>()
+            else {
+                debug!(
+                    "Skipping non-UTF-8 path in `{}`: {}",
+                    self.key,
+                    entry.path().user_display()
+                );
+                continue;
+            };
+            files.push(relative.join("/"));
+        }
+        Ok(files)
+    }
+
     /// Returns `true` if the path is a link to this installation's binary, e.g., as created by
     /// [`create_bin_link`].
     pub fn is_bin_link(&self, path: &Path) -> bool {
@@ -965,6 +1354,15 @@ pub fn python_executable_dir() -> Result<PathBuf, Error> {
         .ok_or(Error::NoExecutableDirectory)
 }
 
+/// Compute the SHA256 of the file at the given path.
+async fn sha256(path: &Path) -> Result<String, Error> {
+    let file = fs::tokio::File::open(path).await?;
+    let mut hashers = [Hasher::from(HashAlgorithm::Sha256)];
+    HashReader::new(file, &mut hashers).finish().await?;
+    let [hasher] = hashers;
+    Ok(HashDigest::from(hasher).digest.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1004,6 +1402,59 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_verify_files() -> Result<(), Error> {
+        let temp_dir = tempfile::tempdir()?;
+        let mut installation = create_test_installation(
+            ImplementationName::CPython,
+            3,
+            12,
+            0,
+            None,
+            PythonVariant::Default,
+            None,
+        );
+        installation.path = temp_dir.path().to_path_buf();
+
+        // Without recorded hashes, the check is skipped.
+        assert_eq!(installation.verify_files().await?, Verification::Skipped);
+
+        let lib = temp_dir.path().join("lib").join("python3.12");
+        fs::create_dir_all(lib.join("__pycache__"))?;
+        fs::create_dir_all(lib.join("site-packages"))?;
+        fs::write(lib.join("os.py"), "import sys\n")?;
+        fs::write(lib.join("__pycache__").join("os.pyc"), "bytecode")?;
+        fs::write(lib.join("site-packages").join("README.txt"), "packages")?;
+
+        installation.record_file_hashes().await?;
+        let hashes = fs::read_to_string(temp_dir.path().join(FILES_SHA256_FILENAME))?;
+        assert_eq!(hashes.lines().count(), 1);
+        assert!(hashes.ends_with("  lib/python3.12/os.py\n"));
+        assert_eq!(installation.verify_files().await?, Verification::Passed);
+
+        // Mutable files may change without failing the check.
+        fs::write(lib.join("__pycache__").join("os.pyc"), "other bytecode")?;
+        fs::write(
+            lib.join("site-packages").join("README.txt"),
+            "other packages",
+        )?;
+        assert_eq!(installation.verify_files().await?, Verification::Passed);
+
+        fs::write(lib.join("os.py"), "")?;
+        assert!(matches!(
+            installation.verify_files().await,
+            Err(Error::FileHashMismatch { .. })
+        ));
+
+        fs::remove_file(lib.join("os.py"))?;
+        assert!(matches!(
+            installation.verify_files().await,
+            Err(Error::FileMissing { .. })
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_is_upgrade_of_same_version() {
         let installation = create_test_installation(
@@ -1312,8 +1763,15 @@ mod tests {
         let platform = Platform::from_env().unwrap();
         let temp_dir = tempfile::tempdir().unwrap();
 
-        // Create mock installation directories
-        fs::create_dir(temp_dir.path().join(format!("cpython-3.10.0-{platform}"))).unwrap();
+        // Create mock installation directories, including an executable such that they're
+        // considered complete
+        let installation_dir = temp_dir.path().join(format!("cpython-3.10.0-{platform}"));
+        fs::create_dir(&installation_dir).unwrap();
+        let executable = ManagedPythonInstallation::from_path(installation_dir)
+            .unwrap()
+            .executable(false);
+        fs::create_dir_all(executable.parent().unwrap()).unwrap();
+        fs::write(&executable, "").unwrap();
 
         temp_env::with_var(
             uv_static::EnvVars::UV_PYTHON_INSTALL_DIR,
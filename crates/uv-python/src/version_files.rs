@@ -151,6 +151,47 @@ impl PythonVersionFile {
         Self::try_from_path(path).await
     }
 
+    /// Find every Python version file in the given directory and its parents, followed by any
+    /// global version file, in order of precedence.
+    ///
+    /// Unlike [`PythonVersionFile::discover`], files that are shadowed by a file with higher
+    /// precedence are included. The first file, if any, is the one that would be discovered.
+    pub async fn discover_all(
+        working_directory: impl AsRef<Path>,
+        options: &DiscoveryOptions<'_>,
+    ) -> Result<Vec<Self>, std::io::Error> {
+        if !options.config_discovery.enabled() {
+            return Ok(Vec::new());
+        }
+
+        let local = if options.no_local {
+            Vec::new()
+        } else {
+            working_directory
+                .as_ref()
+                .ancestors()
+                .take_while(|path| {
+                    options
+                        .stop_discovery_at
+                        .and_then(Path::parent)
+                        .is_none_or(|stop_discovery_at| stop_discovery_at != *path)
+                })
+                .flat_map(|path| Self::find_all_in_directory(path, options))
+                .collect::<Vec<_>>()
+        };
+        let global = user_uv_config_dir()
+            .into_iter()
+            .flat_map(|user_config_dir| Self::find_all_in_directory(&user_config_dir, options));
+
+        let mut files = Vec::new();
+        for path in local.into_iter().chain(global).unique() {
+            if let Some(file) = Self::try_from_path(path).await? {
+                files.push(file);
+            }
+        }
+        Ok(files)
+    }
+
     fn find_global(options: &DiscoveryOptions<'_>) -> Option<PathBuf> {
         let user_config_dir = user_uv_config_dir()?;
         Self::find_in_directory(&user_config_dir, options)
@@ -170,6 +211,14 @@ impl PythonVersionFile {
     }
 
     fn find_in_directory(path: &Path, options: &DiscoveryOptions<'_>) -> Option<PathBuf> {
+        Self::find_all_in_directory(path, options).next()
+    }
+
+    /// Find all version files in the given directory, in order of preference.
+    fn find_all_in_directory(
+        path: &Path,
+        options: &DiscoveryOptions<'_>,
+    ) -> impl Iterator<Item = PathBuf> + use<> {
         let version_path = path.join(PYTHON_VERSION_FILENAME);
         let versions_path = path.join(PYTHON_VERSIONS_FILENAME);
 
@@ -178,7 +227,7 @@ impl PythonVersionFile {
             FilePreference::Version => [version_path, versions_path],
         };
 
-        paths.into_iter().find(|path| path.is_file())
+        paths.into_iter().filter(|path| path.is_file())
     }
 
     /// Try to read a Python version file at the given path.
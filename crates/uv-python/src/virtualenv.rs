@@ -48,6 +48,9 @@ pub struct PyVenvConfiguration {
     pub(super) include_system_site_packages: bool,
     /// The Python version the virtual environment was created with
     pub(super) version: Option<PythonVersion>,
+    /// The environments extended by an ephemeral environment, from the immediate parent to the
+    /// most distant ancestor.
+    pub(super) extends_environment: Vec<PathBuf>,
 }
 
 #[derive(Debug, Error)]
@@ -138,6 +141,58 @@ impl CondaEnvironmentKind {
     }
 }
 
+/// An active conda environment, as identified by `CONDA_PREFIX`.
+#[derive(Debug, Clone)]
+pub struct CondaEnvironment {
+    /// The root of the conda environment, e.g., `/opt/conda/envs/example`.
+    root: PathBuf,
+}
+
+impl CondaEnvironment {
+    /// Detect the active conda environment, if any.
+    ///
+    /// The `CONDA_PREFIX` must contain a `conda-meta` directory, such that a stale or unrelated
+    /// `CONDA_PREFIX` isn't mistaken for a conda environment.
+    pub fn from_env() -> Option<Self> {
+        let root = env::var_os(EnvVars::CONDA_PREFIX).filter(|value| !value.is_empty())?;
+        let root = PathBuf::from(root);
+        is_conda_environment(&root).then_some(Self { root })
+    }
+
+    /// Returns the root of the conda environment.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Returns the name of the conda environment, e.g., `base`.
+    ///
+    /// Environments created with `conda create --prefix` are unnamed, in which case the name of the
+    /// environment directory is used instead.
+    pub fn name(&self) -> Cow<'_, str> {
+        if let Ok(name) = env::var(EnvVars::CONDA_DEFAULT_ENV)
+            && !name.is_empty()
+            && Path::new(&name) != self.root
+        {
+            return Cow::Owned(name);
+        }
+        self.root.file_name().map_or_else(
+            || self.root.to_string_lossy(),
+            |name| name.to_string_lossy(),
+        )
+    }
+
+    /// Returns `true` if this is the base conda environment.
+    pub fn is_base(&self) -> bool {
+        CondaEnvironmentKind::from_prefix_path(&self.root) == CondaEnvironmentKind::Base
+    }
+}
+
+/// Returns `true` if the given prefix is a conda environment, i.e., it contains a `conda-meta`
+/// directory.
+pub fn is_conda_environment(prefix: &Path) -> bool {
+    prefix.join("conda-meta").is_dir()
+}
+
 /// Detect whether the current `CONDA_PREFIX` belongs to a Pixi-managed environment.
 fn is_pixi_environment(path: &Path) -> bool {
     path.join("conda-meta").join("pixi").is_file()
@@ -237,6 +292,7 @@ impl PyVenvConfiguration {
         let mut seed = false;
         let mut include_system_site_packages = true;
         let mut version = None;
+        let mut extends_environment = Vec::new();
 
         // Per https://snarky.ca/how-virtual-environments-work/, the `pyvenv.cfg` file is not a
         // valid INI file, and is instead expected to be parsed by partitioning each line on the
@@ -272,6 +328,11 @@ impl PyVenvConfiguration {
                             .map_err(|e| io::Error::new(std::io::ErrorKind::InvalidData, e))?,
                     );
                 }
+                "extends-environment" => {
+                    extends_environment = env::split_paths(value.trim())
+                        .filter(|path| !path.as_os_str().is_empty())
+                        .collect();
+                }
                 _ => {}
             }
         }
@@ -284,6 +345,7 @@ impl PyVenvConfiguration {
             seed,
             include_system_site_packages,
             version,
+            extends_environment,
         })
     }
 
@@ -312,6 +374,12 @@ impl PyVenvConfiguration {
         self.include_system_site_packages
     }
 
+    /// Returns the environments extended by an ephemeral environment, from the immediate parent to
+    /// the most distant ancestor.
+    pub fn extends_environment(&self) -> &[PathBuf] {
+        &self.extends_environment
+    }
+
     /// Set the key-value pair in the `pyvenv.cfg` file.
     pub fn set(content: &str, key: &str, value: &str) -> String {
         let mut lines = content.lines().map(Cow::Borrowed).collect::<Vec<_>>();
@@ -1,5 +1,6 @@
 pub use crate::extras::*;
 pub use crate::lookahead::*;
+pub use crate::remote::*;
 pub use crate::source_tree::*;
 pub use crate::sources::*;
 pub use crate::specification::*;
@@ -13,6 +14,7 @@ use uv_distribution_types::{Dist, DistErrorKind, Requirement, RequirementSource}
 
 mod extras;
 mod lookahead;
+mod remote;
 mod source_tree;
 mod sources;
 mod specification;
@@ -0,0 +1,147 @@
+//! Fetching remote requirements files (e.g., constraints and overrides provided via URL).
+//!
+//! Remote files are always fetched when online, and the latest contents are persisted to the
+//! cache such that they remain usable with `--offline`.
+
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+use anyhow::{Context, Result, bail};
+use futures::StreamExt;
+use sha2::{Digest, Sha256};
+use tracing::debug;
+use url::Url;
+
+use uv_cache::{Cache, CacheBucket};
+use uv_cache_key::{CanonicalUrl, cache_digest};
+use uv_client::BaseClientBuilder;
+use uv_pypi_types::{HashAlgorithm, HashDigest};
+use uv_redacted::DisplaySafeUrl;
+use uv_warnings::warn_user;
+
+/// The maximum size of a remote requirements file, in bytes.
+const MAX_REMOTE_FILE_SIZE: u64 = 10 * 1024 * 1024;
+
+/// The contents of a remote requirements file.
+#[derive(Debug, Clone)]
+pub struct RemoteFile {
+    /// The contents of the file.
+    pub content: String,
+    /// The SHA-256 digest of the contents.
+    pub hash: HashDigest,
+}
+
+/// Returns the URL of a requirements file, if it refers to a remote (`http` or `https`) file.
+pub fn remote_file_url(path: &Path) -> Option<DisplaySafeUrl> {
+    if !(path.starts_with("http://") || path.starts_with("https://")) {
+        return None;
+    }
+    DisplaySafeUrl::parse(path.to_str()?).ok()
+}
+
+/// Fetch a remote requirements file.
+///
+/// When online, the file is always fetched (following redirects, and with any configured
+/// credentials), and the cached copy is updated. When offline, the cached copy is used, if any.
+pub async fn fetch_remote_file(
+    url: &DisplaySafeUrl,
+    client_builder: &BaseClientBuilder<'_>,
+    cache: &Cache,
+) -> Result<RemoteFile> {
+    let entry = cache.entry(
+        CacheBucket::Requirements,
+        "",
+        format!("{}.txt", cache_digest(&CanonicalUrl::new(url.clone()))),
+    );
+
+    if client_builder.is_offline() {
+        let content = match fs_err::tokio::read_to_string(entry.path()).await {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                bail!(
+                    "Network connectivity is disabled, but the remote file `{url}` has not been cached"
+                );
+            }
+            Err(err) => return Err(err.into()),
+        };
+        let age = fs_err::metadata(entry.path())
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| SystemTime::now().duration_since(modified).ok());
+        match age {
+            Some(age) => warn_user!(
+                "Using cached copy of `{url}` from {} ago, since network connectivity is disabled",
+                format_age(age)
+            ),
+            None => {
+                warn_user!("Using cached copy of `{url}`, since network connectivity is disabled");
+            }
+        }
+        let hash = sha256(content.as_bytes());
+        return Ok(RemoteFile { content, hash });
+    }
+
+    debug!("Fetching remote file: {url}");
+    let client = client_builder.build()?;
+    let response = client
+        .for_host(url)
+        .get(Url::from(url.clone()))
+        .send()
+        .await
+        .with_context(|| format!("Failed to fetch: `{url}`"))?
+        .error_for_status()
+        .with_context(|| format!("Failed to fetch: `{url}`"))?;
+
+    if response
+        .content_length()
+        .is_some_and(|size| size > MAX_REMOTE_FILE_SIZE)
+    {
+        bail!("Remote file `{url}` exceeds the maximum size of {MAX_REMOTE_FILE_SIZE} bytes");
+    }
+
+    let mut bytes = Vec::new();
+    let mut stream = response.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.with_context(|| format!("Failed to read: `{url}`"))?;
+        bytes.extend_from_slice(&chunk);
+        if bytes.len() as u64 > MAX_REMOTE_FILE_SIZE {
+            bail!("Remote file `{url}` exceeds the maximum size of {MAX_REMOTE_FILE_SIZE} bytes");
+        }
+    }
+
+    let hash = sha256(&bytes);
+    let content =
+        String::from_utf8(bytes).with_context(|| format!("Remote file `{url}` is not UTF-8"))?;
+
+    fs_err::tokio::create_dir_all(entry.dir()).await?;
+    uv_fs::write_atomic(entry.path(), content.as_bytes()).await?;
+
+    Ok(RemoteFile { content, hash })
+}
+
+/// Compute the SHA-256 digest of the given bytes.
+fn sha256(bytes: &[u8]) -> HashDigest {
+    HashDigest {
+        algorithm: HashAlgorithm::Sha256,
+        digest: format!("{:x}", Sha256::digest(bytes)).into(),
+    }
+}
+
+/// Format the age of a cached file, e.g., `3 hours`.
+fn format_age(age: Duration) -> String {
+    let seconds = age.as_secs();
+    let (value, unit) = if seconds < 60 {
+        (seconds, "second")
+    } else if seconds < 60 * 60 {
+        (seconds / 60, "minute")
+    } else if seconds < 60 * 60 * 24 {
+        (seconds / (60 * 60), "hour")
+    } else {
+        (seconds / (60 * 60 * 24), "day")
+    };
+    if value == 1 {
+        format!("{value} {unit}")
+    } else {
+        format!("{value} {unit}s")
+    }
+}
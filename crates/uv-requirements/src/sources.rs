@@ -1,10 +1,12 @@
 use std::ffi::OsStr;
 use std::path::{Path, PathBuf};
 
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, bail};
 use console::Term;
 
 use uv_fs::{CWD, Simplified};
+use uv_pep508::{MarkerTree, UnnamedRequirement, UnnamedRequirementUrl};
+use uv_pypi_types::{ParsedUrl, PyProjectToml, VerbatimParsedUrl};
 use uv_requirements_txt::RequirementsTxtRequirement;
 
 #[derive(Debug, Clone)]
@@ -278,12 +280,21 @@ impl RequirementsSource {
             }
         }
 
-        let requirement = RequirementsTxtRequirement::parse(name, &*CWD, false)
-            .with_context(|| format!("Failed to parse: `{name}`"))?;
+        let requirement = parse_local_requirement(name, false)?;
+        validate_local_extras(&requirement)?;
 
         Ok(Self::Package(requirement))
     }
 
+    /// Parse an editable [`RequirementsSource`] from a user-provided string, assumed to be a
+    /// `--with-editable` package (e.g., `uv run --with-editable './pkg[dev]'`).
+    pub fn from_with_editable_argument(name: &str) -> Result<Self> {
+        let requirement = parse_local_requirement(name, true)?;
+        validate_local_extras(&requirement)?;
+
+        Ok(Self::Editable(requirement))
+    }
+
     /// Parse an editable [`RequirementsSource`] (e.g., `uv pip install -e .`).
     pub fn from_editable(name: &str) -> Result<Self> {
         let requirement = RequirementsTxtRequirement::parse(name, &*CWD, true)
@@ -328,6 +339,87 @@ impl std::fmt::Display for RequirementsSource {
     }
 }
 
+/// Parse a requirement from the command line, preferring an existing local path over extras.
+///
+/// Paths may legitimately end in a bracketed component (e.g., `C:\builds\pkg[1]` on Windows), so
+/// if the argument names a path that exists, it's treated verbatim rather than as a path with
+/// extras.
+fn parse_local_requirement(name: &str, editable: bool) -> Result<RequirementsTxtRequirement> {
+    if name.ends_with(']') && CWD.join(name).exists() {
+        let url = VerbatimParsedUrl::parse_path(name, &*CWD)
+            .with_context(|| format!("Failed to parse: `{name}`"))?
+            .with_given(name);
+        return Ok(RequirementsTxtRequirement::Unnamed(UnnamedRequirement {
+            url,
+            extras: Box::default(),
+            marker: MarkerTree::TRUE,
+            origin: None,
+        }));
+    }
+
+    RequirementsTxtRequirement::parse(name, &*CWD, editable)
+        .with_context(|| format!("Failed to parse: `{name}`"))
+}
+
+/// Ensure that any extras requested on a local directory requirement (e.g., `./pkg[dev]`) are
+/// declared in the directory's static `pyproject.toml` metadata.
+///
+/// If the metadata can't be read statically (e.g., the extras are declared as `dynamic`), the
+/// requirement is accepted as-is and any missing extras are reported during resolution.
+fn validate_local_extras(requirement: &RequirementsTxtRequirement) -> Result<()> {
+    let RequirementsTxtRequirement::Unnamed(requirement) = requirement else {
+        return Ok(());
+    };
+    if requirement.extras.is_empty() {
+        return Ok(());
+    }
+    let ParsedUrl::Directory(directory) = &requirement.url.parsed_url else {
+        return Ok(());
+    };
+
+    let pyproject_path = directory.install_path.join("pyproject.toml");
+    let Some(project) = fs_err::read_to_string(&pyproject_path)
+        .ok()
+        .and_then(|contents| {
+            PyProjectToml::from_toml(&contents, pyproject_path.user_display()).ok()
+        })
+        .and_then(|pyproject| pyproject.project)
+    else {
+        return Ok(());
+    };
+    if project
+        .dynamic
+        .as_ref()
+        .is_some_and(|dynamic| dynamic.iter().any(|field| field == "optional-dependencies"))
+    {
+        return Ok(());
+    }
+
+    let declared = project.optional_dependencies.unwrap_or_default();
+    for extra in &requirement.extras {
+        if declared.contains_key(extra) {
+            continue;
+        }
+        if declared.is_empty() {
+            bail!(
+                "`{}` does not declare any extras, but `{extra}` was requested",
+                directory.install_path.user_display(),
+            );
+        }
+        bail!(
+            "`{}` does not declare an extra named `{extra}` (available extras: {})",
+            directory.install_path.user_display(),
+            declared
+                .keys()
+                .map(|extra| format!("`{extra}`"))
+                .collect::<Vec<_>>()
+                .join(", "),
+        );
+    }
+
+    Ok(())
+}
+
 /// Returns `true` if a file name matches the `pylock.toml` pattern defined in PEP 751.
 #[expect(clippy::case_sensitive_file_extension_comparisons)]
 pub fn is_pylock_toml(file_name: &str) -> bool {
@@ -35,6 +35,7 @@ use rustc_hash::FxHashSet;
 use tracing::instrument;
 use url::Url;
 
+use uv_cache::Cache;
 use uv_cache_key::CanonicalUrl;
 use uv_client::BaseClientBuilder;
 use uv_configuration::{
@@ -47,13 +48,14 @@ use uv_distribution_types::{
 };
 use uv_fs::{CWD, Simplified};
 use uv_normalize::{ExtraName, PackageName, PipGroupName};
-use uv_pypi_types::PyProjectToml;
+use uv_pep508::MarkerEnvironment;
+use uv_pypi_types::{HashDigest, PyProjectToml};
 use uv_redacted::DisplaySafeUrl;
 use uv_requirements_txt::{RequirementsTxt, RequirementsTxtRequirement, SourceCache};
 use uv_scripts::{OverrideDependency, Pep723Metadata};
 use uv_warnings::warn_user;
 
-use crate::{RequirementsSource, SourceTree};
+use crate::{RequirementsSource, SourceTree, fetch_remote_file, remote_file_url};
 
 #[derive(Debug, Default, Clone)]
 pub struct RequirementsSpecification {
@@ -89,6 +91,8 @@ pub struct RequirementsSpecification {
     pub no_binary: NoBinary,
     /// The `--no-build` flags to enforce when selecting distributions.
     pub no_build: NoBuild,
+    /// The remote constraints and overrides files, with the SHA-256 digest of their contents.
+    pub remote_files: BTreeMap<DisplaySafeUrl, HashDigest>,
 }
 
 impl RequirementsSpecification {
@@ -299,7 +303,10 @@ impl RequirementsSpecification {
                     .with_context(|| format!("Failed to parse: `{}`", path.user_display()))?;
 
                 Self {
-                    source_trees: vec![SourceTree::PyProjectToml(path.clone(), pyproject_toml)],
+                    source_trees: vec![SourceTree::PyProjectToml(
+                        path.clone(),
+                        Box::new(pyproject_toml),
+                    )],
                     ..Self::default()
                 }
             }
@@ -397,6 +404,9 @@ impl RequirementsSpecification {
     }
 
     /// Read the combined requirements and constraints from a set of sources.
+    ///
+    /// Remote constraints and overrides files are fetched through the [`Cache`], such that they
+    /// remain available offline.
     pub async fn from_sources(
         requirements: &[RequirementsSource],
         constraints: &[RequirementsSource],
@@ -404,9 +414,31 @@ impl RequirementsSpecification {
         excludes: &[RequirementsSource],
         groups: Option<&GroupsSpecification>,
         client_builder: &BaseClientBuilder<'_>,
+        cache: &Cache,
+    ) -> Result<Self> {
+        Self::from_sources_impl(
+            requirements,
+            constraints,
+            overrides,
+            excludes,
+            groups,
+            client_builder,
+            Some(cache),
+        )
+        .await
+    }
+
+    async fn from_sources_impl(
+        requirements: &[RequirementsSource],
+        constraints: &[RequirementsSource],
+        overrides: &[RequirementsSource],
+        excludes: &[RequirementsSource],
+        groups: Option<&GroupsSpecification>,
+        client_builder: &BaseClientBuilder<'_>,
+        cache: Option<&Cache>,
     ) -> Result<Self> {
         let mut spec = Self::default();
-        let mut cache = SourceCache::default();
+        let mut source_cache = SourceCache::default();
 
         // Disallow `pylock.toml` files as constraints.
         if let Some(pylock_toml) = constraints.iter().find_map(|source| {
@@ -531,10 +563,27 @@ impl RequirementsSpecification {
             spec.groups = group_specs;
         }
 
+        // Fetch any remote constraints and overrides files through the cache, such that they
+        // remain available offline and their contents can be recorded.
+        if let Some(cache) = cache {
+            for source in constraints.iter().chain(overrides) {
+                let RequirementsSource::RequirementsTxt(path) = source else {
+                    continue;
+                };
+                let Some(url) = remote_file_url(path) else {
+                    continue;
+                };
+                let file = fetch_remote_file(&url, client_builder, cache).await?;
+                source_cache.insert(path.clone(), file.content);
+                spec.remote_files.insert(url, file.hash);
+            }
+        }
+
         // Resolve sources into specifications so we know their `source_tree`.
         let mut requirement_sources = Vec::new();
         for source in requirements {
-            let source = Self::from_source_with_cache(source, client_builder, &mut cache).await?;
+            let source =
+                Self::from_source_with_cache(source, client_builder, &mut source_cache).await?;
             requirement_sources.push(source);
         }
 
@@ -589,7 +638,8 @@ impl RequirementsSpecification {
         // Read all constraints, treating both requirements _and_ constraints as constraints.
         // Overrides are ignored.
         for source in constraints {
-            let source = Self::from_source_with_cache(source, client_builder, &mut cache).await?;
+            let source =
+                Self::from_source_with_cache(source, client_builder, &mut source_cache).await?;
             for entry in source.requirements {
                 match entry.requirement {
                     UnresolvedRequirement::Named(requirement) => {
@@ -628,7 +678,8 @@ impl RequirementsSpecification {
         // Read all overrides, treating both requirements _and_ overrides as overrides.
         // Constraints are ignored.
         for source in overrides {
-            let source = Self::from_source_with_cache(source, client_builder, &mut cache).await?;
+            let source =
+                Self::from_source_with_cache(source, client_builder, &mut source_cache).await?;
             spec.overrides.extend(source.requirements);
             spec.overrides.extend(source.overrides);
             spec.override_dependencies
@@ -654,7 +705,8 @@ impl RequirementsSpecification {
 
         // Collect excludes.
         for source in excludes {
-            let source = Self::from_source_with_cache(source, client_builder, &mut cache).await?;
+            let source =
+                Self::from_source_with_cache(source, client_builder, &mut source_cache).await?;
             for req_spec in source.requirements {
                 match req_spec.requirement {
                     UnresolvedRequirement::Named(requirement) => {
@@ -681,12 +733,32 @@ impl RequirementsSpecification {
         Ok(UnresolvedRequirementSpecification::from(requirement))
     }
 
+    /// Remove any unnamed requirements (e.g., `./pkg ; sys_platform == 'win32'`) whose markers
+    /// don't apply to the given environment, returning the removed requirements.
+    ///
+    /// Named requirements are retained, as their markers are evaluated during resolution. Unnamed
+    /// requirements, however, must be built to determine their names, which may not be possible on
+    /// a platform to which they don't apply.
+    pub fn remove_inapplicable_unnamed(
+        &mut self,
+        markers: &MarkerEnvironment,
+    ) -> Vec<UnresolvedRequirementSpecification> {
+        let (removed, retained) = std::mem::take(&mut self.requirements)
+            .into_iter()
+            .partition(|spec| {
+                matches!(spec.requirement, UnresolvedRequirement::Unnamed(_))
+                    && !spec.requirement.evaluate_markers(Some(markers), &[])
+            });
+        self.requirements = retained;
+        removed
+    }
+
     /// Read the requirements from a set of sources.
     pub async fn from_simple_sources(
         requirements: &[RequirementsSource],
         client_builder: &BaseClientBuilder<'_>,
     ) -> Result<Self> {
-        Self::from_sources(requirements, &[], &[], &[], None, client_builder).await
+        Self::from_sources_impl(requirements, &[], &[], &[], None, client_builder, None).await
     }
 
     /// Initialize a [`RequirementsSpecification`] from a list of [`Requirement`], including
@@ -3,6 +3,7 @@ use std::ops::Bound;
 
 use either::Either;
 use itertools::Itertools;
+use rustc_hash::FxHashSet;
 use smallvec::SmallVec;
 use tracing::{debug, trace};
 
@@ -22,10 +23,10 @@ use crate::version_map::{VersionMap, VersionMapDistHandle};
 use crate::{Exclusions, Manifest, Options, ResolverEnvironment};
 
 #[derive(Debug, Clone)]
-#[expect(clippy::struct_field_names)]
 pub(crate) struct CandidateSelector {
     resolution_strategy: ResolutionStrategy,
     prerelease_strategy: PrereleaseStrategy,
+    prerelease_packages: FxHashSet<PackageName>,
     index_strategy: IndexStrategy,
 }
 
@@ -49,6 +50,7 @@ impl CandidateSelector {
                 env,
                 options.dependency_mode,
             ),
+            prerelease_packages: options.prerelease_packages.iter().cloned().collect(),
             index_strategy: options.index_strategy,
         }
     }
@@ -71,6 +73,20 @@ impl CandidateSelector {
         &self.index_strategy
     }
 
+    /// Returns whether pre-releases are allowed for the given package, taking into account both
+    /// the [`PrereleaseStrategy`] and any packages that were explicitly opted in to pre-releases
+    /// via `prerelease-packages`.
+    pub(crate) fn allows_prerelease(
+        &self,
+        package_name: &PackageName,
+        env: &ResolverEnvironment,
+    ) -> AllowPrerelease {
+        if self.prerelease_packages.contains(package_name) {
+            return AllowPrerelease::Yes;
+        }
+        self.prerelease_strategy.allows(package_name, env)
+    }
+
     /// Select a [`Candidate`] from a set of candidate versions and files.
     ///
     /// Unless present in the provided [`Exclusions`], local distributions from the
@@ -301,7 +317,7 @@ impl CandidateSelector {
 
             // Respect the pre-release strategy for this fork.
             if version.any_prerelease() {
-                let allow = match self.prerelease_strategy.allows(package_name, env) {
+                let allow = match self.allows_prerelease(package_name, env) {
                     AllowPrerelease::Yes => true,
                     AllowPrerelease::No => false,
                     // If the pre-release was provided via an existing file, rather than from the
@@ -424,7 +440,7 @@ impl CandidateSelector {
         );
         let highest = self.use_highest_version(package_name, env);
 
-        let allow_prerelease = match self.prerelease_strategy.allows(package_name, env) {
+        let allow_prerelease = match self.allows_prerelease(package_name, env) {
             AllowPrerelease::Yes => true,
             AllowPrerelease::No => false,
             // Allow pre-releases if there are no stable versions available.
@@ -0,0 +1,99 @@
+//! Checkpoints of the forks completed during a universal resolution.
+//!
+//! When a resolution spans many forks, a failure in a late fork (e.g., a transient network error)
+//! discards the work done for every fork that had already been solved. A checkpoint records the
+//! versions selected by each completed fork, such that a retried resolution can prefer them.
+//!
+//! Each line of a checkpoint is a pinned requirement, with the fork's markers, e.g.,
+//! `anyio==4.3.0 ; python_full_version >= '3.12'`.
+
+use std::path::Path;
+use std::str::FromStr;
+
+use tracing::debug;
+
+use uv_normalize::PackageName;
+use uv_pep440::{Operator, Version};
+use uv_pep508::{MarkerTree, Requirement, VersionOrUrl};
+
+use crate::Preference;
+use crate::resolver::Resolution;
+use crate::universal_marker::UniversalMarker;
+
+/// The versions selected by the forks completed so far.
+#[derive(Debug, Default)]
+pub(crate) struct Checkpoint(Vec<String>);
+
+impl Checkpoint {
+    /// Record the registry versions selected by a completed fork.
+    pub(crate) fn extend(&mut self, resolution: &Resolution) {
+        let marker = resolution
+            .env
+            .try_universal_markers()
+            .unwrap_or(UniversalMarker::TRUE)
+            .combined();
+        for (package, version) in &resolution.nodes {
+            if package.url.is_some() || package.extra.is_some() || package.dev.is_some() {
+                continue;
+            }
+            self.insert(&package.name, version, marker);
+        }
+    }
+
+    /// Record a version selected by a completed fork.
+    fn insert(&mut self, name: &PackageName, version: &Version, marker: MarkerTree) {
+        match marker.try_to_string() {
+            Some(marker) => self.0.push(format!("{name}=={version} ; {marker}")),
+            None => self.0.push(format!("{name}=={version}")),
+        }
+    }
+
+    /// Persist the checkpoint to the given path.
+    pub(crate) fn write(&self, path: &Path) {
+        let mut contents = self.0.join("\n");
+        contents.push('\n');
+        if let Err(err) = uv_fs::write_atomic_sync(path, contents) {
+            debug!(
+                "Failed to write resolver checkpoint to `{}`: {err}",
+                path.display()
+            );
+        }
+    }
+}
+
+/// Read the preferences recorded in a checkpoint, if one exists.
+///
+/// Malformed entries are ignored, as a checkpoint only serves to speed up the resolution.
+pub fn read_checkpoint(path: &Path) -> Vec<Preference> {
+    let contents = match fs_err::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) => {
+            if err.kind() != std::io::ErrorKind::NotFound {
+                debug!("Failed to read resolver checkpoint: {err}");
+            }
+            return Vec::new();
+        }
+    };
+
+    contents
+        .lines()
+        .filter_map(|line| {
+            let requirement = Requirement::<uv_pep508::VerbatimUrl>::from_str(line).ok()?;
+            let Some(VersionOrUrl::VersionSpecifier(specifiers)) = requirement.version_or_url
+            else {
+                return None;
+            };
+            let [specifier] = specifiers.as_ref() else {
+                return None;
+            };
+            if *specifier.operator() != Operator::Equal {
+                return None;
+            }
+            Some(Preference::from_checkpoint(
+                requirement.name,
+                specifier.version().clone(),
+                requirement.marker,
+            ))
+        })
+        .collect()
+}
@@ -0,0 +1,310 @@
+//! A persistent cache of the dependencies of registry wheels, reused across resolutions.
+//!
+//! Re-locking a large project (e.g., with `--upgrade`) reads the metadata of every candidate wheel
+//! again, even if it was already read by the previous resolution. The metadata of a registry wheel
+//! is immutable, so the cache stores the metadata used by each resolution, keyed by the package
+//! name, version, and the digest of the wheel, such that the next resolution can skip fetching and
+//! parsing it.
+//!
+//! Each cache file records a fingerprint of the settings that affect the metadata returned for a
+//! wheel (e.g., `dependency-metadata` overrides). If the fingerprint changes, the whole file is
+//! discarded. Only the entries used by the last resolution are persisted.
+
+use std::path::{Path, PathBuf};
+
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use uv_distribution::ArchiveMetadata;
+use uv_distribution_types::{BuiltDist, DependencyMetadata, Dist};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_pypi_types::{HashAlgorithm, HashDigest, ResolutionMetadata};
+
+use crate::Options;
+
+/// The key of a cache entry.
+type Key = (PackageName, Version, HashDigest);
+
+/// The dependencies of the registry wheels read during resolution.
+#[derive(Debug)]
+pub(crate) struct DependencyCache {
+    /// The path at which the cache is persisted.
+    path: PathBuf,
+    /// The fingerprint of the settings that the entries were read with.
+    fingerprint: String,
+    /// The entries persisted by a previous resolution.
+    cached: FxHashMap<Key, ResolutionMetadata>,
+    /// The entries read during the current resolution.
+    used: papaya::HashMap<Key, ResolutionMetadata>,
+}
+
+/// The serialized representation of a [`DependencyCache`].
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheFile {
+    fingerprint: String,
+    entries: Vec<Entry>,
+}
+
+/// The metadata of a registry wheel, along with the digest of the wheel.
+#[derive(Debug, Serialize, Deserialize)]
+struct Entry {
+    digest: HashDigest,
+    metadata: ResolutionMetadata,
+}
+
+impl DependencyCache {
+    /// Read the cache persisted at the given path.
+    ///
+    /// Entries written with different settings are discarded. Returns `None` if the settings can't
+    /// be fingerprinted, in which case the cache is skipped entirely.
+    pub(crate) fn read(
+        path: PathBuf,
+        options: &Options,
+        dependency_metadata: &DependencyMetadata,
+    ) -> Option<Self> {
+        let fingerprint = match fingerprint(options, dependency_metadata) {
+            Ok(fingerprint) => fingerprint,
+            Err(err) => {
+                debug!("Skipping dependency cache; failed to fingerprint resolver settings: {err}");
+                return None;
+            }
+        };
+        let cached = match read_entries(&path, &fingerprint) {
+            Ok(entries) => entries,
+            Err(reason) => {
+                debug!(
+                    "Ignoring dependency cache at `{}`: {reason}",
+                    path.display()
+                );
+                FxHashMap::default()
+            }
+        };
+        Some(Self {
+            path,
+            fingerprint,
+            cached,
+            used: papaya::HashMap::default(),
+        })
+    }
+
+    /// Return the cached metadata for a distribution, if any.
+    pub(crate) fn get(&self, dist: &Dist) -> Option<ArchiveMetadata> {
+        let key = key(dist)?;
+        let metadata = self.cached.get(&key)?.clone();
+        self.used.pin().insert(key, metadata.clone());
+        Some(ArchiveMetadata::from_metadata23(metadata))
+    }
+
+    /// Record the metadata read for a distribution.
+    pub(crate) fn insert(&self, dist: &Dist, archive: &ArchiveMetadata) {
+        let Some(key) = key(dist) else {
+            return;
+        };
+
+        // Only cache metadata that was read as-is from the wheel.
+        let metadata = &archive.metadata;
+        if !archive.hashes.is_empty()
+            || !metadata.dependency_groups.is_empty()
+            || metadata.name != key.0
+            || metadata.version != key.1
+            || metadata
+                .requires_dist
+                .iter()
+                .any(|requirement| !requirement.groups.is_empty())
+        {
+            return;
+        }
+
+        let metadata = ResolutionMetadata {
+            name: metadata.name.clone(),
+            version: metadata.version.clone(),
+            requires_dist: metadata
+                .requires_dist
+                .iter()
+                .cloned()
+                .map(uv_pep508::Requirement::from)
+                .collect(),
+            requires_python: metadata.requires_python.clone(),
+            provides_extra: metadata.provides_extra.clone(),
+            dynamic: metadata.dynamic,
+            metadata_version: metadata.metadata_version,
+        };
+        self.used.pin().insert(key, metadata);
+    }
+
+    /// Persist the entries read during the current resolution.
+    pub(crate) fn write(&self) {
+        let mut entries = self
+            .used
+            .pin()
+            .iter()
+            .map(|((.., digest), metadata)| Entry {
+                digest: digest.clone(),
+                metadata: metadata.clone(),
+            })
+            .collect::<Vec<_>>();
+        entries.sort_unstable_by(|a, b| {
+            (&a.metadata.name, &a.metadata.version, &a.digest).cmp(&(
+                &b.metadata.name,
+                &b.metadata.version,
+                &b.digest,
+            ))
+        });
+
+        let file = CacheFile {
+            fingerprint: self.fingerprint.clone(),
+            entries,
+        };
+        let data = match rmp_serde::to_vec(&file) {
+            Ok(data) => data,
+            Err(err) => {
+                debug!("Failed to serialize dependency cache: {err}");
+                return;
+            }
+        };
+        if let Err(err) = uv_fs::write_atomic_sync(&self.path, data) {
+            debug!(
+                "Failed to write dependency cache to `{}`: {err}",
+                self.path.display()
+            );
+        }
+    }
+}
+
+/// Read the entries persisted at the given path, if they were written with the given fingerprint.
+fn read_entries(
+    path: &Path,
+    fingerprint: &str,
+) -> Result<FxHashMap<Key, ResolutionMetadata>, String> {
+    let data = match fs_err::read(path) {
+        Ok(data) => data,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            return Ok(FxHashMap::default());
+        }
+        Err(err) => return Err(err.to_string()),
+    };
+    let file = rmp_serde::from_slice::<CacheFile>(&data).map_err(|err| err.to_string())?;
+    if file.fingerprint != fingerprint {
+        return Err("resolver settings changed".to_string());
+    }
+    Ok(file
+        .entries
+        .into_iter()
+        .map(|entry| {
+            (
+                (
+                    entry.metadata.name.clone(),
+                    entry.metadata.version.clone(),
+                    entry.digest,
+                ),
+                entry.metadata,
+            )
+        })
+        .collect())
+}
+
+/// Return the cache key for a distribution, if its metadata can be cached.
+///
+/// Only registry wheels are cached, as the metadata of a source distribution depends on the build
+/// settings. The wheel must be hashed, as the digest is what identifies its contents.
+fn key(dist: &Dist) -> Option<Key> {
+    let Dist::Built(BuiltDist::Registry(wheels)) = dist else {
+        return None;
+    };
+    let wheel = wheels.best_wheel();
+    let digest = wheel
+        .file
+        .hashes
+        .iter()
+        .find(|digest| digest.algorithm() != HashAlgorithm::Md5)?;
+    Some((
+        wheel.filename.name.clone(),
+        wheel.filename.version.clone(),
+        digest.clone(),
+    ))
+}
+
+/// Compute a fingerprint of the settings that affect the metadata returned for a registry wheel.
+fn fingerprint(
+    options: &Options,
+    dependency_metadata: &DependencyMetadata,
+) -> Result<String, serde_json::Error> {
+    let mut dependency_metadata = dependency_metadata.values().collect::<Vec<_>>();
+    dependency_metadata.sort();
+    let settings = serde_json::to_string(&(
+        uv_version::version(),
+        options.require_metadata,
+        dependency_metadata,
+    ))?;
+    Ok(uv_cache_key::cache_digest(&settings))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_distribution_types::Requirement;
+    use uv_pypi_types::VerbatimParsedUrl;
+
+    use super::*;
+
+    #[test]
+    fn entries_round_trip() {
+        let requires_dist = [
+            "idna>=2.8",
+            "sniffio>=1.1 ; python_full_version < '3.13'",
+            "trio>=0.26.1 ; extra == 'trio'",
+            "foo @ https://example.com/foo-1.0.0-py3-none-any.whl",
+        ]
+        .into_iter()
+        .map(|requirement| {
+            Requirement::from(
+                uv_pep508::Requirement::<VerbatimParsedUrl>::from_str(requirement).unwrap(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+        let file = CacheFile {
+            fingerprint: "fingerprint".to_string(),
+            entries: vec![Entry {
+                digest: HashDigest::from_str("sha256:0123456789abcdef").unwrap(),
+                metadata: ResolutionMetadata {
+                    name: PackageName::from_str("anyio").unwrap(),
+                    version: Version::from_str("4.3.0").unwrap(),
+                    requires_dist: requires_dist
+                        .iter()
+                        .cloned()
+                        .map(uv_pep508::Requirement::from)
+                        .collect(),
+                    requires_python: Some(">=3.8".parse().unwrap()),
+                    provides_extra: Box::new(["trio".parse().unwrap()]),
+                    dynamic: false,
+                    metadata_version: None,
+                },
+            }],
+        };
+
+        let data = rmp_serde::to_vec(&file).unwrap();
+        let file = rmp_serde::from_slice::<CacheFile>(&data).unwrap();
+        assert_eq!(file.fingerprint, "fingerprint");
+
+        let [entry] = file.entries.as_slice() else {
+            panic!("expected a single entry");
+        };
+        let metadata = ArchiveMetadata::from_metadata23(entry.metadata.clone()).metadata;
+        assert_eq!(
+            metadata
+                .requires_dist
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            requires_dist
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(metadata.provides_extra.as_ref(), ["trio".parse().unwrap()]);
+    }
+}
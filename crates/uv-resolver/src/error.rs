@@ -14,7 +14,7 @@ use uv_distribution_types::{
     DerivationChain, DistErrorKind, IndexCapabilities, IndexLocations, IndexUrl, RequestedDist,
 };
 use uv_normalize::{ExtraName, InvalidNameError, PackageName};
-use uv_pep440::{LowerBound, Version};
+use uv_pep440::{LowerBound, UpperBound, Version, VersionSpecifier, VersionSpecifiers};
 use uv_pep508::MarkerEnvironment;
 use uv_platform_tags::Tags;
 use uv_pypi_types::ParsedUrl;
@@ -33,7 +33,8 @@ use crate::pubgrub::{
 use crate::python_requirement::PythonRequirement;
 use crate::resolution::ConflictingDistributionError;
 use crate::resolver::{
-    MetadataUnavailable, ResolverEnvironment, UnavailablePackage, UnavailableReason,
+    MemoryLimitError, MetadataUnavailable, ResolverEnvironment, UnavailablePackage,
+    UnavailableReason,
 };
 use crate::{InMemoryIndex, Options};
 
@@ -93,6 +94,18 @@ pub enum ResolveError {
     #[error("Requirements contain conflicting indexes for package `{0}`: `{1}` vs. `{2}`")]
     ConflictingIndexes(PackageName, String, String),
 
+    #[error(
+        "The wheel source `{url}` for package `{}` is used when `{required}`, but the wheel (`{filename}`) only supports `{supported}`",
+        package_name.cyan()
+    )]
+    IncompatibleWheelSource {
+        package_name: PackageName,
+        url: String,
+        filename: String,
+        required: String,
+        supported: String,
+    },
+
     #[error(
         "Package `{name}` was included as a URL dependency. URL dependencies must be expressed as direct requirements or constraints. Consider adding `{requirement}` to your dependencies or constraints file.",
         name = name.cyan(),
@@ -114,6 +127,9 @@ pub enum ResolveError {
     #[error(transparent)]
     NoSolution(#[from] Box<NoSolutionError>),
 
+    #[error(transparent)]
+    MemoryLimit(#[from] Box<MemoryLimitError>),
+
     #[error("Attempted to construct an invalid version specifier")]
     InvalidVersion(#[from] uv_pep440::VersionSpecifierBuildError),
 
@@ -157,6 +173,9 @@ impl uv_errors::Hint for ResolveError {
             Self::Client(error) => uv_errors::Hint::hints(error),
             Self::Distribution(error) => uv_errors::Hint::hints(error),
             Self::Dependencies(error, ..) => uv_errors::Hint::hints(error.as_ref()),
+            Self::MemoryLimit(_) => uv_errors::Hints::from(
+                "Constrain the versions of these packages (e.g., with lower bounds or constraints) to reduce the number of versions the resolver must consider",
+            ),
             _ => uv_errors::Hints::none(),
         }
     }
@@ -657,6 +676,189 @@ impl NoSolutionError {
         minimum
     }
 
+    /// Given a [`DerivationTree`], identify the largest Python upper bound that is compatible with
+    /// the required lower bound.
+    ///
+    /// This is the counterpart to [`NoSolutionError::find_requires_python`] for cases in which the
+    /// Python version is _too new_ (e.g., a package declares `requires-python = "<3.13"`). When
+    /// multiple upper bounds are involved (e.g., from different versions of the same package), the
+    /// largest one is preferred, to avoid downgrading the interpreter further than necessary.
+    pub fn find_requires_python_upper_bound(&self) -> UpperBound {
+        let lower = self.find_requires_python();
+        let mut maximum: Option<UpperBound> = None;
+        let mut trees = vec![&*self.error];
+
+        while let Some(derivation_tree) = trees.pop() {
+            match derivation_tree {
+                DerivationTree::Derived(derived) => {
+                    trees.push(&derived.cause2);
+                    trees.push(&derived.cause1);
+                }
+                DerivationTree::External(External::FromDependencyOf(.., package, version)) => {
+                    if let PubGrubPackageInner::Python(_) = &**package {
+                        if let Some((.., upper)) = version.bounding_range() {
+                            let upper = UpperBound::new(upper.cloned());
+                            // Ignore unbounded ranges, along with any upper bound that excludes
+                            // every Python version permitted by the lower bound.
+                            if matches!(upper.0, Bound::Unbounded)
+                                || !bounds_overlap(&lower, &upper)
+                            {
+                                continue;
+                            }
+                            if maximum.as_ref().is_none_or(|maximum| upper > *maximum) {
+                                maximum = Some(upper);
+                            }
+                        }
+                    }
+                }
+                DerivationTree::External(_) => {}
+            }
+        }
+
+        maximum.unwrap_or_default()
+    }
+
+    /// Identify candidate remediations for the resolution failure.
+    ///
+    /// The candidates are derived from the requirements on each package in the derivation tree
+    /// and the versions that were available for it. They are _not_ guaranteed to resolve, and
+    /// should be validated (e.g., by re-running the resolution) before being surfaced.
+    pub fn remediations(&self) -> Vec<Remediation> {
+        // Collect the requirements on each package, along with the package that declared them.
+        let mut requirements: BTreeMap<&PackageName, Vec<(&PubGrubPackage, &Range<Version>)>> =
+            BTreeMap::new();
+        let mut trees = vec![&*self.error];
+
+        while let Some(derivation_tree) = trees.pop() {
+            match derivation_tree {
+                DerivationTree::Derived(derived) => {
+                    trees.push(&derived.cause2);
+                    trees.push(&derived.cause1);
+                }
+                DerivationTree::External(External::FromDependencyOf(
+                    package,
+                    _,
+                    dependency,
+                    versions,
+                )) => {
+                    let Some(name) = dependency.name_no_root() else {
+                        continue;
+                    };
+                    // Skip the edges between a proxy package and its base package.
+                    if package.name() == Some(name) {
+                        continue;
+                    }
+                    requirements
+                        .entry(name)
+                        .or_default()
+                        .push((package, versions));
+                }
+                DerivationTree::External(_) => {}
+            }
+        }
+
+        let mut constraints = Vec::new();
+        let mut overrides = Vec::new();
+        let mut relaxations = Vec::new();
+
+        for (name, requirements) in requirements {
+            let Some(versions) = self
+                .included_versions
+                .get(name)
+                .or_else(|| self.available_versions.get(name))
+            else {
+                continue;
+            };
+
+            let is_direct = |package: &PubGrubPackage| {
+                package.is_root() || is_workspace_member(package, &self.workspace_members)
+            };
+            let intersection = |direct: Option<bool>| {
+                requirements
+                    .iter()
+                    .filter(|(package, _)| direct.is_none_or(|direct| is_direct(package) == direct))
+                    .fold(Range::full(), |acc, (_, versions)| {
+                        acc.intersection(versions)
+                    })
+            };
+            let has_direct = requirements.iter().any(|(package, _)| is_direct(package));
+            let has_transitive = requirements.iter().any(|(package, _)| !is_direct(package));
+
+            // If a set of versions satisfies every requirement, constrain the package to it.
+            if let Some(specifiers) = remediation_specifiers(&intersection(None), versions) {
+                constraints.push(Remediation::Constraint {
+                    package: name.clone(),
+                    specifiers,
+                });
+            }
+
+            if has_transitive {
+                // Override the transitive requirements with the direct requirements, if any, or
+                // otherwise with the transitive requirement that admits the newest version.
+                let target = if has_direct {
+                    Some(intersection(Some(true)))
+                } else {
+                    requirements
+                        .iter()
+                        .filter_map(|(_, range)| {
+                            versions
+                                .iter()
+                                .rev()
+                                .find(|version| range.contains(version))
+                                .map(|version| (version, *range))
+                        })
+                        .max_by(|(a, _), (b, _)| a.cmp(b))
+                        .map(|(_, range)| range.clone())
+                };
+                if let Some(specifiers) = target
+                    .as_ref()
+                    .and_then(|target| remediation_specifiers(target, versions))
+                {
+                    overrides.push(Remediation::Override {
+                        package: name.clone(),
+                        specifiers,
+                    });
+                }
+            }
+
+            // Relax any workspace member's requirement to match the transitive requirements or,
+            // if the package is only required directly and no available version satisfies the
+            // requirement, drop the specifier entirely.
+            for (package, range) in &requirements {
+                if !is_workspace_member(package, &self.workspace_members) {
+                    continue;
+                }
+                let Some(member) = package.name() else {
+                    continue;
+                };
+                let target = if has_transitive {
+                    remediation_specifiers(&intersection(Some(false)), versions)
+                } else if remediation_specifiers(range, versions).is_none() {
+                    Some(VersionSpecifiers::empty())
+                } else {
+                    None
+                };
+                let Some(specifiers) = target else {
+                    continue;
+                };
+                let remediation = Remediation::Relax {
+                    member: member.clone(),
+                    package: name.clone(),
+                    specifiers,
+                };
+                if !relaxations.contains(&remediation) {
+                    relaxations.push(remediation);
+                }
+            }
+        }
+
+        constraints
+            .into_iter()
+            .chain(overrides)
+            .chain(relaxations)
+            .collect()
+    }
+
     /// Return the [`ResolverEnvironment`] that caused the failure.
     pub fn environment(&self) -> &ResolverEnvironment {
         &self.env
@@ -684,6 +886,15 @@ impl NoSolutionError {
         &self.cached().1
     }
 
+    /// Return the packages that lack `win_arm64` wheels, but provide `win_amd64` wheels that could
+    /// be installed under x64 emulation, if the resolution targeted Windows ARM64.
+    pub fn windows_arm64_packages(&self) -> Option<&BTreeSet<PackageName>> {
+        self.pubgrub_hints().iter().find_map(|hint| match hint {
+            PubGrubHint::WindowsArm64 { packages } => Some(packages),
+            _ => None,
+        })
+    }
+
     /// Compute the reduced derivation tree, formatted report string, and hints.
     fn compute_report_and_hints(&self) -> (String, IndexSet<PubGrubHint>) {
         let formatter = PubGrubReportFormatter {
@@ -1434,6 +1645,78 @@ impl std::fmt::Display for NoSolutionHeader {
     }
 }
 
+/// A candidate remediation for a resolution failure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Remediation {
+    /// Add a `constraint-dependencies` entry for the package.
+    Constraint {
+        package: PackageName,
+        specifiers: VersionSpecifiers,
+    },
+    /// Add an `override-dependencies` entry for the package.
+    Override {
+        package: PackageName,
+        specifiers: VersionSpecifiers,
+    },
+    /// Relax a workspace member's requirement on the package.
+    Relax {
+        member: PackageName,
+        package: PackageName,
+        specifiers: VersionSpecifiers,
+    },
+}
+
+impl Remediation {
+    /// Return the name of the package that the remediation applies to.
+    pub fn package(&self) -> &PackageName {
+        match self {
+            Self::Constraint { package, .. }
+            | Self::Override { package, .. }
+            | Self::Relax { package, .. } => package,
+        }
+    }
+
+    /// Return the version specifiers that the remediation applies to the package.
+    pub fn specifiers(&self) -> &VersionSpecifiers {
+        match self {
+            Self::Constraint { specifiers, .. }
+            | Self::Override { specifiers, .. }
+            | Self::Relax { specifiers, .. } => specifiers,
+        }
+    }
+}
+
+/// Return the [`VersionSpecifiers`] that capture the available (non-pre-release) versions within
+/// the given range, or `None` if the range doesn't contain any available versions.
+///
+/// Bounds are expressed in terms of the available versions (e.g., `<2` is rendered as `<2.0` if
+/// `2.0` is the next available version) to avoid leaking internal sentinel versions.
+fn remediation_specifiers(
+    range: &Range<Version>,
+    versions: &BTreeSet<Version>,
+) -> Option<VersionSpecifiers> {
+    let mut releases = versions.iter().filter(|version| !version.any_prerelease());
+    let mut matching = releases.clone().filter(|version| range.contains(version));
+    let lowest = matching.next()?;
+    let highest = matching.next_back().unwrap_or(lowest);
+    let (lower, upper) = range.bounding_range()?;
+
+    if lower != Bound::Unbounded && upper != Bound::Unbounded && lowest == highest {
+        return Some(VersionSpecifiers::from(VersionSpecifier::equals_version(
+            lowest.clone(),
+        )));
+    }
+
+    let lower = (lower != Bound::Unbounded)
+        .then(|| VersionSpecifier::greater_than_equal_version(lowest.clone()));
+    let upper =
+        (upper != Bound::Unbounded).then(|| match releases.find(|version| *version > highest) {
+            Some(next) => VersionSpecifier::less_than_version(next.clone()),
+            None => VersionSpecifier::less_than_equal_version(highest.clone()),
+        });
+    Some(lower.into_iter().chain(upper).collect())
+}
+
 /// Given a [`DerivationTree`], simplify version ranges using the included versions for each
 /// package.
 fn simplify_derivation_tree_ranges(
@@ -1554,10 +1837,8 @@ fn simplify_range(
     }
 
     // Check if pre-releases are allowed
-    let prereleases_not_allowed = candidate_selector
-        .prerelease_strategy()
-        .allows(name, resolver_environment)
-        != AllowPrerelease::Yes;
+    let prereleases_not_allowed =
+        candidate_selector.allows_prerelease(name, resolver_environment) != AllowPrerelease::Yes;
 
     let any_prerelease = range.iter().any(|(start, end)| {
         let is_pre1 = match start {
@@ -1592,6 +1873,18 @@ fn simplify_range(
     )))
 }
 
+/// Returns `true` if at least one version satisfies both the lower and the upper bound.
+fn bounds_overlap(lower: &LowerBound, upper: &UpperBound) -> bool {
+    match (&lower.0, &upper.0) {
+        (Bound::Unbounded, _) | (_, Bound::Unbounded) => true,
+        (Bound::Included(lower), Bound::Included(upper)) => lower <= upper,
+        (
+            Bound::Included(lower) | Bound::Excluded(lower),
+            Bound::Included(upper) | Bound::Excluded(upper),
+        ) => lower < upper,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1625,6 +1918,32 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn requires_python_bounds_overlap() {
+        let version = |v: &str| v.parse::<Version>().unwrap();
+        let lower = LowerBound::new(Bound::Included(version("3.10")));
+        assert!(bounds_overlap(
+            &lower,
+            &UpperBound::new(Bound::Excluded(version("3.13")))
+        ));
+        assert!(bounds_overlap(
+            &lower,
+            &UpperBound::new(Bound::Included(version("3.10")))
+        ));
+        assert!(!bounds_overlap(
+            &lower,
+            &UpperBound::new(Bound::Excluded(version("3.10")))
+        ));
+        assert!(!bounds_overlap(
+            &LowerBound::new(Bound::Excluded(version("3.10"))),
+            &UpperBound::new(Bound::Included(version("3.10")))
+        ));
+        assert!(bounds_overlap(
+            &LowerBound::default(),
+            &UpperBound::default()
+        ));
+    }
+
     #[test]
     fn derivation_tree_packages_are_unique() {
         let tree = StackSafeErrorTree::new(deep_derivation_tree());
@@ -1,5 +1,6 @@
+pub use checkpoint::read_checkpoint;
 pub use dependency_mode::DependencyMode;
-pub use error::{ErrorTree, NoSolutionError, NoSolutionHeader, ResolveError};
+pub use error::{ErrorTree, NoSolutionError, NoSolutionHeader, Remediation, ResolveError};
 pub use exclude_newer::{
     ExcludeNewer, ExcludeNewerChange, ExcludeNewerOverrideChange, ExcludeNewerPackage,
     ExcludeNewerPackageChange, ExcludeNewerPackageEntry, ExcludeNewerValueChange,
@@ -9,11 +10,15 @@ pub use exclusions::Exclusions;
 pub use flat_index::{FlatDistributions, FlatIndex};
 pub use fork_strategy::ForkStrategy;
 pub use lock::{
-    DependencySelection, Installable, Lock, LockError, LockVersion, Metadata, Package, PackageMap,
-    PylockToml, PylockTomlError, PylockTomlErrorKind, PythonReport, RequirementsTxtExport,
-    ResolverManifest, SatisfiesResult, SelectedDependency, TreeDisplay, TreeJsonTarget, VERSION,
-    cyclonedx_json, implicit_constraints_marker,
+    ArtifactProvenance, DependencySelection, DuplicatesDisplay, Installable, Lock, LockError,
+    LockVersion, Metadata, Package, PackageMap, PackageProvenance, PylockToml,
+    PylockTomlBundleArtifact, PylockTomlBundleTarget, PylockTomlError, PylockTomlErrorKind,
+    PythonReport, RequirementsTxtExport, ResolverManifest, SatisfiesResult, SelectedDependency,
+    TreeDisplay, TreeJsonTarget, VERSION, cyclonedx_json, implicit_constraints_marker,
+    validate_prune,
 };
+#[cfg(feature = "schemars")]
+pub use lock::{LockProblem, LockValidation, lock_schema, validate_lock};
 pub use manifest::Manifest;
 pub use options::{Flexibility, Options, OptionsBuilder};
 pub use preferences::{Preference, PreferenceError, Preferences};
@@ -25,9 +30,9 @@ pub use resolution::{
 };
 pub use resolution_mode::ResolutionMode;
 pub use resolver::{
-    DefaultResolverProvider, InMemoryIndex, MetadataResponse, PackageVersionsResult,
-    Reporter as ResolverReporter, Resolver, ResolverEnvironment, ResolverProvider,
-    VersionsResponse, WheelMetadataResult,
+    DefaultResolverProvider, InMemoryIndex, MemoryLimitError, MetadataResponse,
+    PackageVersionsResult, Reporter as ResolverReporter, Resolver, ResolverEnvironment,
+    ResolverProvider, VersionsResponse, WheelMetadataResult,
 };
 pub(crate) use universal_marker::ConflictMarker;
 pub use universal_marker::UniversalMarker;
@@ -46,6 +51,8 @@ type FxHashbrownSet<T> = hashbrown::HashSet<T, rustc_hash::FxBuildHasher>;
 type FxHashbrownMap<K, V> = hashbrown::HashMap<K, V, rustc_hash::FxBuildHasher>;
 
 mod candidate_selector;
+mod checkpoint;
+mod dependency_cache;
 mod dependency_mode;
 mod dependency_provider;
 mod error;
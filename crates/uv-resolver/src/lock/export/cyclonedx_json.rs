@@ -21,7 +21,7 @@ use uv_preview::{Preview, PreviewFeature};
 use uv_warnings::warn_user;
 
 use crate::lock::export::{ExportableRequirement, ExportableRequirements};
-use crate::lock::{LockErrorKind, Package, PackageId, RegistrySource, Source};
+use crate::lock::{LockErrorKind, Package, PackageId, PackageProvenance, RegistrySource, Source};
 use crate::{Installable, LockError};
 
 /// Character set for percent-encoding PURL components, copied from packageurl.rs (<https://github.com/scm-rs/packageurl.rs/blob/a725aa0ab332934c350641508017eb09ddfa0813/src/purl.rs#L18>).
@@ -52,6 +52,7 @@ const PURL_ENCODE_SET: &AsciiSet = &CONTROLS
 struct ComponentBuilder<'a> {
     id_counter: usize, // Used as prefix in bom-ref generation, to ensure uniqueness
     package_to_component_map: HashMap<&'a PackageId, Component>,
+    provenance: &'a [PackageProvenance], // Recorded when `tool.uv.lock-provenance` is enabled
 }
 
 impl<'a> ComponentBuilder<'a> {
@@ -218,6 +219,31 @@ impl<'a> ComponentBuilder<'a> {
             ));
         }
 
+        if let Some(provenance) = self.provenance.iter().find(|provenance| {
+            provenance.name == package.id.name
+                && package.id.version.as_ref() == Some(&provenance.version)
+        }) {
+            properties.push(Property::new("uv:provenance:index", &provenance.index));
+            if let Some(index_name) = &provenance.index_name {
+                properties.push(Property::new(
+                    "uv:provenance:index_name",
+                    index_name.as_ref(),
+                ));
+            }
+            properties.push(Property::new(
+                "uv:provenance:locked_at",
+                &provenance.locked_at.to_string(),
+            ));
+            for artifact in &provenance.artifacts {
+                if let Some(upload_time) = artifact.upload_time {
+                    properties.push(Property::new(
+                        format!("uv:provenance:upload_time:{}", artifact.filename),
+                        &upload_time.to_string(),
+                    ));
+                }
+            }
+        }
+
         Component {
             component_type: Classification::Library,
             name: NormalizedString::new(name),
@@ -298,7 +324,10 @@ pub fn from_lock<'lock>(
     }
     .or_else(|| target.lock().root()); // Fallback to project root
 
-    let mut component_builder = ComponentBuilder::default();
+    let mut component_builder = ComponentBuilder {
+        provenance: target.lock().provenance(),
+        ..ComponentBuilder::default()
+    };
 
     let mut metadata = Metadata {
         component: root
@@ -23,7 +23,10 @@ pub(crate) use crate::lock::export::metadata::{
     MetadataWorkspaceMember,
 };
 pub(crate) use crate::lock::export::pylock_toml::PylockTomlPackage;
-pub use crate::lock::export::pylock_toml::{PylockToml, PylockTomlError, PylockTomlErrorKind};
+pub use crate::lock::export::pylock_toml::{
+    PylockToml, PylockTomlBundleArtifact, PylockTomlBundleTarget, PylockTomlError,
+    PylockTomlErrorKind,
+};
 pub use crate::lock::export::requirements_txt::RequirementsTxtExport;
 use crate::universal_marker::resolve_activated_extras;
 use crate::{Installable, LockError, Package};
@@ -40,6 +43,8 @@ struct ExportableRequirement<'lock> {
     package: &'lock Package,
     /// The marker that must be satisfied to install the package.
     marker: MarkerTree,
+    /// The extras requested on the package by its dependents.
+    extras: Vec<&'lock ExtraName>,
     /// The list of packages that depend on this package.
     dependents: Vec<&'lock Package>,
 }
@@ -106,7 +111,11 @@ impl<'lock> ExportableRequirements<'lock> {
 
                 // Push its dependencies on the queue.
                 queue.push_back((dist, None));
-                for extra in extras.extra_names(dist.optional_dependencies.keys()) {
+                for extra in dist
+                    .optional_dependencies
+                    .keys()
+                    .filter(|extra| extras.contains(extra))
+                {
                     queue.push_back((dist, Some(extra)));
                     activated_items.insert(
                         ConflictItem::from((dist.id.name.clone(), extra.clone())),
@@ -327,6 +336,15 @@ impl<'lock> ExportableRequirements<'lock> {
             .map(|(index, package)| ExportableRequirement {
                 package,
                 marker: reachability.remove(&index).unwrap_or_default(),
+                extras: {
+                    let mut extras = graph
+                        .edges_directed(index, Direction::Incoming)
+                        .flat_map(|edge| edge.weight().dep_extras().iter().copied())
+                        .collect::<Vec<_>>();
+                    extras.sort_unstable();
+                    extras.dedup();
+                    extras
+                },
                 dependents: if annotate {
                     let mut dependents = graph
                         .edges_directed(index, Direction::Incoming)
@@ -350,6 +368,81 @@ impl<'lock> ExportableRequirements<'lock> {
     }
 }
 
+/// Validate that none of the pruned packages are direct dependencies of the export roots.
+///
+/// Pruning a direct dependency would silently drop a requirement that the project declares, so
+/// it must be requested explicitly. Pruning a root (i.e., a workspace member) itself is allowed.
+pub fn validate_prune<'lock>(
+    target: &impl Installable<'lock>,
+    prune: &[PackageName],
+    extras: &ExtrasSpecificationWithDefaults,
+    groups: &DependencyGroupsWithDefaults,
+) -> Result<(), LockError> {
+    if prune.is_empty() {
+        return Ok(());
+    }
+
+    let roots = target.roots().collect::<FxHashSet<_>>();
+    let is_pruned = |name: &PackageName| prune.contains(name) && !roots.contains(name);
+
+    for root_name in &roots {
+        if prune.contains(root_name) {
+            continue;
+        }
+        let Ok(Some(dist)) = target.lock().find_by_name(root_name) else {
+            continue;
+        };
+
+        let mut dependencies = Vec::new();
+        if groups.prod() {
+            dependencies.extend(&dist.dependencies);
+            for extra in extras.extra_names(dist.optional_dependencies.keys()) {
+                dependencies.extend(dist.optional_dependencies.get(extra).into_iter().flatten());
+            }
+        }
+        for (group, deps) in &dist.dependency_groups {
+            if groups.contains(group) {
+                dependencies.extend(deps);
+            }
+        }
+
+        if let Some(dep) = dependencies
+            .into_iter()
+            .find(|dep| is_pruned(&dep.package_id.name))
+        {
+            return Err(LockErrorKind::PrunedDirectDependency {
+                name: dep.package_id.name.clone(),
+                dependent: root_name.to_string(),
+            }
+            .into());
+        }
+    }
+
+    // Check the requirements that are exclusive to the workspace root.
+    if let Some(requirement) = target
+        .lock()
+        .requirements()
+        .iter()
+        .chain(
+            target
+                .lock()
+                .dependency_groups()
+                .iter()
+                .filter(|(group, _)| groups.contains(group))
+                .flat_map(|(_, deps)| deps),
+        )
+        .find(|requirement| is_pruned(&requirement.name))
+    {
+        return Err(LockErrorKind::PrunedDirectDependency {
+            name: requirement.name.clone(),
+            dependent: "the workspace".to_string(),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
 /// A node in the graph.
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum Node<'lock> {
@@ -376,7 +469,7 @@ enum Edge<'lock> {
     },
 }
 
-impl Edge<'_> {
+impl<'lock> Edge<'lock> {
     /// Return the [`MarkerTree`] for this edge.
     fn marker(&self) -> &MarkerTree {
         match self {
@@ -387,7 +480,7 @@ impl Edge<'_> {
     }
 
     /// Return the dependency extras activated by traversing this edge.
-    fn dep_extras(&self) -> &[&ExtraName] {
+    fn dep_extras(&self) -> &[&'lock ExtraName] {
         match self {
             Self::Prod { dep_extras, .. } => dep_extras,
             Self::Optional { dep_extras, .. } => dep_extras,
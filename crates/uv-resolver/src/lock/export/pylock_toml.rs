@@ -149,6 +149,8 @@ pub enum PylockTomlErrorKind {
         "Package `{0}` can't be installed because it is marked as `--no-binary` but is itself a binary distribution"
     )]
     NoBinaryWheelOnly(PackageName),
+    #[error("Missing artifacts for the requested platforms:\n{0}")]
+    MissingBundleArtifacts(String),
     #[error(transparent)]
     WheelFilename(#[from] WheelFilenameError),
     #[error(transparent)]
@@ -209,6 +211,30 @@ where
     }
 }
 
+/// A platform for which a bundle must include installable artifacts.
+#[derive(Debug, Clone, Copy)]
+pub struct PylockTomlBundleTarget<'a> {
+    /// A human-readable name for the platform, used in error messages.
+    pub name: &'a str,
+    /// The marker environment for the platform.
+    pub markers: &'a MarkerEnvironment,
+    /// The compatible wheel tags for the platform.
+    pub tags: &'a Tags,
+}
+
+/// An artifact referenced by a [`PylockToml`] that must be copied into a bundle.
+#[derive(Debug, Clone)]
+pub struct PylockTomlBundleArtifact {
+    /// The package that references the artifact.
+    pub package: PackageName,
+    /// The filename of the artifact within the bundle's artifact directory.
+    pub filename: String,
+    /// The location of the artifact, as a remote or `file://` URL.
+    pub url: DisplaySafeUrl,
+    /// The expected hashes of the artifact.
+    pub hashes: HashDigests,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub struct PylockToml {
@@ -1055,6 +1081,155 @@ impl<'lock> PylockToml {
     }
 
     /// Convert the [`PylockToml`] to a [`Resolution`].
+    /// Rewrite the lock to reference artifacts stored within a bundle.
+    ///
+    /// For each package, only the best wheel for each of the given targets is retained, and every
+    /// retained wheel and archive is rewritten to a relative path under `artifacts`. Source
+    /// distributions are dropped, since building them would require access to an index. Local
+    /// directory sources are left untouched.
+    ///
+    /// Returns the artifacts that must be copied into the bundle, or an error listing every
+    /// package that lacks an installable artifact for one of the targets.
+    pub fn bundle(
+        &mut self,
+        install_path: &Path,
+        artifacts: &Path,
+        targets: &[PylockTomlBundleTarget<'_>],
+    ) -> Result<Vec<PylockTomlBundleArtifact>, PylockTomlError> {
+        let mut bundled = Vec::new();
+        let mut missing = Vec::new();
+
+        for package in &mut self.packages {
+            let active = targets
+                .iter()
+                .filter(|target| package.marker.evaluate_pep751(target.markers, &[], &[]))
+                .collect::<Vec<_>>();
+            if active.is_empty() {
+                continue;
+            }
+
+            // Local source trees are expected to be present wherever the bundle is installed.
+            if package.directory.is_some() {
+                continue;
+            }
+
+            if package.vcs.is_some() {
+                missing.push(format!(
+                    "`{}` is a Git dependency, which can't be bundled",
+                    package.name
+                ));
+                continue;
+            }
+
+            if let Some(archive) = package.archive.as_mut() {
+                let url = if let Some(url) = archive.url.as_ref() {
+                    url.clone()
+                } else if let Some(path) = archive.path.as_ref() {
+                    DisplaySafeUrl::from_file_path(install_path.join(path))
+                        .map_err(|()| PylockTomlErrorKind::PathToUrl)?
+                } else {
+                    return Err(
+                        PylockTomlErrorKind::ArchiveMissingPathUrl(package.name.clone()).into(),
+                    );
+                };
+                let Ok(filename) = url.filename().map(|filename| filename.to_string()) else {
+                    return Err(PylockTomlErrorKind::UrlMissingFilename(url).into());
+                };
+                bundled.push(PylockTomlBundleArtifact {
+                    package: package.name.clone(),
+                    filename: filename.clone(),
+                    url,
+                    hashes: HashDigests::from(archive.hashes.clone()),
+                });
+                archive.url = None;
+                archive.path = Some(PortablePathBuf::from(artifacts.join(filename).as_path()));
+                continue;
+            }
+
+            // Identify the best wheel for each target.
+            let mut selected = Vec::new();
+            for target in &active {
+                let Some(wheel) = package.find_best_wheel(target.tags) else {
+                    if package.sdist.is_some() {
+                        missing.push(format!(
+                            "`{}` only provides a source distribution for {}",
+                            package.name, target.name
+                        ));
+                    } else {
+                        missing.push(format!(
+                            "`{}` has no compatible wheel for {}",
+                            package.name, target.name
+                        ));
+                    }
+                    continue;
+                };
+                let filename = wheel.filename(&package.name)?.to_string();
+                if !selected.contains(&filename) {
+                    selected.push(filename);
+                }
+            }
+
+            let Some(wheels) = package.wheels.take() else {
+                continue;
+            };
+            let mut retained = Vec::with_capacity(selected.len());
+            for mut wheel in wheels {
+                let filename = wheel.filename(&package.name)?.to_string();
+                if !selected.contains(&filename) {
+                    continue;
+                }
+                let url = if let Some(url) = wheel.url.as_ref() {
+                    url.clone()
+                } else if let Some(path) = wheel.path.as_ref() {
+                    DisplaySafeUrl::from_file_path(install_path.join(path))
+                        .map_err(|()| PylockTomlErrorKind::PathToUrl)?
+                } else {
+                    return Err(
+                        PylockTomlErrorKind::WheelMissingPathUrl(package.name.clone()).into(),
+                    );
+                };
+                bundled.push(PylockTomlBundleArtifact {
+                    package: package.name.clone(),
+                    filename: filename.clone(),
+                    url,
+                    hashes: HashDigests::from(wheel.hashes.clone()),
+                });
+                wheel.name = None;
+                wheel.url = None;
+                wheel.path = Some(PortablePathBuf::from(artifacts.join(&filename).as_path()));
+                retained.push(wheel);
+            }
+            package.wheels = Some(retained);
+            package.sdist = None;
+        }
+
+        if !missing.is_empty() {
+            let missing = missing
+                .iter()
+                .map(|missing| format!("  - {missing}"))
+                .collect::<Vec<_>>()
+                .join("\n");
+            return Err(PylockTomlErrorKind::MissingBundleArtifacts(missing).into());
+        }
+
+        Ok(bundled)
+    }
+
+    /// Resolve any relative local directory sources in the lock against the given root.
+    ///
+    /// Within a bundle, artifacts are stored relative to the lockfile, while local directory
+    /// sources remain relative to the project from which the bundle was exported.
+    pub fn relocate_directories(&mut self, root: &Path) {
+        for package in &mut self.packages {
+            if let Some(directory) = package.directory.as_mut() {
+                let path: &Path = directory.path.as_ref();
+                if path.is_relative() {
+                    directory.path = PortablePathBuf::from(root.join(path).as_path());
+                }
+            }
+        }
+    }
+
     pub fn to_resolution(
         self,
         install_path: &Path,
@@ -2,6 +2,7 @@ use std::borrow::Cow;
 use std::fmt::Formatter;
 use std::path::{Component, Path, PathBuf};
 
+use itertools::Itertools;
 use owo_colors::OwoColorize;
 use url::Url;
 
@@ -9,9 +10,9 @@ use uv_configuration::{
     DependencyGroupsWithDefaults, EditableMode, ExtrasSpecificationWithDefaults, InstallOptions,
 };
 use uv_distribution_filename::{DistExtension, SourceDistExtension};
-use uv_fs::Simplified;
+use uv_fs::{Simplified, normalize_path};
 use uv_git_types::GitReference;
-use uv_normalize::PackageName;
+use uv_normalize::{ExtraName, PackageName};
 use uv_pypi_types::{ParsedArchiveUrl, ParsedGitDirectoryUrl, ParsedGitPathUrl};
 use uv_redacted::DisplaySafeUrl;
 
@@ -25,6 +26,16 @@ pub struct RequirementsTxtExport<'lock> {
     nodes: Vec<ExportableRequirement<'lock>>,
     hashes: bool,
     editable: Option<EditableMode>,
+    relative_to: Option<RelativeTo>,
+}
+
+/// The directories used to rebase relative paths in the lockfile.
+#[derive(Debug)]
+struct RelativeTo {
+    /// The root of the workspace, against which paths in the lockfile are relative.
+    root: PathBuf,
+    /// The directory against which paths should be written.
+    base: PathBuf,
 }
 
 impl<'lock> RequirementsTxtExport<'lock> {
@@ -57,8 +68,84 @@ impl<'lock> RequirementsTxtExport<'lock> {
             nodes,
             hashes,
             editable,
+            relative_to: None,
         })
     }
+
+    /// Write relative paths against the `base` directory, rather than the workspace `root`.
+    ///
+    /// Paths in the lockfile are relative to the workspace root; rebasing them ensures that the
+    /// exported requirements can be consumed from a different directory (e.g., the directory
+    /// containing the output file).
+    #[must_use]
+    pub fn with_relative_to(mut self, root: &Path, base: &Path) -> Self {
+        if normalize_path(root) != normalize_path(base) {
+            self.relative_to = Some(RelativeTo {
+                root: root.to_path_buf(),
+                base: base.to_path_buf(),
+            });
+        }
+        self
+    }
+
+    /// Remove any requirements that are already included in the `base` export.
+    ///
+    /// A requirement is retained if the package is absent from the base export, or if it's
+    /// included in the base export under a different marker.
+    #[must_use]
+    pub fn difference(mut self, base: &Self) -> Self {
+        self.nodes.retain(|node| {
+            !base
+                .nodes
+                .iter()
+                .any(|other| other.package.id == node.package.id && other.marker == node.marker)
+        });
+        self
+    }
+
+    /// Rebase a path from the lockfile against the requested base directory, if any.
+    fn local_path<'a>(&self, path: &'a Path) -> Cow<'a, Path> {
+        let Some(RelativeTo { root, base }) = &self.relative_to else {
+            return Cow::Borrowed(path);
+        };
+        if path.is_absolute() {
+            return Cow::Borrowed(path);
+        }
+        let path = root.join(path);
+        match uv_fs::relative_to(&path, base) {
+            Ok(relative) => Cow::Owned(relative),
+            // If the path can't be expressed relative to the base (e.g., it's on a different
+            // drive), fall back to an absolute path.
+            Err(_) => Cow::Owned(normalize_path(path).into_owned()),
+        }
+    }
+
+    /// Write a non-editable requirement for a local path, along with its extras.
+    fn write_path(
+        &self,
+        f: &mut Formatter<'_>,
+        name: &PackageName,
+        path: &Path,
+        extras: &[&ExtraName],
+    ) -> std::fmt::Result {
+        let path = self.local_path(path);
+        if path.is_absolute() {
+            let url = Url::from_file_path(path.simplified()).map_err(|()| std::fmt::Error)?;
+            if extras.is_empty() {
+                write!(f, "{url}")
+            } else {
+                // Extras can't be attached to a bare URL, so use a named requirement instead.
+                write!(f, "{name}{} @ {url}", FormatExtras(extras))
+            }
+        } else {
+            write!(
+                f,
+                "{}{}",
+                anchor(&path).portable_display(),
+                FormatExtras(extras)
+            )
+        }
+    }
 }
 
 impl std::fmt::Display for RequirementsTxtExport<'_> {
@@ -67,6 +154,7 @@ impl std::fmt::Display for RequirementsTxtExport<'_> {
         for ExportableRequirement {
             package,
             marker,
+            extras,
             dependents,
         } in &self.nodes
         {
@@ -129,15 +217,7 @@ impl std::fmt::Display for RequirementsTxtExport<'_> {
                     )?;
                 }
                 Source::Path(path) | Source::Directory(path) => {
-                    if path.is_absolute() {
-                        write!(
-                            f,
-                            "{}",
-                            Url::from_file_path(path).map_err(|()| std::fmt::Error)?
-                        )?;
-                    } else {
-                        write!(f, "{}", anchor(path).portable_display())?;
-                    }
+                    self.write_path(f, &package.id.name, path, extras)?;
                 }
                 Source::Editable(path) => match self
                     .editable
@@ -145,18 +225,15 @@ impl std::fmt::Display for RequirementsTxtExport<'_> {
                     .and_then(|editable| editable.for_package(&package.id.name))
                 {
                     None | Some(true) => {
-                        write!(f, "-e {}", anchor(path).portable_display())?;
+                        write!(
+                            f,
+                            "-e {}{}",
+                            anchor(&self.local_path(path)).portable_display(),
+                            FormatExtras(extras)
+                        )?;
                     }
                     Some(false) => {
-                        if path.is_absolute() {
-                            write!(
-                                f,
-                                "{}",
-                                Url::from_file_path(path).map_err(|()| std::fmt::Error)?
-                            )?;
-                        } else {
-                            write!(f, "{}", anchor(path).portable_display())?;
-                        }
+                        self.write_path(f, &package.id.name, path, extras)?;
                     }
                 },
                 Source::Virtual(_) => {
@@ -218,6 +295,18 @@ impl<'lock> From<&'lock Package> for RequirementComparator<'lock> {
     }
 }
 
+/// Format a list of extras as a requirement suffix (e.g., `[dev,test]`), if non-empty.
+struct FormatExtras<'a>(&'a [&'a ExtraName]);
+
+impl std::fmt::Display for FormatExtras<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if !self.0.is_empty() {
+            write!(f, "[{}]", self.0.iter().join(","))?;
+        }
+        Ok(())
+    }
+}
+
 /// Modify a relative [`Path`] to anchor it at the current working directory.
 ///
 /// For example, given `foo/bar`, returns `./foo/bar`.
@@ -984,11 +984,11 @@ mod tests {
         toml::from_str(
             r#"
 version = 1
-revision = 3
+revision = 4
 requires-python = ">=3.11"
 resolution-markers = [
-    "sys_platform == 'darwin'",
     "sys_platform != 'darwin'",
+    "sys_platform == 'darwin'",
 ]
 
 [manifest]
@@ -1067,7 +1067,7 @@ sdist = { url = "https://example.com/unrelated-1.0.0.tar.gz", hash = "sha256:888
         toml::from_str(
             r#"
 version = 1
-revision = 3
+revision = 4
 requires-python = ">=3.11"
 conflicts = [
     [
@@ -1152,7 +1152,7 @@ provides-extras = ["cpu", "gpu"]
         toml::from_str(
             r#"
 version = 1
-revision = 3
+revision = 4
 requires-python = ">=3.11"
 conflicts = [[
     { package = "project", group = "dev" },
@@ -23,15 +23,16 @@ use uv_configuration::{
 };
 use uv_distribution::{DistributionDatabase, FlatRequiresDist, RequiresDist};
 use uv_distribution_filename::{
-    BuildTag, DistExtension, ExtensionError, SourceDistExtension, WheelFilename,
+    BuildTag, DistExtension, DistFilename, ExtensionError, SourceDistExtension, WheelFilename,
 };
 use uv_distribution_types::{
-    BuiltDist, DependencyMetadata, DirectUrlBuiltDist, DirectUrlSourceDist, DirectorySourceDist,
-    Dist, FileLocation, GitDirectorySourceDist, GitPathBuiltDist, GitPathSourceDist, Identifier,
-    IndexLocations, IndexMetadata, IndexUrl, Name, PYPI_URL, PathBuiltDist, PathSourceDist,
-    RegistryBuiltDist, RegistryBuiltWheel, RegistrySourceDist, RemoteSource, Requirement,
-    RequirementSource, RequiresPython, ResolvedDist, SimplifiedMarkerTree, StaticMetadata,
-    ToUrlError, UrlString,
+    ArtifactCandidate, ArtifactRejection, ArtifactVerdict, BuiltDist, DependencyMetadata,
+    DirectUrlBuiltDist, DirectUrlSourceDist, DirectorySourceDist, Dist, FileLocation,
+    GitDirectorySourceDist, GitPathBuiltDist, GitPathSourceDist, Identifier, IncompatibleSource,
+    IncompatibleWheel, IndexLocations, IndexMetadata, IndexUrl, Name, PYPI_URL, PathBuiltDist,
+    PathSourceDist, RegistryBuiltDist, RegistryBuiltWheel, RegistrySourceDist, RemoteSource,
+    Requirement, RequirementSource, RequiresPython, ResolvedDist, SimplifiedMarkerTree,
+    StaticMetadata, ToUrlError, UrlString,
 };
 use uv_fs::{
     PortablePath, PortablePathBuf, Simplified, normalize_path, relative_to, try_relative_to_if,
@@ -48,7 +49,7 @@ use uv_platform_tags::{
 };
 use uv_preview::PreviewFeature;
 use uv_pypi_types::{
-    Conflicts, HashAlgorithm, HashDigest, HashDigests, Hashes, ParsedArchiveUrl,
+    Conflicts, HashAlgorithm, HashDigest, HashDigests, Hashes, MetadataVersion, ParsedArchiveUrl,
     ParsedGitDirectoryUrl, ParsedGitPathUrl, PyProjectToml,
 };
 use uv_redacted::{DisplaySafeUrl, DisplaySafeUrlError};
@@ -61,11 +62,15 @@ use crate::fork_strategy::ForkStrategy;
 pub(crate) use crate::lock::export::PylockTomlPackage;
 pub use crate::lock::export::RequirementsTxtExport;
 pub use crate::lock::export::{
-    Metadata, PylockToml, PylockTomlError, PylockTomlErrorKind, PythonReport, cyclonedx_json,
+    Metadata, PylockToml, PylockTomlBundleArtifact, PylockTomlBundleTarget, PylockTomlError,
+    PylockTomlErrorKind, PythonReport, cyclonedx_json, validate_prune,
 };
 pub use crate::lock::installable::Installable;
 pub use crate::lock::map::PackageMap;
-pub use crate::lock::tree::{TreeDisplay, TreeJsonTarget};
+pub use crate::lock::provenance::{ArtifactProvenance, PackageProvenance};
+#[cfg(feature = "schemars")]
+pub use crate::lock::schema::{LockProblem, LockValidation, lock_schema, validate_lock};
+pub use crate::lock::tree::{DuplicatesDisplay, TreeDisplay, TreeJsonTarget};
 use crate::resolution::{AnnotatedDist, ResolutionGraphNode};
 use crate::universal_marker::{ConflictMarker, UniversalMarker};
 use crate::{
@@ -76,6 +81,9 @@ use crate::{
 pub(crate) mod export;
 mod installable;
 mod map;
+mod provenance;
+#[cfg(feature = "schemars")]
+mod schema;
 mod serialize;
 mod tree;
 
@@ -83,7 +91,12 @@ mod tree;
 pub const VERSION: u32 = 1;
 
 /// The current revision of the lockfile format.
-const REVISION: u32 = 3;
+///
+/// Within a revision, the serialized output for a given lockfile must remain byte-identical
+/// across uv releases, such that users on different patch versions don't produce spurious diffs.
+/// Any change to the canonical ordering of entries or to the rendering of markers must bump the
+/// revision.
+const REVISION: u32 = 4;
 
 static LINUX_MARKERS: LazyLock<UniversalMarker> = LazyLock::new(|| {
     let pep508 = MarkerTree::from_str("os_name == 'posix' and sys_platform == 'linux'").unwrap();
@@ -302,6 +315,10 @@ pub struct Lock {
     by_id: FxHashMap<PackageId, usize>,
     /// The input requirements to the resolution.
     manifest: ResolverManifest,
+    /// The provenance of each package locked from a registry, if recorded.
+    ///
+    /// Provenance is informational only, and is never used to validate the lockfile.
+    provenance: Vec<PackageProvenance>,
 }
 
 /// Return the marker domain covered by the supported environments and `requires-python`.
@@ -567,8 +584,16 @@ impl Lock {
         let options = ResolverOptions {
             resolution_mode: resolution.options.resolution_mode,
             prerelease_mode: resolution.options.prerelease_mode,
+            prerelease_packages: resolution
+                .options
+                .prerelease_packages
+                .iter()
+                .cloned()
+                .collect(),
             fork_strategy: resolution.options.fork_strategy,
+            require_metadata: resolution.options.require_metadata,
             exclude_newer: resolution.options.exclude_newer.clone(),
+            remote_files: BTreeMap::default(),
         };
         // Canonicalize the top-level fork markers to match what is persisted in
         // `uv.lock`. In particular, conflict-only fork markers can serialize to
@@ -602,11 +627,26 @@ impl Lock {
         conflicts: Conflicts,
         supported_environments: Vec<MarkerTree>,
         required_environments: Vec<MarkerTree>,
-        fork_markers: Vec<UniversalMarker>,
+        mut fork_markers: Vec<UniversalMarker>,
     ) -> Result<Self, LockError> {
-        // Put all dependencies for each package in a canonical order and
-        // check for duplicates.
+        // Wheels and resolution markers are put in a canonical order as of Version 1 Revision 4.
+        // Lockfiles written by earlier revisions retain the order in which they were written, so
+        // that they continue to serialize byte-identically.
+        let canonical_order = (version, revision) >= (1, 4);
+        if canonical_order {
+            sort_fork_markers(&mut fork_markers, &requires_python);
+        }
+
+        // Put all dependencies and wheels for each package in a canonical order
+        // and check for duplicates.
         for package in &mut packages {
+            if canonical_order {
+                package
+                    .wheels
+                    .sort_by(|wheel1, wheel2| wheel1.sort_key().cmp(&wheel2.sort_key()));
+                sort_fork_markers(&mut package.fork_markers, &requires_python);
+            }
+
             package.dependencies.sort();
             for [dep1, dep2] in package.dependencies.array_windows() {
                 if dep1 == dep2 {
@@ -756,6 +796,7 @@ impl Lock {
             packages,
             by_id,
             manifest,
+            provenance: Vec::new(),
         };
         Ok(lock)
     }
@@ -784,6 +825,103 @@ impl Lock {
         self
     }
 
+    /// Carry over the pinned index digests from a previous lock, for any package that is locked
+    /// to the same name, version, and source.
+    #[must_use]
+    pub fn with_index_digests_from(mut self, previous: &Self) -> Self {
+        for package in &mut self.packages {
+            if let Some(index) = previous.by_id.get(&package.id) {
+                package
+                    .index_digest
+                    .clone_from(&previous.packages[*index].index_digest);
+            }
+        }
+        self
+    }
+
+    /// Record the pinned index digests for the packages in this lock.
+    ///
+    /// The digests are provided in the same order as [`Lock::packages`]; packages without a new
+    /// digest retain their existing digest, if any.
+    #[must_use]
+    pub fn with_index_digests(mut self, digests: Vec<Option<HashDigest>>) -> Self {
+        for (package, digest) in self.packages.iter_mut().zip(digests) {
+            if let Some(digest) = digest {
+                package.index_digest = Some(Hash::from(digest));
+            }
+        }
+        self
+    }
+
+    /// Returns `true` if any package in this [`Lock`] has a pinned index digest.
+    pub fn has_index_digests(&self) -> bool {
+        self.packages
+            .iter()
+            .any(|package| package.index_digest.is_some())
+    }
+
+    /// Record the remote constraints and overrides files used to generate this lock.
+    ///
+    /// Any credentials are removed from the URLs, such that they're never persisted.
+    #[must_use]
+    pub fn with_remote_files(mut self, files: BTreeMap<DisplaySafeUrl, HashDigest>) -> Self {
+        self.options.remote_files = files
+            .into_iter()
+            .map(|(mut url, hash)| {
+                url.remove_credentials();
+                (url, Hash::from(hash))
+            })
+            .collect();
+        self
+    }
+
+    /// Returns the remote constraints and overrides files used to generate this lock, along with
+    /// the hash of their contents.
+    pub fn remote_files(&self) -> impl Iterator<Item = (&DisplaySafeUrl, &HashDigest)> {
+        self.options
+            .remote_files
+            .iter()
+            .map(|(url, hash)| (url, &hash.0))
+    }
+
+    /// Record the provenance of each package locked from a registry: the index that served its
+    /// metadata, the time at which it was locked, and the upload time of each artifact.
+    ///
+    /// Lock timestamps are retained from the `previous` lock for any package whose index and
+    /// artifacts are unchanged.
+    #[must_use]
+    pub fn with_provenance(
+        mut self,
+        previous: Option<&Self>,
+        indexes: &IndexLocations,
+        root: &Path,
+        now: Timestamp,
+    ) -> Self {
+        let previous = previous
+            .map(|previous| previous.provenance.as_slice())
+            .unwrap_or_default();
+        self.provenance = self
+            .packages
+            .iter()
+            .filter_map(|package| {
+                PackageProvenance::from_package(package, indexes, root, previous, now)
+            })
+            .collect();
+        self
+    }
+
+    /// Remove any recorded provenance from the lock.
+    #[must_use]
+    pub fn without_provenance(mut self) -> Self {
+        self.provenance.clear();
+        self
+    }
+
+    /// Returns the recorded provenance for the packages in this [`Lock`], if any.
+    pub fn provenance(&self) -> &[PackageProvenance] {
+        &self.provenance
+    }
+
     /// Returns `true` if this [`Lock`] includes `provides-extra` metadata.
     pub fn supports_provides_extra(&self) -> bool {
         // `provides-extra` was added in Version 1 Revision 1.
@@ -872,11 +1010,21 @@ impl Lock {
         self.options.prerelease_mode
     }
 
+    /// Returns the packages for which pre-releases were allowed when generating this lock.
+    pub fn prerelease_packages(&self) -> &BTreeSet<PackageName> {
+        &self.options.prerelease_packages
+    }
+
     /// Returns the multi-version mode used to generate this lock.
     pub fn fork_strategy(&self) -> ForkStrategy {
         self.options.fork_strategy
     }
 
+    /// Returns the minimum core metadata version required when generating this lock.
+    pub fn require_metadata(&self) -> Option<MetadataVersion> {
+        self.options.require_metadata
+    }
+
     /// Returns the exclude newer setting used to generate this lock.
     pub fn exclude_newer(&self) -> &ExcludeNewer {
         &self.options.exclude_newer
@@ -902,6 +1050,35 @@ impl Lock {
         &self.manifest.members
     }
 
+    /// Returns the PEP 723 scripts that share this lock, relative to the lockfile's directory.
+    pub fn scripts(&self) -> &BTreeSet<String> {
+        &self.manifest.scripts
+    }
+
+    /// Returns the requirements of the given dependency group that were used to generate this
+    /// lock.
+    pub fn dependency_group_requirements(
+        &self,
+        group: &GroupName,
+    ) -> Option<&BTreeSet<Requirement>> {
+        self.manifest.dependency_groups.get(group)
+    }
+
+    /// Returns the lock for a single script in a lockfile shared by multiple scripts, i.e., a copy
+    /// of this lock in which the requirements of the given dependency group are the root
+    /// requirements.
+    #[must_use]
+    pub fn for_script(&self, group: &GroupName) -> Self {
+        let mut lock = self.clone();
+        lock.manifest.requirements = lock
+            .manifest
+            .dependency_groups
+            .remove(group)
+            .unwrap_or_default();
+        lock.manifest.dependency_groups.clear();
+        lock
+    }
+
     /// Returns the root requirements that were used to generate this lock.
     fn requirements(&self) -> &BTreeSet<Requirement> {
         &self.manifest.requirements
@@ -2499,14 +2676,22 @@ struct ResolverOptions {
     resolution_mode: ResolutionMode,
     /// The [`PrereleaseMode`] used to generate this lock.
     prerelease_mode: PrereleaseMode,
+    /// The packages for which pre-releases were allowed when generating this lock.
+    prerelease_packages: BTreeSet<PackageName>,
     /// The [`ForkStrategy`] used to generate this lock.
     fork_strategy: ForkStrategy,
+    /// The minimum core metadata version required when generating this lock.
+    require_metadata: Option<MetadataVersion>,
     /// The [`ExcludeNewer`] setting used to generate this lock.
     exclude_newer: ExcludeNewer,
+    /// The remote constraints and overrides files used to generate this lock, along with the
+    /// hash of their contents.
+    remote_files: BTreeMap<DisplaySafeUrl, Hash>,
 }
 
 /// The serialized resolver options in the lockfile.
 #[derive(Clone, Debug, Default, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "kebab-case")]
 struct ResolverOptionsWire {
     /// The [`ResolutionMode`] used to generate this lock.
@@ -2515,19 +2700,40 @@ struct ResolverOptionsWire {
     /// The [`PrereleaseMode`] used to generate this lock.
     #[serde(default)]
     prerelease_mode: PrereleaseMode,
+    /// The packages for which pre-releases were allowed when generating this lock.
+    #[serde(default)]
+    prerelease_packages: BTreeSet<PackageName>,
     /// The [`ForkStrategy`] used to generate this lock.
     #[serde(default)]
     fork_strategy: ForkStrategy,
+    /// The minimum core metadata version required when generating this lock.
+    #[serde(default)]
+    require_metadata: Option<MetadataVersion>,
     /// The [`ExcludeNewer`] setting used to generate this lock.
     #[serde(flatten)]
     exclude_newer: ExcludeNewerWire,
+    /// The remote constraints and overrides files used to generate this lock.
+    #[serde(default)]
+    remote_files: Vec<RemoteFileWire>,
+}
+
+/// A remote requirements file recorded in the lockfile.
+#[derive(Clone, Debug, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+struct RemoteFileWire {
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+    url: DisplaySafeUrl,
+    hash: Hash,
 }
 
 #[expect(clippy::struct_field_names)]
 #[derive(Clone, Debug, Default, serde::Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "kebab-case")]
 struct ExcludeNewerWire {
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
     exclude_newer: Option<Timestamp>,
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
     exclude_newer_span: Option<ExcludeNewerSpan>,
     #[serde(default, skip_serializing_if = "ExcludeNewerPackage::is_empty")]
     exclude_newer_package: ExcludeNewerPackage,
@@ -2568,16 +2774,24 @@ impl From<ExcludeNewer> for ExcludeNewerWire {
 }
 
 #[derive(Clone, Debug, Default, serde::Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "kebab-case")]
 pub struct ResolverManifest {
     /// The workspace members included in the lockfile.
     #[serde(default)]
     members: BTreeSet<PackageName>,
+    /// The PEP 723 scripts that share the lockfile, relative to the lockfile's directory.
+    ///
+    /// The requirements of each script are included as a dependency group named after the
+    /// script.
+    #[serde(default)]
+    scripts: BTreeSet<String>,
     /// The requirements provided to the resolver, exclusive of the workspace members.
     ///
     /// These are requirements that are attached to the project, but not to any of its
     /// workspace members. For example, the requirements in a PEP 723 script would be included here.
     #[serde(default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "BTreeSet<serde_json::Value>"))]
     requirements: BTreeSet<Requirement>,
     /// The dependency groups provided to the resolver, exclusive of the workspace members.
     ///
@@ -2585,21 +2799,30 @@ pub struct ResolverManifest {
     /// workspace members. For example, the dependency groups in a `pyproject.toml` without a
     /// `[project]` table would be included here.
     #[serde(default)]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "BTreeMap<GroupName, BTreeSet<serde_json::Value>>")
+    )]
     dependency_groups: BTreeMap<GroupName, BTreeSet<Requirement>>,
     /// The constraints provided to the resolver.
     #[serde(default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "BTreeSet<serde_json::Value>"))]
     constraints: BTreeSet<Requirement>,
     /// The overrides provided to the resolver.
     #[serde(default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "BTreeSet<serde_json::Value>"))]
     overrides: BTreeSet<Override<Requirement>>,
     /// The excludes provided to the resolver.
     #[serde(default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "BTreeSet<serde_json::Value>"))]
     excludes: BTreeSet<ExcludeDependency>,
     /// The build constraints provided to the resolver.
     #[serde(default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "BTreeSet<serde_json::Value>"))]
     build_constraints: BTreeSet<Requirement>,
     /// The static metadata provided to the resolver.
     #[serde(default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "BTreeSet<serde_json::Value>"))]
     dependency_metadata: BTreeSet<StaticMetadata>,
 }
 
@@ -2618,6 +2841,7 @@ impl ResolverManifest {
     ) -> Self {
         Self {
             members: members.into_iter().collect(),
+            scripts: BTreeSet::new(),
             requirements: requirements.into_iter().collect(),
             constraints: constraints.into_iter().collect(),
             overrides: overrides.into_iter().collect(),
@@ -2631,10 +2855,18 @@ impl ResolverManifest {
         }
     }
 
+    /// Record the PEP 723 scripts that share the lockfile.
+    #[must_use]
+    pub fn with_scripts(mut self, scripts: impl IntoIterator<Item = String>) -> Self {
+        self.scripts = scripts.into_iter().collect();
+        self
+    }
+
     /// Convert the manifest to a relative form using the given workspace.
     pub fn relative_to(self, root: &Path) -> Result<Self, io::Error> {
         Ok(Self {
             members: self.members,
+            scripts: self.scripts,
             requirements: self
                 .requirements
                 .into_iter()
@@ -2688,21 +2920,32 @@ impl ResolverManifest {
     }
 }
 
+/// The `uv.lock` file format.
 #[derive(Clone, Debug, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schemars", schemars(title = "uv.lock"))]
 #[serde(rename_all = "kebab-case")]
 struct LockWire {
     version: u32,
     revision: Option<u32>,
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     requires_python: RequiresPython,
     /// If this lockfile was built from a forking resolution with non-identical forks, store the
     /// forks in the lockfile so we can recreate them in subsequent resolutions.
     #[serde(rename = "resolution-markers", default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "Vec<String>"))]
     fork_markers: Vec<SimplifiedMarkerTree>,
     #[serde(rename = "supported-markers", default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "Vec<String>"))]
     supported_environments: Vec<SimplifiedMarkerTree>,
     #[serde(rename = "required-markers", default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "Vec<String>"))]
     required_environments: Vec<SimplifiedMarkerTree>,
     #[serde(rename = "conflicts", default)]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<uv_pypi_types::SchemaConflicts>")
+    )]
     conflicts: Option<Conflicts>,
     /// We discard the lockfile if these options match.
     #[serde(default)]
@@ -2711,28 +2954,15 @@ struct LockWire {
     manifest: ResolverManifest,
     #[serde(rename = "package", alias = "distribution", default)]
     packages: Vec<PackageWire>,
+    #[serde(default)]
+    provenance: Vec<PackageProvenance>,
 }
 
 impl TryFrom<LockWire> for Lock {
     type Error = LockError;
 
     fn try_from(wire: LockWire) -> Result<Self, LockError> {
-        // Count the number of sources for each package name. When
-        // there's only one source for a particular package name (the
-        // overwhelmingly common case), we can omit some data (like source and
-        // version) on dependency edges since it is strictly redundant.
-        let mut unambiguous_package_ids: FxHashMap<PackageName, PackageId> = FxHashMap::default();
-        let mut ambiguous = FxHashSet::default();
-        for dist in &wire.packages {
-            if ambiguous.contains(&dist.id.name) {
-                continue;
-            }
-            if let Some(id) = unambiguous_package_ids.remove(&dist.id.name) {
-                ambiguous.insert(id.name);
-                continue;
-            }
-            unambiguous_package_ids.insert(dist.id.name.clone(), dist.id.clone());
-        }
+        let unambiguous_package_ids = unambiguous_package_ids(&wire.packages);
 
         let fork_markers = wire
             .fork_markers
@@ -2777,10 +3007,17 @@ impl TryFrom<LockWire> for Lock {
         let options = ResolverOptions {
             resolution_mode: options_wire.resolution_mode,
             prerelease_mode: options_wire.prerelease_mode,
+            prerelease_packages: options_wire.prerelease_packages,
             fork_strategy: options_wire.fork_strategy,
+            require_metadata: options_wire.require_metadata,
             exclude_newer: options_wire.exclude_newer.into(),
+            remote_files: options_wire
+                .remote_files
+                .into_iter()
+                .map(|file| (file.url, file.hash))
+                .collect(),
         };
-        let lock = Self::new(
+        let mut lock = Self::new(
             wire.version,
             wire.revision.unwrap_or(0),
             packages,
@@ -2792,11 +3029,34 @@ impl TryFrom<LockWire> for Lock {
             required_environments,
             fork_markers,
         )?;
+        lock.provenance = wire.provenance;
 
         Ok(lock)
     }
 }
 
+/// Count the number of sources for each package name, returning the package IDs for names with
+/// a single source.
+///
+/// When there's only one source for a particular package name (the overwhelmingly common case),
+/// we can omit some data (like source and version) on dependency edges since it is strictly
+/// redundant.
+fn unambiguous_package_ids(packages: &[PackageWire]) -> FxHashMap<PackageName, PackageId> {
+    let mut unambiguous_package_ids: FxHashMap<PackageName, PackageId> = FxHashMap::default();
+    let mut ambiguous = FxHashSet::default();
+    for dist in packages {
+        if ambiguous.contains(&dist.id.name) {
+            continue;
+        }
+        if let Some(id) = unambiguous_package_ids.remove(&dist.id.name) {
+            ambiguous.insert(id.name);
+            continue;
+        }
+        unambiguous_package_ids.insert(dist.id.name.clone(), dist.id.clone());
+    }
+    unambiguous_package_ids
+}
+
 /// Like [`Lock`], but limited to the version field. Used for error reporting: by limiting parsing
 /// to the version field, we can verify compatibility for lockfiles that may otherwise be
 /// unparsable.
@@ -2832,6 +3092,11 @@ pub struct Package {
     dependency_groups: BTreeMap<GroupName, Vec<Dependency>>,
     /// The exact requirements from the package metadata.
     metadata: PackageMetadata,
+    /// A digest of the files served by the index for this package version, recorded when index
+    /// metadata pinning is enabled.
+    ///
+    /// Named `index-digest` in `uv.lock`.
+    index_digest: Option<Hash>,
 }
 
 impl Package {
@@ -2905,6 +3170,7 @@ impl Package {
                 provides_extra,
                 dependency_groups,
             },
+            index_digest: None,
         })
     }
 
@@ -3176,6 +3442,67 @@ impl Package {
         }
     }
 
+    /// Explain which artifact [`Package::to_dist`] would select for installation on the given
+    /// tags, returning every candidate artifact along with whether it was selected, superseded,
+    /// or rejected (and why).
+    pub fn explain_artifacts(
+        &self,
+        workspace_root: &Path,
+        tags: &Tags,
+        build_options: &BuildOptions,
+    ) -> Result<Vec<ArtifactCandidate>, LockError> {
+        let no_binary = build_options.no_binary_package(&self.id.name);
+        let no_build = build_options.no_build_package(&self.id.name);
+
+        let best_wheel_index = if no_binary {
+            None
+        } else {
+            self.find_best_wheel(TagPolicy::Required(tags))
+        };
+
+        let mut candidates = self
+            .wheels
+            .iter()
+            .enumerate()
+            .map(|(i, wheel)| {
+                let verdict = if no_binary {
+                    ArtifactVerdict::Rejected(ArtifactRejection::Wheel(IncompatibleWheel::NoBinary))
+                } else if best_wheel_index == Some(i) {
+                    ArtifactVerdict::Selected
+                } else if let TagCompatibility::Incompatible(tag) =
+                    wheel.filename.compatibility(tags)
+                {
+                    ArtifactVerdict::Rejected(ArtifactRejection::Wheel(IncompatibleWheel::Tag(tag)))
+                } else {
+                    ArtifactVerdict::Superseded
+                };
+                ArtifactCandidate {
+                    artifact: wheel.filename.to_string(),
+                    verdict,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(sdist) = self.to_source_dist(workspace_root)? {
+            let artifact = self
+                .sdist
+                .as_ref()
+                .and_then(SourceDist::filename)
+                .map(Cow::into_owned)
+                .unwrap_or_else(|| self.id.source.to_string());
+            let verdict = if best_wheel_index.is_some() {
+                ArtifactVerdict::Superseded
+            } else if no_build && !sdist.is_virtual() {
+                ArtifactVerdict::Rejected(ArtifactRejection::Source(IncompatibleSource::NoBuild))
+            } else {
+                ArtifactVerdict::Selected
+            };
+            candidates.push(ArtifactCandidate { artifact, verdict });
+        }
+
+        Ok(candidates)
+    }
+
     /// Generate a [`WheelTagHint`] based on wheel-tag incompatibilities.
     fn tag_hint(
         &self,
@@ -3565,6 +3892,31 @@ impl Package {
         }
     }
 
+    /// Returns the pinned digest of the index metadata for this [`Package`], if any.
+    pub fn index_digest(&self) -> Option<&HashDigest> {
+        self.index_digest.as_ref().map(|hash| &hash.0)
+    }
+
+    /// Returns the filenames and hashes of the source and built distributions locked for this
+    /// [`Package`].
+    pub fn artifacts(&self) -> Vec<(DistFilename, Option<&HashDigest>)> {
+        let mut artifacts =
+            Vec::with_capacity(usize::from(self.sdist.is_some()) + self.wheels.len());
+        if let Some(sdist) = &self.sdist
+            && let Some(filename) = sdist.filename()
+            && let Some(filename) = DistFilename::try_from_filename(&filename, &self.id.name)
+        {
+            artifacts.push((filename, sdist.hash().map(|hash| &hash.0)));
+        }
+        for wheel in &self.wheels {
+            artifacts.push((
+                DistFilename::WheelFilename(wheel.filename.clone()),
+                wheel.hash.as_ref().map(|hash| &hash.0),
+            ));
+        }
+        artifacts
+    }
+
     /// Returns all the hashes associated with this [`Package`].
     fn hashes(&self) -> HashDigests {
         let mut hashes = Vec::with_capacity(
@@ -3613,6 +3965,13 @@ impl Package {
         &self.metadata.provides_extra
     }
 
+    /// Returns the requirements declared by the package, if recorded in the lockfile.
+    ///
+    /// Requirements are only recorded for local source trees (e.g., workspace members).
+    pub fn requires_dist(&self) -> &BTreeSet<Requirement> {
+        &self.metadata.requires_dist
+    }
+
     /// Returns the dependency groups the package provides, if any.
     pub fn dependency_groups(&self) -> &BTreeMap<GroupName, BTreeSet<Requirement>> {
         &self.metadata.dependency_groups
@@ -3660,6 +4019,7 @@ fn absolute_path(workspace_root: &Path, path: &Path) -> Result<PathBuf, LockErro
 }
 
 #[derive(Clone, Debug, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "kebab-case")]
 struct PackageWire {
     #[serde(flatten)]
@@ -3667,10 +4027,13 @@ struct PackageWire {
     #[serde(default)]
     metadata: PackageMetadata,
     #[serde(default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<SourceDistWire>"))]
     sdist: Option<SourceDist>,
     #[serde(default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "Vec<WheelWire>"))]
     wheels: Vec<Wheel>,
     #[serde(default, rename = "resolution-markers")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Vec<String>"))]
     fork_markers: Vec<SimplifiedMarkerTree>,
     #[serde(default)]
     dependencies: Vec<DependencyWire>,
@@ -3678,16 +4041,24 @@ struct PackageWire {
     optional_dependencies: BTreeMap<ExtraName, Vec<DependencyWire>>,
     #[serde(default, rename = "dev-dependencies", alias = "dependency-groups")]
     dependency_groups: BTreeMap<GroupName, Vec<DependencyWire>>,
+    #[serde(default)]
+    index_digest: Option<Hash>,
 }
 
 #[derive(Clone, Default, Debug, Eq, PartialEq, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "kebab-case")]
 struct PackageMetadata {
     #[serde(default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "BTreeSet<serde_json::Value>"))]
     requires_dist: BTreeSet<Requirement>,
     #[serde(default, rename = "provides-extras")]
     provides_extra: Box<[ExtraName]>,
     #[serde(default, rename = "requires-dev", alias = "dependency-groups")]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "BTreeMap<GroupName, BTreeSet<serde_json::Value>>")
+    )]
     dependency_groups: BTreeMap<GroupName, BTreeSet<Requirement>>,
 }
 
@@ -3762,6 +4133,7 @@ impl PackageWire {
                 .into_iter()
                 .map(|(group, deps)| Ok((group, unwire_deps(deps)?)))
                 .collect::<Result<_, LockError>>()?,
+            index_digest: self.index_digest,
         })
     }
 }
@@ -3769,10 +4141,13 @@ impl PackageWire {
 /// Inside the lockfile, we match a dependency entry to a package entry through a key made up
 /// of the name, the version and the source url.
 #[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "kebab-case")]
 pub(crate) struct PackageId {
     pub(crate) name: PackageName,
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
     version: Option<Version>,
+    #[cfg_attr(feature = "schemars", schemars(with = "SourceWire"))]
     source: Source,
 }
 
@@ -3811,10 +4186,13 @@ impl Display for PackageId {
 }
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "kebab-case")]
 struct PackageIdForDependency {
     name: PackageName,
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
     version: Option<Version>,
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<SourceWire>"))]
     source: Option<Source>,
 }
 
@@ -4218,28 +4596,36 @@ impl Source {
 }
 
 #[derive(Clone, Debug, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(untagged, rename_all = "kebab-case")]
 enum SourceWire {
     Registry {
+        #[cfg_attr(feature = "schemars", schemars(with = "String"))]
         registry: RegistrySourceWire,
     },
     Git {
         git: String,
     },
     Direct {
+        #[cfg_attr(feature = "schemars", schemars(with = "String"))]
         url: UrlString,
+        #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
         subdirectory: Option<PortablePathBuf>,
     },
     Path {
+        #[cfg_attr(feature = "schemars", schemars(with = "String"))]
         path: PortablePathBuf,
     },
     Directory {
+        #[cfg_attr(feature = "schemars", schemars(with = "String"))]
         directory: PortablePathBuf,
     },
     Editable {
+        #[cfg_attr(feature = "schemars", schemars(with = "String"))]
         editable: PortablePathBuf,
     },
     Virtual {
+        #[cfg_attr(feature = "schemars", schemars(with = "String"))]
         r#virtual: PortablePathBuf,
     },
 }
@@ -4432,6 +4818,7 @@ enum GitSourceKind {
 
 /// Inspired by: <https://discuss.python.org/t/lock-files-again-but-this-time-w-sdists/46593>
 #[derive(Clone, Debug, serde::Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "kebab-case")]
 struct SourceDistMetadata {
     /// A hash of the source distribution.
@@ -4442,6 +4829,7 @@ struct SourceDistMetadata {
     size: Option<u64>,
     /// The upload time of the source distribution.
     #[serde(alias = "upload_time")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
     upload_time: Option<Timestamp>,
 }
 
@@ -4727,14 +5115,17 @@ impl SourceDist {
 }
 
 #[derive(Clone, Debug, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(untagged, rename_all = "kebab-case")]
 enum SourceDistWire {
     Url {
+        #[cfg_attr(feature = "schemars", schemars(with = "String"))]
         url: UrlString,
         #[serde(flatten)]
         metadata: SourceDistMetadata,
     },
     Path {
+        #[cfg_attr(feature = "schemars", schemars(with = "String"))]
         path: PortablePathBuf,
         #[serde(flatten)]
         metadata: SourceDistMetadata,
@@ -4844,6 +5235,7 @@ fn locked_git_url(
 }
 
 #[derive(Clone, Debug, serde::Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 struct ZstdWheel {
     hash: Option<Hash>,
     size: Option<u64>,
@@ -4884,6 +5276,23 @@ struct Wheel {
 }
 
 impl Wheel {
+    /// Returns the key used to order wheels in the lockfile.
+    ///
+    /// Wheels are ordered by the filename in their location, independent of the order in which
+    /// the index listed them.
+    fn sort_key(&self) -> Cow<'_, str> {
+        match &self.url {
+            WheelWireSource::Url { url } => url
+                .filename()
+                .unwrap_or_else(|_| Cow::Borrowed(url.as_ref())),
+            WheelWireSource::Path { path } => path
+                .file_name()
+                .map(|file_name| file_name.to_string_lossy())
+                .unwrap_or_default(),
+            WheelWireSource::Filename { filename } => Cow::Owned(filename.to_string()),
+        }
+    }
+
     fn from_annotated_dist(
         annotated_dist: &AnnotatedDist,
         index_locations: &IndexLocations,
@@ -5177,6 +5586,7 @@ impl Wheel {
 }
 
 #[derive(Clone, Debug, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "kebab-case")]
 struct WheelWire {
     #[serde(flatten)]
@@ -5195,6 +5605,7 @@ struct WheelWire {
     ///
     /// This is only present for wheels that come from registries.
     #[serde(alias = "upload_time")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
     upload_time: Option<Timestamp>,
     /// The zstandard-compressed wheel metadata, if any.
     #[serde(alias = "zstd")]
@@ -5202,6 +5613,7 @@ struct WheelWire {
 }
 
 #[derive(Clone, Debug, serde::Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(untagged, rename_all = "kebab-case")]
 enum WheelWireSource {
     /// Used for all wheels that come from remote sources.
@@ -5210,11 +5622,13 @@ enum WheelWireSource {
         /// does not need to exist in the future, so this should be treated as
         /// only a hint to where to look and/or recording where the wheel file
         /// originally came from.
+        #[cfg_attr(feature = "schemars", schemars(with = "String"))]
         url: UrlString,
     },
     /// Used for wheels that come from local registries (like `--find-links`).
     Path {
         /// The path to the wheel, relative to the index.
+        #[cfg_attr(feature = "schemars", schemars(with = "String"))]
         path: Box<Path>,
     },
     /// Used for path wheels.
@@ -5223,6 +5637,7 @@ enum WheelWireSource {
     Filename {
         /// We duplicate the filename since a lot of code relies on having the filename on the
         /// wheel entry.
+        #[cfg_attr(feature = "schemars", schemars(with = "String"))]
         filename: WheelFilename,
     },
 }
@@ -5364,6 +5779,7 @@ impl Display for Dependency {
 
 /// A single dependency of a package in a lockfile.
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "kebab-case")]
 struct DependencyWire {
     #[serde(flatten)]
@@ -5371,6 +5787,7 @@ struct DependencyWire {
     #[serde(default)]
     extra: BTreeSet<ExtraName>,
     #[serde(default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     marker: SimplifiedMarkerTree,
 }
 
@@ -5501,6 +5918,20 @@ impl<'de> serde::Deserialize<'de> for Hash {
     }
 }
 
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Hash {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("Hash")
+    }
+
+    fn json_schema(_generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "description": "A hash of a distribution artifact, in the format `{algorithm}:{digest}`."
+        })
+    }
+}
+
 impl From<Hash> for Hashes {
     fn from(value: Hash) -> Self {
         match value.0.algorithm {
@@ -5817,6 +6248,16 @@ impl LockError {
             LockErrorKind::NoBuild { .. } | LockErrorKind::NoBinaryNoBuild { .. }
         )
     }
+
+    /// Returns the name of the package that lacks a distribution compatible with the current
+    /// platform, if the [`LockError`] was caused by such a package.
+    pub fn incompatible_package(&self) -> Option<&PackageName> {
+        match &*self.kind {
+            LockErrorKind::IncompatibleWheelOnly { id }
+            | LockErrorKind::NeitherSourceDistNorWheel { id } => Some(&id.name),
+            _ => None,
+        }
+    }
 }
 
 impl<E> From<E> for LockError
@@ -6461,6 +6902,18 @@ enum LockErrorKind {
         /// The ID of the package.
         name: PackageName,
     },
+    /// An error that occurs when a pruned package is a direct dependency of a root package.
+    #[error(
+        "Refusing to prune `{name}`, which is a direct dependency of `{dependent}`; use `--allow-prune-root` to prune it anyway",
+        name = name.cyan(),
+        dependent = dependent.cyan()
+    )]
+    PrunedDirectDependency {
+        /// The name of the pruned package.
+        name: PackageName,
+        /// The name of the root package (or workspace) that depends on it.
+        dependent: String,
+    },
     /// An error that occurs when a root package can't be found.
     #[error("Could not find root package `{name}`", name = name.cyan())]
     MissingRootPackage {
@@ -6626,6 +7079,14 @@ fn simplified_universal_markers(
         .collect()
 }
 
+/// Sort canonicalized fork markers by their rendered form, such that the `resolution-markers`
+/// in `uv.lock` don't depend on the order in which the resolver discovered the forks.
+fn sort_fork_markers(markers: &mut [UniversalMarker], requires_python: &RequiresPython) {
+    markers.sort_by_cached_key(|marker| {
+        SimplifiedMarkerTree::new(requires_python, marker.combined()).try_to_string()
+    });
+}
+
 /// Canonicalize universal markers to match the form persisted in `uv.lock`.
 ///
 /// When the PEP 508 portions of the markers are disjoint, the lockfile stores
@@ -6955,7 +7416,7 @@ mod tests {
         let lock: Lock = toml::from_str(
             r#"
 version = 1
-revision = 3
+revision = 4
 requires-python = ">=3.12"
 
 [[package]]
@@ -7003,7 +7464,7 @@ source = { registry = "https://example.com/simple" }
         let lock: Lock = toml::from_str(
             r#"
 version = 1
-revision = 3
+revision = 4
 requires-python = ">=3.12"
 
 [manifest]
@@ -7033,7 +7494,7 @@ source = { registry = "https://example.com/simple" }
         let lock: Lock = toml::from_str(
             r#"
 version = 1
-revision = 3
+revision = 4
 requires-python = ">=3.12"
 
 [[package]]
@@ -7421,4 +7882,204 @@ wheels = [
             ))
         );
     }
+
+    /// A lockfile written by the current revision, used to test serialization stability.
+    const CANONICAL_LOCK: &str = r#"version = 1
+revision = 4
+requires-python = ">=3.12"
+resolution-markers = [
+    "sys_platform != 'win32'",
+    "sys_platform == 'win32'",
+]
+
+[[package]]
+name = "anyio"
+version = "4.3.0"
+source = { registry = "https://pypi.org/simple" }
+dependencies = [
+    { name = "idna" },
+    { name = "sniffio" },
+]
+sdist = { url = "https://files.pythonhosted.org/packages/db/4d/3970183622f0330d3c23d9b8a5f52e365e50381fd484d08e3285104333d3/anyio-4.3.0.tar.gz", hash = "sha256:f75253795a87df48568485fd18cdd2a3fa5c4f7c5be8e5e36637733fce06fed6", size = 159642, upload-time = "2024-02-19T08:36:28.641Z" }
+wheels = [
+    { url = "https://files.pythonhosted.org/packages/14/fd/2f20c40b45e4fb4324834aea24bd4afdf1143390242c0b33774da0e2e34f/anyio-4.3.0-py3-none-any.whl", hash = "sha256:048e05d0f6caeed70d731f3db756d35dcc1f35747c8c403364a8332c630441b8", size = 85584, upload-time = "2024-02-19T08:36:26.842Z" },
+]
+
+[[package]]
+name = "colorama"
+version = "0.4.6"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "https://files.pythonhosted.org/packages/d8/53/6f443c9a4a8358a93a6792e2acffb9d9d5cb0a5cfd8802644b7b1c9a02e4/colorama-0.4.6.tar.gz", hash = "sha256:08695f5cb7ed6e0531a20572697297273c47b8cae5a63ffc6d6ed5c201be6e44", size = 27697, upload-time = "2022-10-25T02:36:22.414Z" }
+wheels = [
+    { url = "https://files.pythonhosted.org/packages/d1/d6/3965ed04c63042e047cb6a3e6ed1a63a35087b6a609aa3a15ed8ac56c221/colorama-0.4.6-py2.py3-none-any.whl", hash = "sha256:4f1d9991f5acc0ca119f9d443620b77f9d6b33703e51011c16baf57afb285fc6", size = 25335, upload-time = "2022-10-25T02:36:20.889Z" },
+]
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "https://files.pythonhosted.org/packages/bf/3f/ea4b9117521a1e9c50344b909be7886dd00a519552724809bb1f486986c2/idna-3.6.tar.gz", hash = "sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca", size = 175426, upload-time = "2023-11-25T15:40:54.902Z" }
+wheels = [
+    { url = "https://files.pythonhosted.org/packages/c2/e7/a82b05cf63a603df6e68d59ae6a68bf5064484a0718ea5033660af4b54a9/idna-3.6-py3-none-any.whl", hash = "sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f", size = 61567, upload-time = "2023-11-25T15:40:52.604Z" },
+]
+
+[[package]]
+name = "markupsafe"
+version = "2.1.5"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "https://files.pythonhosted.org/packages/87/5b/aae44c6655f3801e81aa3eef09dbbf012431987ba564d7231722f68df02d/MarkupSafe-2.1.5.tar.gz", hash = "sha256:d283d37a890ba4c1ae73ffadf8046435c76e7bc2247bbb63c00bd1a709c6544b", size = 19384, upload-time = "2024-02-02T16:31:22.863Z" }
+wheels = [
+    { url = "https://files.pythonhosted.org/packages/53/bd/583bf3e4c8d6a321938c13f49d44024dbe5ed63e0a7ba127e454a66da974/MarkupSafe-2.1.5-cp312-cp312-macosx_10_9_universal2.whl", hash = "sha256:8dec4936e9c3100156f8a2dc89c4b88d5c435175ff03413b443469c7c8c5f4d1", size = 18215, upload-time = "2024-02-02T16:30:33.081Z" },
+    { url = "https://files.pythonhosted.org/packages/d3/0a/c3d7a9ba9b5b6fec7fe4d44b7ad0e3e1ed2a4a21e1d5d3ec6d4b2a0e2fdd/MarkupSafe-2.1.5-cp312-cp312-manylinux_2_17_x86_64.manylinux2014_x86_64.whl", hash = "sha256:ea3d8a3d18833cf4304cd2fc9cbb1efe188ca9b5efef2bdac7adc20594a0e46b", size = 28187, upload-time = "2024-02-02T16:30:35.071Z" },
+    { url = "https://files.pythonhosted.org/packages/3f/14/c3554d512d5f9100a95e737502f4a2323a1959f6d0d01e0d0997b35f7b10/MarkupSafe-2.1.5-cp312-cp312-win_amd64.whl", hash = "sha256:823b65d8706e32ad2df51ed89496147a42a2a6e01c13cfb6ffb8b1e92bc910bb", size = 17127, upload-time = "2024-02-02T16:30:38.316Z" },
+]
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "anyio" },
+    { name = "colorama", marker = "sys_platform == 'win32'" },
+    { name = "markupsafe" },
+]
+
+[package.dev-dependencies]
+dev = [
+    { name = "sniffio" },
+]
+
+[package.metadata]
+requires-dist = [
+    { name = "anyio", specifier = ">=4" },
+    { name = "colorama", marker = "sys_platform == 'win32'" },
+    { name = "markupsafe" },
+]
+
+[package.metadata.requires-dev]
+dev = [{ name = "sniffio" }]
+
+[[package]]
+name = "sniffio"
+version = "1.3.1"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "https://files.pythonhosted.org/packages/a2/87/a6771e1546d97e7e041b6ae58d80074f81b7d5121207425c964ddf5cfdbd/sniffio-1.3.1.tar.gz", hash = "sha256:f4324edc670a0f49750a81b895f35c3adb843cca46f0530f79fc1babb23789dc", size = 20372, upload-time = "2024-02-25T23:20:04.057Z" }
+wheels = [
+    { url = "https://files.pythonhosted.org/packages/e9/44/75a9c9421471a6c4805dbf2356f7c181a29c1879239abab1ea2cc8f38b40/sniffio-1.3.1-py3-none-any.whl", hash = "sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2", size = 10235, upload-time = "2024-02-25T23:20:01.196Z" },
+]
+"#;
+
+    /// Reverse the order of the resolution markers and packages, and of the dependencies and
+    /// wheels of each package, in the given lockfile.
+    fn reverse_lock_arrays(lock: &str) -> String {
+        let mut document = lock.parse::<toml_edit::DocumentMut>().expect("valid TOML");
+        if let Some(array) = document
+            .get_mut("resolution-markers")
+            .and_then(toml_edit::Item::as_array_mut)
+        {
+            let mut values = array.iter().cloned().collect::<Vec<_>>();
+            values.reverse();
+            *array = values.into_iter().collect();
+        }
+        let packages = document["package"]
+            .as_array_of_tables_mut()
+            .expect("array of packages");
+
+        let mut tables = packages.iter().cloned().collect::<Vec<_>>();
+        tables.reverse();
+        for table in &mut tables {
+            for key in ["dependencies", "wheels"] {
+                if let Some(array) = table.get_mut(key).and_then(toml_edit::Item::as_array_mut) {
+                    let mut values = array.iter().cloned().collect::<Vec<_>>();
+                    values.reverse();
+                    *array = values.into_iter().collect();
+                }
+            }
+        }
+        *packages = tables.into_iter().collect();
+
+        document.to_string()
+    }
+
+    /// A large lockfile written by a previous revision, used to test serialization stability.
+    const WORKSPACE_LOCK: &str = include_str!("../../../../uv.lock");
+
+    /// A large lockfile written by a previous revision round-trips byte-for-byte.
+    #[test]
+    fn round_trip_workspace_lock() {
+        let lock: Lock = toml::from_str(WORKSPACE_LOCK).expect("valid lock");
+        assert_eq!(lock.revision(), 3);
+        assert_eq!(lock.to_toml().expect("serializable lock"), WORKSPACE_LOCK);
+    }
+
+    /// A lockfile written by the current revision round-trips byte-for-byte.
+    #[test]
+    fn round_trip_canonical_lock() {
+        let lock: Lock = toml::from_str(CANONICAL_LOCK).expect("valid lock");
+        assert_eq!(lock.revision(), REVISION);
+        assert_eq!(lock.to_toml().expect("serializable lock"), CANONICAL_LOCK);
+    }
+
+    /// The serialized order of resolution markers, packages, dependencies, and wheels doesn't
+    /// depend on their order in the input.
+    #[test]
+    fn canonical_order_independent_of_input_order() {
+        let reversed = reverse_lock_arrays(CANONICAL_LOCK);
+        assert_ne!(reversed, CANONICAL_LOCK);
+
+        let lock: Lock = toml::from_str(&reversed).expect("valid lock");
+        assert_eq!(lock.to_toml().expect("serializable lock"), CANONICAL_LOCK);
+    }
+
+    /// Lockfiles written by a revision that predates the canonical ordering of wheels and
+    /// resolution markers retain the order of their wheels and resolution markers, while packages
+    /// and dependencies are still ordered canonically.
+    #[test]
+    fn wheel_order_preserved_before_revision_4() {
+        let lock = reverse_lock_arrays(CANONICAL_LOCK).replace("revision = 4", "revision = 3");
+
+        let lock: Lock = toml::from_str(&lock).expect("valid lock");
+        assert_eq!(lock.revision(), 3);
+
+        let markupsafe = lock
+            .packages()
+            .iter()
+            .find(|package| package.name().as_ref() == "markupsafe")
+            .expect("locked package");
+        let wheels = markupsafe
+            .wheels
+            .iter()
+            .map(|wheel| wheel.filename.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            wheels,
+            [
+                "markupsafe-2.1.5-cp312-cp312-win_amd64.whl",
+                "markupsafe-2.1.5-cp312-cp312-manylinux_2_17_x86_64.manylinux2014_x86_64.whl",
+                "markupsafe-2.1.5-cp312-cp312-macosx_10_9_universal2.whl",
+            ]
+        );
+
+        // Re-serializing retains the revision, along with the order of the wheels and resolution
+        // markers.
+        let mut expected = CANONICAL_LOCK
+            .replace("revision = 4", "revision = 3")
+            .lines()
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+        let start = expected
+            .iter()
+            .position(|line| line.contains("sys_platform != 'win32'"))
+            .expect("resolution marker");
+        expected[start..start + 2].reverse();
+        let start = expected
+            .iter()
+            .position(|line| line.contains("MarkupSafe-2.1.5-cp312"))
+            .expect("locked wheel");
+        expected[start..start + 3].reverse();
+        assert_eq!(
+            lock.to_toml().expect("serializable lock"),
+            expected.join("\n") + "\n"
+        );
+    }
 }
@@ -0,0 +1,167 @@
+use std::path::Path;
+
+use jiff::Timestamp;
+
+use uv_distribution_types::{IndexLocations, IndexName, IndexUrl, UrlString};
+use uv_fs::{PortablePath, try_relative_to_if};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+
+use crate::lock::{Package, RegistrySource, Source};
+
+/// The provenance of a package that was locked from a registry.
+///
+/// Provenance is recorded in a dedicated table (when `tool.uv.lock-provenance` is enabled) and
+/// is never consulted when determining whether the lockfile satisfies the project requirements.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct PackageProvenance {
+    /// The name of the package.
+    pub(crate) name: PackageName,
+    /// The locked version of the package.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+    pub(crate) version: Version,
+    /// The URL (or path) of the index that served the package metadata.
+    pub(crate) index: String,
+    /// The name of the index that served the package metadata, if it was defined with one.
+    pub(crate) index_name: Option<IndexName>,
+    /// The time at which the package was first locked with the recorded artifacts.
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+    pub(crate) locked_at: Timestamp,
+    /// The artifacts that were locked for the package.
+    #[serde(default)]
+    pub(crate) artifacts: Vec<ArtifactProvenance>,
+}
+
+/// The provenance of a single locked artifact.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct ArtifactProvenance {
+    /// The filename of the artifact.
+    pub(crate) filename: String,
+    /// The upload time reported by the index, if any.
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub(crate) upload_time: Option<Timestamp>,
+}
+
+impl PackageProvenance {
+    /// Compute the provenance for a locked [`Package`], if it was locked from a registry.
+    ///
+    /// If any `previous` provenance refers to the same package version, index, and artifacts, its
+    /// lock timestamp is retained; otherwise, the package is marked as locked at `now`.
+    pub(crate) fn from_package(
+        package: &Package,
+        indexes: &IndexLocations,
+        root: &Path,
+        previous: &[Self],
+        now: Timestamp,
+    ) -> Option<Self> {
+        let Source::Registry(source) = &package.id.source else {
+            return None;
+        };
+        let version = package.id.version.clone()?;
+
+        let index = match source {
+            RegistrySource::Url(url) => url.to_string(),
+            RegistrySource::Path(path) => PortablePath::from(path).to_string(),
+        };
+        let index_name = indexes
+            .allowed_indexes()
+            .into_iter()
+            .filter(|index| matches_registry_source(index.url(), source, root))
+            .find_map(|index| index.name.clone());
+
+        let artifacts = package
+            .wheels
+            .iter()
+            .map(|wheel| ArtifactProvenance {
+                filename: wheel.filename.to_string(),
+                upload_time: wheel.upload_time,
+            })
+            .chain(package.sdist.iter().filter_map(|sdist| {
+                Some(ArtifactProvenance {
+                    filename: sdist.filename()?.into_owned(),
+                    upload_time: sdist.upload_time(),
+                })
+            }))
+            .collect::<Vec<_>>();
+
+        let locked_at = previous
+            .iter()
+            .find(|previous| {
+                previous.name == package.id.name
+                    && previous.version == version
+                    && previous.index == index
+                    && previous.artifacts == artifacts
+            })
+            .map_or(now, |previous| previous.locked_at);
+
+        Some(Self {
+            name: package.id.name.clone(),
+            version,
+            index,
+            index_name,
+            locked_at,
+            artifacts,
+        })
+    }
+
+    /// Returns the name of the package.
+    pub fn name(&self) -> &PackageName {
+        &self.name
+    }
+
+    /// Returns the locked version of the package.
+    pub fn version(&self) -> &Version {
+        &self.version
+    }
+
+    /// Returns the URL (or path) of the index that served the package metadata.
+    pub fn index(&self) -> &str {
+        &self.index
+    }
+
+    /// Returns the name of the index that served the package metadata, if any.
+    pub fn index_name(&self) -> Option<&IndexName> {
+        self.index_name.as_ref()
+    }
+
+    /// Returns the time at which the package was first locked with the recorded artifacts.
+    pub fn locked_at(&self) -> Timestamp {
+        self.locked_at
+    }
+
+    /// Returns the provenance of each locked artifact.
+    pub fn artifacts(&self) -> &[ArtifactProvenance] {
+        &self.artifacts
+    }
+}
+
+impl ArtifactProvenance {
+    /// Returns the filename of the artifact.
+    pub fn filename(&self) -> &str {
+        &self.filename
+    }
+
+    /// Returns the upload time reported by the index, if any.
+    pub fn upload_time(&self) -> Option<Timestamp> {
+        self.upload_time
+    }
+}
+
+/// Returns `true` if the [`IndexUrl`] refers to the given [`RegistrySource`].
+fn matches_registry_source(url: &IndexUrl, source: &RegistrySource, root: &Path) -> bool {
+    match (url, source) {
+        (IndexUrl::Pypi(_) | IndexUrl::Url(_), RegistrySource::Url(source)) => {
+            UrlString::from(url.without_credentials().as_ref()) == *source
+        }
+        (IndexUrl::Path(url), RegistrySource::Path(source)) => url
+            .to_file_path()
+            .ok()
+            .and_then(|path| try_relative_to_if(&path, root, !url.was_given_absolute()).ok())
+            .is_some_and(|path| *path == **source),
+        _ => false,
+    }
+}
@@ -0,0 +1,303 @@
+use std::fmt::{Display, Formatter};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde_json::Value;
+
+use crate::lock::{Lock, LockWire, PackageId, REVISION, VERSION, unambiguous_package_ids};
+
+/// Generate a JSON Schema describing the `uv.lock` format.
+///
+/// The schema is derived from the types used to deserialize the lockfile.
+pub fn lock_schema() -> Value {
+    schemars::generate::SchemaSettings::draft07()
+        .into_generator()
+        .into_root_schema_for::<LockWire>()
+        .to_value()
+}
+
+/// The result of validating a lockfile via [`validate_lock`].
+#[derive(Debug)]
+pub struct LockValidation {
+    /// The lockfile version, if it could be determined.
+    pub version: Option<u32>,
+    /// The lockfile revision, if present.
+    pub revision: Option<u32>,
+    /// The problems found in the lockfile.
+    pub problems: Vec<LockProblem>,
+}
+
+impl LockValidation {
+    /// Returns `true` if the lockfile was written by a newer version of uv, with a revision that
+    /// this version of uv does not know about.
+    pub fn is_newer_revision(&self) -> bool {
+        self.revision.is_some_and(|revision| revision > REVISION)
+    }
+}
+
+/// A problem found when validating a lockfile.
+#[derive(Debug)]
+pub enum LockProblem {
+    /// The lockfile could not be parsed.
+    Parse(String),
+    /// The lockfile does not declare a version.
+    MissingVersion,
+    /// The lockfile version is not supported by this version of uv.
+    UnsupportedVersion(u32),
+    /// The lockfile contains a field that is not part of the lockfile schema.
+    UnknownField(String),
+    /// A dependency refers to a package that is not present in the lockfile.
+    DanglingDependency { package: String, dependency: String },
+    /// An artifact is missing a hash, despite other artifacts in the lockfile including hashes.
+    MissingHash { package: String, artifact: String },
+    /// The lockfile is otherwise inconsistent.
+    Invalid(String),
+}
+
+impl Display for LockProblem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse(err) => write!(f, "Failed to parse lockfile: {}", err.trim_end()),
+            Self::MissingVersion => write!(f, "Missing `version` field"),
+            Self::UnsupportedVersion(version) => write!(
+                f,
+                "Unsupported lockfile version {version} (expected version {VERSION})"
+            ),
+            Self::UnknownField(path) => write!(f, "Unknown field `{path}`"),
+            Self::DanglingDependency {
+                package,
+                dependency,
+            } => write!(
+                f,
+                "`{package}` depends on `{dependency}`, which is not present in the lockfile"
+            ),
+            Self::MissingHash { package, artifact } => write!(
+                f,
+                "`{artifact}` for `{package}` is missing a hash, but other artifacts in the lockfile include hashes"
+            ),
+            Self::Invalid(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+/// Validate the contents of a lockfile against the lockfile schema, and check it for internal
+/// consistency.
+pub fn validate_lock(contents: &str) -> LockValidation {
+    let table = match toml::from_str::<toml::Table>(contents) {
+        Ok(table) => table,
+        Err(err) => {
+            return LockValidation {
+                version: None,
+                revision: None,
+                problems: vec![LockProblem::Parse(err.to_string())],
+            };
+        }
+    };
+
+    let field = |name: &str| {
+        table
+            .get(name)
+            .and_then(toml::Value::as_integer)
+            .and_then(|value| u32::try_from(value).ok())
+    };
+    let mut validation = LockValidation {
+        version: field("version"),
+        revision: field("revision"),
+        problems: Vec::new(),
+    };
+
+    // If the version is unsupported, the remainder of the lockfile can't be interpreted.
+    match validation.version {
+        Some(VERSION) => {}
+        Some(version) => {
+            validation
+                .problems
+                .push(LockProblem::UnsupportedVersion(version));
+            return validation;
+        }
+        None => {
+            validation.problems.push(LockProblem::MissingVersion);
+            return validation;
+        }
+    }
+
+    // Check for fields that are not part of the schema.
+    let schema = lock_schema();
+    find_unknown_fields(
+        &toml::Value::Table(table),
+        &[&schema],
+        &schema,
+        "",
+        &mut validation.problems,
+    );
+
+    let wire = match toml::from_str::<LockWire>(contents) {
+        Ok(wire) => wire,
+        Err(err) => {
+            validation
+                .problems
+                .push(LockProblem::Parse(err.to_string()));
+            return validation;
+        }
+    };
+
+    // Check that every dependency refers to a package in the lockfile.
+    let unambiguous_package_ids = unambiguous_package_ids(&wire.packages);
+    let ids = wire
+        .packages
+        .iter()
+        .map(|package| &package.id)
+        .collect::<FxHashSet<&PackageId>>();
+    for package in &wire.packages {
+        let dependencies = package
+            .dependencies
+            .iter()
+            .chain(package.optional_dependencies.values().flatten())
+            .chain(package.dependency_groups.values().flatten());
+        for dependency in dependencies {
+            let dependency = match dependency
+                .package_id
+                .clone()
+                .unwire(&unambiguous_package_ids)
+            {
+                Ok(id) if ids.contains(&id) => continue,
+                Ok(id) => id.to_string(),
+                Err(_) => dependency.package_id.name.to_string(),
+            };
+            validation.problems.push(LockProblem::DanglingDependency {
+                package: package.id.to_string(),
+                dependency,
+            });
+        }
+    }
+
+    // Check that, if any artifacts include hashes, all artifacts that may include a hash do so.
+    let hashed = wire.packages.iter().any(|package| {
+        package
+            .sdist
+            .as_ref()
+            .is_some_and(|sdist| sdist.hash().is_some())
+            || package.wheels.iter().any(|wheel| wheel.hash.is_some())
+    });
+    for package in &wire.packages {
+        if !package.id.source.requires_hash().unwrap_or(hashed) {
+            continue;
+        }
+        if let Some(sdist) = &package.sdist
+            && sdist.hash().is_none()
+        {
+            validation.problems.push(LockProblem::MissingHash {
+                package: package.id.to_string(),
+                artifact: sdist.filename().map_or_else(
+                    || "source distribution".to_string(),
+                    |filename| filename.to_string(),
+                ),
+            });
+        }
+        for wheel in &package.wheels {
+            if wheel.hash.is_none() {
+                validation.problems.push(LockProblem::MissingHash {
+                    package: package.id.to_string(),
+                    artifact: wheel.filename.to_string(),
+                });
+            }
+        }
+    }
+
+    // Finally, perform the remaining checks that are applied when reading the lockfile.
+    if validation.problems.is_empty()
+        && let Err(err) = Lock::try_from(wire)
+    {
+        validation
+            .problems
+            .push(LockProblem::Invalid(err.to_string()));
+    }
+
+    validation
+}
+
+/// Report any keys in the given TOML value that aren't described by the given schemas.
+///
+/// Objects without any known properties (e.g., maps, or values with an opaque schema) are
+/// accepted as-is.
+fn find_unknown_fields(
+    value: &toml::Value,
+    schemas: &[&Value],
+    root: &Value,
+    path: &str,
+    problems: &mut Vec<LockProblem>,
+) {
+    let mut candidates = Vec::new();
+    for schema in schemas {
+        expand_schema(schema, root, &mut candidates);
+    }
+
+    match value {
+        toml::Value::Table(table) => {
+            let mut properties: FxHashMap<&str, Vec<&Value>> = FxHashMap::default();
+            let mut additional = Vec::new();
+            let mut structured = false;
+            for schema in &candidates {
+                if let Some(known) = schema.get("properties").and_then(Value::as_object) {
+                    structured = true;
+                    for (key, schema) in known {
+                        properties.entry(key.as_str()).or_default().push(schema);
+                    }
+                }
+                if let Some(schema) = schema
+                    .get("additionalProperties")
+                    .filter(|schema| schema.is_object())
+                {
+                    additional.push(schema);
+                }
+            }
+
+            for (key, value) in table {
+                let path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+                if let Some(schemas) = properties.get(key.as_str()) {
+                    find_unknown_fields(value, schemas, root, &path, problems);
+                } else if !additional.is_empty() {
+                    find_unknown_fields(value, &additional, root, &path, problems);
+                } else if structured {
+                    problems.push(LockProblem::UnknownField(path));
+                }
+            }
+        }
+        toml::Value::Array(items) => {
+            let schemas = candidates
+                .iter()
+                .filter_map(|schema| schema.get("items"))
+                .collect::<Vec<_>>();
+            if schemas.is_empty() {
+                return;
+            }
+            for (index, item) in items.iter().enumerate() {
+                find_unknown_fields(item, &schemas, root, &format!("{path}[{index}]"), problems);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Collect the given schema along with any schemas it references or combines.
+fn expand_schema<'a>(schema: &'a Value, root: &'a Value, schemas: &mut Vec<&'a Value>) {
+    if let Some(schema) = schema
+        .get("$ref")
+        .and_then(Value::as_str)
+        .and_then(|reference| reference.strip_prefix('#'))
+        .and_then(|pointer| root.pointer(pointer))
+    {
+        expand_schema(schema, root, schemas);
+    }
+    for keyword in ["allOf", "anyOf", "oneOf"] {
+        if let Some(subschemas) = schema.get(keyword).and_then(Value::as_array) {
+            for schema in subschemas {
+                expand_schema(schema, root, schemas);
+            }
+        }
+    }
+    schemas.push(schema);
+}
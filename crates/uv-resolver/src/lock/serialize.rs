@@ -13,8 +13,8 @@ use uv_pypi_types::ConflictKind;
 
 use super::{
     Dependency, DirectSource, ExcludeNewerOverride, ExcludeNewerValue, ForkStrategy, Lock, Package,
-    PackageId, PrereleaseMode, RegistrySource, ResolutionMode, ResolverManifest, ResolverOptions,
-    Source, SourceDist, Wheel, WheelWireSource, simplified_universal_markers,
+    PackageId, PackageProvenance, PrereleaseMode, RegistrySource, ResolutionMode, ResolverManifest,
+    ResolverOptions, Source, SourceDist, Wheel, WheelWireSource, simplified_universal_markers,
 };
 
 /// Serializes a lockfile directly while preserving the canonical `uv.lock` layout.
@@ -131,14 +131,21 @@ fn write_lock(writer: &mut LockWriter, lock: &Lock) -> Result<(), WriteError> {
         )?;
     }
 
+    for provenance in &lock.provenance {
+        write_provenance(writer, provenance)?;
+    }
+
     Ok(())
 }
 
 fn write_options(writer: &mut LockWriter, options: &ResolverOptions) -> Result<(), WriteError> {
     let has_options = options.resolution_mode != ResolutionMode::default()
         || options.prerelease_mode != PrereleaseMode::default()
+        || !options.prerelease_packages.is_empty()
         || options.fork_strategy != ForkStrategy::default()
-        || !options.exclude_newer.is_empty();
+        || options.require_metadata.is_some()
+        || !options.exclude_newer.is_empty()
+        || !options.remote_files.is_empty();
     if !has_options {
         return Ok(());
     }
@@ -150,9 +157,13 @@ fn write_options(writer: &mut LockWriter, options: &ResolverOptions) -> Result<(
     if options.prerelease_mode != PrereleaseMode::default() {
         writer.key_value("prerelease-mode", options.prerelease_mode.to_string())?;
     }
+    write_serialized_non_empty_array(writer, "prerelease-packages", &options.prerelease_packages)?;
     if options.fork_strategy != ForkStrategy::default() {
         writer.key_value("fork-strategy", options.fork_strategy.to_string())?;
     }
+    if let Some(require_metadata) = options.require_metadata {
+        writer.key_value("require-metadata", require_metadata.to_string())?;
+    }
 
     let exclude_newer = &options.exclude_newer;
     if let Some(global) = &exclude_newer.global {
@@ -166,6 +177,21 @@ fn write_options(writer: &mut LockWriter, options: &ResolverOptions) -> Result<(
         }
     }
 
+    if !options.remote_files.is_empty() {
+        writer.key_multiline_array(
+            "remote-files",
+            &options.remote_files,
+            |writer, (url, hash)| {
+                let mut first = true;
+                writer.start_inline_table();
+                writer.inline_value(&mut first, "url", url.as_str())?;
+                writer.inline_value(&mut first, "hash", hash.to_string())?;
+                writer.finish_inline_table(first);
+                Ok(())
+            },
+        )?;
+    }
+
     if !exclude_newer.package.is_empty() {
         writer.table(&["options", "exclude-newer-package"])?;
         for (name, setting) in &exclude_newer.package {
@@ -203,6 +229,7 @@ fn write_manifest(writer: &mut LockWriter, manifest: &ResolverManifest) -> Resul
         .values()
         .any(|requirements| !requirements.is_empty());
     let has_manifest = !manifest.members.is_empty()
+        || !manifest.scripts.is_empty()
         || !manifest.requirements.is_empty()
         || !manifest.constraints.is_empty()
         || !manifest.overrides.is_empty()
@@ -220,6 +247,11 @@ fn write_manifest(writer: &mut LockWriter, manifest: &ResolverManifest) -> Resul
             writer.value(member.as_ref())
         })?;
     }
+    if !manifest.scripts.is_empty() {
+        writer.key_multiline_array("scripts", &manifest.scripts, |writer, script| {
+            writer.value(script.as_str())
+        })?;
+    }
     write_serialized_non_empty_array(writer, "requirements", &manifest.requirements)?;
     write_serialized_non_empty_array(writer, "constraints", &manifest.constraints)?;
     write_serialized_non_empty_array(writer, "overrides", &manifest.overrides)?;
@@ -268,6 +300,10 @@ fn write_package(
     writer.array_of_tables(&["package"])?;
     write_package_id(writer, &package.id, None, PackageIdLocation::Table)?;
 
+    if let Some(index_digest) = &package.index_digest {
+        writer.key_value("index-digest", index_digest.to_string())?;
+    }
+
     if !package.fork_markers.is_empty() {
         let markers = simplified_universal_markers(&package.fork_markers, requires_python);
         if !markers.is_empty() {
@@ -373,6 +409,33 @@ fn write_package(
 /// Writes a package identity, omitting fields that a unique package name makes redundant.
 ///
 /// Passing no distribution counts forces the full version and source identity to be written.
+fn write_provenance(
+    writer: &mut LockWriter,
+    provenance: &PackageProvenance,
+) -> Result<(), WriteError> {
+    writer.array_of_tables(&["provenance"])?;
+    writer.key_value("name", provenance.name.as_ref())?;
+    writer.key_value("version", provenance.version.to_string())?;
+    writer.key_value("index", provenance.index.as_str())?;
+    if let Some(index_name) = &provenance.index_name {
+        writer.key_value("index-name", index_name.as_ref())?;
+    }
+    writer.key_value("locked-at", provenance.locked_at.to_string())?;
+    if !provenance.artifacts.is_empty() {
+        writer.key_multiline_array("artifacts", &provenance.artifacts, |writer, artifact| {
+            let mut first = true;
+            writer.start_inline_table();
+            writer.inline_value(&mut first, "filename", artifact.filename.as_str())?;
+            if let Some(upload_time) = artifact.upload_time {
+                writer.inline_value(&mut first, "upload-time", upload_time.to_string())?;
+            }
+            writer.finish_inline_table(first);
+            Ok(())
+        })?;
+    }
+    Ok(())
+}
+
 fn write_package_id(
     writer: &mut LockWriter,
     package_id: &PackageId,
@@ -760,6 +760,82 @@ impl<'env> TreeDisplay<'env> {
             .collect()
     }
 
+    /// Return the packages in the displayed graph that are locked at more than one version.
+    ///
+    /// Each version is paired with its fork markers and the shortest dependency path from the
+    /// roots of the tree that reaches it.
+    pub fn duplicates(&self) -> DuplicatesDisplay<'env> {
+        // Find the shortest path from the roots to each node.
+        let mut parents: FxHashMap<NodeIndex, Option<NodeIndex>> = FxHashMap::default();
+        let mut queue = VecDeque::new();
+        for root in &self.roots {
+            parents.insert(*root, None);
+            queue.push_back(*root);
+        }
+        while let Some(node) = queue.pop_front() {
+            // Visit the dependencies in a stable order, so that the selected path is deterministic.
+            let mut targets = self
+                .graph
+                .edges_directed(node, Direction::Outgoing)
+                .map(|edge| edge.target())
+                .collect::<Vec<_>>();
+            targets.sort_by_key(|index| &self.graph[*index]);
+            for target in targets {
+                if let std::collections::hash_map::Entry::Vacant(entry) = parents.entry(target) {
+                    entry.insert(Some(node));
+                    queue.push_back(target);
+                }
+            }
+        }
+
+        // Group the packages by name.
+        let mut packages: BTreeMap<&'env PackageName, Vec<DuplicateVersion<'env>>> =
+            BTreeMap::new();
+        for index in self.graph.node_indices() {
+            let Node::Package(package_id) = self.graph[index] else {
+                continue;
+            };
+
+            let mut path = Vec::new();
+            let mut cursor = Some(index);
+            while let Some(node) = cursor {
+                if let Node::Package(package_id) = self.graph[node] {
+                    path.push(package_id);
+                }
+                cursor = parents.get(&node).copied().flatten();
+            }
+            path.reverse();
+
+            let package = self.lock.find_by_id(package_id);
+            let markers = package
+                .fork_markers
+                .iter()
+                .filter_map(|marker| {
+                    self.lock
+                        .simplify_environment(marker.pep508())
+                        .try_to_string()
+                })
+                .collect();
+
+            packages
+                .entry(&package_id.name)
+                .or_default()
+                .push(DuplicateVersion {
+                    package_id,
+                    markers,
+                    path,
+                });
+        }
+
+        // Retain the packages with more than one locked version.
+        packages.retain(|_, versions| versions.len() > 1);
+        for versions in packages.values_mut() {
+            versions.sort_by_key(|version| version.package_id);
+        }
+
+        DuplicatesDisplay { packages }
+    }
+
     /// Serialize the displayed dependency graph as JSON.
     pub fn to_json(&self, target: TreeJsonTarget<'_>) -> Result<String, serde_json::Error> {
         serde_json::to_string_pretty(&JsonGraph::new(self, target))
@@ -1419,6 +1495,130 @@ impl<'tree, 'env> JsonGraphBuilder<'tree, 'env> {
     }
 }
 
+/// The packages that are locked at more than one version, as displayed by `uv tree --duplicates`.
+#[derive(Debug)]
+pub struct DuplicatesDisplay<'env> {
+    packages: BTreeMap<&'env PackageName, Vec<DuplicateVersion<'env>>>,
+}
+
+#[derive(Debug)]
+struct DuplicateVersion<'env> {
+    /// The locked package.
+    package_id: &'env PackageId,
+    /// The markers of the forks in which this version was selected.
+    markers: Vec<String>,
+    /// The shortest dependency path from the roots of the tree to this package.
+    path: Vec<&'env PackageId>,
+}
+
+impl DuplicatesDisplay<'_> {
+    /// Returns `true` if no package is locked at more than one version.
+    pub fn is_empty(&self) -> bool {
+        self.packages.is_empty()
+    }
+
+    /// Serialize the duplicated packages as JSON.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        let packages = self
+            .packages
+            .iter()
+            .map(|(name, versions)| JsonDuplicate {
+                name,
+                versions: versions
+                    .iter()
+                    .map(|version| JsonDuplicateVersion {
+                        version: version.package_id.version.as_ref(),
+                        source: version.package_id.source.to_string(),
+                        markers: &version.markers,
+                        path: version
+                            .path
+                            .iter()
+                            .map(|package_id| JsonDuplicatePathEntry {
+                                name: &package_id.name,
+                                version: package_id.version.as_ref(),
+                            })
+                            .collect(),
+                    })
+                    .collect(),
+            })
+            .collect();
+        serde_json::to_string_pretty(&JsonDuplicates {
+            schema: JsonSchema {
+                version: JsonSchemaVersion::Preview,
+            },
+            packages,
+        })
+    }
+}
+
+impl std::fmt::Display for DuplicatesDisplay<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        /// Render a package as it appears in the tree, e.g., `anyio v4.3.0`.
+        fn label(package_id: &PackageId) -> String {
+            match package_id.version.as_ref() {
+                Some(version) => format!("{} v{version}", package_id.name),
+                None => package_id.name.to_string(),
+            }
+        }
+
+        for (name, versions) in &self.packages {
+            writeln!(f, "{name}")?;
+            for (index, version) in versions.iter().enumerate() {
+                let (branch, indent) = if index + 1 == versions.len() {
+                    ("└── ", "    ")
+                } else {
+                    ("├── ", "│   ")
+                };
+
+                let mut line = label(version.package_id);
+                // Distinguish entries that share a version but differ in source.
+                if versions.iter().any(|other| {
+                    !std::ptr::eq(other, version)
+                        && other.package_id.version == version.package_id.version
+                }) {
+                    let _ = write!(line, " ({})", version.package_id.source);
+                }
+                if !version.markers.is_empty() {
+                    let _ = write!(line, " ; {}", version.markers.join(" or "));
+                }
+                writeln!(f, "{branch}{line}")?;
+                writeln!(
+                    f,
+                    "{indent}└── {}",
+                    version.path.iter().copied().map(label).join(" -> ")
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct JsonDuplicates<'a> {
+    schema: JsonSchema,
+    packages: Vec<JsonDuplicate<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonDuplicate<'a> {
+    name: &'a PackageName,
+    versions: Vec<JsonDuplicateVersion<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonDuplicateVersion<'a> {
+    version: Option<&'a Version>,
+    source: String,
+    markers: &'a [String],
+    path: Vec<JsonDuplicatePathEntry<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonDuplicatePathEntry<'a> {
+    name: &'a PackageName,
+    version: Option<&'a Version>,
+}
+
 enum JsonLink {
     Dependency(Option<String>),
     Optional(ExtraName),
@@ -1,5 +1,8 @@
+use std::path::PathBuf;
+
 use uv_configuration::{BuildOptions, IndexStrategy};
-use uv_pypi_types::SupportedEnvironments;
+use uv_normalize::PackageName;
+use uv_pypi_types::{MetadataVersion, SupportedEnvironments};
 use uv_torch::TorchStrategy;
 
 use crate::fork_strategy::ForkStrategy;
@@ -10,14 +13,18 @@ use crate::{DependencyMode, ExcludeNewer, PrereleaseMode, ResolutionMode};
 pub struct Options {
     pub resolution_mode: ResolutionMode,
     pub prerelease_mode: PrereleaseMode,
+    pub prerelease_packages: Vec<PackageName>,
     pub dependency_mode: DependencyMode,
     pub fork_strategy: ForkStrategy,
     pub exclude_newer: ExcludeNewer,
+    pub require_metadata: Option<MetadataVersion>,
     pub index_strategy: IndexStrategy,
     pub artifact_environments: SupportedEnvironments,
     pub flexibility: Flexibility,
     pub build_options: BuildOptions,
     pub torch_backend: Option<TorchStrategy>,
+    pub checkpoint: Option<PathBuf>,
+    pub dependency_cache: Option<PathBuf>,
 }
 
 /// Builder for [`Options`].
@@ -25,14 +32,18 @@ pub struct Options {
 pub struct OptionsBuilder {
     resolution_mode: ResolutionMode,
     prerelease_mode: PrereleaseMode,
+    prerelease_packages: Vec<PackageName>,
     dependency_mode: DependencyMode,
     fork_strategy: ForkStrategy,
     exclude_newer: ExcludeNewer,
+    require_metadata: Option<MetadataVersion>,
     index_strategy: IndexStrategy,
     artifact_environments: SupportedEnvironments,
     flexibility: Flexibility,
     build_options: BuildOptions,
     torch_backend: Option<TorchStrategy>,
+    checkpoint: Option<PathBuf>,
+    dependency_cache: Option<PathBuf>,
 }
 
 impl OptionsBuilder {
@@ -55,6 +66,13 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the packages for which pre-releases are always allowed.
+    #[must_use]
+    pub fn prerelease_packages(mut self, prerelease_packages: Vec<PackageName>) -> Self {
+        self.prerelease_packages = prerelease_packages;
+        self
+    }
+
     /// Sets the dependency mode.
     #[must_use]
     pub fn dependency_mode(mut self, dependency_mode: DependencyMode) -> Self {
@@ -76,6 +94,13 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the minimum core metadata version required of each distribution.
+    #[must_use]
+    pub fn require_metadata(mut self, require_metadata: Option<MetadataVersion>) -> Self {
+        self.require_metadata = require_metadata;
+        self
+    }
+
     /// Sets the index strategy.
     #[must_use]
     pub fn index_strategy(mut self, index_strategy: IndexStrategy) -> Self {
@@ -111,19 +136,38 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the path at which to persist the preferences of each completed fork.
+    #[must_use]
+    pub fn checkpoint(mut self, checkpoint: Option<PathBuf>) -> Self {
+        self.checkpoint = checkpoint;
+        self
+    }
+
+    /// Sets the path at which to persist the dependencies of the registry wheels used during
+    /// resolution.
+    #[must_use]
+    pub fn dependency_cache(mut self, dependency_cache: Option<PathBuf>) -> Self {
+        self.dependency_cache = dependency_cache;
+        self
+    }
+
     /// Builds the options.
     pub fn build(self) -> Options {
         Options {
             resolution_mode: self.resolution_mode,
             prerelease_mode: self.prerelease_mode,
+            prerelease_packages: self.prerelease_packages,
             dependency_mode: self.dependency_mode,
             fork_strategy: self.fork_strategy,
             exclude_newer: self.exclude_newer,
+            require_metadata: self.require_metadata,
             index_strategy: self.index_strategy,
             artifact_environments: self.artifact_environments,
             flexibility: self.flexibility,
             build_options: self.build_options,
             torch_backend: self.torch_backend,
+            checkpoint: self.checkpoint,
+            dependency_cache: self.dependency_cache,
         }
     }
 }
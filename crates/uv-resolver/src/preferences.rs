@@ -120,6 +120,23 @@ impl Preference {
         }))
     }
 
+    /// Create a [`Preference`] from a version selected by a fork of an interrupted resolution.
+    pub(crate) fn from_checkpoint(name: PackageName, version: Version, marker: MarkerTree) -> Self {
+        Self {
+            name,
+            version,
+            marker: MarkerTree::TRUE,
+            index: PreferenceIndex::Any,
+            fork_markers: if marker.is_true() {
+                vec![]
+            } else {
+                vec![UniversalMarker::from_combined(marker)]
+            },
+            hashes: HashDigests::empty(),
+            source: PreferenceSource::Resolver,
+        }
+    }
+
     /// Create a [`Preference`] from an installed distribution.
     pub fn from_installed(dist: &InstalledDist) -> Option<Self> {
         let InstalledDistKind::Registry(dist) = &dist.kind else {
@@ -12,15 +12,18 @@ use pubgrub::{DerivationTree, Derived, External, Map, ReportFormatter, Term};
 use reqwest::StatusCode;
 use rustc_hash::FxHashMap;
 
+use uv_auth::CredentialLookup;
 use uv_configuration::{IndexStrategy, NoBinary, NoBuild};
 use uv_distribution_types::{
     IncompatibleDist, IncompatibleSource, IncompatibleWheel, Index, IndexCapabilities,
-    IndexLocations, IndexMetadata, IndexUrl, RequiresPython,
+    IndexLocations, IndexMetadata, IndexName, IndexUrl, RequiresPython,
 };
 use uv_normalize::PackageName;
 use uv_pep440::{Version, VersionSpecifier, VersionSpecifiers};
 use uv_pep508::{MarkerEnvironment, MarkerExpression, MarkerTree, MarkerValueVersion};
-use uv_platform_tags::{AbiTag, IncompatibleTag, LanguageTag, PlatformTag, Tags};
+use uv_platform_tags::{AbiTag, Arch, IncompatibleTag, LanguageTag, Os, PlatformTag, Tags};
+use uv_pypi_types::MetadataVersion;
+use uv_static::EnvVars;
 
 use crate::candidate_selector::CandidateSelector;
 use crate::error::{ErrorTree, PrefixMatch};
@@ -986,6 +989,40 @@ impl PubGrubReportFormatter<'_> {
                 }
             }
         }
+
+        // On Windows ARM64, summarize the packages that could be installed under x64 emulation.
+        let packages = tags.and_then(|tags| Self::windows_arm64_hint(tags, output_hints));
+        if let Some(packages) = packages {
+            output_hints.insert(PubGrubHint::WindowsArm64 { packages });
+        }
+    }
+
+    /// Return the packages that lack `win_arm64` wheels but provide `win_amd64` wheels, if the
+    /// resolution targets Windows ARM64.
+    fn windows_arm64_hint(
+        tags: &Tags,
+        hints: &IndexSet<PubGrubHint>,
+    ) -> Option<BTreeSet<PackageName>> {
+        let platform = tags.python_platform();
+        if !matches!(platform.os(), Os::Windows) || platform.arch() != Arch::Aarch64 {
+            return None;
+        }
+        let packages = hints
+            .iter()
+            .filter_map(|hint| match hint {
+                PubGrubHint::PlatformTags {
+                    package,
+                    tags: platform_tags,
+                    ..
+                } if platform_tags.contains(&PlatformTag::WinAmd64) => Some(package.clone()),
+                _ => None,
+            })
+            .collect::<BTreeSet<_>>();
+        if packages.is_empty() {
+            None
+        } else {
+            Some(packages)
+        }
     }
 
     /// Collect the version ranges in `derivation_tree` that were excluded solely by
@@ -1213,6 +1250,19 @@ impl PubGrubReportFormatter<'_> {
                     status: *status,
                 });
             }
+            Some(UnavailablePackage::MetadataVersion {
+                artifact,
+                found,
+                required,
+            }) => {
+                hints.insert(PubGrubHint::MetadataVersion {
+                    package: name.clone(),
+                    version: None,
+                    artifact: artifact.clone(),
+                    found: *found,
+                    required: *required,
+                });
+            }
             Some(UnavailablePackage::NotFound) => {}
             None => {}
         }
@@ -1261,6 +1311,19 @@ impl PubGrubReportFormatter<'_> {
                                 status: *status,
                             });
                         }
+                        MetadataUnavailable::MetadataVersion {
+                            artifact,
+                            found,
+                            required,
+                        } => {
+                            hints.insert(PubGrubHint::MetadataVersion {
+                                package: name.clone(),
+                                version: Some(version.clone()),
+                                artifact: artifact.clone(),
+                                found: *found,
+                                required: *required,
+                            });
+                        }
                     }
                     break;
                 }
@@ -1300,8 +1363,27 @@ impl PubGrubReportFormatter<'_> {
         // Add hints due to an index returning an unauthorized response.
         for index in index_locations.allowed_indexes() {
             if index_capabilities.unauthorized(&index.url) {
+                // For named indexes, determine which of the credential environment variables
+                // are unset.
+                let missing_env_vars = index
+                    .name
+                    .as_ref()
+                    .map(|name| {
+                        let name = name.to_env_var();
+                        [
+                            EnvVars::index_username(&name),
+                            EnvVars::index_password(&name),
+                        ]
+                        .into_iter()
+                        .filter(|var| std::env::var_os(var).is_none())
+                        .collect()
+                    })
+                    .unwrap_or_default();
                 hints.insert(PubGrubHint::UnauthorizedIndex {
                     index: index.url.clone(),
+                    name: index.name.clone(),
+                    lookups: index_capabilities.credential_lookups(&index.url),
+                    missing_env_vars,
                 });
             }
             if index_capabilities.forbidden(&index.url) {
@@ -1324,7 +1406,7 @@ impl PubGrubReportFormatter<'_> {
         options: &Options,
         hints: &mut IndexSet<PubGrubHint>,
     ) {
-        if selector.prerelease_strategy().allows(name, env) == AllowPrerelease::Yes {
+        if selector.allows_prerelease(name, env) == AllowPrerelease::Yes {
             return;
         }
 
@@ -1373,20 +1455,30 @@ impl PubGrubReportFormatter<'_> {
                     });
                 }
             }
-        } else if let Some(version) = self.included_versions.get(name).and_then(|versions| {
-            versions
+        } else if let Some(versions) = self.included_versions.get(name)
+            && let Some(version) = versions
                 .iter()
                 .rev()
                 .filter(|version| version.any_prerelease())
                 .find(|version| set.contains(version))
-        }) {
+        {
             // There are pre-release versions available for the package.
             match options.flexibility {
                 Flexibility::Configurable => {
-                    hints.insert(PubGrubHint::PrereleaseAvailable {
-                        package: name.clone(),
-                        version: version.clone(),
-                    });
+                    let only_prereleases = !versions
+                        .iter()
+                        .any(|version| !version.any_prerelease() && set.contains(version));
+                    if only_prereleases {
+                        hints.insert(PubGrubHint::PrereleaseOnly {
+                            package: name.clone(),
+                            version: version.clone(),
+                        });
+                    } else {
+                        hints.insert(PubGrubHint::PrereleaseAvailable {
+                            package: name.clone(),
+                            version: version.clone(),
+                        });
+                    }
                 }
                 Flexibility::Fixed => {
                     hints.insert(PubGrubHint::BuildPrereleaseAvailable {
@@ -1424,6 +1516,13 @@ pub enum PubGrubHint {
         // excluded from `PartialEq` and `Hash`
         version: Version,
     },
+    /// Only pre-release versions of a package are available in the requested range, but
+    /// pre-releases weren't enabled for that package.
+    PrereleaseOnly {
+        package: PackageName,
+        // excluded from `PartialEq` and `Hash`
+        version: Version,
+    },
     /// The resolver runs with fixed options (e.g., for build environments) and requires explicit
     /// pre-release opt-in for a package that only has pre-releases available.
     BuildPrereleaseAvailable {
@@ -1512,6 +1611,18 @@ pub enum PubGrubHint {
         // excluded from `PartialEq` and `Hash`
         status: StatusCode,
     },
+    /// The distribution declares a core metadata version below the `require-metadata` minimum.
+    MetadataVersion {
+        package: PackageName,
+        // excluded from `PartialEq` and `Hash`
+        version: Option<Version>,
+        // excluded from `PartialEq` and `Hash`
+        artifact: String,
+        // excluded from `PartialEq` and `Hash`
+        found: MetadataVersion,
+        // excluded from `PartialEq` and `Hash`
+        required: MetadataVersion,
+    },
     /// The `Requires-Python` requirement was not satisfied.
     RequiresPython {
         source: PythonRequirementSource,
@@ -1560,7 +1671,15 @@ pub enum PubGrubHint {
         option: NoBinary,
     },
     /// An index returned an Unauthorized (401) response.
-    UnauthorizedIndex { index: IndexUrl },
+    UnauthorizedIndex {
+        index: IndexUrl,
+        // excluded from `PartialEq` and `Hash`
+        name: Option<IndexName>,
+        // excluded from `PartialEq` and `Hash`
+        lookups: Vec<CredentialLookup>,
+        // excluded from `PartialEq` and `Hash`
+        missing_env_vars: Vec<String>,
+    },
     /// An index returned a Forbidden (403) response.
     ForbiddenIndex {
         index: IndexUrl,
@@ -1614,6 +1733,9 @@ pub enum PubGrubHint {
     },
     /// The resolution failed for an environment that is different from the current environment.
     DisjointEnvironment,
+    /// The resolution targets Windows ARM64, but some packages only provide wheels for x64, which
+    /// could run under emulation.
+    WindowsArm64 { packages: BTreeSet<PackageName> },
 }
 
 /// This private enum mirrors [`PubGrubHint`] but only includes fields that should be
@@ -1624,6 +1746,9 @@ enum PubGrubHintCore {
     PrereleaseAvailable {
         package: PackageName,
     },
+    PrereleaseOnly {
+        package: PackageName,
+    },
     BuildPrereleaseAvailable {
         package: PackageName,
     },
@@ -1659,6 +1784,9 @@ enum PubGrubHintCore {
     IncompatibleBuildRequirement {
         package: PackageName,
     },
+    MetadataVersion {
+        package: PackageName,
+    },
     RequiresPython {
         source: PythonRequirementSource,
         requires_python: RequiresPython,
@@ -1702,6 +1830,9 @@ enum PubGrubHintCore {
     },
     DisjointPythonVersion,
     DisjointEnvironment,
+    WindowsArm64 {
+        packages: BTreeSet<PackageName>,
+    },
 }
 
 impl From<PubGrubHint> for PubGrubHintCore {
@@ -1711,6 +1842,7 @@ impl From<PubGrubHint> for PubGrubHintCore {
             PubGrubHint::PrereleaseAvailable { package, .. } => {
                 Self::PrereleaseAvailable { package }
             }
+            PubGrubHint::PrereleaseOnly { package, .. } => Self::PrereleaseOnly { package },
             PubGrubHint::BuildPrereleaseAvailable { package, .. } => {
                 Self::BuildPrereleaseAvailable { package }
             }
@@ -1746,6 +1878,7 @@ impl From<PubGrubHint> for PubGrubHintCore {
             PubGrubHint::IncompatibleBuildRequirement { package, .. } => {
                 Self::IncompatibleBuildRequirement { package }
             }
+            PubGrubHint::MetadataVersion { package, .. } => Self::MetadataVersion { package },
             PubGrubHint::RequiresPython {
                 source,
                 requires_python,
@@ -1767,7 +1900,7 @@ impl From<PubGrubHint> for PubGrubHintCore {
                 Self::DependsOnItself { package, workspace }
             }
             PubGrubHint::UncheckedIndex { name: package, .. } => Self::UncheckedIndex { package },
-            PubGrubHint::UnauthorizedIndex { index } => Self::UnauthorizedIndex { index },
+            PubGrubHint::UnauthorizedIndex { index, .. } => Self::UnauthorizedIndex { index },
             PubGrubHint::ForbiddenIndex { index, .. } => Self::ForbiddenIndex { index },
             PubGrubHint::NoBuild { package, .. } => Self::NoBuild { package },
             PubGrubHint::NoBinary { package, .. } => Self::NoBinary { package },
@@ -1779,6 +1912,7 @@ impl From<PubGrubHint> for PubGrubHintCore {
             } => Self::ExcludeNewer { package, source },
             PubGrubHint::DisjointPythonVersion { .. } => Self::DisjointPythonVersion,
             PubGrubHint::DisjointEnvironment => Self::DisjointEnvironment,
+            PubGrubHint::WindowsArm64 { packages } => Self::WindowsArm64 { packages },
         }
     }
 }
@@ -1812,6 +1946,18 @@ impl std::fmt::Display for PubGrubHint {
                     "--prerelease=allow".green(),
                 )
             }
+            Self::PrereleaseOnly { package, version } => {
+                let setting = format!("tool.uv.prerelease-packages = [\"{package}\"]");
+                write!(
+                    f,
+                    "Only pre-releases of `{}` are available in the requested range (e.g., {}), but pre-releases weren't enabled (try: `{}` to allow pre-releases for `{}` only, or `{}`)",
+                    package.cyan(),
+                    version.cyan(),
+                    setting.green(),
+                    package.cyan(),
+                    "--prerelease=allow".green(),
+                )
+            }
             Self::BuildPrereleaseAvailable { package, version } => {
                 let spec = format!("{package}>={version}");
                 write!(
@@ -2007,6 +2153,34 @@ impl std::fmt::Display for PubGrubHint {
                     python_version.cyan(),
                 )
             }
+            Self::MetadataVersion {
+                package,
+                version,
+                artifact,
+                found,
+                required,
+            } => {
+                if let Some(version) = version {
+                    write!(
+                        f,
+                        "`{}` ({}) was rejected because `{}` declares core metadata version {}, but `require-metadata` requires {} or later",
+                        package.cyan(),
+                        format!("v{version}").cyan(),
+                        artifact.cyan(),
+                        found.cyan(),
+                        required.cyan(),
+                    )
+                } else {
+                    write!(
+                        f,
+                        "`{}` was rejected because `{}` declares core metadata version {}, but `require-metadata` requires {} or later",
+                        package.cyan(),
+                        artifact.cyan(),
+                        found.cyan(),
+                        required.cyan(),
+                    )
+                }
+            }
             Self::DependsOnWorkspacePackage {
                 package,
                 dependency,
@@ -2060,13 +2234,60 @@ impl std::fmt::Display for PubGrubHint {
                     "--index-strategy unsafe-best-match".green(),
                 )
             }
-            Self::UnauthorizedIndex { index } => {
-                write!(
-                    f,
-                    "An index URL ({}) could not be queried due to a lack of valid authentication credentials ({})",
-                    index.without_credentials().cyan(),
-                    "401 Unauthorized".red(),
-                )
+            Self::UnauthorizedIndex {
+                index,
+                name,
+                lookups,
+                missing_env_vars,
+            } => {
+                if let Some(name) = name {
+                    write!(
+                        f,
+                        "The `{}` index ({}) could not be queried due to a lack of valid authentication credentials ({})",
+                        name.cyan(),
+                        index.without_credentials().cyan(),
+                        "401 Unauthorized".red(),
+                    )?;
+                } else {
+                    write!(
+                        f,
+                        "An index URL ({}) could not be queried due to a lack of valid authentication credentials ({})",
+                        index.without_credentials().cyan(),
+                        "401 Unauthorized".red(),
+                    )?;
+                }
+                if !lookups.is_empty() {
+                    write!(
+                        f,
+                        ". Credential sources checked: {}",
+                        lookups.iter().join(", ")
+                    )?;
+                }
+                if let Some(name) = name {
+                    if missing_env_vars.is_empty() {
+                        let name = name.to_env_var();
+                        write!(
+                            f,
+                            ". The credentials provided via `{}` and `{}` were rejected",
+                            EnvVars::index_username(&name).green(),
+                            EnvVars::index_password(&name).green(),
+                        )?;
+                    } else {
+                        write!(
+                            f,
+                            ". To provide credentials for this index, set {} or run `{}`",
+                            missing_env_vars
+                                .iter()
+                                .map(|var| format!("`{}`", var.green()))
+                                .join(" and "),
+                            format!("uv auth login {}", index.without_credentials()).green(),
+                        )?;
+                    }
+                }
+                if name.is_some() || !lookups.is_empty() {
+                    write!(f, ".")?;
+                }
+                Ok(())
             }
             Self::ForbiddenIndex {
                 index,
@@ -2291,6 +2512,19 @@ impl std::fmt::Display for PubGrubHint {
                     consider limiting the environments with `tool.uv.environments`.",
                 )
             }
+            Self::WindowsArm64 { packages } => {
+                let s = if packages.len() == 1 { "" } else { "s" };
+                write!(
+                    f,
+                    "No `{}` wheels are available for the following package{s}, but `{}` wheels can run under x64 emulation: {}. Consider reporting the missing wheels upstream, or using an x64 Python interpreter",
+                    "win_arm64".cyan(),
+                    "win_amd64".cyan(),
+                    packages
+                        .iter()
+                        .map(|package| format!("`{}`", package.cyan()))
+                        .join(", "),
+                )
+            }
         }
     }
 }
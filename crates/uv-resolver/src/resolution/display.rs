@@ -21,6 +21,8 @@ pub struct DisplayResolutionGraph<'a> {
     env: &'a ResolverEnvironment,
     /// The packages to exclude from the output.
     no_emit_packages: &'a [PackageName],
+    /// The packages to emit after all other packages, under pip-compile's "unsafe" header.
+    unsafe_packages: &'a [PackageName],
     /// Whether to include hashes in the output.
     show_hashes: bool,
     /// Whether to include extras in the output (e.g., `black[colorama]`).
@@ -55,6 +57,7 @@ impl<'a> DisplayResolutionGraph<'a> {
         underlying: &'a ResolverOutput,
         env: &'a ResolverEnvironment,
         no_emit_packages: &'a [PackageName],
+        unsafe_packages: &'a [PackageName],
         show_hashes: bool,
         include_extras: bool,
         include_markers: bool,
@@ -73,6 +76,7 @@ impl<'a> DisplayResolutionGraph<'a> {
             resolution: underlying,
             env,
             no_emit_packages,
+            unsafe_packages,
             show_hashes,
             include_extras,
             include_markers,
@@ -204,11 +208,30 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
             })
             .collect::<Vec<_>>();
 
-        // Sort the nodes by name, but with editable packages first.
-        nodes.sort_unstable_by_key(|(index, node)| (node.to_comparator(), *index));
+        // Sort the nodes by name, but with editable packages first and unsafe packages last.
+        nodes.sort_unstable_by_key(|(index, node)| {
+            (
+                self.unsafe_packages.contains(node.name()),
+                node.to_comparator(),
+                *index,
+            )
+        });
 
         // Print out the dependency graph.
+        let mut wrote_unsafe_header = false;
         for (index, node) in nodes {
+            // Separate the unsafe packages from the rest of the output, as in pip-compile.
+            if !wrote_unsafe_header && self.unsafe_packages.contains(node.name()) {
+                writeln!(f)?;
+                writeln!(
+                    f,
+                    "{}",
+                    "# The following packages are considered to be unsafe in a requirements file:"
+                        .green()
+                )?;
+                wrote_unsafe_header = true;
+            }
+
             // Display the node itself.
             let mut line = node
                 .to_requirements_txt(&self.resolution.requires_python, self.include_markers)
@@ -20,7 +20,9 @@ use uv_git::GitResolver;
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::{Version, VersionSpecifier};
 use uv_pep508::{MarkerEnvironment, MarkerTree, MarkerTreeKind};
-use uv_pypi_types::{Conflicts, HashDigests, ParsedUrlError, VerbatimParsedUrl, Yanked};
+use uv_pypi_types::{
+    Conflicts, HashDigests, MetadataVersion, ParsedUrlError, VerbatimParsedUrl, Yanked,
+};
 
 use crate::graph_ops::{marker_reachability, simplify_conflict_markers};
 use crate::pins::FilePins;
@@ -613,11 +615,96 @@ impl ResolverOutput {
         self.base_dists().next().is_none()
     }
 
+    /// Return the core metadata version declared by each distinct package in the graph, if known.
+    pub fn metadata_versions(
+        &self,
+    ) -> impl Iterator<Item = (&PackageName, &Version, Option<MetadataVersion>)> {
+        self.base_dists().map(|(_, dist)| {
+            (
+                &dist.name,
+                &dist.version,
+                dist.metadata
+                    .as_ref()
+                    .and_then(|metadata| metadata.metadata_version),
+            )
+        })
+    }
+
     /// Returns `true` if the graph contains the given package.
     pub fn contains(&self, name: &PackageName) -> bool {
         self.dists().any(|dist| dist.name() == name)
     }
 
+    /// Return the requirements declared on the given package by its dependents in the graph, along
+    /// with the name of each dependent (or `None`, for direct requirements and constraints).
+    ///
+    /// Overrides are applied, and requirements that don't apply to the given marker environment
+    /// are omitted.
+    pub fn requirements_on(
+        &self,
+        package: &PackageName,
+        marker_env: &MarkerEnvironment,
+    ) -> Vec<(Option<&PackageName>, Cow<'_, Requirement>)> {
+        let mut requirements = Vec::new();
+        for index in self.graph.node_indices() {
+            if !self
+                .graph
+                .neighbors_directed(index, Direction::Outgoing)
+                .any(|neighbor| self.graph[neighbor].package_name() == Some(package))
+            {
+                continue;
+            }
+            match &self.graph[index] {
+                ResolutionGraphNode::Root => {
+                    requirements.extend(
+                        self.overrides
+                            .apply(self.requirements.iter())
+                            .filter(|requirement| {
+                                requirement.name == *package
+                                    && requirement.evaluate_markers(Some(marker_env), &[])
+                            })
+                            .map(|requirement| (None, requirement)),
+                    );
+                }
+                ResolutionGraphNode::Dist(dist) => {
+                    // Ignore the edges from extras and groups to their base package.
+                    if dist.name == *package {
+                        continue;
+                    }
+                    let Some(metadata) = dist.metadata.as_ref() else {
+                        continue;
+                    };
+                    let declared: &[Requirement] = if let Some(group) = dist.group.as_ref() {
+                        metadata
+                            .dependency_groups
+                            .get(group)
+                            .map(AsRef::as_ref)
+                            .unwrap_or_default()
+                    } else {
+                        metadata.requires_dist.as_ref()
+                    };
+                    requirements.extend(
+                        self.overrides
+                            .apply_for(&dist.name, &dist.version, declared)
+                            .filter(|requirement| {
+                                requirement.name == *package
+                                    && requirement
+                                        .evaluate_markers(Some(marker_env), dist.extra.as_slice())
+                            })
+                            .map(|requirement| (Some(&dist.name), requirement)),
+                    );
+                }
+            }
+        }
+        requirements.extend(
+            self.constraints
+                .requirements()
+                .filter(|requirement| requirement.name == *package)
+                .map(|requirement| (None, Cow::Borrowed(requirement))),
+        );
+        requirements
+    }
+
     /// Return the [`ResolutionDiagnostic`]s that were encountered while building the graph.
     pub fn diagnostics(&self) -> &[ResolutionDiagnostic] {
         &self.diagnostics
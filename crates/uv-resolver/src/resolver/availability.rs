@@ -9,6 +9,7 @@ use uv_distribution_types::{IncompatibleDist, Requirement, RequirementSource};
 use uv_normalize::{ExtraName, PackageName};
 use uv_pep440::{Version, VersionSpecifiers};
 use uv_platform_tags::{AbiTag, Tags};
+use uv_pypi_types::MetadataVersion;
 
 use crate::pubgrub::Range;
 use crate::resolver::{MetadataUnavailable, VersionFork};
@@ -112,6 +113,8 @@ pub enum UnavailableVersion {
     RequiresPython(VersionSpecifiers),
     /// The network request failed with the given status code.
     Network(StatusCode),
+    /// The distribution declares a core metadata version below the required minimum.
+    MetadataVersion(MetadataVersion),
 }
 
 impl UnavailableVersion {
@@ -127,6 +130,9 @@ impl UnavailableVersion {
                 Cow::Owned(format!("Python {requires_python}"))
             }
             Self::Network(status) => Cow::Owned(status.to_string()),
+            Self::MetadataVersion(required) => {
+                Cow::Owned(format!("core metadata older than {required}"))
+            }
         }
     }
 
@@ -142,6 +148,7 @@ impl UnavailableVersion {
             Self::Offline => format!("needs {self}"),
             Self::RequiresPython(..) => format!("requires {self}"),
             Self::Network(..) => format!("could not be fetched from the network (`{self}`)"),
+            Self::MetadataVersion(..) => format!("has {self}"),
         }
     }
 
@@ -155,6 +162,7 @@ impl UnavailableVersion {
             Self::Offline => format!("need {self}"),
             Self::RequiresPython(..) => format!("require {self}"),
             Self::Network(..) => format!("could not be fetched from the network (`{self}`)"),
+            Self::MetadataVersion(..) => format!("have {self}"),
         }
     }
 
@@ -174,6 +182,7 @@ impl UnavailableVersion {
             Self::Offline => None,
             Self::RequiresPython(..) => None,
             Self::Network(..) => None,
+            Self::MetadataVersion(..) => None,
         }
     }
 }
@@ -195,6 +204,9 @@ impl From<&MetadataUnavailable> for UnavailableVersion {
                 Self::RequiresPython(requires_python.clone())
             }
             MetadataUnavailable::Network(status) => Self::Network(*status),
+            MetadataUnavailable::MetadataVersion { required, .. } => {
+                Self::MetadataVersion(*required)
+            }
         }
     }
 }
@@ -237,6 +249,12 @@ pub enum UnavailablePackage {
     InvalidStructure(UnavailableErrorChain),
     /// The network request failed with the given status code.
     Network(StatusCode),
+    /// The distribution declares a core metadata version below the required minimum.
+    MetadataVersion {
+        artifact: String,
+        found: MetadataVersion,
+        required: MetadataVersion,
+    },
 }
 
 impl UnavailablePackage {
@@ -248,6 +266,9 @@ impl UnavailablePackage {
             Self::InvalidMetadata(_) => Cow::Borrowed("invalid metadata"),
             Self::InvalidStructure(_) => Cow::Borrowed("an invalid package format"),
             Self::Network(status) => Cow::Owned(status.to_string()),
+            Self::MetadataVersion { required, .. } => {
+                Cow::Owned(format!("core metadata older than {required}"))
+            }
         }
     }
 
@@ -259,6 +280,7 @@ impl UnavailablePackage {
             Self::InvalidMetadata(_) => format!("has {self}"),
             Self::InvalidStructure(_) => format!("has {self}"),
             Self::Network(_) => format!("could not be fetched from the network (`{self}`)"),
+            Self::MetadataVersion { .. } => format!("has {self}"),
         }
     }
 }
@@ -286,6 +308,15 @@ impl From<&MetadataUnavailable> for UnavailablePackage {
                 unreachable!("`requires-python` is only known upfront for registry distributions")
             }
             MetadataUnavailable::Network(status) => Self::Network(*status),
+            MetadataUnavailable::MetadataVersion {
+                artifact,
+                found,
+                required,
+            } => Self::MetadataVersion {
+                artifact: artifact.clone(),
+                found: *found,
+                required: *required,
+            },
         }
     }
 }
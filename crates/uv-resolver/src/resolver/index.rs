@@ -2,9 +2,10 @@ use std::hash::BuildHasherDefault;
 use std::sync::Arc;
 
 use rustc_hash::FxHasher;
-use uv_distribution_types::{DistributionId, IndexUrl};
+use uv_distribution_types::{ArtifactCandidate, DistributionId, IndexUrl, PrioritizedDist};
 use uv_normalize::PackageName;
 use uv_once_map::OnceMap;
+use uv_pep440::Version;
 
 use crate::resolver::provider::{MetadataResponse, VersionsResponse};
 
@@ -41,4 +42,26 @@ impl InMemoryIndex {
     pub fn distributions(&self) -> &FxOnceMap<DistributionId, Arc<MetadataResponse>> {
         &self.0.distributions
     }
+
+    /// Explain the artifact selection for the given package version, using the index responses
+    /// that were populated during resolution.
+    ///
+    /// Returns `None` if the package version was not fetched from a registry.
+    pub fn explain_artifacts(
+        &self,
+        name: &PackageName,
+        version: &Version,
+        index: Option<&IndexUrl>,
+    ) -> Option<Vec<ArtifactCandidate>> {
+        let response = index
+            .and_then(|index| self.0.explicit.get(&(name.clone(), index.clone())))
+            .or_else(|| self.0.implicit.get(name))?;
+        let VersionsResponse::Found(version_maps) = response.as_ref() else {
+            return None;
+        };
+        version_maps
+            .iter()
+            .find_map(|version_map| version_map.get(version))
+            .map(PrioritizedDist::explain)
+    }
 }
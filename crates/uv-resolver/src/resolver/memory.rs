@@ -0,0 +1,430 @@
+//! Coarse accounting of the memory retained by the resolver.
+//!
+//! The accounting is based on estimates, rather than allocator statistics: each retained
+//! distribution metadata entry (and each of its requirements), each version in a package's version
+//! map, and each incompatibility held by the solver is assigned a fixed cost. The estimates are
+//! intended to fail a pathological resolution with a useful error well before the process is
+//! killed, not to be precise.
+//!
+//! Exceeding the limit doesn't switch the resolver into a different mode: the only mitigation is to
+//! discard the metadata for versions that can no longer be selected from the [`InMemoryIndex`]
+//! (to be re-fetched on demand), and to fail if that isn't enough. In particular, the resolver
+//! continues to retain the metadata for every reachable candidate, rather than streaming it.
+
+use std::fmt::{Display, Formatter};
+use std::iter;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use pubgrub::Term;
+use rustc_hash::FxHashMap;
+use tracing::{Level, debug};
+
+use uv_console::human_readable_bytes;
+use uv_distribution_types::{BuiltDist, Dist, DistributionId, Identifier, Name, SourceDist};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_static::EnvVars;
+use uv_warnings::warn_user_once;
+
+use crate::pubgrub::{PubGrubPackageInner, Range};
+use crate::resolver::{ForkState, InMemoryIndex, MetadataResponse, Resolution, VersionsResponse};
+
+/// The estimated cost of a retained distribution metadata entry, excluding its requirements.
+const METADATA_BYTES: u64 = 2 * 1024;
+
+/// The estimated cost of each requirement in a retained distribution metadata entry.
+const REQUIREMENT_BYTES: u64 = 1024;
+
+/// The estimated cost of each version in a package's version map.
+const VERSION_BYTES: u64 = 512;
+
+/// The estimated cost of each incompatibility held by the solver.
+const INCOMPATIBILITY_BYTES: u64 = 256;
+
+/// The number of packages to include when reporting the packages with the most retained versions.
+const REPORTED_PACKAGES: usize = 10;
+
+/// A soft limit on the memory retained by the resolver, in bytes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) struct MemoryLimit(u64);
+
+impl MemoryLimit {
+    /// Read the limit from `UV_RESOLVER_MEMORY_LIMIT`, if set.
+    pub(crate) fn from_env() -> Option<Self> {
+        let value = std::env::var(EnvVars::UV_RESOLVER_MEMORY_LIMIT).ok()?;
+        if let Some(bytes) = parse_bytes(&value) {
+            Some(Self(bytes))
+        } else {
+            warn_user_once!(
+                "Ignoring invalid value for `{}`: `{value}` (expected a size, e.g., `3GiB` or `3000000000`)",
+                EnvVars::UV_RESOLVER_MEMORY_LIMIT
+            );
+            None
+        }
+    }
+}
+
+/// Parse a (non-zero) size in bytes, with an optional decimal (`KB`, `MB`, `GB`) or binary (`KiB`,
+/// `MiB`, `GiB`) unit.
+fn parse_bytes(value: &str) -> Option<u64> {
+    let value = value.trim();
+    let (number, unit) = value.split_at(
+        value
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(value.len()),
+    );
+    let number = number.parse::<u64>().ok()?;
+    let multiplier: u64 = match unit.trim().to_ascii_lowercase().as_str() {
+        "" | "b" => 1,
+        "k" | "kb" => 1000,
+        "m" | "mb" => 1000 * 1000,
+        "g" | "gb" => 1000 * 1000 * 1000,
+        "kib" => 1024,
+        "mib" => 1024 * 1024,
+        "gib" => 1024 * 1024 * 1024,
+        _ => return None,
+    };
+    number.checked_mul(multiplier).filter(|bytes| *bytes > 0)
+}
+
+/// Tracks the (estimated) memory retained by a resolution, across all forks.
+#[derive(Debug, Default)]
+pub(crate) struct MemoryTracker {
+    limit: Option<MemoryLimit>,
+    state: Mutex<TrackerState>,
+}
+
+#[derive(Debug, Default)]
+struct TrackerState {
+    /// The distribution metadata retained in the [`InMemoryIndex`], for each package.
+    metadata: FxHashMap<PackageName, FxHashMap<DistributionId, RetainedMetadata>>,
+    /// The estimated size of the retained distribution metadata.
+    metadata_bytes: u64,
+    /// The estimated size of the retained version maps.
+    versions_bytes: u64,
+    /// The peak estimated size of all retained state.
+    peak_bytes: u64,
+    /// The peak number of versions with retained metadata, for each package.
+    peak_versions: FxHashMap<PackageName, usize>,
+    /// The number of metadata entries that were discarded to stay under the limit.
+    shed: usize,
+}
+
+#[derive(Debug)]
+struct RetainedMetadata {
+    version: Version,
+    bytes: u64,
+}
+
+impl MemoryTracker {
+    /// Create a [`MemoryTracker`] with an optional soft limit.
+    pub(crate) fn new(limit: Option<MemoryLimit>) -> Self {
+        Self {
+            limit,
+            state: Mutex::default(),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, TrackerState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Record the version map(s) retained for a package.
+    pub(crate) fn record_versions(&self, response: &VersionsResponse) {
+        let VersionsResponse::Found(version_maps) = response else {
+            return;
+        };
+        let versions = version_maps.iter().map(|map| map.len() as u64).sum::<u64>();
+        let mut state = self.lock();
+        state.versions_bytes += VERSION_BYTES * versions;
+        state.update_peak(0);
+    }
+
+    /// Record the metadata retained for a distribution.
+    ///
+    /// Only registry distributions are tracked, since they're the only distributions whose
+    /// metadata can be discarded and later re-fetched on demand.
+    pub(crate) fn record_metadata(&self, dist: &Dist, response: &MetadataResponse) {
+        if !matches!(
+            dist,
+            Dist::Built(BuiltDist::Registry(_)) | Dist::Source(SourceDist::Registry(_))
+        ) {
+            return;
+        }
+        let Some(version) = dist.version() else {
+            return;
+        };
+        let requirements = match response {
+            MetadataResponse::Found(archive) => {
+                archive.metadata.requires_dist.len()
+                    + archive
+                        .metadata
+                        .dependency_groups
+                        .values()
+                        .map(|group| group.len())
+                        .sum::<usize>()
+            }
+            MetadataResponse::Unavailable(_) | MetadataResponse::Error(..) => 0,
+        };
+        let bytes = METADATA_BYTES + REQUIREMENT_BYTES * requirements as u64;
+
+        let mut state = self.lock();
+        let TrackerState {
+            metadata,
+            metadata_bytes,
+            peak_versions,
+            ..
+        } = &mut *state;
+        let retained = metadata.entry(dist.name().clone()).or_default();
+        if let Some(previous) = retained.insert(
+            dist.distribution_id(),
+            RetainedMetadata {
+                version: version.clone(),
+                bytes,
+            },
+        ) {
+            *metadata_bytes -= previous.bytes;
+        }
+        *metadata_bytes += bytes;
+
+        let peak = peak_versions.entry(dist.name().clone()).or_default();
+        *peak = (*peak).max(retained.len());
+
+        state.update_peak(0);
+    }
+
+    /// Check the estimated memory retained by the resolution against the limit, if any.
+    ///
+    /// If the limit is exceeded, discards the metadata for versions that are no longer reachable;
+    /// if the limit is still exceeded, returns an error naming the packages with the most retained
+    /// versions.
+    pub(crate) fn check(
+        &self,
+        index: &InMemoryIndex,
+        fork: &ForkState,
+        pending: &[ForkState],
+        resolutions: &[Resolution],
+    ) -> Result<(), Box<MemoryLimitError>> {
+        let Some(MemoryLimit(limit)) = self.limit else {
+            return Ok(());
+        };
+
+        // Each fork retains its own copy of the incompatibilities.
+        let incompatibilities = iter::once(fork)
+            .chain(pending)
+            .map(|fork| {
+                fork.pubgrub
+                    .incompatibilities
+                    .values()
+                    .map(Vec::len)
+                    .sum::<usize>()
+            })
+            .sum::<usize>();
+        let incompatibility_bytes = INCOMPATIBILITY_BYTES * incompatibilities as u64;
+
+        let mut state = self.lock();
+        state.update_peak(incompatibility_bytes);
+        let retained = state.retained_bytes() + incompatibility_bytes;
+        if retained <= limit {
+            return Ok(());
+        }
+
+        debug!(
+            "Estimated resolver memory ({}) exceeds the limit ({}); discarding metadata for unreachable versions",
+            DisplayBytes(retained),
+            DisplayBytes(limit)
+        );
+        let shed = state.shed(index, fork, pending, resolutions);
+        debug!("Discarded metadata for {shed} unreachable versions");
+
+        let retained = state.retained_bytes() + incompatibility_bytes;
+        if retained <= limit {
+            return Ok(());
+        }
+
+        let mut packages = state
+            .metadata
+            .iter()
+            .map(|(name, retained)| (name.clone(), retained.len()))
+            .filter(|(_, versions)| *versions > 0)
+            .collect::<Vec<_>>();
+        packages.sort_by(|(a_name, a), (b_name, b)| b.cmp(a).then_with(|| a_name.cmp(b_name)));
+        packages.truncate(REPORTED_PACKAGES);
+
+        Err(Box::new(MemoryLimitError {
+            limit,
+            retained,
+            packages,
+        }))
+    }
+
+    /// Log a summary of the peak memory retained by the resolution.
+    pub(crate) fn log_summary(&self) {
+        if !tracing::enabled!(Level::DEBUG) {
+            return;
+        }
+
+        let state = self.lock();
+        if let Some(MemoryLimit(limit)) = self.limit {
+            debug!(
+                "Peak estimated resolver memory: {} (limit: {})",
+                DisplayBytes(state.peak_bytes),
+                DisplayBytes(limit)
+            );
+        } else {
+            debug!(
+                "Peak estimated resolver memory: {}",
+                DisplayBytes(state.peak_bytes)
+            );
+        }
+        if state.shed > 0 {
+            debug!("Discarded metadata for {} unreachable versions", state.shed);
+        }
+
+        let mut peaks = state.peak_versions.iter().collect::<Vec<_>>();
+        peaks.sort_by(|(a_name, a), (b_name, b)| b.cmp(a).then_with(|| a_name.cmp(b_name)));
+        for (name, versions) in peaks.into_iter().take(REPORTED_PACKAGES) {
+            debug!("Peak retained candidates for {name}: {versions}");
+        }
+    }
+}
+
+impl TrackerState {
+    /// The estimated size of the retained metadata and version maps.
+    fn retained_bytes(&self) -> u64 {
+        self.metadata_bytes + self.versions_bytes
+    }
+
+    fn update_peak(&mut self, incompatibility_bytes: u64) {
+        self.peak_bytes = self
+            .peak_bytes
+            .max(self.retained_bytes() + incompatibility_bytes);
+    }
+
+    /// Discard the metadata for versions that are no longer reachable, returning the number of
+    /// discarded entries.
+    ///
+    /// A version is no longer reachable if it isn't pinned by any fork (or by any completed
+    /// resolution), and the current fork has narrowed the package to a range that excludes it.
+    /// Discarded metadata is removed from the [`InMemoryIndex`], such that it's re-fetched if the
+    /// version is selected again.
+    fn shed(
+        &mut self,
+        index: &InMemoryIndex,
+        fork: &ForkState,
+        pending: &[ForkState],
+        resolutions: &[Resolution],
+    ) -> usize {
+        // Collect the terms for each package in the current fork.
+        let mut terms: FxHashMap<&PackageName, &Term<Range<Version>>> = FxHashMap::default();
+        for id in fork
+            .added_dependencies
+            .keys()
+            .chain(fork.pre_visited.keys())
+        {
+            let PubGrubPackageInner::Package {
+                name,
+                extra: None,
+                group: None,
+                ..
+            } = &*fork.pubgrub.package_store[*id]
+            else {
+                continue;
+            };
+            if let Some(term) = fork
+                .pubgrub
+                .partial_solution
+                .term_intersection_for_package(*id)
+            {
+                terms.insert(name, term);
+            }
+        }
+
+        let is_pinned = |name: &PackageName, version: &Version| {
+            iter::once(&fork.pins)
+                .chain(pending.iter().map(|fork| &fork.pins))
+                .chain(resolutions.iter().map(|resolution| &resolution.pins))
+                .any(|pins| pins.get(name, version).is_some())
+        };
+
+        let mut shed = 0;
+        for (name, retained) in &mut self.metadata {
+            let Some(term) = terms.get(name) else {
+                continue;
+            };
+            retained.retain(|id, metadata| {
+                let reachable = match term {
+                    Term::Positive(range) => range.contains(&metadata.version),
+                    Term::Negative(range) => !range.contains(&metadata.version),
+                };
+                if reachable || is_pinned(name, &metadata.version) {
+                    return true;
+                }
+                index.distributions().remove(id);
+                self.metadata_bytes -= metadata.bytes;
+                shed += 1;
+                false
+            });
+        }
+        self.shed += shed;
+        shed
+    }
+}
+
+/// An error raised when the estimated memory retained by the resolver exceeds the limit set via
+/// `UV_RESOLVER_MEMORY_LIMIT`.
+#[derive(Debug)]
+pub struct MemoryLimitError {
+    limit: u64,
+    retained: u64,
+    /// The packages with the most retained versions, in descending order.
+    packages: Vec<(PackageName, usize)>,
+}
+
+impl std::error::Error for MemoryLimitError {}
+
+impl Display for MemoryLimitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "The resolver exceeded its memory limit of {} (set via `{}`), retaining an estimated {} after discarding the metadata for unreachable versions",
+            DisplayBytes(self.limit),
+            EnvVars::UV_RESOLVER_MEMORY_LIMIT,
+            DisplayBytes(self.retained)
+        )?;
+        if !self.packages.is_empty() {
+            write!(f, "\n\nThe packages with the most retained versions are:")?;
+            for (name, versions) in &self.packages {
+                write!(f, "\n- {name} ({versions} versions)")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Display a size in bytes in a human-readable format.
+struct DisplayBytes(u64);
+
+impl Display for DisplayBytes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (bytes, unit) = human_readable_bytes(self.0);
+        write!(f, "{bytes:.1}{unit}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_bytes;
+
+    #[test]
+    fn parse_memory_limit() {
+        assert_eq!(parse_bytes("3000000000"), Some(3_000_000_000));
+        assert_eq!(parse_bytes("512MB"), Some(512_000_000));
+        assert_eq!(parse_bytes("3GiB"), Some(3 * 1024 * 1024 * 1024));
+        assert_eq!(parse_bytes(" 4 gib "), Some(4 * 1024 * 1024 * 1024));
+        assert_eq!(parse_bytes("0"), None);
+        assert_eq!(parse_bytes("GiB"), None);
+        assert_eq!(parse_bytes("3TB"), None);
+        assert_eq!(parse_bytes("1.5GiB"), None);
+    }
+}
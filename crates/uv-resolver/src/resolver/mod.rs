@@ -42,6 +42,8 @@ use uv_types::{BuildContext, HashStrategy, InstalledPackagesProvider};
 use uv_warnings::warn_user_once;
 
 use crate::candidate_selector::{Candidate, CandidateDist, CandidateSelector};
+use crate::checkpoint::Checkpoint;
+use crate::dependency_cache::DependencyCache;
 use crate::dependency_provider::UvDependencyProvider;
 use crate::error::{NoSolutionError, ResolveError, derivation_tree_packages};
 use crate::fork_indexes::ForkIndexes;
@@ -70,6 +72,8 @@ use crate::resolver::environment::{
 pub(crate) use crate::resolver::fork_map::{ForkMap, ForkSet};
 pub use crate::resolver::index::InMemoryIndex;
 use crate::resolver::indexes::Indexes;
+pub use crate::resolver::memory::MemoryLimitError;
+use crate::resolver::memory::{MemoryLimit, MemoryTracker};
 pub use crate::resolver::provider::{
     DefaultResolverProvider, MetadataResponse, PackageVersionsResult, ResolverProvider,
     VersionsResponse, WheelMetadataResult,
@@ -89,6 +93,7 @@ mod environment;
 mod fork_map;
 mod index;
 mod indexes;
+mod memory;
 mod provider;
 mod reporter;
 mod system;
@@ -97,6 +102,9 @@ mod urls;
 /// The number of conflicts a package may accumulate before we re-prioritize and backtrack.
 const CONFLICT_THRESHOLD: usize = 5;
 
+/// The number of solver iterations between checks of the memory retained by the resolver.
+const MEMORY_CHECK_INTERVAL: usize = 64;
+
 pub struct Resolver<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider> {
     state: ResolverState<InstalledPackages>,
     provider: Provider,
@@ -136,6 +144,10 @@ struct ResolverState<InstalledPackages: InstalledPackagesProvider> {
     incomplete_packages: Box<HashMap<PackageName, HashMap<Version, MetadataUnavailable>>>,
     /// The options that were used to configure this resolver.
     options: Options,
+    /// The dependencies of registry wheels persisted across resolutions, if enabled.
+    dependency_cache: Option<DependencyCache>,
+    /// The (estimated) memory retained by the resolution, and the limit, if any.
+    memory: MemoryTracker,
     /// The reporter to use for this resolver.
     reporter: Option<Arc<dyn Reporter>>,
 }
@@ -184,12 +196,17 @@ impl<'a, Context: BuildContext, InstalledPackages: InstalledPackagesProvider>
             AllowedYanks::from_manifest(&manifest, &env, options.dependency_mode),
             hasher,
             options.exclude_newer.clone(),
+            options.require_metadata,
             build_context.locations(),
             build_context.build_options(),
             build_context.capabilities(),
         );
 
-        Ok(Self::new_custom_io(
+        let dependency_cache = options.dependency_cache.clone().and_then(|path| {
+            DependencyCache::read(path, &options, build_context.dependency_metadata())
+        });
+
+        let resolver = Self::new_custom_io(
             manifest,
             options,
             hasher,
@@ -204,7 +221,15 @@ impl<'a, Context: BuildContext, InstalledPackages: InstalledPackagesProvider>
             build_context.locations(),
             provider,
             installed_packages,
-        ))
+        );
+
+        Ok(Self {
+            state: ResolverState {
+                dependency_cache,
+                ..resolver.state
+            },
+            provider: resolver.provider,
+        })
     }
 }
 
@@ -255,6 +280,8 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
             unavailable_packages: Box::default(),
             incomplete_packages: Box::default(),
             options,
+            dependency_cache: None,
+            memory: MemoryTracker::new(MemoryLimit::from_env()),
             reporter: None,
         };
         Self { state, provider }
@@ -303,9 +330,16 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
         let resolve_fut = async move { rx.await.map_err(|_| ResolveError::ChannelClosed) };
 
         // Wait for both to complete.
-        let ((), resolution) = tokio::try_join!(requests_fut, resolve_fut)?;
+        let result = tokio::try_join!(requests_fut, resolve_fut);
+        state.memory.log_summary();
+        let ((), resolution) = result?;
 
         state.on_complete();
+        if resolution.is_ok() {
+            if let Some(dependency_cache) = &state.dependency_cache {
+                dependency_cache.write();
+            }
+        }
         resolution
     }
 }
@@ -346,6 +380,8 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         let mut preferences = self.preferences.clone();
         let mut forked_states = self.env.initial_forked_states(state)?;
         let mut resolutions = vec![];
+        let mut checkpoint = Checkpoint::default();
+        let mut iterations = 0usize;
 
         'FORK: while let Some(mut state) = forked_states.pop() {
             if let Some(split) = state.env.end_user_fork_display() {
@@ -354,6 +390,13 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             }
             let start = Instant::now();
             loop {
+                // Periodically check the memory retained by the resolution against the limit.
+                iterations += 1;
+                if iterations.is_multiple_of(MEMORY_CHECK_INTERVAL) {
+                    self.memory
+                        .check(&self.index, &state, &forked_states, &resolutions)?;
+                }
+
                 let highest_priority_pkg =
                     if let Some(initial) = state.initial_id.take() {
                         // If we just forked based on `requires-python`, we can skip unit
@@ -458,6 +501,13 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                                 }
                             }
 
+                            // Persist the versions selected by this fork, such that they can be
+                            // reused if a subsequent fork fails.
+                            if let Some(path) = &self.options.checkpoint {
+                                checkpoint.extend(&resolution);
+                                checkpoint.write(path);
+                            }
+
                             resolutions.push(resolution);
                             continue 'FORK;
                         };
@@ -1388,6 +1438,31 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                     )),
                 )));
             }
+
+            // If the wheel can't be installed in any of the environments in which the URL is used
+            // (e.g., a Windows-only wheel provided by a source gated on `sys_platform == 'linux'`),
+            // the source is misconfigured. Wheels for other platforms are fine, as long as the
+            // markers under which they're used overlap with the wheel's platform tags. (If none of
+            // the wheel's tags are recognized, the implied markers are empty, and we can't tell.)
+            if let Some(fork_markers) = env.fork_markers() {
+                let mut required = find_environments(id, pubgrub);
+                required.and(fork_markers);
+                let supported = implied_markers(filename);
+                if !required.is_false() && !supported.is_false() && supported.is_disjoint(required)
+                {
+                    return Err(ResolveError::IncompatibleWheelSource {
+                        package_name: name.clone(),
+                        url: url.verbatim.to_string(),
+                        filename: filename.to_string(),
+                        required: required
+                            .try_to_string()
+                            .unwrap_or_else(|| "true".to_string()),
+                        supported: supported
+                            .try_to_string()
+                            .unwrap_or_else(|| "true".to_string()),
+                    });
+                }
+            }
         }
 
         // The version is incompatible due to its `Requires-Python` requirement.
@@ -2546,6 +2621,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             match response? {
                 Some(Response::Package(name, index, version_map)) => {
                     trace!("Received package metadata for: {name}");
+                    self.memory.record_versions(&version_map);
                     if let Some(index) = index {
                         self.index
                             .explicit()
@@ -2575,6 +2651,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                             warn!("{dist} {message}");
                         }
                     }
+                    self.memory.record_metadata(&dist, &metadata);
                     self.index
                         .distributions()
                         .done(dist.distribution_id(), Arc::new(metadata));
@@ -2657,10 +2734,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                     }
                 }
 
-                let metadata = provider
-                    .get_or_build_wheel_metadata(&dist)
-                    .boxed_local()
-                    .await?;
+                let metadata = self.get_or_build_wheel_metadata(&dist, provider).await?;
 
                 if let MetadataResponse::Found(metadata) = &metadata {
                     if &metadata.metadata.name != dist.name() {
@@ -2842,10 +2916,8 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
 
                     let response = match dist {
                         ResolvedDist::Installable { dist, .. } => {
-                            let metadata = provider
-                                .get_or_build_wheel_metadata(&dist)
-                                .boxed_local()
-                                .await?;
+                            let metadata =
+                                self.get_or_build_wheel_metadata(&dist, provider).await?;
 
                             Response::Dist {
                                 dist: (*dist).clone(),
@@ -2871,6 +2943,34 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         }
     }
 
+    /// Fetch the metadata for a distribution, reading from and populating the dependency cache,
+    /// if enabled.
+    async fn get_or_build_wheel_metadata<Provider: ResolverProvider>(
+        &self,
+        dist: &Dist,
+        provider: &Provider,
+    ) -> Result<MetadataResponse, ResolveError> {
+        if let Some(dependency_cache) = &self.dependency_cache {
+            if let Some(metadata) = dependency_cache.get(dist) {
+                debug!("Using cached dependencies for: {dist}");
+                return Ok(MetadataResponse::Found(metadata));
+            }
+        }
+
+        let metadata = provider
+            .get_or_build_wheel_metadata(dist)
+            .boxed_local()
+            .await?;
+
+        if let Some(dependency_cache) = &self.dependency_cache {
+            if let MetadataResponse::Found(metadata) = &metadata {
+                dependency_cache.insert(dist, metadata);
+            }
+        }
+
+        Ok(metadata)
+    }
+
     fn convert_no_solution_err(
         &self,
         mut err: pubgrub::NoSolutionError<UvDependencyProvider>,
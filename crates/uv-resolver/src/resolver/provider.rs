@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::future::Future;
 use std::sync::Arc;
 
@@ -8,11 +9,12 @@ use uv_configuration::BuildOptions;
 use uv_distribution::{ArchiveMetadata, DistributionDatabase, Reporter};
 use uv_distribution_types::{
     Dist, IndexCapabilities, IndexLocations, IndexMetadata, IndexMetadataRef, InstalledDist,
-    RequestedDist, RequiresPython,
+    RemoteSource, RequestedDist, RequiresPython, SourceDist,
 };
 use uv_normalize::PackageName;
 use uv_pep440::{Version, VersionSpecifiers};
 use uv_platform_tags::Tags;
+use uv_pypi_types::MetadataVersion;
 use uv_static::EnvVars;
 use uv_types::{BuildContext, HashStrategy};
 
@@ -66,6 +68,12 @@ pub enum MetadataUnavailable {
     RequiresPython(VersionSpecifiers, Version),
     /// The wheel metadata could not be fetched due to a network error.
     Network(StatusCode),
+    /// The distribution declares a core metadata version below the required minimum.
+    MetadataVersion {
+        artifact: String,
+        found: MetadataVersion,
+        required: MetadataVersion,
+    },
 }
 
 impl MetadataUnavailable {
@@ -77,7 +85,7 @@ impl MetadataUnavailable {
             Self::InvalidMetadata(err) => Some(err),
             Self::InconsistentMetadata(err) => Some(err),
             Self::InvalidStructure(err) => Some(err),
-            Self::RequiresPython(..) | Self::Network(..) => None,
+            Self::RequiresPython(..) | Self::Network(..) | Self::MetadataVersion { .. } => None,
         }
     }
 }
@@ -123,6 +131,7 @@ pub struct DefaultResolverProvider<'a, Context: BuildContext> {
     allowed_yanks: AllowedYanks,
     hasher: HashStrategy,
     exclude_newer: ExcludeNewer,
+    require_metadata: Option<MetadataVersion>,
     available_version_cutoff: Option<jiff::Timestamp>,
     index_locations: &'a IndexLocations,
     build_options: &'a BuildOptions,
@@ -139,6 +148,7 @@ impl<'a, Context: BuildContext> DefaultResolverProvider<'a, Context> {
         allowed_yanks: AllowedYanks,
         hasher: &'a HashStrategy,
         exclude_newer: ExcludeNewer,
+        require_metadata: Option<MetadataVersion>,
         index_locations: &'a IndexLocations,
         build_options: &'a BuildOptions,
         capabilities: &'a IndexCapabilities,
@@ -151,6 +161,7 @@ impl<'a, Context: BuildContext> DefaultResolverProvider<'a, Context> {
             allowed_yanks,
             hasher: hasher.clone(),
             exclude_newer,
+            require_metadata,
             available_version_cutoff: std::env::var(EnvVars::UV_TEST_AVAILABLE_VERSION_CUTOFF)
                 .ok()
                 .and_then(|value| value.parse().ok()),
@@ -170,6 +181,39 @@ impl<'a, Context: BuildContext> DefaultResolverProvider<'a, Context> {
             self.index_locations.exclude_newer_for(index),
         )
     }
+
+    /// Return the [`MetadataUnavailable`] reason if the distribution declares a core metadata
+    /// version below the required minimum.
+    ///
+    /// Distributions that are built from a Git repository or a local directory are exempt, as are
+    /// distributions whose metadata version is unknown (e.g., static `pyproject.toml` metadata).
+    fn check_metadata_version(
+        &self,
+        dist: &Dist,
+        metadata: &ArchiveMetadata,
+    ) -> Option<MetadataUnavailable> {
+        let required = self.require_metadata?;
+        let found = metadata.metadata.metadata_version?;
+        if found >= required {
+            return None;
+        }
+        if matches!(
+            dist,
+            Dist::Source(
+                SourceDist::GitDirectory(_) | SourceDist::GitPath(_) | SourceDist::Directory(_)
+            )
+        ) {
+            return None;
+        }
+        let artifact = dist
+            .filename()
+            .map_or_else(|_| dist.to_string(), Cow::into_owned);
+        Some(MetadataUnavailable::MetadataVersion {
+            artifact,
+            found,
+            required,
+        })
+    }
 }
 
 impl<Context: BuildContext> ResolverProvider for DefaultResolverProvider<'_, Context> {
@@ -278,7 +322,12 @@ impl<Context: BuildContext> ResolverProvider for DefaultResolverProvider<'_, Con
             .get_or_build_wheel_metadata(dist, self.hasher.get(dist))
             .await
         {
-            Ok(metadata) => Ok(MetadataResponse::Found(metadata)),
+            Ok(metadata) => {
+                if let Some(reason) = self.check_metadata_version(dist, &metadata) {
+                    return Ok(MetadataResponse::Unavailable(reason));
+                }
+                Ok(MetadataResponse::Found(metadata))
+            }
             Err(err) => match err {
                 uv_distribution::Error::Client(client) => {
                     let retries = client.retries();
@@ -1,4 +1,5 @@
 use std::sync::Arc;
+use std::time::Duration;
 
 use uv_distribution_types::{BuildableSource, VersionOrUrlRef};
 use uv_normalize::PackageName;
@@ -17,6 +18,12 @@ pub trait Reporter: Send + Sync {
     /// Callback to invoke when a source distribution build is complete.
     fn on_build_complete(&self, source: &BuildableSource, id: usize);
 
+    /// Callback to invoke when a source distribution build emits a line of output.
+    fn on_build_output(&self, id: usize, line: &str);
+
+    /// Callback to invoke periodically while a source distribution build is running.
+    fn on_build_progress(&self, id: usize, elapsed: Duration);
+
     /// Callback to invoke when a download is kicked off.
     fn on_download_start(&self, name: &PackageName, size: Option<u64>) -> usize;
 
@@ -57,6 +64,14 @@ impl uv_distribution::Reporter for Facade {
         self.reporter.on_build_complete(source, id);
     }
 
+    fn on_build_output(&self, id: usize, line: &str) {
+        self.reporter.on_build_output(id, line);
+    }
+
+    fn on_build_progress(&self, id: usize, elapsed: Duration) {
+        self.reporter.on_build_progress(id, elapsed);
+    }
+
     fn on_checkout_start(&self, url: &DisplaySafeUrl, rev: &str) -> usize {
         self.reporter.on_checkout_start(url, rev)
     }
@@ -323,16 +323,19 @@ impl Pep723Script {
         Ok(fs_err::tokio::write(file, script).await?)
     }
 
-    /// Replace the existing metadata in the file with new metadata and write the updated content.
-    pub fn write(&self, metadata: &str) -> Result<(), io::Error> {
-        let content = format!(
+    /// Render the content of the file with the existing metadata replaced by new metadata.
+    pub fn render(&self, metadata: &str) -> String {
+        format!(
             "{}{}{}",
             self.prelude,
             serialize_metadata(metadata),
             self.postlude
-        );
+        )
+    }
 
-        fs_err::write(&self.path, content)?;
+    /// Replace the existing metadata in the file with new metadata and write the updated content.
+    pub fn write(&self, metadata: &str) -> Result<(), io::Error> {
+        fs_err::write(&self.path, self.render(metadata))?;
 
         Ok(())
     }
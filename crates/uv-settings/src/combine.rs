@@ -12,8 +12,8 @@ use uv_distribution_types::{
     PipFindLinks, PipIndex,
 };
 use uv_install_wheel::LinkMode;
-use uv_pypi_types::{SchemaConflicts, SupportedEnvironments};
-use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
+use uv_pypi_types::{MetadataVersion, SchemaConflicts, SupportedEnvironments};
+use uv_python::{PythonDownloads, PythonPreference, PythonVersion, WindowsArm64Fallback};
 use uv_redacted::DisplaySafeUrl;
 use uv_resolver::{
     AnnotationStyle, ExcludeNewer, ExcludeNewerOverride, ExcludeNewerPackage, ExcludeNewerValue,
@@ -103,6 +103,7 @@ impl_combine_or!(IndexStrategy);
 impl_combine_or!(IndexUrl);
 impl_combine_or!(KeyringProviderType);
 impl_combine_or!(LinkMode);
+impl_combine_or!(MetadataVersion);
 impl_combine_or!(DisplaySafeUrl);
 impl_combine_or!(NonZeroUsize);
 impl_combine_or!(PathBuf);
@@ -124,6 +125,7 @@ impl_combine_or!(TargetTriple);
 impl_combine_or!(TorchMode);
 impl_combine_or!(TrustedPublishing);
 impl_combine_or!(Url);
+impl_combine_or!(WindowsArm64Fallback);
 impl_combine_or!(bool);
 
 impl<T> Combine for Option<Vec<T>> {
@@ -18,9 +18,11 @@ use uv_torch::AmdGpuArchitecture;
 use uv_warnings::warn_user;
 
 pub use crate::combine::*;
+pub use crate::lint::*;
 pub use crate::settings::*;
 
 mod combine;
+mod lint;
 mod settings;
 
 /// The [`Options`] as loaded from a configuration file on disk.
@@ -309,6 +311,14 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
         dependency_groups,
         managed,
         package,
+        static_dependencies,
+        scripts,
+        post_sync,
+        strict_config,
+        no_editable_package,
+        lock_provenance,
+        project_environment,
+        workspace_pin_policy,
         build_backend,
     } = options;
     // The `uv.toml` format is not allowed to include any of the following, which are
@@ -347,6 +357,48 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
     if package.is_some() {
         return Err(Error::PyprojectOnlyField(path.to_path_buf(), "package"));
     }
+    if static_dependencies.is_some() {
+        return Err(Error::PyprojectOnlyField(
+            path.to_path_buf(),
+            "static-dependencies",
+        ));
+    }
+    if scripts.is_some() {
+        return Err(Error::PyprojectOnlyField(path.to_path_buf(), "scripts"));
+    }
+    if post_sync.is_some() {
+        return Err(Error::PyprojectOnlyField(path.to_path_buf(), "post-sync"));
+    }
+    if strict_config.is_some() {
+        return Err(Error::PyprojectOnlyField(
+            path.to_path_buf(),
+            "strict-config",
+        ));
+    }
+    if no_editable_package.is_some() {
+        return Err(Error::PyprojectOnlyField(
+            path.to_path_buf(),
+            "no-editable-package",
+        ));
+    }
+    if lock_provenance.is_some() {
+        return Err(Error::PyprojectOnlyField(
+            path.to_path_buf(),
+            "lock-provenance",
+        ));
+    }
+    if project_environment.is_some() {
+        return Err(Error::PyprojectOnlyField(
+            path.to_path_buf(),
+            "project-environment",
+        ));
+    }
+    if workspace_pin_policy.is_some() {
+        return Err(Error::PyprojectOnlyField(
+            path.to_path_buf(),
+            "workspace-pin-policy",
+        ));
+    }
     if build_backend.is_some() {
         return Err(Error::PyprojectOnlyField(
             path.to_path_buf(),
@@ -385,6 +437,7 @@ fn warn_uv_toml_masked_fields(options: &Options) {
                 preview,
                 python_preference,
                 python_downloads,
+                windows_arm64_fallback,
                 concurrent_downloads,
                 concurrent_builds,
                 concurrent_installs,
@@ -405,6 +458,8 @@ fn warn_uv_toml_masked_fields(options: &Options) {
                 resolution,
                 prerelease,
                 fork_strategy,
+                prerelease_packages,
+                require_metadata,
                 dependency_metadata,
                 config_settings,
                 config_settings_package,
@@ -412,6 +467,8 @@ fn warn_uv_toml_masked_fields(options: &Options) {
                 no_build_isolation_package,
                 extra_build_dependencies,
                 extra_build_variables,
+                build_env_passthrough,
+                build_env_passthrough_package,
                 exclude_newer,
                 exclude_newer_package,
                 link_mode,
@@ -458,6 +515,14 @@ fn warn_uv_toml_masked_fields(options: &Options) {
         dependency_groups: _,
         managed: _,
         package: _,
+        static_dependencies: _,
+        scripts: _,
+        post_sync: _,
+        strict_config: _,
+        no_editable_package: _,
+        lock_provenance: _,
+        project_environment: _,
+        workspace_pin_policy: _,
         build_backend: _,
     } = options;
 
@@ -492,6 +557,9 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if python_downloads.is_some() {
         masked_fields.push("python-downloads");
     }
+    if windows_arm64_fallback.is_some() {
+        masked_fields.push("windows-arm64-fallback");
+    }
     if concurrent_downloads.is_some() {
         masked_fields.push("concurrent-downloads");
     }
@@ -543,6 +611,12 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if fork_strategy.is_some() {
         masked_fields.push("fork-strategy");
     }
+    if prerelease_packages.is_some() {
+        masked_fields.push("prerelease-packages");
+    }
+    if require_metadata.is_some() {
+        masked_fields.push("require-metadata");
+    }
     if dependency_metadata.is_some() {
         masked_fields.push("dependency-metadata");
     }
@@ -564,6 +638,12 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if extra_build_variables.is_some() {
         masked_fields.push("extra-build-variables");
     }
+    if build_env_passthrough.is_some() {
+        masked_fields.push("build-env-passthrough");
+    }
+    if build_env_passthrough_package.is_some() {
+        masked_fields.push("build-env-passthrough-package");
+    }
     if exclude_newer.is_some() {
         masked_fields.push("exclude-newer");
     }
@@ -720,6 +800,8 @@ pub struct EnvironmentOptions {
     pub ruff_path: Option<PathBuf>,
     pub ty_path: Option<PathBuf>,
     pub skip_wheel_filename_check: Option<bool>,
+    pub skip_editable_metadata_check: Option<bool>,
+    pub no_scripts_path_notice: Option<bool>,
     pub hide_build_output: Option<bool>,
     pub python_install_bin: Option<bool>,
     pub python_install_registry: Option<bool>,
@@ -735,6 +817,7 @@ pub struct EnvironmentOptions {
     /// timeout.
     pub http_read_timeout_upload: Duration,
     pub http_retries: u32,
+    pub max_simple_response_size: u64,
     pub concurrency: Concurrency,
     #[cfg(feature = "tracing-durations-export")]
     pub tracing_durations_file: Option<PathBuf>,
@@ -742,6 +825,7 @@ pub struct EnvironmentOptions {
     pub locked: EnvFlag,
     pub offline: EnvFlag,
     pub no_sync: EnvFlag,
+    pub no_post_sync: EnvFlag,
     pub managed_python: EnvFlag,
     pub no_managed_python: EnvFlag,
     pub native_tls: EnvFlag,
@@ -798,6 +882,12 @@ impl EnvironmentOptions {
             skip_wheel_filename_check: parse_boolish_environment_variable(
                 EnvVars::UV_SKIP_WHEEL_FILENAME_CHECK,
             )?,
+            skip_editable_metadata_check: parse_boolish_environment_variable(
+                EnvVars::UV_SKIP_EDITABLE_METADATA_CHECK,
+            )?,
+            no_scripts_path_notice: parse_boolish_environment_variable(
+                EnvVars::UV_NO_SCRIPTS_PATH_NOTICE,
+            )?,
             hide_build_output: parse_boolish_environment_variable(EnvVars::UV_HIDE_BUILD_OUTPUT)?,
             python_install_bin: parse_boolish_environment_variable(EnvVars::UV_PYTHON_INSTALL_BIN)?,
             python_install_registry: parse_boolish_environment_variable(
@@ -856,6 +946,11 @@ impl EnvironmentOptions {
             .unwrap_or(DEFAULT_CONNECT_TIMEOUT),
             http_retries: parse_integer_environment_variable(EnvVars::UV_HTTP_RETRIES, None)?
                 .unwrap_or(uv_client::DEFAULT_RETRIES),
+            max_simple_response_size: parse_integer_environment_variable(
+                EnvVars::UV_MAX_SIMPLE_RESPONSE_SIZE,
+                Some("value should be an integer number of bytes"),
+            )?
+            .unwrap_or(uv_client::DEFAULT_MAX_SIMPLE_RESPONSE_SIZE),
             #[cfg(feature = "tracing-durations-export")]
             tracing_durations_file: parse_path_environment_variable(
                 EnvVars::TRACING_DURATIONS_FILE,
@@ -864,6 +959,7 @@ impl EnvironmentOptions {
             locked: EnvFlag::new(EnvVars::UV_LOCKED)?,
             offline: EnvFlag::new(EnvVars::UV_OFFLINE)?,
             no_sync: EnvFlag::new(EnvVars::UV_NO_SYNC)?,
+            no_post_sync: EnvFlag::new(EnvVars::UV_NO_POST_SYNC)?,
             managed_python: EnvFlag::new(EnvVars::UV_MANAGED_PYTHON)?,
             no_managed_python: EnvFlag::new(EnvVars::UV_NO_MANAGED_PYTHON)?,
             native_tls: EnvFlag::new(EnvVars::UV_NATIVE_TLS)?,
@@ -1069,6 +1165,12 @@ impl From<&EnvironmentOptions> for EnvironmentFlags {
         if options.hide_build_output == Some(true) {
             flags.insert(Self::HIDE_BUILD_OUTPUT);
         }
+        if options.skip_editable_metadata_check == Some(true) {
+            flags.insert(Self::SKIP_EDITABLE_METADATA_CHECK);
+        }
+        if options.no_scripts_path_notice == Some(true) {
+            flags.insert(Self::NO_SCRIPTS_PATH_NOTICE);
+        }
         flags
     }
 }
@@ -0,0 +1,793 @@
+//! Lint the configuration files (and environment variables) that apply to a directory.
+//!
+//! Unlike [`FilesystemOptions`](crate::FilesystemOptions), the linter operates on the raw TOML
+//! document, such that it can report every issue in a file (rather than failing on the first
+//! unknown key), along with the line at which the issue occurs.
+
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use toml_edit::{Document, Item, Key, TableLike, Value};
+
+use uv_dirs::{system_config_file, user_uv_config_dir};
+use uv_fs::Simplified;
+use uv_options_metadata::{OptionField, OptionSet, OptionsMetadata, Visit};
+use uv_static::{EnvVars, parse_boolish_environment_variable};
+use uv_workspace::pyproject::ToolUv;
+
+use crate::Options;
+
+/// Settings that have been superseded, beyond those marked as deprecated in the options metadata,
+/// along with their replacements.
+const MIGRATIONS: &[(&str, &str)] = &[
+    (
+        "dev-dependencies",
+        "`dependency-groups.dev` (in the `[dependency-groups]` table)",
+    ),
+    ("index-url", "an `[[index]]` entry with `default = true`"),
+    ("extra-index-url", "`[[index]]` entries"),
+    ("preview", "`preview-features`"),
+];
+
+/// Settings that are only respected in `pyproject.toml` files.
+///
+/// This should be kept in-sync with the fields rejected by `validate_uv_toml`.
+const PYPROJECT_ONLY_FIELDS: &[&str] = &[
+    "build-backend",
+    "conflicts",
+    "default-groups",
+    "dependency-groups",
+    "dev-dependencies",
+    "environments",
+    "lock-provenance",
+    "managed",
+    "no-editable-package",
+    "package",
+    "post-sync",
+    "project-environment",
+    "required-environments",
+    "scripts",
+    "sources",
+    "static-dependencies",
+    "strict-config",
+    "workspace",
+    "workspace-pin-policy",
+];
+
+/// Environment variables that take precedence over a setting in a configuration file.
+const ENVIRONMENT_OVERRIDES: &[(&str, &str)] = &[
+    (EnvVars::UV_CACHE_DIR, "cache-dir"),
+    (EnvVars::UV_NO_CACHE, "no-cache"),
+    (EnvVars::UV_OFFLINE, "offline"),
+    (EnvVars::UV_SYSTEM_CERTS, "system-certs"),
+    (EnvVars::UV_PYTHON_PREFERENCE, "python-preference"),
+    (EnvVars::UV_PYTHON_DOWNLOADS, "python-downloads"),
+    (EnvVars::UV_PYTHON_INSTALL_MIRROR, "python-install-mirror"),
+    (EnvVars::UV_PYPY_INSTALL_MIRROR, "pypy-install-mirror"),
+    (
+        EnvVars::UV_PYTHON_DOWNLOADS_JSON_URL,
+        "python-downloads-json-url",
+    ),
+    (EnvVars::UV_CONCURRENT_DOWNLOADS, "concurrent-downloads"),
+    (EnvVars::UV_CONCURRENT_BUILDS, "concurrent-builds"),
+    (EnvVars::UV_CONCURRENT_INSTALLS, "concurrent-installs"),
+    (EnvVars::UV_INDEX_STRATEGY, "index-strategy"),
+    (EnvVars::UV_KEYRING_PROVIDER, "keyring-provider"),
+    (EnvVars::UV_RESOLUTION, "resolution"),
+    (EnvVars::UV_PRERELEASE, "prerelease"),
+    (EnvVars::UV_FORK_STRATEGY, "fork-strategy"),
+    (EnvVars::UV_EXCLUDE_NEWER, "exclude-newer"),
+    (EnvVars::UV_LINK_MODE, "link-mode"),
+    (EnvVars::UV_COMPILE_BYTECODE, "compile-bytecode"),
+    (EnvVars::UV_NO_BUILD_ISOLATION, "no-build-isolation"),
+    (EnvVars::UV_NO_BUILD, "no-build"),
+    (EnvVars::UV_NO_BINARY, "no-binary"),
+    (EnvVars::UV_NO_SOURCES, "no-sources"),
+    (EnvVars::UV_TORCH_BACKEND, "torch-backend"),
+    (EnvVars::UV_PUBLISH_URL, "publish-url"),
+];
+
+/// Environment variables that have been superseded, along with their replacements.
+const DEPRECATED_ENVIRONMENT_VARIABLES: &[(&str, &str)] = &[
+    (EnvVars::UV_NATIVE_TLS, EnvVars::UV_SYSTEM_CERTS),
+    (EnvVars::UV_INDEX_URL, EnvVars::UV_DEFAULT_INDEX),
+    (EnvVars::UV_EXTRA_INDEX_URL, EnvVars::UV_INDEX),
+];
+
+/// The kind of a [`ConfigSource`], in decreasing order of precedence.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ConfigSourceKind {
+    /// A file provided via `--config-file` (or `UV_CONFIG_FILE`).
+    ConfigFile,
+    /// The nearest `uv.toml` or `pyproject.toml` (with a `[tool.uv]` table).
+    Project,
+    /// The user-level `uv.toml`.
+    User,
+    /// The system-level `uv.toml`.
+    System,
+}
+
+impl Display for ConfigSourceKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ConfigFile => f.write_str("`--config-file`"),
+            Self::Project => f.write_str("project"),
+            Self::User => f.write_str("user"),
+            Self::System => f.write_str("system"),
+        }
+    }
+}
+
+/// A configuration file that applies to an invocation.
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigSource {
+    kind: ConfigSourceKind,
+    path: PathBuf,
+    /// Whether the file is ignored, as it's a `pyproject.toml` alongside a `uv.toml`.
+    masked: bool,
+}
+
+impl ConfigSource {
+    /// Create a [`ConfigSource`] for a file provided via `--config-file`.
+    pub fn from_config_file(path: impl Into<PathBuf>) -> Self {
+        Self {
+            kind: ConfigSourceKind::ConfigFile,
+            path: path.into(),
+            masked: false,
+        }
+    }
+
+    /// Discover the configuration files that apply to the given directory, in decreasing order
+    /// of precedence.
+    ///
+    /// Follows the same discovery as [`FilesystemOptions::find`](crate::FilesystemOptions::find),
+    /// [`FilesystemOptions::user`](crate::FilesystemOptions::user), and
+    /// [`FilesystemOptions::system`](crate::FilesystemOptions::system), but includes files that
+    /// would fail to load, such that they can be linted.
+    pub fn discover(dir: &Path) -> Vec<Self> {
+        let mut sources = Vec::new();
+
+        for ancestor in dir.ancestors() {
+            let uv_toml = ancestor.join("uv.toml");
+            let pyproject_toml = ancestor.join("pyproject.toml");
+            let has_tool_uv = has_tool_uv(&pyproject_toml);
+            if uv_toml.is_file() {
+                sources.push(Self {
+                    kind: ConfigSourceKind::Project,
+                    path: uv_toml,
+                    masked: false,
+                });
+                if has_tool_uv {
+                    sources.push(Self {
+                        kind: ConfigSourceKind::Project,
+                        path: pyproject_toml,
+                        masked: true,
+                    });
+                }
+                break;
+            }
+            if has_tool_uv {
+                sources.push(Self {
+                    kind: ConfigSourceKind::Project,
+                    path: pyproject_toml,
+                    masked: false,
+                });
+                break;
+            }
+        }
+
+        if let Some(file) = user_uv_config_dir()
+            .map(|dir| dir.join("uv.toml"))
+            .filter(|file| file.is_file())
+        {
+            sources.push(Self {
+                kind: ConfigSourceKind::User,
+                path: file,
+                masked: false,
+            });
+        }
+
+        if parse_boolish_environment_variable(EnvVars::UV_NO_SYSTEM_CONFIG)
+            .ok()
+            .flatten()
+            != Some(true)
+            && let Some(file) = system_config_file()
+        {
+            sources.push(Self {
+                kind: ConfigSourceKind::System,
+                path: file,
+                masked: false,
+            });
+        }
+
+        sources
+    }
+
+    /// Return the [`ConfigSourceKind`] of the source.
+    pub fn kind(&self) -> ConfigSourceKind {
+        self.kind
+    }
+
+    /// Return the path to the configuration file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Return `true` if the file is ignored, as it's masked by a `uv.toml` in the same directory.
+    pub fn is_masked(&self) -> bool {
+        self.masked
+    }
+
+    /// Return `true` if the source is a `pyproject.toml` file.
+    fn is_pyproject(&self) -> bool {
+        self.path
+            .file_name()
+            .is_some_and(|name| name == "pyproject.toml")
+    }
+}
+
+/// Returns `true` if the file at the given path is a `pyproject.toml` with a `[tool.uv]` table.
+fn has_tool_uv(path: &Path) -> bool {
+    let Ok(content) = fs_err::read_to_string(path) else {
+        return false;
+    };
+    let Ok(document) = Document::parse(content.as_str()) else {
+        return false;
+    };
+    document
+        .get("tool")
+        .and_then(Item::as_table_like)
+        .is_some_and(|tool| tool.contains_key("uv"))
+}
+
+/// The severity of a [`LintDiagnostic`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LintSeverity {
+    /// The configuration will be rejected.
+    Error,
+    /// The configuration is accepted, but likely doesn't behave as intended.
+    Warning,
+}
+
+impl Display for LintSeverity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Error => f.write_str("error"),
+            Self::Warning => f.write_str("warning"),
+        }
+    }
+}
+
+/// The kind of issue reported by a [`LintDiagnostic`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LintCode {
+    /// The file could not be read, or is not valid TOML.
+    InvalidFile,
+    /// The key is not a known setting.
+    UnknownKey,
+    /// The key has been superseded by another setting.
+    DeprecatedKey,
+    /// The key is only respected in `pyproject.toml` files, but was set in a `uv.toml`.
+    PyprojectOnlyKey,
+    /// The key is only respected in `uv.toml` files, but was set in a `pyproject.toml`.
+    UvTomlOnlyKey,
+    /// The `[tool.uv]` table is ignored, as a `uv.toml` exists in the same directory.
+    MaskedTable,
+    /// The value is overridden by a source with higher precedence.
+    OverriddenValue,
+    /// The environment variable has been superseded by another.
+    DeprecatedEnvironmentVariable,
+    /// The value is accepted, but is likely a mistake.
+    SuspectValue,
+}
+
+impl LintCode {
+    /// Return the [`LintSeverity`] of the code.
+    pub fn severity(self) -> LintSeverity {
+        match self {
+            Self::InvalidFile | Self::UnknownKey | Self::PyprojectOnlyKey => LintSeverity::Error,
+            Self::DeprecatedKey
+            | Self::UvTomlOnlyKey
+            | Self::MaskedTable
+            | Self::OverriddenValue
+            | Self::DeprecatedEnvironmentVariable
+            | Self::SuspectValue => LintSeverity::Warning,
+        }
+    }
+}
+
+impl Display for LintCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidFile => f.write_str("invalid-file"),
+            Self::UnknownKey => f.write_str("unknown-key"),
+            Self::DeprecatedKey => f.write_str("deprecated-key"),
+            Self::PyprojectOnlyKey => f.write_str("pyproject-only-key"),
+            Self::UvTomlOnlyKey => f.write_str("uv-toml-only-key"),
+            Self::MaskedTable => f.write_str("masked-table"),
+            Self::OverriddenValue => f.write_str("overridden-value"),
+            Self::DeprecatedEnvironmentVariable => f.write_str("deprecated-environment-variable"),
+            Self::SuspectValue => f.write_str("suspect-value"),
+        }
+    }
+}
+
+/// The location of a key within a configuration file.
+#[derive(Debug, Clone, Serialize)]
+pub struct LintLocation {
+    /// The path to the configuration file.
+    pub file: PathBuf,
+    /// The table containing the key (e.g., `tool.uv.pip`), or `None` for the root table.
+    pub table: Option<String>,
+    /// The key, if the issue pertains to a specific key.
+    pub key: Option<String>,
+    /// The one-based line number, if known.
+    pub line: Option<usize>,
+}
+
+/// An issue found while linting the configuration.
+#[derive(Debug, Clone, Serialize)]
+pub struct LintDiagnostic {
+    pub severity: LintSeverity,
+    pub code: LintCode,
+    pub message: String,
+    /// A suggested fix, if any.
+    pub help: Option<String>,
+    /// The location of the issue, if it pertains to a configuration file.
+    #[serde(flatten)]
+    pub location: Option<LintLocation>,
+}
+
+/// Lint the given configuration sources (in decreasing order of precedence), along with any
+/// environment variables that override them.
+pub fn lint_configuration(sources: &[ConfigSource]) -> Vec<LintDiagnostic> {
+    let mut linter = Linter::default();
+    for source in sources {
+        linter.lint_source(source);
+    }
+    linter.lint_environment();
+    linter.lint_overrides();
+    linter.diagnostics
+}
+
+/// A scalar value set by a configuration source.
+#[derive(Debug)]
+struct SetValue<'a> {
+    source: &'a ConfigSource,
+    value: String,
+    location: LintLocation,
+}
+
+#[derive(Debug, Default)]
+struct Linter<'a> {
+    diagnostics: Vec<LintDiagnostic>,
+    /// The scalar values set by each (unmasked) source, keyed by their dotted path relative to
+    /// the uv settings table.
+    values: BTreeMap<String, Vec<SetValue<'a>>>,
+}
+
+impl<'a> Linter<'a> {
+    fn push(
+        &mut self,
+        code: LintCode,
+        message: String,
+        help: Option<String>,
+        location: Option<LintLocation>,
+    ) {
+        self.diagnostics.push(LintDiagnostic {
+            severity: code.severity(),
+            code,
+            message,
+            help,
+            location,
+        });
+    }
+
+    /// Lint a single configuration file.
+    fn lint_source(&mut self, source: &'a ConfigSource) {
+        let content = match fs_err::read_to_string(&source.path) {
+            Ok(content) => content,
+            Err(err) => {
+                self.push(
+                    LintCode::InvalidFile,
+                    format!("Failed to read configuration file: {err}"),
+                    None,
+                    Some(LintLocation {
+                        file: source.path.clone(),
+                        table: None,
+                        key: None,
+                        line: None,
+                    }),
+                );
+                return;
+            }
+        };
+        let document = match Document::parse(content.as_str()) {
+            Ok(document) => document,
+            Err(err) => {
+                self.push(
+                    LintCode::InvalidFile,
+                    format!("Failed to parse TOML: {}", err.message()),
+                    None,
+                    Some(LintLocation {
+                        file: source.path.clone(),
+                        table: None,
+                        key: None,
+                        line: err.span().map(|span| line_number(&content, span.start)),
+                    }),
+                );
+                return;
+            }
+        };
+
+        let (table, path) = if source.is_pyproject() {
+            let Some(tool) = document.get("tool").and_then(Item::as_table_like) else {
+                return;
+            };
+            let Some(table) = tool.get("uv").and_then(Item::as_table_like) else {
+                return;
+            };
+            if source.masked {
+                self.push(
+                    LintCode::MaskedTable,
+                    "The `[tool.uv]` table is ignored, since a `uv.toml` in the same directory takes precedence".to_string(),
+                    Some("move these settings into the `uv.toml`".to_string()),
+                    Some(LintLocation {
+                        file: source.path.clone(),
+                        table: Some("tool".to_string()),
+                        key: Some("uv".to_string()),
+                        line: key_line(&content, tool, "uv"),
+                    }),
+                );
+            }
+            (table, vec!["tool".to_string(), "uv".to_string()])
+        } else {
+            (&*document as &dyn TableLike, vec![])
+        };
+
+        self.lint_table(source, &content, table, &path, &[], &Schema::root());
+    }
+
+    /// Lint the keys of a table, recursing into any nested option sets.
+    ///
+    /// The `prefix` is the path of the uv settings table within the document (e.g., `tool.uv`),
+    /// and `path` is the path of the current table relative to it.
+    fn lint_table(
+        &mut self,
+        source: &'a ConfigSource,
+        content: &str,
+        table: &dyn TableLike,
+        prefix: &[String],
+        path: &[String],
+        schema: &Schema,
+    ) {
+        let top_level = path.is_empty();
+        let table_name = Some(
+            prefix
+                .iter()
+                .chain(path)
+                .cloned()
+                .collect::<Vec<_>>()
+                .join("."),
+        )
+        .filter(|name| !name.is_empty());
+
+        for (key, item) in table.iter() {
+            let location = LintLocation {
+                file: source.path.clone(),
+                table: table_name.clone(),
+                key: Some(key.to_string()),
+                line: key_line(content, table, key),
+            };
+
+            if top_level && !source.is_pyproject() && PYPROJECT_ONLY_FIELDS.contains(&key) {
+                self.push(
+                    LintCode::PyprojectOnlyKey,
+                    format!("`{key}` is not allowed in a `uv.toml` file"),
+                    Some(format!(
+                        "`{key}` is only applicable in the context of a project; move it to the `[tool.uv]` table of a `pyproject.toml`"
+                    )),
+                    Some(location),
+                );
+                continue;
+            }
+
+            if top_level
+                && let Some((_, replacement)) = MIGRATIONS.iter().find(|(name, _)| *name == key)
+            {
+                self.push(
+                    LintCode::DeprecatedKey,
+                    format!("`{key}` is deprecated"),
+                    Some(format!("use {replacement} instead")),
+                    Some(location.clone()),
+                );
+            }
+
+            if let Some(field) = schema.fields.get(key) {
+                if let Some(deprecated) = &field.deprecated {
+                    self.push(
+                        LintCode::DeprecatedKey,
+                        format!("`{key}` is deprecated"),
+                        deprecated.message.map(ToString::to_string),
+                        Some(location.clone()),
+                    );
+                }
+                if field.uv_toml_only && source.is_pyproject() {
+                    self.push(
+                        LintCode::UvTomlOnlyKey,
+                        format!("`{key}` is only respected in `uv.toml` files"),
+                        Some(format!(
+                            "move `{key}` to a `uv.toml`, or set it via the command line"
+                        )),
+                        Some(location.clone()),
+                    );
+                }
+                if top_level {
+                    self.lint_value(key, item, content, &location);
+                }
+                if !source.masked
+                    && let Some(value) = item.as_value().filter(|value| is_scalar(value))
+                {
+                    let key = path
+                        .iter()
+                        .map(String::as_str)
+                        .chain([key])
+                        .collect::<Vec<_>>()
+                        .join(".");
+                    self.values.entry(key).or_default().push(SetValue {
+                        source,
+                        value: display_value(value),
+                        location,
+                    });
+                }
+            } else if let Some(set) = schema.sets.get(key) {
+                if let Some(table) = item.as_table_like() {
+                    let path = path
+                        .iter()
+                        .cloned()
+                        .chain([key.to_string()])
+                        .collect::<Vec<_>>();
+                    self.lint_table(source, content, table, prefix, &path, &Schema::of(*set));
+                }
+            } else if !(top_level && MIGRATIONS.iter().any(|(name, _)| *name == key)) {
+                let help = schema
+                    .suggest(key)
+                    .map(|suggestion| format!("did you mean `{suggestion}`?"));
+                self.push(
+                    LintCode::UnknownKey,
+                    format!("Unknown setting `{key}`"),
+                    help,
+                    Some(location),
+                );
+            }
+        }
+    }
+
+    /// Lint the value of a top-level setting for values that are accepted, but likely mistakes.
+    fn lint_value(&mut self, key: &str, item: &Item, content: &str, location: &LintLocation) {
+        match key {
+            "cache-dir" => {
+                if let Some(cache_dir) = item.as_str()
+                    && !Path::new(cache_dir).is_absolute()
+                {
+                    self.push(
+                        LintCode::SuspectValue,
+                        format!(
+                            "`cache-dir` is a relative path (`{cache_dir}`), which is resolved against the working directory of each invocation, rather than the directory containing the configuration file"
+                        ),
+                        Some("use an absolute path".to_string()),
+                        Some(location.clone()),
+                    );
+                }
+            }
+            "index" => {
+                let entries: Vec<(&dyn TableLike, Option<usize>)> =
+                    if let Some(array) = item.as_array_of_tables() {
+                        array
+                            .iter()
+                            .map(|table| {
+                                (
+                                    table as &dyn TableLike,
+                                    table.span().map(|span| line_number(content, span.start)),
+                                )
+                            })
+                            .collect()
+                    } else if let Some(array) = item.as_array() {
+                        array
+                            .iter()
+                            .filter_map(Value::as_inline_table)
+                            .map(|table| {
+                                (
+                                    table as &dyn TableLike,
+                                    table.span().map(|span| line_number(content, span.start)),
+                                )
+                            })
+                            .collect()
+                    } else {
+                        Vec::new()
+                    };
+
+                for (index, line) in entries {
+                    if index.contains_key("name") {
+                        continue;
+                    }
+                    let url = index
+                        .get("url")
+                        .and_then(Item::as_str)
+                        .unwrap_or("<unknown>");
+                    let explicit = index
+                        .get("explicit")
+                        .and_then(Item::as_bool)
+                        .unwrap_or(false);
+                    let message = if explicit {
+                        format!(
+                            "The explicit index `{url}` has no `name`, so no package can be pinned to it"
+                        )
+                    } else {
+                        format!("The index `{url}` has no `name`")
+                    };
+                    self.push(
+                        LintCode::SuspectValue,
+                        message,
+                        Some("add a `name`, such that the index can be referenced in `tool.uv.sources` and its credentials can be provided via `UV_INDEX_<NAME>_USERNAME` and `UV_INDEX_<NAME>_PASSWORD`".to_string()),
+                        Some(LintLocation {
+                            line: line.or(location.line),
+                            ..location.clone()
+                        }),
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Lint the environment for deprecated variables.
+    fn lint_environment(&mut self) {
+        for (name, replacement) in DEPRECATED_ENVIRONMENT_VARIABLES {
+            if std::env::var_os(name).is_some_and(|value| !value.is_empty()) {
+                self.push(
+                    LintCode::DeprecatedEnvironmentVariable,
+                    format!("The `{name}` environment variable is deprecated"),
+                    Some(format!("use `{replacement}` instead")),
+                    None,
+                );
+            }
+        }
+    }
+
+    /// Report values that are overridden by an environment variable or by a configuration source
+    /// with higher precedence.
+    fn lint_overrides(&mut self) {
+        let values = std::mem::take(&mut self.values);
+        for (key, values) in values {
+            // Environment variables take precedence over all configuration files.
+            if let Some((name, _)) = ENVIRONMENT_OVERRIDES
+                .iter()
+                .filter(|(_, setting)| *setting == key)
+                .find(|(name, _)| std::env::var_os(name).is_some_and(|value| !value.is_empty()))
+            {
+                for value in values {
+                    self.push(
+                        LintCode::OverriddenValue,
+                        format!(
+                            "`{key}` is set to `{}`, but is overridden by the `{name}` environment variable",
+                            value.value
+                        ),
+                        Some(
+                            "environment variables take precedence over configuration files"
+                                .to_string(),
+                        ),
+                        Some(value.location),
+                    );
+                }
+                continue;
+            }
+
+            // Otherwise, the first source (i.e., that with the highest precedence) wins.
+            let mut values = values.into_iter();
+            let Some(winner) = values.next() else {
+                continue;
+            };
+            for value in values.filter(|value| value.value != winner.value) {
+                self.push(
+                    LintCode::OverriddenValue,
+                    format!(
+                        "`{key}` is set to `{}`, but is overridden by `{}` in `{}`",
+                        value.value,
+                        winner.value,
+                        winner.source.path.user_display()
+                    ),
+                    Some(format!(
+                        "{} configuration takes precedence over {} configuration",
+                        winner.source.kind, value.source.kind
+                    )),
+                    Some(value.location),
+                );
+            }
+        }
+    }
+}
+
+/// The known settings within a table.
+#[derive(Debug, Default)]
+struct Schema {
+    fields: BTreeMap<String, OptionField>,
+    sets: BTreeMap<String, OptionSet>,
+}
+
+impl Schema {
+    /// The settings of the uv settings table (i.e., `uv.toml` or `[tool.uv]`).
+    fn root() -> Self {
+        let mut schema = Self::default();
+        Options::record(&mut schema);
+        ToolUv::record(&mut schema);
+        schema
+    }
+
+    /// The settings of a nested [`OptionSet`].
+    fn of(set: OptionSet) -> Self {
+        let mut schema = Self::default();
+        set.record(&mut schema);
+        schema
+    }
+
+    /// Return the known setting that most closely matches the given key, if any.
+    fn suggest(&self, key: &str) -> Option<&str> {
+        self.fields
+            .keys()
+            .chain(self.sets.keys())
+            .map(|candidate| (strsim::jaro_winkler(key, candidate), candidate.as_str()))
+            .filter(|(score, _)| *score > 0.8)
+            .max_by(|(a, _), (b, _)| a.total_cmp(b))
+            .map(|(_, candidate)| candidate)
+    }
+}
+
+impl Visit for Schema {
+    fn record_field(&mut self, name: &str, field: OptionField) {
+        self.fields.insert(name.to_string(), field);
+    }
+
+    fn record_set(&mut self, name: &str, set: OptionSet) {
+        self.sets.insert(name.to_string(), set);
+    }
+}
+
+/// Returns `true` if the value is a scalar (i.e., is overridden, rather than merged, when set
+/// in multiple sources).
+fn is_scalar(value: &Value) -> bool {
+    !matches!(value, Value::Array(_) | Value::InlineTable(_))
+}
+
+/// Format a value for display, without any surrounding whitespace or comments.
+fn display_value(value: &Value) -> String {
+    if let Some(value) = value.as_str() {
+        return format!("\"{value}\"");
+    }
+    let mut value = value.clone();
+    value.decor_mut().clear();
+    value.to_string()
+}
+
+/// Return the one-based line number of the given key in a table.
+fn key_line(content: &str, table: &dyn TableLike, key: &str) -> Option<usize> {
+    table
+        .key(key)
+        .and_then(Key::span)
+        .map(|span| line_number(content, span.start))
+}
+
+/// Return the one-based line number of a byte offset.
+fn line_number(content: &str, offset: usize) -> usize {
+    content
+        .get(..offset)
+        .unwrap_or(content)
+        .matches('\n')
+        .count()
+        + 1
+}
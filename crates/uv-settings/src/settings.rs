@@ -1,5 +1,6 @@
 #[cfg(feature = "schemars")]
 use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::{fmt::Debug, num::NonZeroUsize, path::Path, path::PathBuf};
 
 use serde::{Deserialize, Serialize};
@@ -18,8 +19,8 @@ use uv_macros::{CombineOptions, OptionsMetadata};
 use uv_normalize::{ExtraName, PackageName, PipGroupName};
 use uv_pep508::Requirement;
 use uv_preview::{MaybePreviewFeature, Preview};
-use uv_pypi_types::{SupportedEnvironments, VerbatimParsedUrl};
-use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
+use uv_pypi_types::{MetadataVersion, SupportedEnvironments, VerbatimParsedUrl};
+use uv_python::{PythonDownloads, PythonPreference, PythonVersion, WindowsArm64Fallback};
 use uv_redacted::DisplaySafeUrl;
 use uv_resolver::{
     AnnotationStyle, ExcludeNewerOverride, ExcludeNewerPackage, ExcludeNewerSpan,
@@ -192,6 +193,30 @@ pub struct Options {
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub(crate) r#package: Option<serde::de::IgnoredAny>,
 
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub(crate) static_dependencies: Option<serde::de::IgnoredAny>,
+
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub(crate) scripts: Option<serde::de::IgnoredAny>,
+
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub(crate) post_sync: Option<serde::de::IgnoredAny>,
+
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub(crate) strict_config: Option<serde::de::IgnoredAny>,
+
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub(crate) no_editable_package: Option<serde::de::IgnoredAny>,
+
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub(crate) lock_provenance: Option<serde::de::IgnoredAny>,
+
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub(crate) project_environment: Option<serde::de::IgnoredAny>,
+
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub(crate) workspace_pin_policy: Option<serde::de::IgnoredAny>,
+
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub(crate) build_backend: Option<serde::de::IgnoredAny>,
 }
@@ -357,6 +382,26 @@ pub struct GlobalOptions {
         possible_values = true
     )]
     pub python_downloads: Option<PythonDownloads>,
+    /// Whether to fall back to an installed x64 Python interpreter on Windows ARM64, when the
+    /// requested packages don't provide `win_arm64` wheels.
+    ///
+    /// Many packages that lack `win_arm64` wheels provide `win_amd64` wheels, which can run under
+    /// x64 emulation. With `prefer-native`, uv will use the native ARM64 interpreter wherever
+    /// possible, but fall back to an installed x64 interpreter if resolution fails due to missing
+    /// `win_arm64` wheels. With `warn`, uv will continue to use the native interpreter, but will
+    /// warn when an installed x64 interpreter could be used instead.
+    ///
+    /// The fallback applies to commands that select their own interpreter, like `uvx`,
+    /// `uv tool run`, and `uv tool install`.
+    #[option(
+        default = "\"none\"",
+        value_type = "str",
+        example = r#"
+            windows-arm64-fallback = "prefer-native"
+        "#,
+        possible_values = true
+    )]
+    pub windows_arm64_fallback: Option<WindowsArm64Fallback>,
     /// The maximum number of in-flight concurrent downloads that uv will perform at any given
     /// time.
     #[option(
@@ -455,6 +500,7 @@ struct GlobalOptionsWire {
 
     python_preference: Option<PythonPreference>,
     python_downloads: Option<PythonDownloads>,
+    windows_arm64_fallback: Option<WindowsArm64Fallback>,
     concurrent_downloads: Option<NonZeroUsize>,
     concurrent_builds: Option<NonZeroUsize>,
     concurrent_installs: Option<NonZeroUsize>,
@@ -480,6 +526,7 @@ impl TryFrom<GlobalOptionsWire> for GlobalOptions {
             preview_features,
             python_preference,
             python_downloads,
+            windows_arm64_fallback,
             concurrent_downloads,
             concurrent_builds,
             concurrent_installs,
@@ -499,6 +546,7 @@ impl TryFrom<GlobalOptionsWire> for GlobalOptions {
             preview: PreviewOption::try_from(preview, preview_features)?,
             python_preference,
             python_downloads,
+            windows_arm64_fallback,
             concurrent_downloads,
             concurrent_builds,
             concurrent_installs,
@@ -547,6 +595,8 @@ pub struct ResolverOptions {
     pub resolution: Option<ResolutionMode>,
     pub prerelease: Option<PrereleaseMode>,
     pub fork_strategy: Option<ForkStrategy>,
+    pub prerelease_packages: Option<Vec<PackageName>>,
+    pub require_metadata: Option<MetadataVersion>,
     pub dependency_metadata: Option<Vec<StaticMetadata>>,
     pub config_settings: Option<ConfigSettings>,
     pub config_settings_package: Option<PackageConfigSettings>,
@@ -562,6 +612,8 @@ pub struct ResolverOptions {
     pub no_binary_package: Option<Vec<PackageName>>,
     pub extra_build_dependencies: Option<ExtraBuildDependencies>,
     pub extra_build_variables: Option<ExtraBuildVariables>,
+    pub build_env_passthrough: Option<Vec<String>>,
+    pub build_env_passthrough_package: Option<BTreeMap<PackageName, Vec<String>>>,
     pub no_sources: Option<bool>,
     pub no_sources_package: Option<Vec<PackageName>>,
 }
@@ -580,12 +632,16 @@ pub struct ResolverInstallerOptions {
     pub resolution: Option<ResolutionMode>,
     pub prerelease: Option<PrereleaseMode>,
     pub fork_strategy: Option<ForkStrategy>,
+    pub prerelease_packages: Option<Vec<PackageName>>,
+    pub require_metadata: Option<MetadataVersion>,
     pub dependency_metadata: Option<Vec<StaticMetadata>>,
     pub config_settings: Option<ConfigSettings>,
     pub config_settings_package: Option<PackageConfigSettings>,
     pub build_isolation: Option<BuildIsolation>,
     pub extra_build_dependencies: Option<ExtraBuildDependencies>,
     pub extra_build_variables: Option<ExtraBuildVariables>,
+    pub build_env_passthrough: Option<Vec<String>>,
+    pub build_env_passthrough_package: Option<BTreeMap<PackageName, Vec<String>>>,
     pub exclude_newer: Option<ExcludeNewerOverride>,
     pub exclude_newer_package: Option<ExcludeNewerPackage>,
     pub link_mode: Option<LinkMode>,
@@ -614,6 +670,8 @@ impl From<ResolverInstallerSchema> for ResolverInstallerOptions {
             resolution,
             prerelease,
             fork_strategy,
+            prerelease_packages,
+            require_metadata,
             dependency_metadata,
             config_settings,
             config_settings_package,
@@ -621,6 +679,8 @@ impl From<ResolverInstallerSchema> for ResolverInstallerOptions {
             no_build_isolation_package,
             extra_build_dependencies,
             extra_build_variables,
+            build_env_passthrough,
+            build_env_passthrough_package,
             exclude_newer,
             exclude_newer_package,
             link_mode,
@@ -648,6 +708,8 @@ impl From<ResolverInstallerSchema> for ResolverInstallerOptions {
             resolution,
             prerelease,
             fork_strategy,
+            prerelease_packages,
+            require_metadata,
             dependency_metadata,
             config_settings,
             config_settings_package,
@@ -657,6 +719,8 @@ impl From<ResolverInstallerSchema> for ResolverInstallerOptions {
             ),
             extra_build_dependencies,
             extra_build_variables,
+            build_env_passthrough,
+            build_env_passthrough_package,
             exclude_newer,
             exclude_newer_package,
             link_mode,
@@ -901,6 +965,34 @@ pub struct ResolverInstallerSchema {
         possible_values = true
     )]
     pub fork_strategy: Option<ForkStrategy>,
+    /// Allow pre-release versions for the given packages, regardless of the global
+    /// [`prerelease`](#prerelease) strategy.
+    ///
+    /// Useful when a dependency only publishes pre-release versions (or when a specific
+    /// pre-release is required) without opting in to pre-releases for the entire resolution.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            prerelease-packages = ["black"]
+        "#
+    )]
+    pub prerelease_packages: Option<Vec<PackageName>>,
+    /// The minimum core metadata version (`Metadata-Version`) that each resolved distribution
+    /// must declare, like `2.2`.
+    ///
+    /// Distributions whose metadata declares an older version are rejected during resolution, as
+    /// if they were unavailable. Distributions built from a Git repository or a local directory,
+    /// and distributions whose metadata version is unknown (e.g., metadata provided via
+    /// `dependency-metadata`), are exempt.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            require-metadata = "2.2"
+        "#
+    )]
+    pub require_metadata: Option<MetadataVersion>,
     /// Pre-defined static metadata for dependencies of the project (direct or transitive). When
     /// provided, enables the resolver to use the specified metadata instead of querying the
     /// registry or building the relevant package from source.
@@ -995,6 +1087,35 @@ pub struct ResolverInstallerSchema {
         "#
     )]
     pub extra_build_variables: Option<ExtraBuildVariables>,
+    /// Environment variables to pass through to build backends.
+    ///
+    /// By default, PEP 517 build backends inherit the entire environment of the uv process. When
+    /// set, only the listed variables are forwarded from the parent environment, along with a
+    /// small set of variables required for the build to function (e.g., `HOME`, `TMPDIR`, and,
+    /// on Windows, `SYSTEMROOT`). Variables set via
+    /// [`extra-build-variables`](#extra-build-variables) are always included.
+    ///
+    /// Use `--verbose` to list the variables that are forwarded for each build.
+    #[option(
+        default = "None",
+        value_type = "list[str]",
+        example = r#"
+            build-env-passthrough = ["CMAKE_ARGS", "CC", "MACOSX_DEPLOYMENT_TARGET"]
+        "#
+    )]
+    pub build_env_passthrough: Option<Vec<String>>,
+    /// Environment variables to pass through to the build backends of specific packages.
+    ///
+    /// When set for a package, replaces the [`build-env-passthrough`](#build-env-passthrough)
+    /// list for that package.
+    #[option(
+        default = "{}",
+        value_type = "dict[str, list[str]]",
+        example = r#"
+            build-env-passthrough-package = { llama-cpp-python = ["CMAKE_ARGS", "CUDA_HOME"] }
+        "#
+    )]
+    pub build_env_passthrough_package: Option<BTreeMap<PackageName, Vec<String>>>,
     /// Limit candidate packages to those that were uploaded prior to the given date.
     ///
     /// The date is compared against the upload time of each individual distribution artifact
@@ -1650,6 +1771,34 @@ pub struct PipOptions {
         possible_values = true
     )]
     pub fork_strategy: Option<ForkStrategy>,
+    /// Allow pre-release versions for the given packages, regardless of the global
+    /// [`prerelease`](#prerelease) strategy.
+    ///
+    /// Useful when a dependency only publishes pre-release versions (or when a specific
+    /// pre-release is required) without opting in to pre-releases for the entire resolution.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            prerelease-packages = ["black"]
+        "#
+    )]
+    pub prerelease_packages: Option<Vec<PackageName>>,
+    /// The minimum core metadata version (`Metadata-Version`) that each resolved distribution
+    /// must declare, like `2.2`.
+    ///
+    /// Distributions whose metadata declares an older version are rejected during resolution, as
+    /// if they were unavailable. Distributions built from a Git repository or a local directory,
+    /// and distributions whose metadata version is unknown (e.g., metadata provided via
+    /// `dependency-metadata`), are exempt.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            require-metadata = "2.2"
+        "#
+    )]
+    pub require_metadata: Option<MetadataVersion>,
     /// Pre-defined static metadata for dependencies of the project (direct or transitive). When
     /// provided, enables the resolver to use the specified metadata instead of querying the
     /// registry or building the relevant package from source.
@@ -2130,6 +2279,8 @@ impl From<ResolverInstallerSchema> for ResolverOptions {
             resolution: value.resolution,
             prerelease: value.prerelease,
             fork_strategy: value.fork_strategy,
+            prerelease_packages: value.prerelease_packages,
+            require_metadata: value.require_metadata,
             dependency_metadata: value.dependency_metadata,
             config_settings: value.config_settings,
             config_settings_package: value.config_settings_package,
@@ -2156,6 +2307,8 @@ impl From<ResolverInstallerSchema> for ResolverOptions {
             ),
             extra_build_dependencies: value.extra_build_dependencies,
             extra_build_variables: value.extra_build_variables,
+            build_env_passthrough: value.build_env_passthrough,
+            build_env_passthrough_package: value.build_env_passthrough_package,
             no_sources: value.no_sources,
             no_sources_package: value.no_sources_package,
             torch_backend: value.torch_backend,
@@ -2215,6 +2368,8 @@ pub struct ToolOptions {
     resolution: Option<ResolutionMode>,
     prerelease: Option<PrereleaseMode>,
     fork_strategy: Option<ForkStrategy>,
+    prerelease_packages: Option<Vec<PackageName>>,
+    require_metadata: Option<MetadataVersion>,
     dependency_metadata: Option<Vec<StaticMetadata>>,
     config_settings: Option<ConfigSettings>,
     config_settings_package: Option<PackageConfigSettings>,
@@ -2248,6 +2403,8 @@ pub struct ToolOptionsWire {
     resolution: Option<ResolutionMode>,
     prerelease: Option<PrereleaseMode>,
     fork_strategy: Option<ForkStrategy>,
+    prerelease_packages: Option<Vec<PackageName>>,
+    require_metadata: Option<MetadataVersion>,
     dependency_metadata: Option<Vec<StaticMetadata>>,
     config_settings: Option<ConfigSettings>,
     config_settings_package: Option<PackageConfigSettings>,
@@ -2287,6 +2444,8 @@ impl From<ResolverInstallerOptions> for ToolOptions {
             resolution: value.resolution,
             prerelease: value.prerelease,
             fork_strategy: value.fork_strategy,
+            prerelease_packages: value.prerelease_packages,
+            require_metadata: value.require_metadata,
             dependency_metadata: value.dependency_metadata,
             config_settings: value.config_settings,
             config_settings_package: value.config_settings_package,
@@ -2337,6 +2496,8 @@ impl From<ToolOptionsWire> for ToolOptions {
             resolution: value.resolution,
             prerelease: value.prerelease,
             fork_strategy: value.fork_strategy,
+            prerelease_packages: value.prerelease_packages,
+            require_metadata: value.require_metadata,
             dependency_metadata: value.dependency_metadata,
             config_settings: value.config_settings,
             config_settings_package: value.config_settings_package,
@@ -2385,6 +2546,8 @@ impl From<ToolOptions> for ToolOptionsWire {
             resolution: value.resolution,
             prerelease: value.prerelease,
             fork_strategy: value.fork_strategy,
+            prerelease_packages: value.prerelease_packages,
+            require_metadata: value.require_metadata,
             dependency_metadata: value.dependency_metadata,
             config_settings: value.config_settings,
             config_settings_package: value.config_settings_package,
@@ -2420,12 +2583,16 @@ impl From<ToolOptions> for ResolverInstallerOptions {
             resolution: value.resolution,
             prerelease: value.prerelease,
             fork_strategy: value.fork_strategy,
+            prerelease_packages: value.prerelease_packages,
+            require_metadata: value.require_metadata,
             dependency_metadata: value.dependency_metadata,
             config_settings: value.config_settings,
             config_settings_package: value.config_settings_package,
             build_isolation: value.build_isolation,
             extra_build_dependencies: value.extra_build_dependencies,
             extra_build_variables: value.extra_build_variables,
+            build_env_passthrough: None,
+            build_env_passthrough_package: None,
             exclude_newer: value.exclude_newer,
             exclude_newer_package: value.exclude_newer_package,
             link_mode: value.link_mode,
@@ -2460,6 +2627,7 @@ struct OptionsWire {
     preview_features: Option<PreviewFeaturesOption>,
     python_preference: Option<PythonPreference>,
     python_downloads: Option<PythonDownloads>,
+    windows_arm64_fallback: Option<WindowsArm64Fallback>,
     concurrent_downloads: Option<NonZeroUsize>,
     concurrent_builds: Option<NonZeroUsize>,
     concurrent_installs: Option<NonZeroUsize>,
@@ -2480,6 +2648,8 @@ struct OptionsWire {
     resolution: Option<ResolutionMode>,
     prerelease: Option<PrereleaseMode>,
     fork_strategy: Option<ForkStrategy>,
+    prerelease_packages: Option<Vec<PackageName>>,
+    require_metadata: Option<MetadataVersion>,
     dependency_metadata: Option<Vec<StaticMetadata>>,
     config_settings: Option<ConfigSettings>,
     config_settings_package: Option<PackageConfigSettings>,
@@ -2487,6 +2657,8 @@ struct OptionsWire {
     no_build_isolation_package: Option<Vec<PackageName>>,
     extra_build_dependencies: Option<ExtraBuildDependencies>,
     extra_build_variables: Option<ExtraBuildVariables>,
+    build_env_passthrough: Option<Vec<String>>,
+    build_env_passthrough_package: Option<BTreeMap<PackageName, Vec<String>>>,
     exclude_newer: Option<ExcludeNewerOverride>,
     exclude_newer_package: Option<ExcludeNewerPackage>,
     link_mode: Option<LinkMode>,
@@ -2541,9 +2713,17 @@ struct OptionsWire {
     sources: Option<serde::de::IgnoredAny>,
     managed: Option<serde::de::IgnoredAny>,
     r#package: Option<serde::de::IgnoredAny>,
+    static_dependencies: Option<serde::de::IgnoredAny>,
+    scripts: Option<serde::de::IgnoredAny>,
+    post_sync: Option<serde::de::IgnoredAny>,
+    strict_config: Option<serde::de::IgnoredAny>,
+    no_editable_package: Option<serde::de::IgnoredAny>,
+    lock_provenance: Option<serde::de::IgnoredAny>,
+    project_environment: Option<serde::de::IgnoredAny>,
     default_groups: Option<serde::de::IgnoredAny>,
     dependency_groups: Option<serde::de::IgnoredAny>,
     dev_dependencies: Option<serde::de::IgnoredAny>,
+    workspace_pin_policy: Option<serde::de::IgnoredAny>,
 
     // Build backend
     build_backend: Option<serde::de::IgnoredAny>,
@@ -2565,6 +2745,7 @@ impl TryFrom<OptionsWire> for Options {
             preview_features,
             python_preference,
             python_downloads,
+            windows_arm64_fallback,
             python_install_mirror,
             pypy_install_mirror,
             python_downloads_json_url,
@@ -2585,6 +2766,8 @@ impl TryFrom<OptionsWire> for Options {
             resolution,
             prerelease,
             fork_strategy,
+            prerelease_packages,
+            require_metadata,
             dependency_metadata,
             config_settings,
             config_settings_package,
@@ -2624,9 +2807,19 @@ impl TryFrom<OptionsWire> for Options {
             dependency_groups,
             extra_build_dependencies,
             extra_build_variables,
+            build_env_passthrough,
+            build_env_passthrough_package,
             dev_dependencies,
             managed,
             package,
+            static_dependencies,
+            scripts,
+            post_sync,
+            strict_config,
+            no_editable_package,
+            lock_provenance,
+            project_environment,
+            workspace_pin_policy,
             add_bounds: bounds,
             // Used by the build backend
             build_backend,
@@ -2643,6 +2836,7 @@ impl TryFrom<OptionsWire> for Options {
                 preview: PreviewOption::try_from(preview, preview_features)?,
                 python_preference,
                 python_downloads,
+                windows_arm64_fallback,
                 concurrent_downloads,
                 concurrent_builds,
                 concurrent_installs,
@@ -2663,6 +2857,8 @@ impl TryFrom<OptionsWire> for Options {
                 resolution,
                 prerelease,
                 fork_strategy,
+                prerelease_packages,
+                require_metadata,
                 dependency_metadata,
                 config_settings,
                 config_settings_package,
@@ -2670,6 +2866,8 @@ impl TryFrom<OptionsWire> for Options {
                 no_build_isolation_package,
                 extra_build_dependencies,
                 extra_build_variables,
+                build_env_passthrough,
+                build_env_passthrough_package,
                 exclude_newer,
                 exclude_newer_package,
                 link_mode,
@@ -2715,6 +2913,14 @@ impl TryFrom<OptionsWire> for Options {
             dependency_groups,
             managed,
             package,
+            static_dependencies,
+            scripts,
+            post_sync,
+            strict_config,
+            no_editable_package,
+            lock_provenance,
+            project_environment,
+            workspace_pin_policy,
         })
     }
 }
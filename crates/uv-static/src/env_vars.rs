@@ -184,6 +184,14 @@ impl EnvVars {
     #[attr_added_in("0.1.19")]
     pub const UV_KEYRING_PROVIDER: &'static str = "UV_KEYRING_PROVIDER";
 
+    /// Timeout (in seconds) for each invocation of the `keyring` command, when using the
+    /// `subprocess` keyring provider. (default: 5 s)
+    ///
+    /// If the command doesn't complete within the timeout, it's terminated and the lookup is
+    /// treated as if no credentials were found.
+    #[attr_added_in("next release")]
+    pub const UV_KEYRING_TIMEOUT: &'static str = "UV_KEYRING_TIMEOUT";
+
     /// Equivalent to the `--config-file` command-line argument. Expects a path to a
     /// local `uv.toml` file to use as the configuration file.
     #[attr_added_in("0.1.34")]
@@ -367,6 +375,11 @@ impl EnvVars {
     #[attr_added_in("0.4.18")]
     pub const UV_NO_SYNC: &'static str = "UV_NO_SYNC";
 
+    /// Equivalent to the `--no-post-sync` command-line argument. If set, uv will not run the
+    /// commands declared in `tool.uv.post-sync` after syncing the environment.
+    #[attr_added_in("next release")]
+    pub const UV_NO_POST_SYNC: &'static str = "UV_NO_POST_SYNC";
+
     /// Equivalent to the `--locked` command-line argument. If set, uv will assert that the
     /// `uv.lock` remains unchanged.
     #[attr_added_in("0.4.25")]
@@ -426,6 +439,16 @@ impl EnvVars {
     #[attr_added_in("0.11.29")]
     pub const UV_CONCURRENT_CACHE_READS: &'static str = "UV_CONCURRENT_CACHE_READS";
 
+    /// A soft limit on the memory retained by the resolver, e.g., `3GiB` or `3000000000` (in
+    /// bytes).
+    ///
+    /// When the estimated memory retained during a resolution exceeds the limit, uv discards the
+    /// metadata for versions that can no longer be selected; if the estimate still exceeds the
+    /// limit, the resolution fails with an error naming the packages with the most retained
+    /// versions.
+    #[attr_added_in("next release")]
+    pub const UV_RESOLVER_MEMORY_LIMIT: &'static str = "UV_RESOLVER_MEMORY_LIMIT";
+
     /// Equivalent to the `--no-progress` command-line argument. Disables all progress output. For
     /// example, spinners and progress bars.
     #[attr_added_in("0.2.28")]
@@ -673,6 +696,12 @@ impl EnvVars {
     #[attr_added_in("0.3.0")]
     pub const UV_INTERNAL__SHOW_DERIVATION_TREE: &'static str = "UV_INTERNAL__SHOW_DERIVATION_TREE";
 
+    /// Used to persist the preferences of completed forks during `uv lock`, such that a retried
+    /// resolution can reuse them after a failure.
+    #[attr_hidden]
+    #[attr_added_in("next release")]
+    pub const UV_INTERNAL__LOCK_CHECKPOINT: &'static str = "UV_INTERNAL__LOCK_CHECKPOINT";
+
     /// Used to set a temporary directory for some tests.
     #[attr_hidden]
     #[attr_added_in("0.3.4")]
@@ -825,6 +854,11 @@ impl EnvVars {
     #[attr_added_in("0.7.21")]
     pub const UV_HTTP_RETRIES: &'static str = "UV_HTTP_RETRIES";
 
+    /// The maximum size, in bytes, of a Simple API response from a package index. Larger
+    /// responses are rejected rather than parsed and cached. (default: 256 MiB)
+    #[attr_added_in("next release")]
+    pub const UV_MAX_SIMPLE_RESPONSE_SIZE: &'static str = "UV_MAX_SIMPLE_RESPONSE_SIZE";
+
     /// Timeout (in seconds) for HTTP requests. Equivalent to `UV_HTTP_TIMEOUT`.
     #[attr_added_in("0.1.6")]
     pub const UV_REQUEST_TIMEOUT: &'static str = "UV_REQUEST_TIMEOUT";
@@ -937,6 +971,10 @@ impl EnvVars {
     #[attr_added_in("0.0.5")]
     pub const PATH: &'static str = "PATH";
 
+    /// The standard `CC` env var, used to locate the C compiler when building Python from source.
+    #[attr_added_in("next release")]
+    pub const CC: &'static str = "CC";
+
     /// The standard `HOME` env var.
     #[attr_added_in("0.0.5")]
     pub const HOME: &'static str = "HOME";
@@ -1043,6 +1081,13 @@ impl EnvVars {
     #[attr_added_in("0.8.18")]
     pub const GITLAB_CI: &'static str = "GITLAB_CI";
 
+    /// The legacy GitLab CI job token, which can't be used for trusted publishing.
+    ///
+    /// `uv publish` warns if it's set when no ID token is available for trusted publishing.
+    #[attr_hidden]
+    #[attr_added_in("next release")]
+    pub const CI_JOB_JWT_V2: &'static str = "CI_JOB_JWT_V2";
+
     /// Used for testing GitLab CI trusted publishing.
     #[attr_hidden]
     #[attr_added_in("0.8.18")]
@@ -1111,6 +1156,13 @@ impl EnvVars {
     #[attr_added_in("0.1.16")]
     pub const NETRC: &'static str = "NETRC";
 
+    /// Use to set the .netrc file location for uv, taking precedence over `NETRC`.
+    ///
+    /// If unset, uv falls back to `NETRC` and then to the standard location in the user's home
+    /// directory.
+    #[attr_added_in("next release")]
+    pub const UV_NETRC: &'static str = "UV_NETRC";
+
     /// The standard `PAGER` posix env var. Used by `uv` to configure the appropriate pager.
     #[attr_added_in("0.4.18")]
     pub const PAGER: &'static str = "PAGER";
@@ -1427,6 +1479,36 @@ impl EnvVars {
     #[attr_added_in("0.11.14")]
     pub const UV_AZURE_ENDPOINT_URL: &'static str = "UV_AZURE_ENDPOINT_URL";
 
+    /// A personal access token for Azure Artifacts. Used for requests to indexes configured
+    /// with `auth-provider = "azure"`, in lieu of a managed identity token.
+    #[attr_added_in("next release")]
+    pub const AZURE_DEVOPS_TOKEN: &'static str = "AZURE_DEVOPS_TOKEN";
+
+    /// The client ID of a user-assigned managed identity to request Azure Artifacts tokens for,
+    /// for indexes configured with `auth-provider = "azure"`.
+    #[attr_added_in("next release")]
+    pub const AZURE_CLIENT_ID: &'static str = "AZURE_CLIENT_ID";
+
+    /// The base URL of the Azure Instance Metadata Service, used to request managed identity
+    /// tokens for indexes configured with `auth-provider = "azure"`.
+    #[attr_added_in("next release")]
+    pub const AZURE_POD_IDENTITY_AUTHORITY_HOST: &'static str = "AZURE_POD_IDENTITY_AUTHORITY_HOST";
+
+    /// Path to a Google Cloud credentials file, used for requests to indexes configured with
+    /// `auth-provider = "gcp"`.
+    #[attr_added_in("next release")]
+    pub const GOOGLE_APPLICATION_CREDENTIALS: &'static str = "GOOGLE_APPLICATION_CREDENTIALS";
+
+    /// The Google Cloud CLI configuration directory, used to locate Application Default
+    /// Credentials for indexes configured with `auth-provider = "gcp"`.
+    #[attr_added_in("next release")]
+    pub const CLOUDSDK_CONFIG: &'static str = "CLOUDSDK_CONFIG";
+
+    /// The host of the Google Cloud metadata server, used to request access tokens for indexes
+    /// configured with `auth-provider = "gcp"`.
+    #[attr_added_in("next release")]
+    pub const GCE_METADATA_HOST: &'static str = "GCE_METADATA_HOST";
+
     /// The URL of the pyx Simple API server.
     #[attr_added_in("0.8.15")]
     pub const PYX_API_URL: &'static str = "PYX_API_URL";
@@ -1496,6 +1578,18 @@ impl EnvVars {
     #[attr_added_in("0.8.23")]
     pub const UV_SKIP_WHEEL_FILENAME_CHECK: &'static str = "UV_SKIP_WHEEL_FILENAME_CHECK";
 
+    /// Avoid comparing the static version and entry points in an editable package's
+    /// `pyproject.toml` against its installed metadata when checking whether the editable is up to
+    /// date. By default, uv reinstalls an editable whose `[project.scripts]`,
+    /// `[project.gui-scripts]`, `[project.entry-points]`, or `version` have changed.
+    #[attr_added_in("next release")]
+    pub const UV_SKIP_EDITABLE_METADATA_CHECK: &'static str = "UV_SKIP_EDITABLE_METADATA_CHECK";
+
+    /// Avoid showing a notice after `uv sync` or `uv pip install` installs entry point scripts into
+    /// a virtual environment whose scripts directory is not on the `PATH`.
+    #[attr_added_in("next release")]
+    pub const UV_NO_SCRIPTS_PATH_NOTICE: &'static str = "UV_NO_SCRIPTS_PATH_NOTICE";
+
     /// Suppress output from the build backend when building source distributions, even in the event
     /// of build failures.
     #[attr_added_in("0.9.15")]
@@ -1181,6 +1181,8 @@ impl TestContext {
         command
             // When running the tests in a venv, ignore that venv, otherwise we'll capture warnings.
             .env_remove(EnvVars::VIRTUAL_ENV)
+            // Similarly, ignore an active conda environment.
+            .env_remove(EnvVars::CONDA_PREFIX)
             // Disable wrapping of uv output for readability / determinism in snapshots.
             .env(EnvVars::UV_NO_WRAP, "1")
             // Avoid reading host system configuration unless a test opts in.
@@ -1215,6 +1217,9 @@ impl TestContext {
             // Since downloads, fetches and builds run in parallel, their message output order is
             // non-deterministic, so can't capture them in test output.
             .env(EnvVars::UV_TEST_NO_CLI_PROGRESS, "1")
+            // The test environments are never on the `PATH`; tests that exercise the notice opt in
+            // explicitly.
+            .env(EnvVars::UV_NO_SCRIPTS_PATH_NOTICE, "1")
             // I believe the intent of all tests is that they are run outside the
             // context of an existing git repository. And when they aren't, state
             // from the parent git repository can bleed into the behavior of `uv
@@ -1388,6 +1393,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv config lint` command with options shared across scenarios.
+    pub fn config_lint(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("config").arg("lint");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv workspace dir` command with options shared across scenarios.
     pub fn workspace_dir(&self) -> Command {
         let mut command = self.new_command();
@@ -1404,6 +1417,22 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv workspace add` command with options shared across scenarios.
+    pub fn workspace_add(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("workspace").arg("add");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv workspace remove` command with options shared across scenarios.
+    pub fn workspace_remove(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("workspace").arg("remove");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv export` command with options shared across scenarios.
     pub fn export(&self) -> Command {
         let mut command = self.new_command();
@@ -84,3 +84,22 @@ impl<T> BuildArena<T> {
         self.0.pin().remove(key).cloned()
     }
 }
+
+tokio::task_local! {
+    /// The listener for the output of any build backend invoked by the current build.
+    static BUILD_OUTPUT_LISTENER: Arc<dyn Fn(&str) + Send + Sync>;
+}
+
+/// Run a build, forwarding each line of output from any build backend it invokes to the given
+/// listener.
+pub async fn with_build_output_listener<F: Future>(
+    listener: impl Fn(&str) + Send + Sync + 'static,
+    build: F,
+) -> F::Output {
+    BUILD_OUTPUT_LISTENER.scope(Arc::new(listener), build).await
+}
+
+/// Forward a line of output from a build backend to the listener of the current build, if any.
+pub fn report_build_output(line: &str) {
+    let _ = BUILD_OUTPUT_LISTENER.try_with(|listener| listener(line));
+}
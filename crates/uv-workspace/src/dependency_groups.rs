@@ -1,4 +1,7 @@
+use std::collections::BTreeSet;
 use std::collections::btree_map::Entry;
+use std::fmt;
+use std::path::PathBuf;
 use std::str::FromStr;
 use std::{collections::BTreeMap, path::Path};
 
@@ -6,10 +9,10 @@ use thiserror::Error;
 
 use uv_distribution_types::RequiresPython;
 use uv_fs::Simplified;
-use uv_normalize::{DEV_DEPENDENCIES, GroupName};
+use uv_normalize::{DEV_DEPENDENCIES, DefaultGroups, ExtraName, GroupName, PackageName};
 use uv_pep440::VersionSpecifiers;
-use uv_pep508::Pep508Error;
-use uv_pypi_types::{DependencyGroupSpecifier, VerbatimParsedUrl};
+use uv_pep508::{MarkerExpression, MarkerOperator, MarkerTree, MarkerValueString, Pep508Error};
+use uv_pypi_types::{DependencyGroupSpecifier, SupportedEnvironments, VerbatimParsedUrl};
 
 use crate::pyproject::{DependencyGroupSettings, PyProjectToml, ToolUvDependencyGroups};
 
@@ -249,6 +252,463 @@ impl IntoIterator for FlatDependencyGroups {
     }
 }
 
+/// A likely mistake in a `dependency-groups` entry, e.g., a marker that can never be satisfied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupMarkerDiagnostic {
+    /// The path to the `pyproject.toml` that declares the group.
+    pub path: PathBuf,
+    /// The name of the group.
+    pub group: GroupName,
+    /// The (zero-based) index of the entry within the group.
+    pub index: usize,
+    pub kind: GroupMarkerDiagnosticKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GroupMarkerDiagnosticKind {
+    /// The entry compares a marker variable to a value it's never known to take, e.g.,
+    /// `sys_platform == "linx"`.
+    UnknownMarkerValue {
+        key: MarkerValueString,
+        value: String,
+    },
+    /// The entry's markers can't be satisfied in any supported environment.
+    NeverSatisfied { marker: String },
+    /// The entry references the project itself with an extra that the project doesn't declare.
+    MissingExtra {
+        package: PackageName,
+        extra: ExtraName,
+    },
+}
+
+impl fmt::Display for GroupMarkerDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Entry `dependency-groups.{}[{}]` in `{}` ",
+            self.group,
+            self.index,
+            self.path.user_display()
+        )?;
+        match &self.kind {
+            GroupMarkerDiagnosticKind::UnknownMarkerValue { key, value } => write!(
+                f,
+                "compares `{key}` to an unknown value (`\"{value}\"`), so it may never be installed"
+            ),
+            GroupMarkerDiagnosticKind::NeverSatisfied { marker } => write!(
+                f,
+                "has markers (`{marker}`) that are never satisfied given the `requires-python` and `tool.uv.environments` of the workspace"
+            ),
+            GroupMarkerDiagnosticKind::MissingExtra { package, extra } => write!(
+                f,
+                "requests the extra `{extra}`, but `{package}` does not declare it in `project.optional-dependencies`"
+            ),
+        }
+    }
+}
+
+/// Check the entries of the `dependency-groups` in a `pyproject.toml` for likely mistakes in their
+/// markers and extras.
+///
+/// Entries that fail to parse are skipped, as they're reported when the groups are flattened.
+pub fn check_group_markers(
+    path: &Path,
+    pyproject_toml: &PyProjectToml,
+    requires_python: Option<&RequiresPython>,
+    environments: Option<&SupportedEnvironments>,
+) -> Vec<GroupMarkerDiagnostic> {
+    // Determine the markers under which the workspace can be installed.
+    let mut supported = requires_python.map_or(MarkerTree::TRUE, RequiresPython::to_marker_tree);
+    if let Some(environments) = environments.filter(|environments| !environments.is_empty()) {
+        let mut union = MarkerTree::FALSE;
+        for environment in environments {
+            union.or(*environment);
+        }
+        supported.and(union);
+    }
+
+    let mut diagnostics = Vec::new();
+    for (group, specifiers) in pyproject_toml.dependency_groups.iter().flatten() {
+        for (index, specifier) in specifiers.iter().enumerate() {
+            let DependencyGroupSpecifier::Requirement(requirement) = specifier else {
+                continue;
+            };
+            let Ok(requirement) =
+                uv_pep508::Requirement::<VerbatimParsedUrl>::from_str(requirement)
+            else {
+                continue;
+            };
+            let mut push = |kind| {
+                diagnostics.push(GroupMarkerDiagnostic {
+                    path: path.to_path_buf(),
+                    group: group.clone(),
+                    index,
+                    kind,
+                });
+            };
+
+            // Flag comparisons against values that the marker variable never takes.
+            let mut unknown = Vec::new();
+            for expression in requirement.marker.to_dnf().into_iter().flatten() {
+                if let MarkerExpression::String {
+                    key,
+                    operator: MarkerOperator::Equal | MarkerOperator::NotEqual,
+                    value,
+                } = expression
+                {
+                    if !is_known_marker_value(key, &value)
+                        && !unknown.contains(&(key, value.clone()))
+                    {
+                        unknown.push((key, value));
+                    }
+                }
+            }
+            for (key, value) in unknown {
+                push(GroupMarkerDiagnosticKind::UnknownMarkerValue {
+                    key,
+                    value: value.to_string(),
+                });
+            }
+
+            // Flag markers that are disjoint with the supported environments.
+            if !requirement.marker.is_true() {
+                let mut marker = requirement.marker;
+                marker.and(supported);
+                if marker.is_false() {
+                    push(GroupMarkerDiagnosticKind::NeverSatisfied {
+                        marker: requirement
+                            .marker
+                            .contents()
+                            .map(|contents| contents.to_string())
+                            .unwrap_or_else(|| "false".to_string()),
+                    });
+                }
+            }
+
+            // Flag self-referential entries that request undeclared extras.
+            if let Some(project) = pyproject_toml
+                .project
+                .as_ref()
+                .filter(|project| project.name == requirement.name)
+            {
+                for extra in &requirement.extras {
+                    if !project
+                        .optional_dependencies
+                        .as_ref()
+                        .is_some_and(|optional| optional.contains_key(extra))
+                    {
+                        push(GroupMarkerDiagnosticKind::MissingExtra {
+                            package: project.name.clone(),
+                            extra: extra.clone(),
+                        });
+                    }
+                }
+            }
+        }
+    }
+    diagnostics
+}
+
+/// An inconsistency between the extras and dependency groups of a `pyproject.toml`, e.g., an extra
+/// and a group that share a name but differ in their contents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupCollisionDiagnostic {
+    /// The path to the `pyproject.toml` that declares the extras and groups.
+    pub path: PathBuf,
+    pub kind: GroupCollisionDiagnosticKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GroupCollisionDiagnosticKind {
+    /// An extra and a group share a name, but differ in their contents.
+    Mismatch {
+        name: GroupName,
+        extra: Vec<String>,
+        group: Vec<String>,
+    },
+    /// A group is named `all`, which is conventionally the extra that enables all other extras.
+    AllGroup {
+        group: Vec<String>,
+        extras: Vec<ExtraName>,
+    },
+    /// A `tool.uv.default-groups` entry refers to an extra, rather than a group.
+    DefaultGroupIsExtra { name: GroupName, extra: Vec<String> },
+}
+
+impl fmt::Display for GroupCollisionDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = self.path.user_display();
+        match &self.kind {
+            GroupCollisionDiagnosticKind::Mismatch { name, extra, group } => {
+                writeln!(
+                    f,
+                    "The extra `{name}` and the dependency group `{name}` in `{path}` have different contents:"
+                )?;
+                write_definitions(
+                    f,
+                    &[
+                        (
+                            "[project.optional-dependencies]",
+                            name.as_str(),
+                            extra.as_slice(),
+                        ),
+                        ("[dependency-groups]", name.as_str(), group.as_slice()),
+                    ],
+                )
+            }
+            GroupCollisionDiagnosticKind::AllGroup { group, extras } => {
+                writeln!(
+                    f,
+                    "The dependency group `all` in `{path}` shadows the conventional `all` extra, but doesn't enable any extras (use `--all-extras` to enable all extras):"
+                )?;
+                let extras = extras
+                    .iter()
+                    .map(|extra| quote(extra.as_str()))
+                    .collect::<Vec<_>>();
+                write_definitions(
+                    f,
+                    &[
+                        ("[dependency-groups]", "all", group.as_slice()),
+                        (
+                            "[project.optional-dependencies]",
+                            "(names)",
+                            extras.as_slice(),
+                        ),
+                    ],
+                )
+            }
+            GroupCollisionDiagnosticKind::DefaultGroupIsExtra { name, extra } => {
+                writeln!(
+                    f,
+                    "`tool.uv.default-groups` in `{path}` includes `{name}`, which is an extra, not a dependency group:"
+                )?;
+                let default = [quote(name.as_str())];
+                write_definitions(
+                    f,
+                    &[
+                        ("[tool.uv]", "default-groups", default.as_slice()),
+                        (
+                            "[project.optional-dependencies]",
+                            name.as_str(),
+                            extra.as_slice(),
+                        ),
+                    ],
+                )
+            }
+        }
+    }
+}
+
+/// Write a set of TOML definitions, aligned such that they can be compared side by side.
+fn write_definitions(
+    f: &mut fmt::Formatter<'_>,
+    definitions: &[(&str, &str, &[String])],
+) -> fmt::Result {
+    let table_width = definitions
+        .iter()
+        .map(|(table, ..)| table.len())
+        .max()
+        .unwrap_or_default();
+    let key_width = definitions
+        .iter()
+        .map(|(_, key, _)| key.len())
+        .max()
+        .unwrap_or_default();
+    for (index, (table, key, values)) in definitions.iter().enumerate() {
+        if index > 0 {
+            writeln!(f)?;
+        }
+        write!(
+            f,
+            "  {table:table_width$} {key:key_width$} = [{}]",
+            values.join(", ")
+        )?;
+    }
+    Ok(())
+}
+
+/// Check the extras and `dependency-groups` of a `pyproject.toml` for inconsistencies.
+pub fn check_group_collisions(
+    path: &Path,
+    pyproject_toml: &PyProjectToml,
+) -> Vec<GroupCollisionDiagnostic> {
+    let extras = pyproject_toml
+        .project
+        .as_ref()
+        .and_then(|project| project.optional_dependencies.as_ref());
+    let groups = pyproject_toml.dependency_groups.as_ref();
+
+    let mut diagnostics = Vec::new();
+    let mut push = |kind| {
+        diagnostics.push(GroupCollisionDiagnostic {
+            path: path.to_path_buf(),
+            kind,
+        });
+    };
+
+    if let (Some(extras), Some(groups)) = (extras, groups) {
+        // Flag extras and groups that share a name, but not their contents.
+        for (name, extra) in extras {
+            let Ok(name) = GroupName::from_str(name.as_str()) else {
+                continue;
+            };
+            let Some(group) = groups.get(&name) else {
+                continue;
+            };
+            let contents = group.iter().map(|specifier| match specifier {
+                DependencyGroupSpecifier::Requirement(requirement) => requirement.clone(),
+                specifier => display_specifier(specifier),
+            });
+            if normalize_requirements(extra.iter().cloned()) != normalize_requirements(contents) {
+                push(GroupCollisionDiagnosticKind::Mismatch {
+                    name,
+                    extra: extra.iter().map(|requirement| quote(requirement)).collect(),
+                    group: group.iter().map(display_specifier).collect(),
+                });
+            }
+        }
+
+        // Flag an `all` group, which users may expect to enable all extras, as an `all` extra
+        // conventionally would.
+        if let Some(group) = groups
+            .keys()
+            .find(|group| group.as_str() == "all")
+            .and_then(|all| groups.get(all))
+        {
+            if !extras.is_empty() && !extras.keys().any(|extra| extra.as_str() == "all") {
+                push(GroupCollisionDiagnosticKind::AllGroup {
+                    group: group.iter().map(display_specifier).collect(),
+                    extras: extras.keys().cloned().collect(),
+                });
+            }
+        }
+    }
+
+    // Flag default groups that are actually extras.
+    if let Some(DefaultGroups::List(defaults)) = pyproject_toml
+        .tool
+        .as_ref()
+        .and_then(|tool| tool.uv.as_ref())
+        .and_then(|uv| uv.default_groups.as_ref())
+    {
+        for name in defaults {
+            if groups.is_some_and(|groups| groups.contains_key(name)) {
+                continue;
+            }
+            let Some(extra) = extras.and_then(|extras| {
+                extras.iter().find_map(|(extra, requirements)| {
+                    (extra.as_str() == name.as_str()).then_some(requirements)
+                })
+            }) else {
+                continue;
+            };
+            push(GroupCollisionDiagnosticKind::DefaultGroupIsExtra {
+                name: name.clone(),
+                extra: extra.iter().map(|requirement| quote(requirement)).collect(),
+            });
+        }
+    }
+
+    diagnostics
+}
+
+/// Format a `dependency-groups` entry as it would appear in a `pyproject.toml`.
+fn display_specifier(specifier: &DependencyGroupSpecifier) -> String {
+    match specifier {
+        DependencyGroupSpecifier::Requirement(requirement) => quote(requirement),
+        DependencyGroupSpecifier::IncludeGroup { include_group } => {
+            format!("{{ include-group = {} }}", quote(include_group.as_str()))
+        }
+        DependencyGroupSpecifier::Object(object) => {
+            let entries = object
+                .iter()
+                .map(|(key, value)| format!("{key} = {}", quote(value)))
+                .collect::<Vec<_>>();
+            format!("{{ {} }}", entries.join(", "))
+        }
+    }
+}
+
+/// Quote a string as a TOML basic string.
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Normalize a list of requirements, such that differences in formatting and order are ignored.
+fn normalize_requirements(requirements: impl Iterator<Item = String>) -> BTreeSet<String> {
+    requirements
+        .map(|requirement| {
+            uv_pep508::Requirement::<VerbatimParsedUrl>::from_str(&requirement)
+                .map(|requirement| requirement.to_string())
+                .unwrap_or_else(|_| requirement.trim().to_string())
+        })
+        .collect()
+}
+
+/// Returns `true` if the value is known to be taken by the given marker variable.
+///
+/// Variables whose values aren't drawn from a well-known set (e.g., `platform_release`) accept any
+/// value.
+fn is_known_marker_value(key: MarkerValueString, value: &str) -> bool {
+    match key {
+        MarkerValueString::SysPlatform | MarkerValueString::SysPlatformDeprecated => {
+            matches!(
+                value,
+                "aix"
+                    | "android"
+                    | "cygwin"
+                    | "darwin"
+                    | "emscripten"
+                    | "ios"
+                    | "linux"
+                    | "linux2"
+                    | "msys"
+                    | "sunos5"
+                    | "wasi"
+                    | "win32"
+            ) || ["freebsd", "netbsd", "openbsd", "dragonfly"]
+                .iter()
+                .any(|prefix| value.starts_with(prefix))
+        }
+        MarkerValueString::PlatformSystem => {
+            matches!(
+                value,
+                "AIX"
+                    | "Android"
+                    | "Darwin"
+                    | "DragonFly"
+                    | "Emscripten"
+                    | "FreeBSD"
+                    | "Java"
+                    | "Linux"
+                    | "NetBSD"
+                    | "OpenBSD"
+                    | "SunOS"
+                    | "Windows"
+                    | "iOS"
+                    | "iPadOS"
+            ) || value.starts_with("CYGWIN")
+                || value.starts_with("MSYS")
+        }
+        MarkerValueString::OsName | MarkerValueString::OsNameDeprecated => {
+            matches!(value, "posix" | "nt" | "java")
+        }
+        MarkerValueString::ImplementationName => {
+            matches!(
+                value,
+                "cpython" | "pypy" | "graalpy" | "ironpython" | "jython"
+            )
+        }
+        MarkerValueString::PlatformPythonImplementation
+        | MarkerValueString::PlatformPythonImplementationDeprecated
+        | MarkerValueString::PythonImplementationDeprecated => matches!(
+            value,
+            "CPython" | "PyPy" | "GraalVM" | "IronPython" | "Jython"
+        ),
+        _ => true,
+    }
+}
+
 #[derive(Debug, Error)]
 #[error("{} has malformed dependency groups", if path.is_empty() && package.is_empty() {
     "Project".to_string()
@@ -0,0 +1,348 @@
+//! A persistent cache of the members discovered for a workspace.
+//!
+//! Discovering the members of a large workspace requires expanding the member globs, and reading
+//! and parsing the `pyproject.toml` of every member. To avoid repeating that work on every
+//! invocation, the result of a discovery is persisted in the cache, keyed by the workspace root,
+//! along with:
+//!
+//! * The modification time and size of each member's `pyproject.toml`.
+//! * The modification time of each directory listed while expanding the member globs, such that
+//!   added and removed members are detected.
+//!
+//! Each entry is revalidated with a single `stat` per member and directory. If anything changed,
+//! or was modified no earlier than the entry was written, the entry is discarded and the members
+//! are discovered from scratch.
+//!
+//! Only the tables of each member's `pyproject.toml` that are read into a [`PyProjectToml`] are
+//! persisted. The raw document is read again from disk when the entry is used, but unrelated
+//! metadata and tool configuration aren't parsed again.
+
+use std::path::{Component, Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use uv_cache_info::Timestamp;
+use uv_fs::Simplified;
+
+use crate::pyproject::{PyProjectToml, ToolUvWorkspace};
+
+/// The members discovered for a workspace, along with the stamps required to revalidate them.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct DiscoveredMembers {
+    /// The `tool.uv.workspace.members` globs the members were discovered with.
+    members: Vec<String>,
+    /// The `tool.uv.workspace.exclude` globs the members were discovered with.
+    exclude: Vec<String>,
+    /// The directories listed while expanding the member globs.
+    directories: Vec<(PathBuf, Timestamp)>,
+    /// The directories matched by the member globs, in discovery order.
+    candidates: Vec<Candidate>,
+    /// Whether the discovery can't be revalidated from the recorded stamps alone, e.g., because
+    /// it depended on the contents of a nested directory.
+    #[serde(skip)]
+    uncacheable: bool,
+}
+
+/// A directory matched by a member glob.
+#[derive(Debug, Serialize, Deserialize)]
+struct Candidate {
+    /// The directory that was matched.
+    root: PathBuf,
+    /// The `pyproject.toml` in the directory, or `None` if the directory was skipped for lacking
+    /// one.
+    manifest: Option<Manifest>,
+}
+
+/// The `pyproject.toml` of a candidate member.
+#[derive(Debug, Serialize, Deserialize)]
+struct Manifest {
+    /// The modification time of the `pyproject.toml`.
+    timestamp: Timestamp,
+    /// The size of the `pyproject.toml`, in bytes.
+    size: u64,
+    /// The tables of the `pyproject.toml` that are read into a [`PyProjectToml`].
+    relevant: String,
+}
+
+impl DiscoveredMembers {
+    /// Start recording a discovery for the given workspace definition.
+    pub(crate) fn new(definition: &ToolUvWorkspace) -> Self {
+        let (members, exclude) = globs(definition);
+        Self {
+            members,
+            exclude,
+            directories: Vec::new(),
+            candidates: Vec::new(),
+            uncacheable: false,
+        }
+    }
+
+    /// Read the members persisted at the given path, if they were discovered with the same
+    /// workspace definition and none of the recorded stamps changed.
+    ///
+    /// Returns the root and parsed `pyproject.toml` of each member, in discovery order.
+    pub(crate) fn read(
+        path: &Path,
+        definition: &ToolUvWorkspace,
+    ) -> Option<Vec<(PathBuf, PyProjectToml)>> {
+        // Stamps that aren't older than the entry itself may have been modified again within the
+        // timestamp granularity after they were recorded, so they can't be trusted.
+        let written = Timestamp::from_path(path).ok()?;
+        let data = fs_err::read(path).ok()?;
+        let cached = match rmp_serde::from_slice::<Self>(&data) {
+            Ok(cached) => cached,
+            Err(err) => {
+                debug!(
+                    "Ignoring invalid workspace discovery cache entry at `{}`: {err}",
+                    path.display()
+                );
+                return None;
+            }
+        };
+
+        let (member_globs, exclude_globs) = globs(definition);
+        if cached.members != member_globs || cached.exclude != exclude_globs {
+            debug!("Workspace definition changed since the members were cached");
+            return None;
+        }
+
+        for (directory, timestamp) in &cached.directories {
+            if *timestamp >= written
+                || Timestamp::from_path(directory).ok().as_ref() != Some(timestamp)
+            {
+                debug!(
+                    "Workspace directory changed since the members were cached: `{}`",
+                    directory.simplified_display()
+                );
+                return None;
+            }
+        }
+
+        let mut members = Vec::with_capacity(cached.candidates.len());
+        for candidate in cached.candidates {
+            let pyproject_path = candidate.root.join("pyproject.toml");
+            let Some(manifest) = candidate.manifest else {
+                // The candidate was skipped for lacking a `pyproject.toml`; ensure it still does.
+                if !matches!(pyproject_path.try_exists(), Ok(false)) {
+                    debug!(
+                        "Workspace member manifest added since the members were cached: `{}`",
+                        pyproject_path.simplified_display()
+                    );
+                    return None;
+                }
+                continue;
+            };
+
+            let unchanged = manifest.timestamp < written
+                && fs_err::metadata(&pyproject_path).is_ok_and(|metadata| {
+                    Timestamp::from_metadata(&metadata) == manifest.timestamp
+                        && metadata.len() == manifest.size
+                });
+            if !unchanged {
+                debug!(
+                    "Workspace member manifest changed since the members were cached: `{}`",
+                    pyproject_path.simplified_display()
+                );
+                return None;
+            }
+
+            // The raw document is retained by the `PyProjectToml` (e.g., for editing), but isn't
+            // persisted in the entry; ensure it matches the recorded size.
+            let Some(raw) = fs_err::read_to_string(&pyproject_path)
+                .ok()
+                .filter(|raw| raw.len() as u64 == manifest.size)
+            else {
+                debug!(
+                    "Workspace member manifest changed since the members were cached: `{}`",
+                    pyproject_path.simplified_display()
+                );
+                return None;
+            };
+
+            match PyProjectToml::from_relevant_tables(&manifest.relevant, raw) {
+                Ok(pyproject_toml) => members.push((candidate.root, pyproject_toml)),
+                Err(err) => {
+                    debug!(
+                        "Failed to parse cached workspace member manifest `{}`: {err}",
+                        pyproject_path.simplified_display()
+                    );
+                    return None;
+                }
+            }
+        }
+
+        Some(members)
+    }
+
+    /// Persist the members to the given path, if the discovery can be revalidated.
+    pub(crate) fn write(&self, path: &Path) {
+        if self.uncacheable {
+            debug!("Skipping workspace discovery cache for uncacheable member discovery");
+            return;
+        }
+
+        let Some(bucket) = path.parent() else {
+            return;
+        };
+        // Only populate an existing cache directory, rather than creating one at the cache root of
+        // an uninitialized cache.
+        if let Err(err) = fs_err::create_dir(bucket)
+            && err.kind() != std::io::ErrorKind::AlreadyExists
+        {
+            debug!(
+                "Failed to create workspace discovery cache bucket at `{}`: {err}",
+                bucket.display()
+            );
+            return;
+        }
+
+        let data = match rmp_serde::to_vec(self) {
+            Ok(data) => data,
+            Err(err) => {
+                debug!("Failed to serialize workspace discovery cache entry: {err}");
+                return;
+            }
+        };
+        if let Err(err) = uv_fs::write_atomic_sync(path, data) {
+            debug!(
+                "Failed to write workspace discovery cache entry to `{}`: {err}",
+                path.display()
+            );
+        }
+    }
+
+    /// Record the directories that are listed when expanding the given member glob, relative to
+    /// the workspace root.
+    ///
+    /// Must be called before the glob is expanded, such that any concurrent modification
+    /// invalidates the entry.
+    pub(crate) fn record_glob(&mut self, workspace_root: &Path, glob: &Path) {
+        if self.uncacheable {
+            return;
+        }
+
+        let components = glob.components().collect::<Vec<_>>();
+        let mut directories = if glob.is_absolute() {
+            vec![PathBuf::new()]
+        } else {
+            vec![workspace_root.to_path_buf()]
+        };
+        for (index, component) in components.iter().enumerate() {
+            let last = index + 1 == components.len();
+            let name = match component {
+                Component::Prefix(_) | Component::RootDir | Component::ParentDir => {
+                    for directory in &mut directories {
+                        directory.push(component);
+                    }
+                    continue;
+                }
+                Component::CurDir => continue,
+                Component::Normal(name) => name,
+            };
+
+            let Some(name) = name.to_str() else {
+                self.uncacheable = true;
+                return;
+            };
+
+            // A recursive wildcard would require recording every nested directory.
+            if name.contains("**") {
+                self.uncacheable = true;
+                return;
+            }
+
+            let mut matches = Vec::new();
+            if name.contains(['*', '?', '[']) {
+                let Ok(pattern) = glob::Pattern::new(name) else {
+                    self.uncacheable = true;
+                    return;
+                };
+                for directory in directories {
+                    // Record the directory before listing it.
+                    let Ok(timestamp) = Timestamp::from_path(&directory) else {
+                        continue;
+                    };
+                    let Ok(entries) = fs_err::read_dir(&directory) else {
+                        self.uncacheable = true;
+                        return;
+                    };
+                    self.directories.push((directory, timestamp));
+                    for entry in entries {
+                        let Ok(entry) = entry else {
+                            self.uncacheable = true;
+                            return;
+                        };
+                        let path = entry.path();
+                        if entry.file_name().to_str().is_some_and(|file_name| {
+                            pattern.matches(file_name) && (last || path.is_dir())
+                        }) {
+                            matches.push(path);
+                        }
+                    }
+                }
+            } else {
+                for directory in directories {
+                    let path = directory.join(name);
+                    let exists = if last { path.exists() } else { path.is_dir() };
+                    if exists {
+                        matches.push(path);
+                    } else if let Ok(timestamp) = Timestamp::from_path(&directory) {
+                        // Record the parent directory, to detect the path being created.
+                        self.directories.push((directory, timestamp));
+                    }
+                }
+            }
+            directories = matches;
+        }
+    }
+
+    /// Record a candidate member with the given `pyproject.toml`.
+    ///
+    /// The metadata must be read before the contents, such that any concurrent modification
+    /// invalidates the entry.
+    pub(crate) fn record_member(&mut self, root: PathBuf, metadata: &std::fs::Metadata, raw: &str) {
+        let Some(relevant) = PyProjectToml::relevant_tables(raw) else {
+            self.uncacheable = true;
+            return;
+        };
+        self.candidates.push(Candidate {
+            root,
+            manifest: Some(Manifest {
+                timestamp: Timestamp::from_metadata(metadata),
+                size: metadata.len(),
+                relevant,
+            }),
+        });
+    }
+
+    /// Record a candidate member that was skipped for lacking a `pyproject.toml`.
+    pub(crate) fn record_skipped(&mut self, root: PathBuf) {
+        self.candidates.push(Candidate {
+            root,
+            manifest: None,
+        });
+    }
+
+    /// Mark the discovery as depending on state that isn't captured by the recorded stamps.
+    pub(crate) fn mark_uncacheable(&mut self) {
+        self.uncacheable = true;
+    }
+}
+
+/// Return the member and exclude globs of a workspace definition.
+fn globs(definition: &ToolUvWorkspace) -> (Vec<String>, Vec<String>) {
+    let members = definition
+        .members
+        .iter()
+        .flatten()
+        .map(|glob| glob.as_str().to_string())
+        .collect();
+    let exclude = definition
+        .exclude
+        .iter()
+        .flatten()
+        .map(|glob| glob.as_str().to_string())
+        .collect();
+    (members, exclude)
+}
@@ -5,6 +5,7 @@ pub use workspace::{
 };
 
 pub mod dependency_groups;
+mod discovery_cache;
 pub mod pyproject;
 pub mod pyproject_mut;
 mod workspace;
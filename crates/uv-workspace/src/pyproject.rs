@@ -27,7 +27,7 @@ use uv_git_types::GitReference;
 use uv_macros::OptionsMetadata;
 use uv_normalize::{DefaultGroups, ExtraName, GroupName, PackageName};
 use uv_options_metadata::{OptionSet, OptionsMetadata, Visit};
-use uv_pep440::{Version, VersionSpecifiers};
+use uv_pep440::{Operator, Version, VersionSpecifier, VersionSpecifiers};
 use uv_pep508::MarkerTree;
 use uv_pypi_types::{
     ConflictError, Conflicts, DependencyGroups, SchemaConflicts, SupportedEnvironments,
@@ -112,6 +112,55 @@ impl PyProjectToml {
         Ok(Self { raw, ..pyproject })
     }
 
+    /// Extract the tables of a raw `pyproject.toml` that are read into a [`PyProjectToml`],
+    /// omitting unrelated metadata and tool configuration.
+    ///
+    /// Returns `None` if the document can't be parsed or re-serialized.
+    pub(crate) fn relevant_tables(raw: &str) -> Option<String> {
+        let mut document = toml::from_str::<toml::Table>(raw).ok()?;
+        let mut relevant = toml::Table::new();
+        if let Some(mut project) = document.remove("project") {
+            if let Some(project) = project.as_table_mut() {
+                project.retain(|key, _| {
+                    matches!(
+                        key,
+                        "name"
+                            | "version"
+                            | "dynamic"
+                            | "requires-python"
+                            | "dependencies"
+                            | "optional-dependencies"
+                            | "gui-scripts"
+                            | "scripts"
+                    )
+                });
+            }
+            relevant.insert("project".to_string(), project);
+        }
+        if let Some(mut tool) = document.remove("tool") {
+            if let Some(tool) = tool.as_table_mut() {
+                tool.retain(|key, _| key == "uv");
+            }
+            relevant.insert("tool".to_string(), tool);
+        }
+        for key in ["dependency-groups", "build-system"] {
+            if let Some(value) = document.remove(key) {
+                relevant.insert(key.to_string(), value);
+            }
+        }
+        toml::to_string(&relevant).ok()
+    }
+
+    /// Parse a `PyProjectToml` from the tables returned by [`PyProjectToml::relevant_tables`],
+    /// retaining the full raw document.
+    pub(crate) fn from_relevant_tables(
+        relevant: &str,
+        raw: String,
+    ) -> Result<Self, PyprojectTomlError> {
+        let pyproject: Self = toml::from_str(relevant).map_err(PyprojectTomlError::Toml)?;
+        Ok(Self { raw, ..pyproject })
+    }
+
     /// Returns `true` if the project should be considered a Python package, as opposed to a
     /// non-package ("virtual") project.
     pub fn is_package(&self, require_build_system: bool) -> bool {
@@ -141,6 +190,71 @@ impl PyProjectToml {
         }
     }
 
+    /// Returns the object reference (e.g., `mycli.main:main`) of the console script with the
+    /// given name, if declared in `project.scripts`.
+    pub fn script_entrypoint(&self, name: &str) -> Option<String> {
+        #[derive(Deserialize)]
+        struct PyProjectTomlScriptsWire {
+            project: Option<ProjectScriptsWire>,
+        }
+
+        #[derive(Deserialize)]
+        struct ProjectScriptsWire {
+            scripts: Option<BTreeMap<String, String>>,
+        }
+
+        // Avoid re-parsing the manifest if there are no scripts.
+        self.project.as_ref()?.scripts.as_ref()?;
+
+        toml::from_str::<PyProjectTomlScriptsWire>(&self.raw)
+            .ok()?
+            .project?
+            .scripts?
+            .remove(name)
+    }
+
+    /// Returns the `module-root` configured for the uv build backend in
+    /// `tool.uv.build-backend`, if any.
+    pub fn build_backend_module_root(&self) -> Option<PathBuf> {
+        #[derive(Deserialize)]
+        struct PyProjectTomlToolWire {
+            tool: Option<ToolWire>,
+        }
+
+        #[derive(Deserialize)]
+        struct ToolWire {
+            uv: Option<ToolUvWire>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "kebab-case")]
+        struct ToolUvWire {
+            build_backend: Option<BuildBackendWire>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "kebab-case")]
+        struct BuildBackendWire {
+            module_root: Option<PathBuf>,
+        }
+
+        toml::from_str::<PyProjectTomlToolWire>(&self.raw)
+            .ok()?
+            .tool?
+            .uv?
+            .build_backend?
+            .module_root
+    }
+
+    /// Returns the script with the given name, if declared in `tool.uv.scripts`.
+    pub fn project_script(&self, name: &str) -> Option<&ProjectScript> {
+        self.tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.scripts.as_ref())
+            .and_then(|scripts| scripts.get(name))
+    }
+
     /// Returns the set of conflicts for the project.
     pub(crate) fn conflicts(&self) -> Result<Conflicts, ConflictError> {
         let empty = Conflicts::empty();
@@ -215,6 +329,13 @@ struct ProjectWire {
     scripts: Option<serde::de::IgnoredAny>,
 }
 
+impl Project {
+    /// The static version of the project, if not declared as dynamic.
+    pub fn version(&self) -> Option<&Version> {
+        self.version.as_ref()
+    }
+}
+
 impl TryFrom<ProjectWire> for Project {
     type Error = PyprojectTomlError;
 
@@ -387,6 +508,78 @@ pub struct ToolUv {
     )]
     package: Option<bool>,
 
+    /// A requirements file from which to read the project's dependencies when
+    /// `project.dependencies` is declared as dynamic.
+    ///
+    /// Projects that read their dependencies from a file at build time (e.g., via setuptools'
+    /// `tool.setuptools.dynamic.dependencies`) otherwise require uv to build the project to
+    /// determine its dependencies. With this setting, uv reads the file directly when resolving,
+    /// while leaving the actual build to the build backend. If the metadata produced by the build
+    /// diverges from the file, uv will emit a warning.
+    ///
+    /// The path is relative to the project root. The file may only contain requirements (one per
+    /// line) and comments; files that include pip options (e.g., `-r` or `--index-url`) are
+    /// ignored, and uv falls back to building the project.
+    ///
+    /// Only respected for local source trees, such as workspace members and path dependencies.
+    #[option(
+        default = r#"None"#,
+        value_type = "str",
+        example = r#"
+            static-dependencies = "requirements.in"
+        "#
+    )]
+    static_dependencies: Option<PortablePathBuf>,
+
+    /// Named commands that can be invoked with `uv run <name>`.
+    ///
+    /// Each script is either a single command, or a list of commands that are run in order,
+    /// stopping at the first command that fails. Commands are run in the project environment, like
+    /// any other `uv run` invocation, using the system shell (`sh` on Unix, `cmd` on Windows).
+    ///
+    /// Any additional arguments passed to `uv run <name>` are substituted for the `{args}`
+    /// placeholder. If no command contains the placeholder, the arguments are appended to the
+    /// last command.
+    ///
+    /// If a script has the same name as an executable, the script takes precedence; pass
+    /// `--no-project-scripts` to `uv run` to run the executable instead.
+    #[option(
+        default = "{}",
+        value_type = "dict[str, str | list[str]]",
+        example = r#"
+            [tool.uv.scripts]
+            test = "pytest -x {args}"
+            lint = ["ruff check .", "ruff format --check ."]
+        "#
+    )]
+    pub scripts: Option<BTreeMap<String, ProjectScript>>,
+
+    /// Commands to run in the project environment after it's synced.
+    ///
+    /// The commands are run in order after a successful `uv sync`, and after `uv run` syncs the
+    /// project environment, from the workspace root using the system shell (`sh` on Unix, `cmd` on
+    /// Windows). As in `uv run`, the project environment is activated for each command. `UV_NO_SYNC`
+    /// and `UV_NO_POST_SYNC` are set for each command, so nested `uv run` invocations don't sync
+    /// the environment and nested `uv sync` invocations don't run the commands again.
+    ///
+    /// If a command fails, uv exits with its exit code and the remaining commands are skipped.
+    /// Pass `--no-post-sync` to `uv sync` or `uv run` to skip the commands.
+    ///
+    /// !!! note
+    ///     uv will only read `post-sync` from the `pyproject.toml` at the workspace root, and will
+    ///     ignore any declarations in other workspace members.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            post-sync = [
+                "python -m compileall -q src",
+                "python -m grpc_tools.protoc -I proto --python_out=src proto/service.proto",
+            ]
+        "#
+    )]
+    pub post_sync: Option<Vec<String>>,
+
     /// The list of `dependency-groups` to install by default.
     ///
     /// Can also be the literal `"all"` to default enable all groups.
@@ -417,6 +610,90 @@ pub struct ToolUv {
     )]
     pub(crate) dependency_groups: Option<ToolUvDependencyGroups>,
 
+    /// Whether to treat inconsistencies in the project configuration as errors.
+    ///
+    /// By default, `uv lock` warns when an extra and a dependency group share a name but differ in
+    /// their contents, when a dependency group shadows a conventional extra name (e.g., `all`), or
+    /// when `default-groups` refers to an extra rather than a group. When enabled, these warnings
+    /// are raised as errors instead.
+    ///
+    /// Use `uv lock --check-config` to run these checks without locking.
+    ///
+    /// !!! note
+    ///     uv will only read `strict-config` from the `pyproject.toml` at the workspace root, and will
+    ///     ignore any declarations in other workspace members.
+    #[option(
+        default = r#"false"#,
+        value_type = "bool",
+        example = r#"
+            strict-config = true
+        "#
+    )]
+    pub strict_config: Option<bool>,
+
+    /// Workspace members to install from a built wheel, rather than as editables.
+    ///
+    /// By default, `uv sync` and `uv run` install workspace members in editable mode. Packages
+    /// listed here are instead built and installed as regular wheels, e.g., for members that
+    /// include native extensions which need to be rebuilt to pick up changes.
+    ///
+    /// These packages are combined with those passed via `--no-editable-package`. Passing
+    /// `--editable` or `--no-editable` takes precedence over this setting.
+    ///
+    /// !!! note
+    ///     uv will only read `no-editable-package` from the `pyproject.toml` at the workspace
+    ///     root, and will ignore any declarations in other workspace members.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            no-editable-package = ["native-member"]
+        "#
+    )]
+    pub no_editable_package: Option<Vec<PackageName>>,
+
+    /// Whether to record the provenance of each package locked from an index in `uv.lock`.
+    ///
+    /// When enabled, `uv lock` records, for each package locked from an index, the index it was
+    /// selected from (by URL, and by name, if the index is named), the time at which it was locked,
+    /// and the upload time reported by the index for each of its artifacts. The provenance is
+    /// stored in a dedicated `[[provenance]]` table, and is never used to determine whether the
+    /// lockfile is up-to-date, so toggling this setting does not require re-resolving.
+    ///
+    /// Use `uv lock --show-provenance <package>` to display the recorded provenance for a package.
+    ///
+    /// !!! note
+    ///     uv will only read `lock-provenance` from the `pyproject.toml` at the workspace root, and
+    ///     will ignore any declarations in other workspace members.
+    #[option(
+        default = r#"false"#,
+        value_type = "bool",
+        example = r#"
+            lock-provenance = true
+        "#
+    )]
+    pub lock_provenance: Option<bool>,
+
+    /// The path to the project's virtual environment.
+    ///
+    /// By default, the project environment is created at `.venv` in the workspace root. If a
+    /// relative path is provided, it is resolved relative to the workspace root.
+    ///
+    /// This setting is written by `uv venv --move`, which relocates the project environment, and
+    /// is ignored if the `UV_PROJECT_ENVIRONMENT` environment variable is set.
+    ///
+    /// !!! note
+    ///     uv will only read `project-environment` from the `pyproject.toml` at the workspace root,
+    ///     and will ignore any declarations in other workspace members.
+    #[option(
+        default = r#"".venv""#,
+        value_type = "str",
+        example = r#"
+            project-environment = "/scratch/my-project/.venv"
+        "#
+    )]
+    pub project_environment: Option<PathBuf>,
+
     /// The project's development dependencies.
     ///
     /// Development dependencies will be installed by default in `uv run` and `uv sync`, but will
@@ -692,6 +969,36 @@ pub struct ToolUv {
     )]
     pub(crate) conflicts: Option<SchemaConflicts>,
 
+    /// The policy to apply to workspace dependencies that lack a version specifier in
+    /// `project.dependencies`.
+    ///
+    /// Within the workspace, a dependency on another member (with `workspace = true` in
+    /// `tool.uv.sources`) is always satisfied by the local member. However, the published
+    /// metadata of a member only contains the requirement as declared in `project.dependencies`,
+    /// so a workspace dependency without a version specifier will accept _any_ version of the
+    /// sibling package once published.
+    ///
+    /// - `warn`: Warn about unbounded workspace dependencies in `uv lock` and `uv build`.
+    /// - `exact`: When building a member with `uv build`, pin unbounded workspace dependencies to
+    ///   the current version of the sibling member, e.g., `==1.2.3`.
+    /// - `compatible`: When building a member with `uv build`, constrain unbounded workspace
+    ///   dependencies to a compatible release of the sibling member, e.g., `~=1.2.3`.
+    ///
+    /// Pins are applied to the built source distribution and wheel, but the `pyproject.toml` in
+    /// the workspace is left unchanged.
+    ///
+    /// !!! note
+    ///     uv will only read `workspace-pin-policy` from the `pyproject.toml` at the workspace
+    ///     root, and will ignore any declarations in other workspace members.
+    #[option(
+        default = r#"None"#,
+        value_type = r#"str"#,
+        example = r#"
+            workspace-pin-policy = "exact"
+        "#
+    )]
+    pub workspace_pin_policy: Option<WorkspacePinPolicy>,
+
     // Only exists on this type for schema and docs generation, the build backend settings are
     // never merged in a workspace and read separately by the backend code.
     /// Configuration for the uv build backend.
@@ -1242,6 +1549,77 @@ pub enum Source {
     },
 }
 
+/// The policy to apply to workspace dependencies without a version specifier.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(test, derive(Serialize))]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum WorkspacePinPolicy {
+    /// Warn about workspace dependencies without a version specifier.
+    Warn,
+    /// Pin workspace dependencies to the current version of the member, e.g., `==1.2.3`.
+    Exact,
+    /// Constrain workspace dependencies to a compatible release of the member, e.g., `~=1.2.3`.
+    Compatible,
+}
+
+impl WorkspacePinPolicy {
+    /// Returns the version specifiers to inject for a workspace dependency on a member at the
+    /// given version, if any.
+    pub fn specifiers(self, version: &Version) -> Option<VersionSpecifiers> {
+        match self {
+            Self::Warn => None,
+            Self::Exact => Some(VersionSpecifiers::from(VersionSpecifier::equals_version(
+                version.clone(),
+            ))),
+            Self::Compatible => {
+                // Compatible release clauses require at least two release segments, and may not
+                // include a local version.
+                let mut version = version.clone().without_local();
+                if version.release().len() < 2 {
+                    let major = version.release().first().copied().unwrap_or(0);
+                    version = version.with_release([major, 0]);
+                }
+                VersionSpecifier::from_version(Operator::TildeEqual, version)
+                    .ok()
+                    .map(VersionSpecifiers::from)
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for WorkspacePinPolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Warn => write!(f, "warn"),
+            Self::Exact => write!(f, "exact"),
+            Self::Compatible => write!(f, "compatible"),
+        }
+    }
+}
+
+/// A named command declared in `tool.uv.scripts`.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema), schemars(untagged))]
+#[serde(untagged)]
+pub enum ProjectScript {
+    /// A single command.
+    Command(String),
+    /// A list of commands, run in order until one fails.
+    Commands(Vec<String>),
+}
+
+impl ProjectScript {
+    /// Returns the commands to run, in order.
+    pub fn commands(&self) -> &[String] {
+        match self {
+            Self::Command(command) => std::slice::from_ref(command),
+            Self::Commands(commands) => commands,
+        }
+    }
+}
+
 /// A reference to either the current workspace or a workspace discovered from a path.
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema), schemars(untagged))]
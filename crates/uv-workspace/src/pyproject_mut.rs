@@ -1,5 +1,5 @@
 use std::fmt::{Display, Formatter};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::{fmt, iter, mem};
 
@@ -277,8 +277,59 @@ impl PyProjectTomlMut {
     /// Adds a project to the workspace.
     pub fn add_workspace(&mut self, path: impl AsRef<Path>) -> Result<(), Error> {
         // Get or create `tool.uv.workspace.members`.
-        let members = self
+        let members = self.workspace_array("members")?;
+
+        // Add the path to the workspace.
+        members.push(PortablePath::from(path.as_ref()).to_string());
+
+        reformat_array_multiline(members);
+
+        Ok(())
+    }
+
+    /// Excludes a project from the workspace.
+    pub fn add_workspace_exclude(&mut self, path: impl AsRef<Path>) -> Result<(), Error> {
+        // Get or create `tool.uv.workspace.exclude`.
+        let exclude = self.workspace_array("exclude")?;
+
+        // Add the path to the exclusions.
+        exclude.push(PortablePath::from(path.as_ref()).to_string());
+
+        reformat_array_multiline(exclude);
+
+        Ok(())
+    }
+
+    /// Removes an entry from `tool.uv.workspace.members`.
+    ///
+    /// Returns `true` if the entry was found and removed.
+    pub fn remove_workspace_member(&mut self, member: &str) -> Result<bool, Error> {
+        let Some(members) = self
             .doc
+            .get_mut("tool")
+            .and_then(|tool| tool.get_mut("uv"))
+            .and_then(|tool_uv| tool_uv.get_mut("workspace"))
+            .and_then(|workspace| workspace.get_mut("members"))
+        else {
+            return Ok(false);
+        };
+        let members = members.as_array_mut().ok_or(Error::MalformedWorkspace)?;
+
+        let len = members.len();
+        members.retain(|entry| entry.as_str() != Some(member));
+        if members.len() == len {
+            return Ok(false);
+        }
+
+        reformat_array_multiline(members);
+
+        Ok(true)
+    }
+
+    /// Retrieves a mutable reference to the `tool.uv.workspace.<key>` array, creating it if
+    /// necessary.
+    fn workspace_array(&mut self, key: &str) -> Result<&mut Array, Error> {
+        self.doc
             .entry("tool")
             .or_insert(implicit())
             .as_table_mut()
@@ -291,17 +342,10 @@ impl PyProjectTomlMut {
             .or_insert(Item::Table(Table::new()))
             .as_table_mut()
             .ok_or(Error::MalformedWorkspace)?
-            .entry("members")
+            .entry(key)
             .or_insert(Item::Value(Value::Array(Array::new())))
             .as_array_mut()
-            .ok_or(Error::MalformedWorkspace)?;
-
-        // Add the path to the workspace.
-        members.push(PortablePath::from(path.as_ref()).to_string());
-
-        reformat_array_multiline(members);
-
-        Ok(())
+            .ok_or(Error::MalformedWorkspace)
     }
 
     /// Retrieves a mutable reference to the `project` [`Table`] of the TOML document, creating the
@@ -897,6 +941,35 @@ impl PyProjectTomlMut {
         Ok(())
     }
 
+    /// Set the version specifiers for any requirement on the given package in
+    /// `project.dependencies` that lacks a version specifier.
+    ///
+    /// Returns `true` if any requirement was modified.
+    pub fn pin_unbounded_dependency(
+        &mut self,
+        name: &PackageName,
+        specifiers: &VersionSpecifiers,
+    ) -> Result<bool, Error> {
+        let dependencies = self.dependencies_array()?;
+
+        let mut modified = false;
+        for (index, mut req) in find_dependencies(name, None, dependencies) {
+            let is_unbounded = match &req.version_or_url {
+                None => true,
+                Some(VersionOrUrl::VersionSpecifier(existing)) => existing.is_empty(),
+                Some(VersionOrUrl::Url(_)) => false,
+            };
+            if !is_unbounded {
+                continue;
+            }
+            req.version_or_url = Some(VersionOrUrl::VersionSpecifier(specifiers.clone()));
+            dependencies.replace(index, req.to_string());
+            modified = true;
+        }
+
+        Ok(modified)
+    }
+
     /// Get the TOML array for `project.dependencies`.
     fn dependencies_array(&mut self) -> Result<&mut Array, Error> {
         // Get or create `project.dependencies`.
@@ -1237,6 +1310,44 @@ impl PyProjectTomlMut {
             return Ok(());
         }
 
+        self.remove_source_if(name, |_| true)?;
+
+        Ok(())
+    }
+
+    /// Remove a `workspace = true` source for the given package from `tool.uv.sources`, even if
+    /// the dependency is still in use.
+    ///
+    /// Returns `true` if a source was removed.
+    pub fn remove_workspace_source(&mut self, name: &PackageName) -> Result<bool, Error> {
+        self.remove_source_if(name, |source| {
+            let is_workspace = |source: &dyn toml_edit::TableLike| {
+                source
+                    .get("workspace")
+                    .and_then(Item::as_bool)
+                    .unwrap_or(false)
+            };
+            match source {
+                Item::Value(Value::Array(sources)) => sources.iter().any(|source| {
+                    source
+                        .as_inline_table()
+                        .is_some_and(|source| is_workspace(source))
+                }),
+                source => source.as_table_like().is_some_and(is_workspace),
+            }
+        })
+    }
+
+    /// Remove the source for the given package from `tool.uv.sources` if it matches the
+    /// predicate, dropping the `tool.uv.sources` table if it becomes empty.
+    ///
+    /// Returns `true` if a source was removed.
+    fn remove_source_if(
+        &mut self,
+        name: &PackageName,
+        predicate: impl FnOnce(&Item) -> bool,
+    ) -> Result<bool, Error> {
+        let mut removed = false;
         if let Some(sources) = self
             .doc
             .get_mut("tool")
@@ -1249,8 +1360,11 @@ impl PyProjectTomlMut {
             .map(|sources| sources.as_table_mut().ok_or(Error::MalformedSources))
             .transpose()?
         {
-            if let Some(key) = find_source(name, sources) {
+            if let Some(key) = find_source(name, sources)
+                && sources.get(&key).is_some_and(predicate)
+            {
                 sources.remove(&key);
+                removed = true;
 
                 // Remove the `tool.uv.sources` table if it is empty.
                 if sources.is_empty() {
@@ -1268,7 +1382,7 @@ impl PyProjectTomlMut {
             }
         }
 
-        Ok(())
+        Ok(removed)
     }
 
     /// Returns `true` if the `tool.uv.dev-dependencies` table is present.
@@ -1390,6 +1504,65 @@ impl PyProjectTomlMut {
         dynamic.iter().any(|val| val.as_str() == Some("version"))
     }
 
+    /// Returns the file that `tool.uv.build-backend.version` reads the project version from, if
+    /// configured.
+    pub fn version_file(&self) -> Option<PathBuf> {
+        self.doc
+            .get("tool")?
+            .get("uv")?
+            .get("build-backend")?
+            .get("version")?
+            .get("file")?
+            .as_str()
+            .map(PathBuf::from)
+    }
+
+    /// Set `tool.uv.project-environment` to the given path, or remove it if `None`.
+    pub fn set_project_environment(&mut self, path: Option<&Path>) -> Result<(), Error> {
+        let Some(path) = path else {
+            let Some(tool) = self.doc.get_mut("tool").and_then(Item::as_table_like_mut) else {
+                return Ok(());
+            };
+            let Some(tool_uv) = tool.get_mut("uv").and_then(Item::as_table_like_mut) else {
+                return Ok(());
+            };
+            tool_uv.remove("project-environment");
+
+            // Remove the `tool.uv` and `tool` tables, if they're now empty.
+            if tool_uv.is_empty() {
+                tool.remove("uv");
+                if tool.is_empty() {
+                    self.doc.remove("tool");
+                }
+            }
+            return Ok(());
+        };
+
+        let value = Value::from(PortablePath::from(path).to_string());
+        let tool_uv = self
+            .doc
+            .entry("tool")
+            .or_insert(implicit())
+            .as_table_mut()
+            .ok_or(Error::MalformedWorkspace)?
+            .entry("uv")
+            .or_insert(Item::Table(Table::new()))
+            .as_table_like_mut()
+            .ok_or(Error::MalformedWorkspace)?;
+        if let Some(existing) = tool_uv
+            .get_mut("project-environment")
+            .and_then(Item::as_value_mut)
+        {
+            let mut formatted = value;
+            *formatted.decor_mut() = existing.decor().clone();
+            *existing = formatted;
+        } else {
+            tool_uv.insert("project-environment", Item::Value(value));
+        }
+
+        Ok(())
+    }
+
     pub fn set_version(&mut self, version: &Version) -> Result<(), Error> {
         let project = self
             .doc
@@ -1985,7 +2158,7 @@ mod test {
     use toml_edit::DocumentMut;
     use uv_distribution_types::Index;
     use uv_normalize::{ExtraName, GroupName, PackageName};
-    use uv_pep440::Version;
+    use uv_pep440::{Version, VersionSpecifiers};
     use uv_pep508::{Requirement, RequirementOrigin};
 
     #[test]
@@ -2161,6 +2334,31 @@ dependencies = [
         }
     }
 
+    #[test]
+    fn pin_unbounded_dependency() -> Result<()> {
+        let mut pyproject = PyProjectTomlMut::from_toml(
+            r#"[project]
+dependencies = ["bird", "bird>=1 ; sys_platform == 'linux'", "bird ; sys_platform == 'win32'", "feather"]
+            "#,
+            DependencyTarget::PyProjectToml,
+        )?;
+
+        let modified = pyproject.pin_unbounded_dependency(
+            &PackageName::from_str("bird")?,
+            &VersionSpecifiers::from_str("==1.2.3")?,
+        )?;
+        assert!(modified);
+
+        assert_snapshot!(
+            pyproject.to_string(),
+            @r#"
+[project]
+dependencies = ["bird==1.2.3", "bird>=1 ; sys_platform == 'linux'", "bird==1.2.3 ; sys_platform == 'win32'", "feather"]
+"#
+        );
+        Ok(())
+    }
+
     #[test]
     fn replace_dependency_updates_every_exact_match() -> Result<()> {
         let mut pyproject = PyProjectTomlMut::from_toml(
@@ -6,16 +6,18 @@ use std::fmt;
 use std::fmt::Display;
 use std::hash::BuildHasherDefault;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use std::sync::Arc;
 
 use glob::{GlobError, PatternError, glob};
 use itertools::Itertools;
 use rustc_hash::{FxHashSet, FxHasher};
-use tracing::{debug, trace, warn};
+use tracing::{debug, instrument, trace, warn};
 
-use uv_cache::Cache;
+use uv_cache::{Cache, CacheBucket};
+use uv_cache_key::cache_digest;
 use uv_configuration::{DependencyGroupsWithDefaults, ExcludeDependency};
-use uv_distribution_types::{Index, Requirement, RequirementSource};
+use uv_distribution_types::{Index, Requirement, RequirementSource, RequiresPython};
 use uv_fs::{CWD, Simplified, normalize_path};
 use uv_normalize::{DEV_DEPENDENCIES, GroupName, PackageName};
 use uv_once_map::OnceMap;
@@ -25,10 +27,14 @@ use uv_pypi_types::{ConflictError, Conflicts, SupportedEnvironments, VerbatimPar
 use uv_static::EnvVars;
 use uv_warnings::warn_user_once;
 
-use crate::dependency_groups::{DependencyGroupError, FlatDependencyGroup, FlatDependencyGroups};
+use crate::dependency_groups::{
+    DependencyGroupError, FlatDependencyGroup, FlatDependencyGroups, GroupCollisionDiagnostic,
+    GroupMarkerDiagnostic, check_group_collisions, check_group_markers,
+};
+use crate::discovery_cache::DiscoveredMembers;
 use crate::pyproject::{
     OverrideDependency, Project, PyProjectToml, PyprojectTomlError, Source, Sources, ToolUvSources,
-    ToolUvWorkspace, WorkspaceReference,
+    ToolUvWorkspace, WorkspacePinPolicy, WorkspaceReference,
 };
 
 /// The workspace project environment selected by configuration and command-line options.
@@ -36,10 +42,12 @@ use crate::pyproject::{
 pub enum ProjectEnvironmentSelection {
     /// Use the workspace's default project environment.
     Default,
-    /// A path selected by `UV_PROJECT_ENVIRONMENT`.
+    /// A path selected by `UV_PROJECT_ENVIRONMENT` or `tool.uv.project-environment`.
     Override(PathBuf),
     /// The active virtual environment selected by `VIRTUAL_ENV` and `--active`.
     Active(PathBuf),
+    /// The active conda environment selected by `CONDA_PREFIX` and `--active`.
+    Conda(PathBuf),
 }
 
 impl ProjectEnvironmentSelection {
@@ -52,7 +60,7 @@ impl ProjectEnvironmentSelection {
     pub fn explicit_path(&self) -> Option<&Path> {
         match self {
             Self::Default => None,
-            Self::Override(path) | Self::Active(path) => Some(path),
+            Self::Override(path) | Self::Active(path) | Self::Conda(path) => Some(path),
         }
     }
 }
@@ -320,6 +328,7 @@ impl Workspace {
     /// [tool.uv]
     /// dev-dependencies = ["ruff"]
     /// ```
+    #[instrument(name = "discover_workspace", skip_all, fields(path = %path.display()))]
     pub async fn discover(
         path: &Path,
         options: &DiscoveryOptions,
@@ -722,6 +731,43 @@ impl Workspace {
             .and_then(|uv| uv.environments.as_ref())
     }
 
+    /// Check the `dependency-groups` of the workspace root and each member for entries with
+    /// likely mistakes in their markers or extras.
+    pub fn check_group_markers(
+        &self,
+        requires_python: Option<&RequiresPython>,
+    ) -> Vec<GroupMarkerDiagnostic> {
+        let root = self
+            .is_non_project()
+            .then_some((self.install_path.as_path(), &self.pyproject_toml));
+        root.into_iter()
+            .chain(
+                self.packages
+                    .values()
+                    .map(|member| (member.root.as_path(), &member.pyproject_toml)),
+            )
+            .flat_map(|(root, pyproject_toml)| {
+                check_group_markers(
+                    &root.join("pyproject.toml"),
+                    pyproject_toml,
+                    requires_python,
+                    self.environments(),
+                )
+            })
+            .collect()
+    }
+
+    /// Check the extras and `dependency-groups` of each member for inconsistencies, e.g., an extra
+    /// and a group that share a name but differ in their contents.
+    pub fn check_group_collisions(&self) -> Vec<GroupCollisionDiagnostic> {
+        self.packages
+            .values()
+            .flat_map(|member| {
+                check_group_collisions(&member.root.join("pyproject.toml"), &member.pyproject_toml)
+            })
+            .collect()
+    }
+
     /// Returns the set of required platforms for the workspace.
     pub fn required_environments(&self) -> Option<&SupportedEnvironments> {
         self.pyproject_toml
@@ -731,6 +777,124 @@ impl Workspace {
             .and_then(|uv| uv.required_environments.as_ref())
     }
 
+    /// Returns the commands to run after the workspace environment is synced.
+    pub fn post_sync_commands(&self) -> &[String] {
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.post_sync.as_deref())
+            .unwrap_or_default()
+    }
+
+    /// Returns the workspace members that should be installed as non-editable.
+    pub fn no_editable_packages(&self) -> &[PackageName] {
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.no_editable_package.as_deref())
+            .unwrap_or_default()
+    }
+
+    /// Returns `true` if the provenance of locked packages should be recorded in the lockfile.
+    pub fn lock_provenance(&self) -> bool {
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.lock_provenance)
+            .unwrap_or(false)
+    }
+
+    /// Returns the configured path to the project environment, if any.
+    pub fn project_environment(&self) -> Option<&Path> {
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.project_environment.as_deref())
+    }
+
+    /// Returns `true` if inconsistencies in the project configuration should be treated as errors.
+    pub fn strict_config(&self) -> bool {
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.strict_config)
+            .unwrap_or(false)
+    }
+
+    /// Returns the policy for workspace dependencies without a version specifier, if any.
+    pub fn pin_policy(&self) -> Option<WorkspacePinPolicy> {
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.workspace_pin_policy)
+    }
+
+    /// Returns the dependencies in the `project.dependencies` of the given member on other
+    /// workspace members (i.e., with `workspace = true` in `tool.uv.sources`) that lack a version
+    /// specifier.
+    pub fn unbounded_workspace_dependencies(&self, name: &PackageName) -> Vec<PackageName> {
+        let Some(member) = self.packages.get(name) else {
+            return Vec::new();
+        };
+        let Some(dependencies) = member.project.dependencies.as_ref() else {
+            return Vec::new();
+        };
+        let member_sources = member
+            .pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.sources.as_ref())
+            .map(ToolUvSources::inner);
+
+        let mut unbounded = Vec::new();
+        for dependency in dependencies {
+            let Ok(requirement) = uv_pep508::Requirement::<VerbatimParsedUrl>::from_str(dependency)
+            else {
+                continue;
+            };
+            if requirement.name == *name || !self.packages.contains_key(&requirement.name) {
+                continue;
+            }
+            let is_bounded = match &requirement.version_or_url {
+                None => false,
+                Some(uv_pep508::VersionOrUrl::VersionSpecifier(specifiers)) => {
+                    !specifiers.is_empty()
+                }
+                Some(uv_pep508::VersionOrUrl::Url(_)) => true,
+            };
+            if is_bounded {
+                continue;
+            }
+
+            // Sources declared by the member take precedence over those declared at the root.
+            let sources = member_sources
+                .and_then(|sources| sources.get(&requirement.name))
+                .or_else(|| self.sources.get(&requirement.name));
+            let is_workspace = sources.is_some_and(|sources| {
+                sources.iter().any(|source| {
+                    matches!(
+                        source,
+                        Source::Workspace {
+                            workspace: WorkspaceReference::Bool(true),
+                            ..
+                        }
+                    )
+                })
+            });
+            if is_workspace && !unbounded.contains(&requirement.name) {
+                unbounded.push(requirement.name);
+            }
+        }
+        unbounded
+    }
+
     /// Returns the set of conflicts for the workspace.
     pub fn conflicts(&self) -> Result<Conflicts, WorkspaceError> {
         let mut conflicting = Conflicts::empty();
@@ -892,8 +1056,9 @@ impl Workspace {
 
     /// The workspace project environment selection.
     ///
-    /// If `UV_PROJECT_ENVIRONMENT` is set, it will take precedence. If a relative path is provided,
-    /// it is resolved relative to the install path.
+    /// If `UV_PROJECT_ENVIRONMENT` is set, it will take precedence, followed by the
+    /// `tool.uv.project-environment` setting. If a relative path is provided, it is resolved
+    /// relative to the install path.
     ///
     /// If `active` is `true`, the `VIRTUAL_ENV` variable will be preferred. If it is `false`, any
     /// warnings about mismatch between the active environment and the project environment will be
@@ -916,6 +1081,17 @@ impl Workspace {
             Some(workspace.install_path.join(path))
         }
 
+        /// Resolve the `tool.uv.project-environment` setting, if any.
+        fn from_project_environment_setting(workspace: &Workspace) -> Option<PathBuf> {
+            let path = workspace.project_environment()?;
+            if path.as_os_str().is_empty() {
+                return None;
+            }
+
+            // Resolve the path relative to the install path.
+            Some(workspace.install_path.join(path))
+        }
+
         /// Resolve the `VIRTUAL_ENV` variable, if any.
         fn from_virtual_env_variable() -> Option<PathBuf> {
             let value = std::env::var_os(EnvVars::VIRTUAL_ENV)?;
@@ -935,6 +1111,7 @@ impl Workspace {
         }
 
         let selection = from_project_environment_variable(self)
+            .or_else(|| from_project_environment_setting(self))
             .map(ProjectEnvironmentSelection::Override)
             .unwrap_or(ProjectEnvironmentSelection::Default);
         let project_environment_path = selection
@@ -1028,6 +1205,21 @@ impl Workspace {
         }
     }
 
+    /// Returns the `tool.uv.workspace.members` entries that include the path.
+    pub fn including_members(&self, project_path: &Path) -> Result<Vec<&str>, WorkspaceError> {
+        if let Some(workspace) = self
+            .pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.workspace.as_ref())
+        {
+            including_members(project_path, &self.install_path, workspace)
+        } else {
+            Ok(Vec::new())
+        }
+    }
+
     /// Collect the workspace member projects and build the workspace object.
     async fn build(
         workspace_root: PathBuf,
@@ -1163,10 +1355,46 @@ impl Workspace {
             );
         }
 
+        // Reuse the members discovered by a previous invocation, unless any of their manifests or
+        // the directories searched for them changed since. Only full discoveries outside of the
+        // cache are persisted, as they don't depend on the discovery options.
+        let cache_entry = (workspace_definition.members.is_some()
+            && matches!(options.members, MemberDiscovery::All)
+            && options.stop_discovery_at.is_none())
+        .then(|| {
+            cache
+                .entry(
+                    CacheBucket::Workspaces,
+                    "",
+                    format!("{}.msgpack", cache_digest(workspace_root)),
+                )
+                .into_path_buf()
+        });
+        if let Some(cached) = cache_entry
+            .as_deref()
+            .and_then(|path| DiscoveredMembers::read(path, workspace_definition))
+        {
+            debug!(
+                "Using cached workspace members for: `{}`",
+                workspace_root.simplified_display()
+            );
+            for (member_root, pyproject_toml) in cached {
+                add_member(&mut workspace_members, member_root, pyproject_toml)?;
+            }
+            check_nested_workspaces(workspace_root, &workspace_members)?;
+            return Ok(workspace_members);
+        }
+        let mut discovered = cache_entry
+            .is_some()
+            .then(|| DiscoveredMembers::new(workspace_definition));
+
         // Add all other workspace members.
         for member_glob in workspace_definition.clone().members.unwrap_or_default() {
             // Normalize the member glob to remove leading `./` and other relative path components
             let normalized_glob = normalize_path(Path::new(member_glob.as_str()));
+            if let Some(discovered) = discovered.as_mut() {
+                discovered.record_glob(workspace_root.simplified(), &normalized_glob);
+            }
             let absolute_glob = PathBuf::from(glob::Pattern::escape(
                 workspace_root.simplified().to_string_lossy().as_ref(),
             ))
@@ -1223,8 +1451,12 @@ impl Workspace {
                     member_root.user_display()
                 );
 
-                // Read the member `pyproject.toml`.
+                // Read the member `pyproject.toml`, stamping it first if the discovery is cached.
                 let pyproject_path = member_root.join("pyproject.toml");
+                let metadata = match discovered.as_ref() {
+                    Some(_) => fs_err::tokio::metadata(&pyproject_path).await.ok(),
+                    None => None,
+                };
                 let contents = match fs_err::tokio::read_to_string(&pyproject_path).await {
                     Ok(contents) => contents,
                     Err(err) => {
@@ -1261,6 +1493,9 @@ impl Workspace {
                                     "Ignoring hidden workspace member: `{}`",
                                     member_root.simplified_display()
                                 );
+                                if let Some(discovered) = discovered.as_mut() {
+                                    discovered.record_skipped(member_root);
+                                }
                                 continue;
                             }
 
@@ -1271,6 +1506,10 @@ impl Workspace {
                                     "Ignoring workspace member with only gitignored files: `{}`",
                                     member_root.simplified_display()
                                 );
+                                // The nested directory contents aren't stamped.
+                                if let Some(discovered) = discovered.as_mut() {
+                                    discovered.mark_uncacheable();
+                                }
                                 continue;
                             }
 
@@ -1293,78 +1532,28 @@ impl Workspace {
                         return Err(err.into());
                     }
                 };
+                if let Some(discovered) = discovered.as_mut() {
+                    match metadata {
+                        Some(metadata) => {
+                            discovered.record_member(member_root.clone(), &metadata, &contents);
+                        }
+                        None => discovered.mark_uncacheable(),
+                    }
+                }
                 let pyproject_toml = PyProjectToml::from_string(contents, &pyproject_path)
                     .map_err(|err| {
                         WorkspaceErrorKind::Toml(pyproject_path.clone(), Box::new(err))
                     })?;
-
-                // Check if the current project is explicitly marked as unmanaged.
-                if pyproject_toml
-                    .tool
-                    .as_ref()
-                    .and_then(|tool| tool.uv.as_ref())
-                    .and_then(|uv| uv.managed)
-                    == Some(false)
-                {
-                    if let Some(project) = pyproject_toml.project.as_ref() {
-                        debug!(
-                            "Project `{}` is marked as unmanaged; omitting from workspace members",
-                            project.name
-                        );
-                    } else {
-                        debug!(
-                            "Workspace member at `{}` is marked as unmanaged; omitting from workspace members",
-                            member_root.simplified_display()
-                        );
-                    }
-                    continue;
-                }
-
-                // Extract the package name.
-                let Some(project) = pyproject_toml.project.clone() else {
-                    return Err(WorkspaceError::from(WorkspaceErrorKind::MissingProject(
-                        pyproject_path,
-                    )));
-                };
-
-                debug!(
-                    "Adding discovered workspace member: `{}`",
-                    member_root.simplified_display()
-                );
-
-                if let Some(existing) = workspace_members.insert(
-                    project.name.clone(),
-                    WorkspaceMember {
-                        root: member_root.clone(),
-                        project,
-                        pyproject_toml,
-                    },
-                ) {
-                    return Err(WorkspaceError::from(WorkspaceErrorKind::DuplicatePackage {
-                        name: existing.project.name,
-                        first: existing.root.clone(),
-                        second: member_root,
-                    }));
-                }
+                add_member(&mut workspace_members, member_root, pyproject_toml)?;
             }
         }
 
-        // Test for nested workspaces.
-        for member in workspace_members.values() {
-            if member.root() != workspace_root
-                && member
-                    .pyproject_toml
-                    .tool
-                    .as_ref()
-                    .and_then(|tool| tool.uv.as_ref())
-                    .and_then(|uv| uv.workspace.as_ref())
-                    .is_some()
-            {
-                return Err(WorkspaceError::from(WorkspaceErrorKind::NestedWorkspace(
-                    member.root.clone(),
-                )));
-            }
+        check_nested_workspaces(workspace_root, &workspace_members)?;
+
+        if let (Some(path), Some(discovered)) = (cache_entry.as_deref(), discovered.as_ref()) {
+            discovered.write(path);
         }
+
         Ok(workspace_members)
     }
 }
@@ -1913,6 +2102,87 @@ async fn find_workspace(
     Ok(None)
 }
 
+/// Add a discovered member to the workspace, unless it's marked as unmanaged.
+fn add_member(
+    workspace_members: &mut BTreeMap<PackageName, WorkspaceMember>,
+    member_root: PathBuf,
+    pyproject_toml: PyProjectToml,
+) -> Result<(), WorkspaceError> {
+    // Check if the current project is explicitly marked as unmanaged.
+    if pyproject_toml
+        .tool
+        .as_ref()
+        .and_then(|tool| tool.uv.as_ref())
+        .and_then(|uv| uv.managed)
+        == Some(false)
+    {
+        if let Some(project) = pyproject_toml.project.as_ref() {
+            debug!(
+                "Project `{}` is marked as unmanaged; omitting from workspace members",
+                project.name
+            );
+        } else {
+            debug!(
+                "Workspace member at `{}` is marked as unmanaged; omitting from workspace members",
+                member_root.simplified_display()
+            );
+        }
+        return Ok(());
+    }
+
+    // Extract the package name.
+    let Some(project) = pyproject_toml.project.clone() else {
+        return Err(WorkspaceError::from(WorkspaceErrorKind::MissingProject(
+            member_root.join("pyproject.toml"),
+        )));
+    };
+
+    debug!(
+        "Adding discovered workspace member: `{}`",
+        member_root.simplified_display()
+    );
+
+    if let Some(existing) = workspace_members.insert(
+        project.name.clone(),
+        WorkspaceMember {
+            root: member_root.clone(),
+            project,
+            pyproject_toml,
+        },
+    ) {
+        return Err(WorkspaceError::from(WorkspaceErrorKind::DuplicatePackage {
+            name: existing.project.name,
+            first: existing.root.clone(),
+            second: member_root,
+        }));
+    }
+
+    Ok(())
+}
+
+/// Test for nested workspaces.
+fn check_nested_workspaces(
+    workspace_root: &Path,
+    workspace_members: &BTreeMap<PackageName, WorkspaceMember>,
+) -> Result<(), WorkspaceError> {
+    for member in workspace_members.values() {
+        if member.root() != workspace_root
+            && member
+                .pyproject_toml
+                .tool
+                .as_ref()
+                .and_then(|tool| tool.uv.as_ref())
+                .and_then(|uv| uv.workspace.as_ref())
+                .is_some()
+        {
+            return Err(WorkspaceError::from(WorkspaceErrorKind::NestedWorkspace(
+                member.root.clone(),
+            )));
+        }
+    }
+    Ok(())
+}
+
 /// Check if a directory only contains files that are ignored.
 ///
 /// Returns `true` if walking the directory while respecting `.gitignore` and `.ignore` rules
@@ -1974,6 +2244,16 @@ fn is_included_in_workspace(
     workspace_root: &Path,
     workspace: &ToolUvWorkspace,
 ) -> Result<bool, WorkspaceError> {
+    Ok(!including_members(project_path, workspace_root, workspace)?.is_empty())
+}
+
+/// Find the `tool.uv.workspace.members` entries of a workspace that include a path.
+fn including_members<'a>(
+    project_path: &Path,
+    workspace_root: &Path,
+    workspace: &'a ToolUvWorkspace,
+) -> Result<Vec<&'a str>, WorkspaceError> {
+    let mut members = Vec::new();
     for member_glob in workspace.members.iter().flatten() {
         // Normalize the member glob to remove leading `./` and other relative path components
         let normalized_glob = normalize_path(Path::new(member_glob.as_str()));
@@ -1985,10 +2265,10 @@ fn is_included_in_workspace(
         let include_pattern = glob::Pattern::new(&absolute_glob)
             .map_err(|err| WorkspaceErrorKind::Pattern(absolute_glob.to_string(), err))?;
         if include_pattern.matches_path(project_path) {
-            return Ok(true);
+            members.push(member_glob.as_str());
         }
     }
-    Ok(false)
+    Ok(members)
 }
 
 /// A project that can be discovered.
@@ -2011,6 +2291,7 @@ impl VirtualProject {
     ///
     /// This method requires an absolute path and panics otherwise, i.e. this method only supports
     /// discovering the main workspace.
+    #[instrument(name = "discover_workspace", skip_all, fields(path = %path.display()))]
     pub async fn discover(
         path: &Path,
         options: &DiscoveryOptions,
@@ -2253,7 +2534,7 @@ mod tests {
     use assert_fs::prelude::*;
     use insta::{assert_json_snapshot, assert_snapshot};
 
-    use uv_cache::Cache;
+    use uv_cache::{Cache, CacheBucket};
     use uv_normalize::{GroupName, PackageName};
     use uv_pypi_types::DependencyGroupSpecifier;
 
@@ -2514,8 +2795,15 @@ mod tests {
                       },
                       "managed": null,
                       "package": null,
+                      "static-dependencies": null,
+                      "scripts": null,
+                      "post-sync": null,
                       "default-groups": null,
                       "dependency-groups": null,
+                      "strict-config": null,
+                      "no-editable-package": null,
+                      "lock-provenance": null,
+                      "project-environment": null,
                       "dev-dependencies": null,
                       "override-dependencies": null,
                       "exclude-dependencies": null,
@@ -2524,6 +2812,7 @@ mod tests {
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "workspace-pin-policy": null,
                       "build-backend": null
                     }
                   },
@@ -2615,8 +2904,15 @@ mod tests {
                       },
                       "managed": null,
                       "package": null,
+                      "static-dependencies": null,
+                      "scripts": null,
+                      "post-sync": null,
                       "default-groups": null,
                       "dependency-groups": null,
+                      "strict-config": null,
+                      "no-editable-package": null,
+                      "lock-provenance": null,
+                      "project-environment": null,
                       "dev-dependencies": null,
                       "override-dependencies": null,
                       "exclude-dependencies": null,
@@ -2625,6 +2921,7 @@ mod tests {
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "workspace-pin-policy": null,
                       "build-backend": null
                     }
                   },
@@ -2757,6 +3054,210 @@ mod tests {
         Ok(())
     }
 
+    async fn discover_members(root: &Path, cache: &Cache) -> Result<Vec<String>> {
+        let workspace = Workspace::discover(
+            root,
+            &DiscoveryOptions::default(),
+            cache,
+            &WorkspaceCache::default(),
+        )
+        .await?;
+        Ok(workspace
+            .packages()
+            .values()
+            .map(|member| {
+                format!(
+                    "{}=={}",
+                    member.project().name,
+                    member.project().version().unwrap()
+                )
+            })
+            .collect())
+    }
+
+    #[tokio::test]
+    async fn discovery_cache_invalidates_changed_members() -> Result<()> {
+        let root = tempfile::TempDir::new()?;
+        let root = ChildPath::new(root.path());
+
+        root.child("pyproject.toml").write_str(
+            r#"
+            [project]
+            name = "albatross"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+
+            [tool.uv.workspace]
+            members = ["packages/*"]
+            "#,
+        )?;
+        root.child("packages")
+            .child("seeds")
+            .child("pyproject.toml")
+            .write_str(
+                r#"
+            [project]
+            name = "seeds"
+            version = "1.0.0"
+            requires-python = ">=3.12"
+
+            [tool.ruff]
+            line-length = 100
+            "#,
+            )?;
+        root.child("packages").child(".hidden").create_dir_all()?;
+
+        let cache = Cache::temp()?;
+        assert_eq!(
+            discover_members(root.as_ref(), &cache).await?,
+            ["albatross==0.1.0", "seeds==1.0.0"]
+        );
+        assert_eq!(
+            fs_err::read_dir(cache.bucket(CacheBucket::Workspaces))?.count(),
+            1
+        );
+        // Unrelated tool configuration isn't persisted.
+        for entry in fs_err::read_dir(cache.bucket(CacheBucket::Workspaces))? {
+            let data = fs_err::read(entry?.path())?;
+            assert!(!String::from_utf8_lossy(&data).contains("line-length"));
+        }
+        assert_eq!(
+            discover_members(root.as_ref(), &cache).await?,
+            ["albatross==0.1.0", "seeds==1.0.0"]
+        );
+
+        // Adding a member invalidates the listed directory.
+        root.child("packages")
+            .child("bird")
+            .child("pyproject.toml")
+            .write_str(
+                r#"
+            [project]
+            name = "bird"
+            version = "1.0.0"
+            "#,
+            )?;
+        assert_eq!(
+            discover_members(root.as_ref(), &cache).await?,
+            ["albatross==0.1.0", "bird==1.0.0", "seeds==1.0.0"]
+        );
+
+        // Modifying a member invalidates its manifest.
+        root.child("packages")
+            .child("seeds")
+            .child("pyproject.toml")
+            .write_str(
+                r#"
+            [project]
+            name = "seeds"
+            version = "1.0.10"
+            requires-python = ">=3.12"
+            "#,
+            )?;
+        assert_eq!(
+            discover_members(root.as_ref(), &cache).await?,
+            ["albatross==0.1.0", "bird==1.0.0", "seeds==1.0.10"]
+        );
+
+        // Adding a manifest to a skipped directory invalidates the entry.
+        root.child("packages")
+            .child(".hidden")
+            .child("pyproject.toml")
+            .write_str(
+                r#"
+            [project]
+            name = "hidden"
+            version = "1.0.0"
+            "#,
+            )?;
+        assert_eq!(
+            discover_members(root.as_ref(), &cache).await?,
+            [
+                "albatross==0.1.0",
+                "bird==1.0.0",
+                "hidden==1.0.0",
+                "seeds==1.0.10"
+            ]
+        );
+
+        // Changing the workspace definition invalidates the entry.
+        root.child("pyproject.toml").write_str(
+            r#"
+            [project]
+            name = "albatross"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+
+            [tool.uv.workspace]
+            members = ["packages/seeds"]
+            "#,
+        )?;
+        assert_eq!(
+            discover_members(root.as_ref(), &cache).await?,
+            ["albatross==0.1.0", "seeds==1.0.10"]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn relevant_tables_match_full_parse() -> Result<()> {
+        let raw = r#"
+            [build-system]
+            requires = ["uv_build>=0.9,<0.10"]
+            build-backend = "uv_build"
+
+            [project]
+            name = "albatross"
+            version = "0.1.0"
+            description = "An example project"
+            requires-python = ">=3.12"
+            dependencies = ["bird-feeder", "tqdm>=4,<5"]
+            classifiers = ["Programming Language :: Python :: 3"]
+
+            [project.optional-dependencies]
+            cli = ["rich>=13"]
+
+            [project.scripts]
+            albatross = "albatross:main"
+
+            [project.urls]
+            Repository = "https://example.com/albatross"
+
+            [dependency-groups]
+            dev = ["pytest>=8"]
+
+            [tool.ruff]
+            line-length = 100
+
+            [tool.uv]
+            package = true
+            constraint-dependencies = ["idna<4"]
+
+            [tool.uv.sources]
+            bird-feeder = { workspace = true }
+            tqdm = { url = "https://files.pythonhosted.org/packages/tqdm-4.66.0-py3-none-any.whl" }
+
+            [tool.uv.workspace]
+            members = ["packages/*"]
+            exclude = ["packages/seeds"]
+        "#;
+
+        let relevant = PyProjectToml::relevant_tables(raw).unwrap();
+        assert!(!relevant.contains("ruff"));
+        assert!(!relevant.contains("classifiers"));
+
+        let full = PyProjectToml::from_string(raw.to_string(), "pyproject.toml")?;
+        let trimmed = PyProjectToml::from_relevant_tables(&relevant, raw.to_string())?;
+        assert_eq!(full, trimmed);
+        assert_eq!(full.dependency_groups, trimmed.dependency_groups);
+        assert_eq!(full.raw, trimmed.raw);
+        assert!(trimmed.is_package(true));
+        assert!(trimmed.has_scripts());
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn albatross_just_project() {
         let (project, root_escaped) = workspace_test("albatross-just-project").await;
@@ -2950,8 +3451,15 @@ mod tests {
                       },
                       "managed": null,
                       "package": null,
+                      "static-dependencies": null,
+                      "scripts": null,
+                      "post-sync": null,
                       "default-groups": null,
                       "dependency-groups": null,
+                      "strict-config": null,
+                      "no-editable-package": null,
+                      "lock-provenance": null,
+                      "project-environment": null,
                       "dev-dependencies": null,
                       "override-dependencies": null,
                       "exclude-dependencies": null,
@@ -2960,6 +3468,7 @@ mod tests {
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "workspace-pin-policy": null,
                       "build-backend": null
                     }
                   },
@@ -3060,8 +3569,15 @@ mod tests {
                       },
                       "managed": null,
                       "package": null,
+                      "static-dependencies": null,
+                      "scripts": null,
+                      "post-sync": null,
                       "default-groups": null,
                       "dependency-groups": null,
+                      "strict-config": null,
+                      "no-editable-package": null,
+                      "lock-provenance": null,
+                      "project-environment": null,
                       "dev-dependencies": null,
                       "override-dependencies": null,
                       "exclude-dependencies": null,
@@ -3070,6 +3586,7 @@ mod tests {
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "workspace-pin-policy": null,
                       "build-backend": null
                     }
                   },
@@ -3183,8 +3700,15 @@ mod tests {
                       },
                       "managed": null,
                       "package": null,
+                      "static-dependencies": null,
+                      "scripts": null,
+                      "post-sync": null,
                       "default-groups": null,
                       "dependency-groups": null,
+                      "strict-config": null,
+                      "no-editable-package": null,
+                      "lock-provenance": null,
+                      "project-environment": null,
                       "dev-dependencies": null,
                       "override-dependencies": null,
                       "exclude-dependencies": null,
@@ -3193,6 +3717,7 @@ mod tests {
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "workspace-pin-policy": null,
                       "build-backend": null
                     }
                   },
@@ -3280,8 +3805,15 @@ mod tests {
                       },
                       "managed": null,
                       "package": null,
+                      "static-dependencies": null,
+                      "scripts": null,
+                      "post-sync": null,
                       "default-groups": null,
                       "dependency-groups": null,
+                      "strict-config": null,
+                      "no-editable-package": null,
+                      "lock-provenance": null,
+                      "project-environment": null,
                       "dev-dependencies": null,
                       "override-dependencies": null,
                       "exclude-dependencies": null,
@@ -3290,6 +3822,7 @@ mod tests {
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "workspace-pin-policy": null,
                       "build-backend": null
                     }
                   },
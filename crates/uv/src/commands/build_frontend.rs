@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::fmt::Write as _;
 use std::io::Write as _;
 use std::path::{Path, PathBuf};
@@ -8,6 +9,7 @@ use std::{fmt, io, iter};
 use anyhow::{Context, Result};
 use owo_colors::OwoColorize;
 use thiserror::Error;
+use tokio::io::AsyncWriteExt;
 use tracing::{debug, instrument};
 
 use uv_build_backend::check_direct_build;
@@ -23,14 +25,15 @@ use uv_distribution_filename::{
     DistFilename, SourceDistExtension, SourceDistFilename, WheelFilename,
 };
 use uv_distribution_types::{
-    ConfigSettings, DependencyMetadata, ExtraBuildVariables, Index, IndexLocations,
-    PackageConfigSettings, Requirement, SourceDist,
+    BuildEnvPassthrough, ConfigSettings, DependencyMetadata, ExtraBuildVariables, Index,
+    IndexLocations, PackageConfigSettings, Requirement, SourceDist,
 };
 use uv_errors::{ErrorOptions, Hint, Hints, write_error_chain_with_options};
 use uv_fs::{Simplified, normalize_path, relative_to};
 use uv_install_wheel::LinkMode;
 use uv_normalize::PackageName;
-use uv_pep440::Version;
+use uv_pep440::{Version, VersionSpecifiers};
+use uv_pep508::{VerbatimUrl, VersionOrUrl};
 use uv_preview::Preview;
 use uv_python::{
     ConfigDiscovery, EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonInstallation,
@@ -40,13 +43,16 @@ use uv_requirements::RequirementsSource;
 use uv_resolver::{ExcludeNewer, FlatIndex};
 use uv_settings::PythonInstallMirrors;
 use uv_types::{AnyErrorBuild, BuildContext, BuildStack, HashStrategy, SourceTreeEditablePolicy};
-use uv_warnings::warn_user;
-use uv_workspace::pyproject::ExtraBuildDependencies;
+use uv_warnings::{warn_user, warn_user_once};
+use uv_workspace::pyproject::{ExtraBuildDependencies, WorkspacePinPolicy};
+use uv_workspace::pyproject_mut::{DependencyTarget, PyProjectTomlMut};
 use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache, WorkspaceError};
 
 use crate::commands::ExitStatus;
 use crate::commands::pip::operations;
-use crate::commands::project::{ProjectError, find_requires_python};
+use crate::commands::project::{
+    ProjectError, find_requires_python, warn_unbounded_workspace_dependency,
+};
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::printer::Printer;
 use crate::settings::ResolverSettings;
@@ -79,6 +85,8 @@ pub(crate) enum Error {
     BuildFrontend(#[from] uv_build_frontend::Error),
     #[error(transparent)]
     Project(#[from] ProjectError),
+    #[error(transparent)]
+    PyprojectTomlMut(#[from] uv_workspace::pyproject_mut::Error),
     #[error("Failed to write message")]
     Fmt(#[from] fmt::Error),
     #[error("Can't use `--force-pep517` with `--list`")]
@@ -100,6 +108,12 @@ pub(crate) enum Error {
     NameMismatch(PackageName, PackageName),
     #[error("The source distribution declares version {0}, but the wheel declares version {1}")]
     VersionMismatch(Version, Version),
+    #[error(
+        "Workspace dependencies can only be pinned in `.tar.gz` source distributions, but the build backend produced: `{0}`"
+    )]
+    PinSourceDistExt(String),
+    #[error("The source distribution at `{0}` does not contain a named top-level directory")]
+    PinSourceDistTopLevel(String),
 }
 
 impl Hint for Error {
@@ -151,6 +165,7 @@ pub(crate) async fn build_frontend(
     gitignore: bool,
     force_pep517: bool,
     clear: bool,
+    check_bounds: bool,
     build_constraints: Vec<RequirementsSource>,
     build_constraints_from_workspace: Vec<Requirement>,
     hash_checking: Option<HashCheckingMode>,
@@ -180,6 +195,7 @@ pub(crate) async fn build_frontend(
         gitignore,
         force_pep517,
         clear,
+        check_bounds,
         &build_constraints,
         &build_constraints_from_workspace,
         hash_checking,
@@ -229,6 +245,7 @@ async fn build_impl(
     gitignore: bool,
     force_pep517: bool,
     clear: bool,
+    check_bounds: bool,
     build_constraints: &[RequirementsSource],
     build_constraints_from_workspace: &[Requirement],
     hash_checking: Option<HashCheckingMode>,
@@ -252,6 +269,8 @@ async fn build_impl(
         keyring_provider,
         resolution: _,
         prerelease: _,
+        prerelease_packages: _,
+        require_metadata: _,
         fork_strategy: _,
         dependency_metadata,
         config_setting,
@@ -259,6 +278,7 @@ async fn build_impl(
         build_isolation,
         extra_build_dependencies,
         extra_build_variables,
+        build_env_passthrough,
         exclude_newer,
         link_mode,
         upgrade: _,
@@ -411,7 +431,14 @@ async fn build_impl(
         }
     }
 
-    let results: Vec<_> = futures::future::join_all(packages.into_iter().map(|source| {
+    // Determine the version specifiers to inject for dependencies on other workspace members.
+    let pins: Vec<_> = packages
+        .iter()
+        .map(|source| workspace_pins(workspace.as_deref().ok(), &source.source, check_bounds))
+        .collect();
+
+    let packages = packages.into_iter().zip(&pins);
+    let results: Vec<_> = futures::future::join_all(packages.map(|(source, pins)| {
         let future = build_package(
             source.clone(),
             output_dir,
@@ -436,6 +463,7 @@ async fn build_impl(
             build_isolation,
             extra_build_dependencies,
             extra_build_variables,
+            build_env_passthrough,
             *index_strategy,
             *keyring_provider,
             exclude_newer.clone(),
@@ -445,6 +473,7 @@ async fn build_impl(
             sdist,
             wheel,
             list,
+            pins,
             dependency_metadata,
             *link_mode,
             config_setting,
@@ -459,11 +488,20 @@ async fn build_impl(
     .await;
 
     let mut success = true;
+    let mut built = BTreeMap::<PathBuf, Vec<String>>::new();
     for (source, result) in results {
         match result {
             Ok(messages) => {
                 for message in messages {
                     message.print(printer)?;
+                    if let BuildMessage::Build {
+                        raw_filename,
+                        output_dir,
+                        ..
+                    } = message
+                    {
+                        built.entry(output_dir).or_default().push(raw_filename);
+                    }
                 }
             }
             Err(err) => {
@@ -480,11 +518,16 @@ async fn build_impl(
         }
     }
 
-    if success {
-        Ok(BuildResult::Success)
-    } else {
-        Ok(BuildResult::Failure)
+    if !success {
+        return Ok(BuildResult::Failure);
     }
+
+    // Record the distributions produced by this build, for `uv publish --from-build`.
+    for (output_dir, filenames) in &built {
+        uv_publish::write_build_manifest(output_dir, filenames.iter().map(String::as_str))?;
+    }
+
+    Ok(BuildResult::Success)
 }
 
 #[expect(clippy::fn_params_excessive_bools)]
@@ -512,6 +555,7 @@ async fn build_package(
     build_isolation: &BuildIsolation,
     extra_build_dependencies: &ExtraBuildDependencies,
     extra_build_variables: &ExtraBuildVariables,
+    build_env_passthrough: &BuildEnvPassthrough,
     index_strategy: IndexStrategy,
     keyring_provider: KeyringProviderType,
     exclude_newer: ExcludeNewer,
@@ -521,6 +565,7 @@ async fn build_package(
     sdist: bool,
     wheel: bool,
     list: bool,
+    pins: &BTreeMap<PackageName, VersionSpecifiers>,
     dependency_metadata: &DependencyMetadata,
     link_mode: LinkMode,
     config_setting: &ConfigSettings,
@@ -586,7 +631,7 @@ async fn build_package(
 
     // Read build constraints.
     let build_constraints =
-        operations::read_constraints(build_constraints, &client_builder).await?;
+        operations::read_constraints(build_constraints, &client_builder, cache).await?;
 
     // Collect the set of required hashes.
     let hasher = if let Some(hash_checking) = hash_checking {
@@ -673,13 +718,29 @@ async fn build_package(
         workspace_cache.clone(),
         concurrency.clone(),
         preview,
-    );
+    )
+    .with_build_env_passthrough(build_env_passthrough.clone());
 
     prepare_output_directory(&output_dir, gitignore).await?;
 
+    // Forget about any previous build, such that its distributions can't be published with
+    // `uv publish --from-build` if this build fails.
+    if !list {
+        uv_publish::remove_build_manifest(&output_dir)?;
+    }
+
     // Determine the build plan.
     let plan = BuildPlan::determine(&source, sdist, wheel).map_err(Error::BuildPlan)?;
 
+    // Pinning dependencies on other workspace members rewrites the metadata of the source
+    // distribution, so the wheel must be built from the rewritten source distribution.
+    let pin = !pins.is_empty() && !list;
+    let plan = if pin && plan == BuildPlan::SdistAndWheel {
+        BuildPlan::SdistToWheel
+    } else {
+        plan
+    };
+
     // Check if the build backend is matching uv version that allows calling in the uv build backend
     // directly.
     let build_action = if list {
@@ -767,8 +828,12 @@ async fn build_package(
             .await?;
             build_results.push(sdist_build.clone());
 
-            // Extract the source distribution into a temporary directory.
             let path = output_dir.join(sdist_build.raw_filename());
+            if pin {
+                pin_source_dist(&path, pins, cache).await?;
+            }
+
+            // Extract the source distribution into a temporary directory.
             let reader = fs_err::tokio::File::open(&path).await?;
             let ext = SourceDistExtension::from_path(path.as_path())
                 .map_err(|err| Error::InvalidSourceDistExt(path.user_display().to_string(), err))?;
@@ -816,8 +881,59 @@ async fn build_package(
                 build_output,
             )
             .await?;
+            if pin {
+                pin_source_dist(&output_dir.join(sdist_build.raw_filename()), pins, cache).await?;
+            }
             build_results.push(sdist_build);
         }
+        BuildPlan::Wheel if pin => {
+            // Build the wheel from a pinned source distribution, which is discarded afterwards.
+            let temp_dir = tempfile::tempdir_in(cache.bucket(CacheBucket::SourceDistributions))?;
+            let sdist_build = build_sdist(
+                source.path(),
+                temp_dir.path(),
+                build_action,
+                &source,
+                printer,
+                "source distribution",
+                &build_dispatch,
+                &sources,
+                dist,
+                subdirectory,
+                version_id,
+                build_output,
+            )
+            .await?;
+
+            let path = temp_dir.path().join(sdist_build.raw_filename());
+            pin_source_dist(&path, pins, cache).await?;
+
+            // Extract the source distribution into a temporary directory.
+            let reader = fs_err::tokio::File::open(&path).await?;
+            let ext = SourceDistExtension::from_path(path.as_path())
+                .map_err(|err| Error::InvalidSourceDistExt(path.user_display().to_string(), err))?;
+            let extract_dir = tempfile::tempdir_in(temp_dir.path())?;
+            uv_extract::stream::archive(path.display(), reader, ext, extract_dir.path()).await?;
+            let extracted = uv_extract::strip_component(extract_dir.path())?;
+
+            let wheel_build = build_wheel(
+                &extracted,
+                &output_dir,
+                build_action,
+                &source,
+                printer,
+                "wheel from source distribution",
+                &build_dispatch,
+                sources,
+                dist,
+                subdirectory,
+                version_id,
+                build_output,
+                Some(sdist_build.normalized_filename()),
+            )
+            .await?;
+            build_results.push(wheel_build);
+        }
         BuildPlan::Wheel => {
             let wheel_build = build_wheel(
                 source.path(),
@@ -1175,6 +1291,156 @@ async fn build_wheel(
     Ok(build_message)
 }
 
+/// Determine the version specifiers to inject for dependencies of a workspace member on other
+/// workspace members that lack one, per `tool.uv.workspace-pin-policy`.
+///
+/// Dependencies that remain unbounded are reported if `check_bounds` is set or the policy is
+/// `warn`.
+fn workspace_pins(
+    workspace: Option<&Workspace>,
+    source: &Source<'_>,
+    check_bounds: bool,
+) -> BTreeMap<PackageName, VersionSpecifiers> {
+    let mut pins = BTreeMap::new();
+    let (Some(workspace), Source::Directory(directory)) = (workspace, source) else {
+        return pins;
+    };
+    let Some(member) = workspace
+        .packages()
+        .values()
+        .find(|member| normalize_path(member.root()) == normalize_path(&**directory))
+    else {
+        return pins;
+    };
+    let name = &member.project().name;
+    let policy = workspace.pin_policy();
+
+    for dependency in workspace.unbounded_workspace_dependencies(name) {
+        if let Some(policy) = policy.filter(|policy| *policy != WorkspacePinPolicy::Warn) {
+            let version = workspace
+                .packages()
+                .get(&dependency)
+                .and_then(|member| member.project().version());
+            let Some(version) = version else {
+                warn_user_once!(
+                    "Unable to pin the dependency of `{name}` on workspace member `{dependency}`, since `{dependency}` does not declare a static version"
+                );
+                continue;
+            };
+            if let Some(specifiers) = policy.specifiers(version) {
+                debug!("Pinning workspace dependency of `{name}`: `{dependency}{specifiers}`");
+                pins.insert(dependency, specifiers);
+                continue;
+            }
+        }
+
+        if check_bounds || policy == Some(WorkspacePinPolicy::Warn) {
+            warn_unbounded_workspace_dependency(name, &dependency);
+        }
+    }
+
+    pins
+}
+
+/// Inject version specifiers for dependencies on other workspace members into a built source
+/// distribution, rewriting its `pyproject.toml` and `PKG-INFO` in place.
+async fn pin_source_dist(
+    path: &Path,
+    pins: &BTreeMap<PackageName, VersionSpecifiers>,
+    cache: &Cache,
+) -> Result<(), Error> {
+    let ext = SourceDistExtension::from_path(path)
+        .map_err(|err| Error::InvalidSourceDistExt(path.user_display().to_string(), err))?;
+    if ext != SourceDistExtension::TarGz {
+        return Err(Error::PinSourceDistExt(path.user_display().to_string()));
+    }
+
+    // Extract the source distribution into a temporary directory.
+    let reader = fs_err::tokio::File::open(path).await?;
+    let temp_dir = tempfile::tempdir_in(cache.bucket(CacheBucket::SourceDistributions))?;
+    uv_extract::stream::archive(path.display(), reader, ext, temp_dir.path()).await?;
+    let top_level = uv_extract::strip_component(temp_dir.path())?;
+    let top_level_name = top_level
+        .file_name()
+        .ok_or_else(|| Error::PinSourceDistTopLevel(path.user_display().to_string()))?
+        .to_owned();
+
+    // Rewrite `project.dependencies` in the `pyproject.toml`.
+    let pyproject_toml = top_level.join("pyproject.toml");
+    let contents = fs_err::tokio::read_to_string(&pyproject_toml).await?;
+    let mut pyproject = PyProjectTomlMut::from_toml(&contents, DependencyTarget::PyProjectToml)?;
+    for (dependency, specifiers) in pins {
+        pyproject.pin_unbounded_dependency(dependency, specifiers)?;
+    }
+    fs_err::tokio::write(&pyproject_toml, pyproject.to_string()).await?;
+
+    // Rewrite the `Requires-Dist` entries in the `PKG-INFO`.
+    let pkg_info = top_level.join("PKG-INFO");
+    let contents = fs_err::tokio::read_to_string(&pkg_info).await?;
+    fs_err::tokio::write(&pkg_info, pin_requires_dist(&contents, pins)).await?;
+
+    // Repack the source distribution.
+    let file = fs_err::tokio::File::create(path).await?;
+    let encoder = async_compression::tokio::write::GzipEncoder::new(file);
+    let mut builder = tokio_tar::Builder::new(encoder);
+    builder.append_dir_all(&top_level_name, &top_level).await?;
+    let mut encoder = builder.into_inner().await?;
+    encoder.shutdown().await?;
+
+    Ok(())
+}
+
+/// Inject version specifiers into the unbounded, non-optional `Requires-Dist` entries of a
+/// `PKG-INFO` file.
+fn pin_requires_dist(contents: &str, pins: &BTreeMap<PackageName, VersionSpecifiers>) -> String {
+    let mut output = String::with_capacity(contents.len());
+    let mut headers = true;
+    for line in contents.split_inclusive('\n') {
+        // The headers are terminated by an empty line, after which the description follows.
+        let value = line.trim_end_matches(['\r', '\n']);
+        if value.is_empty() {
+            headers = false;
+        }
+
+        if headers
+            && let Some(requirement) = value.strip_prefix("Requires-Dist:")
+            && let Some(requirement) = pin_requirement(requirement, pins)
+        {
+            output.push_str("Requires-Dist: ");
+            output.push_str(&requirement);
+            output.push_str(&line[value.len()..]);
+            continue;
+        }
+
+        output.push_str(line);
+    }
+    output
+}
+
+/// Returns the requirement with the pinned version specifiers, if it's an unbounded,
+/// non-optional requirement on a pinned package.
+fn pin_requirement(
+    requirement: &str,
+    pins: &BTreeMap<PackageName, VersionSpecifiers>,
+) -> Option<String> {
+    let mut requirement =
+        uv_pep508::Requirement::<VerbatimUrl>::from_str(requirement.trim()).ok()?;
+    if requirement.marker.top_level_extra().is_some() {
+        return None;
+    }
+    let is_unbounded = match &requirement.version_or_url {
+        None => true,
+        Some(VersionOrUrl::VersionSpecifier(specifiers)) => specifiers.is_empty(),
+        Some(VersionOrUrl::Url(_)) => false,
+    };
+    if !is_unbounded {
+        return None;
+    }
+    let specifiers = pins.get(&requirement.name)?;
+    requirement.version_or_url = Some(VersionOrUrl::VersionSpecifier(specifiers.clone()));
+    Some(requirement.to_string())
+}
+
 /// Create the output directory and add a `.gitignore`.
 async fn prepare_output_directory(output_dir: &Path, gitignore: bool) -> Result<(), Error> {
     // Create the output directory.
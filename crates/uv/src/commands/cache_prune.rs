@@ -1,12 +1,18 @@
 use std::fmt::Write;
+use std::path::PathBuf;
+use std::time::Duration;
 
 use anyhow::{Context, Result};
 use owo_colors::OwoColorize;
 use tracing::debug;
 
-use uv_cache::{Cache, Removal};
+use uv_cache::{Cache, PruneOptions, ReferencedArtifacts, Removal};
+use uv_configuration::TargetTriple;
+use uv_distribution_filename::{DistFilename, WheelFilename};
 use uv_fs::Simplified;
+use uv_platform_tags::{Arch, Os, Platform, PlatformTag};
 
+use crate::commands::project::lock_target::read_lockfile;
 use crate::commands::{ExitStatus, human_readable_bytes};
 use crate::printer::Printer;
 
@@ -14,6 +20,10 @@ use crate::printer::Printer;
 pub(crate) async fn cache_prune(
     ci: bool,
     force: bool,
+    older_than: Option<Duration>,
+    keep_lockfiles: Vec<PathBuf>,
+    python_platforms: Vec<TargetTriple>,
+    dry_run: bool,
     cache: Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
@@ -26,8 +36,40 @@ pub(crate) async fn cache_prune(
         return Ok(ExitStatus::Success);
     }
 
+    // Collect the artifacts referenced by the lockfiles, prior to locking the cache.
+    let referenced = if keep_lockfiles.is_empty() {
+        None
+    } else {
+        let mut referenced = ReferencedArtifacts::default();
+        for path in &keep_lockfiles {
+            let lock = read_lockfile(path)
+                .await
+                .with_context(|| format!("Failed to read lockfile: {}", path.user_display()))?;
+            for package in lock.packages() {
+                referenced.insert_package(package.name());
+                for (filename, _) in package.artifacts() {
+                    match filename {
+                        DistFilename::WheelFilename(filename) => {
+                            if python_platforms.is_empty()
+                                || is_compatible(&filename, &python_platforms)
+                            {
+                                referenced.insert_wheel(&filename);
+                            }
+                        }
+                        DistFilename::SourceDistFilename(filename) => {
+                            referenced.insert_source_dist(package.name(), &filename.version);
+                        }
+                    }
+                }
+            }
+        }
+        Some(referenced)
+    };
+
     let cache = match cache.with_exclusive_lock_no_wait() {
         Ok(cache) => cache,
+        // A dry run doesn't remove anything, so there's no need to wait for other processes.
+        Err(cache) if dry_run => cache,
         Err(cache) if force => {
             debug!("Cache is currently in use, proceeding due to `--force`");
             cache
@@ -49,46 +91,150 @@ pub(crate) async fn cache_prune(
 
     let mut summary = Removal::default();
 
+    // Remove any artifacts that aren't referenced by the lockfiles. Any unzipped wheels that
+    // are no longer referenced are removed when pruning the archive bucket below.
+    if let Some(referenced) = &referenced {
+        let unreferenced = cache
+            .prune_unreferenced(referenced, dry_run)
+            .with_context(|| {
+                format!("Failed to prune cache at: {}", cache.root().user_display())
+            })?;
+        writeln!(
+            printer.stderr(),
+            "{} {} of artifacts not referenced by {}",
+            if dry_run { "Would remove" } else { "Removed" },
+            format_bytes(unreferenced.total_bytes).green(),
+            if keep_lockfiles.len() == 1 {
+                format!("`{}`", keep_lockfiles[0].user_display())
+            } else {
+                format!("{} lockfiles", keep_lockfiles.len())
+            }
+        )?;
+        summary += unreferenced;
+    }
+
     // Prune the source distribution cache, which is tightly coupled to the builder crate.
-    summary += uv_distribution::prune(&cache)
+    summary += uv_distribution::prune(&cache, dry_run)
         .with_context(|| format!("Failed to prune cache at: {}", cache.root().user_display()))?;
 
     // Prune the remaining cache buckets.
-    summary += cache
-        .prune(ci)
+    let pruned = cache
+        .prune(PruneOptions {
+            ci,
+            older_than,
+            dry_run,
+        })
         .with_context(|| format!("Failed to prune cache at: {}", cache.root().user_display()))?;
+    summary += pruned.removal;
+
+    // When retaining recently used environments, or in a dry run, summarize the space used by
+    // cached environments.
+    let environments = pruned.environments;
+    if (older_than.is_some() || dry_run) && environments.num_environments > 0 {
+        writeln!(
+            printer.stderr(),
+            "Found {} cached environment{} ({}), {} of which {} removed ({})",
+            environments.num_environments,
+            if environments.num_environments == 1 {
+                ""
+            } else {
+                "s"
+            },
+            format_bytes(environments.total_bytes),
+            environments.num_removed,
+            match (dry_run, environments.num_removed) {
+                (true, _) => "would be",
+                (false, 1) => "was",
+                (false, _) => "were",
+            },
+            format_bytes(environments.removed_bytes).green()
+        )?;
+    }
 
     // Write a summary of the number of files and directories removed.
+    let removed = if dry_run { "Would remove" } else { "Removed" };
     match (summary.num_files, summary.num_dirs) {
         (0, 0) => {
             write!(printer.stderr(), "No unused entries found")?;
         }
         (0, 1) => {
-            write!(printer.stderr(), "Removed 1 directory")?;
+            write!(printer.stderr(), "{removed} 1 directory")?;
         }
         (0, num_dirs_removed) => {
-            write!(printer.stderr(), "Removed {num_dirs_removed} directories")?;
+            write!(printer.stderr(), "{removed} {num_dirs_removed} directories")?;
         }
         (1, _) => {
-            write!(printer.stderr(), "Removed 1 file")?;
+            write!(printer.stderr(), "{removed} 1 file")?;
         }
         (num_files_removed, _) => {
-            write!(printer.stderr(), "Removed {num_files_removed} files")?;
+            write!(printer.stderr(), "{removed} {num_files_removed} files")?;
         }
     }
 
     // If any, write a summary of the total byte count removed.
     if summary.total_bytes > 0 {
-        let bytes = if summary.total_bytes < 1024 {
-            format!("{}B", summary.total_bytes)
-        } else {
-            let (bytes, unit) = human_readable_bytes(summary.total_bytes);
-            format!("{bytes:.1}{unit}")
-        };
-        write!(printer.stderr(), " ({})", bytes.green())?;
+        write!(
+            printer.stderr(),
+            " ({})",
+            format_bytes(summary.total_bytes).green()
+        )?;
     }
 
     writeln!(printer.stderr())?;
 
     Ok(ExitStatus::Success)
 }
+
+/// Returns `true` if the wheel may be installed on any of the given platforms.
+///
+/// Only the operating system and architecture are considered, since the Python version isn't
+/// known.
+fn is_compatible(filename: &WheelFilename, python_platforms: &[TargetTriple]) -> bool {
+    python_platforms.iter().any(|python_platform| {
+        let platform = python_platform.platform();
+        filename
+            .platform_tags()
+            .iter()
+            .any(|tag| is_platform_compatible(tag, &platform))
+    })
+}
+
+/// Returns `true` if the platform tag may be installed on the given [`Platform`].
+fn is_platform_compatible(tag: &PlatformTag, platform: &Platform) -> bool {
+    if tag.is_any() {
+        return true;
+    }
+
+    let os = match platform.os() {
+        Os::Manylinux { .. } | Os::Musllinux { .. } => tag.is_linux(),
+        Os::Windows => tag.is_windows(),
+        Os::Macos { .. } => tag.is_macos(),
+        // For any other platform, err on the side of retaining the wheel.
+        _ => return true,
+    };
+    if !os {
+        return false;
+    }
+
+    // macOS wheels can target multiple architectures (e.g., `universal2`).
+    if tag.is_macos() {
+        return true;
+    }
+
+    match platform.arch() {
+        Arch::X86_64 => tag.is_x86_64(),
+        Arch::Aarch64 => tag.is_arm(),
+        Arch::X86 => tag.is_x86(),
+        _ => true,
+    }
+}
+
+/// Format a byte count for display, e.g., `1.2MiB`.
+fn format_bytes(bytes: u64) -> String {
+    if bytes < 1024 {
+        format!("{bytes}B")
+    } else {
+        let (bytes, unit) = human_readable_bytes(bytes);
+        format!("{bytes:.1}{unit}")
+    }
+}
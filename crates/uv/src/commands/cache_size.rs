@@ -1,4 +1,5 @@
 use std::fmt::Write;
+use std::path::Path;
 
 use anyhow::Result;
 use diskus::DiskUsage;
@@ -10,9 +11,11 @@ use uv_preview::{Preview, PreviewFeature};
 use uv_warnings::warn_user;
 
 /// Display the total size of the cache.
+#[expect(clippy::cast_precision_loss)]
 pub(crate) fn cache_size(
     cache: &Cache,
     human_readable: bool,
+    dedup: bool,
     printer: Printer,
     preview: Preview,
 ) -> Result<ExitStatus> {
@@ -36,12 +39,65 @@ pub(crate) fn cache_size(
 
     let total_bytes = disk_usage.count_ignoring_errors();
 
-    if human_readable {
-        let (bytes, unit) = human_readable_bytes(total_bytes);
-        writeln!(printer.stdout_important(), "{bytes:.1}{unit}")?;
-    } else {
-        writeln!(printer.stdout_important(), "{total_bytes}")?;
+    let format = |bytes: u64| {
+        if human_readable {
+            let (bytes, unit) = human_readable_bytes(bytes);
+            format!("{bytes:.1}{unit}")
+        } else {
+            bytes.to_string()
+        }
+    };
+
+    writeln!(printer.stdout_important(), "{}", format(total_bytes))?;
+
+    if dedup {
+        let (apparent_bytes, unique_bytes) = dedup_sizes(cache.root());
+        let ratio = if unique_bytes == 0 {
+            1.0
+        } else {
+            apparent_bytes as f64 / unique_bytes as f64
+        };
+        writeln!(
+            printer.stdout_important(),
+            "Deduplication: {} stored as {} ({ratio:.2}x)",
+            format(apparent_bytes),
+            format(unique_bytes),
+        )?;
     }
 
     Ok(ExitStatus::Success)
 }
+
+/// Compute the combined size of all files under the given directory, along with their size once
+/// files that are hard linked into multiple locations are only counted once.
+///
+/// Symbolic links are not followed, as each points to a directory that is counted on its own.
+fn dedup_sizes(root: &Path) -> (u64, u64) {
+    #[cfg(unix)]
+    let mut seen = rustc_hash::FxHashSet::default();
+
+    let mut apparent_bytes = 0;
+    let mut unique_bytes = 0;
+    for entry in walkdir::WalkDir::new(root).into_iter().flatten() {
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        apparent_bytes += metadata.len();
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+
+            if metadata.nlink() > 1 && !seen.insert((metadata.dev(), metadata.ino())) {
+                continue;
+            }
+        }
+
+        unique_bytes += metadata.len();
+    }
+
+    (apparent_bytes, unique_bytes)
+}
@@ -0,0 +1,149 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use uv_cache::Cache;
+use uv_cli::ConfigLintFormat;
+use uv_fs::Simplified;
+use uv_python::ConfigDiscovery;
+use uv_settings::{ConfigSource, LintDiagnostic, LintSeverity, lint_configuration};
+use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// The JSON report emitted by `uv config lint --output-format json`.
+#[derive(Debug, Serialize)]
+struct ConfigLintReport<'a> {
+    sources: &'a [ConfigSource],
+    diagnostics: &'a [LintDiagnostic],
+}
+
+/// Lint the configuration that applies to the given directory.
+pub(crate) async fn config_lint(
+    project_dir: &Path,
+    config_file: Option<&Path>,
+    config_discovery: ConfigDiscovery,
+    output_format: ConfigLintFormat,
+    cache: &Cache,
+    workspace_cache: &WorkspaceCache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    // Mirror the discovery that's performed when loading settings for any other command.
+    let sources = if let Some(config_file) = config_file {
+        vec![ConfigSource::from_config_file(config_file)]
+    } else if !config_discovery.enabled() {
+        Vec::new()
+    } else if let Ok(workspace) = Workspace::discover(
+        project_dir,
+        &DiscoveryOptions::default(),
+        cache,
+        workspace_cache,
+    )
+    .await
+    {
+        ConfigSource::discover(workspace.install_path())
+    } else {
+        ConfigSource::discover(project_dir)
+    };
+
+    let diagnostics = lint_configuration(&sources);
+
+    match output_format {
+        ConfigLintFormat::Text => {
+            if sources.is_empty() {
+                writeln!(printer.stdout(), "No configuration files found")?;
+            } else {
+                writeln!(
+                    printer.stdout(),
+                    "Configuration sources (in order of precedence):"
+                )?;
+                for source in &sources {
+                    if source.is_masked() {
+                        writeln!(
+                            printer.stdout(),
+                            " - {} ({}, ignored)",
+                            source.path().user_display().cyan(),
+                            source.kind()
+                        )?;
+                    } else {
+                        writeln!(
+                            printer.stdout(),
+                            " - {} ({})",
+                            source.path().user_display().cyan(),
+                            source.kind()
+                        )?;
+                    }
+                }
+            }
+
+            for diagnostic in &diagnostics {
+                writeln!(printer.stdout())?;
+                write_diagnostic(diagnostic, printer)?;
+            }
+
+            writeln!(printer.stdout())?;
+            match diagnostics.len() {
+                0 => writeln!(printer.stdout(), "No issues found")?,
+                1 => writeln!(printer.stdout(), "Found {} issue", "1".bold())?,
+                n => writeln!(printer.stdout(), "Found {} issues", n.to_string().bold())?,
+            }
+        }
+        ConfigLintFormat::Json => {
+            let report = ConfigLintReport {
+                sources: &sources,
+                diagnostics: &diagnostics,
+            };
+            writeln!(
+                printer.stdout(),
+                "{}",
+                serde_json::to_string_pretty(&report)?
+            )?;
+        }
+    }
+
+    if diagnostics.is_empty() {
+        Ok(ExitStatus::Success)
+    } else {
+        Ok(ExitStatus::Failure)
+    }
+}
+
+/// Render a single [`LintDiagnostic`] in the human-readable format.
+fn write_diagnostic(diagnostic: &LintDiagnostic, printer: Printer) -> Result<()> {
+    let header = format!("{}[{}]", diagnostic.severity, diagnostic.code);
+    let header = match diagnostic.severity {
+        LintSeverity::Error => header.red().bold().to_string(),
+        LintSeverity::Warning => header.yellow().bold().to_string(),
+    };
+    writeln!(
+        printer.stdout(),
+        "{header}{} {}",
+        ":".bold(),
+        diagnostic.message.bold()
+    )?;
+
+    if let Some(location) = &diagnostic.location {
+        let file = location.file.user_display();
+        let location = if let Some(line) = location.line {
+            format!("{file}:{line}")
+        } else {
+            file.to_string()
+        };
+        writeln!(printer.stdout(), "  {} {}", "-->".blue().bold(), location)?;
+    }
+
+    if let Some(help) = &diagnostic.help {
+        writeln!(
+            printer.stdout(),
+            "  {}{} {help}",
+            "help".bold().cyan(),
+            ":".bold()
+        )?;
+    }
+
+    Ok(())
+}
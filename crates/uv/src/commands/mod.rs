@@ -20,6 +20,7 @@ pub(crate) use cache_clean::cache_clean;
 pub(crate) use cache_dir::cache_dir;
 pub(crate) use cache_prune::cache_prune;
 pub(crate) use cache_size::cache_size;
+pub(crate) use config_lint::config_lint;
 pub(crate) use help::help;
 pub(crate) use pip::check::pip_check;
 pub(crate) use pip::compile::pip_compile;
@@ -37,7 +38,7 @@ pub(crate) use project::check::check;
 pub(crate) use project::export::export;
 pub(crate) use project::format::format;
 pub(crate) use project::init::{InitKind, InitProjectKind, init};
-pub(crate) use project::lock::lock;
+pub(crate) use project::lock::{lock, lock_schema, lock_validate};
 pub(crate) use project::remove::remove;
 pub(crate) use project::run::{ParsedRunCommand, RunCommand, run};
 pub(crate) use project::sync::sync;
@@ -52,6 +53,7 @@ pub(crate) use python::install::install as python_install;
 pub(crate) use python::install::{PythonUpgrade, PythonUpgradeSource};
 pub(crate) use python::list::list as python_list;
 pub(crate) use python::pin::pin as python_pin;
+pub(crate) use python::platform::list as python_platform_list;
 pub(crate) use python::uninstall::uninstall as python_uninstall;
 pub(crate) use python::update_shell::update_shell as python_update_shell;
 #[cfg(feature = "self-update")]
@@ -71,10 +73,12 @@ use uv_fs::{CWD, Simplified};
 use uv_installer::{compile_files, compile_tree};
 use uv_python::PythonEnvironment;
 use uv_scripts::Pep723Script;
-pub(crate) use venv::venv;
+pub(crate) use venv::{venv, venv_move, venv_update};
+pub(crate) use workspace::add::add as workspace_add;
 pub(crate) use workspace::dir::dir;
 pub(crate) use workspace::list::list;
 pub(crate) use workspace::metadata::metadata;
+pub(crate) use workspace::remove::remove as workspace_remove;
 
 use crate::commands::pip::operations::ChangedDist;
 use crate::printer::Printer;
@@ -86,6 +90,7 @@ mod cache_clean;
 mod cache_dir;
 mod cache_prune;
 mod cache_size;
+mod config_lint;
 pub(crate) mod diagnostics;
 mod editable;
 mod help;
@@ -463,3 +468,12 @@ pub(crate) enum ScriptPath {
     /// The Python file does not include a PEP 723 script tag.
     Path(PathBuf),
 }
+
+/// A set of PEP 723 scripts to lock into a single, shared lockfile.
+#[derive(Debug)]
+pub(crate) struct SharedLock {
+    /// The path to the shared lockfile.
+    pub(crate) path: PathBuf,
+    /// The scripts that share the lockfile.
+    pub(crate) scripts: Vec<Pep723Script>,
+}
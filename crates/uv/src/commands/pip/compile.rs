@@ -1,6 +1,7 @@
 use std::collections::BTreeSet;
 use std::env;
 use std::ffi::OsStr;
+use std::fmt::Write as _;
 use std::io::Write;
 use std::path::Path;
 use std::str::FromStr;
@@ -9,6 +10,7 @@ use anyhow::{Result, anyhow};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
 use rustc_hash::FxHashSet;
+use sha2::{Digest, Sha256};
 use tracing::debug;
 
 use uv_cache::Cache;
@@ -31,7 +33,7 @@ use uv_install_wheel::LinkMode;
 use uv_normalize::PackageName;
 use uv_pep440::Version;
 use uv_preview::Preview;
-use uv_pypi_types::{Conflicts, SupportedEnvironments};
+use uv_pypi_types::{Conflicts, MetadataVersion, SupportedEnvironments};
 use uv_python::{
     EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonInstallation,
     PythonPreference, PythonRequest, PythonVersion, VersionRequest,
@@ -79,16 +81,22 @@ pub(crate) async fn pip_compile(
     format: Option<PipCompileFormat>,
     resolution_mode: ResolutionMode,
     prerelease_mode: PrereleaseMode,
+    prerelease_packages: Vec<PackageName>,
     fork_strategy: ForkStrategy,
     dependency_mode: DependencyMode,
     upgrade: Upgrade,
     generate_hashes: bool,
     no_emit_packages: Vec<PackageName>,
+    unsafe_packages: Vec<PackageName>,
+    allow_unsafe: bool,
     include_extras: bool,
     include_markers: bool,
     include_annotations: bool,
     include_header: bool,
+    pip_tools_header: bool,
     custom_compile_command: Option<String>,
+    include_inputs_hash: bool,
+    check_fresh: Option<&Path>,
     include_index_url: bool,
     include_find_links: bool,
     include_build_options: bool,
@@ -114,6 +122,7 @@ pub(crate) async fn pip_compile(
     python_downloads: PythonDownloads,
     universal: bool,
     exclude_newer: ExcludeNewer,
+    require_metadata: Option<MetadataVersion>,
     sources: NoSources,
     annotation_style: AnnotationStyle,
     link_mode: LinkMode,
@@ -198,6 +207,20 @@ pub(crate) async fn pip_compile(
 
     let client_builder = client_builder.clone().keyring(keyring_provider);
 
+    // If requested, hash the provided sources, so that we can detect whether the output is stale.
+    let mut inputs_hasher = (include_inputs_hash || check_fresh.is_some()).then(Sha256::new);
+    if let Some(hasher) = inputs_hasher.as_mut() {
+        for source in requirements
+            .iter()
+            .chain(constraints)
+            .chain(overrides)
+            .chain(excludes)
+            .chain(build_constraints)
+        {
+            hash_source(hasher, source)?;
+        }
+    }
+
     // Read all requirements from the provided sources.
     let RequirementsSpecification {
         project,
@@ -216,6 +239,7 @@ pub(crate) async fn pip_compile(
         find_links,
         no_binary,
         no_build,
+        remote_files: _,
     } = RequirementsSpecification::from_sources(
         requirements,
         constraints,
@@ -223,6 +247,7 @@ pub(crate) async fn pip_compile(
         excludes,
         Some(&groups),
         &client_builder,
+        &cache,
     )
     .await?;
 
@@ -235,7 +260,7 @@ pub(crate) async fn pip_compile(
         ));
     }
 
-    let constraints = constraints
+    let constraints: Vec<_> = constraints
         .iter()
         .cloned()
         .chain(
@@ -252,7 +277,7 @@ pub(crate) async fn pip_compile(
 
     // Read build constraints.
     let build_constraints: Vec<NameRequirementSpecification> =
-        operations::read_constraints(build_constraints, &client_builder)
+        operations::read_constraints(build_constraints, &client_builder, &cache)
             .await?
             .into_iter()
             .chain(
@@ -491,6 +516,108 @@ pub(crate) async fn pip_compile(
     // Combine the `--no-binary` and `--no-build` flags from the requirements files.
     let build_options = build_options.combine(no_binary, no_build);
 
+    // Hash the remaining inputs: the requirements (including those from nested files), the target
+    // environment, and the resolver settings. The upgrade strategy and output file are omitted, as
+    // in the header command.
+    let inputs_digest = inputs_hasher.map(|mut hasher| {
+        for specification in requirements.iter().chain(&overrides) {
+            hash_input(&mut hasher, "requirement", &specification.requirement);
+            hash_input(&mut hasher, "hashes", specification.hashes.join(" "));
+        }
+        for specification in constraints.iter().chain(&build_constraints) {
+            hash_input(&mut hasher, "constraint", &specification.requirement);
+            hash_input(&mut hasher, "hashes", specification.hashes.join(" "));
+        }
+        hash_input(
+            &mut hasher,
+            "dependencies",
+            format!("{override_dependencies:?} {excludes:?}"),
+        );
+        hash_input(&mut hasher, "format", format!("{format:?}"));
+        if universal {
+            hash_input(
+                &mut hasher,
+                "environment",
+                format!("{:?} {resolver_env:?}", python_requirement.target()),
+            );
+        } else {
+            hash_input(
+                &mut hasher,
+                "environment",
+                format!("{resolver_env:?} {python_platform:?}"),
+            );
+        }
+        hash_input(
+            &mut hasher,
+            "resolver",
+            format!(
+                "{:?}",
+                (
+                    (
+                        &resolution_mode,
+                        &prerelease_mode,
+                        &prerelease_packages,
+                        &fork_strategy,
+                        &dependency_mode,
+                        &extras,
+                        &groups,
+                        &artifact_environments,
+                    ),
+                    (
+                        &index_locations,
+                        &index_strategy,
+                        &torch_backend,
+                        &dependency_metadata,
+                        &config_settings,
+                        &config_settings_package,
+                        &build_isolation,
+                        extra_build_dependencies,
+                        extra_build_variables,
+                        &build_options,
+                    ),
+                    (&exclude_newer, &require_metadata, &sources),
+                ),
+            ),
+        );
+        hash_input(
+            &mut hasher,
+            "output",
+            format!(
+                "{:?}",
+                (
+                    (
+                        &generate_hashes,
+                        &no_emit_packages,
+                        &unsafe_packages,
+                        &allow_unsafe,
+                        &include_extras,
+                        &include_markers,
+                        &include_annotations,
+                        &include_header,
+                        &pip_tools_header,
+                        &custom_compile_command,
+                    ),
+                    (
+                        &include_index_url,
+                        &include_find_links,
+                        &include_build_options,
+                        &include_marker_expression,
+                        &include_index_annotation,
+                        &annotation_style,
+                    ),
+                ),
+            ),
+        );
+        format!("{:x}", hasher.finalize())
+    });
+
+    // If requested, compare the digest against that recorded in the existing output file, rather
+    // than resolving.
+    if let Some(check_fresh) = check_fresh {
+        let digest = inputs_digest.as_deref().unwrap_or_default();
+        return check_inputs_digest(check_fresh, digest, printer);
+    }
+
     // Resolve the flat indexes from `--find-links`.
     let flat_index = {
         let client = FlatIndexClient::new(client.cached_client(), client.connectivity(), &cache);
@@ -557,9 +684,11 @@ pub(crate) async fn pip_compile(
     let options = OptionsBuilder::new()
         .resolution_mode(resolution_mode)
         .prerelease_mode(prerelease_mode)
+        .prerelease_packages(prerelease_packages)
         .fork_strategy(fork_strategy)
         .dependency_mode(dependency_mode)
         .exclude_newer(exclude_newer.clone())
+        .require_metadata(require_metadata)
         .index_strategy(index_strategy)
         .torch_backend(torch_backend)
         .build_options(build_options.clone())
@@ -610,7 +739,35 @@ pub(crate) async fn pip_compile(
     // Write the resolved dependencies to the output channel.
     let mut writer = OutputWriter::new(!quiet || output_file.is_none(), output_file);
 
-    if include_header {
+    if include_header && pip_tools_header {
+        let (major, minor) = python_version.as_ref().map_or_else(
+            || (interpreter.python_major(), interpreter.python_minor()),
+            |python_version| (python_version.major(), python_version.minor()),
+        );
+        writeln!(writer, "{}", "#".green())?;
+        writeln!(
+            writer,
+            "{}",
+            format!("# This file is autogenerated by pip-compile with Python {major}.{minor}")
+                .green()
+        )?;
+        writeln!(writer, "{}", "# by the following command:".green())?;
+        writeln!(writer, "{}", "#".green())?;
+        writeln!(
+            writer,
+            "{}",
+            format!(
+                "#    {}",
+                pip_tools_cmd(
+                    include_index_url,
+                    include_find_links,
+                    custom_compile_command
+                )
+            )
+            .green()
+        )?;
+        writeln!(writer, "{}", "#".green())?;
+    } else if include_header {
         writeln!(
             writer,
             "{}",
@@ -631,6 +788,28 @@ pub(crate) async fn pip_compile(
         )?;
     }
 
+    if include_inputs_hash {
+        if let Some(digest) = inputs_digest.as_deref() {
+            writeln!(
+                writer,
+                "{}",
+                format!("{INPUTS_DIGEST_PREFIX}{digest}").green()
+            )?;
+        }
+    }
+
+    // Unless they're allowed, unsafe packages are excluded from the output like any other
+    // `--no-emit-package`.
+    let excluded_packages = if allow_unsafe {
+        no_emit_packages.clone()
+    } else {
+        no_emit_packages
+            .iter()
+            .chain(&unsafe_packages)
+            .cloned()
+            .collect::<Vec<_>>()
+    };
+
     match format {
         PipCompileFormat::RequirementsTxt => {
             if include_marker_expression {
@@ -713,7 +892,8 @@ pub(crate) async fn pip_compile(
                 DisplayResolutionGraph::new(
                     &resolution,
                     &resolver_env,
-                    &no_emit_packages,
+                    &excluded_packages,
+                    if allow_unsafe { &unsafe_packages } else { &[] },
                     generate_hashes,
                     include_extras,
                     include_markers || universal,
@@ -761,7 +941,7 @@ pub(crate) async fn pip_compile(
             // Convert the resolution to a `pylock.toml` file.
             let export = PylockToml::from_resolution(
                 &resolution,
-                &no_emit_packages,
+                &excluded_packages,
                 install_path,
                 tags.as_deref(),
                 &build_options,
@@ -770,7 +950,7 @@ pub(crate) async fn pip_compile(
         }
     }
 
-    // If any "unsafe" packages were excluded, notify the user.
+    // If any packages were excluded, notify the user.
     let excluded = no_emit_packages
         .into_iter()
         .filter(|name| resolution.contains(name))
@@ -787,6 +967,26 @@ pub(crate) async fn pip_compile(
         }
     }
 
+    // If any unsafe packages were excluded, list them as pip-compile does.
+    if !allow_unsafe {
+        let excluded = unsafe_packages
+            .into_iter()
+            .filter(|name| resolution.contains(name))
+            .collect::<Vec<_>>();
+        if !excluded.is_empty() {
+            writeln!(writer)?;
+            writeln!(
+                writer,
+                "{}",
+                "# The following packages are considered to be unsafe in a requirements file:"
+                    .green()
+            )?;
+            for package in excluded {
+                writeln!(writer, "# {package}")?;
+            }
+        }
+    }
+
     // Commit the output to disk.
     writer.commit().await?;
 
@@ -796,6 +996,90 @@ pub(crate) async fn pip_compile(
     Ok(ExitStatus::Success)
 }
 
+/// The prefix of the header comment that records the digest of the inputs to `uv pip compile`.
+const INPUTS_DIGEST_PREFIX: &str = "# uv: inputs-sha256=";
+
+/// Hash a requirements source provided on the command line, including the contents of any file.
+fn hash_source(hasher: &mut Sha256, source: &RequirementsSource) -> Result<()> {
+    hash_input(hasher, "source", source);
+    match source {
+        RequirementsSource::Package(_) | RequirementsSource::Editable(_) => {}
+        RequirementsSource::Pep723Script(path)
+        | RequirementsSource::PylockToml(path)
+        | RequirementsSource::RequirementsTxt(path)
+        | RequirementsSource::PyprojectToml(path)
+        | RequirementsSource::SetupPy(path)
+        | RequirementsSource::SetupCfg(path)
+        | RequirementsSource::EnvironmentYml(path)
+        | RequirementsSource::Extensionless(path) => {
+            // Requirements may be read from `stdin` (which we can't read twice) or a remote URL
+            // (which is captured by the parsed requirements instead).
+            if path != Path::new("-")
+                && !(path.starts_with("http://") || path.starts_with("https://"))
+            {
+                hasher.update(fs_err::read(path)?);
+                hasher.update(b"\n");
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Hash a labeled input to `uv pip compile`.
+fn hash_input(hasher: &mut Sha256, label: &str, value: impl std::fmt::Display) {
+    hasher.update(label.as_bytes());
+    hasher.update(b"\0");
+    hasher.update(value.to_string().as_bytes());
+    hasher.update(b"\n");
+}
+
+/// Compare the digest of the current inputs against the digest recorded in an output file.
+fn check_inputs_digest(path: &Path, digest: &str, printer: Printer) -> Result<ExitStatus> {
+    let contents = match fs_err::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            writeln!(
+                printer.stderr(),
+                "`{}` does not exist",
+                path.user_display().cyan()
+            )?;
+            return Ok(ExitStatus::Failure);
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    let recorded = contents
+        .lines()
+        .find_map(|line| line.trim().strip_prefix(INPUTS_DIGEST_PREFIX));
+    match recorded {
+        Some(recorded) if recorded.trim() == digest => {
+            writeln!(
+                printer.stderr(),
+                "`{}` is up-to-date",
+                path.user_display().cyan()
+            )?;
+            Ok(ExitStatus::Success)
+        }
+        Some(_) => {
+            writeln!(
+                printer.stderr(),
+                "`{}` is out-of-date: the inputs have changed since it was compiled",
+                path.user_display().cyan()
+            )?;
+            Ok(ExitStatus::Failure)
+        }
+        None => {
+            writeln!(
+                printer.stderr(),
+                "`{}` does not record an inputs digest (compile it with `{}`)",
+                path.user_display().cyan(),
+                "--emit-inputs-hash".green()
+            )?;
+            Ok(ExitStatus::Failure)
+        }
+    }
+}
+
 /// Format the uv command used to generate the output file.
 fn cmd(
     include_index_url: bool,
@@ -805,7 +1089,59 @@ fn cmd(
     if let Some(cmd_str) = custom_compile_command {
         return cmd_str;
     }
-    let args = env::args_os()
+    format!(
+        "uv {}",
+        cmd_args(include_index_url, include_find_links).join(" ")
+    )
+}
+
+/// Format the command used to generate the output file as the equivalent `pip-compile` command,
+/// for `--pip-tools-header`.
+///
+/// The `pip compile` subcommand is replaced with `pip-compile`, and the output file is written as
+/// `--output-file=<path>`, as `pip-compile` does. Other arguments are retained as-is.
+fn pip_tools_cmd(
+    include_index_url: bool,
+    include_find_links: bool,
+    custom_compile_command: Option<String>,
+) -> String {
+    if let Some(cmd_str) = custom_compile_command {
+        return cmd_str;
+    }
+    let mut args = cmd_args(include_index_url, include_find_links);
+    if let Some(index) = args
+        .windows(2)
+        .position(|window| window[0] == "pip" && window[1] == "compile")
+    {
+        args.drain(index..index + 2);
+    }
+    let mut args = args.into_iter().filter(|arg| arg != "--pip-tools-header");
+    let mut pip_compile_args = Vec::new();
+    while let Some(arg) = args.next() {
+        if arg == "--output-file" || arg == "-o" {
+            if let Some(path) = args.next() {
+                pip_compile_args.push(format!("--output-file={path}"));
+            }
+        } else if let Some(path) = arg.strip_prefix("-o")
+            && !path.starts_with('-')
+        {
+            let path = path.strip_prefix('=').unwrap_or(path);
+            pip_compile_args.push(format!("--output-file={path}"));
+        } else {
+            pip_compile_args.push(arg);
+        }
+    }
+    if pip_compile_args.is_empty() {
+        "pip-compile".to_string()
+    } else {
+        format!("pip-compile {}", pip_compile_args.join(" "))
+    }
+}
+
+/// Return the arguments used to generate the output file, omitting those that don't affect the
+/// output.
+fn cmd_args(include_index_url: bool, include_find_links: bool) -> Vec<String> {
+    env::args_os()
         .skip(1)
         .map(|arg| arg.to_string_lossy().to_string())
         .scan(None, move |skip_next, arg| {
@@ -903,6 +1239,5 @@ fn cmd(
             Some(Some(arg))
         })
         .flatten()
-        .join(" ");
-    format!("uv {args}")
+        .collect()
 }
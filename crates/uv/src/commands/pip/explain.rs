@@ -0,0 +1,86 @@
+use std::fmt::Write;
+
+use owo_colors::OwoColorize;
+
+use uv_distribution_types::{ArtifactCandidate, ArtifactVerdict};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_platform_tags::Tags;
+
+use crate::printer::Printer;
+
+/// Report the artifacts that were considered for a package, and why each was selected or
+/// rejected (i.e., for `--explain`).
+pub(crate) fn report_artifact_selection(
+    name: &PackageName,
+    version: Option<&Version>,
+    tags: &Tags,
+    candidates: &[ArtifactCandidate],
+    printer: Printer,
+) -> anyhow::Result<()> {
+    writeln!(
+        printer.stderr(),
+        "Artifacts for {} (Python: {}, ABI: {}, platform: {}):",
+        match version {
+            Some(version) => format!("{name}=={version}"),
+            None => name.to_string(),
+        }
+        .bold(),
+        tags.python_tag()
+            .map_or_else(|| "unknown".to_string(), |tag| tag.to_string())
+            .cyan(),
+        tags.abi_tag()
+            .map_or_else(|| "unknown".to_string(), |tag| tag.to_string())
+            .cyan(),
+        tags.platform_tag()
+            .map_or_else(|| "unknown".to_string(), ToString::to_string)
+            .cyan(),
+    )?;
+
+    if candidates.is_empty() {
+        writeln!(printer.stderr(), " {}", "No artifacts available".dimmed())?;
+        return Ok(());
+    }
+
+    for candidate in candidates {
+        match &candidate.verdict {
+            ArtifactVerdict::Selected => writeln!(
+                printer.stderr(),
+                " {} {} {}",
+                "+".green(),
+                candidate.artifact.bold(),
+                "(selected)".green(),
+            )?,
+            ArtifactVerdict::Superseded => writeln!(
+                printer.stderr(),
+                " {} {}",
+                "~".dimmed(),
+                format!(
+                    "{} (superseded by a higher-priority artifact)",
+                    candidate.artifact
+                )
+                .dimmed(),
+            )?,
+            ArtifactVerdict::Rejected(reason) => writeln!(
+                printer.stderr(),
+                " {} {} {}",
+                "-".red(),
+                candidate.artifact,
+                format!("(rejected: {reason})").red(),
+            )?,
+        }
+    }
+
+    if !candidates
+        .iter()
+        .any(|candidate| matches!(candidate.verdict, ArtifactVerdict::Selected))
+    {
+        writeln!(
+            printer.stderr(),
+            " {}",
+            "No compatible artifact was selected".red()
+        )?;
+    }
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use std::collections::BTreeSet;
+use std::fmt::Write;
 use std::path::PathBuf;
 
 use itertools::Itertools;
@@ -9,6 +10,7 @@ use tracing::{Level, debug, enabled, warn};
 use uv_errors::{Hint, Hints};
 
 use uv_cache::Cache;
+use uv_cli::DryRunFormat;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     BuildIsolation, BuildOptions, Concurrency, Constraints, DryRun, EditableMode,
@@ -21,14 +23,15 @@ use uv_distribution::LoweredExtraBuildDependencies;
 use uv_distribution_types::{
     ConfigSettings, DependencyMetadata, ExtraBuildVariables, Index, IndexLocations, Name,
     NameRequirementSpecification, Origin, PackageConfigSettings, Requirement, Resolution,
+    ResolvedDist,
 };
 use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
 use uv_installer::{InstallationStrategy, SatisfiesResult, SitePackages};
-use uv_normalize::{DefaultExtras, DefaultGroups};
+use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
 use uv_pep440::Version;
 use uv_preview::{Preview, PreviewFeature};
-use uv_pypi_types::Conflicts;
+use uv_pypi_types::{Conflicts, MetadataVersion};
 use uv_python::{
     EnvironmentPreference, Prefix, PythonDownloads, PythonEnvironment, PythonInstallation,
     PythonPreference, PythonRequest, PythonVersion, Target,
@@ -46,7 +49,10 @@ use uv_workspace::WorkspaceCache;
 use uv_workspace::pyproject::ExtraBuildDependencies;
 
 use crate::commands::editable::apply_editable_mode;
-use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger, InstallLogger};
+use crate::commands::pip::explain::report_artifact_selection;
+use crate::commands::pip::loggers::{
+    DefaultInstallLogger, DefaultResolveLogger, DryRunInstallLogger, InstallLogger,
+};
 use crate::commands::pip::operations::Modifications;
 use crate::commands::pip::operations::{report_interpreter, report_target_environment};
 use crate::commands::pip::{operations, resolution_markers, resolution_tags};
@@ -86,11 +92,14 @@ pub(crate) async fn pip_install(
     overrides_from_workspace: Vec<Override<Requirement>>,
     excludes_from_workspace: Vec<ExcludeDependency>,
     build_constraints_from_workspace: Vec<Requirement>,
+    exclude_package: &[PackageName],
+    exclude_unchecked: bool,
     editable: Option<EditableMode>,
     extras: &ExtrasSpecification,
     groups: &GroupsSpecification,
     resolution_mode: ResolutionMode,
     prerelease_mode: PrereleaseMode,
+    prerelease_packages: Vec<PackageName>,
     dependency_mode: DependencyMode,
     upgrade: Upgrade,
     index_locations: IndexLocations,
@@ -119,6 +128,7 @@ pub(crate) async fn pip_install(
     install_mirrors: PythonInstallMirrors,
     strict: bool,
     exclude_newer: ExcludeNewer,
+    require_metadata: Option<MetadataVersion>,
     sources: NoSources,
     python: Option<String>,
     system: bool,
@@ -130,11 +140,20 @@ pub(crate) async fn pip_install(
     cache: Cache,
     workspace_cache: WorkspaceCache,
     dry_run: DryRun,
+    format: DryRunFormat,
+    explain: Vec<PackageName>,
     printer: Printer,
     preview: Preview,
 ) -> anyhow::Result<ExitStatus> {
     let start = std::time::Instant::now();
 
+    if matches!(format, DryRunFormat::Json) && !preview.is_enabled(PreviewFeature::JsonOutput) {
+        warn_user!(
+            "The `--format json` option is experimental and the schema may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::JsonOutput
+        );
+    }
+
     let client_builder = client_builder.clone().keyring(keyring_provider);
 
     // Read all requirements from the provided sources.
@@ -155,6 +174,7 @@ pub(crate) async fn pip_install(
         no_binary,
         no_build,
         extras: _,
+        remote_files: _,
     } = operations::read_requirements(
         requirements,
         constraints,
@@ -163,6 +183,7 @@ pub(crate) async fn pip_install(
         extras,
         Some(groups),
         &client_builder,
+        &cache,
     )
     .await?;
 
@@ -194,7 +215,7 @@ pub(crate) async fn pip_install(
 
     // Read build constraints.
     let build_constraints: Vec<NameRequirementSpecification> =
-        operations::read_constraints(build_constraints, &client_builder)
+        operations::read_constraints(build_constraints, &client_builder, &cache)
             .await?
             .into_iter()
             .chain(
@@ -244,6 +265,10 @@ pub(crate) async fn pip_install(
         LoweredExtraBuildDependencies::from_non_lowered(extra_build_dependencies.clone())
             .into_inner();
 
+    // Scripts installed into a `--target` or `--prefix` directory aren't expected to be on the
+    // `PATH`.
+    let scripts_path_notice = target.is_none() && prefix.is_none();
+
     // Apply any `--target` or `--prefix` directories.
     let environment = if let Some(target) = target {
         debug!(
@@ -360,7 +385,16 @@ pub(crate) async fn pip_install(
                         debug!("Requirement satisfied: {requirement}");
                     }
                 }
-                DefaultInstallLogger.on_check(requirements.len(), start, printer, dry_run)?;
+                if dry_run.enabled() {
+                    DryRunInstallLogger::new(format, &environment).on_check(
+                        requirements.len(),
+                        start,
+                        printer,
+                        dry_run,
+                    )?;
+                } else {
+                    DefaultInstallLogger.on_check(requirements.len(), start, printer, dry_run)?;
+                }
 
                 if strict && !dry_run.enabled() {
                     operations::diagnose_environment(
@@ -527,7 +561,7 @@ pub(crate) async fn pip_install(
         preview,
     );
 
-    let (resolution, hasher) = if let Some(pylock) = pylock {
+    let (resolution, hasher, excluded) = if let Some(pylock) = pylock {
         let (install_path, lock) = read_pylock_toml(&pylock, &client_builder).await?;
 
         // Convert the extras and groups specifications into a concrete form.
@@ -547,7 +581,7 @@ pub(crate) async fn pip_install(
             .cloned()
             .collect::<Vec<_>>();
 
-        resolve_pylock_toml(
+        let (resolution, hasher) = resolve_pylock_toml(
             lock,
             &install_path,
             interpreter,
@@ -557,7 +591,15 @@ pub(crate) async fn pip_install(
             &groups,
             &build_options,
             hash_checking,
-        )?
+        )?;
+        let excluded = operations::ExcludedRequirement::from_locked(
+            &resolution,
+            exclude_package,
+            &marker_env,
+            |_| None,
+        );
+
+        (resolution, hasher, excluded)
     } else {
         // When resolving, don't take any external preferences into account.
         let preferences = Vec::default();
@@ -565,15 +607,17 @@ pub(crate) async fn pip_install(
         let options = OptionsBuilder::new()
             .resolution_mode(resolution_mode)
             .prerelease_mode(prerelease_mode)
+            .prerelease_packages(prerelease_packages)
             .dependency_mode(dependency_mode)
             .exclude_newer(exclude_newer.clone())
+            .require_metadata(require_metadata)
             .index_strategy(index_strategy)
             .torch_backend(torch_backend)
             .build_options(build_options.clone())
             .build();
 
         // Resolve the requirements.
-        let (resolution, hasher) = match operations::resolve(
+        let (resolution, hasher, excluded) = match operations::resolve(
             requirements,
             constraints,
             overrides,
@@ -605,7 +649,14 @@ pub(crate) async fn pip_install(
         )
         .await
         {
-            Ok((graph, hasher)) => (Resolution::from(graph), hasher),
+            Ok((graph, hasher)) => {
+                let excluded = operations::ExcludedRequirement::from_resolver_output(
+                    &graph,
+                    exclude_package,
+                    &marker_env,
+                );
+                (Resolution::from(graph), hasher, excluded)
+            }
             Err(err) => {
                 return diagnostics::OperationDiagnostic::default()
                     .report(err)
@@ -613,13 +664,47 @@ pub(crate) async fn pip_install(
             }
         };
 
-        (resolution, hasher)
+        (resolution, hasher, excluded)
     };
 
+    // Explain the artifact selection for any requested packages.
+    for name in &explain {
+        let Some(dist) = resolution.distributions().find(|dist| dist.name() == name) else {
+            warn_user!("`{name}` is not part of the resolution; nothing to explain");
+            continue;
+        };
+        match dist {
+            ResolvedDist::Installed { dist } => {
+                writeln!(
+                    printer.stderr(),
+                    "{}",
+                    format!("`{name}=={}` is already installed", dist.version()).dimmed()
+                )?;
+            }
+            ResolvedDist::Installable { .. } => {
+                let candidates = dist.version().and_then(|version| {
+                    state
+                        .index()
+                        .explain_artifacts(name, version, dist.index())
+                        .map(|candidates| (version, candidates))
+                });
+                if let Some((version, candidates)) = candidates {
+                    report_artifact_selection(name, Some(version), &tags, &candidates, printer)?;
+                } else {
+                    writeln!(
+                        printer.stderr(),
+                        "{}",
+                        format!("`{name}` was not selected from a package index ({dist})").dimmed()
+                    )?;
+                }
+            }
+        }
+    }
+
     // If necessary, convert editable distributions to non-editable.
     let resolution = apply_editable_mode(resolution, editable);
 
-    let site_packages = match site_packages {
+    let mut site_packages = match site_packages {
         // Only resolved packages can be modified when using sufficient installation semantics.
         None => SitePackages::from_environment_for_packages(
             &environment,
@@ -628,6 +713,15 @@ pub(crate) async fn pip_install(
         Some(site_packages) => site_packages,
     };
 
+    // Omit any excluded packages, which the environment is assumed to provide.
+    let resolution = operations::exclude_packages(
+        resolution,
+        exclude_package,
+        exclude_unchecked,
+        &excluded,
+        &mut site_packages,
+    )?;
+
     // Constrain any build requirements marked as `match-runtime = true`.
     let extra_build_requires = extra_build_requires.match_runtime(&resolution)?;
 
@@ -658,6 +752,12 @@ pub(crate) async fn pip_install(
         preview,
     );
 
+    let logger: Box<dyn InstallLogger> = if dry_run.enabled() {
+        Box::new(DryRunInstallLogger::new(format, &environment))
+    } else {
+        Box::new(DefaultInstallLogger)
+    };
+
     // Sync the environment.
     match operations::install(
         &resolution,
@@ -676,7 +776,7 @@ pub(crate) async fn pip_install(
         &build_dispatch,
         &cache,
         &environment,
-        Box::new(DefaultInstallLogger),
+        logger,
         installer_metadata,
         dry_run,
         printer,
@@ -684,7 +784,11 @@ pub(crate) async fn pip_install(
     )
     .await
     {
-        Ok(..) => {}
+        Ok(changelog) => {
+            if scripts_path_notice && !dry_run.enabled() {
+                operations::report_scripts_not_on_path(&changelog, &environment, false, printer)?;
+            }
+        }
         Err(err) => {
             return diagnostics::OperationDiagnostic::default()
                 .report(err)
@@ -5,12 +5,18 @@ use std::fmt::Write;
 use itertools::Itertools;
 use owo_colors::OwoColorize;
 use rustc_hash::{FxBuildHasher, FxHashMap};
+use serde::Serialize;
 
+use uv_cli::DryRunFormat;
 use uv_configuration::DryRun;
-use uv_distribution_types::Name;
+use uv_distribution_types::{InstalledVersion, Name};
+use uv_fs::PortablePathBuf;
 use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_python::PythonEnvironment;
+use uv_resolver::PythonReport;
 
-use crate::commands::pip::operations::{Changelog, ShortSpecifier};
+use crate::commands::pip::operations::{ChangedDist, Changelog, LongSpecifier, ShortSpecifier};
 use crate::commands::{ChangeEvent, ChangeEventKind, elapsed};
 use crate::printer::Printer;
 
@@ -55,6 +61,18 @@ pub(crate) trait InstallLogger {
 
     /// Log the completion of the operation.
     fn on_complete(&self, changelog: &Changelog, printer: Printer, dry_run: DryRun) -> fmt::Result;
+
+    /// Log the planned changes for a dry run, along with the number of resolved packages that
+    /// would be left unchanged.
+    fn on_dry_run(
+        &self,
+        changelog: &Changelog,
+        _unchanged: usize,
+        printer: Printer,
+        dry_run: DryRun,
+    ) -> fmt::Result {
+        self.on_complete(changelog, printer, dry_run)
+    }
 }
 
 /// The default logger for install operations.
@@ -468,6 +486,347 @@ impl InstallLogger for UpgradeInstallLogger {
     }
 }
 
+/// A logger that renders the plan for a dry run as a diff against the target environment,
+/// collapsing upgrades and downgrades into a single version transition.
+#[derive(Debug)]
+pub(crate) struct DryRunInstallLogger {
+    format: DryRunFormat,
+    environment: EnvironmentReport,
+}
+
+impl DryRunInstallLogger {
+    /// Create a new logger for a dry run against the given environment.
+    pub(crate) fn new(format: DryRunFormat, environment: &PythonEnvironment) -> Self {
+        Self {
+            format,
+            environment: EnvironmentReport::from(environment),
+        }
+    }
+
+    /// Write the plan as JSON to stdout.
+    fn write_json(
+        &self,
+        changes: &[PlannedChange],
+        unchanged: usize,
+        printer: Printer,
+    ) -> fmt::Result {
+        let report = DryRunReport {
+            schema: SchemaReport::default(),
+            environment: &self.environment,
+            changes: changes.iter().map(PlannedChangeReport::from).collect(),
+            unchanged,
+        };
+        let output = serde_json::to_string_pretty(&report).map_err(|_| fmt::Error)?;
+        writeln!(printer.stdout_important(), "{output}")
+    }
+}
+
+impl InstallLogger for DryRunInstallLogger {
+    fn on_check(
+        &self,
+        count: usize,
+        start: std::time::Instant,
+        printer: Printer,
+        dry_run: DryRun,
+    ) -> fmt::Result {
+        DefaultInstallLogger.on_check(count, start, printer, dry_run)?;
+        if matches!(self.format, DryRunFormat::Json) {
+            self.write_json(&[], count, printer)?;
+        }
+        Ok(())
+    }
+
+    fn on_prepare(
+        &self,
+        count: usize,
+        suffix: Option<&str>,
+        start: std::time::Instant,
+        printer: Printer,
+        dry_run: DryRun,
+    ) -> fmt::Result {
+        DefaultInstallLogger.on_prepare(count, suffix, start, printer, dry_run)
+    }
+
+    fn on_uninstall(
+        &self,
+        count: usize,
+        start: std::time::Instant,
+        printer: Printer,
+        dry_run: DryRun,
+    ) -> fmt::Result {
+        DefaultInstallLogger.on_uninstall(count, start, printer, dry_run)
+    }
+
+    fn on_install(
+        &self,
+        count: usize,
+        start: std::time::Instant,
+        printer: Printer,
+        dry_run: DryRun,
+    ) -> fmt::Result {
+        DefaultInstallLogger.on_install(count, start, printer, dry_run)
+    }
+
+    fn on_complete(&self, changelog: &Changelog, printer: Printer, dry_run: DryRun) -> fmt::Result {
+        DefaultInstallLogger.on_complete(changelog, printer, dry_run)
+    }
+
+    fn on_dry_run(
+        &self,
+        changelog: &Changelog,
+        unchanged: usize,
+        printer: Printer,
+        _dry_run: DryRun,
+    ) -> fmt::Result {
+        let changes = PlannedChange::from_changelog(changelog);
+
+        if matches!(self.format, DryRunFormat::Json) {
+            return self.write_json(&changes, unchanged, printer);
+        }
+
+        for change in &changes {
+            match change {
+                PlannedChange::Install(dist) => {
+                    writeln!(
+                        printer.stderr(),
+                        " {} {}{}",
+                        "+".green(),
+                        dist.name().bold(),
+                        dist.long_specifier().dimmed()
+                    )?;
+                }
+                PlannedChange::Uninstall(dist) => {
+                    writeln!(
+                        printer.stderr(),
+                        " {} {}{}",
+                        "-".red(),
+                        dist.name().bold(),
+                        dist.long_specifier().dimmed()
+                    )?;
+                }
+                PlannedChange::Reinstall(dist) => {
+                    writeln!(
+                        printer.stderr(),
+                        " {} {}{}",
+                        "~".yellow(),
+                        dist.name().bold(),
+                        dist.long_specifier().dimmed()
+                    )?;
+                }
+                PlannedChange::Upgrade { name, from, to }
+                | PlannedChange::Downgrade { name, from, to } => {
+                    writeln!(
+                        printer.stderr(),
+                        " {} {} {} -> {}",
+                        "~".yellow(),
+                        name.bold(),
+                        from.dimmed(),
+                        to
+                    )?;
+                }
+            }
+        }
+
+        if unchanged > 0 {
+            let s = if unchanged == 1 { "" } else { "s" };
+            writeln!(
+                printer.stderr(),
+                "{}",
+                format!(
+                    "Would leave {} unchanged",
+                    format!("{unchanged} package{s}").bold()
+                )
+                .dimmed()
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A single change in the plan for a dry run.
+#[derive(Debug)]
+enum PlannedChange<'a> {
+    Install(&'a ChangedDist),
+    Uninstall(&'a ChangedDist),
+    Reinstall(&'a ChangedDist),
+    Upgrade {
+        name: &'a PackageName,
+        from: &'a Version,
+        to: &'a Version,
+    },
+    Downgrade {
+        name: &'a PackageName,
+        from: &'a Version,
+        to: &'a Version,
+    },
+}
+
+impl<'a> PlannedChange<'a> {
+    /// Group the [`Changelog`] by package, collapsing a single removal and installation of
+    /// registry versions into an upgrade or downgrade.
+    fn from_changelog(changelog: &'a Changelog) -> Vec<Self> {
+        let mut removals: FxHashMap<&PackageName, Vec<&ChangedDist>> = FxHashMap::default();
+        for dist in &changelog.uninstalled {
+            removals.entry(dist.name()).or_default().push(dist);
+        }
+        let mut additions: FxHashMap<&PackageName, Vec<&ChangedDist>> = FxHashMap::default();
+        for dist in &changelog.installed {
+            additions.entry(dist.name()).or_default().push(dist);
+        }
+
+        let mut changes = Vec::new();
+        for name in removals.keys().chain(additions.keys()).copied().unique() {
+            let removed = removals.get(name).map(Vec::as_slice).unwrap_or_default();
+            let added = additions.get(name).map(Vec::as_slice).unwrap_or_default();
+            if let ([from], [to]) = (removed, added)
+                && let Some(from) = registry_version(from)
+                && let Some(to) = registry_version(to)
+                && from != to
+            {
+                if from < to {
+                    changes.push(Self::Upgrade { name, from, to });
+                } else {
+                    changes.push(Self::Downgrade { name, from, to });
+                }
+                continue;
+            }
+            changes.extend(removed.iter().copied().map(Self::Uninstall));
+            changes.extend(added.iter().copied().map(Self::Install));
+        }
+        changes.extend(changelog.reinstalled.iter().map(Self::Reinstall));
+
+        changes.sort_unstable_by(|a, b| {
+            a.name()
+                .cmp(b.name())
+                .then_with(|| a.kind().cmp(&b.kind()))
+                .then_with(|| a.version().cmp(&b.version()))
+        });
+        changes
+    }
+
+    fn name(&self) -> &'a PackageName {
+        match self {
+            Self::Install(dist) | Self::Uninstall(dist) | Self::Reinstall(dist) => dist.name(),
+            Self::Upgrade { name, .. } | Self::Downgrade { name, .. } => name,
+        }
+    }
+
+    fn kind(&self) -> PlannedAction {
+        match self {
+            Self::Uninstall(_) => PlannedAction::Uninstall,
+            Self::Install(_) => PlannedAction::Install,
+            Self::Reinstall(_) => PlannedAction::Reinstall,
+            Self::Upgrade { .. } => PlannedAction::Upgrade,
+            Self::Downgrade { .. } => PlannedAction::Downgrade,
+        }
+    }
+
+    fn version(&self) -> Option<&'a Version> {
+        match self {
+            Self::Install(dist) | Self::Uninstall(dist) | Self::Reinstall(dist) => dist.version(),
+            Self::Upgrade { to, .. } | Self::Downgrade { to, .. } => Some(to),
+        }
+    }
+}
+
+/// Returns the version of a distribution, if it was (or would be) installed from a registry.
+fn registry_version(dist: &ChangedDist) -> Option<&Version> {
+    match dist.long_specifier() {
+        LongSpecifier::InstalledVersion(InstalledVersion::Version(version)) => Some(version),
+        LongSpecifier::InstalledVersion(InstalledVersion::Url(..)) | LongSpecifier::Url(_) => None,
+    }
+}
+
+#[derive(Serialize, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+enum SchemaVersion {
+    /// An unstable, experimental schema.
+    #[default]
+    Preview,
+}
+
+#[derive(Serialize, Debug, Default)]
+struct SchemaReport {
+    /// The version of the schema.
+    version: SchemaVersion,
+}
+
+/// The environment against which the dry run was planned.
+#[derive(Serialize, Debug)]
+struct EnvironmentReport {
+    /// The path to the environment.
+    path: PortablePathBuf,
+    /// The Python interpreter for the environment.
+    python: PythonReport,
+}
+
+impl From<&PythonEnvironment> for EnvironmentReport {
+    fn from(environment: &PythonEnvironment) -> Self {
+        Self {
+            path: environment.root().into(),
+            python: PythonReport::from(environment.interpreter()),
+        }
+    }
+}
+
+/// The report for a dry run of `uv pip install` or `uv pip sync`.
+#[derive(Serialize, Debug)]
+struct DryRunReport<'a> {
+    /// The schema of this report.
+    schema: SchemaReport,
+    /// The target environment.
+    environment: &'a EnvironmentReport,
+    /// The changes that would be made to the environment.
+    changes: Vec<PlannedChangeReport<'a>>,
+    /// The number of resolved packages that are already installed and would be left as-is.
+    unchanged: usize,
+}
+
+/// A single planned change to the environment.
+#[derive(Serialize, Debug)]
+struct PlannedChangeReport<'a> {
+    /// The normalized package name.
+    name: &'a PackageName,
+    /// The action that would be taken for the package.
+    action: PlannedAction,
+    /// The currently installed version, for upgrades, downgrades, and uninstalls.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    from: Option<&'a Version>,
+    /// The version that would be installed, for installs, upgrades, downgrades, and reinstalls.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    to: Option<&'a Version>,
+}
+
+impl<'a> From<&PlannedChange<'a>> for PlannedChangeReport<'a> {
+    fn from(change: &PlannedChange<'a>) -> Self {
+        let (from, to) = match change {
+            PlannedChange::Install(dist) | PlannedChange::Reinstall(dist) => (None, dist.version()),
+            PlannedChange::Uninstall(dist) => (dist.version(), None),
+            PlannedChange::Upgrade { from, to, .. } | PlannedChange::Downgrade { from, to, .. } => {
+                (Some(*from), Some(*to))
+            }
+        };
+        Self {
+            name: change.name(),
+            action: change.kind(),
+            from,
+            to,
+        }
+    }
+}
+
+/// The action taken on an individual package during a dry run.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+enum PlannedAction {
+    Uninstall,
+    Install,
+    Upgrade,
+    Downgrade,
+    Reinstall,
+}
+
 /// A trait to handle logging during resolve operations.
 pub(crate) trait ResolveLogger {
     /// Log the completion of the operation.
@@ -7,6 +7,7 @@ use uv_python::{Interpreter, PythonVersion};
 
 pub(crate) mod check;
 pub(crate) mod compile;
+pub(crate) mod explain;
 pub(crate) mod freeze;
 pub(crate) mod install;
 pub(crate) mod latest;
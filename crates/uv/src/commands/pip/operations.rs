@@ -9,6 +9,7 @@ use std::time::{Duration, Instant};
 use anyhow::{Context, anyhow};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use petgraph::Direction;
 use tracing::debug;
 
 use uv_cache::Cache;
@@ -25,12 +26,12 @@ use uv_distribution_types::{
     NameRequirementSpecification, PackageConfigSettings, Requirement, ResolutionDiagnostic,
     UnresolvedRequirement, UnresolvedRequirementSpecification, VersionOrUrlRef,
 };
-use uv_distribution_types::{DistributionMetadata, InstalledMetadata, Name, Resolution};
+use uv_distribution_types::{DistributionMetadata, InstalledMetadata, Name, Node, Resolution};
 use uv_fs::{CWD, Simplified, normalize_path_under};
 use uv_install_wheel::{LinkMode, installed_dist_info_path, read_record_into_iter};
 use uv_installer::{InstallationStrategy, Plan, Planner, Preparer, SitePackages};
 use uv_normalize::PackageName;
-use uv_pep440::Version;
+use uv_pep440::{Version, VersionSpecifier, VersionSpecifiers};
 use uv_pep508::{MarkerEnvironment, RequirementOrigin, VerbatimUrl};
 use uv_platform_tags::Tags;
 use uv_preview::Preview;
@@ -45,12 +46,15 @@ use uv_resolver::{
     DependencyMode, Exclusions, FlatIndex, InMemoryIndex, Manifest, Options, Preference,
     Preferences, PythonRequirement, Resolver, ResolverEnvironment, ResolverOutput, UpgradePackages,
 };
+use uv_shell::Shell;
+use uv_static::EnvVars;
 use uv_tool::InstalledTools;
 use uv_types::{BuildContext, HashStrategy, InFlight, InstalledPackagesProvider};
 use uv_warnings::warn_user;
 
 use crate::commands::pip::loggers::{InstallLogger, ResolveLogger};
 use crate::commands::reporters::{InstallReporter, PrepareReporter, ResolverReporter};
+use crate::commands::venv::activation_command;
 use crate::commands::{compile_bytecode, compile_bytecode_files};
 use crate::printer::Printer;
 
@@ -63,6 +67,7 @@ pub(crate) async fn read_requirements(
     extras: &ExtrasSpecification,
     groups: Option<&GroupsSpecification>,
     client_builder: &BaseClientBuilder<'_>,
+    cache: &Cache,
 ) -> Result<RequirementsSpecification, Error> {
     // If the user requests `extras` but does not provide a valid source (e.g., a `pyproject.toml`),
     // return an error.
@@ -81,6 +86,7 @@ pub(crate) async fn read_requirements(
         excludes,
         groups,
         client_builder,
+        cache,
     )
     .await?)
 }
@@ -89,12 +95,19 @@ pub(crate) async fn read_requirements(
 pub(crate) async fn read_constraints(
     constraints: &[RequirementsSource],
     client_builder: &BaseClientBuilder<'_>,
+    cache: &Cache,
 ) -> Result<Vec<NameRequirementSpecification>, Error> {
-    Ok(
-        RequirementsSpecification::from_sources(&[], constraints, &[], &[], None, client_builder)
-            .await?
-            .constraints,
+    Ok(RequirementsSpecification::from_sources(
+        &[],
+        constraints,
+        &[],
+        &[],
+        None,
+        client_builder,
+        cache,
     )
+    .await?
+    .constraints)
 }
 
 /// Resolve a set of requirements, similar to running `pip compile`.
@@ -665,6 +678,186 @@ impl InstallationPlan {
     }
 }
 
+/// A requirement on a package omitted from the installation via `--exclude-package`.
+#[derive(Debug, Clone)]
+pub(crate) struct ExcludedRequirement {
+    /// The excluded package.
+    name: PackageName,
+    /// The package that requires it, or `None` for a direct requirement.
+    dependent: Option<PackageName>,
+    /// The acceptable versions, or `None` if any version is acceptable (e.g., for URLs).
+    specifiers: Option<VersionSpecifiers>,
+}
+
+impl ExcludedRequirement {
+    /// Collect the requirements on the excluded packages from a resolution.
+    pub(crate) fn from_resolver_output(
+        output: &ResolverOutput,
+        excludes: &[PackageName],
+        marker_env: &MarkerEnvironment,
+    ) -> Vec<Self> {
+        excludes
+            .iter()
+            .flat_map(|name| {
+                output.requirements_on(name, marker_env).into_iter().map(
+                    |(dependent, requirement)| Self {
+                        name: name.clone(),
+                        dependent: dependent.cloned(),
+                        specifiers: requirement.source.version_specifiers().cloned(),
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// Collect the requirements on the excluded packages from a locked resolution.
+    ///
+    /// Locked resolutions don't retain the requirements of every dependent, so any dependent whose
+    /// requirements aren't provided by `requires_dist` is assumed to require the locked version.
+    pub(crate) fn from_locked<'a>(
+        resolution: &Resolution,
+        excludes: &[PackageName],
+        marker_env: &MarkerEnvironment,
+        requires_dist: impl Fn(&PackageName) -> Option<&'a BTreeSet<Requirement>>,
+    ) -> Vec<Self> {
+        let graph = resolution.graph();
+        let mut requirements = Vec::new();
+        for index in graph.node_indices() {
+            let Node::Dist { dist, .. } = &graph[index] else {
+                continue;
+            };
+            if !excludes.contains(dist.name()) {
+                continue;
+            }
+            let locked = dist.version().map(|version| {
+                VersionSpecifiers::from(VersionSpecifier::equals_version(version.clone()))
+            });
+            for dependent in graph.neighbors_directed(index, Direction::Incoming) {
+                let dependent = match &graph[dependent] {
+                    Node::Root => None,
+                    Node::Dist { dist, .. } => Some(dist.name()),
+                };
+                let declared = dependent
+                    .and_then(&requires_dist)
+                    .into_iter()
+                    .flatten()
+                    .filter(|requirement| {
+                        requirement.name == *dist.name()
+                            && requirement.evaluate_markers(Some(marker_env), &[])
+                    })
+                    .map(|requirement| requirement.source.version_specifiers().cloned())
+                    .collect::<Vec<_>>();
+                if declared.is_empty() {
+                    requirements.push(Self {
+                        name: dist.name().clone(),
+                        dependent: dependent.cloned(),
+                        specifiers: locked.clone(),
+                    });
+                } else {
+                    requirements.extend(declared.into_iter().map(|specifiers| Self {
+                        name: dist.name().clone(),
+                        dependent: dependent.cloned(),
+                        specifiers,
+                    }));
+                }
+            }
+        }
+        requirements
+    }
+}
+
+/// Omit the given packages from a resolution, on the assumption that the environment provides
+/// them (`--exclude-package`).
+///
+/// Unless `unchecked` is set, the environment must already contain a version of each excluded
+/// package that satisfies the requirements of its dependents. The excluded packages are also
+/// removed from `site_packages`, such that they're left untouched by the installation.
+pub(crate) fn exclude_packages(
+    resolution: Resolution,
+    excludes: &[PackageName],
+    unchecked: bool,
+    requirements: &[ExcludedRequirement],
+    site_packages: &mut SitePackages,
+) -> Result<Resolution, Error> {
+    if excludes.is_empty() {
+        return Ok(resolution);
+    }
+
+    for name in excludes {
+        if resolution.distributions().any(|dist| dist.name() == name) {
+            check_excluded_package(name, unchecked, requirements, site_packages)?;
+        } else {
+            debug!("Excluded package `{name}` is not part of the resolution");
+        }
+
+        for dist in site_packages.remove_packages(name) {
+            debug!("Leaving excluded package in place: {dist}");
+        }
+    }
+
+    Ok(resolution.filter(|dist| !excludes.contains(dist.name())))
+}
+
+/// Warn that the given package was excluded, and verify that the environment provides a version
+/// that satisfies its dependents, unless `unchecked` is set.
+fn check_excluded_package(
+    name: &PackageName,
+    unchecked: bool,
+    requirements: &[ExcludedRequirement],
+    site_packages: &SitePackages,
+) -> Result<(), Error> {
+    let requirements = requirements
+        .iter()
+        .filter(|requirement| requirement.name == *name)
+        .collect::<Vec<_>>();
+
+    let dependents = requirements
+        .iter()
+        .filter_map(|requirement| requirement.dependent.as_ref())
+        .unique()
+        .map(|dependent| format!("`{}`", dependent.cyan()))
+        .collect::<Vec<_>>();
+    if !dependents.is_empty() {
+        warn_user!(
+            "Excluding `{}` from the installation; {} {} it to be provided by the environment",
+            name.cyan(),
+            dependents.join(", "),
+            if dependents.len() == 1 {
+                "expects"
+            } else {
+                "expect"
+            },
+        );
+    }
+
+    if unchecked {
+        return Ok(());
+    }
+
+    let installed = site_packages.get_packages(name);
+    if installed.iter().any(|dist| {
+        requirements.iter().all(|requirement| {
+            requirement
+                .specifiers
+                .as_ref()
+                .is_none_or(|specifiers| specifiers.contains(dist.version()))
+        })
+    }) {
+        return Ok(());
+    }
+
+    Err(Error::ExcludedPackageUnavailable {
+        name: name.clone(),
+        specifiers: requirements
+            .iter()
+            .filter_map(|requirement| requirement.specifiers.as_ref())
+            .flat_map(|specifiers| specifiers.iter().cloned())
+            .unique()
+            .collect(),
+        installed: installed.first().map(|dist| dist.version().clone()),
+    })
+}
+
 /// Install a set of requirements into the current environment.
 ///
 /// Returns a [`Changelog`] summarizing the changes made to the environment.
@@ -1251,6 +1444,95 @@ pub(crate) fn report_target_environment(
     Ok(writeln!(printer.stderr(), "{}", message.dimmed())?)
 }
 
+/// The marker file written to a virtual environment once the user has been notified that its
+/// scripts directory is not on the `PATH`.
+const SCRIPTS_PATH_NOTICE_MARKER: &str = ".uv-scripts-path-notice";
+
+/// Notify the user if entry point scripts were installed into a virtual environment whose scripts
+/// directory is neither on the `PATH` nor the active virtual environment.
+///
+/// The notice is shown at most once per environment. If `uv_run` is `true`, `uv run` is suggested
+/// as an alternative to activating the environment.
+pub(crate) fn report_scripts_not_on_path(
+    changelog: &Changelog,
+    venv: &PythonEnvironment,
+    uv_run: bool,
+    printer: Printer,
+) -> Result<(), Error> {
+    if uv_flags::contains(uv_flags::EnvironmentFlags::NO_SCRIPTS_PATH_NOTICE) {
+        return Ok(());
+    }
+    if changelog.installed.is_empty() && changelog.reinstalled.is_empty() {
+        return Ok(());
+    }
+    if !venv.interpreter().is_virtualenv() {
+        return Ok(());
+    }
+
+    let marker = venv.root().join(SCRIPTS_PATH_NOTICE_MARKER);
+    if marker.exists() {
+        return Ok(());
+    }
+
+    let scripts = venv.scripts();
+    if Shell::contains_path(scripts) {
+        return Ok(());
+    }
+    if let Some(active) = std::env::var_os(EnvVars::VIRTUAL_ENV).filter(|value| !value.is_empty())
+        && uv_fs::is_same_file_allow_missing(&CWD.join(active), venv.root()) == Some(true)
+    {
+        return Ok(());
+    }
+
+    // Determine the entry point scripts that were installed.
+    let site_packages = match SitePackages::from_environment(venv) {
+        Ok(site_packages) => site_packages,
+        Err(err) => {
+            debug!("Failed to read installed packages: {err}");
+            return Ok(());
+        }
+    };
+    let mut entrypoints = BTreeSet::new();
+    for dist in changelog.installed.iter().chain(&changelog.reinstalled) {
+        for installed in site_packages.get_packages(dist.name()) {
+            match uv_tool::entrypoint_paths(&site_packages, installed.name(), installed.version()) {
+                Ok(paths) => entrypoints.extend(paths.into_iter().map(|(name, _)| name)),
+                Err(err) => debug!("Failed to read entry points for `{installed}`: {err}"),
+            }
+        }
+    }
+    let Some(entrypoint) = entrypoints.first() else {
+        return Ok(());
+    };
+
+    let alternative = if uv_run {
+        let command = format!("uv run {entrypoint}");
+        format!(" or use `{}`", command.green())
+    } else {
+        String::new()
+    };
+    if let Some(activation) = activation_command(scripts) {
+        writeln!(
+            printer.stderr(),
+            "hint: `{}` is not on your PATH. To use installed scripts, activate the environment with `{}`{alternative}.",
+            scripts.simplified_display().cyan(),
+            activation.green(),
+        )?;
+    } else {
+        writeln!(
+            printer.stderr(),
+            "hint: `{}` is not on your PATH. To use installed scripts, activate the environment or add the directory to your PATH{alternative}.",
+            scripts.simplified_display().cyan(),
+        )?;
+    }
+
+    if let Err(err) = fs_err::write(&marker, "") {
+        debug!("Failed to write `{}`: {err}", marker.user_display());
+    }
+
+    Ok(())
+}
+
 /// Report on the results of a dry-run installation.
 fn report_dry_run(
     dry_run: DryRun,
@@ -1314,7 +1596,12 @@ fn report_dry_run(
 
     let changelog = Changelog::new(installed, uninstalled);
 
-    logger.on_complete(&changelog, printer, dry_run)?;
+    logger.on_dry_run(
+        &changelog,
+        resolution.len().saturating_sub(installs),
+        printer,
+        dry_run,
+    )?;
 
     if matches!(dry_run, DryRun::Check) {
         return Err(Error::OutdatedEnvironment(Box::new(changelog)));
@@ -1397,6 +1684,21 @@ pub(crate) enum Error {
 
     #[error("The environment is outdated; run `{}` to update the environment", "uv sync".cyan())]
     OutdatedEnvironment(Box<Changelog>),
+
+    #[error(
+        "`{}` was excluded from the installation, but the environment doesn't provide {}",
+        name.cyan(),
+        if specifiers.is_empty() {
+            "it".to_string()
+        } else {
+            format!("a version that satisfies `{}`", format!("{name}{specifiers}").cyan())
+        }
+    )]
+    ExcludedPackageUnavailable {
+        name: PackageName,
+        specifiers: VersionSpecifiers,
+        installed: Option<Version>,
+    },
 }
 
 impl uv_errors::Hint for Error {
@@ -1411,6 +1713,19 @@ impl uv_errors::Hint for Error {
                 }
                 uv_errors::Hints::none()
             }
+            Self::ExcludedPackageUnavailable {
+                name, installed, ..
+            } => {
+                let mut hints = uv_errors::Hints::none();
+                if let Some(installed) = installed {
+                    hints.push(format!(
+                        "Found `{}` in the environment",
+                        format!("{name}=={installed}").cyan()
+                    ));
+                }
+                hints.push("Use `--exclude-unchecked` to skip this check".to_string());
+                hints
+            }
             _ => uv_errors::Hints::none(),
         }
     }
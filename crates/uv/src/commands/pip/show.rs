@@ -1,18 +1,22 @@
 use std::fmt::Write;
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
 use fs_err::File;
 use itertools::{Either, Itertools};
 use owo_colors::OwoColorize;
 use rustc_hash::FxHashMap;
+use serde::Serialize;
 use tracing::debug;
 
 use uv_cache::Cache;
+use uv_cli::ShowFormat;
 use uv_distribution_types::{DependencyMetadata, Diagnostic, Name};
 use uv_fs::Simplified;
 use uv_install_wheel::read_record;
 use uv_installer::SitePackages;
-use uv_normalize::PackageName;
+use uv_normalize::{ExtraName, PackageName};
+use uv_pep440::Version;
+use uv_pep508::MarkerEnvironment;
 use uv_python::{
     EnvironmentPreference, Prefix, PythonEnvironment, PythonPreference, PythonRequest, Target,
 };
@@ -31,6 +35,7 @@ pub(crate) fn pip_show(
     target: Option<Target>,
     prefix: Option<Prefix>,
     files: bool,
+    format: ShowFormat,
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
@@ -111,114 +116,107 @@ pub(crate) fn pip_show(
         return Ok(ExitStatus::Failure);
     }
 
-    // Since Requires and Required-by fields need data parsed from metadata, especially the
-    // Required-by field which needs to iterate over other installed packages' metadata.
-    // To prevent the need to parse metadata repeatedly when multiple packages need to be shown,
-    // we parse the metadata once and collect the needed data beforehand.
-    let mut requires_map = FxHashMap::default();
-    // For Requires field
-    for dist in &distributions {
-        if let Ok(metadata) = dist.read_metadata() {
-            requires_map.insert(
-                dist.name(),
-                metadata
-                    .requires_dist
+    // Since the Requires and Required-by fields need the requirements of every installed package,
+    // read the metadata once up front and share it across all packages to be shown.
+    let requires_map = installed_requirements(&site_packages, &markers);
+
+    let entries = distributions
+        .iter()
+        .map(|distribution| {
+            let requires = requires_map.get(distribution.name()).cloned();
+            let required_by = requires.as_ref().map(|_| {
+                requires_map
                     .iter()
-                    .filter(|req| req.evaluate_markers(&markers, &[]))
-                    .map(|req| &req.name)
+                    .filter(|(name, pkgs)| {
+                        **name != distribution.name() && pkgs.contains(&distribution.name())
+                    })
+                    .map(|(name, _)| *name)
                     .sorted_unstable()
                     .dedup()
-                    .collect_vec(),
-            );
-        }
-    }
-    // For Required-by field
-    if !requires_map.is_empty() {
-        for installed in site_packages.iter() {
-            if requires_map.contains_key(installed.name()) {
-                continue;
-            }
-            if let Ok(metadata) = installed.read_metadata() {
-                let requires = metadata
-                    .requires_dist
-                    .iter()
-                    .filter(|req| req.evaluate_markers(&markers, &[]))
-                    .map(|req| &req.name)
-                    .collect_vec();
-                if !requires.is_empty() {
-                    requires_map.insert(installed.name(), requires);
-                }
-            }
-        }
-    }
+                    .collect_vec()
+            });
 
-    // Print the information for each package.
-    for (i, distribution) in distributions.iter().enumerate() {
-        if i > 0 {
-            // Print a separator between packages.
-            writeln!(printer.stdout(), "---")?;
-        }
+            // If requested, read the list of installed files.
+            let files = if files {
+                let path = distribution.install_path().join("RECORD");
+                let record = read_record(&mut File::open(path)?)?;
+                Some(record.into_iter().map(|entry| entry.path).collect_vec())
+            } else {
+                None
+            };
 
-        // Print the name, version, and location (e.g., the `site-packages` directory).
-        writeln!(printer.stdout(), "Name: {}", distribution.name())?;
-        writeln!(printer.stdout(), "Version: {}", distribution.version())?;
-        writeln!(
-            printer.stdout(),
-            "Location: {}",
-            distribution
-                .install_path()
-                .parent()
-                .expect("package path is not root")
-                .simplified_display()
-        )?;
+            let location = distribution.install_path().parent().ok_or_else(|| {
+                anyhow!(
+                    "Installed package `{}` has no parent directory: `{}`",
+                    distribution.name(),
+                    distribution.install_path().simplified_display()
+                )
+            })?;
 
-        if let Some(path) = distribution
-            .as_editable()
-            .and_then(|url| url.to_file_path().ok())
-        {
-            writeln!(
-                printer.stdout(),
-                "Editable project location: {}",
-                path.simplified_display()
-            )?;
+            Ok(Entry {
+                name: distribution.name(),
+                version: distribution.version(),
+                location: location.simplified_display().to_string(),
+                editable_project_location: distribution
+                    .as_editable()
+                    .and_then(|url| url.to_file_path().ok())
+                    .map(|path| path.simplified_display().to_string()),
+                requires,
+                required_by,
+                files,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    match format {
+        ShowFormat::Json => {
+            let output = serde_json::to_string(&entries)?;
+            writeln!(printer.stdout_important(), "{output}")?;
         }
+        ShowFormat::Text => {
+            // Print the information for each package.
+            for (i, entry) in entries.iter().enumerate() {
+                if i > 0 {
+                    // Print a separator between packages.
+                    writeln!(printer.stdout(), "---")?;
+                }
 
-        // If available, print the requirements.
-        if let Some(requires) = requires_map.get(distribution.name()) {
-            if requires.is_empty() {
-                writeln!(printer.stdout(), "Requires:")?;
-            } else {
-                writeln!(printer.stdout(), "Requires: {}", requires.iter().join(", "))?;
-            }
+                // Print the name, version, and location (e.g., the `site-packages` directory).
+                writeln!(printer.stdout(), "Name: {}", entry.name)?;
+                writeln!(printer.stdout(), "Version: {}", entry.version)?;
+                writeln!(printer.stdout(), "Location: {}", entry.location)?;
 
-            let required_by = requires_map
-                .iter()
-                .filter(|(name, pkgs)| {
-                    **name != distribution.name()
-                        && pkgs.iter().any(|pkg| *pkg == distribution.name())
-                })
-                .map(|(name, _)| name)
-                .sorted_unstable()
-                .dedup()
-                .collect_vec();
-            if required_by.is_empty() {
-                writeln!(printer.stdout(), "Required-by:")?;
-            } else {
-                writeln!(
-                    printer.stdout(),
-                    "Required-by: {}",
-                    required_by.into_iter().join(", "),
-                )?;
-            }
-        }
+                if let Some(path) = &entry.editable_project_location {
+                    writeln!(printer.stdout(), "Editable project location: {path}")?;
+                }
 
-        // If requests, show the list of installed files.
-        if files {
-            let path = distribution.install_path().join("RECORD");
-            let record = read_record(&mut File::open(path)?)?;
-            writeln!(printer.stdout(), "Files:")?;
-            for entry in record {
-                writeln!(printer.stdout(), "  {}", entry.path)?;
+                // If available, print the requirements.
+                if let Some(requires) = &entry.requires {
+                    if requires.is_empty() {
+                        writeln!(printer.stdout(), "Requires:")?;
+                    } else {
+                        writeln!(printer.stdout(), "Requires: {}", requires.iter().join(", "))?;
+                    }
+                }
+                if let Some(required_by) = &entry.required_by {
+                    if required_by.is_empty() {
+                        writeln!(printer.stdout(), "Required-by:")?;
+                    } else {
+                        writeln!(
+                            printer.stdout(),
+                            "Required-by: {}",
+                            required_by.iter().join(", "),
+                        )?;
+                    }
+                }
+
+                // If requested, show the list of installed files.
+                if let Some(files) = &entry.files {
+                    writeln!(printer.stdout(), "Files:")?;
+                    for path in files {
+                        writeln!(printer.stdout(), "  {path}")?;
+                    }
+                }
             }
         }
     }
@@ -238,3 +236,87 @@ pub(crate) fn pip_show(
 
     Ok(ExitStatus::Success)
 }
+
+/// Return the installed requirements of each package in the environment.
+///
+/// Markers are evaluated against the environment, with the extras that are activated by the
+/// requirements of the other installed packages. For example, if `requests[socks]` is required by
+/// an installed package, the `socks` extra of `requests` is considered when evaluating the
+/// requirements of `requests`.
+fn installed_requirements<'a>(
+    site_packages: &'a SitePackages,
+    markers: &MarkerEnvironment,
+) -> FxHashMap<&'a PackageName, Vec<&'a PackageName>> {
+    // Read the metadata for every installed package. The metadata is cached on the distribution,
+    // so it's only parsed once, regardless of the number of packages to be shown.
+    let metadata = site_packages
+        .iter()
+        .filter_map(|dist| {
+            dist.read_metadata()
+                .ok()
+                .map(|metadata| (dist.name(), &metadata.requires_dist))
+        })
+        .collect_vec();
+
+    // Determine the activated extras for each package, until we reach a fixed point.
+    let mut extras: FxHashMap<&PackageName, Vec<ExtraName>> = FxHashMap::default();
+    loop {
+        let mut activated = Vec::new();
+        for (name, requires_dist) in &metadata {
+            let active = extras.get(name).map(Vec::as_slice).unwrap_or_default();
+            for requirement in *requires_dist {
+                if !requirement.evaluate_markers(markers, active) {
+                    continue;
+                }
+                for extra in &requirement.extras {
+                    if !extras
+                        .get(&requirement.name)
+                        .is_some_and(|extras| extras.contains(extra))
+                    {
+                        activated.push((&requirement.name, extra.clone()));
+                    }
+                }
+            }
+        }
+        if activated.is_empty() {
+            break;
+        }
+        for (name, extra) in activated {
+            let extras = extras.entry(name).or_default();
+            if !extras.contains(&extra) {
+                extras.push(extra);
+            }
+        }
+    }
+
+    metadata
+        .into_iter()
+        .map(|(name, requires_dist)| {
+            let active = extras.get(name).map(Vec::as_slice).unwrap_or_default();
+            let requires = requires_dist
+                .iter()
+                .filter(|requirement| requirement.evaluate_markers(markers, active))
+                .map(|requirement| &requirement.name)
+                .sorted_unstable()
+                .dedup()
+                .collect_vec();
+            (name, requires)
+        })
+        .collect()
+}
+
+/// An entry in a JSON list of installed packages.
+#[derive(Debug, Serialize)]
+struct Entry<'a> {
+    name: &'a PackageName,
+    version: &'a Version,
+    location: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    editable_project_location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    requires: Option<Vec<&'a PackageName>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    required_by: Option<Vec<&'a PackageName>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    files: Option<Vec<String>>,
+}
@@ -6,6 +6,7 @@ use owo_colors::OwoColorize;
 use tracing::{debug, warn};
 
 use uv_cache::Cache;
+use uv_cli::DryRunFormat;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     BuildIsolation, BuildOptions, Concurrency, Constraints, DryRun, ExtrasSpecification,
@@ -21,10 +22,10 @@ use uv_distribution_types::{
 use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
 use uv_installer::{InstallationStrategy, SitePackages};
-use uv_normalize::{DefaultExtras, DefaultGroups};
+use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
 use uv_pep440::Version;
 use uv_preview::{Preview, PreviewFeature};
-use uv_pypi_types::Conflicts;
+use uv_pypi_types::{Conflicts, MetadataVersion};
 use uv_python::{
     EnvironmentPreference, Prefix, PythonDownloads, PythonEnvironment, PythonInstallation,
     PythonPreference, PythonRequest, PythonVersion, Target,
@@ -41,7 +42,9 @@ use uv_warnings::warn_user;
 use uv_workspace::WorkspaceCache;
 use uv_workspace::pyproject::ExtraBuildDependencies;
 
-use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
+use crate::commands::pip::loggers::{
+    DefaultInstallLogger, DefaultResolveLogger, DryRunInstallLogger, InstallLogger,
+};
 use crate::commands::pip::operations::Modifications;
 use crate::commands::pip::operations::{report_interpreter, report_target_environment};
 use crate::commands::pip::{operations, resolution_markers, resolution_tags};
@@ -56,6 +59,8 @@ pub(crate) async fn pip_sync(
     requirements: &[RequirementsSource],
     constraints: &[RequirementsSource],
     build_constraints: &[RequirementsSource],
+    exclude_package: &[PackageName],
+    exclude_unchecked: bool,
     extras: &ExtrasSpecification,
     groups: &GroupsSpecification,
     reinstall: Reinstall,
@@ -84,7 +89,9 @@ pub(crate) async fn pip_sync(
     install_mirrors: PythonInstallMirrors,
     strict: bool,
     exclude_newer: ExcludeNewer,
+    require_metadata: Option<MetadataVersion>,
     python: Option<String>,
+    environment: Option<PythonEnvironment>,
     system: bool,
     break_system_packages: bool,
     target: Option<Target>,
@@ -95,6 +102,7 @@ pub(crate) async fn pip_sync(
     cache: Cache,
     workspace_cache: WorkspaceCache,
     dry_run: DryRun,
+    format: DryRunFormat,
     printer: Printer,
     preview: Preview,
 ) -> Result<ExitStatus> {
@@ -126,6 +134,7 @@ pub(crate) async fn pip_sync(
         no_binary,
         no_build,
         extras: _,
+        remote_files: _,
     } = operations::read_requirements(
         requirements,
         constraints,
@@ -134,6 +143,7 @@ pub(crate) async fn pip_sync(
         extras,
         Some(groups),
         &client_builder,
+        &cache,
     )
     .await?;
 
@@ -146,9 +156,16 @@ pub(crate) async fn pip_sync(
         }
     }
 
+    if matches!(format, DryRunFormat::Json) && !preview.is_enabled(PreviewFeature::JsonOutput) {
+        warn_user!(
+            "The `--format json` option is experimental and the schema may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::JsonOutput
+        );
+    }
+
     // Read build constraints.
     let build_constraints =
-        operations::read_constraints(build_constraints, &client_builder).await?;
+        operations::read_constraints(build_constraints, &client_builder, &cache).await?;
 
     // Validate that the requirements are non-empty.
     if !allow_empty_requirements {
@@ -163,8 +180,11 @@ pub(crate) async fn pip_sync(
         }
     }
 
-    // Detect the current Python interpreter.
-    let environment = if target.is_some() || prefix.is_some() {
+    // Detect the current Python interpreter, unless an environment was provided by the caller
+    // (e.g., by `uv venv --from-lock`).
+    let environment = if let Some(environment) = environment {
+        environment
+    } else if target.is_some() || prefix.is_some() {
         let python_request = python.as_deref().map(PythonRequest::parse);
         let reporter = PythonDownloadReporter::single(printer);
 
@@ -405,9 +425,9 @@ pub(crate) async fn pip_sync(
     );
 
     // Determine the set of installed packages.
-    let site_packages = SitePackages::from_environment(&environment)?;
+    let mut site_packages = SitePackages::from_environment(&environment)?;
 
-    let (resolution, hasher) = if let Some(pylock) = pylock {
+    let (resolution, hasher, excluded) = if let Some(pylock) = pylock {
         let (install_path, lock) = read_pylock_toml(&pylock, &client_builder).await?;
 
         // Convert the extras and groups specifications into a concrete form.
@@ -427,7 +447,7 @@ pub(crate) async fn pip_sync(
             .cloned()
             .collect::<Vec<_>>();
 
-        resolve_pylock_toml(
+        let (resolution, hasher) = resolve_pylock_toml(
             lock,
             &install_path,
             interpreter,
@@ -437,7 +457,15 @@ pub(crate) async fn pip_sync(
             &groups,
             &build_options,
             hash_checking,
-        )?
+        )?;
+        let excluded = operations::ExcludedRequirement::from_locked(
+            &resolution,
+            exclude_package,
+            &marker_env,
+            |_| None,
+        );
+
+        (resolution, hasher, excluded)
     } else {
         // When resolving, don't take any external preferences into account.
         let preferences = Vec::default();
@@ -447,12 +475,13 @@ pub(crate) async fn pip_sync(
             .prerelease_mode(prerelease_mode)
             .dependency_mode(dependency_mode)
             .exclude_newer(exclude_newer.clone())
+            .require_metadata(require_metadata)
             .index_strategy(index_strategy)
             .torch_backend(torch_backend)
             .build_options(build_options.clone())
             .build();
 
-        let (resolution, hasher) = match operations::resolve(
+        let (resolution, hasher, excluded) = match operations::resolve(
             requirements,
             constraints,
             overrides,
@@ -484,7 +513,14 @@ pub(crate) async fn pip_sync(
         )
         .await
         {
-            Ok((resolution, hasher)) => (Resolution::from(resolution), hasher),
+            Ok((resolution, hasher)) => {
+                let excluded = operations::ExcludedRequirement::from_resolver_output(
+                    &resolution,
+                    exclude_package,
+                    &marker_env,
+                );
+                (Resolution::from(resolution), hasher, excluded)
+            }
             Err(err) => {
                 return diagnostics::OperationDiagnostic::default()
                     .report(err)
@@ -492,9 +528,18 @@ pub(crate) async fn pip_sync(
             }
         };
 
-        (resolution, hasher)
+        (resolution, hasher, excluded)
     };
 
+    // Omit any excluded packages, which the environment is assumed to provide.
+    let resolution = operations::exclude_packages(
+        resolution,
+        exclude_package,
+        exclude_unchecked,
+        &excluded,
+        &mut site_packages,
+    )?;
+
     // Constrain any build requirements marked as `match-runtime = true`.
     let extra_build_requires = extra_build_requires.match_runtime(&resolution)?;
 
@@ -525,6 +570,12 @@ pub(crate) async fn pip_sync(
         preview,
     );
 
+    let logger: Box<dyn InstallLogger> = if dry_run.enabled() {
+        Box::new(DryRunInstallLogger::new(format, &environment))
+    } else {
+        Box::new(DefaultInstallLogger)
+    };
+
     // Sync the environment.
     match operations::install(
         &resolution,
@@ -543,7 +594,7 @@ pub(crate) async fn pip_sync(
         &build_dispatch,
         &cache,
         &environment,
-        Box::new(DefaultInstallLogger),
+        logger,
         installer_metadata,
         dry_run,
         printer,
@@ -2,7 +2,7 @@ use std::collections::BTreeMap;
 use std::collections::hash_map::Entry;
 use std::fmt::Write;
 use std::io;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::Arc;
 
@@ -18,7 +18,7 @@ use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     Concurrency, Constraints, DependencyGroups, DependencyGroupsWithDefaults, DevMode, DryRun,
     EditableMode, ExtrasSpecification, ExtrasSpecificationWithDefaults, GitLfsSetting,
-    InstallOptions, NoSources,
+    InstallOptions, NoSources, ShowDiff,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution::{DistributionDatabase, LoweredExtraBuildDependencies};
@@ -31,13 +31,14 @@ use uv_git::store_credentials;
 use uv_normalize::{DEV_DEPENDENCIES, DefaultExtras, DefaultGroups, ExtraName, PackageName};
 use uv_pep508::{MarkerTree, VersionOrUrl};
 use uv_preview::Preview;
+use uv_pypi_types::HashDigest;
 use uv_python::{
     ConfigDiscovery, Interpreter, PythonDownloads, PythonEnvironment, PythonPreference,
     PythonRequest,
 };
 use uv_redacted::DisplaySafeUrl;
 use uv_requirements::{NamedRequirementsResolver, RequirementsSource, RequirementsSpecification};
-use uv_resolver::FlatIndex;
+use uv_resolver::{FlatIndex, Lock};
 use uv_scripts::{Pep723Metadata, Pep723Script};
 use uv_settings::{MalwareCheckSettings, PythonInstallMirrors};
 use uv_static::is_known_standard_library_package;
@@ -53,6 +54,7 @@ use crate::commands::pip::loggers::{
     DefaultInstallLogger, DefaultResolveLogger, SummaryResolveLogger,
 };
 use crate::commands::pip::operations::Modifications;
+use crate::commands::project::diff;
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::LockMode;
 use crate::commands::project::lock_target::LockTarget;
@@ -89,6 +91,7 @@ pub(crate) async fn add(
     dependency_type: DependencyType,
     raw: bool,
     bounds: Option<AddBoundsKind>,
+    show_diff: ShowDiff,
     indexes: Vec<Index>,
     rev: Option<String>,
     tag: Option<String>,
@@ -167,6 +170,25 @@ pub(crate) async fn add(
     // Default groups we need the actual project for, interpreter discovery will use this!
     let defaulted_groups;
 
+    // Read the requirements.
+    let RequirementsSpecification {
+        requirements,
+        constraints,
+        remote_files,
+        ..
+    } = RequirementsSpecification::from_sources(
+        &requirements,
+        &constraints,
+        &[],
+        &[],
+        None,
+        &client_builder
+            .clone()
+            .keyring(settings.resolver.keyring_provider),
+        cache,
+    )
+    .await?;
+
     let mut target = if let Some(script) = script {
         // If we found a PEP 723 script and the user provided a project-only setting, warn.
         if package.is_some() {
@@ -280,7 +302,15 @@ pub(crate) async fn add(
         defaulted_groups =
             groups.with_defaults(default_dependency_groups(project.pyproject_toml())?);
 
-        if frozen.is_some() || no_sync {
+        if frozen.is_some()
+            && requirements
+                .iter()
+                .all(|spec| matches!(spec.requirement, UnresolvedRequirement::Named(_)))
+        {
+            // If `--frozen`, we only need to edit the `pyproject.toml`, unless an unnamed
+            // requirement needs to be built to determine its name.
+            AddTarget::Project(project, Box::new(PythonTarget::None))
+        } else if frozen.is_some() || no_sync {
             // Discover the interpreter.
             let workspace_python = WorkspacePython::from_request(
                 python.as_deref().map(PythonRequest::parse),
@@ -320,6 +350,7 @@ pub(crate) async fn add(
                 no_sync,
                 config_discovery,
                 active,
+                false,
                 cache,
                 DryRun::Disabled,
                 LinkErrorReporting::User,
@@ -344,21 +375,6 @@ pub(crate) async fn add(
         .clone()
         .keyring(settings.resolver.keyring_provider);
 
-    // Read the requirements.
-    let RequirementsSpecification {
-        requirements,
-        constraints,
-        ..
-    } = RequirementsSpecification::from_sources(
-        &requirements,
-        &constraints,
-        &[],
-        &[],
-        None,
-        &client_builder,
-    )
-    .await?;
-
     // Initialize any shared state.
     let state = PlatformState::default();
 
@@ -396,8 +412,8 @@ pub(crate) async fn add(
             let client = RegistryClientBuilder::new(client_builder.clone(), cache.clone())
                 .index_locations(settings.resolver.index_locations.clone())
                 .index_strategy(settings.resolver.index_strategy)
-                .markers(target.interpreter().markers())
-                .platform(target.interpreter().platform())
+                .markers(target.interpreter()?.markers())
+                .platform(target.interpreter()?.platform())
                 .build()?;
 
             // Determine whether to enable build isolation.
@@ -405,11 +421,13 @@ pub(crate) async fn add(
             let build_isolation = match &settings.resolver.build_isolation {
                 uv_configuration::BuildIsolation::Isolate => BuildIsolation::Isolated,
                 uv_configuration::BuildIsolation::Shared => {
-                    environment = PythonEnvironment::from_interpreter(target.interpreter().clone());
+                    environment =
+                        PythonEnvironment::from_interpreter(target.interpreter()?.clone());
                     BuildIsolation::Shared(&environment)
                 }
                 uv_configuration::BuildIsolation::SharedPackage(packages) => {
-                    environment = PythonEnvironment::from_interpreter(target.interpreter().clone());
+                    environment =
+                        PythonEnvironment::from_interpreter(target.interpreter()?.clone());
                     BuildIsolation::SharedPackage(&environment, packages)
                 }
             };
@@ -455,7 +473,7 @@ pub(crate) async fn add(
                 &client,
                 cache,
                 &build_constraints,
-                target.interpreter(),
+                target.interpreter()?,
                 &settings.resolver.index_locations,
                 &flat_index,
                 &settings.resolver.dependency_metadata,
@@ -476,7 +494,8 @@ pub(crate) async fn add(
                 WorkspaceCache::default(),
                 concurrency.clone(),
                 preview,
-            );
+            )
+            .with_build_env_passthrough(settings.resolver.build_env_passthrough.clone());
 
             requirements.extend(
                 NamedRequirementsResolver::new(
@@ -531,6 +550,11 @@ pub(crate) async fn add(
     // Track modification status, for reverts.
     let mut modified = false;
 
+    // Track any packages added as workspace members, along with the edited workspace root, which
+    // is only written to disk when not previewing with `--diff-only`.
+    let mut added_members = Vec::new();
+    let mut pending_root = None;
+
     // Determine whether to use workspace mode.
     let use_workspace = match workspace {
         Some(workspace) => workspace,
@@ -597,6 +621,7 @@ pub(crate) async fn add(
                     .unwrap_or(&absolute_path);
 
                 toml.add_workspace(relative_path)?;
+                added_members.push(requirement.name.clone());
                 modified |= true;
 
                 writeln!(
@@ -609,12 +634,35 @@ pub(crate) async fn add(
 
         // If we modified the workspace root, we need to reload it entirely, since this can impact
         // the discovered members, etc.
-        target = if modified {
+        target = if modified && show_diff.is_only() {
+            let workspace_content = toml.to_string();
+
+            // If the workspace root is the project itself, fold the edit into the project's
+            // `pyproject.toml`, such that a single diff is displayed for the file.
+            if project.workspace().install_path() == project.root() {
+                pending_root = Some(workspace_content);
+            } else {
+                diff::show_diff(
+                    &project.workspace().install_path().join("pyproject.toml"),
+                    &project.workspace().pyproject_toml().raw,
+                    &workspace_content,
+                    printer,
+                )?;
+            }
+
+            AddTarget::Project(project, python_target)
+        } else if modified {
             let workspace_content = toml.to_string();
-            fs_err::write(
-                project.workspace().install_path().join("pyproject.toml"),
-                &workspace_content,
-            )?;
+            let workspace_pyproject = project.workspace().install_path().join("pyproject.toml");
+            if show_diff.enabled() {
+                diff::show_diff(
+                    &workspace_pyproject,
+                    &project.workspace().pyproject_toml().raw,
+                    &workspace_content,
+                    printer,
+                )?;
+            }
+            fs_err::write(workspace_pyproject, &workspace_content)?;
 
             AddTarget::Project(
                 VirtualProject::discover(
@@ -636,7 +684,9 @@ pub(crate) async fn add(
             PyProjectTomlMut::from_toml(&script.metadata.raw, DependencyTarget::Script)
         }
         AddTarget::Project(project, _) => PyProjectTomlMut::from_toml(
-            &project.pyproject_toml().raw,
+            pending_root
+                .as_deref()
+                .unwrap_or(&project.pyproject_toml().raw),
             DependencyTarget::PyProjectToml,
         ),
     }?;
@@ -653,6 +703,7 @@ pub(crate) async fn add(
         lfs,
         &extras_of_dependency,
         index,
+        &added_members,
         &mut toml,
     )?;
 
@@ -706,9 +757,56 @@ pub(crate) async fn add(
         }
     }
 
-    let content = toml.to_string();
+    let mut content = toml.to_string();
+
+    // If `--diff-only`, display the changes without writing them.
+    if show_diff.is_only() {
+        let (path, existing) = target.read()?;
+
+        // Lower bounds are only known after resolution, so resolve (without writing a lockfile)
+        // to determine them.
+        let target = if frozen.is_none() && !raw {
+            if !added_members.is_empty() {
+                bail!(
+                    "`--diff-only` cannot determine lower bounds for requirements added alongside new workspace members; use `--show-diff` or `--raw` instead"
+                );
+            }
+            let target = target.update(&content, &WorkspaceCache::default())?;
+            let lock = Box::pin(
+                project::lock::LockOperation::new(
+                    LockMode::DryRun(target.interpreter()?),
+                    &settings.resolver,
+                    &client_builder,
+                    &state.fork(),
+                    Box::new(DefaultResolveLogger),
+                    &concurrency,
+                    cache,
+                    &WorkspaceCache::default(),
+                    printer,
+                    preview,
+                )
+                .with_constraints(constraints)
+                .with_remote_files(remote_files)
+                .execute((&target).into()),
+            )
+            .await?
+            .into_lock();
+            if add_bounds(&lock, &mut toml, &edits, bounds, printer)? {
+                content = toml.to_string();
+            }
+            target
+        } else {
+            target
+        };
+
+        diff::show_diff(&path, &existing, &target.render(&content), printer)?;
+        return Ok(ExitStatus::Success);
+    }
 
     // Save the modified `pyproject.toml` or script.
+    if show_diff.enabled() {
+        target.show_diff(&content, printer)?;
+    }
     modified |= target.write(&content)?;
 
     // If `--frozen`, exit early. There's no reason to lock and sync, since we don't need a `uv.lock`
@@ -733,6 +831,8 @@ pub(crate) async fn add(
     let _ = ctrlc::set_handler({
         let snapshot = snapshot.clone();
         move || {
+            uv_build_frontend::abort_running_builds();
+
             if modified {
                 let _ = snapshot.revert();
             }
@@ -749,7 +849,7 @@ pub(crate) async fn add(
     // Use separate state for locking and syncing.
     let lock_state = state.fork();
     let sync_state = state;
-    let python_minor = target.interpreter().python_minor();
+    let python_minor = target.interpreter()?.python_minor();
 
     match Box::pin(lock_and_sync(
         target,
@@ -771,7 +871,9 @@ pub(crate) async fn add(
         raw,
         bounds,
         dry_run,
+        show_diff,
         constraints,
+        remote_files,
         &settings,
         &client_builder,
         installer_metadata,
@@ -852,6 +954,7 @@ fn edits(
     lfs: GitLfsSetting,
     extras: &[ExtraName],
     index: Option<&IndexName>,
+    added_members: &[PackageName],
     toml: &mut PyProjectTomlMut,
 ) -> Result<Vec<DependencyEdit>> {
     let mut edits = Vec::<DependencyEdit>::with_capacity(requirements.len());
@@ -898,7 +1001,8 @@ fn edits(
                 let is_workspace_member = project
                     .workspace()
                     .packages()
-                    .contains_key(&requirement.name);
+                    .contains_key(&requirement.name)
+                    || added_members.contains(&requirement.name);
                 resolve_requirement(
                     requirement,
                     is_workspace_member,
@@ -1060,7 +1164,9 @@ async fn lock_and_sync(
     raw: bool,
     bound_kind: Option<AddBoundsKind>,
     dry_run: bool,
+    show_diff: ShowDiff,
     constraints: Vec<NameRequirementSpecification>,
+    remote_files: BTreeMap<DisplaySafeUrl, HashDigest>,
     settings: &ResolverInstallerSettings,
     client_builder: &BaseClientBuilder<'_>,
     installer_metadata: bool,
@@ -1073,11 +1179,11 @@ async fn lock_and_sync(
     let mut lock = Box::pin(
         project::lock::LockOperation::new(
             if let LockCheck::Enabled(lock_check) = lock_check {
-                LockMode::Locked(target.interpreter(), lock_check)
+                LockMode::Locked(target.interpreter()?, lock_check)
             } else if dry_run {
-                LockMode::DryRun(target.interpreter())
+                LockMode::DryRun(target.interpreter()?)
             } else {
-                LockMode::Write(target.interpreter())
+                LockMode::Write(target.interpreter()?)
             },
             &settings.resolver,
             client_builder,
@@ -1090,6 +1196,7 @@ async fn lock_and_sync(
             preview,
         )
         .with_constraints(constraints)
+        .with_remote_files(remote_files)
         .execute((&target).into()),
     )
     .await?
@@ -1097,88 +1204,18 @@ async fn lock_and_sync(
 
     // Avoid modifying the user request further if `--raw-sources` is set.
     if !raw {
-        // Extract the minimum-supported version for each dependency.
-        let mut minimum_version =
-            FxHashMap::with_capacity_and_hasher(lock.packages().len(), FxBuildHasher);
-        for dist in lock.packages() {
-            let name = dist.name();
-            let Some(version) = dist.version() else {
-                continue;
-            };
-            match minimum_version.entry(name) {
-                Entry::Vacant(entry) => {
-                    entry.insert(version);
-                }
-                Entry::Occupied(mut entry) => {
-                    if version < *entry.get() {
-                        entry.insert(version);
-                    }
-                }
-            }
-        }
-
-        // If any of the requirements were added without version specifiers, add a lower bound.
-        let mut modified = false;
-        for edit in edits {
-            // Only set a minimum version for newly-added dependencies (as opposed to updates).
-            let ArrayEdit::Add(index) = &edit.edit else {
-                continue;
-            };
-
-            // Only set a minimum version for registry requirements.
-            if edit
-                .source
-                .as_ref()
-                .is_some_and(|source| !matches!(source, Source::Registry { .. }))
-            {
-                continue;
-            }
-
-            // Only set a minimum version for registry requirements.
-            let is_empty = match edit.requirement.version_or_url.as_ref() {
-                Some(VersionOrUrl::VersionSpecifier(version)) => version.is_empty(),
-                Some(VersionOrUrl::Url(_)) => false,
-                None => true,
-            };
-            if !is_empty {
-                if let Some(bound_kind) = bound_kind {
-                    writeln!(
-                        printer.stderr(),
-                        "{} Using explicit requirement `{}` over bounds preference `{}`",
-                        "note:".bold(),
-                        edit.requirement,
-                        bound_kind
-                    )?;
-                }
-                continue;
-            }
-
-            // Set the minimum version.
-            let Some(minimum) = minimum_version.get(&edit.requirement.name) else {
-                continue;
-            };
-
-            // Drop the local version identifier, which isn't permitted in `>=` constraints.
-            // For example, convert `1.2.3+local` to `1.2.3`.
-            let minimum = (*minimum).clone().without_local();
-
-            toml.set_dependency_bound(
-                &edit.dependency_type,
-                *index,
-                minimum,
-                bound_kind.unwrap_or_default(),
-            )?;
-
-            modified = true;
-        }
+        let modified = add_bounds(&lock, toml, edits, bound_kind, printer)?;
 
         // Save the modified `pyproject.toml`. No need to check for changes in the underlying
-        // string content, since the above loop _must_ change an empty specifier to a non-empty
+        // string content, since adding a bound _must_ change an empty specifier to a non-empty
         // specifier.
         if modified {
             let content = toml.to_string();
 
             // Write the updated `pyproject.toml` to disk.
+            if show_diff.enabled() {
+                target.show_diff(&content, printer)?;
+            }
             target.write(&content)?;
 
             // Update the `pypackage.toml` in-memory.
@@ -1198,11 +1235,11 @@ async fn lock_and_sync(
             lock = Box::pin(
                 project::lock::LockOperation::new(
                     if let LockCheck::Enabled(lock_check) = lock_check {
-                        LockMode::Locked(target.interpreter(), lock_check)
+                        LockMode::Locked(target.interpreter()?, lock_check)
                     } else if dry_run {
-                        LockMode::DryRun(target.interpreter())
+                        LockMode::DryRun(target.interpreter()?)
                     } else {
-                        LockMode::Write(target.interpreter())
+                        LockMode::Write(target.interpreter()?)
                     },
                     &settings.resolver,
                     client_builder,
@@ -1280,6 +1317,95 @@ async fn lock_and_sync(
     Ok(())
 }
 
+/// Add lower bounds to any newly-added registry requirements that were provided without a version
+/// specifier, based on the versions in the resolved [`Lock`].
+///
+/// Returns `true` if any bounds were added.
+fn add_bounds(
+    lock: &Lock,
+    toml: &mut PyProjectTomlMut,
+    edits: &[DependencyEdit],
+    bound_kind: Option<AddBoundsKind>,
+    printer: Printer,
+) -> Result<bool, ProjectError> {
+    // Extract the minimum-supported version for each dependency.
+    let mut minimum_version =
+        FxHashMap::with_capacity_and_hasher(lock.packages().len(), FxBuildHasher);
+    for dist in lock.packages() {
+        let name = dist.name();
+        let Some(version) = dist.version() else {
+            continue;
+        };
+        match minimum_version.entry(name) {
+            Entry::Vacant(entry) => {
+                entry.insert(version);
+            }
+            Entry::Occupied(mut entry) => {
+                if version < *entry.get() {
+                    entry.insert(version);
+                }
+            }
+        }
+    }
+
+    // If any of the requirements were added without version specifiers, add a lower bound.
+    let mut modified = false;
+    for edit in edits {
+        // Only set a minimum version for newly-added dependencies (as opposed to updates).
+        let ArrayEdit::Add(index) = &edit.edit else {
+            continue;
+        };
+
+        // Only set a minimum version for registry requirements.
+        if edit
+            .source
+            .as_ref()
+            .is_some_and(|source| !matches!(source, Source::Registry { .. }))
+        {
+            continue;
+        }
+
+        // Only set a minimum version for registry requirements.
+        let is_empty = match edit.requirement.version_or_url.as_ref() {
+            Some(VersionOrUrl::VersionSpecifier(version)) => version.is_empty(),
+            Some(VersionOrUrl::Url(_)) => false,
+            None => true,
+        };
+        if !is_empty {
+            if let Some(bound_kind) = bound_kind {
+                writeln!(
+                    printer.stderr(),
+                    "{} Using explicit requirement `{}` over bounds preference `{}`",
+                    "note:".bold(),
+                    edit.requirement,
+                    bound_kind
+                )?;
+            }
+            continue;
+        }
+
+        // Set the minimum version.
+        let Some(minimum) = minimum_version.get(&edit.requirement.name) else {
+            continue;
+        };
+
+        // Drop the local version identifier, which isn't permitted in `>=` constraints.
+        // For example, convert `1.2.3+local` to `1.2.3`.
+        let minimum = (*minimum).clone().without_local();
+
+        toml.set_dependency_bound(
+            &edit.dependency_type,
+            *index,
+            minimum,
+            bound_kind.unwrap_or_default(),
+        )?;
+
+        modified = true;
+    }
+
+    Ok(modified)
+}
+
 /// Resolves the source for a requirement and processes it into a PEP 508 compliant format.
 fn resolve_requirement(
     requirement: Requirement,
@@ -1331,14 +1457,17 @@ fn resolve_requirement(
 pub(super) enum PythonTarget {
     Interpreter(Interpreter),
     Environment(PythonEnvironment),
+    /// No interpreter, as the `pyproject.toml` is edited without locking or syncing.
+    None,
 }
 
 impl PythonTarget {
-    /// Return the [`Interpreter`] for the project.
-    fn interpreter(&self) -> &Interpreter {
+    /// Return the [`Interpreter`] for the project, if any.
+    fn interpreter(&self) -> Option<&Interpreter> {
         match self {
-            Self::Interpreter(interpreter) => interpreter,
-            Self::Environment(venv) => venv.interpreter(),
+            Self::Interpreter(interpreter) => Some(interpreter),
+            Self::Environment(venv) => Some(venv.interpreter()),
+            Self::None => None,
         }
     }
 }
@@ -1366,19 +1495,55 @@ impl<'lock> From<&'lock AddTarget> for LockTarget<'lock> {
 impl AddTarget {
     /// Acquire a file lock mapped to the underlying interpreter to prevent concurrent
     /// modifications.
-    pub(super) async fn acquire_lock(&self) -> Result<LockedFile, LockedFileError> {
+    ///
+    /// Returns `None` if the target has no interpreter.
+    pub(super) async fn acquire_lock(&self) -> Result<Option<LockedFile>, LockedFileError> {
         match self {
-            Self::Script(_, interpreter) => interpreter.lock().await,
-            Self::Project(_, python_target) => python_target.interpreter().lock().await,
+            Self::Script(_, interpreter) => interpreter.lock().await.map(Some),
+            Self::Project(_, python_target) => match python_target.interpreter() {
+                Some(interpreter) => interpreter.lock().await.map(Some),
+                None => Ok(None),
+            },
         }
     }
 
     /// Returns the [`Interpreter`] for the target.
-    pub(super) fn interpreter(&self) -> &Interpreter {
+    ///
+    /// Errors if the project is edited without discovering an interpreter, i.e., with `--frozen`.
+    pub(super) fn interpreter(&self) -> Result<&Interpreter, ProjectError> {
+        match self {
+            Self::Script(_, interpreter) => Ok(interpreter),
+            Self::Project(_, venv) => venv.interpreter().ok_or(ProjectError::MissingInterpreter),
+        }
+    }
+
+    /// Return the path to the file backing the target, along with its current content on disk.
+    fn read(&self) -> Result<(PathBuf, String), io::Error> {
+        let path = match self {
+            Self::Script(script, _) => script.path.clone(),
+            Self::Project(project, _) => project.root().join("pyproject.toml"),
+        };
+        let content = fs_err::read_to_string(&path)?;
+        Ok((path, content))
+    }
+
+    /// Render the file content that would be written for the updated content.
+    fn render(&self, content: &str) -> String {
         match self {
-            Self::Script(_, interpreter) => interpreter,
-            Self::Project(_, venv) => venv.interpreter(),
+            Self::Script(script, _) => script.render(content),
+            Self::Project(_, _) => content.to_string(),
+        }
+    }
+
+    /// Display a diff of the changes that writing the updated content would make to the target.
+    fn show_diff(&self, content: &str, printer: Printer) -> Result<()> {
+        if let Self::Script(script, _) = self {
+            if content == script.metadata.raw {
+                return Ok(());
+            }
         }
+        let (path, existing) = self.read()?;
+        diff::show_diff(&path, &existing, &self.render(content), printer)
     }
 
     /// Write the updated content to the target.
@@ -9,7 +9,7 @@ use crate::commands::pip::loggers::DefaultResolveLogger;
 use crate::commands::pip::resolution_markers;
 use crate::commands::project::default_dependency_groups;
 use crate::commands::project::lock::{LockMode, LockOperation};
-use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::lock_target::SingleLockTarget;
 use crate::commands::project::{
     ProjectError, ProjectInterpreter, ScriptInterpreter, UniversalState, WorkspacePython,
 };
@@ -86,7 +86,7 @@ pub(crate) async fn audit(
 
     let workspace;
     let target = if let Some(script) = script.as_ref() {
-        LockTarget::Script(script)
+        SingleLockTarget::Script(script)
     } else {
         workspace = Workspace::discover(
             project_dir,
@@ -95,20 +95,22 @@ pub(crate) async fn audit(
             workspace_cache,
         )
         .await?;
-        LockTarget::Workspace(&workspace)
+        SingleLockTarget::Workspace(&workspace)
     };
 
     // Determine the groups to include.
     let default_groups = match target {
-        LockTarget::Workspace(workspace) => default_dependency_groups(workspace.pyproject_toml())?,
-        LockTarget::Script(_) => DefaultGroups::default(),
+        SingleLockTarget::Workspace(workspace) => {
+            default_dependency_groups(workspace.pyproject_toml())?
+        }
+        SingleLockTarget::Script(_) => DefaultGroups::default(),
     };
     let groups = groups.with_defaults(default_groups);
 
     // Determine the extras to include.
     let default_extras = match &target {
-        LockTarget::Workspace(_) => DefaultExtras::All,
-        LockTarget::Script(_) => DefaultExtras::All,
+        SingleLockTarget::Workspace(_) => DefaultExtras::All,
+        SingleLockTarget::Script(_) => DefaultExtras::All,
     };
     let extras = extras.with_defaults(default_extras);
 
@@ -120,7 +122,7 @@ pub(crate) async fn audit(
         None
     } else {
         Some(match target {
-            LockTarget::Script(script) => ScriptInterpreter::discover(
+            SingleLockTarget::Script(script) => ScriptInterpreter::discover(
                 script.into(),
                 None,
                 &client_builder,
@@ -135,7 +137,7 @@ pub(crate) async fn audit(
             )
             .await?
             .into_interpreter(),
-            LockTarget::Workspace(workspace) => {
+            SingleLockTarget::Workspace(workspace) => {
                 let workspace_python = WorkspacePython::from_request(
                     None,
                     Some(workspace),
@@ -168,7 +170,7 @@ pub(crate) async fn audit(
         LockMode::Frozen(frozen_source.into())
     } else if let LockCheck::Enabled(lock_check) = lock_check {
         LockMode::Locked(interpreter.as_ref().unwrap(), lock_check)
-    } else if matches!(target, LockTarget::Script(_)) && !target.lock_path().is_file() {
+    } else if matches!(target, SingleLockTarget::Script(_)) && !target.lock_path().is_file() {
         // If we're locking a script, avoid creating a lockfile if it doesn't already exist.
         LockMode::DryRun(interpreter.as_ref().unwrap())
     } else {
@@ -192,7 +194,7 @@ pub(crate) async fn audit(
             printer,
             preview,
         )
-        .execute(target),
+        .execute(target.into()),
     )
     .await
     {
@@ -314,7 +316,7 @@ pub(crate) async fn audit(
             let lock_path = target.lock_path();
             // If we've run `uv audit --script`, we might only have an in-memory lockfile.
             // In that case, use the script's own path as the artifact path.
-            let artifact_path = if let LockTarget::Script(script) = target
+            let artifact_path = if let SingleLockTarget::Script(script) = target
                 && !lock_path.is_file()
             {
                 script.path.as_path()
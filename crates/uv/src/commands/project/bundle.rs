@@ -0,0 +1,280 @@
+//! Support for bundling a project's locked dependencies alongside their artifacts, for
+//! installation on machines without network access (`uv export --bundle` and
+//! `uv sync --from-bundle`).
+
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+use owo_colors::OwoColorize;
+use tokio::io::AsyncWriteExt;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_cli::DryRunFormat;
+use uv_client::{BaseClientBuilder, Connectivity};
+use uv_configuration::{
+    Concurrency, DryRun, ExtrasSpecification, HashCheckingMode, KeyringProviderType, TargetTriple,
+};
+use uv_distribution_types::{DependencyMetadata, IndexLocations};
+use uv_extract::hash::{HashReader, Hasher};
+use uv_fs::Simplified;
+use uv_preview::{Preview, PreviewFeature};
+use uv_pypi_types::{HashAlgorithm, HashDigest};
+use uv_python::{PythonDownloads, PythonEnvironment, PythonPreference};
+use uv_requirements::{GroupsSpecification, RequirementsSource};
+use uv_resolver::{ExcludeNewer, PylockToml, PylockTomlBundleArtifact, PylockTomlBundleTarget};
+use uv_settings::PythonInstallMirrors;
+use uv_workspace::WorkspaceCache;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+use crate::settings::ResolverInstallerSettings;
+
+/// The name of the lockfile within a bundle.
+const BUNDLE_LOCKFILE: &str = "pylock.toml";
+
+/// The directory containing the artifacts within a bundle.
+const BUNDLE_ARTIFACTS: &str = "artifacts";
+
+/// Write a bundle containing the given lock and every artifact required to install it on the
+/// given targets.
+pub(crate) async fn write_bundle(
+    mut lock: PylockToml,
+    install_path: &Path,
+    targets: &[PylockTomlBundleTarget<'_>],
+    output: &Path,
+    client_builder: &BaseClientBuilder<'_>,
+    printer: Printer,
+) -> Result<()> {
+    let artifacts = lock.bundle(install_path, Path::new(BUNDLE_ARTIFACTS), targets)?;
+
+    let staging = tempfile::tempdir()?;
+    fs_err::tokio::create_dir_all(staging.path().join(BUNDLE_ARTIFACTS)).await?;
+
+    let client = client_builder.build()?;
+    for artifact in &artifacts {
+        let target = staging
+            .path()
+            .join(BUNDLE_ARTIFACTS)
+            .join(&artifact.filename);
+        if target.exists() {
+            continue;
+        }
+        fetch_artifact(&client, artifact, &target).await?;
+    }
+
+    fs_err::tokio::write(staging.path().join(BUNDLE_LOCKFILE), lock.to_toml()?).await?;
+
+    // Archive the staging directory as a zstd-compressed tarball.
+    if let Some(parent) = output
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        fs_err::tokio::create_dir_all(parent).await?;
+    }
+    let file = fs_err::tokio::File::create(output).await?;
+    let encoder = async_compression::tokio::write::ZstdEncoder::new(file);
+    let mut builder = tokio_tar::Builder::new(encoder);
+    builder
+        .append_path_with_name(staging.path().join(BUNDLE_LOCKFILE), BUNDLE_LOCKFILE)
+        .await?;
+    builder
+        .append_dir_all(BUNDLE_ARTIFACTS, staging.path().join(BUNDLE_ARTIFACTS))
+        .await?;
+    let mut encoder = builder.into_inner().await?;
+    encoder.shutdown().await?;
+
+    writeln!(
+        printer.stderr(),
+        "Bundled {} {} into: {}",
+        artifacts.len().bold(),
+        if artifacts.len() == 1 {
+            "artifact"
+        } else {
+            "artifacts"
+        },
+        output.user_display().cyan()
+    )?;
+
+    Ok(())
+}
+
+/// Download (or copy) an artifact into the bundle, verifying its SHA-256 digest, if known.
+async fn fetch_artifact(
+    client: &uv_client::BaseClient,
+    artifact: &PylockTomlBundleArtifact,
+    target: &Path,
+) -> Result<()> {
+    debug!("Bundling `{}` from: {}", artifact.filename, artifact.url);
+
+    let contents = if artifact.url.scheme() == "file" {
+        let path = artifact
+            .url
+            .to_file_path()
+            .map_err(|()| anyhow::anyhow!("Invalid file URL: {}", artifact.url))?;
+        fs_err::tokio::read(&path).await?
+    } else {
+        let response = client
+            .for_host(&artifact.url)
+            .get(url::Url::from(artifact.url.clone()))
+            .send()
+            .await
+            .with_context(|| format!("Failed to download `{}`", artifact.filename))?;
+        response
+            .error_for_status()
+            .with_context(|| format!("Failed to download `{}`", artifact.filename))?
+            .bytes()
+            .await?
+            .to_vec()
+    };
+
+    let expected = artifact
+        .hashes
+        .iter()
+        .find(|digest| digest.algorithm == HashAlgorithm::Sha256);
+    if let Some(expected) = expected {
+        let mut hashers = vec![Hasher::from(HashAlgorithm::Sha256)];
+        HashReader::new(contents.as_slice(), &mut hashers)
+            .finish()
+            .await?;
+        let actual = HashDigest::from(hashers.remove(0));
+        if actual.digest != expected.digest {
+            bail!(
+                "Hash mismatch for `{}` (from `{}`)\n\nExpected:\n  {expected}\n\nComputed:\n  {actual}",
+                artifact.filename,
+                artifact.package,
+            );
+        }
+    }
+
+    let mut file = fs_err::tokio::File::create(target).await?;
+    file.write_all(&contents).await?;
+    file.flush().await?;
+
+    Ok(())
+}
+
+/// Extract a bundle created by `uv export --bundle` into `target`, returning the path to its
+/// lockfile.
+///
+/// Local directory sources in the lock are resolved against `project_root`, since they refer to
+/// the project from which the bundle was exported rather than the bundle itself.
+pub(crate) async fn extract_bundle(
+    bundle: &Path,
+    project_root: &Path,
+    target: &Path,
+) -> Result<PathBuf> {
+    let reader = fs_err::tokio::File::open(bundle).await?;
+    uv_extract::stream::untar_zst(reader, target)
+        .await
+        .with_context(|| format!("Failed to extract bundle: {}", bundle.user_display()))?;
+
+    let lockfile = target.join(BUNDLE_LOCKFILE);
+    if !lockfile.is_file() {
+        bail!(
+            "`{}` is not a valid bundle (missing `{BUNDLE_LOCKFILE}`)",
+            bundle.user_display()
+        );
+    }
+
+    let contents = fs_err::tokio::read_to_string(&lockfile).await?;
+    let mut lock = toml::from_str::<PylockToml>(&contents).with_context(|| {
+        format!(
+            "Not a valid `pylock.toml` file: {}",
+            lockfile.user_display()
+        )
+    })?;
+    lock.relocate_directories(project_root);
+    fs_err::tokio::write(&lockfile, lock.to_toml()?).await?;
+
+    Ok(lockfile)
+}
+
+/// Install the contents of a bundle created by `uv export --bundle` into the given environment.
+///
+/// The installation is performed exclusively from the artifacts in the bundle: the network is
+/// never accessed, and every artifact is verified against the hashes recorded in the lockfile.
+pub(crate) async fn install_bundle(
+    bundle: &Path,
+    project_root: &Path,
+    environment: &PythonEnvironment,
+    python_platform: Option<TargetTriple>,
+    settings: &ResolverInstallerSettings,
+    client_builder: &BaseClientBuilder<'_>,
+    installer_metadata: bool,
+    concurrency: Concurrency,
+    cache: &Cache,
+    workspace_cache: &WorkspaceCache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    let staging = tempfile::tempdir()?;
+    let lockfile = extract_bundle(bundle, project_root, staging.path()).await?;
+
+    debug!(
+        "Installing from bundle `{}` into: {}",
+        bundle.user_display(),
+        environment.root().user_display()
+    );
+
+    // The bundle is consumed via its `pylock.toml`, which is an implementation detail here
+    // rather than a user-facing `--pylock` argument.
+    let preview = preview.with(PreviewFeature::Pylock);
+
+    Box::pin(crate::commands::pip_sync(
+        &[RequirementsSource::PylockToml(lockfile)],
+        &[],
+        &[],
+        &[],
+        false,
+        &ExtrasSpecification::default(),
+        &GroupsSpecification {
+            root: project_root.to_path_buf(),
+            groups: Vec::new(),
+        },
+        settings.reinstall.clone(),
+        settings.resolver.link_mode,
+        settings.compile_bytecode,
+        Some(HashCheckingMode::Verify),
+        IndexLocations::default(),
+        settings.resolver.index_strategy,
+        None,
+        None,
+        None,
+        DependencyMetadata::default(),
+        KeyringProviderType::Disabled,
+        &client_builder.clone().connectivity(Connectivity::Offline),
+        true,
+        installer_metadata,
+        &settings.resolver.config_setting,
+        &settings.resolver.config_settings_package,
+        settings.resolver.build_isolation.clone(),
+        &settings.resolver.extra_build_dependencies,
+        &settings.resolver.extra_build_variables,
+        settings.resolver.build_options.clone(),
+        None,
+        python_platform,
+        PythonDownloads::Never,
+        PythonInstallMirrors::default(),
+        false,
+        ExcludeNewer::default(),
+        None,
+        Some(environment.root().to_string_lossy().into_owned()),
+        None,
+        false,
+        false,
+        None,
+        None,
+        settings.resolver.sources.clone(),
+        PythonPreference::default(),
+        concurrency,
+        cache.clone(),
+        workspace_cache.clone(),
+        DryRun::Disabled,
+        DryRunFormat::default(),
+        printer,
+        preview,
+    ))
+    .await
+}
@@ -506,6 +506,7 @@ pub(crate) async fn check(
                 no_sync,
                 config_discovery,
                 None,
+                false,
                 cache,
                 DryRun::Disabled,
                 LinkErrorReporting::User,
@@ -0,0 +1,48 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use owo_colors::OwoColorize;
+use similar::{ChangeTag, TextDiff};
+
+use uv_fs::Simplified;
+
+use crate::printer::Printer;
+
+/// Display a colored unified diff between the current and updated contents of a file.
+///
+/// The updated contents should be exactly what is (or would be) written to disk, such that the
+/// diff reflects the serialized output byte-for-byte.
+pub(crate) fn show_diff(path: &Path, old: &str, new: &str, printer: Printer) -> anyhow::Result<()> {
+    if old == new {
+        return Ok(());
+    }
+
+    let mut stdout = printer.stdout_important();
+    let diff = TextDiff::from_lines(old, new);
+
+    let path = path.user_display();
+    writeln!(stdout, "{}", format!("--- {path}").bold())?;
+    writeln!(stdout, "{}", format!("+++ {path}").bold())?;
+
+    for hunk in diff.unified_diff().context_radius(3).iter_hunks() {
+        writeln!(stdout, "{}", hunk.header().cyan())?;
+        for change in hunk.iter_changes() {
+            let value = change.value();
+            let line = format!(
+                "{}{}",
+                change.tag(),
+                value.strip_suffix('\n').unwrap_or(value)
+            );
+            match change.tag() {
+                ChangeTag::Delete => writeln!(stdout, "{}", line.red())?,
+                ChangeTag::Insert => writeln!(stdout, "{}", line.green())?,
+                ChangeTag::Equal => writeln!(stdout, "{line}")?,
+            }
+            if change.missing_newline() {
+                writeln!(stdout, "\\ No newline at end of file")?;
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -1,26 +1,33 @@
-use std::path::Path;
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
-use tracing::debug;
+use sha2::{Digest, Sha256};
+use tracing::{debug, warn};
 
 use crate::commands::pip::loggers::{InstallLogger, ResolveLogger};
 use crate::commands::pip::operations::Modifications;
 use crate::commands::project::{
-    EnvironmentResolution, EnvironmentSpecification, PlatformState, ProjectError,
-    resolve_environment, sync_environment,
+    EnvironmentResolution, EnvironmentSpecification, PlatformState, PreferenceLocation,
+    ProjectError, resolve_environment, sync_environment,
 };
 use crate::printer::Printer;
-use crate::settings::ResolverInstallerSettings;
+use crate::settings::{InstallerSettingsRef, ResolverInstallerSettings};
 
-use uv_cache::{Cache, CacheBucket};
-use uv_cache_info::CacheInfo;
-use uv_cache_key::{cache_digest, hash_digest};
+use uv_cache::{Cache, CacheBucket, CacheEntry, Freshness, Refresh};
+use uv_cache_info::{CacheInfo, Timestamp};
+use uv_cache_key::hash_digest;
 use uv_client::BaseClientBuilder;
 use uv_configuration::{Concurrency, Constraints, HashCheckingMode, TargetTriple};
 use uv_distribution_types::{
-    BuiltDist, Dist, Identifier, Node, Resolution, ResolvedDist, SourceDist,
+    BuiltDist, Dist, Identifier, Name, Node, RequirementSource, Resolution, ResolvedDist,
+    SourceDist, UnresolvedRequirement,
 };
+use uv_fs::is_virtualenv_base;
+use uv_install_wheel::LinkMode;
+use uv_normalize::PackageName;
 use uv_preview::Preview;
-use uv_python::{Interpreter, PythonEnvironment, canonicalize_executable};
+use uv_python::{Interpreter, PyVenvConfiguration, PythonEnvironment, canonicalize_executable};
 use uv_types::{HashStrategy, SourceTreeEditablePolicy};
 use uv_workspace::WorkspaceCache;
 
@@ -40,6 +47,66 @@ impl From<EphemeralEnvironment> for PythonEnvironment {
     }
 }
 
+/// The chain of environments extended by an [`EphemeralEnvironment`].
+#[derive(Debug)]
+pub(crate) struct ParentEnvironments {
+    /// The `sys.prefix` of each environment, from the immediate parent to the most distant
+    /// ancestor.
+    sys_prefixes: Vec<PathBuf>,
+    /// Whether any environment in the chain includes the system site packages.
+    include_system_site_packages: bool,
+}
+
+impl ParentEnvironments {
+    /// Collect the chain of environments starting at the parent environment with the given
+    /// `sys.prefix`, following the `extends-environment` key of each `pyvenv.cfg`.
+    pub(crate) fn from_sys_prefix(sys_prefix: &Path) -> Self {
+        let mut sys_prefixes = vec![sys_prefix.to_path_buf()];
+        let mut include_system_site_packages = false;
+
+        // Visit each environment in the chain. Environments written by older versions of uv only
+        // record their immediate parent, so ancestors are visited in turn to recover the rest of
+        // the chain.
+        let mut index = 0;
+        while let Some(sys_prefix) = sys_prefixes.get(index) {
+            index += 1;
+            let Ok(cfg) = PyVenvConfiguration::parse(sys_prefix.join("pyvenv.cfg")) else {
+                continue;
+            };
+            if cfg.include_system_site_packages() {
+                include_system_site_packages = true;
+            }
+            for ancestor in cfg.extends_environment() {
+                if !sys_prefixes.contains(ancestor) {
+                    sys_prefixes.push(ancestor.clone());
+                }
+            }
+        }
+
+        Self {
+            sys_prefixes,
+            include_system_site_packages,
+        }
+    }
+
+    /// Returns the `sys.prefix` of the parent environment, followed by that of each of its
+    /// ancestors, in order of precedence.
+    pub(crate) fn sys_prefixes(&self) -> impl Iterator<Item = &Path> {
+        self.sys_prefixes.iter().map(PathBuf::as_path)
+    }
+
+    /// Returns the `sys.prefix` of each ancestor of the parent environment, in order of
+    /// precedence.
+    pub(crate) fn ancestors(&self) -> impl Iterator<Item = &Path> {
+        self.sys_prefixes.iter().skip(1).map(PathBuf::as_path)
+    }
+
+    /// Returns `true` if any environment in the chain includes the system site packages.
+    pub(crate) fn include_system_site_packages(&self) -> bool {
+        self.include_system_site_packages
+    }
+}
+
 impl EphemeralEnvironment {
     /// Set the ephemeral overlay for a Python environment.
     pub(crate) fn set_overlay(&self, contents: impl AsRef<[u8]>) -> Result<(), ProjectError> {
@@ -60,7 +127,8 @@ impl EphemeralEnvironment {
         Ok(())
     }
 
-    /// Set the `extends-environment` key in the `pyvenv.cfg` file to the given path.
+    /// Set the `extends-environment` key in the `pyvenv.cfg` file to the given chain of parent
+    /// environments.
     ///
     /// Ephemeral environments created by `uv run --with` extend a parent (virtual or system)
     /// environment by adding a `.pth` file to the ephemeral environment's `site-packages`
@@ -72,15 +140,21 @@ impl EphemeralEnvironment {
     /// `extends-environment` key of the ephemeral environment's `pyvenv.cfg` file, making it
     /// easier for these tools to statically and reliably understand the relationship between
     /// the two environments.
+    ///
+    /// If the parent environment itself extends other environments (e.g., when `uv run --with`
+    /// is nested), the `sys.prefix` of each ancestor is written after that of the parent, in
+    /// order of precedence, using the platform's path-list separator.
     pub(crate) fn set_parent_environment(
         &self,
-        parent_environment_sys_prefix: &Path,
+        parent_environments: &ParentEnvironments,
     ) -> Result<(), ProjectError> {
-        let parent_environment_sys_prefix = parent_environment_sys_prefix
+        let parent_environments = std::env::join_paths(&parent_environments.sys_prefixes)
+            .map_err(|_| ProjectError::InvalidParentEnvironmentPath)?;
+        let parent_environments = parent_environments
             .to_str()
             .ok_or(ProjectError::InvalidParentEnvironmentPath)?;
         self.0
-            .set_pyvenv_cfg("extends-environment", parent_environment_sys_prefix)?;
+            .set_pyvenv_cfg("extends-environment", parent_environments)?;
         Ok(())
     }
 
@@ -116,6 +190,25 @@ struct CachedEnvironmentDist {
     cache_info: Option<CacheInfo>,
 }
 
+/// The resolution of an [`EnvironmentSpecification`], cached such that subsequent requests for the
+/// same specification can skip straight to the environment lookup.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct CachedSpecification {
+    /// The hash of the resolution, used as the key for the cached environment.
+    resolution_hash: String,
+    /// The number of packages in the resolution.
+    packages: usize,
+}
+
+/// The duration for which the cached resolution of an [`EnvironmentSpecification`] is reused.
+///
+/// The resolution of a specification may change as new versions are published to the index, so
+/// cached resolutions are only reused for as long as PyPI allows its index pages to be cached.
+const CACHED_SPECIFICATION_TTL: Duration = Duration::from_mins(10);
+
+/// The marker file written to a cached environment once it has been fully synced.
+const CACHED_ENVIRONMENT_READY: &str = ".ok";
+
 fn cached_environment_resolution_hash(
     resolution_hash: String,
     hash_strategy: &HashStrategy,
@@ -131,6 +224,152 @@ fn cached_environment_resolution_hash(
     }
 }
 
+/// Construct a hash for the settings that affect the contents of a cached environment.
+///
+/// Two invocations that resolve to the same distributions can still produce different
+/// environments: with `--no-binary`, packages are built from source; with different
+/// `--config-settings` or build constraints, they're built differently; and with `symlink` links,
+/// the environment depends on the wheels remaining in the cache. Settings that can't affect the
+/// installed files (e.g., concurrency) are excluded, to avoid fragmenting the cache.
+///
+/// Returns `None` if all such settings match their defaults, to retain existing cache identities.
+fn cached_environment_settings_hash(
+    settings: &ResolverInstallerSettings,
+    build_constraints: &Constraints,
+) -> Option<String> {
+    fn settings_key(
+        settings: &ResolverInstallerSettings,
+        build_constraints: &Constraints,
+    ) -> String {
+        format!(
+            "{:?}",
+            (
+                &settings.resolver.build_options,
+                &settings.resolver.config_setting,
+                &settings.resolver.config_settings_package,
+                &settings.resolver.build_isolation,
+                &settings.resolver.extra_build_dependencies,
+                &settings.resolver.extra_build_variables,
+                &settings.resolver.build_env_passthrough,
+                cached_environment_link_mode(settings.resolver.link_mode),
+                settings.compile_bytecode,
+                cached_environment_build_constraints(build_constraints),
+            )
+        )
+    }
+
+    let key = settings_key(settings, build_constraints);
+    if key
+        == settings_key(
+            &ResolverInstallerSettings::default(),
+            &Constraints::default(),
+        )
+    {
+        None
+    } else {
+        Some(hash_digest(&key))
+    }
+}
+
+/// Return the build constraints of a cached environment in a stable order, for hashing.
+fn cached_environment_build_constraints(build_constraints: &Constraints) -> Vec<String> {
+    let mut build_constraints = build_constraints
+        .requirements()
+        .map(|requirement| format!("{requirement:?}"))
+        .collect::<Vec<_>>();
+    build_constraints.sort_unstable();
+    build_constraints
+}
+
+/// Return the [`LinkMode`] with which a cached environment is installed.
+///
+/// Cached environments are immutable and live alongside the archive, so prefer hard links to
+/// copies: distributions shared across environments are then stored on disk only once. If hard
+/// links aren't supported, installation falls back to copying regardless.
+fn cached_environment_link_mode(link_mode: LinkMode) -> LinkMode {
+    if link_mode == LinkMode::Copy {
+        LinkMode::Hardlink
+    } else {
+        link_mode
+    }
+}
+
+/// Construct a hash for the base interpreter of a cached environment.
+///
+/// Use the canonicalized base interpreter path since that's the interpreter we performed the
+/// resolution with and the interpreter the environment will be created with. The path alone is
+/// insufficient, since the interpreter at that path may be upgraded in place (e.g., from 3.12.3 to
+/// 3.12.8); as such, we also include the interpreter's version, implementation, and pointer size,
+/// such that a changed interpreter gets a new environment rather than reusing a stale one.
+///
+/// We cache environments independent of the environment they'd be layered on top of. The
+/// assumption is such that the environment will _not_ be modified by the user or uv; otherwise,
+/// we risk cache poisoning. For example, if we were to write a `.pth` file to the cached
+/// environment, it would be shared across all projects that use the same interpreter and the same
+/// cached dependencies.
+fn cached_environment_interpreter_hash(interpreter: &Interpreter) -> std::io::Result<String> {
+    Ok(hash_digest(&(
+        canonicalize_executable(interpreter.sys_executable())?,
+        interpreter.python_full_version().to_string(),
+        interpreter.implementation_name(),
+        interpreter.pointer_size().is_64(),
+    )))
+}
+
+/// Compute a digest over the files in a local source tree, based on their paths, sizes, and
+/// modification times.
+///
+/// Respects ignore files and skips hidden files, along with any directories that are written to
+/// when building the package (e.g., `build` or `*.egg-info`), such that building the package
+/// doesn't itself change the digest.
+fn source_tree_digest(root: &Path) -> std::io::Result<String> {
+    let mut builder = ignore::WalkBuilder::new(root);
+    builder.require_git(false).filter_entry(|entry| {
+        if entry.depth() == 0
+            || !entry
+                .file_type()
+                .is_some_and(|file_type| file_type.is_dir())
+        {
+            return true;
+        }
+        let name = entry.file_name().to_string_lossy();
+        if matches!(name.as_ref(), "__pycache__" | "build" | "dist" | "target")
+            || name.ends_with(".egg-info")
+        {
+            return false;
+        }
+        !is_virtualenv_base(entry.path())
+    });
+
+    let mut files: Vec<(PathBuf, u64, Timestamp)> = Vec::new();
+    for entry in builder.build() {
+        let entry = entry.map_err(std::io::Error::other)?;
+        if !entry
+            .file_type()
+            .is_some_and(|file_type| file_type.is_file())
+        {
+            continue;
+        }
+        let metadata = entry.metadata().map_err(std::io::Error::other)?;
+        let path = entry
+            .path()
+            .strip_prefix(root)
+            .unwrap_or(entry.path())
+            .to_path_buf();
+        files.push((path, metadata.len(), Timestamp::from_metadata(&metadata)));
+    }
+    files.sort_unstable();
+
+    Ok(hash_digest(&files))
+}
+
+/// Compute a SHA-256 digest over the contents of a local wheel.
+fn wheel_digest(path: &Path) -> std::io::Result<String> {
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut fs_err::File::open(path)?, &mut hasher)?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
 impl CachedEnvironment {
     /// Get or create an [`CachedEnvironment`] based on a given set of requirements.
     pub(crate) async fn from_spec(
@@ -152,29 +391,63 @@ impl CachedEnvironment {
     ) -> Result<Self, ProjectError> {
         let interpreter = Self::base_interpreter(interpreter, cache)?;
 
-        // Resolve the requirements with the interpreter.
-        let resolution = Resolution::from(
-            resolve_environment(
-                spec,
-                EnvironmentResolution::Specific,
+        // If the same specification was resolved recently, reuse its environment without resolving.
+        let specification_entry = Self::specification_entry(
+            &spec,
+            &build_constraints,
+            &interpreter,
+            python_platform,
+            settings,
+            cache,
+        )?;
+        if let Some(specification_entry) = specification_entry.as_ref()
+            && let Some(environment) = Self::from_specification_entry(
+                specification_entry,
                 &interpreter,
-                python_platform,
-                SourceTreeEditablePolicy::Project,
-                build_constraints.clone(),
-                &settings.resolver,
-                client_builder,
-                state,
-                resolve,
-                concurrency,
                 cache,
-                workspace_cache,
+                resolve.as_ref(),
                 printer,
-                preview,
-            )
-            .await?,
-        );
+            )?
+        {
+            return Ok(environment);
+        }
 
-        Self::from_resolution(
+        // Resolve the requirements with the interpreter.
+        let output = resolve_environment(
+            spec,
+            EnvironmentResolution::Specific,
+            &interpreter,
+            python_platform,
+            SourceTreeEditablePolicy::Project,
+            build_constraints.clone(),
+            &settings.resolver,
+            client_builder,
+            state,
+            resolve,
+            concurrency,
+            cache,
+            workspace_cache,
+            printer,
+            preview,
+        )
+        .await?;
+        let packages = output.len();
+        let resolution = Resolution::from(output);
+
+        // Compute the cache key ahead of time, if the resolution is to be recorded.
+        let resolution_hash = specification_entry
+            .is_some()
+            .then(|| {
+                Self::resolution_hash(
+                    &resolution,
+                    &HashStrategy::default(),
+                    &build_constraints,
+                    settings,
+                )
+            })
+            .transpose()?;
+
+        let environment = Self::from_resolution(
             &resolution,
             HashStrategy::default(),
             build_constraints,
@@ -189,7 +462,182 @@ impl CachedEnvironment {
             printer,
             preview,
         )
-        .await
+        .await?;
+
+        // Record the resolution, such that subsequent requests for the same specification can skip
+        // resolution entirely.
+        if let (Some(specification_entry), Some(resolution_hash)) =
+            (specification_entry, resolution_hash)
+        {
+            let cached = CachedSpecification {
+                resolution_hash,
+                packages,
+            };
+            if let Err(err) = fs_err::create_dir_all(specification_entry.dir()).and_then(|()| {
+                fs_err::write(
+                    specification_entry.path(),
+                    serde_json::to_vec(&cached).map_err(std::io::Error::other)?,
+                )
+            }) {
+                warn!("Failed to cache resolution of environment specification: {err}");
+            }
+        }
+
+        Ok(environment)
+    }
+
+    /// Return the [`CacheEntry`] for the resolution of an [`EnvironmentSpecification`], or `None`
+    /// if the resolution can't be cached.
+    ///
+    /// The resolution is only cached if it depends solely on the registry indexes: specifications
+    /// that include local or direct URL requirements, source trees, or `--find-links` locations are
+    /// always resolved, as are all specifications when the cache is being refreshed or packages are
+    /// being upgraded. The key includes the requirements, preferences, and resolver settings
+    /// (including the index configuration and `exclude-newer`), such that changing any of them
+    /// leads to a distinct entry.
+    fn specification_entry(
+        spec: &EnvironmentSpecification<'_>,
+        build_constraints: &Constraints,
+        interpreter: &Interpreter,
+        python_platform: Option<&TargetTriple>,
+        settings: &ResolverInstallerSettings,
+        cache: &Cache,
+    ) -> Result<Option<CacheEntry>, ProjectError> {
+        // Note that the resolution for a PyTorch backend may depend on the detected accelerator.
+        if !matches!(cache.refresh(), Refresh::None(_))
+            || !settings.resolver.upgrade.is_none()
+            || settings.resolver.torch_backend.is_some()
+        {
+            return Ok(None);
+        }
+
+        let requirements = &spec.requirements;
+        if !requirements.source_trees.is_empty()
+            || !requirements.groups.is_empty()
+            || requirements.pylock.is_some()
+            || !requirements.find_links.is_empty()
+            || !requirements.override_dependencies.is_empty()
+            || settings
+                .resolver
+                .index_locations
+                .flat_indexes()
+                .next()
+                .is_some()
+        {
+            return Ok(None);
+        }
+
+        let is_registry = |requirement: &UnresolvedRequirement| match requirement {
+            UnresolvedRequirement::Named(requirement) => {
+                matches!(requirement.source, RequirementSource::Registry { .. })
+            }
+            UnresolvedRequirement::Unnamed(_) => false,
+        };
+        if !requirements
+            .requirements
+            .iter()
+            .chain(&requirements.overrides)
+            .all(|specification| is_registry(&specification.requirement))
+            || !requirements.constraints.iter().all(|specification| {
+                matches!(
+                    specification.requirement.source,
+                    RequirementSource::Registry { .. }
+                )
+            })
+        {
+            return Ok(None);
+        }
+
+        let preferences = match &spec.preferences {
+            None => None,
+            Some(PreferenceLocation::Lock { lock, install_path }) => {
+                let Ok(lock) = lock.to_toml() else {
+                    return Ok(None);
+                };
+                Some(hash_digest(&(lock, install_path)))
+            }
+            Some(PreferenceLocation::Entries(preferences)) => {
+                Some(hash_digest(&format!("{preferences:?}")))
+            }
+        };
+
+        let specification_hash = hash_digest(&(
+            &requirements.requirements,
+            &requirements.constraints,
+            &requirements.overrides,
+            format!(
+                "{:?}",
+                (
+                    &requirements.excludes,
+                    &requirements.index_url,
+                    &requirements.extra_index_urls,
+                    requirements.no_index,
+                    &requirements.no_binary,
+                    &requirements.no_build,
+                    &requirements.remote_files,
+                )
+            ),
+            preferences,
+            cached_environment_build_constraints(build_constraints),
+            python_platform.map(|platform| format!("{platform:?}")),
+            format!("{:?}", settings.resolver),
+            settings.compile_bytecode,
+        ));
+
+        Ok(Some(cache.entry(
+            CacheBucket::Environments,
+            cached_environment_interpreter_hash(interpreter)?,
+            format!("{specification_hash}.spec"),
+        )))
+    }
+
+    /// Return the [`CachedEnvironment`] for a cached resolution of an [`EnvironmentSpecification`],
+    /// if the resolution is fresh and its environment still exists.
+    fn from_specification_entry(
+        specification_entry: &CacheEntry,
+        interpreter: &Interpreter,
+        cache: &Cache,
+        resolve: &dyn ResolveLogger,
+        printer: Printer,
+    ) -> Result<Option<Self>, ProjectError> {
+        let start = std::time::Instant::now();
+
+        let Ok(modified) =
+            fs_err::metadata(specification_entry.path()).and_then(|metadata| metadata.modified())
+        else {
+            return Ok(None);
+        };
+        if modified
+            .elapsed()
+            .is_ok_and(|age| age >= CACHED_SPECIFICATION_TTL)
+        {
+            return Ok(None);
+        }
+
+        let Some(cached) = fs_err::read(specification_entry.path())
+            .ok()
+            .and_then(|data| serde_json::from_slice::<CachedSpecification>(&data).ok())
+        else {
+            return Ok(None);
+        };
+
+        let cache_entry = cache.entry(
+            CacheBucket::Environments,
+            cached_environment_interpreter_hash(interpreter)?,
+            &cached.resolution_hash,
+        );
+        let Some(environment) = Self::from_cache_entry(&cache_entry, interpreter, cache) else {
+            return Ok(None);
+        };
+
+        debug!(
+            "Using cached resolution for environment specification: {}",
+            specification_entry.path().display()
+        );
+        resolve.on_complete(cached.packages, start, printer)?;
+        Self::touch(&cache_entry);
+
+        Ok(Some(Self(environment)))
     }
 
     /// Get or create a [`CachedEnvironment`] from a lock-backed [`Resolution`].
@@ -248,61 +696,68 @@ impl CachedEnvironment {
         printer: Printer,
         preview: Preview,
     ) -> Result<Self, ProjectError> {
-        // Hash the resolution by hashing the generated lockfile.
-        let resolution_hash = {
-            let mut distributions = resolution
-                .graph()
-                .node_weights()
-                .filter_map(|node| match node {
-                    Node::Dist {
-                        dist,
-                        hashes,
-                        install: true,
-                    } => Some((dist, hashes)),
-                    Node::Dist { install: false, .. } | Node::Root => None,
-                })
-                .map(|(dist, hashes)| {
-                    Ok(CachedEnvironmentDist {
-                        dist: dist.clone(),
-                        hashes: hashes.clone(),
-                        cache_info: Self::cache_info(dist).map_err(ProjectError::from)?,
-                    })
-                })
-                .collect::<Result<Vec<_>, ProjectError>>()?;
-            distributions.sort_unstable_by(|left, right| {
-                left.dist
-                    .distribution_id()
-                    .cmp(&right.dist.distribution_id())
-            });
-            cached_environment_resolution_hash(hash_digest(&distributions), &hash_strategy)
-        };
-
-        // Construct a hash for the environment.
-        //
-        // Use the canonicalized base interpreter path since that's the interpreter we performed the
-        // resolution with and the interpreter the environment will be created with.
-        //
-        // We cache environments independent of the environment they'd be layered on top of. The
-        // assumption is such that the environment will _not_ be modified by the user or uv;
-        // otherwise, we risk cache poisoning. For example, if we were to write a `.pth` file to
-        // the cached environment, it would be shared across all projects that use the same
-        // interpreter and the same cached dependencies.
-        //
-        // TODO(zanieb): We should include the version of the base interpreter in the hash, so if
-        // the interpreter at the canonicalized path changes versions we construct a new
-        // environment.
-        let interpreter_hash =
-            cache_digest(&canonicalize_executable(interpreter.sys_executable())?);
+        let resolution_hash =
+            Self::resolution_hash(resolution, &hash_strategy, &build_constraints, settings)?;
 
         // Search in the content-addressed cache.
-        let cache_entry = cache.entry(CacheBucket::Environments, interpreter_hash, resolution_hash);
+        let cache_entry = cache.entry(
+            CacheBucket::Environments,
+            cached_environment_interpreter_hash(interpreter)?,
+            &resolution_hash,
+        );
 
-        if let Ok(root) = cache.resolve_link(cache_entry.path()) {
-            if let Ok(environment) = PythonEnvironment::from_root(root, cache) {
-                return Ok(Self(environment));
-            }
+        // If the cache is being refreshed, rebuild any environment that predates the refresh,
+        // replacing it at the same cache key.
+        if Self::is_stale(resolution, &cache_entry, cache)? {
+            debug!(
+                "Rebuilding cached environment due to refresh: {}",
+                cache_entry.path().display()
+            );
+        } else if let Some(environment) = Self::from_cache_entry(&cache_entry, interpreter, cache) {
+            Self::touch(&cache_entry);
+            return Ok(Self(environment));
         }
 
+        // Avoid building the same environment in multiple processes at once. If another process
+        // holds the lock, wait for it, then check whether it created the environment. The lock is
+        // best-effort: if it can't be acquired (e.g., on timeout), build the environment anyway.
+        let _lock = cache_entry
+            .with_file(format!("{resolution_hash}.lock"))
+            .lock_for("building the environment")
+            .await
+            .inspect_err(|err| {
+                warn!("Failed to acquire cached environment lock: {err}");
+            })
+            .ok();
+
+        if !Self::is_stale(resolution, &cache_entry, cache)?
+            && let Some(environment) = Self::from_cache_entry(&cache_entry, interpreter, cache)
+        {
+            debug!("Using environment created by another uv process");
+            Self::touch(&cache_entry);
+            return Ok(Self(environment));
+        }
+
+        // Rebuild any local source trees, rather than reusing a wheel built from a previous version
+        // of their sources.
+        let source_trees = Self::source_trees(resolution);
+        let cache = if source_trees.is_empty() {
+            Cow::Borrowed(cache)
+        } else {
+            let (packages, paths) = source_trees
+                .into_iter()
+                .map(|(name, path)| (name.clone(), Box::from(path)))
+                .unzip();
+            Cow::Owned(cache.clone().with_refresh(
+                cache.refresh().clone().combine(Refresh::Packages(
+                    packages,
+                    paths,
+                    Timestamp::now(),
+                )),
+            ))
+        };
+        let cache: &Cache = &cache;
+
         // Create the environment in the cache, then relocate it to its content-addressed location.
         let temp_dir = cache.venv_dir()?;
         let venv = uv_virtualenv::create_venv(
@@ -316,13 +771,20 @@ impl CachedEnvironment {
             false,
         )?;
 
+        let mut installer_settings = InstallerSettingsRef::from(settings);
+        let link_mode = cached_environment_link_mode(installer_settings.link_mode);
+        if link_mode != installer_settings.link_mode {
+            debug!("Using hard links rather than copies for cached environment");
+            installer_settings.link_mode = link_mode;
+        }
+
         sync_environment(
             venv,
             resolution,
             hash_strategy,
             Modifications::Exact,
             build_constraints,
-            settings.into(),
+            installer_settings,
             client_builder,
             state,
             install,
@@ -334,13 +796,208 @@ impl CachedEnvironment {
         )
         .await?;
 
+        // Mark the environment as complete, such that an interrupted build is never reused.
+        fs_err::write(temp_dir.path().join(CACHED_ENVIRONMENT_READY), "")?;
+
         // Now that the environment is complete, sync it to its content-addressed location.
         let id = cache.persist(temp_dir.keep(), cache_entry.path()).await?;
         let root = cache.archive(&id);
+        Self::touch(&cache_entry);
 
         Ok(Self(PythonEnvironment::from_root(root, cache)?))
     }
 
+    /// Return the cached environment linked from the given [`CacheEntry`], if it's intact.
+    ///
+    /// An environment is intact if it was fully synced, its base interpreter still exists at the
+    /// expected location, and its `site-packages` directory is present. Otherwise (e.g., if the
+    /// base interpreter was uninstalled), the link is discarded, such that the environment is
+    /// rebuilt in its place.
+    fn from_cache_entry(
+        cache_entry: &CacheEntry,
+        interpreter: &Interpreter,
+        cache: &Cache,
+    ) -> Option<PythonEnvironment> {
+        let root = cache.resolve_link(cache_entry.path()).ok()?;
+        let environment = Self::validate(&root, interpreter, cache);
+        if environment.is_none()
+            && let Err(err) = fs_err::remove_file(cache_entry.path())
+        {
+            warn!("Failed to discard cached environment: {err}");
+        }
+        environment
+    }
+
+    /// Validate the cached environment at the given root, returning it if it's intact.
+    fn validate(
+        root: &Path,
+        interpreter: &Interpreter,
+        cache: &Cache,
+    ) -> Option<PythonEnvironment> {
+        if !root.join(CACHED_ENVIRONMENT_READY).is_file() {
+            debug!(
+                "Cached environment was not fully synced: {}",
+                root.display()
+            );
+            return None;
+        }
+
+        let environment = match PythonEnvironment::from_root(root, cache) {
+            Ok(environment) => environment,
+            Err(err) => {
+                debug!("Cached environment is invalid: {}: {err}", root.display());
+                return None;
+            }
+        };
+
+        // Ensure that the environment's base interpreter is the one it was created from.
+        let base_python = if cfg!(unix) {
+            environment.interpreter().find_base_python()
+        } else {
+            environment.interpreter().to_base_python()
+        };
+        let base_python = base_python.and_then(canonicalize_executable);
+        let expected = canonicalize_executable(interpreter.sys_executable());
+        match (base_python, expected) {
+            (Ok(base_python), Ok(expected)) if base_python == expected => {}
+            (Ok(_), Ok(_)) => {
+                debug!(
+                    "Cached environment was created from a different interpreter: {}",
+                    root.display()
+                );
+                return None;
+            }
+            (Err(err), _) | (_, Err(err)) => {
+                debug!(
+                    "Base interpreter of cached environment is missing: {}: {err}",
+                    root.display()
+                );
+                return None;
+            }
+        }
+
+        if let Some(site_packages) = environment
+            .site_packages()
+            .find(|site_packages| !site_packages.is_dir())
+        {
+            debug!(
+                "Cached environment is missing `site-packages`: {}",
+                site_packages.display()
+            );
+            return None;
+        }
+
+        Some(environment)
+    }
+
+    /// Compute the hash of a [`Resolution`], used as the key for its cached environment.
+    fn resolution_hash(
+        resolution: &Resolution,
+        hash_strategy: &HashStrategy,
+        build_constraints: &Constraints,
+        settings: &ResolverInstallerSettings,
+    ) -> Result<String, ProjectError> {
+        // Hash the resolution by hashing the generated lockfile.
+        let resolution_hash = {
+            let mut distributions = resolution
+                .graph()
+                .node_weights()
+                .filter_map(|node| match node {
+                    Node::Dist {
+                        dist,
+                        hashes,
+                        install: true,
+                    } => Some((dist, hashes)),
+                    Node::Dist { install: false, .. } | Node::Root => None,
+                })
+                .map(|(dist, hashes)| {
+                    Ok(CachedEnvironmentDist {
+                        dist: dist.clone(),
+                        hashes: hashes.clone(),
+                        cache_info: Self::cache_info(dist).map_err(ProjectError::from)?,
+                    })
+                })
+                .collect::<Result<Vec<_>, ProjectError>>()?;
+            distributions.sort_unstable_by(|left, right| {
+                left.dist
+                    .distribution_id()
+                    .cmp(&right.dist.distribution_id())
+            });
+            cached_environment_resolution_hash(hash_digest(&distributions), hash_strategy)
+        };
+
+        // Include the settings that affect the installed files, such that environments built with
+        // different settings aren't shared.
+        let resolution_hash = match cached_environment_settings_hash(settings, build_constraints) {
+            Some(settings_hash) => hash_digest(&(resolution_hash, settings_hash)),
+            None => resolution_hash,
+        };
+
+        // Local, non-editable source trees are built into the environment, so edits to their
+        // sources aren't reflected in the resolution itself. Include a digest over each source tree
+        // in the hash, such that an edited package gets a new environment. (Resolutions without any
+        // local source trees retain their existing hash.)
+        let source_trees = Self::source_trees(resolution);
+        let resolution_hash = if source_trees.is_empty() {
+            resolution_hash
+        } else {
+            let digests = source_trees
+                .iter()
+                .map(|(_, path)| source_tree_digest(path))
+                .collect::<Result<Vec<_>, _>>()?;
+            hash_digest(&(resolution_hash, digests))
+        };
+
+        // Local wheels can be rebuilt in place under the same filename (e.g., with
+        // `uvx ./tool-1.0-py3-none-any.whl`), so include a digest over the contents of each wheel
+        // in the hash, such that a rebuilt wheel gets a new environment.
+        let wheels = Self::local_wheels(resolution);
+        if wheels.is_empty() {
+            Ok(resolution_hash)
+        } else {
+            let digests = wheels
+                .into_iter()
+                .map(wheel_digest)
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(hash_digest(&(resolution_hash, digests)))
+        }
+    }
+
+    /// Returns `true` if the cached environment was created before the cache's [`Refresh`] policy
+    /// took effect, for any of the distributions in the [`Resolution`].
+    fn is_stale(
+        resolution: &Resolution,
+        cache_entry: &CacheEntry,
+        cache: &Cache,
+    ) -> std::io::Result<bool> {
+        match cache.refresh() {
+            Refresh::None(_) => Ok(false),
+            Refresh::All(_) => Ok(matches!(
+                cache.freshness(cache_entry, None, None)?,
+                Freshness::Stale
+            )),
+            Refresh::Packages(..) | Refresh::Scoped(..) => {
+                for dist in resolution.distributions() {
+                    if matches!(
+                        cache.freshness(cache_entry, Some(dist.name()), None)?,
+                        Freshness::Stale
+                    ) {
+                        return Ok(true);
+                    }
+                }
+                Ok(false)
+            }
+        }
+    }
+
+    /// Record that the cached environment was used, such that `uv cache prune --older-than` retains
+    /// it.
+    fn touch(cache_entry: &CacheEntry) {
+        if let Err(err) = cache_entry.touch() {
+            warn!("Failed to record use of cached environment: {err}");
+        }
+    }
+
     /// Return any mutable cache info that should invalidate a cached environment for a given
     /// distribution.
     fn cache_info(dist: &ResolvedDist) -> Result<Option<CacheInfo>, uv_cache_info::CacheInfoError> {
@@ -357,6 +1014,42 @@ impl CachedEnvironment {
         Ok(Some(CacheInfo::from_path(path)?))
     }
 
+    /// Return the local, non-editable source trees in a [`Resolution`].
+    ///
+    /// Editable and virtual packages are excluded, since their sources aren't copied into the
+    /// environment.
+    fn source_trees(resolution: &Resolution) -> Vec<(&PackageName, &Path)> {
+        resolution
+            .distributions()
+            .filter_map(|dist| match dist {
+                ResolvedDist::Installable { dist, .. } => match dist.as_ref() {
+                    Dist::Source(SourceDist::Directory(directory))
+                        if !directory.editable.unwrap_or(false)
+                            && !directory.r#virtual.unwrap_or(false) =>
+                    {
+                        Some((&directory.name, directory.install_path.as_ref()))
+                    }
+                    _ => None,
+                },
+                ResolvedDist::Installed { .. } => None,
+            })
+            .collect()
+    }
+
+    /// Return the paths of the local wheels in a [`Resolution`].
+    fn local_wheels(resolution: &Resolution) -> Vec<&Path> {
+        resolution
+            .distributions()
+            .filter_map(|dist| match dist {
+                ResolvedDist::Installable { dist, .. } => match dist.as_ref() {
+                    Dist::Built(BuiltDist::Path(wheel)) => Some(wheel.install_path.as_ref()),
+                    _ => None,
+                },
+                ResolvedDist::Installed { .. } => None,
+            })
+            .collect()
+    }
+
     /// Return the [`Interpreter`] to use for the cached environment, based on a given
     /// [`Interpreter`].
     ///
@@ -392,9 +1085,15 @@ impl CachedEnvironment {
 mod tests {
     use std::sync::Arc;
 
+    #[cfg(unix)]
+    use uv_cache::{Cache, CacheBucket};
+    #[cfg(unix)]
+    use uv_python::Interpreter;
     use uv_types::HashStrategy;
 
-    use super::{cached_environment_resolution_hash, hash_digest};
+    use super::{
+        cached_environment_interpreter_hash, cached_environment_resolution_hash, hash_digest,
+    };
 
     #[test]
     fn verified_cached_environment_uses_separate_resolution_hash() {
@@ -409,4 +1108,103 @@ mod tests {
         assert_eq!(unverified, resolution_hash);
         assert_ne!(verified, unverified);
     }
+
+    /// Write a fake Python interpreter that reports the given version when queried.
+    #[cfg(unix)]
+    fn write_mock_interpreter(path: &std::path::Path, full_version: &str) {
+        let json = indoc::indoc! {r##"
+            {
+                "result": "success",
+                "platform": {
+                    "os": {
+                        "name": "manylinux",
+                        "major": 2,
+                        "minor": 38
+                    },
+                    "arch": "x86_64"
+                },
+                "manylinux_compatible": false,
+                "standalone": false,
+                "markers": {
+                    "implementation_name": "cpython",
+                    "implementation_version": "{FULL_VERSION}",
+                    "os_name": "posix",
+                    "platform_machine": "x86_64",
+                    "platform_python_implementation": "CPython",
+                    "platform_release": "6.5.0-13-generic",
+                    "platform_system": "Linux",
+                    "platform_version": "#13-Ubuntu SMP PREEMPT_DYNAMIC Fri Nov  3 12:16:05 UTC 2023",
+                    "python_full_version": "{FULL_VERSION}",
+                    "python_version": "3.12",
+                    "sys_platform": "linux"
+                },
+                "sys_base_exec_prefix": "/home/ferris/.pyenv/versions/3.12",
+                "sys_base_prefix": "/home/ferris/.pyenv/versions/3.12",
+                "sys_prefix": "/home/ferris/.pyenv/versions/3.12",
+                "sys_executable": "{PATH}",
+                "sys_path": [
+                    "/home/ferris/.pyenv/versions/3.12/lib/python3.12",
+                    "/home/ferris/.pyenv/versions/3.12/lib/python3.12/site-packages"
+                ],
+                "site_packages": [
+                    "/home/ferris/.pyenv/versions/3.12/lib/python3.12/site-packages"
+                ],
+                "stdlib": "/home/ferris/.pyenv/versions/3.12/lib/python3.12",
+                "extension_suffixes": [".cpython-312-x86_64-linux-gnu.so", ".abi3.so", ".so"],
+                "scheme": {
+                    "data": "/home/ferris/.pyenv/versions/3.12",
+                    "include": "/home/ferris/.pyenv/versions/3.12/include",
+                    "platlib": "/home/ferris/.pyenv/versions/3.12/lib/python3.12/site-packages",
+                    "purelib": "/home/ferris/.pyenv/versions/3.12/lib/python3.12/site-packages",
+                    "scripts": "/home/ferris/.pyenv/versions/3.12/bin"
+                },
+                "virtualenv": {
+                    "data": "",
+                    "include": "include",
+                    "platlib": "lib/python3.12/site-packages",
+                    "purelib": "lib/python3.12/site-packages",
+                    "scripts": "bin"
+                },
+                "pointer_size": "64",
+                "gil_disabled": false,
+                "debug_enabled": false
+            }
+        "##}
+        .replace("{FULL_VERSION}", full_version)
+        .replace("{PATH}", path.to_str().unwrap());
+
+        fs_err::write(path, format!("#!/bin/sh\necho '{json}'\n")).unwrap();
+        fs_err::set_permissions(path, std::os::unix::fs::PermissionsExt::from_mode(0o770)).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn cached_environment_changes_with_interpreter_version() {
+        let cache = Cache::temp().unwrap().init().await.unwrap();
+        let temp_dir = tempfile::tempdir().unwrap();
+        let python = fs_err::canonicalize(temp_dir.path())
+            .unwrap()
+            .join("python");
+        let resolution_hash = hash_digest(&["ty==0.0.17"]);
+
+        write_mock_interpreter(&python, "3.12.3");
+        let interpreter = Interpreter::query(&python, &cache).unwrap();
+        let stale = cache.entry(
+            CacheBucket::Environments,
+            cached_environment_interpreter_hash(&interpreter).unwrap(),
+            &resolution_hash,
+        );
+
+        // Upgrading the interpreter in-place should lead to a distinct cache entry.
+        write_mock_interpreter(&python, "3.12.8");
+        let interpreter = Interpreter::query(&python, &cache).unwrap();
+        assert_eq!(interpreter.python_full_version().to_string(), "3.12.8");
+        let fresh = cache.entry(
+            CacheBucket::Environments,
+            cached_environment_interpreter_hash(&interpreter).unwrap(),
+            &resolution_hash,
+        );
+
+        assert_ne!(stale.path(), fresh.path());
+    }
 }
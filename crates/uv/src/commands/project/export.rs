@@ -12,20 +12,26 @@ use rustc_hash::FxHashSet;
 use uv_cache::Cache;
 use uv_client::BaseClientBuilder;
 use uv_configuration::{
-    Concurrency, DependencyGroups, EditableMode, ExportFormat, ExtrasSpecification, InstallOptions,
+    Concurrency, DependencyGroups, DependencyGroupsWithDefaults, EditableMode, ExportFormat,
+    ExtrasSpecification, InstallOptions, TargetTriple,
 };
 use uv_distribution_types::Verbatim;
 use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
-use uv_preview::Preview;
+use uv_preview::{Preview, PreviewFeature};
 use uv_python::{ConfigDiscovery, PythonDownloads, PythonPreference, PythonRequest};
 use uv_requirements::is_pylock_toml;
-use uv_resolver::{PylockToml, RequirementsTxtExport, cyclonedx_json};
+use uv_resolver::{
+    Installable, PylockToml, PylockTomlBundleTarget, RequirementsTxtExport, cyclonedx_json,
+    validate_prune,
+};
 use uv_scripts::Pep723Script;
 use uv_settings::PythonInstallMirrors;
 use uv_warnings::warn_user;
 use uv_workspace::{DiscoveryOptions, MemberDiscovery, VirtualProject, WorkspaceCache};
 
 use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::pip::{resolution_markers, resolution_tags};
+use crate::commands::project::bundle::write_bundle;
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::{LockMode, LockOperation};
 use crate::commands::project::lock_target::LockTarget;
@@ -64,14 +70,21 @@ pub(crate) async fn export(
     all_packages: bool,
     package: Vec<PackageName>,
     prune: Vec<PackageName>,
+    allow_prune_root: bool,
     hashes: bool,
     install_options: InstallOptions,
     output_file: Option<PathBuf>,
+    relative_to: Option<PathBuf>,
+    bundle: Option<PathBuf>,
+    python_platforms: Vec<TargetTriple>,
+    split_output_dir: Option<PathBuf>,
     extras: ExtrasSpecification,
     groups: DependencyGroups,
     editable: Option<EditableMode>,
     lock_check: LockCheck,
     frozen: Option<FrozenSource>,
+    lockfile: Option<PathBuf>,
+    no_validate: bool,
     include_annotations: bool,
     include_header: bool,
     include_index_url: bool,
@@ -95,7 +108,7 @@ pub(crate) async fn export(
     let target = if let Some(script) = script {
         ExportTarget::Script(script)
     } else {
-        let project = if frozen.is_some() {
+        let project = if frozen.is_some() || lockfile.is_some() {
             VirtualProject::discover(
                 project_dir,
                 &DiscoveryOptions {
@@ -150,8 +163,9 @@ pub(crate) async fn export(
     let groups = groups.with_defaults(default_groups);
     let extras = extras.with_defaults(default_extras);
 
-    // Find an interpreter for the project, unless `--frozen` is set.
-    let interpreter = if frozen.is_some() {
+    // Find an interpreter for the project, unless `--frozen` or `--lockfile` is set. A bundle
+    // always requires an interpreter, to determine the compatible wheels.
+    let interpreter = if (frozen.is_some() || lockfile.is_some()) && bundle.is_none() {
         None
     } else {
         Some(match &target {
@@ -199,7 +213,12 @@ pub(crate) async fn export(
     };
 
     // Determine the lock mode.
-    let mode = if let Some(frozen_source) = frozen {
+    let mode = if let Some(path) = lockfile.as_deref() {
+        LockMode::Lockfile {
+            path,
+            validate: !no_validate,
+        }
+    } else if let Some(frozen_source) = frozen {
         LockMode::Frozen(frozen_source.into())
     } else if let LockCheck::Enabled(lock_check) = lock_check {
         LockMode::Locked(interpreter.as_ref().unwrap(), lock_check)
@@ -303,10 +322,150 @@ pub(crate) async fn export(
         },
     };
 
+    // Export any configured non-editable members without `-e`.
+    let editable = EditableMode::with_no_editable_packages(editable, target.no_editable_packages());
+
     // Validate that the set of requested extras and development groups are defined in the lockfile.
     target.validate_extras(&extras)?;
     target.validate_groups(&groups)?;
 
+    // Validate that none of the pruned packages are direct dependencies of the project.
+    if preview.is_enabled(PreviewFeature::ExportPruneRootCheck) && !allow_prune_root {
+        validate_prune(&target, &prune, &extras, &groups)?;
+    }
+
+    // If requested, write a bundle of the lockfile and its artifacts.
+    if let Some(bundle) = bundle {
+        detect_conflicts(&target, &extras, &groups)?;
+
+        let lock = PylockToml::from_lock(
+            &target,
+            &prune,
+            &extras,
+            &groups,
+            include_annotations,
+            editable.as_ref(),
+            &install_options,
+        )?;
+
+        let interpreter = interpreter
+            .as_ref()
+            .ok_or_else(|| anyhow!("A Python interpreter is required to export a bundle"))?;
+        let mut environments = Vec::new();
+        if python_platforms.is_empty() {
+            environments.push((
+                "the current platform".to_string(),
+                resolution_markers(None, None, interpreter),
+                resolution_tags(None, None, interpreter)?,
+            ));
+        } else {
+            for python_platform in &python_platforms {
+                let name = python_platform
+                    .to_possible_value()
+                    .map(|value| format!("`{}`", value.get_name()))
+                    .unwrap_or_else(|| format!("{python_platform:?}"));
+                environments.push((
+                    name,
+                    resolution_markers(None, Some(python_platform), interpreter),
+                    resolution_tags(None, Some(python_platform), interpreter)?,
+                ));
+            }
+        }
+        let targets = environments
+            .iter()
+            .map(|(name, markers, tags)| PylockTomlBundleTarget {
+                name,
+                markers: markers.markers(),
+                tags,
+            })
+            .collect::<Vec<_>>();
+
+        write_bundle(
+            lock,
+            target.install_path(),
+            &targets,
+            &bundle,
+            &client_builder,
+            printer,
+        )
+        .await?;
+
+        return Ok(ExitStatus::Success);
+    }
+
+    // If requested, write a separate `requirements.txt` file for each dependency group.
+    if let Some(output_dir) = split_output_dir {
+        if format.is_some_and(|format| !matches!(format, ExportFormat::RequirementsTxt)) {
+            return Err(anyhow!(
+                "`{}` is only supported for the `requirements.txt` format",
+                "--split-per-group".green()
+            ));
+        }
+
+        detect_conflicts(&target, &extras, &groups)?;
+
+        // Write any relative paths against the output directory, unless overridden.
+        let relative_to = match relative_to {
+            Some(relative_to) => std::path::absolute(relative_to)?,
+            None => std::path::absolute(&output_dir)?,
+        };
+
+        // Write the project's dependencies, excluding any dependency groups, to the base file.
+        let base = RequirementsTxtExport::from_lock(
+            &target,
+            &prune,
+            &extras,
+            &DependencyGroupsWithDefaults::none(),
+            include_annotations,
+            editable.clone(),
+            hashes,
+            &install_options,
+        )?
+        .with_relative_to(target.install_path(), &relative_to);
+
+        let base_file = output_dir.join("requirements.txt");
+        let mut writer = OutputWriter::new(false, Some(&base_file));
+        write_requirements_txt_preamble(
+            &mut writer,
+            &settings,
+            include_header,
+            include_index_url,
+            include_find_links,
+        )?;
+        write!(writer, "{base}")?;
+        writer.commit().await?;
+
+        // Write the packages added by each selected group, relative to the base file.
+        for group in target
+            .group_names()
+            .into_iter()
+            .filter(|group| groups.contains(group))
+        {
+            let export = RequirementsTxtExport::from_lock(
+                &target,
+                &prune,
+                &extras,
+                &DependencyGroups::from_group(group.clone())
+                    .with_defaults(DefaultGroups::default()),
+                include_annotations,
+                editable.clone(),
+                hashes,
+                &install_options,
+            )?
+            .with_relative_to(target.install_path(), &relative_to)
+            .difference(&base);
+
+            let group_file = output_dir.join(format!("requirements-{group}.txt"));
+            let mut writer = OutputWriter::new(false, Some(&group_file));
+            write_requirements_txt_preamble(&mut writer, &settings, include_header, false, false)?;
+            writeln!(writer, "-r requirements.txt")?;
+            write!(writer, "{export}")?;
+            writer.commit().await?;
+        }
+
+        return Ok(ExitStatus::Success);
+    }
+
     if output_file
         .as_deref()
         .and_then(Path::file_name)
@@ -369,7 +528,7 @@ pub(crate) async fn export(
     // Generate the export.
     match format {
         ExportFormat::RequirementsTxt => {
-            let export = RequirementsTxtExport::from_lock(
+            let mut export = RequirementsTxtExport::from_lock(
                 &target,
                 &prune,
                 &extras,
@@ -380,58 +539,26 @@ pub(crate) async fn export(
                 &install_options,
             )?;
 
-            if include_header {
-                writeln!(
-                    writer,
-                    "{}",
-                    "# This file was autogenerated by uv via the following command:".green()
-                )?;
-                writeln!(writer, "{}", format!("#    {}", cmd()).green())?;
-            }
-
-            let mut wrote_preamble = false;
-
-            // If necessary, include the `--index-url` and `--extra-index-url` locations.
-            if include_index_url {
-                let mut seen = FxHashSet::default();
-                let mut emitted_explicit_index = false;
-
-                if let Some(index) = settings.index_locations.default_index() {
-                    writeln!(writer, "--index-url {}", index.url().verbatim())?;
-                    seen.insert(index.url());
-                    wrote_preamble = true;
-                    emitted_explicit_index |= index.explicit;
-                }
-                for index in settings
-                    .index_locations
-                    .implicit_indexes()
-                    .chain(settings.index_locations.explicit_indexes())
-                {
-                    if seen.insert(index.url()) {
-                        writeln!(writer, "--extra-index-url {}", index.url().verbatim())?;
-                        wrote_preamble = true;
-                    }
-                    emitted_explicit_index |= index.explicit;
-                }
-
-                if emitted_explicit_index {
-                    warn_user!(
-                        "`requirements.txt` does not support per-package index pinning; explicit indexes were emitted globally via `--extra-index-url`."
-                    );
-                }
-            }
-
-            // If necessary, include the `--find-links` locations.
-            if include_find_links {
-                for flat_index in settings.index_locations.flat_indexes() {
-                    writeln!(writer, "--find-links {}", flat_index.url().verbatim())?;
-                    wrote_preamble = true;
-                }
+            // Write any relative paths against the directory containing the output file, unless
+            // overridden. When writing to stdout, paths remain relative to the workspace root.
+            let relative_to = match (relative_to, output_file.as_deref()) {
+                (Some(relative_to), _) => Some(std::path::absolute(relative_to)?),
+                (None, Some(output_file)) => std::path::absolute(output_file)?
+                    .parent()
+                    .map(Path::to_path_buf),
+                (None, None) => None,
+            };
+            if let Some(relative_to) = relative_to {
+                export = export.with_relative_to(target.install_path(), &relative_to);
             }
 
-            if wrote_preamble {
-                writeln!(writer)?;
-            }
+            write_requirements_txt_preamble(
+                &mut writer,
+                &settings,
+                include_header,
+                include_index_url,
+                include_find_links,
+            )?;
 
             write!(writer, "{export}")?;
         }
@@ -477,6 +604,70 @@ pub(crate) async fn export(
     Ok(ExitStatus::Success)
 }
 
+/// Write the header and any index locations at the top of a `requirements.txt` file.
+fn write_requirements_txt_preamble(
+    writer: &mut impl Write,
+    settings: &ResolverSettings,
+    include_header: bool,
+    include_index_url: bool,
+    include_find_links: bool,
+) -> Result<()> {
+    if include_header {
+        writeln!(
+            writer,
+            "{}",
+            "# This file was autogenerated by uv via the following command:".green()
+        )?;
+        writeln!(writer, "{}", format!("#    {}", cmd()).green())?;
+    }
+
+    let mut wrote_preamble = false;
+
+    // If necessary, include the `--index-url` and `--extra-index-url` locations.
+    if include_index_url {
+        let mut seen = FxHashSet::default();
+        let mut emitted_explicit_index = false;
+
+        if let Some(index) = settings.index_locations.default_index() {
+            writeln!(writer, "--index-url {}", index.url().verbatim())?;
+            seen.insert(index.url());
+            wrote_preamble = true;
+            emitted_explicit_index |= index.explicit;
+        }
+        for index in settings
+            .index_locations
+            .implicit_indexes()
+            .chain(settings.index_locations.explicit_indexes())
+        {
+            if seen.insert(index.url()) {
+                writeln!(writer, "--extra-index-url {}", index.url().verbatim())?;
+                wrote_preamble = true;
+            }
+            emitted_explicit_index |= index.explicit;
+        }
+
+        if emitted_explicit_index {
+            warn_user!(
+                "`requirements.txt` does not support per-package index pinning; explicit indexes were emitted globally via `--extra-index-url`."
+            );
+        }
+    }
+
+    // If necessary, include the `--find-links` locations.
+    if include_find_links {
+        for flat_index in settings.index_locations.flat_indexes() {
+            writeln!(writer, "--find-links {}", flat_index.url().verbatim())?;
+            wrote_preamble = true;
+        }
+    }
+
+    if wrote_preamble {
+        writeln!(writer)?;
+    }
+
+    Ok(())
+}
+
 /// Format the uv command used to generate the output file.
 fn cmd() -> String {
     let args = env::args_os()
@@ -0,0 +1,281 @@
+//! Pin the index metadata for locked registry packages, and verify it on subsequent operations.
+
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+
+use futures::StreamExt;
+use sha2::{Digest, Sha256};
+use tracing::debug;
+
+use uv_client::{MetadataFormat, RegistryClient, VersionFiles};
+use uv_configuration::Concurrency;
+use uv_distribution_filename::DistFilename;
+use uv_distribution_types::{File, IndexCapabilities, IndexMetadataRef, IndexUrl};
+use uv_pep440::Version;
+use uv_pypi_types::{HashAlgorithm, HashDigest};
+use uv_resolver::{Lock, Package};
+use uv_warnings::warn_user;
+
+use crate::commands::project::ProjectError;
+use crate::settings::LockCheckSource;
+
+/// A locked distribution that no longer matches the metadata served by its index.
+#[derive(Debug)]
+pub(crate) enum IndexMetadataMismatch {
+    /// The locked distribution is no longer served by the index.
+    Removed { package: String, filename: String },
+    /// The locked distribution is served with a different hash.
+    Altered {
+        package: String,
+        filename: String,
+        hash: HashDigest,
+    },
+}
+
+impl Display for IndexMetadataMismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Removed { package, filename } => {
+                write!(f, "`{package}`: `{filename}` was removed from the index")
+            }
+            Self::Altered {
+                package,
+                filename,
+                hash,
+            } => {
+                write!(
+                    f,
+                    "`{package}`: `{filename}` is no longer served with hash `{hash}`"
+                )
+            }
+        }
+    }
+}
+
+/// The locked distributions that no longer match the metadata served by their index.
+#[derive(Debug)]
+pub(crate) struct IndexMetadataMismatches(pub(crate) Vec<IndexMetadataMismatch>);
+
+impl Display for IndexMetadataMismatches {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut first = true;
+        for mismatch in &self.0 {
+            if !first {
+                writeln!(f)?;
+            }
+            first = false;
+            write!(f, "  - {mismatch}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Record a digest of the index metadata for the registry packages in the lock.
+///
+/// If `repin` is `false`, only packages without an existing digest are pinned.
+pub(crate) async fn pin_index_metadata(
+    lock: Lock,
+    root: &Path,
+    repin: bool,
+    client: &RegistryClient,
+    capabilities: &IndexCapabilities,
+    concurrency: &Concurrency,
+) -> Result<Lock, ProjectError> {
+    let digests = futures::stream::iter(lock.packages())
+        .map(async |package| {
+            if !repin && package.index_digest().is_some() {
+                return Ok(None);
+            }
+            let Some(files) = fetch_files(package, root, client, capabilities, concurrency).await?
+            else {
+                return Ok(None);
+            };
+            let digest = index_digest(&files);
+            if let Some(previous) = package.index_digest()
+                && *previous != digest
+            {
+                warn_user!(
+                    "Re-pinned the index metadata for `{}`, which changed since it was last pinned",
+                    display_package(package)
+                );
+            }
+            Ok::<_, ProjectError>(Some(digest))
+        })
+        .buffered(concurrency.downloads)
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(lock.with_index_digests(digests))
+}
+
+/// Verify that the index metadata for the pinned packages in the lock still contains the locked
+/// distributions with identical hashes.
+///
+/// The index metadata is fetched with the client's connectivity: when offline, the cached metadata
+/// is used, and verification fails for any package whose metadata is not in the cache.
+pub(crate) async fn verify_index_metadata(
+    lock: &Lock,
+    root: &Path,
+    lock_source: LockCheckSource,
+    client: &RegistryClient,
+    capabilities: &IndexCapabilities,
+    concurrency: &Concurrency,
+) -> Result<(), ProjectError> {
+    let mut fetches = futures::stream::iter(
+        lock.packages()
+            .iter()
+            .filter(|package| package.index_digest().is_some()),
+    )
+    .map(async |package| {
+        let files = fetch_files(package, root, client, capabilities, concurrency).await?;
+        Ok::<_, ProjectError>((package, files))
+    })
+    .buffered(concurrency.downloads);
+
+    let mut mismatches = Vec::new();
+    while let Some((package, files)) = fetches.next().await.transpose()? {
+        let Some(files) = files else {
+            continue;
+        };
+        let before = mismatches.len();
+        for (filename, hash) in package.artifacts() {
+            let Some((_, file)) = files.iter().find(|(served, _)| *served == filename) else {
+                mismatches.push(IndexMetadataMismatch::Removed {
+                    package: display_package(package),
+                    filename: filename.to_string(),
+                });
+                continue;
+            };
+            if let Some(hash) = hash
+                && !file.hashes.iter().any(|served| served == hash)
+            {
+                mismatches.push(IndexMetadataMismatch::Altered {
+                    package: display_package(package),
+                    filename: filename.to_string(),
+                    hash: hash.clone(),
+                });
+            }
+        }
+
+        // New distributions may be published for an existing version, so a changed digest alone
+        // is not treated as an error.
+        if mismatches.len() == before && package.index_digest() != Some(&index_digest(&files)) {
+            warn_user!(
+                "The index metadata for `{}` changed since it was pinned, but the locked distributions are unchanged",
+                display_package(package)
+            );
+        }
+    }
+
+    if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(ProjectError::IndexMetadataMismatch(
+            IndexMetadataMismatches(mismatches),
+            lock_source,
+        ))
+    }
+}
+
+/// Fetch the files served by the index for the locked version of a registry package.
+///
+/// Returns `None` for packages that aren't locked from a remote Simple API index.
+async fn fetch_files(
+    package: &Package,
+    root: &Path,
+    client: &RegistryClient,
+    capabilities: &IndexCapabilities,
+    concurrency: &Concurrency,
+) -> Result<Option<Vec<(DistFilename, File)>>, ProjectError> {
+    let Some(version) = package.version() else {
+        return Ok(None);
+    };
+    let Some(index) = package.index(root)? else {
+        return Ok(None);
+    };
+    if matches!(index, IndexUrl::Path(_)) {
+        return Ok(None);
+    }
+
+    debug!(
+        "Fetching index metadata for: `{}`",
+        display_package(package)
+    );
+
+    let archives = match client
+        .simple_detail(
+            package.name(),
+            Some(IndexMetadataRef::from(&index)),
+            capabilities,
+            &concurrency.downloads_semaphore,
+        )
+        .await
+    {
+        Ok(archives) => archives,
+        Err(err) if matches!(err.kind(), uv_client::ErrorKind::RemotePackageNotFound(_)) => {
+            return Ok(Some(Vec::new()));
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut files = Vec::new();
+    for (_, archive) in archives {
+        let MetadataFormat::Simple(archive) = archive else {
+            return Ok(None);
+        };
+        for datum in archive.iter() {
+            let datum_version = rkyv::deserialize::<Version, rkyv::rancor::Error>(&datum.version)
+                .expect("archived version always deserializes");
+            if datum_version != *version {
+                continue;
+            }
+            let version_files =
+                rkyv::deserialize::<VersionFiles, rkyv::rancor::Error>(&datum.files)
+                    .expect("archived version files always deserializes");
+            files.extend(version_files.all(package.name()));
+        }
+    }
+    Ok(Some(files))
+}
+
+/// Compute a digest over the filenames and hashes of the files served for a package version.
+fn index_digest(files: &[(DistFilename, File)]) -> HashDigest {
+    let mut entries = files
+        .iter()
+        .map(|(_, file)| {
+            let mut hashes = file
+                .hashes
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>();
+            hashes.sort_unstable();
+            (file.filename.as_ref(), hashes)
+        })
+        .collect::<Vec<_>>();
+    entries.sort_unstable();
+
+    let mut hasher = Sha256::new();
+    for (filename, hashes) in entries {
+        hasher.update(filename.as_bytes());
+        for hash in hashes {
+            hasher.update(b" ");
+            hasher.update(hash.as_bytes());
+        }
+        hasher.update(b"\n");
+    }
+
+    HashDigest {
+        algorithm: HashAlgorithm::Sha256,
+        digest: format!("{:x}", hasher.finalize()).into(),
+    }
+}
+
+/// Format a package as `{name}=={version}`.
+fn display_package(package: &Package) -> String {
+    match package.version() {
+        Some(version) => format!("{}=={version}", package.name()),
+        None => package.name().to_string(),
+    }
+}
@@ -1,5 +1,6 @@
 use std::fmt::Write;
 use std::iter;
+use std::ops::Bound;
 use std::path::{Path, PathBuf};
 use std::process::Stdio;
 use std::str::FromStr;
@@ -13,7 +14,8 @@ use uv_cache::Cache;
 use uv_cli::AuthorFrom;
 use uv_client::BaseClientBuilder;
 use uv_configuration::{
-    DependencyGroupsWithDefaults, ProjectBuildBackend, VersionControlError, VersionControlSystem,
+    CiProvider, DependencyGroupsWithDefaults, ProjectBuildBackend, VersionControlError,
+    VersionControlSystem,
 };
 use uv_distribution_types::RequiresPython;
 use uv_fs::{CWD, Simplified};
@@ -51,6 +53,7 @@ pub(crate) async fn init(
     description: Option<String>,
     no_description: bool,
     vcs: Option<VersionControlSystem>,
+    ci: CiProvider,
     build_backend: Option<ProjectBuildBackend>,
     no_readme: bool,
     author_from: Option<AuthorFrom>,
@@ -150,6 +153,7 @@ pub(crate) async fn init(
                 description,
                 no_description,
                 vcs,
+                ci,
                 build_backend,
                 no_readme,
                 author_from,
@@ -290,6 +294,7 @@ async fn init_project(
     description: Option<String>,
     no_description: bool,
     vcs: Option<VersionControlSystem>,
+    ci: CiProvider,
     build_backend: Option<ProjectBuildBackend>,
     no_readme: bool,
     author_from: Option<AuthorFrom>,
@@ -419,6 +424,14 @@ async fn init_project(
         package,
     )?;
 
+    // CI workflows are only discovered at the repository root, so workspace members share the
+    // workflow at the root of the workspace.
+    let ci_root = match workspace.as_deref() {
+        Some(workspace) if !workspace.excludes(path)? => workspace.install_path().as_path(),
+        _ => path,
+    };
+    init_ci(ci_root, ci, &requires_python)?;
+
     if let Some(workspace) = workspace {
         if workspace.excludes(path)? {
             // If the member is excluded by the workspace, ignore it.
@@ -1455,6 +1468,154 @@ fn detect_git_repository(path: &Path) -> GitDiscoveryResult {
     }
 }
 
+/// The newest Python minor version to include in generated CI workflows.
+const CI_LATEST_PYTHON_MINOR: u64 = 14;
+
+/// Generate a continuous integration workflow for the project, if requested.
+///
+/// Existing workflow files are left untouched.
+fn init_ci(path: &Path, ci: CiProvider, requires_python: &RequiresPython) -> Result<()> {
+    let Some(workflow_path) = ci.workflow_path() else {
+        return Ok(());
+    };
+
+    let python_versions = ci_python_versions(requires_python);
+    let contents = match ci {
+        CiProvider::None => return Ok(()),
+        CiProvider::Github => github_workflow(&python_versions),
+        CiProvider::Gitlab => gitlab_workflow(&python_versions),
+    };
+
+    let workflow = path.join(workflow_path);
+    if workflow.try_exists()? {
+        warn_user_once!(
+            "Skipping CI workflow generation; `{}` already exists",
+            workflow_path.user_display()
+        );
+        return Ok(());
+    }
+    if let Some(parent) = workflow.parent() {
+        fs_err::create_dir_all(parent)?;
+    }
+    fs_err::write(workflow, contents)?;
+
+    Ok(())
+}
+
+/// Determine the Python versions to test in a generated CI workflow: the oldest and newest
+/// Python minor versions supported by the project.
+fn ci_python_versions(requires_python: &RequiresPython) -> Vec<String> {
+    let range = requires_python.range();
+    let (major, oldest) = match range.lower().as_ref() {
+        Bound::Included(version) => (
+            version.release().first().copied().unwrap_or(3),
+            version.release().get(1).copied().unwrap_or(0),
+        ),
+        // An excluded `major.minor` bound (e.g., `>3.10`) excludes the minor version itself,
+        // while an excluded patch bound (e.g., `>3.10.2`) admits later patch releases.
+        Bound::Excluded(version) => match *version.release() {
+            [] => (3, 0),
+            [major] => (major + 1, 0),
+            [major, minor] => (major, minor + 1),
+            [major, minor, ..] => (major, minor),
+        },
+        Bound::Unbounded => (3, CI_LATEST_PYTHON_MINOR),
+    };
+
+    // Python 2 isn't supported by uv, so start at the first Python 3 release.
+    let (major, oldest) = if major < 3 { (3, 0) } else { (major, oldest) };
+
+    // We only know the latest minor version for Python 3.
+    if major > 3 {
+        return vec![format!("{major}.{oldest}")];
+    }
+
+    let newest = (oldest..=CI_LATEST_PYTHON_MINOR)
+        .rev()
+        .find(|minor| requires_python.contains(&Version::new([3, *minor])))
+        .unwrap_or(oldest);
+
+    let mut versions = vec![format!("3.{oldest}")];
+    if newest != oldest {
+        versions.push(format!("3.{newest}"));
+    }
+    versions
+}
+
+/// Generate a GitHub Actions workflow that verifies the lockfile and runs the project's tests.
+fn github_workflow(python_versions: &[String]) -> String {
+    let matrix = python_versions
+        .iter()
+        .map(|version| format!("\"{version}\""))
+        .collect::<Vec<_>>()
+        .join(", ");
+    indoc::formatdoc! {r#"
+        name: CI
+
+        on:
+          push:
+            branches: [main]
+          pull_request:
+
+        jobs:
+          test:
+            runs-on: ubuntu-latest
+            strategy:
+              matrix:
+                python-version: [{matrix}]
+            steps:
+              - uses: actions/checkout@v5
+
+              - name: Install uv
+                uses: astral-sh/setup-uv@v7
+                with:
+                  python-version: ${{{{ matrix.python-version }}}}
+                  enable-cache: true
+                  cache-dependency-glob: "uv.lock"
+
+              - name: Verify the lockfile
+                run: uv lock --check
+
+              - name: Install the project
+                run: uv sync --locked --all-extras --dev
+
+              - name: Run tests
+                run: uv run --with pytest pytest
+    "#}
+}
+
+/// Generate a GitLab CI/CD pipeline that verifies the lockfile and runs the project's tests.
+fn gitlab_workflow(python_versions: &[String]) -> String {
+    let matrix = python_versions
+        .iter()
+        .map(|version| format!("\"{version}\""))
+        .collect::<Vec<_>>()
+        .join(", ");
+    indoc::formatdoc! {r"
+        variables:
+          UV_CACHE_DIR: .uv-cache
+          UV_LINK_MODE: copy
+
+        test:
+          image: ghcr.io/astral-sh/uv:python$PYTHON_VERSION-bookworm-slim
+          parallel:
+            matrix:
+              - PYTHON_VERSION: [{matrix}]
+          cache:
+            - key:
+                files:
+                  - uv.lock
+              paths:
+                - $UV_CACHE_DIR
+          script:
+            - uv lock --check
+            - uv sync --locked --all-extras --dev
+            - uv run --with pytest pytest
+          after_script:
+            - uv cache prune --ci
+    "}
+}
+
 /// Initialize the version control system at the given path, if applicable.
 fn init_vcs(path: &Path, vcs: Option<VersionControlSystem>) -> Result<()> {
     // vcs is None for an existing repository because we don't want to initialize again.
@@ -1553,6 +1714,8 @@ fn get_author_from_git(path: &Path) -> Result<Author> {
 
 #[cfg(test)]
 mod tests {
+    use uv_pep440::VersionSpecifiers;
+
     use super::*;
 
     #[test]
@@ -1567,4 +1730,28 @@ mod tests {
             "{ name = 'Tony \"Iron Man\" Stark', email = \"ironman@example.com\" }"
         );
     }
+
+    fn ci_python_versions_for(specifiers: &str) -> Vec<String> {
+        let specifiers = VersionSpecifiers::from_str(specifiers).unwrap();
+        ci_python_versions(&RequiresPython::from_specifiers(specifiers))
+    }
+
+    #[test]
+    fn ci_python_versions_included_lower_bound() {
+        assert_eq!(ci_python_versions_for(">=3.10"), ["3.10", "3.14"]);
+        assert_eq!(ci_python_versions_for(">=3.10,<3.13"), ["3.10", "3.12"]);
+        assert_eq!(ci_python_versions_for("==3.12.*"), ["3.12"]);
+    }
+
+    #[test]
+    fn ci_python_versions_excluded_lower_bound() {
+        assert_eq!(ci_python_versions_for(">3.10"), ["3.11", "3.14"]);
+        assert_eq!(ci_python_versions_for(">3.10.2"), ["3.10", "3.14"]);
+    }
+
+    #[test]
+    fn ci_python_versions_major_version() {
+        assert_eq!(ci_python_versions_for(">=2.7"), ["3.0", "3.14"]);
+        assert_eq!(ci_python_versions_for(">=4.1"), ["4.1"]);
+    }
 }
@@ -11,7 +11,7 @@ use uv_configuration::{
     ExtrasSpecificationWithDefaults, InstallOptions,
 };
 use uv_distribution_types::{Index, Resolution};
-use uv_normalize::{ExtraName, PackageName};
+use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_platform_tags::Tags;
 use uv_pypi_types::{
     DependencyGroupSpecifier, LenientRequirement, ResolverMarkerEnvironment, VerbatimParsedUrl,
@@ -202,6 +202,17 @@ impl<'lock> InstallTarget<'lock> {
         }
     }
 
+    /// Return the packages that the target configures to be installed as non-editable.
+    pub(crate) fn no_editable_packages(self) -> &'lock [PackageName] {
+        match self {
+            Self::Project { workspace, .. }
+            | Self::Projects { workspace, .. }
+            | Self::Workspace { workspace, .. }
+            | Self::NonProjectWorkspace { workspace, .. } => workspace.no_editable_packages(),
+            Self::Script { .. } => &[],
+        }
+    }
+
     /// Return an iterator over all [`Sources`] defined by the target.
     pub(crate) fn sources(&self) -> impl Iterator<Item = &Source> {
         match self {
@@ -460,6 +471,36 @@ impl<'lock> InstallTarget<'lock> {
         Ok(())
     }
 
+    /// Returns the names of all dependency groups defined for the target.
+    pub(crate) fn group_names(self) -> BTreeSet<GroupName> {
+        match self {
+            Self::Workspace { lock, workspace } | Self::NonProjectWorkspace { lock, workspace } => {
+                let roots = self.roots().collect::<FxHashSet<_>>();
+                lock.packages()
+                    .iter()
+                    .filter(|package| roots.contains(package.name()))
+                    .flat_map(|package| package.dependency_groups().keys().cloned())
+                    .chain(
+                        workspace
+                            .workspace_dependency_groups()
+                            .ok()
+                            .into_iter()
+                            .flat_map(BTreeMap::into_keys),
+                    )
+                    .collect()
+            }
+            Self::Project { lock, .. } | Self::Projects { lock, .. } => {
+                let roots = self.roots().collect::<FxHashSet<_>>();
+                lock.packages()
+                    .iter()
+                    .filter(|package| roots.contains(package.name()))
+                    .flat_map(|package| package.dependency_groups().keys().cloned())
+                    .collect()
+            }
+            Self::Script { .. } => BTreeSet::new(),
+        }
+    }
+
     /// Returns the names of all packages in the workspace that will be installed.
     ///
     /// Note this only includes workspace members.
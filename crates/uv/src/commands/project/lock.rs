@@ -2,14 +2,18 @@
 
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use std::sync::Arc;
 
+use anyhow::Context;
+use itertools::Itertools;
 use owo_colors::OwoColorize;
 use rustc_hash::{FxBuildHasher, FxHashMap};
 use tracing::debug;
 
-use uv_cache::{Cache, Refresh};
+use uv_cache::{Cache, CacheBucket, Refresh};
+use uv_cache_key::cache_digest;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     Concurrency, Constraints, DependencyGroupsWithDefaults, DryRun, ExcludeDependency,
@@ -18,42 +22,58 @@ use uv_configuration::{
 use uv_dispatch::BuildDispatch;
 use uv_distribution::{DistributionDatabase, LoweredExtraBuildDependencies};
 use uv_distribution_types::{
-    DependencyMetadata, HashGeneration, Index, IndexLocations, NameRequirementSpecification,
-    Requirement, RequiresPython, UnresolvedRequirementSpecification,
+    DependencyMetadata, ExtraBuildRequires, HashGeneration, Index, IndexLocations,
+    InstalledDistKind, Name, NameRequirementSpecification, Requirement, RequirementSource,
+    RequiresPython, UnresolvedRequirementSpecification,
 };
+use uv_fs::Simplified;
 use uv_git::ResolvedRepositoryReference;
 use uv_git_types::GitOid;
+use uv_installer::SitePackages;
 use uv_normalize::{GroupName, PackageName};
 use uv_pep440::Version;
+use uv_pep508::{MarkerTree, VersionOrUrl};
 use uv_preview::{Preview, PreviewFeature};
-use uv_pypi_types::{ConflictKind, Conflicts, SupportedEnvironments};
+use uv_pypi_types::{
+    ConflictKind, Conflicts, HashDigest, MetadataVersion, SupportedEnvironments, VerbatimParsedUrl,
+};
 use uv_python::{
     ConfigDiscovery, Interpreter, PythonDownloads, PythonEnvironment, PythonPreference,
     PythonRequest,
 };
-use uv_requirements::{ExtrasResolver, LockedRequirements, read_lock_requirements};
+use uv_redacted::DisplaySafeUrl;
+use uv_requirements::{
+    ExtrasResolver, LockedRequirements, fetch_remote_file, read_lock_requirements,
+};
 use uv_resolver::{
-    FlatIndex, InMemoryIndex, Lock, Options, OptionsBuilder, Package, PythonRequirement,
-    ResolverEnvironment, ResolverManifest, SatisfiesResult, UniversalMarker,
+    FlatIndex, InMemoryIndex, Lock, Options, OptionsBuilder, Package, Preference,
+    PythonRequirement, Remediation, ResolverEnvironment, ResolverManifest, ResolverOutput,
+    SatisfiesResult, UniversalMarker, read_checkpoint, validate_lock,
 };
 use uv_scripts::Pep723Script;
 use uv_settings::PythonInstallMirrors;
+use uv_static::EnvVars;
 use uv_types::{
     BuildContext, BuildIsolation, EmptyInstalledPackages, HashStrategy, SourceTreeEditablePolicy,
 };
 use uv_warnings::{warn_user, warn_user_once};
+use uv_workspace::pyproject::{OverrideDependency, WorkspacePinPolicy};
 use uv_workspace::{
     DiscoveryOptions, Editability, VirtualProject, WorkspaceCache, WorkspaceMember,
 };
 
 use crate::commands::pip::loggers::{DefaultResolveLogger, ResolveLogger, SummaryResolveLogger};
-use crate::commands::project::lock_target::{LockTarget, find_lock_format_error};
+use crate::commands::project::index_metadata;
+use crate::commands::project::lock_target::{
+    LockTarget, SharedScripts, find_lock_format_error, has_conflict_markers, read_lockfile,
+};
 use crate::commands::project::{
     MissingLockfileSource, ProjectError, ProjectInterpreter, ScriptInterpreter, UniversalState,
     WorkspacePython, init_script_python_requirement, script_extra_build_requires,
+    warn_unbounded_workspace_dependency,
 };
 use crate::commands::reporters::{PythonDownloadReporter, ResolverReporter};
-use crate::commands::{ExitStatus, ScriptPath, UvError, diagnostics, pip};
+use crate::commands::{ExitStatus, ScriptPath, SharedLock, UvError, diagnostics, pip};
 use crate::printer::Printer;
 use crate::settings::{FrozenSource, LockCheck, LockCheckSource, ResolverSettings};
 
@@ -84,17 +104,26 @@ impl LockResult {
 }
 
 /// Resolve the project requirements into a lockfile.
+#[expect(clippy::fn_params_excessive_bools, clippy::option_option)]
 pub(crate) async fn lock(
     project_dir: &Path,
     lock_check: LockCheck,
     frozen: Option<FrozenSource>,
     dry_run: DryRun,
+    pin_index_metadata: bool,
+    prefer_installed: Option<Option<PathBuf>>,
+    resolve_conflicts: bool,
+    report_metadata_versions: bool,
+    check_config: bool,
+    show_provenance: Option<PackageName>,
+    suggest: bool,
     refresh: Refresh,
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
     settings: ResolverSettings,
     client_builder: BaseClientBuilder<'_>,
     script: Option<ScriptPath>,
+    shared_lock: Option<SharedLock>,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
     concurrency: Concurrency,
@@ -127,9 +156,31 @@ pub(crate) async fn lock(
         None => None,
     };
 
+    // Refuse to resolve conflicts in the lockfile while the project itself has merge conflicts.
+    if resolve_conflicts && script.is_none() && shared_lock.is_none() {
+        for pyproject_toml in project_dir
+            .ancestors()
+            .map(|directory| directory.join("pyproject.toml"))
+        {
+            if fs_err::tokio::read_to_string(&pyproject_toml)
+                .await
+                .is_ok_and(|contents| has_conflict_markers(&contents))
+            {
+                return Err(ProjectError::ConflictedPyproject(
+                    pyproject_toml.user_display().to_string(),
+                )
+                .into());
+            }
+        }
+    }
+
     // Find the project requirements.
     let workspace;
-    let target = if let Some(script) = script.as_ref() {
+    let shared;
+    let target = if let Some(shared_lock) = shared_lock {
+        shared = SharedScripts::new(&shared_lock.path, shared_lock.scripts)?;
+        LockTarget::Scripts(&shared)
+    } else if let Some(script) = script.as_ref() {
         LockTarget::Script(script)
     } else {
         workspace = VirtualProject::discover(
@@ -142,6 +193,40 @@ pub(crate) async fn lock(
         LockTarget::Workspace(workspace.workspace())
     };
 
+    // Check for inconsistencies between the extras and dependency groups of each member.
+    if let LockTarget::Workspace(workspace) = target {
+        let diagnostics = workspace.check_group_collisions();
+        if workspace.strict_config() && !diagnostics.is_empty() {
+            return Err(ProjectError::StrictConfig(diagnostics.iter().join("\n\n")).into());
+        }
+        for diagnostic in &diagnostics {
+            warn_user!("{diagnostic}");
+        }
+        if check_config {
+            return if diagnostics.is_empty() {
+                writeln!(
+                    printer.stderr(),
+                    "No issues found in the project configuration"
+                )?;
+                Ok(ExitStatus::Success)
+            } else {
+                Ok(ExitStatus::Failure)
+            };
+        }
+    }
+
+    // Display the recorded provenance for a package, rather than locking.
+    if let Some(name) = show_provenance {
+        let Some(lock) = target.read().await? else {
+            anyhow::bail!(
+                "Unable to find lockfile at `{}`; run `{}` to create one",
+                target.lock_path().user_display(),
+                "uv lock".green()
+            );
+        };
+        return show_lock_provenance(&lock, &name, printer);
+    }
+
     // Determine the lock mode.
     let interpreter;
     let mode = if let Some(frozen_source) = frozen {
@@ -190,6 +275,31 @@ pub(crate) async fn lock(
             )
             .await?
             .into_interpreter(),
+            LockTarget::Scripts(shared) => {
+                // Discover the interpreter for the first script; the lock itself is resolved for
+                // the intersection of the scripts' `requires-python`.
+                let Some(script) = shared.scripts().next() else {
+                    return Err(ProjectError::SharedLockEmpty(
+                        target.lock_path().user_display().to_string(),
+                    )
+                    .into());
+                };
+                ScriptInterpreter::discover(
+                    script.into(),
+                    python.as_deref().map(PythonRequest::parse),
+                    &client_builder,
+                    python_preference,
+                    python_downloads,
+                    &install_mirrors,
+                    false,
+                    config_discovery,
+                    Some(false),
+                    cache,
+                    printer,
+                )
+                .await?
+                .into_interpreter()
+            }
         };
 
         if let LockCheck::Enabled(lock_check) = lock_check {
@@ -201,6 +311,20 @@ pub(crate) async fn lock(
         }
     };
 
+    // If requested, read the packages installed in an existing environment.
+    let installed = prefer_installed
+        .map(|path| read_installed(path.as_deref(), target, cache))
+        .transpose()?;
+
+    // If requested, replace a lockfile with merge conflicts, preferring the versions locked on
+    // either side of the conflict.
+    let conflicted_locks =
+        if resolve_conflicts && matches!(mode, LockMode::Write(_) | LockMode::DryRun(_)) {
+            read_conflicted_locks(target, printer).await?
+        } else {
+            None
+        };
+
     // Initialize any shared state.
     let state = UniversalState::default();
 
@@ -219,6 +343,17 @@ pub(crate) async fn lock(
             preview,
         )
         .with_refresh(&refresh)
+        .with_pin_index_metadata(pin_index_metadata)
+        .with_installed_preferences(
+            installed
+                .iter()
+                .flat_map(|site_packages| {
+                    site_packages.iter().filter_map(Preference::from_installed)
+                })
+                .collect(),
+        )
+        .with_conflicted_locks(conflicted_locks)
+        .with_metadata_version_report(report_metadata_versions)
         .with_lockfile_contents_check(
             matches!(&refresh, Refresh::All(..))
                 && preview.is_enabled(PreviewFeature::LockfileFormatCheck),
@@ -272,19 +407,355 @@ pub(crate) async fn lock(
                 }
             }
 
+            // Report any installed versions that couldn't be preserved.
+            if let Some(site_packages) = &installed {
+                report_unpreserved(site_packages, lock.lock(), printer)?;
+            }
+
             Ok(ExitStatus::Success)
         }
         // Lock mismatches from `--check`/`--locked` are expected validation failures.
-        Err(err @ (ProjectError::LockMismatch(..) | ProjectError::LockFormat(..))) => {
-            Err(UvError::user(err).into())
+        Err(
+            err @ (ProjectError::LockMismatch(..)
+            | ProjectError::SharedLockMismatch(..)
+            | ProjectError::LockFormat(..)
+            | ProjectError::IndexMetadataMismatch(..)
+            | ProjectError::RemoteFileMismatch(..)),
+        ) => Err(UvError::user(err).into()),
+        Err(ProjectError::Operation(err)) => {
+            let mut diagnostic = diagnostics::OperationDiagnostic::default();
+
+            // If requested, suggest (validated) changes that would resolve the failure.
+            if suggest
+                && let LockMode::Write(interpreter) | LockMode::DryRun(interpreter) = mode
+                && let pip::operations::Error::Resolve(uv_resolver::ResolveError::NoSolution(error)) =
+                    &err
+            {
+                let suggestions = suggest_remediations(
+                    error,
+                    target,
+                    interpreter,
+                    &settings,
+                    &client_builder,
+                    &state,
+                    &concurrency,
+                    cache,
+                    workspace_cache,
+                    preview,
+                )
+                .await;
+                if suggestions.is_empty() {
+                    diagnostic = diagnostic.with_hint(
+                        "No suggested changes were found that resolve the conflict".to_string(),
+                    );
+                }
+                for suggestion in suggestions {
+                    diagnostic = diagnostic.with_hint(suggestion);
+                }
+            }
+
+            diagnostic
+                .report(err)
+                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
         }
-        Err(ProjectError::Operation(err)) => diagnostics::OperationDiagnostic::default()
-            .report(err)
-            .map_or(Ok(ExitStatus::Failure), |err| Err(err.into())),
         Err(err) => Err(err.into()),
     }
 }
 
+/// The maximum number of suggestions to display for `uv lock --suggest`.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Identify up to [`MAX_SUGGESTIONS`] changes to the project requirements that would resolve the
+/// given resolution failure, validating each one by re-running the resolution with the change
+/// applied.
+///
+/// Returns a rendered hint for each validated suggestion.
+async fn suggest_remediations(
+    error: &uv_resolver::NoSolutionError,
+    target: LockTarget<'_>,
+    interpreter: &Interpreter,
+    settings: &ResolverSettings,
+    client_builder: &BaseClientBuilder<'_>,
+    state: &UniversalState,
+    concurrency: &Concurrency,
+    cache: &Cache,
+    workspace_cache: &WorkspaceCache,
+    preview: Preview,
+) -> Vec<String> {
+    let mut suggestions = Vec::new();
+
+    for remediation in error.remediations() {
+        if suggestions.len() >= MAX_SUGGESTIONS {
+            break;
+        }
+
+        let requirement = uv_pep508::Requirement {
+            name: remediation.package().clone(),
+            extras: Box::default(),
+            version_or_url: if remediation.specifiers().is_empty() {
+                None
+            } else {
+                Some(VersionOrUrl::VersionSpecifier(
+                    remediation.specifiers().clone(),
+                ))
+            },
+            marker: MarkerTree::TRUE,
+            origin: None,
+        };
+
+        // Re-run the resolution with the change applied, without writing the lockfile.
+        let operation = LockOperation::new(
+            LockMode::DryRun(interpreter),
+            settings,
+            client_builder,
+            state,
+            Box::new(SummaryResolveLogger),
+            concurrency,
+            cache,
+            workspace_cache,
+            Printer::Silent,
+            preview,
+        );
+        let operation = match &remediation {
+            Remediation::Constraint { .. } => {
+                operation.with_constraints(vec![NameRequirementSpecification::from(
+                    Requirement::from(requirement.clone()),
+                )])
+            }
+            // Package-scoped overrides don't replace a member's direct requirements, so a relaxed
+            // requirement is validated as a global override instead. Since the relaxed specifiers
+            // are derived from the transitive requirements in the conflict, the two are equivalent
+            // for the packages involved.
+            Remediation::Override { .. } | Remediation::Relax { .. } => {
+                operation.with_overrides(vec![Override::Requirement(requirement.clone())])
+            }
+        };
+        if let Err(err) = Box::pin(operation.execute(target)).await {
+            debug!(
+                "Discarding suggestion for `{}`: {err}",
+                remediation.package()
+            );
+            continue;
+        }
+
+        let suggestion = match &remediation {
+            Remediation::Constraint { package, .. } => format!(
+                "The conflict can be resolved by constraining `{}` in `{}`:\n      {}",
+                package.cyan(),
+                "tool.uv.constraint-dependencies".green(),
+                format!("\"{requirement}\"").green(),
+            ),
+            Remediation::Override { package, .. } => format!(
+                "The conflict can be resolved by overriding the transitive requirements on `{}` in `{}`:\n      {}",
+                package.cyan(),
+                "tool.uv.override-dependencies".green(),
+                format!("\"{requirement}\"").green(),
+            ),
+            Remediation::Relax {
+                member, package, ..
+            } => {
+                let existing = find_member_requirement(target, member, package);
+                match existing {
+                    Some((path, existing)) => format!(
+                        "The conflict can be resolved by relaxing the requirement on `{}` in `{}`:\n      {}\n      {}",
+                        package.cyan(),
+                        path.user_display().cyan(),
+                        format!("- \"{existing}\"").red(),
+                        format!("+ \"{requirement}\"").green(),
+                    ),
+                    None => format!(
+                        "The conflict can be resolved by relaxing the requirement on `{}` in `{}` to: {}",
+                        package.cyan(),
+                        member.cyan(),
+                        format!("\"{requirement}\"").green(),
+                    ),
+                }
+            }
+        };
+        suggestions.push(suggestion);
+    }
+
+    suggestions
+}
+
+/// Find the requirement on `package` declared by the workspace member `member`, returning the path
+/// to the member's `pyproject.toml` and the requirement as written.
+fn find_member_requirement(
+    target: LockTarget<'_>,
+    member: &PackageName,
+    package: &PackageName,
+) -> Option<(PathBuf, String)> {
+    let LockTarget::Workspace(workspace) = target else {
+        return None;
+    };
+    let member = workspace.packages().get(member)?;
+    let project = member.pyproject_toml().project.as_ref()?;
+    let existing = project
+        .dependencies
+        .iter()
+        .flatten()
+        .chain(
+            project
+                .optional_dependencies
+                .iter()
+                .flat_map(|extras| extras.values().flatten()),
+        )
+        .find(|requirement| {
+            uv_pep508::Requirement::<VerbatimParsedUrl>::from_str(requirement)
+                .is_ok_and(|requirement| requirement.name == *package)
+        })?;
+    Some((member.root().join("pyproject.toml"), existing.clone()))
+}
+
+/// Read the lockfiles on either side of any merge conflicts in the existing lockfile.
+///
+/// Returns `None` if the lockfile does not exist or does not contain conflict markers.
+async fn read_conflicted_locks(
+    target: LockTarget<'_>,
+    printer: Printer,
+) -> Result<Option<Vec<Lock>>, ProjectError> {
+    let lock_filename = target.lock_filename();
+    let Some(conflicted) = target.read_conflicted().await? else {
+        warn_user!(
+            "No merge conflicts found in `{}`; locking as usual",
+            lock_filename.user_display()
+        );
+        return Ok(None);
+    };
+
+    writeln!(
+        printer.stderr(),
+        "Resolving merge conflicts in `{}`",
+        lock_filename.user_display().cyan()
+    )?;
+
+    let mut locks = Vec::new();
+    for (side, lock) in ["current", "incoming"].into_iter().zip(conflicted.sides()) {
+        match lock {
+            Ok(lock) => locks.push(lock),
+            Err(err) => {
+                warn_user!(
+                    "Failed to parse the {side} side of the conflicts in `{}`; ignoring its locked versions: {err}",
+                    lock_filename.user_display()
+                );
+            }
+        }
+    }
+
+    Ok(Some(locks))
+}
+
+/// Print a JSON Schema describing the lockfile format.
+pub(crate) fn lock_schema(printer: Printer) -> anyhow::Result<ExitStatus> {
+    let schema = serde_json::to_string_pretty(&uv_resolver::lock_schema())?;
+    writeln!(printer.stdout(), "{schema}")?;
+    Ok(ExitStatus::Success)
+}
+
+/// Validate the lockfile at the given path against the lockfile schema.
+pub(crate) async fn lock_validate(path: &Path, printer: Printer) -> anyhow::Result<ExitStatus> {
+    let contents = fs_err::tokio::read_to_string(path).await?;
+    let validation = validate_lock(&contents);
+
+    if validation.is_newer_revision() {
+        warn_user!(
+            "`{}` was written by a newer version of uv (revision {}); some fields may not be recognized",
+            path.user_display(),
+            validation.revision.unwrap_or_default()
+        );
+    }
+
+    if validation.problems.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!(
+                "`{}` is a valid lockfile (version {}, revision {})",
+                path.user_display(),
+                validation.version.unwrap_or_default(),
+                validation.revision.unwrap_or_default()
+            )
+            .dimmed()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let problems = if validation.problems.len() == 1 {
+        "problem"
+    } else {
+        "problems"
+    };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Found {} in `{}`",
+            format!("{} {}", validation.problems.len(), problems).bold(),
+            path.user_display()
+        )
+        .dimmed()
+    )?;
+    for problem in &validation.problems {
+        writeln!(printer.stderr(), "{}", problem.to_string().bold())?;
+    }
+
+    Ok(ExitStatus::Failure)
+}
+
+/// Print the provenance recorded in the lockfile for the given package.
+fn show_lock_provenance(
+    lock: &Lock,
+    name: &PackageName,
+    printer: Printer,
+) -> anyhow::Result<ExitStatus> {
+    if lock.provenance().is_empty() {
+        anyhow::bail!(
+            "No provenance is recorded in the lockfile; set `{}` and run `{}` to record it",
+            "tool.uv.lock-provenance = true".green(),
+            "uv lock".green()
+        );
+    }
+
+    let mut found = false;
+    for provenance in lock
+        .provenance()
+        .iter()
+        .filter(|provenance| provenance.name() == name)
+    {
+        found = true;
+        writeln!(
+            printer.stdout(),
+            "{}",
+            format!("{} v{}", provenance.name(), provenance.version()).bold()
+        )?;
+        match provenance.index_name() {
+            Some(index_name) => writeln!(
+                printer.stdout(),
+                "  index: {} ({index_name})",
+                provenance.index()
+            )?,
+            None => writeln!(printer.stdout(), "  index: {}", provenance.index())?,
+        }
+        writeln!(printer.stdout(), "  locked-at: {}", provenance.locked_at())?;
+        for artifact in provenance.artifacts() {
+            match artifact.upload_time() {
+                Some(upload_time) => writeln!(
+                    printer.stdout(),
+                    "  artifact: {} (uploaded {upload_time})",
+                    artifact.filename()
+                )?,
+                None => writeln!(printer.stdout(), "  artifact: {}", artifact.filename())?,
+            }
+        }
+    }
+
+    if !found {
+        anyhow::bail!("No provenance is recorded for `{}`", name.cyan());
+    }
+
+    Ok(ExitStatus::Success)
+}
+
 #[derive(Debug, Clone, Copy)]
 pub(crate) enum LockMode<'env> {
     /// Write the lockfile to disk.
@@ -295,14 +766,22 @@ pub(crate) enum LockMode<'env> {
     Locked(&'env Interpreter, LockCheckSource),
     /// Use the existing lockfile without performing a resolution.
     Frozen(MissingLockfileSource),
+    /// Use the lockfile at the given path (or stdin, for `-`) without performing a resolution.
+    Lockfile { path: &'env Path, validate: bool },
 }
 
 /// A lock operation.
 pub(crate) struct LockOperation<'env> {
     mode: LockMode<'env>,
     constraints: Vec<NameRequirementSpecification>,
+    overrides: Vec<OverrideDependency>,
+    installed_preferences: Vec<Preference>,
+    conflicted_locks: Option<Vec<Lock>>,
+    report_metadata_versions: bool,
+    remote_files: BTreeMap<DisplaySafeUrl, HashDigest>,
     refresh: Option<&'env Refresh>,
     check_lockfile_contents: bool,
+    pin_index_metadata: bool,
     settings: &'env ResolverSettings,
     client_builder: &'env BaseClientBuilder<'env>,
     state: &'env UniversalState,
@@ -331,8 +810,14 @@ impl<'env> LockOperation<'env> {
         Self {
             mode,
             constraints: vec![],
+            overrides: vec![],
+            installed_preferences: vec![],
+            conflicted_locks: None,
+            report_metadata_versions: false,
+            remote_files: BTreeMap::default(),
             refresh: None,
             check_lockfile_contents: false,
+            pin_index_metadata: false,
             settings,
             client_builder,
             state,
@@ -355,6 +840,54 @@ impl<'env> LockOperation<'env> {
         self
     }
 
+    /// Set additional overrides for the [`LockOperation`], on top of those declared by the
+    /// project.
+    #[must_use]
+    fn with_overrides(mut self, overrides: Vec<OverrideDependency>) -> Self {
+        self.overrides = overrides;
+        self
+    }
+
+    /// Set the preferences derived from the packages installed in an existing environment.
+    ///
+    /// Installed preferences are only used when the lockfile is written (or in a dry run), and
+    /// take lower precedence than the versions in an existing lockfile.
+    #[must_use]
+    fn with_installed_preferences(mut self, preferences: Vec<Preference>) -> Self {
+        self.installed_preferences = preferences;
+        self
+    }
+
+    /// Replace an existing lockfile that has unresolved merge conflicts, using the lockfiles parsed
+    /// from either side of the conflict as preferences.
+    ///
+    /// Only used when the lockfile is written (or in a dry run).
+    #[must_use]
+    fn with_conflicted_locks(mut self, locks: Option<Vec<Lock>>) -> Self {
+        self.conflicted_locks = locks;
+        self
+    }
+
+    /// Re-resolve, even if the existing lockfile is up-to-date, and report the core metadata
+    /// versions declared by the resolved distributions.
+    ///
+    /// Only used when the lockfile is written (or in a dry run).
+    #[must_use]
+    fn with_metadata_version_report(mut self, enabled: bool) -> Self {
+        self.report_metadata_versions = enabled;
+        self
+    }
+
+    /// Set the remote files (e.g., constraints provided via URL) to record in the lockfile.
+    #[must_use]
+    pub(crate) fn with_remote_files(
+        mut self,
+        remote_files: BTreeMap<DisplaySafeUrl, HashDigest>,
+    ) -> Self {
+        self.remote_files = remote_files;
+        self
+    }
+
     /// Set the refresh strategy for the [`LockOperation`].
     #[must_use]
     pub(crate) fn with_refresh(mut self, refresh: &'env Refresh) -> Self {
@@ -369,6 +902,13 @@ impl<'env> LockOperation<'env> {
         self
     }
 
+    /// Pin (or re-pin) the index metadata for every registry package in the lock.
+    #[must_use]
+    fn with_pin_index_metadata(mut self, enabled: bool) -> Self {
+        self.pin_index_metadata = enabled;
+        self
+    }
+
     /// Perform a [`LockOperation`].
     pub(crate) async fn execute(self, target: LockTarget<'_>) -> Result<LockResult, ProjectError> {
         match self.mode {
@@ -381,15 +921,25 @@ impl<'env> LockOperation<'env> {
                     .ok_or(ProjectError::MissingLockfile(source, lock_filename))?;
 
                 // Check if the discovered workspace members match the locked workspace members.
-                if let LockTarget::Workspace(workspace) = target {
-                    for package_name in workspace.packages().keys() {
-                        existing
-                            .find_by_name(package_name)
-                            .map_err(|_| ProjectError::LockWorkspaceMismatch(package_name.clone()))?
-                            .ok_or_else(|| {
-                                ProjectError::LockWorkspaceMismatch(package_name.clone())
-                            })?;
-                    }
+                target.validate_members(&existing)?;
+                Ok(LockResult::Unchanged(existing))
+            }
+            LockMode::Lockfile { path, validate } => {
+                // Read the given lockfile, but don't attempt to lock the project.
+                let existing = read_lockfile(path).await?;
+
+                if validate {
+                    target
+                        .validate_members(&existing)
+                        .map_err(|err| match err {
+                            ProjectError::LockWorkspaceMismatch(name) => {
+                                ProjectError::LockfileWorkspaceMismatch(
+                                    path.user_display().to_string(),
+                                    name,
+                                )
+                            }
+                            err => err,
+                        })?;
                 }
                 Ok(LockResult::Unchanged(existing))
             }
@@ -421,7 +971,14 @@ impl<'env> LockOperation<'env> {
                     interpreter,
                     Some(existing),
                     check_lockfile_contents,
+                    false,
+                    Some(lock_source),
                     self.constraints,
+                    self.overrides,
+                    Vec::new(),
+                    &[],
+                    false,
+                    self.remote_files,
                     self.refresh,
                     self.settings,
                     self.client_builder,
@@ -437,6 +994,17 @@ impl<'env> LockOperation<'env> {
 
                 // If the lockfile changed, return an error.
                 if let LockResult::Changed(prev, cur) = result {
+                    // If the lockfile is shared by multiple scripts, name the script that
+                    // invalidated it.
+                    if let LockTarget::Scripts(shared) = target
+                        && let Some(script) = shared.changed_script(prev.as_ref(), &cur)
+                    {
+                        return Err(ProjectError::SharedLockMismatch(
+                            lock_filename,
+                            script,
+                            lock_source,
+                        ));
+                    }
                     return Err(ProjectError::LockMismatch(
                         prev.map(Box::new),
                         Box::new(cur),
@@ -447,8 +1015,13 @@ impl<'env> LockOperation<'env> {
                 Ok(result)
             }
             LockMode::Write(interpreter) | LockMode::DryRun(interpreter) => {
-                // Read the existing lockfile.
-                let (existing, existing_contents) = match target.read_with_contents().await {
+                // Read the existing lockfile, unless it's being replaced due to merge conflicts.
+                let existing = if self.conflicted_locks.is_some() {
+                    Ok(None)
+                } else {
+                    target.read_with_contents().await
+                };
+                let (existing, existing_contents) = match existing {
                     Ok(Some((existing, existing_contents))) => {
                         (Some(existing), Some(existing_contents))
                     }
@@ -474,7 +1047,14 @@ impl<'env> LockOperation<'env> {
                     interpreter,
                     existing,
                     check_lockfile_contents,
+                    self.pin_index_metadata,
+                    None,
                     self.constraints,
+                    self.overrides,
+                    self.installed_preferences,
+                    self.conflicted_locks.as_deref().unwrap_or_default(),
+                    self.report_metadata_versions,
+                    self.remote_files,
                     self.refresh,
                     self.settings,
                     self.client_builder,
@@ -507,7 +1087,14 @@ async fn do_lock(
     interpreter: &Interpreter,
     existing_lock: Option<Lock>,
     check_lockfile_contents: Option<String>,
+    pin_index_metadata: bool,
+    verify_index_metadata: Option<LockCheckSource>,
     external: Vec<NameRequirementSpecification>,
+    external_overrides: Vec<OverrideDependency>,
+    installed_preferences: Vec<Preference>,
+    conflicted_locks: &[Lock],
+    report_metadata_versions: bool,
+    remote_files: BTreeMap<DisplaySafeUrl, HashDigest>,
     refresh: Option<&Refresh>,
     settings: &ResolverSettings,
     client_builder: &BaseClientBuilder<'_>,
@@ -528,6 +1115,8 @@ async fn do_lock(
         keyring_provider,
         resolution,
         prerelease,
+        prerelease_packages,
+        require_metadata,
         fork_strategy,
         dependency_metadata,
         config_setting,
@@ -535,6 +1124,7 @@ async fn do_lock(
         build_isolation,
         extra_build_dependencies,
         extra_build_variables,
+        build_env_passthrough,
         exclude_newer,
         link_mode,
         upgrade,
@@ -550,7 +1140,7 @@ async fn do_lock(
     let packages = target.packages();
     let required_members = target.required_members();
     let requirements = target.requirements();
-    let overrides = target.overrides();
+    let overrides = target.overrides().into_iter().chain(external_overrides);
     let excludes = target.exclude_dependencies();
     let constraints = target.constraints();
     let build_constraints = target.build_constraints();
@@ -644,6 +1234,26 @@ async fn do_lock(
     }
     let dependency_groups = lowered_dependency_groups;
 
+    // Determine whether to record the provenance of the locked packages, and the lock timestamp
+    // to use for any newly locked packages.
+    let lock_provenance = target.lock_provenance();
+    let now = jiff::Timestamp::now();
+    let now = now.round(jiff::Unit::Second).unwrap_or(now);
+
+    // Warn about workspace dependencies that are unbounded in the published metadata.
+    if let LockTarget::Workspace(workspace) = target
+        && workspace.pin_policy() == Some(WorkspacePinPolicy::Warn)
+    {
+        for (name, member) in workspace.packages() {
+            if !member.pyproject_toml().is_package(true) {
+                continue;
+            }
+            for dependency in workspace.unbounded_workspace_dependencies(name) {
+                warn_unbounded_workspace_dependency(name, &dependency);
+            }
+        }
+    }
+
     // Collect the conflicts.
     let mut conflicts = target.conflicts()?;
     if let LockTarget::Workspace(workspace) = target {
@@ -775,6 +1385,13 @@ async fn do_lock(
         }
     }
 
+    // Warn about likely mistakes in the markers of any dependency group entries.
+    if let LockTarget::Workspace(workspace) = target {
+        for diagnostic in workspace.check_group_markers(Some(&requires_python)) {
+            warn_user!("{diagnostic}");
+        }
+    }
+
     // Determine the Python requirement.
     let python_requirement =
         PythonRequirement::from_requires_python(interpreter, requires_python.clone());
@@ -792,7 +1409,7 @@ async fn do_lock(
     }
 
     // Initialize the registry client.
-    let client = RegistryClientBuilder::new(client_builder, cache.clone())
+    let client = RegistryClientBuilder::new(client_builder.clone(), cache.clone())
         .index_locations(index_locations.clone())
         .index_strategy(*index_strategy)
         .markers(interpreter.markers())
@@ -823,14 +1440,49 @@ async fn do_lock(
             .collect(),
     );
 
+    // If enabled, persist the versions selected by each completed fork, such that a retried
+    // resolution can reuse them after a failure.
+    let checkpoint = if std::env::var_os(EnvVars::UV_INTERNAL__LOCK_CHECKPOINT).is_some() {
+        let entry = cache.entry(
+            CacheBucket::Checkpoints,
+            "",
+            format!("{}.txt", cache_digest(&target.install_path())),
+        );
+        fs_err::create_dir_all(entry.dir())?;
+        Some(entry.into_path_buf())
+    } else {
+        None
+    };
+
+    // If enabled, persist the dependencies of the registry wheels used by the resolution, such
+    // that a subsequent resolution can skip reading their metadata. Refreshing the cache bypasses
+    // the persisted dependencies.
+    let dependency_cache = if preview.is_enabled(PreviewFeature::LockDependencyCache)
+        && matches!(cache.refresh(), Refresh::None(_))
+    {
+        let entry = cache.entry(
+            CacheBucket::Dependencies,
+            "",
+            format!("{}.msgpack", cache_digest(&target.install_path())),
+        );
+        fs_err::create_dir_all(entry.dir())?;
+        Some(entry.into_path_buf())
+    } else {
+        None
+    };
+
     let options = OptionsBuilder::new()
         .resolution_mode(*resolution)
         .prerelease_mode(*prerelease)
+        .prerelease_packages(prerelease_packages.clone())
+        .require_metadata(*require_metadata)
         .fork_strategy(*fork_strategy)
         .exclude_newer(exclude_newer.clone())
         .index_strategy(*index_strategy)
         .build_options(build_options.clone())
         .artifact_environments(artifact_environments.clone())
+        .checkpoint(checkpoint.clone())
+        .dependency_cache(dependency_cache)
         .build();
     let hasher = HashStrategy::Generate(HashGeneration::Url);
 
@@ -874,6 +1526,30 @@ async fn do_lock(
             )
             .await?
         }
+        LockTarget::Scripts(shared) => {
+            // Merge the extra build dependencies across all scripts.
+            let mut extra_build_requires = ExtraBuildRequires::default();
+            for script in shared.scripts() {
+                for (name, requirements) in script_extra_build_requires(
+                    script.into(),
+                    settings,
+                    cache,
+                    workspace_cache,
+                    client.credentials_cache(),
+                )
+                .await?
+                .into_inner()
+                {
+                    let entry = extra_build_requires.entry(name).or_default();
+                    for requirement in requirements {
+                        if !entry.contains(&requirement) {
+                            entry.push(requirement);
+                        }
+                    }
+                }
+            }
+            LoweredExtraBuildDependencies::from_lowered(extra_build_requires)
+        }
     }
     .into_inner();
 
@@ -905,7 +1581,8 @@ async fn do_lock(
         workspace_cache.clone(),
         concurrency.clone(),
         preview,
-    );
+    )
+    .with_build_env_passthrough(build_env_passthrough.clone());
 
     let database = DistributionDatabase::new(
         &client,
@@ -915,60 +1592,139 @@ async fn do_lock(
 
     // If any of the resolution-determining settings changed, invalidate the lock.
     let existing_lock = if let Some(existing_lock) = existing_lock {
-        match ValidatedLock::validate(
-            existing_lock,
-            target.install_path(),
-            packages,
-            &members,
-            required_members,
-            &requirements,
-            &dependency_groups,
-            &constraints,
-            &overrides,
-            &excludes,
-            &build_constraints,
-            &conflicts,
-            environments,
-            required_environments,
-            dependency_metadata,
-            interpreter,
-            &requires_python,
-            index_locations,
-            upgrade,
-            refresh,
-            &options,
-            &hasher,
-            state.index(),
-            &database,
-            printer,
-        )
-        .await
+        // If the set of scripts that share the lockfile changed, re-resolve, but retain the
+        // existing versions and forks.
+        if let LockTarget::Scripts(shared) = target
+            && *existing_lock.scripts() != shared.file_names()
         {
-            Ok(result) => Some(result),
-            Err(ProjectError::Lock(err)) if err.is_resolution() || err.is_no_build() => {
-                // Resolver errors are not recoverable, as such errors can leave the resolver in a
-                // broken state. Specifically, tasks that fail with an error can be left as pending.
-                //
-                // Disabled builds are user policy errors. Static local projects are validated
-                // before this point, so reaching this case means validation genuinely needs
-                // metadata that cannot be obtained under `--no-build`.
-                return Err(ProjectError::Lock(err));
-            }
-            Err(err) => {
-                warn_user!("Failed to validate existing lockfile: {err}");
-                None
+            debug!("Resolving despite existing lockfile due to change in scripts");
+            Some(ValidatedLock::Preferable(existing_lock))
+        } else {
+            match ValidatedLock::validate(
+                existing_lock,
+                target.install_path(),
+                packages,
+                &members,
+                required_members,
+                &requirements,
+                &dependency_groups,
+                &constraints,
+                &overrides,
+                &excludes,
+                &build_constraints,
+                &conflicts,
+                environments,
+                required_environments,
+                dependency_metadata,
+                interpreter,
+                &requires_python,
+                index_locations,
+                upgrade,
+                refresh,
+                &options,
+                &hasher,
+                state.index(),
+                &database,
+                printer,
+            )
+            .await
+            {
+                Ok(result) => Some(result),
+                Err(ProjectError::Lock(err)) if err.is_resolution() || err.is_no_build() => {
+                    // Resolver errors are not recoverable, as such errors can leave the resolver in a
+                    // broken state. Specifically, tasks that fail with an error can be left as pending.
+                    //
+                    // Disabled builds are user policy errors. Static local projects are validated
+                    // before this point, so reaching this case means validation genuinely needs
+                    // metadata that cannot be obtained under `--no-build`.
+                    return Err(ProjectError::Lock(err));
+                }
+                Err(err) => {
+                    warn_user!("Failed to validate existing lockfile: {err}");
+                    None
+                }
             }
         }
     } else {
         None
     };
 
+    // If a report was requested, re-resolve, but retain the existing versions and forks.
+    let existing_lock = match existing_lock {
+        Some(ValidatedLock::Satisfies(lock)) if report_metadata_versions => {
+            debug!("Resolving despite existing lockfile to report core metadata versions");
+            Some(ValidatedLock::Preferable(lock))
+        }
+        existing_lock => existing_lock,
+    };
+
     match existing_lock {
         // Resolution from the lockfile succeeded.
         Some(ValidatedLock::Satisfies(lock)) => {
             // Print the success message after completing resolution.
             logger.on_complete(lock.len(), start, printer)?;
 
+            // If requested, re-pin the index metadata for the existing lock.
+            if pin_index_metadata {
+                let pinned = index_metadata::pin_index_metadata(
+                    lock.clone(),
+                    target.install_path(),
+                    true,
+                    &client,
+                    state.capabilities(),
+                    concurrency,
+                )
+                .await?;
+                if pinned != lock {
+                    return Ok(LockResult::Changed(Some(lock), pinned));
+                }
+            }
+
+            // If remote files were provided, record them in the existing lock.
+            if !remote_files.is_empty() {
+                let updated = lock.clone().with_remote_files(remote_files);
+                if updated != lock {
+                    return Ok(LockResult::Changed(Some(lock), updated));
+                }
+            }
+
+            // Record (or remove) the provenance of the locked packages, without re-resolving.
+            if lock_provenance || !lock.provenance().is_empty() {
+                let updated = if lock_provenance {
+                    lock.clone().with_provenance(
+                        Some(&lock),
+                        index_locations,
+                        target.install_path(),
+                        now,
+                    )
+                } else {
+                    lock.clone().without_provenance()
+                };
+                if updated != lock {
+                    return Ok(LockResult::Changed(Some(lock), updated));
+                }
+            }
+
+            // If the index metadata is pinned, verify that it still matches the index.
+            if let Some(lock_source) = verify_index_metadata
+                && lock.has_index_digests()
+            {
+                index_metadata::verify_index_metadata(
+                    &lock,
+                    target.install_path(),
+                    lock_source,
+                    &client,
+                    state.capabilities(),
+                    concurrency,
+                )
+                .await?;
+            }
+
+            // If remote files are recorded, verify that their contents haven't changed.
+            if let Some(lock_source) = verify_index_metadata {
+                verify_remote_files(&lock, lock_source, &client_builder, cache).await?;
+            }
+
             Ok(LockResult::Unchanged(lock))
         }
 
@@ -983,11 +1739,52 @@ async fn do_lock(
                 ValidatedLock::Unusable(_) => None,
             });
 
-            // If an existing lockfile exists, build up a set of preferences.
-            let LockedRequirements { preferences, git } = versions_lock
-                .map(|lock| read_lock_requirements(lock, target.install_path(), upgrade))
-                .transpose()?
-                .unwrap_or_default();
+            // If an existing lockfile exists, build up a set of preferences. If the lockfile had
+            // merge conflicts, combine the preferences from either side, in order.
+            let LockedRequirements {
+                mut preferences,
+                git,
+            } = if let Some(lock) = versions_lock {
+                read_lock_requirements(lock, target.install_path(), upgrade)?
+            } else {
+                conflicted_locks.iter().try_fold(
+                    LockedRequirements::default(),
+                    |mut requirements, lock| {
+                        let LockedRequirements { preferences, git } =
+                            read_lock_requirements(lock, target.install_path(), upgrade)?;
+                        requirements.preferences.extend(preferences);
+                        requirements.git.extend(git);
+                        Ok::<_, ProjectError>(requirements)
+                    },
+                )?
+            };
+
+            // If a previous resolution was interrupted, prefer the versions selected by its
+            // completed forks, after those in the lockfile.
+            if let Some(checkpoint) = checkpoint.as_deref() {
+                let reused = read_checkpoint(checkpoint);
+                if !reused.is_empty() {
+                    debug!(
+                        "Reusing {} preferences from an interrupted resolution",
+                        reused.len()
+                    );
+                    preferences.extend(reused);
+                }
+            }
+
+            // If requested, prefer the versions installed in an existing environment, after those
+            // in the lockfile.
+            if !installed_preferences.is_empty() && !upgrade.is_all() {
+                debug!(
+                    "Using {} preferences from an existing environment",
+                    installed_preferences.len()
+                );
+                preferences.extend(installed_preferences.into_iter().filter(|preference| {
+                    !upgrade
+                        .packages()
+                        .is_some_and(|packages| packages.contains(preference.name()))
+                }));
+            }
 
             // Populate the Git resolver.
             for ResolvedRepositoryReference { reference, sha } in git {
@@ -1078,13 +1875,27 @@ async fn do_lock(
             )
             .await?;
 
+            // The resolution succeeded, so the checkpoint is no longer needed.
+            if let Some(checkpoint) = checkpoint.as_deref() {
+                if let Err(err) = fs_err::remove_file(checkpoint) {
+                    if err.kind() != std::io::ErrorKind::NotFound {
+                        debug!("Failed to remove resolver checkpoint: {err}");
+                    }
+                }
+            }
+
             // Print the success message after completing resolution.
             logger.on_complete(resolution.len(), start, printer)?;
 
             // Notify the user of any resolution diagnostics.
             pip::operations::diagnose_resolution(resolution.diagnostics(), printer)?;
 
-            let manifest = ResolverManifest::new(
+            // If requested, report the core metadata versions across the resolution.
+            if report_metadata_versions {
+                report_metadata_version_distribution(&resolution, *require_metadata, printer)?;
+            }
+
+            let mut manifest = ResolverManifest::new(
                 members,
                 requirements,
                 constraints,
@@ -1095,9 +1906,12 @@ async fn do_lock(
                 dependency_metadata.values().cloned(),
             )
             .relative_to(target.install_path())?;
+            if let LockTarget::Scripts(shared) = target {
+                manifest = manifest.with_scripts(shared.file_names());
+            }
 
             let previous = existing_lock.map(ValidatedLock::into_lock);
-            let lock = Lock::from_resolution(
+            let mut lock = Lock::from_resolution(
                 &resolution,
                 target.install_path(),
                 lock_supported_environments.clone().into_markers(),
@@ -1107,6 +1921,43 @@ async fn do_lock(
             .with_conflicts(conflicts)
             .with_required_environments(lock_required_environments.into_markers());
 
+            // Retain any pinned index metadata, and pin any packages that were added to the lock.
+            if let Some(previous) = previous.as_ref() {
+                lock = lock.with_index_digests_from(previous);
+            }
+
+            // Record the remote files used in the resolution, or retain those that were recorded
+            // previously.
+            if !remote_files.is_empty() {
+                lock = lock.with_remote_files(remote_files);
+            } else if let Some(previous) = previous.as_ref() {
+                lock = lock.with_remote_files(
+                    previous
+                        .remote_files()
+                        .map(|(url, hash)| (url.clone(), hash.clone()))
+                        .collect(),
+                );
+            }
+            if lock_provenance {
+                lock = lock.with_provenance(
+                    previous.as_ref(),
+                    index_locations,
+                    target.install_path(),
+                    now,
+                );
+            }
+            if pin_index_metadata || previous.as_ref().is_some_and(Lock::has_index_digests) {
+                lock = index_metadata::pin_index_metadata(
+                    lock,
+                    target.install_path(),
+                    pin_index_metadata,
+                    &client,
+                    state.capabilities(),
+                    concurrency,
+                )
+                .await?;
+            }
+
             let unchanged = if let Some(check_lockfile_contents) = check_lockfile_contents {
                 previous.is_some() && check_lockfile_contents == lock.to_toml()?.as_str()
             } else {
@@ -1114,6 +1965,22 @@ async fn do_lock(
             };
 
             if unchanged {
+                if let Some(lock_source) = verify_index_metadata
+                    && lock.has_index_digests()
+                {
+                    index_metadata::verify_index_metadata(
+                        &lock,
+                        target.install_path(),
+                        lock_source,
+                        &client,
+                        state.capabilities(),
+                        concurrency,
+                    )
+                    .await?;
+                }
+                if let Some(lock_source) = verify_index_metadata {
+                    verify_remote_files(&lock, lock_source, &client_builder, cache).await?;
+                }
                 Ok(LockResult::Unchanged(lock))
             } else {
                 Ok(LockResult::Changed(previous, lock))
@@ -1122,6 +1989,174 @@ async fn do_lock(
     }
 }
 
+/// Read the packages installed in the environment at the given path, or in the project
+/// environment if no path is provided.
+fn read_installed(
+    path: Option<&Path>,
+    target: LockTarget<'_>,
+    cache: &Cache,
+) -> anyhow::Result<SitePackages> {
+    let root = match (path, target) {
+        (Some(path), _) => path.to_path_buf(),
+        (None, LockTarget::Workspace(workspace)) => workspace
+            .environment_selection(Some(false))
+            .explicit_path()
+            .map_or_else(|| workspace.install_path().join(".venv"), Path::to_path_buf),
+        (None, LockTarget::Script(_) | LockTarget::Scripts(_)) => {
+            anyhow::bail!(
+                "`--prefer-installed` requires the path to an environment when locking a script"
+            );
+        }
+    };
+
+    let environment = PythonEnvironment::from_root(&root, cache).with_context(|| {
+        format!(
+            "Failed to read installed packages from: `{}`",
+            root.user_display()
+        )
+    })?;
+    debug!(
+        "Using installed packages as preferences from: `{}`",
+        environment.root().user_display()
+    );
+    SitePackages::from_environment(&environment)
+}
+
+/// Report any registry packages installed in the environment whose versions couldn't be
+/// preserved in the lockfile.
+fn report_unpreserved(
+    site_packages: &SitePackages,
+    lock: &Lock,
+    printer: Printer,
+) -> anyhow::Result<()> {
+    for dist in site_packages
+        .iter()
+        .filter(|dist| matches!(dist.kind, InstalledDistKind::Registry(_)))
+        .sorted_by_key(|dist| dist.name())
+    {
+        let locked = lock
+            .packages()
+            .iter()
+            .filter(|package| package.name() == dist.name())
+            .filter_map(Package::version)
+            .collect::<BTreeSet<_>>();
+
+        // Packages that are no longer required are omitted from the lockfile entirely.
+        if locked.is_empty() || locked.contains(dist.version()) {
+            continue;
+        }
+
+        // Determine whether the installed version is excluded by a direct requirement.
+        let excluded_by = lock
+            .packages()
+            .iter()
+            .flat_map(|package| {
+                package
+                    .requires_dist()
+                    .iter()
+                    .chain(package.dependency_groups().values().flatten())
+            })
+            .find(|requirement| {
+                requirement.name == *dist.name()
+                    && matches!(
+                        &requirement.source,
+                        RequirementSource::Registry { specifier, .. }
+                            if !specifier.contains(dist.version())
+                    )
+            });
+        let reason = match excluded_by {
+            Some(requirement) => format!("excluded by `{requirement}`"),
+            None => "incompatible with the other requirements".to_string(),
+        };
+
+        writeln!(
+            printer.stderr(),
+            "{} {} v{} ({reason}); locked {}",
+            "Could not preserve".yellow().bold(),
+            dist.name(),
+            dist.version(),
+            locked
+                .iter()
+                .map(|version| format!("v{version}"))
+                .join(", ")
+        )?;
+    }
+    Ok(())
+}
+
+/// Report the distribution of core metadata versions declared across a resolution, along with any
+/// packages that fall below the `require-metadata` floor.
+fn report_metadata_version_distribution(
+    resolution: &ResolverOutput,
+    require_metadata: Option<MetadataVersion>,
+    printer: Printer,
+) -> Result<(), std::fmt::Error> {
+    let packages = resolution
+        .metadata_versions()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    let mut counts = BTreeMap::<Option<MetadataVersion>, usize>::new();
+    for (.., metadata_version) in &packages {
+        *counts.entry(*metadata_version).or_default() += 1;
+    }
+
+    writeln!(printer.stdout(), "{}", "Core metadata versions:".bold())?;
+    for (metadata_version, count) in counts.iter().rev() {
+        let label = match metadata_version {
+            Some(metadata_version) => metadata_version.to_string(),
+            None => "unknown".to_string(),
+        };
+        let noun = if *count == 1 { "package" } else { "packages" };
+        writeln!(printer.stdout(), "  {label}: {count} {noun}")?;
+    }
+
+    if let Some(require_metadata) = require_metadata {
+        let below = packages
+            .iter()
+            .filter_map(|(name, version, metadata_version)| {
+                metadata_version
+                    .filter(|metadata_version| *metadata_version < require_metadata)
+                    .map(|metadata_version| (name, version, metadata_version))
+            })
+            .collect::<Vec<_>>();
+        if !below.is_empty() {
+            writeln!(
+                printer.stdout(),
+                "{}",
+                format!("Packages below `require-metadata` ({require_metadata}):").bold()
+            )?;
+            for (name, version, metadata_version) in below {
+                writeln!(printer.stdout(), "  {name} v{version} ({metadata_version})")?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Verify that the remote files recorded in the lockfile still have the same contents.
+async fn verify_remote_files(
+    lock: &Lock,
+    lock_source: LockCheckSource,
+    client_builder: &BaseClientBuilder<'_>,
+    cache: &Cache,
+) -> Result<(), ProjectError> {
+    for (url, expected) in lock.remote_files() {
+        let file = fetch_remote_file(url, client_builder, cache).await?;
+        if file.hash != *expected {
+            return Err(ProjectError::RemoteFileMismatch(
+                url.clone(),
+                expected.clone(),
+                file.hash,
+                lock_source,
+            ));
+        }
+    }
+    Ok(())
+}
+
 #[derive(Debug)]
 pub(crate) enum ValidatedLock {
     /// An existing lockfile was provided, but its contents should be ignored.
@@ -1187,6 +2222,18 @@ impl ValidatedLock {
             );
             return Ok(Self::Unusable(lock));
         }
+        if lock.require_metadata() != options.require_metadata {
+            let display = |version: Option<MetadataVersion>| {
+                version.map_or_else(|| "none".to_string(), |version| version.to_string())
+            };
+            let _ = writeln!(
+                printer.stderr(),
+                "Resolving despite existing lockfile due to change in required metadata version: `{}` vs. `{}`",
+                display(lock.require_metadata()).cyan(),
+                display(options.require_metadata).cyan()
+            );
+            return Ok(Self::Preferable(lock));
+        }
         if let Some(change) = lock.exclude_newer().compare(&options.exclude_newer) {
             // If a relative value is used, we won't invalidate on every tick of the clock unless
             // the span duration changed or some other operation causes a new resolution
@@ -1314,6 +2361,23 @@ impl ValidatedLock {
             return Ok(Self::Preferable(lock));
         }
 
+        // If the set of packages with pre-releases enabled has changed, we have to re-resolve, but
+        // can retain the existing versions and forks.
+        let prerelease_packages = options
+            .prerelease_packages
+            .iter()
+            .cloned()
+            .collect::<BTreeSet<_>>();
+        if *lock.prerelease_packages() != prerelease_packages {
+            let _ = writeln!(
+                printer.stderr(),
+                "Resolving despite existing lockfile due to change in pre-release packages: `[{}]` vs. `[{}]`",
+                lock.prerelease_packages().iter().join(", ").cyan(),
+                prerelease_packages.iter().join(", ").cyan()
+            );
+            return Ok(Self::Preferable(lock));
+        }
+
         // If the user specified `--upgrade-package` or `--upgrade-group`, then at best we can
         // prefer some of the existing versions.
         if !(upgrade.is_none() || upgrade.is_all()) {
@@ -1328,8 +2392,11 @@ impl ValidatedLock {
             return Ok(Self::Preferable(lock));
         }
 
-        // If the user specified `--refresh`, then we have to re-resolve.
-        if matches!(refresh, Some(Refresh::All(..) | Refresh::Packages(..))) {
+        // If the user specified `--refresh` (or refreshed any index metadata), then we have to
+        // re-resolve.
+        if matches!(refresh, Some(Refresh::All(..) | Refresh::Packages(..)))
+            || matches!(refresh, Some(Refresh::Scoped(scopes, ..)) if scopes.includes_metadata())
+        {
             debug!("Resolving despite existing lockfile due to `--refresh`");
             return Ok(Self::Preferable(lock));
         }
@@ -1,22 +1,26 @@
 use itertools::Either;
-use std::collections::BTreeMap;
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Read;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use toml_parser::Source;
 use toml_parser::lexer::TokenKind;
-use tracing::info_span;
+use tracing::{debug, info_span};
 
 use uv_auth::CredentialsCache;
 use uv_cache::Cache;
 use uv_configuration::{DependencyGroupsWithDefaults, ExcludeDependency, NoSources};
 use uv_distribution::LoweredRequirement;
 use uv_distribution_types::{Index, IndexLocations, Requirement, RequiresPython};
+use uv_fs::Simplified;
 use uv_normalize::{GroupName, PackageName};
 use uv_pep508::RequirementOrigin;
 use uv_pypi_types::{Conflicts, SupportedEnvironments, VerbatimParsedUrl};
 use uv_resolver::{Lock, LockVersion, VERSION};
-use uv_scripts::Pep723Script;
+use uv_scripts::{Pep723Error, Pep723Script};
 use uv_workspace::dependency_groups::{DependencyGroupError, FlatDependencyGroup};
-use uv_workspace::pyproject::OverrideDependency;
+use uv_workspace::pyproject::{OverrideDependency, Sources};
 use uv_workspace::{Editability, Workspace, WorkspaceCache, WorkspaceMember};
 
 use crate::commands::project::{ProjectError, find_requires_python};
@@ -26,6 +30,37 @@ use crate::commands::project::{ProjectError, find_requires_python};
 pub(crate) enum LockTarget<'lock> {
     Workspace(&'lock Workspace),
     Script(&'lock Pep723Script),
+    Scripts(&'lock SharedScripts),
+}
+
+/// A [`LockTarget`] for a single workspace or script.
+///
+/// Used by commands that don't support lockfiles shared by multiple scripts.
+#[derive(Debug, Copy, Clone)]
+pub(crate) enum SingleLockTarget<'lock> {
+    Workspace(&'lock Workspace),
+    Script(&'lock Pep723Script),
+}
+
+impl<'lock> From<SingleLockTarget<'lock>> for LockTarget<'lock> {
+    fn from(target: SingleLockTarget<'lock>) -> Self {
+        match target {
+            SingleLockTarget::Workspace(workspace) => Self::Workspace(workspace),
+            SingleLockTarget::Script(script) => Self::Script(script),
+        }
+    }
+}
+
+impl<'lock> SingleLockTarget<'lock> {
+    /// Return the path to the lock root.
+    pub(crate) fn install_path(self) -> &'lock Path {
+        LockTarget::from(self).install_path()
+    }
+
+    /// Return the path to the lockfile.
+    pub(crate) fn lock_path(self) -> PathBuf {
+        LockTarget::from(self).lock_path()
+    }
 }
 
 impl<'lock> From<&'lock Workspace> for LockTarget<'lock> {
@@ -47,6 +82,8 @@ impl<'lock> LockTarget<'lock> {
         match self {
             Self::Workspace(workspace) => workspace.requirements(),
             Self::Script(script) => script.metadata.dependencies.clone().unwrap_or_default(),
+            // The requirements of each script are modeled as a dependency group.
+            Self::Scripts(_) => Vec::new(),
         }
     }
 
@@ -64,6 +101,10 @@ impl<'lock> LockTarget<'lock> {
                 .flatten()
                 .cloned()
                 .collect(),
+            Self::Scripts(shared) => shared
+                .scripts()
+                .flat_map(|script| Self::Script(script).overrides())
+                .collect(),
         }
     }
 
@@ -81,6 +122,10 @@ impl<'lock> LockTarget<'lock> {
                 .flatten()
                 .cloned()
                 .collect(),
+            Self::Scripts(shared) => shared
+                .scripts()
+                .flat_map(|script| Self::Script(script).exclude_dependencies())
+                .collect(),
         }
     }
 
@@ -98,6 +143,10 @@ impl<'lock> LockTarget<'lock> {
                 .flatten()
                 .cloned()
                 .collect(),
+            Self::Scripts(shared) => shared
+                .scripts()
+                .flat_map(|script| Self::Script(script).constraints())
+                .collect(),
         }
     }
 
@@ -115,6 +164,10 @@ impl<'lock> LockTarget<'lock> {
                 .flatten()
                 .cloned()
                 .collect(),
+            Self::Scripts(shared) => shared
+                .scripts()
+                .flat_map(|script| Self::Script(script).build_constraints())
+                .collect(),
         }
     }
 
@@ -126,6 +179,19 @@ impl<'lock> LockTarget<'lock> {
         match self {
             Self::Workspace(workspace) => workspace.workspace_dependency_groups(),
             Self::Script(_) => Ok(BTreeMap::new()),
+            Self::Scripts(shared) => Ok(shared
+                .scripts
+                .iter()
+                .map(|(group, script)| {
+                    (
+                        group.clone(),
+                        FlatDependencyGroup {
+                            requirements: script.metadata.dependencies.clone().unwrap_or_default(),
+                            requires_python: None,
+                        },
+                    )
+                })
+                .collect()),
         }
     }
 
@@ -133,7 +199,7 @@ impl<'lock> LockTarget<'lock> {
     pub(crate) fn members_requirements(self) -> impl Iterator<Item = Requirement> + 'lock {
         match self {
             Self::Workspace(workspace) => Either::Left(workspace.members_requirements()),
-            Self::Script(_) => Either::Right(std::iter::empty()),
+            Self::Script(_) | Self::Scripts(_) => Either::Right(std::iter::empty()),
         }
     }
 
@@ -141,7 +207,7 @@ impl<'lock> LockTarget<'lock> {
     pub(crate) fn group_requirements(self) -> impl Iterator<Item = Requirement> + 'lock {
         match self {
             Self::Workspace(workspace) => Either::Left(workspace.group_requirements()),
-            Self::Script(_) => Either::Right(std::iter::empty()),
+            Self::Script(_) | Self::Scripts(_) => Either::Right(std::iter::empty()),
         }
     }
 
@@ -161,7 +227,7 @@ impl<'lock> LockTarget<'lock> {
 
                 members
             }
-            Self::Script(_) => Vec::new(),
+            Self::Script(_) | Self::Scripts(_) => Vec::new(),
         }
     }
 
@@ -169,7 +235,7 @@ impl<'lock> LockTarget<'lock> {
     pub(crate) fn packages(self) -> &'lock BTreeMap<PackageName, WorkspaceMember> {
         match self {
             Self::Workspace(workspace) => workspace.packages(),
-            Self::Script(_) => {
+            Self::Script(_) | Self::Scripts(_) => {
                 static EMPTY: BTreeMap<PackageName, WorkspaceMember> = BTreeMap::new();
                 &EMPTY
             }
@@ -181,7 +247,7 @@ impl<'lock> LockTarget<'lock> {
     pub(crate) fn required_members(self) -> &'lock BTreeMap<PackageName, Editability> {
         match self {
             Self::Workspace(workspace) => workspace.required_members(),
-            Self::Script(_) => {
+            Self::Script(_) | Self::Scripts(_) => {
                 static EMPTY: BTreeMap<PackageName, Editability> = BTreeMap::new();
                 &EMPTY
             }
@@ -192,7 +258,7 @@ impl<'lock> LockTarget<'lock> {
     pub(crate) fn environments(self) -> Option<&'lock SupportedEnvironments> {
         match self {
             Self::Workspace(workspace) => workspace.environments(),
-            Self::Script(_) => {
+            Self::Script(_) | Self::Scripts(_) => {
                 // TODO(charlie): Add support for environments in scripts.
                 None
             }
@@ -203,18 +269,27 @@ impl<'lock> LockTarget<'lock> {
     pub(crate) fn required_environments(self) -> Option<&'lock SupportedEnvironments> {
         match self {
             Self::Workspace(workspace) => workspace.required_environments(),
-            Self::Script(_) => {
+            Self::Script(_) | Self::Scripts(_) => {
                 // TODO(charlie): Add support for environments in scripts.
                 None
             }
         }
     }
 
+    /// Returns `true` if the provenance of locked packages should be recorded for the
+    /// [`LockTarget`].
+    pub(crate) fn lock_provenance(self) -> bool {
+        match self {
+            Self::Workspace(workspace) => workspace.lock_provenance(),
+            Self::Script(_) | Self::Scripts(_) => false,
+        }
+    }
+
     /// Returns the set of conflicts for the [`LockTarget`].
     pub(crate) fn conflicts(self) -> Result<Conflicts, ProjectError> {
         match self {
             Self::Workspace(workspace) => Ok(workspace.conflicts()?),
-            Self::Script(_) => Ok(Conflicts::empty()),
+            Self::Script(_) | Self::Scripts(_) => Ok(Conflicts::empty()),
         }
     }
 
@@ -233,16 +308,8 @@ impl<'lock> LockTarget<'lock> {
                         .flatten()
                 }),
             )),
-            Self::Script(script) => Either::Right(
-                script
-                    .metadata
-                    .tool
-                    .as_ref()
-                    .and_then(|tool| tool.uv.as_ref())
-                    .and_then(|uv| uv.top_level.index.as_deref())
-                    .into_iter()
-                    .flatten(),
-            ),
+            Self::Script(script) => Either::Right(script_indexes(script).iter()),
+            Self::Scripts(shared) => Either::Right(shared.indexes.iter()),
         }
     }
 
@@ -259,6 +326,11 @@ impl<'lock> LockTarget<'lock> {
                 .requires_python
                 .as_ref()
                 .map(|specifiers| RequiresPython::from_specifiers(specifiers.clone()))),
+            Self::Scripts(shared) => {
+                Ok(RequiresPython::intersection(shared.scripts().filter_map(
+                    |script| script.metadata.requires_python.as_ref(),
+                )))
+            }
         }
     }
 
@@ -267,6 +339,7 @@ impl<'lock> LockTarget<'lock> {
         match self {
             Self::Workspace(workspace) => workspace.install_path(),
             Self::Script(script) => script.path.parent().unwrap(),
+            Self::Scripts(shared) => &shared.root,
         }
     }
 
@@ -289,6 +362,8 @@ impl<'lock> LockTarget<'lock> {
                 file_name.push(".lock");
                 script.path.with_file_name(file_name)
             }
+            // The lockfile shared by the scripts, e.g., `uv.lock`
+            Self::Scripts(shared) => shared.lock_path.clone(),
         }
     }
 
@@ -309,40 +384,39 @@ impl<'lock> LockTarget<'lock> {
         let lock_path = self.lock_path();
         match fs_err::tokio::read_to_string(&lock_path).await {
             Ok(encoded) => {
-                let result = info_span!("toml::from_str lock", path = %lock_path.display())
-                    .in_scope(|| toml::from_str::<Lock>(&encoded));
-                match result {
-                    Ok(lock) => {
-                        // If the lockfile uses an unsupported version, raise an error.
-                        if lock.version() != VERSION {
-                            return Err(ProjectError::UnsupportedLockVersion(
-                                VERSION,
-                                lock.version(),
-                            ));
-                        }
-                        Ok(Some((lock, encoded)))
-                    }
-                    Err(err) => {
-                        // If we failed to parse the lockfile, determine whether it's a supported
-                        // version.
-                        if let Ok(lock) = toml::from_str::<LockVersion>(&encoded) {
-                            if lock.version() != VERSION {
-                                return Err(ProjectError::UnparsableLockVersion(
-                                    VERSION,
-                                    lock.version(),
-                                    err,
-                                ));
-                            }
-                        }
-                        Err(ProjectError::UvLockParse(err))
-                    }
-                }
+                let lock = parse_lock(&encoded, &lock_path)?;
+                Ok(Some((lock, encoded)))
             }
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
             Err(err) => Err(err.into()),
         }
     }
 
+    /// Read the lockfile, splitting any unresolved merge conflicts into the contents on either
+    /// side of the conflict.
+    ///
+    /// Returns `Ok(None)` if the lockfile does not exist or does not contain conflict markers.
+    pub(crate) async fn read_conflicted(self) -> Result<Option<ConflictedLock>, ProjectError> {
+        let lock_path = self.lock_path();
+        match fs_err::tokio::read_to_string(&lock_path).await {
+            Ok(encoded) => Ok(ConflictedLock::from_contents(&encoded, lock_path)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Validate that the workspace members match the members in the given lockfile.
+    pub(crate) fn validate_members(self, lock: &Lock) -> Result<(), ProjectError> {
+        if let Self::Workspace(workspace) = self {
+            for package_name in workspace.packages().keys() {
+                lock.find_by_name(package_name)
+                    .map_err(|_| ProjectError::LockWorkspaceMismatch(package_name.clone()))?
+                    .ok_or_else(|| ProjectError::LockWorkspaceMismatch(package_name.clone()))?;
+            }
+        }
+        Ok(())
+    }
+
     /// Read the lockfile from the workspace as bytes.
     pub(crate) async fn read_bytes(self) -> Result<Option<Vec<u8>>, std::io::Error> {
         match fs_err::tokio::read(self.lock_path()).await {
@@ -400,16 +474,6 @@ impl<'lock> LockTarget<'lock> {
                     .collect::<Vec<_>>())
             }
             Self::Script(script) => {
-                // Collect any `tool.uv.index` from the script.
-                let empty = Vec::default();
-                let indexes = script
-                    .metadata
-                    .tool
-                    .as_ref()
-                    .and_then(|tool| tool.uv.as_ref())
-                    .and_then(|uv| uv.top_level.index.as_deref())
-                    .unwrap_or(&empty);
-
                 // Collect any `tool.uv.sources` from the script.
                 let empty = BTreeMap::default();
                 let sources_map = script
@@ -420,43 +484,386 @@ impl<'lock> LockTarget<'lock> {
                     .and_then(|uv| uv.sources.as_ref())
                     .unwrap_or(&empty);
 
-                let mut lowered = Vec::new();
-                for requirement in requirements {
-                    if sources.for_package(&requirement.name) {
-                        lowered.push(Requirement::from(requirement));
-                        continue;
-                    }
+                lower_script_requirements(
+                    requirements,
+                    self.install_path(),
+                    sources_map,
+                    script_indexes(script),
+                    locations,
+                    sources,
+                    cache,
+                    workspace_cache,
+                    credentials_cache,
+                )
+                .await
+            }
+            Self::Scripts(shared) => {
+                lower_script_requirements(
+                    requirements,
+                    &shared.root,
+                    &shared.sources,
+                    &shared.indexes,
+                    locations,
+                    sources,
+                    cache,
+                    workspace_cache,
+                    credentials_cache,
+                )
+                .await
+            }
+        }
+    }
+}
+
+/// Return the `tool.uv.index` definitions from a PEP 723 script.
+fn script_indexes(script: &Pep723Script) -> &[Index] {
+    script
+        .metadata
+        .tool
+        .as_ref()
+        .and_then(|tool| tool.uv.as_ref())
+        .and_then(|uv| uv.top_level.index.as_deref())
+        .unwrap_or_default()
+}
+
+/// Lower the requirements of one or more PEP 723 scripts, relative to the given root.
+async fn lower_script_requirements(
+    requirements: Vec<uv_pep508::Requirement<VerbatimParsedUrl>>,
+    root: &Path,
+    sources_map: &BTreeMap<PackageName, Sources>,
+    indexes: &[Index],
+    locations: &IndexLocations,
+    sources: &NoSources,
+    cache: &Cache,
+    workspace_cache: &WorkspaceCache,
+    credentials_cache: &CredentialsCache,
+) -> Result<Vec<Requirement>, uv_distribution::MetadataError> {
+    let mut lowered = Vec::new();
+    for requirement in requirements {
+        if sources.for_package(&requirement.name) {
+            lowered.push(Requirement::from(requirement));
+            continue;
+        }
 
-                    let requirement_name = requirement.name.clone();
-                    lowered.extend(
-                        LoweredRequirement::from_non_workspace_requirement(
-                            requirement,
-                            script.path.parent().unwrap(),
-                            sources_map,
-                            indexes,
-                            locations,
-                            cache,
-                            workspace_cache,
-                            credentials_cache,
+        let requirement_name = requirement.name.clone();
+        lowered.extend(
+            LoweredRequirement::from_non_workspace_requirement(
+                requirement,
+                root,
+                sources_map,
+                indexes,
+                locations,
+                cache,
+                workspace_cache,
+                credentials_cache,
+            )
+            .await
+            .map(|requirement| {
+                requirement
+                    .map(LoweredRequirement::into_inner)
+                    .map_err(|err| {
+                        uv_distribution::MetadataError::LoweringError(
+                            requirement_name.clone(),
+                            Box::new(err),
                         )
-                        .await
-                        .map(|requirement| {
-                            requirement
-                                .map(LoweredRequirement::into_inner)
-                                .map_err(|err| {
-                                    uv_distribution::MetadataError::LoweringError(
-                                        requirement_name.clone(),
-                                        Box::new(err),
-                                    )
-                                })
-                        })
-                        .collect::<Result<Vec<_>, _>>()?,
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+        );
+    }
+    Ok(lowered)
+}
+
+/// A set of PEP 723 scripts that share a single lockfile.
+///
+/// The scripts must live alongside the lockfile. The requirements of each script are locked as a
+/// dependency group named after the script, such that each script can be installed from its own
+/// subset of the lockfile.
+#[derive(Debug)]
+pub(crate) struct SharedScripts {
+    /// The absolute path to the lockfile.
+    lock_path: PathBuf,
+    /// The directory that contains the lockfile and the scripts.
+    root: PathBuf,
+    /// The scripts that share the lockfile, keyed by their dependency group.
+    scripts: BTreeMap<GroupName, Pep723Script>,
+    /// The `tool.uv.sources` across all scripts.
+    sources: BTreeMap<PackageName, Sources>,
+    /// The `tool.uv.index` definitions across all scripts.
+    indexes: Vec<Index>,
+}
+
+impl SharedScripts {
+    /// Collect the scripts that share the lockfile at the given path.
+    pub(crate) fn new(
+        lock_path: &Path,
+        scripts: impl IntoIterator<Item = Pep723Script>,
+    ) -> Result<Self, ProjectError> {
+        let lock_path = std::path::absolute(lock_path)?;
+        let Some(root) = lock_path.parent().map(Path::to_path_buf) else {
+            return Err(ProjectError::SharedLockPath(
+                lock_path.user_display().to_string(),
+            ));
+        };
+
+        let mut by_group = BTreeMap::new();
+        let mut sources = BTreeMap::new();
+        let mut indexes = Vec::new();
+        for script in scripts {
+            let path = std::path::absolute(&script.path)?;
+            if path.parent() != Some(root.as_path()) {
+                return Err(ProjectError::SharedLockDirectory(
+                    script.path.user_display().to_string(),
+                    root.user_display().to_string(),
+                ));
+            }
+
+            // Use the (normalized) file stem as the name of the script's dependency group.
+            let stem = path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy())
+                .unwrap_or_default();
+            let group = GroupName::from_str(&stem).map_err(|err| {
+                ProjectError::SharedLockScriptName(script.path.user_display().to_string(), err)
+            })?;
+
+            if let Some(tool_uv) = script
+                .metadata
+                .tool
+                .as_ref()
+                .and_then(|tool| tool.uv.as_ref())
+            {
+                for (name, source) in tool_uv.sources.iter().flatten() {
+                    match sources.entry(name.clone()) {
+                        Entry::Vacant(entry) => {
+                            entry.insert(source.clone());
+                        }
+                        Entry::Occupied(entry) => {
+                            if entry.get() != source {
+                                return Err(ProjectError::SharedLockSourceConflict(name.clone()));
+                            }
+                        }
+                    }
+                }
+            }
+            for index in script_indexes(&script) {
+                if !indexes.contains(index) {
+                    indexes.push(index.clone());
+                }
+            }
+
+            match by_group.entry(group) {
+                Entry::Vacant(entry) => {
+                    entry.insert(Pep723Script { path, ..script });
+                }
+                Entry::Occupied(entry) => {
+                    return Err(ProjectError::SharedLockScriptCollision(
+                        entry.get().path.user_display().to_string(),
+                        script.path.user_display().to_string(),
+                        entry.key().clone(),
+                    ));
+                }
+            }
+        }
+
+        Ok(Self {
+            lock_path,
+            root,
+            scripts: by_group,
+            sources,
+            indexes,
+        })
+    }
+
+    /// Discover the lockfile shared by the given script, if any.
+    ///
+    /// A script shares the `uv.lock` in its directory if the lockfile lists it among its scripts.
+    /// The other scripts listed in the lockfile are read from disk; any that no longer exist are
+    /// dropped.
+    pub(crate) async fn discover(script: &Pep723Script) -> Result<Option<Self>, ProjectError> {
+        let Some(file_name) = script.path.file_name().and_then(|name| name.to_str()) else {
+            return Ok(None);
+        };
+        let lock_path = script.path.with_file_name("uv.lock");
+        let lock = match fs_err::tokio::read_to_string(&lock_path).await {
+            Ok(encoded) => match parse_lock(&encoded, &lock_path) {
+                Ok(lock) => lock,
+                Err(err) => {
+                    // The lockfile may belong to an unrelated project.
+                    debug!(
+                        "Ignoring unreadable lockfile at `{}`: {err}",
+                        lock_path.user_display()
+                    );
+                    return Ok(None);
+                }
+            },
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        if !lock.scripts().contains(file_name) {
+            return Ok(None);
+        }
+
+        debug!("Found shared lockfile at: `{}`", lock_path.user_display());
+        let mut scripts = vec![script.clone()];
+        for name in lock.scripts() {
+            if name == file_name {
+                continue;
+            }
+            let path = script.path.with_file_name(name);
+            match Pep723Script::read(&path).await {
+                Ok(Some(script)) => scripts.push(script),
+                Ok(None) => {
+                    debug!(
+                        "Ignoring `{}` in shared lockfile, as it no longer contains a PEP 723 metadata tag",
+                        path.user_display()
                     );
                 }
-                Ok(lowered)
+                Err(Pep723Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {
+                    debug!(
+                        "Ignoring `{}` in shared lockfile, as it no longer exists",
+                        path.user_display()
+                    );
+                }
+                Err(err) => return Err(ProjectError::Anyhow(err.into())),
+            }
+        }
+
+        Self::new(&lock_path, scripts).map(Some)
+    }
+
+    /// Return the scripts that share the lockfile.
+    pub(crate) fn scripts(&self) -> impl Iterator<Item = &Pep723Script> {
+        self.scripts.values()
+    }
+
+    /// Return the file names of the scripts that share the lockfile, as recorded in the lockfile.
+    pub(crate) fn file_names(&self) -> BTreeSet<String> {
+        self.scripts
+            .values()
+            .filter_map(|script| script.path.file_name())
+            .map(|name| name.to_string_lossy().into_owned())
+            .collect()
+    }
+
+    /// Return the dependency group that holds the requirements of the script at the given path.
+    pub(crate) fn group(&self, path: &Path) -> Option<&GroupName> {
+        let path = std::path::absolute(path).ok()?;
+        self.scripts
+            .iter()
+            .find(|(_, script)| script.path == path)
+            .map(|(group, _)| group)
+    }
+
+    /// Return the script whose requirements differ between the previous and current lock, if
+    /// any, for use in user-facing messages.
+    pub(crate) fn changed_script(&self, previous: Option<&Lock>, current: &Lock) -> Option<String> {
+        let previous = previous?;
+
+        // Identify any script that was added, or whose requirements changed.
+        for (group, script) in &self.scripts {
+            let added = script
+                .path
+                .file_name()
+                .is_some_and(|name| !previous.scripts().contains(&*name.to_string_lossy()));
+            let requirements = |lock: &Lock| {
+                lock.dependency_group_requirements(group)
+                    .filter(|requirements| !requirements.is_empty())
+                    .cloned()
+            };
+            if added || requirements(previous) != requirements(current) {
+                return Some(script.path.user_display().to_string());
+            }
+        }
+
+        // Identify any script that was removed.
+        previous
+            .scripts()
+            .difference(current.scripts())
+            .next()
+            .cloned()
+    }
+}
+
+/// A lockfile with unresolved merge conflicts, split into the contents on either side.
+#[derive(Debug)]
+pub(crate) struct ConflictedLock {
+    lock_path: PathBuf,
+    ours: String,
+    theirs: String,
+}
+
+impl ConflictedLock {
+    /// Split the lockfile contents along any merge conflict markers.
+    ///
+    /// Returns `None` if the contents don't contain any conflicts.
+    fn from_contents(source: &str, lock_path: PathBuf) -> Option<Self> {
+        let (ours, theirs) = split_conflicts(source)?;
+        Some(Self {
+            lock_path,
+            ours,
+            theirs,
+        })
+    }
+
+    /// Parse the lockfile on either side of the conflicts, starting with the current branch.
+    pub(crate) fn sides(&self) -> impl Iterator<Item = Result<Lock, ProjectError>> {
+        [&self.ours, &self.theirs]
+            .into_iter()
+            .map(|encoded| parse_lock(encoded, &self.lock_path))
+    }
+}
+
+/// Return `true` if the contents contain Git merge conflict markers.
+pub(crate) fn has_conflict_markers(source: &str) -> bool {
+    split_conflicts(source).is_some()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConflictSection {
+    Both,
+    Ours,
+    Base,
+    Theirs,
+}
+
+/// Split the contents into the versions on either side of any Git merge conflicts.
+///
+/// Lines outside of a conflict are shared by both sides, while the common ancestor emitted by the
+/// `diff3` and `zdiff3` conflict styles is dropped. Returns `None` if there are no conflicts.
+fn split_conflicts(source: &str) -> Option<(String, String)> {
+    let mut ours = String::with_capacity(source.len());
+    let mut theirs = String::with_capacity(source.len());
+    let mut section = ConflictSection::Both;
+    let mut conflicted = false;
+
+    for line in source.split_inclusive('\n') {
+        let marker = line.trim_end_matches(['\n', '\r']);
+        match section {
+            ConflictSection::Both if marker.starts_with("<<<<<<<") => {
+                section = ConflictSection::Ours;
+                conflicted = true;
             }
+            ConflictSection::Both => {
+                ours.push_str(line);
+                theirs.push_str(line);
+            }
+            ConflictSection::Ours if marker.starts_with("|||||||") => {
+                section = ConflictSection::Base;
+            }
+            ConflictSection::Ours | ConflictSection::Base if marker == "=======" => {
+                section = ConflictSection::Theirs;
+            }
+            ConflictSection::Ours => ours.push_str(line),
+            ConflictSection::Base => {}
+            ConflictSection::Theirs if marker.starts_with(">>>>>>>") => {
+                section = ConflictSection::Both;
+            }
+            ConflictSection::Theirs => theirs.push_str(line),
         }
     }
+
+    conflicted.then_some((ours, theirs))
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -583,16 +990,59 @@ pub(crate) fn find_lock_format_error(source: &str) -> Option<usize> {
     Some(line)
 }
 
+/// Read a lockfile from the given path, or from stdin if the path is `-`.
+pub(crate) async fn read_lockfile(path: &Path) -> Result<Lock, ProjectError> {
+    let encoded = if path == Path::new("-") {
+        let mut encoded = String::new();
+        std::io::stdin().read_to_string(&mut encoded)?;
+        encoded
+    } else {
+        fs_err::tokio::read_to_string(path).await?
+    };
+    parse_lock(&encoded, path)
+}
+
+/// Parse a lockfile, raising an error if it uses an unsupported version.
+fn parse_lock(encoded: &str, lock_path: &Path) -> Result<Lock, ProjectError> {
+    let result = info_span!("toml::from_str lock", path = %lock_path.display())
+        .in_scope(|| toml::from_str::<Lock>(encoded));
+    match result {
+        Ok(lock) => {
+            // If the lockfile uses an unsupported version, raise an error.
+            if lock.version() != VERSION {
+                return Err(ProjectError::UnsupportedLockVersion(
+                    VERSION,
+                    lock.version(),
+                ));
+            }
+            Ok(lock)
+        }
+        Err(err) => {
+            // If we failed to parse the lockfile, determine whether it's a supported version.
+            if let Ok(lock) = toml::from_str::<LockVersion>(encoded) {
+                if lock.version() != VERSION {
+                    return Err(ProjectError::UnparsableLockVersion(
+                        VERSION,
+                        lock.version(),
+                        err,
+                    ));
+                }
+            }
+            Err(ProjectError::UvLockParse(err))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::find_lock_format_error;
+    use super::{find_lock_format_error, split_conflicts};
 
     const FORMATTED: &str = r#"version = 1
-revision = 3
+revision = 4
 requires-python = ">=3.12"
 resolution-markers = [
-    "sys_platform == 'darwin'",
     "sys_platform != 'darwin'",
+    "sys_platform == 'darwin'",
 ]
 conflicts = [[
     { package = "project", extra = "cpu" },
@@ -640,4 +1090,58 @@ requires-dist = [{ name = "sniffio" }]
 
         assert!(find_lock_format_error(FORMATTED.trim_end_matches('\n')).is_some());
     }
+
+    #[test]
+    fn splits_merge_conflicts() {
+        let conflicted = r#"version = 1
+
+[[package]]
+name = "anyio"
+<<<<<<< HEAD
+version = "4.3.0"
+||||||| base
+version = "4.0.0"
+=======
+version = "4.4.0"
+>>>>>>> feature
+
+[[package]]
+name = "idna"
+<<<<<<< HEAD
+version = "3.6"
+=======
+version = "3.7"
+>>>>>>> feature
+"#;
+
+        let (ours, theirs) = split_conflicts(conflicted).unwrap();
+        assert_eq!(
+            ours,
+            r#"version = 1
+
+[[package]]
+name = "anyio"
+version = "4.3.0"
+
+[[package]]
+name = "idna"
+version = "3.6"
+"#
+        );
+        assert_eq!(
+            theirs,
+            r#"version = 1
+
+[[package]]
+name = "anyio"
+version = "4.4.0"
+
+[[package]]
+name = "idna"
+version = "3.7"
+"#
+        );
+
+        assert_eq!(split_conflicts(FORMATTED), None);
+    }
 }
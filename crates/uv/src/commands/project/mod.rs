@@ -31,14 +31,15 @@ use uv_normalize::{DEV_DEPENDENCIES, DefaultGroups, ExtraName, GroupName, Packag
 use uv_pep440::{TildeVersionSpecifier, Version, VersionSpecifiers};
 use uv_pep508::MarkerTreeContents;
 use uv_preview::{Preview, PreviewFeature};
-use uv_pypi_types::{ConflictItem, ConflictKind, ConflictSet, Conflicts};
+use uv_pypi_types::{ConflictItem, ConflictKind, ConflictSet, Conflicts, HashDigest};
 use uv_python::managed::{ManagedPythonInstallation, PythonMinorVersionLink};
 use uv_python::{
-    BrokenLink, ConfigDiscovery, EnvironmentPreference, Interpreter, InvalidEnvironmentKind,
-    LenientImplementationName, PythonDownloads, PythonEnvironment, PythonInstallation,
-    PythonPreference, PythonRequest, PythonSource, PythonVariant, PythonVersionFile,
-    VersionFileDiscoveryOptions, VersionRequest,
+    BrokenLink, CondaEnvironment, ConfigDiscovery, EnvironmentPreference, Interpreter,
+    InvalidEnvironmentKind, LenientImplementationName, PythonDownloads, PythonEnvironment,
+    PythonInstallation, PythonPreference, PythonRequest, PythonSource, PythonVariant,
+    PythonVersionFile, VersionFileDiscoveryOptions, VersionRequest,
 };
+use uv_redacted::DisplaySafeUrl;
 use uv_requirements::{
     LockedRequirements, NamedRequirementsResolver, RequirementsSpecification,
     read_lock_requirements,
@@ -59,6 +60,7 @@ use uv_workspace::{ProjectEnvironmentSelection, RequiresPythonSources, Workspace
 
 use crate::commands::pip::loggers::{InstallLogger, ResolveLogger};
 use crate::commands::pip::operations::{Changelog, Modifications};
+use crate::commands::project::index_metadata::IndexMetadataMismatches;
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::reporters::{PythonDownloadReporter, ResolverReporter};
 use crate::commands::{capitalize, conjunction, pip};
@@ -70,15 +72,19 @@ use crate::settings::{
 
 pub(crate) mod add;
 pub(crate) mod audit;
+mod bundle;
 pub(crate) mod check;
+mod diff;
 pub(crate) mod environment;
 pub(crate) mod export;
 pub(crate) mod format;
+mod index_metadata;
 pub(crate) mod init;
 pub(crate) mod install_target;
 pub(crate) mod lock;
 pub(crate) mod lock_target;
 pub(crate) mod remove;
+pub(crate) mod repair;
 pub(crate) mod run;
 pub(crate) mod sync;
 mod toolchain;
@@ -145,21 +151,44 @@ pub(crate) enum ProjectError {
     #[error("The lockfile at `uv.lock` needs to be updated, but `{2}` was provided.")]
     LockMismatch(Option<Box<Lock>>, Box<Lock>, LockCheckSource),
 
+    #[error(
+        "The lockfile at `{0}` needs to be updated for changes to `{1}`, but `{2}` was provided."
+    )]
+    SharedLockMismatch(PathBuf, String, LockCheckSource),
+
     #[error(
         "The lockfile at `{0}` has non-canonical formatting at line {1}, but `{2}` was provided."
     )]
     LockFormat(PathBuf, usize, LockCheckSource),
 
+    #[error(
+        "The index metadata pinned in `uv.lock` no longer matches the index, but `{1}` was provided:\n{0}"
+    )]
+    IndexMetadataMismatch(IndexMetadataMismatches, LockCheckSource),
+
+    #[error(
+        "The remote file `{0}` recorded in `uv.lock` has changed (expected `{1}`, found `{2}`), but `{3}` was provided."
+    )]
+    RemoteFileMismatch(DisplaySafeUrl, HashDigest, HashDigest, LockCheckSource),
+
     #[error(
         "Unable to find lockfile at `{1}`, but {0} was provided. To create a lockfile, run `uv lock` or `uv sync` without the flag."
     )]
     MissingLockfile(MissingLockfileSource, PathBuf),
 
+    #[error(
+        "`{0}` contains unresolved merge conflicts; resolve them before running `uv lock --resolve-conflicts`"
+    )]
+    ConflictedPyproject(String),
+
     #[error(
         "The lockfile at `uv.lock` needs to be updated, but `--frozen` was provided: Missing workspace member `{0}`."
     )]
     LockWorkspaceMismatch(PackageName),
 
+    #[error("The lockfile at `{0}` does not match the workspace: Missing workspace member `{1}`.")]
+    LockfileWorkspaceMismatch(String, PackageName),
+
     #[error(
         "The lockfile at `uv.lock` uses an unsupported schema version (v{1}, but only v{0} is supported). Downgrade to a compatible uv version, or remove the `uv.lock` prior to running `uv lock` or `uv sync`."
     )]
@@ -225,6 +254,11 @@ pub(crate) enum ProjectError {
     )]
     RequiresPythonScriptIncompatibility(Version, RequiresPython),
 
+    #[error(
+        "A Python interpreter is required to lock or sync the project, but none was discovered"
+    )]
+    MissingInterpreter,
+
     #[error("Group `{0}` is not defined in the project's `dependency-groups` table")]
     MissingGroupProject(GroupName),
 
@@ -234,11 +268,40 @@ pub(crate) enum ProjectError {
     #[error("PEP 723 scripts do not support dependency groups, but group `{0}` was specified")]
     MissingGroupScript(GroupName),
 
+    #[error(
+        "Scripts that share a lockfile must be in the same directory as the lockfile (`{1}`), but `{0}` is not"
+    )]
+    SharedLockDirectory(String, String),
+
+    #[error("Expected the shared lockfile at `{0}` to be in a directory")]
+    SharedLockPath(String),
+
+    #[error("At least one script is required to share the lockfile at `{0}`")]
+    SharedLockEmpty(String),
+
+    #[error(
+        "The script name `{0}` is not a valid dependency group name, which is required to share a lockfile"
+    )]
+    SharedLockScriptName(String, #[source] uv_normalize::InvalidNameError),
+
+    #[error(
+        "Scripts that share a lockfile must have distinct names, but `{0}` and `{1}` both map to `{2}`"
+    )]
+    SharedLockScriptCollision(String, String, GroupName),
+
+    #[error("Scripts that share a lockfile must declare the same `tool.uv.sources` for `{0}`")]
+    SharedLockSourceConflict(PackageName),
+
     #[error(
         "Default group `{0}` (from `tool.uv.default-groups`) is not defined in the project's `dependency-groups` table"
     )]
     MissingDefaultGroup(GroupName),
 
+    #[error(
+        "Found inconsistencies in the project configuration, and `tool.uv.strict-config` is enabled:\n\n{0}"
+    )]
+    StrictConfig(String),
+
     #[error("Extra `{0}` is not defined in the `optional-dependencies` table for `{1}`")]
     MissingExtraProject(ExtraName, PackageName),
 
@@ -268,6 +331,13 @@ pub(crate) enum ProjectError {
     #[error("Project virtual environment directory `{0}` cannot be used because {1}")]
     InvalidProjectEnvironmentDir(PathBuf, String),
 
+    #[error(
+        "The active conda environment `{}` at `{}` is treated as a system environment and will not be synced; use `--allow-conda` to sync into it, or omit `--active` to use the project environment",
+        _0,
+        _1.user_display()
+    )]
+    CondaEnvironment(String, PathBuf),
+
     #[error("Failed to parse `uv.lock`")]
     UvLockParse(#[source] toml::de::Error),
 
@@ -412,6 +482,18 @@ impl uv_errors::Hint for ProjectError {
             Self::LockMismatch(..) | Self::LockWorkspaceMismatch(..) => {
                 uv_errors::Hints::from("To update the lockfile, run `uv lock`.")
             }
+            Self::SharedLockMismatch(..) => uv_errors::Hints::from(
+                "To update the lockfile, run `uv lock --script <scripts> --shared-lock <lockfile>`.",
+            ),
+            Self::LockfileWorkspaceMismatch(..) => {
+                uv_errors::Hints::from("To use the lockfile regardless, provide `--no-validate`.")
+            }
+            Self::IndexMetadataMismatch(..) => uv_errors::Hints::from(
+                "If the changes are expected, run `uv lock --pin-index-metadata` to re-pin the index metadata.",
+            ),
+            Self::RemoteFileMismatch(..) => uv_errors::Hints::from(
+                "If the changes are expected, re-run the command that provided the file without `--locked` to update the lockfile.",
+            ),
             Self::LockFormat(..) => uv_errors::Hints::from(
                 "To regenerate the lockfile, run `uv lock --refresh --preview-features lockfile-format-check`.",
             ),
@@ -1137,6 +1219,63 @@ fn discover_project_environment(
     }
 }
 
+/// Select the environment for a project, accounting for an active conda environment.
+///
+/// Conda environments are treated like system Python environments, rather than virtual
+/// environments: an active conda environment is only selected with `--active` (and only if no
+/// virtual environment is active), and is otherwise ignored in favor of the project environment.
+pub(crate) fn project_environment_selection(
+    workspace: &Workspace,
+    active: Option<bool>,
+) -> ProjectEnvironmentSelection {
+    let selection = workspace.environment_selection(active);
+
+    // An active virtual environment takes precedence, e.g., if it was created and activated from
+    // within a conda environment.
+    if std::env::var_os(EnvVars::VIRTUAL_ENV).is_some_and(|value| !value.is_empty()) {
+        return selection;
+    }
+    let Some(conda) = CondaEnvironment::from_env() else {
+        return selection;
+    };
+
+    // If the conda environment is explicitly selected as the project environment, use it.
+    if selection
+        .explicit_path()
+        .is_some_and(|path| uv_fs::is_same_file_allow_missing(path, conda.root()).unwrap_or(false))
+    {
+        return selection;
+    }
+
+    match active {
+        Some(true) => {
+            debug!(
+                "Using active conda environment `{}` at `{}` instead of the project environment",
+                conda.name(),
+                conda.root().user_display()
+            );
+            ProjectEnvironmentSelection::Conda(conda.root().to_path_buf())
+        }
+        Some(false) => selection,
+        None => {
+            // The base environment is often activated by default, so only warn for environments
+            // that were activated deliberately.
+            if conda.is_base() {
+                debug!(
+                    "Ignoring active base conda environment `{}` in favor of the project environment",
+                    conda.name()
+                );
+            } else {
+                warn_user_once!(
+                    "The active conda environment `{}` will be ignored in favor of the project environment; use `--active --allow-conda` to target the conda environment instead",
+                    conda.name()
+                );
+            }
+            selection
+        }
+    }
+}
+
 /// Return whether to use centralized project environments for this invocation.
 pub(crate) fn centralized_environments_enabled(
     selection: &ProjectEnvironmentSelection,
@@ -1352,7 +1491,7 @@ impl ProjectInterpreter {
         active: Option<bool>,
         cache: &Cache,
     ) -> Result<Option<PythonEnvironment>, ProjectError> {
-        let selection = workspace.environment_selection(active);
+        let selection = project_environment_selection(workspace, active);
         let root = selection
             .explicit_path()
             .map_or_else(|| workspace.install_path().join(".venv"), Path::to_path_buf);
@@ -1388,7 +1527,7 @@ impl ProjectInterpreter {
             requires_python,
         } = workspace_python;
 
-        let environment_selection = workspace.environment_selection(active);
+        let environment_selection = project_environment_selection(workspace, active);
         let centralized = centralized_environments_enabled(&environment_selection, cache);
         let upgradeable = python_request
             .as_ref()
@@ -1802,14 +1941,27 @@ impl ProjectEnvironment {
         no_sync: bool,
         config_discovery: ConfigDiscovery,
         active: Option<bool>,
+        allow_conda: bool,
         cache: &Cache,
         dry_run: DryRun,
         link_error_reporting: LinkErrorReporting,
         printer: Printer,
     ) -> Result<Self, ProjectError> {
-        let environment_selection = workspace.environment_selection(active);
+        let environment_selection = project_environment_selection(workspace, active);
         let centralized = centralized_environments_enabled(&environment_selection, cache);
 
+        // Avoid modifying an active conda environment unless explicitly allowed, as with any other
+        // system Python environment.
+        if let ProjectEnvironmentSelection::Conda(root) = &environment_selection
+            && !allow_conda
+            && !no_sync
+        {
+            let name = CondaEnvironment::from_env()
+                .map(|conda| conda.name().into_owned())
+                .unwrap_or_default();
+            return Err(ProjectError::CondaEnvironment(name, root.clone()));
+        }
+
         // Lock the project environment to avoid synchronization issues.
         let _lock = lock_project_environment(workspace)
             .await
@@ -2284,7 +2436,10 @@ pub(crate) async fn resolve_names(
                 build_isolation,
                 extra_build_dependencies,
                 extra_build_variables,
+                build_env_passthrough,
                 prerelease: _,
+                prerelease_packages: _,
+                require_metadata: _,
                 resolution: _,
                 sources,
                 torch_backend,
@@ -2381,7 +2536,8 @@ pub(crate) async fn resolve_names(
         workspace_cache.clone(),
         concurrency.clone(),
         preview,
-    );
+    )
+    .with_build_env_passthrough(build_env_passthrough.clone());
 
     // Resolve the unnamed requirements.
     requirements.extend(
@@ -2473,6 +2629,8 @@ pub(crate) async fn resolve_environment(
         keyring_provider,
         resolution,
         prerelease,
+        prerelease_packages,
+        require_metadata,
         fork_strategy,
         dependency_metadata,
         config_setting,
@@ -2480,6 +2638,7 @@ pub(crate) async fn resolve_environment(
         build_isolation,
         extra_build_dependencies,
         extra_build_variables,
+        build_env_passthrough,
         exclude_newer,
         link_mode,
         upgrade,
@@ -2579,6 +2738,8 @@ pub(crate) async fn resolve_environment(
     let options = OptionsBuilder::new()
         .resolution_mode(*resolution)
         .prerelease_mode(*prerelease)
+        .prerelease_packages(prerelease_packages.clone())
+        .require_metadata(*require_metadata)
         .fork_strategy(*fork_strategy)
         .exclude_newer(exclude_newer.clone())
         .index_strategy(*index_strategy)
@@ -2661,7 +2822,8 @@ pub(crate) async fn resolve_environment(
         workspace_cache.clone(),
         concurrency.clone(),
         preview,
-    );
+    )
+    .with_build_env_passthrough(build_env_passthrough.clone());
 
     // Resolve the requirements.
     Ok(pip::operations::resolve(
@@ -2725,6 +2887,7 @@ pub(crate) async fn sync_environment(
         build_isolation,
         extra_build_dependencies,
         extra_build_variables,
+        build_env_passthrough,
         exclude_newer,
         link_mode,
         compile_bytecode,
@@ -2803,7 +2966,8 @@ pub(crate) async fn sync_environment(
         workspace_cache,
         concurrency.clone(),
         preview,
-    );
+    )
+    .with_build_env_passthrough(build_env_passthrough.clone());
 
     // Sync the environment.
     pip::operations::install(
@@ -2893,7 +3057,10 @@ pub(crate) async fn update_environment(
                 build_isolation,
                 extra_build_dependencies: _,
                 extra_build_variables,
+                build_env_passthrough,
                 prerelease,
+                prerelease_packages,
+                require_metadata,
                 resolution,
                 sources,
                 torch_backend,
@@ -3017,6 +3184,8 @@ pub(crate) async fn update_environment(
     let options = OptionsBuilder::new()
         .resolution_mode(*resolution)
         .prerelease_mode(*prerelease)
+        .prerelease_packages(prerelease_packages.clone())
+        .require_metadata(*require_metadata)
         .fork_strategy(*fork_strategy)
         .exclude_newer(exclude_newer.clone())
         .index_strategy(*index_strategy)
@@ -3068,7 +3237,8 @@ pub(crate) async fn update_environment(
         workspace_cache.clone(),
         concurrency.clone(),
         preview,
-    );
+    )
+    .with_build_env_passthrough(build_env_passthrough.clone());
 
     // Resolve the requirements.
     let (resolution, hasher) = match pip::operations::resolve(
@@ -3193,6 +3363,14 @@ pub(crate) async fn init_script_python_requirement(
     ))
 }
 
+/// Warn that a workspace member depends on another workspace member without a version specifier,
+/// since the published distributions of the member would accept any version of the dependency.
+pub(crate) fn warn_unbounded_workspace_dependency(member: &PackageName, dependency: &PackageName) {
+    warn_user_once!(
+        "`{member}` depends on workspace member `{dependency}` without a version specifier; published distributions of `{member}` will accept any version of `{dependency}`"
+    );
+}
+
 /// Returns the default dependency groups from the [`PyProjectToml`].
 pub(crate) fn default_dependency_groups(
     pyproject_toml: &PyProjectToml,
@@ -10,7 +10,7 @@ use tracing::{debug, warn};
 use uv_cache::Cache;
 use uv_client::BaseClientBuilder;
 use uv_configuration::{
-    Concurrency, DependencyGroups, DryRun, ExtrasSpecification, InstallOptions,
+    Concurrency, DependencyGroups, DryRun, ExtrasSpecification, InstallOptions, ShowDiff,
 };
 use uv_fs::Simplified;
 use uv_normalize::PackageName;
@@ -27,6 +27,7 @@ use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache};
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
 use crate::commands::pip::operations::Modifications;
 use crate::commands::project::add::{AddTarget, PythonTarget};
+use crate::commands::project::diff;
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::LockMode;
 use crate::commands::project::lock_target::LockTarget;
@@ -47,6 +48,7 @@ pub(crate) async fn remove(
     no_sync: bool,
     packages: Vec<PackageName>,
     dependency_type: DependencyType,
+    show_diff: ShowDiff,
     package: Option<PackageName>,
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
@@ -188,6 +190,14 @@ pub(crate) async fn remove(
 
     let content = toml.to_string();
 
+    // Display the changes and, if `--diff-only`, exit without writing them.
+    if show_diff.enabled() {
+        target.show_diff(&content, printer)?;
+        if show_diff.is_only() {
+            return Ok(ExitStatus::Success);
+        }
+    }
+
     // Save the modified `pyproject.toml` or script.
     target.write(&content)?;
 
@@ -263,6 +273,7 @@ pub(crate) async fn remove(
                     no_sync,
                     config_discovery,
                     active,
+                    false,
                     cache,
                     DryRun::Disabled,
                     LinkErrorReporting::User,
@@ -305,9 +316,9 @@ pub(crate) async fn remove(
 
     // Determine the lock mode.
     let mode = if let LockCheck::Enabled(lock_check) = lock_check {
-        LockMode::Locked(target.interpreter(), lock_check)
+        LockMode::Locked(target.interpreter()?, lock_check)
     } else {
-        LockMode::Write(target.interpreter())
+        LockMode::Write(target.interpreter()?)
     };
 
     // Initialize any shared state.
@@ -412,6 +423,24 @@ enum RemoveTarget {
 }
 
 impl RemoveTarget {
+    /// Display a diff of the changes that writing the updated content would make to the target.
+    fn show_diff(&self, content: &str, printer: Printer) -> Result<()> {
+        match self {
+            Self::Script(script) => {
+                if content == script.metadata.raw {
+                    return Ok(());
+                }
+                let existing = fs_err::read_to_string(&script.path)?;
+                diff::show_diff(&script.path, &existing, &script.render(content), printer)
+            }
+            Self::Project(project) => {
+                let pyproject_path = project.root().join("pyproject.toml");
+                let existing = fs_err::read_to_string(&pyproject_path)?;
+                diff::show_diff(&pyproject_path, &existing, content, printer)
+            }
+        }
+    }
+
     /// Write the updated content to the target.
     ///
     /// Returns `true` if the content was modified.
@@ -0,0 +1,506 @@
+//! Repair a project environment that was moved from its original location (e.g., after renaming
+//! the project directory), or relocate an environment that is moved by `uv venv --move`.
+
+use std::fmt::{Display, Formatter, Write};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use owo_colors::OwoColorize;
+use tracing::{debug, trace};
+
+use uv_distribution_types::Name;
+use uv_fs::Simplified;
+use uv_installer::SitePackages;
+use uv_normalize::PackageName;
+use uv_python::PythonEnvironment;
+use uv_shell::escape_posix_for_single_quotes;
+
+use crate::printer::Printer;
+
+/// The maximum length of a shebang line, beyond which scripts are wrapped in `/bin/sh`.
+///
+/// Matches the limit used when installing scripts from wheels.
+const MAX_SHEBANG_LENGTH: usize = 127;
+
+/// The prefix of a script shebang that is wrapped in `/bin/sh`.
+const SH_SHEBANG_PREFIX: &str = "#!/bin/sh\n'''exec' '";
+
+/// The suffix of a script shebang that is wrapped in `/bin/sh`.
+const SH_SHEBANG_SUFFIX: &str = "' \"$0\" \"$@\"\n' '''";
+
+/// The activation scripts that embed the absolute path to the environment.
+const ACTIVATORS: &[&str] = &[
+    "activate",
+    "activate.bat",
+    "activate.csh",
+    "activate.fish",
+    "activate.nu",
+];
+
+/// A reference to the previous location of a moved environment that was repaired.
+#[derive(Debug)]
+enum Repair {
+    /// A script whose shebang referenced the previous location.
+    Shebang(PathBuf),
+    /// A console-script launcher that referenced the previous location.
+    Launcher(PathBuf),
+    /// An activation script that referenced the previous location.
+    Activator(PathBuf),
+    /// A `pyvenv.cfg` entry that referenced the previous location.
+    PyVenvCfg(String),
+    /// A `.pth` file or editable finder in `site-packages` that referenced the previous location.
+    SitePackages(PathBuf),
+    /// An editable install of a workspace member that referenced the previous location of the
+    /// workspace, which is reinstalled by the subsequent sync.
+    Editable(PackageName, PathBuf),
+}
+
+impl Display for Repair {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Shebang(path) => {
+                write!(f, "Rewrote the shebang of `{}`", path.user_display().cyan())
+            }
+            Self::Launcher(path) => {
+                write!(f, "Rewrote the launcher `{}`", path.user_display().cyan())
+            }
+            Self::Activator(path) => {
+                write!(
+                    f,
+                    "Rewrote the activation script `{}`",
+                    path.user_display().cyan()
+                )
+            }
+            Self::SitePackages(path) => {
+                write!(f, "Rewrote `{}`", path.user_display().cyan())
+            }
+            Self::PyVenvCfg(key) => {
+                write!(f, "Updated `{}` in `{}`", key.cyan(), "pyvenv.cfg".cyan())
+            }
+            Self::Editable(name, path) => {
+                write!(
+                    f,
+                    "Reinstalling editable `{}` (previously at `{}`)",
+                    name.cyan(),
+                    path.user_display().cyan()
+                )
+            }
+        }
+    }
+}
+
+/// Repair an environment that was moved from its original location, rewriting any references to
+/// the previous location, and summarize the repairs.
+///
+/// Only references that clearly point into the previous location of the environment are
+/// rewritten. If the environment lives within the workspace, editable installs that point into the
+/// previous location of the workspace are reported, as the subsequent sync reinstalls them from
+/// the new location.
+pub(crate) fn repair_environment(
+    environment: &PythonEnvironment,
+    workspace_root: Option<&Path>,
+    dry_run: bool,
+    printer: Printer,
+) -> anyhow::Result<()> {
+    let root = environment.root();
+    let Some(previous) = previous_root(environment)? else {
+        debug!(
+            "No previous location detected for environment at: `{}`",
+            root.user_display()
+        );
+        return Ok(());
+    };
+    debug!(
+        "Detected environment moved from `{}` to: `{}`",
+        previous.user_display(),
+        root.user_display()
+    );
+
+    let mut repairs = rewrite_references(environment, &previous, dry_run)?;
+
+    // Identify any editable installs that point into the previous location of the workspace.
+    if let Some(previous_workspace) =
+        workspace_root.and_then(|workspace_root| previous_ancestor(root, workspace_root, &previous))
+    {
+        let site_packages = SitePackages::from_environment(environment)?;
+        for dist in site_packages.iter() {
+            let Some(path) = dist.as_editable().and_then(|url| url.to_file_path().ok()) else {
+                continue;
+            };
+            if path.starts_with(&previous_workspace) {
+                repairs.push(Repair::Editable(dist.name().clone(), path));
+            }
+        }
+    }
+
+    if repairs.is_empty() {
+        debug!("No references to the previous location of the environment were found");
+        return Ok(());
+    }
+
+    writeln!(
+        printer.stderr(),
+        "{} environment moved from `{}`:",
+        if dry_run { "Would repair" } else { "Repaired" }.bold(),
+        previous.user_display().cyan()
+    )?;
+    for repair in &repairs {
+        writeln!(printer.stderr(), " - {repair}")?;
+    }
+
+    Ok(())
+}
+
+/// Rewrite the references to the previous location of an environment that was moved to its
+/// current location by uv.
+///
+/// Unlike [`repair_environment`], the previous location is known, rather than detected.
+pub(crate) fn relocate_environment(
+    environment: &PythonEnvironment,
+    previous: &Path,
+) -> anyhow::Result<()> {
+    for repair in rewrite_references(environment, previous, false)? {
+        debug!("{repair}");
+    }
+    Ok(())
+}
+
+/// Rewrite the references to the previous location of an environment that was moved to its
+/// current location.
+fn rewrite_references(
+    environment: &PythonEnvironment,
+    previous: &Path,
+    dry_run: bool,
+) -> anyhow::Result<Vec<Repair>> {
+    let root = environment.root();
+    let previous_str = previous.simplified_display().to_string();
+    let root_str = root.simplified_display().to_string();
+    let mut repairs = Vec::new();
+
+    // Rewrite the scripts and launchers that invoke the interpreter at the previous location.
+    let mut scripts = Vec::new();
+    for entry in fs_err::read_dir(environment.scripts())? {
+        let entry = entry?;
+        if !entry.file_type()?.is_file() {
+            continue;
+        }
+        if ACTIVATORS
+            .iter()
+            .any(|name| entry.file_name().to_str() == Some(*name))
+        {
+            continue;
+        }
+        scripts.push(entry.path());
+    }
+    scripts.sort();
+    for path in scripts {
+        if cfg!(windows) {
+            if repair_launcher(&path, previous, root, dry_run)? {
+                repairs.push(Repair::Launcher(relative_to(&path, root)));
+            }
+        } else if repair_shebang(&path, previous, root, dry_run)? {
+            repairs.push(Repair::Shebang(relative_to(&path, root)));
+        }
+    }
+
+    // Rewrite the activation scripts, which embed the path to the environment.
+    for name in ACTIVATORS {
+        let path = environment.scripts().join(name);
+        let Ok(contents) = fs_err::read_to_string(&path) else {
+            continue;
+        };
+        let previous_str = escape_posix_for_single_quotes(&previous_str);
+        if !contents.contains(&*previous_str) {
+            continue;
+        }
+        if !dry_run {
+            let root_str = escape_posix_for_single_quotes(&root_str);
+            fs_err::write(&path, contents.replace(&*previous_str, &root_str))?;
+        }
+        repairs.push(Repair::Activator(relative_to(&path, root)));
+    }
+
+    // Update any `pyvenv.cfg` entries that point into the previous location.
+    let contents = fs_err::read_to_string(root.join("pyvenv.cfg"))?;
+    for (key, value) in contents.lines().filter_map(|line| line.split_once('=')) {
+        let (key, value) = (key.trim(), value.trim());
+        let Some(value) = relocate(Path::new(value), previous, root) else {
+            continue;
+        };
+        if !dry_run {
+            environment.set_pyvenv_cfg(key, &value.simplified_display().to_string())?;
+        }
+        repairs.push(Repair::PyVenvCfg(key.to_string()));
+    }
+
+    // Rewrite any `.pth` files and editable finders that embed the previous location.
+    for site_packages in environment.site_packages() {
+        let Ok(entries) = fs_err::read_dir(site_packages.as_ref()) else {
+            continue;
+        };
+        let mut paths = Vec::new();
+        for entry in entries {
+            let entry = entry?;
+            if !entry.file_type()?.is_file() {
+                continue;
+            }
+            let is_pth = entry
+                .path()
+                .extension()
+                .is_some_and(|extension| extension == "pth");
+            let is_finder = entry.file_name().to_str().is_some_and(|name| {
+                name.starts_with("__editable__") && name.ends_with("_finder.py")
+            });
+            if is_pth || is_finder {
+                paths.push(entry.path());
+            }
+        }
+        paths.sort();
+        for path in paths {
+            let Ok(contents) = fs_err::read_to_string(&path) else {
+                continue;
+            };
+            let Some(contents) = relocate_text(&contents, &previous_str, &root_str) else {
+                continue;
+            };
+            if !dry_run {
+                fs_err::write(&path, contents)?;
+            }
+            repairs.push(Repair::SitePackages(relative_to(&path, root)));
+        }
+    }
+
+    Ok(repairs)
+}
+
+/// Determine the previous location of an environment that was moved, if any.
+///
+/// The location is read from the activation script, which embeds the absolute path to the
+/// environment, falling back to the interpreter invoked by the environment's scripts.
+fn previous_root(environment: &PythonEnvironment) -> std::io::Result<Option<PathBuf>> {
+    let root = environment.root();
+    let candidate = match activator_root(environment.scripts())? {
+        Some(previous) => Some(previous),
+        None => scripts_root(environment)?,
+    };
+    Ok(candidate
+        .filter(|previous| !uv_fs::is_same_file_allow_missing(previous, root).unwrap_or(false)))
+}
+
+/// Read the path to the environment from its activation script.
+fn activator_root(scripts: &Path) -> std::io::Result<Option<PathBuf>> {
+    let (name, prefix, suffix) = if cfg!(windows) {
+        (
+            "activate.bat",
+            "@for %%i in (\"",
+            "\") do @set \"VIRTUAL_ENV=%%~fi\"",
+        )
+    } else {
+        ("activate", "VIRTUAL_ENV='", "'")
+    };
+    let contents = match fs_err::read_to_string(scripts.join(name)) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err),
+    };
+    Ok(contents
+        .lines()
+        .find_map(|line| line.strip_prefix(prefix)?.strip_suffix(suffix))
+        .map(|path| PathBuf::from(path.replace(r#"'"'"'"#, "'")))
+        // Relocatable activation scripts compute the path at runtime.
+        .filter(|path| path.is_absolute()))
+}
+
+/// Infer the path to the environment from the interpreter invoked by its scripts.
+fn scripts_root(environment: &PythonEnvironment) -> std::io::Result<Option<PathBuf>> {
+    let Ok(scripts) = environment.scripts().strip_prefix(environment.root()) else {
+        return Ok(None);
+    };
+    for entry in fs_err::read_dir(environment.scripts())? {
+        let entry = entry?;
+        if !entry.file_type()?.is_file() {
+            continue;
+        }
+        let executable = if cfg!(windows) {
+            uv_trampoline_builder::Launcher::try_from_path(&entry.path())
+                .ok()
+                .flatten()
+                .map(|launcher| launcher.python_path)
+        } else {
+            read_shebang(&entry.path())?.map(|(executable, _)| executable)
+        };
+        let Some(executable) = executable else {
+            continue;
+        };
+        // Only consider interpreters in the scripts directory of an environment.
+        let is_python = executable
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with("python"));
+        if !is_python
+            || !executable
+                .parent()
+                .is_some_and(|parent| parent.ends_with(scripts))
+        {
+            continue;
+        }
+        if let Some(previous) = executable.ancestors().nth(1 + scripts.components().count()) {
+            return Ok(Some(previous.to_path_buf()));
+        }
+    }
+    Ok(None)
+}
+
+/// Read the Python executable from the shebang of a script, along with the remainder of the
+/// script.
+fn read_shebang(path: &Path) -> std::io::Result<Option<(PathBuf, String)>> {
+    let mut file = fs_err::File::open(path)?;
+    let mut buffer = [0u8; 2];
+    if file.read_exact(&mut buffer).is_err() || &buffer != b"#!" {
+        return Ok(None);
+    }
+
+    let mut contents = String::from("#!");
+    match file.read_to_string(&mut contents) {
+        Ok(_) => {}
+        Err(err) if err.kind() == std::io::ErrorKind::InvalidData => {
+            trace!("Skipping `{}`: is not valid UTF-8", path.user_display());
+            return Ok(None);
+        }
+        Err(err) => return Err(err),
+    }
+
+    // Check for a shebang that is wrapped in `/bin/sh`, as used for long paths or paths with
+    // spaces.
+    if let Some(rest) = contents.strip_prefix(SH_SHEBANG_PREFIX) {
+        let Some((executable, rest)) = rest.split_once(SH_SHEBANG_SUFFIX) else {
+            return Ok(None);
+        };
+        let executable = PathBuf::from(executable.replace(r#"'"'"'"#, "'"));
+        return Ok(Some((executable, rest.to_string())));
+    }
+
+    // Otherwise, check for an absolute path shebang.
+    let Some((executable, rest)) = contents
+        .strip_prefix("#!")
+        .and_then(|contents| contents.split_once('\n'))
+    else {
+        return Ok(None);
+    };
+    // Skip shebangs with arguments, which uv never writes.
+    if executable.contains(' ') {
+        return Ok(None);
+    }
+    let executable = PathBuf::from(executable);
+    if !executable.is_absolute() {
+        return Ok(None);
+    }
+    Ok(Some((executable, format!("\n{rest}"))))
+}
+
+/// Rewrite the shebang of a script that invokes the interpreter at the previous location.
+fn repair_shebang(
+    path: &Path,
+    previous: &Path,
+    root: &Path,
+    dry_run: bool,
+) -> anyhow::Result<bool> {
+    let Some((executable, rest)) = read_shebang(path)? else {
+        return Ok(false);
+    };
+    let Some(executable) = relocate(&executable, previous, root) else {
+        return Ok(false);
+    };
+    if !dry_run {
+        // Writing to the existing file retains its permissions.
+        fs_err::write(path, format!("{}{rest}", format_shebang(&executable)))?;
+    }
+    Ok(true)
+}
+
+/// Rewrite a console-script launcher that invokes the interpreter at the previous location.
+fn repair_launcher(
+    path: &Path,
+    previous: &Path,
+    root: &Path,
+    dry_run: bool,
+) -> anyhow::Result<bool> {
+    let Ok(Some(launcher)) = uv_trampoline_builder::Launcher::try_from_path(path) else {
+        return Ok(false);
+    };
+    let Some(python_path) = relocate(&launcher.python_path, previous, root) else {
+        return Ok(false);
+    };
+    if !dry_run {
+        let is_gui = launcher.python_path.ends_with("pythonw.exe");
+        let launcher = launcher.with_python_path(python_path);
+        let mut file = fs_err::File::create(path)?;
+        launcher.write_to_file(&mut file, is_gui)?;
+    }
+    Ok(true)
+}
+
+/// Format a shebang for the given executable, wrapping it in `/bin/sh` if it's too long or contains
+/// spaces.
+fn format_shebang(executable: &Path) -> String {
+    let executable = executable.simplified_display().to_string();
+    if 2 + executable.len() + 1 > MAX_SHEBANG_LENGTH || executable.contains(' ') {
+        format!(
+            "{SH_SHEBANG_PREFIX}{}{SH_SHEBANG_SUFFIX}",
+            escape_posix_for_single_quotes(&executable)
+        )
+    } else {
+        format!("#!{executable}")
+    }
+}
+
+/// If the path is within the previous location, return the equivalent path within the new
+/// location.
+fn relocate(path: &Path, previous: &Path, root: &Path) -> Option<PathBuf> {
+    path.strip_prefix(previous)
+        .ok()
+        .map(|relative| root.join(relative))
+}
+
+/// Replace any paths within the previous location in the given text with the equivalent paths
+/// within the new location, returning `None` if there are no such paths.
+fn relocate_text(contents: &str, previous: &str, root: &str) -> Option<String> {
+    let mut relocated = String::with_capacity(contents.len());
+    let mut rest = contents;
+    let mut changed = false;
+    while let Some(index) = rest.find(previous) {
+        let (before, after) = rest.split_at(index);
+        let after = &after[previous.len()..];
+        relocated.push_str(before);
+        // Only rewrite complete path components, e.g., not `.venv` within `.venv-other`.
+        if after.is_empty() || after.starts_with(['/', '\\', '\'', '"', '\r', '\n']) {
+            relocated.push_str(root);
+            changed = true;
+        } else {
+            relocated.push_str(previous);
+        }
+        rest = after;
+    }
+    relocated.push_str(rest);
+    changed.then_some(relocated)
+}
+
+/// Given an environment at `root` within `ancestor`, return the previous location of `ancestor`,
+/// if the environment was moved along with it.
+fn previous_ancestor(root: &Path, ancestor: &Path, previous: &Path) -> Option<PathBuf> {
+    let relative = root.strip_prefix(ancestor).ok()?;
+    if !previous.ends_with(relative) {
+        return None;
+    }
+    previous
+        .ancestors()
+        .nth(relative.components().count())
+        .map(Path::to_path_buf)
+}
+
+/// Return the path relative to the environment root, for display.
+fn relative_to(path: &Path, root: &Path) -> PathBuf {
+    path.strip_prefix(root)
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|_| path.to_path_buf())
+}
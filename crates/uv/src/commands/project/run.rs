@@ -7,6 +7,7 @@ use std::io::Read;
 #[cfg(unix)]
 use std::os::unix::ffi::OsStringExt;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 use anyhow::{Context, anyhow, bail};
 use futures::StreamExt;
@@ -18,22 +19,22 @@ use tracing::{debug, trace, warn};
 use url::Url;
 
 use uv_cache::Cache;
-use uv_cli::{ExternalCommand, GlobalArgs};
+use uv_cli::{ExternalCommand, GlobalArgs, WithLock};
 use uv_client::BaseClientBuilder;
 use uv_configuration::{
     Concurrency, Constraints, DependencyGroups, DryRun, EditableMode, EnvFile, ExtrasSpecification,
     InstallOptions, TargetTriple,
 };
 use uv_distribution::LoweredExtraBuildDependencies;
-use uv_distribution_types::Requirement;
+use uv_distribution_types::{Requirement, Resolution};
 use uv_fs::which::is_executable;
 use uv_fs::{PythonExt, Simplified, create_symlink};
 use uv_installer::{InstallationStrategy, SatisfiesResult, SitePackages};
-use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
+use uv_normalize::{DefaultExtras, DefaultGroups, ExtraName, PackageName};
 use uv_preview::Preview;
 use uv_python::{
-    ConfigDiscovery, EnvironmentPreference, Interpreter, PyVenvConfiguration, PythonDownloads,
-    PythonEnvironment, PythonInstallation, PythonPreference, PythonRequest, PythonVersionFile,
+    ConfigDiscovery, EnvironmentPreference, Interpreter, PythonDownloads, PythonEnvironment,
+    PythonInstallation, PythonPreference, PythonRequest, PythonVersionFile,
     VersionFileDiscoveryOptions,
 };
 use uv_redacted::DisplaySafeUrl;
@@ -43,11 +44,13 @@ use uv_scripts::{Pep723Error, Pep723Item, Pep723Metadata, Pep723Script};
 use uv_settings::{
     EnvironmentOptions, FilesystemOptions, MalwareCheckSettings, PythonInstallMirrors,
 };
-use uv_shell::WindowsRunnable;
+use uv_shell::{WindowsRunnable, escape_posix_for_single_quotes};
 use uv_static::EnvVars;
 use uv_types::SourceTreeEditablePolicy;
 use uv_warnings::warn_user;
-use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache, WorkspaceErrorKind};
+use uv_workspace::{
+    DiscoveryOptions, VirtualProject, Workspace, WorkspaceCache, WorkspaceErrorKind,
+};
 
 use crate::child::run_to_completion;
 
@@ -65,10 +68,13 @@ use crate::commands::pip::loggers::{
     DefaultInstallLogger, DefaultResolveLogger, SummaryInstallLogger, SummaryResolveLogger,
 };
 use crate::commands::pip::operations::Modifications;
-use crate::commands::project::environment::{CachedEnvironment, EphemeralEnvironment};
+use crate::commands::pip::{resolution_markers, resolution_tags};
+use crate::commands::project::environment::{
+    CachedEnvironment, EphemeralEnvironment, ParentEnvironments,
+};
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::LockMode;
-use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::lock_target::{LockTarget, SharedScripts, read_lockfile};
 use crate::commands::project::{
     EnvironmentSpecification, LinkErrorReporting, PreferenceLocation, ProjectEnvironment,
     ProjectError, ScriptEnvironment, ScriptInterpreter, UniversalState, WorkspacePython,
@@ -90,15 +96,20 @@ pub(crate) async fn run(
     script: Option<Pep723Item>,
     command: Option<RunCommand>,
     requirements: Vec<RequirementsSource>,
+    with_lock: Option<WithLock>,
     show_resolution: bool,
     lock_check: LockCheck,
     frozen: Option<FrozenSource>,
     active: Option<bool>,
+    allow_conda: bool,
     no_sync: bool,
     isolated: bool,
     all_packages: bool,
     package: Option<PackageName>,
     no_project: bool,
+    no_project_scripts: bool,
+    no_post_sync: bool,
+    show_environment: bool,
     config_discovery: ConfigDiscovery,
     extras: ExtrasSpecification,
     groups: DependencyGroups,
@@ -196,12 +207,23 @@ pub(crate) async fn run(
             }
         }
 
-        // If a lockfile already exists, lock the script.
-        if let Some(target) = script
-            .as_script()
-            .map(LockTarget::from)
-            .filter(|target| target.lock_path().is_file())
+        // If the script doesn't have its own lockfile, check whether it shares a lockfile with
+        // other scripts.
+        let shared = if let Some(script) = script.as_script()
+            && !LockTarget::from(script).lock_path().is_file()
         {
+            SharedScripts::discover(script).await?
+        } else {
+            None
+        };
+
+        // If a lockfile already exists, lock the script.
+        if let Some(target) = shared.as_ref().map(LockTarget::Scripts).or_else(|| {
+            script
+                .as_script()
+                .map(LockTarget::from)
+                .filter(|target| target.lock_path().is_file())
+        }) {
             debug!("Found existing lockfile for script");
 
             // Discover the interpreter for the script.
@@ -271,6 +293,17 @@ pub(crate) async fn run(
                 Err(err) => return Err(err.into()),
             };
 
+            // If the lockfile is shared with other scripts, install only this script's subset.
+            let lock = if let Some(group) = shared
+                .as_ref()
+                .zip(script.as_script())
+                .and_then(|(shared, script)| shared.group(&script.path))
+            {
+                lock.for_script(group)
+            } else {
+                lock
+            };
+
             // Sync the environment.
             let target = InstallTarget::Script {
                 script: script.as_script().unwrap(),
@@ -499,6 +532,15 @@ pub(crate) async fn run(
         None
     };
 
+    // The entrypoint of the command, if it's a console script declared by the project.
+    let mut project_entrypoint = None;
+
+    // The script to run in lieu of the command, if it's declared in `tool.uv.scripts`.
+    let mut project_script = None;
+
+    // The environment of the workspace member selected with `--package`, if it has its own.
+    let mut member_env = None;
+
     // Discover and sync the base environment.
     let temp_dir;
     let base_interpreter = if let Some(script_interpreter) = script_interpreter {
@@ -634,6 +676,25 @@ pub(crate) async fn run(
                     project.workspace().install_path().display()
                 );
             }
+
+            // If the command is a console script declared by the project, remember its
+            // entrypoint, in case the script isn't installed (e.g., with `--no-sync`).
+            if let Some(RunCommand::External(executable, _)) = command.as_ref() {
+                project_entrypoint = executable.to_str().and_then(|name| {
+                    let object_reference = project.pyproject_toml().script_entrypoint(name)?;
+                    ProjectEntrypoint::parse(
+                        &object_reference,
+                        project.root(),
+                        project.pyproject_toml().build_backend_module_root(),
+                    )
+                });
+                if !no_project_scripts {
+                    project_script = executable
+                        .to_str()
+                        .and_then(|name| project.pyproject_toml().project_script(name))
+                        .cloned();
+                }
+            }
             // Determine the groups and extras to include.
             let default_groups = default_dependency_groups(project.pyproject_toml())?;
             let default_extras = DefaultExtras::default();
@@ -713,6 +774,7 @@ pub(crate) async fn run(
                     no_sync,
                     config_discovery,
                     active,
+                    allow_conda,
                     &cache,
                     DryRun::Disabled,
                     LinkErrorReporting::Log,
@@ -722,6 +784,12 @@ pub(crate) async fn run(
                 .into_environment()?
             };
 
+            // If the selected workspace member has its own environment, its executables take
+            // precedence over those in the workspace environment.
+            if package.is_some() && !isolated {
+                member_env = member_environment(&project, &venv, &cache);
+            }
+
             if no_sync {
                 debug!("Skipping environment synchronization due to `--no-sync`");
 
@@ -872,6 +940,17 @@ pub(crate) async fn run(
                     Err(err) => return Err(err.into()),
                 }
 
+                // Release the environment lock, since post-sync commands may invoke `uv` themselves.
+                drop(_lock);
+
+                if !isolated && !no_post_sync {
+                    let status =
+                        run_post_sync_commands(project.workspace(), &venv, printer).await?;
+                    if !matches!(status, ExitStatus::Success) {
+                        return Ok(status);
+                    }
+                }
+
                 base_lock = Some((
                     result.into_lock(),
                     project.workspace().install_path().to_owned(),
@@ -949,9 +1028,17 @@ pub(crate) async fn run(
     let spec = if requirements.is_empty() {
         None
     } else {
-        let spec =
+        let mut spec =
             RequirementsSpecification::from_simple_sources(&requirements, &client_builder).await?;
 
+        // Skip any unnamed `--with` requirements that don't apply to the interpreter.
+        for requirement in spec.remove_inapplicable_unnamed(base_interpreter.markers()) {
+            debug!(
+                "Skipping `--with` requirement with inapplicable markers: {}",
+                requirement.requirement
+            );
+        }
+
         Some(spec)
     };
 
@@ -1030,6 +1117,105 @@ pub(crate) async fn run(
         }
     };
 
+    // If a lockfile was provided via `--with-lock`, install its packages into a cached environment.
+    // (`--with-lock` conflicts with the `--with` requirements, so there's nothing to combine.)
+    let requirements_env = match with_lock.as_ref() {
+        None => requirements_env,
+        Some(with_lock) => {
+            debug!(
+                "Syncing `--with-lock` packages from `{}` to cached environment",
+                with_lock.path.user_display()
+            );
+
+            let interpreter = CachedEnvironment::base_interpreter(&base_interpreter, &cache)?;
+            let (resolution, build_constraints) = with_lock_resolution(
+                with_lock,
+                &interpreter,
+                python_platform.as_ref(),
+                &settings,
+                &client_builder,
+                &cache,
+                workspace_cache,
+            )
+            .await?;
+
+            let result = CachedEnvironment::from_locked_resolution(
+                &resolution,
+                build_constraints,
+                &interpreter,
+                &settings,
+                &client_builder,
+                &sync_state,
+                if show_resolution {
+                    Box::new(DefaultInstallLogger)
+                } else {
+                    Box::new(SummaryInstallLogger)
+                },
+                installer_metadata,
+                &concurrency,
+                &cache,
+                printer,
+                preview,
+            )
+            .await;
+
+            let environment = match result {
+                Ok(environment) => environment,
+                Err(ProjectError::Operation(err)) => {
+                    return diagnostics::OperationDiagnostic::default()
+                        .with_context("`--with-lock`")
+                        .report(err)
+                        .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                }
+                Err(err) => return Err(err.into()),
+            };
+
+            Some(PythonEnvironment::from(environment))
+        }
+    };
+
+    // Determine the environments extended by the base environment, if any, and the base
+    // environment's `site-packages` directories in import order.
+    let parent_environments = ParentEnvironments::from_sys_prefix(base_interpreter.sys_prefix());
+    let base_site_packages = base_site_packages_dirs(&base_interpreter, &parent_environments);
+
+    // If requested, show the environment instead of running the command.
+    //
+    // When layering requirements atop the base environment, the command would run in a temporary
+    // environment that is removed on exit, so we show the cached requirements environment instead.
+    if show_environment {
+        let environment = if let Some(requirements_env) = requirements_env.as_ref() {
+            let requirements_site_packages =
+                requirements_env.site_packages().next().ok_or_else(|| {
+                    anyhow!("Requirements environment has no site packages directory")
+                })?;
+            EnvironmentReport {
+                sys_executable: requirements_env.interpreter().sys_executable(),
+                sys_prefix: requirements_env.interpreter().sys_prefix(),
+                extends_environment: parent_environments.sys_prefixes().collect(),
+                site_packages: std::iter::once(requirements_site_packages.into_owned())
+                    .chain(base_site_packages)
+                    .unique()
+                    .collect(),
+                scripts: vec![requirements_env.scripts(), base_interpreter.scripts()],
+            }
+        } else {
+            EnvironmentReport {
+                sys_executable: base_interpreter.sys_executable(),
+                sys_prefix: base_interpreter.sys_prefix(),
+                extends_environment: parent_environments.ancestors().collect(),
+                site_packages: base_site_packages.into_iter().unique().collect(),
+                scripts: vec![base_interpreter.scripts()],
+            }
+        };
+        writeln!(
+            printer.stdout(),
+            "{}",
+            serde_json::to_string_pretty(&environment)?
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
     // If we're layering requirements atop the project environment, run the command in an ephemeral,
     // isolated environment. Otherwise, modifications to the "active virtual environment" would
     // poison the cache.
@@ -1074,21 +1260,15 @@ pub(crate) async fn run(
                 requirements_env.site_packages().next().ok_or_else(|| {
                     anyhow!("Requirements environment has no site packages directory")
                 })?;
-            let mut base_site_packages = base_interpreter
-                .runtime_site_packages()
-                .iter()
-                .map(|path| Cow::Borrowed(path.as_path()))
-                .chain(base_interpreter.site_packages())
-                .peekable();
-            if base_site_packages.peek().is_none() {
+            if base_site_packages.is_empty() {
                 return Err(anyhow!("Base environment has no site packages directory"));
             }
 
             let overlay_content = format!(
                 "import site; {}",
-                std::iter::once(requirements_site_packages)
+                std::iter::once(requirements_site_packages.into_owned())
                     .chain(base_site_packages)
-                    .dedup()
+                    .unique()
                     .inspect(|path| debug!("Adding `{}` to site packages", path.display()))
                     .map(|path| format!("site.addsitedir({})", path.escape_for_python()))
                     .collect::<Vec<_>>()
@@ -1173,20 +1353,17 @@ pub(crate) async fn run(
 
             // Write the `sys.prefix` of the parent environment to the `extends-environment` key of the `pyvenv.cfg`
             // file. This helps out static-analysis tools such as ty (see docs on
-            // `CachedEnvironment::set_parent_environment`).
+            // `EphemeralEnvironment::set_parent_environment`).
             //
             // Note that we do this even if the parent environment is not a virtual environment.
             // For ephemeral environments created by `uv run --with`, the parent environment's
             // `site-packages` directory is added to `sys.path` even if the parent environment is not
             // a virtual environment and even if `--system-site-packages` was not explicitly selected.
-            ephemeral_env.set_parent_environment(base_interpreter.sys_prefix())?;
+            ephemeral_env.set_parent_environment(&parent_environments)?;
 
-            // If `--system-site-packages` is enabled, add the system site packages to the ephemeral
-            // environment.
-            if base_interpreter.is_virtualenv()
-                && PyVenvConfiguration::parse(base_interpreter.sys_prefix().join("pyvenv.cfg"))
-                    .is_ok_and(|cfg| cfg.include_system_site_packages())
-            {
+            // If `--system-site-packages` is enabled in the parent environment (or any environment
+            // it extends), add the system site packages to the ephemeral environment.
+            if parent_environments.include_system_site_packages() {
                 ephemeral_env.set_system_site_packages()?;
             }
         }
@@ -1261,10 +1438,6 @@ pub(crate) async fn run(
         return Ok(ExitStatus::Error);
     };
 
-    debug!("Running `{command}`");
-    let mut process = command.as_command(interpreter);
-    process.envs(env_file_environment);
-
     // Construct the `PATH` environment variable.
     let new_path = std::env::join_paths(
         ephemeral_env
@@ -1272,6 +1445,7 @@ pub(crate) async fn run(
             .map(PythonEnvironment::scripts)
             .into_iter()
             .chain(requirements_env.as_ref().map(PythonEnvironment::scripts))
+            .chain(member_env.as_ref().map(PythonEnvironment::scripts))
             .chain(std::iter::once(base_interpreter.scripts()))
             .chain(
                 // On Windows, non-virtual Python distributions put `python.exe` in the top-level
@@ -1289,19 +1463,89 @@ pub(crate) async fn run(
                     .flat_map(std::env::split_paths),
             ),
     )?;
-    process.env(EnvVars::PATH, new_path);
 
-    // Increment recursion depth counter.
-    process.env(
-        EnvVars::UV_RUN_RECURSION_DEPTH,
-        (recursion_depth + 1).to_string(),
-    );
+    // Apply the environment shared by every process spawned for the command.
+    let configure_process = |process: &mut Command| {
+        process.envs(env_file_environment.iter().map(|(key, value)| (key, value)));
+        process.env(EnvVars::PATH, &new_path);
+
+        // Increment recursion depth counter.
+        process.env(
+            EnvVars::UV_RUN_RECURSION_DEPTH,
+            (recursion_depth + 1).to_string(),
+        );
+
+        // Ensure `VIRTUAL_ENV` is set, preferring the selected member's environment, if any.
+        if let Some(member_env) = member_env.as_ref() {
+            process.env(EnvVars::VIRTUAL_ENV, member_env.root().as_os_str());
+        } else if interpreter.is_virtualenv() {
+            process.env(EnvVars::VIRTUAL_ENV, interpreter.sys_prefix().as_os_str());
+        }
+    };
+
+    // If the command is a script declared in `tool.uv.scripts`, run each of its commands in the
+    // system shell, stopping at the first failure.
+    if let (Some(script), RunCommand::External(name, args)) = (project_script, &command) {
+        if project_entrypoint.is_some()
+            || which::which_in(name, Some(&new_path), project_dir).is_ok()
+        {
+            warn_user!(
+                "`{}` is defined in `tool.uv.scripts` and shadows an executable with the same name; pass `--no-project-scripts` to run the executable instead",
+                name.to_string_lossy()
+            );
+        }
 
-    // Ensure `VIRTUAL_ENV` is set.
-    if interpreter.is_virtualenv() {
-        process.env(EnvVars::VIRTUAL_ENV, interpreter.sys_prefix().as_os_str());
+        for script_command in expand_project_script(script.commands(), args) {
+            debug!("Running `{script_command}`");
+            let mut process = shell_command(&script_command);
+            configure_process(&mut process);
+
+            let handle = process
+                .spawn()
+                .with_context(|| format!("Failed to spawn: `{script_command}`"))?;
+            let status = run_to_completion(handle).await?;
+            if !matches!(status, ExitStatus::Success) {
+                return Ok(status);
+            }
+        }
+
+        return Ok(ExitStatus::Success);
     }
 
+    // If the command is a console script declared by the project, but the script isn't installed
+    // in the environment (e.g., because the environment wasn't synced), call its entrypoint
+    // directly.
+    let command = match (command, project_entrypoint) {
+        (RunCommand::External(executable, args), Some(entrypoint))
+            if !ephemeral_env
+                .as_ref()
+                .map(PythonEnvironment::scripts)
+                .into_iter()
+                .chain(requirements_env.as_ref().map(PythonEnvironment::scripts))
+                .chain(member_env.as_ref().map(PythonEnvironment::scripts))
+                .chain(std::iter::once(base_interpreter.scripts()))
+                .any(|scripts| {
+                    // Append the extension, since script names may contain dots (e.g., `foo.cli`).
+                    let mut name = executable.clone();
+                    name.push(std::env::consts::EXE_SUFFIX);
+                    is_executable(&scripts.join(name))
+                }) =>
+        {
+            debug!(
+                "`{}` is not installed; calling its entrypoint `{}:{}` directly",
+                executable.to_string_lossy(),
+                entrypoint.module,
+                entrypoint.function
+            );
+            RunCommand::PythonEntrypoint(executable, entrypoint, args)
+        }
+        (command, _) => command,
+    };
+
+    debug!("Running `{command}`");
+    let mut process = command.as_command(interpreter);
+    configure_process(&mut process);
+
     // Spawn and wait for completion
     // Standard input, output, and error streams are all inherited
     // TODO(zanieb): Throw a nicer error message if the command is not found
@@ -1312,7 +1556,289 @@ pub(crate) async fn run(
     run_to_completion(handle).await
 }
 
+/// Find the environment of a workspace member, if it has one of its own (i.e., a virtual
+/// environment at `.venv` in the member directory) distinct from the workspace environment.
+fn member_environment(
+    project: &VirtualProject,
+    workspace_env: &PythonEnvironment,
+    cache: &Cache,
+) -> Option<PythonEnvironment> {
+    let VirtualProject::Project(project) = project else {
+        return None;
+    };
+    if project.project_root() == project.workspace().install_path() {
+        return None;
+    }
+
+    let root = project.project_root().join(".venv");
+    if !root.is_dir()
+        || uv_fs::is_same_file_allow_missing(&root, workspace_env.root()).unwrap_or(false)
+    {
+        return None;
+    }
+
+    match PythonEnvironment::from_root(&root, cache) {
+        Ok(environment) => {
+            debug!(
+                "Using executables from the environment of `{}` at: {}",
+                project.project_name(),
+                root.user_display()
+            );
+            Some(environment)
+        }
+        Err(err) => {
+            debug!(
+                "Ignoring invalid environment of `{}` at `{}`: {err}",
+                project.project_name(),
+                root.user_display()
+            );
+            None
+        }
+    }
+}
+
+/// Expand the commands of a script declared in `tool.uv.scripts`, substituting the given arguments
+/// for the `{args}` placeholder.
+///
+/// If no command contains the placeholder, the arguments are appended to the last command.
+fn expand_project_script(commands: &[String], args: &[OsString]) -> Vec<String> {
+    let args = args
+        .iter()
+        .map(|arg| quote_shell_argument(&arg.to_string_lossy()))
+        .join(" ");
+
+    if commands.iter().any(|command| command.contains("{args}")) {
+        return commands
+            .iter()
+            .map(|command| command.replace("{args}", &args))
+            .collect();
+    }
+
+    let mut commands = commands.to_vec();
+    if let Some(last) = commands.last_mut()
+        && !args.is_empty()
+    {
+        last.push(' ');
+        last.push_str(&args);
+    }
+    commands
+}
+
+/// Quote an argument, if necessary, for safe use in the system shell.
+fn quote_shell_argument(arg: &str) -> String {
+    if cfg!(windows) {
+        // `cmd` has no escape for double quotes within a quoted argument, but most programs
+        // interpret a doubled quote as a literal quote.
+        if arg.is_empty() || arg.contains([' ', '\t', '"', '&', '|', '<', '>', '^']) {
+            format!("\"{}\"", arg.replace('"', "\"\""))
+        } else {
+            arg.to_string()
+        }
+    } else if !arg.is_empty()
+        && arg
+            .bytes()
+            .all(|byte| byte.is_ascii_alphanumeric() || b"@%+=:,./-_".contains(&byte))
+    {
+        arg.to_string()
+    } else {
+        format!("'{}'", escape_posix_for_single_quotes(arg))
+    }
+}
+
+/// Create a [`Command`] that runs the given command line in the system shell.
+fn shell_command(command: &str) -> Command {
+    if cfg!(windows) {
+        let mut process = Command::new("cmd");
+        process.args(["/d", "/s", "/c"]);
+        #[cfg(windows)]
+        process.raw_arg(format!("\"{command}\""));
+        process
+    } else {
+        let mut process = Command::new("sh");
+        process.arg("-c").arg(command);
+        process
+    }
+}
+
+/// Run the commands declared in `tool.uv.post-sync` against the synced environment.
+///
+/// Each command runs in the system shell from the workspace root, with the environment activated,
+/// stopping at the first failure.
+pub(crate) async fn run_post_sync_commands(
+    workspace: &Workspace,
+    environment: &PythonEnvironment,
+    printer: Printer,
+) -> anyhow::Result<ExitStatus> {
+    let commands = workspace.post_sync_commands();
+    if commands.is_empty() {
+        return Ok(ExitStatus::Success);
+    }
+
+    let new_path = std::env::join_paths(
+        std::iter::once(environment.scripts().to_path_buf()).chain(
+            std::env::var_os(EnvVars::PATH)
+                .as_ref()
+                .iter()
+                .flat_map(std::env::split_paths),
+        ),
+    )?;
+
+    for command in commands {
+        writeln!(
+            printer.stderr(),
+            "Running post-sync command: `{}`",
+            command.cyan()
+        )?;
+
+        let mut process = shell_command(command);
+        process
+            .current_dir(workspace.install_path())
+            .env(EnvVars::PATH, &new_path)
+            .env(EnvVars::VIRTUAL_ENV, environment.root().as_os_str())
+            // Avoid re-syncing (and re-running the hooks) from nested `uv` invocations.
+            .env(EnvVars::UV_NO_SYNC, "1")
+            .env(EnvVars::UV_NO_POST_SYNC, "1");
+
+        let status = process
+            .status()
+            .await
+            .with_context(|| format!("Failed to spawn post-sync command: `{command}`"))?;
+        if status.success() {
+            continue;
+        }
+
+        writeln!(
+            printer.stderr_important(),
+            "{}{} Post-sync command `{}` failed ({status})",
+            "error".red().bold(),
+            ":".bold(),
+            command.cyan()
+        )?;
+        return Ok(status
+            .code()
+            .and_then(|code| u8::try_from(code).ok())
+            .map_or(ExitStatus::Failure, ExitStatus::External));
+    }
+
+    Ok(ExitStatus::Success)
+}
+
 /// Returns `true` if we can skip creating an additional ephemeral environment in `uv run`.
+/// Select the packages to install for `--with-lock` from the given lockfile.
+///
+/// Returns the [`Resolution`] for the current interpreter, along with the lockfile's build
+/// constraints. The resulting environment is keyed on the selected distributions, and so is shared
+/// across invocations that select the same packages from the same lockfile.
+async fn with_lock_resolution(
+    with_lock: &WithLock,
+    interpreter: &Interpreter,
+    python_platform: Option<&TargetTriple>,
+    settings: &ResolverInstallerSettings,
+    client_builder: &BaseClientBuilder<'_>,
+    cache: &Cache,
+    workspace_cache: &WorkspaceCache,
+) -> anyhow::Result<(Resolution, Constraints)> {
+    let lock = read_lockfile(&with_lock.path).await.with_context(|| {
+        format!(
+            "Failed to read lockfile at: `{}`",
+            with_lock.path.user_display()
+        )
+    })?;
+
+    // Discover the workspace that owns the lockfile, to resolve its relative paths and groups.
+    let lock_path = std::path::absolute(&with_lock.path)?;
+    let Some(lock_dir) = lock_path.parent() else {
+        bail!(
+            "Failed to determine the directory of the lockfile at: `{}`",
+            with_lock.path.user_display()
+        );
+    };
+    let project = VirtualProject::discover(
+        lock_dir,
+        &DiscoveryOptions::default(),
+        cache,
+        workspace_cache,
+    )
+    .await?;
+    let target = project::sync::identify_project_installation_target(&project, &lock, true, &[]);
+
+    // Determine whether the name refers to a dependency group or an extra, preferring groups.
+    let (extras, groups) = match with_lock.name.as_ref() {
+        None => (ExtrasSpecification::default(), DependencyGroups::default()),
+        Some(name) => {
+            let groups = DependencyGroups::from_group(name.clone());
+            if target
+                .validate_groups(&groups.with_defaults(DefaultGroups::default()))
+                .is_ok()
+            {
+                (ExtrasSpecification::default(), groups)
+            } else {
+                let extras =
+                    ExtrasSpecification::from_extra(vec![ExtraName::from_str(name.as_str())?]);
+                if target.validate_extras(&extras).is_err() {
+                    bail!(
+                        "`{}` is neither a dependency group nor an extra in: `{}`",
+                        name.cyan(),
+                        with_lock.path.user_display()
+                    );
+                }
+                (extras, DependencyGroups::default())
+            }
+        }
+    };
+    let extras = extras.with_defaults(DefaultExtras::default());
+    let groups = groups.with_defaults(DefaultGroups::default());
+
+    project::sync::store_credentials_from_target(target, client_builder)?;
+
+    let marker_env = resolution_markers(None, python_platform, interpreter);
+    let tags = resolution_tags(None, python_platform, interpreter)?;
+
+    // Convert the lockfile to a resolution, omitting the workspace members themselves. A package
+    // without a compatible distribution fails the conversion, so omit each such package in turn
+    // to report all of them at once.
+    let mut incompatible = Vec::new();
+    let resolution = loop {
+        let install_options = InstallOptions::new(
+            false,
+            false,
+            true,
+            false,
+            false,
+            false,
+            incompatible.clone(),
+            Vec::new(),
+        );
+        match target.to_resolution(
+            &marker_env,
+            &tags,
+            &extras,
+            &groups,
+            &settings.resolver.build_options,
+            &install_options,
+        ) {
+            Ok(resolution) => break resolution,
+            Err(err) => match err.incompatible_package() {
+                Some(name) if !incompatible.contains(name) => incompatible.push(name.clone()),
+                _ => return Err(err.into()),
+            },
+        }
+    };
+    if !incompatible.is_empty() {
+        bail!(
+            "The following packages in `{}` have no distributions compatible with the current interpreter: {}",
+            with_lock.path.user_display(),
+            incompatible
+                .iter()
+                .map(|name| format!("`{}`", name.cyan()))
+                .join(", ")
+        );
+    }
+
+    let build_constraints = lock.build_constraints(project.workspace().install_path());
+    Ok((resolution, build_constraints))
+}
+
 fn can_skip_ephemeral(
     spec: &RequirementsSpecification,
     interpreter: &Interpreter,
@@ -1413,6 +1939,9 @@ pub(crate) enum RunCommand {
     PythonGuiStdin(Vec<u8>, Vec<OsString>),
     /// Execute a Python script downloaded from a remote URL.
     PythonRemote(tempfile::NamedTempFile, Vec<OsString>),
+    /// Call the entrypoint of a console script declared by the project, in lieu of its
+    /// (uninstalled) launcher.
+    PythonEntrypoint(OsString, ProjectEntrypoint, Vec<OsString>),
     /// Execute an external command.
     External(OsString, Vec<OsString>),
     /// Execute an empty command (in practice, `python` with no arguments).
@@ -1672,6 +2201,7 @@ impl RunCommand {
             | Self::PythonZipapp(..)
             | Self::PythonModule(..)
             | Self::PythonRemote(..)
+            | Self::PythonEntrypoint(..)
             | Self::External(..)
             | Self::Empty => Ok(None),
         }
@@ -1689,6 +2219,7 @@ impl RunCommand {
             // the available scripts in the interpreter — we could improve this message
             Self::PythonPackage(target, ..) => target.to_string_lossy(),
             Self::PythonModule(..) => Cow::Borrowed("python -m"),
+            Self::PythonEntrypoint(executable, ..) => executable.to_string_lossy(),
             Self::PythonGuiScript(..) => {
                 if cfg!(windows) {
                     Cow::Borrowed("pythonw")
@@ -1820,6 +2351,17 @@ impl RunCommand {
 
                 process
             }
+            Self::PythonEntrypoint(executable, entrypoint, args) => {
+                let mut process = Command::new(interpreter.sys_executable());
+                process.arg("-c");
+                process.arg(entrypoint.launcher());
+                // Pass the import root and script name as arguments, rather than interpolating
+                // them into the launcher, to avoid quoting issues.
+                process.arg(&entrypoint.import_root);
+                process.arg(executable);
+                process.args(args);
+                process
+            }
             Self::External(executable, args) => {
                 let mut process = if cfg!(windows) {
                     WindowsRunnable::from_script_path(interpreter.scripts(), executable).into()
@@ -1845,6 +2387,7 @@ impl RunCommand {
             | Self::PythonStdin(..)
             | Self::PythonGuiStdin(..)
             | Self::PythonRemote(..)
+            | Self::PythonEntrypoint(..)
             | Self::External(..)
             | Self::Empty => None,
         };
@@ -1900,7 +2443,7 @@ impl std::fmt::Display for RunCommand {
                 write!(f, "pythonw -c")?;
                 Ok(())
             }
-            Self::External(executable, args) => {
+            Self::PythonEntrypoint(executable, _, args) | Self::External(executable, args) => {
                 write!(f, "{}", executable.to_string_lossy())?;
                 for arg in args {
                     write!(f, " {}", arg.to_string_lossy())?;
@@ -1915,6 +2458,87 @@ impl std::fmt::Display for RunCommand {
     }
 }
 
+/// The entrypoint of a console script declared in the project's `project.scripts` table.
+#[derive(Debug, Clone)]
+pub(crate) struct ProjectEntrypoint {
+    /// The module to import, e.g., `mycli.main`.
+    module: String,
+    /// The attribute to call, e.g., `main` or `App.run`.
+    function: String,
+    /// The directory from which the project can be imported if it isn't installed, i.e., the
+    /// `module-root` of the uv build backend, `src` for projects using the `src` layout, and the
+    /// project root otherwise.
+    import_root: PathBuf,
+}
+
+impl ProjectEntrypoint {
+    /// Parse an object reference like `mycli.main:main` or `mycli:App.run [extra]`.
+    ///
+    /// See: <https://packaging.python.org/en/latest/specifications/entry-points/>
+    fn parse(
+        object_reference: &str,
+        project_root: &Path,
+        module_root: Option<PathBuf>,
+    ) -> Option<Self> {
+        // Extras are irrelevant when calling the entrypoint directly.
+        let object_reference = object_reference
+            .split_once('[')
+            .map_or(object_reference, |(object_reference, _)| object_reference);
+        let (module, function) = object_reference.split_once(':')?;
+        let (module, function) = (module.trim(), function.trim());
+
+        // Both parts are interpolated into the launcher, so only accept dotted identifiers.
+        let is_dotted_identifier = |value: &str| {
+            !value.is_empty()
+                && value.split('.').all(|part| {
+                    !part.is_empty()
+                        && !part.starts_with(|c: char| c.is_ascii_digit())
+                        && part.chars().all(|c| c.is_alphanumeric() || c == '_')
+                })
+        };
+        if !is_dotted_identifier(module) || !is_dotted_identifier(function) {
+            debug!("Ignoring invalid entrypoint `{object_reference}`");
+            return None;
+        }
+
+        let import_root = if let Some(module_root) = module_root {
+            project_root.join(module_root)
+        } else if project_root.join("src").is_dir() {
+            project_root.join("src")
+        } else {
+            project_root.to_path_buf()
+        };
+
+        Some(Self {
+            module: module.to_string(),
+            function: function.to_string(),
+            import_root,
+        })
+    }
+
+    /// Return the Python source that calls the entrypoint, modeled after the launchers that are
+    /// generated when installing a wheel.
+    ///
+    /// Expects the import root and script name as the first two arguments. The import root is
+    /// appended to `sys.path`, such that an installed copy of the project takes precedence.
+    fn launcher(&self) -> String {
+        let Self {
+            module, function, ..
+        } = self;
+        let import_name = function
+            .split_once('.')
+            .map_or(function.as_str(), |(import_name, _)| import_name);
+        format!(
+            r"import sys
+sys.path.append(sys.argv.pop(1))
+sys.argv[0] = sys.argv.pop(1)
+from {module} import {import_name}
+sys.exit({function}())
+"
+        )
+    }
+}
+
 /// Resolve a GitHub Gist URL to its raw file URL using the GitHub API.
 async fn resolve_gist_url(
     url: &DisplaySafeUrl,
@@ -1997,6 +2621,50 @@ fn is_python_zipapp(target: &Path) -> bool {
     false
 }
 
+/// A description of the environment that `uv run` would run a command in, as shown by
+/// `--show-environment`.
+#[derive(Debug, serde::Serialize)]
+struct EnvironmentReport<'a> {
+    /// The path to the Python executable.
+    sys_executable: &'a Path,
+    /// The `sys.prefix` of the environment.
+    sys_prefix: &'a Path,
+    /// The `sys.prefix` of each environment extended by the environment, in order of precedence.
+    extends_environment: Vec<&'a Path>,
+    /// The `site-packages` directories, in import order.
+    site_packages: Vec<PathBuf>,
+    /// The scripts directories, in order of precedence.
+    scripts: Vec<&'a Path>,
+}
+
+/// Returns the `site-packages` directories of the base environment, followed by those of any
+/// environments it extends, in import order.
+fn base_site_packages_dirs(
+    base_interpreter: &Interpreter,
+    parent_environments: &ParentEnvironments,
+) -> Vec<PathBuf> {
+    // If the base environment is itself an ephemeral environment, include the `site-packages`
+    // directories of each environment it extends, in order of precedence.
+    let scheme = base_interpreter.virtualenv();
+    let ancestor_site_packages = parent_environments
+        .ancestors()
+        .flat_map(|ancestor| {
+            [
+                ancestor.join(&scheme.purelib),
+                ancestor.join(&scheme.platlib),
+            ]
+        })
+        .filter(|path| path.is_dir());
+
+    base_interpreter
+        .runtime_site_packages()
+        .iter()
+        .cloned()
+        .chain(base_interpreter.site_packages().map(Cow::into_owned))
+        .chain(ancestor_site_packages)
+        .collect()
+}
+
 /// Read and parse recursion depth from the environment.
 ///
 /// Returns Ok(0) if `EnvVars::UV_RUN_RECURSION_DEPTH` is not set.
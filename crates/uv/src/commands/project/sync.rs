@@ -1,8 +1,9 @@
+use std::collections::{BTreeSet, VecDeque};
 use std::fmt::Write;
 use std::ops::Deref;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use anyhow::Result;
+use anyhow::{Result, bail};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
 use rustc_hash::FxHashSet;
@@ -10,13 +11,15 @@ use serde::Serialize;
 use tracing::{trace, warn};
 use uv_audit::Dependency;
 use uv_audit::osv::{self, Filter};
-use uv_cache::Cache;
+use uv_cache::{Cache, Refresh};
 use uv_cli::SyncFormat;
-use uv_client::{BaseClientBuilder, CachedClient, FlatIndexClient, RegistryClientBuilder};
+use uv_client::{
+    BaseClientBuilder, CachedClient, Connectivity, FlatIndexClient, RegistryClientBuilder,
+};
 use uv_configuration::{
     Concurrency, Constraints, DependencyGroups, DependencyGroupsWithDefaults, DryRun, EditableMode,
     ExtrasSpecification, ExtrasSpecificationWithDefaults, HashCheckingMode, InstallOptions,
-    TargetTriple, Upgrade,
+    Reinstall, TargetTriple, Upgrade,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution::LoweredExtraBuildDependencies;
@@ -42,13 +45,17 @@ use uv_workspace::pyproject::Source;
 use uv_workspace::{DiscoveryOptions, MemberDiscovery, VirtualProject, Workspace, WorkspaceCache};
 
 use crate::commands::editable::apply_editable_mode;
+use crate::commands::pip::explain::report_artifact_selection;
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger, InstallLogger};
 use crate::commands::pip::operations::{ChangedDist, Changelog, Modifications};
 use crate::commands::pip::resolution_markers;
 use crate::commands::pip::{operations, resolution_tags};
+use crate::commands::project::bundle::install_bundle;
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::{LockMode, LockOperation, LockResult};
 use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::repair::repair_environment;
+use crate::commands::project::run::run_post_sync_commands;
 use crate::commands::project::{
     EnvironmentUpdate, LinkErrorReporting, MalwareFindings, PlatformState, ProjectEnvironment,
     ProjectError, ScriptEnvironment, UniversalState, default_dependency_groups, detect_conflicts,
@@ -62,12 +69,20 @@ use crate::settings::{
 };
 
 /// Sync the project environment.
+#[expect(clippy::fn_params_excessive_bools)]
 pub(crate) async fn sync(
     project_dir: &Path,
     lock_check: LockCheck,
     frozen: Option<FrozenSource>,
+    from_bundle: Option<PathBuf>,
     dry_run: DryRun,
+    explain: Vec<PackageName>,
+    check_group_markers: bool,
+    no_post_sync: bool,
+    repair: bool,
+    cascade: bool,
     active: Option<bool>,
+    allow_conda: bool,
     all_packages: bool,
     package: Vec<PackageName>,
     extras: ExtrasSpecification,
@@ -80,9 +95,10 @@ pub(crate) async fn sync(
     install_mirrors: PythonInstallMirrors,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
-    settings: ResolverInstallerSettings,
+    mut settings: ResolverInstallerSettings,
     client_builder: BaseClientBuilder<'_>,
     script: Option<Pep723Script>,
+    environment: Option<PythonEnvironment>,
     installer_metadata: bool,
     concurrency: Concurrency,
     config_discovery: ConfigDiscovery,
@@ -100,12 +116,19 @@ pub(crate) async fn sync(
         );
     }
 
+    // When installing from a bundle, never access the network.
+    let client_builder = if from_bundle.is_some() {
+        client_builder.connectivity(Connectivity::Offline)
+    } else {
+        client_builder
+    };
+
     // Identify the target.
     let target = if let Some(script) = script {
         SyncTarget::Script(script)
     } else {
         // Identify the project.
-        let project = if frozen.is_some() {
+        let project = if frozen.is_some() || from_bundle.is_some() {
             VirtualProject::discover(
                 project_dir,
                 &DiscoveryOptions {
@@ -146,6 +169,25 @@ pub(crate) async fn sync(
         SyncTarget::Project(project)
     };
 
+    // Check the markers of any dependency group entries, failing on likely mistakes.
+    if check_group_markers && let SyncTarget::Project(project) = &target {
+        let requires_python = LockTarget::from(project.workspace()).requires_python()?;
+        let diagnostics = project
+            .workspace()
+            .check_group_markers(requires_python.as_ref());
+        if !diagnostics.is_empty() {
+            for diagnostic in &diagnostics {
+                writeln!(
+                    printer.stderr_important(),
+                    "{}{} {diagnostic}",
+                    "error".red().bold(),
+                    ":".bold()
+                )?;
+            }
+            return Ok(ExitStatus::Failure);
+        }
+    }
+
     // Determine the groups and extras to include.
     let default_groups = match &target {
         SyncTarget::Project(project) => default_dependency_groups(project.pyproject_toml())?,
@@ -158,9 +200,13 @@ pub(crate) async fn sync(
     let groups = groups.with_defaults(default_groups);
     let extras = extras.with_defaults(default_extras);
 
-    // Discover or create the virtual environment.
-    let environment = match &target {
-        SyncTarget::Project(project) => SyncEnvironment::Project(
+    // Discover or create the virtual environment, unless one was provided by the caller (e.g., by
+    // `uv venv --sync`).
+    let environment = match (&target, environment) {
+        (SyncTarget::Project(_), Some(environment)) => {
+            SyncEnvironment::Project(ProjectEnvironment::Existing(environment))
+        }
+        (SyncTarget::Project(project), None) => SyncEnvironment::Project(
             ProjectEnvironment::get_or_init(
                 project.workspace(),
                 &groups,
@@ -172,6 +218,7 @@ pub(crate) async fn sync(
                 false,
                 config_discovery,
                 active,
+                allow_conda,
                 cache,
                 dry_run,
                 LinkErrorReporting::User,
@@ -179,7 +226,7 @@ pub(crate) async fn sync(
             )
             .await?,
         ),
-        SyncTarget::Script(script) => SyncEnvironment::Script(
+        (SyncTarget::Script(script), _) => SyncEnvironment::Script(
             ScriptEnvironment::get_or_init(
                 script.into(),
                 python.as_deref().map(PythonRequest::parse),
@@ -198,6 +245,29 @@ pub(crate) async fn sync(
         ),
     };
 
+    // Special-case: we're installing from a bundle, which bypasses the project lockfile entirely.
+    // The environment lock is acquired by the installation itself.
+    if let Some(bundle) = from_bundle {
+        let SyncTarget::Project(project) = &target else {
+            bail!("`--from-bundle` cannot be used with `--script`");
+        };
+        return Box::pin(install_bundle(
+            &bundle,
+            project.workspace().install_path(),
+            &environment,
+            python_platform,
+            &settings,
+            &client_builder,
+            installer_metadata,
+            concurrency,
+            cache,
+            workspace_cache,
+            printer,
+            preview,
+        ))
+        .await;
+    }
+
     let _lock = environment
         .lock()
         .await
@@ -206,6 +276,15 @@ pub(crate) async fn sync(
         })
         .ok();
 
+    // If requested, repair an environment that was moved from its original location.
+    if repair {
+        let workspace_root = match &target {
+            SyncTarget::Project(project) => Some(project.workspace().install_path().as_path()),
+            SyncTarget::Script(..) => None,
+        };
+        repair_environment(&environment, workspace_root, dry_run.enabled(), printer)?;
+    }
+
     let sync_report = SyncReport {
         dry_run: dry_run.enabled(),
         environment: EnvironmentReport::from(&environment),
@@ -377,7 +456,13 @@ pub(crate) async fn sync(
                 .report(err)
                 .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
         }
-        Err(err @ ProjectError::LockFormat(..)) => return Err(UvError::user(err).into()),
+        Err(
+            err @ (ProjectError::LockFormat(..)
+            | ProjectError::IndexMetadataMismatch(..)
+            | ProjectError::RemoteFileMismatch(..)),
+        ) => {
+            return Err(UvError::user(err).into());
+        }
         Err(ProjectError::LockMismatch(prev, cur, lock_source)) => {
             if dry_run.enabled() {
                 // The lockfile is mismatched, but we're in dry-run mode. We should proceed with the
@@ -417,6 +502,69 @@ pub(crate) async fn sync(
         }
     }
 
+    // Explain the artifact selection for any requested packages.
+    if !explain.is_empty() {
+        let tags = resolution_tags(None, python_platform.as_ref(), environment.interpreter())?;
+        for name in &explain {
+            let mut found = false;
+            for package in sync_target
+                .lock()
+                .packages()
+                .iter()
+                .filter(|package| package.name() == name)
+            {
+                found = true;
+                let candidates = package.explain_artifacts(
+                    sync_target.install_path(),
+                    &tags,
+                    &settings.resolver.build_options,
+                )?;
+                report_artifact_selection(name, package.version(), &tags, &candidates, printer)?;
+            }
+            if !found {
+                warn_user!("`{name}` is not in the lockfile; nothing to explain");
+            }
+        }
+    }
+
+    // With `--cascade`, also reinstall (and rebuild) every locked package that depends on a
+    // reinstalled package.
+    let cascade_cache;
+    let cache = if cascade && let Reinstall::Packages(packages, ..) = &settings.reinstall {
+        let dependents = reverse_dependencies(sync_target.lock(), packages);
+        let packages = packages
+            .iter()
+            .map(|package| package.cyan().to_string())
+            .join(", ");
+        if dependents.is_empty() {
+            writeln!(
+                printer.stderr(),
+                "No locked packages depend on {packages}; nothing to cascade"
+            )?;
+            cache
+        } else {
+            writeln!(
+                printer.stderr(),
+                "Reinstalling dependents of {packages}: {}",
+                dependents
+                    .iter()
+                    .map(|package| package.cyan().to_string())
+                    .join(", ")
+            )?;
+            let dependents = Reinstall::Packages(dependents.into_iter().collect(), Vec::new());
+            cascade_cache = cache.clone().with_refresh(
+                cache
+                    .refresh()
+                    .clone()
+                    .combine(Refresh::from(dependents.clone())),
+            );
+            settings.reinstall = settings.reinstall.combine(dependents);
+            &cascade_cache
+        }
+    } else {
+        cache
+    };
+
     let state = state.fork();
 
     // Perform the sync operation.
@@ -477,6 +625,25 @@ pub(crate) async fn sync(
         printer,
     )?;
 
+    if let SyncEnvironment::Project(environment) = &environment
+        && !dry_run.enabled()
+    {
+        operations::report_scripts_not_on_path(&changelog, environment, true, printer)?;
+    }
+
+    // Run the post-sync commands, releasing the environment lock first, since the commands may
+    // invoke `uv` themselves.
+    if let (SyncTarget::Project(project), Outcome::Success(..)) = (&target, &outcome)
+        && !dry_run.enabled()
+        && !no_post_sync
+    {
+        drop(_lock);
+        let status = run_post_sync_commands(project.workspace(), &environment, printer).await?;
+        if !matches!(status, ExitStatus::Success) {
+            return Ok(status);
+        }
+    }
+
     match outcome {
         Outcome::Success(..) => Ok(ExitStatus::Success),
         Outcome::LockMismatch(prev, cur, lock_source) => {
@@ -580,6 +747,30 @@ pub(crate) fn identify_project_installation_target<'a>(
     }
 }
 
+/// Return the locked packages that depend, directly or transitively, on any of the given packages
+/// (excluding the packages themselves), including through optional dependencies and dependency
+/// groups.
+fn reverse_dependencies(lock: &Lock, packages: &[PackageName]) -> BTreeSet<PackageName> {
+    let mut seen = packages.iter().collect::<FxHashSet<_>>();
+    let mut queue = packages.iter().collect::<VecDeque<_>>();
+    let mut dependents = BTreeSet::new();
+    while let Some(name) = queue.pop_front() {
+        for package in lock.packages() {
+            let depends = package
+                .dependencies()
+                .iter()
+                .chain(package.optional_dependencies().values().flatten())
+                .chain(package.resolved_dependency_groups().values().flatten())
+                .any(|dependency| dependency.package_name() == name);
+            if depends && seen.insert(package.name()) {
+                dependents.insert(package.name().clone());
+                queue.push_back(package.name());
+            }
+        }
+    }
+    dependents
+}
+
 #[derive(Debug, Clone)]
 #[expect(clippy::large_enum_variant)]
 enum SyncTarget {
@@ -667,6 +858,7 @@ pub(crate) async fn do_sync(
         build_isolation,
         extra_build_dependencies,
         extra_build_variables,
+        build_env_passthrough,
         exclude_newer,
         link_mode,
         compile_bytecode,
@@ -708,7 +900,10 @@ pub(crate) async fn do_sync(
                 build_isolation: build_isolation.clone(),
                 extra_build_dependencies: extra_build_dependencies.clone(),
                 extra_build_variables: extra_build_variables.clone(),
+                build_env_passthrough: build_env_passthrough.clone(),
                 prerelease: PrereleaseMode::default(),
+                prerelease_packages: Vec::new(),
+                require_metadata: None,
                 resolution: ResolutionMode::default(),
                 sources: sources.clone(),
                 torch_backend: None,
@@ -796,8 +991,32 @@ pub(crate) async fn do_sync(
     let resolution = apply_no_virtual_project(resolution);
 
     // If necessary, convert editable to non-editable distributions.
+    let editable = EditableMode::with_no_editable_packages(editable, target.no_editable_packages());
     let resolution = apply_editable_mode(resolution, editable);
 
+    // Omit any excluded packages, which the environment is assumed to provide.
+    let mut site_packages = SitePackages::from_environment(venv)?;
+    let excluded = operations::ExcludedRequirement::from_locked(
+        &resolution,
+        install_options.exclude_package(),
+        &marker_env,
+        |name| {
+            target
+                .lock()
+                .find_by_name(name)
+                .ok()
+                .flatten()
+                .map(uv_resolver::Package::requires_dist)
+        },
+    );
+    let resolution = operations::exclude_packages(
+        resolution,
+        install_options.exclude_package(),
+        install_options.exclude_unchecked(),
+        &excluded,
+        &mut site_packages,
+    )?;
+
     // Constrain any build requirements marked as `match-runtime = true`.
     let extra_build_requires = extra_build_requires.match_runtime(&resolution)?;
 
@@ -808,7 +1027,6 @@ pub(crate) async fn do_sync(
     store_credentials_from_target(target, &client_builder)?;
 
     let bytecode_compilation = compile_bytecode.then_some(operations::BytecodeCompilation::All);
-    let site_packages = SitePackages::from_environment(venv)?;
     let installation_plan = operations::InstallationPlan::build(
         &resolution,
         site_packages,
@@ -908,7 +1126,8 @@ pub(crate) async fn do_sync(
         workspace_cache.clone(),
         concurrency.clone(),
         preview,
-    );
+    )
+    .with_build_env_passthrough(build_env_passthrough.clone());
 
     // Run a malware check against OSV before installing.
     maybe_check_malware(
@@ -1510,7 +1729,7 @@ impl From<(&LockTarget<'_>, &LockMode<'_>, &Outcome)> for LockReport {
                     match result {
                         LockResult::Unchanged(..) => match mode {
                             // When `--frozen` is used, we don't check the lockfile.
-                            LockMode::Frozen(_) => LockAction::Use,
+                            LockMode::Frozen(_) | LockMode::Lockfile { .. } => LockAction::Use,
                             LockMode::DryRun(_) | LockMode::Locked(_, _) | LockMode::Write(_) => {
                                 LockAction::Check
                             }
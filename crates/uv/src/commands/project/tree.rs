@@ -1,5 +1,5 @@
 use std::fmt::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use anstream::print;
 use anyhow::{Error, Result};
@@ -18,13 +18,13 @@ use uv_resolver::{PackageMap, TreeDisplay, TreeJsonTarget};
 use uv_scripts::Pep723Script;
 use uv_settings::PythonInstallMirrors;
 use uv_warnings::warn_user;
-use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache};
+use uv_workspace::{DiscoveryOptions, MemberDiscovery, VirtualProject, WorkspaceCache};
 
 use crate::commands::pip::latest::LatestClient;
 use crate::commands::pip::loggers::DefaultResolveLogger;
 use crate::commands::pip::resolution_markers;
 use crate::commands::project::lock::{LockMode, LockOperation};
-use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::lock_target::SingleLockTarget;
 use crate::commands::project::{
     ProjectError, ProjectInterpreter, ScriptInterpreter, UniversalState, WorkspacePython,
     default_dependency_groups,
@@ -43,8 +43,11 @@ pub(crate) async fn tree(
     groups: DependencyGroups,
     lock_check: LockCheck,
     frozen: Option<FrozenSource>,
+    lockfile: Option<PathBuf>,
+    no_validate: bool,
     universal: bool,
     format: TreeFormat,
+    duplicates: bool,
     depth: u8,
     prune: Vec<PackageName>,
     package: Vec<PackageName>,
@@ -78,31 +81,41 @@ pub(crate) async fn tree(
     // Find the project requirements.
     let virtual_project;
     let target = if let Some(script) = script.as_ref() {
-        LockTarget::Script(script)
+        SingleLockTarget::Script(script)
     } else {
         virtual_project = VirtualProject::discover(
             project_dir,
-            &DiscoveryOptions::default(),
+            &if lockfile.is_some() {
+                DiscoveryOptions {
+                    members: MemberDiscovery::None,
+                    ..DiscoveryOptions::default()
+                }
+            } else {
+                DiscoveryOptions::default()
+            },
             cache,
             workspace_cache,
         )
         .await?;
-        LockTarget::Workspace(virtual_project.workspace())
+        SingleLockTarget::Workspace(virtual_project.workspace())
     };
 
     // Determine the groups to include.
     let default_groups = match target {
-        LockTarget::Workspace(workspace) => default_dependency_groups(workspace.pyproject_toml())?,
-        LockTarget::Script(_) => DefaultGroups::default(),
+        SingleLockTarget::Workspace(workspace) => {
+            default_dependency_groups(workspace.pyproject_toml())?
+        }
+        SingleLockTarget::Script(_) => DefaultGroups::default(),
     };
     let groups = groups.with_defaults(default_groups);
 
-    // Find an interpreter for the project, unless `--frozen` and `--universal` are both set.
-    let interpreter = if frozen.is_some() && universal {
+    // Find an interpreter for the project, unless `--frozen` (or `--lockfile`) and `--universal`
+    // are both set.
+    let interpreter = if (frozen.is_some() || lockfile.is_some()) && universal {
         None
     } else {
         Some(match target {
-            LockTarget::Script(script) => ScriptInterpreter::discover(
+            SingleLockTarget::Script(script) => ScriptInterpreter::discover(
                 script.into(),
                 python.as_deref().map(PythonRequest::parse),
                 client_builder,
@@ -117,7 +130,7 @@ pub(crate) async fn tree(
             )
             .await?
             .into_interpreter(),
-            LockTarget::Workspace(workspace) => {
+            SingleLockTarget::Workspace(workspace) => {
                 let workspace_python = WorkspacePython::from_request(
                     python.as_deref().map(PythonRequest::parse),
                     Some(workspace),
@@ -146,11 +159,16 @@ pub(crate) async fn tree(
     };
 
     // Determine the lock mode.
-    let mode = if let Some(frozen_source) = frozen {
+    let mode = if let Some(path) = lockfile.as_deref() {
+        LockMode::Lockfile {
+            path,
+            validate: !no_validate,
+        }
+    } else if let Some(frozen_source) = frozen {
         LockMode::Frozen(frozen_source.into())
     } else if let LockCheck::Enabled(lock_check) = lock_check {
         LockMode::Locked(interpreter.as_ref().unwrap(), lock_check)
-    } else if matches!(target, LockTarget::Script(_)) && !target.lock_path().is_file() {
+    } else if matches!(target, SingleLockTarget::Script(_)) && !target.lock_path().is_file() {
         // If we're locking a script, avoid creating a lockfile if it doesn't already exist.
         LockMode::DryRun(interpreter.as_ref().unwrap())
     } else {
@@ -174,7 +192,7 @@ pub(crate) async fn tree(
             printer,
             preview,
         )
-        .execute(target),
+        .execute(target.into()),
     )
     .await
     {
@@ -222,6 +240,8 @@ pub(crate) async fn tree(
                 keyring_provider,
                 resolution: _,
                 prerelease: _,
+                prerelease_packages: _,
+                require_metadata: _,
                 fork_strategy: _,
                 dependency_metadata: _,
                 config_setting: _,
@@ -229,6 +249,7 @@ pub(crate) async fn tree(
                 build_isolation: _,
                 extra_build_dependencies: _,
                 extra_build_variables: _,
+                build_env_passthrough: _,
                 exclude_newer: _,
                 link_mode: _,
                 upgrade: _,
@@ -313,16 +334,29 @@ pub(crate) async fn tree(
         show_sizes,
     );
 
+    if duplicates {
+        let duplicates = tree.duplicates();
+        match format {
+            TreeFormat::Text if duplicates.is_empty() => writeln!(
+                printer.stderr(),
+                "No packages are locked at multiple versions"
+            )?,
+            TreeFormat::Text => print!("{duplicates}"),
+            TreeFormat::Json => writeln!(printer.stdout_important(), "{}", duplicates.to_json()?)?,
+        }
+        return Ok(ExitStatus::Success);
+    }
+
     match format {
         TreeFormat::Text => print!("{tree}"),
         TreeFormat::Json => writeln!(
             printer.stdout_important(),
             "{}",
             tree.to_json(match target {
-                LockTarget::Workspace(workspace) => {
+                SingleLockTarget::Workspace(workspace) => {
                     TreeJsonTarget::Workspace(workspace.install_path())
                 }
-                LockTarget::Script(script) => TreeJsonTarget::Script(&script.path),
+                SingleLockTarget::Script(script) => TreeJsonTarget::Script(&script.path),
             })?
         )?,
     }
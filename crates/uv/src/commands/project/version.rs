@@ -12,7 +12,7 @@ use uv_cli::version::ProjectVersionInfo;
 use uv_cli::{VersionBump, VersionBumpSpec, VersionFormat};
 use uv_client::BaseClientBuilder;
 use uv_configuration::{
-    Concurrency, DependencyGroups, DryRun, ExtrasSpecification, InstallOptions,
+    Concurrency, DependencyGroups, DryRun, ExtrasSpecification, InstallOptions, ShowDiff,
 };
 use uv_fs::Simplified;
 use uv_normalize::DefaultExtras;
@@ -32,6 +32,7 @@ use uv_workspace::{
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
 use crate::commands::pip::operations::Modifications;
 use crate::commands::project::add::{AddTarget, PythonTarget};
+use crate::commands::project::diff;
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::LockMode;
 use crate::commands::project::lock_target::LockTarget;
@@ -78,6 +79,7 @@ pub(crate) async fn project_version(
     package: Option<PackageName>,
     explicit_project: bool,
     dry_run: bool,
+    show_diff: ShowDiff,
     lock_check: LockCheck,
     frozen: Option<FrozenSource>,
     active: Option<bool>,
@@ -142,24 +144,39 @@ pub(crate) async fn project_version(
         DependencyTarget::PyProjectToml,
     )?;
 
-    let old_version = toml.version().map_err(|err| match err {
-        Error::MalformedWorkspace => {
-            if toml.has_dynamic_version() {
-                anyhow!(
-                    "We cannot get or set dynamic project versions in: {}",
-                    pyproject_path.user_display()
-                )
-            } else {
-                anyhow!(
-                    "There is no 'project.version' field in: {}",
-                    pyproject_path.user_display()
-                )
+    // If the version is read from a file by the build backend, read and write that file instead.
+    let version_file = toml
+        .version_file()
+        .map(|version_file| project.root().join(version_file));
+    if version_file.is_some() && !toml.has_dynamic_version() {
+        return Err(anyhow!(
+            "`project.dynamic` must contain `version` when `tool.uv.build-backend.version` is used in: {}",
+            pyproject_path.user_display()
+        ));
+    }
+
+    let old_version = if let Some(version_file) = &version_file {
+        read_version_file(version_file)?
+    } else {
+        toml.version().map_err(|err| match err {
+            Error::MalformedWorkspace => {
+                if toml.has_dynamic_version() {
+                    anyhow!(
+                        "We cannot get or set dynamic project versions in: {}",
+                        pyproject_path.user_display()
+                    )
+                } else {
+                    anyhow!(
+                        "There is no 'project.version' field in: {}",
+                        pyproject_path.user_display()
+                    )
+                }
             }
-        }
-        err => {
-            anyhow!("{err}: {}", pyproject_path.user_display())
-        }
-    })?;
+            err => {
+                anyhow!("{err}: {}", pyproject_path.user_display())
+            }
+        })?
+    };
 
     // Figure out new metadata
     let new_version = if let Some(value) = value {
@@ -331,35 +348,52 @@ pub(crate) async fn project_version(
     let status = if dry_run {
         ExitStatus::Success
     } else if let Some(new_version) = &new_version {
-        let project = update_project(
-            project,
-            new_version,
-            &mut toml,
-            &pyproject_path,
-            workspace_cache,
-        )?;
-        Box::pin(lock_and_sync(
-            project,
-            project_dir,
-            lock_check,
-            frozen,
-            active,
-            no_sync,
-            python,
-            install_mirrors,
-            &settings,
-            client_builder,
-            python_preference,
-            python_downloads,
-            installer_metadata,
-            &concurrency,
-            config_discovery,
-            cache,
-            printer,
-            preview,
-            &malware_settings,
-        ))
-        .await?
+        // Render the updated version file or `pyproject.toml`.
+        let (path, content) = if let Some(version_file) = &version_file {
+            // Normalize the file to the version followed by a single newline.
+            (version_file.as_path(), format!("{new_version}\n"))
+        } else {
+            toml.set_version(new_version)?;
+            (pyproject_path.as_path(), toml.to_string())
+        };
+
+        // Display the changes and, if `--diff-only`, skip writing them.
+        if show_diff.enabled() {
+            let existing = fs_err::read_to_string(path)?;
+            diff::show_diff(path, &existing, &content, printer)?;
+        }
+        if show_diff.is_only() {
+            ExitStatus::Success
+        } else {
+            let project = if version_file.is_some() {
+                fs_err::write(path, content)?;
+                project
+            } else {
+                update_project(project, content, &pyproject_path, workspace_cache)?
+            };
+            Box::pin(lock_and_sync(
+                project,
+                project_dir,
+                lock_check,
+                frozen,
+                active,
+                no_sync,
+                python,
+                install_mirrors,
+                &settings,
+                client_builder,
+                python_preference,
+                python_downloads,
+                installer_metadata,
+                &concurrency,
+                config_discovery,
+                cache,
+                printer,
+                preview,
+                &malware_settings,
+            ))
+            .await?
+        }
     } else {
         debug!("No changes to version; skipping update");
         ExitStatus::Success
@@ -438,17 +472,22 @@ async fn find_target(
     Ok(project)
 }
 
+/// Read the project version from the file configured with `tool.uv.build-backend.version`.
+fn read_version_file(path: &Path) -> Result<Version> {
+    let contents = fs_err::read_to_string(path)?;
+    // Tolerate surrounding whitespace, such as a trailing newline.
+    Version::from_str(contents.trim())
+        .map_err(|err| anyhow!("Invalid version in `{}`: {err}", path.user_display()))
+}
+
 /// Update the pyproject.toml on-disk and in-memory with a new version
 fn update_project(
     project: VirtualProject,
-    new_version: &Version,
-    toml: &mut PyProjectTomlMut,
+    content: String,
     pyproject_path: &Path,
     workspace_cache: &WorkspaceCache,
 ) -> Result<VirtualProject> {
     // Save to disk
-    toml.set_version(new_version)?;
-    let content = toml.to_string();
     fs_err::write(pyproject_path, &content)?;
 
     // Update the `pyproject.toml` in-memory.
@@ -608,6 +647,7 @@ async fn lock_and_sync(
             no_sync,
             config_discovery,
             active,
+            false,
             cache,
             DryRun::Disabled,
             LinkErrorReporting::User,
@@ -621,9 +661,9 @@ async fn lock_and_sync(
 
     // Determine the lock mode.
     let mode = if let LockCheck::Enabled(lock_check) = lock_check {
-        LockMode::Locked(target.interpreter(), lock_check)
+        LockMode::Locked(target.interpreter()?, lock_check)
     } else {
-        LockMode::Write(target.interpreter())
+        LockMode::Write(target.interpreter()?)
     };
 
     // Initialize any shared state.
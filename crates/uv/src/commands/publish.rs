@@ -1,4 +1,6 @@
+use std::collections::BTreeSet;
 use std::fmt::Write;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use anyhow::{Context, Result, bail};
@@ -17,19 +19,22 @@ use uv_distribution_types::{IndexCapabilities, IndexLocations, IndexUrl};
 use uv_errors::{ErrorOptions, Hints, write_error_chain_with_options};
 use uv_preview::{Preview, PreviewFeature};
 use uv_publish::{
-    CheckUrlClient, FormMetadata, PublishError, TrustedPublishResult, check_trusted_publishing,
-    group_files_for_publishing, upload, upload_two_phase,
+    CheckUrlCapability, CheckUrlClient, FormMetadata, PublishError, TrustedPublishResult,
+    check_trusted_publishing, group_build_files_for_publishing, group_files_for_publishing, upload,
+    upload_two_phase,
 };
 use uv_redacted::DisplaySafeUrl;
 use uv_settings::EnvironmentOptions;
-use uv_warnings::warn_user_once;
+use uv_warnings::{warn_user, warn_user_once};
 
 use crate::commands::reporters::PublishReporter;
 use crate::commands::{ExitStatus, human_readable_bytes};
 use crate::printer::Printer;
 
+#[expect(clippy::fn_params_excessive_bools)]
 pub(crate) async fn publish(
     paths: Vec<String>,
+    from_build: Option<PathBuf>,
     publish_url: DisplaySafeUrl,
     trusted_publishing: TrustedPublishing,
     keyring_provider: KeyringProviderType,
@@ -41,6 +46,7 @@ pub(crate) async fn publish(
     index: Option<String>,
     index_locations: IndexLocations,
     dry_run: bool,
+    check_metadata: bool,
     no_attestations: bool,
     direct: bool,
     preview: Preview,
@@ -103,7 +109,11 @@ pub(crate) async fn publish(
         (publish_url, check_url)
     };
 
-    let mut groups = group_files_for_publishing(paths, no_attestations)?;
+    let mut groups = if let Some(output_dir) = from_build {
+        group_build_files_for_publishing(&output_dir, no_attestations)?
+    } else {
+        group_files_for_publishing(paths, no_attestations)?
+    };
     // Sort by filename first so the stable type sort preserves filename order within each type.
     groups.sort_by(|left, right| left.raw_filename.cmp(&right.raw_filename));
     // Sort by distribution type, with wheels before source distributions.
@@ -126,6 +136,36 @@ pub(crate) async fn publish(
         }
     }
 
+    // Check the metadata of all files before uploading any of them.
+    if check_metadata {
+        let mut invalid_count: usize = 0;
+        for group in &groups {
+            match uv_publish::check_metadata(&group.file, &group.filename).await {
+                Ok(warnings) => {
+                    for warning in warnings {
+                        warn_user!("`{}`: {warning}", group.raw_filename);
+                    }
+                }
+                Err(err) => {
+                    write_error_chain_with_options(
+                        &err,
+                        Hints::none(),
+                        ErrorOptions::default().with_stream(printer.stderr()),
+                    )?;
+                    invalid_count += 1;
+                }
+            }
+        }
+        if invalid_count > 0 {
+            let failed = if invalid_count == 1 { "file" } else { "files" };
+            writeln!(
+                printer.stderr(),
+                "Found invalid metadata in {invalid_count} {failed}"
+            )?;
+            return Ok(ExitStatus::Failure);
+        }
+    }
+
     // * For the uploads themselves, we roll our own retries due to
     //   https://github.com/seanmonstar/reqwest/issues/2416, but for trusted publishing, we want
     //   the default retries. We set the retries to 0 here and manually construct the retry policy
@@ -204,6 +244,54 @@ pub(crate) async fn publish(
 
     let mut error_count: usize = 0;
 
+    // Probe the check URL for each project before uploading any files.
+    if let Some(check_url_client) = &check_url_client {
+        let package_names = groups
+            .iter()
+            .map(|group| group.filename.name())
+            .collect::<BTreeSet<_>>();
+        for package_name in package_names {
+            let index_url = &check_url_client.index_url;
+            match check_url_client.probe(package_name).await {
+                Ok(CheckUrlCapability::Json) => {
+                    writeln!(
+                        printer.stderr(),
+                        "Found `{package_name}` on {index_url} (JSON Simple API)"
+                    )?;
+                }
+                Ok(CheckUrlCapability::Html) => {
+                    writeln!(
+                        printer.stderr(),
+                        "Found `{package_name}` on {index_url} (HTML Simple API)"
+                    )?;
+                }
+                Ok(CheckUrlCapability::MislabeledHtml) => {
+                    warn_user!(
+                        "{index_url} serves HTML for `{package_name}`, but labels it as JSON; falling back to parsing it as HTML"
+                    );
+                }
+                Ok(CheckUrlCapability::ProjectNotFound) => {
+                    writeln!(
+                        printer.stderr(),
+                        "`{package_name}` does not exist on {index_url} yet, assuming no files have been uploaded"
+                    )?;
+                }
+                Err(err) => {
+                    if dry_run {
+                        write_error_chain_with_options(
+                            &err,
+                            Hints::none(),
+                            ErrorOptions::default().with_stream(printer.stderr()),
+                        )?;
+                        error_count += 1;
+                        continue;
+                    }
+                    return Err(err.into());
+                }
+            }
+        }
+    }
+
     for group in groups {
         // Check if the filename is normalized (e.g., version `2025.09.4` should be `2025.9.4`).
         let normalized_filename = group.filename.to_string();
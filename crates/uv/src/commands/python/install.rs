@@ -23,11 +23,12 @@ use uv_platform::{Arch, Libc};
 use uv_preview::{Preview, PreviewFeature};
 use uv_python::downloads::{
     self, ArchRequest, DownloadResult, ManagedPythonDownload, ManagedPythonDownloadList,
-    PythonDownloadRequest,
+    PythonDownloadRequest, SourceBuildOptions,
 };
 use uv_python::managed::{
-    ManagedPythonInstallation, ManagedPythonInstallations, PythonMinorVersionLink,
+    ManagedPythonInstallation, ManagedPythonInstallations, PythonMinorVersionLink, Verification,
     compare_build_versions, create_link_to_executable, python_executable_dir,
+    remove_installation_dir,
 };
 use uv_python::{
     ConfigDiscovery, ImplementationName, Interpreter, PythonDownloads, PythonInstallationKey,
@@ -38,7 +39,7 @@ use uv_shell::Shell;
 use uv_trampoline_builder::{Launcher, LauncherKind};
 use uv_warnings::warn_user;
 
-use crate::commands::python::{ChangeEvent, ChangeEventKind};
+use crate::commands::python::{ChangeEvent, ChangeEventKind, verify_installation};
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::{ExitStatus, conjunction, elapsed};
 use crate::printer::Printer;
@@ -147,6 +148,7 @@ impl Changelog {
 #[derive(Debug, Clone, Copy)]
 enum InstallErrorKind {
     DownloadUnpack,
+    Verify,
     Bin,
     #[cfg_attr(not(windows), allow(dead_code))]
     Registry,
@@ -193,6 +195,8 @@ pub(crate) async fn install(
     python_downloads_json_url: Option<String>,
     client_builder: BaseClientBuilder<'_>,
     default: bool,
+    verify: bool,
+    build_from_source: Option<SourceBuildOptions>,
     python_downloads: PythonDownloads,
     config_discovery: ConfigDiscovery,
     compile_bytecode: bool,
@@ -241,6 +245,8 @@ pub(crate) async fn install(
         client_builder,
         cache,
         default,
+        verify,
+        build_from_source,
         python_downloads,
         config_discovery,
         compile_bytecode.then_some(sender),
@@ -285,6 +291,7 @@ pub(crate) async fn install(
     installer_result
 }
 
+#[expect(clippy::fn_params_excessive_bools)]
 async fn perform_install(
     project_dir: &Path,
     install_dir: Option<PathBuf>,
@@ -300,6 +307,8 @@ async fn perform_install(
     client_builder: BaseClientBuilder<'_>,
     cache: &Cache,
     default: bool,
+    verify: bool,
+    build_from_source: Option<SourceBuildOptions>,
     python_downloads: PythonDownloads,
     config_discovery: ConfigDiscovery,
     bytecode_compilation_sender: Option<mpsc::UnboundedSender<ManagedPythonInstallation>>,
@@ -329,6 +338,7 @@ async fn perform_install(
     let installations_dir = installations.root();
     let scratch_dir = installations.scratch();
     let _lock = installations.lock().await?;
+    installations.clean_scratch()?;
     let existing_installations: Vec<_> = installations
         .find_all()?
         .inspect(|installation| trace!("Found existing installation {}", installation.key()))
@@ -603,13 +613,33 @@ async fn perform_install(
         .unique_by(|download| download.key())
         .collect::<Vec<_>>();
 
+    if build_from_source.is_some() {
+        for download in &downloads {
+            writeln!(
+                printer.stderr(),
+                "Building {} from source; this may take a while",
+                download.key().green()
+            )?;
+        }
+    }
+
     // Download and unpack the Python versions concurrently
     let reporter = PythonDownloadReporter::new(printer, Some(downloads.len() as u64));
 
     let mut tasks = futures::stream::iter(&downloads)
         .map(async |download| {
-            (
-                *download,
+            let result = if let Some(options) = build_from_source {
+                download
+                    .build_from_source(
+                        &client,
+                        installations_dir,
+                        &scratch_dir,
+                        reinstall,
+                        options,
+                        Some(&reporter),
+                    )
+                    .await
+            } else {
                 download
                     .fetch_with_retry(
                         &client,
@@ -621,8 +651,9 @@ async fn perform_install(
                         pypy_install_mirror.as_deref(),
                         Some(&reporter),
                     )
-                    .await,
-            )
+                    .await
+            };
+            (*download, result)
         })
         .buffer_unordered(concurrency.downloads);
 
@@ -638,12 +669,11 @@ async fn perform_install(
                     DownloadResult::Fetched(path) => path,
                 };
 
-                let installation = ManagedPythonInstallation::new(path, download);
-                if let Some(ref sender) = bytecode_compilation_sender {
-                    sender
-                        .send(installation.clone())
-                        .map_err(|err| anyhow::anyhow!(err))?;
-                }
+                let installation = if build_from_source.is_some() {
+                    ManagedPythonInstallation::from_source_build(path, download)
+                } else {
+                    ManagedPythonInstallation::new(path, download)
+                };
                 changelog.installed.insert(installation.key().clone());
                 for request in &requests {
                     // Take note of which installations satisfied which requests
@@ -684,10 +714,84 @@ async fn perform_install(
         installation.ensure_sysconfig_patched()?;
         installation.ensure_canonical_executables()?;
         installation.ensure_build_file()?;
+        installation.ensure_archive_hash_file()?;
         if let Err(e) = installation.ensure_dylib_patched() {
             e.warn_user(installation);
         }
+    }
+
+    // Record the hashes of the newly installed files, such that `--verify` can re-validate them.
+    for installation in &downloaded {
+        installation.record_file_hashes().await?;
+    }
+
+    // Run a smoke test against the downloaded installations, removing any that are not functional.
+    // With `--verify`, existing installations that match the request are tested too.
+    let mut verified = Vec::new();
+    let mut broken = FxHashSet::default();
+    for installation in &downloaded {
+        match installation.verify().await {
+            Ok(verification) => {
+                if verify && verification == Verification::Passed {
+                    verified.push(installation.key().clone());
+                }
+            }
+            Err(err) => {
+                debug!(
+                    "Removing broken installation at: {}",
+                    installation.path().user_display()
+                );
+                if let Err(remove_err) =
+                    remove_installation_dir(installation.path(), &scratch_dir).await
+                {
+                    warn_user!(
+                        "Failed to remove broken installation at `{}`: {remove_err}",
+                        installation.path().user_display()
+                    );
+                }
+                changelog.installed.remove(installation.key());
+                broken.insert(installation.key().clone());
+                errors.push((
+                    InstallErrorKind::Verify,
+                    installation.key().clone(),
+                    anyhow::Error::new(err),
+                ));
+            }
+        }
+    }
+    if verify {
+        for installation in &satisfied {
+            match verify_installation(installation).await {
+                Ok(Verification::Passed) => verified.push(installation.key().clone()),
+                Ok(Verification::Skipped) => {}
+                Err(err) => {
+                    errors.push((
+                        InstallErrorKind::Verify,
+                        installation.key().clone(),
+                        anyhow::Error::new(err),
+                    ));
+                }
+            }
+        }
+    }
+    let installations: Vec<_> = installations
+        .into_iter()
+        .filter(|installation| !broken.contains(installation.key()))
+        .collect();
+
+    if let Some(ref sender) = bytecode_compilation_sender {
+        downloaded
+            .iter()
+            .filter(|installation| !broken.contains(installation.key()))
+            .cloned()
+            .try_for_each(|installation| {
+                sender
+                    .send(installation)
+                    .map_err(|err| anyhow::anyhow!(err))
+            })?;
+    }
 
+    for installation in &installations {
         let upgradeable = (default || is_default_install)
             || requested_minor_versions.contains(&installation.key().version().python_version());
 
@@ -731,16 +835,21 @@ async fn perform_install(
 
     let minor_versions =
         PythonInstallationMinorVersionKey::highest_installations_by_minor_version_key(
-            installations
-                .iter()
-                .copied()
-                .chain(existing_installations.iter()),
+            installations.iter().copied().chain(
+                existing_installations
+                    .iter()
+                    .filter(|installation| !broken.contains(installation.key())),
+            ),
         );
 
     for installation in minor_versions.values() {
         installation.ensure_minor_version_link()?;
     }
 
+    for key in &verified {
+        writeln!(printer.stderr(), "Verified {}", key.bold())?;
+    }
+
     if changelog.installed.is_empty() && errors.is_empty() {
         if is_default_install {
             if matches!(
@@ -911,7 +1020,7 @@ async fn perform_install(
         let fatal = !errors.iter().all(|(kind, _, _)| match kind {
             InstallErrorKind::Bin => bin.is_none(),
             InstallErrorKind::Registry => registry.is_none(),
-            InstallErrorKind::DownloadUnpack => false,
+            InstallErrorKind::DownloadUnpack | InstallErrorKind::Verify => false,
         });
 
         for (kind, key, err) in errors
@@ -926,6 +1035,18 @@ async fn perform_install(
                         ErrorOptions::default().with_stream(printer.stderr()),
                     )?;
                 }
+                InstallErrorKind::Verify => {
+                    let context = if broken.contains(&key) {
+                        format!("Failed to install {key}")
+                    } else {
+                        format!("Failed to verify {key}")
+                    };
+                    write_error_chain_with_options(
+                        err.context(context).as_ref(),
+                        Hints::none(),
+                        ErrorOptions::default().with_stream(printer.stderr()),
+                    )?;
+                }
                 InstallErrorKind::Bin => {
                     let (level, color) = match bin {
                         None => ("warning", AnsiColors::Yellow),
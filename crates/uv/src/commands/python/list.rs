@@ -5,21 +5,27 @@ use uv_cli::PythonListFormat;
 use uv_pep440::Version;
 
 use anyhow::Result;
+use diskus::DiskUsage;
 use itertools::Either;
+use jiff::tz::TimeZone;
+use jiff::{Timestamp, Unit};
 use owo_colors::OwoColorize;
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 use uv_cache::Cache;
 use uv_client::BaseClientBuilder;
+use uv_errors::{ErrorOptions, Hints, write_error_chain_with_options};
 use uv_fs::Simplified;
 use uv_python::downloads::{
     Error as PythonDownloadError, ManagedPythonDownloadList, PythonDownloadRequest,
 };
+use uv_python::managed::{ManagedPythonInstallation, ManagedPythonInstallations, Verification};
 use uv_python::{
     EnvironmentPreference, PythonDownloads, PythonPreference, PythonRequest, PythonSource,
-    find_all_python_installations,
+    find_all_python_installations, is_conda_environment,
 };
 
-use crate::commands::ExitStatus;
+use crate::commands::python::verify_installation;
+use crate::commands::{ExitStatus, human_readable_bytes};
 use crate::printer::Printer;
 use crate::settings::PythonListKinds;
 
@@ -50,6 +56,70 @@ struct PrintData {
     implementation: String,
     arch: String,
     libc: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    verification: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    origin: Option<&'static str>,
+    #[serde(flatten)]
+    usage: Option<Usage>,
+}
+
+/// The disk usage and last use of a managed Python installation, shown with `--show-usage`.
+#[derive(Debug, Clone, Serialize)]
+struct Usage {
+    /// The size of the installation directory, in bytes.
+    disk_usage: u64,
+    /// The time at which the installation was last selected by a uv command, if known.
+    last_used: Option<Timestamp>,
+}
+
+impl Usage {
+    fn from_installation(installation: &ManagedPythonInstallation) -> Self {
+        let disk_usage =
+            DiskUsage::new(vec![installation.path().to_path_buf()]).count_ignoring_errors();
+        let last_used = installation
+            .last_used()
+            .and_then(|last_used| Timestamp::try_from(last_used).ok())
+            .and_then(|last_used| last_used.round(Unit::Second).ok());
+        Self {
+            disk_usage,
+            last_used,
+        }
+    }
+
+    /// Format the usage as a column of the text output.
+    fn to_column(&self) -> String {
+        let (size, unit) = human_readable_bytes(self.disk_usage);
+        let last_used = match self.last_used {
+            Some(last_used) => format!(
+                "last used {}",
+                last_used.to_zoned(TimeZone::system()).strftime("%Y-%m-%d")
+            ),
+            None => "last used unknown".to_string(),
+        };
+        format!(
+            "    {}    {}",
+            format!("{size:.1}{unit}").bold(),
+            last_used.dimmed()
+        )
+    }
+}
+
+/// The result of verifying a managed Python installation with `--verify`.
+enum Status {
+    Passed,
+    Skipped,
+    Failed(uv_python::managed::Error),
+}
+
+impl Status {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Passed => "passed",
+            Self::Skipped => "skipped",
+            Self::Failed(_) => "failed",
+        }
+    }
 }
 
 /// List available Python installations.
@@ -61,6 +131,8 @@ pub(crate) async fn list(
     all_platforms: bool,
     all_arches: bool,
     show_urls: bool,
+    verify: bool,
+    show_usage: bool,
     output_format: PythonListFormat,
     python_downloads_json_url: Option<String>,
     python_install_mirror: Option<String>,
@@ -163,6 +235,8 @@ pub(crate) async fn list(
         PythonListKinds::Downloads => None,
     };
 
+    // Track the interpreters provided by conda, so that their origin can be labeled.
+    let mut conda = FxHashSet::default();
     if let Some(installed) = installed {
         for installation in installed {
             let kind = if matches!(installation.source(), PythonSource::Managed) {
@@ -170,11 +244,11 @@ pub(crate) async fn list(
             } else {
                 Kind::System
             };
-            output.insert((
-                installation.key(),
-                kind,
-                Either::Left(installation.interpreter().real_executable().to_path_buf()),
-            ));
+            let executable = installation.interpreter().real_executable().to_path_buf();
+            if is_conda_environment(installation.interpreter().sys_prefix()) {
+                conda.insert(executable.clone());
+            }
+            output.insert((installation.key(), kind, Either::Left(executable)));
         }
     }
 
@@ -231,6 +305,49 @@ pub(crate) async fn list(
         include.push((key, uri));
     }
 
+    let managed = if verify || show_usage {
+        ManagedPythonInstallations::from_settings(None)?
+            .find_all()?
+            .map(|installation| (installation.key().clone(), installation))
+            .collect::<FxHashMap<_, _>>()
+    } else {
+        FxHashMap::default()
+    };
+
+    // Run the smoke test against each managed installation, if requested.
+    let mut statuses = FxHashMap::default();
+    if verify {
+        for (key, _) in &include {
+            let Some(installation) = managed.get(*key) else {
+                continue;
+            };
+            let status = match verify_installation(installation).await {
+                Ok(Verification::Passed) => Status::Passed,
+                Ok(Verification::Skipped) => Status::Skipped,
+                Err(err) => Status::Failed(err),
+            };
+            statuses.insert((*key).clone(), status);
+        }
+    }
+
+    // Compute the disk usage of each managed installation, if requested.
+    let mut usages = FxHashMap::default();
+    if show_usage {
+        for (key, uri) in &include {
+            // Only managed installations are measured, not downloads or system interpreters.
+            let Either::Left(path) = uri else {
+                continue;
+            };
+            let Some(installation) = managed
+                .get(*key)
+                .filter(|installation| path.starts_with(installation.path()))
+            else {
+                continue;
+            };
+            usages.insert(path, Usage::from_installation(installation));
+        }
+    }
+
     match output_format {
         PythonListFormat::Json => {
             let data = include
@@ -273,6 +390,15 @@ pub(crate) async fn list(
                         os: key.os().to_string(),
                         variant: key.variant().to_string(),
                         libc: key.libc().to_string(),
+                        verification: statuses.get(*key).map(Status::as_str),
+                        origin: match uri {
+                            Either::Left(path) if conda.contains(path) => Some("conda"),
+                            _ => None,
+                        },
+                        usage: match uri {
+                            Either::Left(path) => usages.get(path).cloned(),
+                            Either::Right(_) => None,
+                        },
                     })
                 })
                 .collect::<Result<Vec<_>>>()?;
@@ -284,22 +410,39 @@ pub(crate) async fn list(
                 .iter()
                 .fold(0usize, |acc, (key, _)| acc.max(key.to_string().len()));
 
-            for (key, uri) in include {
+            for (key, uri) in &include {
+                let status = match statuses.get(*key) {
+                    Some(status @ Status::Passed) => format!("    {}", status.as_str().green()),
+                    Some(status @ Status::Skipped) => {
+                        format!("    {}", status.as_str().dimmed())
+                    }
+                    Some(status @ Status::Failed(_)) => format!("    {}", status.as_str().red()),
+                    None => String::new(),
+                };
                 let key = key.to_string();
                 match uri {
                     Either::Left(path) => {
+                        let status = if conda.contains(path) {
+                            format!(" {}{status}", "(conda)".dimmed())
+                        } else {
+                            status
+                        };
+                        let status = match usages.get(path) {
+                            Some(usage) => format!("{status}{}", usage.to_column()),
+                            None => status,
+                        };
                         let is_symlink = fs_err::symlink_metadata(path)?.is_symlink();
                         if is_symlink {
                             writeln!(
                                 printer.stdout(),
-                                "{key:width$}    {} -> {}",
+                                "{key:width$}    {} -> {}{status}",
                                 path.user_display().cyan(),
                                 path.read_link()?.user_display().cyan()
                             )?;
                         } else {
                             writeln!(
                                 printer.stdout(),
-                                "{key:width$}    {}",
+                                "{key:width$}    {}{status}",
                                 path.user_display().cyan()
                             )?;
                         }
@@ -320,5 +463,22 @@ pub(crate) async fn list(
         }
     }
 
+    // Report the reason for any failed verification.
+    let mut failed = false;
+    for (key, _) in &include {
+        if let Some(Status::Failed(err)) = statuses.remove(*key) {
+            let err = anyhow::Error::new(err).context(format!("Failed to verify {key}"));
+            write_error_chain_with_options(
+                err.as_ref(),
+                Hints::none(),
+                ErrorOptions::default().with_stream(printer.stderr()),
+            )?;
+            failed = true;
+        }
+    }
+    if failed {
+        return Ok(ExitStatus::Failure);
+    }
+
     Ok(ExitStatus::Success)
 }
@@ -3,6 +3,7 @@ pub(crate) mod find;
 pub(crate) mod install;
 pub(crate) mod list;
 pub(crate) mod pin;
+pub(crate) mod platform;
 pub(crate) mod uninstall;
 pub(crate) mod update_shell;
 
@@ -21,3 +22,24 @@ pub(super) struct ChangeEvent {
     key: uv_python::PythonInstallationKey,
     kind: ChangeEventKind,
 }
+
+/// Verify an existing managed Python installation with `--verify`.
+///
+/// Runs the smoke test against the installation and re-validates the installed files against the
+/// hashes recorded at install time. If the archive the installation was extracted from was
+/// retained, its checksum is re-validated too. The installation only passes if both the smoke
+/// test and the file check pass.
+pub(super) async fn verify_installation(
+    installation: &uv_python::managed::ManagedPythonInstallation,
+) -> Result<uv_python::managed::Verification, uv_python::managed::Error> {
+    use uv_python::managed::Verification;
+
+    let smoke_test = installation.verify().await?;
+    let files = installation.verify_files().await?;
+    installation.verify_archive().await?;
+    if smoke_test == Verification::Passed && files == Verification::Passed {
+        Ok(Verification::Passed)
+    } else {
+        Ok(Verification::Skipped)
+    }
+}
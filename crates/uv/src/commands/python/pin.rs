@@ -2,6 +2,7 @@ use std::fmt::Write;
 use std::path::Path;
 
 use anyhow::{Result, bail};
+use itertools::Itertools;
 use owo_colors::OwoColorize;
 use tracing::debug;
 use uv_python::downloads::ManagedPythonDownloadList;
@@ -15,6 +16,7 @@ use uv_python::{
     PythonPreference, PythonRequest, PythonVersionFile, VersionFileDiscoveryOptions,
 };
 use uv_settings::PythonInstallMirrors;
+use uv_static::EnvVars;
 use uv_warnings::warn_user_once;
 use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache};
 
@@ -34,12 +36,17 @@ pub(crate) async fn pin(
     no_project: bool,
     global: bool,
     rm: bool,
+    show_resolution: bool,
     install_mirrors: PythonInstallMirrors,
     client_builder: BaseClientBuilder<'_>,
     cache: &Cache,
     workspace_cache: &WorkspaceCache,
     printer: Printer,
 ) -> Result<ExitStatus> {
+    if show_resolution {
+        return show_pin_resolution(project_dir, global, printer).await;
+    }
+
     let virtual_project = if no_project {
         None
     } else {
@@ -254,6 +261,88 @@ pub(crate) async fn pin(
     Ok(ExitStatus::Success)
 }
 
+/// Show every Python version file that applies to the given directory, in order of precedence,
+/// along with which one is effective and why.
+async fn show_pin_resolution(
+    project_dir: &Path,
+    global: bool,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let files = PythonVersionFile::discover_all(
+        project_dir,
+        &VersionFileDiscoveryOptions::default().with_no_local(global),
+    )
+    .await?;
+
+    if let Some((effective, shadowed)) = files.split_first() {
+        writeln!(
+            printer.stdout(),
+            "Python version files, in order of precedence:"
+        )?;
+
+        let reason = if effective.is_global() {
+            "global pin; no local version file found"
+        } else {
+            "nearest to the working directory"
+        };
+        writeln!(
+            printer.stdout(),
+            "  `{}`: {} ({}; {reason})",
+            effective.path().user_display().cyan(),
+            display_versions(effective).green(),
+            "effective".bold(),
+        )?;
+
+        for file in shadowed {
+            writeln!(
+                printer.stdout(),
+                "  `{}`: {} ({}shadowed by `{}`)",
+                file.path().user_display().cyan(),
+                display_versions(file),
+                if file.is_global() { "global pin; " } else { "" },
+                effective.path().user_display(),
+            )?;
+        }
+    } else if global {
+        writeln!(printer.stdout(), "No global Python pin found")?;
+    } else {
+        writeln!(printer.stdout(), "No Python version files found")?;
+    }
+
+    if let Some(python) = std::env::var(EnvVars::UV_PYTHON)
+        .ok()
+        .filter(|python| !python.is_empty())
+    {
+        writeln!(
+            printer.stdout(),
+            "`{}` is set to `{}`, which takes precedence over any version file",
+            EnvVars::UV_PYTHON,
+            python.green()
+        )?;
+    } else {
+        writeln!(
+            printer.stdout(),
+            "A `--python` request or `{}` would take precedence over any version file",
+            EnvVars::UV_PYTHON
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Display the requests declared in a version file.
+fn display_versions(file: &PythonVersionFile) -> String {
+    let versions = file
+        .versions()
+        .map(PythonRequest::to_canonical_string)
+        .join(", ");
+    if versions.is_empty() {
+        "(empty)".to_string()
+    } else {
+        versions
+    }
+}
+
 /// Check if pinned request is compatible with the workspace/project's `Requires-Python`.
 fn warn_if_existing_pin_incompatible_with_project(
     pin: &PythonRequest,
@@ -0,0 +1,71 @@
+use std::fmt::Write;
+
+use anyhow::Result;
+use clap::ValueEnum;
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+
+use uv_configuration::TargetTriple;
+use uv_platform_tags::{Tags, TagsOptions};
+use uv_python::PythonVersion;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// List the platforms accepted by `--python-platform`, along with the wheel tags they expand to.
+pub(crate) fn list(
+    python_platform: Option<TargetTriple>,
+    python_version: &PythonVersion,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let targets = match python_platform {
+        Some(python_platform) => vec![python_platform],
+        None => TargetTriple::value_variants().to_vec(),
+    };
+
+    let version_tuple = (python_version.major(), python_version.minor());
+
+    for (index, target) in targets.into_iter().enumerate() {
+        let Some(value) = target.to_possible_value() else {
+            continue;
+        };
+
+        if index > 0 {
+            writeln!(printer.stdout())?;
+        }
+
+        let mut names = value.get_name_and_aliases();
+        let name = names.next().expect("a possible value always has a name");
+        let aliases = names.join(", ");
+        if aliases.is_empty() {
+            writeln!(printer.stdout(), "{}", name.bold())?;
+        } else {
+            writeln!(
+                printer.stdout(),
+                "{} {}",
+                name.bold(),
+                format!("(alias: {aliases})").dimmed()
+            )?;
+        }
+
+        // Mirror the tags used by `--python-platform` during resolution and installation, for a
+        // CPython interpreter of the requested version.
+        let tags = Tags::from_env(
+            target.platform(),
+            version_tuple,
+            "cpython",
+            version_tuple,
+            TagsOptions {
+                manylinux_compatible: target.manylinux_compatible(),
+                gil_disabled: false,
+                debug_enabled: false,
+                is_cross: true,
+            },
+        )?;
+        for tag in tags.to_string().lines() {
+            writeln!(printer.stdout(), "  {tag}")?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
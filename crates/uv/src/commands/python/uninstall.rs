@@ -15,6 +15,7 @@ use uv_fs::Simplified;
 use uv_python::downloads::PythonDownloadRequest;
 use uv_python::managed::{
     ManagedPythonInstallations, PythonMinorVersionLink, python_executable_dir,
+    remove_installation_dir,
 };
 use uv_python::{PythonInstallationKey, PythonInstallationMinorVersionKey, PythonRequest};
 
@@ -198,12 +199,13 @@ async fn do_uninstall(
             .insert(executable);
     }
 
+    let scratch_dir = installations.scratch();
     let mut tasks = FuturesUnordered::new();
     for installation in &matching_installations {
         tasks.push(async {
             (
                 installation.key(),
-                fs_err::tokio::remove_dir_all(installation.path()).await,
+                remove_installation_dir(installation.path(), &scratch_dir).await,
             )
         });
     }
@@ -3,13 +3,13 @@ use std::fmt::Write;
 use std::ops::Deref;
 use std::sync::LazyLock;
 use std::sync::{Arc, Mutex};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
 use owo_colors::OwoColorize;
 use rustc_hash::FxHashMap;
 
-use crate::commands::human_readable_bytes;
+use crate::commands::{elapsed, human_readable_bytes};
 use crate::printer::Printer;
 use uv_cache::Removal;
 use uv_distribution_filename::DistFilename;
@@ -27,6 +27,9 @@ use uv_static::EnvVars;
 static HAS_UV_TEST_NO_CLI_PROGRESS: LazyLock<bool> =
     LazyLock::new(|| env::var(EnvVars::UV_TEST_NO_CLI_PROGRESS).is_ok());
 
+/// The minimum interval between updates to the output line of a build.
+const BUILD_OUTPUT_INTERVAL: Duration = Duration::from_millis(100);
+
 #[derive(Debug)]
 struct ProgressReporter {
     printer: Printer,
@@ -53,8 +56,18 @@ enum ProgressBarKind {
         /// The download size in bytes, if known.
         size: Option<u64>,
     },
-    /// A progress spinner for a task, such as a build.
+    /// A progress spinner for a task, such as a checkout.
     Spinner { progress: ProgressBar },
+    /// A progress spinner for a source distribution build.
+    Build {
+        progress: ProgressBar,
+        /// The name of the distribution being built.
+        name: String,
+        /// A line beneath the build showing the latest output of the build backend, if any.
+        output: Option<ProgressBar>,
+        /// The time at which the output line was last updated.
+        updated: Instant,
+    },
 }
 
 impl Deref for ProgressBarKind {
@@ -64,6 +77,7 @@ impl Deref for ProgressBarKind {
         match self {
             Self::Numeric { progress, .. } => progress,
             Self::Spinner { progress } => progress,
+            Self::Build { progress, .. } => progress,
         }
     }
 }
@@ -170,7 +184,7 @@ impl ProgressReporter {
             ProgressBar::with_draw_target(None, self.printer.target()),
         );
 
-        progress.set_style(ProgressStyle::with_template("{wide_msg}").unwrap());
+        progress.set_style(ProgressStyle::with_template("{msg} {elapsed:.dim}").unwrap());
         let message = format!(
             "   {} {}",
             "Building".bold().cyan(),
@@ -180,12 +194,98 @@ impl ProgressReporter {
             let _ = writeln!(self.printer.stderr(), "{message}");
         }
         progress.set_message(message);
+        progress.enable_steady_tick(Duration::from_secs(1));
 
         state.headers += 1;
-        state.bars.insert(id, ProgressBarKind::Spinner { progress });
+        state.bars.insert(
+            id,
+            ProgressBarKind::Build {
+                progress,
+                name: source.to_color_string(),
+                output: None,
+                updated: Instant::now(),
+            },
+        );
         id
     }
 
+    fn on_build_output(&self, id: usize, line: &str) {
+        let ProgressMode::Multi {
+            multi_progress,
+            state,
+        } = &self.mode
+        else {
+            return;
+        };
+
+        if multi_progress.is_hidden() {
+            return;
+        }
+
+        let line = line.trim();
+        if line.is_empty() {
+            return;
+        }
+
+        let mut state = state.lock().unwrap();
+        let Some(ProgressBarKind::Build {
+            progress,
+            output,
+            updated,
+            ..
+        }) = state.bars.get_mut(&id)
+        else {
+            return;
+        };
+
+        // Avoid redrawing for every line of a chatty build backend.
+        if output.is_some() && updated.elapsed() < BUILD_OUTPUT_INTERVAL {
+            return;
+        }
+        *updated = Instant::now();
+
+        let added = output.is_none();
+        let output = output.get_or_insert_with(|| {
+            let output = multi_progress.insert_after(
+                progress,
+                ProgressBar::with_draw_target(None, self.printer.target()),
+            );
+            output.set_style(ProgressStyle::with_template("            {wide_msg:.dim}").unwrap());
+            output
+        });
+        output.set_message(line.to_string());
+
+        if added {
+            state.headers += 1;
+        }
+    }
+
+    fn on_build_progress(&self, id: usize, duration: Duration) {
+        let ProgressMode::Multi {
+            multi_progress,
+            state,
+        } = &self.mode
+        else {
+            return;
+        };
+
+        // With visible progress bars, the elapsed time is rendered alongside the build.
+        if !multi_progress.is_hidden() || *HAS_UV_TEST_NO_CLI_PROGRESS {
+            return;
+        }
+
+        let state = state.lock().unwrap();
+        let Some(ProgressBarKind::Build { name, .. }) = state.bars.get(&id) else {
+            return;
+        };
+        let _ = writeln!(
+            self.printer.stderr(),
+            "   {} {name} ({} elapsed)",
+            "Still building".bold().cyan(),
+            elapsed(duration)
+        );
+    }
+
     fn on_build_complete(&self, source: &BuildableSource, id: usize) {
         let ProgressMode::Multi {
             state,
@@ -201,6 +301,17 @@ impl ProgressReporter {
             state.bars.remove(&id).unwrap()
         };
 
+        if let ProgressBarKind::Build {
+            output: Some(output),
+            ..
+        } = &progress
+        {
+            output.finish_and_clear();
+            multi_progress.remove(output);
+            state.lock().unwrap().headers -= 1;
+        }
+        progress.set_style(ProgressStyle::with_template("{wide_msg}").unwrap());
+
         let message = format!(
             "      {} {}",
             "Built".bold().green(),
@@ -488,6 +599,14 @@ impl uv_installer::PrepareReporter for PrepareReporter {
         self.reporter.on_build_complete(source, id);
     }
 
+    fn on_build_output(&self, id: usize, line: &str) {
+        self.reporter.on_build_output(id, line);
+    }
+
+    fn on_build_progress(&self, id: usize, elapsed: Duration) {
+        self.reporter.on_build_progress(id, elapsed);
+    }
+
     fn on_download_start(&self, name: &PackageName, size: Option<u64>) -> usize {
         self.reporter.on_download_start(name.to_string(), size)
     }
@@ -564,6 +683,14 @@ impl uv_resolver::ResolverReporter for ResolverReporter {
         self.reporter.on_build_complete(source, id);
     }
 
+    fn on_build_output(&self, id: usize, line: &str) {
+        self.reporter.on_build_output(id, line);
+    }
+
+    fn on_build_progress(&self, id: usize, elapsed: Duration) {
+        self.reporter.on_build_progress(id, elapsed);
+    }
+
     fn on_checkout_start(&self, url: &DisplaySafeUrl, rev: &str) -> usize {
         self.reporter.on_checkout_start(url, rev)
     }
@@ -594,6 +721,14 @@ impl uv_distribution::Reporter for ResolverReporter {
         self.reporter.on_build_complete(source, id);
     }
 
+    fn on_build_output(&self, id: usize, line: &str) {
+        self.reporter.on_build_output(id, line);
+    }
+
+    fn on_build_progress(&self, id: usize, elapsed: Duration) {
+        self.reporter.on_build_progress(id, elapsed);
+    }
+
     fn on_download_start(&self, name: &PackageName, size: Option<u64>) -> usize {
         self.reporter.on_download_start(name.to_string(), size)
     }
@@ -32,13 +32,15 @@ use uv_fs::{CWD, Simplified};
 use uv_git::GitResolver;
 use uv_installer::SitePackages;
 use uv_normalize::{DefaultExtras, GroupName, PackageName};
-use uv_pep440::{Version, VersionSpecifier, VersionSpecifiers};
+use uv_pep440::{LowerBound, UpperBound, Version, VersionSpecifier, VersionSpecifiers};
+use uv_platform_tags::{Arch, Os};
 use uv_preview::Preview;
 use uv_pypi_types::Conflicts;
 use uv_python::{
     ConfigDiscovery, EnvironmentPreference, Interpreter, PythonDownloads, PythonEnvironment,
     PythonInstallation, PythonPreference, PythonRequest, PythonVariant, PythonVersionFile,
-    VersionFileDiscoveryOptions, VersionRequest,
+    VersionFileDiscoveryOptions, VersionRequest, WindowsArm64Fallback,
+    find_all_python_installations,
 };
 use uv_requirements::RequirementsSpecification;
 use uv_resolver::{
@@ -48,7 +50,7 @@ use uv_settings::{PythonInstallMirrors, ToolOptions};
 use uv_shell::Shell;
 use uv_tool::{InstalledTools, Tool, ToolEntrypoint, entrypoint_paths};
 use uv_types::{BuildIsolation, HashStrategy, SourceTreeEditablePolicy};
-use uv_warnings::warn_user_once;
+use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::WorkspaceCache;
 
 use crate::commands::pip;
@@ -129,18 +131,29 @@ pub(super) fn matching_packages(name: &str, site_packages: &SitePackages) -> Vec
                 .and_then(|entrypoints| {
                     entrypoints
                         .iter()
-                        .any(|entrypoint| {
-                            entrypoint
-                                .0
-                                .strip_suffix(std::env::consts::EXE_SUFFIX)
-                                .is_some_and(|stripped| stripped == name)
-                        })
+                        .any(|(filename, _)| executable_name(filename) == name)
                         .then(|| package.clone())
                 })
         })
         .collect()
 }
 
+/// Return the name with which an executable in the scripts directory is invoked.
+///
+/// On Windows, executables are invoked without their extension, e.g., `foo` for `foo.exe`, or for
+/// a `foo.cmd` script installed from a wheel's `.data` directory.
+pub(super) fn executable_name(filename: &str) -> &str {
+    if cfg!(windows)
+        && let Some((stem, extension)) = filename.rsplit_once('.')
+        && ["exe", "cmd", "bat", "ps1"]
+            .iter()
+            .any(|candidate| extension.eq_ignore_ascii_case(candidate))
+    {
+        return stem;
+    }
+    filename
+}
+
 /// Remove any entrypoints attached to the [`Tool`].
 pub(crate) fn remove_entrypoints(tool: &Tool) {
     remove_entrypoint_paths(
@@ -415,6 +428,8 @@ impl ToolLock {
             keyring_provider,
             resolution,
             prerelease,
+            prerelease_packages,
+            require_metadata,
             fork_strategy,
             dependency_metadata,
             config_setting,
@@ -422,6 +437,7 @@ impl ToolLock {
             build_isolation,
             extra_build_dependencies,
             extra_build_variables,
+            build_env_passthrough,
             exclude_newer,
             link_mode,
             upgrade,
@@ -458,6 +474,8 @@ impl ToolLock {
         let options = OptionsBuilder::new()
             .resolution_mode(*resolution)
             .prerelease_mode(*prerelease)
+            .prerelease_packages(prerelease_packages.clone())
+            .require_metadata(*require_metadata)
             .fork_strategy(*fork_strategy)
             .exclude_newer(exclude_newer.clone())
             .index_strategy(*index_strategy)
@@ -503,7 +521,8 @@ impl ToolLock {
             workspace_cache.clone(),
             concurrency.clone(),
             preview,
-        );
+        )
+        .with_build_env_passthrough(build_env_passthrough.clone());
         let database = DistributionDatabase::new(
             &client,
             &build_dispatch,
@@ -643,7 +662,9 @@ pub(crate) async fn refine_interpreter(
     install_mirrors: &PythonInstallMirrors,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
+    windows_arm64_fallback: WindowsArm64Fallback,
     cache: &Cache,
+    printer: Printer,
 ) -> anyhow::Result<Option<Interpreter>, ProjectError> {
     let pip::operations::Error::Resolve(uv_resolver::ResolveError::NoSolution(no_solution_err)) =
         err
@@ -651,8 +672,52 @@ pub(crate) async fn refine_interpreter(
         return Ok(None);
     };
 
+    // If the resolution failed due to missing `win_arm64` wheels, try an x64 interpreter, which
+    // can install the `win_amd64` wheels instead.
+    if let Some(packages) = no_solution_err.windows_arm64_packages() {
+        if let Some(interpreter) = windows_arm64_interpreter(
+            interpreter,
+            python_request,
+            packages,
+            windows_arm64_fallback,
+            python_preference,
+            cache,
+            printer,
+        )? {
+            return Ok(Some(interpreter));
+        }
+    }
+
     // Infer the `requires-python` constraint from the error.
     let requires_python = no_solution_err.find_requires_python();
+    let requires_python_upper = no_solution_err.find_requires_python_upper_bound();
+
+    // If the existing interpreter is too new, fall back to an older interpreter.
+    //
+    // For example, if we discovered a Python 3.13 interpreter on the user's machine, but the tool
+    // requires Python 3.12 or earlier, we can try to use an installed Python 3.12 interpreter.
+    //
+    // An explicit `--python` request is authoritative, so we never replace it.
+    if requires_python.contains(interpreter.python_version())
+        && !requires_python_upper.contains(interpreter.python_version())
+    {
+        if python_request.is_some() {
+            return Ok(None);
+        }
+        return downgrade_interpreter(
+            interpreter,
+            &requires_python,
+            &requires_python_upper,
+            client_builder,
+            reporter,
+            install_mirrors,
+            python_preference,
+            python_downloads,
+            cache,
+            printer,
+        )
+        .await;
+    }
 
     // If the existing interpreter already satisfies the `requires-python` constraint, we don't need
     // to refine it. We'd expect to fail again anyway.
@@ -722,6 +787,163 @@ pub(crate) async fn refine_interpreter(
     Ok(Some(interpreter))
 }
 
+/// Select an installed x64 interpreter in lieu of a native Windows ARM64 interpreter, for packages
+/// that lack `win_arm64` wheels but provide `win_amd64` wheels, which can run under emulation.
+///
+/// Interpreters with the same Python version are preferred. Per the [`WindowsArm64Fallback`], the
+/// x64 interpreter is either used, or merely suggested in a warning.
+fn windows_arm64_interpreter(
+    interpreter: &Interpreter,
+    python_request: Option<&PythonRequest>,
+    packages: &BTreeSet<PackageName>,
+    windows_arm64_fallback: WindowsArm64Fallback,
+    python_preference: PythonPreference,
+    cache: &Cache,
+    printer: Printer,
+) -> anyhow::Result<Option<Interpreter>, ProjectError> {
+    if windows_arm64_fallback == WindowsArm64Fallback::None {
+        return Ok(None);
+    }
+
+    // Only fall back from a native ARM64 interpreter on Windows.
+    let platform = interpreter.platform();
+    if !matches!(platform.os(), Os::Windows) || platform.arch() != Arch::Aarch64 {
+        return Ok(None);
+    }
+
+    // If the user passed a `--python` request, the x64 interpreter must satisfy it too.
+    let satisfies_request = |interpreter: &Interpreter| {
+        python_request.is_none_or(|python_request| python_request.satisfied(interpreter, cache))
+    };
+
+    let version = (interpreter.python_major(), interpreter.python_minor());
+    let Some(x64) = find_all_python_installations(
+        &PythonRequest::Any,
+        EnvironmentPreference::OnlySystem,
+        python_preference,
+        cache,
+    )
+    .map_err(uv_python::Error::from)?
+    .into_iter()
+    .map(PythonInstallation::into_interpreter)
+    .filter(|candidate| candidate.platform().arch() == Arch::X86_64)
+    .filter(|candidate| satisfies_request(candidate))
+    .max_by_key(|candidate| {
+        (
+            (candidate.python_major(), candidate.python_minor()) == version,
+            candidate.python_version().clone(),
+        )
+    }) else {
+        debug!("No x64 interpreter is installed to fall back to on Windows ARM64");
+        return Ok(None);
+    };
+
+    let packages = packages
+        .iter()
+        .map(|package| format!("`{}`", package.cyan()))
+        .join(", ");
+
+    match windows_arm64_fallback {
+        WindowsArm64Fallback::None => Ok(None),
+        WindowsArm64Fallback::Warn => {
+            warn_user!(
+                "No `win_arm64` wheels are available for {packages}, but an x64 interpreter that can install them under emulation was found at `{}` (set `{}` to use it)",
+                x64.sys_executable().user_display(),
+                "windows-arm64-fallback = \"prefer-native\"".green(),
+            );
+            Ok(None)
+        }
+        WindowsArm64Fallback::PreferNative => {
+            writeln!(
+                printer.stderr(),
+                "Using x64 Python {} under emulation, since no `win_arm64` wheels are available for: {packages}",
+                x64.python_version().cyan(),
+            )?;
+            Ok(Some(x64))
+        }
+    }
+}
+
+/// Select the newest interpreter that satisfies the inferred `requires-python` bounds, for cases
+/// in which the discovered interpreter is newer than the tool supports.
+///
+/// Installed interpreters are preferred (respecting the [`PythonPreference`]); a compatible
+/// interpreter is only downloaded if none are available.
+async fn downgrade_interpreter(
+    interpreter: &Interpreter,
+    lower_bound: &LowerBound,
+    upper_bound: &UpperBound,
+    client_builder: &BaseClientBuilder<'_>,
+    reporter: &PythonDownloadReporter,
+    install_mirrors: &PythonInstallMirrors,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    cache: &Cache,
+    printer: Printer,
+) -> anyhow::Result<Option<Interpreter>, ProjectError> {
+    let Some(upper_specifier) = upper_bound.specifier() else {
+        return Ok(None);
+    };
+    let specifiers = lower_bound
+        .specifier()
+        .into_iter()
+        .chain(std::iter::once(upper_specifier))
+        .collect::<VersionSpecifiers>();
+    let requires_python_request = PythonRequest::Version(VersionRequest::from_specifiers(
+        specifiers.clone(),
+        PythonVariant::default(),
+    ));
+
+    debug!("Refining interpreter with: {requires_python_request}");
+
+    // Select the newest compatible interpreter, breaking ties by discovery order.
+    let installed = find_all_python_installations(
+        &requires_python_request,
+        EnvironmentPreference::OnlySystem,
+        python_preference,
+        cache,
+    )
+    .map_err(uv_python::Error::from)?
+    .into_iter()
+    .map(PythonInstallation::into_interpreter)
+    .reduce(|best, candidate| {
+        if candidate.python_version() > best.python_version() {
+            candidate
+        } else {
+            best
+        }
+    });
+
+    let refined = if let Some(installed) = installed {
+        installed
+    } else {
+        PythonInstallation::find_or_download(
+            Some(&requires_python_request),
+            EnvironmentPreference::OnlySystem,
+            python_preference,
+            python_downloads,
+            client_builder,
+            cache,
+            Some(reporter),
+            install_mirrors.python_install_mirror.as_deref(),
+            install_mirrors.pypy_install_mirror.as_deref(),
+            install_mirrors.python_downloads_json_url.as_deref(),
+        )
+        .await?
+        .into_interpreter()
+    };
+
+    writeln!(
+        printer.stderr(),
+        "Using Python {} instead of Python {}, which is incompatible with the tool's Python requirement: `{}`",
+        refined.python_version().cyan(),
+        interpreter.python_version().cyan(),
+        specifiers.cyan(),
+    )?;
+
+    Ok(Some(refined))
+}
+
 /// Finalizes a tool installation, after creation of an environment.
 ///
 /// Installs tool executables for a given package, handling any conflicts.
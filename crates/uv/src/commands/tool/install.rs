@@ -2,6 +2,7 @@ use std::fmt::Write;
 use std::str::FromStr;
 
 use anyhow::{Result, bail};
+use itertools::Itertools;
 use owo_colors::OwoColorize;
 use tracing::{debug, trace};
 
@@ -24,10 +25,10 @@ use uv_pep508::MarkerTree;
 use uv_preview::{Preview, PreviewFeature};
 use uv_python::{
     ConfigDiscovery, EnvironmentPreference, Interpreter, PythonDownloads, PythonEnvironment,
-    PythonInstallation, PythonPreference, PythonRequest,
+    PythonInstallation, PythonPreference, PythonRequest, WindowsArm64Fallback,
 };
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
-use uv_settings::{PythonInstallMirrors, ResolverInstallerOptions, ToolOptions};
+use uv_settings::{Combine, PythonInstallMirrors, ResolverInstallerOptions, ToolOptions};
 use uv_tool::{InstalledTools, Tool};
 use uv_types::{HashStrategy, SourceTreeEditablePolicy};
 use uv_warnings::{warn_user, warn_user_once};
@@ -54,6 +55,7 @@ use crate::printer::Printer;
 use crate::settings::{ResolverInstallerSettings, ResolverSettings};
 
 /// Install a tool.
+#[expect(clippy::fn_params_excessive_bools)]
 pub(crate) async fn install(
     package: String,
     editable: bool,
@@ -69,11 +71,13 @@ pub(crate) async fn install(
     python_platform: Option<TargetTriple>,
     install_mirrors: PythonInstallMirrors,
     force: bool,
+    keep_options: bool,
     options: ResolverInstallerOptions,
     settings: ResolverInstallerSettings,
     client_builder: BaseClientBuilder<'_>,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
+    windows_arm64_fallback: WindowsArm64Fallback,
     installer_metadata: bool,
     concurrency: Concurrency,
     config_discovery: ConfigDiscovery,
@@ -84,6 +88,65 @@ pub(crate) async fn install(
     preview: Preview,
 ) -> Result<ExitStatus> {
     let tool_locks = preview.is_enabled(PreviewFeature::ToolInstallLocks);
+
+    // If the user passed `--keep-options`, reinstall the tool from its existing receipt.
+    let kept_tool_receipt = if keep_options {
+        let Ok(name) = PackageName::from_str(&package) else {
+            bail!(
+                "`--keep-options` requires the name of an installed tool, but got: `{}`",
+                package.cyan()
+            );
+        };
+        match InstalledTools::from_settings()?.get_tool_receipt(&name) {
+            Ok(Some(receipt)) => Some(receipt),
+            Ok(None) => {
+                bail!(
+                    "`{}` is not installed; `--keep-options` requires an existing tool receipt",
+                    name.cyan()
+                );
+            }
+            Err(_) => {
+                bail!(
+                    "`{}` is missing a valid receipt; run `{}` to reinstall",
+                    name.cyan(),
+                    format!("uv tool install --force {name}").green()
+                );
+            }
+        }
+    } else {
+        None
+    };
+
+    // The Python request and options recorded in the receipt take precedence over those from the
+    // command line and configuration files.
+    let (python, options, settings) = if let Some(receipt) = &kept_tool_receipt {
+        let python = python.or_else(|| {
+            receipt
+                .python()
+                .as_ref()
+                .map(|request| request.to_canonical_string().into_owned())
+        });
+        let options = ResolverInstallerOptions::from(receipt.options().clone()).combine(options);
+        let mut kept_settings = ResolverInstallerSettings::from(options.clone());
+        kept_settings.resolver.torch_backend = kept_settings
+            .resolver
+            .torch_backend
+            .or(settings.resolver.torch_backend);
+        (python, options, kept_settings)
+    } else {
+        (python, options, settings)
+    };
+    let kept_entrypoints = kept_tool_receipt.as_ref().map(|receipt| {
+        receipt
+            .entrypoints()
+            .iter()
+            .filter_map(|entrypoint| PackageName::from_str(entrypoint.from.as_ref()?).ok())
+            .filter(|name| name.as_str() != package)
+            .unique()
+            .collect::<Vec<_>>()
+    });
+    let entrypoints = kept_entrypoints.as_deref().unwrap_or(entrypoints);
+
     if settings.resolver.torch_backend.is_some() {
         warn_user_once!(
             "The `--torch-backend` option is experimental and may change without warning."
@@ -357,16 +420,27 @@ pub(crate) async fn install(
     };
 
     // Read the `--with` requirements.
-    let spec = RequirementsSpecification::from_sources(
+    let mut spec = RequirementsSpecification::from_sources(
         with,
         constraints,
         overrides,
         excludes,
         None,
         &client_builder,
+        &cache,
     )
     .await?;
 
+    // Skip any unnamed `--with` requirements that don't apply to the interpreter. Named
+    // requirements are recorded in the receipt (with their markers), such that they're
+    // re-evaluated on upgrade; unnamed requirements can't be recorded without a name.
+    for requirement in spec.remove_inapplicable_unnamed(interpreter.markers()) {
+        warn_user!(
+            "Skipping `--with` requirement `{}`, as its markers don't apply to the current interpreter",
+            requirement.requirement
+        );
+    }
+
     // Resolve the `--from` and `--with` requirements.
     let requirements = {
         let mut requirements = Vec::with_capacity(1 + with.len());
@@ -448,12 +522,37 @@ pub(crate) async fn install(
 
     // Resolve the build constraints.
     let receipt_build_constraints =
-        operations::read_constraints(build_constraints, &client_builder)
+        operations::read_constraints(build_constraints, &client_builder, &cache)
             .await?
             .into_iter()
             .map(|constraint| constraint.requirement)
             .collect::<Vec<_>>();
 
+    // With `--keep-options`, reuse the requirements recorded in the receipt.
+    let (
+        requirements,
+        receipt_constraints,
+        receipt_overrides,
+        receipt_excludes,
+        receipt_build_constraints,
+    ) = if let Some(receipt) = kept_tool_receipt {
+        (
+            receipt.requirements().to_vec(),
+            receipt.constraints().to_vec(),
+            receipt.overrides().to_vec(),
+            receipt.excludes().to_vec(),
+            receipt.build_constraints().to_vec(),
+        )
+    } else {
+        (
+            requirements,
+            receipt_constraints,
+            receipt_overrides,
+            receipt_excludes,
+            receipt_build_constraints,
+        )
+    };
+
     // Convert to tool options.
     let options = ToolOptions::from(options);
     let lock_manifest = ToolLock::manifest(
@@ -949,7 +1048,9 @@ pub(crate) async fn install(
                             &install_mirrors,
                             python_preference,
                             python_downloads,
+                            windows_arm64_fallback,
                             &cache,
+                            printer,
                         )
                         .await
                         .ok()
@@ -11,7 +11,7 @@ use uv_cache_info::Timestamp;
 use uv_client::{BaseClientBuilder, RegistryClientBuilder};
 use uv_configuration::Concurrency;
 use uv_distribution_filename::DistFilename;
-use uv_distribution_types::{IndexCapabilities, RequiresPython};
+use uv_distribution_types::{IndexCapabilities, RequirementSource, RequiresPython};
 use uv_fs::Simplified;
 use uv_normalize::PackageName;
 use uv_python::LenientImplementationName;
@@ -248,12 +248,47 @@ pub(crate) async fn list(
             .take_if(|requirements| requirements.peek().is_some())
             .map(|requirements| {
                 let requirements = requirements
-                    .map(|req| format!("{}{}", req.name, req.source))
+                    .map(|req| match req.marker.contents() {
+                        Some(marker) => format!("{}{} ; {marker}", req.name, req.source),
+                        None => format!("{}{}", req.name, req.source),
+                    })
                     .join(", ");
                 format!(" [with: {requirements}]")
             })
             .unwrap_or_default();
 
+        // In verbose mode, show the index and source recorded for the tool, such that any drift
+        // from the current configuration is visible.
+        let recorded_source = if printer == Printer::Verbose {
+            let settings = ResolverInstallerSettings::from(ResolverInstallerOptions::from(
+                tool.options().clone(),
+            ));
+            let index_locations = &settings.resolver.index_locations;
+            let indexes = index_locations
+                .indexes()
+                .chain(index_locations.flat_indexes())
+                .map(|index| index.url().to_string())
+                .join(", ");
+            let sources = tool
+                .requirements()
+                .iter()
+                .filter(|req| req.name == name)
+                .filter(|req| !matches!(req.source, RequirementSource::Registry { .. }))
+                .map(|req| req.source.to_string().trim().to_string())
+                .join(", ");
+
+            let mut recorded_source = String::new();
+            if !indexes.is_empty() {
+                write!(recorded_source, " [index: {indexes}]")?;
+            }
+            if !sources.is_empty() {
+                write!(recorded_source, " [source: {sources}]")?;
+            }
+            recorded_source
+        } else {
+            String::new()
+        };
+
         let latest_version = if outdated {
             latest
                 .get(&name)
@@ -269,7 +304,7 @@ pub(crate) async fn list(
                 printer.stdout(),
                 "{} ({})",
                 format!(
-                    "{name} v{version}{version_specifier}{extra_requirements}{with_requirements}{python_version}{latest_version}"
+                    "{name} v{version}{version_specifier}{extra_requirements}{with_requirements}{python_version}{recorded_source}{latest_version}"
                 )
                 .bold(),
                 installed_tools.tool_dir(&name).simplified_display().cyan(),
@@ -279,7 +314,7 @@ pub(crate) async fn list(
                 printer.stdout(),
                 "{}",
                 format!(
-                    "{name} v{version}{version_specifier}{extra_requirements}{with_requirements}{python_version}{latest_version}"
+                    "{name} v{version}{version_specifier}{extra_requirements}{with_requirements}{python_version}{recorded_source}{latest_version}"
                 )
                 .bold()
             )?;
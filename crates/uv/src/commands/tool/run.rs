@@ -1,5 +1,6 @@
 use std::fmt::Display;
 use std::fmt::Write;
+use std::io::{BufRead, BufReader, Read};
 use std::path::Path;
 use std::path::PathBuf;
 use std::str::FromStr;
@@ -17,11 +18,13 @@ use uv_cli::ExternalCommand;
 use uv_client::{BaseClientBuilder, RegistryClientBuilder};
 use uv_configuration::{Concurrency, Constraints, GitLfsSetting, TargetTriple};
 use uv_distribution::LoweredExtraBuildDependencies;
+use uv_distribution_filename::WheelFilename;
 use uv_distribution_types::InstalledDist;
 use uv_distribution_types::{
     IndexCapabilities, IndexUrl, Name, NameRequirementSpecification, Requirement,
     RequirementSource, UnresolvedRequirement, UnresolvedRequirementSpecification,
 };
+use uv_fs::Simplified;
 use uv_installer::{InstallationStrategy, SatisfiesResult, SitePackages};
 use uv_normalize::PackageName;
 use uv_pep440::{VersionSpecifier, VersionSpecifiers};
@@ -29,7 +32,7 @@ use uv_pep508::MarkerTree;
 use uv_preview::Preview;
 use uv_python::{
     ConfigDiscovery, EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonInstallation,
-    PythonPreference, PythonRequest,
+    PythonPreference, PythonRequest, WindowsArm64Fallback,
 };
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 use uv_settings::{PythonInstallMirrors, ResolverInstallerOptions, ToolOptions};
@@ -52,7 +55,9 @@ use crate::commands::project::{
     EnvironmentSpecification, PlatformState, ProjectError, resolve_names,
 };
 use crate::commands::reporters::PythonDownloadReporter;
-use crate::commands::tool::common::{ToolPython, matching_packages, refine_interpreter};
+use crate::commands::tool::common::{
+    ToolPython, executable_name, matching_packages, refine_interpreter,
+};
 use crate::commands::tool::{Target, ToolRequest};
 use crate::commands::{
     UvError, diagnostics, project::environment::CachedEnvironment, read_env_files,
@@ -98,7 +103,9 @@ fn find_verbose_flag(args: &[std::ffi::OsString]) -> Option<&str> {
 pub(crate) async fn run(
     command: Option<ExternalCommand>,
     from: Option<String>,
+    entrypoint: Option<String>,
     with: &[RequirementsSource],
+    with_executables: &[PathBuf],
     constraints: &[RequirementsSource],
     overrides: &[RequirementsSource],
     build_constraints: &[RequirementsSource],
@@ -114,6 +121,7 @@ pub(crate) async fn run(
     isolated: bool,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
+    windows_arm64_fallback: WindowsArm64Fallback,
     installer_metadata: bool,
     concurrency: Concurrency,
     cache: Cache,
@@ -129,6 +137,12 @@ pub(crate) async fn run(
             .is_some_and(|ext| ext.eq_ignore_ascii_case("py") || ext.eq_ignore_ascii_case("pyw"))
     }
 
+    /// Whether or not a path has the given file extension.
+    fn has_ext(path: &Path, extension: &str) -> bool {
+        path.extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case(extension))
+    }
+
     if settings.resolver.torch_backend.is_some() {
         warn_user_once!(
             "The `--torch-backend` option is experimental and may change without warning."
@@ -159,6 +173,75 @@ pub(crate) async fn run(
         ));
     };
 
+    // If the user passed a local zipapp, e.g., `uvx ./tool.pyz`, run it with a Python interpreter.
+    let zipapp = (from.is_none() && has_ext(Path::new(target), "pyz")).then(|| Path::new(target));
+    if let Some(zipapp) = zipapp
+        && !zipapp.is_file()
+    {
+        bail!("Zipapp not found at: `{}`", zipapp.user_display());
+    }
+
+    for executable in with_executables {
+        if !executable.is_file() {
+            bail!("Executable not found at: `{}`", executable.user_display());
+        }
+    }
+
+    // Respect the interpreter requested by the zipapp's shebang, unless `--python` was provided.
+    let python = python.or_else(|| zipapp.and_then(zipapp_python_request));
+
+    // Without additional requirements, there's no need to create an environment.
+    if let Some(zipapp) = zipapp
+        && with.is_empty()
+        && with_executables.is_empty()
+    {
+        return run_zipapp(
+            zipapp,
+            args,
+            python.as_deref(),
+            install_mirrors,
+            &client_builder,
+            python_preference,
+            python_downloads,
+            env_file_environment,
+            &cache,
+            printer,
+        )
+        .await;
+    }
+
+    // If the user passed a local wheel, e.g., `uvx ./tool-1.0-py3-none-any.whl`, install it and
+    // run one of its entry points.
+    let wheel = if from.is_none() && has_ext(Path::new(target), "whl") {
+        let path = Path::new(target);
+        let filename = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .map(WheelFilename::from_str)
+            .transpose()?
+            .ok_or_else(|| anyhow::anyhow!("Invalid wheel path: `{}`", path.user_display()))?;
+        if !path.is_file() {
+            bail!("Wheel not found at: `{}`", path.user_display());
+        }
+        Some((path, filename))
+    } else {
+        None
+    };
+
+    // Run the wheel via `--from`, using the requested entry point (or the package name) as a
+    // placeholder command until the environment's entry points are known. Similarly, with
+    // `--entrypoint`, the command is the package that provides the entry point.
+    let from = match (&wheel, entrypoint.as_deref()) {
+        (Some((path, _)), _) => Some(path.to_string_lossy().into_owned()),
+        (None, Some(_)) => Some(target.to_string()),
+        (None, None) => from,
+    };
+    let target = match (&wheel, entrypoint.as_deref()) {
+        (_, Some(entrypoint)) => entrypoint,
+        (Some((_, filename)), None) => filename.name.as_str(),
+        (None, None) => target,
+    };
+
     if let Some(ref from) = from {
         if has_python_script_ext(Path::new(from)) {
             let package_name = PackageName::from_str(from)?;
@@ -195,6 +278,18 @@ pub(crate) async fn run(
     // If the user tries to invoke `uvx run ruff`, hint them towards `uvx ruff`, but only if
     // the `run` package is guaranteed to come from PyPI.
     let (mut target, mut args) = (target, args);
+
+    // With additional requirements, run the zipapp with the interpreter of an ephemeral
+    // environment, e.g., `uvx --with rich ./tool.pyz` runs `python ./tool.pyz`.
+    let zipapp_args;
+    if let Some(zipapp) = zipapp {
+        zipapp_args = std::iter::once(zipapp.as_os_str().to_owned())
+            .chain(args.iter().cloned())
+            .collect::<Vec<_>>();
+        target = "python";
+        args = &zipapp_args;
+    }
+
     if from.is_none()
         && invocation_source == ToolRunCommand::Uvx
         && target == "run"
@@ -256,6 +351,7 @@ pub(crate) async fn run(
         lfs,
         python_preference,
         python_downloads,
+        windows_arm64_fallback,
         installer_metadata,
         &concurrency,
         &cache,
@@ -308,14 +404,48 @@ pub(crate) async fn run(
         Err(err) => return Err(err.into()),
     };
 
-    // TODO(zanieb): Determine the executable command via the package entry points
-    let executable = from.executable();
     let site_packages = SitePackages::from_environment(&environment)?;
 
+    // Determine the executable command via the package entry points, which include scripts from
+    // the `.data` directory of the package's wheel.
+    let selected_executable;
+    let executable = match (&wheel, &from) {
+        (Some((path, _)), ToolRequirement::Package { requirement, .. }) => {
+            let entrypoints = get_entrypoints(&requirement.name, &site_packages)?;
+            let provider = format!("The wheel at `{}`", path.user_display());
+            selected_executable =
+                select_entrypoint(&provider, entrypoint.as_deref(), &entrypoints)?;
+            selected_executable.as_str()
+        }
+        (None, ToolRequirement::Package { requirement, .. }) if entrypoint.is_some() => {
+            let entrypoints = get_entrypoints(&requirement.name, &site_packages)?;
+            let provider = format!("Package `{}`", requirement.name);
+            selected_executable =
+                select_entrypoint(&provider, entrypoint.as_deref(), &entrypoints)?;
+            selected_executable.as_str()
+        }
+        (None, ToolRequirement::Package { requirement, .. }) if !explicit_from => {
+            // If the package doesn't provide an executable with exactly the requested name, look
+            // for an executable that matches the package name after normalization, e.g., a
+            // `foo_bar` script for `uvx foo-bar`.
+            let executable = from.executable();
+            match find_normalized_entrypoint(executable, &requirement.name, &site_packages) {
+                Some(name) => {
+                    debug!("Using executable `{name}` for `{executable}`");
+                    selected_executable = name;
+                    selected_executable.as_str()
+                }
+                None => executable,
+            }
+        }
+        _ => from.executable(),
+    };
+
     // Check if the provided command is not part of the executables for the `from` package,
     // and if it's provided by another package in the environment.
     let provider_hints = match &from {
         ToolRequirement::Python { .. } => None,
+        ToolRequirement::Package { .. } if wheel.is_some() || entrypoint.is_some() => None,
         ToolRequirement::Package { requirement, .. } => Some(ExecutableProviderHints::new(
             executable,
             requirement,
@@ -353,14 +483,24 @@ pub(crate) async fn run(
     process.args(args);
     process.envs(env_file_environment);
 
+    // Link any requested host executables into a directory that follows the scripts directory on
+    // the `PATH`. The directory must outlive the command.
+    let host_executables = link_host_executables(with_executables, environment.scripts())?;
+
     // Construct the `PATH` environment variable.
     let new_path = std::env::join_paths(
-        std::iter::once(environment.scripts().to_path_buf()).chain(
-            std::env::var_os(EnvVars::PATH)
-                .as_ref()
-                .iter()
-                .flat_map(std::env::split_paths),
-        ),
+        std::iter::once(environment.scripts().to_path_buf())
+            .chain(
+                host_executables
+                    .as_ref()
+                    .map(|directory| directory.path().to_path_buf()),
+            )
+            .chain(
+                std::env::var_os(EnvVars::PATH)
+                    .as_ref()
+                    .iter()
+                    .flat_map(std::env::split_paths),
+            ),
     )
     .context("Failed to build new PATH variable")?;
     process.env(EnvVars::PATH, new_path);
@@ -395,6 +535,205 @@ pub(crate) async fn run(
     run_to_completion(handle).await
 }
 
+/// Link the given host executables into a temporary directory, for inclusion on the `PATH`.
+///
+/// On Unix, the executables are symlinked. On Windows, a trampoline that invokes the executable is
+/// written instead, such that the link is resolved irrespective of `PATHEXT`.
+fn link_host_executables(
+    executables: &[PathBuf],
+    scripts: &Path,
+) -> anyhow::Result<Option<tempfile::TempDir>> {
+    if executables.is_empty() {
+        return Ok(None);
+    }
+
+    let directory = tempfile::tempdir()?;
+    for executable in executables {
+        let executable = std::path::absolute(executable)?;
+        let name = if cfg!(windows) {
+            executable
+                .file_stem()
+                .map(|stem| format!("{}.exe", stem.to_string_lossy()))
+        } else {
+            executable
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+        }
+        .ok_or_else(|| {
+            anyhow::anyhow!("Invalid executable path: `{}`", executable.user_display())
+        })?;
+
+        if scripts.join(&name).exists() {
+            bail!(
+                "Executable `{}` (from `{}`) conflicts with an executable in the tool environment",
+                name.cyan(),
+                executable.user_display().cyan()
+            );
+        }
+
+        let link = directory.path().join(&name);
+        if link.exists() {
+            bail!(
+                "Multiple `{}` paths provide an executable named `{}`",
+                "--with-executable".green(),
+                name.cyan()
+            );
+        }
+
+        debug!(
+            "Linking host executable `{}` to: `{}`",
+            executable.user_display(),
+            link.user_display()
+        );
+        if cfg!(windows) {
+            let launcher = uv_trampoline_builder::windows_python_launcher(&executable, false)?;
+            fs_err::write(&link, launcher)?;
+        } else {
+            // Note this will never copy on Unix.
+            uv_fs::symlink_or_copy_file(&executable, &link)?;
+        }
+    }
+
+    Ok(Some(directory))
+}
+
+/// Select the entry point to run from a local wheel or a package.
+///
+/// Uses the requested entry point, if any; otherwise, the provider must declare exactly one.
+fn select_entrypoint(
+    provider: &str,
+    requested: Option<&str>,
+    entrypoints: &[(String, PathBuf)],
+) -> anyhow::Result<String> {
+    let names = entrypoints
+        .iter()
+        .map(|(name, _)| executable_name(name).to_string())
+        .sorted()
+        .dedup()
+        .collect::<Vec<_>>();
+
+    let available = || {
+        names
+            .iter()
+            .map(|name| format!("- {}", name.cyan()))
+            .join("\n")
+    };
+
+    match (requested, names.as_slice()) {
+        (Some(requested), _) if names.iter().any(|name| name == requested) => {
+            Ok(requested.to_string())
+        }
+        (Some(requested), []) => bail!(
+            "{provider} does not provide any entry points, so `{}` cannot be run",
+            requested.cyan()
+        ),
+        (Some(requested), _) => bail!(
+            "{provider} does not provide an entry point named `{}`. The following entry points are available:\n{}",
+            requested.cyan(),
+            available()
+        ),
+        (None, [name]) => Ok(name.clone()),
+        (None, []) => bail!("{provider} does not provide any entry points"),
+        (None, _) => bail!(
+            "{provider} provides multiple entry points; select one with `{}`:\n{}",
+            "--entrypoint".green(),
+            available()
+        ),
+    }
+}
+
+/// Find the executable provided by a package whose name matches the requested command after
+/// normalization, if the package doesn't provide an executable with exactly the requested name.
+fn find_normalized_entrypoint(
+    executable: &str,
+    package: &PackageName,
+    site_packages: &SitePackages,
+) -> Option<String> {
+    let entrypoints = get_entrypoints(package, site_packages).ok()?;
+    let names = entrypoints
+        .iter()
+        .map(|(name, _)| executable_name(name))
+        .collect::<Vec<_>>();
+    if names.contains(&executable) {
+        return None;
+    }
+    let requested = PackageName::from_str(executable).ok()?;
+    let mut matches = names
+        .into_iter()
+        .filter(|name| PackageName::from_str(name).is_ok_and(|name| name == requested))
+        .sorted()
+        .dedup();
+    match (matches.next(), matches.next()) {
+        (Some(name), None) => Some(name.to_string()),
+        _ => None,
+    }
+}
+
+/// Determine the Python interpreter requested by a zipapp's shebang, if any.
+///
+/// For example, `#!/usr/bin/env python3.12` requests `python3.12`.
+fn zipapp_python_request(zipapp: &Path) -> Option<String> {
+    // Only inspect the start of the file, since the remainder is a zip archive.
+    let mut reader = BufReader::new(fs_err::File::open(zipapp).ok()?.take(1024));
+    let mut line = String::new();
+    reader.read_line(&mut line).ok()?;
+    let shebang = line.strip_prefix("#!")?;
+    let program = shebang.split_whitespace().last()?;
+    let name = Path::new(program).file_name()?.to_str()?;
+    PythonRequest::try_from_tool_name(name)
+        .ok()
+        .flatten()
+        .map(|_| name.to_string())
+}
+
+/// Run a zipapp directly with a Python interpreter, without creating an environment.
+async fn run_zipapp(
+    zipapp: &Path,
+    args: &[std::ffi::OsString],
+    python: Option<&str>,
+    install_mirrors: PythonInstallMirrors,
+    client_builder: &BaseClientBuilder<'_>,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    env_file_environment: Vec<(String, String)>,
+    cache: &Cache,
+    printer: Printer,
+) -> anyhow::Result<ExitStatus> {
+    let reporter = PythonDownloadReporter::single(printer);
+    let python_request = python.map(PythonRequest::parse);
+    let interpreter = PythonInstallation::find_or_download(
+        python_request.as_ref(),
+        EnvironmentPreference::OnlySystem,
+        python_preference,
+        python_downloads,
+        client_builder,
+        cache,
+        Some(&reporter),
+        install_mirrors.python_install_mirror.as_deref(),
+        install_mirrors.pypy_install_mirror.as_deref(),
+        install_mirrors.python_downloads_json_url.as_deref(),
+    )
+    .await?
+    .into_interpreter();
+
+    let mut process = Command::new(interpreter.sys_executable());
+    process.arg(zipapp);
+    process.args(args);
+    process.envs(env_file_environment);
+
+    debug!(
+        "Running `{} {}`",
+        zipapp.user_display(),
+        args.iter().map(|arg| arg.to_string_lossy()).join(" ")
+    );
+
+    let handle = process
+        .spawn()
+        .with_context(|| format!("Failed to spawn: `{}`", zipapp.user_display()))?;
+
+    run_to_completion(handle).await
+}
+
 /// Return the entry points for the specified package.
 fn get_entrypoints(
     from: &PackageName,
@@ -685,6 +1024,7 @@ async fn get_or_create_environment(
     lfs: GitLfsSetting,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
+    windows_arm64_fallback: WindowsArm64Fallback,
     installer_metadata: bool,
     concurrency: &Concurrency,
     cache: &Cache,
@@ -938,15 +1278,25 @@ async fn get_or_create_environment(
     };
 
     // Read the `--with` requirements.
-    let spec = RequirementsSpecification::from_sources(
+    let mut spec = RequirementsSpecification::from_sources(
         with,
         constraints,
         overrides,
         &[],
         None,
         client_builder,
+        cache,
     )
     .await?;
+
+    // Skip any unnamed `--with` requirements that don't apply to the interpreter.
+    for requirement in spec.remove_inapplicable_unnamed(interpreter.markers()) {
+        debug!(
+            "Skipping `--with` requirement with inapplicable markers: {}",
+            requirement.requirement
+        );
+    }
+
     let exclusions = uv_configuration::Excludes::from_entries(spec.excludes.iter().cloned());
 
     // Resolve the `--from` and `--with` requirements.
@@ -1090,7 +1440,7 @@ async fn get_or_create_environment(
 
     // Read the `--build-constraints` requirements.
     let build_constraints = Constraints::from_requirements(
-        operations::read_constraints(build_constraints, client_builder)
+        operations::read_constraints(build_constraints, client_builder, cache)
             .await?
             .into_iter()
             .map(|constraint| constraint.requirement),
@@ -1145,7 +1495,9 @@ async fn get_or_create_environment(
                     &install_mirrors,
                     python_preference,
                     python_downloads,
+                    windows_arm64_fallback,
                     cache,
+                    printer,
                 )
                 .await
                 .ok()
@@ -319,12 +319,36 @@ async fn upgrade_tool(
     };
 
     // Resolve the appropriate settings, preferring: CLI > receipt > user.
-    let options = args.clone().combine(
-        ResolverInstallerOptions::from(existing_tool_receipt.options().clone())
-            .combine(filesystem.clone()),
-    );
+    //
+    // Index options provided on the command line replace (rather than extend) those recorded in
+    // the receipt, such that a tool can be migrated to a different index.
+    let recorded_options = ResolverInstallerOptions::from(existing_tool_receipt.options().clone());
+    let replaces_indexes = has_index_options(args);
+    let receipt_options = if replaces_indexes {
+        ResolverInstallerOptions {
+            index: None,
+            index_url: None,
+            extra_index_url: None,
+            no_index: None,
+            find_links: None,
+            ..recorded_options.clone()
+        }
+    } else {
+        recorded_options.clone()
+    };
+    let options = args
+        .clone()
+        .combine(receipt_options.combine(filesystem.clone()));
     let settings = ResolverInstallerSettings::from(options.clone());
 
+    // Determine whether the recorded index options changed, in which case the receipt must be
+    // updated regardless of the outcome of the upgrade.
+    let changed_indexes = replaces_indexes
+        && !same_index_options(
+            &recorded_options,
+            &ResolverInstallerOptions::from(ToolOptions::from(options.clone())),
+        );
+
     let build_constraint_requirements = existing_tool_receipt.build_constraints().to_vec();
     let build_constraints =
         Constraints::from_requirements(build_constraint_requirements.iter().cloned());
@@ -609,6 +633,21 @@ async fn upgrade_tool(
                 .clone()
                 .with_options(ToolOptions::from(options)),
         )?;
+    } else if changed_indexes {
+        installed_tools.add_tool_receipt(
+            name,
+            existing_tool_receipt
+                .clone()
+                .with_options(ToolOptions::from(options)),
+        )?;
+    }
+
+    if changed_indexes {
+        writeln!(
+            printer.stderr(),
+            "Updated the recorded index for `{}`",
+            name.cyan()
+        )?;
     }
 
     let constraint = match &outcome {
@@ -625,6 +664,25 @@ async fn upgrade_tool(
     })
 }
 
+/// Returns `true` if the given options include any index options (e.g., `--index` or
+/// `--find-links`).
+fn has_index_options(options: &ResolverInstallerOptions) -> bool {
+    options.index.is_some()
+        || options.index_url.is_some()
+        || options.extra_index_url.is_some()
+        || options.no_index.is_some()
+        || options.find_links.is_some()
+}
+
+/// Returns `true` if the given options include the same index options.
+fn same_index_options(a: &ResolverInstallerOptions, b: &ResolverInstallerOptions) -> bool {
+    a.index == b.index
+        && a.index_url == b.index_url
+        && a.extra_index_url == b.extra_index_url
+        && a.no_index == b.no_index
+        && a.find_links == b.find_links
+}
+
 fn pinned_requirement_version(tool: &Tool, name: &PackageName) -> Option<Version> {
     pinned_version_from(tool.requirements(), name)
         .or_else(|| pinned_version_from(tool.constraints(), name))
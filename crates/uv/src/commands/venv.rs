@@ -3,10 +3,10 @@ use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::vec;
 
-use anyhow::Result;
+use anyhow::{Result, anyhow, bail};
 use owo_colors::OwoColorize;
 use thiserror::Error;
-use tracing::warn;
+use tracing::{debug, warn};
 
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
@@ -19,31 +19,35 @@ use uv_distribution_types::{
     ConfigSettings, DependencyMetadata, ExtraBuildRequires, Index, IndexLocations,
     PackageConfigSettings, Requirement,
 };
-use uv_fs::Simplified;
+use uv_fs::{CWD, Simplified};
 use uv_install_wheel::LinkMode;
 use uv_normalize::DefaultGroups;
 use uv_preview::Preview;
 use uv_python::{
-    ConfigDiscovery, EnvironmentPreference, PythonDownloads, PythonInstallation, PythonPreference,
-    PythonRequest,
+    ConfigDiscovery, EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonInstallation,
+    PythonPreference, PythonRequest,
 };
 use uv_resolver::{ExcludeNewer, FlatIndex};
 use uv_settings::PythonInstallMirrors;
 use uv_shell::{Shell, shlex_posix, shlex_windows};
+use uv_static::EnvVars;
 use uv_types::{
     AnyErrorBuild, BuildContext, BuildIsolation, BuildStack, HashStrategy, SourceTreeEditablePolicy,
 };
 use uv_virtualenv::{OnExisting, RemovalReason, Seed};
 use uv_warnings::warn_user;
+use uv_workspace::pyproject_mut::{DependencyTarget, PyProjectTomlMut};
 use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache, WorkspaceErrorKind};
 
 use crate::commands::ExitStatus;
 use crate::commands::pip::loggers::{DefaultInstallLogger, InstallLogger};
 use crate::commands::pip::operations::{Changelog, report_interpreter};
+use crate::commands::project::repair::relocate_environment;
 use crate::commands::project::{
-    LinkErrorReporting, WorkspacePython, centralized_environment_root,
+    LinkErrorReporting, ProjectInterpreter, WorkspacePython, centralized_environment_root,
     centralized_environments_enabled, is_centralized_environment_reference,
-    lock_project_environment, update_project_environment_link, validate_project_requires_python,
+    lock_project_environment, project_environment_selection, update_project_environment_link,
+    validate_project_requires_python,
 };
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::printer::Printer;
@@ -65,7 +69,65 @@ enum VenvError {
     FlatIndex(#[source] uv_client::FlatIndexError),
 }
 
+/// A virtual environment created by [`venv`].
+#[derive(Debug)]
+pub(crate) struct CreatedVenv {
+    /// The virtual environment.
+    environment: PythonEnvironment,
+    /// Whether the virtual environment directory was created by this invocation, rather than
+    /// reused via `--allow-existing`.
+    created: bool,
+    /// The command to activate the virtual environment, if the shell could be detected.
+    activation: Option<String>,
+}
+
+impl CreatedVenv {
+    /// Return the virtual environment.
+    pub(crate) fn environment(&self) -> &PythonEnvironment {
+        &self.environment
+    }
+
+    /// Print the command to activate the virtual environment, if known.
+    pub(crate) fn report_activation(&self, printer: Printer) -> Result<ExitStatus> {
+        if let Some(activation) = &self.activation {
+            writeln!(printer.stderr(), "Activate with: {}", activation.green())?;
+        }
+        Ok(ExitStatus::Success)
+    }
+
+    /// Remove the virtual environment, e.g., after failing to install packages into it.
+    ///
+    /// An existing environment that was reused via `--allow-existing` is left in place.
+    ///
+    /// Any error is reported as a warning, since the caller is already propagating a failure.
+    pub(crate) fn remove(self, printer: Printer) -> Result<()> {
+        let root = self.environment.root();
+        if !self.created {
+            warn_user!(
+                "Leaving the existing virtual environment at `{}` in place; it may be incomplete",
+                root.user_display()
+            );
+            return Ok(());
+        }
+        match uv_fs::remove_virtualenv(root) {
+            Ok(()) => writeln!(
+                printer.stderr(),
+                "Removed incomplete virtual environment at: {}",
+                root.user_display().cyan()
+            )?,
+            Err(err) => warn_user!(
+                "Failed to remove incomplete virtual environment at `{}`: {err}",
+                root.user_display()
+            ),
+        }
+        Ok(())
+    }
+}
+
 /// Create a virtual environment.
+///
+/// The activation command is not printed; callers should use [`CreatedVenv::report_activation`]
+/// once the environment is ready for use.
 pub(crate) async fn venv(
     project_dir: &Path,
     path: Option<PathBuf>,
@@ -92,7 +154,7 @@ pub(crate) async fn venv(
     printer: Printer,
     relocatable: bool,
     preview: Preview,
-) -> Result<ExitStatus> {
+) -> Result<CreatedVenv> {
     let project = if no_project {
         None
     } else {
@@ -270,6 +332,10 @@ pub(crate) async fn venv(
         _ => on_existing,
     };
 
+    // Only an environment created by this invocation should be removed on failure.
+    let created = !matches!(on_existing, OnExisting::Allow)
+        || !matches!(fs_err::symlink_metadata(&path), Ok(metadata) if metadata.is_dir());
+
     // Create the virtual environment.
     let venv = uv_virtualenv::create_venv(
         &path,
@@ -398,7 +464,340 @@ pub(crate) async fn venv(
     };
 
     // Determine the appropriate activation command.
-    let activation = match Shell::from_env() {
+    let activation = activation_command(&scripts);
+
+    Ok(CreatedVenv {
+        environment: venv,
+        created,
+        activation,
+    })
+}
+
+/// Update the configuration of an existing virtual environment.
+pub(crate) async fn venv_update(
+    project_dir: &Path,
+    path: Option<PathBuf>,
+    system_site_packages: Option<bool>,
+    no_project: bool,
+    cache: &Cache,
+    workspace_cache: &WorkspaceCache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let Some(system_site_packages) = system_site_packages else {
+        bail!("`--existing` requires `--system-site-packages` or `--no-system-site-packages`");
+    };
+
+    // Determine the environment to update. As when creating an environment, the project
+    // environment is only used if we're invoked from the project root with no explicit path.
+    let project = if no_project || path.is_some() {
+        None
+    } else {
+        VirtualProject::discover(
+            project_dir,
+            &DiscoveryOptions::default(),
+            cache,
+            workspace_cache,
+        )
+        .await
+        .ok()
+    };
+    let workspace = project
+        .as_ref()
+        .map(VirtualProject::workspace)
+        .filter(|workspace| workspace.install_path() == project_dir);
+    let environment = if let Some(workspace) = workspace {
+        ProjectInterpreter::discover_existing(workspace, Some(false), cache)?
+            .ok_or_else(|| anyhow!("No project environment found; run `uv venv` to create one"))?
+    } else {
+        PythonEnvironment::from_root(path.unwrap_or_else(|| PathBuf::from(".venv")), cache)?
+    };
+
+    if !environment.interpreter().is_virtualenv() {
+        bail!(
+            "Expected a virtual environment at: `{}`",
+            environment.root().user_display()
+        );
+    }
+
+    // Lock the environment to avoid concurrent modifications.
+    let _lock = environment
+        .lock()
+        .await
+        .inspect_err(|err| {
+            warn!("Failed to acquire environment lock: {err}");
+        })
+        .ok();
+
+    environment.set_pyvenv_cfg(
+        "include-system-site-packages",
+        if system_site_packages {
+            "true"
+        } else {
+            "false"
+        },
+    )?;
+
+    writeln!(
+        printer.stderr(),
+        "{} access to the system site packages in: {}",
+        if system_site_packages {
+            "Enabled"
+        } else {
+            "Disabled"
+        },
+        environment.root().user_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Move an existing virtual environment to a new location.
+pub(crate) async fn venv_move(
+    project_dir: &Path,
+    path: Option<PathBuf>,
+    target: PathBuf,
+    no_project: bool,
+    cache: &Cache,
+    workspace_cache: &WorkspaceCache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    // Determine the environment to move. As when creating an environment, the project environment
+    // is only used if we're invoked from the project root with no explicit path.
+    let project = if no_project || path.is_some() {
+        None
+    } else {
+        VirtualProject::discover(
+            project_dir,
+            &DiscoveryOptions::default(),
+            cache,
+            workspace_cache,
+        )
+        .await
+        .ok()
+    };
+    let workspace = project
+        .as_ref()
+        .map(VirtualProject::workspace)
+        .filter(|workspace| workspace.install_path() == project_dir);
+    let source = if let Some(workspace) = workspace {
+        project_environment_selection(workspace, Some(false))
+            .explicit_path()
+            .map_or_else(|| workspace.install_path().join(".venv"), Path::to_path_buf)
+    } else {
+        path.unwrap_or_else(|| PathBuf::from(".venv"))
+    };
+    let source = uv_fs::normalize_path(CWD.join(source)).into_owned();
+    let target = uv_fs::normalize_path(CWD.join(target)).into_owned();
+
+    // Centralized project environments are referenced by a link, which we can't move.
+    if !fs_err::symlink_metadata(&source).is_ok_and(|metadata| metadata.is_dir()) {
+        bail!(
+            "Expected a virtual environment directory at: `{}`",
+            source.user_display()
+        );
+    }
+    let environment = PythonEnvironment::from_root(&source, cache)?;
+    if !environment.interpreter().is_virtualenv() {
+        bail!(
+            "Expected a virtual environment at: `{}`",
+            source.user_display()
+        );
+    }
+    if uv_fs::is_same_file_allow_missing(&source, &target).unwrap_or(false) {
+        bail!(
+            "The virtual environment is already at: `{}`",
+            target.user_display()
+        );
+    }
+    if target.starts_with(&source) {
+        bail!(
+            "Cannot move the virtual environment at `{}` into itself",
+            source.user_display()
+        );
+    }
+    match fs_err::read_dir(&target) {
+        Ok(mut entries) => {
+            if entries.next().is_some() {
+                bail!("The target path is not empty: `{}`", target.user_display());
+            }
+            fs_err::remove_dir(&target)?;
+        }
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+        Err(err) if err.kind() == std::io::ErrorKind::NotADirectory => {
+            bail!(
+                "The target path is not a directory: `{}`",
+                target.user_display()
+            );
+        }
+        Err(err) => return Err(err.into()),
+    }
+
+    // Lock the environment to avoid concurrent modifications.
+    let lock = environment
+        .lock()
+        .await
+        .inspect_err(|err| {
+            warn!("Failed to acquire environment lock: {err}");
+        })
+        .ok();
+
+    let copied = move_environment(&source, &target)?;
+
+    // Rewrite any references to the previous location.
+    let environment = PythonEnvironment::from_root(&target, cache)?;
+    relocate_environment(&environment, &source)?;
+
+    // Record the new location of the project environment.
+    if let Some(workspace) = workspace {
+        let recorded = target
+            .strip_prefix(workspace.install_path())
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|_| target.clone());
+        let recorded = (recorded != Path::new(".venv")).then_some(recorded);
+
+        let pyproject_path = workspace.install_path().join("pyproject.toml");
+        let contents = fs_err::read_to_string(&pyproject_path)?;
+        let mut pyproject =
+            PyProjectTomlMut::from_toml(&contents, DependencyTarget::PyProjectToml)?;
+        pyproject.set_project_environment(recorded.as_deref())?;
+        let updated = pyproject.to_string();
+        if updated != contents {
+            fs_err::write(&pyproject_path, updated)?;
+            if let Some(recorded) = &recorded {
+                writeln!(
+                    printer.stderr(),
+                    "Set `{}` to `{}` in `{}`",
+                    "tool.uv.project-environment".cyan(),
+                    recorded.user_display().cyan(),
+                    "pyproject.toml".cyan()
+                )?;
+            } else {
+                writeln!(
+                    printer.stderr(),
+                    "Removed `{}` from `{}`",
+                    "tool.uv.project-environment".cyan(),
+                    "pyproject.toml".cyan()
+                )?;
+            }
+        }
+
+        if std::env::var_os(EnvVars::UV_PROJECT_ENVIRONMENT).is_some_and(|value| !value.is_empty())
+        {
+            warn_user!(
+                "`{}` is set and takes precedence over `tool.uv.project-environment`; update it to `{}` to use the moved environment",
+                EnvVars::UV_PROJECT_ENVIRONMENT,
+                target.user_display()
+            );
+        }
+    }
+
+    // Now that the copy is in place, remove the original environment.
+    drop(lock);
+    if copied {
+        uv_fs::remove_virtualenv(&source)?;
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Moved virtual environment from `{}` to: {}",
+        source.user_display().cyan(),
+        target.user_display().cyan()
+    )?;
+
+    if let Some(activation) = activation_command(environment.scripts()) {
+        writeln!(printer.stderr(), "Activate with: {}", activation.green())?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Move the environment at `source` to `target`, returning `true` if it was copied, in which case
+/// the original environment remains to be removed.
+///
+/// The environment is renamed if possible. Otherwise (e.g., when moving across file systems), it's
+/// copied to a temporary directory alongside the target, verified, and then renamed into place,
+/// such that an interruption leaves the original environment intact.
+fn move_environment(source: &Path, target: &Path) -> Result<bool> {
+    let parent = target
+        .parent()
+        .ok_or_else(|| anyhow!("Invalid target path: `{}`", target.user_display()))?;
+    fs_err::create_dir_all(parent)?;
+
+    match fs_err::rename(source, target) {
+        Ok(()) => return Ok(false),
+        Err(err) => {
+            debug!("Failed to rename environment, copying instead: {err}");
+        }
+    }
+
+    let temp_dir = tempfile::tempdir_in(parent)?;
+    copy_environment(source, temp_dir.path())?;
+    verify_environment_copy(source, temp_dir.path())?;
+    fs_err::rename(temp_dir.keep(), target)?;
+
+    Ok(true)
+}
+
+/// Copy the contents of an environment, preserving symbolic links (e.g., to the base interpreter).
+fn copy_environment(source: &Path, destination: &Path) -> Result<()> {
+    for entry in walkdir::WalkDir::new(source).min_depth(1) {
+        let entry = entry?;
+        let relative = entry.path().strip_prefix(source)?;
+        // The environment lock is held by this process, and isn't needed in the copy.
+        if relative == Path::new(".lock") {
+            continue;
+        }
+        let path = destination.join(relative);
+        let file_type = entry.file_type();
+        if file_type.is_dir() {
+            fs_err::create_dir_all(&path)?;
+            continue;
+        }
+        #[cfg(unix)]
+        if file_type.is_symlink() {
+            fs_err::os::unix::fs::symlink(fs_err::read_link(entry.path())?, &path)?;
+            continue;
+        }
+        fs_err::copy(entry.path(), &path)?;
+    }
+    Ok(())
+}
+
+/// Verify that every entry in the environment was copied, with the same size.
+fn verify_environment_copy(source: &Path, destination: &Path) -> Result<()> {
+    for entry in walkdir::WalkDir::new(source).min_depth(1) {
+        let entry = entry?;
+        let relative = entry.path().strip_prefix(source)?;
+        if relative == Path::new(".lock") {
+            continue;
+        }
+        let path = destination.join(relative);
+        let matches = fs_err::symlink_metadata(&path).is_ok_and(|metadata| {
+            if entry.file_type().is_dir() {
+                metadata.is_dir()
+            } else if entry.file_type().is_file() {
+                entry
+                    .metadata()
+                    .is_ok_and(|source| source.len() == metadata.len())
+            } else {
+                true
+            }
+        });
+        if !matches {
+            bail!(
+                "Failed to copy `{}` to `{}`",
+                entry.path().user_display(),
+                path.user_display()
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Return the command to activate the virtual environment with the given scripts directory in the
+/// current shell, if the shell can be detected.
+pub(crate) fn activation_command(scripts: &Path) -> Option<String> {
+    match Shell::from_env() {
         None => None,
         Some(Shell::Bash | Shell::Zsh | Shell::Ksh) => {
             Some(format!("source {}", shlex_posix(scripts.join("activate"))))
@@ -417,10 +816,5 @@ pub(crate) async fn venv(
         )),
         Some(Shell::Powershell) => Some(shlex_windows(scripts.join("activate"), Shell::Powershell)),
         Some(Shell::Cmd) => Some(shlex_windows(scripts.join("activate"), Shell::Cmd)),
-    };
-    if let Some(act) = activation {
-        writeln!(printer.stderr(), "Activate with: {}", act.green())?;
     }
-
-    Ok(ExitStatus::Success)
 }
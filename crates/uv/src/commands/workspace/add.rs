@@ -0,0 +1,163 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use anyhow::{Context, Result, bail};
+use owo_colors::OwoColorize;
+
+use uv_cache::Cache;
+use uv_client::BaseClientBuilder;
+use uv_configuration::{CiProvider, ProjectBuildBackend, VersionControlSystem};
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_pep508::{MarkerTree, Requirement};
+use uv_preview::{Preview, PreviewFeature};
+use uv_python::{ConfigDiscovery, PythonDownloads, PythonPreference};
+use uv_settings::PythonInstallMirrors;
+use uv_warnings::warn_user;
+use uv_workspace::pyproject::{Source, WorkspaceReference};
+use uv_workspace::pyproject_mut::{DependencyTarget, PyProjectTomlMut};
+use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
+
+use crate::commands::{ExitStatus, InitKind, init};
+use crate::printer::Printer;
+
+/// Add a new member to the workspace.
+pub(crate) async fn add(
+    project_dir: &Path,
+    path: PathBuf,
+    name: Option<PackageName>,
+    package: bool,
+    init_kind: InitKind,
+    description: Option<String>,
+    build_backend: Option<ProjectBuildBackend>,
+    no_readme: bool,
+    r#for: Option<PackageName>,
+    python: Option<String>,
+    install_mirrors: PythonInstallMirrors,
+    client_builder: &BaseClientBuilder<'_>,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    config_discovery: ConfigDiscovery,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeature::WorkspaceMembers) {
+        warn_user!(
+            "`uv workspace add` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::WorkspaceMembers
+        );
+    }
+
+    let workspace = Workspace::discover(
+        project_dir,
+        &DiscoveryOptions::default(),
+        cache,
+        &WorkspaceCache::default(),
+    )
+    .await?;
+
+    let path = std::path::absolute(&path)?;
+    if !path.starts_with(workspace.install_path()) {
+        bail!(
+            "`{}` is outside of the workspace at `{}`",
+            path.user_display().cyan(),
+            workspace.install_path().user_display().cyan()
+        );
+    }
+    if workspace.excludes(&path)? {
+        bail!(
+            "`{}` is excluded by the workspace at `{}`",
+            path.user_display().cyan(),
+            workspace.install_path().user_display().cyan()
+        );
+    }
+
+    // Validate the dependent member before creating any files.
+    let dependent = match r#for {
+        Some(dependent) => {
+            let Some(member) = workspace.packages().get(&dependent) else {
+                bail!("Package `{dependent}` not found in workspace");
+            };
+            Some((dependent, member.root().clone()))
+        }
+        None => None,
+    };
+
+    Box::pin(init(
+        project_dir,
+        Some(path.clone()),
+        name,
+        package,
+        init_kind,
+        false,
+        description,
+        false,
+        Some(VersionControlSystem::None),
+        CiProvider::default(),
+        build_backend,
+        no_readme,
+        None,
+        false,
+        python,
+        install_mirrors,
+        false,
+        client_builder,
+        python_preference,
+        python_downloads,
+        config_discovery,
+        cache,
+        printer,
+    ))
+    .await?;
+
+    let Some((dependent, dependent_root)) = dependent else {
+        return Ok(ExitStatus::Success);
+    };
+
+    // Re-discover the workspace to determine the name of the new member.
+    let workspace = Workspace::discover(
+        project_dir,
+        &DiscoveryOptions::default(),
+        cache,
+        &WorkspaceCache::default(),
+    )
+    .await?;
+    let Some(name) = workspace
+        .packages()
+        .iter()
+        .find(|(_, member)| member.root() == &path)
+        .map(|(name, _)| name.clone())
+    else {
+        bail!(
+            "`{}` was not discovered as a member of the workspace",
+            path.user_display().cyan()
+        );
+    };
+
+    // Add the new member as a workspace dependency of the dependent member.
+    let pyproject_path = dependent_root.join("pyproject.toml");
+    let content = fs_err::read_to_string(&pyproject_path)?;
+    let mut pyproject = PyProjectTomlMut::from_toml(&content, DependencyTarget::PyProjectToml)?;
+    let requirement = Requirement::from_str(name.as_ref())
+        .with_context(|| format!("Failed to create a requirement for `{name}`"))?;
+    let source = Source::Workspace {
+        workspace: WorkspaceReference::Bool(true),
+        editable: None,
+        marker: MarkerTree::TRUE,
+        extra: None,
+        group: None,
+    };
+    pyproject.add_dependency(&requirement, Some(&source), false)?;
+    fs_err::write(&pyproject_path, pyproject.to_string())?;
+
+    writeln!(
+        printer.stderr(),
+        "Added `{}` as a dependency of `{}`",
+        name.cyan(),
+        dependent.cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
@@ -16,7 +16,7 @@ use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache};
 use crate::commands::pip::loggers::DefaultResolveLogger;
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::{LockMode, LockOperation};
-use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::lock_target::SingleLockTarget;
 use crate::commands::project::{
     LinkErrorReporting, ProjectEnvironment, ProjectError, ProjectInterpreter, ScriptEnvironment,
     ScriptInterpreter, UniversalState, WorkspacePython,
@@ -60,7 +60,7 @@ pub(crate) async fn metadata(
 
     let virtual_project;
     let target = if let Some(script) = script.as_ref() {
-        LockTarget::Script(script)
+        SingleLockTarget::Script(script)
     } else {
         virtual_project = VirtualProject::discover(
             project_dir,
@@ -69,7 +69,7 @@ pub(crate) async fn metadata(
             workspace_cache,
         )
         .await?;
-        LockTarget::Workspace(virtual_project.workspace())
+        SingleLockTarget::Workspace(virtual_project.workspace())
     };
 
     // Don't enable any groups' requires-python for interpreter discovery.
@@ -81,7 +81,7 @@ pub(crate) async fn metadata(
         LockMode::Frozen(frozen_source.into())
     } else {
         interpreter = match target {
-            LockTarget::Script(script) => ScriptInterpreter::discover(
+            SingleLockTarget::Script(script) => ScriptInterpreter::discover(
                 script.into(),
                 python.as_deref().map(PythonRequest::parse),
                 &client_builder,
@@ -96,7 +96,7 @@ pub(crate) async fn metadata(
             )
             .await?
             .into_interpreter(),
-            LockTarget::Workspace(workspace) => {
+            SingleLockTarget::Workspace(workspace) => {
                 let workspace_python = WorkspacePython::from_request(
                     python.as_deref().map(PythonRequest::parse),
                     Some(workspace),
@@ -126,7 +126,7 @@ pub(crate) async fn metadata(
         if let LockCheck::Enabled(lock_check) = lock_check {
             LockMode::Locked(&interpreter, lock_check)
         } else if dry_run.enabled()
-            || (matches!(target, LockTarget::Script(_)) && !target.lock_path().is_file())
+            || (matches!(target, SingleLockTarget::Script(_)) && !target.lock_path().is_file())
         {
             LockMode::DryRun(&interpreter)
         } else {
@@ -152,18 +152,18 @@ pub(crate) async fn metadata(
             preview,
         )
         .with_refresh(&refresh)
-        .execute(target),
+        .execute(target.into()),
     )
     .await
     {
         Ok(lock) => {
             let lock = lock.into_lock();
             let install_target = match target {
-                LockTarget::Workspace(workspace) => InstallTarget::Workspace {
+                SingleLockTarget::Workspace(workspace) => InstallTarget::Workspace {
                     workspace,
                     lock: &lock,
                 },
-                LockTarget::Script(script) => InstallTarget::Script {
+                SingleLockTarget::Script(script) => InstallTarget::Script {
                     script,
                     lock: &lock,
                 },
@@ -171,7 +171,7 @@ pub(crate) async fn metadata(
             let mut export = metadata_for_target(install_target)?;
             let environment = if sync {
                 Some(match target {
-                    LockTarget::Workspace(workspace) => ProjectEnvironment::get_or_init(
+                    SingleLockTarget::Workspace(workspace) => ProjectEnvironment::get_or_init(
                         workspace,
                         &groups,
                         python.as_deref().map(PythonRequest::parse),
@@ -182,6 +182,7 @@ pub(crate) async fn metadata(
                         false,
                         config_discovery,
                         Some(active),
+                        false,
                         cache,
                         DryRun::Disabled,
                         LinkErrorReporting::User,
@@ -189,7 +190,7 @@ pub(crate) async fn metadata(
                     )
                     .await?
                     .into_environment()?,
-                    LockTarget::Script(script) => ScriptEnvironment::get_or_init(
+                    SingleLockTarget::Script(script) => ScriptEnvironment::get_or_init(
                         script.into(),
                         python.as_deref().map(PythonRequest::parse),
                         &client_builder,
@@ -208,10 +209,10 @@ pub(crate) async fn metadata(
                 })
             } else {
                 match target {
-                    LockTarget::Workspace(workspace) => {
+                    SingleLockTarget::Workspace(workspace) => {
                         ProjectInterpreter::discover_existing(workspace, Some(active), cache)?
                     }
-                    LockTarget::Script(script) => {
+                    SingleLockTarget::Script(script) => {
                         ScriptInterpreter::discover_existing(script.into(), Some(active), cache)
                     }
                 }
@@ -1,4 +1,6 @@
+pub(crate) mod add;
 pub(crate) mod dir;
 pub(crate) mod list;
 pub(crate) mod metadata;
 mod module_owners;
+pub(crate) mod remove;
@@ -62,6 +62,7 @@ pub(crate) async fn collect_module_owners(
             build_isolation: &settings.build_isolation,
             extra_build_dependencies: &settings.extra_build_dependencies,
             extra_build_variables: &settings.extra_build_variables,
+            build_env_passthrough: &settings.build_env_passthrough,
             exclude_newer: &settings.exclude_newer,
             link_mode: settings.link_mode,
             compile_bytecode: false,
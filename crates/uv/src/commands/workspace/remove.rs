@@ -0,0 +1,144 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Result, bail};
+use owo_colors::OwoColorize;
+
+use uv_cache::Cache;
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_preview::{Preview, PreviewFeature};
+use uv_warnings::warn_user;
+use uv_workspace::pyproject_mut::{DependencyTarget, PyProjectTomlMut};
+use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Remove a member from the workspace.
+pub(crate) async fn remove(
+    project_dir: &Path,
+    package: PackageName,
+    delete: bool,
+    cache: &Cache,
+    workspace_cache: &WorkspaceCache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeature::WorkspaceMembers) {
+        warn_user!(
+            "`uv workspace remove` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::WorkspaceMembers
+        );
+    }
+
+    let workspace = Workspace::discover(
+        project_dir,
+        &DiscoveryOptions::default(),
+        cache,
+        workspace_cache,
+    )
+    .await?;
+
+    let Some(member) = workspace.packages().get(&package) else {
+        bail!("Package `{package}` not found in workspace");
+    };
+    if member.root() == workspace.install_path() {
+        bail!("Cannot remove the workspace root `{package}` from the workspace");
+    }
+    let member_root = member.root().clone();
+
+    // Drop the explicit `members` entries for the member. If a glob still includes it, exclude it
+    // instead, unless its directory is about to be deleted.
+    let mut root = PyProjectTomlMut::from_toml(
+        &workspace.pyproject_toml().raw,
+        DependencyTarget::PyProjectToml,
+    )?;
+    let mut globbed = false;
+    for entry in workspace.including_members(&member_root)? {
+        if entry.contains(['*', '?', '[']) {
+            globbed = true;
+        } else {
+            root.remove_workspace_member(entry)?;
+        }
+    }
+    if globbed && !delete {
+        root.add_workspace_exclude(member_root.strip_prefix(workspace.install_path())?)?;
+    }
+
+    // Remove any workspace sources that refer to the member, and find members that still depend
+    // on it.
+    let mut dependents = Vec::new();
+    if root.remove_workspace_source(&package)? {
+        writeln!(
+            printer.stderr(),
+            "Removed workspace source for `{}` from `{}`",
+            package.cyan(),
+            workspace
+                .install_path()
+                .join("pyproject.toml")
+                .user_display()
+                .cyan()
+        )?;
+    }
+    for (name, other) in workspace.packages() {
+        if *name == package {
+            continue;
+        }
+        if other.root() == workspace.install_path() {
+            if !root.find_dependency(&package, None).is_empty() {
+                dependents.push(name);
+            }
+            continue;
+        }
+
+        let pyproject_path = other.root().join("pyproject.toml");
+        let mut pyproject = PyProjectTomlMut::from_toml(
+            &other.pyproject_toml().raw,
+            DependencyTarget::PyProjectToml,
+        )?;
+        if pyproject.remove_workspace_source(&package)? {
+            fs_err::write(&pyproject_path, pyproject.to_string())?;
+            writeln!(
+                printer.stderr(),
+                "Removed workspace source for `{}` from `{}`",
+                package.cyan(),
+                pyproject_path.user_display().cyan()
+            )?;
+        }
+        if !pyproject.find_dependency(&package, None).is_empty() {
+            dependents.push(name);
+        }
+    }
+    fs_err::write(
+        workspace.install_path().join("pyproject.toml"),
+        root.to_string(),
+    )?;
+
+    if delete {
+        fs_err::remove_dir_all(&member_root)?;
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Removed `{}` from workspace `{}`",
+        package.cyan(),
+        workspace.install_path().simplified_display().cyan()
+    )?;
+    if delete {
+        writeln!(
+            printer.stderr(),
+            "Deleted `{}`",
+            member_root.user_display().cyan()
+        )?;
+    }
+
+    for dependent in dependents {
+        warn_user!(
+            "`{dependent}` depends on `{package}`, which is no longer a workspace member; remove the dependency with `{}`",
+            format!("uv remove {package} --package {dependent}").green()
+        );
+    }
+
+    Ok(ExitStatus::Success)
+}
@@ -28,12 +28,13 @@ use uv_cache_info::Timestamp;
 use uv_cli::SelfUpdateArgs;
 use uv_cli::{
     AuthCommand, AuthHelperCommand, AuthNamespace, BuildBackendCommand, CacheCommand,
-    CacheNamespace, Cli, Commands, PipCommand, PipNamespace, ProjectCommand, PythonCommand,
-    PythonNamespace, SelfCommand, SelfNamespace, ToolCommand, ToolNamespace, TopLevelArgs,
+    CacheNamespace, Cli, Commands, ConfigCommand, ConfigNamespace, DryRunFormat, PipCommand,
+    PipNamespace, ProjectCommand, PythonCommand, PythonNamespace, PythonPlatformCommand,
+    PythonPlatformNamespace, SelfCommand, SelfNamespace, ToolCommand, ToolNamespace, TopLevelArgs,
     WorkspaceCommand, WorkspaceNamespace, compat::CompatArgs, options::ArgumentError,
 };
 use uv_client::BaseClientBuilder;
-use uv_configuration::min_stack_size;
+use uv_configuration::{DryRun, min_stack_size};
 use uv_flags::EnvironmentFlags;
 use uv_fs::{CWD, Simplified, normalize_path};
 #[cfg(feature = "self-update")]
@@ -51,7 +52,8 @@ use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
 
 use crate::commands::{
-    ExitStatus, ParsedRunCommand, ProjectError, RunCommand, ScriptPath, ToolRunCommand, UvError,
+    ExitStatus, ParsedRunCommand, ProjectError, RunCommand, ScriptPath, SharedLock, ToolRunCommand,
+    UvError,
 };
 use crate::printer::Printer;
 use crate::settings::{
@@ -67,6 +69,7 @@ mod install_source;
 mod logging;
 pub(crate) mod printer;
 pub(crate) mod settings;
+mod timings;
 
 /// Whether to initialize process-global state.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -174,6 +177,10 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
                 3.. => logging::Level::TraceAll,
             },
             durations_layer,
+            cli.top_level
+                .global_args
+                .timings
+                .map(timings::TimingsLayer::install),
             resolve_color(&cli.top_level.global_args),
             environment.log_context.unwrap_or_default(),
         )?;
@@ -302,7 +309,11 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
         cli.top_level.cache_args.cache_dir.clone(),
     )?;
     let workspace_cache = WorkspaceCache::default();
-    let filesystem = if let Some(config_file) = cli.top_level.config_file.as_ref() {
+    let filesystem = if matches!(&*cli.command, Commands::Config(_)) {
+        // `uv config` inspects the configuration files itself, and must run even if they're
+        // invalid.
+        None
+    } else if let Some(config_file) = cli.top_level.config_file.as_ref() {
         if config_file
             .file_name()
             .is_some_and(|file_name| file_name == "pyproject.toml")
@@ -362,15 +373,20 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
             ProjectCommand::Add(uv_cli::AddArgs {
                 script: Some(script),
                 ..
-            })
-            | ProjectCommand::Lock(uv_cli::LockArgs {
-                script: Some(script),
-                ..
             }) => match Pep723Script::read(script).await {
                 Ok(Some(script)) => Some(Pep723Item::Script(script)),
                 Ok(None) => None,
                 Err(err) => return Err(err.into()),
             },
+            ProjectCommand::Lock(uv_cli::LockArgs {
+                script,
+                shared_lock: None,
+                ..
+            }) if script.len() == 1 => match Pep723Script::read(&script[0]).await {
+                Ok(Some(script)) => Some(Pep723Item::Script(script)),
+                Ok(None) => None,
+                Err(err) => return Err(err.into()),
+            },
             // For the remaining commands, the PEP 723 tag must exist already.
             ProjectCommand::Remove(uv_cli::RemoveArgs {
                 script: Some(script),
@@ -559,6 +575,12 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
         }))?;
     }
 
+    if global_initialization.needs_initialization() {
+        // Build backends run in their own process group, so they don't receive the terminal's
+        // SIGINT; terminate them explicitly on Ctrl-C.
+        tokio::spawn(abort_builds_on_interrupt(printer));
+    }
+
     // Don't initialize the rayon threadpool yet, this is too costly when we're doing a noop sync.
     uv_configuration::RAYON_PARALLELISM.store(globals.concurrency.installs, Ordering::Relaxed);
 
@@ -641,7 +663,9 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
     .cache_read_concurrency(globals.concurrency.cache_reads)
     .http_proxy(globals.network_settings.http_proxy.clone())
     .https_proxy(globals.network_settings.https_proxy.clone())
-    .no_proxy(globals.network_settings.no_proxy.clone());
+    .no_proxy(globals.network_settings.no_proxy.clone())
+    .strict_index_tracking(globals.network_settings.strict_index_tracking)
+    .max_simple_response_size(environment.max_simple_response_size);
 
     match *cli.command {
         Commands::Auth(AuthNamespace {
@@ -790,16 +814,22 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
                 args.format,
                 args.settings.resolution,
                 args.settings.prerelease,
+                args.settings.prerelease_packages,
                 args.settings.fork_strategy,
                 args.settings.dependency_mode,
                 args.settings.upgrade,
                 args.settings.generate_hashes,
                 args.settings.no_emit_package,
+                args.unsafe_packages,
+                args.allow_unsafe,
                 args.settings.no_strip_extras,
                 args.settings.no_strip_markers,
                 !args.settings.no_annotate,
                 !args.settings.no_header,
+                args.pip_tools_header,
                 args.settings.custom_compile_command,
+                args.emit_inputs_hash,
+                args.check_fresh.as_deref(),
                 args.settings.emit_index_url,
                 args.settings.emit_find_links,
                 args.settings.emit_build_options,
@@ -825,6 +855,7 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
                 globals.python_downloads,
                 args.settings.universal,
                 args.settings.exclude_newer,
+                args.settings.require_metadata,
                 args.settings.sources,
                 args.settings.annotation_style,
                 args.settings.link_mode,
@@ -885,6 +916,8 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
                 &requirements,
                 &constraints,
                 &build_constraints,
+                &args.exclude_package,
+                args.exclude_unchecked,
                 &args.settings.extras,
                 &groups,
                 args.settings.reinstall,
@@ -913,7 +946,9 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
                 args.settings.install_mirrors,
                 args.settings.strict,
                 args.settings.exclude_newer,
+                args.settings.require_metadata,
                 args.settings.python,
+                None,
                 args.settings.system,
                 args.settings.break_system_packages,
                 args.settings.target,
@@ -924,6 +959,7 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
                 cache,
                 workspace_cache,
                 args.dry_run,
+                args.format,
                 printer,
                 globals.preview,
             ))
@@ -1042,11 +1078,14 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
                 args.overrides_from_workspace,
                 args.excludes_from_workspace,
                 args.build_constraints_from_workspace,
+                &args.exclude_package,
+                args.exclude_unchecked,
                 args.editable,
                 &args.settings.extras,
                 &groups,
                 args.settings.resolution,
                 args.settings.prerelease,
+                args.settings.prerelease_packages,
                 args.settings.dependency_mode,
                 args.settings.upgrade,
                 args.settings.index_locations,
@@ -1075,6 +1114,7 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
                 args.settings.install_mirrors,
                 args.settings.strict,
                 args.settings.exclude_newer,
+                args.settings.require_metadata,
                 args.settings.sources,
                 args.settings.python,
                 args.settings.system,
@@ -1086,6 +1126,8 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
                 cache,
                 workspace_cache,
                 args.dry_run,
+                args.format,
+                args.explain,
                 printer,
                 globals.preview,
             ))
@@ -1206,6 +1248,7 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
                 args.settings.target,
                 args.settings.prefix,
                 args.files,
+                args.format,
                 &cache,
                 printer,
             )
@@ -1279,14 +1322,24 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
             command: CacheCommand::Prune(args),
         }) => {
             show_settings!(args);
-            commands::cache_prune(args.ci, args.force, cache, printer).await
+            commands::cache_prune(
+                args.ci,
+                args.force,
+                args.older_than,
+                args.keep_lockfile,
+                args.python_platform,
+                args.dry_run,
+                cache,
+                printer,
+            )
+            .await
         }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Dir,
         }) => commands::cache_dir(&cache, printer),
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Size(args),
-        }) => commands::cache_size(&cache, args.human, printer, globals.preview),
+        }) => commands::cache_size(&cache, args.human, args.dedup, printer, globals.preview),
         Commands::Build(args) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = settings::BuildSettings::resolve(args, filesystem, environment)?;
@@ -1323,6 +1376,7 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
                 args.gitignore,
                 args.force_pep517,
                 args.clear,
+                args.check_bounds,
                 build_constraints,
                 args.build_constraints_from_workspace,
                 args.hash_checking,
@@ -1372,6 +1426,34 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
                     .combine(Refresh::from(args.settings.upgrade.clone())),
             );
 
+            // Update an existing environment, rather than creating one, if requested.
+            if args.existing {
+                return commands::venv_update(
+                    &project_dir,
+                    args.path,
+                    args.system_site_packages,
+                    args.no_project,
+                    &cache,
+                    &workspace_cache,
+                    printer,
+                )
+                .await;
+            }
+
+            // Move an existing environment, rather than creating one, if requested.
+            if let Some(target) = args.move_to {
+                return commands::venv_move(
+                    &project_dir,
+                    args.path,
+                    target,
+                    args.no_project,
+                    &cache,
+                    &workspace_cache,
+                    printer,
+                )
+                .await;
+            }
+
             // Since we use ".venv" as the default name, we use "." as the default prompt.
             let prompt = args.prompt.or_else(|| {
                 if args.path.is_none() {
@@ -1397,25 +1479,35 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
                 },
             );
 
-            Box::pin(commands::venv(
+            // Validate the lockfile before creating the environment.
+            let from_lock = if let Some(from_lock) = args.from_lock {
+                if !from_lock.is_file() {
+                    bail!("File not found: `{}`", from_lock.user_display());
+                }
+                Some(RequirementsSource::from_requirements_file(from_lock)?)
+            } else {
+                None
+            };
+
+            let venv = Box::pin(commands::venv(
                 &project_dir,
                 args.path,
                 python_request,
-                args.settings.install_mirrors,
+                args.settings.install_mirrors.clone(),
                 globals.python_preference,
                 globals.python_downloads,
                 args.settings.link_mode,
                 &args.settings.index_locations,
                 args.settings.index_strategy,
-                args.settings.dependency_metadata,
+                args.settings.dependency_metadata.clone(),
                 args.settings.keyring_provider,
-                &client_builder.subcommand(vec!["venv".to_owned()]),
+                &client_builder.clone().subcommand(vec!["venv".to_owned()]),
                 uv_virtualenv::Prompt::from_args(prompt),
-                args.system_site_packages,
+                args.system_site_packages.unwrap_or(false),
                 uv_virtualenv::Seed::from_args(args.seed),
                 on_existing,
-                args.settings.exclude_newer,
-                globals.concurrency,
+                args.settings.exclude_newer.clone(),
+                globals.concurrency.clone(),
                 args.no_project,
                 config_discovery,
                 &cache,
@@ -1428,7 +1520,125 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
                         && !args.no_relocatable),
                 globals.preview,
             ))
-            .await
+            .await?;
+
+            // Populate the environment, if requested.
+            let result = if let Some(sync) = args.sync {
+                Box::pin(commands::sync(
+                    &project_dir,
+                    sync.lock_check,
+                    sync.frozen,
+                    sync.from_bundle,
+                    sync.dry_run,
+                    sync.explain,
+                    sync.check_group_markers,
+                    sync.no_post_sync,
+                    sync.repair,
+                    sync.cascade,
+                    sync.active,
+                    sync.allow_conda,
+                    sync.all_packages,
+                    sync.package,
+                    sync.extras,
+                    sync.groups,
+                    sync.editable,
+                    sync.install_options,
+                    sync.modifications,
+                    sync.python,
+                    sync.python_platform,
+                    sync.install_mirrors,
+                    globals.python_preference,
+                    globals.python_downloads,
+                    sync.settings,
+                    client_builder.subcommand(vec!["venv".to_owned()]),
+                    None,
+                    Some(venv.environment().clone()),
+                    globals.installer_metadata,
+                    globals.concurrency,
+                    config_discovery,
+                    &cache,
+                    &workspace_cache,
+                    printer,
+                    globals.preview,
+                    sync.output_format,
+                    sync.malware_settings,
+                ))
+                .await
+            } else if let Some(from_lock) = from_lock {
+                let requirements = [from_lock];
+                let groups = GroupsSpecification {
+                    root: project_dir.to_path_buf(),
+                    groups: args.settings.groups,
+                };
+
+                Box::pin(commands::pip_sync(
+                    &requirements,
+                    &[],
+                    &[],
+                    &[],
+                    false,
+                    &args.settings.extras,
+                    &groups,
+                    args.settings.reinstall,
+                    args.settings.link_mode,
+                    args.settings.compile_bytecode,
+                    args.settings.hash_checking,
+                    args.settings.index_locations,
+                    args.settings.index_strategy,
+                    args.settings.torch_backend,
+                    args.settings.cuda_driver_version,
+                    args.settings.amd_gpu_architecture,
+                    args.settings.dependency_metadata,
+                    args.settings.keyring_provider,
+                    &client_builder.subcommand(vec!["venv".to_owned()]),
+                    args.settings.allow_empty_requirements,
+                    globals.installer_metadata,
+                    &args.settings.config_setting,
+                    &args.settings.config_settings_package,
+                    args.settings.build_isolation.clone(),
+                    &args.settings.extra_build_dependencies,
+                    &args.settings.extra_build_variables,
+                    args.settings.build_options,
+                    None,
+                    None,
+                    globals.python_downloads,
+                    args.settings.install_mirrors,
+                    args.settings.strict,
+                    args.settings.exclude_newer,
+                    args.settings.require_metadata,
+                    None,
+                    Some(venv.environment().clone()),
+                    false,
+                    false,
+                    None,
+                    None,
+                    args.settings.sources,
+                    globals.python_preference,
+                    globals.concurrency,
+                    cache,
+                    workspace_cache,
+                    DryRun::Disabled,
+                    DryRunFormat::default(),
+                    printer,
+                    globals.preview,
+                ))
+                .await
+            } else {
+                return venv.report_activation(printer);
+            };
+
+            // If installation failed, remove the environment rather than leaving it half-populated.
+            match result {
+                Ok(ExitStatus::Success) => venv.report_activation(printer),
+                Ok(status) => {
+                    venv.remove(printer)?;
+                    Ok(status)
+                }
+                Err(err) => {
+                    venv.remove(printer)?;
+                    Err(err)
+                }
+            }
         }
         Commands::Project(project) => {
             Box::pin(run_project(
@@ -1549,7 +1759,7 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
                     requirements.push(RequirementsSource::from_with_package_argument(&package)?);
                 }
                 for package in args.with_editable {
-                    requirements.push(RequirementsSource::from_editable(&package)?);
+                    requirements.push(RequirementsSource::from_with_editable_argument(&package)?);
                 }
                 requirements.extend(
                     args.with_requirements
@@ -1586,7 +1796,9 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
             Box::pin(commands::tool_run(
                 args.command,
                 args.from,
+                args.entrypoint,
                 &requirements,
+                &args.with_executable,
                 &constraints,
                 &overrides,
                 &build_constraints,
@@ -1602,6 +1814,7 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
                 args.isolated,
                 globals.python_preference,
                 globals.python_downloads,
+                globals.windows_arm64_fallback,
                 globals.installer_metadata,
                 globals.concurrency,
                 cache,
@@ -1643,7 +1856,7 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
                 requirements.push(RequirementsSource::from_with_package_argument(&pkg)?);
             }
             for pkg in args.with_editable {
-                requirements.push(RequirementsSource::from_editable(&pkg)?);
+                requirements.push(RequirementsSource::from_with_editable_argument(&pkg)?);
             }
             for path in args.with_requirements {
                 requirements.push(RequirementsSource::from_requirements_file(path)?);
@@ -1698,11 +1911,13 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
                 args.python_platform,
                 args.install_mirrors,
                 args.force,
+                args.keep_options,
                 args.options,
                 args.settings,
                 client_builder.subcommand(vec!["tool".to_owned(), "install".to_owned()]),
                 globals.python_preference,
                 globals.python_downloads,
+                globals.windows_arm64_fallback,
                 globals.installer_metadata,
                 globals.concurrency,
                 config_discovery,
@@ -1814,6 +2029,8 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
                 args.all_platforms,
                 args.all_arches,
                 args.show_urls,
+                args.verify,
+                args.show_usage,
                 args.output_format,
                 args.python_downloads_json_url,
                 args.python_install_mirror,
@@ -1850,6 +2067,8 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
                 args.python_downloads_json_url,
                 client_builder.subcommand(vec!["python".to_owned(), "install".to_owned()]),
                 args.default,
+                args.verify,
+                args.build_from_source,
                 globals.python_downloads,
                 config_discovery,
                 args.compile_bytecode,
@@ -1885,6 +2104,8 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
                 args.python_downloads_json_url,
                 client_builder.subcommand(vec!["python".to_owned(), "upgrade".to_owned()]),
                 args.default,
+                false,
+                None,
                 globals.python_downloads,
                 config_discovery,
                 args.compile_bytecode,
@@ -1964,6 +2185,7 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
                 args.no_project,
                 args.global,
                 args.rm,
+                args.show_resolution,
                 args.install_mirrors,
                 client_builder.subcommand(vec!["python".to_owned(), "pin".to_owned()]),
                 &cache,
@@ -1982,6 +2204,18 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
             commands::python_dir(args.bin, printer)?;
             Ok(ExitStatus::Success)
         }
+        Commands::Python(PythonNamespace {
+            command:
+                PythonCommand::Platform(PythonPlatformNamespace {
+                    command: PythonPlatformCommand::List(args),
+                }),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::PythonPlatformListSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::python_platform_list(args.python_platform, &args.python_version, printer)
+        }
         Commands::Python(PythonNamespace {
             command: PythonCommand::UpdateShell,
         }) => {
@@ -2005,9 +2239,11 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
 
             let PublishSettings {
                 files,
+                from_build,
                 username,
                 password,
                 dry_run,
+                check_metadata,
                 no_attestations,
                 direct,
                 publish_url,
@@ -2020,6 +2256,7 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
 
             commands::publish(
                 files,
+                from_build,
                 publish_url,
                 trusted_publishing,
                 keyring_provider,
@@ -2031,6 +2268,7 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
                 index,
                 index_locations,
                 dry_run,
+                check_metadata,
                 no_attestations,
                 direct,
                 globals.preview,
@@ -2039,6 +2277,20 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
             )
             .await
         }
+        Commands::Config(ConfigNamespace {
+            command: ConfigCommand::Lint(args),
+        }) => {
+            commands::config_lint(
+                &project_dir,
+                cli.top_level.config_file.as_deref(),
+                config_discovery,
+                args.output_format,
+                &cache,
+                &workspace_cache,
+                printer,
+            )
+            .await
+        }
         Commands::Workspace(WorkspaceNamespace { command }) => match command {
             WorkspaceCommand::Metadata(args) => {
                 // Resolve the settings from the command-line arguments and workspace configuration.
@@ -2109,6 +2361,53 @@ pub async fn run(cli: Cli, global_initialization: GlobalInitialization) -> Resul
                 )
                 .await
             }
+            WorkspaceCommand::Add(args) => {
+                // Resolve the settings from the command-line arguments and workspace configuration.
+                let args = settings::WorkspaceAddSettings::resolve(
+                    args,
+                    filesystem,
+                    environment,
+                    globals.preview,
+                )?;
+                show_settings!(args);
+
+                // Initialize the cache.
+                let cache = cache.init().await?;
+
+                Box::pin(commands::workspace_add(
+                    &project_dir,
+                    args.path,
+                    args.name,
+                    args.package,
+                    args.kind,
+                    args.description,
+                    args.build_backend,
+                    args.no_readme,
+                    args.r#for,
+                    args.python,
+                    args.install_mirrors,
+                    &client_builder.subcommand(vec!["workspace".to_owned(), "add".to_owned()]),
+                    globals.python_preference,
+                    globals.python_downloads,
+                    config_discovery,
+                    &cache,
+                    printer,
+                    globals.preview,
+                ))
+                .await
+            }
+            WorkspaceCommand::Remove(args) => {
+                commands::workspace_remove(
+                    &project_dir,
+                    args.package,
+                    args.delete,
+                    &cache,
+                    &workspace_cache,
+                    printer,
+                    globals.preview,
+                )
+                .await
+            }
         },
         Commands::BuildBackend { command } => spawn_blocking(move || match command {
             BuildBackendCommand::BuildSdist { sdist_directory } => {
@@ -2267,6 +2566,7 @@ async fn run_project(
                 args.description,
                 args.no_description,
                 args.vcs,
+                args.ci,
                 args.build_backend,
                 args.no_readme,
                 args.author_from,
@@ -2307,7 +2607,7 @@ async fn run_project(
                 requirements.push(RequirementsSource::from_with_package_argument(&package)?);
             }
             for package in args.with_editable {
-                requirements.push(RequirementsSource::from_editable(&package)?);
+                requirements.push(RequirementsSource::from_with_editable_argument(&package)?);
             }
             requirements.extend(
                 args.with_requirements
@@ -2321,15 +2621,20 @@ async fn run_project(
                 script,
                 command,
                 requirements,
+                args.with_lock,
                 args.show_resolution || globals.verbose > 0,
                 args.lock_check,
                 args.frozen,
                 args.active,
+                args.allow_conda,
                 args.no_sync,
                 args.isolated,
                 args.all_packages,
                 args.package,
                 args.no_project,
+                args.no_project_scripts,
+                args.no_post_sync,
+                args.show_environment,
                 config_discovery,
                 args.extras,
                 args.groups,
@@ -2382,8 +2687,15 @@ async fn run_project(
                 project_dir,
                 args.lock_check,
                 args.frozen,
+                args.from_bundle,
                 args.dry_run,
+                args.explain,
+                args.check_group_markers,
+                args.no_post_sync,
+                args.repair,
+                args.cascade,
                 args.active,
+                args.allow_conda,
                 args.all_packages,
                 args.package,
                 args.extras,
@@ -2399,6 +2711,7 @@ async fn run_project(
                 args.settings,
                 client_builder.subcommand(vec!["sync".to_owned()]),
                 script,
+                None,
                 globals.installer_metadata,
                 globals.concurrency,
                 config_discovery,
@@ -2416,6 +2729,14 @@ async fn run_project(
             let args = settings::LockSettings::resolve(args, filesystem, environment)?;
             show_settings!(args);
 
+            // Print the lockfile schema, or validate a lockfile, rather than locking the project.
+            if args.schema {
+                return commands::lock_schema(printer);
+            }
+            if let Some(path) = args.validate {
+                return commands::lock_validate(&path, printer).await;
+            }
+
             // Check for conflicts between offline and refresh.
             globals
                 .network_settings
@@ -2428,30 +2749,60 @@ async fn run_project(
                     .combine(Refresh::from(args.settings.upgrade.clone())),
             );
 
+            // If the scripts share a lockfile, they must already include a PEP 723 tag.
+            let shared_lock = if let Some(path) = args.shared_lock {
+                let mut scripts = Vec::with_capacity(args.script.len());
+                for script in &args.script {
+                    match Pep723Script::read(script).await {
+                        Ok(Some(script)) => scripts.push(script),
+                        Ok(None) => {
+                            bail!(
+                                "`{}` does not contain a PEP 723 metadata tag; run `{}` to initialize the script",
+                                script.user_display().cyan(),
+                                format!("uv init --script {}", script.user_display()).green()
+                            )
+                        }
+                        Err(err) => return Err(err.into()),
+                    }
+                }
+                Some(SharedLock { path, scripts })
+            } else {
+                None
+            };
+
             // If the script already exists, use it; otherwise, propagate the file path and we'll
             // initialize it later on.
-            let script = script
-                .map(|script| match script {
-                    Pep723Item::Script(script) => script,
-                    Pep723Item::Stdin(..) => unreachable!("`uv add` does not support stdin"),
-                    Pep723Item::Remote(..) => {
-                        unreachable!("`uv add` does not support remote files")
+            let script = if shared_lock.is_some() {
+                None
+            } else {
+                match script {
+                    Some(Pep723Item::Script(script)) => Some(ScriptPath::Script(script)),
+                    // `uv lock` only reads scripts from local files.
+                    Some(Pep723Item::Stdin(..) | Pep723Item::Remote(..)) | None => {
+                        args.script.into_iter().next().map(ScriptPath::Path)
                     }
-                })
-                .map(ScriptPath::Script)
-                .or(args.script.map(ScriptPath::Path));
+                }
+            };
 
             Box::pin(commands::lock(
                 project_dir,
                 args.lock_check,
                 args.frozen,
                 args.dry_run,
+                args.pin_index_metadata,
+                args.prefer_installed,
+                args.resolve_conflicts,
+                args.report_metadata_versions,
+                args.check_config,
+                args.show_provenance,
+                args.suggest,
                 args.refresh,
                 args.python,
                 args.install_mirrors,
                 args.settings,
                 client_builder.subcommand(vec!["lock".to_owned()]),
                 script,
+                shared_lock,
                 globals.python_preference,
                 globals.python_downloads,
                 globals.concurrency,
@@ -2601,6 +2952,7 @@ async fn run_project(
                 args.dependency_type,
                 args.raw,
                 args.bounds,
+                args.show_diff,
                 args.indexes,
                 args.rev,
                 args.tag,
@@ -2658,6 +3010,7 @@ async fn run_project(
                 args.no_sync,
                 args.packages,
                 args.dependency_type,
+                args.show_diff,
                 args.package,
                 args.python,
                 args.install_mirrors,
@@ -2707,6 +3060,7 @@ async fn run_project(
                 args.package,
                 explicit_project,
                 args.dry_run,
+                args.show_diff,
                 args.lock_check,
                 args.frozen,
                 args.active,
@@ -2748,8 +3102,11 @@ async fn run_project(
                 args.groups,
                 args.lock_check,
                 args.frozen,
+                args.lockfile,
+                args.no_validate,
                 args.universal,
                 args.format,
+                args.duplicates,
                 args.depth,
                 args.prune,
                 args.package,
@@ -2796,14 +3153,21 @@ async fn run_project(
                 args.all_packages,
                 args.package,
                 args.prune,
+                args.allow_prune_root,
                 args.hashes,
                 args.install_options,
                 args.output_file,
+                args.relative_to,
+                args.bundle,
+                args.python_platforms,
+                args.split_output_dir,
                 args.extras,
                 args.groups,
                 args.editable,
                 args.lock_check,
                 args.frozen,
+                args.lockfile,
+                args.no_validate,
                 args.include_annotations,
                 args.include_header,
                 args.include_index_url,
@@ -2996,6 +3360,45 @@ fn suggest_subcommand(err: &mut Error) {
     }
 }
 
+/// Abort any running builds when the user presses Ctrl-C, then exit.
+///
+/// If a child process is running (e.g., via `uv run`), signal handling is left to
+/// [`child::run_to_completion`] instead.
+async fn abort_builds_on_interrupt(printer: Printer) {
+    while tokio::signal::ctrl_c().await.is_ok() {
+        if child::is_running_child() {
+            continue;
+        }
+
+        let aborted = uv_build_frontend::abort_running_builds();
+        if !aborted.is_empty() {
+            let _ = writeln!(
+                printer.stderr_important(),
+                "{}{} Aborted {}: {}",
+                "error".red().bold(),
+                ":".bold(),
+                if aborted.len() == 1 {
+                    "build"
+                } else {
+                    "builds"
+                },
+                aborted
+                    .iter()
+                    .map(|name| name.cyan().to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
+
+        #[expect(clippy::exit, clippy::cast_possible_wrap)]
+        std::process::exit(if cfg!(windows) {
+            0xC000_013A_u32 as i32
+        } else {
+            130
+        });
+    }
+}
+
 /// The main entry point for a uv invocation.
 ///
 /// # Usage
@@ -3074,6 +3477,13 @@ where
         .join()
         .expect("Tokio executor failed, was there a panic?");
 
+    // Report the timings regardless of the outcome, but prefer the command's own error.
+    let report = timings::report(printer);
+    let result = result.and_then(|status| {
+        report?;
+        Ok(status)
+    });
+
     match result {
         Ok(code) => code.into(),
         Err(err) => {
@@ -3083,7 +3493,11 @@ where
                 Err(err)
                     if matches!(
                         err.downcast_ref::<ProjectError>(),
-                        Some(ProjectError::LockFormat(..))
+                        Some(
+                            ProjectError::LockFormat(..)
+                                | ProjectError::IndexMetadataMismatch(..)
+                                | ProjectError::RemoteFileMismatch(..)
+                        )
                     ) =>
                 {
                     UvError::User(err)
@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+use std::io::Write;
 use std::str::FromStr;
 
 use anyhow::Context;
@@ -6,6 +8,7 @@ use tracing_durations_export::{
     DurationsLayer, DurationsLayerBuilder, DurationsLayerDropGuard, plot::PlotConfig,
 };
 use tracing_subscriber::filter::Directive;
+use tracing_subscriber::fmt::MakeWriter;
 use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::util::SubscriberInitExt;
 use tracing_subscriber::{EnvFilter, Layer, Registry};
@@ -14,6 +17,9 @@ use tracing_tree::time::Uptime;
 
 use uv_cli::ColorChoice;
 use uv_logging::{UvFormat, uv_fields};
+use uv_redacted::redact_urls;
+
+use crate::timings::TimingsLayer;
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum Level {
@@ -33,6 +39,7 @@ pub(crate) enum Level {
 pub(crate) fn setup_logging(
     level: Level,
     durations_layer: Option<impl Layer<Registry> + Send + Sync>,
+    timings_layer: Option<TimingsLayer>,
     color: ColorChoice,
     detailed_logging: bool,
 ) -> anyhow::Result<()> {
@@ -64,6 +71,12 @@ pub(crate) fn setup_logging(
                 .with_target("", tracing::level_filters::LevelFilter::INFO),
         )
     });
+    let timings_layer = timings_layer.map(|timings_layer| {
+        timings_layer.with_filter(
+            tracing_subscriber::filter::Targets::new()
+                .with_target("", tracing::level_filters::LevelFilter::INFO),
+        )
+    });
     let filter = EnvFilter::builder()
         .with_default_directive(default_directive)
         .from_env()
@@ -85,12 +98,16 @@ pub(crate) fn setup_logging(
             ColorChoice::Never => (false, anstream::ColorChoice::Never),
             ColorChoice::Auto => unreachable!("anstream can't return auto as choice"),
         };
-    let writer = std::sync::Mutex::new(anstream::AutoStream::new(std::io::stderr(), color_choice));
+    let writer = RedactUrls(std::sync::Mutex::new(anstream::AutoStream::new(
+        std::io::stderr(),
+        color_choice,
+    )));
 
     if detailed_logging {
         // Regardless of the tracing level, include the uptime and target for each message.
         tracing_subscriber::registry()
             .with(durations_layer)
+            .with(timings_layer)
             .with(
                 HierarchicalLayer::default()
                     .with_targets(true)
@@ -103,6 +120,7 @@ pub(crate) fn setup_logging(
     } else {
         tracing_subscriber::registry()
             .with(durations_layer)
+            .with(timings_layer)
             .with(
                 tracing_subscriber::fmt::layer()
                     .event_format(UvFormat::default())
@@ -117,6 +135,40 @@ pub(crate) fn setup_logging(
     Ok(())
 }
 
+/// A [`MakeWriter`] that masks credentials in any URLs before they're written to the log.
+///
+/// Log messages are formatted by many crates, not all of which use [`uv_redacted::DisplaySafeUrl`]
+/// (e.g., `reqwest` errors include the request URL verbatim), so we redact the rendered output.
+struct RedactUrls<M>(M);
+
+impl<'a, M: MakeWriter<'a>> MakeWriter<'a> for RedactUrls<M> {
+    type Writer = RedactUrlsWriter<M::Writer>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        RedactUrlsWriter(self.0.make_writer())
+    }
+}
+
+struct RedactUrlsWriter<W>(W);
+
+impl<W: Write> Write for RedactUrlsWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        // Each event is formatted into a buffer and written at once, so URLs are not split across
+        // calls.
+        if let Ok(message) = std::str::from_utf8(buf)
+            && let Cow::Owned(redacted) = redact_urls(message)
+        {
+            self.0.write_all(redacted.as_bytes())?;
+            return Ok(buf.len());
+        }
+        self.0.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.0.flush()
+    }
+}
+
 /// Setup the `TRACING_DURATIONS_FILE` environment variable to enable tracing durations.
 #[cfg(feature = "tracing-durations-export")]
 pub(crate) fn setup_durations(
@@ -15,17 +15,17 @@ use uv_cache::{CacheArgs, Refresh};
 use uv_cli::comma::CommaSeparatedRequirements;
 use uv_cli::{
     AddArgs, AuditArgs, AuditOutputFormat, AuthLoginArgs, AuthLogoutArgs, AuthTokenArgs,
-    ColorChoice, ExternalCommand, GlobalArgs, InitArgs, ListFormat, LockArgs, Maybe, MetadataArgs,
-    PipCheckArgs, PipCompileArgs, PipFreezeArgs, PipInstallArgs, PipListArgs, PipShowArgs,
-    PipSyncArgs, PipTreeArgs, PipUninstallArgs, PythonFindArgs, PythonInstallArgs, PythonListArgs,
-    PythonListFormat, PythonPinArgs, PythonUninstallArgs, PythonUpgradeArgs, RemoveArgs, RunArgs,
-    SyncArgs, SyncFormat, ToolDirArgs, ToolInstallArgs, ToolListArgs, ToolRunArgs,
-    ToolUninstallArgs, TreeArgs, TreeFormat, UpgradeArgs, VenvArgs, VersionArgs, VersionBumpSpec,
-    VersionFormat,
+    ColorChoice, DryRunFormat, ExternalCommand, GlobalArgs, InitArgs, ListFormat, LockArgs, Maybe,
+    MetadataArgs, PipCheckArgs, PipCompileArgs, PipFreezeArgs, PipInstallArgs, PipListArgs,
+    PipShowArgs, PipSyncArgs, PipTreeArgs, PipUninstallArgs, PythonFindArgs, PythonInstallArgs,
+    PythonListArgs, PythonListFormat, PythonPinArgs, PythonUninstallArgs, PythonUpgradeArgs,
+    RemoveArgs, RunArgs, ShowFormat, SyncArgs, SyncFormat, ToolDirArgs, ToolInstallArgs,
+    ToolListArgs, ToolRunArgs, ToolUninstallArgs, TreeArgs, TreeFormat, UpgradeArgs, VenvArgs,
+    VersionArgs, VersionBumpSpec, VersionFormat, WithLock, WorkspaceAddArgs,
 };
 use uv_cli::{
     AuthorFrom, BuildArgs, CheckArgs, ExportArgs, FormatArgs, PublishArgs, PythonDirArgs,
-    ResolverInstallerArgs, ToolUpgradeArgs,
+    PythonPlatformListArgs, ResolverInstallerArgs, ToolUpgradeArgs,
     options::{
         Flag, FlagSource, check_conflicts, flag, indexes_from_args, resolve_flag,
         resolve_flag_pair, resolver_installer_options, resolver_installer_options_with_indexes,
@@ -34,23 +34,27 @@ use uv_cli::{
 };
 use uv_client::Connectivity;
 use uv_configuration::{
-    BuildIsolation, BuildOptions, Concurrency, DependencyGroups, DevMode, DryRun, EditableMode,
-    EnvFile, ExcludeDependency, ExportFormat, ExtrasSpecification, GitLfsSetting, HashCheckingMode,
-    IndexStrategy, InstallOptions, KeyringProviderType, NoBinary, NoBuild, NoSources, Override,
-    PackageOverride, PipCompileFormat, ProjectBuildBackend, ProxyUrl, Reinstall, RequiredVersion,
-    TargetTriple, TrustedHost, TrustedPublishing, Upgrade, VersionControlSystem,
+    BuildIsolation, BuildOptions, CiProvider, Concurrency, DependencyGroups, DevMode, DryRun,
+    EditableMode, EnvFile, ExcludeDependency, ExportFormat, ExtrasSpecification, GitLfsSetting,
+    HashCheckingMode, IndexStrategy, InstallOptions, KeyringProviderType, NoBinary, NoBuild,
+    NoSources, Override, PackageOverride, PipCompileFormat, ProjectBuildBackend, ProxyUrl,
+    Reinstall, RequiredVersion, ShowDiff, TargetTriple, TrustedHost, TrustedPublishing, Upgrade,
+    VersionControlSystem,
 };
 use uv_distribution_types::{
-    ConfigSettings, DependencyMetadata, ExtraBuildVariables, Index, IndexLocations, IndexUrl,
-    PackageConfigSettings, Requirement,
+    BuildEnvPassthrough, ConfigSettings, DependencyMetadata, ExtraBuildVariables, Index,
+    IndexLocations, IndexUrl, PackageConfigSettings, Requirement,
 };
 use uv_install_wheel::LinkMode;
 use uv_normalize::{ExtraName, PackageName, PipGroupName};
 use uv_pep440::Version;
 use uv_pep508::{MarkerTree, RequirementOrigin};
 use uv_preview::{Preview, PreviewFeature};
-use uv_pypi_types::SupportedEnvironments;
-use uv_python::{Prefix, PythonDownloads, PythonPreference, PythonVersion, Target};
+use uv_pypi_types::{MetadataVersion, SupportedEnvironments};
+use uv_python::downloads::SourceBuildOptions;
+use uv_python::{
+    Prefix, PythonDownloads, PythonPreference, PythonVersion, Target, WindowsArm64Fallback,
+};
 use uv_redacted::DisplaySafeUrl;
 use uv_resolver::{
     AnnotationStyle, DependencyMode, ExcludeNewer, ExcludeNewerOverride, ExcludeNewerPackage,
@@ -88,6 +92,7 @@ pub(crate) struct GlobalSettings {
     pub(crate) preview: Preview,
     pub(crate) python_preference: PythonPreference,
     pub(crate) python_downloads: PythonDownloads,
+    pub(crate) windows_arm64_fallback: WindowsArm64Fallback,
     pub(crate) no_progress: bool,
     pub(crate) installer_metadata: bool,
 }
@@ -146,6 +151,9 @@ impl GlobalSettings {
             .combine(env(env::UV_PYTHON_DOWNLOADS))
             .combine(workspace.and_then(|workspace| workspace.globals.python_downloads))
             .unwrap_or_default(),
+            windows_arm64_fallback: workspace
+                .and_then(|workspace| workspace.globals.windows_arm64_fallback)
+                .unwrap_or_default(),
             // Disable the progress bar with `RUST_LOG` to avoid progress fragments interleaving
             // with log messages.
             no_progress: resolve_flag(args.no_progress, "no-progress", environment.no_progress)
@@ -277,6 +285,7 @@ pub(crate) struct NetworkSettings {
     pub(super) https_proxy: Option<ProxyUrl>,
     pub(super) no_proxy: Option<Vec<String>>,
     pub(super) allow_insecure_host: Vec<TrustedHost>,
+    pub(super) strict_index_tracking: bool,
     pub(super) read_timeout: Duration,
     pub(super) connect_timeout: Duration,
     pub(super) retries: u32,
@@ -396,6 +405,7 @@ impl NetworkSettings {
             https_proxy,
             no_proxy,
             allow_insecure_host,
+            strict_index_tracking: args.strict_index_tracking,
             read_timeout: environment.http_read_timeout,
             connect_timeout: environment.http_connect_timeout,
             retries: environment.http_retries,
@@ -450,6 +460,7 @@ pub(crate) struct InitSettings {
     pub(crate) description: Option<String>,
     pub(crate) no_description: bool,
     pub(crate) vcs: Option<VersionControlSystem>,
+    pub(crate) ci: CiProvider,
     pub(crate) build_backend: Option<ProjectBuildBackend>,
     pub(crate) no_readme: bool,
     pub(crate) author_from: Option<AuthorFrom>,
@@ -480,6 +491,7 @@ impl InitSettings {
             description,
             no_description,
             vcs,
+            ci,
             build_backend,
             no_readme,
             author_from,
@@ -498,104 +510,200 @@ impl InitSettings {
 
         let no_description = no_description || (bare && description.is_none());
 
-        let (kind, package) = if preview.is_enabled(PreviewFeature::PackagedInit) {
-            if r#virtual && lib {
-                bail!("`--virtual` and `--lib` are mutually exclusive");
-            }
-            if r#virtual && build_backend.is_some() {
-                bail!("`--virtual` and `--build-backend` are mutually exclusive");
-            }
+        let (kind, package) = resolve_init_kind(
+            script,
+            bare,
+            r#virtual,
+            app,
+            lib,
+            package,
+            no_package,
+            build_backend,
+            preview,
+        )?;
 
-            let package_flag = flag(
-                package || build_backend.is_some(),
-                no_package || r#virtual,
-                "virtual",
-            )?;
-
-            let kind = if script {
-                InitKind::Script
-            } else if bare {
-                if package_flag == Some(true) || lib {
-                    InitKind::Project(InitProjectKind::BareWithBuildSystem)
-                } else {
-                    InitKind::Project(InitProjectKind::Bare)
-                }
+        Ok(Self {
+            path,
+            name,
+            package,
+            kind,
+            bare,
+            description,
+            no_description,
+            vcs: vcs.or(bare.then_some(VersionControlSystem::None)),
+            ci: ci.unwrap_or_default(),
+            build_backend,
+            no_readme,
+            author_from,
+            pin_python: flag(pin_python, no_pin_python, "pin-python")?.unwrap_or(!bare),
+            no_workspace,
+            python: python.and_then(Maybe::into_option),
+            install_mirrors: environment
+                .install_mirrors
+                .combine(filesystem_install_mirrors),
+        })
+    }
+}
+
+/// Resolve the kind of project to initialize, and whether it should be packaged, from the
+/// `uv init` project flags.
+#[expect(clippy::fn_params_excessive_bools)]
+fn resolve_init_kind(
+    script: bool,
+    bare: bool,
+    r#virtual: bool,
+    app: bool,
+    lib: bool,
+    package: bool,
+    no_package: bool,
+    build_backend: Option<ProjectBuildBackend>,
+    preview: Preview,
+) -> Result<(InitKind, bool)> {
+    if preview.is_enabled(PreviewFeature::PackagedInit) {
+        if r#virtual && lib {
+            bail!("`--virtual` and `--lib` are mutually exclusive");
+        }
+        if r#virtual && build_backend.is_some() {
+            bail!("`--virtual` and `--build-backend` are mutually exclusive");
+        }
+
+        let package_flag = flag(
+            package || build_backend.is_some(),
+            no_package || r#virtual,
+            "virtual",
+        )?;
+
+        let kind = if script {
+            InitKind::Script
+        } else if bare {
+            if package_flag == Some(true) || lib {
+                InitKind::Project(InitProjectKind::BareWithBuildSystem)
             } else {
-                // Merge `--app` and `--lib`.
-                let app_lib_kind = match (app, lib) {
-                    (false, false) => InitProjectKind::ApplicationWithLibrary,
-                    (true, false) => InitProjectKind::Application,
-                    (false, true) => InitProjectKind::Library,
-                    (true, true) => bail!("`app` and `lib` are mutually exclusive"),
-                };
-
-                // Apply overrides from `--package`/`--no-package`.
-                let app_lib_kind = match (app_lib_kind, package_flag) {
-                    (InitProjectKind::ApplicationWithLibrary, None | Some(true)) => {
-                        InitProjectKind::ApplicationWithLibrary
-                    }
-                    (InitProjectKind::ApplicationWithLibrary, Some(false)) => {
-                        InitProjectKind::Application
-                    }
-                    // The user specifically asked for `--app`, so no library.
-                    (InitProjectKind::Application, None | Some(false)) => {
-                        InitProjectKind::Application
-                    }
-                    (InitProjectKind::Application, Some(true)) => {
-                        InitProjectKind::ApplicationWithLibrary
-                    }
-                    (InitProjectKind::Library, None | Some(true)) => InitProjectKind::Library,
-                    (InitProjectKind::Library, Some(false)) => {
-                        bail!("`lib` and `no_package` are mutually exclusive");
-                    }
-                    (InitProjectKind::Bare | InitProjectKind::BareWithBuildSystem, _) => {
-                        unreachable!()
-                    }
-                    (InitProjectKind::ApplicationOld | InitProjectKind::LibraryOld, _) => {
-                        unreachable!()
-                    }
-                };
-                InitKind::Project(app_lib_kind)
+                InitKind::Project(InitProjectKind::Bare)
+            }
+        } else {
+            // Merge `--app` and `--lib`.
+            let app_lib_kind = match (app, lib) {
+                (false, false) => InitProjectKind::ApplicationWithLibrary,
+                (true, false) => InitProjectKind::Application,
+                (false, true) => InitProjectKind::Library,
+                (true, true) => bail!("`app` and `lib` are mutually exclusive"),
             };
 
-            // Packaging is encoded in `kind`; `package` is only consumed by the old paths.
-            (kind, false)
-        } else {
-            // TODO(konsti): Remove when stabilizing packaged-init.
-            let kind = match (app, lib, script) {
-                (true, false, false) => InitKind::Project(InitProjectKind::ApplicationOld),
-                (false, true, false) => InitKind::Project(InitProjectKind::LibraryOld),
-                (false, false, true) => InitKind::Script,
-                (false, false, false) => InitKind::Project(InitProjectKind::ApplicationOld),
-                (_, _, _) => bail!("`app`, `lib`, and `script` are mutually exclusive"),
+            // Apply overrides from `--package`/`--no-package`.
+            let app_lib_kind = match (app_lib_kind, package_flag) {
+                (InitProjectKind::ApplicationWithLibrary, None | Some(true)) => {
+                    InitProjectKind::ApplicationWithLibrary
+                }
+                (InitProjectKind::ApplicationWithLibrary, Some(false)) => {
+                    InitProjectKind::Application
+                }
+                // The user specifically asked for `--app`, so no library.
+                (InitProjectKind::Application, None | Some(false)) => InitProjectKind::Application,
+                (InitProjectKind::Application, Some(true)) => {
+                    InitProjectKind::ApplicationWithLibrary
+                }
+                (InitProjectKind::Library, None | Some(true)) => InitProjectKind::Library,
+                (InitProjectKind::Library, Some(false)) => {
+                    bail!("`lib` and `no_package` are mutually exclusive");
+                }
+                (InitProjectKind::Bare | InitProjectKind::BareWithBuildSystem, _) => {
+                    unreachable!()
+                }
+                (InitProjectKind::ApplicationOld | InitProjectKind::LibraryOld, _) => {
+                    unreachable!()
+                }
             };
+            InitKind::Project(app_lib_kind)
+        };
 
-            let package = flag(
-                package || build_backend.is_some(),
-                no_package || r#virtual,
-                "virtual",
-            )?
-            .unwrap_or(matches!(
-                kind,
-                InitKind::Project(InitProjectKind::LibraryOld)
-            ));
-            (kind, package)
+        // Packaging is encoded in `kind`; `package` is only consumed by the old paths.
+        Ok((kind, false))
+    } else {
+        // TODO(konsti): Remove when stabilizing packaged-init.
+        let kind = match (app, lib, script) {
+            (true, false, false) => InitKind::Project(InitProjectKind::ApplicationOld),
+            (false, true, false) => InitKind::Project(InitProjectKind::LibraryOld),
+            (false, false, true) => InitKind::Script,
+            (false, false, false) => InitKind::Project(InitProjectKind::ApplicationOld),
+            (_, _, _) => bail!("`app`, `lib`, and `script` are mutually exclusive"),
         };
 
+        let package = flag(
+            package || build_backend.is_some(),
+            no_package || r#virtual,
+            "virtual",
+        )?
+        .unwrap_or(matches!(
+            kind,
+            InitKind::Project(InitProjectKind::LibraryOld)
+        ));
+        Ok((kind, package))
+    }
+}
+
+/// The resolved settings to use for a `workspace add` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct WorkspaceAddSettings {
+    pub(crate) path: PathBuf,
+    pub(crate) name: Option<PackageName>,
+    pub(crate) package: bool,
+    pub(crate) kind: InitKind,
+    pub(crate) description: Option<String>,
+    pub(crate) build_backend: Option<ProjectBuildBackend>,
+    pub(crate) no_readme: bool,
+    pub(crate) r#for: Option<PackageName>,
+    pub(crate) python: Option<String>,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+}
+
+impl WorkspaceAddSettings {
+    /// Resolve the [`WorkspaceAddSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(
+        args: WorkspaceAddArgs,
+        filesystem: Option<FilesystemOptions>,
+        environment: EnvironmentOptions,
+        preview: Preview,
+    ) -> Result<Self> {
+        let WorkspaceAddArgs {
+            path,
+            name,
+            package,
+            no_package,
+            app,
+            lib,
+            description,
+            build_backend,
+            no_readme,
+            r#for,
+            python,
+        } = args;
+
+        let filesystem_install_mirrors = filesystem
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
+        let (kind, package) = resolve_init_kind(
+            false,
+            false,
+            false,
+            app,
+            lib,
+            package,
+            no_package,
+            build_backend,
+            preview,
+        )?;
+
         Ok(Self {
             path,
             name,
             package,
             kind,
-            bare,
             description,
-            no_description,
-            vcs: vcs.or(bare.then_some(VersionControlSystem::None)),
             build_backend,
             no_readme,
-            author_from,
-            pin_python: flag(pin_python, no_pin_python, "pin-python")?.unwrap_or(!bare),
-            no_workspace,
+            r#for,
             python: python.and_then(Maybe::into_option),
             install_mirrors: environment
                 .install_mirrors
@@ -686,12 +794,17 @@ pub(crate) struct RunSettings {
     pub(crate) with: Vec<String>,
     pub(crate) with_editable: Vec<String>,
     pub(crate) with_requirements: Vec<PathBuf>,
+    pub(crate) with_lock: Option<WithLock>,
     pub(crate) isolated: bool,
     pub(crate) show_resolution: bool,
     pub(crate) all_packages: bool,
     pub(crate) package: Option<PackageName>,
     pub(crate) no_project: bool,
+    pub(crate) no_project_scripts: bool,
+    pub(crate) no_post_sync: bool,
+    pub(crate) show_environment: bool,
     pub(crate) active: Option<bool>,
+    pub(crate) allow_conda: bool,
     pub(crate) no_sync: bool,
     pub(crate) python: Option<String>,
     pub(crate) python_platform: Option<TargetTriple>,
@@ -737,13 +850,18 @@ impl RunSettings {
             exact,
             script: _,
             gui_script: _,
+            no_project_scripts,
+            no_post_sync,
+            show_environment,
             command: _,
             with,
             with_editable,
             with_requirements,
+            with_lock,
             isolated,
             active,
             no_active,
+            allow_conda,
             no_sync,
             locked,
             frozen,
@@ -842,13 +960,19 @@ impl RunSettings {
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
+            with_lock,
             isolated,
             show_resolution,
             all_packages,
             package,
             no_project,
+            no_project_scripts,
+            no_post_sync: resolve_flag(no_post_sync, "no-post-sync", environment.no_post_sync)
+                .is_enabled(),
+            show_environment,
             no_sync: no_sync.is_enabled(),
             active: flag(active, no_active, "active")?,
+            allow_conda,
             python: python.and_then(Maybe::into_option),
             python_platform,
             refresh: Refresh::try_from(refresh)?,
@@ -872,9 +996,11 @@ impl RunSettings {
 pub(crate) struct ToolRunSettings {
     pub(crate) command: Option<ExternalCommand>,
     pub(crate) from: Option<String>,
+    pub(crate) entrypoint: Option<String>,
     pub(crate) with: Vec<String>,
     pub(crate) with_requirements: Vec<PathBuf>,
     pub(crate) with_editable: Vec<String>,
+    pub(crate) with_executable: Vec<PathBuf>,
     pub(crate) constraints: Vec<PathBuf>,
     pub(crate) overrides: Vec<PathBuf>,
     pub(crate) build_constraints: Vec<PathBuf>,
@@ -902,9 +1028,11 @@ impl ToolRunSettings {
         let ToolRunArgs {
             command,
             from,
+            entrypoint,
             with,
             with_editable,
             with_requirements,
+            with_executable,
             constraints,
             overrides,
             build_constraints,
@@ -979,6 +1107,7 @@ impl ToolRunSettings {
         Ok(Self {
             command,
             from,
+            entrypoint,
             with: with
                 .into_iter()
                 .flat_map(CommaSeparatedRequirements::into_iter)
@@ -991,6 +1120,7 @@ impl ToolRunSettings {
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
+            with_executable,
             constraints: constraints
                 .into_iter()
                 .filter_map(Maybe::into_option)
@@ -1040,6 +1170,7 @@ pub(crate) struct ToolInstallSettings {
     pub(crate) options: ResolverInstallerOptions,
     pub(crate) settings: ResolverInstallerSettings,
     pub(crate) force: bool,
+    pub(crate) keep_options: bool,
     pub(crate) editable: bool,
     pub(crate) install_mirrors: PythonInstallMirrors,
 }
@@ -1066,6 +1197,7 @@ impl ToolInstallSettings {
             lfs,
             installer,
             force,
+            keep_options,
             build,
             refresh,
             python,
@@ -1135,6 +1267,7 @@ impl ToolInstallSettings {
             python: python.and_then(Maybe::into_option),
             python_platform,
             force,
+            keep_options,
             editable,
             refresh: Refresh::try_from(refresh)?,
             options,
@@ -1368,6 +1501,8 @@ pub(crate) struct PythonListSettings {
     pub(crate) all_arches: bool,
     pub(crate) all_versions: bool,
     pub(crate) show_urls: bool,
+    pub(crate) verify: bool,
+    pub(crate) show_usage: bool,
     pub(crate) output_format: PythonListFormat,
     pub(crate) python_downloads_json_url: Option<String>,
     pub(crate) python_install_mirror: Option<String>,
@@ -1390,6 +1525,8 @@ impl PythonListSettings {
             only_installed,
             only_downloads,
             show_urls,
+            verify,
+            show_usage,
             output_format,
             python_downloads_json_url: python_downloads_json_url_arg,
         } = args;
@@ -1442,6 +1579,8 @@ impl PythonListSettings {
             all_arches,
             all_versions,
             show_urls,
+            verify,
+            show_usage,
             output_format,
             python_downloads_json_url,
             python_install_mirror,
@@ -1466,6 +1605,31 @@ impl PythonDirSettings {
     }
 }
 
+/// The resolved settings to use for a `python platform list` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PythonPlatformListSettings {
+    pub(crate) python_platform: Option<TargetTriple>,
+    pub(crate) python_version: PythonVersion,
+}
+
+impl PythonPlatformListSettings {
+    /// Resolve the [`PythonPlatformListSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(
+        args: PythonPlatformListArgs,
+        _filesystem: Option<FilesystemOptions>,
+    ) -> Self {
+        let PythonPlatformListArgs {
+            python_platform,
+            python_version,
+        } = args;
+
+        Self {
+            python_platform,
+            python_version,
+        }
+    }
+}
+
 /// The resolved settings to use for a `python install` invocation.
 #[derive(Debug, Clone)]
 pub(crate) struct PythonInstallSettings {
@@ -1480,6 +1644,8 @@ pub(crate) struct PythonInstallSettings {
     pub(crate) pypy_install_mirror: Option<String>,
     pub(crate) python_downloads_json_url: Option<String>,
     pub(crate) default: bool,
+    pub(crate) verify: bool,
+    pub(crate) build_from_source: Option<SourceBuildOptions>,
     pub(crate) compile_bytecode: bool,
 }
 
@@ -1519,6 +1685,9 @@ impl PythonInstallSettings {
             pypy_mirror: _,
             python_downloads_json_url: _,
             default,
+            verify,
+            build_from_source,
+            enable_optimizations,
             compile_bytecode,
         } = args;
 
@@ -1547,6 +1716,10 @@ impl PythonInstallSettings {
             pypy_install_mirror,
             python_downloads_json_url,
             default,
+            verify,
+            build_from_source: build_from_source.then_some(SourceBuildOptions {
+                enable_optimizations,
+            }),
             compile_bytecode: flag(
                 compile_bytecode.compile_bytecode,
                 compile_bytecode.no_compile_bytecode,
@@ -1731,6 +1904,7 @@ pub(crate) struct PythonPinSettings {
     pub(crate) no_project: bool,
     pub(crate) global: bool,
     pub(crate) rm: bool,
+    pub(crate) show_resolution: bool,
     pub(crate) install_mirrors: PythonInstallMirrors,
 }
 
@@ -1748,6 +1922,7 @@ impl PythonPinSettings {
             no_project,
             global,
             rm,
+            show_resolution,
             python_downloads_json_url,
         } = args;
 
@@ -1768,6 +1943,7 @@ impl PythonPinSettings {
             no_project,
             global,
             rm,
+            show_resolution,
             install_mirrors,
         })
     }
@@ -1779,9 +1955,16 @@ impl PythonPinSettings {
 pub(crate) struct SyncSettings {
     pub(super) lock_check: LockCheck,
     pub(super) frozen: Option<FrozenSource>,
+    pub(super) from_bundle: Option<PathBuf>,
     pub(super) dry_run: DryRun,
+    pub(super) explain: Vec<PackageName>,
+    pub(super) check_group_markers: bool,
+    pub(super) no_post_sync: bool,
+    pub(super) repair: bool,
+    pub(super) cascade: bool,
     pub(super) script: Option<PathBuf>,
     pub(super) active: Option<bool>,
+    pub(super) allow_conda: bool,
     pub(super) extras: ExtrasSpecification,
     pub(super) groups: DependencyGroups,
     pub(super) editable: Option<EditableMode>,
@@ -1831,11 +2014,16 @@ impl SyncSettings {
             only_install_local,
             no_install_package,
             only_install_package,
+            exclude_package,
+            exclude_unchecked,
             locked,
             frozen,
+            from_bundle,
             active,
             no_active,
+            allow_conda,
             dry_run,
+            explain,
             installer,
             build,
             refresh,
@@ -1846,6 +2034,10 @@ impl SyncSettings {
             python_platform,
             check,
             no_check,
+            check_group_markers,
+            no_post_sync,
+            repair,
+            cascade,
             output_format,
         } = args;
         let filesystem_install_mirrors = filesystem
@@ -1935,9 +2127,17 @@ impl SyncSettings {
             output_format,
             lock_check: resolve_lock_check(locked),
             frozen: resolve_frozen(frozen),
+            from_bundle,
             dry_run,
+            explain,
+            check_group_markers,
+            no_post_sync: resolve_flag(no_post_sync, "no-post-sync", environment.no_post_sync)
+                .is_enabled(),
+            repair,
+            cascade,
             script,
             active: flag(active, no_active, "active")?,
+            allow_conda,
             extras: ExtrasSpecification::from_args(
                 extra.unwrap_or_default(),
                 no_extra,
@@ -1972,7 +2172,8 @@ impl SyncSettings {
                 only_install_local,
                 no_install_package,
                 only_install_package,
-            ),
+            )
+            .with_exclude_package(exclude_package, exclude_unchecked),
             modifications: if flag(exact, inexact, "inexact")?.unwrap_or(true) {
                 Modifications::Exact
             } else {
@@ -1998,7 +2199,18 @@ pub(crate) struct LockSettings {
     pub(crate) lock_check: LockCheck,
     pub(crate) frozen: Option<FrozenSource>,
     pub(crate) dry_run: DryRun,
-    pub(crate) script: Option<PathBuf>,
+    pub(crate) pin_index_metadata: bool,
+    #[expect(clippy::option_option)]
+    pub(crate) prefer_installed: Option<Option<PathBuf>>,
+    pub(crate) resolve_conflicts: bool,
+    pub(crate) report_metadata_versions: bool,
+    pub(crate) check_config: bool,
+    pub(crate) show_provenance: Option<PackageName>,
+    pub(crate) suggest: bool,
+    pub(crate) script: Vec<PathBuf>,
+    pub(crate) shared_lock: Option<PathBuf>,
+    pub(crate) schema: bool,
+    pub(crate) validate: Option<PathBuf>,
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) refresh: Refresh,
@@ -2017,13 +2229,27 @@ impl LockSettings {
             locked,
             check_exists,
             dry_run,
+            pin_index_metadata,
+            prefer_installed,
+            resolve_conflicts,
+            report_metadata_versions,
+            check_config,
+            show_provenance,
+            suggest,
             script,
+            shared_lock,
+            schema,
+            validate,
             resolver,
             build,
             refresh,
             python,
         } = args;
 
+        if script.len() > 1 && shared_lock.is_none() {
+            bail!("Locking multiple scripts requires `--shared-lock`");
+        }
+
         let filesystem_install_mirrors = filesystem
             .clone()
             .map(|fs| fs.install_mirrors.clone())
@@ -2046,7 +2272,17 @@ impl LockSettings {
             lock_check,
             frozen: resolve_frozen(frozen),
             dry_run: DryRun::from_args(dry_run),
+            pin_index_metadata,
+            prefer_installed,
+            resolve_conflicts,
+            report_metadata_versions,
+            check_config,
+            show_provenance,
+            suggest,
             script,
+            shared_lock,
+            schema,
+            validate,
             python: python.and_then(Maybe::into_option),
             refresh: Refresh::try_from(refresh)?,
             settings: ResolverSettings::combine(
@@ -2185,6 +2421,7 @@ pub(crate) struct AddSettings {
     pub(crate) extras: Vec<ExtraName>,
     pub(crate) raw: bool,
     pub(crate) bounds: Option<AddBoundsKind>,
+    pub(crate) show_diff: ShowDiff,
     pub(crate) rev: Option<String>,
     pub(crate) tag: Option<String>,
     pub(crate) branch: Option<String>,
@@ -2229,6 +2466,8 @@ impl AddSettings {
             extra,
             raw,
             bounds,
+            show_diff,
+            diff_only,
             rev,
             tag,
             branch,
@@ -2421,6 +2660,7 @@ impl AddSettings {
             dependency_type,
             raw,
             bounds,
+            show_diff: ShowDiff::from_args(show_diff, diff_only),
             rev,
             tag,
             branch,
@@ -2467,6 +2707,7 @@ pub(crate) struct RemoveSettings {
     pub(super) no_sync: bool,
     pub(super) packages: Vec<PackageName>,
     pub(super) dependency_type: DependencyType,
+    pub(super) show_diff: ShowDiff,
     pub(super) package: Option<PackageName>,
     pub(super) script: Option<PathBuf>,
     pub(super) python: Option<String>,
@@ -2488,6 +2729,8 @@ impl RemoveSettings {
             optional,
             packages,
             group,
+            show_diff,
+            diff_only,
             no_sync,
             locked,
             frozen,
@@ -2544,6 +2787,7 @@ impl RemoveSettings {
             no_sync: no_sync.is_enabled(),
             packages,
             dependency_type,
+            show_diff: ShowDiff::from_args(show_diff, diff_only),
             package,
             script,
             python: python.and_then(Maybe::into_option),
@@ -2569,6 +2813,7 @@ pub(crate) struct VersionSettings {
     pub(crate) short: bool,
     pub(crate) output_format: VersionFormat,
     pub(crate) dry_run: bool,
+    pub(crate) show_diff: ShowDiff,
     pub(crate) lock_check: LockCheck,
     pub(crate) frozen: Option<FrozenSource>,
     pub(crate) active: Option<bool>,
@@ -2594,6 +2839,8 @@ impl VersionSettings {
             short,
             output_format,
             dry_run,
+            show_diff,
+            diff_only,
             no_sync,
             locked,
             frozen,
@@ -2630,6 +2877,7 @@ impl VersionSettings {
             short,
             output_format,
             dry_run,
+            show_diff: ShowDiff::from_args(show_diff, diff_only),
             lock_check: resolve_lock_check(locked),
             frozen: resolve_frozen(frozen),
             active: flag(active, no_active, "active")?,
@@ -2656,8 +2904,11 @@ pub(crate) struct TreeSettings {
     pub(super) groups: DependencyGroups,
     pub(super) lock_check: LockCheck,
     pub(super) frozen: Option<FrozenSource>,
+    pub(super) lockfile: Option<PathBuf>,
+    pub(super) no_validate: bool,
     pub(super) universal: bool,
     pub(super) format: TreeFormat,
+    pub(super) duplicates: bool,
     pub(super) depth: u8,
     pub(super) prune: Vec<PackageName>,
     pub(super) package: Vec<PackageName>,
@@ -2685,6 +2936,7 @@ impl TreeSettings {
             tree,
             universal,
             format,
+            duplicates,
             dev,
             only_dev,
             no_dev,
@@ -2695,6 +2947,8 @@ impl TreeSettings {
             all_groups,
             locked,
             frozen,
+            lockfile,
+            no_validate,
             build,
             resolver,
             script,
@@ -2739,8 +2993,11 @@ impl TreeSettings {
             ),
             lock_check: resolve_lock_check(locked),
             frozen: resolve_frozen(frozen),
-            universal,
+            lockfile,
+            no_validate,
+            universal: universal || duplicates,
             format,
+            duplicates,
             depth: tree.depth,
             prune: tree.prune,
             package: tree.package,
@@ -2772,14 +3029,21 @@ pub(crate) struct ExportSettings {
     pub(super) all_packages: bool,
     pub(super) package: Vec<PackageName>,
     pub(super) prune: Vec<PackageName>,
+    pub(super) allow_prune_root: bool,
     pub(super) extras: ExtrasSpecification,
     pub(super) groups: DependencyGroups,
     pub(super) editable: Option<EditableMode>,
     pub(super) hashes: bool,
     pub(super) install_options: InstallOptions,
     pub(super) output_file: Option<PathBuf>,
+    pub(super) relative_to: Option<PathBuf>,
+    pub(super) bundle: Option<PathBuf>,
+    pub(super) python_platforms: Vec<TargetTriple>,
+    pub(super) split_output_dir: Option<PathBuf>,
     pub(super) lock_check: LockCheck,
     pub(super) frozen: Option<FrozenSource>,
+    pub(super) lockfile: Option<PathBuf>,
+    pub(super) no_validate: bool,
     pub(super) include_annotations: bool,
     pub(super) include_header: bool,
     pub(super) include_index_url: bool,
@@ -2803,6 +3067,7 @@ impl ExportSettings {
             all_packages,
             package,
             prune,
+            allow_prune_root,
             extra,
             all_extras,
             no_extra,
@@ -2829,6 +3094,11 @@ impl ExportSettings {
             hashes,
             no_hashes,
             output_file,
+            relative_to,
+            bundle,
+            python_platform,
+            split_per_group,
+            output_dir,
             no_emit_project,
             only_emit_project,
             no_emit_workspace,
@@ -2839,6 +3109,8 @@ impl ExportSettings {
             only_emit_package,
             locked,
             frozen: frozen_cli,
+            lockfile,
+            no_validate,
             resolver,
             build,
             refresh,
@@ -2879,6 +3151,7 @@ impl ExportSettings {
             all_packages,
             package,
             prune,
+            allow_prune_root,
             extras: ExtrasSpecification::from_args(
                 extra.unwrap_or_default(),
                 no_extra,
@@ -2916,8 +3189,14 @@ impl ExportSettings {
                 only_emit_package,
             ),
             output_file,
+            relative_to,
+            bundle,
+            python_platforms: python_platform,
+            split_output_dir: output_dir.filter(|_| split_per_group),
             lock_check: resolve_lock_check(locked),
             frozen: resolve_frozen(frozen),
+            lockfile,
+            no_validate,
             include_annotations: flag(annotate, no_annotate, "annotate")?.unwrap_or(true),
             include_header: flag(header, no_header, "header")?.unwrap_or(true),
             include_index_url: flag(emit_index_url, no_emit_index_url, "emit-index-url")?
@@ -3278,6 +3557,11 @@ pub(crate) struct PipCompileSettings {
     pub(crate) build_constraints_from_workspace: Vec<Requirement>,
     pub(crate) environments: SupportedEnvironments,
     pub(crate) required_environments: SupportedEnvironments,
+    pub(crate) unsafe_packages: Vec<PackageName>,
+    pub(crate) allow_unsafe: bool,
+    pub(crate) pip_tools_header: bool,
+    pub(crate) emit_inputs_hash: bool,
+    pub(crate) check_fresh: Option<PathBuf>,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
@@ -3314,6 +3598,11 @@ impl PipCompileSettings {
             header,
             annotation_style,
             custom_compile_command,
+            pip_tools_header,
+            no_pip_tools_header,
+            emit_inputs_hash,
+            no_emit_inputs_hash,
+            check_fresh,
             resolver,
             python,
             system,
@@ -3329,6 +3618,9 @@ impl PipCompileSettings {
             universal,
             no_universal,
             no_emit_package,
+            unsafe_package,
+            allow_unsafe,
+            no_allow_unsafe,
             emit_index_url,
             no_emit_index_url,
             emit_find_links,
@@ -3397,6 +3689,18 @@ impl PipCompileSettings {
             SupportedEnvironments::default()
         };
 
+        // As in pip-compile, `--allow-unsafe` and `--no-allow-unsafe` fall back to the default
+        // unsafe packages if none are provided.
+        let allow_unsafe = flag(allow_unsafe, no_allow_unsafe, "allow-unsafe")?;
+        let unsafe_packages = match (unsafe_package, allow_unsafe) {
+            (Some(unsafe_package), _) => unsafe_package,
+            (None, Some(_)) => ["distribute", "pip", "setuptools"]
+                .into_iter()
+                .map(PackageName::from_str)
+                .collect::<Result<_, _>>()?,
+            (None, None) => Vec::new(),
+        };
+
         Ok(Self {
             format,
             src_file,
@@ -3422,6 +3726,13 @@ impl PipCompileSettings {
             build_constraints_from_workspace,
             environments,
             required_environments,
+            unsafe_packages,
+            allow_unsafe: allow_unsafe.unwrap_or(false),
+            pip_tools_header: flag(pip_tools_header, no_pip_tools_header, "pip-tools-header")?
+                .unwrap_or(false),
+            emit_inputs_hash: flag(emit_inputs_hash, no_emit_inputs_hash, "emit-inputs-hash")?
+                .unwrap_or(false),
+            check_fresh,
             refresh: Refresh::try_from(refresh)?,
             settings: PipSettings::combine(
                 PipOptions {
@@ -3479,7 +3790,10 @@ pub(crate) struct PipSyncSettings {
     pub(crate) src_file: Vec<PathBuf>,
     pub(crate) constraints: Vec<PathBuf>,
     pub(crate) build_constraints: Vec<PathBuf>,
+    pub(crate) exclude_package: Vec<PackageName>,
+    pub(crate) exclude_unchecked: bool,
     pub(crate) dry_run: DryRun,
+    pub(crate) format: DryRunFormat,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
@@ -3495,6 +3809,8 @@ impl PipSyncSettings {
             src_file,
             constraints,
             build_constraints,
+            exclude_package,
+            exclude_unchecked,
             extra,
             all_extras,
             no_all_extras,
@@ -3523,6 +3839,7 @@ impl PipSyncSettings {
             strict,
             no_strict,
             dry_run,
+            format,
             torch_backend,
             compat_args: _,
         } = *args;
@@ -3537,7 +3854,10 @@ impl PipSyncSettings {
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
+            exclude_package,
+            exclude_unchecked,
             dry_run: DryRun::from_args(dry_run),
+            format,
             refresh: Refresh::try_from(refresh)?,
             settings: PipSettings::combine(
                 PipOptions {
@@ -3586,8 +3906,12 @@ pub(crate) struct PipInstallSettings {
     pub(crate) constraints: Vec<PathBuf>,
     pub(crate) overrides: Vec<PathBuf>,
     pub(crate) excludes: Vec<PathBuf>,
+    pub(crate) exclude_package: Vec<PackageName>,
+    pub(crate) exclude_unchecked: bool,
     pub(crate) build_constraints: Vec<PathBuf>,
     pub(crate) dry_run: DryRun,
+    pub(crate) format: DryRunFormat,
+    pub(crate) explain: Vec<PackageName>,
     pub(crate) constraints_from_workspace: Vec<Requirement>,
     pub(crate) overrides_from_workspace: Vec<Override<Requirement>>,
     pub(crate) excludes_from_workspace: Vec<ExcludeDependency>,
@@ -3613,6 +3937,8 @@ impl PipInstallSettings {
             constraints,
             overrides,
             excludes,
+            exclude_package,
+            exclude_unchecked,
             build_constraints,
             extra,
             all_extras,
@@ -3644,6 +3970,8 @@ impl PipInstallSettings {
             strict,
             no_strict,
             dry_run,
+            format,
+            explain,
             torch_backend,
             compat_args: _,
         } = args;
@@ -3703,11 +4031,15 @@ impl PipInstallSettings {
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
+            exclude_package,
+            exclude_unchecked,
             build_constraints: build_constraints
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
             dry_run: DryRun::from_args(dry_run),
+            format,
+            explain,
             constraints_from_workspace,
             overrides_from_workspace,
             excludes_from_workspace,
@@ -3926,6 +4258,7 @@ impl PipListSettings {
 pub(crate) struct PipShowSettings {
     pub(crate) package: Vec<PackageName>,
     pub(crate) files: bool,
+    pub(crate) format: ShowFormat,
     pub(crate) settings: PipSettings,
 }
 
@@ -3941,6 +4274,7 @@ impl PipShowSettings {
             strict,
             no_strict,
             files,
+            format,
             python,
             system,
             no_system,
@@ -3952,6 +4286,7 @@ impl PipShowSettings {
         Ok(Self {
             package,
             files,
+            format,
             settings: PipSettings::combine(
                 PipOptions {
                     python: python.and_then(Maybe::into_option),
@@ -4073,6 +4408,7 @@ pub(crate) struct BuildSettings {
     pub(crate) gitignore: bool,
     pub(crate) force_pep517: bool,
     pub(crate) clear: bool,
+    pub(crate) check_bounds: bool,
     pub(crate) build_constraints: Vec<PathBuf>,
     pub(crate) build_constraints_from_workspace: Vec<Requirement>,
     pub(crate) hash_checking: Option<HashCheckingMode>,
@@ -4099,6 +4435,7 @@ impl BuildSettings {
             list,
             force_pep517,
             clear,
+            check_bounds,
             build_constraints,
             require_hashes,
             no_require_hashes,
@@ -4142,6 +4479,7 @@ impl BuildSettings {
             build_logs: flag(build_logs, no_build_logs, "build-logs")?.unwrap_or(true),
             force_pep517,
             clear,
+            check_bounds,
             gitignore: flag(create_gitignore, no_create_gitignore, "create-gitignore")?
                 .unwrap_or(true),
             build_constraints: build_constraints
@@ -4177,10 +4515,14 @@ pub(crate) struct VenvSettings {
     pub(crate) no_clear: bool,
     pub(crate) path: Option<PathBuf>,
     pub(crate) prompt: Option<String>,
-    pub(crate) system_site_packages: bool,
+    pub(crate) system_site_packages: Option<bool>,
+    pub(crate) existing: bool,
+    pub(crate) move_to: Option<PathBuf>,
     pub(crate) relocatable: bool,
     pub(crate) no_relocatable: bool,
     pub(crate) no_project: bool,
+    pub(crate) sync: Option<SyncSettings>,
+    pub(crate) from_lock: Option<PathBuf>,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
@@ -4204,8 +4546,13 @@ impl VenvSettings {
             path,
             prompt,
             system_site_packages,
+            no_system_site_packages,
+            existing,
+            move_to,
             relocatable,
             no_relocatable,
+            sync,
+            from_lock,
             index_args,
             index_strategy,
             keyring_provider,
@@ -4236,6 +4583,79 @@ impl VenvSettings {
             None,
         );
 
+        let refresh = Refresh::try_from(refresh)?;
+
+        let pip_options = PipOptions {
+            python: python.clone().and_then(Maybe::into_option),
+            system: flag(system, no_system, "system")?,
+            index_strategy,
+            keyring_provider,
+            exclude_newer,
+            exclude_newer_package: exclude_newer_package.map(ExcludeNewerPackage::from_iter),
+            link_mode,
+            ..PipOptions::from(index_args)
+        };
+
+        // With `--sync`, sync the project as `uv sync` would, respecting the index and installer
+        // options passed to `uv venv`.
+        let sync = if sync {
+            let locked = resolve_flag(false, "locked", environment.locked);
+            let frozen = resolve_flag(false, "frozen", environment.frozen);
+            check_conflicts(locked, frozen)?;
+            Some(SyncSettings {
+                lock_check: resolve_lock_check(locked),
+                frozen: resolve_frozen(frozen),
+                from_bundle: None,
+                dry_run: DryRun::Disabled,
+                explain: vec![],
+                check_group_markers: false,
+                no_post_sync: resolve_flag(false, "no-post-sync", environment.no_post_sync)
+                    .is_enabled(),
+                repair: false,
+                cascade: false,
+                script: None,
+                active: None,
+                allow_conda: false,
+                extras: ExtrasSpecification::default(),
+                groups: DependencyGroups::default(),
+                editable: None,
+                install_options: InstallOptions::default(),
+                modifications: Modifications::Exact,
+                all_packages: false,
+                package: vec![],
+                python: python.clone().and_then(Maybe::into_option),
+                python_platform: None,
+                install_mirrors: environment.install_mirrors.clone().combine(
+                    filesystem
+                        .as_ref()
+                        .map(|fs| fs.install_mirrors.clone())
+                        .unwrap_or_default(),
+                ),
+                refresh: refresh.clone(),
+                settings: ResolverInstallerSettings::combine(
+                    ResolverInstallerOptions {
+                        index: pip_options.index.clone(),
+                        index_url: pip_options.index_url.clone(),
+                        extra_index_url: pip_options.extra_index_url.clone(),
+                        no_index: pip_options.no_index,
+                        find_links: pip_options.find_links.clone(),
+                        index_strategy: pip_options.index_strategy,
+                        keyring_provider: pip_options.keyring_provider,
+                        exclude_newer: pip_options.exclude_newer.clone(),
+                        exclude_newer_package: pip_options.exclude_newer_package.clone(),
+                        link_mode: pip_options.link_mode,
+                        ..ResolverInstallerOptions::default()
+                    },
+                    filesystem.clone(),
+                    &environment,
+                ),
+                output_format: SyncFormat::default(),
+                malware_settings: MalwareCheckSettings::resolve(filesystem.as_ref(), &environment),
+            })
+        } else {
+            None
+        };
+
         Ok(Self {
             seed,
             allow_existing,
@@ -4244,26 +4664,20 @@ impl VenvSettings {
             no_clear: no_clear.into(),
             path,
             prompt,
-            system_site_packages,
+            system_site_packages: flag(
+                system_site_packages,
+                no_system_site_packages,
+                "system-site-packages",
+            )?,
+            existing,
+            move_to,
             no_project,
+            sync,
+            from_lock,
             relocatable: relocatable.into(),
             no_relocatable: no_relocatable.into(),
-            refresh: Refresh::try_from(refresh)?,
-            settings: PipSettings::combine(
-                PipOptions {
-                    python: python.and_then(Maybe::into_option),
-                    system: flag(system, no_system, "system")?,
-                    index_strategy,
-                    keyring_provider,
-                    exclude_newer,
-                    exclude_newer_package: exclude_newer_package
-                        .map(ExcludeNewerPackage::from_iter),
-                    link_mode,
-                    ..PipOptions::from(index_args)
-                },
-                filesystem,
-                environment,
-            ),
+            refresh,
+            settings: PipSettings::combine(pip_options, filesystem, environment),
         })
     }
 }
@@ -4283,6 +4697,7 @@ pub(crate) struct InstallerSettingsRef<'a> {
     pub(crate) build_isolation: &'a BuildIsolation,
     pub(crate) extra_build_dependencies: &'a ExtraBuildDependencies,
     pub(crate) extra_build_variables: &'a ExtraBuildVariables,
+    pub(crate) build_env_passthrough: &'a BuildEnvPassthrough,
     pub(crate) exclude_newer: &'a ExcludeNewer,
     pub(crate) link_mode: LinkMode,
     pub(crate) compile_bytecode: bool,
@@ -4310,7 +4725,10 @@ pub(crate) struct ResolverSettings {
     pub(crate) build_isolation: BuildIsolation,
     pub(crate) extra_build_dependencies: ExtraBuildDependencies,
     pub(crate) extra_build_variables: ExtraBuildVariables,
+    pub(crate) build_env_passthrough: BuildEnvPassthrough,
     pub(crate) prerelease: PrereleaseMode,
+    pub(crate) prerelease_packages: Vec<PackageName>,
+    pub(crate) require_metadata: Option<MetadataVersion>,
     pub(crate) resolution: ResolutionMode,
     pub(crate) sources: NoSources,
     pub(crate) torch_backend: Option<TorchMode>,
@@ -4375,6 +4793,8 @@ impl From<ResolverOptions> for ResolverSettings {
             index_locations,
             resolution: value.resolution.unwrap_or_default(),
             prerelease: value.prerelease.unwrap_or_default(),
+            prerelease_packages: value.prerelease_packages.unwrap_or_default(),
+            require_metadata: value.require_metadata,
             fork_strategy: value.fork_strategy.unwrap_or_default(),
             dependency_metadata: DependencyMetadata::from_entries(
                 value.dependency_metadata.into_iter().flatten(),
@@ -4386,6 +4806,10 @@ impl From<ResolverOptions> for ResolverSettings {
             build_isolation: value.build_isolation.unwrap_or_default(),
             extra_build_dependencies: value.extra_build_dependencies.unwrap_or_default(),
             extra_build_variables: value.extra_build_variables.unwrap_or_default(),
+            build_env_passthrough: BuildEnvPassthrough::from_args(
+                value.build_env_passthrough,
+                value.build_env_passthrough_package.unwrap_or_default(),
+            ),
             exclude_newer: ExcludeNewer::from_args(
                 value.exclude_newer,
                 value
@@ -4517,7 +4941,13 @@ impl From<ResolverInstallerOptions> for ResolverInstallerSettings {
                 build_isolation: value.build_isolation.unwrap_or_default(),
                 extra_build_dependencies: value.extra_build_dependencies.unwrap_or_default(),
                 extra_build_variables: value.extra_build_variables.unwrap_or_default(),
+                build_env_passthrough: BuildEnvPassthrough::from_args(
+                    value.build_env_passthrough,
+                    value.build_env_passthrough_package.unwrap_or_default(),
+                ),
                 prerelease: value.prerelease.unwrap_or_default(),
+                prerelease_packages: value.prerelease_packages.unwrap_or_default(),
+                require_metadata: value.require_metadata,
                 resolution: value.resolution.unwrap_or_default(),
                 sources: NoSources::from_args(
                     value.no_sources,
@@ -4563,6 +4993,8 @@ pub(crate) struct PipSettings {
     pub(crate) dependency_mode: DependencyMode,
     pub(crate) resolution: ResolutionMode,
     pub(crate) prerelease: PrereleaseMode,
+    pub(crate) prerelease_packages: Vec<PackageName>,
+    pub(crate) require_metadata: Option<MetadataVersion>,
     pub(crate) fork_strategy: ForkStrategy,
     pub(crate) dependency_metadata: DependencyMetadata,
     pub(crate) output_file: Option<PathBuf>,
@@ -4639,6 +5071,8 @@ impl PipSettings {
             allow_empty_requirements,
             resolution,
             prerelease,
+            prerelease_packages,
+            require_metadata,
             fork_strategy,
             dependency_metadata,
             output_file,
@@ -4685,6 +5119,8 @@ impl PipSettings {
             resolution: top_level_resolution,
             prerelease: top_level_prerelease,
             fork_strategy: top_level_fork_strategy,
+            prerelease_packages: top_level_prerelease_packages,
+            require_metadata: top_level_require_metadata,
             dependency_metadata: top_level_dependency_metadata,
             config_settings: top_level_config_settings,
             config_settings_package: top_level_config_settings_package,
@@ -4692,6 +5128,8 @@ impl PipSettings {
             no_build_isolation_package: top_level_no_build_isolation_package,
             extra_build_dependencies: top_level_extra_build_dependencies,
             extra_build_variables: top_level_extra_build_variables,
+            build_env_passthrough: _,
+            build_env_passthrough_package: _,
             exclude_newer: top_level_exclude_newer,
             link_mode: top_level_link_mode,
             compile_bytecode: top_level_compile_bytecode,
@@ -4723,6 +5161,8 @@ impl PipSettings {
         let resolution = resolution.combine(top_level_resolution);
         let prerelease = prerelease.combine(top_level_prerelease);
         let fork_strategy = fork_strategy.combine(top_level_fork_strategy);
+        let prerelease_packages = prerelease_packages.combine(top_level_prerelease_packages);
+        let require_metadata = require_metadata.combine(top_level_require_metadata);
         let dependency_metadata = dependency_metadata.combine(top_level_dependency_metadata);
         let config_settings = config_settings.combine(top_level_config_settings);
         let config_settings_package =
@@ -4792,6 +5232,11 @@ impl PipSettings {
             },
             resolution: args.resolution.combine(resolution).unwrap_or_default(),
             prerelease: args.prerelease.combine(prerelease).unwrap_or_default(),
+            prerelease_packages: args
+                .prerelease_packages
+                .combine(prerelease_packages)
+                .unwrap_or_default(),
+            require_metadata: args.require_metadata.combine(require_metadata),
             fork_strategy: args
                 .fork_strategy
                 .combine(fork_strategy)
@@ -4978,6 +5423,7 @@ impl<'a> From<&'a ResolverInstallerSettings> for InstallerSettingsRef<'a> {
             build_isolation: &settings.resolver.build_isolation,
             extra_build_dependencies: &settings.resolver.extra_build_dependencies,
             extra_build_variables: &settings.resolver.extra_build_variables,
+            build_env_passthrough: &settings.resolver.build_env_passthrough,
             exclude_newer: &settings.resolver.exclude_newer,
             link_mode: settings.resolver.link_mode,
             compile_bytecode: settings.compile_bytecode,
@@ -4993,10 +5439,12 @@ impl<'a> From<&'a ResolverInstallerSettings> for InstallerSettingsRef<'a> {
 pub(crate) struct PublishSettings {
     // CLI only, see [`PublishArgs`] for docs.
     pub(crate) files: Vec<String>,
+    pub(crate) from_build: Option<PathBuf>,
     pub(crate) username: Option<String>,
     pub(crate) password: Option<String>,
     pub(crate) index: Option<String>,
     pub(crate) dry_run: bool,
+    pub(crate) check_metadata: bool,
     pub(crate) no_attestations: bool,
     pub(crate) direct: bool,
 
@@ -5014,10 +5462,12 @@ impl fmt::Debug for PublishSettings {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("PublishSettings")
             .field("files", &self.files)
+            .field("from_build", &self.from_build)
             .field("username", &self.username)
             .field("password", &self.password.as_ref().map(|_| "****"))
             .field("index", &self.index)
             .field("dry_run", &self.dry_run)
+            .field("check_metadata", &self.check_metadata)
             .field("no_attestations", &self.no_attestations)
             .field("direct", &self.direct)
             .field("publish_url", &self.publish_url)
@@ -5060,9 +5510,11 @@ impl PublishSettings {
 
         Self {
             files: args.files,
+            from_build: args.from_build,
             username,
             password,
             dry_run: args.dry_run,
+            check_metadata: args.check_metadata,
             no_attestations: args.no_attestations,
             direct: args.direct,
             publish_url: args
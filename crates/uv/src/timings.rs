@@ -0,0 +1,396 @@
+//! A local breakdown of the time spent in each phase of a command (`--timings`).
+//!
+//! Timings are collected from the `tracing` spans emitted throughout uv and are only ever
+//! reported to the user.
+
+use std::fmt::Write;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use owo_colors::OwoColorize;
+use serde::Serialize;
+use tracing::Subscriber;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing_subscriber::Layer;
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::registry::LookupSpan;
+
+use uv_cli::TimingsFormat;
+
+use crate::commands::{elapsed, human_readable_bytes};
+use crate::printer::Printer;
+
+/// The timings collected for this process, if requested.
+static TIMINGS: OnceLock<(TimingsFormat, Arc<Mutex<Timings>>)> = OnceLock::new();
+
+/// A phase of a command, as reported by `--timings`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum Phase {
+    /// Settings and workspace discovery.
+    Discovery,
+    /// Querying Python interpreters.
+    Interpreter,
+    /// Validating an existing lockfile.
+    LockValidation,
+    /// Resolving dependencies.
+    Resolution,
+    /// Downloading and unpacking distributions.
+    Download,
+    /// Building source distributions.
+    Build,
+    /// Installing distributions into the environment.
+    Install,
+    /// Compiling bytecode.
+    Compile,
+}
+
+impl Phase {
+    /// Determine the phase for a span, given its target and name.
+    fn from_span(target: &str, name: &str) -> Option<Self> {
+        let krate = target.split("::").next()?;
+        match (krate, name) {
+            ("uv_settings", name) if name.starts_with("toml::from_str filesystem options") => {
+                Some(Self::Discovery)
+            }
+            ("uv_workspace", "discover_workspace") => Some(Self::Discovery),
+            ("uv_python", "query_interpreter") => Some(Self::Interpreter),
+            ("uv_resolver", "satisfies") => Some(Self::LockValidation),
+            ("uv_resolver", "solve") => Some(Self::Resolution),
+            ("uv_installer", "get_wheel") => Some(Self::Download),
+            ("uv_dispatch", "setup_build") | ("uv_build_frontend", "build") => Some(Self::Build),
+            ("uv_installer", "install") => Some(Self::Install),
+            ("uv_installer", "compile_tree") => Some(Self::Compile),
+            _ => None,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Discovery => "Discovery",
+            Self::Interpreter => "Interpreter query",
+            Self::LockValidation => "Lock validation",
+            Self::Resolution => "Resolution",
+            Self::Download => "Download",
+            Self::Build => "Build",
+            Self::Install => "Install",
+            Self::Compile => "Bytecode compilation",
+        }
+    }
+}
+
+/// The timing of a span that is in progress.
+#[derive(Debug)]
+struct SpanTiming {
+    phase: Phase,
+    start: Instant,
+    /// The time at which the span was last entered, if it is currently entered.
+    entered: Option<Instant>,
+    /// The total time for which the span was entered, i.e., not waiting.
+    busy: Duration,
+    /// The package being built, for builds.
+    package: Option<String>,
+    /// The size of the distribution, for downloads.
+    size: Option<u64>,
+}
+
+impl Visit for SpanTiming {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "version_id" {
+            self.package = Some(value.to_string());
+        }
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        if field.name() == "size" {
+            self.size = Some(value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        match field.name() {
+            "version_id" => {
+                let value = format!("{value:?}");
+                self.package = Some(value.trim_matches('"').to_string());
+            }
+            "size" => {
+                // The size is recorded as an `Option<u64>`.
+                let value = format!("{value:?}");
+                self.size = value
+                    .strip_prefix("Some(")
+                    .and_then(|value| value.strip_suffix(')'))
+                    .and_then(|value| value.parse().ok());
+            }
+            _ => {}
+        }
+    }
+}
+
+/// A completed span.
+#[derive(Debug)]
+struct SpanRecord {
+    phase: Phase,
+    start: Instant,
+    end: Instant,
+    busy: Duration,
+    package: Option<String>,
+    size: Option<u64>,
+}
+
+#[derive(Debug)]
+struct Timings {
+    start: Instant,
+    spans: Vec<SpanRecord>,
+}
+
+/// A [`Layer`] that records the time spent in each [`Phase`].
+pub(crate) struct TimingsLayer {
+    timings: Arc<Mutex<Timings>>,
+}
+
+impl TimingsLayer {
+    /// Create a [`TimingsLayer`] for this process, to be reported with [`report`] in the given
+    /// format.
+    pub(crate) fn install(format: TimingsFormat) -> Self {
+        let timings = Arc::new(Mutex::new(Timings {
+            start: Instant::now(),
+            spans: Vec::new(),
+        }));
+        let _ = TIMINGS.set((format, timings.clone()));
+        Self { timings }
+    }
+}
+
+impl<S> Layer<S> for TimingsLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let Some(phase) = Phase::from_span(span.metadata().target(), span.metadata().name()) else {
+            return;
+        };
+
+        // Attribute any work performed on behalf of a build (e.g., resolving and installing its
+        // build dependencies) to the build itself.
+        if span.scope().skip(1).any(|parent| {
+            parent
+                .extensions()
+                .get::<SpanTiming>()
+                .is_some_and(|timing| timing.phase == Phase::Build)
+        }) {
+            return;
+        }
+
+        let mut timing = SpanTiming {
+            phase,
+            start: Instant::now(),
+            entered: None,
+            busy: Duration::ZERO,
+            package: None,
+            size: None,
+        };
+        attrs.record(&mut timing);
+        span.extensions_mut().insert(timing);
+    }
+
+    fn on_enter(&self, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        if let Some(timing) = span.extensions_mut().get_mut::<SpanTiming>() {
+            timing.entered.get_or_insert_with(Instant::now);
+        }
+    }
+
+    fn on_exit(&self, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        if let Some(timing) = span.extensions_mut().get_mut::<SpanTiming>()
+            && let Some(entered) = timing.entered.take()
+        {
+            timing.busy += entered.elapsed();
+        }
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(&id) else {
+            return;
+        };
+        let Some(timing) = span.extensions_mut().remove::<SpanTiming>() else {
+            return;
+        };
+        self.timings.lock().unwrap().spans.push(SpanRecord {
+            phase: timing.phase,
+            start: timing.start,
+            end: Instant::now(),
+            busy: timing.busy,
+            package: timing.package,
+            size: timing.size,
+        });
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct TimingsReport {
+    /// The total wall time of the command, in seconds.
+    total: f64,
+    phases: Vec<PhaseReport>,
+    builds: Vec<BuildReport>,
+}
+
+#[derive(Debug, Serialize)]
+struct PhaseReport {
+    phase: Phase,
+    /// The wall time during which the phase was in progress, in seconds.
+    wall: f64,
+    /// The time spent actively working on the phase, in seconds.
+    busy: f64,
+    /// The wall time during which the phase was waiting (e.g., on the network), in seconds.
+    waiting: f64,
+    /// The number of operations in the phase.
+    count: usize,
+    /// The number of bytes downloaded, for downloads.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bytes: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+struct BuildReport {
+    package: String,
+    /// The wall time of the build, in seconds.
+    wall: f64,
+}
+
+impl Timings {
+    /// Aggregate the recorded spans into a report.
+    fn report(&self) -> TimingsReport {
+        let mut phases = Vec::new();
+        let mut spans = self.spans.iter().collect::<Vec<_>>();
+        spans.sort_by_key(|span| (span.phase, span.start));
+
+        for chunk in spans.chunk_by(|a, b| a.phase == b.phase) {
+            let phase = chunk[0].phase;
+
+            // Concurrent operations overlap, so the wall time is the union of their intervals.
+            let mut wall = Duration::ZERO;
+            let mut current: Option<(Instant, Instant)> = None;
+            for span in chunk {
+                current = match current {
+                    Some((start, end)) if span.start <= end => Some((start, end.max(span.end))),
+                    Some((start, end)) => {
+                        wall += end - start;
+                        Some((span.start, span.end))
+                    }
+                    None => Some((span.start, span.end)),
+                };
+            }
+            if let Some((start, end)) = current {
+                wall += end - start;
+            }
+
+            let busy = chunk.iter().map(|span| span.busy).sum::<Duration>();
+            let bytes =
+                (phase == Phase::Download).then(|| chunk.iter().filter_map(|span| span.size).sum());
+
+            phases.push(PhaseReport {
+                phase,
+                wall: wall.as_secs_f64(),
+                busy: busy.as_secs_f64(),
+                waiting: wall.saturating_sub(busy).as_secs_f64(),
+                count: chunk.len(),
+                bytes,
+            });
+        }
+
+        // Combine the environment setup and the build itself for each package.
+        let mut builds: Vec<BuildReport> = Vec::new();
+        for span in &self.spans {
+            if span.phase != Phase::Build {
+                continue;
+            }
+            let package = span.package.as_deref().unwrap_or("<unknown>");
+            let wall = (span.end - span.start).as_secs_f64();
+            if let Some(build) = builds.iter_mut().find(|build| build.package == package) {
+                build.wall += wall;
+            } else {
+                builds.push(BuildReport {
+                    package: package.to_string(),
+                    wall,
+                });
+            }
+        }
+        builds.sort_by(|a, b| b.wall.total_cmp(&a.wall));
+
+        TimingsReport {
+            total: self.start.elapsed().as_secs_f64(),
+            phases,
+            builds,
+        }
+    }
+}
+
+/// Print the timings collected for this process, if requested via `--timings`.
+pub(crate) fn report(printer: Printer) -> anyhow::Result<()> {
+    let Some((format, timings)) = TIMINGS.get() else {
+        return Ok(());
+    };
+    let report = timings.lock().unwrap().report();
+
+    match format {
+        TimingsFormat::Json => {
+            writeln!(
+                printer.stderr_important(),
+                "{}",
+                serde_json::to_string_pretty(&report)?
+            )?;
+        }
+        TimingsFormat::Text => {
+            let mut stderr = printer.stderr_important();
+            writeln!(
+                stderr,
+                "{} (total {})",
+                "Timings".bold(),
+                elapsed(Duration::from_secs_f64(report.total))
+            )?;
+            writeln!(
+                stderr,
+                "  {:<22}{:>10}{:>10}{:>10}{:>8}",
+                "Phase", "Wall", "Busy", "Waiting", "Count"
+            )?;
+            for phase in &report.phases {
+                write!(
+                    stderr,
+                    "  {:<22}{:>10}{:>10}{:>10}{:>8}",
+                    phase.phase.label(),
+                    elapsed(Duration::from_secs_f64(phase.wall)),
+                    elapsed(Duration::from_secs_f64(phase.busy)),
+                    elapsed(Duration::from_secs_f64(phase.waiting)),
+                    phase.count,
+                )?;
+                if let Some(bytes) = phase.bytes.filter(|bytes| *bytes > 0) {
+                    let (bytes, unit) = human_readable_bytes(bytes);
+                    write!(stderr, "  {}", format!("({bytes:.1}{unit})").dimmed())?;
+                }
+                writeln!(stderr)?;
+
+                if phase.phase == Phase::Build {
+                    for build in &report.builds {
+                        writeln!(
+                            stderr,
+                            "    {:<20}{:>10}",
+                            build.package,
+                            elapsed(Duration::from_secs_f64(build.wall))
+                        )?;
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
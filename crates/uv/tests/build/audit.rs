@@ -89,7 +89,7 @@ fn write_audit_output_project(project_dir: &impl PathChild, index_url: &str) {
     lockfile
         .write_str(&formatdoc! {r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -1676,7 +1676,7 @@ async fn audit_script_no_vulnerabilities() {
     lockfile
         .write_str(indoc! {r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -1743,7 +1743,7 @@ async fn audit_script_vulnerability_found() {
     lockfile
         .write_str(indoc! {r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -1848,7 +1848,7 @@ async fn audit_script_no_dependencies() {
     lockfile
         .write_str(indoc! {r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -1935,7 +1935,7 @@ async fn audit_script_multiple_dependencies() {
     lockfile
         .write_str(indoc! {r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -2018,7 +2018,7 @@ async fn audit_script_extras() {
     lockfile
         .write_str(indoc! {r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
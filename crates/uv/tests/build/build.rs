@@ -2949,6 +2949,51 @@ fn build_clear() -> Result<()> {
     Ok(())
 }
 
+/// `uv build` records the distributions from the most recent build for `uv publish --from-build`.
+#[test]
+fn build_manifest() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let project = context.temp_dir.child("project");
+
+    context.init().arg(project.path()).assert().success();
+
+    context
+        .build()
+        .arg("project")
+        .arg("--no-build-logs")
+        .assert()
+        .success();
+
+    assert_snapshot!(fs_err::read_to_string(project.child("dist").child(".uv-build-manifest"))?, @"
+    project-0.1.0-py3-none-any.whl
+    project-0.1.0.tar.gz
+    ");
+
+    // Bump the version and only build a wheel; the previous distributions are no longer recorded.
+    let pyproject_toml = fs_err::read_to_string(project.child("pyproject.toml"))?;
+    project
+        .child("pyproject.toml")
+        .write_str(&pyproject_toml.replace(r#"version = "0.1.0""#, r#"version = "0.2.0""#))?;
+
+    context
+        .build()
+        .arg("project")
+        .arg("--wheel")
+        .arg("--no-build-logs")
+        .assert()
+        .success();
+
+    assert_snapshot!(fs_err::read_to_string(project.child("dist").child(".uv-build-manifest"))?, @"project-0.2.0-py3-none-any.whl");
+
+    project
+        .child("dist")
+        .child("project-0.1.0.tar.gz")
+        .assert(predicate::path::is_file());
+
+    Ok(())
+}
+
 /// Test `uv build --no-create-gitignore`.
 #[test]
 fn build_no_gitignore() -> Result<()> {
@@ -2992,3 +3037,183 @@ fn build_no_gitignore() -> Result<()> {
 
     Ok(())
 }
+
+/// Write a workspace in which the root depends on a member without a version specifier.
+fn write_unbounded_workspace(
+    context: &uv_test::TestContext,
+    pin_policy: Option<&str>,
+) -> Result<()> {
+    let pin_policy = pin_policy
+        .map(|pin_policy| format!("workspace-pin-policy = \"{pin_policy}\""))
+        .unwrap_or_default();
+
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(&formatdoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["member", "iniconfig"]
+
+        [tool.uv]
+        {pin_policy}
+
+        [tool.uv.workspace]
+        members = ["packages/*"]
+
+        [tool.uv.sources]
+        member = {{ workspace = true }}
+
+        [build-system]
+        requires = ["uv_build>=0.5.15,<10000"]
+        build-backend = "uv_build"
+    "#})?;
+    project
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .touch()?;
+
+    let member = project.child("packages").child("member");
+    member.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "member"
+        version = "1.2.3"
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = ["uv_build>=0.5.15,<10000"]
+        build-backend = "uv_build"
+    "#})?;
+    member
+        .child("src")
+        .child("member")
+        .child("__init__.py")
+        .touch()?;
+
+    Ok(())
+}
+
+/// `--check-bounds` warns about dependencies on workspace members without a version specifier.
+#[test]
+fn build_check_bounds() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    write_unbounded_workspace(&context, None)?;
+
+    // Without `--check-bounds`, no warning is shown.
+    uv_snapshot!(context.filters(), context.build().arg("project").arg("--sdist"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Building source distribution (uv build backend)...
+    Successfully built project/dist/project-0.1.0.tar.gz
+    ");
+
+    uv_snapshot!(context.filters(), context.build().arg("project").arg("--sdist").arg("--check-bounds"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    warning: `project` depends on workspace member `member` without a version specifier; published distributions of `project` will accept any version of `member`
+    Building source distribution (uv build backend)...
+    Successfully built project/dist/project-0.1.0.tar.gz
+    ");
+
+    Ok(())
+}
+
+/// With `workspace-pin-policy = "exact"`, dependencies on workspace members without a version
+/// specifier are pinned to the member's current version in the built distributions.
+#[test]
+fn build_workspace_pin_policy_exact() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    write_unbounded_workspace(&context, Some("exact"))?;
+
+    uv_snapshot!(context.filters(), context.build().arg("project").arg("--check-bounds"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Building source distribution (uv build backend)...
+    Building wheel from source distribution (uv build backend)...
+    Successfully built project/dist/project-0.1.0.tar.gz
+    Successfully built project/dist/project-0.1.0-py3-none-any.whl
+    ");
+
+    context
+        .pip_install()
+        .arg("--no-deps")
+        .arg(
+            context
+                .temp_dir
+                .join("project")
+                .join("dist")
+                .join("project-0.1.0-py3-none-any.whl"),
+        )
+        .assert()
+        .success();
+
+    let metadata = fs_err::read_to_string(
+        context
+            .site_packages()
+            .join("project-0.1.0.dist-info")
+            .join("METADATA"),
+    )?;
+    assert_snapshot!(metadata, @"
+    Metadata-Version: 2.4
+    Name: project
+    Version: 0.1.0
+    Requires-Dist: member==1.2.3
+    Requires-Dist: iniconfig
+    Requires-Python: >=3.12
+    ");
+
+    // The source tree itself is left untouched.
+    assert!(
+        fs_err::read_to_string(context.temp_dir.join("project").join("pyproject.toml"))?
+            .contains(r#"dependencies = ["member", "iniconfig"]"#)
+    );
+
+    Ok(())
+}
+
+/// With `workspace-pin-policy = "compatible"`, dependencies on workspace members without a version
+/// specifier are bounded by a compatible release clause, including in wheel-only builds.
+#[test]
+fn build_workspace_pin_policy_compatible() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    write_unbounded_workspace(&context, Some("compatible"))?;
+
+    uv_snapshot!(context.filters(), context.build().arg("project").arg("--wheel"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Building source distribution (uv build backend)...
+    Building wheel from source distribution (uv build backend)...
+    Successfully built project/dist/project-0.1.0-py3-none-any.whl
+    ");
+
+    context
+        .pip_install()
+        .arg("--no-deps")
+        .arg(
+            context
+                .temp_dir
+                .join("project")
+                .join("dist")
+                .join("project-0.1.0-py3-none-any.whl"),
+        )
+        .assert()
+        .success();
+
+    let metadata = fs_err::read_to_string(
+        context
+            .site_packages()
+            .join("project-0.1.0.dist-info")
+            .join("METADATA"),
+    )?;
+    assert_snapshot!(metadata, @"
+    Metadata-Version: 2.4
+    Name: project
+    Version: 0.1.0
+    Requires-Dist: member~=1.2.3
+    Requires-Dist: iniconfig
+    Requires-Python: >=3.12
+    ");
+
+    Ok(())
+}
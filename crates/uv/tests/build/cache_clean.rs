@@ -131,7 +131,7 @@ fn clean_package_pypi() -> Result<()> {
     // Assert that the `.rkyv` file is created for `iniconfig`.
     let rkyv = context
         .cache_dir
-        .child("simple-v24")
+        .child("simple-v26")
         .child("pypi")
         .child("iniconfig.rkyv");
     assert!(
@@ -201,7 +201,7 @@ fn clean_package_index() -> Result<()> {
     // Assert that the `.rkyv` file is created for `iniconfig`.
     let rkyv = context
         .cache_dir
-        .child("simple-v24")
+        .child("simple-v26")
         .child("index")
         .child("e8208120cae3ba69")
         .child("iniconfig.rkyv");
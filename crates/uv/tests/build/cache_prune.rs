@@ -156,6 +156,82 @@ fn prune_cached_env() {
     ");
 }
 
+/// `cache prune --older-than` should retain recently used cached environments.
+#[test]
+fn prune_cached_env_older_than() {
+    let context = uv_test::test_context!("3.12").with_filtered_counts();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    context
+        .tool_run()
+        .arg("pytest@8.0.0")
+        .arg("--version")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([
+            (r"Removed \d+ files", "Removed [N] files"),
+            (r"Would remove \d+ files", "Would remove [N] files"),
+            // The cache entry does not have a stable key, so we filter it out
+            (
+                r"\[CACHE_DIR\](\\|\/)(.*?)(\\|\/).*",
+                "[CACHE_DIR]/$2/[ENTRY]",
+            ),
+        ])
+        .collect();
+
+    // The environment was just used, so it should be retained.
+    uv_snapshot!(&filters, context.prune().arg("--older-than").arg("30d"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    Found 1 cached environment ([SIZE]), 0 of which were removed ([SIZE])
+    No unused entries found
+    ");
+
+    // A dry run should report the space that would be reclaimed, without removing anything.
+    uv_snapshot!(&filters, context.prune().arg("--older-than").arg("0s").arg("--dry-run"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    Found 1 cached environment ([SIZE]), 1 of which would be removed ([SIZE])
+    Would remove [N] files ([SIZE])
+    ");
+
+    // Running the tool again should reuse the cached environment.
+    uv_snapshot!(&filters, context.tool_run()
+        .arg("pytest@8.0.0")
+        .arg("--version")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    pytest 8.0.0
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    ");
+
+    // Otherwise, an environment that wasn't used within the window should be removed.
+    uv_snapshot!(&filters, context.prune().arg("--older-than").arg("0s").arg("--verbose"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    DEBUG Searching for user configuration in: `[UV_USER_CONFIG_DIR]/uv.toml`
+    DEBUG uv [VERSION] ([COMMIT] DATE)
+    Pruning cache at: [CACHE_DIR]/
+    DEBUG Removing unused cached environment: [CACHE_DIR]/environments-v2/[ENTRY]
+    DEBUG Removing dangling cache archive: [CACHE_DIR]/archive-v0/[ENTRY]
+    Found 1 cached environment ([SIZE]), 1 of which was removed ([SIZE])
+    Removed [N] files ([SIZE])
+    ");
+}
+
 /// `cache prune` should remove any stale symlink from the cache.
 #[test]
 fn prune_stale_symlink() -> Result<()> {
@@ -439,3 +515,64 @@ fn prune_stale_revision() -> Result<()> {
 
     Ok(())
 }
+
+/// `cache prune --keep-lockfile` should remove any artifacts that aren't referenced by the
+/// lockfile.
+#[test]
+fn prune_keep_lockfile() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_exclude_newer("2025-01-01T00:00Z");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+    "# })?;
+
+    context.sync().assert().success();
+
+    // Install an unrelated package, to populate the cache.
+    context
+        .pip_install()
+        .arg("sniffio==1.3.1")
+        .assert()
+        .success();
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([(r"Removed \d+ files", "Removed [N] files")])
+        .collect();
+
+    uv_snapshot!(&filters, context.prune().arg("--keep-lockfile").arg("uv.lock"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    Removed [SIZE] of artifacts not referenced by `uv.lock`
+    Removed [N] files ([SIZE])
+    ");
+
+    // The project's dependencies should still be available offline.
+    context.venv().arg("--clear").assert().success();
+    uv_snapshot!(&filters, context.sync().arg("--offline"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    // But the unrelated package should require a download.
+    uv_snapshot!(&filters, context.pip_install().arg("sniffio==1.3.1").arg("--offline"), @"
+    exit_code: 1 (failure)
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because sniffio was not found in the cache and you require sniffio==1.3.1, we can conclude that your requirements are unsatisfiable.
+
+          hint: Packages were unavailable because the network was disabled. When the network is disabled, registry packages may only be read from the cache.
+    ");
+
+    Ok(())
+}
@@ -48,3 +48,62 @@ fn cache_size_with_packages_human() {
     [SIZE]
     ");
 }
+
+/// Test that `cache size --dedup` reports distributions shared by cached environments once, even
+/// when the environments are created with `--link-mode copy`.
+#[cfg(unix)]
+#[test]
+fn cache_size_dedup_environments() {
+    let context = uv_test::test_context!("3.12");
+
+    // Create two cached environments that share `iniconfig`.
+    context
+        .run()
+        .arg("--no-project")
+        .arg("--link-mode")
+        .arg("copy")
+        .arg("--with")
+        .arg("iniconfig")
+        .arg("python")
+        .arg("-c")
+        .arg("import iniconfig")
+        .assert()
+        .success();
+    context
+        .run()
+        .arg("--no-project")
+        .arg("--link-mode")
+        .arg("copy")
+        .arg("--with")
+        .arg("iniconfig")
+        .arg("--with")
+        .arg("typing-extensions")
+        .arg("python")
+        .arg("-c")
+        .arg("import iniconfig")
+        .assert()
+        .success();
+
+    let output = context
+        .cache_size()
+        .arg("--preview")
+        .arg("--dedup")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    // e.g., `Deduplication: 123 stored as 45 (2.73x)`
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let line = stdout
+        .lines()
+        .find_map(|line| line.strip_prefix("Deduplication: "))
+        .expect("deduplication statistics");
+    let (apparent, rest) = line.split_once(" stored as ").unwrap();
+    let (unique, _) = rest.split_once(' ').unwrap();
+    let apparent = apparent.parse::<u64>().unwrap();
+    let unique = unique.parse::<u64>().unwrap();
+    assert!(
+        apparent > unique,
+        "expected shared files to be deduplicated: {line}"
+    );
+}
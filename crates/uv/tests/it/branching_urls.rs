@@ -199,11 +199,11 @@ fn root_package_splits_transitive_too() -> Result<()> {
 
     assert_snapshot!(context.read("uv.lock"), @r#"
     version = 1
-    revision = 3
+    revision = 4
     requires-python = ">=3.11, <3.13"
     resolution-markers = [
-        "python_full_version >= '3.12'",
         "python_full_version < '3.12'",
+        "python_full_version >= '3.12'",
     ]
 
     [options]
@@ -393,11 +393,11 @@ fn root_package_splits_other_dependencies_too() -> Result<()> {
 
     assert_snapshot!(context.read("uv.lock"), @r#"
     version = 1
-    revision = 3
+    revision = 4
     requires-python = ">=3.11, <3.13"
     resolution-markers = [
-        "python_full_version >= '3.12'",
         "python_full_version < '3.12'",
+        "python_full_version >= '3.12'",
     ]
 
     [options]
@@ -553,11 +553,11 @@ fn branching_between_registry_and_direct_url() -> Result<()> {
     // We have source dist and wheel for the registry, but only the wheel for the direct URL.
     assert_snapshot!(context.read("uv.lock"), @r#"
     version = 1
-    revision = 3
+    revision = 4
     requires-python = ">=3.11, <3.13"
     resolution-markers = [
-        "python_full_version >= '3.12'",
         "python_full_version < '3.12'",
+        "python_full_version >= '3.12'",
     ]
 
     [options]
@@ -637,11 +637,11 @@ fn branching_urls_of_different_sources_disjoint() -> Result<()> {
     // We have source dist and wheel for the registry, but only the wheel for the direct URL.
     assert_snapshot!(context.read("uv.lock"), @r#"
     version = 1
-    revision = 3
+    revision = 4
     requires-python = ">=3.11, <3.13"
     resolution-markers = [
-        "python_full_version >= '3.12'",
         "python_full_version < '3.12'",
+        "python_full_version >= '3.12'",
     ]
 
     [options]
@@ -761,7 +761,7 @@ fn dont_pre_visit_url_packages() -> Result<()> {
 
     assert_snapshot!(context.read("uv.lock"), @r#"
     version = 1
-    revision = 3
+    revision = 4
     requires-python = ">=3.11, <3.13"
 
     [options]
@@ -0,0 +1,174 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+
+use uv_static::EnvVars;
+use uv_test::uv_snapshot;
+
+/// Lint a `uv.toml` with unknown, deprecated, misplaced, and suspect settings.
+#[test]
+fn config_lint_uv_toml() -> Result<()> {
+    let context = uv_test::test_context_with_versions!(&[]);
+
+    context
+        .temp_dir
+        .child("uv.toml")
+        .write_str(indoc::indoc! {r#"
+        resoluton = "lowest"
+        index-url = "https://test.pypi.org/simple"
+        cache-dir = ".cache"
+        managed = true
+
+        [pip]
+        compile-bytecod = true
+
+        [[index]]
+        url = "https://download.pytorch.org/whl/cpu"
+        explicit = true
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.config_lint(), @r#"
+    exit_code: 1 (failure)
+    ----- stdout -----
+    Configuration sources (in order of precedence):
+     - uv.toml (project)
+
+    error[unknown-key]: Unknown setting `resoluton`
+      --> uv.toml:1
+      help: did you mean `resolution`?
+
+    warning[deprecated-key]: `index-url` is deprecated
+      --> uv.toml:2
+      help: use an `[[index]]` entry with `default = true` instead
+
+    warning[suspect-value]: `cache-dir` is a relative path (`.cache`), which is resolved against the working directory of each invocation, rather than the directory containing the configuration file
+      --> uv.toml:3
+      help: use an absolute path
+
+    error[pyproject-only-key]: `managed` is not allowed in a `uv.toml` file
+      --> uv.toml:4
+      help: `managed` is only applicable in the context of a project; move it to the `[tool.uv]` table of a `pyproject.toml`
+
+    error[unknown-key]: Unknown setting `compile-bytecod`
+      --> uv.toml:7
+      help: did you mean `compile-bytecode`?
+
+    warning[suspect-value]: The explicit index `https://download.pytorch.org/whl/cpu` has no `name`, so no package can be pinned to it
+      --> uv.toml:9
+      help: add a `name`, such that the index can be referenced in `tool.uv.sources` and its credentials can be provided via `UV_INDEX_<NAME>_USERNAME` and `UV_INDEX_<NAME>_PASSWORD`
+
+    Found 6 issues
+    "#);
+
+    Ok(())
+}
+
+/// Report a `[tool.uv]` table that's masked by a `uv.toml`, and values that are overridden by
+/// environment variables.
+#[test]
+fn config_lint_masked_and_overridden() -> Result<()> {
+    let context = uv_test::test_context_with_versions!(&[]);
+
+    context
+        .temp_dir
+        .child("uv.toml")
+        .write_str(indoc::indoc! {r#"
+        resolution = "lowest"
+    "#})?;
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc::indoc! {r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv]
+        system-certs = true
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.config_lint().env(EnvVars::UV_RESOLUTION, "highest"), @r#"
+    exit_code: 1 (failure)
+    ----- stdout -----
+    Configuration sources (in order of precedence):
+     - uv.toml (project)
+     - pyproject.toml (project, ignored)
+
+    warning[masked-table]: The `[tool.uv]` table is ignored, since a `uv.toml` in the same directory takes precedence
+      --> pyproject.toml:7
+      help: move these settings into the `uv.toml`
+
+    warning[uv-toml-only-key]: `system-certs` is only respected in `uv.toml` files
+      --> pyproject.toml:8
+      help: move `system-certs` to a `uv.toml`, or set it via the command line
+
+    warning[overridden-value]: `resolution` is set to `"lowest"`, but is overridden by the `UV_RESOLUTION` environment variable
+      --> uv.toml:1
+      help: environment variables take precedence over configuration files
+
+    Found 3 issues
+    "#);
+
+    Ok(())
+}
+
+/// Lint a file provided via `--config-file`, even if it's invalid, with JSON output.
+#[test]
+fn config_lint_config_file_json() -> Result<()> {
+    let context = uv_test::test_context_with_versions!(&[]);
+
+    context
+        .temp_dir
+        .child("config.toml")
+        .write_str(indoc::indoc! {r"
+        [pip]
+        no-binray = true
+    "})?;
+
+    uv_snapshot!(context.filters(), context.config_lint().arg("--config-file").arg("config.toml").arg("--output-format").arg("json"), @r#"
+    exit_code: 1 (failure)
+    ----- stdout -----
+    {
+      "sources": [
+        {
+          "kind": "config-file",
+          "path": "config.toml",
+          "masked": false
+        }
+      ],
+      "diagnostics": [
+        {
+          "severity": "error",
+          "code": "unknown-key",
+          "message": "Unknown setting `no-binray`",
+          "help": "did you mean `no-binary`?",
+          "file": "config.toml",
+          "table": "pip",
+          "key": "no-binray",
+          "line": 2
+        }
+      ]
+    }
+    "#);
+
+    // A clean configuration file produces no diagnostics.
+    context
+        .temp_dir
+        .child("config.toml")
+        .write_str(indoc::indoc! {r#"
+        [pip]
+        no-binary = [":all:"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.config_lint().arg("--config-file").arg("config.toml"), @r"
+    exit_code: 0 (success)
+    ----- stdout -----
+    Configuration sources (in order of precedence):
+     - config.toml (`--config-file`)
+
+    No issues found
+    ");
+
+    Ok(())
+}
@@ -35,6 +35,7 @@ fn help() {
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
       workspace                  Inspect uv workspaces
+      config                     Inspect uv's configuration
       cache                      Manage uv's cache
       self                       Manage the uv executable
       generate-shell-completion  Generate shell completion
@@ -67,6 +68,9 @@ fn help() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --timings[=<FORMAT>]
+              Print a breakdown of the time spent in each phase of the command on completion [possible
+              values: text, json]
           --directory <DIRECTORY>
               Change to the given directory prior to running the command [env: UV_WORKING_DIR=]
           --project <PROJECT>
@@ -116,6 +120,7 @@ fn help_flag() {
       build      Build Python packages into source distributions and wheels
       publish    Upload distributions to an index
       workspace  Inspect uv workspaces
+      config     Inspect uv's configuration
       cache      Manage uv's cache
       self       Manage the uv executable
       help       Display documentation for a command
@@ -147,6 +152,9 @@ fn help_flag() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --timings[=<FORMAT>]
+              Print a breakdown of the time spent in each phase of the command on completion [possible
+              values: text, json]
           --directory <DIRECTORY>
               Change to the given directory prior to running the command [env: UV_WORKING_DIR=]
           --project <PROJECT>
@@ -196,6 +204,7 @@ fn help_short_flag() {
       build      Build Python packages into source distributions and wheels
       publish    Upload distributions to an index
       workspace  Inspect uv workspaces
+      config     Inspect uv's configuration
       cache      Manage uv's cache
       self       Manage the uv executable
       help       Display documentation for a command
@@ -227,6 +236,9 @@ fn help_short_flag() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --timings[=<FORMAT>]
+              Print a breakdown of the time spent in each phase of the command on completion [possible
+              values: text, json]
           --directory <DIRECTORY>
               Change to the given directory prior to running the command [env: UV_WORKING_DIR=]
           --project <PROJECT>
@@ -287,6 +299,9 @@ fn help_flag_workspace() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --timings[=<FORMAT>]
+              Print a breakdown of the time spent in each phase of the command on completion [possible
+              values: text, json]
           --directory <DIRECTORY>
               Change to the given directory prior to running the command [env: UV_WORKING_DIR=]
           --project <PROJECT>
@@ -361,6 +376,7 @@ fn help_subcommand() {
       pin           Pin to a specific Python version
       dir           Show the uv Python installation directory
       uninstall     Uninstall Python versions
+      platform      Inspect the target platforms accepted by `--python-platform`
       update-shell  Ensure that the Python executable directory is on the `PATH`
 
     Cache options:
@@ -463,6 +479,16 @@ fn help_subcommand() {
 
               [env: UV_NO_PROGRESS=]
 
+          --timings[=<FORMAT>]
+              Print a breakdown of the time spent in each phase of the command on completion.
+
+              Use `--timings=json` to print the breakdown as JSON, e.g., to aggregate timings across
+              runs. Timings are only reported locally.
+
+              Possible values:
+              - text: Display the timings as a table
+              - json: Display the timings as JSON
+
           --directory <DIRECTORY>
               Change to the given directory prior to running the command.
 
@@ -744,6 +770,16 @@ fn help_subsubcommand() {
 
               [env: UV_NO_PROGRESS=]
 
+          --timings[=<FORMAT>]
+              Print a breakdown of the time spent in each phase of the command on completion.
+
+              Use `--timings=json` to print the breakdown as JSON, e.g., to aggregate timings across
+              runs. Timings are only reported locally.
+
+              Possible values:
+              - text: Display the timings as a table
+              - json: Display the timings as JSON
+
           --directory <DIRECTORY>
               Change to the given directory prior to running the command.
 
@@ -809,6 +845,7 @@ fn help_flag_subcommand() {
       pin           Pin to a specific Python version
       dir           Show the uv Python installation directory
       uninstall     Uninstall Python versions
+      platform      Inspect the target platforms accepted by `--python-platform`
       update-shell  Ensure that the Python executable directory is on the `PATH`
 
     Cache options:
@@ -838,6 +875,9 @@ fn help_flag_subcommand() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --timings[=<FORMAT>]
+              Print a breakdown of the time spent in each phase of the command on completion [possible
+              values: text, json]
           --directory <DIRECTORY>
               Change to the given directory prior to running the command [env: UV_WORKING_DIR=]
           --project <PROJECT>
@@ -919,6 +959,9 @@ fn help_flag_subsubcommand() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --timings[=<FORMAT>]
+              Print a breakdown of the time spent in each phase of the command on completion [possible
+              values: text, json]
           --directory <DIRECTORY>
               Change to the given directory prior to running the command [env: UV_WORKING_DIR=]
           --project <PROJECT>
@@ -1046,6 +1089,7 @@ fn help_with_global_option() {
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
       workspace                  Inspect uv workspaces
+      config                     Inspect uv's configuration
       cache                      Manage uv's cache
       self                       Manage the uv executable
       generate-shell-completion  Generate shell completion
@@ -1078,6 +1122,9 @@ fn help_with_global_option() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --timings[=<FORMAT>]
+              Print a breakdown of the time spent in each phase of the command on completion [possible
+              values: text, json]
           --directory <DIRECTORY>
               Change to the given directory prior to running the command [env: UV_WORKING_DIR=]
           --project <PROJECT>
@@ -1162,6 +1209,7 @@ fn help_with_no_pager() {
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
       workspace                  Inspect uv workspaces
+      config                     Inspect uv's configuration
       cache                      Manage uv's cache
       self                       Manage the uv executable
       generate-shell-completion  Generate shell completion
@@ -1194,6 +1242,9 @@ fn help_with_no_pager() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --timings[=<FORMAT>]
+              Print a breakdown of the time spent in each phase of the command on completion [possible
+              values: text, json]
           --directory <DIRECTORY>
               Change to the given directory prior to running the command [env: UV_WORKING_DIR=]
           --project <PROJECT>
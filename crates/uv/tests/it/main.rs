@@ -7,6 +7,8 @@ mod auth;
 #[cfg(all(feature = "test-pypi", feature = "test-universal"))]
 mod branching_urls;
 
+mod config_lint;
+
 #[cfg(all(
     feature = "test-python",
     feature = "test-pypi",
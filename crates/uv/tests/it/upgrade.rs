@@ -96,6 +96,9 @@ fn upgrade_help() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --timings[=<FORMAT>]
+              Print a breakdown of the time spent in each phase of the command on completion [possible
+              values: text, json]
           --directory <DIRECTORY>
               Change to the given directory prior to running the command [env: UV_WORKING_DIR=]
           --project <PROJECT>
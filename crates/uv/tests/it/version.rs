@@ -2000,6 +2000,179 @@ fn version_set_dynamic() -> Result<()> {
     Ok(())
 }
 
+// Read the version from the file configured with `tool.uv.build-backend.version`
+#[test]
+fn version_get_file() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    context.temp_dir.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "myproject"
+        dynamic = ["version"]
+        requires-python = ">=3.12"
+
+        [tool.uv.build-backend]
+        version = { file = "VERSION" }
+        "#,
+    )?;
+    context
+        .temp_dir
+        .child("VERSION")
+        .write_str("  1.10.31\n\n")?;
+
+    uv_snapshot!(context.filters(), context.version(), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    myproject 1.10.31
+    ");
+
+    Ok(())
+}
+
+// Write the version to the file configured with `tool.uv.build-backend.version`
+#[test]
+fn version_set_file() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "myproject"
+        dynamic = ["version"]
+        requires-python = ">=3.12"
+
+        [tool.uv.build-backend]
+        version = { file = "VERSION" }
+        "#,
+    )?;
+    let version_file = context.temp_dir.child("VERSION");
+    version_file.write_str("1.10.31 \n")?;
+
+    uv_snapshot!(context.filters(), context.version()
+        .arg("--bump")
+        .arg("minor")
+        .arg("--frozen"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    myproject 1.10.31 => 1.11.0
+    ");
+
+    // The file is normalized to the version and a trailing newline.
+    assert_eq!(fs_err::read_to_string(&version_file)?, "1.11.0\n");
+
+    // The `pyproject.toml` is untouched.
+    let pyproject = fs_err::read_to_string(&pyproject_toml)?;
+    assert!(!pyproject.contains("1.11.0"));
+
+    Ok(())
+}
+
+// Changes to the version file are picked up when re-locking and syncing the project
+#[test]
+fn version_file_sync() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    context.temp_dir.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "myproject"
+        dynamic = ["version"]
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+
+        [tool.uv.build-backend]
+        version = { file = "VERSION" }
+        "#,
+    )?;
+    context
+        .temp_dir
+        .child("src")
+        .child("myproject")
+        .child("__init__.py")
+        .touch()?;
+    let version_file = context.temp_dir.child("VERSION");
+    version_file.write_str("1.10.31\n")?;
+
+    uv_snapshot!(context.filters(), context.sync(), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + myproject==1.10.31 (from file://[TEMP_DIR]/)
+    ");
+
+    // Bumping the version re-locks and re-syncs the project with the new version.
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("minor"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    myproject 1.10.31 => 1.11.0
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - myproject==1.10.31 (from file://[TEMP_DIR]/)
+     + myproject==1.11.0 (from file://[TEMP_DIR]/)
+    ");
+
+    // Editing the version file directly invalidates the cached metadata too.
+    version_file.write_str("2.0.0\n")?;
+
+    uv_snapshot!(context.filters(), context.sync(), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - myproject==1.11.0 (from file://[TEMP_DIR]/)
+     + myproject==2.0.0 (from file://[TEMP_DIR]/)
+    ");
+
+    uv_snapshot!(context.filters(), context.version(), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    myproject 2.0.0
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+// A version file requires `version` in `project.dynamic`
+#[test]
+fn version_file_not_dynamic() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    context.temp_dir.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "myproject"
+        requires-python = ">=3.12"
+
+        [tool.uv.build-backend]
+        version = { file = "VERSION" }
+        "#,
+    )?;
+    context.temp_dir.child("VERSION").write_str("1.10.31\n")?;
+
+    uv_snapshot!(context.filters(), context.version(), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    error: `project.dynamic` must contain `version` when `tool.uv.build-backend.version` is used in: pyproject.toml
+    ");
+
+    Ok(())
+}
+
 /// Previously would fallback to `uv --version` if this pyproject.toml isn't usable for whatever reason
 /// (In this case, because tool.uv.managed = false)
 #[test]
@@ -2449,7 +2622,7 @@ fn version_get_frozen_workspace_without_python() -> Result<()> {
 
     context.temp_dir.child("uv.lock").write_str(indoc! {r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [manifest]
@@ -2576,7 +2749,7 @@ fn version_set_workspace() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -2635,7 +2808,7 @@ fn version_set_workspace() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -2743,7 +2916,7 @@ fn version_set_workspace() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -2885,7 +3058,7 @@ fn version_set_evil_constraints() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -2970,7 +3143,7 @@ fn version_set_evil_constraints() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -3219,3 +3392,79 @@ conflicts = [[{"extra" = "foo"}, {"extra" = "bar"}]]
 
     Ok(())
 }
+
+// Set version --diff-only
+#[test]
+fn version_set_diff_only() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"[project]
+name = "myproject"
+version = "1.10.31"
+requires-python = ">=3.12"
+"#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.version()
+        .arg("1.2.3")
+        .arg("--diff-only"), @r#"
+    exit_code: 0 (success)
+    ----- stdout -----
+    --- pyproject.toml
+    +++ pyproject.toml
+    @@ -1,4 +1,4 @@
+     [project]
+     name = "myproject"
+    -version = "1.10.31"
+    +version = "1.2.3"
+     requires-python = ">=3.12"
+    myproject 1.10.31 => 1.2.3
+    "#);
+
+    let pyproject = fs_err::read_to_string(&pyproject_toml)?;
+    assert_snapshot!(
+        pyproject,
+    @r#"
+    [project]
+    name = "myproject"
+    version = "1.10.31"
+    requires-python = ">=3.12"
+    "#
+    );
+
+    // With `--show-diff`, the same diff is displayed and the change is written.
+    uv_snapshot!(context.filters(), context.version()
+        .arg("1.2.3")
+        .arg("--show-diff"), @r#"
+    exit_code: 0 (success)
+    ----- stdout -----
+    --- pyproject.toml
+    +++ pyproject.toml
+    @@ -1,4 +1,4 @@
+     [project]
+     name = "myproject"
+    -version = "1.10.31"
+    +version = "1.2.3"
+     requires-python = ">=3.12"
+    myproject 1.10.31 => 1.2.3
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Checked in [TIME]
+    "#);
+
+    let pyproject = fs_err::read_to_string(&pyproject_toml)?;
+    assert_snapshot!(
+        pyproject,
+    @r#"
+    [project]
+    name = "myproject"
+    version = "1.2.3"
+    requires-python = ">=3.12"
+    "#
+    );
+
+    Ok(())
+}
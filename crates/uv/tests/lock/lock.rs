@@ -55,7 +55,7 @@ fn lock_wheel_registry() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -142,6 +142,48 @@ fn lock_wheel_registry() -> Result<()> {
     Ok(())
 }
 
+/// Re-lock with `--upgrade`, reading the dependencies of registry wheels from the dependency cache
+/// rather than the wheel metadata.
+#[cfg(feature = "test-universal")]
+#[test]
+fn lock_dependency_cache() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--preview-features").arg("lock-dependency-cache"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    assert!(context.cache_dir.child("dependencies-v0").is_dir());
+    let lock = context.read("uv.lock");
+
+    // Remove the cached wheel metadata, such that an offline resolution can only succeed by
+    // reading the dependency cache.
+    fs_err::remove_dir_all(context.cache_dir.child("wheels-v6"))?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--upgrade").arg("--offline").arg("--preview-features").arg("lock-dependency-cache"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    assert_eq!(context.read("uv.lock"), lock);
+
+    Ok(())
+}
+
 /// Lock a requirement from PyPI.
 #[cfg(feature = "test-universal")]
 #[test]
@@ -173,7 +215,7 @@ fn lock_sdist_registry() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -259,7 +301,7 @@ fn lock_sdist_git() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -337,7 +379,7 @@ fn lock_sdist_git() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -392,7 +434,7 @@ fn lock_sdist_git() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -446,7 +488,7 @@ fn lock_sdist_git() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -505,7 +547,7 @@ fn lock_sdist_git_subdirectory() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -587,7 +629,7 @@ fn lock_sdist_git_pep508() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -645,7 +687,7 @@ fn lock_sdist_git_pep508() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -697,7 +739,7 @@ fn lock_sdist_git_pep508() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -748,7 +790,7 @@ fn lock_sdist_git_pep508() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -810,7 +852,7 @@ fn lock_sdist_git_short_rev() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -907,7 +949,7 @@ fn lock_sdist_git_archive() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -1048,7 +1090,7 @@ fn lock_wheel_git_archive() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -1184,7 +1226,7 @@ fn lock_wheel_url() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -1326,7 +1368,7 @@ fn lock_sdist_url() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -1420,7 +1462,7 @@ fn lock_sdist_url_subdirectory() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -1543,7 +1585,7 @@ fn lock_sdist_url_subdirectory_pep508() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -1669,7 +1711,7 @@ fn lock_project_extra() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -1856,7 +1898,7 @@ fn lock_project_with_scoped_overrides() -> Result<()> {
     }, {
         assert_snapshot!(context.read("uv.lock"), @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -2187,6 +2229,109 @@ fn lock_project_with_excluded_scoped_override_prerelease() -> Result<()> {
     Ok(())
 }
 
+/// Allow pre-releases for a single package via `prerelease-packages`, and record the setting in
+/// the lockfile.
+#[cfg(feature = "test-universal")]
+#[test]
+fn lock_prerelease_packages() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let server = PackseServer::new("prereleases/transitive-package-only-prereleases-in-range.toml");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["a"]
+        "#,
+    )?;
+
+    // Only pre-releases of `b` satisfy `a`'s requirement, so we should suggest opting in.
+    uv_snapshot!(context.filters(), context
+        .lock()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER), @r#"
+    exit_code: 1 (failure)
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because only b<=0.1 is available and all versions of a depend on b>0.1, we can conclude that all versions of a cannot be used.
+          And because your project depends on a, we can conclude that your project's requirements are unsatisfiable.
+
+    hint: Only pre-releases of `b` are available in the requested range (e.g., 1.0.0a1), but pre-releases weren't enabled (try: `tool.uv.prerelease-packages = ["b"]` to allow pre-releases for `b` only, or `--prerelease=allow`)
+    "#);
+
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["a"]
+
+        [tool.uv]
+        prerelease-packages = ["b"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context
+        .lock()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("prerelease-packages = [\"b\"]"));
+    assert!(lock.contains("version = \"1.0.0a1\""));
+
+    // The setting is recorded in the lockfile, so `--locked` should succeed.
+    uv_snapshot!(context.filters(), context
+        .lock()
+        .arg("--locked")
+        .arg("--index-url")
+        .arg(server.index_url())
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    // Removing the setting should invalidate the lockfile. The locked pre-release is retained as a
+    // preference, but the recorded options no longer match.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["a"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context
+        .lock()
+        .arg("--locked")
+        .arg("--index-url")
+        .arg(server.index_url())
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER), @"
+    exit_code: 1 (failure)
+    ----- stderr -----
+    Resolving despite existing lockfile due to change in pre-release packages: `[b]` vs. `[]`
+    Resolved 3 packages in [TIME]
+    error: The lockfile at `uv.lock` needs to be updated, but `--locked` was provided.
+
+    hint: To update the lockfile, run `uv lock`.
+    ");
+
+    Ok(())
+}
+
 /// Lock a project with an explicitly pinned yanked release from a scoped override.
 #[cfg(feature = "test-universal")]
 #[test]
@@ -2314,7 +2459,7 @@ fn lock_project_with_excludes() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -2697,7 +2842,7 @@ fn lock_dependency_extra() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -2887,12 +3032,12 @@ fn lock_conditional_dependency_extra() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.7"
         resolution-markers = [
+            "python_full_version < '3.8'",
             "python_full_version >= '3.10'",
             "python_full_version >= '3.8' and python_full_version < '3.10'",
-            "python_full_version < '3.8'",
         ]
 
         [options]
@@ -3176,7 +3321,7 @@ fn lock_dependency_non_existent_extra() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -3398,7 +3543,7 @@ fn lock_conflicting_project_basic1() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", group = "foo" },
@@ -3566,7 +3711,7 @@ fn lock_conflicting_workspace_members() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "example" },
@@ -3817,7 +3962,7 @@ fn lock_conflicting_workspace_members_depends_direct_extra() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "example", extra = "foo" },
@@ -4119,7 +4264,7 @@ fn lock_conflicting_workspace_members_depends_transitive_extra() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "example" },
@@ -4286,7 +4431,7 @@ fn lock_conflicting_project_basic2() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "example", group = "foo" },
@@ -4487,7 +4632,7 @@ fn lock_conflicting_mixed() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", extra = "project2" },
@@ -4621,7 +4766,7 @@ fn lock_upgrade_log() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -4697,7 +4842,7 @@ fn lock_upgrade_log() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -4783,7 +4928,7 @@ fn lock_upgrade_log_multi_version() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
             "sys_platform != 'win32'",
@@ -4863,7 +5008,7 @@ fn lock_upgrade_log_multi_version() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -5017,7 +5162,7 @@ fn lock_check_refresh_workspace_conflicts() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "package-a", extra = "non-prod" },
@@ -5144,7 +5289,7 @@ fn lock_preference() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -5201,7 +5346,7 @@ fn lock_preference() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -5245,7 +5390,7 @@ fn lock_preference() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -5311,7 +5456,7 @@ fn lock_git_plus_prefix() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -5388,11 +5533,11 @@ fn lock_partial_git() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.10"
         resolution-markers = [
-            "python_full_version >= '3.12'",
             "python_full_version < '3.12'",
+            "python_full_version >= '3.12'",
         ]
 
         [options]
@@ -5664,7 +5809,7 @@ fn lock_git_sha() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -5759,13 +5904,13 @@ fn lock_requires_python() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.7"
         resolution-markers = [
-            "python_full_version >= '3.8'",
-            "python_full_version >= '3.7.9' and python_full_version < '3.8'",
-            "python_full_version >= '3.7.4' and python_full_version < '3.7.9'",
             "python_full_version < '3.7.4'",
+            "python_full_version >= '3.7.4' and python_full_version < '3.7.9'",
+            "python_full_version >= '3.7.9' and python_full_version < '3.8'",
+            "python_full_version >= '3.8'",
         ]
 
         [options]
@@ -5788,9 +5933,9 @@ fn lock_requires_python() -> Result<()> {
         version = "23.1.2"
         source = { registry = "https://pypi.org/simple" }
         resolution-markers = [
-            "python_full_version >= '3.7.9' and python_full_version < '3.8'",
-            "python_full_version >= '3.7.4' and python_full_version < '3.7.9'",
             "python_full_version < '3.7.4'",
+            "python_full_version >= '3.7.4' and python_full_version < '3.7.9'",
+            "python_full_version >= '3.7.9' and python_full_version < '3.8'",
         ]
         dependencies = [
             { name = "attrs" },
@@ -5862,9 +6007,9 @@ fn lock_requires_python() -> Result<()> {
         version = "2023.0.1"
         source = { registry = "https://pypi.org/simple" }
         resolution-markers = [
-            "python_full_version >= '3.8'",
-            "python_full_version >= '3.7.4' and python_full_version < '3.7.9'",
             "python_full_version < '3.7.4'",
+            "python_full_version >= '3.7.4' and python_full_version < '3.7.9'",
+            "python_full_version >= '3.8'",
         ]
         dependencies = [
             { name = "attrs" },
@@ -5986,9 +6131,9 @@ fn lock_requires_python() -> Result<()> {
         version = "4.7.1"
         source = { registry = "https://pypi.org/simple" }
         resolution-markers = [
-            "python_full_version >= '3.7.9' and python_full_version < '3.8'",
-            "python_full_version >= '3.7.4' and python_full_version < '3.7.9'",
             "python_full_version < '3.7.4'",
+            "python_full_version >= '3.7.4' and python_full_version < '3.7.9'",
+            "python_full_version >= '3.7.9' and python_full_version < '3.8'",
         ]
         sdist = { url = "https://files.pythonhosted.org/packages/3c/8b/0111dd7d6c1478bf83baa1cab85c686426c7a6274119aceb2bd9d35395ad/typing_extensions-4.7.1.tar.gz", hash = "sha256:b75ddc264f0ba5615db7ba217daeb99701ad295353c45f9e95963337ceeeffb2", size = 72876, upload-time = "2023-07-02T14:20:55.045Z" }
         wheels = [
@@ -6047,11 +6192,11 @@ fn lock_requires_python() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.7.9"
         resolution-markers = [
-            "python_full_version >= '3.8'",
             "python_full_version < '3.8'",
+            "python_full_version >= '3.8'",
         ]
 
         [options]
@@ -6264,7 +6409,7 @@ fn lock_requires_python() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -6395,7 +6540,7 @@ fn lock_requires_python_upper() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = "==3.11.*"
 
         [options]
@@ -6514,7 +6659,7 @@ fn lock_requires_python_exact() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = "==3.13"
 
         [options]
@@ -6642,7 +6787,7 @@ fn lock_requires_python_fork() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.9"
 
         [options]
@@ -6732,7 +6877,7 @@ fn lock_requires_python_wheels() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = "==3.12.*"
 
         [options]
@@ -6811,7 +6956,7 @@ fn lock_requires_python_wheels() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = "==3.11.*"
 
         [options]
@@ -6900,7 +7045,7 @@ fn lock_requires_python_star() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = "==3.11.*"
 
         [options]
@@ -7017,7 +7162,7 @@ fn lock_requires_python_not_equal() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">3.10, !=3.10.9, !=3.10.10, !=3.11.*, <3.13"
 
         [options]
@@ -7091,7 +7236,7 @@ fn lock_requires_python_pre() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.11"
 
         [options]
@@ -7208,11 +7353,11 @@ fn lock_requires_python_unbounded() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = "<=3.12"
         resolution-markers = [
-            "python_full_version >= '3.7'",
             "python_full_version < '3.7'",
+            "python_full_version >= '3.7'",
         ]
 
         [options]
@@ -7342,11 +7487,11 @@ fn lock_requires_python_maximum_version() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.8"
         resolution-markers = [
-            "python_full_version >= '3.9'",
             "python_full_version < '3.9'",
+            "python_full_version >= '3.9'",
         ]
 
         [options]
@@ -7496,7 +7641,7 @@ fn lock_requires_python_fewest_versions() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.8"
 
         [options]
@@ -7608,13 +7753,13 @@ fn lock_python_version_marker_complement() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.8"
         resolution-markers = [
-            "python_full_version >= '3.11'",
-            "python_full_version > '3.10' and python_full_version < '3.11'",
-            "python_full_version == '3.10'",
             "python_full_version < '3.10'",
+            "python_full_version == '3.10'",
+            "python_full_version > '3.10' and python_full_version < '3.11'",
+            "python_full_version >= '3.11'",
         ]
 
         [options]
@@ -7715,7 +7860,7 @@ fn lock_dev() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -7821,7 +7966,7 @@ fn lock_conditional_unconditional() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -7894,7 +8039,7 @@ fn lock_multiple_markers() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -8004,7 +8149,7 @@ fn lock_relative_and_absolute_paths() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.11, <3.13"
 
         [options]
@@ -8048,6 +8193,171 @@ fn lock_relative_and_absolute_paths() -> Result<()> {
     Ok(())
 }
 
+/// Check that path sources containing spaces and non-ASCII characters round-trip through the
+/// lockfile and `requirements.txt` export.
+#[test]
+fn lock_path_with_spaces_and_non_ascii() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "a"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["b"]
+
+        [tool.uv.sources]
+        b = { path = "py wheels/café" }
+        "#
+    })?;
+    context
+        .temp_dir
+        .child("py wheels/café/pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "b"
+        version = "0.1.0"
+        dependencies = []
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+    "#})?;
+    context
+        .temp_dir
+        .child("py wheels/café/src/b/__init__.py")
+        .touch()?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 4
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "a"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "b" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "b", directory = "py wheels/café" }]
+
+        [[package]]
+        name = "b"
+        version = "0.1.0"
+        source = { directory = "py wheels/café" }
+        "#
+        );
+    });
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    uv_snapshot!(context.filters(), context.export(), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR]
+    ./py wheels/café
+        # via a
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    // Install from the exported requirements.
+    context
+        .export()
+        .arg("-o")
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.pip_install().arg("-r").arg("requirements.txt"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + b==0.1.0 (from file://[TEMP_DIR]/py%20wheels/caf%C3%A9)
+    ");
+
+    Ok(())
+}
+
+/// Check that Windows drive-relative paths (e.g., `C:b`) are resolved against the project
+/// directory, rather than recorded verbatim.
+#[cfg(windows)]
+#[test]
+fn lock_drive_relative_path() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    // Ex) `C:`
+    let drive = context.temp_dir.simplified_display().to_string()[..2].to_string();
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! {r#"
+        [project]
+        name = "a"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["b"]
+
+        [tool.uv.sources]
+        b = {{ path = '{drive}b' }}
+        "#
+    })?;
+    context
+        .temp_dir
+        .child("b/pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "b"
+        version = "0.1.0"
+        dependencies = []
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+    "#})?;
+    context.temp_dir.child("b/src/b/__init__.py").touch()?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains(r#"source = { directory = "b" }"#), "{lock}");
+
+    Ok(())
+}
+
 /// Check PEP 508 URL handling when they contain variables
 #[cfg(feature = "test-universal")]
 #[test]
@@ -8112,7 +8422,7 @@ fn lock_pep508_urls_with_vars() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.11, <3.13"
 
         [options]
@@ -8220,7 +8530,7 @@ fn lock_constraint_dependency_absolute_path() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -8337,7 +8647,7 @@ fn lock_index_absolute_path_from_config() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -8399,7 +8709,7 @@ fn lock_cycles() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -8594,7 +8904,7 @@ fn lock_new_extras() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -8713,7 +9023,7 @@ fn lock_new_extras() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -9015,7 +9325,7 @@ fn lock_mixed_hashes() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.13"
 
         [[package]]
@@ -9091,7 +9401,7 @@ fn lock_mixed_hashes() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.13"
 
         [[package]]
@@ -9209,7 +9519,7 @@ async fn lock_index_hash_algorithm() -> Result<()> {
     }, {
         assert_snapshot!(context.read("uv.lock"), @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.13"
 
         [[package]]
@@ -9248,7 +9558,7 @@ async fn lock_index_hash_algorithm() -> Result<()> {
     }, {
         assert_snapshot!(context.read("uv.lock"), @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.13"
 
         [[package]]
@@ -9355,6 +9665,354 @@ async fn lock_index_hash_algorithm_missing() -> Result<()> {
     Ok(())
 }
 
+/// Pin the index metadata, then verify it against an index that removed and altered files.
+#[cfg(feature = "test-universal")]
+#[tokio::test]
+async fn lock_pin_index_metadata() -> Result<()> {
+    let context = uv_test::test_context!("3.13");
+    let server = MockServer::start().await;
+
+    let simple_index = json!({
+        "meta": {
+            "api-version": "1.1"
+        },
+        "name": "basic-package",
+        "files": [{
+            "filename": "basic_package-0.1.0-py3-none-any.whl",
+            "url": format!("{}/files/basic_package-0.1.0-py3-none-any.whl", server.uri()),
+            "hashes": {
+                "sha256": "7b6229db79b5800e4e98a351b5628c1c8a944533a2d428aeeaa7275a30d4ea82",
+                "sha512": "765bde25938af485e492e25ee0e8cde262462565122c1301213a69bf9ceb2008e3997b652a604092a238c4b1a6a334e697ff3cee3c22f9a617cb14f34e26ef17"
+            },
+            "core-metadata": true
+        }, {
+            "filename": "basic_package-0.1.0.tar.gz",
+            "url": format!("{}/files/basic_package-0.1.0.tar.gz", server.uri()),
+            "hashes": {
+                "sha256": "af478ff91ec60856c99a540b8df13d756513bebb65bc301fb27e0d1f974532b4",
+                "sha512": "f754f5955ce76c8fbdccdacd6e0e34977354b04d062d7f993fa84f3301309257fd225c85ebc99571b8b8ad711b37c407af65c5eae73599802ea3b4d3082d2f32"
+            }
+        }]
+    });
+
+    Mock::given(method("GET"))
+        .and(path("/simple/basic-package/"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            simple_index.to_string(),
+            "application/vnd.pypi.simple.v1+json",
+        ))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/files/basic_package-0.1.0-py3-none-any.whl.metadata"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(indoc! {"
+            Metadata-Version: 2.1
+            Name: basic-package
+            Version: 0.1.0
+        "}))
+        .mount(&server)
+        .await;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.13"
+        dependencies = ["basic-package"]
+
+        [tool.uv.sources]
+        basic-package = {{ index = "test-registry" }}
+
+        [[tool.uv.index]]
+        name = "test-registry"
+        url = "{}/simple"
+        explicit = true
+        "#,
+        server.uri()
+    })?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--pin-index-metadata").env_remove(EnvVars::UV_EXCLUDE_NEWER), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(context.read("uv.lock"), @r#"
+        version = 1
+        revision = 4
+        requires-python = ">=3.13"
+
+        [[package]]
+        name = "basic-package"
+        version = "0.1.0"
+        source = { registry = "http://[LOCALHOST]/simple" }
+        index-digest = "sha256:8bdb71c689914e86de04998b9452147570b8ef8b413bb0899f781c359afb5843"
+        sdist = { url = "http://[LOCALHOST]/files/basic_package-0.1.0.tar.gz", hash = "sha512:f754f5955ce76c8fbdccdacd6e0e34977354b04d062d7f993fa84f3301309257fd225c85ebc99571b8b8ad711b37c407af65c5eae73599802ea3b4d3082d2f32" }
+        wheels = [
+            { url = "http://[LOCALHOST]/files/basic_package-0.1.0-py3-none-any.whl", hash = "sha512:765bde25938af485e492e25ee0e8cde262462565122c1301213a69bf9ceb2008e3997b652a604092a238c4b1a6a334e697ff3cee3c22f9a617cb14f34e26ef17" },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "basic-package" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "basic-package", index = "http://[LOCALHOST]/simple" }]
+        "#);
+    });
+
+    // The index still serves the locked files.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked").env_remove(EnvVars::UV_EXCLUDE_NEWER), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    // Remove the source distribution, and replace the wheel with a different file.
+    server.reset().await;
+    Mock::given(method("GET"))
+        .and(path("/simple/basic-package/"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            json!({
+                "meta": {
+                    "api-version": "1.1"
+                },
+                "name": "basic-package",
+                "files": [{
+                    "filename": "basic_package-0.1.0-py3-none-any.whl",
+                    "url": format!("{}/files/basic_package-0.1.0-py3-none-any.whl", server.uri()),
+                    "hashes": {
+                        "sha256": "0000000000000000000000000000000000000000000000000000000000000000"
+                    },
+                    "core-metadata": true
+                }]
+            })
+            .to_string(),
+            "application/vnd.pypi.simple.v1+json",
+        ))
+        .mount(&server)
+        .await;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--locked").arg("--no-cache").env_remove(EnvVars::UV_EXCLUDE_NEWER), @"
+    exit_code: 1 (failure)
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    error: The index metadata pinned in `uv.lock` no longer matches the index, but `--locked` was provided:
+      - `basic-package==0.1.0`: `basic_package-0.1.0.tar.gz` was removed from the index
+      - `basic-package==0.1.0`: `basic_package-0.1.0-py3-none-any.whl` is no longer served with hash `sha512:765bde25938af485e492e25ee0e8cde262462565122c1301213a69bf9ceb2008e3997b652a604092a238c4b1a6a334e697ff3cee3c22f9a617cb14f34e26ef17`
+
+    hint: If the changes are expected, run `uv lock --pin-index-metadata` to re-pin the index metadata.
+    ");
+
+    Ok(())
+}
+
+/// Record the provenance of registry packages in the lockfile.
+#[test]
+fn lock_provenance() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+
+        [tool.uv]
+        lock-provenance = true
+        "#,
+    )?;
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([
+            (
+                r#"locked-at = "\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}Z""#,
+                r#"locked-at = "[TIMESTAMP]""#,
+            ),
+            (
+                r"locked-at: \d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}Z",
+                "locked-at: [TIMESTAMP]",
+            ),
+        ])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.lock(), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => filters.clone(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 4
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646, upload-time = "2023-01-07T11:08:11.254Z" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892, upload-time = "2023-01-07T11:08:09.864Z" },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "iniconfig" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "iniconfig", specifier = "==2.0.0" }]
+
+        [[provenance]]
+        name = "iniconfig"
+        version = "2.0.0"
+        index = "https://pypi.org/simple"
+        locked-at = "[TIMESTAMP]"
+        artifacts = [
+            { filename = "iniconfig-2.0.0-py3-none-any.whl", upload-time = "2023-01-07T11:08:09.864Z" },
+            { filename = "iniconfig-2.0.0.tar.gz", upload-time = "2023-01-07T11:08:11.254Z" },
+        ]
+        "#
+        );
+    });
+
+    // Re-locking retains the recorded provenance.
+    uv_snapshot!(filters, context.lock().arg("--locked"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    uv_snapshot!(filters, context.lock().arg("--show-provenance").arg("iniconfig"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    iniconfig v2.0.0
+      index: https://pypi.org/simple
+      locked-at: [TIMESTAMP]
+      artifact: iniconfig-2.0.0-py3-none-any.whl (uploaded 2023-01-07T11:08:09.864Z)
+      artifact: iniconfig-2.0.0.tar.gz (uploaded 2023-01-07T11:08:11.254Z)
+    ");
+
+    uv_snapshot!(filters, context.lock().arg("--show-provenance").arg("project"), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    error: No provenance is recorded for `project`
+    ");
+
+    // Disabling provenance removes the table, without re-resolving.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    uv_snapshot!(filters, context.lock(), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    assert!(!context.read("uv.lock").contains("[[provenance]]"));
+
+    Ok(())
+}
+
+/// Suggest validated changes to the project requirements when the resolution fails.
+#[test]
+fn lock_suggest() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0", "idna<2"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--suggest"), @r#"
+    exit_code: 1 (failure)
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because anyio==3.7.0 depends on idna>=2.8 and your project depends on anyio==3.7.0, we can conclude that your project depends on idna>=2.8.
+          And because your project depends on idna<2, we can conclude that your project's requirements are unsatisfiable.
+
+    hint: The conflict can be resolved by overriding the transitive requirements on `idna` in `tool.uv.override-dependencies`:
+          "idna<2.0"
+    hint: The conflict can be resolved by relaxing the requirement on `idna` in `pyproject.toml`:
+          - "idna<2"
+          + "idna>=2.8"
+    "#);
+
+    // The suggestions are never applied.
+    assert!(!context.temp_dir.child("uv.lock").exists());
+
+    // If no version satisfies a direct requirement, suggest dropping the specifier.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig>=99"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--suggest"), @r#"
+    exit_code: 1 (failure)
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because only iniconfig<=2.0.0 is available and your project depends on iniconfig>=99, we can conclude that your project's requirements are unsatisfiable.
+
+    hint: The conflict can be resolved by relaxing the requirement on `iniconfig` in `pyproject.toml`:
+          - "iniconfig>=99"
+          + "iniconfig"
+    "#);
+
+    // Without `--suggest`, no suggestions are made.
+    uv_snapshot!(context.filters(), context.lock(), @"
+    exit_code: 1 (failure)
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because only iniconfig<=2.0.0 is available and your project depends on iniconfig>=99, we can conclude that your project's requirements are unsatisfiable.
+    ");
+
+    Ok(())
+}
+
 /// Lock with an index which serves zstd-compressed wheels.
 #[cfg(feature = "test-universal")]
 #[tokio::test]
@@ -9447,7 +10105,7 @@ async fn lock_zstd_wheel() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.13"
 
         [[package]]
@@ -9505,7 +10163,7 @@ fn lock_resolution_mode() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -9580,7 +10238,7 @@ fn lock_resolution_mode() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -9735,11 +10393,11 @@ fn lock_same_version_multiple_urls() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
-            "sys_platform == 'darwin'",
             "sys_platform != 'darwin'",
+            "sys_platform == 'darwin'",
         ]
 
         [options]
@@ -9947,7 +10605,7 @@ fn lock_exclusion() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -10253,11 +10911,11 @@ fn lock_workspace_member_with_standalone_path_source() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
-            "sys_platform == 'darwin'",
             "sys_platform != 'darwin'",
+            "sys_platform == 'darwin'",
         ]
 
         [options]
@@ -10349,7 +11007,7 @@ fn lock_external_workspace_source() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -10535,7 +11193,7 @@ fn lock_peer_member() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -10623,7 +11281,7 @@ fn lock_workspace_member_with_fragment_delimiter() -> Result<()> {
     }, {
         assert_snapshot!(context.read("uv.lock"), @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -10732,7 +11390,7 @@ async fn lock_index_workspace_member() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -10880,7 +11538,7 @@ fn lock_dev_transitive() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -11009,7 +11667,7 @@ async fn lock_redact_http() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -11242,7 +11900,7 @@ fn lock_redact_git_pep508() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -11321,7 +11979,7 @@ fn lock_redact_git_sources() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -11398,7 +12056,7 @@ fn lock_redact_git_pep508_non_project() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -11476,7 +12134,7 @@ async fn lock_redact_index_sources() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -11557,7 +12215,7 @@ async fn lock_redact_url_sources() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -11635,7 +12293,7 @@ async fn lock_env_credentials() -> Result<()> {
       × No solution found when resolving dependencies:
       ╰─▶ Because iniconfig was not found in the package registry and your project depends on iniconfig, we can conclude that your project's requirements are unsatisfiable.
 
-    hint: An index URL (http://[LOCALHOST]/basic-auth/simple) could not be queried due to a lack of valid authentication credentials (401 Unauthorized)
+    hint: The `internal-proxy` index (http://[LOCALHOST]/basic-auth/simple) could not be queried due to a lack of valid authentication credentials (401 Unauthorized). To provide credentials for this index, set `UV_INDEX_INTERNAL_PROXY_USERNAME` and `UV_INDEX_INTERNAL_PROXY_PASSWORD` or run `uv auth login http://[LOCALHOST]/basic-auth/simple`.
     ");
 
     // Provide credentials via environment variables.
@@ -11656,7 +12314,7 @@ async fn lock_env_credentials() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -11688,6 +12346,42 @@ async fn lock_env_credentials() -> Result<()> {
     Ok(())
 }
 
+/// Credentials embedded in an index URL should never appear in verbose logs or the lockfile.
+#[cfg(feature = "test-universal")]
+#[tokio::test]
+async fn lock_verbose_redacts_credentials() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let proxy = crate::pypi_proxy::start().await;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    let output = context
+        .lock()
+        .arg("-vv")
+        .arg("--index-url")
+        .arg(proxy.authenticated_url("public", "heron", "/basic-auth/simple"))
+        .output()?;
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("public:****@"));
+    assert!(!stderr.contains("heron"), "{stderr}");
+
+    let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock"))?;
+    assert!(!lock.contains("heron"), "{lock}");
+
+    Ok(())
+}
+
 /// Test solving for packages that are pinned to separate indexes in the same realm.
 /// This requires the credentials to be cached at the URL-level instead of the realm-level, or
 /// credentials for one index will be used for both indexes and the request will fail.
@@ -11817,7 +12511,7 @@ async fn lock_relative_index() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -11922,7 +12616,7 @@ fn lock_no_sources() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -12007,7 +12701,7 @@ fn lock_no_sources() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -12173,7 +12867,7 @@ fn lock_migrate() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -12267,7 +12961,7 @@ fn lock_upgrade_package() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -12357,7 +13051,7 @@ fn lock_upgrade_package() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -12435,7 +13129,7 @@ fn lock_upgrade_package() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -12828,7 +13522,7 @@ fn lock_find_links_local_wheel() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -12941,7 +13635,7 @@ fn lock_find_links_ignore_explicit_index() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -13053,7 +13747,7 @@ fn lock_find_links_relative_url() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -13161,7 +13855,7 @@ fn lock_find_links_local_sdist() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -13247,7 +13941,7 @@ fn lock_find_links_http_wheel() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -13331,7 +14025,7 @@ fn lock_find_links_http_sdist() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -13441,7 +14135,7 @@ fn lock_find_links_explicit_index() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -13538,7 +14232,7 @@ fn lock_find_links_higher_priority_index() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -13631,7 +14325,7 @@ fn lock_find_links_lower_priority_index() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -13765,7 +14459,7 @@ fn lock_local_index() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.13"
 
         [[package]]
@@ -13845,7 +14539,7 @@ fn lock_sources_url() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -14039,7 +14733,7 @@ fn lock_sources_archive() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -14181,7 +14875,7 @@ fn lock_sources_source_tree() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -14310,7 +15004,7 @@ fn lock_editable() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -14485,7 +15179,7 @@ fn lock_mixed_extras() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -14671,7 +15365,7 @@ fn lock_transitive_extra() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -14811,7 +15505,7 @@ fn lock_mismatched_sources() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -14852,7 +15546,7 @@ fn lock_mismatched_sources() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -14917,7 +15611,7 @@ fn lock_mismatched_versions() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -15000,7 +15694,7 @@ fn lock_no_sources_package() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -15104,7 +15798,7 @@ fn lock_no_sources_package_multiple() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -15218,7 +15912,7 @@ fn lock_no_sources_with_no_sources_package() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -15324,7 +16018,7 @@ fn lock_no_sources_package_env_var() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -15612,7 +16306,7 @@ fn normalize_false_marker_dependency_groups() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.11"
 
         [options]
@@ -15675,7 +16369,7 @@ fn normalize_false_marker_requires_dist() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.11"
 
         [options]
@@ -15733,7 +16427,7 @@ fn lock_impossible_platform_markers() -> Result<()> {
     }, {
         assert_snapshot!(context.read("uv.lock"), @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.9"
 
         [options]
@@ -15787,7 +16481,7 @@ async fn lock_change_index() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -15831,7 +16525,7 @@ async fn lock_change_index() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -15920,7 +16614,7 @@ fn lock_remove_member() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -16037,7 +16731,7 @@ fn lock_remove_member() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -16139,7 +16833,7 @@ fn lock_remove_member() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -16195,7 +16889,7 @@ fn lock_add_member_with_build_system() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -16293,7 +16987,7 @@ fn lock_add_member_with_build_system() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -16392,7 +17086,7 @@ fn lock_add_member_without_build_system() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -16486,7 +17180,7 @@ fn lock_add_member_without_build_system() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -16598,7 +17292,7 @@ fn lock_add_member_without_build_system() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -16706,7 +17400,7 @@ fn lock_redundant_add_member() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -16804,7 +17498,7 @@ fn lock_redundant_add_member() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -16894,7 +17588,7 @@ fn lock_new_constraints() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -16994,7 +17688,7 @@ fn lock_new_constraints() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -17097,7 +17791,7 @@ fn lock_remove_member_non_project() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -17199,7 +17893,7 @@ fn lock_remove_member_non_project() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -17244,7 +17938,7 @@ fn lock_rename_project() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -17318,7 +18012,7 @@ fn lock_rename_project() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -17433,7 +18127,7 @@ fn lock_missing_metadata() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -17582,7 +18276,7 @@ fn lock_dev_dependencies_alias() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -17663,7 +18357,7 @@ fn lock_reorder() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -17805,7 +18499,7 @@ fn lock_narrowed_python_version_upper() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.7, <4"
         resolution-markers = [
             "python_full_version >= '3.10'",
@@ -17911,12 +18605,12 @@ fn lock_narrowed_python_version() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.7"
         resolution-markers = [
+            "python_full_version < '3.9'",
             "python_full_version >= '3.11'",
             "python_full_version >= '3.9' and python_full_version < '3.11'",
-            "python_full_version < '3.9'",
         ]
 
         [options]
@@ -18006,11 +18700,11 @@ fn lock_exclude_unnecessary_python_forks() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
-            "sys_platform == 'darwin'",
             "sys_platform != 'darwin'",
+            "sys_platform == 'darwin'",
         ]
 
         [options]
@@ -18109,7 +18803,7 @@ fn lock_constrained_environment() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
             "sys_platform != 'win32'",
@@ -18274,7 +18968,7 @@ fn lock_constrained_environment() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -18418,7 +19112,7 @@ fn lock_constrained_environment_non_project() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
             "sys_platform != 'win32'",
@@ -18597,11 +19291,11 @@ fn lock_non_project_fork() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.10"
         resolution-markers = [
-            "python_full_version >= '3.11'",
             "python_full_version < '3.11'",
+            "python_full_version >= '3.11'",
         ]
 
         [options]
@@ -18776,7 +19470,7 @@ fn lock_non_project_conditional() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -18877,7 +19571,7 @@ fn lock_non_project_group() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.10"
 
         [options]
@@ -19009,7 +19703,7 @@ fn lock_non_project_group_standard() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.10"
 
         [options]
@@ -19097,7 +19791,7 @@ fn lock_non_project_sources() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -19223,7 +19917,7 @@ fn lock_non_project_member_conflicts() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "member-a" },
@@ -19380,7 +20074,7 @@ fn lock_dropped_dev_extra() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -19487,7 +20181,7 @@ fn lock_empty_dev_dependencies() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -19583,7 +20277,7 @@ fn lock_empty_dependency_group() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -19673,7 +20367,7 @@ fn lock_add_empty_dependency_group() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -19748,7 +20442,7 @@ fn lock_add_empty_dependency_group() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -19823,7 +20517,7 @@ fn lock_add_empty_dependency_group() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -19896,7 +20590,7 @@ fn lock_trailing_slash_index_url() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -20059,7 +20753,7 @@ fn lock_explicit_index() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -20165,7 +20859,7 @@ fn lock_explicit_default_index() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -20252,7 +20946,7 @@ fn lock_explicit_default_index() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -20416,7 +21110,7 @@ async fn lock_named_index() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -20483,7 +21177,7 @@ fn lock_default_index() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -20543,7 +21237,7 @@ fn lock_default_index() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -20618,7 +21312,7 @@ fn lock_named_index_cli() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -20904,7 +21598,7 @@ fn lock_repeat_named_index_member() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -20991,7 +21685,7 @@ fn lock_unique_named_index() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -21063,7 +21757,7 @@ fn lock_repeat_named_index_cli() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -21128,7 +21822,7 @@ fn lock_repeat_named_index_cli() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -21225,11 +21919,11 @@ fn lock_named_index_overlap() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
-            "sys_platform == 'linux'",
             "sys_platform != 'linux'",
+            "sys_platform == 'linux'",
         ]
 
         [options]
@@ -21306,7 +22000,7 @@ fn lock_explicit_virtual_project() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -21517,7 +22211,7 @@ fn lock_implicit_virtual_project() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -21737,7 +22431,7 @@ fn lock_implicit_package_path() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -21908,7 +22602,7 @@ fn lock_split_python_environment() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.7"
         resolution-markers = [
             "python_full_version < '3.8'",
@@ -22011,12 +22705,12 @@ fn lock_python_upper_bound() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.8"
         resolution-markers = [
-            "python_full_version >= '3.9' and python_full_version < '3.13'",
             "python_full_version < '3.9'",
             "python_full_version >= '3.13'",
+            "python_full_version >= '3.9' and python_full_version < '3.13'",
         ]
 
         [options]
@@ -22375,11 +23069,11 @@ fn lock_simplified_environments() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = "==3.11.*"
         resolution-markers = [
-            "sys_platform == 'darwin'",
             "sys_platform != 'darwin'",
+            "sys_platform == 'darwin'",
         ]
         supported-markers = [
             "sys_platform == 'darwin'",
@@ -22474,7 +23168,7 @@ fn lock_dependency_metadata() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -22692,7 +23386,7 @@ fn lock_dependency_metadata_git() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -22794,7 +23488,7 @@ fn lock_strip_fragment() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -23164,11 +23858,11 @@ fn lock_change_requires_python() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
-            "python_full_version >= '3.13'",
             "python_full_version < '3.13'",
+            "python_full_version >= '3.13'",
         ]
 
         [options]
@@ -23271,12 +23965,12 @@ fn lock_change_requires_python() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.10"
         resolution-markers = [
-            "python_full_version >= '3.13'",
-            "python_full_version == '3.12.*'",
             "python_full_version < '3.12'",
+            "python_full_version == '3.12.*'",
+            "python_full_version >= '3.13'",
         ]
 
         [options]
@@ -23416,7 +24110,7 @@ async fn lock_keyring_credentials() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -23513,7 +24207,7 @@ async fn lock_keyring_explicit_always() -> Result<()> {
       × No solution found when resolving dependencies:
       ╰─▶ Because iniconfig was not found in the package registry and your project depends on iniconfig, we can conclude that your project's requirements are unsatisfiable.
 
-    hint: An index URL (http://[LOCALHOST]/basic-auth/simple) could not be queried due to a lack of valid authentication credentials (401 Unauthorized)
+    hint: The `proxy` index (http://[LOCALHOST]/basic-auth/simple) could not be queried due to a lack of valid authentication credentials (401 Unauthorized). Credential sources checked: keyring (rejected). To provide credentials for this index, set `UV_INDEX_PROXY_USERNAME` and `UV_INDEX_PROXY_PASSWORD` or run `uv auth login http://[LOCALHOST]/basic-auth/simple`.
     ");
 
     // With valid credentials, we should succeed
@@ -23603,7 +24297,7 @@ async fn lock_keyring_credentials_always_authenticate_fetches_username() -> Resu
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -23728,7 +24422,7 @@ fn lock_multiple_sources() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
             "sys_platform != 'win32'",
@@ -23901,11 +24595,11 @@ fn lock_multiple_sources_index_disjoint_markers() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
-            "sys_platform == 'win32'",
             "sys_platform != 'win32'",
+            "sys_platform == 'win32'",
         ]
 
         [options]
@@ -24027,11 +24721,11 @@ fn lock_multiple_sources_index_mixed() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
-            "sys_platform == 'win32'",
             "sys_platform != 'win32'",
+            "sys_platform == 'win32'",
         ]
 
         [options]
@@ -24156,11 +24850,11 @@ fn lock_multiple_sources_index_non_total() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
-            "sys_platform == 'win32'",
             "sys_platform != 'win32'",
+            "sys_platform == 'win32'",
         ]
 
         [options]
@@ -24252,11 +24946,11 @@ fn lock_multiple_sources_index_explicit() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
-            "sys_platform == 'win32'",
             "sys_platform != 'win32'",
+            "sys_platform == 'win32'",
         ]
 
         [options]
@@ -24393,11 +25087,11 @@ fn lock_multiple_sources_non_total() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
-            "sys_platform == 'darwin'",
             "sys_platform != 'darwin'",
+            "sys_platform == 'darwin'",
         ]
 
         [options]
@@ -24489,7 +25183,7 @@ fn lock_multiple_sources_respect_marker() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -24566,7 +25260,7 @@ fn lock_multiple_sources_extra() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -24965,7 +25659,7 @@ fn lock_group_include() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -25150,11 +25844,11 @@ fn lock_group_requires_python() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
-            "python_full_version >= '3.13'",
             "python_full_version < '3.13'",
+            "python_full_version >= '3.13'",
         ]
 
         [options]
@@ -25270,13 +25964,13 @@ fn lock_group_includes_requires_python() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
-            "python_full_version >= '3.13.1'",
-            "python_full_version >= '3.13' and python_full_version < '3.13.1'",
-            "python_full_version >= '3.12.[X]' and python_full_version < '3.13'",
             "python_full_version < '3.12.[X]'",
+            "python_full_version >= '3.12.[X]' and python_full_version < '3.13'",
+            "python_full_version >= '3.13' and python_full_version < '3.13.1'",
+            "python_full_version >= '3.13.1'",
         ]
 
         [options]
@@ -25480,11 +26174,11 @@ fn lock_group_includes_requires_python_contradiction() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
-            "python_full_version >= '3.13'",
             "python_full_version < '3.13'",
+            "python_full_version >= '3.13'",
         ]
 
         [options]
@@ -25950,7 +26644,7 @@ fn lock_group_workspace() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -26138,7 +26832,7 @@ fn lock_transitive_git() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -26290,7 +26984,7 @@ fn lock_dynamic_version() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -26326,7 +27020,7 @@ fn lock_dynamic_version() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -26395,7 +27089,7 @@ fn lock_dynamic_version_dependencies() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -26431,7 +27125,7 @@ fn lock_dynamic_version_dependencies() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -26584,7 +27278,7 @@ fn lock_dynamic_version_workspace_member() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -26651,7 +27345,7 @@ fn lock_dynamic_version_workspace_member() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -26767,7 +27461,7 @@ fn lock_dynamic_version_path_dependency() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -26828,7 +27522,7 @@ fn lock_dynamic_version_path_dependency() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -26926,7 +27620,7 @@ fn lock_dynamic_version_self_extra_hatchling() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -27078,7 +27772,7 @@ fn lock_dynamic_version_self_extra_setuptools() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -27222,7 +27916,7 @@ fn lock_dynamic_built_cache() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -27263,7 +27957,7 @@ fn lock_dynamic_built_cache() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -27336,11 +28030,11 @@ fn lock_shared_build_dependency() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.8"
         resolution-markers = [
-            "python_full_version >= '3.9'",
             "python_full_version < '3.9'",
+            "python_full_version >= '3.9'",
         ]
 
         [options]
@@ -27610,7 +28304,7 @@ fn lock_dynamic_to_static() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -27663,7 +28357,7 @@ fn lock_dynamic_to_static() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -27715,7 +28409,7 @@ fn lock_static_to_dynamic() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -27788,7 +28482,7 @@ fn lock_static_to_dynamic() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -27834,7 +28528,7 @@ fn lock_bump_static_version() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -27884,7 +28578,7 @@ fn lock_bump_static_version() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -28211,7 +28905,7 @@ fn lock_relative_project() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -28305,7 +28999,7 @@ fn lock_recursive_extra() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -28437,7 +29131,7 @@ fn lock_no_build_static_metadata() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -28519,6 +29213,73 @@ fn lock_no_build_dynamic_metadata() -> Result<()> {
     Ok(())
 }
 
+/// Read dynamic dependencies from the file declared via `tool.uv.static-dependencies`, rather than
+/// building the project.
+#[cfg(feature = "test-universal")]
+#[test]
+fn lock_static_dependencies() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "dummy"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dynamic = ["dependencies"]
+
+        [tool.uv]
+        static-dependencies = "requirements.in"
+        "#,
+    )?;
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(indoc! {r"
+        # Runtime dependencies.
+        iniconfig  # Inline comment.
+        typing-extensions \
+            >=4
+    "})?;
+
+    // Building is disabled, so the dependencies must be read from `requirements.in`.
+    uv_snapshot!(context.filters(), context.lock().arg("--no-build"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    uv_snapshot!(context.filters(), context.lock().arg("--no-build").arg("--locked"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    // Changes to `requirements.in` should invalidate the lockfile.
+    requirements_in.write_str("iniconfig")?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--no-build").arg("--locked"), @"
+    exit_code: 1 (failure)
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    error: The lockfile at `uv.lock` needs to be updated, but `--locked` was provided.
+
+    hint: To update the lockfile, run `uv lock`.
+    ");
+
+    // Files with pip options can't be read statically, so uv falls back to building the project.
+    requirements_in.write_str("-r base.in")?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--no-build"), @"
+    exit_code: 1 (failure)
+    ----- stderr -----
+      × Failed to build `dummy @ file://[TEMP_DIR]/`
+      ╰─▶ Building source distributions for `dummy` is disabled
+    ");
+
+    Ok(())
+}
+
 #[cfg(feature = "test-universal")]
 #[test]
 fn lock_self_compatible() -> Result<()> {
@@ -28549,7 +29310,7 @@ fn lock_self_compatible() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -28638,7 +29399,7 @@ fn lock_self_exact() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -28758,7 +29519,7 @@ fn lock_self_extra_to_extra_compatible() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -28914,7 +29675,7 @@ fn lock_self_extra_compatible() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -29035,7 +29796,7 @@ fn lock_self_marker_compatible() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -29157,7 +29918,7 @@ fn lock_split_on_windows() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
             "sys_platform != 'win32'",
@@ -29276,7 +30037,7 @@ fn lock_arm() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
             "platform_machine == 'arm64'",
@@ -29349,7 +30110,7 @@ fn lock_x86_64() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
             "platform_machine == 'x86_64'",
@@ -29423,7 +30184,7 @@ fn lock_x86() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
             "platform_machine == 'i686'",
@@ -29493,7 +30254,7 @@ fn lock_script() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.11"
 
         [options]
@@ -29624,7 +30385,7 @@ fn lock_script_path() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.11"
 
         [options]
@@ -29758,7 +30519,7 @@ fn lock_script_editable_path_dependency_change() -> Result<()> {
             lock,
             @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.11"
 
         [options]
@@ -29842,7 +30603,7 @@ fn lock_script_editable_path_dependency_change() -> Result<()> {
             lock,
             @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.11"
 
         [options]
@@ -29909,7 +30670,7 @@ fn lock_script_initialize() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -30015,14 +30776,14 @@ fn lock_pytorch_cpu() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12.[X]"
         resolution-markers = [
+            "(python_full_version < '3.13' and platform_machine == 'aarch64' and platform_python_implementation == 'CPython' and sys_platform == 'linux' and extra == 'extra-7-project-cpu' and extra != 'extra-7-project-cu124') or (sys_platform == 'darwin' and extra == 'extra-7-project-cpu' and extra != 'extra-7-project-cu124')",
             "(python_full_version >= '3.13' and extra != 'extra-7-project-cpu' and extra == 'extra-7-project-cu124') or (platform_machine != 'aarch64' and extra != 'extra-7-project-cpu' and extra == 'extra-7-project-cu124') or (platform_python_implementation != 'CPython' and extra != 'extra-7-project-cpu' and extra == 'extra-7-project-cu124') or (sys_platform != 'linux' and extra != 'extra-7-project-cpu' and extra == 'extra-7-project-cu124')",
-            "python_full_version < '3.13' and platform_machine == 'aarch64' and platform_python_implementation == 'CPython' and sys_platform == 'linux' and extra != 'extra-7-project-cpu' and extra == 'extra-7-project-cu124'",
             "(python_full_version >= '3.13' and sys_platform == 'linux' and extra == 'extra-7-project-cpu' and extra != 'extra-7-project-cu124') or (platform_machine != 'aarch64' and sys_platform == 'linux' and extra == 'extra-7-project-cpu' and extra != 'extra-7-project-cu124') or (platform_python_implementation != 'CPython' and sys_platform == 'linux' and extra == 'extra-7-project-cpu' and extra != 'extra-7-project-cu124') or (sys_platform != 'darwin' and sys_platform != 'linux' and extra == 'extra-7-project-cpu' and extra != 'extra-7-project-cu124')",
-            "(python_full_version < '3.13' and platform_machine == 'aarch64' and platform_python_implementation == 'CPython' and sys_platform == 'linux' and extra == 'extra-7-project-cpu' and extra != 'extra-7-project-cu124') or (sys_platform == 'darwin' and extra == 'extra-7-project-cpu' and extra != 'extra-7-project-cu124')",
             "extra != 'extra-7-project-cpu' and extra != 'extra-7-project-cu124'",
+            "python_full_version < '3.13' and platform_machine == 'aarch64' and platform_python_implementation == 'CPython' and sys_platform == 'linux' and extra != 'extra-7-project-cpu' and extra == 'extra-7-project-cu124'",
         ]
         conflicts = [[
             { package = "project", extra = "cpu" },
@@ -30666,15 +31427,15 @@ fn lock_pytorch_index_preferences() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.10.0"
         resolution-markers = [
-            "sys_platform != 'darwin' and extra != 'extra-7-project-cpu' and extra == 'extra-7-project-cu118'",
-            "sys_platform == 'darwin' and extra != 'extra-7-project-cpu' and extra == 'extra-7-project-cu118'",
             "(python_full_version >= '3.13' and sys_platform == 'linux' and extra == 'extra-7-project-cpu' and extra != 'extra-7-project-cu118') or (platform_machine != 'aarch64' and sys_platform == 'linux' and extra == 'extra-7-project-cpu' and extra != 'extra-7-project-cu118') or (platform_python_implementation != 'CPython' and sys_platform == 'linux' and extra == 'extra-7-project-cpu' and extra != 'extra-7-project-cu118') or (sys_platform != 'darwin' and sys_platform != 'linux' and extra == 'extra-7-project-cpu' and extra != 'extra-7-project-cu118')",
+            "extra != 'extra-7-project-cpu' and extra != 'extra-7-project-cu118'",
             "python_full_version < '3.13' and platform_machine == 'aarch64' and platform_python_implementation == 'CPython' and sys_platform == 'linux' and extra == 'extra-7-project-cpu' and extra != 'extra-7-project-cu118'",
+            "sys_platform != 'darwin' and extra != 'extra-7-project-cpu' and extra == 'extra-7-project-cu118'",
+            "sys_platform == 'darwin' and extra != 'extra-7-project-cpu' and extra == 'extra-7-project-cu118'",
             "sys_platform == 'darwin' and extra == 'extra-7-project-cpu' and extra != 'extra-7-project-cu118'",
-            "extra != 'extra-7-project-cpu' and extra != 'extra-7-project-cu118'",
         ]
         conflicts = [[
             { package = "project", extra = "cpu" },
@@ -31131,11 +31892,11 @@ fn lock_intel_mac() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.11"
         resolution-markers = [
-            "(python_full_version >= '3.12' and platform_machine != 'x86_64') or (python_full_version >= '3.12' and sys_platform != 'darwin')",
             "(python_full_version < '3.12' and platform_machine != 'x86_64') or (python_full_version < '3.12' and sys_platform != 'darwin')",
+            "(python_full_version >= '3.12' and platform_machine != 'x86_64') or (python_full_version >= '3.12' and sys_platform != 'darwin')",
             "platform_machine == 'x86_64' and sys_platform == 'darwin'",
         ]
         required-markers = [
@@ -31409,8 +32170,8 @@ fn lock_intel_mac() -> Result<()> {
         version = "2.5.1"
         source = { registry = "https://pypi.org/simple" }
         resolution-markers = [
-            "(python_full_version >= '3.12' and platform_machine != 'x86_64') or (python_full_version >= '3.12' and sys_platform != 'darwin')",
             "(python_full_version < '3.12' and platform_machine != 'x86_64') or (python_full_version < '3.12' and sys_platform != 'darwin')",
+            "(python_full_version >= '3.12' and platform_machine != 'x86_64') or (python_full_version >= '3.12' and sys_platform != 'darwin')",
         ]
         dependencies = [
             { name = "filelock" },
@@ -31520,11 +32281,11 @@ fn lock_pytorch_local_preference() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12.[X]"
         resolution-markers = [
-            "sys_platform == 'darwin'",
             "sys_platform != 'darwin'",
+            "sys_platform == 'darwin'",
         ]
         supported-markers = [
             "sys_platform == 'darwin'",
@@ -31853,11 +32614,11 @@ fn windows_arm() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = "==3.12.*"
         resolution-markers = [
-            "platform_machine == 'x86_64' and sys_platform == 'linux'",
             "platform_machine == 'AMD64' and sys_platform == 'win32'",
+            "platform_machine == 'x86_64' and sys_platform == 'linux'",
         ]
         supported-markers = [
             "platform_machine == 'x86_64' and sys_platform == 'linux'",
@@ -31928,7 +32689,7 @@ fn windows_amd64_required() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = "==3.12.*"
         required-markers = [
             "platform_machine == 'x86' and sys_platform == 'win32'",
@@ -32000,7 +32761,7 @@ fn windows_arm64_required() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = "==3.12.*"
         required-markers = [
             "platform_machine == 'ARM64' and sys_platform == 'win32'",
@@ -32066,7 +32827,7 @@ fn lock_empty_extra() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -32165,7 +32926,7 @@ fn lock_empty_extra() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -32239,9 +33000,9 @@ fn lock_invalid_fork_markers() -> Result<()> {
         version = 1
         requires-python = ">=3.8"
         resolution-markers = [
-            "python_full_version >= '3.10' and platform_python_implementation == 'CPython'",
-            "python_full_version == '3.9.*'",
             "python_full_version < '3.9'",
+            "python_full_version == '3.9.*'",
+            "python_full_version >= '3.10' and platform_python_implementation == 'CPython'",
         ]
 
         [options]
@@ -32321,7 +33082,7 @@ fn lock_omit_wheels_exclude_newer() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -32460,12 +33221,12 @@ fn lock_requires_python_empty_lock_file() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = "==3.13.0"
         resolution-markers = [
-            "sys_platform == 'darwin'",
-            "platform_machine == 'aarch64' and sys_platform == 'linux'",
             "(platform_machine != 'aarch64' and sys_platform == 'linux') or (sys_platform != 'darwin' and sys_platform != 'linux')",
+            "platform_machine == 'aarch64' and sys_platform == 'linux'",
+            "sys_platform == 'darwin'",
         ]
 
         [options]
@@ -32533,12 +33294,12 @@ fn lock_requires_python_empty_lock_file() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = "==3.13.2"
         resolution-markers = [
-            "sys_platform == 'darwin'",
-            "platform_machine == 'aarch64' and sys_platform == 'linux'",
             "(platform_machine != 'aarch64' and sys_platform == 'linux') or (sys_platform != 'darwin' and sys_platform != 'linux')",
+            "platform_machine == 'aarch64' and sys_platform == 'linux'",
+            "sys_platform == 'darwin'",
         ]
 
         [options]
@@ -32708,7 +33469,7 @@ async fn lock_trailing_slash_index_url_in_pyproject_not_index_argument() -> Resu
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -33076,7 +33837,7 @@ fn lock_trailing_slash_find_links() -> Result<()> {
             assert_snapshot!(
                 lock, @r#"
             version = 1
-            revision = 3
+            revision = 4
             requires-python = ">=3.12"
 
             [options]
@@ -33150,7 +33911,7 @@ fn lock_trailing_slash_find_links() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -33272,7 +34033,7 @@ fn lock_exclude_newer_disable_cli() -> Result<()> {
     }, {
         assert_snapshot!(context.read("uv.lock"), @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [[package]]
@@ -33318,7 +34079,7 @@ fn lock_exclude_newer_disable_environment() -> Result<()> {
     }, {
         assert_snapshot!(context.read("uv.lock"), @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [[package]]
@@ -33364,7 +34125,7 @@ fn lock_exclude_newer_disable_config() -> Result<()> {
     }, {
         assert_snapshot!(context.read("uv.lock"), @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [[package]]
@@ -33415,7 +34176,7 @@ fn lock_exclude_newer_package_disable() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -33501,7 +34262,7 @@ fn lock_exclude_newer_package() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -34440,7 +35201,7 @@ fn lock_android() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
             "sys_platform == 'android'",
@@ -34532,11 +35293,11 @@ fn lock_required_intersection() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
-            "platform_machine == 'x86_64' and sys_platform == 'linux'",
             "platform_machine == 'arm64' and sys_platform == 'darwin'",
+            "platform_machine == 'x86_64' and sys_platform == 'linux'",
         ]
         supported-markers = [
             "platform_machine == 'x86_64' and sys_platform == 'linux'",
@@ -34743,7 +35504,7 @@ fn lock_refresh() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -34876,7 +35637,7 @@ fn lock_refresh_deindents_lockfile() -> Result<()> {
     ");
     assert_snapshot!(context.read("uv.lock"), @r#"
     version = 1
-    revision = 3
+    revision = 4
     requires-python = ">=3.12"
 
     [options]
@@ -35288,7 +36049,7 @@ fn lock_supported_environment_abi3_wheel() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
             "python_full_version < '3.13' and platform_machine == 'x86_64' and sys_platform == 'linux'",
@@ -35455,3 +36216,456 @@ fn lock_frozen_warning() -> Result<()> {
 
     Ok(())
 }
+
+/// Print the lockfile schema with `uv lock --schema`.
+#[test]
+fn lock_schema() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let output = context.lock().arg("--schema").output()?;
+    assert!(output.status.success());
+
+    let schema: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(schema["title"], "uv.lock");
+    assert!(schema["properties"]["package"].is_object());
+    assert!(schema["properties"]["requires-python"].is_object());
+
+    Ok(())
+}
+
+/// Validate lockfiles with `uv lock --validate`.
+#[test]
+fn lock_validate() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    context.temp_dir.child("uv.lock").write_str(indoc! {r#"
+        version = 1
+        revision = 4
+        requires-python = ">=3.12"
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646, upload-time = "2023-01-07T11:08:11.254Z" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892, upload-time = "2023-01-07T11:08:09.864Z" },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "iniconfig" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "iniconfig", specifier = "==2.0.0" }]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--validate").arg("uv.lock"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    `uv.lock` is a valid lockfile (version 1, revision 4)
+    ");
+
+    // Include an unknown field, a dependency on a missing package, and a wheel without a hash.
+    context.temp_dir.child("uv.lock").write_str(indoc! {r#"
+        version = 1
+        revision = 4
+        requires-python = ">=3.12"
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646, upload-time = "2023-01-07T11:08:11.254Z" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", size = 5892, upload-time = "2023-01-07T11:08:09.864Z" },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "iniconfig" },
+            { name = "missing" },
+        ]
+        requires-dist = [{ name = "iniconfig", specifier = "==2.0.0" }]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--validate").arg("uv.lock"), @"
+    exit_code: 1 (failure)
+    ----- stderr -----
+    Found 3 problems in `uv.lock`
+    Unknown field `package[1].requires-dist`
+    `project==0.1.0 @ virtual+.` depends on `missing`, which is not present in the lockfile
+    `iniconfig-2.0.0-py3-none-any.whl` for `iniconfig==2.0.0 @ registry+https://pypi.org/simple` is missing a hash, but other artifacts in the lockfile include hashes
+    ");
+
+    // Lockfiles with an unsupported version are rejected.
+    context.temp_dir.child("uv.lock").write_str(indoc! {r#"
+        version = 2
+        requires-python = ">=3.12"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--validate").arg("uv.lock"), @"
+    exit_code: 1 (failure)
+    ----- stderr -----
+    Found 1 problem in `uv.lock`
+    Unsupported lockfile version 2 (expected version 1)
+    ");
+
+    Ok(())
+}
+
+/// `uv lock --prefer-installed` should prefer the versions installed in the project environment.
+#[test]
+fn lock_prefer_installed() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio", "iniconfig>=2"]
+    "#})?;
+
+    // Install older versions into the project environment.
+    context
+        .pip_install()
+        .arg("anyio==3.7.0")
+        .arg("iniconfig==1.1.1")
+        .assert()
+        .success();
+
+    // The installed version of `anyio` should be preserved, but `iniconfig` should be upgraded.
+    uv_snapshot!(context.filters(), context.lock().arg("--prefer-installed"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Could not preserve installed iniconfig v1.1.1 (excluded by `iniconfig>=2`); locked v2.0.0
+    ");
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("name = \"anyio\"\nversion = \"3.7.0\""));
+
+    // Locking a script requires an explicit environment.
+    let script = context.temp_dir.child("script.py");
+    script.write_str(indoc! {r#"
+        # /// script
+        # requires-python = ">=3.12"
+        # dependencies = ["anyio"]
+        # ///
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--script").arg("script.py").arg("--prefer-installed"), @"
+    exit_code: 2 (error)
+    ----- stderr -----
+    error: `--prefer-installed` requires the path to an environment when locking a script
+    ");
+
+    uv_snapshot!(context.filters(), context.lock().arg("--script").arg("script.py").arg("--prefer-installed").arg(".venv"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    let lock = context.read("script.py.lock");
+    assert!(lock.contains("name = \"anyio\"\nversion = \"3.7.0\""));
+
+    Ok(())
+}
+
+/// `uv lock --resolve-conflicts` should regenerate a lockfile with merge conflicts, preferring the
+/// versions locked on either side of the conflict.
+#[test]
+fn lock_resolve_conflicts() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+
+    // Lock an older `anyio` on the current branch.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+    "#})?;
+    context.lock().assert().success();
+    let ours = context.read("uv.lock");
+
+    // Lock an older `iniconfig` on the incoming branch.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==1.1.1"]
+    "#})?;
+    context.lock().assert().success();
+    let theirs = context.read("uv.lock");
+
+    // Merge the requirements from both branches, leaving the lockfile conflicted.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio", "iniconfig"]
+    "#})?;
+    context.temp_dir.child("uv.lock").write_str(&format!(
+        "<<<<<<< HEAD\n{ours}=======\n{theirs}>>>>>>> feature\n"
+    ))?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--resolve-conflicts"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolving merge conflicts in `uv.lock`
+    Resolved 5 packages in [TIME]
+    ");
+
+    // The versions locked on either branch should be preserved.
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("name = \"anyio\"\nversion = \"3.7.0\""));
+    assert!(lock.contains("name = \"iniconfig\"\nversion = \"1.1.1\""));
+
+    // Without conflicts, the project is locked as usual.
+    uv_snapshot!(context.filters(), context.lock().arg("--resolve-conflicts"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    warning: No merge conflicts found in `uv.lock`; locking as usual
+    Resolved 5 packages in [TIME]
+    ");
+
+    // Conflicts in the `pyproject.toml` must be resolved first.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        <<<<<<< HEAD
+        dependencies = ["anyio==3.7.0"]
+        =======
+        dependencies = ["iniconfig==1.1.1"]
+        >>>>>>> feature
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--resolve-conflicts"), @"
+    exit_code: 2 (error)
+    ----- stderr -----
+    error: `pyproject.toml` contains unresolved merge conflicts; resolve them before running `uv lock --resolve-conflicts`
+    ");
+
+    Ok(())
+}
+
+/// `require-metadata` should reject distributions that declare an older core metadata version, and
+/// `uv lock --report-metadata-versions` should summarize the declared versions.
+#[test]
+fn lock_require_metadata() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+    "#})?;
+
+    context.lock().assert().success();
+
+    // The report forces a resolution, even though the lockfile is up-to-date.
+    uv_snapshot!(context.filters(), context.lock().arg("--report-metadata-versions"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    Core metadata versions:
+      2.1: 1 package
+      unknown: 1 package
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    // Require a newer core metadata version than `iniconfig` declares.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+
+        [tool.uv]
+        require-metadata = "9.9"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    exit_code: 1 (failure)
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because iniconfig==2.0.0 has core metadata older than 9.9 and your project depends on iniconfig==2.0.0, we can conclude that your project's requirements are unsatisfiable.
+
+    hint: `iniconfig` (v2.0.0) was rejected because `iniconfig-2.0.0-py3-none-any.whl` declares core metadata version 2.1, but `require-metadata` requires 9.9 or later
+    ");
+
+    Ok(())
+}
+
+/// Lock a package with platform-specific wheel URLs, gated by markers in `tool.uv.sources`. Each
+/// fork should claim the wheel for its own platform, regardless of the current platform.
+#[test]
+fn lock_platform_specific_wheel_sources() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = "==3.12.*"
+        dependencies = ["markupsafe"]
+
+        [tool.uv.sources]
+        markupsafe = [
+            { url = "https://files.pythonhosted.org/packages/0a/0d/2454f072fae3b5a137c119abf15465d1771319dfe9e4acbb31722a0fff91/MarkupSafe-2.1.5-cp312-cp312-manylinux_2_17_x86_64.manylinux2014_x86_64.whl", marker = "sys_platform == 'linux'" },
+            { url = "https://files.pythonhosted.org/packages/3f/14/c3554d512d5f9100a95e737502f4a2323a1959f6d0d01e0d0997b35f7b10/MarkupSafe-2.1.5-cp312-cp312-win_amd64.whl", marker = "sys_platform == 'win32'" },
+        ]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    // Gating the Windows wheel on Linux is an error, and should name the source.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = "==3.12.*"
+        dependencies = ["markupsafe"]
+
+        [tool.uv.sources]
+        markupsafe = [
+            { url = "https://files.pythonhosted.org/packages/3f/14/c3554d512d5f9100a95e737502f4a2323a1959f6d0d01e0d0997b35f7b10/MarkupSafe-2.1.5-cp312-cp312-win_amd64.whl", marker = "sys_platform == 'linux'" },
+            { url = "https://files.pythonhosted.org/packages/0a/0d/2454f072fae3b5a137c119abf15465d1771319dfe9e4acbb31722a0fff91/MarkupSafe-2.1.5-cp312-cp312-manylinux_2_17_x86_64.manylinux2014_x86_64.whl", marker = "sys_platform == 'win32'" },
+        ]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    exit_code: 1 (failure)
+    ----- stderr -----
+      × Failed to resolve dependencies for `project` (v0.1.0)
+      ╰─▶ The wheel source `https://files.pythonhosted.org/packages/3f/14/c3554d512d5f9100a95e737502f4a2323a1959f6d0d01e0d0997b35f7b10/MarkupSafe-2.1.5-cp312-cp312-win_amd64.whl` for package `markupsafe` is used when `sys_platform == 'linux'`, but the wheel (`MarkupSafe-2.1.5-cp312-cp312-win_amd64.whl`) only supports `python_full_version == '3.12.*' and platform_python_implementation == 'CPython' and sys_platform == 'win32' and platform_machine == 'AMD64'`
+    ");
+
+    Ok(())
+}
+
+/// Extras and dependency groups that share a name but differ in their contents should be reported
+/// by `uv lock --check-config`, and rejected by `uv lock` with `tool.uv.strict-config`.
+#[test]
+fn lock_check_config() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [project.optional-dependencies]
+        docs = ["sphinx"]
+        test = ["iniconfig"]
+
+        [dependency-groups]
+        all = ["iniconfig"]
+        test = ["iniconfig>=2", "pytest"]
+
+        [tool.uv]
+        default-groups = ["docs"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--check-config"), @r#"
+    exit_code: 1 (failure)
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: The extra `test` and the dependency group `test` in `pyproject.toml` have different contents:
+      [project.optional-dependencies] test = ["iniconfig"]
+      [dependency-groups]             test = ["iniconfig>=2", "pytest"]
+    warning: The dependency group `all` in `pyproject.toml` shadows the conventional `all` extra, but doesn't enable any extras (use `--all-extras` to enable all extras):
+      [dependency-groups]             all     = ["iniconfig"]
+      [project.optional-dependencies] (names) = ["docs", "test"]
+    warning: `tool.uv.default-groups` in `pyproject.toml` includes `docs`, which is an extra, not a dependency group:
+      [tool.uv]                       default-groups = ["docs"]
+      [project.optional-dependencies] docs           = ["sphinx"]
+    "#);
+
+    // With `strict-config`, the inconsistencies are errors.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [project.optional-dependencies]
+        test = ["iniconfig"]
+
+        [dependency-groups]
+        test = ["iniconfig>=2"]
+
+        [tool.uv]
+        strict-config = true
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r#"
+    exit_code: 2 (error)
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Found inconsistencies in the project configuration, and `tool.uv.strict-config` is enabled:
+
+    The extra `test` and the dependency group `test` in `pyproject.toml` have different contents:
+      [project.optional-dependencies] test = ["iniconfig"]
+      [dependency-groups]             test = ["iniconfig>=2"]
+    "#);
+
+    // Equivalent definitions (modulo formatting) are fine.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [project.optional-dependencies]
+        test = ["iniconfig >= 2"]
+
+        [dependency-groups]
+        test = ["iniconfig>=2"]
+
+        [tool.uv]
+        strict-config = true
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--check-config"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+
+    ----- stderr -----
+    No issues found in the project configuration
+    ");
+
+    Ok(())
+}
@@ -45,7 +45,7 @@ fn extra_conflict_discovery_respects_parent_reachability() -> Result<()> {
     context.temp_dir.child("uv.lock").write_str(
         r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "q", extra = "bar" },
@@ -178,7 +178,7 @@ fn extra_basic() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", extra = "extra1" },
@@ -348,7 +348,7 @@ fn extra_basic_three_extras() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", extra = "extra1" },
@@ -778,7 +778,7 @@ fn extra_multiple_independent() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", extra = "extra1" },
@@ -925,7 +925,7 @@ fn extra_config_change_ignore_lockfile() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", extra = "extra1" },
@@ -1739,7 +1739,7 @@ fn extra_depends_on_conflicting_extra_transitive() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "example", extra = "bar" },
@@ -1909,7 +1909,7 @@ fn group_basic() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", group = "group1" },
@@ -2046,7 +2046,7 @@ fn group_default() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", group = "group1" },
@@ -2261,7 +2261,7 @@ fn groups_respect_supported_environments_when_filtering_wheels() -> Result<()> {
             lock,
             @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
             "platform_machine == 'x86_64' and sys_platform == 'linux'",
@@ -2366,7 +2366,7 @@ fn extra_conflict_environments_omit_redundant_markers() -> Result<()> {
             lock,
             @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.10.0"
         resolution-markers = [
             "platform_machine == 'x86_64' and sys_platform == 'darwin'",
@@ -2575,7 +2575,7 @@ fn mixed() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", extra = "extra1" },
@@ -2727,7 +2727,7 @@ fn multiple_sources_index_disjoint_extras() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", extra = "cu118" },
@@ -2871,7 +2871,7 @@ fn multiple_sources_index_disjoint_groups() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", group = "cu118" },
@@ -3014,7 +3014,7 @@ fn multiple_sources_index_disjoint_extras_with_extra() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", extra = "cu118" },
@@ -3177,13 +3177,13 @@ fn multiple_sources_index_disjoint_extras_with_marker() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
+            "extra != 'extra-7-project-cu118' and extra != 'extra-7-project-cu124'",
             "extra != 'extra-7-project-cu118' and extra == 'extra-7-project-cu124'",
-            "sys_platform == 'darwin' and extra == 'extra-7-project-cu118' and extra != 'extra-7-project-cu124'",
             "sys_platform != 'darwin' and extra == 'extra-7-project-cu118' and extra != 'extra-7-project-cu124'",
-            "extra != 'extra-7-project-cu118' and extra != 'extra-7-project-cu124'",
+            "sys_platform == 'darwin' and extra == 'extra-7-project-cu118' and extra != 'extra-7-project-cu124'",
         ]
         conflicts = [[
             { package = "project", extra = "cu118" },
@@ -3488,7 +3488,7 @@ fn shared_optional_dependency_extra1() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", extra = "bar" },
@@ -3625,7 +3625,7 @@ fn shared_optional_dependency_group1() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", group = "bar" },
@@ -3763,7 +3763,7 @@ fn shared_optional_dependency_mixed1() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", extra = "foo" },
@@ -3905,7 +3905,7 @@ fn shared_optional_dependency_extra2() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = "==3.11.*"
         conflicts = [[
             { package = "project", extra = "bar" },
@@ -4043,7 +4043,7 @@ fn shared_optional_dependency_group2() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = "==3.11.*"
         conflicts = [[
             { package = "project", group = "bar" },
@@ -4186,7 +4186,7 @@ fn shared_optional_dependency_mixed2() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = "==3.11.*"
         conflicts = [[
             { package = "project", extra = "foo" },
@@ -4327,7 +4327,7 @@ fn shared_dependency_extra() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", extra = "bar" },
@@ -4490,7 +4490,7 @@ fn shared_dependency_group() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", group = "bar" },
@@ -4654,7 +4654,7 @@ fn shared_dependency_mixed() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", extra = "foo" },
@@ -4857,7 +4857,7 @@ conflicts = [
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = "==3.11.*"
         conflicts = [[
             { package = "project", extra = "x1" },
@@ -5040,7 +5040,7 @@ fn jinja_no_conflict_markers1() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", extra = "cu118" },
@@ -5199,13 +5199,13 @@ fn jinja_no_conflict_markers2() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
+            "extra != 'extra-7-project-cu118' and extra != 'extra-7-project-cu124'",
             "extra != 'extra-7-project-cu118' and extra == 'extra-7-project-cu124'",
-            "sys_platform == 'darwin' and extra == 'extra-7-project-cu118' and extra != 'extra-7-project-cu124'",
             "sys_platform != 'darwin' and extra == 'extra-7-project-cu118' and extra != 'extra-7-project-cu124'",
-            "extra != 'extra-7-project-cu118' and extra != 'extra-7-project-cu124'",
+            "sys_platform == 'darwin' and extra == 'extra-7-project-cu118' and extra != 'extra-7-project-cu124'",
         ]
         conflicts = [[
             { package = "project", extra = "cu118" },
@@ -5357,7 +5357,7 @@ fn collision_extra() -> Result<()> {
             lock,
             @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "pkg", extra = "bar" },
@@ -5571,7 +5571,7 @@ fn extra_inferences() -> Result<()> {
             lock,
             @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "pkg", extra = "x1" },
@@ -7604,14 +7604,14 @@ fn deduplicate_resolution_markers() -> Result<()> {
             lock,
             @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
+            "extra != 'extra-3-pkg-x1' and extra != 'extra-3-pkg-x2'",
             "sys_platform != 'linux' and extra != 'extra-3-pkg-x1' and extra == 'extra-3-pkg-x2'",
-            "sys_platform == 'linux' and extra != 'extra-3-pkg-x1' and extra == 'extra-3-pkg-x2'",
             "sys_platform != 'linux' and extra == 'extra-3-pkg-x1' and extra != 'extra-3-pkg-x2'",
+            "sys_platform == 'linux' and extra != 'extra-3-pkg-x1' and extra == 'extra-3-pkg-x2'",
             "sys_platform == 'linux' and extra == 'extra-3-pkg-x1' and extra != 'extra-3-pkg-x2'",
-            "extra != 'extra-3-pkg-x1' and extra != 'extra-3-pkg-x2'",
         ]
         conflicts = [[
             { package = "pkg", extra = "x1" },
@@ -7773,7 +7773,7 @@ fn incorrect_extra_simplification_leads_to_multiple_torch_packages() -> Result<(
     }, {
         assert_snapshot!(lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "test", extra = "chgnet" },
@@ -8008,7 +8008,7 @@ fn duplicate_torch_and_sympy_because_of_wrong_inferences() -> Result<()> {
     }, {
         assert_snapshot!(lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "test", extra = "alignn" },
@@ -8268,17 +8268,17 @@ fn overlapping_resolution_markers() -> Result<()> {
             lock,
             @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = "==3.10.*"
         resolution-markers = [
-            "sys_platform == 'linux' and extra != 'extra-14-ads-mega-model-cpu' and extra == 'extra-14-ads-mega-model-cu118'",
-            "sys_platform != 'linux' and extra != 'extra-14-ads-mega-model-cpu' and extra == 'extra-14-ads-mega-model-cu118'",
             "(platform_machine != 'aarch64' and sys_platform == 'linux' and extra == 'extra-14-ads-mega-model-cpu' and extra != 'extra-14-ads-mega-model-cu118') or (platform_python_implementation != 'CPython' and sys_platform == 'linux' and extra == 'extra-14-ads-mega-model-cpu' and extra != 'extra-14-ads-mega-model-cu118')",
             "platform_machine == 'aarch64' and platform_python_implementation == 'CPython' and sys_platform == 'linux' and extra == 'extra-14-ads-mega-model-cpu' and extra != 'extra-14-ads-mega-model-cu118'",
             "sys_platform != 'darwin' and sys_platform != 'linux' and extra == 'extra-14-ads-mega-model-cpu' and extra != 'extra-14-ads-mega-model-cu118'",
+            "sys_platform != 'linux' and extra != 'extra-14-ads-mega-model-cpu' and extra != 'extra-14-ads-mega-model-cu118'",
+            "sys_platform != 'linux' and extra != 'extra-14-ads-mega-model-cpu' and extra == 'extra-14-ads-mega-model-cu118'",
             "sys_platform == 'darwin' and extra == 'extra-14-ads-mega-model-cpu' and extra != 'extra-14-ads-mega-model-cu118'",
             "sys_platform == 'linux' and extra != 'extra-14-ads-mega-model-cpu' and extra != 'extra-14-ads-mega-model-cu118'",
-            "sys_platform != 'linux' and extra != 'extra-14-ads-mega-model-cpu' and extra != 'extra-14-ads-mega-model-cu118'",
+            "sys_platform == 'linux' and extra != 'extra-14-ads-mega-model-cpu' and extra == 'extra-14-ads-mega-model-cu118'",
         ]
         conflicts = [[
             { package = "ads-mega-model", extra = "cpu" },
@@ -8768,9 +8768,9 @@ fn overlapping_resolution_markers() -> Result<()> {
         version = "2.2.2"
         source = { registry = "https://pypi.org/simple" }
         resolution-markers = [
-            "sys_platform == 'linux' and extra != 'extra-14-ads-mega-model-cpu' and extra == 'extra-14-ads-mega-model-cu118'",
             "sys_platform != 'linux' and extra != 'extra-14-ads-mega-model-cpu' and extra == 'extra-14-ads-mega-model-cu118'",
             "sys_platform == 'darwin' and extra == 'extra-14-ads-mega-model-cpu' and extra != 'extra-14-ads-mega-model-cu118'",
+            "sys_platform == 'linux' and extra != 'extra-14-ads-mega-model-cpu' and extra == 'extra-14-ads-mega-model-cu118'",
         ]
         dependencies = [
             { name = "filelock", marker = "(sys_platform != 'darwin' and extra == 'extra-14-ads-mega-model-cu118') or (sys_platform == 'darwin' and extra == 'extra-14-ads-mega-model-cpu') or (extra != 'extra-14-ads-mega-model-cpu' and extra == 'extra-14-ads-mega-model-cu118')" },
@@ -8938,13 +8938,13 @@ fn conditional_sources_keep_default_platform_specific_transitive_dependencies()
             lock,
             @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = "==3.12.*"
         resolution-markers = [
+            "extra != 'extra-10-test-torch-cpu' and extra != 'extra-10-test-torch-cu124'",
             "extra != 'extra-10-test-torch-cpu' and extra == 'extra-10-test-torch-cu124'",
             "sys_platform != 'darwin' and extra == 'extra-10-test-torch-cpu' and extra != 'extra-10-test-torch-cu124'",
             "sys_platform == 'darwin' and extra == 'extra-10-test-torch-cpu' and extra != 'extra-10-test-torch-cu124'",
-            "extra != 'extra-10-test-torch-cpu' and extra != 'extra-10-test-torch-cu124'",
         ]
         conflicts = [[
             { package = "test-torch", extra = "cpu" },
@@ -9399,13 +9399,13 @@ fn avoids_exponential_lock_file_growth() -> Result<()> {
             lock,
             @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
+            "extra != 'extra-27-resolution-markers-for-days-cpu' and extra != 'extra-27-resolution-markers-for-days-cu124'",
             "extra != 'extra-27-resolution-markers-for-days-cpu' and extra == 'extra-27-resolution-markers-for-days-cu124'",
             "sys_platform != 'darwin' and extra == 'extra-27-resolution-markers-for-days-cpu' and extra != 'extra-27-resolution-markers-for-days-cu124'",
             "sys_platform == 'darwin' and extra == 'extra-27-resolution-markers-for-days-cpu' and extra != 'extra-27-resolution-markers-for-days-cu124'",
-            "extra != 'extra-27-resolution-markers-for-days-cpu' and extra != 'extra-27-resolution-markers-for-days-cu124'",
         ]
         conflicts = [[
             { package = "resolution-markers-for-days", extra = "cpu" },
@@ -9811,13 +9811,13 @@ fn avoids_exponential_lock_file_growth() -> Result<()> {
             lock,
             @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
+            "extra != 'extra-27-resolution-markers-for-days-cpu' and extra != 'extra-27-resolution-markers-for-days-cu124'",
             "extra != 'extra-27-resolution-markers-for-days-cpu' and extra == 'extra-27-resolution-markers-for-days-cu124'",
             "sys_platform != 'darwin' and extra == 'extra-27-resolution-markers-for-days-cpu' and extra != 'extra-27-resolution-markers-for-days-cu124'",
             "sys_platform == 'darwin' and extra == 'extra-27-resolution-markers-for-days-cpu' and extra != 'extra-27-resolution-markers-for-days-cu124'",
-            "extra != 'extra-27-resolution-markers-for-days-cpu' and extra != 'extra-27-resolution-markers-for-days-cu124'",
         ]
         conflicts = [[
             { package = "resolution-markers-for-days", extra = "cpu" },
@@ -10257,13 +10257,13 @@ fn do_not_simplify_if_not_all_conflict_extras_satisfy_the_marker_by_themselves()
             lock,
             @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = "==3.12.*"
         resolution-markers = [
+            "extra != 'extra-5-debug-a' and extra != 'extra-5-debug-b'",
+            "extra == 'extra-5-debug-a' and extra != 'extra-5-debug-b'",
             "platform_machine != 'inapplicable' and extra != 'extra-5-debug-a' and extra == 'extra-5-debug-b'",
             "platform_machine == 'inapplicable' and extra != 'extra-5-debug-a' and extra == 'extra-5-debug-b'",
-            "extra == 'extra-5-debug-a' and extra != 'extra-5-debug-b'",
-            "extra != 'extra-5-debug-a' and extra != 'extra-5-debug-b'",
         ]
         conflicts = [[
             { package = "debug", extra = "a" },
@@ -10300,8 +10300,8 @@ fn do_not_simplify_if_not_all_conflict_extras_satisfy_the_marker_by_themselves()
         version = "2.8.0"
         source = { registry = "https://pypi.org/simple" }
         resolution-markers = [
-            "platform_machine == 'inapplicable' and extra != 'extra-5-debug-a' and extra == 'extra-5-debug-b'",
             "extra == 'extra-5-debug-a' and extra != 'extra-5-debug-b'",
+            "platform_machine == 'inapplicable' and extra != 'extra-5-debug-a' and extra == 'extra-5-debug-b'",
         ]
         dependencies = [
             { name = "six", marker = "(platform_machine == 'inapplicable' and extra == 'extra-5-debug-b') or extra == 'extra-5-debug-a'" },
@@ -10474,7 +10474,7 @@ fn many_pairwise_conflicts_shared_extra() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", extra = "a" },
@@ -10638,7 +10638,7 @@ fn project_level_conflict_with_extra() -> Result<()> {
             lock,
             @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "pkg-a" },
@@ -10816,7 +10816,7 @@ fn project_level_conflict_with_extras_and_cross_dependency() -> Result<()> {
             lock,
             @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "pkg-a" },
@@ -11028,7 +11028,7 @@ fn project_level_conflict_with_group() -> Result<()> {
             lock,
             @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "pkg-a" },
@@ -118,7 +118,7 @@ fn wrong_backtracking_basic() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [[package]]
@@ -281,7 +281,7 @@ fn wrong_backtracking_indirect() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [[package]]
@@ -420,12 +420,12 @@ fn fork_allows_non_conflicting_non_overlapping_dependencies() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
+            "sys_platform != 'darwin' and sys_platform != 'linux'",
             "sys_platform == 'darwin'",
             "sys_platform == 'linux'",
-            "sys_platform != 'darwin' and sys_platform != 'linux'",
         ]
 
         [[package]]
@@ -532,7 +532,7 @@ fn fork_allows_non_conflicting_repeated_dependencies() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [[package]]
@@ -629,12 +629,12 @@ fn fork_basic() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
+            "sys_platform != 'darwin' and sys_platform != 'linux'",
             "sys_platform == 'darwin'",
             "sys_platform == 'linux'",
-            "sys_platform != 'darwin' and sys_platform != 'linux'",
         ]
 
         [[package]]
@@ -904,12 +904,12 @@ fn fork_filter_sibling_dependencies() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
-            "sys_platform == 'linux'",
-            "sys_platform == 'darwin'",
             "sys_platform != 'darwin' and sys_platform != 'linux'",
+            "sys_platform == 'darwin'",
+            "sys_platform == 'linux'",
         ]
 
         [[package]]
@@ -1080,7 +1080,7 @@ fn fork_upgrade() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [[package]]
@@ -1198,12 +1198,12 @@ fn fork_incomplete_markers() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
-            "python_full_version >= '3.14'",
-            "python_full_version == '3.13.*'",
             "python_full_version < '3.13'",
+            "python_full_version == '3.13.*'",
+            "python_full_version >= '3.14'",
         ]
 
         [[package]]
@@ -1349,7 +1349,7 @@ fn fork_marker_accrue() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [[package]]
@@ -1547,14 +1547,14 @@ fn fork_marker_inherit_combined_allowed() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
-            "implementation_name == 'pypy' and sys_platform == 'darwin'",
-            "implementation_name == 'cpython' and sys_platform == 'darwin'",
             "implementation_name != 'cpython' and implementation_name != 'pypy' and sys_platform == 'darwin'",
-            "sys_platform == 'linux'",
+            "implementation_name == 'cpython' and sys_platform == 'darwin'",
+            "implementation_name == 'pypy' and sys_platform == 'darwin'",
             "sys_platform != 'darwin' and sys_platform != 'linux'",
+            "sys_platform == 'linux'",
         ]
 
         [[package]]
@@ -1562,9 +1562,9 @@ fn fork_marker_inherit_combined_allowed() -> Result<()> {
         version = "1.0.0"
         source = { registry = "http://[LOCALHOST]/simple/" }
         resolution-markers = [
-            "implementation_name == 'pypy' and sys_platform == 'darwin'",
-            "implementation_name == 'cpython' and sys_platform == 'darwin'",
             "implementation_name != 'cpython' and implementation_name != 'pypy' and sys_platform == 'darwin'",
+            "implementation_name == 'cpython' and sys_platform == 'darwin'",
+            "implementation_name == 'pypy' and sys_platform == 'darwin'",
         ]
         dependencies = [
             { name = "b", version = "1.0.0", source = { registry = "http://[LOCALHOST]/simple/" }, marker = "implementation_name == 'pypy'" },
@@ -1725,14 +1725,14 @@ fn fork_marker_inherit_combined_disallowed() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
-            "implementation_name == 'pypy' and sys_platform == 'darwin'",
-            "implementation_name == 'cpython' and sys_platform == 'darwin'",
             "implementation_name != 'cpython' and implementation_name != 'pypy' and sys_platform == 'darwin'",
-            "sys_platform == 'linux'",
+            "implementation_name == 'cpython' and sys_platform == 'darwin'",
+            "implementation_name == 'pypy' and sys_platform == 'darwin'",
             "sys_platform != 'darwin' and sys_platform != 'linux'",
+            "sys_platform == 'linux'",
         ]
 
         [[package]]
@@ -1740,9 +1740,9 @@ fn fork_marker_inherit_combined_disallowed() -> Result<()> {
         version = "1.0.0"
         source = { registry = "http://[LOCALHOST]/simple/" }
         resolution-markers = [
-            "implementation_name == 'pypy' and sys_platform == 'darwin'",
-            "implementation_name == 'cpython' and sys_platform == 'darwin'",
             "implementation_name != 'cpython' and implementation_name != 'pypy' and sys_platform == 'darwin'",
+            "implementation_name == 'cpython' and sys_platform == 'darwin'",
+            "implementation_name == 'pypy' and sys_platform == 'darwin'",
         ]
         dependencies = [
             { name = "b", version = "1.0.0", source = { registry = "http://[LOCALHOST]/simple/" }, marker = "implementation_name == 'pypy'" },
@@ -1892,14 +1892,14 @@ fn fork_marker_inherit_combined() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
-            "implementation_name == 'pypy' and sys_platform == 'darwin'",
-            "implementation_name == 'cpython' and sys_platform == 'darwin'",
             "implementation_name != 'cpython' and implementation_name != 'pypy' and sys_platform == 'darwin'",
-            "sys_platform == 'linux'",
+            "implementation_name == 'cpython' and sys_platform == 'darwin'",
+            "implementation_name == 'pypy' and sys_platform == 'darwin'",
             "sys_platform != 'darwin' and sys_platform != 'linux'",
+            "sys_platform == 'linux'",
         ]
 
         [[package]]
@@ -1907,9 +1907,9 @@ fn fork_marker_inherit_combined() -> Result<()> {
         version = "1.0.0"
         source = { registry = "http://[LOCALHOST]/simple/" }
         resolution-markers = [
-            "implementation_name == 'pypy' and sys_platform == 'darwin'",
-            "implementation_name == 'cpython' and sys_platform == 'darwin'",
             "implementation_name != 'cpython' and implementation_name != 'pypy' and sys_platform == 'darwin'",
+            "implementation_name == 'cpython' and sys_platform == 'darwin'",
+            "implementation_name == 'pypy' and sys_platform == 'darwin'",
         ]
         dependencies = [
             { name = "b", version = "1.0.0", source = { registry = "http://[LOCALHOST]/simple/" }, marker = "implementation_name == 'pypy'" },
@@ -2052,12 +2052,12 @@ fn fork_marker_inherit_isolated() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
+            "sys_platform != 'darwin' and sys_platform != 'linux'",
             "sys_platform == 'darwin'",
             "sys_platform == 'linux'",
-            "sys_platform != 'darwin' and sys_platform != 'linux'",
         ]
 
         [[package]]
@@ -2198,12 +2198,12 @@ fn fork_marker_inherit_transitive() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
+            "sys_platform != 'darwin' and sys_platform != 'linux'",
             "sys_platform == 'darwin'",
             "sys_platform == 'linux'",
-            "sys_platform != 'darwin' and sys_platform != 'linux'",
         ]
 
         [[package]]
@@ -2352,12 +2352,12 @@ fn fork_marker_inherit() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
+            "sys_platform != 'darwin' and sys_platform != 'linux'",
             "sys_platform == 'darwin'",
             "sys_platform == 'linux'",
-            "sys_platform != 'darwin' and sys_platform != 'linux'",
         ]
 
         [[package]]
@@ -2488,12 +2488,12 @@ fn fork_marker_limited_inherit() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
+            "sys_platform != 'darwin' and sys_platform != 'linux'",
             "sys_platform == 'darwin'",
             "sys_platform == 'linux'",
-            "sys_platform != 'darwin' and sys_platform != 'linux'",
         ]
 
         [[package]]
@@ -2641,12 +2641,12 @@ fn fork_marker_selection() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
+            "sys_platform != 'darwin' and sys_platform != 'linux'",
             "sys_platform == 'darwin'",
             "sys_platform == 'linux'",
-            "sys_platform != 'darwin' and sys_platform != 'linux'",
         ]
 
         [[package]]
@@ -2793,12 +2793,12 @@ fn fork_marker_track() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
+            "sys_platform != 'darwin' and sys_platform != 'linux'",
             "sys_platform == 'darwin'",
             "sys_platform == 'linux'",
-            "sys_platform != 'darwin' and sys_platform != 'linux'",
         ]
 
         [[package]]
@@ -2943,7 +2943,7 @@ fn fork_non_fork_marker_transitive() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [[package]]
@@ -3230,12 +3230,12 @@ fn fork_overlapping_markers_basic() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
-            "python_full_version >= '3.14'",
-            "python_full_version == '3.13.*'",
             "python_full_version < '3.13'",
+            "python_full_version == '3.13.*'",
+            "python_full_version >= '3.14'",
         ]
 
         [[package]]
@@ -3395,11 +3395,11 @@ fn preferences_dependent_forking_bistable() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
-            "sys_platform == 'linux'",
             "sys_platform != 'linux'",
+            "sys_platform == 'linux'",
         ]
 
         [[package]]
@@ -3768,11 +3768,11 @@ fn preferences_dependent_forking_tristable() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
-            "sys_platform == 'linux'",
             "sys_platform != 'linux'",
+            "sys_platform == 'linux'",
         ]
 
         [[package]]
@@ -4049,11 +4049,11 @@ fn preferences_dependent_forking() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
-            "sys_platform == 'linux'",
             "sys_platform != 'linux'",
+            "sys_platform == 'linux'",
         ]
 
         [[package]]
@@ -4221,14 +4221,14 @@ fn fork_remaining_universe_partitioning() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
+            "os_name != 'darwin' and os_name != 'linux' and sys_platform == 'illumos'",
             "os_name == 'darwin' and sys_platform == 'illumos'",
             "os_name == 'linux' and sys_platform == 'illumos'",
-            "os_name != 'darwin' and os_name != 'linux' and sys_platform == 'illumos'",
-            "sys_platform == 'windows'",
             "sys_platform != 'illumos' and sys_platform != 'windows'",
+            "sys_platform == 'windows'",
         ]
 
         [[package]]
@@ -4236,9 +4236,9 @@ fn fork_remaining_universe_partitioning() -> Result<()> {
         version = "1.0.0"
         source = { registry = "http://[LOCALHOST]/simple/" }
         resolution-markers = [
+            "os_name != 'darwin' and os_name != 'linux' and sys_platform == 'illumos'",
             "os_name == 'darwin' and sys_platform == 'illumos'",
             "os_name == 'linux' and sys_platform == 'illumos'",
-            "os_name != 'darwin' and os_name != 'linux' and sys_platform == 'illumos'",
         ]
         dependencies = [
             { name = "b", version = "1.0.0", source = { registry = "http://[LOCALHOST]/simple/" }, marker = "os_name == 'darwin'" },
@@ -4370,7 +4370,7 @@ fn fork_requires_python_full_prerelease() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.10"
 
         [[package]]
@@ -4451,7 +4451,7 @@ fn fork_requires_python_full() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.10"
 
         [[package]]
@@ -4537,7 +4537,7 @@ fn fork_requires_python_patch_overlap() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.10.1"
 
         [[package]]
@@ -4627,7 +4627,7 @@ fn fork_requires_python() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.10"
 
         [[package]]
@@ -4704,7 +4704,7 @@ fn requires_python_wheels() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.10"
 
         [[package]]
@@ -4797,7 +4797,7 @@ fn unreachable_package() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [[package]]
@@ -4895,7 +4895,7 @@ fn unreachable_wheels() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [[package]]
@@ -5022,7 +5022,7 @@ fn marker_variants_have_different_extras() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
             "platform_python_implementation != 'PyPy'",
@@ -5156,7 +5156,7 @@ fn virtual_package_extra_priorities() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [[package]]
@@ -5265,7 +5265,7 @@ fn requires_python_subset() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         required-markers = [
             "sys_platform == 'linux'",
@@ -5368,7 +5368,7 @@ fn specific_architecture() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [[package]]
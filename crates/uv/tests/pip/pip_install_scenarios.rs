@@ -2226,14 +2226,14 @@ fn package_only_prereleases_in_range() {
 
     uv_snapshot!(context.filters(), command(&context, &server)
         .arg("a>0.1.0")
-        , @"
+        , @r#"
     exit_code: 1 (failure)
     ----- stderr -----
       × No solution found when resolving dependencies:
       ╰─▶ Because only a<=0.1.0 is available and you require a>0.1.0, we can conclude that your requirements are unsatisfiable.
 
-    hint: Pre-releases are available for `a` in the requested range (e.g., 1.0.0a1), but pre-releases weren't enabled (try: `--prerelease=allow`)
-    ");
+    hint: Only pre-releases of `a` are available in the requested range (e.g., 1.0.0a1), but pre-releases weren't enabled (try: `tool.uv.prerelease-packages = ["a"]` to allow pre-releases for `a` only, or `--prerelease=allow`)
+    "#);
 
     // Since there are stable versions of `a` available, prerelease versions should not be selected without explicit opt-in.
     context.assert_not_installed("a");
@@ -2629,15 +2629,15 @@ fn transitive_package_only_prereleases_in_range() {
 
     uv_snapshot!(context.filters(), command(&context, &server)
         .arg("a")
-        , @"
+        , @r#"
     exit_code: 1 (failure)
     ----- stderr -----
       × No solution found when resolving dependencies:
       ╰─▶ Because only b<=0.1 is available and all versions of a depend on b>0.1, we can conclude that all versions of a cannot be used.
           And because you require a, we can conclude that your requirements are unsatisfiable.
 
-    hint: Pre-releases are available for `b` in the requested range (e.g., 1.0.0a1), but pre-releases weren't enabled (try: `--prerelease=allow`)
-    ");
+    hint: Only pre-releases of `b` are available in the requested range (e.g., 1.0.0a1), but pre-releases weren't enabled (try: `tool.uv.prerelease-packages = ["b"]` to allow pre-releases for `b` only, or `--prerelease=allow`)
+    "#);
 
     // Since there are stable versions of `b` available, the prerelease version should not be selected without explicit opt-in. The available version is excluded by the range requested by the user.
     context.assert_not_installed("a");
@@ -2,8 +2,10 @@ use std::env::current_dir;
 
 use anyhow::Result;
 use assert_cmd::prelude::*;
+use assert_fs::fixture::FileTouch;
 use assert_fs::fixture::FileWriteStr;
 use assert_fs::fixture::PathChild;
+use assert_fs::fixture::PathCreateDir;
 use indoc::indoc;
 
 use uv_static::EnvVars;
@@ -414,6 +416,107 @@ fn show_required_by_multiple() -> Result<()> {
     Ok(())
 }
 
+/// Requirements that are gated on an extra are included if the extra is requested by another
+/// installed package.
+#[test]
+#[cfg(feature = "test-pypi")]
+fn show_required_by_extra() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["requests[socks]==2.31.0"]
+
+        [build-system]
+        requires = ["uv_build>=0.8.22,<10000"]
+        build-backend = "uv_build"
+    "#})?;
+    project.child("src/project").create_dir_all()?;
+    project.child("src/project/__init__.py").touch()?;
+
+    uv_snapshot!(context.filters(), context
+        .pip_install()
+        .arg(project.path())
+        .arg("--strict"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 7 packages in [TIME]
+    Prepared 7 packages in [TIME]
+    Installed 7 packages in [TIME]
+     + certifi==2024.2.2
+     + charset-normalizer==3.3.2
+     + idna==3.6
+     + project==0.1.0 (from file://[TEMP_DIR]/project)
+     + pysocks==1.7.1
+     + requests==2.31.0
+     + urllib3==2.2.1
+    "
+    );
+
+    // `pysocks` is only required by `requests` via the `socks` extra, which is requested by
+    // `project`.
+    uv_snapshot!(context.filters(), context.pip_show()
+        .arg("requests")
+        .arg("pysocks"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    Name: pysocks
+    Version: 1.7.1
+    Location: [SITE_PACKAGES]/
+    Requires:
+    Required-by: requests
+    ---
+    Name: requests
+    Version: 2.31.0
+    Location: [SITE_PACKAGES]/
+    Requires: certifi, charset-normalizer, idna, pysocks, urllib3
+    Required-by: project
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "test-pypi")]
+fn show_json() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        anyio==4.0.0
+        requests==2.31.0
+    "
+    })?;
+
+    context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict")
+        .assert()
+        .success();
+
+    // Windows paths are escaped in JSON.
+    #[cfg(not(windows))]
+    uv_snapshot!(context.filters(), context.pip_show()
+        .arg("idna")
+        .arg("requests")
+        .arg("--format")
+        .arg("json"), @r#"
+    exit_code: 0 (success)
+    ----- stdout -----
+    [{"name":"idna","version":"3.6","location":"[SITE_PACKAGES]/","requires":[],"required_by":["anyio","requests"]},{"name":"requests","version":"2.31.0","location":"[SITE_PACKAGES]/","requires":["certifi","charset-normalizer","idna","urllib3"],"required_by":[]}]
+    "#
+    );
+
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "test-pypi")]
 fn show_files() {
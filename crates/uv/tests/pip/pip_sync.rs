@@ -1067,6 +1067,102 @@ fn warn_on_yanked_dry_run() -> Result<()> {
     Ok(())
 }
 
+/// Report the planned changes for a dry run as JSON, including upgrades, removals, and the
+/// packages that would be left as-is.
+#[test]
+fn dry_run_json() -> Result<()> {
+    let context = uv_test::test_context!("3.12")
+        .with_filtered_python_names()
+        .with_filtered_virtualenv_bin();
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==1.1.1\nmarkupsafe==2.1.3\ntomli==2.0.1")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--strict"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + iniconfig==1.1.1
+     + markupsafe==2.1.3
+     + tomli==2.0.1
+    "
+    );
+
+    requirements_txt.write_str("iniconfig==2.0.0\nmarkupsafe==2.1.3")?;
+
+    // The text output collapses the upgrade into a single transition.
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--dry-run")
+        .arg("--strict"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Would download 1 package
+    Would uninstall 2 packages
+    Would install 1 package
+     ~ iniconfig 1.1.1 -> 2.0.0
+     - tomli==2.0.1
+    Would leave 1 package unchanged
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--dry-run")
+        .arg("--format")
+        .arg("json")
+        .arg("--preview-features")
+        .arg("json-output")
+        .arg("--strict"), @r#"
+    exit_code: 0 (success)
+    ----- stdout -----
+    {
+      "schema": {
+        "version": "preview"
+      },
+      "environment": {
+        "path": "[VENV]/",
+        "python": {
+          "path": "[VENV]/[BIN]/[PYTHON]",
+          "version": "3.12.[X]",
+          "implementation": "cpython"
+        }
+      },
+      "changes": [
+        {
+          "name": "iniconfig",
+          "action": "upgrade",
+          "from": "1.1.1",
+          "to": "2.0.0"
+        },
+        {
+          "name": "tomli",
+          "action": "uninstall",
+          "from": "2.0.1"
+        }
+      ],
+      "unchanged": 1
+    }
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Would download 1 package
+    Would uninstall 2 packages
+    Would install 1 package
+    "#
+    );
+
+    // The environment should be untouched.
+    context.assert_command("import tomli").success();
+
+    Ok(())
+}
+
 /// Resolve a local wheel.
 #[test]
 fn install_local_wheel() -> Result<()> {
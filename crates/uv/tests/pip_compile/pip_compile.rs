@@ -385,6 +385,82 @@ fn compile_constraints_txt() -> Result<()> {
     Ok(())
 }
 
+/// Resolve with a constraints file provided via URL, then re-use the cached copy offline.
+#[tokio::test]
+async fn compile_constraints_remote() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/constraints.txt"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("idna<3.4"))
+        .mount(&server)
+        .await;
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==3.7.0")?;
+
+    let server_uri = server.uri();
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([
+            (server_uri.as_str(), "[SERVER]"),
+            (r"from \d+ seconds? ago", "from [AGE] ago"),
+        ])
+        .collect::<Vec<_>>();
+
+    let constraints = format!("{server_uri}/constraints.txt");
+
+    uv_snapshot!(filters, context.pip_compile()
+            .arg("requirements.in")
+            .arg("--constraint")
+            .arg(&constraints), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --constraint [SERVER]/constraints.txt
+    anyio==3.7.0
+        # via -r requirements.in
+    idna==3.3
+        # via
+        #   -c [SERVER]/constraints.txt
+        #   anyio
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "
+    );
+
+    // With `--offline`, the cached copy should be used.
+    uv_snapshot!(filters, context.pip_compile()
+            .arg("requirements.in")
+            .arg("--constraint")
+            .arg(&constraints)
+            .arg("--offline"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --constraint [SERVER]/constraints.txt --offline
+    anyio==3.7.0
+        # via -r requirements.in
+    idna==3.3
+        # via
+        #   -c [SERVER]/constraints.txt
+        #   anyio
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    warning: Using cached copy of `[SERVER]/constraints.txt` from [AGE] ago, since network connectivity is disabled
+    Resolved 3 packages in [TIME]
+    "
+    );
+
+    Ok(())
+}
+
 /// <https://github.com/astral-sh/uv/issues/19672>
 #[test]
 fn compile_constraints_many_versions() -> Result<()> {
@@ -6324,12 +6400,12 @@ fn custom_compile_command() -> Result<()> {
     Ok(())
 }
 
-/// Emit warnings when users pass redundant options from `pip-compile`.
+/// Pin pip-compile's default unsafe packages at the end of the output with `--allow-unsafe`.
 #[test]
 fn allow_unsafe() -> Result<()> {
     let context = uv_test::test_context!("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("werkzeug==3.0.1")?;
+    requirements_in.write_str("setuptools-scm==8.0.1")?;
 
     uv_snapshot!(context.filters(), context.pip_compile()
             .arg("requirements.in")
@@ -6338,14 +6414,142 @@ fn allow_unsafe() -> Result<()> {
     ----- stdout -----
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --allow-unsafe
-    markupsafe==2.1.5
-        # via werkzeug
-    werkzeug==3.0.1
+    packaging==24.0
+        # via setuptools-scm
+    setuptools-scm==8.0.1
+        # via -r requirements.in
+
+    # The following packages are considered to be unsafe in a requirements file:
+    setuptools==69.2.0
+        # via setuptools-scm
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "
+    );
+
+    // With `--no-allow-unsafe`, the default unsafe packages are listed as comments instead.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--no-allow-unsafe"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --no-allow-unsafe
+    packaging==24.0
+        # via setuptools-scm
+    setuptools-scm==8.0.1
         # via -r requirements.in
 
+    # The following packages are considered to be unsafe in a requirements file:
+    # setuptools
+
     ----- stderr -----
-    warning: pip-compile's `--allow-unsafe` has no effect (uv can safely pin `pip` and other packages)
-    Resolved 2 packages in [TIME]
+    Resolved 3 packages in [TIME]
+    "
+    );
+
+    Ok(())
+}
+
+/// Treat packages as unsafe when compiling from a `pyproject.toml`, with a pip-compile header.
+#[test]
+fn unsafe_package_pyproject_pip_tools_header() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"[project]
+name = "project"
+version = "0.1.0"
+dependencies = ["setuptools-scm==8.0.1"]
+"#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("pyproject.toml")
+            .arg("--unsafe-package")
+            .arg("setuptools")
+            .arg("--pip-tools-header")
+            .arg("--custom-compile-command")
+            .arg("pip-compile pyproject.toml"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    #
+    # This file is autogenerated by pip-compile with Python 3.12
+    # by the following command:
+    #
+    #    pip-compile pyproject.toml
+    #
+    packaging==24.0
+        # via setuptools-scm
+    setuptools-scm==8.0.1
+        # via project (pyproject.toml)
+
+    # The following packages are considered to be unsafe in a requirements file:
+    # setuptools
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "
+    );
+
+    // `--allow-unsafe` pins the named packages, rather than the defaults.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("pyproject.toml")
+            .arg("--unsafe-package")
+            .arg("packaging")
+            .arg("--allow-unsafe")
+            .arg("--pip-tools-header")
+            .arg("--custom-compile-command")
+            .arg("pip-compile --allow-unsafe pyproject.toml"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    #
+    # This file is autogenerated by pip-compile with Python 3.12
+    # by the following command:
+    #
+    #    pip-compile --allow-unsafe pyproject.toml
+    #
+    setuptools==69.2.0
+        # via setuptools-scm
+    setuptools-scm==8.0.1
+        # via project (pyproject.toml)
+
+    # The following packages are considered to be unsafe in a requirements file:
+    packaging==24.0
+        # via setuptools-scm
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "
+    );
+
+    // Without a custom command, the header includes the equivalent `pip-compile` command.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("pyproject.toml")
+            .arg("--unsafe-package")
+            .arg("setuptools")
+            .arg("--pip-tools-header")
+            .arg("-o")
+            .arg("requirements.txt"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    #
+    # This file is autogenerated by pip-compile with Python 3.12
+    # by the following command:
+    #
+    #    pip-compile --cache-dir [CACHE_DIR] pyproject.toml --unsafe-package setuptools --output-file=requirements.txt
+    #
+    packaging==24.0
+        # via setuptools-scm
+    setuptools-scm==8.0.1
+        # via project (pyproject.toml)
+
+    # The following packages are considered to be unsafe in a requirements file:
+    # setuptools
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
     "
     );
 
@@ -10476,7 +10680,7 @@ fn unsafe_package() -> Result<()> {
     werkzeug==3.0.1
         # via flask
 
-    # The following packages were excluded from the output:
+    # The following packages are considered to be unsafe in a requirements file:
     # jinja2
 
     ----- stderr -----
@@ -15221,6 +15425,49 @@ fn invalid_platform() -> Result<()> {
     Ok(())
 }
 
+/// When resolving for Windows ARM64, list the packages that only provide x64 wheels.
+#[test]
+fn invalid_platform_windows_arm64() -> Result<()> {
+    let context = uv_test::test_context!("3.10");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("open3d")?;
+
+    uv_snapshot!(context
+        .pip_compile()
+        .arg("--python-platform")
+        .arg("aarch64-pc-windows-msvc")
+        .arg("requirements.in"), @"
+    exit_code: 1 (failure)
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because only the following versions of open3d are available:
+              open3d==0.8.0.0
+              open3d==0.9.0.0
+              open3d==0.10.0.0
+              open3d==0.10.0.1
+              open3d==0.11.0
+              open3d==0.11.1
+              open3d==0.11.2
+              open3d==0.12.0
+              open3d==0.13.0
+              open3d==0.14.1
+              open3d==0.15.1
+              open3d==0.15.2
+              open3d==0.16.0
+              open3d==0.16.1
+              open3d==0.17.0
+              open3d==0.18.0
+          and open3d<=0.15.2 has no wheels with a matching Python ABI tag (e.g., `cp310`), we can conclude that open3d<=0.15.2 cannot be used.
+          And because open3d>=0.16.0 has no wheels with a matching platform tag (e.g., `win_arm64`) and you require open3d, we can conclude that your requirements are unsatisfiable.
+
+    hint: You require CPython 3.10 (`cp310`), but we only found wheels for `open3d` (v0.15.2) with the following Python ABI tags: `cp36m`, `cp37m`, `cp38`, `cp39`
+    hint: Wheels are available for `open3d` (v0.18.0) on the following platforms: `manylinux_2_27_aarch64`, `manylinux_2_27_x86_64`, `macosx_11_0_x86_64`, `macosx_13_0_arm64`, `win_amd64`
+    hint: No `win_arm64` wheels are available for the following package, but `win_amd64` wheels can run under x64 emulation: `open3d`. Consider reporting the missing wheels upstream, or using an x64 Python interpreter
+    ");
+
+    Ok(())
+}
+
 /// Treat `sys_platform` and `sys.platform` as equivalent markers in the marker algebra.
 #[cfg(feature = "test-universal")]
 #[test]
@@ -18535,3 +18782,103 @@ async fn compile_missing_python_download_error_warning() {
       Caused by: tunnel error: unsuccessful
     ");
 }
+
+/// Record a digest of the inputs with `--emit-inputs-hash`, and compare against it with
+/// `--check-fresh`.
+#[test]
+fn compile_inputs_hash() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let filters: Vec<_> = [(r"inputs-sha256=[0-9a-f]{64}", "inputs-sha256=[DIGEST]")]
+        .into_iter()
+        .chain(context.filters())
+        .collect();
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig==2.0.0")?;
+
+    uv_snapshot!(filters, context.pip_compile()
+            .arg("requirements.in")
+            .arg("--output-file")
+            .arg("requirements.txt")
+            .arg("--emit-inputs-hash"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --output-file requirements.txt --emit-inputs-hash
+    # uv: inputs-sha256=[DIGEST]
+    iniconfig==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "
+    );
+
+    // The inputs haven't changed, so the output is fresh.
+    uv_snapshot!(filters, context.pip_compile()
+            .arg("requirements.in")
+            .arg("--output-file")
+            .arg("requirements.txt")
+            .arg("--emit-inputs-hash")
+            .arg("--check-fresh")
+            .arg("requirements.txt"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+
+    ----- stderr -----
+    `requirements.txt` is up-to-date
+    "
+    );
+
+    // Changing the resolver settings invalidates the digest.
+    uv_snapshot!(filters, context.pip_compile()
+            .arg("requirements.in")
+            .arg("--output-file")
+            .arg("requirements.txt")
+            .arg("--emit-inputs-hash")
+            .arg("--generate-hashes")
+            .arg("--check-fresh")
+            .arg("requirements.txt"), @"
+    exit_code: 1 (failure)
+    ----- stdout -----
+
+    ----- stderr -----
+    `requirements.txt` is out-of-date: the inputs have changed since it was compiled
+    "
+    );
+
+    // As does changing the requirements.
+    requirements_in.write_str("iniconfig==1.1.1")?;
+
+    uv_snapshot!(filters, context.pip_compile()
+            .arg("requirements.in")
+            .arg("--output-file")
+            .arg("requirements.txt")
+            .arg("--emit-inputs-hash")
+            .arg("--check-fresh")
+            .arg("requirements.txt"), @"
+    exit_code: 1 (failure)
+    ----- stdout -----
+
+    ----- stderr -----
+    `requirements.txt` is out-of-date: the inputs have changed since it was compiled
+    "
+    );
+
+    // A file compiled without a digest is never fresh.
+    context.temp_dir.child("other.txt").write_str("iniconfig==1.1.1\n")?;
+
+    uv_snapshot!(filters, context.pip_compile()
+            .arg("requirements.in")
+            .arg("--check-fresh")
+            .arg("other.txt"), @"
+    exit_code: 1 (failure)
+    ----- stdout -----
+
+    ----- stderr -----
+    `other.txt` does not record an inputs digest (compile it with `--emit-inputs-hash`)
+    "
+    );
+
+    Ok(())
+}
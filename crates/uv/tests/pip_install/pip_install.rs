@@ -502,7 +502,7 @@ fn invalid_pyproject_toml_option_unknown_field() -> Result<()> {
         |
       2 | unknown = "field"
         | ^^^^^^^
-      unknown field `unknown`, expected one of `required-version`, `system-certs`, `native-tls`, `offline`, `no-cache`, `cache-dir`, `preview`, `preview-features`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `http-proxy`, `https-proxy`, `no-proxy`, `allow-insecure-host`, `resolution`, `prerelease`, `fork-strategy`, `dependency-metadata`, `config-settings`, `config-settings-package`, `no-build-isolation`, `no-build-isolation-package`, `extra-build-dependencies`, `extra-build-variables`, `exclude-newer`, `exclude-newer-package`, `link-mode`, `compile-bytecode`, `no-sources`, `no-sources-package`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `torch-backend`, `python-install-mirror`, `pypy-install-mirror`, `python-downloads-json-url`, `publish-url`, `trusted-publishing`, `check-url`, `add-bounds`, `audit`, `pip`, `cache-keys`, `override-dependencies`, `exclude-dependencies`, `constraint-dependencies`, `build-constraint-dependencies`, `environments`, `required-environments`, `conflicts`, `workspace`, `sources`, `managed`, `package`, `default-groups`, `dependency-groups`, `dev-dependencies`, `build-backend`
+      unknown field `unknown`, expected one of `required-version`, `system-certs`, `native-tls`, `offline`, `no-cache`, `cache-dir`, `preview`, `preview-features`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `http-proxy`, `https-proxy`, `no-proxy`, `allow-insecure-host`, `resolution`, `prerelease`, `fork-strategy`, `dependency-metadata`, `config-settings`, `config-settings-package`, `no-build-isolation`, `no-build-isolation-package`, `extra-build-dependencies`, `extra-build-variables`, `build-env-passthrough`, `build-env-passthrough-package`, `exclude-newer`, `exclude-newer-package`, `link-mode`, `compile-bytecode`, `no-sources`, `no-sources-package`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `torch-backend`, `python-install-mirror`, `pypy-install-mirror`, `python-downloads-json-url`, `publish-url`, `trusted-publishing`, `check-url`, `add-bounds`, `audit`, `pip`, `cache-keys`, `override-dependencies`, `exclude-dependencies`, `constraint-dependencies`, `build-constraint-dependencies`, `environments`, `required-environments`, `conflicts`, `workspace`, `sources`, `managed`, `package`, `default-groups`, `dependency-groups`, `dev-dependencies`, `build-backend`
 
     Resolved in [TIME]
     Checked in [TIME]
@@ -2612,6 +2612,140 @@ fn install_git_percent_encoded_ref() -> Result<()> {
     Ok(())
 }
 
+/// Install a package from a Git repository with a large file in its history, which should be
+/// omitted from the (partially cloned) Git database.
+#[test]
+#[cfg(feature = "test-git")]
+fn install_git_partial_clone() -> Result<()> {
+    // Partial clones require Git 2.24 or later.
+    let output = Command::new("git").arg("--version").output()?;
+    let version = String::from_utf8(output.stdout)?;
+    let supported = version
+        .trim()
+        .strip_prefix("git version ")
+        .and_then(|version| {
+            let mut parts = version.split(['.', ' ']);
+            let major = parts.next()?.parse::<u32>().ok()?;
+            let minor = parts.next()?.parse::<u32>().ok()?;
+            Some((major, minor))
+        })
+        .is_some_and(|version| version >= (2, 24));
+    if !supported {
+        return Ok(());
+    }
+
+    let context = uv_test::test_context!(DEFAULT_PYTHON_VERSION);
+
+    let repository = context.temp_dir.child("repository");
+    repository
+        .child("packages/example/example")
+        .create_dir_all()?;
+    repository
+        .child("packages/example/example/__init__.py")
+        .write_str(r#"__version__ = "0.1.0""#)?;
+    repository
+        .child("packages/example/pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "example"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+    "#})?;
+    repository
+        .child("data.bin")
+        .write_binary(&vec![0; 1024 * 1024])?;
+
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .arg("-C")
+            .arg(repository.path())
+            .arg("-c")
+            .arg("user.name=Example")
+            .arg("-c")
+            .arg("user.email=example@example.com")
+            .args(args)
+            .env("GIT_AUTHOR_DATE", "2000-01-01T00:00:00Z")
+            .env("GIT_COMMITTER_DATE", "2000-01-01T00:00:00Z")
+            .assert()
+            .success()
+    };
+    Command::new("git")
+        .arg("init")
+        .arg(repository.path())
+        .assert()
+        .success();
+    // Allow partial clones from the repository.
+    git(&["config", "uploadpack.allowFilter", "true"]);
+    git(&["add", "."]);
+    git(&["commit", "-m", "Initial commit"]);
+    git(&["rm", "data.bin"]);
+    git(&["commit", "-m", "Remove data"]);
+
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repository.path())
+        .arg("rev-parse")
+        .arg("HEAD~1:data.bin")
+        .output()?;
+    let blob = String::from_utf8(output.stdout)?.trim().to_string();
+
+    let repository_url = Url::from_directory_path(repository.path())
+        .map_err(|()| anyhow!("failed to convert repository path to file URL"))?;
+    let repository_url = repository_url.as_str().trim_end_matches('/');
+
+    let mut filters = context.filters();
+    filters.push((r"@[0-9a-f]{40}", "@[COMMIT]"));
+    uv_snapshot!(filters, context
+        .pip_install()
+        .arg(format!(
+            "example @ git+{repository_url}#subdirectory=packages/example"
+        )), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + example==0.1.0 (from git+file://[TEMP_DIR]/repository@[COMMIT]#subdirectory=packages/example)
+    ");
+
+    context.assert_installed("example", "0.1.0");
+
+    // The blob from the repository's history should be missing from the Git database.
+    let db = fs::read_dir(context.cache_dir.join("git-v0").join("db"))?
+        .next()
+        .context("expected a Git database")??
+        .path();
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(&db)
+        .arg("rev-list")
+        .arg("--objects")
+        .arg("--missing=print")
+        .arg("--all")
+        .output()?;
+    let objects = String::from_utf8(output.stdout)?;
+    assert!(objects.lines().any(|line| line == format!("?{blob}")));
+
+    // The checkout should be a worktree of the Git database, rather than a copy.
+    let checkouts = context.cache_dir.join("git-v0").join("checkouts");
+    let checkout = fs::read_dir(
+        fs::read_dir(&checkouts)?
+            .next()
+            .context("expected a Git checkout")??
+            .path(),
+    )?
+    .next()
+    .context("expected a Git checkout")??
+    .path();
+    assert!(checkout.join(".git").is_file());
+
+    Ok(())
+}
+
 /// Reject an ambiguous Git URL when the ref contains an unescaped `@`.
 #[test]
 fn install_git_unescaped_ref() {
@@ -5860,6 +5994,7 @@ fn dry_run_uninstall_url_dependency() -> std::result::Result<(), Box<dyn std::er
     Would install 1 package
      - anyio==4.2.0 (from https://files.pythonhosted.org/packages/2d/b8/7333d87d5f03247215d86a86362fd3e324111788c6cdd8d2e6196a6ba833/anyio-4.2.0.tar.gz)
      + anyio==4.3.0
+    Would leave 2 packages unchanged
     "
     );
 
@@ -5948,6 +6083,7 @@ fn dry_run_install_transitive_dependency_already_installed()
      + httpx==0.25.1
      + idna==3.6
      + sniffio==1.3.1
+    Would leave 3 packages unchanged
     "
     );
 
@@ -5992,8 +6128,8 @@ fn dry_run_install_then_upgrade() -> std::result::Result<(), Box<dyn std::error:
     Would download 1 package
     Would uninstall 1 package
     Would install 1 package
-     - httpx==0.25.0
-     + httpx==0.25.1
+     ~ httpx 0.25.0 -> 0.25.1
+    Would leave 6 packages unchanged
     "
     );
 
@@ -6140,6 +6276,46 @@ async fn install_package_basic_auth_from_netrc() -> Result<()> {
     Ok(())
 }
 
+/// Install a package from an index that requires authentication, reading the netrc file from
+/// `UV_NETRC` in favor of `NETRC`.
+#[tokio::test]
+async fn install_package_basic_auth_from_uv_netrc() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let proxy = crate::pypi_proxy::start().await;
+    let netrc = context.temp_dir.child("credentials").child("netrc");
+    netrc.write_str(&format!(
+        "machine {} login public password heron",
+        proxy.host()
+    ))?;
+    let wrong_netrc = context.temp_dir.child(".netrc");
+    wrong_netrc.write_str(&format!(
+        "machine {} login public password eagle",
+        proxy.host()
+    ))?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("anyio")
+        .arg("--index-url")
+        .arg(proxy.url("/basic-auth/simple"))
+        .env(EnvVars::UV_NETRC, netrc.as_os_str())
+        .env(EnvVars::NETRC, wrong_netrc.as_os_str())
+        .arg("--strict"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "
+    );
+
+    context.assert_command("import anyio").success();
+
+    Ok(())
+}
+
 /// Install a package from a known pyx URL by falling back to netrc when the pyx store is empty.
 #[tokio::test]
 async fn install_package_known_pyx_url_from_netrc_without_pyx_token() -> Result<()> {
@@ -6419,7 +6595,7 @@ async fn install_package_basic_auth_from_keyring_wrong_password() {
       × No solution found when resolving dependencies:
       ╰─▶ Because anyio was not found in the package registry and you require anyio, we can conclude that your requirements are unsatisfiable.
 
-    hint: An index URL (http://[LOCALHOST]/basic-auth/simple) could not be queried due to a lack of valid authentication credentials (401 Unauthorized)
+    hint: An index URL (http://[LOCALHOST]/basic-auth/simple) could not be queried due to a lack of valid authentication credentials (401 Unauthorized). Credential sources checked: keyring (rejected).
     "
     );
 }
@@ -6461,7 +6637,7 @@ async fn install_package_basic_auth_from_keyring_wrong_username() {
       × No solution found when resolving dependencies:
       ╰─▶ Because anyio was not found in the package registry and you require anyio, we can conclude that your requirements are unsatisfiable.
 
-    hint: An index URL (http://[LOCALHOST]/basic-auth/simple) could not be queried due to a lack of valid authentication credentials (401 Unauthorized)
+    hint: An index URL (http://[LOCALHOST]/basic-auth/simple) could not be queried due to a lack of valid authentication credentials (401 Unauthorized). Credential sources checked: keyring (no matching credentials).
     "
     );
 }
@@ -8669,6 +8845,129 @@ fn local_index_relative() -> Result<()> {
     Ok(())
 }
 
+/// Combine a project across indexes with `unsafe-best-match`, verifying that the indexes declare
+/// each other as alternate locations, per PEP 708.
+#[test]
+fn local_index_tracking() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let links = Url::from_directory_path(context.workspace_root.join("test/links/")).unwrap();
+    let first = Url::from_directory_path(context.temp_dir.child("first")).unwrap();
+    let second = Url::from_directory_path(context.temp_dir.child("second")).unwrap();
+
+    // Write a `tqdm` project page to the given index, declaring the given alternate location.
+    let write_index = |index: &str, alternate: &str| -> Result<()> {
+        let tqdm = context.temp_dir.child(index).child("tqdm");
+        fs_err::create_dir_all(&tqdm)?;
+        tqdm.child("index.html").write_str(&indoc::formatdoc! {r#"
+            <!DOCTYPE html>
+            <html>
+              <head>
+                <meta name="pypi:repository-version" content="1.1" />
+                <meta name="pypi:alternate-locations" content="{alternate}" />
+              </head>
+              <body>
+                <h1>Links for tqdm</h1>
+                <a href="{links}/tqdm-1000.0.0-py3-none-any.whl">
+                  tqdm-1000.0.0-py3-none-any.whl
+                </a>
+              </body>
+            </html>
+        "#})?;
+        Ok(())
+    };
+
+    // Neither index declares the other, so combining them should warn.
+    write_index("first", "https://example.com/simple/tqdm/")?;
+    write_index("second", "https://example.com/simple/tqdm/")?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("tqdm")
+        .arg("--index-url")
+        .arg(first.as_str())
+        .arg("--extra-index-url")
+        .arg(second.as_str())
+        .arg("--index-strategy")
+        .arg("unsafe-best-match")
+        .arg("--dry-run"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    warning: `tqdm` is available on both `file://[TEMP_DIR]/second/` and `file://[TEMP_DIR]/first/`, but the indexes don't declare each other as alternate locations; if this is unexpected, it may indicate a dependency confusion attack (use `--strict-index-tracking` to make this an error)
+    Resolved 1 package in [TIME]
+    Would download 1 package
+    Would install 1 package
+     + tqdm==1000.0.0
+    "
+    );
+
+    // With `--strict-index-tracking`, combining them should fail.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("tqdm")
+        .arg("--index-url")
+        .arg(first.as_str())
+        .arg("--extra-index-url")
+        .arg(second.as_str())
+        .arg("--index-strategy")
+        .arg("unsafe-best-match")
+        .arg("--strict-index-tracking")
+        .arg("--dry-run"), @"
+    exit_code: 1
+    ----- stderr -----
+      × Failed to fetch: `tqdm`
+      ╰─▶ Package `tqdm` is available on both `file://[TEMP_DIR]/second/` and `file://[TEMP_DIR]/first/`, but the indexes don't declare each other as alternate locations
+    "
+    );
+
+    // Alternate locations must be declared by both indexes, so a one-sided declaration should
+    // still fail.
+    write_index("first", &format!("{second}tqdm/"))?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("tqdm")
+        .arg("--index-url")
+        .arg(first.as_str())
+        .arg("--extra-index-url")
+        .arg(second.as_str())
+        .arg("--index-strategy")
+        .arg("unsafe-best-match")
+        .arg("--strict-index-tracking")
+        .arg("--dry-run"), @"
+    exit_code: 1
+    ----- stderr -----
+      × Failed to fetch: `tqdm`
+      ╰─▶ Package `tqdm` is available on both `file://[TEMP_DIR]/second/` and `file://[TEMP_DIR]/first/`, but the indexes don't declare each other as alternate locations
+    "
+    );
+
+    // Once the indexes declare each other, combining them should succeed silently.
+    write_index("second", &format!("{first}tqdm/"))?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("tqdm")
+        .arg("--index-url")
+        .arg(first.as_str())
+        .arg("--extra-index-url")
+        .arg(second.as_str())
+        .arg("--index-strategy")
+        .arg("unsafe-best-match")
+        .arg("--strict-index-tracking")
+        .arg("--dry-run"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Would download 1 package
+    Would install 1 package
+     + tqdm==1000.0.0
+    "
+    );
+
+    Ok(())
+}
+
 /// Resolve against a local directory laid out as a PEP 503-compatible index, provided via a
 /// `requirements.txt` file.
 #[test]
@@ -15604,3 +15903,84 @@ fn compile_bytecode_excludes_stdlib() -> Result<()> {
 
     Ok(())
 }
+
+/// Omit a package from the installation with `--exclude-package`, assuming that the environment
+/// provides it.
+#[test]
+fn install_exclude_package() {
+    let context = uv_test::test_context!("3.12");
+
+    // The environment doesn't provide `idna`, so the installation should fail.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("anyio==4.3.0")
+        .arg("--exclude-package")
+        .arg("idna"), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    warning: Excluding `idna` from the installation; `anyio` expects it to be provided by the environment
+    error: `idna` was excluded from the installation, but the environment doesn't provide a version that satisfies `idna>=2.8`
+
+    hint: Use `--exclude-unchecked` to skip this check
+    "
+    );
+
+    // An incompatible version of `idna` should also be rejected.
+    context.pip_install().arg("idna==2.7").assert().success();
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("anyio==4.3.0")
+        .arg("--exclude-package")
+        .arg("idna"), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    warning: Excluding `idna` from the installation; `anyio` expects it to be provided by the environment
+    error: `idna` was excluded from the installation, but the environment doesn't provide a version that satisfies `idna>=2.8`
+
+    hint: Found `idna==2.7` in the environment
+    hint: Use `--exclude-unchecked` to skip this check
+    "
+    );
+
+    // With `--exclude-unchecked`, the incompatible version should be left in place.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("anyio==4.3.0")
+        .arg("--exclude-package")
+        .arg("idna")
+        .arg("--exclude-unchecked"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    warning: Excluding `idna` from the installation; `anyio` expects it to be provided by the environment
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + anyio==4.3.0
+     + sniffio==1.3.1
+    "
+    );
+
+    context
+        .assert_command("import idna; assert idna.__version__ == '2.7'")
+        .success();
+
+    // Once a compatible version is installed, the check should pass.
+    context.pip_install().arg("idna==3.6").assert().success();
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("anyio==4.3.0")
+        .arg("--exclude-package")
+        .arg("idna")
+        .arg("--reinstall"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    warning: Excluding `idna` from the installation; `anyio` expects it to be provided by the environment
+    Prepared 2 packages in [TIME]
+    Uninstalled 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     ~ anyio==4.3.0
+     ~ sniffio==1.3.1
+    "
+    );
+}
@@ -79,7 +79,7 @@ fn add_registry() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -218,7 +218,7 @@ fn add_git() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -343,7 +343,7 @@ fn add_git_private_source() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -431,7 +431,7 @@ fn add_git_private_raw() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -682,7 +682,7 @@ fn add_git_lfs() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.13"
 
         [options]
@@ -884,7 +884,7 @@ fn add_git_raw() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -1151,7 +1151,7 @@ fn add_unnamed() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -1241,7 +1241,7 @@ fn add_remove_dev() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -1350,7 +1350,7 @@ fn add_remove_dev() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -1434,7 +1434,7 @@ fn add_remove_optional() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -1543,7 +1543,7 @@ fn add_remove_optional() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -1765,7 +1765,7 @@ fn add_remove_workspace() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -1843,7 +1843,7 @@ fn add_remove_workspace() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -2391,7 +2391,7 @@ fn add_workspace_editable() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -2517,7 +2517,7 @@ fn add_workspace_path() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -2639,7 +2639,7 @@ fn add_path_implicit_workspace() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -2755,7 +2755,7 @@ fn add_path_no_workspace() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -2863,7 +2863,7 @@ fn add_path_adjacent_directory() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -3033,7 +3033,7 @@ fn add_relative_and_absolute_paths() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -3209,7 +3209,7 @@ fn update() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -3914,7 +3914,7 @@ fn add_inexact() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -4027,7 +4027,7 @@ fn remove_registry() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -4168,8 +4168,6 @@ fn add_frozen() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.add().arg("anyio==3.7.0").arg("--frozen"), @"
     exit_code: 0 (success)
-    ----- stderr -----
-    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
     ");
 
     let pyproject_toml = context.read("pyproject.toml");
@@ -4244,6 +4242,68 @@ fn add_no_sync() -> Result<()> {
     Ok(())
 }
 
+/// Edit the `pyproject.toml` with `--frozen` when no Python interpreter is available.
+#[test]
+fn add_remove_version_frozen_without_python() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    // Remove the virtual environment.
+    fs_err::remove_dir_all(&context.venv)?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    let python = context.temp_dir.child("missing").child("python");
+
+    uv_snapshot!(context.filters(), context.add().arg("anyio==3.7.0").arg("--frozen").env(EnvVars::UV_PYTHON, python.as_os_str()), @"
+    exit_code: 0 (success)
+    ");
+
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("minor").arg("--frozen").env(EnvVars::UV_PYTHON, python.as_os_str()), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    project 0.1.0 => 0.2.0
+    ");
+
+    uv_snapshot!(context.filters(), context.remove().arg("anyio").arg("--frozen").env(EnvVars::UV_PYTHON, python.as_os_str()), @"
+    exit_code: 0 (success)
+    ");
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r#"
+        [project]
+        name = "project"
+        version = "0.2.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#
+        );
+    });
+
+    // Locking requires an interpreter.
+    uv_snapshot!(context.filters(), context.add().arg("anyio==3.7.0").arg("--no-sync").env(EnvVars::UV_PYTHON, python.as_os_str()), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    error: No interpreter found at path `missing/python`
+    ");
+
+    assert!(!context.temp_dir.join("uv.lock").exists());
+    assert!(!context.venv.exists());
+
+    Ok(())
+}
+
 #[test]
 fn add_reject_multiple_git_ref_flags() {
     let context = uv_test::test_context!("3.12");
@@ -4668,7 +4728,7 @@ fn add_lower_bound_optional() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -4779,7 +4839,7 @@ fn add_lower_bound_local() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [[package]]
@@ -4872,7 +4932,7 @@ fn add_non_project() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -5829,7 +5889,7 @@ fn add_requirements_file_constraints() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -5966,6 +6026,94 @@ fn add_requirements_file_constraints() -> Result<()> {
     Ok(())
 }
 
+/// Add a requirement with a constraints file provided via URL, which is recorded in the lockfile.
+#[tokio::test]
+async fn add_constraints_remote() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/constraints.txt"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("idna<3.4"))
+        .mount(&server)
+        .await;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    let server_uri = server.uri();
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(server_uri.as_str(), "[SERVER]")])
+        .collect::<Vec<_>>();
+
+    let constraints = format!("{server_uri}/constraints.txt");
+
+    uv_snapshot!(filters, context.add().arg("anyio==3.7.0").arg("-c").arg(&constraints), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.3
+     + sniffio==1.3.1
+    ");
+
+    let lock = context.read("uv.lock");
+    let options = lock.split("[[package]]").next().unwrap();
+
+    insta::with_settings!({
+        filters => filters.clone(),
+    }, {
+        assert_snapshot!(
+            options, @r#"
+        version = 1
+        revision = 4
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+        remote-files = [
+            { url = "[SERVER]/constraints.txt", hash = "sha256:d8ddf19c6757e5774c934a46ccc4a500118f9b3d7cf96b8a76809152f71cf733" },
+        ]
+        "#
+        );
+    });
+
+    // The lockfile is up-to-date while the remote file is unchanged.
+    uv_snapshot!(filters, context.lock().arg("--check"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    // Change the contents of the remote file.
+    server.reset().await;
+    Mock::given(method("GET"))
+        .and(path("/constraints.txt"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("idna<3.3"))
+        .mount(&server)
+        .await;
+
+    uv_snapshot!(filters, context.lock().arg("--check"), @"
+    exit_code: 1 (failure)
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    error: The remote file `[SERVER]/constraints.txt` recorded in `uv.lock` has changed (expected `sha256:d8ddf19c6757e5774c934a46ccc4a500118f9b3d7cf96b8a76809152f71cf733`, found `sha256:ba9809b9f81234b8604cd722d0a9a57285105bc2997845683dd28155cb024642`), but `--check` was provided.
+
+    hint: If the changes are expected, re-run the command that provided the file without `--locked` to update the lockfile.
+    ");
+
+    Ok(())
+}
+
 /// Add a requirement to a dependency group.
 #[test]
 fn add_group() -> Result<()> {
@@ -6750,7 +6898,7 @@ fn add_script_settings() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.11"
 
         [options]
@@ -7396,7 +7544,7 @@ fn add_remove_script_lock() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.11"
 
         [options]
@@ -7576,7 +7724,7 @@ fn add_remove_script_lock() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.11"
 
         [options]
@@ -7778,7 +7926,7 @@ fn add_remove_script_lock() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.11"
 
         [options]
@@ -9362,7 +9510,7 @@ fn add_warn_index_url() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -9458,7 +9606,7 @@ fn add_no_warn_index_url() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -9548,7 +9696,7 @@ fn add_index() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -9631,7 +9779,7 @@ fn add_index() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -9740,7 +9888,7 @@ fn add_index() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -9857,7 +10005,7 @@ fn add_index() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -9983,7 +10131,7 @@ fn add_index() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -10116,7 +10264,7 @@ fn add_default_index_url() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -10186,7 +10334,7 @@ fn add_default_index_url() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -10285,7 +10433,7 @@ async fn add_index_credentials() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -10382,7 +10530,7 @@ async fn existing_index_credentials() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -10472,7 +10620,7 @@ fn add_index_with_trailing_slash() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -10565,7 +10713,7 @@ fn add_index_without_trailing_slash() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -10630,8 +10778,6 @@ fn add_index_with_existing_relative_path_index() -> Result<()> {
     let index = format!("local={}", packages.path().display());
     uv_snapshot!(context.filters(), context.add().arg("iniconfig").arg("--frozen").arg("--project").arg(project.path()).arg("--index").arg(index), @"
     exit_code: 0 (success)
-    ----- stderr -----
-    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
     ");
 
     let pyproject_toml = fs_err::read_to_string(project.join("pyproject.toml"))?;
@@ -10910,7 +11056,7 @@ fn add_group_comment() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.11"
 
         [options]
@@ -11038,7 +11184,7 @@ fn add_index_comments() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -11340,7 +11486,7 @@ fn add_direct_url_subdirectory() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -11464,7 +11610,7 @@ fn add_direct_url_subdirectory_raw() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -12179,7 +12325,7 @@ fn multiple_index_cli() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -12284,7 +12430,7 @@ fn repeated_index_cli_environment_variable() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -12384,7 +12530,7 @@ fn repeated_index_cli_environment_variable_newline() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -12488,7 +12634,7 @@ fn repeated_index_cli() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -12592,7 +12738,7 @@ fn repeated_index_cli_reversed() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -12808,7 +12954,7 @@ async fn add_full_url_in_keyring() -> Result<()> {
       × No solution found when resolving dependencies:
       ╰─▶ Because anyio was not found in the package registry and your project depends on anyio, we can conclude that your project's requirements are unsatisfiable.
 
-    hint: An index URL (http://[LOCALHOST]/basic-auth/simple) could not be queried due to a lack of valid authentication credentials (401 Unauthorized)
+    hint: The `proxy` index (http://[LOCALHOST]/basic-auth/simple) could not be queried due to a lack of valid authentication credentials (401 Unauthorized). Credential sources checked: keyring (no matching credentials). To provide credentials for this index, set `UV_INDEX_PROXY_PASSWORD` or run `uv auth login http://[LOCALHOST]/basic-auth/simple`.
     hint: If you want to add the package regardless of the failed resolution, provide the `--frozen` flag to skip locking and syncing
     "
     );
@@ -12843,7 +12989,7 @@ async fn add_stop_index_search_early_on_auth_failure() -> Result<()> {
       × No solution found when resolving dependencies:
       ╰─▶ Because anyio was not found in the package registry and your project depends on anyio, we can conclude that your project's requirements are unsatisfiable.
 
-    hint: An index URL (http://[LOCALHOST]/basic-auth/simple) could not be queried due to a lack of valid authentication credentials (401 Unauthorized)
+    hint: The `my-index` index (http://[LOCALHOST]/basic-auth/simple) could not be queried due to a lack of valid authentication credentials (401 Unauthorized). To provide credentials for this index, set `UV_INDEX_MY_INDEX_USERNAME` and `UV_INDEX_MY_INDEX_PASSWORD` or run `uv auth login http://[LOCALHOST]/basic-auth/simple`.
     hint: If you want to add the package regardless of the failed resolution, provide the `--frozen` flag to skip locking and syncing
     "
     );
@@ -13268,8 +13414,8 @@ fn add_auth_policy_always_with_username_no_password() -> Result<()> {
     Ok(())
 }
 
-/// In authentication "never", even if the correct credentials are supplied
-/// in the URL, no authenticated requests will be allowed.
+/// In authentication "never", credentials supplied in the index URL are still
+/// used, including for files hosted under the index.
 #[tokio::test]
 async fn add_auth_policy_never_with_url_credentials() -> Result<()> {
     let context = uv_test::test_context!("3.12");
@@ -13294,18 +13440,24 @@ async fn add_auth_policy_never_with_url_credentials() -> Result<()> {
     ))?;
 
     uv_snapshot!(context.filters(), context.add().arg("anyio"), @"
-    exit_code: 2 (failure)
+    exit_code: 0 (success)
     ----- stderr -----
-    error: Failed to fetch: `http://[LOCALHOST]/basic-auth/files/packages/14/fd/2f20c40b45e4fb4324834aea24bd4afdf1143390242c0b33774da0e2e34f/anyio-4.3.0-py3-none-any.whl`
-      Caused by: HTTP status client error (401 Unauthorized) for url (http://[LOCALHOST]/basic-auth/files/packages/14/fd/2f20c40b45e4fb4324834aea24bd4afdf1143390242c0b33774da0e2e34f/anyio-4.3.0-py3-none-any.whl)
+    Resolved 4 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + sniffio==1.3.1
     "
     );
 
+    context.assert_command("import anyio").success();
+
     Ok(())
 }
 
 /// In authentication "never", client errors that are configured to be ignored should allow the
-/// resolver to try another version.
+/// resolver to move on when the credentials in the index URL are rejected.
 #[tokio::test]
 async fn add_auth_policy_never_with_url_credentials_ignored() -> Result<()> {
     let context = uv_test::test_context!("3.12");
@@ -13327,17 +13479,15 @@ async fn add_auth_policy_never_with_url_credentials_ignored() -> Result<()> {
         ignore-error-codes = [401]
         default = true
         "#,
-        proxy_auth_uri = proxy.authenticated_uri("public", "heron")
+        proxy_auth_uri = proxy.authenticated_uri("public", "eagle")
     ))?;
 
     uv_snapshot!(context.filters(), context.add().arg("anyio"), @"
     exit_code: 1 (failure)
     ----- stderr -----
       × No solution found when resolving dependencies:
-      ╰─▶ Because only anyio==4.3.0 is available and anyio==4.3.0 could not be fetched from the network (`401 Unauthorized`), we can conclude that all versions of anyio cannot be used.
-          And because your project depends on anyio, we can conclude that your project's requirements are unsatisfiable.
+      ╰─▶ Because anyio was not found in the package registry and your project depends on anyio, we can conclude that your project's requirements are unsatisfiable.
 
-    hint: Metadata for `anyio` (v4.3.0) could not be fetched; the server returned: `401 Unauthorized`
     hint: If you want to add the package regardless of the failed resolution, provide the `--frozen` flag to skip locking and syncing
     "
     );
@@ -13345,8 +13495,8 @@ async fn add_auth_policy_never_with_url_credentials_ignored() -> Result<()> {
     Ok(())
 }
 
-/// In authentication "never", even if the correct credentials are supplied
-/// via env vars, no authenticated requests will be allowed.
+/// In authentication "never", credentials supplied for the index via env vars
+/// are still used.
 #[tokio::test]
 async fn add_auth_policy_never_with_env_var_credentials() -> Result<()> {
     let context = uv_test::test_context!("3.12");
@@ -13373,16 +13523,18 @@ async fn add_auth_policy_never_with_env_var_credentials() -> Result<()> {
     uv_snapshot!(context.filters(), context.add().arg("anyio")
         .env(EnvVars::UV_INDEX_MY_INDEX_USERNAME, "public")
         .env(EnvVars::UV_INDEX_MY_INDEX_PASSWORD, "heron"), @"
-    exit_code: 1 (failure)
+    exit_code: 0 (success)
     ----- stderr -----
-      × No solution found when resolving dependencies:
-      ╰─▶ Because anyio was not found in the package registry and your project depends on anyio, we can conclude that your project's requirements are unsatisfiable.
-
-    hint: An index URL (http://[LOCALHOST]/basic-auth/simple) could not be queried due to a lack of valid authentication credentials (401 Unauthorized)
-    hint: If you want to add the package regardless of the failed resolution, provide the `--frozen` flag to skip locking and syncing
+    Resolved 4 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + sniffio==1.3.1
     "
     );
 
+    context.assert_command("import anyio").success();
     Ok(())
 }
 
@@ -13593,7 +13745,7 @@ async fn add_redirect_with_keyring_cross_origin() -> Result<()> {
       × No solution found when resolving dependencies:
       ╰─▶ Because anyio was not found in the package registry and your project depends on anyio, we can conclude that your project's requirements are unsatisfiable.
 
-    hint: An index URL (http://[LOCALHOST]/) could not be queried due to a lack of valid authentication credentials (401 Unauthorized)
+    hint: An index URL (http://[LOCALHOST]/) could not be queried due to a lack of valid authentication credentials (401 Unauthorized). Credential sources checked: keyring (no matching credentials).
     hint: If you want to add the package regardless of the failed resolution, provide the `--frozen` flag to skip locking and syncing
     "
     );
@@ -14681,7 +14833,7 @@ fn add_no_install_project() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -14831,3 +14983,138 @@ async fn add_malware_detected() {
     error: Malware detected in one or more dependencies that would be installed; aborting sync. Set `UV_MALWARE_CHECK=0` to bypass this check.
     ");
 }
+
+/// Display a diff of each `pyproject.toml` edit with `--show-diff`, and preview edits without
+/// writing them with `--diff-only`.
+#[test]
+fn add_remove_show_diff() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    // The requirement is written before locking, and the lower bound is added afterwards.
+    uv_snapshot!(context.filters(), context.add().arg("iniconfig").arg("--show-diff"), @r#"
+    exit_code: 0 (success)
+    ----- stdout -----
+    --- pyproject.toml
+    +++ pyproject.toml
+    @@ -2,4 +2,6 @@
+     name = "project"
+     version = "0.1.0"
+     requires-python = ">=3.12"
+    -dependencies = []
+    +dependencies = [
+    +    "iniconfig",
+    +]
+    --- pyproject.toml
+    +++ pyproject.toml
+    @@ -3,5 +3,5 @@
+     version = "0.1.0"
+     requires-python = ">=3.12"
+     dependencies = [
+    -    "iniconfig",
+    +    "iniconfig>=2.0.0",
+     ]
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "#);
+
+    // With `--diff-only`, the edit is displayed, but not written.
+    uv_snapshot!(context.filters(), context.remove().arg("iniconfig").arg("--diff-only"), @r#"
+    exit_code: 0 (success)
+    ----- stdout -----
+    --- pyproject.toml
+    +++ pyproject.toml
+    @@ -2,6 +2,4 @@
+     name = "project"
+     version = "0.1.0"
+     requires-python = ">=3.12"
+    -dependencies = [
+    -    "iniconfig>=2.0.0",
+    -]
+    +dependencies = []
+    "#);
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "iniconfig>=2.0.0",
+        ]
+        "#
+        );
+    });
+
+    Ok(())
+}
+
+/// Preview an edit to a script's inline metadata with `--diff-only`, including the lower bound
+/// determined by resolution.
+#[test]
+fn add_script_diff_only() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_filtered_counts();
+
+    let script = context.temp_dir.child("script.py");
+    script.write_str(indoc! {r#"
+        # /// script
+        # requires-python = ">=3.11"
+        # dependencies = [
+        #   "requests<3",
+        # ]
+        # ///
+
+        import requests
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.add().arg("iniconfig").arg("--script").arg("script.py").arg("--diff-only"), @r#"
+    exit_code: 0 (success)
+    ----- stdout -----
+    --- script.py
+    +++ script.py
+    @@ -1,6 +1,7 @@
+     # /// script
+     # requires-python = ">=3.11"
+     # dependencies = [
+    +#   "iniconfig>=2.0.0",
+     #   "requests<3",
+     # ]
+     # ///
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    "#);
+
+    // The script is unchanged, and no lockfile is created.
+    script.assert(indoc! {r#"
+        # /// script
+        # requires-python = ">=3.11"
+        # dependencies = [
+        #   "requests<3",
+        # ]
+        # ///
+
+        import requests
+    "#});
+    assert!(!context.temp_dir.join("script.py.lock").exists());
+
+    Ok(())
+}
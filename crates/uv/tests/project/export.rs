@@ -454,7 +454,7 @@ fn requirements_txt_simplifies_selected_root_extra_markers_from_lock() -> Result
     lock.write_str(
         r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [[package]]
@@ -568,6 +568,143 @@ fn requirements_txt_prune() -> Result<()> {
     Ok(())
 }
 
+#[cfg(feature = "test-universal")]
+#[test]
+fn requirements_txt_prune_direct_dependency() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "jupyter-client"
+        ]
+    "#,
+    )?;
+
+    // By default, pruning a direct dependency is allowed.
+    uv_snapshot!(
+        context.filters(),
+        context.export()
+            .arg("--no-hashes")
+            .arg("--prune")
+            .arg("jupyter-client"),
+            @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --no-hashes --prune jupyter-client
+
+    ----- stderr -----
+    Resolved 12 packages in [TIME]
+    "
+    );
+
+    // In preview, pruning a direct dependency requires opting in.
+    uv_snapshot!(
+        context.filters(),
+        context.export()
+            .arg("--no-hashes")
+            .arg("--prune")
+            .arg("jupyter-client")
+            .arg("--preview-features")
+            .arg("export-prune-root-check"),
+            @"
+    exit_code: 2 (failure)
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 12 packages in [TIME]
+    error: Refusing to prune `jupyter-client`, which is a direct dependency of `project`; use `--allow-prune-root` to prune it anyway
+    "
+    );
+
+    uv_snapshot!(
+        context.filters(),
+        context.export()
+            .arg("--no-hashes")
+            .arg("--prune")
+            .arg("jupyter-client")
+            .arg("--allow-prune-root")
+            .arg("--preview-features")
+            .arg("export-prune-root-check"),
+            @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --no-hashes --prune jupyter-client --allow-prune-root --preview-features export-prune-root-check
+
+    ----- stderr -----
+    Resolved 12 packages in [TIME]
+    "
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "test-universal")]
+#[test]
+fn requirements_txt_omit() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "jupyter-client"
+        ]
+    "#,
+    )?;
+
+    // Omitting a package retains its dependencies.
+    uv_snapshot!(
+        context.filters(),
+        context.export()
+            .arg("--no-hashes")
+            .arg("--omit")
+            .arg("jupyter-core"),
+            @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --no-hashes --omit jupyter-core
+    cffi==1.16.0 ; implementation_name == 'pypy'
+        # via pyzmq
+    jupyter-client==8.6.1
+        # via project
+    platformdirs==4.2.0
+        # via jupyter-core
+    pycparser==2.21 ; implementation_name == 'pypy'
+        # via cffi
+    python-dateutil==2.9.0.post0
+        # via jupyter-client
+    pyzmq==25.1.2
+        # via jupyter-client
+    six==1.16.0
+        # via python-dateutil
+    tornado==6.4
+        # via jupyter-client
+    traitlets==5.14.2
+        # via
+        #   jupyter-client
+        #   jupyter-core
+
+    ----- stderr -----
+    Resolved 12 packages in [TIME]
+    "
+    );
+
+    Ok(())
+}
+
 #[cfg(feature = "test-universal")]
 #[test]
 fn requirements_txt_dependency_marker() -> Result<()> {
@@ -742,12 +879,12 @@ fn requirements_txt_dependency_conflicting_markers() -> Result<()> {
             insta::assert_snapshot!(
                 lock, @r#"
             version = 1
-            revision = 3
+            revision = 4
             requires-python = ">=3.12"
             resolution-markers = [
+                "sys_platform != 'darwin' and sys_platform != 'win32'",
                 "sys_platform == 'darwin'",
                 "sys_platform == 'win32'",
-                "sys_platform != 'darwin' and sys_platform != 'win32'",
             ]
 
             [options]
@@ -1163,6 +1300,76 @@ fn requirements_txt_frozen() -> Result<()> {
     Ok(())
 }
 
+/// Export from a lockfile provided on stdin, rather than the project's `uv.lock`.
+#[cfg(feature = "test-universal")]
+#[test]
+fn requirements_txt_lockfile_stdin() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    // Move the lockfile out of the project.
+    let lock = context.read("uv.lock");
+    fs_err::remove_file(context.temp_dir.join("uv.lock"))?;
+
+    uv_snapshot!(context.filters(), context.export().arg("--lockfile").arg("-"), input=&lock, @r"
+    exit_code: 0 (success)
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --lockfile -
+    -e .
+    iniconfig==2.0.0 \
+        --hash=sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3 \
+        --hash=sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374
+        # via project
+
+    ----- stderr -----
+    ");
+
+    // The lockfile must not be written back to the project.
+    assert!(!context.temp_dir.join("uv.lock").exists());
+
+    // A lockfile for a different project is rejected, unless validation is disabled.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "other"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.export().arg("--lockfile").arg("-"), input=&lock, @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    error: The lockfile at `-` does not match the workspace: Missing workspace member `other`.
+
+    hint: To use the lockfile regardless, provide `--no-validate`.
+    ");
+
+    assert_cmd::Command::from_std(context.export())
+        .arg("--lockfile")
+        .arg("-")
+        .arg("--no-validate")
+        .write_stdin(lock)
+        .assert()
+        .success();
+
+    Ok(())
+}
+
 #[cfg(feature = "test-universal")]
 #[test]
 fn requirements_txt_create_missing_dir() -> Result<()> {
@@ -1192,7 +1399,7 @@ fn requirements_txt_create_missing_dir() -> Result<()> {
     ----- stdout -----
     # This file was autogenerated by uv via the following command:
     #    uv export --cache-dir [CACHE_DIR] --output-file requirements/requirements.txt
-    -e .
+    -e ..
     anyio==3.7.0 \
         --hash=sha256:275d9973793619a5374e1c89a4f4ad3f4b0a5510a2b5b939444bee8f4c4d37ce \
         --hash=sha256:eddca883c4175f14df8aedce21054bfca3adb70ffe76a9f607aef9d7fa2ea7f0
@@ -1224,7 +1431,7 @@ fn requirements_txt_create_missing_dir() -> Result<()> {
     insta::assert_snapshot!(contents, @r"
     # This file was autogenerated by uv via the following command:
     #    uv export --cache-dir [CACHE_DIR] --output-file requirements/requirements.txt
-    -e .
+    -e ..
     anyio==3.7.0 \
         --hash=sha256:275d9973793619a5374e1c89a4f4ad3f4b0a5510a2b5b939444bee8f4c4d37ce \
         --hash=sha256:eddca883c4175f14df8aedce21054bfca3adb70ffe76a9f607aef9d7fa2ea7f0
@@ -1747,12 +1954,12 @@ fn requirements_txt_non_project_fork() -> Result<()> {
             insta::assert_snapshot!(
                 lock, @r#"
             version = 1
-            revision = 3
+            revision = 4
             requires-python = ">=3.12"
             resolution-markers = [
-                "sys_platform == 'win32'",
-                "sys_platform == 'linux'",
                 "sys_platform != 'linux' and sys_platform != 'win32'",
+                "sys_platform == 'linux'",
+                "sys_platform == 'win32'",
             ]
 
             [options]
@@ -1787,8 +1994,8 @@ fn requirements_txt_non_project_fork() -> Result<()> {
             version = "3.0.0"
             source = { registry = "https://pypi.org/simple" }
             resolution-markers = [
-                "sys_platform == 'linux'",
                 "sys_platform != 'linux' and sys_platform != 'win32'",
+                "sys_platform == 'linux'",
             ]
             dependencies = [
                 { name = "idna" },
@@ -2663,6 +2870,108 @@ fn requirements_txt_no_editable() -> Result<()> {
     Ok(())
 }
 
+/// Extras requested on a path dependency should be preserved, and relative paths should be
+/// written against `--relative-to`.
+#[cfg(feature = "test-universal")]
+#[test]
+fn requirements_txt_path_extras() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child[tracing]"]
+
+        [tool.uv.workspace]
+        members = ["libs/child"]
+
+        [tool.uv.sources]
+        child = { workspace = true }
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+        "#,
+    )?;
+
+    let child = context.temp_dir.child("libs").child("child");
+    child.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.optional-dependencies]
+        tracing = ["iniconfig>=2"]
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.export(), @r"
+    exit_code: 0 (success)
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR]
+    -e .
+    -e ./libs/child[tracing]
+        # via project
+    iniconfig==2.0.0 \
+        --hash=sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3 \
+        --hash=sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374
+        # via child
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    uv_snapshot!(context.filters(), context.export().arg("--no-editable"), @r"
+    exit_code: 0 (success)
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --no-editable
+    .
+    ./libs/child[tracing]
+        # via project
+    iniconfig==2.0.0 \
+        --hash=sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3 \
+        --hash=sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374
+        # via child
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    uv_snapshot!(context.filters(), context.export().arg("--relative-to").arg("requirements"), @r"
+    exit_code: 0 (success)
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --relative-to requirements
+    -e ..
+    -e ../libs/child[tracing]
+        # via project
+    iniconfig==2.0.0 \
+        --hash=sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3 \
+        --hash=sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374
+        # via child
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
 #[cfg(feature = "test-universal")]
 #[test]
 fn requirements_txt_export_group() -> Result<()> {
@@ -2832,6 +3141,94 @@ fn requirements_txt_export_group() -> Result<()> {
     Ok(())
 }
 
+#[cfg(feature = "test-universal")]
+#[test]
+fn requirements_txt_split_per_group() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["typing-extensions"]
+
+        [dependency-groups]
+        dev = ["sniffio", "iniconfig"]
+        docs = ["anyio ; sys_platform == 'darwin'", "iniconfig", "typing-extensions"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.export().arg("--split-per-group").arg("--output-dir").arg("requirements").arg("--group").arg("docs"), @r"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    ");
+
+    // The base file only includes the project's dependencies.
+    let contents = apply_filters(
+        context.read("requirements/requirements.txt"),
+        context.filters(),
+    );
+    insta::assert_snapshot!(contents, @r"
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --split-per-group --output-dir requirements --group docs
+    typing-extensions==4.10.0 \
+        --hash=sha256:69b1a937c3a517342112fb4c6df7e72fc39a38e7891a5730ed4985b5214b5475 \
+        --hash=sha256:b0abd7c89e8fb96f98db18d86106ff1d90ab692004eb746cf6eda2682f91b3cb
+        # via project
+    ");
+
+    // Each group file only includes the packages that are not already in the base file.
+    let contents = apply_filters(
+        context.read("requirements/requirements-dev.txt"),
+        context.filters(),
+    );
+    insta::assert_snapshot!(contents, @r"
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --split-per-group --output-dir requirements --group docs
+    -r requirements.txt
+    iniconfig==2.0.0 \
+        --hash=sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3 \
+        --hash=sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374
+    sniffio==1.3.1 \
+        --hash=sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2 \
+        --hash=sha256:f4324edc670a0f49750a81b895f35c3adb843cca46f0530f79fc1babb23789dc
+    ");
+
+    // Packages shared between groups, but not reachable from the project, are included in each
+    // group file.
+    let contents = apply_filters(
+        context.read("requirements/requirements-docs.txt"),
+        context.filters(),
+    );
+    insta::assert_snapshot!(contents, @r"
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --split-per-group --output-dir requirements --group docs
+    -r requirements.txt
+    anyio==4.3.0 ; sys_platform == 'darwin' \
+        --hash=sha256:048e05d0f6caeed70d731f3db756d35dcc1f35747c8c403364a8332c630441b8 \
+        --hash=sha256:f75253795a87df48568485fd18cdd2a3fa5c4f7c5be8e5e36637733fce06fed6
+    idna==3.6 ; sys_platform == 'darwin' \
+        --hash=sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca \
+        --hash=sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f
+        # via anyio
+    iniconfig==2.0.0 \
+        --hash=sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3 \
+        --hash=sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374
+    sniffio==1.3.1 ; sys_platform == 'darwin' \
+        --hash=sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2 \
+        --hash=sha256:f4324edc670a0f49750a81b895f35c3adb843cca46f0530f79fc1babb23789dc
+        # via anyio
+    ");
+
+    Ok(())
+}
+
 #[cfg(feature = "test-universal")]
 #[test]
 fn requirements_txt_script_no_config() -> Result<()> {
@@ -2921,12 +3318,12 @@ fn requirements_txt_script() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.11"
         resolution-markers = [
-            "sys_platform == 'win32'",
-            "sys_platform == 'linux'",
             "sys_platform != 'linux' and sys_platform != 'win32'",
+            "sys_platform == 'linux'",
+            "sys_platform == 'win32'",
         ]
 
         [options]
@@ -3039,12 +3436,12 @@ fn requirements_txt_script() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.11"
         resolution-markers = [
-            "sys_platform == 'win32'",
-            "sys_platform == 'linux'",
             "sys_platform != 'linux' and sys_platform != 'win32'",
+            "sys_platform == 'linux'",
+            "sys_platform == 'win32'",
         ]
 
         [options]
@@ -5287,7 +5684,7 @@ fn export_lock_workspace_mismatch_with_frozen() -> Result<()> {
     pyproject_toml.write_str(
         r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [[package]]
@@ -9827,7 +10224,7 @@ fn requirements_txt_emit_indexes() -> Result<()> {
     lock.write_str(
         r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [[package]]
@@ -9878,3 +10275,97 @@ fn requirements_txt_emit_indexes() -> Result<()> {
 
     Ok(())
 }
+
+/// Export a bundle, then install it into a fresh environment without network access.
+#[test]
+fn export_bundle_round_trip() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.export()
+        .arg("--bundle")
+        .arg("bundle.tar.zst"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Bundled 1 artifact into: bundle.tar.zst
+    ");
+
+    // Install from the bundle with an empty cache, and without network access.
+    fs_err::remove_dir_all(&context.venv)?;
+    let cache_dir = context.temp_dir.child("empty-cache");
+
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("--from-bundle")
+        .arg("bundle.tar.zst")
+        .arg("--cache-dir")
+        .arg(cache_dir.path())
+        .arg("--offline"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Using Python 3.12.[X] environment at: .venv
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    Ok(())
+}
+
+/// Bundling fails if a package has no wheel for a requested platform.
+#[test]
+fn export_bundle_missing_artifacts() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["source-distribution==0.0.1"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.export()
+        .arg("--bundle")
+        .arg("bundle.tar.zst")
+        .arg("--python-platform")
+        .arg("linux"), @"
+    exit_code: 2 (failure)
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    error: Missing artifacts for the requested platforms:
+      - `source-distribution` only provides a source distribution for `linux`
+    ");
+
+    context
+        .temp_dir
+        .child("bundle.tar.zst")
+        .assert(predicates::path::missing());
+
+    Ok(())
+}
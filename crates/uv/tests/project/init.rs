@@ -2736,6 +2736,117 @@ fn init_vcs_none() {
     child.child(".git").assert(predicate::path::missing());
 }
 
+#[test]
+fn init_ci_github() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let child = context.temp_dir.child("foo");
+
+    uv_snapshot!(context.filters(), context.init().arg(child.as_ref()).arg("--package").arg("--ci").arg("github"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Initialized project `foo` at `[TEMP_DIR]/foo`
+    ");
+
+    let workflow = fs_err::read_to_string(child.join(".github").join("workflows").join("ci.yml"))?;
+    assert_snapshot!(
+        workflow, @r#"
+    name: CI
+
+    on:
+      push:
+        branches: [main]
+      pull_request:
+
+    jobs:
+      test:
+        runs-on: ubuntu-latest
+        strategy:
+          matrix:
+            python-version: ["3.12", "3.14"]
+        steps:
+          - uses: actions/checkout@v5
+
+          - name: Install uv
+            uses: astral-sh/setup-uv@v7
+            with:
+              python-version: ${{ matrix.python-version }}
+              enable-cache: true
+              cache-dependency-glob: "uv.lock"
+
+          - name: Verify the lockfile
+            run: uv lock --check
+
+          - name: Install the project
+            run: uv sync --locked --all-extras --dev
+
+          - name: Run tests
+            run: uv run --with pytest pytest
+    "#
+    );
+
+    Ok(())
+}
+
+/// A workspace member's workflow is written at the root of the workspace.
+#[test]
+fn init_ci_workspace_member() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#,
+    })?;
+
+    let child = context.temp_dir.child("foo");
+
+    uv_snapshot!(context.filters(), context.init().arg(child.as_ref()).arg("--ci").arg("gitlab"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Adding `foo` as member of workspace `[TEMP_DIR]/`
+    Initialized project `foo` at `[TEMP_DIR]/foo`
+    ");
+
+    context
+        .temp_dir
+        .child(".gitlab-ci.yml")
+        .assert(predicate::path::is_file());
+    child
+        .child(".gitlab-ci.yml")
+        .assert(predicate::path::missing());
+
+    Ok(())
+}
+
+/// An existing workflow file should never be overwritten.
+#[test]
+fn init_ci_existing_workflow() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let child = context.temp_dir.child("foo");
+    child
+        .child(".gitlab-ci.yml")
+        .write_str("stages: [build]\n")?;
+
+    uv_snapshot!(context.filters(), context.init().arg(child.as_ref()).arg("--ci").arg("gitlab"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    warning: Skipping CI workflow generation; `.gitlab-ci.yml` already exists
+    Initialized project `foo` at `[TEMP_DIR]/foo`
+    ");
+
+    let workflow = fs_err::read_to_string(child.join(".gitlab-ci.yml"))?;
+    assert_snapshot!(workflow, @"stages: [build]");
+
+    Ok(())
+}
+
 /// Run `uv init` from within a Git repository. Do not try to reinitialize one.
 #[test]
 #[cfg(feature = "test-git")]
@@ -1052,7 +1052,7 @@ fn run_pep723_script_lock() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.11"
 
         [options]
@@ -1156,7 +1156,7 @@ fn run_pep723_script_lock() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.11"
 
         [options]
@@ -1202,6 +1202,156 @@ fn run_pep723_script_lock() -> Result<()> {
     Ok(())
 }
 
+/// Run PEP 723 scripts that share a lockfile.
+#[test]
+fn run_pep723_script_shared_lock() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let alpha = context.temp_dir.child("alpha.py");
+    alpha.write_str(indoc! { r#"
+        # /// script
+        # requires-python = ">=3.11"
+        # dependencies = [
+        #   "iniconfig",
+        # ]
+        # ///
+
+        import iniconfig
+
+        print("Hello, alpha!")
+       "#
+    })?;
+
+    let beta = context.temp_dir.child("beta.py");
+    beta.write_str(indoc! { r#"
+        # /// script
+        # requires-python = ">=3.11"
+        # dependencies = [
+        #   "sniffio",
+        # ]
+        # ///
+
+        import sniffio
+
+        print("Hello, beta!")
+       "#
+    })?;
+
+    // Multiple scripts require a shared lockfile.
+    uv_snapshot!(context.filters(), context.lock().arg("--script").arg("alpha.py").arg("beta.py"), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    error: Locking multiple scripts requires `--shared-lock`
+    ");
+
+    // Lock the scripts together.
+    uv_snapshot!(context.filters(), context.lock().arg("--script").arg("alpha.py").arg("beta.py").arg("--shared-lock").arg("uv.lock"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 4
+        requires-python = ">=3.11"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [manifest]
+        scripts = [
+            "alpha.py",
+            "beta.py",
+        ]
+
+        [manifest.dependency-groups]
+        alpha = [{ name = "iniconfig" }]
+        beta = [{ name = "sniffio" }]
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646, upload-time = "2023-01-07T11:08:11.254Z" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892, upload-time = "2023-01-07T11:08:09.864Z" },
+        ]
+
+        [[package]]
+        name = "sniffio"
+        version = "1.3.1"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/a2/87/a6771e1546d97e7e041b6ae58d80074f81b7d5121207425c964ddf5cfdbd/sniffio-1.3.1.tar.gz", hash = "sha256:f4324edc670a0f49750a81b895f35c3adb843cca46f0530f79fc1babb23789dc", size = 20372, upload-time = "2024-02-25T23:20:04.057Z" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/e9/44/75a9c9421471a6c4805dbf2356f7c181a29c1879239abab1ea2cc8f38b40/sniffio-1.3.1-py3-none-any.whl", hash = "sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2", size = 10235, upload-time = "2024-02-25T23:20:01.196Z" },
+        ]
+        "#
+        );
+    });
+
+    // Running a script should only install its own requirements.
+    uv_snapshot!(context.filters(), context.run().arg("--locked").arg("alpha.py"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    Hello, alpha!
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    // Modify the metadata of one of the scripts.
+    beta.write_str(indoc! { r#"
+        # /// script
+        # requires-python = ">=3.11"
+        # dependencies = [
+        #   "anyio",
+        # ]
+        # ///
+
+        import anyio
+
+        print("Hello, beta!")
+       "#
+    })?;
+
+    // Running the other script with `--locked` should name the stale script.
+    uv_snapshot!(context.filters(), context.run().arg("--locked").arg("alpha.py"), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    error: The lockfile at `uv.lock` needs to be updated for changes to `beta.py`, but `--locked` was provided.
+
+    hint: To update the lockfile, run `uv lock --script <scripts> --shared-lock <lockfile>`.
+    ");
+
+    // Without `--locked`, the shared lockfile is updated.
+    uv_snapshot!(context.filters(), context.run().arg("beta.py"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    Hello, beta!
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + sniffio==1.3.1
+    ");
+
+    Ok(())
+}
+
 /// With `managed = false`, we should avoid installing the project itself.
 #[test]
 fn run_managed_false() -> Result<()> {
@@ -1541,6 +1691,269 @@ fn run_with_local_wheel_refreshes_rebuilt_wheel() -> Result<()> {
     Ok(())
 }
 
+/// Test that a cached environment is not reused after a local source tree is modified.
+#[test]
+fn run_with_local_directory_picks_up_changes() -> Result<()> {
+    let context = uv_test::test_context_with_versions!(&["3.12"]);
+
+    let package = context.temp_dir.child("foo");
+    package.child("pyproject.toml").write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+        "#
+    })?;
+    let init = package.child("src").child("foo").child("__init__.py");
+    init.write_str(indoc! { r#"
+        def hello() -> str:
+            return "Hello from foo!"
+        "#
+    })?;
+    filetime::set_file_mtime(
+        init.path(),
+        filetime::FileTime::from_unix_time(1_700_000_000, 0),
+    )
+    .unwrap();
+
+    // First run: build and install the package.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--isolated")
+        .arg("--with")
+        .arg(package.path())
+        .arg("python")
+        .arg("-c")
+        .arg("import foo; print(foo.hello())")
+        .env_remove(EnvVars::VIRTUAL_ENV), @r"
+    exit_code: 0 (success)
+    ----- stdout -----
+    Hello from foo!
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + foo==0.1.0 (from file://[TEMP_DIR]/foo)
+    ");
+
+    // Second run: the source tree is unchanged, so the cached environment should be reused.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--isolated")
+        .arg("--with")
+        .arg(package.path())
+        .arg("python")
+        .arg("-c")
+        .arg("import foo; print(foo.hello())")
+        .env_remove(EnvVars::VIRTUAL_ENV), @r"
+    exit_code: 0 (success)
+    ----- stdout -----
+    Hello from foo!
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    ");
+
+    // Modify the package, without changing any of its cache keys.
+    init.write_str(indoc! { r#"
+        def hello() -> str:
+            return "Updated code!"
+        "#
+    })?;
+    filetime::set_file_mtime(
+        init.path(),
+        filetime::FileTime::from_unix_time(1_700_000_001, 0),
+    )
+    .unwrap();
+
+    // Third run: the package should be rebuilt into a new environment.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--isolated")
+        .arg("--with")
+        .arg(package.path())
+        .arg("python")
+        .arg("-c")
+        .arg("import foo; print(foo.hello())")
+        .env_remove(EnvVars::VIRTUAL_ENV), @r"
+    exit_code: 0 (success)
+    ----- stdout -----
+    Updated code!
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + foo==0.1.0 (from file://[TEMP_DIR]/foo)
+    ");
+
+    Ok(())
+}
+
+/// Test that repeated `--with` invocations reuse the cached resolution of the requirements.
+#[test]
+fn run_with_cached_resolution() -> Result<()> {
+    let context = uv_test::test_context_with_versions!(&["3.12"]);
+
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--no-project")
+        .arg("--with")
+        .arg("iniconfig")
+        .arg("python")
+        .arg("-c")
+        .arg("import iniconfig")
+        .env_remove(EnvVars::VIRTUAL_ENV), @r"
+    exit_code: 0 (success)
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    // The second invocation should skip resolution entirely.
+    let output = context
+        .run()
+        .arg("-v")
+        .arg("--no-project")
+        .arg("--with")
+        .arg("iniconfig")
+        .arg("python")
+        .arg("-c")
+        .arg("import iniconfig")
+        .env_remove(EnvVars::VIRTUAL_ENV)
+        .output()?;
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Using cached resolution for environment specification"),
+        "{stderr}"
+    );
+    assert!(!stderr.contains("Solving with"), "{stderr}");
+
+    // Refreshing the cache should resolve the requirements again.
+    let output = context
+        .run()
+        .arg("-v")
+        .arg("--refresh")
+        .arg("--no-project")
+        .arg("--with")
+        .arg("iniconfig")
+        .arg("python")
+        .arg("-c")
+        .arg("import iniconfig")
+        .env_remove(EnvVars::VIRTUAL_ENV)
+        .output()?;
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Solving with"), "{stderr}");
+
+    Ok(())
+}
+
+/// Test that `--with` invocations with different install settings don't share a cached environment.
+#[test]
+fn run_with_install_settings_cached_environment() -> Result<()> {
+    let context = uv_test::test_context_with_versions!(&["3.12"]);
+
+    let sys_prefix = |args: &[&str]| -> Result<String> {
+        let output = context
+            .run()
+            .arg("--no-project")
+            .arg("--with")
+            .arg("iniconfig")
+            .args(args)
+            .arg("python")
+            .arg("-c")
+            .arg("import sys; print(sys.prefix)")
+            .env_remove(EnvVars::VIRTUAL_ENV)
+            .output()?;
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        Ok(String::from_utf8(output.stdout)?.trim().to_string())
+    };
+
+    let default = sys_prefix(&[])?;
+    let no_binary = sys_prefix(&["--no-binary", "iniconfig"])?;
+    let compile = sys_prefix(&["--compile-bytecode"])?;
+
+    // Differing settings should lead to distinct environments.
+    assert_ne!(default, no_binary);
+    assert_ne!(default, compile);
+    assert_ne!(no_binary, compile);
+
+    // Repeated identical invocations should reuse the cached environments.
+    assert_eq!(sys_prefix(&[])?, default);
+    assert_eq!(sys_prefix(&["--no-binary", "iniconfig"])?, no_binary);
+
+    // Cached environments are hard linked rather than copied, so the two share an environment.
+    assert_eq!(
+        sys_prefix(&["--link-mode", "copy"])?,
+        sys_prefix(&["--link-mode", "hardlink"])?
+    );
+
+    Ok(())
+}
+
+/// Cached environments that are no longer intact are rebuilt, rather than reused.
+#[test]
+fn run_with_invalid_cached_environment() -> Result<()> {
+    let context = uv_test::test_context_with_versions!(&["3.12"]);
+
+    let sys_prefix = || -> Result<String> {
+        let output = context
+            .run()
+            .arg("--no-project")
+            .arg("--with")
+            .arg("iniconfig")
+            .arg("python")
+            .arg("-c")
+            .arg("import iniconfig, sys; print(sys.prefix)")
+            .env_remove(EnvVars::VIRTUAL_ENV)
+            .output()?;
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        Ok(String::from_utf8(output.stdout)?.trim().to_string())
+    };
+
+    let initial = sys_prefix()?;
+    assert_eq!(sys_prefix()?, initial);
+
+    // Remove the marker that's written once the environment is fully synced, as if the build had
+    // been interrupted.
+    fs_err::remove_file(Path::new(&initial).join(".ok"))?;
+    let rebuilt = sys_prefix()?;
+    assert_ne!(rebuilt, initial);
+    assert_eq!(sys_prefix()?, rebuilt);
+
+    // Point the environment at a base interpreter that no longer exists, as if it had been
+    // uninstalled.
+    #[cfg(unix)]
+    {
+        let python = Path::new(&rebuilt).join("bin").join("python");
+        fs_err::remove_file(&python)?;
+        fs_err::os::unix::fs::symlink(
+            context.temp_dir.child("missing").join("python3.12"),
+            &python,
+        )?;
+        let rebuilt_again = sys_prefix()?;
+        assert_ne!(rebuilt_again, rebuilt);
+        assert_eq!(sys_prefix()?, rebuilt_again);
+    }
+
+    Ok(())
+}
+
 /// Test that an ephemeral environment writes the path of its parent environment to the `extends-environment` key
 /// of its `pyvenv.cfg` file. This feature makes it easier for static-analysis tools like ty to resolve which import
 /// search paths are available in these ephemeral environments.
@@ -1596,27 +2009,197 @@ fn run_with_pyvenv_cfg_file() -> Result<()> {
         .env(EnvVars::VIRTUAL_ENV, parent_environment.path())
         .arg("--with")
         .arg("iniconfig")
-        .arg("main.py"), @"
-    exit_code: 0 (success)
-    ----- stdout -----
-    home = [PYTHON_HOME]
-    implementation = CPython
-    uv = [UV_VERSION]
-    version_info = 3.12.[X]
-    include-system-site-packages = false
-    extends-environment = [PARENT_VENV]/
-
+        .arg("main.py"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    home = [PYTHON_HOME]
+    implementation = CPython
+    uv = [UV_VERSION]
+    version_info = 3.12.[X]
+    include-system-site-packages = false
+    extends-environment = [PARENT_VENV]/
+
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + foo==1.0.0 (from file://[TEMP_DIR]/)
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    Ok(())
+}
+
+/// Test that an ephemeral environment layered over an environment that itself extends another
+/// environment records the full chain in `extends-environment`, and can import packages that are
+/// only installed in the most distant ancestor.
+#[test]
+#[cfg(unix)]
+fn run_with_nested_parent_environments() -> Result<()> {
+    let context = uv_test::test_context_with_versions!(&["3.12"]).with_pyvenv_cfg_filters();
+
+    // Create a grandparent environment with `iniconfig` installed.
+    let grandparent_environment = context.temp_dir.child("grandparent");
+    context
+        .venv()
+        .arg(grandparent_environment.path())
+        .assert()
+        .success();
+    context
+        .pip_install()
+        .arg("--python")
+        .arg(grandparent_environment.path())
+        .arg("iniconfig")
+        .assert()
+        .success();
+
+    // Create a parent environment that extends the grandparent, as an ephemeral environment would.
+    let parent_environment = context.temp_dir.child("parent");
+    context
+        .venv()
+        .arg(parent_environment.path())
+        .assert()
+        .success();
+    let pyvenv_cfg = parent_environment.child("pyvenv.cfg");
+    let contents = fs_err::read_to_string(&pyvenv_cfg)?;
+    fs_err::write(
+        &pyvenv_cfg,
+        format!(
+            "{contents}extends-environment = {}\n",
+            grandparent_environment.path().display()
+        ),
+    )?;
+
+    let context = context
+        .with_filtered_path(&parent_environment, "PARENT_VENV")
+        .with_filtered_path(&grandparent_environment, "GRANDPARENT_VENV");
+
+    let test_script = context.temp_dir.child("main.py");
+    test_script.write_str(indoc! { r#"
+        import os
+
+        import iniconfig
+
+        with open(f'{os.getenv("VIRTUAL_ENV")}/pyvenv.cfg') as f:
+            print(f.read())
+       "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.run()
+        .env(EnvVars::VIRTUAL_ENV, parent_environment.path())
+        .arg("--no-project")
+        .arg("--with")
+        .arg("sniffio")
+        .arg("main.py"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    home = [PYTHON_HOME]
+    implementation = CPython
+    uv = [UV_VERSION]
+    version_info = 3.12.[X]
+    include-system-site-packages = false
+    extends-environment = [PARENT_VENV]/:[GRANDPARENT_VENV]/
+
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + sniffio==1.3.1
+    ");
+
+    Ok(())
+}
+
+/// Test that `--show-environment` describes the environment that the command would run in,
+/// without running it.
+#[test]
+fn run_show_environment() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio"]
+        "#
+    })?;
+
+    let test_script = context.temp_dir.child("main.py");
+    test_script.write_str(indoc! { r"
+        open('marker', 'w').close()
+       "
+    })?;
+
+    let output = context
+        .run()
+        .arg("--show-environment")
+        .arg("--with")
+        .arg("iniconfig")
+        .arg("main.py")
+        .output()?;
+    assert!(output.status.success());
+
+    // The command should not be run.
+    context
+        .temp_dir
+        .child("marker")
+        .assert(predicate::path::missing());
+
+    let environment: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    let sys_prefix = Path::new(environment["sys_prefix"].as_str().unwrap());
+    let extends_environment = environment["extends_environment"].as_array().unwrap();
+    let site_packages = environment["site_packages"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|path| Path::new(path.as_str().unwrap()))
+        .collect::<Vec<_>>();
+
+    // The `--with` requirements take precedence over the project environment.
+    assert!(site_packages[0].starts_with(sys_prefix));
+    assert!(site_packages[0].join("iniconfig").is_dir());
+    assert_eq!(extends_environment.len(), 1);
+    let parent = Path::new(extends_environment[0].as_str().unwrap());
+    assert!(parent.ends_with(".venv"));
+    assert!(site_packages[1].starts_with(parent));
+    assert!(site_packages[1].join("anyio").is_dir());
+    assert_eq!(environment["scripts"].as_array().unwrap().len(), 2);
+
+    // A subsequent invocation reuses the cached environment, so the output is identical.
+    let cached = context
+        .run()
+        .arg("--show-environment")
+        .arg("--with")
+        .arg("iniconfig")
+        .arg("main.py")
+        .output()?;
+    assert!(cached.status.success());
+    assert_eq!(output.stdout, cached.stdout);
 
-    ----- stderr -----
-    Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + foo==1.0.0 (from file://[TEMP_DIR]/)
-    Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + iniconfig==2.0.0
-    ");
+    // Without `--with`, the project environment is shown.
+    let output = context
+        .run()
+        .arg("--show-environment")
+        .arg("main.py")
+        .output()?;
+    assert!(output.status.success());
+    let environment: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert!(Path::new(environment["sys_prefix"].as_str().unwrap()).ends_with(".venv"));
+    assert_eq!(
+        environment["extends_environment"].as_array().unwrap().len(),
+        0
+    );
+    context
+        .temp_dir
+        .child("marker")
+        .assert(predicate::path::missing());
 
     Ok(())
 }
@@ -2080,6 +2663,84 @@ fn run_in_workspace() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn run_with_local_extras() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let child = context.temp_dir.child("vendor").child("child");
+    child.child("pyproject.toml").write_str(indoc! { r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.optional-dependencies]
+        test = ["iniconfig"]
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+        "#
+    })?;
+    child
+        .child("src")
+        .child("child")
+        .child("__init__.py")
+        .touch()?;
+
+    // Extras on a local directory should be enabled.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--no-project")
+        .arg("--with")
+        .arg("./vendor/child[test]")
+        .arg("python")
+        .arg("-c")
+        .arg("import child, iniconfig"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + child==0.1.0 (from file://[TEMP_DIR]/vendor/child)
+     + iniconfig==2.0.0
+    ");
+
+    // An extra that the directory doesn't declare should be rejected up front.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--no-project")
+        .arg("--with-editable")
+        .arg("./vendor/child[dev]")
+        .arg("python")
+        .arg("-c")
+        .arg("import child"), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    error: `vendor/child` does not declare an extra named `dev` (available extras: `test`)
+    ");
+
+    // If a directory with brackets in its name exists, prefer the literal path.
+    let bracketed = context.temp_dir.child("vendor").child("child[test]");
+    copy_dir_all(&child, &bracketed)?;
+
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--no-project")
+        .arg("--with")
+        .arg("./vendor/child[test]")
+        .arg("python")
+        .arg("-c")
+        .arg("import child"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + child==0.1.0 (from file://[TEMP_DIR]/vendor/child[test])
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn run_with_editable() -> Result<()> {
     let context = uv_test::test_context!("3.12");
@@ -2475,7 +3136,7 @@ fn run_locked() -> Result<()> {
         assert_snapshot!(
             existing, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -2592,7 +3253,190 @@ fn run_locked() -> Result<()> {
 }
 
 #[test]
-fn run_frozen() -> Result<()> {
+fn run_frozen() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+        "#,
+    )?;
+    context
+        .temp_dir
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .touch()?;
+
+    // Running with `--frozen` should error, if no lockfile is present.
+    uv_snapshot!(context.filters(), context.run().arg("--frozen").arg("--").arg("python").arg("--version"), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    error: Unable to find lockfile at `uv.lock`, but `--frozen` was provided. To create a lockfile, run `uv lock` or `uv sync` without the flag.
+    ");
+
+    context.lock().assert().success();
+
+    // Update the requirements.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+        "#,
+    )?;
+
+    // Running with `--frozen` should install the stale lockfile.
+    uv_snapshot!(context.filters(), context.run().arg("--frozen").arg("--").arg("python").arg("--version"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    Python 3.12.[X]
+
+    ----- stderr -----
+    Prepared 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.6
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+     + sniffio==1.3.1
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn run_no_sync() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+        "#,
+    )?;
+    context
+        .temp_dir
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .touch()?;
+
+    // Running with `--no-sync` should succeed, even if the lockfile isn't present.
+    uv_snapshot!(context.filters(), context.run().arg("--no-sync").arg("--").arg("python").arg("--version"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    Python 3.12.[X]
+    ");
+
+    context.lock().assert().success();
+
+    // Running with `--no-sync` should not install any requirements.
+    uv_snapshot!(context.filters(), context.run().arg("--no-sync").arg("--").arg("python").arg("--version"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    Python 3.12.[X]
+    ");
+
+    context.sync().assert().success();
+
+    // But it should have access to the installed packages.
+    uv_snapshot!(context.filters(), context.run().arg("--no-sync").arg("--").arg("python").arg("-c").arg("import anyio; print(anyio.__name__)"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    anyio
+    ");
+
+    Ok(())
+}
+
+/// Console scripts declared by the project can be run before they're installed, e.g., with
+/// `--no-sync`.
+#[test]
+fn run_no_sync_project_script() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.scripts]
+        greet = "project.cli:main"
+        invalid = "project.cli"
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+        "#,
+    )?;
+    context
+        .temp_dir
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .touch()?;
+    context
+        .temp_dir
+        .child("src")
+        .child("project")
+        .child("cli.py")
+        .write_str(indoc! { r#"
+            import sys
+
+            def main():
+                print(f"Hello from {sys.argv[0]}: {sys.argv[1:]}")
+        "#
+        })?;
+
+    // The script isn't installed, so call its entrypoint directly.
+    uv_snapshot!(context.filters(), context.run().arg("--no-sync").arg("greet").arg("--flag").arg("value"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    Hello from greet: ['--flag', 'value']
+    ");
+
+    // Undeclared commands still aren't found.
+    uv_snapshot!(context.filters(), context.run().arg("--no-sync").arg("missing"), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    error: Failed to spawn: `missing`
+      Caused by: [OS ERROR 2]
+    ");
+
+    Ok(())
+}
+
+/// Console scripts are called from the `module-root` configured for the uv build backend, and
+/// may contain dots in their name.
+#[test]
+fn run_no_sync_project_script_module_root() -> Result<()> {
     let context = uv_test::test_context!("3.12");
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
@@ -2602,7 +3446,13 @@ fn run_frozen() -> Result<()> {
         name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = ["anyio==3.7.0"]
+        dependencies = []
+
+        [project.scripts]
+        "greet.cli" = "project.cli:main"
+
+        [tool.uv.build-backend]
+        module-root = "lib"
 
         [build-system]
         requires = ["uv_build>=0.7,<10000"]
@@ -2611,55 +3461,100 @@ fn run_frozen() -> Result<()> {
     )?;
     context
         .temp_dir
-        .child("src")
+        .child("lib")
         .child("project")
         .child("__init__.py")
         .touch()?;
+    context
+        .temp_dir
+        .child("lib")
+        .child("project")
+        .child("cli.py")
+        .write_str(indoc! { r#"
+            import sys
 
-    // Running with `--frozen` should error, if no lockfile is present.
-    uv_snapshot!(context.filters(), context.run().arg("--frozen").arg("--").arg("python").arg("--version"), @"
-    exit_code: 2 (failure)
-    ----- stderr -----
-    error: Unable to find lockfile at `uv.lock`, but `--frozen` was provided. To create a lockfile, run `uv lock` or `uv sync` without the flag.
+            def main():
+                print(f"Hello from {sys.argv[0]}: {sys.argv[1:]}")
+        "#
+        })?;
+
+    uv_snapshot!(context.filters(), context.run().arg("--no-sync").arg("greet.cli").arg("--flag"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    Hello from greet.cli: ['--flag']
     ");
 
-    context.lock().assert().success();
+    Ok(())
+}
 
-    // Update the requirements.
+/// Run the scripts declared in `tool.uv.scripts`.
+#[test]
+fn run_tool_uv_scripts() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
     pyproject_toml.write_str(
         r#"
         [project]
         name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = ["iniconfig"]
+        dependencies = []
 
-        [build-system]
-        requires = ["uv_build>=0.7,<10000"]
-        build-backend = "uv_build"
+        [project.scripts]
+        greet = "project.cli:main"
+
+        [tool.uv.scripts]
+        hello = "python -c \"import sys; print('hello', sys.argv[1:])\" {args} done"
+        steps = [
+            "python -c \"print('one')\"",
+            "python -c \"import sys; sys.exit(3)\"",
+            "python -c \"print('two')\"",
+        ]
+        greet = "python -c \"print('greetings from tool.uv.scripts')\""
         "#,
     )?;
 
-    // Running with `--frozen` should install the stale lockfile.
-    uv_snapshot!(context.filters(), context.run().arg("--frozen").arg("--").arg("python").arg("--version"), @"
+    context.sync().assert().success();
+
+    // Extra arguments are substituted for `{args}`.
+    uv_snapshot!(context.filters(), context.run().arg("--no-sync").arg("hello").arg("first").arg("second"), @"
     exit_code: 0 (success)
     ----- stdout -----
-    Python 3.12.[X]
+    hello ['first', 'second', 'done']
+    ");
+
+    // A list of commands stops at the first failure.
+    uv_snapshot!(context.filters(), context.run().arg("--no-sync").arg("steps"), @"
+    exit_code: 3 (failure)
+    ----- stdout -----
+    one
+    ");
+
+    // Scripts take precedence over executables with the same name.
+    uv_snapshot!(context.filters(), context.run().arg("--no-sync").arg("greet"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    greetings from tool.uv.scripts
 
     ----- stderr -----
-    Prepared 4 packages in [TIME]
-    Installed 4 packages in [TIME]
-     + anyio==3.7.0
-     + idna==3.6
-     + project==0.1.0 (from file://[TEMP_DIR]/)
-     + sniffio==1.3.1
+    warning: `greet` is defined in `tool.uv.scripts` and shadows an executable with the same name; pass `--no-project-scripts` to run the executable instead
+    ");
+
+    // Unless `--no-project-scripts` is provided.
+    uv_snapshot!(context.filters(), context.run().arg("--no-sync").arg("--no-project-scripts").arg("hello"), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    error: Failed to spawn: `hello`
+      Caused by: [OS ERROR 2]
     ");
 
     Ok(())
 }
 
+/// Run the commands declared in `tool.uv.post-sync` after `uv run` syncs the environment.
 #[test]
-fn run_no_sync() -> Result<()> {
+fn run_post_sync() -> Result<()> {
     let context = uv_test::test_context!("3.12");
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
@@ -2669,45 +3564,68 @@ fn run_no_sync() -> Result<()> {
         name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = ["anyio==3.7.0"]
+        dependencies = []
 
-        [build-system]
-        requires = ["uv_build>=0.7,<10000"]
-        build-backend = "uv_build"
+        [tool.uv]
+        post-sync = ["python -c \"import os; print('post-sync', os.environ['UV_NO_SYNC'])\""]
         "#,
     )?;
-    context
-        .temp_dir
-        .child("src")
-        .child("project")
-        .child("__init__.py")
-        .touch()?;
 
-    // Running with `--no-sync` should succeed, even if the lockfile isn't present.
-    uv_snapshot!(context.filters(), context.run().arg("--no-sync").arg("--").arg("python").arg("--version"), @"
+    uv_snapshot!(context.filters(), context.run().arg("python").arg("-c").arg("print('main')"), @r#"
     exit_code: 0 (success)
     ----- stdout -----
-    Python 3.12.[X]
-    ");
+    post-sync 1
+    main
 
-    context.lock().assert().success();
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Resolved 1 package in [TIME]
+    Checked in [TIME]
+    Running post-sync command: `python -c "import os; print('post-sync', os.environ['UV_NO_SYNC'])"`
+    "#);
 
-    // Running with `--no-sync` should not install any requirements.
-    uv_snapshot!(context.filters(), context.run().arg("--no-sync").arg("--").arg("python").arg("--version"), @"
+    // The commands are skipped with `--no-post-sync`...
+    uv_snapshot!(context.filters(), context.run().arg("--no-post-sync").arg("python").arg("-c").arg("print('main')"), @"
     exit_code: 0 (success)
     ----- stdout -----
-    Python 3.12.[X]
-    ");
+    main
 
-    context.sync().assert().success();
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Checked in [TIME]
+    ");
 
-    // But it should have access to the installed packages.
-    uv_snapshot!(context.filters(), context.run().arg("--no-sync").arg("--").arg("python").arg("-c").arg("import anyio; print(anyio.__name__)"), @"
+    // ...and when the environment isn't synced.
+    uv_snapshot!(context.filters(), context.run().arg("--no-sync").arg("python").arg("-c").arg("print('main')"), @"
     exit_code: 0 (success)
     ----- stdout -----
-    anyio
+    main
     ");
 
+    // A failing command aborts before running the target command.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv]
+        post-sync = ["python -c \"import sys; sys.exit(4)\""]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.run().arg("python").arg("-c").arg("print('main')"), @r#"
+    exit_code: 4 (failure)
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Checked in [TIME]
+    Running post-sync command: `python -c "import sys; sys.exit(4)"`
+    error: Post-sync command `python -c "import sys; sys.exit(4)"` failed (exit status: 4)
+    "#);
+
     Ok(())
 }
 
@@ -5988,7 +6906,7 @@ fn run_pep723_script_with_constraints_lock() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.11"
 
         [options]
@@ -6251,6 +7169,79 @@ fn isolate_child_environment() -> Result<()> {
     Ok(())
 }
 
+/// Run an executable from the environment of the workspace member selected with `--package`.
+#[cfg(unix)]
+#[test]
+fn run_package_member_environment() -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let context = uv_test::test_context!("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! { r#"
+        [tool.uv.workspace]
+        members = ["api", "worker"]
+        "#
+        })?;
+
+    for member in ["api", "worker"] {
+        context
+            .temp_dir
+            .child(member)
+            .child("pyproject.toml")
+            .write_str(&formatdoc! { r#"
+            [project]
+            name = "{member}"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = []
+            "#
+            })?;
+
+        // Give each member its own environment, with a same-named script.
+        let venv = context.temp_dir.child(member).child(".venv");
+        context.venv().arg(venv.path()).assert().success();
+        let script = venv.child("bin").child("serve");
+        script.write_str(&formatdoc! { r#"
+            #!/bin/sh
+            echo "Serving {member} from $VIRTUAL_ENV"
+            "#
+        })?;
+        fs_err::set_permissions(script.path(), PermissionsExt::from_mode(0o0755))?;
+    }
+
+    context.sync().assert().success();
+
+    uv_snapshot!(context.filters(), context.run().arg("--no-sync").arg("--package").arg("api").arg("serve"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    Serving api from [TEMP_DIR]/api/.venv
+    ");
+
+    uv_snapshot!(context.filters(), context.run().arg("--no-sync").arg("--package").arg("worker").arg("serve"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    Serving worker from [TEMP_DIR]/worker/.venv
+    ");
+
+    // The member's environment also takes precedence with `--with`.
+    uv_snapshot!(context.filters(), context.run().arg("--no-sync").arg("--package").arg("worker").arg("--with").arg("iniconfig").arg("serve"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    Serving worker from [TEMP_DIR]/worker/.venv
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn run_only_group_and_extra_conflict() -> Result<()> {
     let context = uv_test::test_context!("3.12");
@@ -6771,3 +7762,143 @@ fn run_centralized_environment_path_file() -> Result<()> {
     "#);
     Ok(())
 }
+
+#[test]
+fn run_with_lock() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#
+        })?;
+
+    // Lock a separate project, to provide the packages for `--with-lock`.
+    let tools = context.temp_dir.child("tools");
+    tools.child("pyproject.toml").write_str(indoc! { r#"
+        [project]
+        name = "tools"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [project.optional-dependencies]
+        types = ["typing-extensions"]
+
+        [dependency-groups]
+        lint = ["sniffio"]
+        "#
+    })?;
+    context.lock().current_dir(tools.path()).assert().success();
+
+    // Without a name, install the base dependencies of the locked project.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--with-lock")
+        .arg("tools/uv.lock")
+        .arg("python")
+        .arg("-c")
+        .arg("import iniconfig"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    // The environment is cached across invocations.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--with-lock")
+        .arg("tools/uv.lock")
+        .arg("python")
+        .arg("-c")
+        .arg("import iniconfig"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    ");
+
+    // Include a dependency group.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--with-lock")
+        .arg("tools/uv.lock:lint")
+        .arg("python")
+        .arg("-c")
+        .arg("import iniconfig, sniffio"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + sniffio==1.3.1
+    ");
+
+    // Include an extra.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--with-lock")
+        .arg("tools/uv.lock:types")
+        .arg("python")
+        .arg("-c")
+        .arg("import iniconfig, typing_extensions"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + typing-extensions==4.10.0
+    ");
+
+    // Reject a name that's neither a group nor an extra.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--with-lock")
+        .arg("tools/uv.lock:test")
+        .arg("python")
+        .arg("-c")
+        .arg("import iniconfig"), @"
+    exit_code: 2 (failure)
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    error: `test` is neither a dependency group nor an extra in: `tools/uv.lock`
+    ");
+
+    // `--with-lock` can't be combined with `--with`.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--with-lock")
+        .arg("tools/uv.lock")
+        .arg("--with")
+        .arg("sniffio")
+        .arg("python"), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    error: the argument '--with-lock <WITH_LOCK>' cannot be used with '--with <WITH>'
+
+    Usage: uv run --cache-dir [CACHE_DIR] --with-lock <WITH_LOCK> --exclude-newer <EXCLUDE_NEWER> [COMMAND]
+
+    For more information, try '--help'.
+    ");
+
+    Ok(())
+}
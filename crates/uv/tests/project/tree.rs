@@ -2094,6 +2094,114 @@ fn repeated_version() -> Result<()> {
     Ok(())
 }
 
+/// `--duplicates` shows only the packages that are locked at more than one version.
+#[cfg(feature = "test-universal")]
+#[test]
+fn duplicates() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+          "anyio<4 ; sys_platform == 'win32'",
+          "anyio>=4 ; sys_platform != 'win32'",
+          "iniconfig",
+        ]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.tree().arg("--duplicates").arg("--frozen"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    anyio
+    ├── anyio v3.7.1 ; sys_platform == 'win32'
+    │   └── project v0.1.0 -> anyio v3.7.1
+    └── anyio v4.3.0 ; sys_platform != 'win32'
+        └── project v0.1.0 -> anyio v4.3.0
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.tree().arg("--duplicates").arg("--frozen").arg("--format").arg("json").arg("--preview-features").arg("json-output"), @r#"
+    exit_code: 0 (success)
+    ----- stdout -----
+    {
+      "schema": {
+        "version": "preview"
+      },
+      "packages": [
+        {
+          "name": "anyio",
+          "versions": [
+            {
+              "version": "3.7.1",
+              "source": "registry+https://pypi.org/simple",
+              "markers": [
+                "sys_platform == 'win32'"
+              ],
+              "path": [
+                {
+                  "name": "project",
+                  "version": "0.1.0"
+                },
+                {
+                  "name": "anyio",
+                  "version": "3.7.1"
+                }
+              ]
+            },
+            {
+              "version": "4.3.0",
+              "source": "registry+https://pypi.org/simple",
+              "markers": [
+                "sys_platform != 'win32'"
+              ],
+              "path": [
+                {
+                  "name": "project",
+                  "version": "0.1.0"
+                },
+                {
+                  "name": "anyio",
+                  "version": "4.3.0"
+                }
+              ]
+            }
+          ]
+        }
+      ]
+    }
+    "#
+    );
+
+    // Once the versions are collapsed, there's nothing to show.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio>=4", "iniconfig"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.tree().arg("--duplicates"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    No packages are locked at multiple versions
+    "
+    );
+
+    Ok(())
+}
+
 #[test]
 fn dev_dependencies() -> Result<()> {
     let context = uv_test::test_context!("3.12");
@@ -3442,11 +3550,11 @@ fn invert_preserves_marker_split_versions() -> Result<()> {
     context.temp_dir.child("uv.lock").write_str(
         r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         resolution-markers = [
-            "sys_platform == 'win32'",
             "sys_platform != 'win32'",
+            "sys_platform == 'win32'",
         ]
 
         [[package]]
@@ -3568,7 +3676,7 @@ fn invert_preserves_conflict_marker_attribution() -> Result<()> {
     context.temp_dir.child("uv.lock").write_str(
         r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "a", extra = "bar" },
@@ -3985,7 +4093,7 @@ fn script() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.11"
 
         [options]
@@ -4170,7 +4278,7 @@ fn script() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.11"
 
         [options]
@@ -2,6 +2,8 @@
 
 mod python_dir;
 
+mod python_platform;
+
 #[cfg(feature = "test-python")]
 mod python_find;
 
@@ -168,6 +168,218 @@ fn python_reinstall() {
     ");
 }
 
+#[test]
+#[cfg(unix)]
+fn python_install_verify() {
+    let context = uv_test::test_context_with_versions!(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_filtered_latest_python_versions()
+        .with_filtered_python_install_bin()
+        .with_filtered_python_names()
+        .with_managed_python_dirs()
+        .with_python_download_cache();
+
+    // Newly installed versions are smoke tested implicitly
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Installed Python 3.12.[LATEST] in [TIME]
+     + cpython-3.12.[LATEST]-[PLATFORM] (python3.12)
+    ");
+
+    // With `--verify`, existing installations are tested too
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12").arg("--verify"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Verified cpython-3.12.[LATEST]-[PLATFORM]
+    Python 3.12 is already installed
+    ");
+
+    uv_snapshot!(context.filters(), context.python_list().arg("3.12").arg("--only-installed").arg("--verify"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    cpython-3.12.[LATEST]-[PLATFORM]    managed/cpython-3.12-[PLATFORM]/[INSTALL-BIN]/[PYTHON]    passed
+    ");
+
+    // Break the installation by removing the `sqlite3` package from the standard library
+    let installation = context
+        .temp_dir
+        .child("managed")
+        .read_dir()
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .find(|path| {
+            path.file_name()
+                .is_some_and(|name| name.to_string_lossy().starts_with("cpython-3.12"))
+        })
+        .unwrap();
+    fs_err::remove_dir_all(installation.join("lib").join("python3.12").join("sqlite3")).unwrap();
+
+    context
+        .python_install()
+        .arg("3.12")
+        .arg("--verify")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Failed to verify cpython-3.12"));
+
+    context
+        .python_list()
+        .arg("3.12")
+        .arg("--only-installed")
+        .arg("--verify")
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("failed"));
+}
+
+#[test]
+#[cfg(unix)]
+fn python_install_verify_files() {
+    let context = uv_test::test_context_with_versions!(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_filtered_latest_python_versions()
+        .with_filtered_python_install_bin()
+        .with_filtered_python_names()
+        .with_managed_python_dirs()
+        .with_python_download_cache();
+
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Installed Python 3.12.[LATEST] in [TIME]
+     + cpython-3.12.[LATEST]-[PLATFORM] (python3.12)
+    ");
+
+    // The hashes of the installed files are recorded in the installation
+    let installation = context
+        .temp_dir
+        .child("managed")
+        .read_dir()
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .find(|path| {
+            path.file_name()
+                .is_some_and(|name| name.to_string_lossy().starts_with("cpython-3.12"))
+        })
+        .unwrap();
+    let hashes = fs_err::read_to_string(installation.join("SHA256SUMS")).unwrap();
+    assert!(hashes.contains("  lib/python3.12/json/decoder.py\n"));
+
+    // Truncate a file that the smoke test doesn't import
+    let decoder = installation
+        .join("lib")
+        .join("python3.12")
+        .join("json")
+        .join("decoder.py");
+    let contents = fs_err::read(&decoder).unwrap();
+    fs_err::write(&decoder, &contents[..contents.len() / 2]).unwrap();
+
+    context
+        .python_install()
+        .arg("3.12")
+        .arg("--verify")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Hash mismatch for"));
+
+    context
+        .python_list()
+        .arg("3.12")
+        .arg("--only-installed")
+        .arg("--verify")
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("failed"));
+
+    // Missing files are reported too
+    fs_err::remove_file(&decoder).unwrap();
+
+    context
+        .python_install()
+        .arg("3.12")
+        .arg("--verify")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is missing from"));
+}
+
+#[test]
+#[cfg(unix)]
+fn python_install_verify_archive() {
+    let context = uv_test::test_context_with_versions!(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_filtered_latest_python_versions()
+        .with_filtered_python_install_bin()
+        .with_filtered_python_names()
+        .with_managed_python_dirs();
+
+    // Retain the downloaded archive in a cache that is private to this test
+    let python_cache = context.temp_dir.child("python-cache");
+
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12").env(EnvVars::UV_PYTHON_CACHE_DIR, python_cache.path()), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Installed Python 3.12.[LATEST] in [TIME]
+     + cpython-3.12.[LATEST]-[PLATFORM] (python3.12)
+    ");
+
+    // The hash of the archive is recorded in the installation
+    let installation = context
+        .temp_dir
+        .child("managed")
+        .read_dir()
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .find(|path| {
+            path.file_name()
+                .is_some_and(|name| name.to_string_lossy().starts_with("cpython-3.12"))
+        })
+        .unwrap();
+    assert!(installation.join("SHA256").is_file());
+
+    // With `--verify`, the checksum of the retained archive is re-validated
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12").arg("--verify").env(EnvVars::UV_PYTHON_CACHE_DIR, python_cache.path()), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Verified cpython-3.12.[LATEST]-[PLATFORM]
+    Python 3.12 is already installed
+    ");
+
+    // Corrupt the retained archive
+    let archive = python_cache
+        .read_dir()
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .find(|path| path.is_file())
+        .unwrap();
+    let mut contents = fs_err::read(&archive).unwrap();
+    contents.extend_from_slice(b"corrupted");
+    fs_err::write(&archive, contents).unwrap();
+
+    context
+        .python_install()
+        .arg("3.12")
+        .arg("--verify")
+        .env(EnvVars::UV_PYTHON_CACHE_DIR, python_cache.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Hash mismatch for the archive"));
+
+    context
+        .python_list()
+        .arg("3.12")
+        .arg("--only-installed")
+        .arg("--verify")
+        .env(EnvVars::UV_PYTHON_CACHE_DIR, python_cache.path())
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("failed"));
+}
+
 #[test]
 fn python_reinstall_patch() {
     let context = uv_test::test_context_with_versions!(&[])
@@ -3514,6 +3726,52 @@ fn python_install_build_version() {
     ");
 }
 
+/// An interrupted install or removal shouldn't leave behind an installation that's treated as valid.
+#[cfg(unix)]
+#[test]
+fn python_install_incomplete() {
+    let context = uv_test::test_context_with_versions!(&[])
+        .with_filtered_python_keys()
+        .with_managed_python_dirs()
+        .with_python_download_cache()
+        .with_filtered_exe_suffix();
+
+    uv_snapshot!(context.filters(), context.python_install()
+        .arg("3.12")
+        .env(EnvVars::UV_PYTHON_CPYTHON_BUILD, "20240814"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Installed Python 3.12.5 in [TIME]
+     + cpython-3.12.5-[PLATFORM] (python3.12)
+    ");
+
+    // Simulate an interrupted extraction, and a partially removed installation.
+    let managed_dir = context.temp_dir.child("managed");
+    let orphan = managed_dir.child(".temp").child(".tmpOrphan");
+    orphan.create_dir_all().unwrap();
+    let cpython_dir = managed_dir.child(format!(
+        "cpython-3.12.5-{}",
+        platform_key_from_env().unwrap()
+    ));
+    fs_err::remove_dir_all(cpython_dir.child("bin")).unwrap();
+
+    // The incomplete installation should be replaced, rather than treated as installed.
+    uv_snapshot!(context.filters(), context.python_install()
+        .arg("3.12")
+        .env(EnvVars::UV_PYTHON_CPYTHON_BUILD, "20240814"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Installed Python 3.12.5 in [TIME]
+     + cpython-3.12.5-[PLATFORM] (python3.12)
+    ");
+
+    cpython_dir
+        .child("bin")
+        .child("python3.12")
+        .assert(predicate::path::exists());
+    orphan.assert(predicate::path::missing());
+}
+
 #[test]
 fn python_install_build_version_pypy() {
     use uv_python::managed::platform_key_from_env;
@@ -4003,3 +4261,31 @@ fn python_install_compile_bytecode_pypy() {
     Bytecode compiled [COUNT] files in [TIME]
     ");
 }
+
+#[cfg(unix)]
+#[test]
+fn python_install_build_from_source_missing_dependencies() {
+    let context = uv_test::test_context_with_versions!(&[])
+        .with_filtered_python_keys()
+        .with_managed_python_dirs()
+        .with_filtered_latest_python_versions();
+
+    // Without any build tools on the `PATH`, the build fails before downloading anything.
+    let empty_path = context.temp_dir.child("empty");
+    empty_path.create_dir_all().unwrap();
+    uv_snapshot!(context.filters(), context
+        .python_install()
+        .arg("3.12")
+        .arg("--build-from-source")
+        .arg("--offline")
+        .env(EnvVars::PATH, empty_path.as_os_str())
+        .env_remove(EnvVars::CC), @"
+    exit_code: 1 (failure)
+    ----- stderr -----
+    Building cpython-3.12.[LATEST]-[PLATFORM] from source; this may take a while
+    error: Failed to install cpython-3.12.[LATEST]-[PLATFORM]
+      Caused by: Missing system dependencies required to build Python from source:
+        - `make`
+        - a C compiler (`cc` or `gcc`, or set `CC`)
+    ");
+}
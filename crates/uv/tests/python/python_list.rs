@@ -469,6 +469,83 @@ fn python_list_managed_symlinks() {
     ");
 }
 
+/// Test that `--show-usage` reports the disk usage and last use of managed installations.
+#[test]
+#[cfg(feature = "test-python-managed")]
+fn python_list_show_usage() -> Result<()> {
+    use assert_cmd::assert::OutputAssertExt;
+
+    let context = uv_test::test_context_with_versions!(&[])
+        .with_filtered_python_keys()
+        .with_filtered_python_install_bin()
+        .with_filtered_python_names()
+        .with_managed_python_dirs()
+        .with_filtered_latest_python_versions();
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([
+            (r"\d+\.\dMiB", "[SIZE]"),
+            (r"last used \d{4}-\d{2}-\d{2}", "last used [DATE]"),
+        ])
+        .collect::<Vec<_>>();
+
+    context.python_install().arg("3.10").assert().success();
+
+    // The installation hasn't been used by a command yet.
+    uv_snapshot!(filters, context.python_list()
+        .arg("3.10")
+        .arg("--only-installed")
+        .arg("--show-usage"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    cpython-3.10.[LATEST]-[PLATFORM]    managed/cpython-3.10-[PLATFORM]/[INSTALL-BIN]/[PYTHON]    [SIZE]    last used unknown
+    ");
+
+    // Selecting the installation for a command records its use.
+    context.python_find().arg("3.10").assert().success();
+
+    uv_snapshot!(filters, context.python_list()
+        .arg("3.10")
+        .arg("--only-installed")
+        .arg("--show-usage"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    cpython-3.10.[LATEST]-[PLATFORM]    managed/cpython-3.10-[PLATFORM]/[INSTALL-BIN]/[PYTHON]    [SIZE]    last used [DATE]
+    ");
+
+    // The usage is included in the JSON output.
+    let output = context
+        .python_list()
+        .arg("3.10")
+        .arg("--only-installed")
+        .arg("--show-usage")
+        .arg("--output-format")
+        .arg("json")
+        .output()?;
+    let data: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    let installation = &data[0];
+    assert!(
+        installation["disk_usage"]
+            .as_u64()
+            .is_some_and(|size| size > 0)
+    );
+    assert!(installation["last_used"].is_string());
+
+    // Without `--show-usage`, the usage is omitted.
+    let output = context
+        .python_list()
+        .arg("3.10")
+        .arg("--only-installed")
+        .arg("--output-format")
+        .arg("json")
+        .output()?;
+    let data: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert!(data[0].get("disk_usage").is_none());
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn python_list_remote_python_downloads_json_url() -> Result<()> {
     let context = uv_test::test_context_with_versions!(&[]);
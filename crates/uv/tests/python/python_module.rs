@@ -431,6 +431,126 @@ fn find_uv_bin_error_message() {
     );
 }
 
+/// `python -m uv` should pass arguments through verbatim and propagate the exit code.
+#[cfg(unix)]
+#[test]
+fn python_module_argv() -> anyhow::Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    // Install in a virtual environment
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg(context.workspace_root.join("test/packages/fake-uv")), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + uv==0.1.0 (from file://[WORKSPACE]/test/packages/fake-uv)
+    "
+    );
+
+    // Replace the fake binary with one that echoes its arguments and exits with a non-zero code
+    fs_err::write(
+        context.venv.child("bin").child("uv"),
+        indoc! {r#"
+            #!/usr/bin/env sh
+            for arg in "$@"; do
+                echo "arg: [$arg]"
+            done
+            exit 3
+        "#},
+    )?;
+
+    uv_snapshot!(context.filters(), context.python_command()
+        .arg("-m")
+        .arg("uv")
+        .arg("pip")
+        .arg("install")
+        .arg("a b")
+        .arg("")
+        .arg("--flag=c d"), @"
+    exit_code: 3 (failure)
+    ----- stdout -----
+    arg: [pip]
+    arg: [install]
+    arg: [a b]
+    arg: []
+    arg: [--flag=c d]
+    "
+    );
+
+    Ok(())
+}
+
+/// `python -m uv` should explain that the binary is missing, rather than showing a traceback.
+#[test]
+fn python_module_missing_binary() {
+    let mut context = uv_test::test_context!("3.12")
+        .with_filtered_python_names()
+        .with_filtered_virtualenv_bin()
+        .with_filtered_exe_suffix()
+        .with_filter(user_scheme_bin_filter())
+        // Target installs always use "bin" on all platforms. On Windows,
+        // `with_filtered_virtualenv_bin` only filters "Scripts", not "bin"
+        .with_filter((r"[\\/]bin".to_string(), "/[BIN]".to_string()));
+
+    // Filter the base interpreter's scripts directory, which is searched by `find_uv_bin`
+    let python_info: Vec<_> = context.python_versions.clone();
+    for (version, executable) in &python_info {
+        let bin_dir = if cfg!(windows) {
+            executable
+                .canonicalize()
+                .unwrap()
+                .parent()
+                .unwrap()
+                .join("Scripts")
+        } else {
+            executable
+                .canonicalize()
+                .unwrap()
+                .parent()
+                .unwrap()
+                .to_path_buf()
+        };
+        context = context.with_filtered_path(&bin_dir, &format!("PYTHON-BIN-{version}"));
+    }
+
+    // Install in a virtual environment
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg(context.workspace_root.join("test/packages/fake-uv")), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + uv==0.1.0 (from file://[WORKSPACE]/test/packages/fake-uv)
+    "
+    );
+
+    // Remove the binary, as if the package were installed without it
+    fs_err::remove_file(if cfg!(unix) {
+        context.venv.child("bin").child("uv")
+    } else {
+        context.venv.child("Scripts").child("uv.exe")
+    })
+    .unwrap();
+
+    uv_snapshot!(context.filters(), context.python_command()
+        .arg("-m")
+        .arg("uv")
+        .arg("--version"), @"
+    exit_code: 1 (failure)
+    ----- stderr -----
+    error: Could not find the uv binary in any of the following locations:
+     - [VENV]/[BIN]
+     - [PYTHON-BIN-3.12]
+     - [SITE_PACKAGES]/[BIN]
+     - [USER_SCHEME]/[BIN]
+    hint: The uv binary is not included when the `uv` package is installed from a source distribution; reinstall it from a wheel, or see https://docs.astral.sh/uv/getting-started/installation/
+    "
+    );
+}
+
 #[cfg(feature = "test-python-eol")]
 #[test]
 fn find_uv_bin_py38() {
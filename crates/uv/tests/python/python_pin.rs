@@ -151,6 +151,34 @@ fn python_pin() {
     }
 }
 
+/// An implementation-qualified request should round-trip through the `.python-version` file.
+#[test]
+#[cfg(unix)]
+fn python_pin_implementation_version() {
+    let context = uv_test::test_context_with_versions!(&["3.12"]);
+
+    uv_snapshot!(context.filters(), context.python_pin().arg("PyPy3.10"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    Pinned `.python-version` to `pypy@3.10`
+
+    ----- stderr -----
+    warning: No interpreter found for PyPy 3.10 in managed installations or search path
+    ");
+
+    let python_version = context.read(PYTHON_VERSION_FILENAME);
+    assert_snapshot!(python_version, @"pypy@3.10");
+
+    // Reading the pin back should preserve the implementation
+    uv_snapshot!(context.filters(), context.python_pin(), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    pypy@3.10
+
+    ----- stderr -----
+    ");
+}
+
 #[test]
 fn python_pin_uses_python_downloads_json_url() {
     let context = uv_test::test_context_with_versions!(&[]).with_filtered_python_sources();
@@ -307,6 +335,55 @@ fn python_pin_global_use_local_if_available() -> Result<()> {
     Ok(())
 }
 
+/// `--show-resolution` lists every version file in order of precedence.
+#[test]
+fn python_pin_show_resolution() -> Result<()> {
+    let context = uv_test::test_context_with_versions!(&["3.11", "3.12"]);
+    context.user_config_dir.child("uv").create_dir_all()?;
+
+    uv_snapshot!(context.filters(), context.python_pin().arg("--show-resolution").env_remove(EnvVars::UV_PYTHON), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    No Python version files found
+    A `--python` request or `UV_PYTHON` would take precedence over any version file
+    ");
+
+    context
+        .user_config_dir
+        .child("uv")
+        .child(PYTHON_VERSION_FILENAME)
+        .write_str("3.12")?;
+    context
+        .temp_dir
+        .child(PYTHON_VERSION_FILENAME)
+        .write_str("3.11")?;
+    let child = context.temp_dir.child("child");
+    child.create_dir_all()?;
+    child
+        .child(PYTHON_VERSIONS_FILENAME)
+        .write_str("3.12\n3.11")?;
+
+    uv_snapshot!(context.filters(), context.python_pin().arg("--show-resolution").env_remove(EnvVars::UV_PYTHON).current_dir(&child), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    Python version files, in order of precedence:
+      `.python-versions`: 3.12, 3.11 (effective; nearest to the working directory)
+      `[TEMP_DIR]/.python-version`: 3.11 (shadowed by `.python-versions`)
+      `[UV_USER_CONFIG_DIR]/.python-version`: 3.12 (global pin; shadowed by `.python-versions`)
+    A `--python` request or `UV_PYTHON` would take precedence over any version file
+    ");
+
+    uv_snapshot!(context.filters(), context.python_pin().arg("--show-resolution").arg("--global").env(EnvVars::UV_PYTHON, "3.11"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    Python version files, in order of precedence:
+      `[UV_USER_CONFIG_DIR]/.python-version`: 3.12 (effective; global pin; no local version file found)
+    `UV_PYTHON` is set to `3.11`, which takes precedence over any version file
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn python_pin_global_creates_parent_dirs() {
     let context = uv_test::test_context_with_versions!(&["3.12"]);
@@ -0,0 +1,61 @@
+use uv_test::uv_snapshot;
+
+#[test]
+fn python_platform_list_alias() {
+    let context = uv_test::test_context_with_versions!(&[]);
+
+    // The alias expands to the same tags as the target triple.
+    uv_snapshot!(context.filters(), context.command()
+        .arg("python")
+        .arg("platform")
+        .arg("list")
+        .arg("windows-arm64")
+        .arg("--python-version")
+        .arg("3.12"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    aarch64-pc-windows-msvc (alias: windows-arm64)
+      cp312-cp312-win_arm64
+      cp312-abi3-win_arm64
+      cp312-none-win_arm64
+      cp311-abi3-win_arm64
+      cp310-abi3-win_arm64
+      cp39-abi3-win_arm64
+      cp38-abi3-win_arm64
+      cp37-abi3-win_arm64
+      cp36-abi3-win_arm64
+      cp35-abi3-win_arm64
+      cp34-abi3-win_arm64
+      cp33-abi3-win_arm64
+      cp32-abi3-win_arm64
+      py312-none-win_arm64
+      py3-none-win_arm64
+      py311-none-win_arm64
+      py310-none-win_arm64
+      py39-none-win_arm64
+      py38-none-win_arm64
+      py37-none-win_arm64
+      py36-none-win_arm64
+      py35-none-win_arm64
+      py34-none-win_arm64
+      py33-none-win_arm64
+      py32-none-win_arm64
+      py31-none-win_arm64
+      py30-none-win_arm64
+      cp312-none-any
+      py312-none-any
+      py3-none-any
+      py311-none-any
+      py310-none-any
+      py39-none-any
+      py38-none-any
+      py37-none-any
+      py36-none-any
+      py35-none-any
+      py34-none-any
+      py33-none-any
+      py32-none-any
+      py31-none-any
+      py30-none-any
+    ");
+}
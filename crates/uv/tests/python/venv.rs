@@ -712,6 +712,192 @@ fn virtual_dependency_group() -> Result<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "test-pypi")]
+fn create_venv_sync() -> Result<()> {
+    let context = uv_test::test_context_with_versions!(&["3.12"]);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    // Create the environment and sync the project into it in one step.
+    uv_snapshot!(context.filters(), context.venv().arg("--sync"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    Activate with: source .venv/[BIN]/activate
+    "
+    );
+
+    context.venv.assert(predicates::path::is_dir());
+    context
+        .temp_dir
+        .child("uv.lock")
+        .assert(predicates::path::is_file());
+
+    // `--sync` requires the project environment.
+    uv_snapshot!(context.filters(), context.venv().arg("--sync").arg("--no-project"), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    error: the argument '--sync' cannot be used with '--no-project'
+
+    Usage: uv venv --cache-dir [CACHE_DIR] --sync --exclude-newer <EXCLUDE_NEWER> [PATH]
+
+    For more information, try '--help'.
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn create_venv_from_lock() -> Result<()> {
+    let context = uv_test::test_context_with_versions!(&["3.12"]);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    context
+        .export()
+        .arg("-o")
+        .arg("pylock.toml")
+        .assert()
+        .success();
+
+    // Create the environment and install the locked packages into it in one step.
+    uv_snapshot!(context.filters(), context.venv()
+        .arg("--from-lock")
+        .arg("pylock.toml")
+        .arg("--python")
+        .arg("3.12")
+        .arg("--preview-features")
+        .arg("pylock"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    Activate with: source .venv/[BIN]/activate
+    "
+    );
+
+    context.assert_command("import iniconfig").success();
+
+    Ok(())
+}
+
+#[test]
+fn create_venv_from_lock_missing() {
+    let context = uv_test::test_context_with_versions!(&["3.12"]);
+
+    // A missing lockfile is reported before the environment is created.
+    uv_snapshot!(context.filters(), context.venv()
+        .arg("--from-lock")
+        .arg("pylock.toml")
+        .arg("--python")
+        .arg("3.12"), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    error: File not found: `pylock.toml`
+    "
+    );
+
+    context.venv.assert(predicates::path::missing());
+}
+
+#[test]
+fn create_venv_from_lock_failure() -> Result<()> {
+    let context = uv_test::test_context_with_versions!(&["3.12"]);
+
+    context.temp_dir.child("pylock.toml").write_str(
+        r#"
+        lock-version = "1.0"
+        created-by = "uv"
+
+        [[packages]]
+        name = "iniconfig"
+        version = "2.0.0"
+        wheels = [{ url = "https://example.com/iniconfig-2.0.0-py3-none-any.whl", hashes = { sha256 = "0000000000000000000000000000000000000000000000000000000000000000" } }]
+
+        [[packages]]
+        name = "iniconfig"
+        version = "2.1.0"
+        wheels = [{ url = "https://example.com/iniconfig-2.1.0-py3-none-any.whl", hashes = { sha256 = "1111111111111111111111111111111111111111111111111111111111111111" } }]
+        "#,
+    )?;
+
+    // If installation fails, the environment is removed.
+    uv_snapshot!(context.filters(), context.venv()
+        .arg("--from-lock")
+        .arg("pylock.toml")
+        .arg("--python")
+        .arg("3.12")
+        .arg("--preview-features")
+        .arg("pylock"), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Removed incomplete virtual environment at: .venv
+    error: Multiple active package entries found for `iniconfig`
+    "
+    );
+
+    context.venv.assert(predicates::path::missing());
+
+    // An existing environment reused via `--allow-existing` is left in place.
+    context
+        .venv()
+        .arg("--python")
+        .arg("3.12")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.venv()
+        .arg("--allow-existing")
+        .arg("--from-lock")
+        .arg("pylock.toml")
+        .arg("--python")
+        .arg("3.12")
+        .arg("--preview-features")
+        .arg("pylock"), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    warning: Leaving the existing virtual environment at `.venv` in place; it may be incomplete
+    error: Multiple active package entries found for `iniconfig`
+    "
+    );
+
+    context.venv.assert(predicates::path::is_dir());
+
+    Ok(())
+}
+
 #[test]
 fn create_venv_defaults_to_cwd() {
     let context = uv_test::test_context_with_versions!(&["3.12"]);
@@ -2391,3 +2577,162 @@ fn no_clear_conflicts_with_allow_existing() {
     "
     );
 }
+
+/// Toggle access to the system site packages in an existing virtual environment.
+#[test]
+fn venv_existing_system_site_packages() {
+    let context = uv_test::test_context_with_versions!(&["3.12"]);
+
+    context
+        .venv()
+        .arg(context.venv.as_os_str())
+        .arg("--python")
+        .arg("3.12")
+        .assert()
+        .success();
+    context.pip_install().arg("iniconfig").assert().success();
+
+    uv_snapshot!(context.filters(), context.venv()
+        .arg("--existing")
+        .arg("--system-site-packages"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Enabled access to the system site packages in: .venv
+    "
+    );
+
+    let pyvenv_cfg = context.venv.child("pyvenv.cfg");
+    pyvenv_cfg.assert(predicates::str::contains(
+        "include-system-site-packages = true",
+    ));
+
+    uv_snapshot!(context.filters(), context.venv()
+        .arg("--existing")
+        .arg("--no-system-site-packages"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Disabled access to the system site packages in: .venv
+    "
+    );
+
+    pyvenv_cfg.assert(predicates::str::contains(
+        "include-system-site-packages = false",
+    ));
+
+    // The environment is updated in-place, so installed packages are retained.
+    uv_snapshot!(context.filters(), context.pip_freeze(), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    iniconfig==2.0.0
+    "
+    );
+
+    // A setting to change is required.
+    uv_snapshot!(context.filters(), context.venv()
+        .arg("--existing"), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    error: `--existing` requires `--system-site-packages` or `--no-system-site-packages`
+    "
+    );
+}
+
+/// Move the project environment, recording its new location in the `pyproject.toml`.
+#[test]
+fn venv_move_project_environment() -> Result<()> {
+    let context = uv_test::test_context_with_versions!(&["3.12"]);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+    "#})?;
+
+    context.venv().assert().success();
+
+    uv_snapshot!(context.filters(), context.venv()
+        .arg("--move")
+        .arg("moved"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Set `tool.uv.project-environment` to `moved` in `pyproject.toml`
+    Moved virtual environment from `.venv` to: moved
+    Activate with: source moved/[BIN]/activate
+    "
+    );
+
+    context.venv.assert(predicates::path::missing());
+    context
+        .temp_dir
+        .child("moved")
+        .child("pyvenv.cfg")
+        .assert(predicates::path::is_file());
+
+    insta::assert_snapshot!(fs_err::read_to_string(&pyproject_toml)?, @r#"
+    [project]
+    name = "project"
+    version = "0.1.0"
+    requires-python = ">=3.12"
+    dependencies = ["iniconfig"]
+
+    [tool.uv]
+    project-environment = "moved"
+    "#);
+
+    // The activation script refers to the new location.
+    #[cfg(unix)]
+    context
+        .temp_dir
+        .child("moved")
+        .child("bin")
+        .child("activate")
+        .assert(predicates::str::contains(
+            context.temp_dir.child("moved").to_string_lossy().as_ref(),
+        ));
+
+    // Project commands use the moved environment, rather than creating a new one.
+    uv_snapshot!(context.filters(), context.sync(), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "
+    );
+
+    context.venv.assert(predicates::path::missing());
+
+    // Moving the environment back to the default location removes the setting.
+    uv_snapshot!(context.filters(), context.venv()
+        .arg("--move")
+        .arg(".venv"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Removed `tool.uv.project-environment` from `pyproject.toml`
+    Moved virtual environment from `moved` to: .venv
+    Activate with: source .venv/[BIN]/activate
+    "
+    );
+
+    insta::assert_snapshot!(fs_err::read_to_string(&pyproject_toml)?, @r#"
+    [project]
+    name = "project"
+    version = "0.1.0"
+    requires-python = ">=3.12"
+    dependencies = ["iniconfig"]
+    "#);
+
+    // The installed packages are retained.
+    uv_snapshot!(context.filters(), context.pip_freeze(), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    iniconfig==2.0.0
+    "
+    );
+
+    Ok(())
+}
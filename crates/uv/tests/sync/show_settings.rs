@@ -52,6 +52,7 @@ fn pip_compile_baseline() {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            strict_index_tracking: false,
             read_timeout: [TIME],
             connect_timeout: [TIME],
             retries: 3,
@@ -68,6 +69,7 @@ fn pip_compile_baseline() {
         },
         python_preference: Managed,
         python_downloads: Automatic,
+        windows_arm64_fallback: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -96,6 +98,11 @@ fn pip_compile_baseline() {
         required_environments: SupportedEnvironments(
             [],
         ),
+        unsafe_packages: [],
+        allow_unsafe: false,
+        pip_tools_header: false,
+        emit_inputs_hash: false,
+        check_fresh: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -161,6 +168,8 @@ fn pip_compile_baseline() {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
+            require_metadata: None,
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -250,6 +259,7 @@ fn publish_resolved_settings() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            strict_index_tracking: false,
             read_timeout: [TIME],
             connect_timeout: [TIME],
             retries: 3,
@@ -266,6 +276,7 @@ fn publish_resolved_settings() -> anyhow::Result<()> {
         },
         python_preference: Managed,
         python_downloads: Automatic,
+        windows_arm64_fallback: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -279,6 +290,7 @@ fn publish_resolved_settings() -> anyhow::Result<()> {
         files: [
             "dist/*",
         ],
+        from_build: None,
         username: Some(
             "__token__",
         ),
@@ -287,6 +299,7 @@ fn publish_resolved_settings() -> anyhow::Result<()> {
         ),
         index: None,
         dry_run: false,
+        check_metadata: false,
         no_attestations: false,
         direct: false,
         publish_url: DisplaySafeUrl {
@@ -374,10 +387,12 @@ fn publish_resolved_settings() -> anyhow::Result<()> {
                     format: Simple,
                     publish_url: None,
                     authenticate: Auto,
+                    auth_provider: None,
                     ignore_error_codes: None,
                     cache_control: None,
                     hash_algorithm: None,
                     exclude_newer: None,
+                    rewrite_files: None,
                 },
             ],
             flat_index: [],
@@ -416,6 +431,7 @@ fn pip_install_baseline() {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            strict_index_tracking: false,
             read_timeout: [TIME],
             connect_timeout: [TIME],
             retries: 3,
@@ -432,6 +448,7 @@ fn pip_install_baseline() {
         },
         python_preference: Managed,
         python_downloads: Automatic,
+        windows_arm64_fallback: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -451,8 +468,12 @@ fn pip_install_baseline() {
         constraints: [],
         overrides: [],
         excludes: [],
+        exclude_package: [],
+        exclude_unchecked: false,
         build_constraints: [],
         dry_run: Disabled,
+        format: Text,
+        explain: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         excludes_from_workspace: [],
@@ -523,6 +544,8 @@ fn pip_install_baseline() {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
+            require_metadata: None,
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -597,6 +620,7 @@ fn lock_baseline() {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            strict_index_tracking: false,
             read_timeout: [TIME],
             connect_timeout: [TIME],
             retries: 3,
@@ -613,6 +637,7 @@ fn lock_baseline() {
         },
         python_preference: Managed,
         python_downloads: Automatic,
+        windows_arm64_fallback: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -626,7 +651,17 @@ fn lock_baseline() {
         lock_check: Disabled,
         frozen: None,
         dry_run: Disabled,
-        script: None,
+        pin_index_metadata: false,
+        prefer_installed: None,
+        resolve_conflicts: false,
+        report_metadata_versions: false,
+        check_config: false,
+        show_provenance: None,
+        suggest: false,
+        script: [],
+        shared_lock: None,
+        schema: false,
+        validate: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
@@ -677,7 +712,13 @@ fn lock_baseline() {
             extra_build_variables: ExtraBuildVariables(
                 {},
             ),
+            build_env_passthrough: BuildEnvPassthrough {
+                global: None,
+                package: {},
+            },
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
+            require_metadata: None,
             resolution: Highest,
             sources: None,
             torch_backend: None,
@@ -717,6 +758,7 @@ fn version_baseline() {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            strict_index_tracking: false,
             read_timeout: [TIME],
             connect_timeout: [TIME],
             retries: 3,
@@ -733,6 +775,7 @@ fn version_baseline() {
         },
         python_preference: Managed,
         python_downloads: Automatic,
+        windows_arm64_fallback: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -748,6 +791,7 @@ fn version_baseline() {
         short: false,
         output_format: Text,
         dry_run: false,
+        show_diff: Disabled,
         lock_check: Disabled,
         frozen: None,
         active: None,
@@ -804,7 +848,13 @@ fn version_baseline() {
                 extra_build_variables: ExtraBuildVariables(
                     {},
                 ),
+                build_env_passthrough: BuildEnvPassthrough {
+                    global: None,
+                    package: {},
+                },
                 prerelease: IfNecessaryOrExplicit,
+                prerelease_packages: [],
+                require_metadata: None,
                 resolution: Highest,
                 sources: None,
                 torch_backend: None,
@@ -852,6 +902,7 @@ fn tool_install_baseline() {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            strict_index_tracking: false,
             read_timeout: [TIME],
             connect_timeout: [TIME],
             retries: 3,
@@ -868,6 +919,7 @@ fn tool_install_baseline() {
         },
         python_preference: Managed,
         python_downloads: Automatic,
+        windows_arm64_fallback: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -910,12 +962,15 @@ fn tool_install_baseline() {
             resolution: None,
             prerelease: None,
             fork_strategy: None,
+            prerelease_packages: None,
             dependency_metadata: None,
             config_settings: None,
             config_settings_package: None,
             build_isolation: None,
             extra_build_dependencies: None,
             extra_build_variables: None,
+            build_env_passthrough: None,
+            build_env_passthrough_package: None,
             exclude_newer: None,
             exclude_newer_package: None,
             link_mode: Some(
@@ -969,7 +1024,13 @@ fn tool_install_baseline() {
                 extra_build_variables: ExtraBuildVariables(
                     {},
                 ),
+                build_env_passthrough: BuildEnvPassthrough {
+                    global: None,
+                    package: {},
+                },
                 prerelease: IfNecessaryOrExplicit,
+                prerelease_packages: [],
+                require_metadata: None,
                 resolution: Highest,
                 sources: None,
                 torch_backend: None,
@@ -984,6 +1045,7 @@ fn tool_install_baseline() {
             reinstall: None,
         },
         force: false,
+        keep_options: false,
         editable: false,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
@@ -1065,10 +1127,12 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
     +                    format: Simple,
     +                    publish_url: None,
     +                    authenticate: Auto,
+    +                    auth_provider: None,
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    hash_algorithm: None,
     +                    exclude_newer: None,
+    +                    rewrite_files: None,
     +                },
     +            ],
                  flat_index: [],
@@ -1081,8 +1145,9 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
     -        resolution: Highest,
     +        resolution: LowestDirect,
              prerelease: IfNecessaryOrExplicit,
+             prerelease_packages: [],
+             require_metadata: None,
              fork_strategy: RequiresPython,
-             dependency_metadata: DependencyMetadata(
     ...
              no_annotate: false,
              no_header: false,
@@ -1109,8 +1174,9 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
     -        resolution: LowestDirect,
     +        resolution: Highest,
              prerelease: IfNecessaryOrExplicit,
+             prerelease_packages: [],
+             require_metadata: None,
              fork_strategy: RequiresPython,
-             dependency_metadata: DependencyMetadata(
     ...
     "
     );
@@ -1219,10 +1285,12 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
     +                    format: Simple,
     +                    publish_url: None,
     +                    authenticate: Auto,
+    +                    auth_provider: None,
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    hash_algorithm: None,
     +                    exclude_newer: None,
+    +                    rewrite_files: None,
     +                },
     +            ],
                  flat_index: [],
@@ -1235,8 +1303,9 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
     -        resolution: Highest,
     +        resolution: LowestDirect,
              prerelease: IfNecessaryOrExplicit,
+             prerelease_packages: [],
+             require_metadata: None,
              fork_strategy: RequiresPython,
-             dependency_metadata: DependencyMetadata(
     ...
              no_annotate: false,
              no_header: false,
@@ -1287,6 +1356,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                          format: Simple,
                          publish_url: None,
                          authenticate: Auto,
+                         auth_provider: None,
     ...
                  {},
              ),
@@ -1376,10 +1446,12 @@ fn resolve_index_url() -> anyhow::Result<()> {
     +                    format: Simple,
     +                    publish_url: None,
     +                    authenticate: Auto,
+    +                    auth_provider: None,
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    hash_algorithm: None,
     +                    exclude_newer: None,
+    +                    rewrite_files: None,
     +                },
     +                Index {
     +                    name: None,
@@ -1412,10 +1484,12 @@ fn resolve_index_url() -> anyhow::Result<()> {
     +                    format: Simple,
     +                    publish_url: None,
     +                    authenticate: Auto,
+    +                    auth_provider: None,
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    hash_algorithm: None,
     +                    exclude_newer: None,
+    +                    rewrite_files: None,
     +                },
     +            ],
                  flat_index: [],
@@ -1468,10 +1542,12 @@ fn resolve_index_url() -> anyhow::Result<()> {
     +                    format: Simple,
     +                    publish_url: None,
     +                    authenticate: Auto,
+    +                    auth_provider: None,
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    hash_algorithm: None,
     +                    exclude_newer: None,
+    +                    rewrite_files: None,
     +                },
     +                Index {
     +                    name: None,
@@ -1557,10 +1633,12 @@ fn resolve_find_links() -> anyhow::Result<()> {
     +                    format: Flat,
     +                    publish_url: None,
     +                    authenticate: Auto,
+    +                    auth_provider: None,
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    hash_algorithm: None,
     +                    exclude_newer: None,
+    +                    rewrite_files: None,
     +                },
     +            ],
     +            no_index: true,
@@ -1614,8 +1692,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
     -        resolution: Highest,
     +        resolution: LowestDirect,
              prerelease: IfNecessaryOrExplicit,
+             prerelease_packages: [],
+             require_metadata: None,
              fork_strategy: RequiresPython,
-             dependency_metadata: DependencyMetadata(
     ...
     "
     );
@@ -1679,10 +1758,12 @@ fn resolve_top_level() -> anyhow::Result<()> {
     +                    format: Simple,
     +                    publish_url: None,
     +                    authenticate: Auto,
+    +                    auth_provider: None,
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    hash_algorithm: None,
     +                    exclude_newer: None,
+    +                    rewrite_files: None,
     +                },
     +                Index {
     +                    name: None,
@@ -1715,10 +1796,12 @@ fn resolve_top_level() -> anyhow::Result<()> {
     +                    format: Simple,
     +                    publish_url: None,
     +                    authenticate: Auto,
+    +                    auth_provider: None,
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    hash_algorithm: None,
     +                    exclude_newer: None,
+    +                    rewrite_files: None,
     +                },
     +            ],
                  flat_index: [],
@@ -1741,8 +1824,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
     -        resolution: Highest,
     +        resolution: LowestDirect,
              prerelease: IfNecessaryOrExplicit,
+             prerelease_packages: [],
+             require_metadata: None,
              fork_strategy: RequiresPython,
-             dependency_metadata: DependencyMetadata(
     ...
     "
     );
@@ -1790,8 +1874,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
     -        resolution: Highest,
     +        resolution: LowestDirect,
              prerelease: IfNecessaryOrExplicit,
+             prerelease_packages: [],
+             require_metadata: None,
              fork_strategy: RequiresPython,
-             dependency_metadata: DependencyMetadata(
     ...
     "
     );
@@ -1896,8 +1981,9 @@ fn resolve_system_configuration_can_be_disabled() -> anyhow::Result<()> {
     -        resolution: Highest,
     +        resolution: LowestDirect,
              prerelease: IfNecessaryOrExplicit,
+             prerelease_packages: [],
+             require_metadata: None,
              fork_strategy: RequiresPython,
-             dependency_metadata: DependencyMetadata(
     ...
     ");
 
@@ -1963,11 +2049,12 @@ fn resolve_tool() -> anyhow::Result<()> {
     +        ),
              prerelease: None,
              fork_strategy: None,
-             dependency_metadata: None,
+             prerelease_packages: None,
     ...
-                     {},
                  ),
                  prerelease: IfNecessaryOrExplicit,
+                 prerelease_packages: [],
+                 require_metadata: None,
     -            resolution: Highest,
     +            resolution: LowestDirect,
                  sources: None,
@@ -2030,8 +2117,9 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
     -        resolution: Highest,
     +        resolution: LowestDirect,
              prerelease: IfNecessaryOrExplicit,
+             prerelease_packages: [],
+             require_metadata: None,
              fork_strategy: RequiresPython,
-             dependency_metadata: DependencyMetadata(
     ...
     "
     );
@@ -2128,10 +2216,12 @@ fn resolve_both() -> anyhow::Result<()> {
     +                    format: Simple,
     +                    publish_url: None,
     +                    authenticate: Auto,
+    +                    auth_provider: None,
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    hash_algorithm: None,
     +                    exclude_newer: None,
+    +                    rewrite_files: None,
     +                },
     +            ],
                  flat_index: [],
@@ -2144,8 +2234,9 @@ fn resolve_both() -> anyhow::Result<()> {
     -        resolution: Highest,
     +        resolution: LowestDirect,
              prerelease: IfNecessaryOrExplicit,
+             prerelease_packages: [],
+             require_metadata: None,
              fork_strategy: RequiresPython,
-             dependency_metadata: DependencyMetadata(
     ...
              no_annotate: false,
              no_header: false,
@@ -2262,10 +2353,12 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
     +                    format: Simple,
     +                    publish_url: None,
     +                    authenticate: Auto,
+    +                    auth_provider: None,
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    hash_algorithm: None,
     +                    exclude_newer: None,
+    +                    rewrite_files: None,
     +                },
     +            ],
                  flat_index: [],
@@ -2278,8 +2371,9 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
     -        resolution: Highest,
     +        resolution: LowestDirect,
              prerelease: IfNecessaryOrExplicit,
+             prerelease_packages: [],
+             require_metadata: None,
              fork_strategy: RequiresPython,
-             dependency_metadata: DependencyMetadata(
     ...
              no_annotate: false,
              no_header: false,
@@ -2606,10 +2700,12 @@ fn resolve_config_file() -> anyhow::Result<()> {
     +                    format: Simple,
     +                    publish_url: None,
     +                    authenticate: Auto,
+    +                    auth_provider: None,
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    hash_algorithm: None,
     +                    exclude_newer: None,
+    +                    rewrite_files: None,
     +                },
     +            ],
                  flat_index: [],
@@ -2622,8 +2718,9 @@ fn resolve_config_file() -> anyhow::Result<()> {
     -        resolution: Highest,
     +        resolution: LowestDirect,
              prerelease: IfNecessaryOrExplicit,
+             prerelease_packages: [],
+             require_metadata: None,
              fork_strategy: RequiresPython,
-             dependency_metadata: DependencyMetadata(
     ...
              no_annotate: false,
              no_header: false,
@@ -2662,7 +2759,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
           |
         1 | [project]
           |  ^^^^^^^
-        unknown field `project`, expected one of `required-version`, `system-certs`, `native-tls`, `offline`, `no-cache`, `cache-dir`, `preview`, `preview-features`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `http-proxy`, `https-proxy`, `no-proxy`, `allow-insecure-host`, `resolution`, `prerelease`, `fork-strategy`, `dependency-metadata`, `config-settings`, `config-settings-package`, `no-build-isolation`, `no-build-isolation-package`, `extra-build-dependencies`, `extra-build-variables`, `exclude-newer`, `exclude-newer-package`, `link-mode`, `compile-bytecode`, `no-sources`, `no-sources-package`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `torch-backend`, `python-install-mirror`, `pypy-install-mirror`, `python-downloads-json-url`, `publish-url`, `trusted-publishing`, `check-url`, `add-bounds`, `audit`, `pip`, `cache-keys`, `override-dependencies`, `exclude-dependencies`, `constraint-dependencies`, `build-constraint-dependencies`, `environments`, `required-environments`, `conflicts`, `workspace`, `sources`, `managed`, `package`, `default-groups`, `dependency-groups`, `dev-dependencies`, `build-backend`
+        unknown field `project`, expected one of `required-version`, `system-certs`, `native-tls`, `offline`, `no-cache`, `cache-dir`, `preview`, `preview-features`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `http-proxy`, `https-proxy`, `no-proxy`, `allow-insecure-host`, `resolution`, `prerelease`, `fork-strategy`, `dependency-metadata`, `config-settings`, `config-settings-package`, `no-build-isolation`, `no-build-isolation-package`, `extra-build-dependencies`, `extra-build-variables`, `build-env-passthrough`, `build-env-passthrough-package`, `exclude-newer`, `exclude-newer-package`, `link-mode`, `compile-bytecode`, `no-sources`, `no-sources-package`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `torch-backend`, `python-install-mirror`, `pypy-install-mirror`, `python-downloads-json-url`, `publish-url`, `trusted-publishing`, `check-url`, `add-bounds`, `audit`, `pip`, `cache-keys`, `override-dependencies`, `exclude-dependencies`, `constraint-dependencies`, `build-constraint-dependencies`, `environments`, `required-environments`, `conflicts`, `workspace`, `sources`, `managed`, `package`, `default-groups`, `dependency-groups`, `dev-dependencies`, `build-backend`
     "
     );
 
@@ -2751,8 +2848,9 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
     -        resolution: Highest,
     +        resolution: LowestDirect,
              prerelease: IfNecessaryOrExplicit,
+             prerelease_packages: [],
+             require_metadata: None,
              fork_strategy: RequiresPython,
-             dependency_metadata: DependencyMetadata(
     ...
     "
     );
@@ -2899,10 +2997,12 @@ fn index_priority() -> anyhow::Result<()> {
     +                    format: Simple,
     +                    publish_url: None,
     +                    authenticate: Auto,
+    +                    auth_provider: None,
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    hash_algorithm: None,
     +                    exclude_newer: None,
+    +                    rewrite_files: None,
     +                },
     +                Index {
     +                    name: None,
@@ -2937,10 +3037,12 @@ fn index_priority() -> anyhow::Result<()> {
     +                    format: Simple,
     +                    publish_url: None,
     +                    authenticate: Auto,
+    +                    auth_provider: None,
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    hash_algorithm: None,
     +                    exclude_newer: None,
+    +                    rewrite_files: None,
     +                },
     +            ],
                  flat_index: [],
@@ -3226,6 +3328,9 @@ fn preview_features() {
     +            NoDistutilsPatch,
     +            IndexHashAlgorithm,
     +            LockfileFormatCheck,
+    +            WorkspaceMembers,
+    +            LockDependencyCache,
+    +            ExportPruneRootCheck,
     +        ],
          },
          python_preference: Managed,
@@ -3025,7 +3025,7 @@ fn sync_relative_wheel() -> Result<()> {
             assert_snapshot!(
                 lock, @r#"
             version = 1
-            revision = 3
+            revision = 4
             requires-python = ">=3.12"
 
             [options]
@@ -3649,6 +3649,90 @@ fn sync_dev_group() -> Result<()> {
     Ok(())
 }
 
+/// Check the markers of dependency group entries with `--check-group-markers`.
+#[cfg(unix)]
+#[test]
+fn sync_check_group_markers() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.optional-dependencies]
+        cli = []
+
+        [dependency-groups]
+        dev = [
+            "iniconfig; sys_platform == 'linx'",
+            "typing-extensions; python_version < '3.10'",
+            "project[cli,gui]",
+            "sniffio; sys_platform == 'linux'",
+        ]
+
+        [tool.uv]
+        environments = ["sys_platform == 'linux'", "sys_platform == 'darwin'"]
+        "#,
+    )?;
+
+    // The findings are reported as warnings when locking.
+    uv_snapshot!(context.filters(), context.lock(), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    warning: Entry `dependency-groups.dev[0]` in `pyproject.toml` compares `sys_platform` to an unknown value (`\"linx\"`), so it may never be installed
+    warning: Entry `dependency-groups.dev[0]` in `pyproject.toml` has markers (`sys_platform == 'linx'`) that are never satisfied given the `requires-python` and `tool.uv.environments` of the workspace
+    warning: Entry `dependency-groups.dev[1]` in `pyproject.toml` has markers (`python_full_version < '3.10'`) that are never satisfied given the `requires-python` and `tool.uv.environments` of the workspace
+    warning: Entry `dependency-groups.dev[2]` in `pyproject.toml` requests the extra `gui`, but `project` does not declare it in `project.optional-dependencies`
+    Resolved 4 packages in [TIME]
+    ");
+
+    // With `--check-group-markers`, the findings are errors.
+    uv_snapshot!(context.filters(), context.sync().arg("--check-group-markers"), @"
+    exit_code: 1 (failure)
+    ----- stderr -----
+    error: Entry `dependency-groups.dev[0]` in `pyproject.toml` compares `sys_platform` to an unknown value (`\"linx\"`), so it may never be installed
+    error: Entry `dependency-groups.dev[0]` in `pyproject.toml` has markers (`sys_platform == 'linx'`) that are never satisfied given the `requires-python` and `tool.uv.environments` of the workspace
+    error: Entry `dependency-groups.dev[1]` in `pyproject.toml` has markers (`python_full_version < '3.10'`) that are never satisfied given the `requires-python` and `tool.uv.environments` of the workspace
+    error: Entry `dependency-groups.dev[2]` in `pyproject.toml` requests the extra `gui`, but `project` does not declare it in `project.optional-dependencies`
+    ");
+
+    // Once the entries are fixed, the environment is synced as usual.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.optional-dependencies]
+        cli = []
+
+        [dependency-groups]
+        dev = [
+            "iniconfig; sys_platform == 'linux'",
+            "project[cli]",
+        ]
+
+        [tool.uv]
+        environments = ["sys_platform == 'linux'", "sys_platform == 'darwin'"]
+        "#,
+    )?;
+
+    context
+        .sync()
+        .arg("--check-group-markers")
+        .assert()
+        .success();
+
+    Ok(())
+}
+
 #[test]
 fn sync_non_existent_group() -> Result<()> {
     let context = uv_test::test_context!("3.12");
@@ -4602,7 +4686,7 @@ fn sync_group_non_project_member() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -4713,7 +4797,7 @@ fn sync_group_self() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -5690,6 +5774,17 @@ fn no_install_local() -> Result<()> {
      + sniffio==1.3.1
     ");
 
+    // A subsequent sync without the flag should build and install exactly the skipped packages.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + local==0.1.0 (from file://[TEMP_DIR]/local)
+     + local-editable==0.1.0 (from file://[TEMP_DIR]/local-editable)
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    ");
+
     Ok(())
 }
 
@@ -5778,6 +5873,78 @@ fn no_install_package() -> Result<()> {
     Ok(())
 }
 
+/// Omit a package provided by the environment when `--exclude-package` is provided.
+#[test]
+fn exclude_package() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+        "#,
+    )?;
+    context
+        .temp_dir
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .touch()?;
+
+    // Generate a lockfile.
+    context.lock().assert().success();
+
+    // The environment doesn't provide `idna`, so the sync should fail.
+    uv_snapshot!(context.filters(), context.sync().arg("--exclude-package").arg("idna"), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    warning: Excluding `idna` from the installation; `anyio` expects it to be provided by the environment
+    error: `idna` was excluded from the installation, but the environment doesn't provide a version that satisfies `idna==3.6`
+
+    hint: Use `--exclude-unchecked` to skip this check
+    ");
+
+    // Once the environment provides the locked version, everything else should be installed.
+    context.pip_install().arg("idna==3.6").assert().success();
+
+    uv_snapshot!(context.filters(), context.sync().arg("--exclude-package").arg("idna"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    warning: Excluding `idna` from the installation; `anyio` expects it to be provided by the environment
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==3.7.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+     + sniffio==1.3.1
+    ");
+
+    // The excluded package should be left in place, despite the exact sync.
+    uv_snapshot!(context.filters(), context.sync().arg("--exclude-package").arg("idna"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    warning: Excluding `idna` from the installation; `anyio` expects it to be provided by the environment
+    Audited 3 packages in [TIME]
+    ");
+
+    context.assert_command("import idna").success();
+
+    // The lockfile should be unchanged.
+    context.lock().arg("--locked").assert().success();
+
+    Ok(())
+}
+
 /// Ensure that `--no-build` isn't enforced for projects that aren't installed in the first place.
 #[test]
 fn no_install_project_no_build() -> Result<()> {
@@ -6297,7 +6464,7 @@ fn convert_to_virtual() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -6354,7 +6521,7 @@ fn convert_to_virtual() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -6420,7 +6587,7 @@ fn convert_to_package() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -6488,7 +6655,7 @@ fn convert_to_package() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -6843,6 +7010,74 @@ fn sync_active_project_environment_with_relative_managed_python_dir() -> Result<
     Ok(())
 }
 
+#[test]
+fn sync_active_conda_environment() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    // Create a mock conda environment.
+    context.venv().arg("conda-env").assert().success();
+    let conda_env = context.temp_dir.child("conda-env");
+    conda_env.child("conda-meta").create_dir_all()?;
+
+    // Using `--active` should refuse to sync into the conda environment.
+    uv_snapshot!(context.filters(), context.sync()
+        .env(EnvVars::CONDA_PREFIX, conda_env.as_os_str())
+        .arg("--active"), @"
+    exit_code: 2 (error)
+    ----- stderr -----
+    error: The active conda environment `conda-env` at `conda-env` is treated as a system environment and will not be synced; use `--allow-conda` to sync into it, or omit `--active` to use the project environment
+    ");
+
+    // Unless `--allow-conda` is provided.
+    uv_snapshot!(context.filters(), context.sync()
+        .env(EnvVars::CONDA_PREFIX, conda_env.as_os_str())
+        .arg("--active")
+        .arg("--allow-conda"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    context
+        .temp_dir
+        .child(".venv")
+        .assert(predicate::path::missing());
+
+    // Otherwise, the conda environment should be ignored in favor of the project environment.
+    uv_snapshot!(context.filters(), context.sync()
+        .env(EnvVars::CONDA_PREFIX, conda_env.as_os_str()), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    warning: The active conda environment `conda-env` will be ignored in favor of the project environment; use `--active --allow-conda` to target the conda environment instead
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Resolved 2 packages in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    context
+        .temp_dir
+        .child(".venv")
+        .assert(predicate::path::is_dir());
+
+    Ok(())
+}
+
 #[test]
 fn sync_active_script_environment() -> Result<()> {
     let context = uv_test::test_context_with_versions!(&["3.11", "3.12"])
@@ -8216,110 +8451,319 @@ fn sync_no_editable() -> Result<()> {
     Ok(())
 }
 
+/// Reinstall an editable when its entry points change, even if its cache keys are unchanged.
 #[test]
-/// Check warning message for <https://github.com/astral-sh/uv/issues/6998>
-/// if no `build-system` section is defined.
-fn sync_scripts_without_build_system() -> Result<()> {
+fn sync_editable_entry_points_changed() -> Result<()> {
     let context = uv_test::test_context!("3.12");
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
     pyproject_toml.write_str(
         r#"
         [project]
-        name = "foo"
+        name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = []
 
-        [project.scripts]
-        entry = "foo:custom_entry"
-        "#,
-    )?;
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
 
-    let test_script = context.temp_dir.child("src/__init__.py");
-    test_script.write_str(
-        r#"
-        def custom_entry():
-            print!("Hello")
-       "#,
+        [tool.uv]
+        cache-keys = [{ file = "src/project/__init__.py" }]
+        "#,
     )?;
+    context
+        .temp_dir
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .write_str("def main(): pass")?;
 
     uv_snapshot!(context.filters(), context.sync(), @"
     exit_code: 0 (success)
     ----- stderr -----
     Resolved 1 package in [TIME]
-    warning: Skipping installation of entry points (`project.scripts`) for package `foo` because this project is not packaged; to install entry points, set `tool.uv.package = true` or define a `build-system`
-    Checked in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + project==0.1.0 (from file://[TEMP_DIR]/)
     ");
 
-    Ok(())
-}
-
-#[test]
-/// Check warning message for <https://github.com/astral-sh/uv/issues/6998>
-/// if the project is marked as `package = false`.
-fn sync_scripts_project_not_packaged() -> Result<()> {
-    let context = uv_test::test_context!("3.12");
-
-    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    // Add a script, which isn't covered by the cache keys.
     pyproject_toml.write_str(
         r#"
         [project]
-        name = "foo"
+        name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = []
 
         [project.scripts]
-        entry = "foo:custom_entry"
+        project = "project:main"
 
         [build-system]
         requires = ["hatchling"]
         build-backend = "hatchling.build"
 
         [tool.uv]
-        package = false
+        cache-keys = [{ file = "src/project/__init__.py" }]
         "#,
     )?;
 
-    let test_script = context.temp_dir.child("src/__init__.py");
-    test_script.write_str(
-        r#"
-        def custom_entry():
-            print!("Hello")
-       "#,
-    )?;
-
+    // The project should be reinstalled, since its entry points changed.
     uv_snapshot!(context.filters(), context.sync(), @"
     exit_code: 0 (success)
     ----- stderr -----
     Resolved 1 package in [TIME]
-    warning: Skipping installation of entry points (`project.scripts`) for package `foo` because this project is not packaged; to install entry points, set `tool.uv.package = true` or define a `build-system`
-    Checked in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ project==0.1.0 (from file://[TEMP_DIR]/)
     ");
 
-    Ok(())
-}
-
-#[test]
-/// Check warning message for <https://github.com/astral-sh/uv/issues/18388>
-/// if a workspace member has scripts but no `build-system`.
-fn sync_scripts_workspace_member_not_packaged() -> Result<()> {
-    let context = uv_test::test_context!("3.12");
+    // Re-syncing should be a no-op.
+    uv_snapshot!(context.filters(), context.sync(), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Checked 1 package in [TIME]
+    ");
 
-    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    // Remove the script, but disable the check.
     pyproject_toml.write_str(
         r#"
         [project]
-        name = "root"
+        name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = []
-
-        [tool.uv.workspace]
-        members = ["member"]
-        "#,
-    )?;
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+
+        [tool.uv]
+        cache-keys = [{ file = "src/project/__init__.py" }]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync().env(EnvVars::UV_SKIP_EDITABLE_METADATA_CHECK, "1"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Checked 1 package in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// Notify the user, once, that installed scripts aren't on the `PATH`.
+#[test]
+#[cfg(unix)]
+fn sync_scripts_not_on_path() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [project.scripts]
+        project = "project:main"
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+        "#,
+    )?;
+    context
+        .temp_dir
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .write_str("def main(): pass")?;
+
+    uv_snapshot!(context.filters(), context.sync().env_remove(EnvVars::UV_NO_SCRIPTS_PATH_NOTICE), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    hint: `.venv/bin` is not on your PATH. To use installed scripts, activate the environment with `source .venv/bin/activate` or use `uv run project`.
+    ");
+
+    // The notice is only shown once per environment.
+    uv_snapshot!(context.filters(), context.sync().arg("--reinstall-package").arg("project").env_remove(EnvVars::UV_NO_SCRIPTS_PATH_NOTICE), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ project==0.1.0 (from file://[TEMP_DIR]/)
+    ");
+
+    Ok(())
+}
+
+/// Scripts installed into an environment with a path that exceeds the shebang length limit should
+/// use a `/bin/sh` trampoline, rather than a shebang that would be truncated.
+#[test]
+#[cfg(unix)]
+fn sync_scripts_deep_environment() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [project.scripts]
+        project = "project:main"
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+        "#,
+    )?;
+    context
+        .temp_dir
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .write_str("def main(): print('Hello from project')")?;
+
+    let environment = context
+        .temp_dir
+        .child("a".repeat(64))
+        .child("b".repeat(64))
+        .child(".venv");
+
+    context
+        .sync()
+        .arg("-v")
+        .env(EnvVars::UV_PROJECT_ENVIRONMENT, environment.path())
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("using a `/bin/sh` trampoline"));
+
+    uv_snapshot!(context.filters(), Command::new(venv_bin_path(&environment).join("project")), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    Hello from project
+    ");
+
+    Ok(())
+}
+
+#[test]
+/// Check warning message for <https://github.com/astral-sh/uv/issues/6998>
+/// if no `build-system` section is defined.
+fn sync_scripts_without_build_system() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.scripts]
+        entry = "foo:custom_entry"
+        "#,
+    )?;
+
+    let test_script = context.temp_dir.child("src/__init__.py");
+    test_script.write_str(
+        r#"
+        def custom_entry():
+            print!("Hello")
+       "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync(), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    warning: Skipping installation of entry points (`project.scripts`) for package `foo` because this project is not packaged; to install entry points, set `tool.uv.package = true` or define a `build-system`
+    Checked in [TIME]
+    ");
+
+    Ok(())
+}
+
+#[test]
+/// Check warning message for <https://github.com/astral-sh/uv/issues/6998>
+/// if the project is marked as `package = false`.
+fn sync_scripts_project_not_packaged() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.scripts]
+        entry = "foo:custom_entry"
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+
+        [tool.uv]
+        package = false
+        "#,
+    )?;
+
+    let test_script = context.temp_dir.child("src/__init__.py");
+    test_script.write_str(
+        r#"
+        def custom_entry():
+            print!("Hello")
+       "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync(), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    warning: Skipping installation of entry points (`project.scripts`) for package `foo` because this project is not packaged; to install entry points, set `tool.uv.package = true` or define a `build-system`
+    Checked in [TIME]
+    ");
+
+    Ok(())
+}
+
+#[test]
+/// Check warning message for <https://github.com/astral-sh/uv/issues/18388>
+/// if a workspace member has scripts but no `build-system`.
+fn sync_scripts_workspace_member_not_packaged() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "root"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv.workspace]
+        members = ["member"]
+        "#,
+    )?;
 
     let member = context.temp_dir.child("member");
     fs_err::create_dir_all(&member)?;
@@ -8444,7 +8888,7 @@ fn sync_dynamic_extra() -> Result<()> {
             assert_snapshot!(
                 lock, @r#"
             version = 1
-            revision = 3
+            revision = 4
             requires-python = ">=3.12"
 
             [options]
@@ -9856,7 +10300,7 @@ fn sync_stale_egg_info() -> Result<()> {
             assert_snapshot!(
                 lock, @r#"
             version = 1
-            revision = 3
+            revision = 4
             requires-python = ">=3.13"
 
             [options]
@@ -9957,7 +10401,7 @@ fn sync_git_repeated_member_static_metadata() -> Result<()> {
             assert_snapshot!(
                 lock, @r#"
             version = 1
-            revision = 3
+            revision = 4
             requires-python = ">=3.13"
 
             [options]
@@ -10045,7 +10489,7 @@ fn sync_git_repeated_member_dynamic_metadata() -> Result<()> {
             assert_snapshot!(
                 lock, @r#"
             version = 1
-            revision = 3
+            revision = 4
             requires-python = ">=3.13"
 
             [options]
@@ -10157,7 +10601,7 @@ fn sync_git_repeated_member_backwards_path() -> Result<()> {
             assert_snapshot!(
                 lock, @r#"
             version = 1
-            revision = 3
+            revision = 4
             requires-python = ">=3.13"
 
             [options]
@@ -10244,7 +10688,7 @@ fn sync_git_path_archive() -> Result<()> {
             assert_snapshot!(
                 lock, @r###"
             version = 1
-            revision = 3
+            revision = 4
             requires-python = ">=3.13"
 
             [options]
@@ -10318,7 +10762,7 @@ fn sync_git_path_archive_missing_lfs() -> Result<()> {
     lock.write_str(
         r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.13"
 
         [options]
@@ -10482,7 +10926,7 @@ fn sync_git_path_dependency() -> Result<()> {
             assert_snapshot!(
                 lock, @r#"
             version = 1
-            revision = 3
+            revision = 4
             requires-python = ">=3.13"
 
             [options]
@@ -10636,7 +11080,7 @@ fn lock_git_poetry_path_dependency() -> Result<()> {
             assert_snapshot!(
                 lock, @r#"
             version = 1
-            revision = 3
+            revision = 4
             requires-python = ">=3.13"
 
             [options]
@@ -10768,7 +11212,7 @@ fn lock_git_metadata_archive_dependency() -> Result<()> {
             assert_snapshot!(
                 lock, @r#"
             version = 1
-            revision = 3
+            revision = 4
             requires-python = ">=3.13"
 
             [options]
@@ -10863,7 +11307,7 @@ fn sync_build_tag() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -11472,7 +11916,7 @@ fn sync_locked_script() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.11"
 
         [options]
@@ -11570,7 +12014,7 @@ fn sync_locked_script() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.11"
 
         [options]
@@ -12251,7 +12695,7 @@ fn locked_version_coherence() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -12346,7 +12790,7 @@ fn sync_build_constraints() -> Result<()> {
             assert_snapshot!(
                 lock, @r#"
             version = 1
-            revision = 3
+            revision = 4
             requires-python = ">=3.12"
 
             [options]
@@ -13204,7 +13648,7 @@ fn conflicting_editable() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", group = "bar" },
@@ -13355,7 +13799,7 @@ fn undeclared_editable() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", group = "bar" },
@@ -14121,41 +14565,149 @@ fn sync_extra_build_variables() -> Result<()> {
 }
 
 #[test]
-fn reject_unmatched_runtime() -> Result<()> {
-    let context = uv_test::test_context!("3.12").with_exclude_newer("2025-01-01T00:00Z");
+fn sync_build_env_passthrough() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_filtered_counts();
+
+    // Create a build backend that fails if `EXPECTED_ANYIO_VERSION` is forwarded to the build.
+    let build_backend = context.temp_dir.child("build_backend.py");
+    build_backend.write_str(indoc! {r#"
+        import os
+        import sys
+        from hatchling.build import *
+
+        if "EXPECTED_ANYIO_VERSION" in os.environ:
+            print("`EXPECTED_ANYIO_VERSION` is set", file=sys.stderr)
+            sys.exit(1)
+    "#})?;
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
-    pyproject_toml.write_str(
-        r#"
-         [project]
-        name = "foo"
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "parent"
         version = "0.1.0"
-        requires-python = ">=3.12"
-        dependencies = ["source-distribution", "iniconfig"]
+        requires-python = ">=3.9"
 
-        [tool.uv.extra-build-dependencies]
-        source-distribution = [{ requirement = "iniconfig", match-runtime = true }]
-        "#,
-    )?;
+        [build-system]
+        requires = ["hatchling"]
+        backend-path = ["."]
+        build-backend = "build_backend"
+    "#})?;
+    context.temp_dir.child("src/parent/__init__.py").touch()?;
 
-    uv_snapshot!(context.filters(), context.lock(), @"
+    // By default, the entire environment is passed through to the build backend.
+    uv_snapshot!(context.filters(), context.sync().env(EnvVars::EXPECTED_ANYIO_VERSION, "4.3.0"), @"
     exit_code: 1 (failure)
     ----- stderr -----
-      × Failed to download and build `source-distribution==0.0.3`
-      ╰─▶ Extra build requirement `iniconfig` was declared with `match-runtime = true`, but `source-distribution` does not declare static metadata, making runtime-matching impossible
+    Resolved [N] packages in [TIME]
+      × Failed to build `parent @ file://[TEMP_DIR]/`
+      ├─▶ The build backend returned an error
+      ╰─▶ Call to `build_backend.build_editable` failed (exit status: 1)
 
-    hint: `source-distribution` (v0.0.3) was included because `foo` (v0.1.0) depends on `source-distribution`
-    ");
+          [stderr]
+          `EXPECTED_ANYIO_VERSION` is set
 
-    Ok(())
-}
 
-/// Test Git LFS configuration.
-#[test]
-#[cfg(feature = "test-git-lfs")]
-fn sync_git_lfs() -> Result<()> {
-    let context = uv_test::test_context!("3.13").with_git_lfs_config();
-    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    hint: Build failures usually indicate a problem with the package or the build environment
+    ");
+
+    // With an allowlist, variables that aren't listed are not passed through.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "parent"
+        version = "0.1.0"
+        requires-python = ">=3.9"
+
+        [build-system]
+        requires = ["hatchling"]
+        backend-path = ["."]
+        build-backend = "build_backend"
+
+        [tool.uv]
+        build-env-passthrough = ["CMAKE_ARGS"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.sync().env(EnvVars::EXPECTED_ANYIO_VERSION, "4.3.0"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + parent==0.1.0 (from file://[TEMP_DIR]/)
+    ");
+
+    // A package-specific list overrides the global list.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "parent"
+        version = "0.1.0"
+        requires-python = ">=3.9"
+
+        [build-system]
+        requires = ["hatchling"]
+        backend-path = ["."]
+        build-backend = "build_backend"
+
+        [tool.uv]
+        build-env-passthrough = ["CMAKE_ARGS"]
+
+        [tool.uv.build-env-passthrough-package]
+        parent = ["EXPECTED_ANYIO_VERSION"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--reinstall-package").arg("parent").env(EnvVars::EXPECTED_ANYIO_VERSION, "4.3.0"), @"
+    exit_code: 1 (failure)
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+      × Failed to build `parent @ file://[TEMP_DIR]/`
+      ├─▶ The build backend returned an error
+      ╰─▶ Call to `build_backend.build_editable` failed (exit status: 1)
+
+          [stderr]
+          `EXPECTED_ANYIO_VERSION` is set
+
+
+    hint: Build failures usually indicate a problem with the package or the build environment
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn reject_unmatched_runtime() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_exclude_newer("2025-01-01T00:00Z");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+         [project]
+        name = "foo"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["source-distribution", "iniconfig"]
+
+        [tool.uv.extra-build-dependencies]
+        source-distribution = [{ requirement = "iniconfig", match-runtime = true }]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    exit_code: 1 (failure)
+    ----- stderr -----
+      × Failed to download and build `source-distribution==0.0.3`
+      ╰─▶ Extra build requirement `iniconfig` was declared with `match-runtime = true`, but `source-distribution` does not declare static metadata, making runtime-matching impossible
+
+    hint: `source-distribution` (v0.0.3) was included because `foo` (v0.1.0) depends on `source-distribution`
+    ");
+
+    Ok(())
+}
+
+/// Test Git LFS configuration.
+#[test]
+#[cfg(feature = "test-git-lfs")]
+fn sync_git_lfs() -> Result<()> {
+    let context = uv_test::test_context!("3.13").with_git_lfs_config();
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
 
     // Set `lfs = true` in the source
     pyproject_toml.write_str(
@@ -14194,7 +14746,7 @@ fn sync_git_lfs() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.13"
 
         [options]
@@ -14308,7 +14860,7 @@ fn sync_git_lfs() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.13"
 
         [options]
@@ -14446,7 +14998,7 @@ fn sync_git_lfs() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.13"
 
         [options]
@@ -14498,7 +15050,7 @@ fn sync_git_lfs() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.13"
 
         [options]
@@ -14910,7 +15462,7 @@ fn toggle_workspace_editable() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -15014,7 +15566,7 @@ fn toggle_workspace_editable() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -15165,7 +15717,7 @@ fn workspace_editable_conflict() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -15259,7 +15811,7 @@ fn workspace_editable_conflict() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -16090,3 +16642,715 @@ fn sync_frozen_workspace_member_git_credentials() -> Result<()> {
 
     Ok(())
 }
+
+/// Run several `uv sync` invocations concurrently against one cache, with overlapping
+/// dependencies, including a source distribution that each invocation needs to build.
+#[test]
+fn sync_concurrent_shared_cache() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let dependencies = [
+        r#"["source-distribution==0.0.1", "iniconfig"]"#,
+        r#"["source-distribution==0.0.1", "anyio"]"#,
+        r#"["iniconfig", "anyio"]"#,
+        r#"["source-distribution==0.0.1", "iniconfig", "anyio"]"#,
+    ];
+
+    let mut children = Vec::new();
+    for (index, dependencies) in dependencies.iter().enumerate() {
+        let project = context.temp_dir.child(format!("project-{index}"));
+        project.child("pyproject.toml").write_str(&formatdoc! {r#"
+            [project]
+            name = "project-{index}"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = {dependencies}
+            "#
+        })?;
+
+        children.push(
+            context
+                .sync()
+                .current_dir(project.path())
+                .stdout(std::process::Stdio::piped())
+                .stderr(std::process::Stdio::piped())
+                .spawn()?,
+        );
+    }
+
+    for child in children {
+        let output = child.wait_with_output()?;
+        assert!(
+            output.status.success(),
+            "concurrent `uv sync` failed:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    // Each project should have been locked and synced.
+    for index in 0..dependencies.len() {
+        let project = context.temp_dir.child(format!("project-{index}"));
+        project.child("uv.lock").assert(predicate::path::is_file());
+        project.child(".venv").assert(predicate::path::is_dir());
+    }
+
+    Ok(())
+}
+
+/// Run many `uv sync` invocations concurrently against one cache, over several rounds: the first
+/// round populates the cache (and builds the shared source distribution), while later rounds
+/// reinstall from the fully materialized cache entries.
+#[test]
+fn sync_concurrent_shared_cache_high_volume() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let dependencies = [
+        r#""source-distribution==0.0.1""#,
+        r#""iniconfig""#,
+        r#""anyio""#,
+        r#""typing-extensions""#,
+    ];
+
+    // Give each project an overlapping subset of the dependencies.
+    let projects = (0..12)
+        .map(|index| {
+            let project = context.temp_dir.child(format!("project-{index}"));
+            let dependencies = dependencies
+                .iter()
+                .enumerate()
+                .filter(|(position, _)| (index >> position) & 1 == 1 || *position == index % 4)
+                .map(|(_, dependency)| *dependency)
+                .collect::<Vec<_>>()
+                .join(", ");
+            project.child("pyproject.toml").write_str(&formatdoc! {r#"
+                [project]
+                name = "project-{index}"
+                version = "0.1.0"
+                requires-python = ">=3.12"
+                dependencies = [{dependencies}]
+                "#
+            })?;
+            Ok(project)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    for round in 0..3 {
+        let children = projects
+            .iter()
+            .map(|project| {
+                let mut command = context.sync();
+                command
+                    .current_dir(project.path())
+                    .stdout(std::process::Stdio::piped())
+                    .stderr(std::process::Stdio::piped());
+                if round > 0 {
+                    command.arg("--reinstall");
+                }
+                command.spawn()
+            })
+            .collect::<std::io::Result<Vec<_>>>()?;
+
+        for child in children {
+            let output = child.wait_with_output()?;
+            assert!(
+                output.status.success(),
+                "concurrent `uv sync` failed in round {round}:\n{}",
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+    }
+
+    // Each project should have been locked and synced.
+    for project in &projects {
+        project.child("uv.lock").assert(predicate::path::is_file());
+        project.child(".venv").assert(predicate::path::is_dir());
+    }
+
+    Ok(())
+}
+
+/// Run the commands declared in `tool.uv.post-sync` after syncing.
+#[test]
+fn sync_post_sync() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv]
+        post-sync = [
+            "python -c \"import pathlib, sys; pathlib.Path('hook.txt').write_text(sys.prefix)\"",
+            "python -c \"print('post-sync done')\"",
+        ]
+        "#,
+    )?;
+
+    // With `--no-post-sync`, the commands are skipped.
+    context.sync().arg("--no-post-sync").assert().success();
+    context
+        .temp_dir
+        .child("hook.txt")
+        .assert(predicate::path::missing());
+
+    // The commands run in order, in the project environment.
+    uv_snapshot!(context.filters(), context.sync(), @r#"
+    exit_code: 0 (success)
+    ----- stdout -----
+    post-sync done
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Checked in [TIME]
+    Running post-sync command: `python -c "import pathlib, sys; pathlib.Path('hook.txt').write_text(sys.prefix)"`
+    Running post-sync command: `python -c "print('post-sync done')"`
+    "#);
+    let prefix = fs_err::read_to_string(context.temp_dir.child("hook.txt"))?;
+    assert_eq!(
+        fs_err::canonicalize(prefix)?,
+        fs_err::canonicalize(context.temp_dir.child(".venv"))?
+    );
+
+    // `UV_NO_POST_SYNC` skips the commands, too.
+    uv_snapshot!(context.filters(), context.sync().env(EnvVars::UV_NO_POST_SYNC, "1"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Checked in [TIME]
+    ");
+
+    // A failing command aborts with its exit code, skipping the remaining commands.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv]
+        post-sync = [
+            "python -c \"import sys; print('failing', file=sys.stderr); sys.exit(3)\"",
+            "python -c \"print('unreachable')\"",
+        ]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync(), @r#"
+    exit_code: 3 (failure)
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Checked in [TIME]
+    Running post-sync command: `python -c "import sys; print('failing', file=sys.stderr); sys.exit(3)"`
+    failing
+    error: Post-sync command `python -c "import sys; print('failing', file=sys.stderr); sys.exit(3)"` failed (exit status: 3)
+    "#);
+
+    Ok(())
+}
+
+/// Repair a project environment after the project directory is moved, with `uv sync --repair`.
+#[test]
+#[cfg(unix)]
+fn sync_repair() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_filtered_counts();
+    let project_dir = context.temp_dir.child("project");
+    project_dir.create_dir_all()?;
+    project_dir.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["black"]
+        "#,
+    )?;
+
+    context
+        .sync()
+        .current_dir(project_dir.path())
+        .assert()
+        .success();
+
+    let relocated_dir = context.temp_dir.child("relocated");
+    fs_err::rename(project_dir.path(), relocated_dir.path())?;
+
+    uv_snapshot!(context.filters(), context.sync()
+        .current_dir(relocated_dir.path())
+        .env_remove(EnvVars::VIRTUAL_ENV)
+        .arg("--repair"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Repaired environment moved from `[TEMP_DIR]/project/.venv`:
+     - Rewrote the shebang of `bin/black`
+     - Rewrote the shebang of `bin/blackd`
+     - Rewrote the activation script `bin/activate`
+     - Rewrote the activation script `bin/activate.bat`
+     - Rewrote the activation script `bin/activate.csh`
+     - Rewrote the activation script `bin/activate.fish`
+     - Rewrote the activation script `bin/activate.nu`
+    Resolved [N] packages in [TIME]
+    Checked [N] packages in [TIME]
+    ");
+
+    // The scripts invoke the interpreter at the new location.
+    uv_snapshot!(context.filters(), context.run()
+        .current_dir(relocated_dir.path())
+        .env_remove(EnvVars::VIRTUAL_ENV)
+        .arg("--no-sync")
+        .arg("black")
+        .arg("--version"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    black, 24.3.0 (compiled: yes)
+    Python (CPython) 3.12.[X]
+    ");
+
+    relocated_dir
+        .child(".venv")
+        .child("bin")
+        .child("activate")
+        .assert(predicate::str::contains("relocated/.venv"));
+
+    // Repairing again is a no-op.
+    uv_snapshot!(context.filters(), context.sync()
+        .current_dir(relocated_dir.path())
+        .env_remove(EnvVars::VIRTUAL_ENV)
+        .arg("--repair"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Checked [N] packages in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// Install workspace members listed in `tool.uv.no-editable-package` as non-editable, and reinstall
+/// them when the setting changes.
+#[test]
+#[cfg(not(windows))]
+fn sync_no_editable_package_setting() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let child = context.temp_dir.child("child");
+    child.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig>=1"]
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+        "#,
+    )?;
+    child
+        .child("src")
+        .child("child")
+        .child("__init__.py")
+        .touch()?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child"]
+
+        [tool.uv]
+        no-editable-package = ["child"]
+
+        [tool.uv.workspace]
+        members = ["child"]
+
+        [tool.uv.sources]
+        child = { workspace = true }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync(), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + child==0.1.0 (from file://[TEMP_DIR]/child)
+     + iniconfig==2.0.0
+    ");
+
+    assert!(!context.site_packages().join("_child.pth").exists());
+
+    // Syncing again is a no-op.
+    uv_snapshot!(context.filters(), context.sync(), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Audited 2 packages in [TIME]
+    ");
+
+    // The member is exported without `-e`.
+    uv_snapshot!(context.filters(), context.export().arg("--no-hashes"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --no-hashes
+    ./child
+        # via project
+    iniconfig==2.0.0
+        # via child
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    // `--editable` takes precedence over the setting.
+    uv_snapshot!(context.filters(), context.sync().arg("--editable"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ child==0.1.0 (from file://[TEMP_DIR]/child)
+    ");
+
+    assert!(context.site_packages().join("_child.pth").exists());
+
+    // Returning to the configured mode reinstalls the member from a wheel.
+    uv_snapshot!(context.filters(), context.sync(), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ child==0.1.0 (from file://[TEMP_DIR]/child)
+    ");
+
+    assert!(!context.site_packages().join("_child.pth").exists());
+
+    // Removing the setting reinstalls the member as editable.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child"]
+
+        [tool.uv.workspace]
+        members = ["child"]
+
+        [tool.uv.sources]
+        child = { workspace = true }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync(), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ child==0.1.0 (from file://[TEMP_DIR]/child)
+    ");
+
+    assert!(context.site_packages().join("_child.pth").exists());
+
+    Ok(())
+}
+
+/// Explain which artifact was selected for a package with `--explain`.
+#[test]
+fn sync_explain() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("--explain").arg("iniconfig")
+        .arg("--explain").arg("missing")
+        .arg("--python-platform").arg("x86_64-manylinux_2_28"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Artifacts for iniconfig==2.0.0 (Python: cp312, ABI: cp312, platform: manylinux_2_28_x86_64):
+     + iniconfig-2.0.0-py3-none-any.whl (selected)
+     ~ iniconfig-2.0.0.tar.gz (superseded by a higher-priority artifact)
+    warning: `missing` is not in the lockfile; nothing to explain
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    // With `--no-binary-package`, the wheel is rejected in favor of the source distribution.
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("--explain").arg("iniconfig")
+        .arg("--no-binary-package").arg("iniconfig")
+        .arg("--python-platform").arg("x86_64-manylinux_2_28"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Artifacts for iniconfig==2.0.0 (Python: cp312, ABI: cp312, platform: manylinux_2_28_x86_64):
+     - iniconfig-2.0.0-py3-none-any.whl (rejected: binary distributions are disabled (`--no-binary`))
+     + iniconfig-2.0.0.tar.gz (selected)
+    Audited 1 package in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// Reinstall the dependents of a package with `--reinstall-package` and `--cascade`.
+#[test]
+fn sync_reinstall_package_cascade() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child"]
+
+        [tool.uv.workspace]
+        members = ["child"]
+
+        [tool.uv.sources]
+        child = { workspace = true }
+        "#,
+    )?;
+
+    let child = context.temp_dir.child("child");
+    child.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+        "#,
+    )?;
+    child
+        .child("src")
+        .child("child")
+        .child("__init__.py")
+        .touch()?;
+
+    uv_snapshot!(context.filters(), context.sync(), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + child==0.1.0 (from file://[TEMP_DIR]/child)
+     + iniconfig==2.0.0
+    ");
+
+    // Without `--cascade`, only the named package is reinstalled.
+    uv_snapshot!(context.filters(), context.sync().arg("--reinstall-package").arg("iniconfig"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ iniconfig==2.0.0
+    ");
+
+    // With `--cascade`, the packages that depend on it are reinstalled (and rebuilt) too.
+    uv_snapshot!(context.filters(), context.sync().arg("--reinstall-package").arg("iniconfig").arg("--cascade"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Reinstalling dependents of iniconfig: child, project
+    Prepared 2 packages in [TIME]
+    Uninstalled 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     ~ child==0.1.0 (from file://[TEMP_DIR]/child)
+     ~ iniconfig==2.0.0
+    ");
+
+    // Packages without dependents are reinstalled alone.
+    uv_snapshot!(context.filters(), context.sync().arg("--reinstall-package").arg("project").arg("--cascade"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    No locked packages depend on project; nothing to cascade
+    Audited 2 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// Files served by an index with `rewrite-files` are downloaded from the mirror, and validated
+/// against the hashes in the lockfile.
+#[tokio::test]
+async fn sync_index_rewrite_files() -> Result<()> {
+    let context = uv_test::test_context!("3.13");
+    let server = MockServer::start().await;
+    let mirror = MockServer::start().await;
+
+    let simple_index = json!({
+        "meta": {
+            "api-version": "1.1"
+        },
+        "name": "basic-package",
+        "files": [{
+            "filename": "basic_package-0.1.0-py3-none-any.whl",
+            "url": format!("{}/files/basic_package-0.1.0-py3-none-any.whl", server.uri()),
+            "hashes": {
+                "sha256": "7b6229db79b5800e4e98a351b5628c1c8a944533a2d428aeeaa7275a30d4ea82"
+            },
+            "core-metadata": true
+        }]
+    });
+    let metadata = indoc! {"
+        Metadata-Version: 2.1
+        Name: basic-package
+        Version: 0.1.0
+    "};
+
+    Mock::given(method("GET"))
+        .and(path("/simple/basic-package/"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            simple_index.to_string(),
+            "application/vnd.pypi.simple.v1+json",
+        ))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/files/basic_package-0.1.0-py3-none-any.whl.metadata"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(metadata))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(
+            "/mirror/basic_package-0.1.0-py3-none-any.whl.metadata",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_string(metadata))
+        .mount(&mirror)
+        .await;
+
+    // The mirror serves a different wheel than the index advertises.
+    Mock::given(method("GET"))
+        .and(path("/mirror/basic_package-0.1.0-py3-none-any.whl"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_bytes(fs_err::read(
+                context
+                    .workspace_root
+                    .join("test/links/ok-1.0.0-py3-none-any.whl"),
+            )?),
+        )
+        .mount(&mirror)
+        .await;
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(&formatdoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.13"
+        dependencies = ["basic-package"]
+
+        [tool.uv.sources]
+        basic-package = {{ index = "test-registry" }}
+
+        [[tool.uv.index]]
+        name = "test-registry"
+        url = "{server}/simple"
+        explicit = true
+        rewrite-files = {{ from = "{server}/files/", to = "{mirror}/mirror/" }}
+        "#,
+            server = server.uri(),
+            mirror = mirror.uri(),
+        })?;
+
+    uv_snapshot!(context.filters(), context.lock().env_remove(EnvVars::UV_EXCLUDE_NEWER), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    // The lockfile retains the URLs served by the index.
+    let lock = context.read("uv.lock");
+    assert!(lock.contains(&format!(
+        "{}/files/basic_package-0.1.0-py3-none-any.whl",
+        server.uri()
+    )));
+    assert!(!lock.contains(&mirror.uri()));
+
+    // The wheel served by the mirror doesn't match the hash in the lockfile.
+    context
+        .sync()
+        .arg("--locked")
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Hash mismatch for `basic-package==0.1.0`",
+        ));
+
+    // Once the mirror serves the expected wheel, it's installed.
+    mirror.reset().await;
+    Mock::given(method("GET"))
+        .and(path("/mirror/basic_package-0.1.0-py3-none-any.whl"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_bytes(fs_err::read(
+                context
+                    .workspace_root
+                    .join("test/links/basic_package-0.1.0-py3-none-any.whl"),
+            )?),
+        )
+        .mount(&mirror)
+        .await;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--locked").env_remove(EnvVars::UV_EXCLUDE_NEWER), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + basic-package==0.1.0
+    ");
+
+    // The wheel was only ever downloaded from the mirror.
+    let requests = server.received_requests().await.unwrap_or_default();
+    assert!(
+        requests
+            .iter()
+            .all(|request| request.url.path() != "/files/basic_package-0.1.0-py3-none-any.whl")
+    );
+    let requests = mirror.received_requests().await.unwrap_or_default();
+    assert!(
+        requests.iter().any(|request| {
+            request.url.path() == "/mirror/basic_package-0.1.0-py3-none-any.whl"
+        })
+    );
+
+    Ok(())
+}
@@ -312,6 +312,189 @@ fn tool_install_from_directory_ignores_global_pin_outside_requires_python_range(
     ");
 }
 
+/// Tools installed into a directory with a path that exceeds the shebang length limit should use a
+/// `/bin/sh` trampoline for their executables.
+#[test]
+#[cfg(unix)]
+fn tool_install_deep_tool_dir() {
+    let context = uv_test::test_context!("3.12")
+        .with_filtered_counts()
+        .with_filtered_exe_suffix();
+    let tool_dir = context
+        .temp_dir
+        .child("a".repeat(64))
+        .child("b".repeat(64))
+        .child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    let foo_dir = context.temp_dir.child("foo");
+    foo_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.scripts]
+        foo = "foo.main:run"
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+        "#
+        })
+        .unwrap();
+    foo_dir
+        .child("src")
+        .child("foo")
+        .child("__init__.py")
+        .touch()
+        .unwrap();
+    foo_dir
+        .child("src")
+        .child("foo")
+        .child("main.py")
+        .write_str(indoc! {r#"
+        def run():
+            print("Hello from foo")
+        "#
+        })
+        .unwrap();
+
+    context
+        .tool_install()
+        .arg(foo_dir.as_os_str())
+        .arg("-v")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str())
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("using a `/bin/sh` trampoline"));
+
+    uv_snapshot!(context.filters(), Command::new("foo")
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    Hello from foo
+    ");
+}
+
+#[test]
+fn tool_install_falls_back_to_older_interpreter() {
+    let context = uv_test::test_context_with_versions!(&["3.13", "3.12"])
+        .with_filtered_counts()
+        .with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // The tool itself supports Python 3.13, but one of its dependencies does not.
+    let foo_dir = context.temp_dir.child("foo");
+    foo_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["bar"]
+
+        [project.scripts]
+        foo = "foo.main:run"
+
+        [tool.uv.sources]
+        bar = { path = "../bar" }
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+        "#
+        })
+        .unwrap();
+    foo_dir
+        .child("src")
+        .child("foo")
+        .child("__init__.py")
+        .touch()
+        .unwrap();
+    foo_dir
+        .child("src")
+        .child("foo")
+        .child("main.py")
+        .write_str(indoc! {r#"
+        import sys
+
+        def run():
+            print(f"{sys.version_info.major}.{sys.version_info.minor}")
+        "#
+        })
+        .unwrap();
+
+    let bar_dir = context.temp_dir.child("bar");
+    bar_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "bar"
+        version = "0.1.0"
+        requires-python = ">=3.12,<3.13"
+        dependencies = []
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+        "#
+        })
+        .unwrap();
+    bar_dir
+        .child("src")
+        .child("bar")
+        .child("__init__.py")
+        .touch()
+        .unwrap();
+
+    // The first interpreter (Python 3.13) is incompatible, so we should fall back to Python 3.12.
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg(foo_dir.as_os_str())
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Using Python 3.12.[X] instead of Python 3.13.[X], which is incompatible with the tool's Python requirement: `>=3.12, <3.13`
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + bar==0.1.0 (from file://[TEMP_DIR]/bar)
+     + foo==0.1.0 (from file://[TEMP_DIR]/foo)
+    Installed 1 executable: foo
+    ");
+
+    uv_snapshot!(context.filters(), Command::new("foo")
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    3.12
+    ");
+
+    // An explicit `--python` request is authoritative.
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg(foo_dir.as_os_str())
+        .arg("--python")
+        .arg("3.13")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @"
+    exit_code: 1 (failure)
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because the current Python version (3.13.[X]) does not satisfy Python>=3.12, <3.13 and bar==0.1.0 depends on Python>=3.12, <3.13, we can conclude that bar==0.1.0 cannot be used.
+          And because only bar==0.1.0 is available and foo depends on bar, we can conclude that your requirements are unsatisfiable.
+    ");
+}
+
 #[test]
 fn tool_install_from_directory_uses_global_pin_within_requires_python_range() {
     let context = uv_test::test_context_with_versions!(&["3.13", "3.12", "3.11"])
@@ -661,6 +844,77 @@ fn tool_install_force_respects_global_python_change() {
     ");
 }
 
+/// Test installing a tool with a platform-conditional `--with` requirement.
+#[test]
+fn tool_install_with_markers() {
+    let context = uv_test::test_context!("3.12")
+        .with_filtered_counts()
+        .with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // The marker doesn't apply, so `iniconfig` is skipped.
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("--with")
+        .arg("iniconfig ; sys_platform == 'nonexistent', anyio[trio] ; python_version >= '3.8'")
+        .arg("black")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + anyio==4.3.0
+     + attrs==23.2.0
+     + black==24.3.0
+     + click==8.1.7
+     + idna==3.6
+     + mypy-extensions==1.0.0
+     + outcome==1.3.0.post0
+     + packaging==24.0
+     + pathspec==0.12.1
+     + platformdirs==4.2.0
+     + sniffio==1.3.1
+     + sortedcontainers==2.4.0
+     + trio==0.25.0
+    Installed 2 executables: black, blackd
+    ");
+
+    // The requirements are recorded with their markers, to be re-evaluated on upgrade.
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(fs_err::read_to_string(tool_dir.join("black").join("uv-receipt.toml")).unwrap(), @r#"
+        [tool]
+        requirements = [
+            { name = "black" },
+            { name = "iniconfig", marker = "sys_platform == 'nonexistent'" },
+            { name = "anyio", extras = ["trio"], marker = "python_full_version >= '3.8'" },
+        ]
+        entrypoints = [
+            { name = "black", install-path = "[TEMP_DIR]/bin/black", from = "black" },
+            { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd", from = "black" },
+        ]
+
+        [tool.options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+        "#);
+    });
+
+    uv_snapshot!(context.filters(), context.tool_list()
+        .arg("--show-with")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    black v24.3.0 [with: iniconfig ; sys_platform == 'nonexistent', anyio[trio] ; python_full_version >= '3.8']
+    - black
+    - blackd
+    ");
+}
+
 #[test]
 fn tool_install_with_editable() -> Result<()> {
     let context = uv_test::test_context!("3.12")
@@ -4827,6 +5081,92 @@ fn tool_install_settings() {
     });
 }
 
+/// Test reinstalling a tool from its receipt with `--keep-options`.
+#[test]
+fn tool_install_keep_options() {
+    let context = uv_test::test_context!("3.12")
+        .with_filtered_counts()
+        .with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // `--keep-options` requires an existing receipt.
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("flask")
+        .arg("--reinstall")
+        .arg("--keep-options")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    error: `flask` is not installed; `--keep-options` requires an existing tool receipt
+    ");
+
+    // Install the lowest `flask>=3` version.
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("flask>=3")
+        .arg("--resolution=lowest-direct")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + blinker==1.7.0
+     + click==8.1.7
+     + flask==3.0.0
+     + itsdangerous==2.1.2
+     + jinja2==3.1.3
+     + markupsafe==2.1.5
+     + werkzeug==3.0.1
+    Installed 1 executable: flask
+    ");
+
+    // Reinstall from the receipt, which should retain the requirement and resolution strategy.
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("flask")
+        .arg("--reinstall")
+        .arg("--keep-options")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Uninstalled [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     ~ blinker==1.7.0
+     ~ click==8.1.7
+     ~ flask==3.0.0
+     ~ itsdangerous==2.1.2
+     ~ jinja2==3.1.3
+     ~ markupsafe==2.1.5
+     ~ werkzeug==3.0.1
+    Installed 1 executable: flask
+    ");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        // The receipt should be unchanged.
+        assert_snapshot!(fs_err::read_to_string(tool_dir.join("flask").join("uv-receipt.toml")).unwrap(), @r#"
+        [tool]
+        requirements = [{ name = "flask", specifier = ">=3" }]
+        entrypoints = [
+            { name = "flask", install-path = "[TEMP_DIR]/bin/flask", from = "flask" },
+        ]
+
+        [tool.options]
+        resolution = "lowest-direct"
+        exclude-newer = "2024-03-25T00:00:00Z"
+        "#);
+    });
+}
+
 /// Test installing a tool with `uv tool install {package}@{version}`.
 #[test]
 fn tool_install_at_version() {
@@ -5960,7 +6300,7 @@ fn tool_install_locks_are_preview() {
     insta::with_settings!({ filters => context.filters() }, {
         assert_snapshot!(context.read("tools/simple-launcher/uv.lock"), @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -6004,7 +6344,7 @@ fn tool_install_lock_supports_local_wheel() {
     insta::with_settings!({ filters => context.filters() }, {
         assert_snapshot!(context.read("tools/simple-launcher/uv.lock"), @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -669,6 +669,53 @@ fn tool_list_show_python() {
     ");
 }
 
+#[test]
+fn tool_list_verbose() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Install `babel` from Test PyPI.
+    context
+        .tool_install()
+        .arg("babel==2.6.0")
+        .arg("--index-url")
+        .arg("https://test.pypi.org/simple/")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    // The recorded index is only displayed in verbose mode.
+    uv_snapshot!(context.filters(), context.tool_list()
+    .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+    .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    babel v2.6.0
+    - pybabel
+    ");
+
+    // In verbose mode, debug logs are written to stderr, so only inspect stdout.
+    let output = context
+        .tool_list()
+        .arg("--verbose")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .output()?;
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout)?;
+
+    insta::with_settings!({ filters => context.filters() }, {
+        assert_snapshot!(stdout, @"
+        babel v2.6.0 [index: https://test.pypi.org/simple/]
+        - pybabel
+        ");
+    });
+
+    Ok(())
+}
+
 #[test]
 fn tool_list_show_all() {
     let context = uv_test::test_context!("3.12").with_filtered_exe_suffix();
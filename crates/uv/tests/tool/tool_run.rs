@@ -739,7 +739,7 @@ fn tool_run_cache() {
     Resolved [N] packages in [TIME]
     ");
 
-    // Verify that `--refresh` allows cache reuse.
+    // Verify that `--refresh` rebuilds the cached environment.
     uv_snapshot!(context.filters(), context.tool_run()
         .arg("-p")
         .arg("3.12")
@@ -755,9 +755,34 @@ fn tool_run_cache() {
 
     ----- stderr -----
     Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + black==24.3.0
+     + click==8.1.7
+     + mypy-extensions==1.0.0
+     + packaging==24.0
+     + pathspec==0.12.1
+     + platformdirs==4.2.0
     ");
 
-    // Verify that `--refresh-package` allows cache reuse.
+    // Verify that the rebuilt environment is reused.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("-p")
+        .arg("3.12")
+        .arg("black")
+        .arg("--version")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    black, 24.3.0 (compiled: yes)
+    Python (CPython) 3.12.[X]
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    ");
+
+    // Verify that `--refresh-package` rebuilds the cached environment.
     uv_snapshot!(context.filters(), context.tool_run()
         .arg("-p")
         .arg("3.12")
@@ -772,6 +797,33 @@ fn tool_run_cache() {
     black, 24.3.0 (compiled: yes)
     Python (CPython) 3.12.[X]
 
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + black==24.3.0
+     + click==8.1.7
+     + mypy-extensions==1.0.0
+     + packaging==24.0
+     + pathspec==0.12.1
+     + platformdirs==4.2.0
+    ");
+
+    // Verify that `--refresh-package` for a package outside of the environment allows cache reuse.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("-p")
+        .arg("3.12")
+        .arg("--refresh-package")
+        .arg("iniconfig")
+        .arg("black")
+        .arg("--version")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    black, 24.3.0 (compiled: yes)
+    Python (CPython) 3.12.[X]
+
     ----- stderr -----
     Resolved [N] packages in [TIME]
     ");
@@ -3735,3 +3787,434 @@ async fn tool_run_latest_keyring_auth() {
     Installed 1 executable: app
     ");
 }
+
+/// Run the single entry point of a local wheel, e.g., `uvx ./tool-1.0-py3-none-any.whl`.
+#[test]
+fn tool_run_wheel() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_filtered_counts();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    fs_err::copy(
+        context
+            .workspace_root
+            .join("test/links/simple_launcher-0.1.0-py3-none-any.whl"),
+        context
+            .temp_dir
+            .join("simple_launcher-0.1.0-py3-none-any.whl"),
+    )?;
+
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("./simple_launcher-0.1.0-py3-none-any.whl")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    Hi from the simple launcher!
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + simple-launcher==0.1.0 (from file://[TEMP_DIR]/simple_launcher-0.1.0-py3-none-any.whl)
+    ");
+
+    // The entry point can be selected explicitly.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--entrypoint")
+        .arg("simple_launcher")
+        .arg("./simple_launcher-0.1.0-py3-none-any.whl")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    Hi from the simple launcher!
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    ");
+
+    // An unknown entry point lists the available ones.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--entrypoint")
+        .arg("missing")
+        .arg("./simple_launcher-0.1.0-py3-none-any.whl")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @"
+    exit_code: 2 (error)
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    error: The wheel at `./simple_launcher-0.1.0-py3-none-any.whl` does not provide an entry point named `missing`. The following entry points are available:
+    - simple_launcher
+    ");
+
+    Ok(())
+}
+
+/// A local wheel that's rebuilt in place gets a new environment.
+#[test]
+fn tool_run_wheel_rebuilt() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_filtered_counts();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    let project = context.temp_dir.child("greeter");
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "greeter"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [project.scripts]
+        greeter = "greeter:main"
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+    "#})?;
+    let init = project.child("src").child("greeter").child("__init__.py");
+    init.write_str(indoc! {r#"
+        def main():
+            print("Hello")
+    "#})?;
+
+    context
+        .build()
+        .arg("--wheel")
+        .current_dir(&project)
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("./greeter/dist/greeter-0.1.0-py3-none-any.whl")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    Hello
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + greeter==0.1.0 (from file://[TEMP_DIR]/greeter/dist/greeter-0.1.0-py3-none-any.whl)
+    ");
+
+    // Rebuild the wheel under the same filename.
+    init.write_str(indoc! {r#"
+        def main():
+            print("Goodbye")
+    "#})?;
+    context
+        .build()
+        .arg("--wheel")
+        .current_dir(&project)
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("./greeter/dist/greeter-0.1.0-py3-none-any.whl")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    Goodbye
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + greeter==0.1.0 (from file://[TEMP_DIR]/greeter/dist/greeter-0.1.0-py3-none-any.whl)
+    ");
+
+    Ok(())
+}
+
+/// A local wheel without any entry points can't be run.
+#[test]
+fn tool_run_wheel_no_entrypoints() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_filtered_counts();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    fs_err::copy(
+        context
+            .workspace_root
+            .join("test/links/ok-1.0.0-py3-none-any.whl"),
+        context.temp_dir.join("ok-1.0.0-py3-none-any.whl"),
+    )?;
+
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("./ok-1.0.0-py3-none-any.whl")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @"
+    exit_code: 2 (error)
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + ok==1.0.0 (from file://[TEMP_DIR]/ok-1.0.0-py3-none-any.whl)
+    error: The wheel at `./ok-1.0.0-py3-none-any.whl` does not provide any entry points
+    ");
+
+    Ok(())
+}
+
+/// Run an executable that's installed from a wheel's `.data/scripts` directory, rather than a
+/// console script.
+#[cfg(not(windows))]
+#[test]
+fn tool_run_data_script_entrypoint() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_filtered_counts();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    let project = context.temp_dir.child("wrapper");
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "wrapper"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+
+        [tool.uv.build-backend.data]
+        scripts = "scripts"
+    "#})?;
+    project.child("src/wrapper/__init__.py").touch()?;
+    project.child("scripts/wrapper-cli").write_str(indoc! {r#"
+            #!python
+            print("Hello from a data script")
+        "#})?;
+
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--entrypoint")
+        .arg("wrapper-cli")
+        .arg("./wrapper")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    Hello from a data script
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + wrapper==0.1.0 (from file://[TEMP_DIR]/wrapper)
+    ");
+
+    // An unknown entry point lists the available ones, including the data script.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--entrypoint")
+        .arg("missing")
+        .arg("./wrapper")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @"
+    exit_code: 2 (error)
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    error: Package `wrapper` does not provide an entry point named `missing`. The following entry points are available:
+    - wrapper-cli
+    ");
+
+    Ok(())
+}
+
+/// Run a zipapp directly, e.g., `uvx ./tool.pyz`.
+#[test]
+fn tool_run_zipapp() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_filtered_counts();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    let source = context.temp_dir.child("tool");
+    source.child("__main__.py").write_str(indoc! { r#"
+        import sys
+
+        print(f"Hello from a zipapp: {sys.argv[1:]}")
+
+        try:
+            import iniconfig
+        except ImportError:
+            print("iniconfig is not installed")
+        else:
+            print("iniconfig is installed")
+    "#})?;
+
+    context
+        .python_command()
+        .arg("-m")
+        .arg("zipapp")
+        .arg(source.path())
+        .arg("--output")
+        .arg(context.temp_dir.join("tool.pyz"))
+        .arg("--python")
+        .arg("/usr/bin/env python3.12")
+        .assert()
+        .success();
+
+    // Without additional requirements, the zipapp runs without creating an environment.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("./tool.pyz")
+        .arg("--flag")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    Hello from a zipapp: ['--flag']
+    iniconfig is not installed
+    ");
+
+    // With `--with`, the zipapp runs in an ephemeral environment.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--with")
+        .arg("iniconfig")
+        .arg("./tool.pyz")
+        .arg("--flag")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    Hello from a zipapp: ['--flag']
+    iniconfig is installed
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    Ok(())
+}
+
+/// Expose host executables to the command with `--with-executable`.
+#[test]
+#[cfg(unix)]
+fn tool_run_with_executable() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_filtered_counts();
+
+    let host = context.temp_dir.child("host");
+    for name in ["greet", "python"] {
+        let executable = host.child(name);
+        executable.write_str(indoc! { r#"
+            #!/bin/sh
+            echo "Hello from the host"
+        "# })?;
+        let mut permissions = metadata(executable.path())?.permissions();
+        permissions.set_mode(0o755);
+        set_permissions(executable.path(), permissions)?;
+    }
+
+    // The executable is available to the command, even though it isn't on the `PATH`.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--with-executable")
+        .arg("host/greet")
+        .arg("python")
+        .arg("-c")
+        .arg("import subprocess; subprocess.run(['greet'], check=True)"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    Hello from the host
+
+    ----- stderr -----
+    Resolved in [TIME]
+    Checked in [TIME]
+    ");
+
+    // Executables that collide with those in the tool environment are rejected.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--with-executable")
+        .arg("host/python")
+        .arg("python")
+        .arg("--version"), @"
+    exit_code: 2 (error)
+    ----- stderr -----
+    Resolved in [TIME]
+    error: Executable `python` (from `[TEMP_DIR]/host/python`) conflicts with an executable in the tool environment
+    ");
+
+    // Missing executables are rejected upfront.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--with-executable")
+        .arg("host/missing")
+        .arg("python")
+        .arg("--version"), @"
+    exit_code: 2 (error)
+    ----- stderr -----
+    error: Executable not found at: `host/missing`
+    ");
+
+    Ok(())
+}
+
+/// Resolve a tool as of a given date with `--exclude-newer`; differing dates shouldn't share a
+/// cached environment.
+#[test]
+fn tool_run_exclude_newer() {
+    let context = uv_test::test_context!("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--exclude-newer")
+        .arg("2024-01-15T00:00:00Z")
+        .arg("pytest")
+        .arg("--version")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    pytest 7.4.4
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Prepared 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + iniconfig==2.0.0
+     + packaging==23.2
+     + pluggy==1.3.0
+     + pytest==7.4.4
+    ");
+
+    // A later date resolves to a newer version, rather than reusing the environment above.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--exclude-newer")
+        .arg("2024-03-25T00:00:00Z")
+        .arg("pytest")
+        .arg("--version")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    pytest 8.1.1
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + iniconfig==2.0.0
+     + packaging==24.0
+     + pluggy==1.4.0
+     + pytest==8.1.1
+    ");
+
+    // Repeating the original date reuses its environment.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--exclude-newer")
+        .arg("2024-01-15T00:00:00Z")
+        .arg("pytest")
+        .arg("--version")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    pytest 7.4.4
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+}
@@ -516,6 +516,7 @@ fn tool_upgrade_name() {
      + babel==2.14.0
      - pytz==2018.5
     Installed 1 executable: pybabel
+    Updated the recorded index for `babel`
     ");
 }
 
@@ -632,10 +633,12 @@ fn tool_upgrade_multiple_names() {
      + babel==2.14.0
      - pytz==2018.5
     Installed 1 executable: pybabel
+    Updated the recorded index for `babel`
     Updated python-dotenv v0.10.2.post2 -> v1.0.1
      - python-dotenv==0.10.2.post2
      + python-dotenv==1.0.1
     Installed 1 executable: dotenv
+    Updated the recorded index for `python-dotenv`
     ");
 }
 
@@ -679,6 +682,7 @@ fn tool_upgrade_pinned_hint() {
     Modified babel environment
      - pytz==2018.5
      + pytz==2024.1
+    Updated the recorded index for `babel`
 
     hint: `babel` is pinned to `2.6.0` (installed with an exact version pin); reinstall with `uv tool install babel@latest` to upgrade to a new version.
     ");
@@ -725,6 +729,7 @@ fn tool_upgrade_pinned_hint_with_mixed_constraint() {
     Modified babel environment
      - pytz==2018.5
      + pytz==2024.1
+    Updated the recorded index for `babel`
 
     hint: `babel` is pinned to `2.6.0` (installed with an exact version pin); reinstall with `uv tool install babel@latest` to upgrade to a new version.
     ");
@@ -788,10 +793,12 @@ fn tool_upgrade_all() {
      + babel==2.14.0
      - pytz==2018.5
     Installed 1 executable: pybabel
+    Updated the recorded index for `babel`
     Updated python-dotenv v0.10.2.post2 -> v1.0.1
      - python-dotenv==0.10.2.post2
      + python-dotenv==1.0.1
     Installed 1 executable: dotenv
+    Updated the recorded index for `python-dotenv`
     ");
 }
 
@@ -891,6 +898,7 @@ fn tool_upgrade_not_stop_if_upgrade_fails() -> anyhow::Result<()> {
      + babel==2.14.0
      - pytz==2018.5
     Installed 1 executable: pybabel
+    Updated the recorded index for `babel`
     error: Failed to upgrade python-dotenv
       Caused by: `python-dotenv` is missing a valid receipt; run `uv tool install --force python-dotenv` to reinstall
     ");
@@ -1047,6 +1055,7 @@ fn tool_upgrade_respect_constraints() {
      - pytz==2018.5
      + pytz==2024.1
     Installed 1 executable: pybabel
+    Updated the recorded index for `babel`
     ");
 }
 
@@ -1092,6 +1101,7 @@ fn tool_upgrade_constraint() {
      - pytz==2018.5
      + pytz==2024.1
     Installed 1 executable: pybabel
+    Updated the recorded index for `babel`
     ");
 
     // Upgrade `babel`, but apply a constraint via `--upgrade-package`.
@@ -1189,6 +1199,82 @@ fn tool_upgrade_with() {
     Modified babel environment
      - pytz==2018.5
      + pytz==2024.1
+    Updated the recorded index for `babel`
+
+    hint: `babel` is pinned to `2.6.0` (installed with an exact version pin); reinstall with `uv tool install babel@latest` to upgrade to a new version.
+    ");
+}
+
+/// Index options passed to `uv tool upgrade` should replace those in the receipt, and be
+/// persisted even if the tool itself isn't upgraded.
+#[test]
+fn tool_upgrade_persists_index() {
+    let context = uv_test::test_context!("3.12")
+        .with_filtered_counts()
+        .with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Install `babel` from Test PyPI, to get an outdated version.
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("babel==2.6.0")
+        .arg("--index-url")
+        .arg("https://test.pypi.org/simple/")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + babel==2.6.0
+     + pytz==2018.5
+    Installed 1 executable: pybabel
+    ");
+
+    // Upgrade `babel` from PyPI. The tool itself is pinned, but the index should be recorded.
+    uv_snapshot!(context.filters(), context.tool_upgrade()
+        .arg("babel")
+        .arg("--index-url")
+        .arg("https://pypi.org/simple/")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Modified babel environment
+     - pytz==2018.5
+     + pytz==2024.1
+    Updated the recorded index for `babel`
+
+    hint: `babel` is pinned to `2.6.0` (installed with an exact version pin); reinstall with `uv tool install babel@latest` to upgrade to a new version.
+    ");
+
+    // Upgrading again should use the recorded index, rather than reverting to Test PyPI.
+    uv_snapshot!(context.filters(), context.tool_upgrade()
+        .arg("babel")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Nothing to upgrade
+
+    hint: `babel` is pinned to `2.6.0` (installed with an exact version pin); reinstall with `uv tool install babel@latest` to upgrade to a new version.
+    ");
+
+    // Passing the recorded index again shouldn't report a change.
+    uv_snapshot!(context.filters(), context.tool_upgrade()
+        .arg("babel")
+        .arg("--index-url")
+        .arg("https://pypi.org/simple/")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Nothing to upgrade
 
     hint: `babel` is pinned to `2.6.0` (installed with an exact version pin); reinstall with `uv tool install babel@latest` to upgrade to a new version.
     ");
@@ -1442,6 +1528,7 @@ fn tool_upgrade_excludes() {
      - babel==2.6.0
      + babel==2.9.1
     Installed 1 executable: pybabel
+    Updated the recorded index for `babel`
     ");
 }
 
@@ -1547,7 +1634,7 @@ fn tool_upgrade_writes_preview_lock() {
     insta::with_settings!({ filters => context.filters() }, {
         assert_snapshot!(context.read("tools/simple-launcher/uv.lock"), @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -2,8 +2,12 @@
 
 mod workspace;
 
+mod workspace_add;
+
 mod workspace_dir;
 
 mod workspace_list;
 
 mod workspace_metadata;
+
+mod workspace_remove;
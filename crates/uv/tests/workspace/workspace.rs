@@ -1400,7 +1400,7 @@ fn workspace_inherit_sources() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -2155,7 +2155,7 @@ fn transitive_dep_in_git_workspace_with_cache_inside_workspace() -> Result<()> {
     }, {
         assert_snapshot!(context.read("uv.lock"), @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -2396,3 +2396,41 @@ fn workspace_unmanaged_member_no_project() -> Result<()> {
 
     Ok(())
 }
+
+/// With `workspace-pin-policy = "warn"`, `uv lock` warns about dependencies on workspace members
+/// without a version specifier.
+#[test]
+fn workspace_pin_policy_warn() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let workspace = context.temp_dir.child("workspace");
+    workspace.child("pyproject.toml").write_str(indoc! {r#"
+        [tool.uv]
+        workspace-pin-policy = "warn"
+
+        [tool.uv.workspace]
+        members = ["packages/*"]
+    "#})?;
+
+    let deps = indoc! {r#"
+        dependencies = ["bar", "baz>=0.1"]
+
+        [tool.uv.sources]
+        bar = { workspace = true }
+        baz = { workspace = true }
+    "#};
+    make_project(&workspace.join("packages").join("foo"), "foo", deps)?;
+    make_project(&workspace.join("packages").join("bar"), "bar", "")?;
+    make_project(&workspace.join("packages").join("baz"), "baz", "")?;
+
+    uv_snapshot!(context.filters(), context.lock().current_dir(&workspace), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    warning: `foo` depends on workspace member `bar` without a version specifier; published distributions of `foo` will accept any version of `bar`
+    Resolved 3 packages in [TIME]
+    "
+    );
+
+    Ok(())
+}
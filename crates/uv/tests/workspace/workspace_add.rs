@@ -0,0 +1,132 @@
+use anyhow::Result;
+use assert_fs::fixture::{FileWriteStr, PathChild, PathCreateDir};
+use indoc::indoc;
+use insta::assert_snapshot;
+
+use uv_test::uv_snapshot;
+
+/// Add a library member that isn't covered by the existing `members` globs, and depend on it from
+/// an existing member.
+#[test]
+fn workspace_add_lib_for_member() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    context.temp_dir.child("pyproject.toml").write_str(indoc! {
+        r#"
+        [project]
+        name = "root"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv.workspace]
+        members = ["packages/*"]
+        "#
+    })?;
+    let api = context.temp_dir.child("packages/api");
+    api.create_dir_all()?;
+    api.child("pyproject.toml").write_str(indoc! {
+        r#"
+        [project]
+        name = "api"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.workspace_add()
+        .arg("libs/newlib")
+        .arg("--lib")
+        .arg("--for")
+        .arg("api")
+        .arg("--preview-features")
+        .arg("workspace-members"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Adding `newlib` as member of workspace `[TEMP_DIR]/`
+    Initialized project `newlib` at `[TEMP_DIR]/libs/newlib`
+    Added `newlib` as a dependency of `api`
+    ");
+
+    assert_snapshot!(context.read("pyproject.toml"), @r#"
+    [project]
+    name = "root"
+    version = "0.1.0"
+    requires-python = ">=3.12"
+    dependencies = []
+
+    [tool.uv.workspace]
+    members = [
+        "packages/*",
+        "libs/newlib",
+    ]
+    "#);
+
+    assert_snapshot!(context.read("packages/api/pyproject.toml"), @r#"
+    [project]
+    name = "api"
+    version = "0.1.0"
+    requires-python = ">=3.12"
+    dependencies = [
+        "newlib",
+    ]
+
+    [tool.uv.sources]
+    newlib = { workspace = true }
+    "#);
+
+    // A member that's already covered by a glob isn't added to `members` again.
+    uv_snapshot!(context.filters(), context.workspace_add()
+        .arg("packages/web")
+        .arg("--preview-features")
+        .arg("workspace-members"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Project `web` is already a member of workspace `[TEMP_DIR]/`
+    Initialized project `web` at `[TEMP_DIR]/packages/web`
+    ");
+
+    uv_snapshot!(context.filters(), context.workspace_list(), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    api
+    newlib
+    root
+    web
+    ");
+
+    Ok(())
+}
+
+/// `--for` must refer to an existing workspace member.
+#[test]
+fn workspace_add_for_missing_member() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    context.temp_dir.child("pyproject.toml").write_str(indoc! {
+        r#"
+        [project]
+        name = "root"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.workspace_add()
+        .arg("libs/newlib")
+        .arg("--for")
+        .arg("api")
+        .arg("--preview-features")
+        .arg("workspace-members"), @"
+    exit_code: 2 (error)
+    ----- stderr -----
+    error: Package `api` not found in workspace
+    ");
+
+    // No files should have been created.
+    assert!(!context.temp_dir.child("libs").path().exists());
+
+    Ok(())
+}
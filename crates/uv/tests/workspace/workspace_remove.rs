@@ -0,0 +1,197 @@
+use anyhow::Result;
+use assert_fs::fixture::{FileWriteStr, PathChild, PathCreateDir};
+use indoc::indoc;
+use insta::assert_snapshot;
+
+use uv_test::uv_snapshot;
+
+fn write_member(context: &uv_test::TestContext, path: &str, contents: &str) -> Result<()> {
+    let member = context.temp_dir.child(path);
+    member.create_dir_all()?;
+    member.child("pyproject.toml").write_str(contents)?;
+    Ok(())
+}
+
+/// Remove a member that's included by a glob and still depended on by another member.
+#[test]
+fn workspace_remove_globbed_member() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    context.temp_dir.child("pyproject.toml").write_str(indoc! {
+        r#"
+        [project]
+        name = "root"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv.workspace]
+        members = ["packages/*"]
+        "#
+    })?;
+    write_member(
+        &context,
+        "packages/api",
+        indoc! {
+            r#"
+            [project]
+            name = "api"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = ["lib"]
+
+            [tool.uv.sources]
+            lib = { workspace = true }
+            "#
+        },
+    )?;
+    write_member(
+        &context,
+        "packages/lib",
+        indoc! {
+            r#"
+            [project]
+            name = "lib"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = []
+            "#
+        },
+    )?;
+
+    uv_snapshot!(context.filters(), context.workspace_remove()
+        .arg("lib")
+        .arg("--preview-features")
+        .arg("workspace-members"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Removed workspace source for `lib` from `packages/api/pyproject.toml`
+    Removed `lib` from workspace `[TEMP_DIR]/`
+    warning: `api` depends on `lib`, which is no longer a workspace member; remove the dependency with `uv remove lib --package api`
+    ");
+
+    // The member is excluded, since it's still matched by the glob.
+    assert_snapshot!(context.read("pyproject.toml"), @r#"
+    [project]
+    name = "root"
+    version = "0.1.0"
+    requires-python = ">=3.12"
+    dependencies = []
+
+    [tool.uv.workspace]
+    members = ["packages/*"]
+    exclude = [
+        "packages/lib",
+    ]
+    "#);
+
+    // The dependency is left in place, but the source is removed.
+    assert_snapshot!(context.read("packages/api/pyproject.toml"), @r#"
+    [project]
+    name = "api"
+    version = "0.1.0"
+    requires-python = ">=3.12"
+    dependencies = ["lib"]
+    "#);
+
+    // The files are left in place.
+    assert!(
+        context
+            .temp_dir
+            .child("packages/lib/pyproject.toml")
+            .path()
+            .exists()
+    );
+
+    uv_snapshot!(context.filters(), context.workspace_list(), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    api
+    root
+    ");
+
+    Ok(())
+}
+
+/// Remove an explicitly listed member and delete its files.
+#[test]
+fn workspace_remove_delete() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    context.temp_dir.child("pyproject.toml").write_str(indoc! {
+        r#"
+        [project]
+        name = "root"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv.workspace]
+        members = ["api", "lib"]
+        "#
+    })?;
+    write_member(
+        &context,
+        "api",
+        indoc! {
+            r#"
+            [project]
+            name = "api"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = []
+            "#
+        },
+    )?;
+    write_member(
+        &context,
+        "lib",
+        indoc! {
+            r#"
+            [project]
+            name = "lib"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = []
+            "#
+        },
+    )?;
+
+    uv_snapshot!(context.filters(), context.workspace_remove()
+        .arg("lib")
+        .arg("--delete")
+        .arg("--preview-features")
+        .arg("workspace-members"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Removed `lib` from workspace `[TEMP_DIR]/`
+    Deleted `lib`
+    ");
+
+    assert_snapshot!(context.read("pyproject.toml"), @r#"
+    [project]
+    name = "root"
+    version = "0.1.0"
+    requires-python = ">=3.12"
+    dependencies = []
+
+    [tool.uv.workspace]
+    members = [
+        "api",
+    ]
+    "#);
+
+    assert!(!context.temp_dir.child("lib").path().exists());
+
+    // The workspace root can't be removed.
+    uv_snapshot!(context.filters(), context.workspace_remove()
+        .arg("root")
+        .arg("--preview-features")
+        .arg("workspace-members"), @"
+    exit_code: 2 (error)
+    ----- stderr -----
+    error: Cannot remove the workspace root `root` from the workspace
+    ");
+
+    Ok(())
+}
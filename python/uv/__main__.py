@@ -2,6 +2,7 @@ import os
 import sys
 
 from uv import find_uv_bin
+from uv._find_uv import UvNotFound
 
 
 def _detect_virtualenv() -> str:
@@ -24,7 +25,18 @@ def _detect_virtualenv() -> str:
 
 
 def _run() -> None:
-    uv = find_uv_bin()
+    try:
+        uv = find_uv_bin()
+    except UvNotFound as err:
+        # The binary is missing, e.g., if the package was built from a source distribution
+        # without the binary, or if the binary was removed after installation.
+        sys.stderr.write(f"error: {err}")
+        sys.stderr.write(
+            "hint: The uv binary is not included when the `uv` package is installed from a source "
+            "distribution; reinstall it from a wheel, or see "
+            "https://docs.astral.sh/uv/getting-started/installation/\n"
+        )
+        sys.exit(1)
 
     env = os.environ.copy()
     venv = _detect_virtualenv()
@@ -37,17 +49,22 @@ def _run() -> None:
     if sys.platform == "win32":
         import subprocess
 
-        # Avoid emitting a traceback on interrupt
-        try:
-            completed_process = subprocess.run(
-                [uv, *sys.argv[1:]], env=env, check=False
-            )
-        except KeyboardInterrupt:
-            sys.exit(2)
+        process = subprocess.Popen([uv, *sys.argv[1:]], env=env)
 
-        sys.exit(completed_process.returncode)
+        # The console delivers interrupts to both processes; rather than emitting a traceback,
+        # wait for uv to handle the interrupt and exit, then propagate its exit code.
+        while True:
+            try:
+                returncode = process.wait()
+                break
+            except KeyboardInterrupt:
+                continue
+
+        sys.exit(returncode)
     else:
-        os.execvpe(uv, [uv, *sys.argv[1:]], env=env)
+        # Replace the current process, such that signals are delivered to uv directly and its
+        # exit code is propagated as-is.
+        os.execve(uv, [uv, *sys.argv[1:]], env)
 
 
 if __name__ == "__main__":